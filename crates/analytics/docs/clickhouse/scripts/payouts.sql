@@ -28,6 +28,9 @@ CREATE TABLE payout_queue (
     `business_country` LowCardinality(Nullable(String)),
     `business_label` Nullable(String),
     `merchant_connector_id` Nullable(String),
+    `fee` Nullable(UInt64),
+    `net_amount` Nullable(Int64),
+    `fee_source` LowCardinality(Nullable(String)),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payout-events',
@@ -65,6 +68,9 @@ CREATE TABLE payout (
     `business_country` LowCardinality(Nullable(String)),
     `business_label` Nullable(String),
     `merchant_connector_id` Nullable(String),
+    `fee` Nullable(UInt64),
+    `net_amount` Nullable(Int64),
+    `fee_source` LowCardinality(Nullable(String)),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `sign_flag` Int8,
     INDEX payoutTypeIndex payout_type TYPE bloom_filter GRANULARITY 1,
@@ -107,6 +113,9 @@ CREATE MATERIALIZED VIEW payout_mv TO payout (
     `business_country` LowCardinality(Nullable(String)),
     `business_label` Nullable(String),
     `merchant_connector_id` Nullable(String),
+    `fee` Nullable(UInt64),
+    `net_amount` Nullable(Int64),
+    `fee_source` LowCardinality(Nullable(String)),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `sign_flag` Int8,
 ) AS
@@ -140,6 +149,9 @@ SELECT
     business_country,
     business_label,
     merchant_connector_id,
+    fee,
+    net_amount,
+    fee_source,
     now() as inserted_at,
     sign_flag
 FROM
@@ -11,7 +11,7 @@ use common_utils::{
 use common_utils::{crypto::OptionalEncryptableName, ext_traits::ValueExt};
 #[cfg(feature = "v2")]
 use masking::ExposeInterface;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use url;
 use utoipa::ToSchema;
@@ -20,7 +20,9 @@ use super::payments::AddressDetails;
 #[cfg(feature = "v1")]
 use crate::routing;
 use crate::{
-    consts::{MAX_ORDER_FULFILLMENT_EXPIRY, MIN_ORDER_FULFILLMENT_EXPIRY},
+    consts::{
+        MAX_AUTO_REDIRECT_TIMEOUT_MS, MAX_ORDER_FULFILLMENT_EXPIRY, MIN_ORDER_FULFILLMENT_EXPIRY,
+    },
     enums as api_enums, payment_methods,
 };
 
@@ -29,6 +31,66 @@ pub struct MerchantAccountListRequest {
     pub organization_id: id_type::OrganizationId,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantAccountSearchRequest {
+    /// Case-insensitive substring to be matched against the merchant name
+    #[schema(example = "NewAge Retailer")]
+    pub merchant_name: Option<String>,
+
+    /// The publishable key of the merchant account
+    #[schema(example = "AH3423bkjbkjdsfbkj")]
+    pub publishable_key: Option<String>,
+
+    /// Only return merchant accounts created at or after this time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.gte"
+    )]
+    pub created_gte: Option<time::PrimitiveDateTime>,
+
+    /// Only return merchant accounts created at or before this time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lte"
+    )]
+    pub created_lte: Option<time::PrimitiveDateTime>,
+
+    /// The reconciliation status of the merchant account
+    #[schema(value_type = Option<ReconStatus>, example = "not_requested")]
+    pub recon_status: Option<api_enums::ReconStatus>,
+
+    /// Limit on the number of merchant accounts to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_merchant_account_search_limit")]
+    pub limit: u32,
+
+    /// The number of merchant accounts to skip
+    #[serde(default)]
+    pub offset: u32,
+}
+
+#[cfg(feature = "v1")]
+fn default_merchant_account_search_limit() -> u32 {
+    10
+}
+
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, ToSchema, Serialize)]
+pub struct MerchantAccountSearchResponse {
+    /// The number of merchant accounts included in this response
+    pub count: usize,
+    /// The total number of merchant accounts matching the search filters
+    pub total_count: usize,
+    /// The merchant accounts matching the search filters
+    pub data: Vec<MerchantAccountResponse>,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -108,6 +170,15 @@ pub struct MerchantAccountCreate {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// Prefixes to be used for the ids generated for payments, refunds and payouts of the merchant
+    #[schema(value_type = Option<MerchantIdPrefixes>)]
+    pub id_prefixes: Option<MerchantIdPrefixes>,
+
+    /// Retention periods of the different classes of data stored for the merchant, after which the
+    /// data is purged
+    #[schema(value_type = Option<MerchantDataRetentionPolicy>)]
+    pub data_retention_policy: Option<MerchantDataRetentionPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -140,6 +211,24 @@ impl MerchantAccountCreate {
             .transpose()
     }
 
+    pub fn get_id_prefixes_as_value(
+        &self,
+    ) -> CustomResult<Option<serde_json::Value>, errors::ParsingError> {
+        self.id_prefixes
+            .as_ref()
+            .map(|id_prefixes| id_prefixes.encode_to_value())
+            .transpose()
+    }
+
+    pub fn get_data_retention_policy_as_value(
+        &self,
+    ) -> CustomResult<Option<serde_json::Value>, errors::ParsingError> {
+        self.data_retention_policy
+            .as_ref()
+            .map(|data_retention_policy| data_retention_policy.encode_to_value())
+            .transpose()
+    }
+
     pub fn get_merchant_details_as_secret(
         &self,
     ) -> CustomResult<Option<pii::SecretSerdeValue>, errors::ParsingError> {
@@ -237,7 +326,8 @@ impl MerchantAccountCreate {
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct AuthenticationConnectorDetails {
-    /// List of authentication connectors
+    /// List of authentication connectors, in the order they are attempted. The authentication
+    /// fails over to the next connector if the 3DS server of a connector is unavailable
     #[schema(value_type = Vec<AuthenticationConnectors>)]
     pub authentication_connectors: Vec<common_enums::AuthenticationConnectors>,
     /// URL of the (customer service) website that will be shown to the shopper in case of technical errors during the 3D Secure 2 process.
@@ -329,6 +419,15 @@ pub struct MerchantAccountUpdate {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// Prefixes to be used for the ids generated for payments, refunds and payouts of the merchant
+    #[schema(value_type = Option<MerchantIdPrefixes>)]
+    pub id_prefixes: Option<MerchantIdPrefixes>,
+
+    /// Retention periods of the different classes of data stored for the merchant, after which the
+    /// data is purged
+    #[schema(value_type = Option<MerchantDataRetentionPolicy>)]
+    pub data_retention_policy: Option<MerchantDataRetentionPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -351,6 +450,24 @@ impl MerchantAccountUpdate {
             .transpose()
     }
 
+    pub fn get_id_prefixes_as_value(
+        &self,
+    ) -> CustomResult<Option<serde_json::Value>, errors::ParsingError> {
+        self.id_prefixes
+            .as_ref()
+            .map(|id_prefixes| id_prefixes.encode_to_value())
+            .transpose()
+    }
+
+    pub fn get_data_retention_policy_as_value(
+        &self,
+    ) -> CustomResult<Option<serde_json::Value>, errors::ParsingError> {
+        self.data_retention_policy
+            .as_ref()
+            .map(|data_retention_policy| data_retention_policy.encode_to_value())
+            .transpose()
+    }
+
     pub fn get_merchant_details_as_secret(
         &self,
     ) -> CustomResult<Option<pii::SecretSerdeValue>, errors::ParsingError> {
@@ -522,6 +639,15 @@ pub struct MerchantAccountResponse {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// Prefixes to be used for the ids generated for payments, refunds and payouts of the merchant
+    #[schema(value_type = Option<MerchantIdPrefixes>)]
+    pub id_prefixes: Option<MerchantIdPrefixes>,
+
+    /// Retention periods of the different classes of data stored for the merchant, after which the
+    /// data is purged
+    #[schema(value_type = Option<MerchantDataRetentionPolicy>)]
+    pub data_retention_policy: Option<MerchantDataRetentionPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -648,6 +774,33 @@ pub struct MerchantAccountDeleteResponse {
     pub deleted: bool,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RotatePublishableKeyRequest {
+    /// The period in seconds for which the previous publishable key remains valid after the
+    /// rotation, before it is revoked. Defaults to 86400 (1 day), and can be at most 604800 (7 days).
+    #[schema(example = 86400, maximum = 604800)]
+    pub overlap_period_in_secs: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RotatePublishableKeyResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The new publishable key of the merchant account
+    #[schema(example = "pk_snd_3b33cd9404234113804aa1accaabe22f")]
+    pub publishable_key: String,
+    /// The previous publishable key of the merchant account, which remains valid until
+    /// `previous_publishable_key_expires_at`
+    #[schema(example = "pk_snd_0b8e4b2b7d0c4a55a8a8a1c5e2f1d3c4")]
+    pub previous_publishable_key: String,
+    /// The time at which the previous publishable key is revoked
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub previous_publishable_key_expires_at: time::PrimitiveDateTime,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize)]
 pub struct MerchantId {
     pub merchant_id: id_type::MerchantId,
@@ -747,6 +900,14 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     // By default the ConnectorStatus is Active
     pub status: Option<api_enums::ConnectorStatus>,
@@ -889,6 +1050,14 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
 
@@ -1114,6 +1283,14 @@ pub struct MerchantConnectorResponse {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
 
@@ -1237,6 +1414,14 @@ pub struct MerchantConnectorResponse {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
 
@@ -1258,6 +1443,13 @@ impl MerchantConnectorResponse {
     }
 }
 
+/// Query parameters for listing the connector accounts of a merchant
+#[derive(Debug, Default, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantConnectorListConstraints {
+    /// Only the connector accounts tagged with this tag are listed
+    pub tag: Option<String>,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -1343,6 +1535,14 @@ pub struct MerchantConnectorListResponse {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
 }
@@ -1426,6 +1626,14 @@ pub struct MerchantConnectorListResponse {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
 }
@@ -1517,6 +1725,14 @@ pub struct MerchantConnectorUpdate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
 
@@ -1623,6 +1839,14 @@ pub struct MerchantConnectorUpdate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Free-form tags to group connector accounts logically, these can be used to filter the connector accounts list and in `connector_tag` predicates of routing rules
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The expiry of the credentials of the connector account, such as its API keys and Apple Pay certificates. The merchant is alerted through webhooks and emails ahead of their expiry
+    #[schema(value_type = Option<ConnectorCredentialsExpiry>)]
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
 
@@ -1755,6 +1979,224 @@ pub struct MerchantConnectorDeleteResponse {
     pub deleted: bool,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorBulkToggleRequest {
+    /// Toggle all the connector accounts of this connector, across profiles
+    #[schema(value_type = Option<Connector>, example = "stripe")]
+    pub connector_name: Option<api_enums::Connector>,
+    /// Toggle all the connector accounts of this profile
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Whether the matching connector accounts should be disabled or enabled
+    #[schema(example = true)]
+    pub disabled: bool,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantConnectorBulkToggleResponse {
+    /// The number of connector accounts that were updated
+    pub count: usize,
+    /// The connector accounts that were updated
+    pub data: Vec<MerchantConnectorToggleResponse>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantConnectorToggleResponse {
+    /// Unique ID of the connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// Name of the Connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+    /// The id of the profile the connector account belongs to
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+    /// A boolean value to indicate if the connector is disabled
+    #[schema(example = false)]
+    pub disabled: Option<bool>,
+    /// The status of the connector account
+    #[schema(value_type = ConnectorStatus, example = "active")]
+    pub status: api_enums::ConnectorStatus,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantConnectorWebhookHealthResponse {
+    /// Unique ID of the connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// Name of the Connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+    /// The id of the profile the connector account belongs to
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+    /// The time at which the last webhook from the connector was received
+    #[schema(example = "2024-12-23T10:15:30Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_received_at: Option<time::PrimitiveDateTime>,
+    /// The number of webhooks received from the connector
+    pub received_count: u64,
+    /// The number of webhooks whose source verification failed
+    pub verification_failure_count: u64,
+    /// The time at which the source verification of a webhook last failed
+    #[schema(example = "2024-12-23T10:15:30Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_verification_failure_at: Option<time::PrimitiveDateTime>,
+    /// The number of webhooks which could not be processed
+    pub processing_error_count: u64,
+    /// The time at which the processing of a webhook last failed
+    #[schema(example = "2024-12-23T10:15:30Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_processing_error_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The expiry of the credentials of a connector account, which have to be renewed with the connector before they expire
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorCredentialsExpiry {
+    /// The credentials of the connector account which expire
+    pub credentials: Vec<ConnectorCredentialExpiry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorCredentialExpiry {
+    /// The type of the credential
+    #[schema(value_type = ConnectorCredentialType, example = "apple_pay_certificate")]
+    pub credential_type: api_enums::ConnectorCredentialType,
+    /// The time at which the credential expires
+    #[schema(value_type = String, example = "2025-11-30T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+    /// A description to identify the credential, such as the name of the certificate
+    #[schema(example = "Apple Pay merchant identity certificate")]
+    pub description: Option<String>,
+}
+
+/// Query parameters for listing the connector credentials of a merchant which are about to expire
+#[cfg(feature = "v1")]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExpiringConnectorCredentialsListConstraints {
+    /// The credentials expiring within this many days are listed, defaults to 30 days
+    #[schema(example = 30)]
+    pub within_days: Option<u16>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExpiringConnectorCredentialsResponse {
+    /// Unique ID of the connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// Name of the Connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+    /// A unique label to identify the connector account created under a profile
+    #[schema(example = "stripe_US_travel")]
+    pub connector_label: Option<String>,
+    /// The id of the profile the connector account belongs to
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+    /// The credentials of the connector account which are about to expire
+    pub credentials: Vec<ExpiringConnectorCredential>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExpiringConnectorCredential {
+    /// The type of the credential
+    #[schema(value_type = ConnectorCredentialType, example = "apple_pay_certificate")]
+    pub credential_type: api_enums::ConnectorCredentialType,
+    /// The time at which the credential expires
+    #[schema(value_type = String, example = "2025-11-30T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+    /// A description to identify the credential, such as the name of the certificate
+    #[schema(example = "Apple Pay merchant identity certificate")]
+    pub description: Option<String>,
+    /// The number of whole days left until the credential expires
+    #[schema(example = 7)]
+    pub days_to_expiry: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMaintenanceWindowCreateRequest {
+    /// The time at which the maintenance window starts, in the `timezone` of the window
+    #[schema(value_type = String, example = "2024-11-10T02:00:00")]
+    #[serde(with = "common_utils::custom_serde::iso8601::without_timezone")]
+    pub start_time: time::PrimitiveDateTime,
+
+    /// The time at which the maintenance window ends, in the `timezone` of the window
+    #[schema(value_type = String, example = "2024-11-10T04:30:00")]
+    #[serde(with = "common_utils::custom_serde::iso8601::without_timezone")]
+    pub end_time: time::PrimitiveDateTime,
+
+    /// The UTC offset in which `start_time` and `end_time` are expressed. Defaults to UTC
+    #[schema(example = "+05:30")]
+    pub timezone: Option<String>,
+
+    /// A description of the maintenance, for reference
+    #[schema(example = "Scheduled processor upgrade")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorMaintenanceWindowResponse {
+    /// The identifier of the maintenance window
+    #[schema(example = "mw_9cLd3oXyJDBtDAqFwP0z")]
+    pub maintenance_window_id: String,
+
+    /// The connector account which is under maintenance during the window
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// Name of the connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+
+    /// The time at which the maintenance window starts, in UTC
+    #[schema(value_type = PrimitiveDateTime, example = "2024-11-09T20:30:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+
+    /// The time at which the maintenance window ends, in UTC
+    #[schema(value_type = PrimitiveDateTime, example = "2024-11-09T23:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+
+    /// The UTC offset in which the maintenance window was scheduled
+    #[schema(example = "+05:30")]
+    pub timezone: String,
+
+    /// A description of the maintenance, for reference
+    #[schema(example = "Scheduled processor upgrade")]
+    pub description: Option<String>,
+
+    /// The time at which the maintenance window was created
+    #[schema(value_type = PrimitiveDateTime, example = "2024-11-01T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+impl ConnectorMaintenanceWindowResponse {
+    /// Whether the connector account is under maintenance at the given time
+    pub fn is_active_at(&self, time: time::PrimitiveDateTime) -> bool {
+        self.start_time <= time && time < self.end_time
+    }
+
+    /// Whether the maintenance window has already ended at the given time
+    pub fn is_expired_at(&self, time: time::PrimitiveDateTime) -> bool {
+        self.end_time <= time
+    }
+}
+
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MerchantConnectorDeleteResponse {
@@ -1944,6 +2386,99 @@ pub struct ProfileCreate {
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
 
+    /// Default configuration for customer portal links created under this profile
+    #[schema(value_type = Option<BusinessGenericLinkConfig>)]
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+
+    /// Configuration for invoices generated for payments made under this profile
+    #[schema(value_type = Option<BusinessInvoiceConfig>)]
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+
+    /// The event classes for which outgoing webhooks are sent to the merchant. Webhooks are sent for all event classes if not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["payments", "refunds"]))]
+    pub enabled_webhook_events: Option<Vec<api_enums::EventClass>>,
+
+    /// The version of the payload schema used for outgoing webhooks. Defaults to `v1` if not set
+    #[schema(value_type = Option<WebhookPayloadVersion>, example = "v1")]
+    pub webhook_payload_version: Option<api_enums::WebhookPayloadVersion>,
+
+    /// Default statement descriptor for payments made under this profile, used when the payment does not specify one
+    #[schema(value_type = Option<BusinessStatementDescriptorConfig>)]
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+
+    /// Configuration for automatically representing low value disputes using evidence assembled from templates
+    #[schema(value_type = Option<BusinessDisputeRepresentmentConfig>)]
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+
+    /// Mutual TLS client certificate or OAuth 2.0 client credentials used to authenticate the outgoing webhook requests
+    #[schema(value_type = Option<OutgoingWebhookAuthConfig>)]
+    pub outgoing_webhook_auth: Option<OutgoingWebhookAuthConfig>,
+
+    /// Branding and behaviour of the pages shown while redirecting the customer for 3DS or other
+    /// authentication, and the domains allowed in the `return_url` of payments
+    #[schema(value_type = Option<BusinessRedirectConfig>)]
+    pub redirect_config: Option<BusinessRedirectConfig>,
+
+    /// Origins from which the client side calls authenticated with the client secret, like sessions, confirm and payment methods list, are allowed
+    #[schema(value_type = Option<BusinessClientOriginConfig>)]
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+
+    /// Whether the client secret of a payment can be used to confirm the payment only once. A new client secret is issued in the response of every confirm made with the client secret
+    #[schema(default = false, example = false)]
+    pub is_client_secret_single_use: Option<bool>,
+
+    /// Rules evaluated against the AVS and CVV results of a payment after it has been authorized, like voiding the authorization on a full AVS mismatch
+    #[schema(value_type = Option<BusinessPostAuthRulesConfig>)]
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+
+    /// The currencies in which payments and payouts can be created under this profile. Payments and payouts of all currencies are allowed if not set
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub allowed_currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Free-form tags to group business profiles logically, these can be used to filter the business profiles list
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// Retry policy for the payouts created under this profile, which takes precedence over the payout retry configs of the merchant
+    #[schema(value_type = Option<BusinessPayoutRetryConfig>)]
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+
+    /// Time of the day windows within which payouts created under this profile with `auto_fulfill` are fulfilled, the fulfillment of payouts created outside the windows is deferred until the next window
+    #[schema(value_type = Option<BusinessPayoutFulfillmentConfig>)]
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+
+    /// Defaults of the capture method, setup future usage and authentication type for the payments created under this profile, applied when they are not passed in the payment request
+    #[schema(value_type = Option<BusinessPaymentDefaultsConfig>)]
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+
+    /// Whether the authorized payments created under this profile are captured when their shipment is notified through the shipments endpoint of the payment
+    #[schema(value_type = Option<BusinessCaptureOnShipmentConfig>)]
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+
+    /// The dynamic currency conversion offered to the customers paying with a card in a currency other than the currency of the payment, through the connectors which support it
+    #[schema(value_type = Option<BusinessDccConfig>)]
+    pub dcc_config: Option<BusinessDccConfig>,
+
+    /// The fee schedules of the payout connectors, from which the fees of the payouts are estimated when the connector does not report the fee it charged
+    #[schema(value_type = Option<BusinessPayoutFeeConfig>)]
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+
+    /// The rules which hold back a percentage of the payouts of the profile in its ledger balance, and block the payouts which the balance does not cover net of the refunds and chargebacks
+    #[schema(value_type = Option<BusinessPayoutBalanceConfig>)]
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+
+    /// Groups of connector accounts of the same connector which hold the same merchant account credentials. A capture, void or refund of a payment, whose connector account has been disabled or deleted since the payment was processed, is processed through another connector account in its group
+    #[schema(value_type = Option<BusinessOperationFallbackConfig>)]
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+
+    /// Whether the payouts to UK and EU bank accounts created under this profile are fulfilled when the name of the recipient does not match the name of the account holder, as verified by the Confirmation of Payee service. The name of the account holder is not verified if this is not set
+    #[schema(value_type = Option<AccountNameMismatchAction>, example = "block_on_no_match")]
+    pub account_name_mismatch_action: Option<api_enums::AccountNameMismatchAction>,
+
+    /// The gradual rollout of the traffic of an incumbent connector account to a new canary connector account, which is rolled back when its success rate drops below that of the incumbent by more than the configured margin. Passing this starts a new rollout
+    #[schema(value_type = Option<ConnectorCanaryRolloutConfig>)]
+    pub connector_canary_rollout: Option<ConnectorCanaryRolloutConfig>,
+
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
@@ -2062,6 +2597,99 @@ pub struct ProfileCreate {
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
 
+    /// Default configuration for customer portal links created under this profile
+    #[schema(value_type = Option<BusinessGenericLinkConfig>)]
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+
+    /// Configuration for invoices generated for payments made under this profile
+    #[schema(value_type = Option<BusinessInvoiceConfig>)]
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+
+    /// The event classes for which outgoing webhooks are sent to the merchant. Webhooks are sent for all event classes if not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["payments", "refunds"]))]
+    pub enabled_webhook_events: Option<Vec<api_enums::EventClass>>,
+
+    /// The version of the payload schema used for outgoing webhooks. Defaults to `v1` if not set
+    #[schema(value_type = Option<WebhookPayloadVersion>, example = "v1")]
+    pub webhook_payload_version: Option<api_enums::WebhookPayloadVersion>,
+
+    /// Default statement descriptor for payments made under this profile, used when the payment does not specify one
+    #[schema(value_type = Option<BusinessStatementDescriptorConfig>)]
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+
+    /// Configuration for automatically representing low value disputes using evidence assembled from templates
+    #[schema(value_type = Option<BusinessDisputeRepresentmentConfig>)]
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+
+    /// Mutual TLS client certificate or OAuth 2.0 client credentials used to authenticate the outgoing webhook requests
+    #[schema(value_type = Option<OutgoingWebhookAuthConfig>)]
+    pub outgoing_webhook_auth: Option<OutgoingWebhookAuthConfig>,
+
+    /// Branding and behaviour of the pages shown while redirecting the customer for 3DS or other
+    /// authentication, and the domains allowed in the `return_url` of payments
+    #[schema(value_type = Option<BusinessRedirectConfig>)]
+    pub redirect_config: Option<BusinessRedirectConfig>,
+
+    /// Origins from which the client side calls authenticated with the client secret, like sessions, confirm and payment methods list, are allowed
+    #[schema(value_type = Option<BusinessClientOriginConfig>)]
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+
+    /// Whether the client secret of a payment can be used to confirm the payment only once. A new client secret is issued in the response of every confirm made with the client secret
+    #[schema(default = false, example = false)]
+    pub is_client_secret_single_use: Option<bool>,
+
+    /// Rules evaluated against the AVS and CVV results of a payment after it has been authorized, like voiding the authorization on a full AVS mismatch
+    #[schema(value_type = Option<BusinessPostAuthRulesConfig>)]
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+
+    /// The currencies in which payments and payouts can be created under this profile. Payments and payouts of all currencies are allowed if not set
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub allowed_currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Free-form tags to group business profiles logically, these can be used to filter the business profiles list
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// Retry policy for the payouts created under this profile, which takes precedence over the payout retry configs of the merchant
+    #[schema(value_type = Option<BusinessPayoutRetryConfig>)]
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+
+    /// Time of the day windows within which payouts created under this profile with `auto_fulfill` are fulfilled, the fulfillment of payouts created outside the windows is deferred until the next window
+    #[schema(value_type = Option<BusinessPayoutFulfillmentConfig>)]
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+
+    /// Defaults of the capture method, setup future usage and authentication type for the payments created under this profile, applied when they are not passed in the payment request
+    #[schema(value_type = Option<BusinessPaymentDefaultsConfig>)]
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+
+    /// Whether the authorized payments created under this profile are captured when their shipment is notified through the shipments endpoint of the payment
+    #[schema(value_type = Option<BusinessCaptureOnShipmentConfig>)]
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+
+    /// The dynamic currency conversion offered to the customers paying with a card in a currency other than the currency of the payment, through the connectors which support it
+    #[schema(value_type = Option<BusinessDccConfig>)]
+    pub dcc_config: Option<BusinessDccConfig>,
+
+    /// The fee schedules of the payout connectors, from which the fees of the payouts are estimated when the connector does not report the fee it charged
+    #[schema(value_type = Option<BusinessPayoutFeeConfig>)]
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+
+    /// The rules which hold back a percentage of the payouts of the profile in its ledger balance, and block the payouts which the balance does not cover net of the refunds and chargebacks
+    #[schema(value_type = Option<BusinessPayoutBalanceConfig>)]
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+
+    /// Groups of connector accounts of the same connector which hold the same merchant account credentials. A capture, void or refund of a payment, whose connector account has been disabled or deleted since the payment was processed, is processed through another connector account in its group
+    #[schema(value_type = Option<BusinessOperationFallbackConfig>)]
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+
+    /// Whether the payouts to UK and EU bank accounts created under this profile are fulfilled when the name of the recipient does not match the name of the account holder, as verified by the Confirmation of Payee service. The name of the account holder is not verified if this is not set
+    #[schema(value_type = Option<AccountNameMismatchAction>, example = "block_on_no_match")]
+    pub account_name_mismatch_action: Option<api_enums::AccountNameMismatchAction>,
+
+    /// The gradual rollout of the traffic of an incumbent connector account to a new canary connector account, which is rolled back when its success rate drops below that of the incumbent by more than the configured margin. Passing this starts a new rollout
+    #[schema(value_type = Option<ConnectorCanaryRolloutConfig>)]
+    pub connector_canary_rollout: Option<ConnectorCanaryRolloutConfig>,
+
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
@@ -2084,6 +2712,13 @@ pub struct ProfileCreate {
     pub is_click_to_pay_enabled: bool,
 }
 
+/// Query parameters for listing the business profiles of a merchant
+#[derive(Debug, Default, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProfileListConstraints {
+    /// Only the business profiles tagged with this tag are listed
+    pub tag: Option<String>,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Clone, Debug, ToSchema, Serialize)]
 pub struct ProfileResponse {
@@ -2188,6 +2823,99 @@ pub struct ProfileResponse {
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
 
+    /// Default configuration for customer portal links created under this profile
+    #[schema(value_type = Option<BusinessGenericLinkConfig>)]
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+
+    /// Configuration for invoices generated for payments made under this profile
+    #[schema(value_type = Option<BusinessInvoiceConfig>)]
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+
+    /// The event classes for which outgoing webhooks are sent to the merchant. Webhooks are sent for all event classes if not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["payments", "refunds"]))]
+    pub enabled_webhook_events: Option<Vec<api_enums::EventClass>>,
+
+    /// The version of the payload schema used for outgoing webhooks
+    #[schema(value_type = WebhookPayloadVersion, example = "v1")]
+    pub webhook_payload_version: api_enums::WebhookPayloadVersion,
+
+    /// Default statement descriptor for payments made under this profile, used when the payment does not specify one
+    #[schema(value_type = Option<BusinessStatementDescriptorConfig>)]
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+
+    /// Configuration for automatically representing low value disputes using evidence assembled from templates
+    #[schema(value_type = Option<BusinessDisputeRepresentmentConfig>)]
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+
+    /// Authentication applied to the outgoing webhook requests, without any of the credentials
+    #[schema(value_type = Option<OutgoingWebhookAuthConfigResponse>)]
+    pub outgoing_webhook_auth: Option<OutgoingWebhookAuthConfigResponse>,
+
+    /// Branding and behaviour of the pages shown while redirecting the customer for 3DS or other
+    /// authentication, and the domains allowed in the `return_url` of payments
+    #[schema(value_type = Option<BusinessRedirectConfig>)]
+    pub redirect_config: Option<BusinessRedirectConfig>,
+
+    /// Origins from which the client side calls authenticated with the client secret, like sessions, confirm and payment methods list, are allowed
+    #[schema(value_type = Option<BusinessClientOriginConfig>)]
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+
+    /// Whether the client secret of a payment can be used to confirm the payment only once. A new client secret is issued in the response of every confirm made with the client secret
+    #[schema(default = false, example = false)]
+    pub is_client_secret_single_use: Option<bool>,
+
+    /// Rules evaluated against the AVS and CVV results of a payment after it has been authorized, like voiding the authorization on a full AVS mismatch
+    #[schema(value_type = Option<BusinessPostAuthRulesConfig>)]
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+
+    /// The currencies in which payments and payouts can be created under this profile. Payments and payouts of all currencies are allowed if not set
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub allowed_currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Free-form tags to group business profiles logically, these can be used to filter the business profiles list
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// Retry policy for the payouts created under this profile, which takes precedence over the payout retry configs of the merchant
+    #[schema(value_type = Option<BusinessPayoutRetryConfig>)]
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+
+    /// Time of the day windows within which payouts created under this profile with `auto_fulfill` are fulfilled, the fulfillment of payouts created outside the windows is deferred until the next window
+    #[schema(value_type = Option<BusinessPayoutFulfillmentConfig>)]
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+
+    /// Defaults of the capture method, setup future usage and authentication type for the payments created under this profile, applied when they are not passed in the payment request
+    #[schema(value_type = Option<BusinessPaymentDefaultsConfig>)]
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+
+    /// Whether the authorized payments created under this profile are captured when their shipment is notified through the shipments endpoint of the payment
+    #[schema(value_type = Option<BusinessCaptureOnShipmentConfig>)]
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+
+    /// The dynamic currency conversion offered to the customers paying with a card in a currency other than the currency of the payment, through the connectors which support it
+    #[schema(value_type = Option<BusinessDccConfig>)]
+    pub dcc_config: Option<BusinessDccConfig>,
+
+    /// The fee schedules of the payout connectors, from which the fees of the payouts are estimated when the connector does not report the fee it charged
+    #[schema(value_type = Option<BusinessPayoutFeeConfig>)]
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+
+    /// The rules which hold back a percentage of the payouts of the profile in its ledger balance, and block the payouts which the balance does not cover net of the refunds and chargebacks
+    #[schema(value_type = Option<BusinessPayoutBalanceConfig>)]
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+
+    /// Groups of connector accounts of the same connector which hold the same merchant account credentials. A capture, void or refund of a payment, whose connector account has been disabled or deleted since the payment was processed, is processed through another connector account in its group
+    #[schema(value_type = Option<BusinessOperationFallbackConfig>)]
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+
+    /// Whether the payouts to UK and EU bank accounts created under this profile are fulfilled when the name of the recipient does not match the name of the account holder, as verified by the Confirmation of Payee service. The name of the account holder is not verified if this is not set
+    #[schema(value_type = Option<AccountNameMismatchAction>, example = "block_on_no_match")]
+    pub account_name_mismatch_action: Option<api_enums::AccountNameMismatchAction>,
+
+    /// The gradual rollout of the traffic of an incumbent connector account to a new canary connector account, along with its current state
+    #[schema(value_type = Option<ConnectorCanaryRolloutResponse>)]
+    pub connector_canary_rollout: Option<ConnectorCanaryRolloutResponse>,
+
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
@@ -2303,27 +3031,120 @@ pub struct ProfileResponse {
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
 
-    /// These key-value pairs are sent as additional custom headers in the outgoing webhook request.
-    #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
-    pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
+    /// Default configuration for customer portal links created under this profile
+    #[schema(value_type = Option<BusinessGenericLinkConfig>)]
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
 
-    /// Will be used to determine the time till which your payment will be active once the payment session starts
-    #[schema(value_type = Option<u32>, example = 900)]
-    pub order_fulfillment_time: Option<OrderFulfillmentTime>,
+    /// Configuration for invoices generated for payments made under this profile
+    #[schema(value_type = Option<BusinessInvoiceConfig>)]
+    pub invoice_config: Option<BusinessInvoiceConfig>,
 
-    /// Whether the order fulfillment time is calculated from the origin or the time of creating the payment, or confirming the payment
-    #[schema(value_type = Option<OrderFulfillmentTimeOrigin>, example = "create")]
-    pub order_fulfillment_time_origin: Option<api_enums::OrderFulfillmentTimeOrigin>,
+    /// The event classes for which outgoing webhooks are sent to the merchant. Webhooks are sent for all event classes if not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["payments", "refunds"]))]
+    pub enabled_webhook_events: Option<Vec<api_enums::EventClass>>,
 
-    /// Merchant Connector id to be stored for tax_calculator connector
-    #[schema(value_type = Option<String>)]
-    pub tax_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// The version of the payload schema used for outgoing webhooks
+    #[schema(value_type = WebhookPayloadVersion, example = "v1")]
+    pub webhook_payload_version: api_enums::WebhookPayloadVersion,
 
-    /// Indicates if tax_calculator connector is enabled or not.
-    /// If set to `true` tax_connector_id will be checked.
-    pub is_tax_connector_enabled: bool,
+    /// Default statement descriptor for payments made under this profile, used when the payment does not specify one
+    #[schema(value_type = Option<BusinessStatementDescriptorConfig>)]
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
 
-    /// Indicates if network tokenization is enabled or not.
+    /// Configuration for automatically representing low value disputes using evidence assembled from templates
+    #[schema(value_type = Option<BusinessDisputeRepresentmentConfig>)]
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+
+    /// Authentication applied to the outgoing webhook requests, without any of the credentials
+    #[schema(value_type = Option<OutgoingWebhookAuthConfigResponse>)]
+    pub outgoing_webhook_auth: Option<OutgoingWebhookAuthConfigResponse>,
+
+    /// Branding and behaviour of the pages shown while redirecting the customer for 3DS or other
+    /// authentication, and the domains allowed in the `return_url` of payments
+    #[schema(value_type = Option<BusinessRedirectConfig>)]
+    pub redirect_config: Option<BusinessRedirectConfig>,
+
+    /// Origins from which the client side calls authenticated with the client secret, like sessions, confirm and payment methods list, are allowed
+    #[schema(value_type = Option<BusinessClientOriginConfig>)]
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+
+    /// Whether the client secret of a payment can be used to confirm the payment only once. A new client secret is issued in the response of every confirm made with the client secret
+    #[schema(default = false, example = false)]
+    pub is_client_secret_single_use: Option<bool>,
+
+    /// Rules evaluated against the AVS and CVV results of a payment after it has been authorized, like voiding the authorization on a full AVS mismatch
+    #[schema(value_type = Option<BusinessPostAuthRulesConfig>)]
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+
+    /// The currencies in which payments and payouts can be created under this profile. Payments and payouts of all currencies are allowed if not set
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub allowed_currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Free-form tags to group business profiles logically, these can be used to filter the business profiles list
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// Retry policy for the payouts created under this profile, which takes precedence over the payout retry configs of the merchant
+    #[schema(value_type = Option<BusinessPayoutRetryConfig>)]
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+
+    /// Time of the day windows within which payouts created under this profile with `auto_fulfill` are fulfilled, the fulfillment of payouts created outside the windows is deferred until the next window
+    #[schema(value_type = Option<BusinessPayoutFulfillmentConfig>)]
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+
+    /// Defaults of the capture method, setup future usage and authentication type for the payments created under this profile, applied when they are not passed in the payment request
+    #[schema(value_type = Option<BusinessPaymentDefaultsConfig>)]
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+
+    /// Whether the authorized payments created under this profile are captured when their shipment is notified through the shipments endpoint of the payment
+    #[schema(value_type = Option<BusinessCaptureOnShipmentConfig>)]
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+
+    /// The dynamic currency conversion offered to the customers paying with a card in a currency other than the currency of the payment, through the connectors which support it
+    #[schema(value_type = Option<BusinessDccConfig>)]
+    pub dcc_config: Option<BusinessDccConfig>,
+
+    /// The fee schedules of the payout connectors, from which the fees of the payouts are estimated when the connector does not report the fee it charged
+    #[schema(value_type = Option<BusinessPayoutFeeConfig>)]
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+
+    /// The rules which hold back a percentage of the payouts of the profile in its ledger balance, and block the payouts which the balance does not cover net of the refunds and chargebacks
+    #[schema(value_type = Option<BusinessPayoutBalanceConfig>)]
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+
+    /// Groups of connector accounts of the same connector which hold the same merchant account credentials. A capture, void or refund of a payment, whose connector account has been disabled or deleted since the payment was processed, is processed through another connector account in its group
+    #[schema(value_type = Option<BusinessOperationFallbackConfig>)]
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+
+    /// Whether the payouts to UK and EU bank accounts created under this profile are fulfilled when the name of the recipient does not match the name of the account holder, as verified by the Confirmation of Payee service. The name of the account holder is not verified if this is not set
+    #[schema(value_type = Option<AccountNameMismatchAction>, example = "block_on_no_match")]
+    pub account_name_mismatch_action: Option<api_enums::AccountNameMismatchAction>,
+
+    /// The gradual rollout of the traffic of an incumbent connector account to a new canary connector account, along with its current state
+    #[schema(value_type = Option<ConnectorCanaryRolloutResponse>)]
+    pub connector_canary_rollout: Option<ConnectorCanaryRolloutResponse>,
+
+    /// These key-value pairs are sent as additional custom headers in the outgoing webhook request.
+    #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
+    pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// Will be used to determine the time till which your payment will be active once the payment session starts
+    #[schema(value_type = Option<u32>, example = 900)]
+    pub order_fulfillment_time: Option<OrderFulfillmentTime>,
+
+    /// Whether the order fulfillment time is calculated from the origin or the time of creating the payment, or confirming the payment
+    #[schema(value_type = Option<OrderFulfillmentTimeOrigin>, example = "create")]
+    pub order_fulfillment_time_origin: Option<api_enums::OrderFulfillmentTimeOrigin>,
+
+    /// Merchant Connector id to be stored for tax_calculator connector
+    #[schema(value_type = Option<String>)]
+    pub tax_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// Indicates if tax_calculator connector is enabled or not.
+    /// If set to `true` tax_connector_id will be checked.
+    pub is_tax_connector_enabled: bool,
+
+    /// Indicates if network tokenization is enabled or not.
     #[schema(default = false, example = false)]
     pub is_network_tokenization_enabled: bool,
 
@@ -2431,6 +3252,99 @@ pub struct ProfileUpdate {
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
 
+    /// Default configuration for customer portal links created under this profile
+    #[schema(value_type = Option<BusinessGenericLinkConfig>)]
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+
+    /// Configuration for invoices generated for payments made under this profile
+    #[schema(value_type = Option<BusinessInvoiceConfig>)]
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+
+    /// The event classes for which outgoing webhooks are sent to the merchant. Webhooks are sent for all event classes if not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["payments", "refunds"]))]
+    pub enabled_webhook_events: Option<Vec<api_enums::EventClass>>,
+
+    /// The version of the payload schema used for outgoing webhooks. Defaults to `v1` if not set
+    #[schema(value_type = Option<WebhookPayloadVersion>, example = "v1")]
+    pub webhook_payload_version: Option<api_enums::WebhookPayloadVersion>,
+
+    /// Default statement descriptor for payments made under this profile, used when the payment does not specify one
+    #[schema(value_type = Option<BusinessStatementDescriptorConfig>)]
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+
+    /// Configuration for automatically representing low value disputes using evidence assembled from templates
+    #[schema(value_type = Option<BusinessDisputeRepresentmentConfig>)]
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+
+    /// Mutual TLS client certificate or OAuth 2.0 client credentials used to authenticate the outgoing webhook requests
+    #[schema(value_type = Option<OutgoingWebhookAuthConfig>)]
+    pub outgoing_webhook_auth: Option<OutgoingWebhookAuthConfig>,
+
+    /// Branding and behaviour of the pages shown while redirecting the customer for 3DS or other
+    /// authentication, and the domains allowed in the `return_url` of payments
+    #[schema(value_type = Option<BusinessRedirectConfig>)]
+    pub redirect_config: Option<BusinessRedirectConfig>,
+
+    /// Origins from which the client side calls authenticated with the client secret, like sessions, confirm and payment methods list, are allowed
+    #[schema(value_type = Option<BusinessClientOriginConfig>)]
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+
+    /// Whether the client secret of a payment can be used to confirm the payment only once. A new client secret is issued in the response of every confirm made with the client secret
+    #[schema(default = false, example = false)]
+    pub is_client_secret_single_use: Option<bool>,
+
+    /// Rules evaluated against the AVS and CVV results of a payment after it has been authorized, like voiding the authorization on a full AVS mismatch
+    #[schema(value_type = Option<BusinessPostAuthRulesConfig>)]
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+
+    /// The currencies in which payments and payouts can be created under this profile. Payments and payouts of all currencies are allowed if not set
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub allowed_currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Free-form tags to group business profiles logically, these can be used to filter the business profiles list
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// Retry policy for the payouts created under this profile, which takes precedence over the payout retry configs of the merchant
+    #[schema(value_type = Option<BusinessPayoutRetryConfig>)]
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+
+    /// Time of the day windows within which payouts created under this profile with `auto_fulfill` are fulfilled, the fulfillment of payouts created outside the windows is deferred until the next window
+    #[schema(value_type = Option<BusinessPayoutFulfillmentConfig>)]
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+
+    /// Defaults of the capture method, setup future usage and authentication type for the payments created under this profile, applied when they are not passed in the payment request
+    #[schema(value_type = Option<BusinessPaymentDefaultsConfig>)]
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+
+    /// Whether the authorized payments created under this profile are captured when their shipment is notified through the shipments endpoint of the payment
+    #[schema(value_type = Option<BusinessCaptureOnShipmentConfig>)]
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+
+    /// The dynamic currency conversion offered to the customers paying with a card in a currency other than the currency of the payment, through the connectors which support it
+    #[schema(value_type = Option<BusinessDccConfig>)]
+    pub dcc_config: Option<BusinessDccConfig>,
+
+    /// The fee schedules of the payout connectors, from which the fees of the payouts are estimated when the connector does not report the fee it charged
+    #[schema(value_type = Option<BusinessPayoutFeeConfig>)]
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+
+    /// The rules which hold back a percentage of the payouts of the profile in its ledger balance, and block the payouts which the balance does not cover net of the refunds and chargebacks
+    #[schema(value_type = Option<BusinessPayoutBalanceConfig>)]
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+
+    /// Groups of connector accounts of the same connector which hold the same merchant account credentials. A capture, void or refund of a payment, whose connector account has been disabled or deleted since the payment was processed, is processed through another connector account in its group
+    #[schema(value_type = Option<BusinessOperationFallbackConfig>)]
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+
+    /// Whether the payouts to UK and EU bank accounts created under this profile are fulfilled when the name of the recipient does not match the name of the account holder, as verified by the Confirmation of Payee service. The name of the account holder is not verified if this is not set
+    #[schema(value_type = Option<AccountNameMismatchAction>, example = "block_on_no_match")]
+    pub account_name_mismatch_action: Option<api_enums::AccountNameMismatchAction>,
+
+    /// The gradual rollout of the traffic of an incumbent connector account to a new canary connector account, which is rolled back when its success rate drops below that of the incumbent by more than the configured margin. Passing this starts a new rollout
+    #[schema(value_type = Option<ConnectorCanaryRolloutConfig>)]
+    pub connector_canary_rollout: Option<ConnectorCanaryRolloutConfig>,
+
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
@@ -2548,6 +3462,99 @@ pub struct ProfileUpdate {
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
 
+    /// Default configuration for customer portal links created under this profile
+    #[schema(value_type = Option<BusinessGenericLinkConfig>)]
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+
+    /// Configuration for invoices generated for payments made under this profile
+    #[schema(value_type = Option<BusinessInvoiceConfig>)]
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+
+    /// The event classes for which outgoing webhooks are sent to the merchant. Webhooks are sent for all event classes if not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["payments", "refunds"]))]
+    pub enabled_webhook_events: Option<Vec<api_enums::EventClass>>,
+
+    /// The version of the payload schema used for outgoing webhooks. Defaults to `v1` if not set
+    #[schema(value_type = Option<WebhookPayloadVersion>, example = "v1")]
+    pub webhook_payload_version: Option<api_enums::WebhookPayloadVersion>,
+
+    /// Default statement descriptor for payments made under this profile, used when the payment does not specify one
+    #[schema(value_type = Option<BusinessStatementDescriptorConfig>)]
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+
+    /// Configuration for automatically representing low value disputes using evidence assembled from templates
+    #[schema(value_type = Option<BusinessDisputeRepresentmentConfig>)]
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+
+    /// Mutual TLS client certificate or OAuth 2.0 client credentials used to authenticate the outgoing webhook requests
+    #[schema(value_type = Option<OutgoingWebhookAuthConfig>)]
+    pub outgoing_webhook_auth: Option<OutgoingWebhookAuthConfig>,
+
+    /// Branding and behaviour of the pages shown while redirecting the customer for 3DS or other
+    /// authentication, and the domains allowed in the `return_url` of payments
+    #[schema(value_type = Option<BusinessRedirectConfig>)]
+    pub redirect_config: Option<BusinessRedirectConfig>,
+
+    /// Origins from which the client side calls authenticated with the client secret, like sessions, confirm and payment methods list, are allowed
+    #[schema(value_type = Option<BusinessClientOriginConfig>)]
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+
+    /// Whether the client secret of a payment can be used to confirm the payment only once. A new client secret is issued in the response of every confirm made with the client secret
+    #[schema(default = false, example = false)]
+    pub is_client_secret_single_use: Option<bool>,
+
+    /// Rules evaluated against the AVS and CVV results of a payment after it has been authorized, like voiding the authorization on a full AVS mismatch
+    #[schema(value_type = Option<BusinessPostAuthRulesConfig>)]
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+
+    /// The currencies in which payments and payouts can be created under this profile. Payments and payouts of all currencies are allowed if not set
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub allowed_currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Free-form tags to group business profiles logically, these can be used to filter the business profiles list
+    #[schema(value_type = Option<Vec<String>>, example = json!(["high_risk", "eu"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// Retry policy for the payouts created under this profile, which takes precedence over the payout retry configs of the merchant
+    #[schema(value_type = Option<BusinessPayoutRetryConfig>)]
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+
+    /// Time of the day windows within which payouts created under this profile with `auto_fulfill` are fulfilled, the fulfillment of payouts created outside the windows is deferred until the next window
+    #[schema(value_type = Option<BusinessPayoutFulfillmentConfig>)]
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+
+    /// Defaults of the capture method, setup future usage and authentication type for the payments created under this profile, applied when they are not passed in the payment request
+    #[schema(value_type = Option<BusinessPaymentDefaultsConfig>)]
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+
+    /// Whether the authorized payments created under this profile are captured when their shipment is notified through the shipments endpoint of the payment
+    #[schema(value_type = Option<BusinessCaptureOnShipmentConfig>)]
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+
+    /// The dynamic currency conversion offered to the customers paying with a card in a currency other than the currency of the payment, through the connectors which support it
+    #[schema(value_type = Option<BusinessDccConfig>)]
+    pub dcc_config: Option<BusinessDccConfig>,
+
+    /// The fee schedules of the payout connectors, from which the fees of the payouts are estimated when the connector does not report the fee it charged
+    #[schema(value_type = Option<BusinessPayoutFeeConfig>)]
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+
+    /// The rules which hold back a percentage of the payouts of the profile in its ledger balance, and block the payouts which the balance does not cover net of the refunds and chargebacks
+    #[schema(value_type = Option<BusinessPayoutBalanceConfig>)]
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+
+    /// Groups of connector accounts of the same connector which hold the same merchant account credentials. A capture, void or refund of a payment, whose connector account has been disabled or deleted since the payment was processed, is processed through another connector account in its group
+    #[schema(value_type = Option<BusinessOperationFallbackConfig>)]
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+
+    /// Whether the payouts to UK and EU bank accounts created under this profile are fulfilled when the name of the recipient does not match the name of the account holder, as verified by the Confirmation of Payee service. The name of the account holder is not verified if this is not set
+    #[schema(value_type = Option<AccountNameMismatchAction>, example = "block_on_no_match")]
+    pub account_name_mismatch_action: Option<api_enums::AccountNameMismatchAction>,
+
+    /// The gradual rollout of the traffic of an incumbent connector account to a new canary connector account, which is rolled back when its success rate drops below that of the incumbent by more than the configured margin. Passing this starts a new rollout
+    #[schema(value_type = Option<ConnectorCanaryRolloutConfig>)]
+    pub connector_canary_rollout: Option<ConnectorCanaryRolloutConfig>,
+
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
@@ -2568,6 +3575,54 @@ pub struct ProfileUpdate {
     pub is_click_to_pay_enabled: Option<bool>,
 }
 
+/// Prefixes of the ids generated by Hyperswitch for the objects of a merchant. The generated id is
+/// of the format `{prefix}_{random_string}`, ids provided by the merchant in the request are not
+/// prefixed.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantIdPrefixes {
+    /// Prefix of the generated `payment_id`, defaults to `pay`
+    #[schema(max_length = 16, example = "acme")]
+    pub payment_id: Option<String>,
+
+    /// Prefix of the generated `refund_id`, defaults to `ref`
+    #[schema(max_length = 16, example = "acmeref")]
+    pub refund_id: Option<String>,
+
+    /// Prefix of the generated `payout_id`, payout ids are generated without a prefix by default
+    #[schema(max_length = 16, example = "acmepo")]
+    pub payout_id: Option<String>,
+}
+
+/// Retention periods of the classes of data stored for a merchant. Data older than the retention
+/// period of its class is purged by a daily job, data of a class without a retention period is
+/// retained indefinitely.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantDataRetentionPolicy {
+    /// Number of days for which the personal data of customers provided in payments, such as the
+    /// customer, billing and shipping details, is retained
+    #[schema(minimum = 1, example = 365)]
+    pub pii_retention_period_in_days: Option<u16>,
+
+    /// Number of days for which the metadata, order details and FRM metadata of payments are
+    /// retained
+    #[schema(minimum = 1, example = 730)]
+    pub payment_metadata_retention_period_in_days: Option<u16>,
+
+    /// Number of days for which the logs of the webhooks delivered to the merchant are retained
+    #[schema(minimum = 1, example = 90)]
+    pub logs_retention_period_in_days: Option<u16>,
+}
+
+impl MerchantDataRetentionPolicy {
+    pub fn is_empty(&self) -> bool {
+        self.pii_retention_period_in_days.is_none()
+            && self.payment_metadata_retention_period_in_days.is_none()
+            && self.logs_retention_period_in_days.is_none()
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct BusinessCollectLinkConfig {
     #[serde(flatten)]
@@ -2592,6 +3647,811 @@ pub struct BusinessPayoutLinkConfig {
     pub payout_test_mode: Option<bool>,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessStatementDescriptorConfig {
+    /// The statement descriptor shown on the customer's bank statement
+    #[schema(max_length = 22, example = "Juspay Router")]
+    pub name: Option<String>,
+
+    /// The suffix appended to the statement descriptor of the merchant account at the connector.
+    /// Marketplaces can use it as a soft descriptor identifying the seller of the goods
+    #[schema(max_length = 22, example = "Seller 42")]
+    pub suffix: Option<String>,
+}
+
+impl BusinessStatementDescriptorConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        let rules = common_utils::validation::StatementDescriptorRules::CARD_SCHEME;
+
+        let is_name_valid = self
+            .name
+            .as_deref()
+            .map(|name| rules.is_valid(name))
+            .unwrap_or(true);
+        if !is_name_valid {
+            return Err("Invalid name received in statement_descriptor_config");
+        }
+
+        let is_suffix_valid = self
+            .suffix
+            .as_deref()
+            .map(|suffix| rules.is_valid(suffix))
+            .unwrap_or(true);
+        if !is_suffix_valid {
+            return Err("Invalid suffix received in statement_descriptor_config");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessDisputeRepresentmentConfig {
+    /// Disputes with an amount up to and including this value are represented automatically,
+    /// without waiting for the merchant to submit evidence
+    #[schema(value_type = i64, example = 5000)]
+    pub max_dispute_amount: common_utils::types::MinorUnit,
+
+    /// Templates of the evidence statements submitted to the connector
+    pub evidence_templates: DisputeEvidenceTemplates,
+}
+
+/// Templates of the evidence statements submitted while representing a dispute.
+/// Payment details can be referred to using placeholders such as `{{payment_id}}`, `{{avs_result}}`,
+/// `{{cvv_result}}`, `{{three_ds_authenticated}}` or `{{shipping_tracking_number}}`.
+/// Delivery details are read from the `delivery_details` object in the metadata of the payment.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplates {
+    /// Template of the description of the purchased product
+    #[schema(example = "Order for payment {{payment_id}} was shipped via {{shipping_carrier}}")]
+    pub product_description: Option<String>,
+
+    /// Template of the explanation of why the customer is not entitled to a refund
+    pub refund_refusal_explanation: Option<String>,
+
+    /// Template of the explanation of why the subscription of the customer was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+
+    /// Template of any additional evidence statements
+    #[schema(
+        example = "AVS check: {{avs_result}}, CVV check: {{cvv_result}}, 3DS authenticated: {{three_ds_authenticated}}"
+    )]
+    pub uncategorized_text: Option<String>,
+}
+
+impl DisputeEvidenceTemplates {
+    pub fn get_templates(&self) -> impl Iterator<Item = &String> {
+        [
+            &self.product_description,
+            &self.refund_refusal_explanation,
+            &self.cancellation_rebuttal,
+            &self.uncategorized_text,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl BusinessDisputeRepresentmentConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.max_dispute_amount <= common_utils::types::MinorUnit::zero() {
+            return Err(
+                "max_dispute_amount in dispute_representment_config must be greater than zero",
+            );
+        }
+
+        let mut templates = self.evidence_templates.get_templates().peekable();
+        if templates.peek().is_none() {
+            return Err(
+                "At least one evidence template is required in dispute_representment_config",
+            );
+        }
+
+        let are_placeholders_valid = templates.all(|template| {
+            crate::disputes::EvidenceTemplatePlaceholder::are_placeholders_valid(template)
+        });
+        if !are_placeholders_valid {
+            return Err("Invalid placeholder received in dispute_representment_config");
+        }
+
+        Ok(())
+    }
+}
+
+/// Authentication applied to the outgoing webhook requests sent to the merchant
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OutgoingWebhookAuthConfig {
+    /// Client certificate presented to the webhook endpoint of the merchant for mutual TLS
+    pub mutual_tls: Option<OutgoingWebhookMutualTls>,
+
+    /// OAuth 2.0 client credentials used to obtain an access token which is sent as a bearer token
+    pub oauth2_client_credentials: Option<OutgoingWebhookOAuth2ClientCredentials>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OutgoingWebhookMutualTls {
+    /// Base64 encoded client certificate in PEM format
+    #[schema(value_type = String)]
+    pub certificate: Secret<String>,
+
+    /// Base64 encoded private key of the client certificate in PEM format
+    #[schema(value_type = String)]
+    pub certificate_key: Secret<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OutgoingWebhookOAuth2ClientCredentials {
+    /// Endpoint of the authorization server from which the access token is obtained
+    #[schema(value_type = String, example = "https://auth.example.com/oauth2/token")]
+    pub token_url: url::Url,
+
+    /// Client identifier issued to hyperswitch by the authorization server
+    pub client_id: String,
+
+    /// Client secret issued to hyperswitch by the authorization server
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+
+    /// Space separated scopes requested for the access token
+    #[schema(example = "webhooks.write")]
+    pub scope: Option<String>,
+}
+
+impl OutgoingWebhookAuthConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.mutual_tls.is_none() && self.oauth2_client_credentials.is_none() {
+            return Err(
+                "Either mutual_tls or oauth2_client_credentials is required in outgoing_webhook_auth",
+            );
+        }
+
+        if let Some(mutual_tls) = &self.mutual_tls {
+            if mutual_tls.certificate.peek().trim().is_empty()
+                || mutual_tls.certificate_key.peek().trim().is_empty()
+            {
+                return Err(
+                    "certificate and certificate_key in outgoing_webhook_auth must not be empty",
+                );
+            }
+        }
+
+        if let Some(oauth2_client_credentials) = &self.oauth2_client_credentials {
+            if oauth2_client_credentials.token_url.scheme() != "https" {
+                return Err("token_url in outgoing_webhook_auth must use https");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Authentication configured for the outgoing webhook requests, without any of the credentials
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct OutgoingWebhookAuthConfigResponse {
+    /// Whether a client certificate is presented to the webhook endpoint for mutual TLS
+    pub is_mutual_tls_enabled: bool,
+
+    /// Endpoint of the authorization server from which the access token is obtained
+    #[schema(value_type = Option<String>, example = "https://auth.example.com/oauth2/token")]
+    pub oauth2_token_url: Option<url::Url>,
+
+    /// Client identifier used to obtain the access token
+    pub oauth2_client_id: Option<String>,
+
+    /// Scopes requested for the access token
+    pub oauth2_scope: Option<String>,
+}
+
+impl From<OutgoingWebhookAuthConfig> for OutgoingWebhookAuthConfigResponse {
+    fn from(config: OutgoingWebhookAuthConfig) -> Self {
+        let oauth2_client_credentials = config.oauth2_client_credentials;
+        Self {
+            is_mutual_tls_enabled: config.mutual_tls.is_some(),
+            oauth2_token_url: oauth2_client_credentials
+                .as_ref()
+                .map(|credentials| credentials.token_url.clone()),
+            oauth2_client_id: oauth2_client_credentials
+                .as_ref()
+                .map(|credentials| credentials.client_id.clone()),
+            oauth2_scope: oauth2_client_credentials.and_then(|credentials| credentials.scope),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessRedirectConfig {
+    /// Branding of the intermediate pages shown while redirecting the customer
+    #[serde(flatten)]
+    #[schema(value_type = GenericLinkUiConfig)]
+    pub ui_config: link_utils::GenericLinkUiConfig,
+
+    /// Locale of the text shown on the intermediate pages. Defaults to the locale of the request
+    #[schema(example = "en")]
+    pub locale: Option<String>,
+
+    /// Time in milliseconds after which the customer is redirected automatically. Defaults to 300
+    #[schema(maximum = 30000, example = 1000)]
+    pub auto_redirect_timeout_ms: Option<u32>,
+
+    /// Domains allowed in the `return_url` of payments, payments with a `return_url` on any other
+    /// domain are rejected. Domains prefixed with `*.` allow all of their subdomains
+    #[schema(value_type = Option<HashSet<String>>, example = json!(["shop.example.com", "*.example.net"]))]
+    pub allowed_return_url_domains: Option<HashSet<String>>,
+}
+
+impl BusinessRedirectConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        let is_timeout_valid = self
+            .auto_redirect_timeout_ms
+            .map(|timeout| timeout <= MAX_AUTO_REDIRECT_TIMEOUT_MS)
+            .unwrap_or(true);
+        if !is_timeout_valid {
+            return Err("auto_redirect_timeout_ms in redirect_config must not exceed 30000");
+        }
+
+        let is_locale_valid = self
+            .locale
+            .as_deref()
+            .map(|locale| !locale.trim().is_empty())
+            .unwrap_or(true);
+        if !is_locale_valid {
+            return Err("locale in redirect_config must not be empty");
+        }
+
+        let is_theme_valid = self
+            .ui_config
+            .theme
+            .as_deref()
+            .map(|theme| {
+                theme.strip_prefix('#').is_some_and(|hex| {
+                    matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+                })
+            })
+            .unwrap_or(true);
+        if !is_theme_valid {
+            return Err("theme in redirect_config must be a hex color code");
+        }
+
+        let are_allowed_domains_valid = self
+            .allowed_return_url_domains
+            .as_ref()
+            .map(|allowed_domains| {
+                !allowed_domains.is_empty()
+                    && allowed_domains
+                        .iter()
+                        .all(|allowed_domain| link_utils::validate_host_domain(allowed_domain))
+            })
+            .unwrap_or(true);
+        if !are_allowed_domains_valid {
+            return Err("Invalid allowed_return_url_domains received in redirect_config");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessClientOriginConfig {
+    /// Domains from which the client side calls are allowed, the port is included if it is not the default one. Glob patterns like `*.example.com` are supported
+    #[schema(value_type = Vec<String>, example = json!(["www.example.com", "*.example.com"]))]
+    pub allowed_origins: HashSet<String>,
+
+    /// Whether calls from other origins are rejected or only reported
+    #[serde(default)]
+    #[schema(value_type = OriginEnforcementMode)]
+    pub enforcement_mode: api_enums::OriginEnforcementMode,
+}
+
+impl BusinessClientOriginConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.allowed_origins.is_empty() {
+            return Err("allowed_origins in client_origin_config must not be empty");
+        }
+
+        let are_allowed_origins_valid = self
+            .allowed_origins
+            .iter()
+            .all(|allowed_origin| !allowed_origin.trim().is_empty());
+        if !are_allowed_origins_valid {
+            return Err("Invalid allowed_origins received in client_origin_config");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessPostAuthRulesConfig {
+    /// The rules are evaluated in order after a payment has been authorized, the action of the first matching rule is taken
+    pub rules: Vec<PostAuthRule>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PostAuthRule {
+    /// The rule matches if the AVS result of the payment is one of these
+    #[schema(value_type = Option<Vec<AvsResult>>, example = json!(["no_match"]))]
+    pub avs_results: Option<HashSet<api_enums::AvsResult>>,
+
+    /// The rule matches if the CVV result of the payment is one of these
+    #[schema(value_type = Option<Vec<CvvResult>>, example = json!(["no_match"]))]
+    pub cvv_results: Option<HashSet<api_enums::CvvResult>>,
+
+    /// The action taken on the payment when the rule matches
+    #[schema(value_type = PostAuthAction, example = "void")]
+    pub action: api_enums::PostAuthAction,
+}
+
+impl BusinessPostAuthRulesConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.rules.is_empty() {
+            return Err("rules in post_auth_rules_config must not be empty");
+        }
+
+        let are_rules_valid = self.rules.iter().all(|rule| {
+            let has_condition = rule.avs_results.is_some() || rule.cvv_results.is_some();
+            let has_empty_condition = rule
+                .avs_results
+                .as_ref()
+                .is_some_and(|avs_results| avs_results.is_empty())
+                || rule
+                    .cvv_results
+                    .as_ref()
+                    .is_some_and(|cvv_results| cvv_results.is_empty());
+            has_condition && !has_empty_condition
+        });
+        if !are_rules_valid {
+            return Err(
+                "Every rule in post_auth_rules_config must have non empty avs_results or cvv_results",
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessPayoutRetryConfig {
+    /// Maximum number of times a failed payout is retried with the same connector
+    #[serde(default)]
+    #[schema(default = 0, maximum = 5, example = 2)]
+    pub max_single_connector_retries: u8,
+
+    /// Maximum number of times a failed payout is retried with the next eligible connector
+    #[serde(default)]
+    #[schema(default = 0, maximum = 5, example = 1)]
+    pub max_multi_connector_retries: u8,
+
+    /// The categories of the connector errors for which payouts are retried. All errors marked as retryable are retried if not set
+    #[schema(value_type = Option<Vec<ErrorCategory>>, example = json!(["processor_downtime"]))]
+    pub retryable_error_categories: Option<Vec<api_enums::ErrorCategory>>,
+
+    /// Delay in milliseconds before a payout is retried, which is doubled on every subsequent retry of the payout
+    #[schema(maximum = 5000, example = 500)]
+    pub backoff_in_millis: Option<u32>,
+}
+
+impl BusinessPayoutRetryConfig {
+    const MAX_RETRIES: u8 = 5;
+    const MAX_BACKOFF_IN_MILLIS: u32 = 5000;
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_single_connector_retries > Self::MAX_RETRIES
+            || self.max_multi_connector_retries > Self::MAX_RETRIES
+        {
+            return Err(format!(
+                "Retries in payout_retry_config must not be more than {}",
+                Self::MAX_RETRIES
+            ));
+        }
+
+        if self
+            .retryable_error_categories
+            .as_ref()
+            .is_some_and(|retryable_error_categories| retryable_error_categories.is_empty())
+        {
+            return Err(
+                "retryable_error_categories in payout_retry_config must not be empty".to_string(),
+            );
+        }
+
+        if self
+            .backoff_in_millis
+            .is_some_and(|backoff_in_millis| backoff_in_millis > Self::MAX_BACKOFF_IN_MILLIS)
+        {
+            return Err(format!(
+                "backoff_in_millis in payout_retry_config must not be more than {}",
+                Self::MAX_BACKOFF_IN_MILLIS
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessPayoutFeeConfig {
+    /// The fee schedules of the connector accounts, from which the fee of a payout is estimated when the connector does not report the fee it charged. The first schedule which applies to the payout is used
+    pub fee_schedules: Vec<PayoutFeeSchedule>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutFeeSchedule {
+    /// The connector account whose payouts the schedule applies to
+    #[schema(value_type = String, example = "mca_sAD3OZLATetvjLOYhUSy")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The schedule applies to payouts in this currency, applies to payouts in all currencies if not set. It is required for a fixed fee
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The fixed fee charged for a payout, in the lowest denomination of the currency of the schedule
+    #[serde(default)]
+    #[schema(value_type = i64, default = 0, example = 25)]
+    pub fixed_fee: common_utils::types::MinorUnit,
+
+    /// The fee charged in proportion to the amount of the payout, in basis points
+    #[serde(default)]
+    #[schema(default = 0, maximum = 10000, example = 50)]
+    pub percentage_in_basis_points: u16,
+}
+
+impl BusinessPayoutFeeConfig {
+    const MAX_PERCENTAGE_IN_BASIS_POINTS: u16 = 10000;
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.fee_schedules.is_empty() {
+            return Err("fee_schedules in payout_fee_config must not be empty".to_string());
+        }
+
+        for fee_schedule in &self.fee_schedules {
+            let fixed_fee = fee_schedule.fixed_fee.get_amount_as_i64();
+            if fixed_fee < 0 {
+                return Err("fixed_fee in payout_fee_config must not be negative".to_string());
+            }
+
+            if fixed_fee > 0 && fee_schedule.currency.is_none() {
+                return Err("currency is required for a fixed_fee in payout_fee_config".to_string());
+            }
+
+            if fee_schedule.percentage_in_basis_points > Self::MAX_PERCENTAGE_IN_BASIS_POINTS {
+                return Err(format!(
+                    "percentage_in_basis_points in payout_fee_config must not be more than {}",
+                    Self::MAX_PERCENTAGE_IN_BASIS_POINTS
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessPayoutBalanceConfig {
+    /// The percentage of the amount of each payout, in basis points, held back in the connector balance of the ledger of the profile to cover refunds and chargebacks. A payout is fulfilled only if the connector balance, net of the refunds and chargebacks recorded in the ledger, covers both its amount and the amount held back, so that payouts are blocked rather than drive the balance negative
+    #[serde(default)]
+    #[schema(default = 0, maximum = 10000, example = 1000)]
+    pub holdback_percentage_in_basis_points: u16,
+}
+
+impl BusinessPayoutBalanceConfig {
+    const MAX_HOLDBACK_PERCENTAGE_IN_BASIS_POINTS: u16 = 10000;
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.holdback_percentage_in_basis_points > Self::MAX_HOLDBACK_PERCENTAGE_IN_BASIS_POINTS
+        {
+            return Err(format!(
+                "holdback_percentage_in_basis_points in payout_balance_config must not be more than {}",
+                Self::MAX_HOLDBACK_PERCENTAGE_IN_BASIS_POINTS
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessPayoutFulfillmentConfig {
+    /// The windows are evaluated in order, payouts are auto fulfilled only within the first window which applies to the payout. Payouts to which no window applies are fulfilled right away
+    pub windows: Vec<PayoutFulfillmentWindow>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutFulfillmentWindow {
+    /// The window applies to payouts of these payout types, applies to all payout types if not set
+    #[schema(value_type = Option<Vec<PayoutType>>, example = json!(["bank"]))]
+    pub payout_types: Option<Vec<api_enums::PayoutType>>,
+
+    /// The window applies to payouts of these payout method types, applies to all payout method types if not set
+    #[schema(value_type = Option<Vec<PaymentMethodType>>, example = json!(["sepa"]))]
+    pub payout_method_types: Option<Vec<api_enums::PaymentMethodType>>,
+
+    /// Start of the window in the `HH:MM` format, in the time zone of `utc_offset_in_minutes`
+    #[schema(example = "06:00")]
+    pub start_time: String,
+
+    /// End of the window in the `HH:MM` format, in the time zone of `utc_offset_in_minutes`
+    #[schema(example = "16:00")]
+    pub end_time: String,
+
+    /// Offset of the time zone of the window from UTC, in minutes
+    #[serde(default)]
+    #[schema(default = 0, example = 60)]
+    pub utc_offset_in_minutes: i16,
+
+    /// Whether payouts are fulfilled only on banking days, from Monday to Friday
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub banking_days_only: bool,
+}
+
+impl BusinessPayoutFulfillmentConfig {
+    const MAX_UTC_OFFSET_IN_MINUTES: i16 = 14 * 60;
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.windows.is_empty() {
+            return Err("windows in payout_fulfillment_config must not be empty".to_string());
+        }
+
+        for window in &self.windows {
+            let start_time = parse_time_of_day(&window.start_time).ok_or_else(|| {
+                format!(
+                    "Invalid start_time {} in payout_fulfillment_config, expected the HH:MM format",
+                    window.start_time
+                )
+            })?;
+            let end_time = parse_time_of_day(&window.end_time).ok_or_else(|| {
+                format!(
+                    "Invalid end_time {} in payout_fulfillment_config, expected the HH:MM format",
+                    window.end_time
+                )
+            })?;
+            if start_time >= end_time {
+                return Err(
+                    "start_time of a window in payout_fulfillment_config must be before its end_time"
+                        .to_string(),
+                );
+            }
+            if window.utc_offset_in_minutes.abs() > Self::MAX_UTC_OFFSET_IN_MINUTES {
+                return Err(format!(
+                    "utc_offset_in_minutes in payout_fulfillment_config must be within {} minutes of UTC",
+                    Self::MAX_UTC_OFFSET_IN_MINUTES
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a time of the day in the `HH:MM` format into the minutes since midnight
+fn parse_time_of_day(time_of_day: &str) -> Option<u16> {
+    let (hour, minute) = time_of_day.split_once(':')?;
+    let (hour, minute) = (hour.parse::<u16>().ok()?, minute.parse::<u16>().ok()?);
+    (hour < 24 && minute < 60).then_some(hour * 60 + minute)
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessPaymentDefaultsConfig {
+    /// The capture method of the payments which do not pass `capture_method` in the request
+    #[schema(value_type = Option<CaptureMethod>, example = "manual")]
+    pub capture_method: Option<api_enums::CaptureMethod>,
+
+    /// The setup future usage of the payments which do not pass `setup_future_usage` in the request
+    #[schema(value_type = Option<FutureUsage>, example = "off_session")]
+    pub setup_future_usage: Option<api_enums::FutureUsage>,
+
+    /// The authentication type of the payments which do not pass `authentication_type` in the request
+    #[schema(value_type = Option<AuthenticationType>, example = "three_ds")]
+    pub authentication_type: Option<api_enums::AuthenticationType>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessCaptureOnShipmentConfig {
+    /// Whether the payments are captured when their shipment is notified
+    #[schema(example = true)]
+    pub enabled: bool,
+
+    /// Whether a shipment of a part of the line items of the order details captures only the amount of the shipped line items. The full amount of the payment is captured on the first shipment if this is not enabled
+    #[schema(default = false, example = true)]
+    #[serde(default)]
+    pub partial_capture_enabled: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessDccConfig {
+    /// Whether dynamic currency conversion is offered to the customers
+    #[schema(example = true)]
+    pub enabled: bool,
+
+    /// The markup applied over the forex rate to the exchange rate offered to the customers, in basis points. It is limited to 1000 basis points
+    #[schema(default = 0, example = 300, maximum = 1000)]
+    #[serde(default)]
+    pub markup_in_basis_points: u16,
+}
+
+impl BusinessDccConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.markup_in_basis_points > 1000 {
+            return Err("markup_in_basis_points in dcc_config must be at most 1000");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessOperationFallbackConfig {
+    /// A connector account can be in at most one of the groups
+    pub groups: Vec<OperationFallbackGroup>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct OperationFallbackGroup {
+    /// The connector accounts in the group, in the order in which they are preferred as a fallback
+    #[schema(value_type = Vec<String>, example = json!(["mca_5apGeP94tMts6rg3U3kR", "mca_pX2Gk8fBPrYaE0hJ7Qm1"]))]
+    pub merchant_connector_ids: Vec<id_type::MerchantConnectorAccountId>,
+}
+
+impl BusinessOperationFallbackConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self
+            .groups
+            .iter()
+            .any(|group| group.merchant_connector_ids.len() < 2)
+        {
+            return Err(
+                "each group in operation_fallback_config must have at least two merchant_connector_ids",
+            );
+        }
+        let mut merchant_connector_ids = HashSet::new();
+        if !self
+            .groups
+            .iter()
+            .flat_map(|group| group.merchant_connector_ids.iter())
+            .all(|merchant_connector_id| merchant_connector_ids.insert(merchant_connector_id))
+        {
+            return Err(
+                "a merchant_connector_id can be in only one group of operation_fallback_config, and only once",
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorCanaryRolloutConfig {
+    /// The identifier of the new connector account to which the traffic is gradually rolled out
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub canary_merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The identifier of the connector account whose traffic is diverted to the canary connector account
+    #[schema(value_type = String, example = "mca_pX2Gk8fBPrYaE0hJ7Qm1")]
+    pub incumbent_merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The percentages of the traffic of the incumbent which are routed to the canary in each stage of the rollout. These must be increasing, and the last stage must route all of the traffic
+    #[serde(default = "default_canary_traffic_percentage_stages")]
+    #[schema(default = json!([1, 5, 25, 100]), example = json!([1, 5, 25, 100]))]
+    pub traffic_percentage_stages: Vec<u8>,
+
+    /// The duration of each stage of the rollout, in seconds
+    #[schema(example = 86400)]
+    pub stage_duration_in_secs: u32,
+
+    /// The margin in percentage points by which the success rate of the canary may drop below that of the incumbent, before the rollout is rolled back
+    #[schema(example = 2.5)]
+    pub rollback_margin: f64,
+
+    /// The minimum number of payments which must be processed by each of the connector accounts, before their success rates are compared
+    #[serde(default = "default_canary_min_sample_size")]
+    #[schema(default = 100, example = 100)]
+    pub min_sample_size: u32,
+}
+
+fn default_canary_traffic_percentage_stages() -> Vec<u8> {
+    vec![1, 5, 25, 100]
+}
+
+fn default_canary_min_sample_size() -> u32 {
+    100
+}
+
+impl ConnectorCanaryRolloutConfig {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.canary_merchant_connector_id == self.incumbent_merchant_connector_id {
+            return Err("canary_merchant_connector_id and incumbent_merchant_connector_id in connector_canary_rollout must be different");
+        }
+        if self.traffic_percentage_stages.last() != Some(&100) {
+            return Err(
+                "the last stage in traffic_percentage_stages of connector_canary_rollout must be 100",
+            );
+        }
+        if self.traffic_percentage_stages.first() == Some(&0)
+            || self
+                .traffic_percentage_stages
+                .windows(2)
+                .any(|stages| matches!(stages, [previous, next] if previous >= next))
+        {
+            return Err("traffic_percentage_stages in connector_canary_rollout must be increasing percentages greater than 0");
+        }
+        if self.stage_duration_in_secs == 0 {
+            return Err(
+                "stage_duration_in_secs in connector_canary_rollout must be greater than 0",
+            );
+        }
+        if !(0.0..=100.0).contains(&self.rollback_margin) {
+            return Err("rollback_margin in connector_canary_rollout must be between 0 and 100");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorCanaryRolloutResponse {
+    /// The identifier of the new connector account to which the traffic is gradually rolled out
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub canary_merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The identifier of the connector account whose traffic is diverted to the canary connector account
+    #[schema(value_type = String, example = "mca_pX2Gk8fBPrYaE0hJ7Qm1")]
+    pub incumbent_merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The percentages of the traffic of the incumbent which are routed to the canary in each stage of the rollout
+    #[schema(example = json!([1, 5, 25, 100]))]
+    pub traffic_percentage_stages: Vec<u8>,
+
+    /// The duration of each stage of the rollout, in seconds
+    #[schema(example = 86400)]
+    pub stage_duration_in_secs: u32,
+
+    /// The margin in percentage points by which the success rate of the canary may drop below that of the incumbent, before the rollout is rolled back
+    #[schema(example = 2.5)]
+    pub rollback_margin: f64,
+
+    /// The minimum number of payments which must be processed by each of the connector accounts, before their success rates are compared
+    #[schema(example = 100)]
+    pub min_sample_size: u32,
+
+    /// The status of the rollout
+    #[schema(value_type = CanaryRolloutStatus, example = "in_progress")]
+    pub status: api_enums::CanaryRolloutStatus,
+
+    /// The percentage of the traffic of the incumbent which is currently routed to the canary
+    #[schema(example = 5)]
+    pub current_traffic_percentage: u8,
+
+    /// The time at which the rollout was started
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: time::PrimitiveDateTime,
+
+    /// The time at which the rollout was rolled back
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub rolled_back_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BusinessInvoiceConfig {
+    /// Whether an invoice should be generated and emailed to the customer when a payment succeeds
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub send_email_on_payment_success: bool,
+
+    /// The format in which invoices are generated
+    #[serde(default)]
+    #[schema(value_type = InvoiceFormat, example = "pdf")]
+    pub format: api_enums::InvoiceFormat,
+
+    /// A note printed at the bottom of every invoice
+    #[schema(max_length = 255, example = "Thank you for your business!")]
+    pub footer_note: Option<String>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct BusinessGenericLinkConfig {
     /// Custom domain name to be used for hosting the link
@@ -2643,6 +4503,22 @@ pub struct BusinessPaymentLinkConfig {
     /// A list of allowed domains (glob patterns) where this link can be embedded / opened from
     #[schema(value_type = Option<HashSet<String>>)]
     pub allowed_domains: Option<HashSet<String>>,
+    /// Configuration for recovering payment links which expire without the payment being completed
+    #[schema(value_type = Option<PaymentLinkAbandonedCartConfig>)]
+    pub abandoned_cart_config: Option<PaymentLinkAbandonedCartConfig>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkAbandonedCartConfig {
+    /// Whether the customer is sent a recovery email with a regenerated payment link once the
+    /// payment link expires. The email is sent using the `payment_link_recovery` email template
+    /// of the profile
+    #[schema(default = false, example = true)]
+    pub send_recovery_email: bool,
+    /// The number of seconds for which the regenerated payment link is active. Defaults to 24 hours
+    #[schema(default = 86400, minimum = 60, maximum = 7890000, example = 86400)]
+    pub recovery_link_expiry: Option<u32>,
 }
 
 impl BusinessPaymentLinkConfig {
@@ -2669,6 +4545,16 @@ impl BusinessPaymentLinkConfig {
             return Err("Invalid allowed domain names received in payment_link_config");
         }
 
+        let is_recovery_link_expiry_valid = self
+            .abandoned_cart_config
+            .as_ref()
+            .and_then(|abandoned_cart_config| abandoned_cart_config.recovery_link_expiry)
+            .map(|recovery_link_expiry| (60..=7890000).contains(&recovery_link_expiry))
+            .unwrap_or(true);
+        if !is_recovery_link_expiry_valid {
+            return Err("recovery_link_expiry in payment_link_config should be between 60(1 min) to 7890000(3 months)");
+        }
+
         Ok(())
     }
 }
@@ -2752,20 +4638,56 @@ pub struct PaymentLinkConfig {
     pub transaction_details: Option<Vec<PaymentLinkTransactionDetails>>,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, ToSchema)]
 pub struct ExtendedCardInfoChoice {
+    /// Whether extended card info is collected for the payments of the profile
+    #[schema(example = true)]
     pub enabled: bool,
 }
 
 impl common_utils::events::ApiEventMetric for ExtendedCardInfoChoice {}
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, ToSchema)]
 pub struct ConnectorAgnosticMitChoice {
+    /// Whether merchant initiated transactions of the profile may be processed through a connector
+    /// other than the one the mandate was created with
+    #[schema(example = true)]
     pub enabled: bool,
 }
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RotateWebhookSecretRequest {
+    /// The period in seconds for which the outgoing webhooks are signed with both the previous and
+    /// the new secret after the rotation. Defaults to 86400 (1 day), and can be at most 604800 (7
+    /// days).
+    #[schema(example = 86400, maximum = 604800)]
+    pub overlap_period_in_secs: Option<u32>,
+}
+
+impl common_utils::events::ApiEventMetric for RotateWebhookSecretRequest {}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookSecretResponse {
+    /// The identifier for the profile
+    #[schema(max_length = 64, value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The secret the outgoing webhooks of the profile are signed with, sent in the
+    /// `X-Webhook-Signature-512` header
+    #[schema(example = "Qk9WNEdGU0h5Vk9SN0tJUm1xZkxhbWRtRTVwa0pOZmI")]
+    pub payment_response_hash_key: Option<String>,
+    /// The time until which the outgoing webhooks are also signed with the previous secret, sent
+    /// in the `X-Webhook-Signature-512-Previous` header. Not present once the overlap period of
+    /// the latest rotation has elapsed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secret_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSecretResponse {}
+
 impl common_utils::events::ApiEventMetric for payment_methods::PaymentMethodMigrate {}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
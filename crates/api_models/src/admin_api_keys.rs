@@ -0,0 +1,147 @@
+use masking::StrongSecret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::api_keys::ApiKeyExpiration;
+
+/// The request body for creating a scoped admin API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAdminApiKeyRequest {
+    /// A unique name for the admin API Key to help you identify it.
+    #[schema(max_length = 64, example = "Merchant onboarding key")]
+    pub name: String,
+
+    /// A description to provide more context about the admin API Key.
+    #[schema(
+        max_length = 256,
+        example = "Key used by the onboarding team to create merchant accounts and connectors"
+    )]
+    pub description: Option<String>,
+
+    /// The identifier of the admin role to assign to the admin API Key, which determines the admin
+    /// endpoints that the admin API Key is permitted to access.
+    #[schema(max_length = 64, example = "admin_merchant_onboarding")]
+    pub role_id: String,
+
+    /// An expiration date for the admin API Key.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: ApiKeyExpiration,
+}
+
+/// The response body for creating a scoped admin API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateAdminApiKeyResponse {
+    /// The identifier for the admin API Key.
+    #[schema(max_length = 64, example = "adm_key_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+
+    /// The unique name for the admin API Key to help you identify it.
+    #[schema(max_length = 64, example = "Merchant onboarding key")]
+    pub name: String,
+
+    /// The description to provide more context about the admin API Key.
+    #[schema(
+        max_length = 256,
+        example = "Key used by the onboarding team to create merchant accounts and connectors"
+    )]
+    pub description: Option<String>,
+
+    /// The plaintext admin API Key. Ensure you store the admin API Key securely as you will not be
+    /// able to see it again.
+    #[schema(value_type = String, max_length = 128)]
+    pub api_key: StrongSecret<String>,
+
+    /// The identifier of the admin role assigned to the admin API Key.
+    #[schema(max_length = 64, example = "admin_merchant_onboarding")]
+    pub role_id: String,
+
+    /// The time at which the admin API Key was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The expiration date for the admin API Key.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: ApiKeyExpiration,
+}
+
+/// The response body for retrieving a scoped admin API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RetrieveAdminApiKeyResponse {
+    /// The identifier for the admin API Key.
+    #[schema(max_length = 64, example = "adm_key_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+
+    /// The unique name for the admin API Key to help you identify it.
+    #[schema(max_length = 64, example = "Merchant onboarding key")]
+    pub name: String,
+
+    /// The description to provide more context about the admin API Key.
+    #[schema(
+        max_length = 256,
+        example = "Key used by the onboarding team to create merchant accounts and connectors"
+    )]
+    pub description: Option<String>,
+
+    /// The first few characters of the plaintext admin API Key to help you identify it.
+    #[schema(value_type = String, max_length = 64)]
+    pub prefix: StrongSecret<String>,
+
+    /// The identifier of the admin role assigned to the admin API Key.
+    #[schema(max_length = 64, example = "admin_merchant_onboarding")]
+    pub role_id: String,
+
+    /// The time at which the admin API Key was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The expiration date for the admin API Key.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: ApiKeyExpiration,
+}
+
+/// The request body for assigning an admin role to a scoped admin API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdminRoleAssignmentRequest {
+    /// The identifier of the admin role to assign to the admin API Key.
+    #[schema(max_length = 64, example = "admin_connector_manager")]
+    pub role_id: String,
+}
+
+/// The response body for revoking a scoped admin API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevokeAdminApiKeyResponse {
+    /// The identifier for the admin API Key.
+    #[schema(max_length = 64, example = "adm_key_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+
+    /// Indicates whether the admin API Key was revoked or not.
+    #[schema(example = "true")]
+    pub revoked: bool,
+}
+
+/// An admin role, which can be assigned to scoped admin API Keys.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminRoleResponse {
+    /// The identifier of the admin role.
+    #[schema(max_length = 64, example = "admin_connector_manager")]
+    pub role_id: String,
+
+    /// The name of the admin role.
+    #[schema(max_length = 64, example = "connector_manager")]
+    pub role_name: String,
+
+    /// The admin permissions granted by the admin role.
+    #[schema(value_type = Vec<AdminPermission>, example = json!(["merchant_connector_manage"]))]
+    pub permissions: Vec<common_enums::AdminPermission>,
+}
+
+/// The path parameters for the endpoints of a scoped admin API Key.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdminApiKeyId {
+    pub key_id: String,
+}
@@ -23,6 +23,12 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes to restrict the API Key to. An API Key restricted to one or more scopes can only
+    /// be used to access the endpoints belonging to those scopes, and cannot be used for any other
+    /// API access. The scopes of an API Key cannot be changed once it is created.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["tokenize"]))]
+    pub scopes: Option<Vec<common_enums::ApiKeyScope>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +66,10 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes that the API Key is restricted to. The API Key is not restricted if empty.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["tokenize"]))]
+    pub scopes: Option<Vec<common_enums::ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +112,10 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes that the API Key is restricted to. The API Key is not restricted if empty.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["tokenize"]))]
+    pub scopes: Option<Vec<common_enums::ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentArchivalRequest {
+    /// Payments created more than these many days ago, which are in a terminal status, are moved to
+    /// the archive tables
+    pub retention_period_in_days: u16,
+    /// The number of payments archived in a single batch
+    pub batch_size: Option<u16>,
+    /// The interval between consecutive runs of the archival job, once all the payments past the
+    /// retention period have been archived
+    pub interval_in_hours: Option<u16>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentArchivalResponse {
+    pub retention_period_in_days: u16,
+    pub batch_size: u16,
+    pub interval_in_hours: u16,
+    /// The number of payments archived since the archival job was scheduled
+    pub payments_archived: u64,
+    /// The time at which the last run of the archival job archived all the payments past the
+    /// retention period
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_completed_run_at: Option<time::PrimitiveDateTime>,
+    /// The time at which the archival job runs next
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<time::PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
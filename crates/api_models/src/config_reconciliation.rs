@@ -0,0 +1,141 @@
+use common_utils::{id_type, pii};
+use masking::Secret;
+
+use crate::{
+    admin::{MerchantDetails, PaymentMethodsEnabled, WebhookDetails},
+    enums as api_enums,
+};
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct MerchantConfigReconcileQuery {
+    /// Reports the plan to reconcile the live configuration without applying it, defaults to
+    /// `true`
+    pub dry_run: Option<bool>,
+}
+
+/// Request to reconcile the live configuration of merchants with a declarative document
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MerchantConfigReconcileRequest {
+    pub dry_run: bool,
+    /// The YAML document describing the merchants, which may contain connector credentials
+    pub document: Secret<String>,
+}
+
+/// Declarative description of the configuration of merchants. Only the fields present in the
+/// document are reconciled, the fields which are not present are left as they are.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConfigDocument {
+    pub merchants: Vec<MerchantDeclaration>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantDeclaration {
+    pub merchant_id: id_type::MerchantId,
+    /// The organization the merchant is created under, only used when the merchant is created
+    pub organization_id: Option<id_type::OrganizationId>,
+    pub merchant_name: Option<String>,
+    pub merchant_details: Option<MerchantDetails>,
+    pub webhook_details: Option<WebhookDetails>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// The business profiles of the merchant, identified by their names
+    #[serde(default)]
+    pub profiles: Vec<ProfileDeclaration>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileDeclaration {
+    pub profile_name: String,
+    pub return_url: Option<url::Url>,
+    pub webhook_details: Option<WebhookDetails>,
+    pub enable_payment_response_hash: Option<bool>,
+    pub redirect_to_merchant_with_http_post: Option<bool>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// The connector accounts of the profile, identified by their connector names and labels
+    #[serde(default)]
+    pub connectors: Vec<ConnectorDeclaration>,
+    /// The routing algorithm which is to be active for the payments of the profile
+    pub routing: Option<RoutingDeclaration>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorDeclaration {
+    pub connector_name: api_enums::Connector,
+    /// Identifies the connector account along with the connector name, required when the
+    /// profile has more than one account of the connector
+    pub connector_label: Option<String>,
+    pub connector_type: api_enums::ConnectorType,
+    pub connector_account_details: Option<pii::SecretSerdeValue>,
+    pub payment_methods_enabled: Option<Vec<PaymentMethodsEnabled>>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub test_mode: Option<bool>,
+    pub disabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingDeclaration {
+    pub name: String,
+    pub description: Option<String>,
+    pub algorithm: RoutingAlgorithmDeclaration,
+}
+
+/// Static routing algorithms, referring to the connector accounts of the profile declared in the
+/// document
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingAlgorithmDeclaration {
+    Priority(Vec<ConnectorReference>),
+    VolumeSplit(Vec<ConnectorVolumeSplitDeclaration>),
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorReference {
+    pub connector_name: api_enums::Connector,
+    pub connector_label: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplitDeclaration {
+    pub connector: ConnectorReference,
+    pub split: u8,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciledResourceType {
+    MerchantAccount,
+    Profile,
+    MerchantConnectorAccount,
+    RoutingAlgorithm,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconcileAction {
+    Create,
+    Update,
+    NoOp,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReconcilePlanEntry {
+    pub resource_type: ReconciledResourceType,
+    /// The path of the resource in the document, such as `merchant_id/profile_name/connector_label`
+    pub resource: String,
+    pub action: ReconcileAction,
+    /// The fields of the resource which differ from the document, in case of an update
+    pub changed_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MerchantConfigReconcileResponse {
+    /// Whether the plan was only reported, and not applied
+    pub dry_run: bool,
+    pub plan: Vec<ReconcilePlanEntry>,
+}
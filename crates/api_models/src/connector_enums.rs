@@ -79,6 +79,7 @@ pub enum Connector {
     Fiservemea,
     Fiuu,
     Forte,
+    Generichttp,
     Globalpay,
     Globepay,
     Gocardless,
@@ -109,6 +110,7 @@ pub enum Connector {
     Paypal,
     Payu,
     Placetopay,
+    Plugin,
     Powertranz,
     Prophetpay,
     Rapyd,
@@ -167,6 +169,20 @@ impl Connector {
     pub fn supports_vendor_disburse_account_create_for_payout(&self) -> bool {
         matches!(self, Self::Stripe)
     }
+    #[cfg(feature = "payouts")]
+    pub fn supports_payout_balance(&self) -> bool {
+        matches!(self, Self::Adyenplatform)
+    }
+    #[cfg(feature = "payouts")]
+    pub fn supports_payout_recipient_status(&self) -> bool {
+        matches!(self, Self::Stripe)
+    }
+    pub fn supports_bank_list(&self, payment_method_type: common_enums::PaymentMethodType) -> bool {
+        matches!(
+            (self, payment_method_type),
+            (Self::Multisafepay, common_enums::PaymentMethodType::Ideal)
+        )
+    }
     pub fn supports_access_token(&self, payment_method: PaymentMethod) -> bool {
         matches!(
             (self, payment_method),
@@ -224,6 +240,7 @@ impl Connector {
             | Self::Fiservemea
             | Self::Fiuu
             | Self::Forte
+            | Self::Generichttp
             | Self::Globalpay
             | Self::Globepay
             | Self::Gocardless
@@ -249,6 +266,7 @@ impl Connector {
             | Self::Paypal
             | Self::Payu
             | Self::Placetopay
+            | Self::Plugin
             | Self::Powertranz
             | Self::Prophetpay
             | Self::Rapyd
@@ -3,3 +3,6 @@ pub const MAX_ORDER_FULFILLMENT_EXPIRY: i64 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_ORDER_FULFILLMENT_EXPIRY: i64 = 60;
+
+/// Max time in milliseconds after which the customer is redirected from the intermediate pages
+pub const MAX_AUTO_REDIRECT_TIMEOUT_MS: u32 = 30000;
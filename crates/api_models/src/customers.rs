@@ -1,9 +1,9 @@
-use common_utils::{crypto, custom_serde, id_type, pii, types::Description};
+use common_utils::{crypto, custom_serde, id_type, link_utils, pii, types::Description};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::payments;
+use crate::{enums, payment_methods, payments};
 
 /// The customer details
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -158,6 +158,14 @@ pub struct CustomerResponse {
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The payment methods saved for the customer, present when `expand=payment_method` is passed
+    #[schema(value_type = Option<Vec<PaymentMethodResponse>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_methods: Option<Vec<payment_methods::PaymentMethodResponse>>,
+    /// The status of the KYC of the customer with the payout connector, present for customers who
+    /// are onboarded as payout recipients with connectors which verify the recipients
+    #[schema(value_type = Option<RecipientKycStatus>, example = "verified")]
+    pub recipient_kyc_status: Option<enums::RecipientKycStatus>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -208,6 +216,10 @@ pub struct CustomerResponse {
     pub default_payment_method_id: Option<String>,
     /// Global id
     pub id: String,
+    /// The status of the KYC of the customer with the payout connector, present for customers who
+    /// are onboarded as payout recipients with connectors which verify the recipients
+    #[schema(value_type = Option<RecipientKycStatus>, example = "verified")]
+    pub recipient_kyc_status: Option<enums::RecipientKycStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -234,6 +246,39 @@ impl CustomerId {
     }
 }
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerRetrieveRequest {
+    pub customer_id: id_type::CustomerId,
+    /// The list of related objects to be expanded in the response
+    pub expand: Option<Vec<enums::ExpandableObject>>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl CustomerRetrieveRequest {
+    pub fn get_merchant_reference_id(&self) -> id_type::CustomerId {
+        self.customer_id.clone()
+    }
+
+    /// Checks if the given related object was requested to be expanded in the response
+    pub fn should_expand(&self, object: enums::ExpandableObject) -> bool {
+        self.expand
+            .as_ref()
+            .is_some_and(|expand| expand.contains(&object))
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CustomerRetrieveBody {
+    /// The comma separated list of related objects to be expanded in the response
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
+    pub expand: Option<Vec<enums::ExpandableObject>>,
+}
+
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GlobalId {
@@ -416,3 +461,165 @@ impl UpdateCustomerId {
         Self(id)
     }
 }
+
+/// Request for creating a hosted portal link for a customer to manage their saved payment methods
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerPortalLinkRequest {
+    /// The unique identifier for the customer portal link. Autogenerated if not provided.
+    #[schema(value_type = Option<String>, example = "customer_portal_link_2bdacf398vwzq5n422S1")]
+    pub customer_portal_link_id: Option<String>,
+
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The business profile whose `customer_portal_link_config` is used for the link. Defaults to the merchant's default profile.
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// UI configuration overriding the profile's configuration
+    #[serde(flatten)]
+    #[schema(value_type = Option<GenericLinkUiConfig>)]
+    pub ui_config: Option<link_utils::GenericLinkUiConfig>,
+
+    /// Time in seconds after which the link expires
+    #[schema(value_type = Option<u32>, example = 900)]
+    pub session_expiry: Option<u32>,
+
+    /// Redirect to this URL when the customer is done with the portal
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<String>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerPortalLinkResponse {
+    /// The unique identifier for the customer portal link
+    #[schema(value_type = String, example = "customer_portal_link_2bdacf398vwzq5n422S1")]
+    pub customer_portal_link_id: String,
+
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The business profile used for the link
+    #[schema(value_type = String, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// Time when this link will be expired in ISO8601 format
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub expiry: time::PrimitiveDateTime,
+
+    /// URL of the hosted customer portal
+    #[schema(value_type = String, example = "https://sandbox.hyperswitch.io/customer_portal/merchant_1671528864/customer_portal_link_2bdacf398vwzq5n422S1")]
+    pub link: Secret<url::Url>,
+
+    /// Redirect to this URL when the customer is done with the portal
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<String>,
+
+    /// UI configuration used for the link
+    #[serde(flatten)]
+    #[schema(value_type = GenericLinkUiConfig)]
+    pub ui_config: link_utils::GenericLinkUiConfig,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CustomerPortalLinkRenderRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub customer_portal_link_id: String,
+}
+
+/// Details injected into the hosted customer portal page
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Serialize)]
+pub struct CustomerPortalLinkDetails {
+    pub customer_portal_link_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub client_secret: Secret<String>,
+    pub customer_id: id_type::CustomerId,
+    #[serde(with = "custom_serde::iso8601")]
+    pub session_expiry: time::PrimitiveDateTime,
+    pub return_url: Option<String>,
+    #[serde(flatten)]
+    pub ui_config: link_utils::GenericLinkUiConfigFormData,
+    pub payment_methods: Vec<CustomerPortalPaymentMethod>,
+    pub billing_address: Option<payments::AddressDetails>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Serialize)]
+pub struct CustomerPortalPaymentMethod {
+    pub payment_method_id: String,
+    pub payment_method: enums::PaymentMethod,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    pub card: Option<CustomerPortalCardDetails>,
+    pub is_default: bool,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Serialize)]
+pub struct CustomerPortalCardDetails {
+    pub last4_digits: Option<String>,
+    pub card_network: Option<enums::CardNetwork>,
+    pub expiry_month: Option<Secret<String>>,
+    pub expiry_year: Option<Secret<String>>,
+    pub card_holder_name: Option<Secret<String>>,
+}
+
+/// Request for deleting a saved payment method from the customer portal
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerPortalPaymentMethodDeleteRequest {
+    #[serde(skip)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    #[serde(skip)]
+    pub customer_portal_link_id: String,
+
+    #[serde(skip)]
+    pub payment_method_id: String,
+
+    /// Client secret of the customer portal link
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+}
+
+/// Request for updating the customer's billing address from the customer portal
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerPortalBillingAddressUpdateRequest {
+    #[serde(skip)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    #[serde(skip)]
+    pub customer_portal_link_id: String,
+
+    /// Client secret of the customer portal link
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+
+    /// The updated billing address of the customer
+    #[schema(value_type = AddressDetails)]
+    pub address: payments::AddressDetails,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerPortalBillingAddressResponse {
+    /// The unique identifier for the customer portal link
+    pub customer_portal_link_id: String,
+
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The billing address of the customer
+    #[schema(value_type = Option<AddressDetails>)]
+    pub address: Option<payments::AddressDetails>,
+}
@@ -0,0 +1,42 @@
+use common_utils::id_type;
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DataRetentionPurgeRequest {
+    /// Reports the data which is past its retention period without purging it, defaults to `true`
+    pub dry_run: Option<bool>,
+}
+
+/// Classes of data stored for a merchant, each of which is retained for the period configured in
+/// the data retention policy of the merchant
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataClass {
+    /// Customer, billing and shipping details provided in payments
+    Pii,
+    /// Metadata, order details and FRM metadata of payments
+    PaymentMetadata,
+    /// Logs of the webhooks delivered to the merchant
+    Logs,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DataRetentionPurgeReport {
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the data was only reported, and not purged
+    pub dry_run: bool,
+    /// Reports of the data classes for which a retention period is configured
+    pub data_classes: Vec<DataClassPurgeReport>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DataClassPurgeReport {
+    pub data_class: DataClass,
+    pub retention_period_in_days: u16,
+    /// Data of the class stored before this time is past its retention period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub purge_before: time::PrimitiveDateTime,
+    /// The number of payments or webhook events whose data was purged, or would be purged in case
+    /// of a dry run
+    pub records: usize,
+}
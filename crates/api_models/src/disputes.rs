@@ -2,7 +2,6 @@ use std::collections::HashMap;
 
 use common_utils::types::TimeRange;
 use masking::{Deserialize, Serialize};
-use serde::de::Error;
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
@@ -103,6 +102,51 @@ pub enum EvidenceType {
     UncategorizedFile,
 }
 
+/// Payment details that can be referred to in dispute evidence templates, written as `{{placeholder}}`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display, strum::EnumString, strum::EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub enum EvidenceTemplatePlaceholder {
+    PaymentId,
+    Amount,
+    Currency,
+    PaymentCreatedAt,
+    CardNetwork,
+    CardLast4,
+    AvsResult,
+    CvvResult,
+    ThreeDsAuthenticated,
+    Eci,
+    ShippingCarrier,
+    ShippingTrackingNumber,
+    ShippingDate,
+}
+
+impl EvidenceTemplatePlaceholder {
+    pub fn get_template_key(&self) -> String {
+        format!("{{{{{self}}}}}")
+    }
+
+    /// Checks that every placeholder in the template is terminated and is a known placeholder
+    pub fn are_placeholders_valid(template: &str) -> bool {
+        template.split("{{").skip(1).all(|segment| {
+            segment
+                .split_once("}}")
+                .is_some_and(|(placeholder, _)| placeholder.parse::<Self>().is_ok())
+        })
+    }
+}
+
+/// Delivery details of a payment, supplied by the merchant as `delivery_details` in the payment metadata
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DeliveryDetails {
+    /// Delivery service that shipped the product
+    pub shipping_carrier: Option<String>,
+    /// Tracking number of the shipped product
+    pub shipping_tracking_number: Option<String>,
+    /// Date on which the product was shipped
+    pub shipping_date: Option<String>,
+}
+
 #[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct DisputeEvidenceBlock {
     /// Evidence type
@@ -126,18 +170,30 @@ pub struct DisputeListGetConstraints {
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     /// The comma separated list of status of the disputes
-    #[serde(default, deserialize_with = "parse_comma_separated")]
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
     pub dispute_status: Option<Vec<DisputeStatus>>,
     /// The comma separated list of stages of the disputes
-    #[serde(default, deserialize_with = "parse_comma_separated")]
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
     pub dispute_stage: Option<Vec<DisputeStage>>,
     /// Reason for the dispute
     pub reason: Option<String>,
     /// The comma separated list of connectors linked to disputes
-    #[serde(default, deserialize_with = "parse_comma_separated")]
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
     pub connector: Option<Vec<String>>,
     /// The comma separated list of currencies of the disputes
-    #[serde(default, deserialize_with = "parse_comma_separated")]
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
     pub currency: Option<Vec<Currency>>,
     /// The merchant connector id to filter the disputes list
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
@@ -230,18 +286,35 @@ pub struct DisputesAggregateResponse {
     pub status_with_count: HashMap<DisputeStatus, i64>,
 }
 
-fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-    T: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug + std::fmt::Display + std::error::Error,
-{
-    let output = Option::<&str>::deserialize(v)?;
-    output
-        .map(|s| {
-            s.split(",")
-                .map(|x| x.parse::<T>().map_err(D::Error::custom))
-                .collect::<Result<_, _>>()
-        })
-        .transpose()
+#[cfg(test)]
+mod evidence_template_tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholder_validation() {
+        assert!(EvidenceTemplatePlaceholder::are_placeholders_valid(
+            "Payment {{payment_id}} passed the CVV check ({{cvv_result}})"
+        ));
+        assert!(EvidenceTemplatePlaceholder::are_placeholders_valid(
+            "No placeholders"
+        ));
+        assert!(!EvidenceTemplatePlaceholder::are_placeholders_valid(
+            "Shipped on {{delivery_date}}"
+        ));
+        assert!(!EvidenceTemplatePlaceholder::are_placeholders_valid(
+            "Unterminated {{payment_id"
+        ));
+    }
+
+    #[test]
+    fn test_template_key() {
+        assert_eq!(
+            EvidenceTemplatePlaceholder::ThreeDsAuthenticated.get_template_key(),
+            "{{three_ds_authenticated}}"
+        );
+        assert_eq!(
+            EvidenceTemplatePlaceholder::CardLast4.get_template_key(),
+            "{{card_last4}}"
+        );
+    }
 }
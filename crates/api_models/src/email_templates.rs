@@ -0,0 +1,117 @@
+use common_utils::id_type;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request for creating an email template for a business profile
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EmailTemplateCreateRequest {
+    /// The type of email the template is used for
+    #[schema(value_type = EmailTemplateType, example = "payment_receipt")]
+    pub email_type: api_enums::EmailTemplateType,
+
+    /// Locale of the template. Emails for other locales fall back to the template of the default locale `en`
+    #[schema(max_length = 32, example = "en")]
+    pub locale: Option<String>,
+
+    /// Subject of the email. Variables can be substituted using the `{{variable_name}}` syntax. If not provided, the system subject for the email type is used
+    #[schema(max_length = 255, example = "Your receipt from {{merchant_name}}")]
+    pub subject: Option<String>,
+
+    /// HTML body of the email. Variables can be substituted using the `{{variable_name}}` syntax. If not provided, the system template for the email type is used with the configured branding
+    #[schema(
+        example = "<p>Hi {{customer_name}}, we received your payment of {{amount}} {{currency}}.</p>"
+    )]
+    pub body: Option<String>,
+
+    /// URL of the logo displayed in the email
+    #[schema(max_length = 2048, example = "https://hyperswitch.io/favicon.ico")]
+    pub logo_url: Option<String>,
+
+    /// Primary color used in the email, as a hex color code
+    #[schema(max_length = 32, example = "#4285F4")]
+    pub primary_color: Option<String>,
+
+    /// Whether emails of this type are sent using the template
+    #[schema(default = true, example = true)]
+    pub is_active: Option<bool>,
+}
+
+/// Request for updating an email template of a business profile
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EmailTemplateUpdateRequest {
+    /// Subject of the email. Variables can be substituted using the `{{variable_name}}` syntax
+    #[schema(max_length = 255, example = "Your receipt from {{merchant_name}}")]
+    pub subject: Option<String>,
+
+    /// HTML body of the email. Variables can be substituted using the `{{variable_name}}` syntax
+    pub body: Option<String>,
+
+    /// URL of the logo displayed in the email
+    #[schema(max_length = 2048, example = "https://hyperswitch.io/favicon.ico")]
+    pub logo_url: Option<String>,
+
+    /// Primary color used in the email, as a hex color code
+    #[schema(max_length = 32, example = "#4285F4")]
+    pub primary_color: Option<String>,
+
+    /// Whether emails of this type are sent using the template
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct EmailTemplateResponse {
+    /// The identifier for the email template
+    #[schema(example = "email_template_QIZcUeHfMOHqh5ClSBQR")]
+    pub template_id: String,
+
+    /// The identifier for the business profile the template belongs to
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The type of email the template is used for
+    #[schema(value_type = EmailTemplateType, example = "payment_receipt")]
+    pub email_type: api_enums::EmailTemplateType,
+
+    /// Locale of the template
+    #[schema(example = "en")]
+    pub locale: String,
+
+    /// Subject of the email
+    pub subject: String,
+
+    /// HTML body of the email, if the system template is not used
+    pub body: Option<String>,
+
+    /// URL of the logo displayed in the email
+    pub logo_url: Option<String>,
+
+    /// Primary color used in the email
+    pub primary_color: Option<String>,
+
+    /// Whether emails of this type are sent using the template
+    pub is_active: bool,
+
+    /// Variables which can be substituted in the subject and body of the template
+    #[schema(example = json!(["merchant_name", "customer_name", "amount", "currency"]))]
+    pub available_variables: Vec<String>,
+
+    /// Time at which the template was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// Time at which the template was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct EmailTemplateDeleteResponse {
+    /// The identifier for the email template
+    pub template_id: String,
+
+    /// Whether the template was deleted
+    pub deleted: bool,
+}
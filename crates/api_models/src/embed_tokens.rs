@@ -0,0 +1,55 @@
+use common_utils::id_type;
+use masking::Secret;
+use utoipa::ToSchema;
+
+/// The routes which can be called with an embed token
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EmbedTokenScope {
+    /// Listing and filtering the payments of the profile
+    PaymentsRead,
+}
+
+/// Request for creating a token for a dashboard widget embedded in the portal of a platform
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EmbedTokenCreateRequest {
+    /// The routes which can be called with the token, for the profile of the token only
+    #[schema(value_type = Vec<EmbedTokenScope>, example = json!(["payments_read"]))]
+    pub scopes: Vec<EmbedTokenScope>,
+
+    /// The time in seconds after which the token expires, defaults to 900 seconds and limited to 3600 seconds
+    #[schema(example = 900)]
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct EmbedTokenResponse {
+    /// The token, to be passed in the `X-Embed-Token` header of the requests of the widget
+    #[schema(value_type = String)]
+    pub token: Secret<String>,
+
+    /// The profile whose data can be accessed with the token
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The routes which can be called with the token
+    #[schema(value_type = Vec<EmbedTokenScope>)]
+    pub scopes: Vec<EmbedTokenScope>,
+
+    /// The time at which the token expires
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
@@ -363,9 +363,42 @@ pub enum RetryAction {
     Requeue,
 }
 
+/// The related objects which can be expanded in a retrieve response
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExpandableObject {
+    /// The timeline of the status transitions of the object
+    StatusHistory,
+    /// The customer associated with the object
+    Customer,
+    /// The payment method(s) associated with the object
+    PaymentMethod,
+    /// The refunds initiated against the object
+    Refunds,
+    /// The disputes raised against the object
+    Disputes,
+    /// All the attempts made for the object
+    Attempts,
+}
+
 #[derive(Clone, Copy)]
 pub enum LockerChoice {
     HyperswitchCardVault,
+    /// The card vault configured at `locker.host_rs`, to which stored cards are migrated when
+    /// switching card vaults
+    RustCardVault,
 }
 
 #[derive(
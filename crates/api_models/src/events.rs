@@ -23,20 +23,33 @@ use crate::customers::CustomerListRequest;
 #[allow(unused_imports)]
 use crate::{
     admin::*,
+    admin_api_keys::*,
     analytics::{
         api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
         outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
     },
     api_keys::*,
+    archival::*,
     cards_info::*,
+    config_reconciliation::*,
+    data_retention::*,
     disputes::*,
+    email_templates::*,
+    embed_tokens::*,
     files::*,
+    installment_plans::*,
+    ledger::*,
+    link_delivery::*,
     mandates::*,
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
     },
+    payload_logs::*,
     payment_methods::*,
+    payment_templates::*,
     payments::*,
+    test_clocks::*,
+    usage::*,
     user::{UserKeyTransferRequest, UserTransferKeyResponse},
     verifications::*,
 };
@@ -65,9 +78,45 @@ impl_api_event_type!(
         PaymentMethodId,
         PaymentMethodCreate,
         PaymentLinkInitiateRequest,
+        PaymentLinkExpiryUpdateRequest,
+        EmailTemplateCreateRequest,
+        EmailTemplateUpdateRequest,
+        EmailTemplateResponse,
+        EmailTemplateDeleteResponse,
+        EmbedTokenCreateRequest,
+        EmbedTokenResponse,
+        PaymentTemplateCreateRequest,
+        PaymentTemplateUpdateRequest,
+        PaymentTemplateResponse,
+        PaymentTemplateDeleteResponse,
+        LedgerBalancesResponse,
+        LedgerStatementRequest,
+        LedgerStatementResponse,
+        PayoutBalanceOverrideResponse,
+        LinkDeliveryListConstraints,
+        LinkDeliveryResponse,
+        InstallmentPlanCreateRequest,
+        InstallmentPlanResponse,
+        InstallmentPlanListConstraints,
+        InstallmentPlanListResponse,
+        InstallmentPlanId,
+        TestClockCreateRequest,
+        TestClockAdvanceRequest,
+        TestClockResponse,
+        TestClockListResponse,
+        TestClockDeleteResponse,
+        TestClockId,
+        PaymentLinkTemplateCreateRequest,
+        PaymentLinkTemplateUpdateRequest,
+        PaymentLinkTemplateResponse,
+        PaymentLinkTemplateListConstraints,
+        PaymentLinkTemplateListResponse,
+        PaymentLinkTemplateUsageResponse,
+        PaymentLinkTemplateInitiateRequest,
         RetrievePaymentLinkResponse,
         MandateListConstraints,
         CreateFileResponse,
+        FileDownloadUrlResponse,
         MerchantConnectorResponse,
         MerchantConnectorId,
         MandateResponse,
@@ -87,6 +136,8 @@ impl_api_event_type!(
         ToggleAllKVResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
+        RotatePublishableKeyRequest,
+        RotatePublishableKeyResponse,
         CardInfoResponse,
         CreateApiKeyResponse,
         CreateApiKeyRequest,
@@ -150,6 +201,63 @@ impl_api_event_type!(
     )
 );
 
+#[cfg(feature = "v1")]
+impl_api_event_type!(
+    Miscellaneous,
+    (
+        MerchantAccountSearchRequest,
+        MerchantAccountSearchResponse,
+        MerchantConnectorBulkToggleRequest,
+        MerchantConnectorBulkToggleResponse,
+        MerchantConnectorWebhookHealthResponse,
+        ExpiringConnectorCredentialsListConstraints,
+        ExpiringConnectorCredentialsResponse
+    )
+);
+
+impl_api_event_type!(
+    Miscellaneous,
+    (
+        ConnectorMaintenanceWindowCreateRequest,
+        ConnectorMaintenanceWindowResponse
+    )
+);
+
+impl_api_event_type!(Miscellaneous, (UsageRetrieveRequest, MerchantUsageResponse));
+
+impl_api_event_type!(
+    Miscellaneous,
+    (PaymentArchivalRequest, PaymentArchivalResponse)
+);
+
+impl_api_event_type!(Miscellaneous, (crate::enums::Connector));
+
+impl_api_event_type!(
+    Miscellaneous,
+    (DataRetentionPurgeRequest, DataRetentionPurgeReport)
+);
+
+impl_api_event_type!(
+    Miscellaneous,
+    (
+        MerchantConfigReconcileRequest,
+        MerchantConfigReconcileResponse
+    )
+);
+
+impl_api_event_type!(
+    Miscellaneous,
+    (
+        CreateAdminApiKeyRequest,
+        CreateAdminApiKeyResponse,
+        RetrieveAdminApiKeyResponse,
+        AdminRoleAssignmentRequest,
+        RevokeAdminApiKeyResponse,
+        AdminRoleResponse,
+        AdminApiKeyId
+    )
+);
+
 impl<T> ApiEventMetric for MetricsResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Miscellaneous)
@@ -202,3 +310,13 @@ impl ApiEventMetric for DisputeListFilters {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl_api_event_type!(
+    PayloadLog,
+    (
+        PayloadLogRetrieveRequest,
+        PayloadLogListConstraints,
+        PayloadLogResponse,
+        PayloadLogListResponse
+    )
+);
@@ -1,12 +1,16 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-use crate::customers::CustomerId;
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use crate::customers::GlobalId;
 use crate::customers::{
     CustomerDeleteResponse, CustomerRequest, CustomerResponse, CustomerUpdateRequest,
 };
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::customers::{
+    CustomerId, CustomerPortalBillingAddressResponse, CustomerPortalBillingAddressUpdateRequest,
+    CustomerPortalLinkRenderRequest, CustomerPortalLinkRequest, CustomerPortalLinkResponse,
+    CustomerPortalPaymentMethodDeleteRequest, CustomerRetrieveRequest,
+};
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 impl ApiEventMetric for CustomerDeleteResponse {
@@ -71,6 +75,15 @@ impl ApiEventMetric for CustomerId {
     }
 }
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.get_merchant_reference_id(),
+        })
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 impl ApiEventMetric for GlobalId {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -97,4 +110,58 @@ impl ApiEventMetric for CustomerUpdateRequest {
         })
     }
 }
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerPortalLinkRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerPortalLinkResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::CustomerPortalLink {
+            link_id: self.customer_portal_link_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerPortalLinkRenderRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::CustomerPortalLink {
+            link_id: self.customer_portal_link_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerPortalPaymentMethodDeleteRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::CustomerPortalLink {
+            link_id: self.customer_portal_link_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerPortalBillingAddressUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::CustomerPortalLink {
+            link_id: self.customer_portal_link_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerPortalBillingAddressResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::CustomerPortalLink {
+            link_id: self.customer_portal_link_id.clone(),
+        })
+    }
+}
 // These needs to be fixed for v2
@@ -1,9 +1,23 @@
 use common_utils::events::ApiEventMetric;
 
-use crate::locker_migration::MigrateCardResponse;
+use crate::locker_migration::{
+    LockerMigrationRequest, LockerMigrationResponse, MigrateCardResponse,
+};
 
 impl ApiEventMetric for MigrateCardResponse {
     fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
         Some(common_utils::events::ApiEventsType::RustLocker)
     }
 }
+
+impl ApiEventMetric for LockerMigrationRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RustLocker)
+    }
+}
+
+impl ApiEventMetric for LockerMigrationResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RustLocker)
+    }
+}
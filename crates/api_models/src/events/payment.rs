@@ -12,26 +12,39 @@ use super::{
 use crate::payment_methods::CustomerPaymentMethodsListResponse;
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use crate::payment_methods::CustomerPaymentMethodsListResponse;
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::payment_methods::{
+    PaymentMethodCollectLinkSubmitRequest, VaultTokenizeRequest, VaultTokenizeResponse,
+};
 use crate::{
+    invoices::{InvoiceCreateRequest, InvoiceResponse},
     payment_methods::{
         CustomerDefaultPaymentMethodResponse, DefaultPaymentMethod, ListCountriesCurrenciesRequest,
         ListCountriesCurrenciesResponse, PaymentMethodCollectLinkRenderRequest,
         PaymentMethodCollectLinkRequest, PaymentMethodCollectLinkResponse,
+        PaymentMethodCollectLinkSubmitResponse, PaymentMethodConsentEventResponse,
         PaymentMethodDeleteResponse, PaymentMethodListRequest, PaymentMethodListResponse,
         PaymentMethodMigrateResponse, PaymentMethodResponse, PaymentMethodUpdate,
+        SharePaymentMethodRequest, SharedPaymentMethodResponse, VaultDetokenizeRequest,
+        VaultDetokenizeResponse,
     },
     payments::{
         self, ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
         PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
         PaymentListResponse, PaymentListResponseV2, PaymentsAggregateResponse,
-        PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
-        PaymentsCompleteAuthorizeRequest, PaymentsDynamicTaxCalculationRequest,
-        PaymentsDynamicTaxCalculationResponse, PaymentsExternalAuthenticationRequest,
-        PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
-        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse,
-        PaymentsPostSessionTokensRequest, PaymentsPostSessionTokensResponse, PaymentsRejectRequest,
-        PaymentsResponse, PaymentsRetrieveRequest, PaymentsSessionResponse, PaymentsStartRequest,
-        RedirectionResponse,
+        PaymentsApproveRequest, PaymentsAsyncConfirmResponse, PaymentsCancelRequest,
+        PaymentsCaptureRequest, PaymentsClientSecretRotateResponse,
+        PaymentsCompleteAuthorizeRequest, PaymentsDccOfferRequest, PaymentsDccOfferResponse,
+        PaymentsDynamicTaxCalculationRequest, PaymentsDynamicTaxCalculationResponse,
+        PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
+        PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
+        PaymentsManualUpdateResponse, PaymentsPostSessionTokensRequest,
+        PaymentsPostSessionTokensResponse, PaymentsRejectRequest, PaymentsResponse,
+        PaymentsRetrieveRequest, PaymentsSessionResponse, PaymentsShipmentRequest,
+        PaymentsStartRequest, RedirectionResponse,
     },
 };
 
@@ -65,6 +78,33 @@ impl ApiEventMetric for PaymentsCaptureRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsShipmentRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsDccOfferRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsDccOfferResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsCompleteAuthorizeRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -195,6 +235,14 @@ impl ApiEventMetric for PaymentsResponse {
     }
 }
 
+impl ApiEventMetric for PaymentsAsyncConfirmResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentMethodResponse {
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
@@ -243,6 +291,68 @@ impl ApiEventMetric for PaymentMethodMigrateResponse {
 
 impl ApiEventMetric for PaymentMethodUpdate {}
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl ApiEventMetric for VaultTokenizeRequest {}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl ApiEventMetric for VaultTokenizeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.token.clone(),
+            payment_method: Some(common_enums::PaymentMethod::Card),
+            payment_method_type: None,
+        })
+    }
+}
+
+impl ApiEventMetric for SharePaymentMethodRequest {}
+
+impl ApiEventMetric for SharedPaymentMethodResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.source_payment_method_id.clone(),
+            payment_method: None,
+            payment_method_type: None,
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentMethodConsentEventResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.payment_method_id.clone(),
+            payment_method: None,
+            payment_method_type: None,
+        })
+    }
+}
+
+impl ApiEventMetric for VaultDetokenizeRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.token.clone(),
+            payment_method: Some(common_enums::PaymentMethod::Card),
+            payment_method_type: None,
+        })
+    }
+}
+
+impl ApiEventMetric for VaultDetokenizeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.token.clone(),
+            payment_method: Some(common_enums::PaymentMethod::Card),
+            payment_method_type: None,
+        })
+    }
+}
+
 impl ApiEventMetric for DefaultPaymentMethod {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethod {
@@ -318,6 +428,26 @@ impl ApiEventMetric for PaymentMethodCollectLinkResponse {
     }
 }
 
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+impl ApiEventMetric for PaymentMethodCollectLinkSubmitRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodCollectLink {
+            link_id: self.pm_collect_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentMethodCollectLinkSubmitResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodCollectLink {
+            link_id: self.pm_collect_link_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentListFilterConstraints {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -384,6 +514,15 @@ impl ApiEventMetric for PaymentsExternalAuthenticationRequest {
 #[cfg(feature = "v1")]
 impl ApiEventMetric for ExtendedCardInfoResponse {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsClientSecretRotateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsManualUpdateRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -418,3 +557,19 @@ impl ApiEventMetric for PaymentStartRedirectionRequest {
         })
     }
 }
+
+impl ApiEventMetric for InvoiceCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
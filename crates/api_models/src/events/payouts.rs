@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
+    PayoutActionRequest, PayoutAttemptListRequest, PayoutAttemptListResponse, PayoutBalanceRequest,
+    PayoutBalanceResponse, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
     PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
     PayoutRetrieveRequest,
 };
@@ -38,6 +39,22 @@ impl ApiEventMetric for PayoutActionRequest {
     }
 }
 
+impl ApiEventMetric for PayoutAttemptListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutAttemptListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payout {
+            payout_id: self.payout_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PayoutListConstraints {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -69,3 +86,15 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBalanceRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutBalanceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -10,17 +10,24 @@ use crate::user::{
     dashboard_metadata::{
         GetMetaDataRequest, GetMetaDataResponse, GetMultipleMetaDataPayload, SetMetaDataRequest,
     },
+    scim::{
+        ScimConfigRequest, ScimConfigResponse, ScimGroupListResponse, ScimGroupRequest,
+        ScimGroupResponse, ScimListQuery, ScimPatchRequest, ScimResourceId, ScimUserListResponse,
+        ScimUserRequest, ScimUserResponse,
+    },
     AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginTotpResponse,
     ChangePasswordRequest, ConnectAccountRequest, CreateInternalUserRequest,
-    CreateUserAuthenticationMethodRequest, ForgotPasswordRequest, GetSsoAuthUrlRequest,
-    GetUserAuthenticationMethodsRequest, GetUserDetailsResponse, GetUserRoleDetailsRequest,
-    GetUserRoleDetailsResponseV2, InviteUserRequest, ReInviteUserRequest, RecoveryCodes,
-    ResetPasswordRequest, RotatePasswordRequest, SendVerifyEmailRequest, SignUpRequest,
-    SignUpWithMerchantIdRequest, SsoSignInRequest, SwitchMerchantRequest,
-    SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse, TwoFactorAuthStatusResponse,
-    TwoFactorStatus, UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest,
-    UserFromEmailRequest, UserMerchantCreate, VerifyEmailRequest, VerifyRecoveryCodeRequest,
-    VerifyTotpRequest,
+    CreateUserAuthenticationMethodRequest, ForgotPasswordRequest, GetSsoAuthUrlByEmailRequest,
+    GetSsoAuthUrlRequest, GetUserAuthenticationMethodsRequest, GetUserDetailsResponse,
+    GetUserRoleDetailsRequest, GetUserRoleDetailsResponseV2, InviteUserRequest,
+    ReInviteUserRequest, RecoveryCodes, ResetPasswordRequest, ResetTwoFactorAuthRequest,
+    RotatePasswordRequest, SendVerifyEmailRequest, SignUpRequest, SignUpWithMerchantIdRequest,
+    SsoSignInRequest, SwitchMerchantRequest, SwitchOrganizationRequest, SwitchProfileRequest,
+    TokenResponse, TwoFactorAuthStatusResponse, TwoFactorStatus, UpdateUserAccountDetailsRequest,
+    UpdateUserAuthenticationMethodRequest, UserFromEmailRequest, UserMerchantCreate,
+    VerifyEmailRequest, VerifyRecoveryCodeRequest, VerifyTotpRequest,
+    WebauthnAuthenticationRequest, WebauthnChallengeResponse, WebauthnCredentialId,
+    WebauthnCredentialResponse, WebauthnRegistrationRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -64,8 +71,26 @@ common_utils::impl_api_event_type!(
         CreateUserAuthenticationMethodRequest,
         UpdateUserAuthenticationMethodRequest,
         GetSsoAuthUrlRequest,
+        GetSsoAuthUrlByEmailRequest,
         SsoSignInRequest,
-        AuthSelectRequest
+        AuthSelectRequest,
+        ScimConfigRequest,
+        ScimConfigResponse,
+        ScimUserRequest,
+        ScimUserResponse,
+        ScimUserListResponse,
+        ScimGroupRequest,
+        ScimGroupResponse,
+        ScimGroupListResponse,
+        ScimListQuery,
+        ScimPatchRequest,
+        ScimResourceId,
+        WebauthnChallengeResponse,
+        WebauthnRegistrationRequest,
+        WebauthnAuthenticationRequest,
+        WebauthnCredentialResponse,
+        WebauthnCredentialId,
+        ResetTwoFactorAuthRequest
     )
 );
 
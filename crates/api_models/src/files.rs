@@ -19,3 +19,15 @@ pub struct FileMetadataResponse {
     /// File availability
     pub available: bool,
 }
+
+#[derive(Debug, serde::Serialize, ToSchema, Clone)]
+pub struct FileDownloadUrlResponse {
+    /// ID of the file
+    pub file_id: String,
+    /// Short-lived URL from which the file can be downloaded without authentication
+    pub url: String,
+    /// Time after which the URL can no longer be used to download the file
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    pub expires_at: time::PrimitiveDateTime,
+}
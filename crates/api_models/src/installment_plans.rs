@@ -0,0 +1,193 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request for creating a plan that charges the total amount in installments against a mandate
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentPlanCreateRequest {
+    /// The identifier for the mandate the installments are charged against. The mandate must be
+    /// active and must allow multiple payments
+    #[schema(example = "man_QIZcUeHfMOHqh5ClSBQR")]
+    pub mandate_id: String,
+
+    /// Total amount to be charged over all the installments, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 60000)]
+    pub total_amount: MinorUnit,
+
+    /// Currency of the installments
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The number of installments the total amount is split into. Any remainder of the split is
+    /// charged with the first installment
+    #[schema(minimum = 2, maximum = 60, example = 4)]
+    pub installment_count: u16,
+
+    /// The number of days between two consecutive installments
+    #[schema(minimum = 1, example = 30)]
+    pub interval_days: u16,
+
+    /// Time at which the first installment is charged. Defaults to the time at which the plan is created
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub first_charge_at: Option<PrimitiveDateTime>,
+
+    /// The number of times a failed installment is retried before it is considered missed
+    #[schema(default = 3, maximum = 10, example = 3)]
+    pub max_retries_per_installment: Option<u8>,
+
+    /// The number of hours to wait before retrying a failed installment
+    #[schema(default = 24, minimum = 1, example = 24)]
+    pub retry_interval_hours: Option<u16>,
+
+    /// The number of installments that can be missed before the plan is defaulted. The remaining
+    /// installments of a defaulted plan are not charged
+    #[schema(default = 0, example = 1)]
+    pub max_missed_installments: Option<u16>,
+
+    /// The identifier for the business profile the installments are charged under. Defaults to
+    /// the default business profile of the merchant
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// A description of the plan, which is included in the description of every installment payment
+    #[schema(max_length = 255, example = "Laptop purchase")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// A single installment of an installment plan
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InstallmentResponse {
+    /// The position of the installment in the plan, starting from 1
+    #[schema(example = 1)]
+    pub installment_number: i32,
+
+    /// Amount of the installment, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 15000)]
+    pub amount: MinorUnit,
+
+    /// Time at which the installment is due to be charged
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_at: PrimitiveDateTime,
+
+    /// The status of the installment
+    #[schema(value_type = InstallmentStatus, example = "charged")]
+    pub status: api_enums::InstallmentStatus,
+
+    /// The number of payments attempted for the installment
+    #[schema(example = 1)]
+    pub attempt_count: i32,
+
+    /// The identifier for the payment created by the latest attempt to charge the installment
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InstallmentPlanResponse {
+    /// The identifier for the installment plan
+    #[schema(example = "inst_QIZcUeHfMOHqh5ClSBQR")]
+    pub plan_id: String,
+
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the business profile the installments are charged under
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier for the customer the mandate belongs to
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the mandate the installments are charged against
+    #[schema(example = "man_QIZcUeHfMOHqh5ClSBQR")]
+    pub mandate_id: String,
+
+    /// The status of the installment plan
+    #[schema(value_type = InstallmentPlanStatus, example = "active")]
+    pub status: api_enums::InstallmentPlanStatus,
+
+    /// Currency of the installments
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Total amount charged over all the installments, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 60000)]
+    pub total_amount: MinorUnit,
+
+    /// The number of days between two consecutive installments
+    #[schema(example = 30)]
+    pub interval_days: i32,
+
+    /// The installments of the plan, in the order in which they are charged
+    pub installments: Vec<InstallmentResponse>,
+
+    /// The number of times a failed installment is retried before it is considered missed
+    #[schema(example = 3)]
+    pub max_retries_per_installment: i32,
+
+    /// The number of hours to wait before retrying a failed installment
+    #[schema(example = 24)]
+    pub retry_interval_hours: i32,
+
+    /// The number of installments that can be missed before the plan is defaulted
+    #[schema(example = 1)]
+    pub max_missed_installments: i32,
+
+    /// The number of installments missed so far
+    #[schema(example = 0)]
+    pub missed_count: i32,
+
+    /// Time at which the plan is charged next, either for the next installment or to retry a failed installment
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_charge_at: Option<PrimitiveDateTime>,
+
+    /// A description of the plan
+    #[schema(example = "Laptop purchase")]
+    pub description: Option<String>,
+
+    /// Metadata provided when the plan was created
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the plan was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentPlanListConstraints {
+    /// Only list the plans of this customer
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InstallmentPlanListResponse {
+    /// The number of installment plans included in the list
+    pub count: usize,
+    /// The list of installment plans
+    pub data: Vec<InstallmentPlanResponse>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct InstallmentPlanId {
+    pub plan_id: String,
+}
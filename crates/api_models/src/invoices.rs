@@ -0,0 +1,83 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request for generating an invoice for a payment
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceCreateRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+
+    /// Line items to be listed on the invoice. If not provided, the order details of the payment are used
+    pub line_items: Option<Vec<InvoiceLineItem>>,
+
+    /// The format in which the invoice is generated. Defaults to the format configured in the business profile
+    #[schema(value_type = Option<InvoiceFormat>, example = "pdf")]
+    pub format: Option<api_enums::InvoiceFormat>,
+
+    /// Whether the invoice should be emailed to the customer once generated
+    #[schema(default = false, example = true)]
+    pub send_email: Option<bool>,
+
+    /// Email address the invoice is sent to. Defaults to the email of the customer associated with the payment
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub recipient_email: Option<pii::Email>,
+}
+
+/// A single line item listed on an invoice
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceLineItem {
+    /// Description of the line item
+    #[schema(max_length = 255, example = "Red T-Shirt")]
+    pub description: String,
+
+    /// Quantity of the line item
+    #[schema(example = 1)]
+    pub quantity: u16,
+
+    /// Amount per unit of the line item, in the lowest denomination of the payment currency
+    #[schema(value_type = i64, example = 1000)]
+    pub unit_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoiceResponse {
+    /// The unique number identifying the invoice
+    #[schema(example = "inv_QIZcUeHfMOHqh5ClSBQR")]
+    pub invoice_number: String,
+
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+
+    /// ID of the file the invoice is stored under. The invoice can be downloaded from the files API
+    pub file_id: String,
+
+    /// A short-lived URL from which the invoice can be downloaded
+    #[schema(example = "https://sandbox.hyperswitch.io/files/download/fdt_1ySw3Gz0fs9ZbXo5gTjP")]
+    pub download_url: Option<String>,
+
+    /// The format in which the invoice was generated
+    #[schema(value_type = InvoiceFormat, example = "pdf")]
+    pub format: api_enums::InvoiceFormat,
+
+    /// Total amount of the invoice, in the lowest denomination of the payment currency
+    #[schema(value_type = i64, example = 6540)]
+    pub total_amount: MinorUnit,
+
+    /// Currency of the invoice
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Whether the invoice was emailed to the customer
+    pub email_sent: bool,
+
+    /// Time at which the invoice was generated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
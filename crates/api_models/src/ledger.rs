@@ -0,0 +1,160 @@
+use common_utils::{id_type, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The balance of an account of the ledger of a business profile in a currency
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerAccountBalance {
+    /// The ledger account
+    #[schema(value_type = LedgerAccount, example = "connector_balance")]
+    pub account: api_enums::LedgerAccount,
+
+    /// The three letter ISO currency code of the balance
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The balance of the account on its normal side, in the lowest denomination of the currency. Debits increase the balance of the `connector_balance`, `refunds`, `payouts`, `fees` and `chargebacks` accounts, and credits increase the balance of the `payments` account
+    #[schema(value_type = i64, example = 6540)]
+    pub balance: MinorUnit,
+}
+
+/// The balances of the accounts of the ledger of a business profile
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerBalancesResponse {
+    /// The identifier for the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The balances of the accounts, for each currency in which the profile has balance movements
+    pub balances: Vec<LedgerAccountBalance>,
+
+    /// Time at which the balances were computed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub as_of: PrimitiveDateTime,
+}
+
+/// Query parameters for retrieving the statement of an account of the ledger of a business profile
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerStatementRequest {
+    /// The ledger account of the statement
+    #[schema(value_type = LedgerAccount, example = "connector_balance")]
+    pub account: api_enums::LedgerAccount,
+
+    /// The three letter ISO currency code of the statement
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The start of the period of the statement, inclusive. Defaults to the first entry of the account
+    #[schema(example = "2024-12-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+
+    /// The end of the period of the statement, exclusive. Defaults to the current time
+    #[schema(example = "2025-01-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+
+    /// The maximum number of entries to return
+    #[schema(default = 100, maximum = 1000)]
+    pub limit: Option<u32>,
+
+    /// The number of entries to skip
+    pub offset: Option<u32>,
+}
+
+/// A debit or credit posted to an account of the ledger of a business profile
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerEntryResponse {
+    /// The identifier for the ledger entry
+    #[schema(example = "ledger_entry_QIZcUeHfMOHqh5ClSBQR")]
+    pub entry_id: String,
+
+    /// The identifier for the ledger transaction of the entry, shared by the entries whose debits and credits balance
+    #[schema(example = "ledger_txn_Fq3gQfIyUjDrKk0OPRZH")]
+    pub transaction_id: String,
+
+    /// The ledger account the entry is posted to
+    #[schema(value_type = LedgerAccount, example = "connector_balance")]
+    pub account: api_enums::LedgerAccount,
+
+    /// The side of the account the entry is posted to
+    #[schema(value_type = LedgerEntryDirection, example = "debit")]
+    pub direction: api_enums::LedgerEntryDirection,
+
+    /// The amount of the entry, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The three letter ISO currency code of the entry
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The type of the operation whose balance movement is recorded by the entry
+    #[schema(value_type = LedgerSourceType, example = "payment")]
+    pub source_type: api_enums::LedgerSourceType,
+
+    /// The identifier of the payment, refund, payout or dispute whose balance movement is recorded by the entry
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub source_id: String,
+
+    /// Time at which the entry was recorded
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The statement of an account of the ledger of a business profile in a currency
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerStatementResponse {
+    /// The identifier for the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The ledger account of the statement
+    #[schema(value_type = LedgerAccount, example = "connector_balance")]
+    pub account: api_enums::LedgerAccount,
+
+    /// The three letter ISO currency code of the statement
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The start of the period of the statement
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+
+    /// The end of the period of the statement
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+
+    /// The balance of the account at the start of the period
+    #[schema(value_type = i64, example = 0)]
+    pub opening_balance: MinorUnit,
+
+    /// The balance of the account at the end of the period
+    #[schema(value_type = i64, example = 6540)]
+    pub closing_balance: MinorUnit,
+
+    /// The entries posted to the account in the period, in the order in which they were recorded
+    pub entries: Vec<LedgerEntryResponse>,
+}
+
+/// The override of the balance rules of a business profile for a payout
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PayoutBalanceOverrideResponse {
+    /// The identifier for the payout
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_id: String,
+
+    /// The identifier for the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus, example = "requires_fulfillment")]
+    pub status: api_enums::PayoutStatus,
+
+    /// Whether the payout can be fulfilled regardless of the balance rules of the profile, even if the connector balance of the ledger of the profile does not cover the payout
+    pub balance_rules_overridden: bool,
+}
@@ -1,16 +1,22 @@
 pub mod admin;
+pub mod admin_api_keys;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
+pub mod archival;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod config_reconciliation;
 pub mod connector_enums;
 pub mod connector_onboarding;
 pub mod consts;
 pub mod currency;
 pub mod customers;
+pub mod data_retention;
 pub mod disputes;
+pub mod email_templates;
+pub mod embed_tokens;
 pub mod enums;
 pub mod ephemeral_key;
 #[cfg(feature = "errors")]
@@ -19,10 +25,16 @@ pub mod events;
 pub mod files;
 pub mod gsm;
 pub mod health_check;
+pub mod installment_plans;
+pub mod invoices;
+pub mod ledger;
+pub mod link_delivery;
 pub mod locker_migration;
 pub mod mandates;
 pub mod organization;
+pub mod payload_logs;
 pub mod payment_methods;
+pub mod payment_templates;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
@@ -32,7 +44,10 @@ pub mod poll;
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod status_history;
 pub mod surcharge_decision_configs;
+pub mod test_clocks;
+pub mod usage;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
@@ -0,0 +1,79 @@
+use common_utils::id_type;
+use masking::Secret;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request for delivering a link to the customer over a messaging channel
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LinkDeliveryRequest {
+    /// The channel over which the link is delivered
+    #[schema(value_type = MessagingChannel, example = "sms")]
+    pub channel: api_enums::MessagingChannel,
+
+    /// The phone number the link is delivered to. If not provided, the phone number of the customer is used
+    #[schema(value_type = Option<String>, max_length = 15, example = "9123456789")]
+    pub phone: Option<Secret<String>>,
+
+    /// The country code for the phone number the link is delivered to
+    #[schema(max_length = 5, example = "+1")]
+    pub phone_country_code: Option<String>,
+}
+
+/// Query parameters for listing the deliveries of a link
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LinkDeliveryListConstraints {
+    /// The identifier of the payment link or payout link
+    #[schema(example = "plink_QIZcUeHfMOHqh5ClSBQR")]
+    pub link_id: String,
+}
+
+/// The delivery of a link to the customer over a messaging channel
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LinkDeliveryResponse {
+    /// The identifier for the link delivery
+    #[schema(example = "link_delivery_QIZcUeHfMOHqh5ClSBQR")]
+    pub delivery_id: String,
+
+    /// The identifier for the business profile the link belongs to
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The identifier of the payment link or payout link
+    #[schema(example = "plink_QIZcUeHfMOHqh5ClSBQR")]
+    pub link_id: String,
+
+    /// The type of link delivered
+    #[schema(value_type = DeliveredLinkType, example = "payment_link")]
+    pub link_type: api_enums::DeliveredLinkType,
+
+    /// The channel over which the link is delivered
+    #[schema(value_type = MessagingChannel, example = "sms")]
+    pub channel: api_enums::MessagingChannel,
+
+    /// The masked phone number the link is delivered to
+    #[schema(example = "+1******6789")]
+    pub recipient: String,
+
+    /// The delivery status of the link
+    #[schema(value_type = LinkDeliveryStatus, example = "delivered")]
+    pub status: api_enums::LinkDeliveryStatus,
+
+    /// Error code returned by the messaging provider, if the delivery failed
+    pub error_code: Option<String>,
+
+    /// Error message, if the delivery failed
+    pub error_message: Option<String>,
+
+    /// Time at which the delivery was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// Time at which the delivery was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -6,3 +6,39 @@ pub struct MigrateCardResponse {
     pub customers_moved: usize,
     pub cards_moved: usize,
 }
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct LockerMigrationRequest {
+    /// The number of customers whose cards are migrated in a single batch
+    pub batch_size: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LockerMigrationStatus {
+    /// The migration has been scheduled, and no batch has been migrated yet
+    Scheduled,
+    /// Some of the batches have been migrated, and the remaining batches are scheduled
+    InProgress,
+    /// The cards of all the customers have been migrated
+    Completed,
+    /// The migration was stopped before the cards of all the customers were migrated
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LockerMigrationResponse {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub status: LockerMigrationStatus,
+    /// The number of customers whose cards have been processed
+    pub customers_processed: u32,
+    /// The number of cards copied to the target locker
+    pub cards_moved: usize,
+    /// The number of cards which could not be copied to the target locker
+    pub cards_failed: usize,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
@@ -47,6 +47,11 @@ pub struct OrganizationUpdateRequest {
     /// Metadata is useful for storing additional, unstructured information on an object.
     #[schema(value_type = Option<Object>)]
     pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Whether the merchant accounts of the organization can share customers and saved payment
+    /// methods with each other
+    #[schema(example = false)]
+    pub is_token_sharing_enabled: Option<bool>,
 }
 #[cfg(feature = "v1")]
 #[derive(Debug, serde::Serialize, Clone, ToSchema)]
@@ -65,6 +70,10 @@ pub struct OrganizationResponse {
     /// Metadata is useful for storing additional, unstructured information on an object.
     #[schema(value_type = Option<Object>)]
     pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Whether the merchant accounts of the organization can share customers and saved payment
+    /// methods with each other
+    pub is_token_sharing_enabled: bool,
     pub modified_at: time::PrimitiveDateTime,
     pub created_at: time::PrimitiveDateTime,
 }
@@ -86,6 +95,10 @@ pub struct OrganizationResponse {
     /// Metadata is useful for storing additional, unstructured information on an object.
     #[schema(value_type = Option<Object>)]
     pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Whether the merchant accounts of the organization can share customers and saved payment
+    /// methods with each other
+    pub is_token_sharing_enabled: bool,
     pub modified_at: time::PrimitiveDateTime,
     pub created_at: time::PrimitiveDateTime,
 }
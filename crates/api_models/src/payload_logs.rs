@@ -0,0 +1,41 @@
+use common_utils::id_type;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PayloadLogRetrieveRequest {
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayloadLogListConstraints {
+    /// The number of the most recent payload logs to return, defaults to 20 and limited to 100
+    pub limit: Option<u16>,
+}
+
+/// The redacted payloads of a request made to the admin or payment APIs of a merchant
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PayloadLogResponse {
+    /// The identifier of the request, returned in the `x-request-id` header of the response
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub flow: String,
+    pub http_method: String,
+    pub url_path: String,
+    pub status_code: i64,
+    /// The request body, with its sensitive fields masked
+    pub request: serde_json::Value,
+    /// The response body, with its sensitive fields masked
+    pub response: Option<serde_json::Value>,
+    /// The error returned for the request, if it failed
+    pub error: Option<serde_json::Value>,
+    pub latency_in_millis: u128,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PayloadLogListResponse {
+    pub count: usize,
+    /// The payload logs, most recent first
+    pub data: Vec<PayloadLogResponse>,
+}
@@ -713,7 +713,7 @@ pub enum PaymentMethodResponseData {
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema, Clone)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -1951,6 +1951,11 @@ pub struct PaymentMethodCollectLinkRequest {
     /// List of payment methods shown on collect UI
     #[schema(value_type = Option<Vec<EnabledPaymentMethod>>, example = r#"[{"payment_method": "bank_transfer", "payment_method_types": ["ach", "bacs"]}]"#)]
     pub enabled_payment_methods: Option<Vec<link_utils::EnabledPaymentMethod>>,
+
+    /// The business profile whose webhook configuration is used for notifying the merchant once
+    /// the details are submitted. Defaults to the merchant's default profile.
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: Option<id_type::ProfileId>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1997,6 +2002,74 @@ pub struct PaymentMethodCollectLinkRenderRequest {
     pub pm_collect_link_id: String,
 }
 
+/// Request for submitting the payment method details collected through a payment method collect link
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodCollectLinkSubmitRequest {
+    #[serde(skip)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    #[serde(skip)]
+    pub pm_collect_link_id: String,
+
+    /// Client secret of the payment method collect link
+    #[schema(value_type = String)]
+    pub client_secret: masking::Secret<String>,
+
+    /// The type of payment method being submitted
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// This is a sub-category of payment method.
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// Card details, required if the payment method is card
+    pub card: Option<CardDetail>,
+
+    /// Bank details, required if the payment method is bank transfer
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Bank>)]
+    pub bank_transfer: Option<payouts::Bank>,
+
+    /// Wallet details, required if the payment method is wallet
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Wallet>)]
+    pub wallet: Option<payouts::Wallet>,
+
+    /// The billing details of the payment method
+    #[schema(value_type = Option<Address>)]
+    pub billing: Option<payments::Address>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodCollectLinkSubmitResponse {
+    /// The unique identifier for the collect link.
+    #[schema(value_type = String, example = "pm_collect_link_2bdacf398vwzq5n422S1")]
+    pub pm_collect_link_id: String,
+
+    /// The unique identifier of the customer.
+    #[schema(value_type = String, example = "cus_92dnwed8s32bV9D8Snbiasd8v")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The unique identifier of the payment method created from the submitted details
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+
+    /// Status of the collect link
+    #[schema(value_type = PaymentMethodCollectStatus, example = "submitted")]
+    pub status: link_utils::PaymentMethodCollectStatus,
+
+    /// Redirect to this URL post completion
+    #[schema(value_type = Option<String>, example = "https://sandbox.hyperswitch.io/payment_method/collect/pm_collect_link_2bdacf398vwzq5n422S1/status")]
+    pub return_url: Option<String>,
+}
+
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PaymentMethodCollectLinkDetails {
     pub publishable_key: masking::Secret<String>,
@@ -2437,3 +2510,200 @@ impl From<(PaymentMethodRecord, id_type::MerchantId)> for customers::CustomerReq
 //         }
 //     }
 // }
+
+/// The request body for vaulting a card without creating a payment.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VaultTokenizeRequest {
+    /// The unique identifier of the customer the card belongs to.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The details of the card to be vaulted.
+    #[schema(example = json!({
+    "card_number": "4111111145551142",
+    "card_exp_month": "10",
+    "card_exp_year": "25",
+    "card_holder_name": "John Doe"}))]
+    pub card: CardDetail,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = json!({ "city": "NY", "unit": "245" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The response body for vaulting a card.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct VaultTokenizeResponse {
+    /// The multi-use token referencing the vaulted card. The token can be used as the
+    /// `payment_method_id` in the `recurring_details` of a payment to pay with the card.
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub token: String,
+
+    /// The unique identifier of the customer the card belongs to.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The non-sensitive details of the vaulted card.
+    pub card: Option<CardDetailFromLocker>,
+
+    /// The time at which the card was vaulted.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created: Option<time::PrimitiveDateTime>,
+}
+
+/// The request body for retrieving the card referenced by a token.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VaultDetokenizeRequest {
+    /// The token referencing the vaulted card.
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub token: String,
+}
+
+/// The response body containing the card referenced by a token.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct VaultDetokenizeResponse {
+    /// The token referencing the vaulted card.
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub token: String,
+
+    /// The unique identifier of the customer the card belongs to.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The details of the vaulted card.
+    pub card: VaultCardDetails,
+}
+
+/// The details of a card retrieved from the vault.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct VaultCardDetails {
+    /// Card Number
+    #[schema(value_type = String, example = "4111111145551142")]
+    pub card_number: CardNumber,
+
+    /// Card Expiry Month
+    #[schema(value_type = String, example = "10")]
+    pub card_exp_month: masking::Secret<String>,
+
+    /// Card Expiry Year
+    #[schema(value_type = String, example = "25")]
+    pub card_exp_year: masking::Secret<String>,
+
+    /// Card Holder Name
+    #[schema(value_type = Option<String>, example = "John Doe")]
+    pub card_holder_name: Option<masking::Secret<String>>,
+
+    /// Card Holder's Nick Name
+    #[schema(value_type = Option<String>, example = "John Doe")]
+    pub nick_name: Option<masking::Secret<String>>,
+
+    /// Card network of the card
+    #[schema(example = "Visa")]
+    pub card_network: Option<String>,
+}
+
+/// The request body for sharing a payment method with another merchant account of the organization.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SharePaymentMethodRequest {
+    /// The identifier of the merchant account to share the payment method with. The merchant
+    /// account must belong to the same organization.
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub target_merchant_id: id_type::MerchantId,
+}
+
+/// A payment method shared with another merchant account of the organization.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SharedPaymentMethodResponse {
+    /// The unique identifier of the share.
+    #[schema(example = "spm_iouuy468iyuowqs")]
+    pub share_id: String,
+
+    /// The identifier of the organization the merchant accounts belong to.
+    #[schema(value_type = String, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: id_type::OrganizationId,
+
+    /// The identifier of the merchant account which shared the payment method.
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub source_merchant_id: id_type::MerchantId,
+
+    /// The identifier of the shared payment method.
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub source_payment_method_id: String,
+
+    /// The identifier of the merchant account the payment method was shared with.
+    #[schema(value_type = String, example = "merchant_1668273826")]
+    pub target_merchant_id: id_type::MerchantId,
+
+    /// The identifier of the payment method in the merchant account it was shared with.
+    #[schema(example = "pm_jsd8hd72jd8hd9s")]
+    pub target_payment_method_id: String,
+
+    /// The identifier of the customer the payment method belongs to in the merchant account which
+    /// shared the payment method.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier of the customer the payment method was saved against in the merchant
+    /// account it was shared with.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_a8sdj2ks7dhs82hdk2jsd8sk2j")]
+    pub target_customer_id: id_type::CustomerId,
+
+    /// The time at which the payment method was shared.
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// The consent of a customer to store a payment method for future usage, recorded when the
+/// payment method was saved.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodConsentEventResponse {
+    /// The identifier of the payment method the consent was given for.
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+
+    /// The identifier of the customer who gave the consent.
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The identifier of the payment during which the payment method was saved.
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+
+    /// The future usage the customer consented to.
+    #[schema(value_type = FutureUsage, example = "off_session")]
+    pub setup_future_usage: api_enums::FutureUsage,
+
+    /// The version of the consent text which was presented to the customer.
+    #[schema(example = "v1.2")]
+    pub consent_text_version: Option<String>,
+
+    /// The IP address of the customer at the time of the consent.
+    #[schema(value_type = Option<String>, example = "123.32.25.123")]
+    pub ip_address: Option<masking::Secret<String, pii::IpAddress>>,
+
+    /// The user agent of the browser of the customer at the time of the consent.
+    pub user_agent: Option<String>,
+
+    /// The time at which the customer gave the consent.
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub accepted_at: time::PrimitiveDateTime,
+
+    /// The time at which the consent was recorded.
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
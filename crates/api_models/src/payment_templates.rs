@@ -0,0 +1,134 @@
+use common_utils::id_type;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request for creating a payment template for a business profile
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentTemplateCreateRequest {
+    /// Name of the template, unique within the business profile
+    #[schema(max_length = 64, example = "subscription_renewal")]
+    pub name: String,
+
+    /// The three letter ISO currency code of the payments created with the template
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The capture method of the payments created with the template
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<api_enums::CaptureMethod>,
+
+    /// Metadata of the payments created with the template. The keys of the metadata passed in the payment request take precedence over the keys of the template
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+
+    /// The statement descriptor name of the payments created with the template
+    #[schema(max_length = 255, example = "Hyperswitch Router")]
+    pub statement_descriptor_name: Option<String>,
+
+    /// The statement descriptor suffix of the payments created with the template
+    #[schema(max_length = 255, example = "Subscription renewal")]
+    pub statement_descriptor_suffix: Option<String>,
+
+    /// The payment method types allowed for the payments created with the template
+    #[schema(value_type = Option<Vec<PaymentMethodType>>)]
+    pub allowed_payment_method_types: Option<Vec<api_enums::PaymentMethodType>>,
+
+    /// Whether payments can be created with the template
+    #[schema(default = true, example = true)]
+    pub is_active: Option<bool>,
+}
+
+/// Request for updating a payment template of a business profile
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentTemplateUpdateRequest {
+    /// Name of the template, unique within the business profile
+    #[schema(max_length = 64, example = "subscription_renewal")]
+    pub name: Option<String>,
+
+    /// The three letter ISO currency code of the payments created with the template
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The capture method of the payments created with the template
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<api_enums::CaptureMethod>,
+
+    /// Metadata of the payments created with the template
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+
+    /// The statement descriptor name of the payments created with the template
+    #[schema(max_length = 255, example = "Hyperswitch Router")]
+    pub statement_descriptor_name: Option<String>,
+
+    /// The statement descriptor suffix of the payments created with the template
+    #[schema(max_length = 255, example = "Subscription renewal")]
+    pub statement_descriptor_suffix: Option<String>,
+
+    /// The payment method types allowed for the payments created with the template
+    #[schema(value_type = Option<Vec<PaymentMethodType>>)]
+    pub allowed_payment_method_types: Option<Vec<api_enums::PaymentMethodType>>,
+
+    /// Whether payments can be created with the template
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentTemplateResponse {
+    /// The identifier for the payment template, passed as `template_id` when creating a payment
+    #[schema(example = "payment_template_QIZcUeHfMOHqh5ClSBQR")]
+    pub template_id: String,
+
+    /// The identifier for the business profile the template belongs to
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// Name of the template
+    #[schema(example = "subscription_renewal")]
+    pub name: String,
+
+    /// The three letter ISO currency code of the payments created with the template
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The capture method of the payments created with the template
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<api_enums::CaptureMethod>,
+
+    /// Metadata of the payments created with the template
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+
+    /// The statement descriptor name of the payments created with the template
+    pub statement_descriptor_name: Option<String>,
+
+    /// The statement descriptor suffix of the payments created with the template
+    pub statement_descriptor_suffix: Option<String>,
+
+    /// The payment method types allowed for the payments created with the template
+    #[schema(value_type = Option<Vec<PaymentMethodType>>)]
+    pub allowed_payment_method_types: Option<Vec<api_enums::PaymentMethodType>>,
+
+    /// Whether payments can be created with the template
+    pub is_active: bool,
+
+    /// Time at which the template was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// Time at which the template was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentTemplateDeleteResponse {
+    /// The identifier for the payment template
+    pub template_id: String,
+
+    /// Whether the template was deleted
+    pub deleted: bool,
+}
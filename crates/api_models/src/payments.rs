@@ -32,7 +32,7 @@ use crate::{
     disputes, enums as api_enums,
     ephemeral_key::EphemeralKeyCreateResponse,
     mandates::RecurringDetails,
-    refunds,
+    refunds, status_history,
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -590,6 +590,11 @@ pub struct PaymentsRequest {
     #[mandatory_in(PaymentsCreateRequest = Currency)]
     pub currency: Option<api_enums::Currency>,
 
+    /// The three letter ISO currency code in uppercase, in which the amount is presented to the customer, if it is different from the currency of the payment in which it is settled to the merchant. The amount in the presentment currency is converted at the current exchange rates, unless the connector converts it by dynamic currency conversion
+    #[schema(example = "EUR", value_type = Option<Currency>)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub presentment_currency: Option<api_enums::Currency>,
+
     /// The Amount to be captured / debited from the users payment method. It shall be in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc., If not provided, the default amount_to_capture will be the payment amount. Also, it must be less than or equal to the original payment account.
     #[schema(value_type = Option<i64>, example = 6540)]
     pub amount_to_capture: Option<MinorUnit>,
@@ -635,17 +640,23 @@ pub struct PaymentsRequest {
     /// The billing details of the payment. This address will be used for invoicing.
     pub billing: Option<Address>,
 
-    /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
-    /// Providing this field will automatically set `capture` to true
+    /// A timestamp (ISO 8601 code) at which the payment is automatically captured. Only applicable
+    /// to payments with `capture_method = manual`, which are captured at this time unless they have
+    /// been captured or voided before. Timestamps in the past are ignored
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
-    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest, PaymentsConfirmRequest)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
     pub capture_on: Option<PrimitiveDateTime>,
 
     /// Whether to confirm the payment (if applicable). It can be used to completely process a payment by attaching a payment method, setting `confirm=true` and `capture_method = automatic` in the *Payments/Create API* request itself.
     #[schema(default = false, example = true)]
     pub confirm: Option<bool>,
 
+    /// Whether to process the confirm asynchronously. When set to true, a `202 Accepted` response is returned once the request has been validated, and the payment is processed with the connector in the background. The outcome is reported via webhooks and can be retrieved using the *Payments/Retrieve API*. Only applicable when the payment is confirmed using the API key.
+    #[schema(default = false, example = true)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub async_confirm: Option<bool>,
+
     /// Passing this object creates a new customer or attaches an existing customer to the payment
     pub customer: Option<CustomerDetails>,
 
@@ -821,6 +832,20 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<id_type::ProfileId>,
 
+    /// Whether to select the business profile of the payment from the billing country and currency of the payment, using the profile selection rules configured for the merchant. Only applicable when the profile_id is not passed
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(default = false, example = true)]
+    pub auto_select_profile: Option<bool>,
+
+    /// The identifier of a payment template of the business profile. The currency, capture method, metadata, statement descriptors and allowed payment method types which are not passed in the request are taken from the template
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(example = "payment_template_QIZcUeHfMOHqh5ClSBQR")]
+    pub template_id: Option<String>,
+
+    /// The choice of the customer on the dynamic currency conversion offered for the payment. The payment is processed in the presentment currency of the offer if the customer accepted it, and the connector of the payment supports dynamic currency conversion
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub dcc_choice: Option<DccChoice>,
+
     #[remove_in(PaymentsConfirmRequest)]
     #[schema(value_type = Option<RequestSurchargeDetails>)]
     pub surcharge_details: Option<RequestSurchargeDetails>,
@@ -1204,6 +1229,78 @@ pub struct PaymentAttemptResponse {
     pub client_source: Option<String>,
     /// Value passed in X-CLIENT-VERSION header during payments confirm request by the client
     pub client_version: Option<String>,
+    /// The result of the address verification performed by the issuer, normalized across connectors
+    #[schema(value_type = Option<AvsResult>, example = "full_match")]
+    pub avs_result: Option<enums::AvsResult>,
+    /// The result of the card verification code check performed by the issuer, normalized across connectors
+    #[schema(value_type = Option<CvvResult>, example = "match")]
+    pub cvv_result: Option<enums::CvvResult>,
+    /// The response codes of the connector as received, before they are unified. This is only
+    /// populated for merchants which have opted in to exposing raw connector response codes
+    pub connector_response_codes: Option<ConnectorResponseCodes>,
+    /// The currency in which the amount is presented to the customer
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub presentment_currency: Option<enums::Currency>,
+    /// The amount in the presentment currency
+    #[schema(value_type = Option<i64>, example = 6010)]
+    pub presentment_amount: Option<MinorUnit>,
+    /// The exchange rate from the currency of the payment to the presentment currency
+    #[schema(example = "0.918842")]
+    pub exchange_rate: Option<String>,
+    /// The dynamic currency conversion offered to the customer for the attempt, and the choice of the customer
+    pub dcc_details: Option<DccDetails>,
+}
+
+/// The dynamic currency conversion offered to the customer for a payment attempt
+#[derive(Debug, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct DccDetails {
+    /// The identifier of the offer
+    #[schema(example = "dcc_offer_9Qg1Es0XhLrRPhUoJ0Eu")]
+    pub offer_id: String,
+    /// The currency in which the customer was offered to pay
+    #[schema(value_type = Currency, example = "EUR")]
+    pub presentment_currency: enums::Currency,
+    /// The amount offered in the presentment currency
+    #[schema(value_type = i64, example = 6186)]
+    pub presentment_amount: MinorUnit,
+    /// The exchange rate from the currency of the payment to the presentment currency at the forex rates, before the markup
+    #[schema(example = "0.918842")]
+    pub base_exchange_rate: String,
+    /// The markup applied over the forex rate, in basis points
+    #[schema(example = 300)]
+    pub markup_in_basis_points: u16,
+    /// The exchange rate offered to the customer, which includes the markup
+    #[schema(example = "0.946407")]
+    pub exchange_rate: String,
+    /// Whether the customer accepted to pay in the presentment currency
+    pub accepted: bool,
+    /// Whether the offer was applied at authorization, which is only the case if it was accepted and the connector of the attempt supports dynamic currency conversion
+    pub applied: bool,
+    /// The time at which the offer was made
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub offered_at: PrimitiveDateTime,
+    /// The time at which the customer accepted or declined the offer
+    #[schema(example = "2022-09-10T10:12:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub decided_at: PrimitiveDateTime,
+}
+
+/// Raw response codes returned by the connector for a payment attempt
+#[derive(Debug, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ConnectorResponseCodes {
+    /// The decline code returned by the connector, if the attempt was declined
+    #[schema(example = "do_not_honor")]
+    pub decline_code: Option<String>,
+    /// The result of the address verification performed by the issuer
+    #[schema(example = "Y")]
+    pub avs_result: Option<String>,
+    /// The result of the card verification code check performed by the issuer
+    #[schema(example = "M")]
+    pub cvv_result: Option<String>,
+    /// The response code returned by the acquirer
+    #[schema(example = "00")]
+    pub acquirer_response_code: Option<String>,
 }
 
 #[derive(
@@ -1471,6 +1568,13 @@ pub struct CustomerAcceptance {
     pub accepted_at: Option<PrimitiveDateTime>,
     /// Information required for online mandate generation
     pub online: Option<OnlineMandate>,
+    /// The version of the consent text which was presented to the customer
+    #[schema(max_length = 64, example = "v1.2")]
+    pub consent_text_version: Option<String>,
+    /// The identifier of the mandate document signed by the customer, uploaded through the files
+    /// API with the `mandate_evidence` purpose. Applicable only to bank debit mandates
+    #[schema(max_length = 64, example = "file_Xxy3sHBFqCkHYDmAqfdA")]
+    pub signed_mandate_file_id: Option<String>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, Clone, ToSchema)]
@@ -2281,6 +2385,7 @@ impl GetPaymentMethodType for BankTransferData {
             Self::Pix { .. } => api_enums::PaymentMethodType::Pix,
             Self::Pse {} => api_enums::PaymentMethodType::Pse,
             Self::LocalBankTransfer { .. } => api_enums::PaymentMethodType::LocalBankTransfer,
+            Self::VirtualAccountBankTransfer {} => api_enums::PaymentMethodType::VirtualAccount,
         }
     }
 }
@@ -2930,6 +3035,9 @@ pub enum BankTransferData {
     LocalBankTransfer {
         bank_code: Option<String>,
     },
+    /// A virtual account or a payment reference is generated by the connector for the payment, to
+    /// which the customer transfers the amount from their bank
+    VirtualAccountBankTransfer {},
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -2997,7 +3105,10 @@ impl GetAddressFromPaymentMethodData for BankTransferData {
                     email: details.email.clone(),
                 })
             }
-            Self::LocalBankTransfer { .. } | Self::Pix { .. } | Self::Pse {} => None,
+            Self::LocalBankTransfer { .. }
+            | Self::Pix { .. }
+            | Self::Pse {}
+            | Self::VirtualAccountBankTransfer {} => None,
         }
     }
 }
@@ -3917,6 +4028,101 @@ pub struct PaymentsCaptureRequest {
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+/// A shipment of the order of an authorized payment, notified by the order management system of
+/// the merchant to capture the payment
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsShipmentRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier of the shipment in the order management system. A shipment is captured only once, repeated notifications of the same shipment are rejected
+    #[schema(max_length = 64, example = "shipment_1234")]
+    pub shipment_id: String,
+    /// The line items of the order details shipped in the shipment. The whole order is considered shipped if this is not passed
+    pub line_items: Option<Vec<ShipmentLineItem>>,
+    /// The carrier of the shipment
+    #[schema(example = "dhl")]
+    pub carrier: Option<String>,
+    /// The tracking number of the shipment with the carrier
+    pub tracking_number: Option<String>,
+}
+
+/// Request for a dynamic currency conversion offer, for the customer to pay in the currency of
+/// their card instead of the currency of the payment
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsDccOfferRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The client secret of the payment
+    #[schema(example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: String,
+    /// The currency in which the customer is offered to pay, usually the currency of the card of the customer
+    #[schema(value_type = Currency, example = "EUR")]
+    pub presentment_currency: api_enums::Currency,
+}
+
+/// A dynamic currency conversion offer, which the customer accepts or declines when the payment is confirmed
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentsDccOfferResponse {
+    /// The identifier of the offer, to be passed in the `dcc_choice` of the confirm request of the payment
+    #[schema(example = "dcc_offer_9Qg1Es0XhLrRPhUoJ0Eu")]
+    pub offer_id: String,
+    /// The unique identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The amount the customer pays in the presentment currency if the offer is accepted
+    #[schema(value_type = i64, example = 6186)]
+    pub presentment_amount: MinorUnit,
+    /// The currency in which the customer is offered to pay
+    #[schema(value_type = Currency, example = "EUR")]
+    pub presentment_currency: api_enums::Currency,
+    /// The exchange rate from the currency of the payment to the presentment currency, which includes the markup
+    #[schema(example = "0.946407")]
+    pub exchange_rate: String,
+    /// The markup applied over the forex rate, in basis points, to be disclosed to the customer
+    #[schema(example = 300)]
+    pub markup_in_basis_points: u16,
+    /// The time until which the offer can be accepted
+    #[schema(example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+/// The choice of the customer on a dynamic currency conversion offer
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DccChoice {
+    /// The identifier of the offer, as returned for the dcc offer request of the payment
+    #[schema(example = "dcc_offer_9Qg1Es0XhLrRPhUoJ0Eu")]
+    pub offer_id: String,
+    /// Whether the customer accepted to pay in the presentment currency of the offer
+    pub accepted: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ShipmentLineItem {
+    /// Name of the product shipped, as passed in the order details of the payment
+    #[schema(max_length = 255, example = "shirt")]
+    pub product_name: String,
+    /// ID of the product shipped, as passed in the order details of the payment. Required to identify the line item if multiple line items have the same product name
+    pub product_id: Option<String>,
+    /// The quantity of the product shipped
+    #[schema(example = 1)]
+    pub quantity: u16,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -4131,6 +4337,8 @@ pub enum BankTransferInstructions {
     BacsBankInstructions(Box<BacsBankTransferInstructions>),
     /// The instructions for Multibanco bank transactions
     Multibanco(Box<MultibancoTransferInstructions>),
+    /// The instructions for transfers to a virtual account generated for the payment
+    VirtualAccount(Box<VirtualAccountTransferInstructions>),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -4185,14 +4393,37 @@ pub struct AchTransfer {
     pub swift_code: Secret<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VirtualAccountTransferInstructions {
+    /// The number of the virtual account to which the customer has to transfer the amount
+    #[schema(value_type = String, example = "1234567890")]
+    pub account_number: Secret<String>,
+    /// The name of the holder of the virtual account
+    #[schema(value_type = Option<String>, example = "Jane Doe")]
+    pub account_holder_name: Option<Secret<String>>,
+    /// The name of the bank of the virtual account
+    pub bank_name: Option<String>,
+    /// The code of the bank of the virtual account, such as the routing number or the sort code
+    #[schema(value_type = Option<String>, example = "110000000")]
+    pub bank_code: Option<Secret<String>>,
+    /// The reference that the customer has to include in the transfer
+    #[schema(value_type = Option<String>, example = "U2PVVSEV4V9Y")]
+    pub reference: Option<Secret<String>>,
+    /// The time until which the virtual account accepts transfers, as a unix timestamp
+    #[schema(example = 1707091200)]
+    pub expires_at: Option<i64>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ReceiverDetails {
     /// The amount received by receiver
-    amount_received: i64,
+    pub amount_received: i64,
     /// The amount charged by ACH
-    amount_charged: Option<i64>,
+    pub amount_charged: Option<i64>,
     /// The amount remaining to be sent via ACH
-    amount_remaining: Option<i64>,
+    pub amount_remaining: Option<i64>,
+    /// The amount received in excess of the amount of the payment, which is not captured
+    pub amount_overpaid: Option<i64>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema, router_derive::PolymorphicSchema)]
@@ -4284,6 +4515,11 @@ pub struct PaymentsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attempts: Option<Vec<PaymentAttemptResponse>>,
 
+    /// The timeline of the status transitions of the payment, present when `expand=status_history` is passed
+    #[schema(value_type = Option<Vec<StatusHistoryResponse>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_history: Option<Vec<status_history::StatusHistoryResponse>>,
+
     /// List of captures done on latest attempt
     #[schema(value_type = Option<Vec<CaptureResponse>>)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4328,6 +4564,11 @@ pub struct PaymentsResponse {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
 
+    /// Indicates that the card could not be saved as the vault was unreachable. The card is held
+    /// temporarily and the `payment_token` is only valid until the order fulfillment time
+    #[schema(example = true)]
+    pub vault_fallback_applied: Option<bool>,
+
     /// The shipping address for the payment
     pub shipping: Option<Address>,
 
@@ -4530,6 +4771,18 @@ pub struct PaymentsResponse {
 
     /// Connector Identifier for the payment method
     pub connector_mandate_id: Option<String>,
+
+    /// The currency in which the amount is presented to the customer, if it is different from the currency of the payment in which it is settled to the merchant
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub presentment_currency: Option<api_enums::Currency>,
+
+    /// The amount in the presentment currency, as converted by the connector or at the exchange rate when the payment was created or authorized
+    #[schema(value_type = Option<i64>, example = 6010)]
+    pub presentment_amount: Option<MinorUnit>,
+
+    /// The exchange rate from the currency of the payment to the presentment currency
+    #[schema(example = "0.918842")]
+    pub exchange_rate: Option<String>,
 }
 
 // Serialize is implemented because, this will be serialized in the api events.
@@ -5290,6 +5543,19 @@ pub struct PaymentsRetrieveRequest {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// The list of related objects to be expanded in the response
+    #[schema(value_type = Option<Vec<ExpandableObject>>, example = json!(["attempts", "status_history"]))]
+    pub expand: Option<Vec<api_enums::ExpandableObject>>,
+}
+
+#[cfg(feature = "v1")]
+impl PaymentsRetrieveRequest {
+    /// Checks if the given related object was requested to be expanded in the response
+    pub fn should_expand(&self, object: api_enums::ExpandableObject) -> bool {
+        self.expand
+            .as_ref()
+            .is_some_and(|expand| expand.contains(&object))
+    }
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -5404,6 +5670,19 @@ pub struct PaymentsPostSessionTokensResponse {
     pub status: api_enums::IntentStatus,
 }
 
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsAsyncConfirmResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The status of the payment at the time the confirm was accepted
+    #[schema(value_type = IntentStatus, example = "requires_confirmation")]
+    pub status: api_enums::IntentStatus,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsDynamicTaxCalculationRequest {
     /// The unique identifier for the payment
@@ -5912,7 +6191,7 @@ pub struct GpayShippingAddressParameters {
     pub phone_number_required: bool,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct KlarnaSessionTokenResponse {
     /// The session token for Klarna
@@ -5921,7 +6200,7 @@ pub struct KlarnaSessionTokenResponse {
     pub session_id: String,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct PaypalSessionTokenResponse {
     /// Name of the connector
@@ -5962,7 +6241,7 @@ pub struct ApplepaySessionTokenResponse {
     pub connector_merchant_id: Option<String>,
 }
 
-#[derive(Debug, Eq, PartialEq, serde::Serialize, Clone, ToSchema)]
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct SdkNextAction {
     /// The type of next action
     pub next_action: NextActionCall,
@@ -6126,6 +6405,13 @@ pub struct PaymentRetrieveBody {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// The comma separated list of related objects to be expanded in the response
+    #[schema(value_type = Option<String>, example = "attempts,status_history")]
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
+    pub expand: Option<Vec<api_enums::ExpandableObject>>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -6250,13 +6536,16 @@ pub enum ThreeDsCompletionIndicator {
     NotAvailable,
 }
 
-/// Device Channel indicating whether request is coming from App or Browser
+/// Device Channel indicating whether request is coming from App or Browser, or is initiated by the 3DS Requestor without the cardholder
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema, Eq, PartialEq)]
 pub enum DeviceChannel {
     #[serde(rename = "APP")]
     App,
     #[serde(rename = "BRW")]
     Browser,
+    /// 3DS Requestor Initiated (3RI), used to authenticate merchant initiated payments in which the cardholder is not present
+    #[serde(rename = "3RI")]
+    ThreeDsRequestorInitiated,
 }
 
 /// SDK Information if request is from SDK
@@ -6640,6 +6929,9 @@ pub struct RetrievePaymentLinkResponse {
     pub currency: Option<api_enums::Currency>,
     /// Secure payment link (with security checks and listing saved payment methods)
     pub secure_link: Option<String>,
+    /// Date and time at which the Payment Link was deactivated
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub deactivated_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -6648,6 +6940,155 @@ pub struct PaymentLinkInitiateRequest {
     pub merchant_id: id_type::MerchantId,
     #[schema(value_type = String)]
     pub payment_id: id_type::PaymentId,
+    /// Token identifying the currently valid URL of a regenerated payment link
+    pub link_token: Option<String>,
+}
+
+#[derive(Default, Debug, serde::Deserialize, Clone, serde::Serialize)]
+pub struct PaymentLinkRenderQuery {
+    /// Token identifying the currently valid URL of a regenerated payment link
+    pub link_token: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkTemplateCreateRequest {
+    /// Fixed amount charged on every use of the link, in the lowest denomination of the currency. If not provided, the customer enters the amount while paying
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// Minimum amount the customer can enter, applicable only when `amount` is not provided
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    /// Maximum amount the customer can enter, applicable only when `amount` is not provided
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    /// The currency of the payments created from the link
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// Description shown on the payments created from the link
+    #[schema(max_length = 255, example = "Donation")]
+    pub description: Option<String>,
+    /// The business profile to be used for the payments created from the link
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Payment link configuration applied to every payment created from the link
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+    /// Session expiry in seconds for every payment created from the link
+    #[schema(example = 900, minimum = 60, maximum = 7890000)]
+    pub session_expiry: Option<u32>,
+    /// Maximum number of times the link can be used. If not provided, the link can be used any number of times
+    #[schema(example = 100)]
+    pub usage_limit: Option<u32>,
+    /// Date and time after which the link can no longer be used
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkTemplateUpdateRequest {
+    /// Description shown on the payments created from the link
+    #[schema(max_length = 255, example = "Donation")]
+    pub description: Option<String>,
+    /// Maximum number of times the link can be used
+    #[schema(example = 100)]
+    pub usage_limit: Option<u32>,
+    /// Whether the link can be used to make payments
+    pub is_active: Option<bool>,
+    /// Date and time after which the link can no longer be used
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentLinkTemplateResponse {
+    /// Identifier for the payment link template
+    pub template_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The business profile used for the payments created from the link
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// Reusable URL of the link. Every visit creates a new payment
+    pub link_to_pay: String,
+    /// Fixed amount charged on every use of the link
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// Minimum amount the customer can enter
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    /// Maximum amount the customer can enter
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    /// The currency of the payments created from the link
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// Description shown on the payments created from the link
+    pub description: Option<String>,
+    /// Maximum number of times the link can be used
+    pub usage_limit: Option<u32>,
+    /// Number of times the link has been used
+    pub usage_count: u32,
+    /// Whether the link can be used to make payments
+    pub is_active: bool,
+    /// Date and time after which the link can no longer be used
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// Date and time of creation of the link
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkTemplateListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentLinkTemplateListResponse {
+    /// The number of templates included in the list
+    pub count: usize,
+    /// The list of payment link templates
+    pub data: Vec<PaymentLinkTemplateResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentLinkTemplateUsageResponse {
+    /// Identifier for the payment link template
+    pub template_id: String,
+    /// The number of payment links included in the list
+    pub count: usize,
+    /// The payment links created on every use of the template
+    pub data: Vec<RetrievePaymentLinkResponse>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentLinkTemplateInitiateRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub template_id: String,
+    /// Amount entered by the customer, in major units of the currency
+    pub amount: Option<StringMajorUnit>,
+}
+
+#[derive(Default, Debug, serde::Deserialize, Clone, serde::Serialize)]
+pub struct PaymentLinkTemplateRenderQuery {
+    /// Amount entered by the customer, in major units of the currency
+    pub amount: Option<StringMajorUnit>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkExpiryUpdateRequest {
+    /// Number of seconds from now after which the payment link expires. Can be used to either extend or shorten the expiry of the payment link
+    #[schema(example = 900, minimum = 60, maximum = 7890000)]
+    pub session_expiry: u32,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -6771,6 +7212,9 @@ pub struct PaymentCreatePaymentLinkConfig {
     #[schema(value_type = Option<PaymentLinkConfigRequest>)]
     /// Theme config for the particular payment
     pub theme_config: admin::PaymentLinkConfigRequest,
+
+    /// Deliver the payment link to the customer over SMS or WhatsApp
+    pub link_delivery: Option<crate::link_delivery::LinkDeliveryRequest>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -6809,6 +7253,17 @@ pub struct ExtendedCardInfoResponse {
     pub payload: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsClientSecretRotateResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+
+    /// The new client secret of the payment, the previous client secret can no longer be used
+    #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: Secret<String>,
+}
+
 #[cfg(feature = "v1")]
 #[cfg(test)]
 mod payments_request_api_contract {
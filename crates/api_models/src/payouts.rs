@@ -7,6 +7,7 @@ use common_utils::{
     pii::{self, Email},
     transformers::ForeignFrom,
     types::{UnifiedCode, UnifiedMessage},
+    validation::bank_account,
 };
 use masking::Secret;
 use router_derive::FlatStruct;
@@ -14,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{enums as api_enums, payment_methods::RequiredFieldInfo, payments};
+use crate::{enums as api_enums, payment_methods::RequiredFieldInfo, payments, status_history};
 
 #[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 pub enum PayoutRequest {
@@ -218,6 +219,9 @@ pub struct PayoutCreatePayoutLinkConfig {
     /// - check for making sure link is accessed within an iframe
     #[schema(value_type = Option<bool>, example = false)]
     pub test_mode: Option<bool>,
+
+    /// Deliver the payout link to the customer over SMS or WhatsApp
+    pub link_delivery: Option<crate::link_delivery::LinkDeliveryRequest>,
 }
 
 /// The payout method information required for carrying out a payout
@@ -467,6 +471,28 @@ pub struct PayoutCreateResponse {
     #[schema(value_type = Option<CustomerDetailsResponse>)]
     pub customer: Option<payments::CustomerDetailsResponse>,
 
+    /// The status of the KYC of the recipient with the payout connector. The payout is fulfilled
+    /// only once the recipient is verified, for connectors which verify the recipients
+    #[schema(value_type = Option<RecipientKycStatus>, example = "verified")]
+    pub recipient_kyc_status: Option<api_enums::RecipientKycStatus>,
+
+    /// The result of matching the name of the recipient against the name of the holder of the
+    /// bank account, for payouts to UK and EU bank accounts whose profile verifies the name
+    #[schema(value_type = Option<AccountNameMatchResult>, example = "match")]
+    pub account_name_match_result: Option<api_enums::AccountNameMatchResult>,
+
+    /// The fee charged by the connector for the payout, in the lowest denomination of the currency of the payout. It is reported by the connector, or estimated from the fee schedule of the connector configured for the profile
+    #[schema(value_type = Option<i64>, example = 150)]
+    pub fee: Option<common_utils::types::MinorUnit>,
+
+    /// The amount of the payout net of the fee, in the lowest denomination of the currency of the payout
+    #[schema(value_type = Option<i64>, example = 6433)]
+    pub net_amount: Option<common_utils::types::MinorUnit>,
+
+    /// Whether the fee was reported by the connector or estimated
+    #[schema(value_type = Option<PayoutFeeSource>, example = "connector")]
+    pub fee_source: Option<api_enums::PayoutFeeSource>,
+
     /// It's a token used for client side verification.
     #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
     pub client_secret: Option<String>,
@@ -537,6 +563,11 @@ pub struct PayoutCreateResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attempts: Option<Vec<PayoutAttemptResponse>>,
 
+    /// The timeline of the status transitions of the payout, present when `expand=status_history` is passed
+    #[schema(value_type = Option<Vec<StatusHistoryResponse>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_history: Option<Vec<status_history::StatusHistoryResponse>>,
+
     /// If payout link was requested, this contains the link's ID and the URL to render the payout widget
     #[schema(value_type = Option<PayoutLinkResponse>)]
     pub payout_link: Option<PayoutLinkResponse>,
@@ -613,6 +644,32 @@ pub struct PayoutAttemptResponse {
     pub connector_transaction_id: Option<String>,
     /// If the payout was cancelled the reason provided here
     pub cancellation_reason: Option<String>,
+    /// Unique identifier of the merchant connector account used for the attempt
+    #[schema(value_type = Option<String>, example = "mca_sAD3OZLATetvjLOYhUSy")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// The routing information which was used for selecting the connector of the attempt
+    #[schema(value_type = Option<Object>)]
+    pub routing_info: Option<serde_json::Value>,
+    /// Time when the attempt was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    /// Time when the attempt was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_modified_at: Option<PrimitiveDateTime>,
+    /// The result of matching the name of the recipient against the name of the holder of the bank account
+    #[schema(value_type = Option<AccountNameMatchResult>, example = "match")]
+    pub account_name_match_result: Option<api_enums::AccountNameMatchResult>,
+    /// The fee charged by the connector for the attempt, in the lowest denomination of the currency of the payout
+    #[schema(value_type = Option<i64>, example = 150)]
+    pub fee: Option<common_utils::types::MinorUnit>,
+    /// The amount of the attempt net of the fee
+    #[schema(value_type = Option<i64>, example = 6433)]
+    pub net_amount: Option<common_utils::types::MinorUnit>,
+    /// Whether the fee was reported by the connector or estimated
+    #[schema(value_type = Option<PayoutFeeSource>, example = "connector")]
+    pub fee_source: Option<api_enums::PayoutFeeSource>,
     /// (This field is not live yet)
     /// Error code unified across the connectors is received here in case of errors while calling the underlying connector
     #[remove_in(PayoutAttemptResponse)]
@@ -630,6 +687,12 @@ pub struct PayoutRetrieveBody {
     pub force_sync: Option<bool>,
     #[schema(value_type = Option<String>)]
     pub merchant_id: Option<id_type::MerchantId>,
+    #[schema(value_type = Option<String>)]
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
+    pub expand: Option<Vec<api_enums::ExpandableObject>>,
 }
 
 #[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -652,6 +715,19 @@ pub struct PayoutRetrieveRequest {
     /// The identifier for the Merchant Account.
     #[schema(value_type = Option<String>)]
     pub merchant_id: Option<id_type::MerchantId>,
+
+    /// The list of related objects to be expanded in the response
+    #[schema(value_type = Option<Vec<ExpandableObject>>, example = json!(["attempts", "status_history"]))]
+    pub expand: Option<Vec<api_enums::ExpandableObject>>,
+}
+
+impl PayoutRetrieveRequest {
+    /// Checks if the given related object was requested to be expanded in the response
+    pub fn should_expand(&self, object: api_enums::ExpandableObject) -> bool {
+        self.expand
+            .as_ref()
+            .is_some_and(|expand| expand.contains(&object))
+    }
 }
 
 #[derive(
@@ -793,6 +869,23 @@ pub struct PayoutListResponse {
     pub total_count: Option<i64>,
 }
 
+#[derive(Default, Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutAttemptListRequest {
+    /// Unique identifier for the payout
+    #[schema(value_type = String, example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutAttemptListResponse {
+    /// Unique identifier for the payout
+    pub payout_id: String,
+    /// The number of payout attempts included in the list
+    pub total_count: usize,
+    /// The list of payout attempts, ordered by their creation time
+    pub data: Vec<PayoutAttemptResponse>,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutListFilters {
     /// The list of available connector filters
@@ -809,17 +902,76 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBalanceRequest {
+    /// The identifier of the payout connector account to fetch the balance for. Balances of all payout connector accounts are fetched if this is not passed
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// Only balances held in this currency are returned
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBalanceResponse {
+    /// The balances available with each of the payout connector accounts
+    pub data: Vec<PayoutConnectorBalanceResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutConnectorBalanceResponse {
+    /// The identifier of the payout connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The payout connector
+    #[schema(value_type = PayoutConnectors, example = "adyenplatform")]
+    pub connector: api_enums::PayoutConnectors,
+
+    /// The business profile the payout connector account belongs to
+    #[schema(value_type = String, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The balance held with the connector, per currency
+    pub balances: Vec<PayoutCurrencyBalance>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutCurrencyBalance {
+    /// The currency of the balance
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The amount which can be disbursed right away, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 100000)]
+    pub available_amount: common_utils::types::MinorUnit,
+
+    /// The amount which is yet to settle with the connector, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 2500)]
+    pub pending_amount: Option<common_utils::types::MinorUnit>,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
+    /// The unique identifier for the payout link
+    #[schema(example = "payout_link_2bdacf398vwzq5n422S1")]
     pub payout_link_id: String,
-    #[schema(value_type = String)]
+
+    /// URL to the payout link, where the customer submits the payout method details
+    #[schema(value_type = String, example = "https://sandbox.hyperswitch.io/payout_link/merchant_1671528864/187282ab-40ef-47a9-9206-5099ba31e432")]
     pub link: Secret<url::Url>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
 pub struct PayoutLinkInitiateRequest {
-    #[schema(value_type = String)]
+    /// Unique identifier for the merchant
+    #[schema(value_type = String, example = "merchant_1671528864")]
     pub merchant_id: id_type::MerchantId,
+
+    /// Unique identifier for the payout
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payout_id: String,
 }
 
@@ -842,6 +994,25 @@ pub struct PayoutLinkDetails {
     pub locale: String,
     pub form_layout: Option<common_enums::UIWidgetFormLayout>,
     pub test_mode: bool,
+    /// The form for the bank account details of the country of the payout, if its bank account
+    /// scheme is supported
+    pub bank_account_form: Option<PayoutLinkBankAccountForm>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PayoutLinkBankAccountForm {
+    pub country: api_enums::CountryAlpha2,
+    pub scheme: bank_account::BankAccountScheme,
+    /// The fields of the bank account details, in the order in which they are to be displayed
+    pub fields: Vec<PayoutLinkBankAccountFieldInfo>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PayoutLinkBankAccountFieldInfo {
+    /// The label of the field, in the locale of the payout link
+    pub label: String,
+    #[serde(flatten)]
+    pub rule: bank_account::BankAccountFieldRule,
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
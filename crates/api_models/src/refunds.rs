@@ -9,7 +9,7 @@ use utoipa::ToSchema;
 use super::payments::AmountFilter;
 use crate::{
     admin::{self, MerchantConnectorInfo},
-    enums,
+    enums, status_history,
 };
 
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
@@ -59,6 +59,51 @@ pub struct RefundRequest {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+
+    /// The capture against which the refund is to be initiated, for payments that were captured in multiple parts. If not provided, the refund is initiated against the earliest capture that has enough amount left to be refunded
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub capture_id: Option<String>,
+
+    /// The line items of the order details of the payment to be refunded. If the amount is not provided, it is computed from the amounts of the line items
+    #[schema(value_type = Option<Vec<RefundLineItemRequest>>)]
+    pub line_items: Option<Vec<RefundLineItemRequest>>,
+}
+
+/// A line item of the order details of a payment to be refunded
+#[derive(Debug, ToSchema, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundLineItemRequest {
+    /// Name of the product, as passed in the order details of the payment
+    #[schema(example = "Apple iPhone 16")]
+    pub product_name: String,
+
+    /// ID of the product, as passed in the order details of the payment. Required to identify the line item if multiple line items have the same product name
+    #[schema(example = "iphone_16_128gb")]
+    pub product_id: Option<String>,
+
+    /// The quantity of the product to be refunded
+    #[schema(example = 1)]
+    pub quantity: u16,
+}
+
+/// A line item of the order details of a payment refunded by a refund
+#[derive(Debug, ToSchema, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RefundLineItemResponse {
+    /// Name of the product
+    #[schema(example = "Apple iPhone 16")]
+    pub product_name: String,
+
+    /// ID of the product
+    #[schema(example = "iphone_16_128gb")]
+    pub product_id: Option<String>,
+
+    /// The quantity of the product refunded
+    #[schema(example = 1)]
+    pub quantity: u16,
+
+    /// The amount per quantity of the product
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
 }
 
 #[cfg(feature = "v2")]
@@ -103,6 +148,11 @@ pub struct RefundsCreateRequest {
 #[derive(Default, Debug, Clone, Deserialize)]
 pub struct RefundsRetrieveBody {
     pub force_sync: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::comma_separated::deserialize"
+    )]
+    pub expand: Option<Vec<enums::ExpandableObject>>,
 }
 
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
@@ -121,6 +171,19 @@ pub struct RefundsRetrieveRequest {
 
     /// Merchant connector details used to make payments.
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+
+    /// The list of related objects to be expanded in the response
+    #[schema(value_type = Option<Vec<ExpandableObject>>, example = json!(["status_history"]))]
+    pub expand: Option<Vec<enums::ExpandableObject>>,
+}
+
+impl RefundsRetrieveRequest {
+    /// Checks if the given related object was requested to be expanded in the response
+    pub fn should_expand(&self, object: enums::ExpandableObject) -> bool {
+        self.expand
+            .as_ref()
+            .is_some_and(|expand| expand.contains(&object))
+    }
 }
 
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
@@ -206,6 +269,26 @@ pub struct RefundResponse {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+    /// The id of the payout issued to the customer when the refund was refused by the connector
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub fallback_payout_id: Option<String>,
+    /// The capture against which the refund was initiated, for payments that were captured in multiple parts
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub capture_id: Option<String>,
+    /// The date by which the refunded amount is expected to reach the customer, as estimated by the connector
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_settlement_date: Option<PrimitiveDateTime>,
+    /// The reason for the current status of the refund, normalized across connectors
+    #[schema(value_type = Option<RefundStatusReason>)]
+    pub status_reason: Option<common_enums::RefundStatusReason>,
+    /// The line items of the order details of the payment refunded by the refund
+    #[schema(value_type = Option<Vec<RefundLineItemResponse>>)]
+    pub line_items: Option<Vec<RefundLineItemResponse>>,
+    /// The timeline of the status transitions of the refund, present when `expand=status_history` is passed
+    #[schema(value_type = Option<Vec<StatusHistoryResponse>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_history: Option<Vec<status_history::StatusHistoryResponse>>,
 }
 
 #[cfg(feature = "v1")]
@@ -107,6 +107,9 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardBin,
         DirKeyKind::CardType,
         DirKeyKind::CardNetwork,
+        DirKeyKind::CardBinNumber,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IssuerCardType,
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::UpiType,
@@ -125,6 +128,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
+        DirKeyKind::ConnectorTag,
         DirKeyKind::MetaData,
         DirKeyKind::RewardType,
         DirKeyKind::VoucherType,
@@ -0,0 +1,26 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// A status transition of a payment, refund or payout
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct StatusHistoryResponse {
+    /// The status before the transition, not present for the initial status
+    #[schema(example = "processing")]
+    pub previous_status: Option<String>,
+    /// The status after the transition
+    #[schema(example = "succeeded")]
+    pub status: String,
+    /// The operation which caused the transition
+    #[schema(example = "response_update")]
+    pub trigger: String,
+    /// The connector which processed the object at the time of the transition
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// The identifier of the attempt which transitioned, not present for transitions of the
+    /// payment itself, or of refunds
+    pub attempt_id: Option<String>,
+    /// Time at which the transition happened
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,82 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Request for creating a test clock, which simulates the passage of time for the merchant in
+/// the sandbox environment once attached
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestClockCreateRequest {
+    /// A name to identify the test clock
+    #[schema(example = "Subscription renewal")]
+    pub name: Option<String>,
+
+    /// The time the clock is frozen at. Defaults to the current time if not provided
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub frozen_time: Option<PrimitiveDateTime>,
+}
+
+/// Request for advancing the time of a test clock
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestClockAdvanceRequest {
+    /// The time the clock is advanced to, which must be later than the current frozen time of
+    /// the clock
+    #[schema(value_type = PrimitiveDateTime, example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub frozen_time: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct TestClockResponse {
+    /// The identifier for the test clock
+    #[schema(example = "clock_QIZcUeHfMOHqh5ClSBQR")]
+    pub clock_id: String,
+
+    /// The name of the test clock
+    #[schema(example = "Subscription renewal")]
+    pub name: Option<String>,
+
+    /// The time the clock was frozen at when it was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub initial_time: PrimitiveDateTime,
+
+    /// The current time of the clock
+    #[schema(value_type = PrimitiveDateTime, example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub frozen_time: PrimitiveDateTime,
+
+    /// Whether the clock is attached to the merchant. Scheduled tasks of the merchant are run
+    /// according to the time of the attached clock
+    #[schema(example = true)]
+    pub attached: bool,
+
+    /// Time at which the clock was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct TestClockListResponse {
+    /// The number of test clocks included in the list
+    pub count: usize,
+    /// The list of test clocks
+    pub data: Vec<TestClockResponse>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct TestClockDeleteResponse {
+    /// The identifier for the test clock
+    #[schema(example = "clock_QIZcUeHfMOHqh5ClSBQR")]
+    pub clock_id: String,
+    /// Whether the test clock was deleted
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct TestClockId {
+    pub clock_id: String,
+}
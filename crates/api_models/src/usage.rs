@@ -0,0 +1,35 @@
+use common_utils::id_type;
+use utoipa::ToSchema;
+
+/// Query parameters for retrieving the metered usage of merchant accounts
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UsageRetrieveRequest {
+    /// The month for which the usage is retrieved, in the format YYYY-MM. Defaults to the current month
+    #[schema(example = "2024-12")]
+    pub month: Option<String>,
+}
+
+/// The billable operations performed by a merchant account in a month
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct MerchantUsageResponse {
+    /// The identifier for the merchant account
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The month the usage belongs to, in the format YYYY-MM
+    #[schema(example = "2024-12")]
+    pub month: String,
+
+    /// The number of payments created
+    #[schema(example = 120)]
+    pub payments_created: u64,
+
+    /// The number of payouts processed by the connector
+    #[schema(example = 15)]
+    pub payouts_processed: u64,
+
+    /// The number of payment method tokens stored in the vault
+    #[schema(example = 40)]
+    pub vault_tokens_stored: u64,
+}
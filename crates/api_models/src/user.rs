@@ -8,6 +8,7 @@ use crate::user_role::UserStatus;
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 #[cfg(feature = "control_center_theme")]
 pub mod theme;
 
@@ -189,6 +190,7 @@ pub struct TokenResponse {
 pub struct TwoFactorAuthStatusResponse {
     pub totp: bool,
     pub recovery_code: bool,
+    pub webauthn: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -201,6 +203,7 @@ pub struct TwoFactorAuthAttempts {
 pub struct TwoFactorAuthStatusResponseWithAttempts {
     pub totp: TwoFactorAuthAttempts,
     pub recovery_code: TwoFactorAuthAttempts,
+    pub webauthn: TwoFactorAuthAttempts,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -240,6 +243,47 @@ pub struct RecoveryCodes {
     pub recovery_codes: Vec<Secret<String>>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebauthnChallengeResponse {
+    /// The options to be passed to `navigator.credentials.create()` or
+    /// `navigator.credentials.get()` in the browser
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebauthnRegistrationRequest {
+    /// The name given by the user to identify the passkey
+    pub name: String,
+    /// The public key credential returned by `navigator.credentials.create()`
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebauthnAuthenticationRequest {
+    /// The public key credential returned by `navigator.credentials.get()`
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebauthnCredentialResponse {
+    pub id: String,
+    pub name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebauthnCredentialId {
+    pub id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ResetTwoFactorAuthRequest {
+    pub email: pii::Email,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
 #[serde(rename_all = "snake_case")]
@@ -289,6 +333,9 @@ pub struct CreateUserAuthenticationMethodRequest {
     pub owner_type: common_enums::Owner,
     pub auth_method: AuthConfig,
     pub allow_signup: bool,
+    /// The email domain whose users are routed to this auth method on SSO, which is only
+    /// applicable to OpenID Connect
+    pub email_domain: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -296,6 +343,7 @@ pub struct UpdateUserAuthenticationMethodRequest {
     pub id: String,
     // TODO: When adding more fields make config and new fields option
     pub auth_method: AuthConfig,
+    pub email_domain: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -309,6 +357,7 @@ pub struct UserAuthenticationMethodResponse {
     pub auth_id: String,
     pub auth_method: AuthMethodDetails,
     pub allow_signup: bool,
+    pub email_domain: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -323,6 +372,11 @@ pub struct GetSsoAuthUrlRequest {
     pub id: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct GetSsoAuthUrlByEmailRequest {
+    pub email: pii::Email,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct SsoSignInRequest {
     pub state: Secret<String>,
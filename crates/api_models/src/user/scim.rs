@@ -0,0 +1,179 @@
+use common_utils::{id_type, pii};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimGroupRoleMapping {
+    /// The display name of the group in the identity provider
+    pub group_name: String,
+    /// The role assigned to the members of the group
+    pub role_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScimConfigRequest {
+    pub group_role_mappings: Vec<ScimGroupRoleMapping>,
+    /// The role assigned to the provisioned users who are not members of any mapped group
+    pub default_role_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimConfigResponse {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub group_role_mappings: Vec<ScimGroupRoleMapping>,
+    pub default_role_id: Option<String>,
+    pub token_prefix: String,
+    /// The bearer token to be configured in the identity provider, which is only returned when
+    /// the token is generated
+    pub token: Option<Secret<String>>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    pub formatted: Option<Secret<String>>,
+    pub given_name: Option<Secret<String>>,
+    pub family_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimEmail {
+    pub value: pii::Email,
+    #[serde(default)]
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+    pub user_name: pii::Email,
+    pub name: Option<ScimName>,
+    pub display_name: Option<Secret<String>>,
+    pub active: Option<bool>,
+}
+
+impl ScimUserRequest {
+    /// The display name of the user if present, or the name of the user otherwise
+    pub fn get_name(&self) -> Option<Secret<String>> {
+        self.display_name.clone().or_else(|| {
+            self.name.as_ref().and_then(|name| {
+                name.formatted.clone().or_else(|| {
+                    let name_parts = [name.given_name.as_ref(), name.family_name.as_ref()]
+                        .into_iter()
+                        .flatten()
+                        .map(|name_part| name_part.peek().trim())
+                        .filter(|name_part| !name_part.is_empty())
+                        .collect::<Vec<_>>();
+                    (!name_parts.is_empty()).then(|| Secret::new(name_parts.join(" ")))
+                })
+            })
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimMember {
+    pub value: String,
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+    pub schemas: Vec<&'static str>,
+    pub id: String,
+    pub user_name: pii::Email,
+    pub name: ScimName,
+    pub display_name: Secret<String>,
+    pub emails: Vec<ScimEmail>,
+    pub active: bool,
+    pub groups: Vec<ScimMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupRequest {
+    pub display_name: String,
+    #[serde(default)]
+    pub members: Vec<ScimMember>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResponse {
+    pub schemas: Vec<&'static str>,
+    pub id: String,
+    pub display_name: String,
+    pub members: Vec<ScimMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T> {
+    pub schemas: Vec<&'static str>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+pub type ScimUserListResponse = ScimListResponse<ScimUserResponse>;
+pub type ScimGroupListResponse = ScimListResponse<ScimGroupResponse>;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    /// Only the `eq` operator is supported, on the `userName` attribute for users and the
+    /// `displayName` attribute for groups
+    pub filter: Option<String>,
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScimPatchOp {
+    #[serde(alias = "Add")]
+    Add,
+    #[serde(alias = "Remove")]
+    Remove,
+    #[serde(alias = "Replace")]
+    Replace,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimPatchOperation {
+    pub op: ScimPatchOp,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScimPatchRequest {
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScimResourceId {
+    pub id: String,
+}
@@ -1,4 +1,4 @@
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
+use common_enums::{EventClass, EventType, WebhookDeliveryAttempt, WebhookPayloadVersion};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -187,3 +187,55 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The query parameters for previewing the webhook payload of an event.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookPayloadPreviewRequest {
+    /// The version of the payload schema to preview. Defaults to the version configured for the
+    /// business profile of the event.
+    #[schema(value_type = Option<WebhookPayloadVersion>, example = "v2")]
+    pub payload_version: Option<WebhookPayloadVersion>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookPayloadPreviewRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_id: String,
+    pub payload_version: Option<WebhookPayloadVersion>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookPayloadPreviewRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body for previewing the webhook payload of an event.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookPayloadPreviewResponse {
+    /// The identifier for the Event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The version of the payload schema used for the preview.
+    #[schema(value_type = WebhookPayloadVersion, example = "v2")]
+    pub payload_version: WebhookPayloadVersion,
+
+    /// The request information (headers and body) that would be sent in the webhook, built from
+    /// the current state of the object the event corresponds to.
+    pub request: OutgoingWebhookRequestContent,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookPayloadPreviewResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
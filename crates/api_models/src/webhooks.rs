@@ -5,6 +5,8 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
+#[cfg(feature = "v1")]
+use crate::{admin, payment_methods};
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
@@ -258,6 +260,75 @@ pub struct OutgoingWebhook {
     pub timestamp: PrimitiveDateTime,
 }
 
+/// The `v2` payload of the outgoing webhook
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OutgoingWebhookV2 {
+    /// The version of the webhook payload
+    #[schema(value_type = WebhookPayloadVersion, example = "v2")]
+    pub payload_version: api_enums::WebhookPayloadVersion,
+
+    /// The unique event id for each webhook
+    pub event_id: String,
+
+    /// The merchant id of the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The type of event this webhook corresponds to.
+    #[schema(value_type = EventType)]
+    pub event_type: api_enums::EventType,
+
+    /// The class of the event, based on the object the webhook corresponds to
+    #[schema(value_type = EventClass)]
+    pub event_class: api_enums::EventClass,
+
+    /// The time at which webhook was sent
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// This is specific to the flow, for ex: it will be `PaymentsResponse` for payments flow
+    pub data: OutgoingWebhookContent,
+}
+
+impl From<OutgoingWebhook> for OutgoingWebhookV2 {
+    fn from(outgoing_webhook: OutgoingWebhook) -> Self {
+        let OutgoingWebhook {
+            merchant_id,
+            event_id,
+            event_type,
+            content,
+            timestamp,
+        } = outgoing_webhook;
+
+        Self {
+            payload_version: api_enums::WebhookPayloadVersion::V2,
+            event_id,
+            merchant_id,
+            event_type,
+            event_class: content.get_event_class(),
+            created_at: timestamp,
+            data: content,
+        }
+    }
+}
+
+impl OutgoingWebhookContent {
+    pub fn get_event_class(&self) -> api_enums::EventClass {
+        match self {
+            Self::PaymentDetails(_) => api_enums::EventClass::Payments,
+            Self::RefundDetails(_) => api_enums::EventClass::Refunds,
+            Self::DisputeDetails(_) => api_enums::EventClass::Disputes,
+            Self::MandateDetails(_) => api_enums::EventClass::Mandates,
+            #[cfg(feature = "payouts")]
+            Self::PayoutDetails(_) => api_enums::EventClass::Payouts,
+            #[cfg(feature = "v1")]
+            Self::PaymentMethodDetails(_) => api_enums::EventClass::PaymentMethods,
+            #[cfg(feature = "v1")]
+            Self::ConnectorCredentialsExpiryDetails(_) => api_enums::EventClass::ConnectorAccounts,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 #[cfg(feature = "v1")]
@@ -273,6 +344,13 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
+    #[schema(
+        value_type = ExpiringConnectorCredentialsResponse,
+        title = "ExpiringConnectorCredentialsResponse"
+    )]
+    ConnectorCredentialsExpiryDetails(Box<admin::ExpiringConnectorCredentialsResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -76,6 +76,7 @@ pub enum RoutableConnectors {
     Fiservemea,
     Fiuu,
     Forte,
+    Generichttp,
     Globalpay,
     Globepay,
     Gocardless,
@@ -104,6 +105,7 @@ pub enum RoutableConnectors {
     Paypal,
     Payu,
     Placetopay,
+    Plugin,
     Powertranz,
     Prophetpay,
     Rapyd,
@@ -173,6 +173,42 @@ impl AttemptStatus {
             | Self::DeviceDataCollectionPending => false,
         }
     }
+
+    /// The precedence of the status when concurrent updates of an attempt conflict, such as those
+    /// made while processing a webhook and a sync of the payment. An update never replaces a
+    /// status of a higher precedence, so that a payment never moves back from a terminal status.
+    pub fn get_precedence(self) -> u8 {
+        match self {
+            Self::Started
+            | Self::AuthenticationPending
+            | Self::AuthenticationSuccessful
+            | Self::Authorizing
+            | Self::CodInitiated
+            | Self::Pending
+            | Self::PaymentMethodAwaited
+            | Self::ConfirmationAwaited
+            | Self::DeviceDataCollectionPending => 0,
+            Self::AuthenticationFailed
+            | Self::Authorized
+            | Self::AuthorizationFailed
+            | Self::VoidInitiated
+            | Self::CaptureInitiated
+            | Self::PartialChargedAndChargeable
+            | Self::Unresolved => 1,
+            Self::RouterDeclined
+            | Self::Charged
+            | Self::AutoRefunded
+            | Self::Voided
+            | Self::VoidFailed
+            | Self::CaptureFailed
+            | Self::Failure
+            | Self::PartialCharged => 2,
+        }
+    }
+
+    pub fn has_precedence_over(self, other: Self) -> bool {
+        self.get_precedence() > other.get_precedence()
+    }
 }
 
 /// Pass this parameter to force 3DS or non 3DS auth for this payment. Some connectors will still force 3DS auth even in case of passing 'no_three_ds' here and vice versa. Default value is 'no_three_ds' if not set
@@ -1158,6 +1194,8 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    PaymentMethods,
+    ConnectorAccounts,
 }
 
 #[derive(
@@ -1203,6 +1241,17 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    /// Payment method details were submitted through a payment method collect link
+    PaymentMethodCollected,
+    /// The session of a payment link expired without the payment being completed
+    PaymentLinkAbandoned,
+    /// The KYC status of a payout recipient was updated by the payout connector
+    PayoutRecipientKycUpdated,
+    /// A payout link expired without the payout method details being submitted, and the payout
+    /// was cancelled
+    PayoutLinkExpired,
+    /// The credentials of a connector account are about to expire
+    ConnectorCredentialsExpiring,
 }
 
 #[derive(
@@ -1376,6 +1425,59 @@ pub enum PaymentMethodIssuerCode {
     JpBacs,
 }
 
+/// The scopes that an API key can be restricted to. An API key restricted to one or more scopes
+/// can only be used to access the endpoints that belong to those scopes.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyScope {
+    /// Allows vaulting payment method data and obtaining a token for it.
+    Tokenize,
+    /// Allows retrieving the payment method data associated with a token.
+    Detokenize,
+}
+
+/// The permissions that can be granted to scoped admin API keys through their admin role. The
+/// admin API key configured for the application is granted every permission.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AdminPermission {
+    /// Allows creating merchant accounts.
+    MerchantAccountCreate,
+    /// Allows creating, updating and deleting merchant connector accounts.
+    MerchantConnectorManage,
+    /// Allows activating and configuring the dynamic routing algorithms of profiles.
+    RoutingActivate,
+    /// Allows enabling and disabling KV mode for merchant accounts.
+    KvToggle,
+}
+
 /// Payment Method Status
 #[derive(
     Clone,
@@ -1587,6 +1689,7 @@ pub enum PaymentMethodType {
     Vipps,
     VietQr,
     Venmo,
+    VirtualAccount,
     Walley,
     WeChatPay,
     SevenEleven,
@@ -1769,6 +1872,64 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The status of an installment plan, which indicates whether its installments are still being
+/// charged against the mandate.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InstallmentPlanStatus {
+    /// The remaining installments of the plan are scheduled to be charged
+    #[default]
+    Active,
+    /// Every installment of the plan has been charged or missed
+    Completed,
+    /// The plan was stopped after more installments were missed than the plan allows
+    Defaulted,
+    /// The plan was cancelled by the merchant before all installments were charged
+    Cancelled,
+}
+
+/// The status of a single installment of an installment plan.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InstallmentStatus {
+    /// The installment is yet to be charged
+    #[default]
+    Scheduled,
+    /// A payment for the installment was successfully created against the mandate
+    Charged,
+    /// Every attempt to charge the installment failed
+    Missed,
+    /// The installment was not charged as the plan was cancelled or defaulted
+    Void,
+}
+
 /// Indicates the card network.
 #[derive(
     Clone,
@@ -2359,7 +2520,8 @@ pub enum PayoutStatus {
     RequiresVendorAccountCreation,
 }
 
-/// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
+/// The status of the KYC and onboarding of a customer with the payout connector, for connectors
+/// which require the recipient to be verified before the payouts are disbursed
 #[derive(
     Clone,
     Copy,
@@ -2371,27 +2533,36 @@ pub enum PayoutStatus {
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
-    strum::VariantNames,
-    strum::EnumIter,
     strum::EnumString,
     ToSchema,
 )]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum PayoutType {
+pub enum RecipientKycStatus {
+    /// The verification of the recipient is in progress at the connector
     #[default]
-    Card,
-    Bank,
-    Wallet,
+    Pending,
+    /// The connector requires more information to verify the recipient
+    RequiresInformation,
+    /// The recipient is verified, and payouts can be disbursed to the recipient
+    Verified,
+    /// The verification of the recipient was rejected by the connector
+    Rejected,
 }
 
-/// Type of entity to whom the payout is being carried out to, select from the given list of options
+impl RecipientKycStatus {
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified)
+    }
+}
+
+/// The result of matching the name of the recipient against the name of the holder of the bank
+/// account, as reported by the Confirmation of Payee service
 #[derive(
     Clone,
     Copy,
     Debug,
-    Default,
     Eq,
     Hash,
     PartialEq,
@@ -2402,128 +2573,135 @@ pub enum PayoutType {
     ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
-#[serde(rename_all = "PascalCase")]
-#[strum(serialize_all = "PascalCase")]
-pub enum PayoutEntityType {
-    /// Adyen
-    #[default]
-    Individual,
-    Company,
-    NonProfit,
-    PublicSector,
-    NaturalPerson,
-
-    /// Wise
-    #[strum(serialize = "lowercase")]
-    #[serde(rename = "lowercase")]
-    Business,
-    Personal,
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountNameMatchResult {
+    /// The name matches the name of the account holder
+    Match,
+    /// The name is similar to, but does not exactly match the name of the account holder
+    CloseMatch,
+    /// The name does not match the name of the account holder
+    NoMatch,
+    /// The name could not be checked, as the bank of the account does not support it
+    Unavailable,
 }
 
-/// The send method which will be required for processing payouts, check options for better understanding.
+/// Whether the payouts to bank accounts are fulfilled when the name of the recipient does not
+/// match the name of the holder of the bank account
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
     strum::EnumString,
     ToSchema,
-    Hash,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum PayoutSendPriority {
-    Instant,
-    Fast,
-    Regular,
-    Wire,
-    CrossBorder,
-    Internal,
+pub enum AccountNameMismatchAction {
+    /// The result of the match is only recorded against the payout
+    Record,
+    /// The payouts are not fulfilled if the name does not match
+    BlockOnNoMatch,
+    /// The payouts are not fulfilled unless the name matches exactly
+    BlockOnCloseMatch,
+}
+
+impl AccountNameMismatchAction {
+    /// Whether the fulfillment of the payout is blocked for the result of the match
+    pub fn is_blocked(&self, match_result: AccountNameMatchResult) -> bool {
+        match self {
+            Self::Record => false,
+            Self::BlockOnNoMatch => match_result == AccountNameMatchResult::NoMatch,
+            Self::BlockOnCloseMatch => matches!(
+                match_result,
+                AccountNameMatchResult::NoMatch | AccountNameMatchResult::CloseMatch
+            ),
+        }
+    }
 }
 
+/// The source of the fee recorded against a payout attempt
 #[derive(
     Clone,
     Copy,
     Debug,
-    Default,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
     strum::EnumString,
     ToSchema,
-    Hash,
 )]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum PaymentSource {
-    #[default]
-    MerchantServer,
-    Postman,
-    Dashboard,
-    Sdk,
-    Webhook,
-    ExternalAuthenticator,
-}
-
-#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
-pub enum BrowserName {
-    #[default]
-    Safari,
-    #[serde(other)]
-    Unknown,
-}
-
-#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-pub enum ClientPlatform {
-    #[default]
-    Web,
-    Ios,
-    Android,
-    #[serde(other)]
-    Unknown,
-}
-
-impl PaymentSource {
-    pub fn is_for_internal_use_only(&self) -> bool {
-        match self {
-            Self::Dashboard | Self::Sdk | Self::MerchantServer | Self::Postman => false,
-            Self::Webhook | Self::ExternalAuthenticator => true,
-        }
-    }
+pub enum PayoutFeeSource {
+    /// The fee was reported by the connector in its response
+    Connector,
+    /// The fee was estimated from the fee schedule of the connector configured for the profile
+    Estimated,
 }
 
+/// The accounts of the internal ledger of a profile, kept for each currency
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
-    serde::Serialize,
     serde::Deserialize,
+    serde::Serialize,
     strum::Display,
+    strum::EnumIter,
     strum::EnumString,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum MerchantDecision {
-    Approved,
-    Rejected,
-    AutoRefunded,
+pub enum LedgerAccount {
+    /// The funds of the profile held by the connectors
+    ConnectorBalance,
+    /// The amounts captured from the payments of the profile
+    Payments,
+    /// The amounts refunded to the customers of the profile
+    Refunds,
+    /// The amounts paid out to the recipients of the payouts of the profile, net of fees
+    Payouts,
+    /// The fees charged by the connectors
+    Fees,
+    /// The amounts of the payments of the profile lost to chargebacks
+    Chargebacks,
+}
+
+impl LedgerAccount {
+    /// The side of the account which increases its balance
+    pub fn get_normal_balance(&self) -> LedgerEntryDirection {
+        match self {
+            Self::ConnectorBalance
+            | Self::Refunds
+            | Self::Payouts
+            | Self::Fees
+            | Self::Chargebacks => LedgerEntryDirection::Debit,
+            Self::Payments => LedgerEntryDirection::Credit,
+        }
+    }
 }
 
+/// The side of a ledger account to which an entry is posted
 #[derive(
     Clone,
     Copy,
-    Default,
     Debug,
     Eq,
     Hash,
@@ -2534,17 +2712,18 @@ pub enum MerchantDecision {
     strum::EnumString,
     ToSchema,
 )]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum FrmSuggestion {
-    #[default]
-    FrmCancelTransaction,
-    FrmManualReview,
-    FrmAuthorizeTransaction, // When manual capture payment which was marked fraud and held, when approved needs to be authorized.
+pub enum LedgerEntryDirection {
+    Debit,
+    Credit,
 }
 
+/// The operation whose balance movement is recorded by a ledger transaction
 #[derive(
     Clone,
+    Copy,
     Debug,
     Eq,
     Hash,
@@ -2553,19 +2732,20 @@ pub enum FrmSuggestion {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
-    utoipa::ToSchema,
-    Copy,
+    ToSchema,
 )]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum ReconStatus {
-    NotRequested,
-    Requested,
-    Active,
-    Disabled,
+pub enum LedgerSourceType {
+    Payment,
+    Refund,
+    Payout,
+    Dispute,
 }
 
+/// The type of a credential of a connector account which expires, and has to be renewed with the
+/// connector
 #[derive(
     Clone,
     Copy,
@@ -2573,42 +2753,295 @@ pub enum ReconStatus {
     Eq,
     Hash,
     PartialEq,
-    serde::Serialize,
     serde::Deserialize,
+    serde::Serialize,
     strum::Display,
     strum::EnumString,
     ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum AuthenticationConnectors {
-    Threedsecureio,
-    Netcetera,
-    Gpayments,
-}
-
-impl AuthenticationConnectors {
-    pub fn is_separate_version_call_required(&self) -> bool {
-        match self {
-            Self::Threedsecureio | Self::Netcetera => false,
-            Self::Gpayments => true,
-        }
-    }
+pub enum ConnectorCredentialType {
+    ApiKey,
+    ApplePayCertificate,
+    ClientCertificate,
+    WebhookSecret,
+    Other,
 }
 
+/// The type of the entity whose status transitions are recorded in the status history
 #[derive(
     Clone,
+    Copy,
     Debug,
     Eq,
-    Default,
     Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
     strum::EnumString,
-    utoipa::ToSchema,
-    Copy,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StatusHistoryEntityType {
+    Payment,
+    Refund,
+    Payout,
+}
+
+/// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Card,
+    Bank,
+    Wallet,
+}
+
+/// Type of entity to whom the payout is being carried out to, select from the given list of options
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "PascalCase")]
+pub enum PayoutEntityType {
+    /// Adyen
+    #[default]
+    Individual,
+    Company,
+    NonProfit,
+    PublicSector,
+    NaturalPerson,
+
+    /// Wise
+    #[strum(serialize = "lowercase")]
+    #[serde(rename = "lowercase")]
+    Business,
+    Personal,
+}
+
+/// The send method which will be required for processing payouts, check options for better understanding.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutSendPriority {
+    Instant,
+    Fast,
+    Regular,
+    Wire,
+    CrossBorder,
+    Internal,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentSource {
+    #[default]
+    MerchantServer,
+    Postman,
+    Dashboard,
+    Sdk,
+    Webhook,
+    ExternalAuthenticator,
+}
+
+#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
+pub enum BrowserName {
+    #[default]
+    Safari,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ClientPlatform {
+    #[default]
+    Web,
+    Ios,
+    Android,
+    #[serde(other)]
+    Unknown,
+}
+
+impl PaymentSource {
+    pub fn is_for_internal_use_only(&self) -> bool {
+        match self {
+            Self::Dashboard | Self::Sdk | Self::MerchantServer | Self::Postman => false,
+            Self::Webhook | Self::ExternalAuthenticator => true,
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantDecision {
+    Approved,
+    Rejected,
+    AutoRefunded,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmSuggestion {
+    #[default]
+    FrmCancelTransaction,
+    FrmManualReview,
+    FrmAuthorizeTransaction, // When manual capture payment which was marked fraud and held, when approved needs to be authorized.
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+    Copy,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconStatus {
+    NotRequested,
+    Requested,
+    Active,
+    Disabled,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthenticationConnectors {
+    Threedsecureio,
+    Netcetera,
+    Gpayments,
+}
+
+impl AuthenticationConnectors {
+    pub fn is_separate_version_call_required(&self) -> bool {
+        match self {
+            Self::Threedsecureio | Self::Netcetera => false,
+            Self::Gpayments => true,
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Default,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+    Copy,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
@@ -2828,6 +3261,7 @@ pub enum PermissionGroup {
     // TODO: Remove alias post migration
     #[serde(alias = "recon_ops")]
     ReconOpsManage,
+    PayloadLogsView,
 }
 
 #[derive(Clone, Debug, serde::Serialize, PartialEq, Eq, Hash, strum::EnumIter)]
@@ -2840,6 +3274,7 @@ pub enum ParentGroup {
     ReconOps,
     ReconReports,
     Account,
+    PayloadLogs,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize)]
@@ -2868,6 +3303,7 @@ pub enum Resource {
     ReconReports,
     RunRecon,
     ReconConfig,
+    PayloadLog,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, Hash)]
@@ -3103,6 +3539,7 @@ pub enum GenericLinkType {
     #[default]
     PaymentMethodCollect,
     PayoutLink,
+    CustomerPortal,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, strum::Display, serde::Deserialize, serde::Serialize)]
@@ -3397,3 +3834,350 @@ pub enum ErrorCategory {
     IssueWithPaymentMethod,
     ProcessorDeclineIncorrectData,
 }
+
+/// The format in which an invoice is generated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceFormat {
+    #[default]
+    Html,
+    Pdf,
+}
+
+/// The type of notification email sent to customers, for which a business profile can configure a
+/// branded template
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EmailTemplateType {
+    /// Receipt sent to the customer once a payment succeeds
+    PaymentReceipt,
+    /// Notification sent to the customer once a refund succeeds
+    RefundNotification,
+    /// Email delivering a payout link to the customer
+    PayoutLink,
+    /// Email sent to the customer with a regenerated payment link once the payment link is abandoned
+    PaymentLinkRecovery,
+}
+
+/// The channel over which a message is delivered to a customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MessagingChannel {
+    Sms,
+    Whatsapp,
+}
+
+/// The delivery status of a link sent to a customer over a messaging channel
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LinkDeliveryStatus {
+    /// The message is yet to be handed over to the messaging provider
+    Pending,
+    /// The message was accepted by the messaging provider
+    Queued,
+    /// The message was sent to the carrier
+    Sent,
+    /// The message was delivered to the customer's device
+    Delivered,
+    /// The message could not be delivered
+    Failed,
+}
+
+/// The type of link delivered to a customer over a messaging channel
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DeliveredLinkType {
+    PaymentLink,
+    PayoutLink,
+}
+
+/// The version of the payload schema used for outgoing webhooks sent to the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookPayloadVersion {
+    /// The object is sent under `content`, along with the event type and the time the webhook was sent
+    #[default]
+    V1,
+    /// The object is sent under `data`, along with the event class and the version of the payload
+    V2,
+}
+
+/// How the origin of client side requests authenticated with the client secret is enforced
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OriginEnforcementMode {
+    /// Requests from origins which are not allowed are only logged
+    #[default]
+    ReportOnly,
+    /// Requests from origins which are not allowed are rejected
+    Enforce,
+}
+
+/// The result of the address verification performed by the issuer, normalized across connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AvsResult {
+    /// Both the street address and the postal code match
+    FullMatch,
+    /// The street address matches, the postal code does not match or was not verified
+    AddressMatch,
+    /// The postal code matches, the street address does not match or was not verified
+    PostalCodeMatch,
+    /// Neither the street address nor the postal code match
+    NoMatch,
+    /// The address could not be verified by the issuer
+    Unavailable,
+}
+
+impl AvsResult {
+    /// Maps the AVS response code returned by the card networks to the normalized result
+    pub fn from_network_code(code: &str) -> Self {
+        match code.trim().to_ascii_uppercase().as_str() {
+            "Y" | "X" | "D" | "F" | "M" => Self::FullMatch,
+            "A" | "B" => Self::AddressMatch,
+            "Z" | "W" | "P" => Self::PostalCodeMatch,
+            "N" | "C" => Self::NoMatch,
+            _ => Self::Unavailable,
+        }
+    }
+}
+
+/// The result of the card verification code check performed by the issuer, normalized across
+/// connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CvvResult {
+    /// The card verification code matches
+    Match,
+    /// The card verification code does not match
+    NoMatch,
+    /// The card verification code was not processed by the issuer
+    NotProcessed,
+    /// The card verification code could not be verified, or was not provided
+    Unavailable,
+}
+
+/// The reason for the current status of a refund, normalized across connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundStatusReason {
+    /// The refund is being processed by the connector
+    ProcessingAtConnector,
+    /// The refund is awaiting the settlement of the payment which is being refunded
+    AwaitingPaymentSettlement,
+    /// The refund was processed, and the funds are on the way to the customer
+    AwaitingSettlement,
+    /// The balance of the merchant with the connector is insufficient for the refund
+    InsufficientMerchantBalance,
+    /// The card of the customer was reported lost or stolen
+    LostOrStolenCard,
+    /// The card or account of the customer has expired or was closed
+    ExpiredOrClosedAccount,
+    /// The payment being refunded is under dispute
+    PaymentDisputed,
+    /// The refund was declined by the connector or the issuer
+    Declined,
+    /// The refund was canceled at the request of the merchant
+    CanceledByMerchant,
+    /// The connector did not provide a reason which could be normalized
+    Unknown,
+}
+
+impl CvvResult {
+    /// Maps the CVV response code returned by the card networks to the normalized result
+    pub fn from_network_code(code: &str) -> Self {
+        match code.trim().to_ascii_uppercase().as_str() {
+            "M" => Self::Match,
+            "N" => Self::NoMatch,
+            "P" => Self::NotProcessed,
+            _ => Self::Unavailable,
+        }
+    }
+}
+
+/// The action taken on an authorized payment when a post authorization rule of the profile matches
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PostAuthAction {
+    /// The authorization is voided. Only payments which have not been captured yet can be voided
+    Void,
+}
+
+/// The status of the canary rollout of a connector account of a profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CanaryRolloutStatus {
+    /// Traffic is being ramped up to the canary connector account as per the stages of the rollout
+    #[default]
+    InProgress,
+    /// The canary connector account was rolled back, as its success rate dropped below that of the
+    /// incumbent connector account by more than the configured margin
+    RolledBack,
+}
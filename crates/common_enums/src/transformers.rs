@@ -1867,6 +1867,7 @@ impl From<PaymentMethodType> for PaymentMethod {
             PaymentMethodType::Vipps => Self::Wallet,
             PaymentMethodType::Venmo => Self::Wallet,
             PaymentMethodType::VietQr => Self::RealTimePayment,
+            PaymentMethodType::VirtualAccount => Self::BankTransfer,
             PaymentMethodType::Walley => Self::PayLater,
             PaymentMethodType::WeChatPay => Self::Wallet,
             PaymentMethodType::TouchNGo => Self::Wallet,
@@ -11,3 +11,11 @@ pub fn create_access_token_key(
 ) -> String {
     merchant_id.get_access_token_key(merchant_connector_id_or_connector_name)
 }
+
+/// Create a key for the lock held while refreshing the access token
+pub fn create_access_token_lock_key(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id_or_connector_name: impl Display,
+) -> String {
+    merchant_id.get_access_token_lock_key(merchant_connector_id_or_connector_name)
+}
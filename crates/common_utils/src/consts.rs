@@ -31,6 +31,9 @@ pub fn default_payments_list_limit() -> u32 {
 /// Average delay (in seconds) between account onboarding's API response and the changes to actually reflect at Stripe's end
 pub const STRIPE_ACCOUNT_ONBOARDING_DELAY_IN_SECONDS: i64 = 15;
 
+/// Delay (in seconds) after which the KYC status of a payout recipient is polled from the connector, when the payout is awaiting the recipient's verification
+pub const RECIPIENT_KYC_STATUS_POLL_DELAY_IN_SECONDS: i64 = 300;
+
 /// Maximum limit for payment link list get api
 pub const PAYMENTS_LINK_LIST_LIMIT: u32 = 100;
 
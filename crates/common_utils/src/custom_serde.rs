@@ -84,6 +84,42 @@ pub mod iso8601 {
             })
         }
     }
+    /// Use the well-known ISO 8601 format which is without timezone when serializing and deserializing a
+    /// [`PrimitiveDateTime`].
+    pub mod without_timezone {
+        use serde::{de, Deserialize, Serialize};
+        use time::macros::format_description;
+
+        use super::*;
+
+        /// Serialize a [`PrimitiveDateTime`] using the well-known ISO 8601 format which is without timezone.
+        pub fn serialize<S>(date_time: &PrimitiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let format = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+            date_time
+                .assume_utc()
+                .format(format)
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+
+        /// Deserialize a [`PrimitiveDateTime`] from its ISO 8601 representation which is without timezone.
+        pub fn deserialize<'a, D>(deserializer: D) -> Result<PrimitiveDateTime, D::Error>
+        where
+            D: Deserializer<'a>,
+        {
+            let time_string = String::deserialize(deserializer)?;
+            let format = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+            PrimitiveDateTime::parse(&time_string, format).map_err(|_| {
+                de::Error::custom(format!(
+                    "Failed to parse PrimitiveDateTime from {time_string}"
+                ))
+            })
+        }
+    }
+
     /// Use the well-known ISO 8601 format which is without timezone when serializing and deserializing an
     /// [`Option<PrimitiveDateTime>`][PrimitiveDateTime].
     ///
@@ -217,6 +253,31 @@ pub mod json_string {
     }
 }
 
+/// Deserialize a comma separated list of values, such as a query parameter of the form
+/// `key=value1,value2`, into an optional [`Vec`].
+pub mod comma_separated {
+    use std::str::FromStr;
+
+    use serde::de::{self, Deserialize, Deserializer};
+
+    /// Deserialize an optional comma separated string into an optional list of values
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        <T as FromStr>::Err: std::fmt::Display,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|item| item.trim().parse::<T>().map_err(de::Error::custom))
+                    .collect::<Result<_, _>>()
+            })
+            .transpose()
+    }
+}
+
 /// Use a custom ISO 8601 format when serializing and deserializing
 /// [`PrimitiveDateTime`][PrimitiveDateTime].
 ///
@@ -98,10 +98,14 @@ pub enum ApiEventsType {
     PaymentMethodCollectLink {
         link_id: String,
     },
+    CustomerPortalLink {
+        link_id: String,
+    },
     Poll {
         poll_id: String,
     },
     Analytics,
+    PayloadLog,
 }
 
 impl ApiEventMetric for serde_json::Value {}
@@ -9,7 +9,7 @@ use crate::{
     date_time,
     errors::{CustomResult, ValidationError},
     generate_id_with_default_len,
-    id_type::{AlphaNumericId, LengthId},
+    id_type::{AlphaNumericId, LengthId, MerchantConnectorAccountId},
     new_type::MerchantName,
     types::keymanager,
 };
@@ -159,6 +159,17 @@ impl MerchantId {
         )
     }
 
+    /// get_access_token_lock_key
+    pub fn get_access_token_lock_key(
+        &self,
+        merchant_connector_id_or_connector_name: impl Display,
+    ) -> String {
+        format!(
+            "access_token_lock_{}_{merchant_connector_id_or_connector_name}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_skip_saving_wallet_at_connector_key
     pub fn get_skip_saving_wallet_at_connector_key(&self) -> String {
         format!("skip_saving_wallet_at_connector_{}", self.get_string_repr())
@@ -199,6 +210,77 @@ impl MerchantId {
         }
     }
 
+    /// Get should check payout balance key for the merchant
+    pub fn get_should_check_payout_balance_key(&self) -> String {
+        format!("should_check_payout_balance_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the connector maintenance windows of the merchant are stored
+    pub fn get_connector_maintenance_windows_key(&self) -> String {
+        format!("connector_maintenance_windows_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the health of the incoming webhooks of a connector account is tracked
+    pub fn get_incoming_webhook_health_key(
+        &self,
+        merchant_connector_id: &MerchantConnectorAccountId,
+    ) -> String {
+        format!(
+            "incoming_webhook_health_{}_{}",
+            self.get_string_repr(),
+            merchant_connector_id.get_string_repr()
+        )
+    }
+
+    /// Get the key under which the billable operations of the merchant in a month are metered
+    pub fn get_usage_metering_key(&self, month: &str) -> String {
+        format!("usage_metering_{}_{}", self.get_string_repr(), month)
+    }
+
+    /// Get the key under which the redacted payloads of a request of the merchant are logged
+    pub fn get_payload_log_key(&self, request_id: &str) -> String {
+        format!("payload_log_{}_{}", self.get_string_repr(), request_id)
+    }
+
+    /// Get the key of the list of the requests of the merchant whose payloads are logged, in the
+    /// order they were made
+    pub fn get_payload_log_index_key(&self) -> String {
+        format!("payload_log_index_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the payout fallback of refunds refused by the connector is
+    /// configured for the merchant
+    pub fn get_refund_payout_fallback_key(&self) -> String {
+        format!("refund_payout_fallback_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the merchant opts in to receiving payment methods shared by other
+    /// merchant accounts of the organization
+    pub fn get_accept_shared_payment_methods_key(&self) -> String {
+        format!("accept_shared_payment_methods_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the exposure of raw connector response codes on payment attempts
+    /// is configured for the merchant
+    pub fn get_expose_raw_connector_response_codes_key(&self) -> String {
+        format!(
+            "expose_raw_connector_response_codes_{}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key under which the rules selecting the business profile of a payment from its
+    /// billing country and currency are configured for the merchant
+    pub fn get_profile_selection_rules_key(&self) -> String {
+        format!("profile_selection_rules_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the test clocks of the merchant are stored, along with the clock
+    /// attached to the merchant
+    pub fn get_test_clocks_key(&self) -> String {
+        format!("test_clocks_{}", self.get_string_repr())
+    }
+
     /// Get should call gsm key for payment
     pub fn get_should_call_gsm_key(&self) -> String {
         format!("should_call_gsm_{}", self.get_string_repr())
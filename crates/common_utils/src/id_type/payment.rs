@@ -62,6 +62,14 @@ impl PaymentId {
         Self(id)
     }
 
+    /// Generate a payment id with the given prefix, used for merchants which configure their own prefix
+    pub fn generate_with_prefix(prefix: &str) -> Self {
+        let id = generate_id_with_default_len(prefix);
+        let alphanumeric_id = AlphaNumericId::new_unchecked(id);
+        let id = LengthId::new_unchecked(alphanumeric_id);
+        Self(id)
+    }
+
     /// Wrap a string inside PaymentId
     pub fn wrap(payment_id_string: String) -> CustomResult<Self, ValidationError> {
         Self::try_from(std::borrow::Cow::from(payment_id_string))
@@ -32,6 +32,8 @@ pub enum GenericLinkStatus {
     PaymentMethodCollect(PaymentMethodCollectStatus),
     /// Status variants for payout link
     PayoutLink(PayoutLinkStatus),
+    /// Status variants for customer portal link
+    CustomerPortal(CustomerPortalStatus),
 }
 
 impl Default for GenericLinkStatus {
@@ -66,9 +68,9 @@ where
         let generic_status: GenericLinkStatus = serde_json::from_value(value)?;
         match generic_status {
             GenericLinkStatus::PaymentMethodCollect(status) => Ok(status),
-            GenericLinkStatus::PayoutLink(_) => Err(report!(ParsingError::EnumParseFailure(
-                "PaymentMethodCollectStatus"
-            )))
+            GenericLinkStatus::PayoutLink(_) | GenericLinkStatus::CustomerPortal(_) => Err(
+                report!(ParsingError::EnumParseFailure("PaymentMethodCollectStatus")),
+            )
             .attach_printable("Invalid status for PaymentMethodCollect")?,
         }
     }
@@ -115,7 +117,7 @@ where
         let generic_status: GenericLinkStatus = serde_json::from_value(value)?;
         match generic_status {
             GenericLinkStatus::PayoutLink(status) => Ok(status),
-            GenericLinkStatus::PaymentMethodCollect(_) => {
+            GenericLinkStatus::PaymentMethodCollect(_) | GenericLinkStatus::CustomerPortal(_) => {
                 Err(report!(ParsingError::EnumParseFailure("PayoutLinkStatus")))
                     .attach_printable("Invalid status for PayoutLink")?
             }
@@ -140,6 +142,48 @@ where
     }
 }
 
+#[derive(
+    Serialize, serde::Deserialize, Debug, Clone, Eq, PartialEq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[diesel(sql_type = Jsonb)]
+/// Status variants for customer portal links
+pub enum CustomerPortalStatus {
+    /// Link was initialized
+    Initiated,
+    /// Link was expired or invalidated
+    Invalidated,
+}
+
+impl<DB: Backend> FromSql<Jsonb, DB> for CustomerPortalStatus
+where
+    serde_json::Value: FromSql<Jsonb, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = <serde_json::Value as FromSql<Jsonb, DB>>::from_sql(bytes)?;
+        let generic_status: GenericLinkStatus = serde_json::from_value(value)?;
+        match generic_status {
+            GenericLinkStatus::CustomerPortal(status) => Ok(status),
+            GenericLinkStatus::PaymentMethodCollect(_) | GenericLinkStatus::PayoutLink(_) => Err(
+                report!(ParsingError::EnumParseFailure("CustomerPortalStatus")),
+            )
+            .attach_printable("Invalid status for CustomerPortal")?,
+        }
+    }
+}
+
+impl ToSql<Jsonb, diesel::pg::Pg> for CustomerPortalStatus
+where
+    serde_json::Value: ToSql<Jsonb, diesel::pg::Pg>,
+{
+    // This wraps CustomerPortalStatus with GenericLinkStatus
+    // Required for storing the status in required format in DB (GenericLinkStatus)
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        let value = serde_json::to_value(GenericLinkStatus::CustomerPortal(self.clone()))?;
+        <serde_json::Value as ToSql<Jsonb, diesel::pg::Pg>>::to_sql(&value, &mut out.reborrow())
+    }
+}
+
 #[derive(Serialize, serde::Deserialize, Debug, Clone, FromSqlRow, AsExpression, ToSchema)]
 #[diesel(sql_type = Jsonb)]
 /// Payout link object
@@ -245,6 +289,28 @@ pub fn validate_wildcard_domain(domain: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Validates a host name which may be prefixed with `*.` to match all of its subdomains.
+/// Schemes, ports and paths are not allowed.
+pub fn validate_host_domain(domain: &str) -> bool {
+    let host = domain.strip_prefix("*.").unwrap_or(domain);
+    !host.contains(':') && validate_strict_domain(host)
+}
+
+/// Checks whether the host matches any of the allowed domains validated by
+/// [`validate_host_domain`]. The comparison is case insensitive.
+pub fn is_host_allowed(host: &str, allowed_domains: &HashSet<String>) -> bool {
+    let host = host.to_ascii_lowercase();
+    allowed_domains.iter().any(|allowed_domain| {
+        let allowed_domain = allowed_domain.to_ascii_lowercase();
+        match allowed_domain.strip_prefix("*.") {
+            Some(parent_domain) => host
+                .strip_suffix(parent_domain)
+                .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+            None => host == allowed_domain,
+        }
+    })
+}
+
 #[cfg(test)]
 mod domain_tests {
     use regex::Regex;
@@ -354,4 +420,36 @@ mod domain_tests {
             );
         }
     }
+
+    #[test]
+    fn test_validate_host_domain() {
+        for domain in ["example.com", "*.example.com", "pay.example.co.uk"] {
+            assert!(
+                validate_host_domain(domain),
+                "Could not validate host domain: {}",
+                domain
+            );
+        }
+
+        for domain in ["", "*", "https://example.com", "example.com:443", "*.*.com"] {
+            assert!(
+                !validate_host_domain(domain),
+                "Could not validate invalid host domain: {}",
+                domain
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_host_allowed() {
+        let allowed_domains = HashSet::from(["shop.com".to_string(), "*.Example.com".to_string()]);
+
+        assert!(is_host_allowed("shop.com", &allowed_domains));
+        assert!(is_host_allowed("pay.example.com", &allowed_domains));
+        assert!(is_host_allowed("a.pay.EXAMPLE.com", &allowed_domains));
+        assert!(!is_host_allowed("example.com", &allowed_domains));
+        assert!(!is_host_allowed("badexample.com", &allowed_domains));
+        assert!(!is_host_allowed("pay.shop.com", &allowed_domains));
+        assert!(!is_host_allowed("shop.com.evil.com", &allowed_domains));
+    }
 }
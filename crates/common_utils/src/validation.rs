@@ -1,5 +1,7 @@
 //! Custom validations for some shared types.
 
+pub mod bank_account;
+
 use std::collections::HashSet;
 
 use error_stack::report;
@@ -81,6 +83,43 @@ pub fn validate_domain_against_allowed_domains(
     })
 }
 
+/// Constraints on the statement descriptors accepted by a connector
+#[derive(Debug, Clone, Copy)]
+pub struct StatementDescriptorRules {
+    /// Minimum number of characters in the statement descriptor
+    pub min_length: usize,
+    /// Maximum number of characters in the statement descriptor
+    pub max_length: usize,
+    /// Checks whether a character is allowed in the statement descriptor
+    pub is_allowed_character: fn(char) -> bool,
+}
+
+impl StatementDescriptorRules {
+    /// The constraints imposed by the card schemes, which are accepted by most connectors
+    pub const CARD_SCHEME: Self = Self {
+        min_length: 1,
+        max_length: crate::consts::MAX_STATEMENT_DESCRIPTOR_LENGTH as usize,
+        is_allowed_character: is_card_scheme_statement_descriptor_character,
+    };
+
+    /// Checks whether the statement descriptor satisfies the constraints
+    pub fn is_valid(&self, statement_descriptor: &str) -> bool {
+        let length = statement_descriptor.chars().count();
+        length >= self.min_length
+            && length <= self.max_length
+            && statement_descriptor
+                .chars()
+                .all(|character| (self.is_allowed_character)(character))
+    }
+}
+
+/// The card schemes accept printable latin characters, excluding the characters which are
+/// commonly rejected by issuers
+fn is_card_scheme_statement_descriptor_character(character: char) -> bool {
+    (character.is_ascii_graphic() || character == ' ')
+        && !matches!(character, '<' | '>' | '\\' | '\'' | '"')
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{faker::internet::en::SafeEmail, Fake};
@@ -125,6 +164,20 @@ mod tests {
         assert!(validate_phone_number(phone_number).is_ok());
     }
 
+    #[test_case("Juspay Router" ; "plain descriptor")]
+    #[test_case("JUSPAY*SELLER 42" ; "descriptor with soft descriptor")]
+    fn test_valid_card_scheme_statement_descriptor(statement_descriptor: &str) {
+        assert!(StatementDescriptorRules::CARD_SCHEME.is_valid(statement_descriptor));
+    }
+
+    #[test_case("" ; "empty descriptor")]
+    #[test_case("A descriptor which is too long" ; "descriptor longer than 22 characters")]
+    #[test_case("Juspay <Router>" ; "descriptor with disallowed characters")]
+    #[test_case("Jüspay" ; "descriptor with non latin characters")]
+    fn test_invalid_card_scheme_statement_descriptor(statement_descriptor: &str) {
+        assert!(!StatementDescriptorRules::CARD_SCHEME.is_valid(statement_descriptor));
+    }
+
     #[test_case("9123456789" ; "Romanian invalid phone number")]
     fn test_invalid_phone_number(phone_number: &str) {
         let res = validate_phone_number(phone_number);
@@ -0,0 +1,580 @@
+//! Validations of the bank account details of the different local and international bank account
+//! schemes
+
+use common_enums::CountryAlpha2;
+
+/// A field of the bank account details
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BankAccountField {
+    /// The account number of the bank account
+    AccountNumber,
+    /// The ABA routing number of a US bank account
+    RoutingNumber,
+    /// The sort code of a UK bank account
+    SortCode,
+    /// The International Bank Account Number
+    Iban,
+    /// The CLABE (Clave Bancaria Estandarizada) of a Mexican bank account
+    Clabe,
+    /// The IFSC (Indian Financial System Code) of the branch of an Indian bank account
+    Ifsc,
+}
+
+/// The validation failure of a field of the bank account details
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid {field}: {reason}")]
+pub struct BankAccountValidationError {
+    /// The field which failed the validation
+    pub field: BankAccountField,
+    /// The reason why the field failed the validation
+    pub reason: String,
+}
+
+impl BankAccountValidationError {
+    fn new(field: BankAccountField, reason: impl Into<String>) -> Self {
+        Self {
+            field,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// The result of the validation of the bank account details
+pub type BankAccountValidationResult = Result<(), BankAccountValidationError>;
+
+const ABA_ROUTING_NUMBER_LENGTH: usize = 9;
+const ABA_ROUTING_NUMBER_WEIGHTS: [u32; ABA_ROUTING_NUMBER_LENGTH] = [3, 7, 1, 3, 7, 1, 3, 7, 1];
+const US_ACCOUNT_NUMBER_MIN_LENGTH: usize = 4;
+const US_ACCOUNT_NUMBER_MAX_LENGTH: usize = 17;
+const CLABE_LENGTH: usize = 18;
+const CLABE_WEIGHTS: [u32; CLABE_LENGTH - 1] = [3, 7, 1, 3, 7, 1, 3, 7, 1, 3, 7, 1, 3, 7, 1, 3, 7];
+const IFSC_LENGTH: usize = 11;
+const SORT_CODE_LENGTH: usize = 6;
+const UK_ACCOUNT_NUMBER_MIN_LENGTH: usize = 6;
+const UK_ACCOUNT_NUMBER_LENGTH: usize = 8;
+const IBAN_MIN_LENGTH: usize = 15;
+const IBAN_MAX_LENGTH: usize = 34;
+
+/// Validates an ABA routing number of a US bank account, which consists of 9 digits satisfying
+/// the 3-7-1 weighted checksum
+pub fn validate_aba_routing_number(routing_number: &str) -> BankAccountValidationResult {
+    let digits = get_digits(
+        routing_number,
+        ABA_ROUTING_NUMBER_LENGTH..=ABA_ROUTING_NUMBER_LENGTH,
+        BankAccountField::RoutingNumber,
+    )?;
+
+    let checksum: u32 = digits
+        .iter()
+        .zip(ABA_ROUTING_NUMBER_WEIGHTS)
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+
+    if checksum % 10 != 0 {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::RoutingNumber,
+            "checksum verification failed",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the account number of a US bank account, which consists of 4 to 17 digits
+pub fn validate_us_account_number(account_number: &str) -> BankAccountValidationResult {
+    get_digits(
+        account_number,
+        US_ACCOUNT_NUMBER_MIN_LENGTH..=US_ACCOUNT_NUMBER_MAX_LENGTH,
+        BankAccountField::AccountNumber,
+    )
+    .map(|_| ())
+}
+
+/// Validates the CLABE of a Mexican bank account, which consists of 18 digits with the last digit
+/// being the control digit of the first 17 digits
+pub fn validate_clabe(clabe: &str) -> BankAccountValidationResult {
+    let digits = get_digits(clabe, CLABE_LENGTH..=CLABE_LENGTH, BankAccountField::Clabe)?;
+
+    let weighted_sum: u32 = digits
+        .iter()
+        .zip(CLABE_WEIGHTS)
+        .map(|(digit, weight)| (digit * weight) % 10)
+        .sum();
+    let control_digit = (10 - weighted_sum % 10) % 10;
+
+    if digits.last() != Some(&control_digit) {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::Clabe,
+            "control digit verification failed",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the IFSC of the branch of an Indian bank account, which consists of the 4 letter
+/// bank code, followed by a `0` and the 6 character alphanumeric branch code
+pub fn validate_ifsc(ifsc: &str) -> BankAccountValidationResult {
+    let is_valid = ifsc.len() == IFSC_LENGTH
+        && ifsc.char_indices().all(|(index, character)| match index {
+            0..=3 => character.is_ascii_uppercase(),
+            4 => character == '0',
+            _ => character.is_ascii_uppercase() || character.is_ascii_digit(),
+        });
+
+    if !is_valid {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::Ifsc,
+            "must be a 4 letter bank code, followed by a `0` and a 6 character branch code",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the sort code and the account number of a UK bank account. The sort code consists of
+/// 6 digits, optionally separated by hyphens, and the account number consists of 6 to 8 digits,
+/// which are padded to 8 digits with leading zeroes. The account number is further verified
+/// against the modulus check of the sort code, if one is known.
+pub fn validate_sort_code_and_account_number(
+    sort_code: &str,
+    account_number: &str,
+) -> BankAccountValidationResult {
+    let sort_code_digits = get_digits(
+        &sort_code.replace('-', ""),
+        SORT_CODE_LENGTH..=SORT_CODE_LENGTH,
+        BankAccountField::SortCode,
+    )?;
+    let account_number_digits = get_digits(
+        account_number,
+        UK_ACCOUNT_NUMBER_MIN_LENGTH..=UK_ACCOUNT_NUMBER_LENGTH,
+        BankAccountField::AccountNumber,
+    )?;
+
+    let sort_code_value = sort_code_digits
+        .iter()
+        .fold(0, |value, digit| value * 10 + digit);
+    let Some(modulus_check) = SORT_CODE_MODULUS_CHECKS
+        .iter()
+        .find(|modulus_check| modulus_check.sort_codes.contains(&sort_code_value))
+    else {
+        // As per the VocaLink specification, the account numbers of the sort codes without a
+        // modulus check are presumed to be valid
+        return Ok(());
+    };
+
+    let padding = [0; UK_ACCOUNT_NUMBER_LENGTH];
+    let digits = sort_code_digits
+        .iter()
+        .chain(
+            padding
+                .iter()
+                .take(UK_ACCOUNT_NUMBER_LENGTH.saturating_sub(account_number_digits.len())),
+        )
+        .chain(account_number_digits.iter());
+
+    if !modulus_check.is_satisfied_by(digits) {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::AccountNumber,
+            "modulus check against the sort code failed",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates an IBAN, which consists of the country code, the check digits and the country
+/// specific BBAN, whose total length is fixed for each country, and which satisfies the ISO 13616
+/// mod-97 check
+pub fn validate_iban(iban: &str) -> BankAccountValidationResult {
+    let iban = iban.replace(' ', "");
+
+    if !(IBAN_MIN_LENGTH..=IBAN_MAX_LENGTH).contains(&iban.len()) {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::Iban,
+            format!("must be {IBAN_MIN_LENGTH} to {IBAN_MAX_LENGTH} characters long"),
+        ));
+    }
+    if !iban
+        .chars()
+        .all(|character| character.is_ascii_uppercase() || character.is_ascii_digit())
+    {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::Iban,
+            "must only contain uppercase letters and digits",
+        ));
+    }
+
+    let (country_code, _) = iban.split_at(2);
+    let expected_length = get_iban_length(country_code).ok_or_else(|| {
+        BankAccountValidationError::new(
+            BankAccountField::Iban,
+            format!("IBAN is not supported for the country `{country_code}`"),
+        )
+    })?;
+    if iban.len() != expected_length {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::Iban,
+            format!("must be {expected_length} characters long for the country `{country_code}`"),
+        ));
+    }
+
+    // The country code and the check digits are moved to the end, and the letters are replaced
+    // by the numbers 10 to 35, with the remainder being computed digit by digit
+    let (prefix, bban) = iban.split_at(4);
+    let remainder = bban
+        .chars()
+        .chain(prefix.chars())
+        .filter_map(|character| character.to_digit(36))
+        .fold(0, |remainder, value| {
+            let multiplier = if value < 10 { 10 } else { 100 };
+            (remainder * multiplier + value) % 97
+        });
+
+    if remainder != 1 {
+        return Err(BankAccountValidationError::new(
+            BankAccountField::Iban,
+            "check digits verification failed",
+        ));
+    }
+
+    Ok(())
+}
+
+/// The length of the IBANs of the country, as per the IBAN registry
+pub fn get_iban_length(country_code: &str) -> Option<usize> {
+    let length = match country_code {
+        "NO" => 15,
+        "BE" => 16,
+        "DK" | "FI" | "FO" | "GL" | "NL" | "SD" => 18,
+        "MK" | "SI" => 19,
+        "AT" | "BA" | "EE" | "KZ" | "LT" | "LU" | "XK" => 20,
+        "CH" | "HR" | "LI" | "LV" => 21,
+        "BG" | "BH" | "CR" | "DE" | "GB" | "GE" | "IE" | "ME" | "RS" | "VA" => 22,
+        "AE" | "GI" | "IL" | "IQ" | "TL" => 23,
+        "AD" | "CZ" | "ES" | "MD" | "PK" | "RO" | "SA" | "SE" | "SK" | "TN" | "VG" => 24,
+        "LY" | "PT" | "ST" => 25,
+        "IS" | "TR" => 26,
+        "FR" | "GR" | "IT" | "MC" | "MR" | "SM" => 27,
+        "AL" | "AZ" | "BY" | "CY" | "DO" | "GT" | "HU" | "LB" | "PL" | "SV" => 28,
+        "BR" | "EG" | "PS" | "QA" | "UA" => 29,
+        "JO" | "KW" | "MU" => 30,
+        "MT" | "SC" => 31,
+        "LC" => 32,
+        _ => return None,
+    };
+
+    Some(length)
+}
+
+/// The scheme of the bank account details of a country
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BankAccountScheme {
+    /// The ABA routing number and the account number of a US bank account
+    RoutingNumberAndAccountNumber,
+    /// The sort code and the account number of a UK bank account
+    SortCodeAndAccountNumber,
+    /// The CLABE of a Mexican bank account
+    Clabe,
+    /// The IBAN of a bank account of a country in the IBAN registry
+    Iban,
+}
+
+/// The checksum which the value of a field of the bank account details must satisfy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BankAccountChecksum {
+    /// The 3-7-1 weighted checksum of the ABA routing numbers
+    AbaWeighted,
+    /// The control digit of the CLABE
+    ClabeControlDigit,
+    /// The ISO 13616 mod-97 check of the IBAN
+    IbanMod97,
+}
+
+/// The rules for the validation of a field of the bank account details, which are used by the
+/// clients to validate the field before it is submitted. The lengths and the pattern apply to the
+/// value of the field with its spaces and hyphens removed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct BankAccountFieldRule {
+    /// The field of the bank account details
+    pub field: BankAccountField,
+    /// The minimum length of the value of the field
+    pub min_length: usize,
+    /// The maximum length of the value of the field
+    pub max_length: usize,
+    /// The regular expression which the value of the field must match
+    pub pattern: String,
+    /// The checksum which the value of the field must satisfy, if any
+    pub checksum: Option<BankAccountChecksum>,
+}
+
+impl BankAccountFieldRule {
+    fn digits(
+        field: BankAccountField,
+        allowed_length: std::ops::RangeInclusive<usize>,
+        checksum: Option<BankAccountChecksum>,
+    ) -> Self {
+        let (min_length, max_length) = allowed_length.into_inner();
+        let pattern = if min_length == max_length {
+            format!("^[0-9]{{{min_length}}}$")
+        } else {
+            format!("^[0-9]{{{min_length},{max_length}}}$")
+        };
+
+        Self {
+            field,
+            min_length,
+            max_length,
+            pattern,
+            checksum,
+        }
+    }
+}
+
+/// The scheme of the bank account details of the country, with the domestic scheme of the country
+/// being preferred over the IBAN
+pub fn get_bank_account_scheme(country: CountryAlpha2) -> Option<BankAccountScheme> {
+    match country {
+        CountryAlpha2::US => Some(BankAccountScheme::RoutingNumberAndAccountNumber),
+        CountryAlpha2::GB => Some(BankAccountScheme::SortCodeAndAccountNumber),
+        CountryAlpha2::MX => Some(BankAccountScheme::Clabe),
+        _ => get_iban_length(&country.to_string()).map(|_| BankAccountScheme::Iban),
+    }
+}
+
+/// The rules for the validation of the fields of the bank account details of the country, in the
+/// order in which the fields are to be displayed
+pub fn get_bank_account_field_rules(country: CountryAlpha2) -> Option<Vec<BankAccountFieldRule>> {
+    let field_rules = match get_bank_account_scheme(country)? {
+        BankAccountScheme::RoutingNumberAndAccountNumber => vec![
+            BankAccountFieldRule::digits(
+                BankAccountField::RoutingNumber,
+                ABA_ROUTING_NUMBER_LENGTH..=ABA_ROUTING_NUMBER_LENGTH,
+                Some(BankAccountChecksum::AbaWeighted),
+            ),
+            BankAccountFieldRule::digits(
+                BankAccountField::AccountNumber,
+                US_ACCOUNT_NUMBER_MIN_LENGTH..=US_ACCOUNT_NUMBER_MAX_LENGTH,
+                None,
+            ),
+        ],
+        // The modulus check of the account number against the sort code is only verified on
+        // submission, since it depends on the modulus weight table
+        BankAccountScheme::SortCodeAndAccountNumber => vec![
+            BankAccountFieldRule::digits(
+                BankAccountField::SortCode,
+                SORT_CODE_LENGTH..=SORT_CODE_LENGTH,
+                None,
+            ),
+            BankAccountFieldRule::digits(
+                BankAccountField::AccountNumber,
+                UK_ACCOUNT_NUMBER_MIN_LENGTH..=UK_ACCOUNT_NUMBER_LENGTH,
+                None,
+            ),
+        ],
+        BankAccountScheme::Clabe => vec![BankAccountFieldRule::digits(
+            BankAccountField::Clabe,
+            CLABE_LENGTH..=CLABE_LENGTH,
+            Some(BankAccountChecksum::ClabeControlDigit),
+        )],
+        BankAccountScheme::Iban => {
+            let country_code = country.to_string();
+            let iban_length = get_iban_length(&country_code)?;
+            vec![BankAccountFieldRule {
+                field: BankAccountField::Iban,
+                min_length: iban_length,
+                max_length: iban_length,
+                pattern: format!(
+                    "^{country_code}[0-9]{{2}}[A-Z0-9]{{{}}}$",
+                    iban_length.saturating_sub(4)
+                ),
+                checksum: Some(BankAccountChecksum::IbanMod97),
+            }]
+        }
+    };
+
+    Some(field_rules)
+}
+
+/// Parses the digits of the field, ensuring that the number of digits is within the allowed range
+fn get_digits(
+    value: &str,
+    allowed_length: std::ops::RangeInclusive<usize>,
+    field: BankAccountField,
+) -> Result<Vec<u32>, BankAccountValidationError> {
+    let digits = value
+        .chars()
+        .map(|character| character.to_digit(10))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| BankAccountValidationError::new(field, "must only contain digits"))?;
+
+    if !allowed_length.contains(&digits.len()) {
+        let (min_length, max_length) = allowed_length.into_inner();
+        let reason = if min_length == max_length {
+            format!("must be {min_length} digits long")
+        } else {
+            format!("must be {min_length} to {max_length} digits long")
+        };
+        return Err(BankAccountValidationError::new(field, reason));
+    }
+
+    Ok(digits)
+}
+
+/// The algorithms of the modulus checks of the UK bank accounts
+#[derive(Debug, Clone, Copy)]
+enum SortCodeModulusAlgorithm {
+    /// The weighted sum must be divisible by 10
+    Mod10,
+    /// The weighted sum must be divisible by 11
+    Mod11,
+    /// The sum of the digits of the weighted products must be divisible by 10
+    DoubleAlternate,
+}
+
+/// The modulus check of a range of sort codes, as per the VocaLink modulus weight table. The
+/// weights apply to the 6 digits of the sort code followed by the 8 digits of the account number.
+#[derive(Debug)]
+struct SortCodeModulusCheck {
+    sort_codes: std::ops::RangeInclusive<u32>,
+    algorithm: SortCodeModulusAlgorithm,
+    weights: [u32; SORT_CODE_LENGTH + UK_ACCOUNT_NUMBER_LENGTH],
+}
+
+impl SortCodeModulusCheck {
+    fn is_satisfied_by<'a>(&self, digits: impl Iterator<Item = &'a u32>) -> bool {
+        let products = digits
+            .zip(self.weights)
+            .map(|(digit, weight)| digit * weight);
+
+        match self.algorithm {
+            SortCodeModulusAlgorithm::Mod10 => products.sum::<u32>() % 10 == 0,
+            SortCodeModulusAlgorithm::Mod11 => products.sum::<u32>() % 11 == 0,
+            SortCodeModulusAlgorithm::DoubleAlternate => {
+                products
+                    .map(|product| product / 10 + product % 10)
+                    .sum::<u32>()
+                    % 10
+                    == 0
+            }
+        }
+    }
+}
+
+/// The modulus checks of the sort codes whose account numbers are verified
+const SORT_CODE_MODULUS_CHECKS: [SortCodeModulusCheck; 3] = [
+    SortCodeModulusCheck {
+        sort_codes: 89999..=89999,
+        algorithm: SortCodeModulusAlgorithm::Mod10,
+        weights: [0, 0, 0, 0, 0, 0, 7, 1, 3, 7, 1, 3, 7, 1],
+    },
+    SortCodeModulusCheck {
+        sort_codes: 107999..=107999,
+        algorithm: SortCodeModulusAlgorithm::Mod11,
+        weights: [0, 0, 0, 0, 0, 0, 8, 7, 6, 5, 4, 3, 2, 1],
+    },
+    SortCodeModulusCheck {
+        sort_codes: 202959..=202959,
+        algorithm: SortCodeModulusAlgorithm::DoubleAlternate,
+        weights: [2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("021000021" ; "jp morgan chase")]
+    #[test_case("110000000" ; "stripe test bank")]
+    fn test_valid_aba_routing_number(routing_number: &str) {
+        assert!(validate_aba_routing_number(routing_number).is_ok());
+    }
+
+    #[test_case("021000022" ; "invalid checksum")]
+    #[test_case("02100002" ; "too short")]
+    #[test_case("02100002A" ; "non digit")]
+    fn test_invalid_aba_routing_number(routing_number: &str) {
+        let error = validate_aba_routing_number(routing_number).unwrap_err();
+        assert_eq!(error.field, BankAccountField::RoutingNumber);
+    }
+
+    #[test]
+    fn test_clabe() {
+        assert!(validate_clabe("032180000118359719").is_ok());
+        assert!(validate_clabe("032180000118359718").is_err());
+        assert!(validate_clabe("03218000011835971").is_err());
+    }
+
+    #[test_case("HDFC0000001", true ; "valid")]
+    #[test_case("SBIN0ABC123", true ; "alphanumeric branch code")]
+    #[test_case("HDFC1000001", false ; "missing zero")]
+    #[test_case("HDF00000001", false ; "numeric bank code")]
+    #[test_case("hdfc0000001", false ; "lowercase")]
+    fn test_ifsc(ifsc: &str, is_valid: bool) {
+        assert_eq!(validate_ifsc(ifsc).is_ok(), is_valid);
+    }
+
+    #[test_case("089999", "66374958", true ; "mod 10")]
+    #[test_case("107999", "88837491", true ; "mod 11")]
+    #[test_case("20-29-59", "63748472", true ; "double alternate")]
+    #[test_case("202959", "63748473", false ; "failed modulus check")]
+    #[test_case("231470", "28821822", true ; "no modulus check")]
+    #[test_case("231470", "288218", true ; "short account number")]
+    #[test_case("23147", "28821822", false ; "short sort code")]
+    fn test_sort_code_and_account_number(sort_code: &str, account_number: &str, is_valid: bool) {
+        assert_eq!(
+            validate_sort_code_and_account_number(sort_code, account_number).is_ok(),
+            is_valid
+        );
+    }
+
+    #[test_case("GB82WEST12345698765432" ; "gb")]
+    #[test_case("DE89 3704 0044 0532 0130 00" ; "de with spaces")]
+    #[test_case("NL46TEST0136169112" ; "nl")]
+    #[test_case("MT84MALT011000012345MTLCAST001S" ; "longer than u128")]
+    fn test_valid_iban(iban: &str) {
+        assert!(validate_iban(iban).is_ok());
+    }
+
+    #[test_case("GB82WEST12345698765431" ; "invalid check digits")]
+    #[test_case("GB82WEST1234569876543" ; "invalid country length")]
+    #[test_case("US82WEST12345698765432" ; "unsupported country")]
+    #[test_case("gb82west12345698765432" ; "lowercase")]
+    fn test_invalid_iban(iban: &str) {
+        let error = validate_iban(iban).unwrap_err();
+        assert_eq!(error.field, BankAccountField::Iban);
+    }
+
+    #[test_case(CountryAlpha2::US, Some(BankAccountScheme::RoutingNumberAndAccountNumber) ; "us")]
+    #[test_case(CountryAlpha2::GB, Some(BankAccountScheme::SortCodeAndAccountNumber) ; "gb")]
+    #[test_case(CountryAlpha2::MX, Some(BankAccountScheme::Clabe) ; "mx")]
+    #[test_case(CountryAlpha2::DE, Some(BankAccountScheme::Iban) ; "de")]
+    #[test_case(CountryAlpha2::IN, None ; "unsupported country")]
+    fn test_bank_account_scheme(country: CountryAlpha2, scheme: Option<BankAccountScheme>) {
+        assert_eq!(get_bank_account_scheme(country), scheme);
+    }
+
+    #[test_case(CountryAlpha2::US, &["110000000", "000123456789"] ; "us")]
+    #[test_case(CountryAlpha2::GB, &["089999", "66374958"] ; "gb")]
+    #[test_case(CountryAlpha2::MX, &["032180000118359719"] ; "mx")]
+    #[test_case(CountryAlpha2::DE, &["DE89370400440532013000"] ; "de")]
+    fn test_bank_account_field_rules(country: CountryAlpha2, values: &[&str]) {
+        let field_rules = get_bank_account_field_rules(country).unwrap();
+        assert_eq!(field_rules.len(), values.len());
+
+        for (field_rule, value) in field_rules.iter().zip(values) {
+            let pattern = regex::Regex::new(&field_rule.pattern).unwrap();
+            assert!(pattern.is_match(value));
+            assert!((field_rule.min_length..=field_rule.max_length).contains(&value.len()));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+#![warn(missing_docs, missing_debug_implementations)]
+
+//! Protocol and interface for connector integrations running as out-of-process plugins.
+//!
+//! The types in this crate form the wire format exchanged between the application and a plugin,
+//! and are versioned by [`PROTOCOL_VERSION`]. Changes to the protocol are additive within a
+//! version, so that plugins built against an older release of this crate keep working.
+
+pub mod plugin;
+pub mod types;
+
+pub use self::{
+    plugin::{dispatch, ConnectorPlugin},
+    types::*,
+};
+
+/// The version of the plugin protocol implemented by this crate
+pub const PROTOCOL_VERSION: &str = "1";
+
+/// Headers sent by the application to the plugin
+pub mod headers {
+    /// Header carrying the version of the plugin protocol used for the request
+    pub const PROTOCOL_VERSION: &str = "X-Plugin-Protocol-Version";
+    /// Header carrying the api key configured for the plugin, which authenticates the application
+    pub const PLUGIN_API_KEY: &str = "X-Plugin-Api-Key";
+}
@@ -0,0 +1,95 @@
+//! The interface implemented by connector plugins
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    types::{
+        AuthorizeData, CaptureData, PaymentResponse, PaymentSyncData, PluginError, PluginFlow,
+        PluginRequest, RefundData, RefundResponse, RefundSyncData, VoidData,
+    },
+    PROTOCOL_VERSION,
+};
+
+/// A connector integration served out of process. Only the authorize flow is required, the other
+/// flows respond with a `flow_not_supported` error unless implemented.
+#[async_trait::async_trait]
+pub trait ConnectorPlugin: Send + Sync {
+    /// Authorizes, and optionally captures, a payment
+    async fn authorize(
+        &self,
+        request: PluginRequest<AuthorizeData>,
+    ) -> Result<PaymentResponse, PluginError>;
+
+    /// Captures an authorized payment
+    async fn capture(
+        &self,
+        _request: PluginRequest<CaptureData>,
+    ) -> Result<PaymentResponse, PluginError> {
+        Err(PluginError::flow_not_supported(PluginFlow::Capture))
+    }
+
+    /// Voids an authorized payment
+    async fn void(
+        &self,
+        _request: PluginRequest<VoidData>,
+    ) -> Result<PaymentResponse, PluginError> {
+        Err(PluginError::flow_not_supported(PluginFlow::Void))
+    }
+
+    /// Retrieves the status of a payment
+    async fn psync(
+        &self,
+        _request: PluginRequest<PaymentSyncData>,
+    ) -> Result<PaymentResponse, PluginError> {
+        Err(PluginError::flow_not_supported(PluginFlow::Psync))
+    }
+
+    /// Refunds a captured payment
+    async fn refund(
+        &self,
+        _request: PluginRequest<RefundData>,
+    ) -> Result<RefundResponse, PluginError> {
+        Err(PluginError::flow_not_supported(PluginFlow::Refund))
+    }
+
+    /// Retrieves the status of a refund
+    async fn rsync(
+        &self,
+        _request: PluginRequest<RefundSyncData>,
+    ) -> Result<RefundResponse, PluginError> {
+        Err(PluginError::flow_not_supported(PluginFlow::Rsync))
+    }
+}
+
+/// Deserializes the request body of the flow, calls the plugin and serializes its response. This
+/// is meant to be called by the http server hosting the plugin, which responds with a `200`
+/// status code on success and a `400` status code along with the serialized error otherwise.
+pub async fn dispatch(
+    plugin: &dyn ConnectorPlugin,
+    flow: PluginFlow,
+    body: &[u8],
+) -> Result<Vec<u8>, PluginError> {
+    match flow {
+        PluginFlow::Authorize => to_body(plugin.authorize(parse_request(body)?).await?),
+        PluginFlow::Capture => to_body(plugin.capture(parse_request(body)?).await?),
+        PluginFlow::Void => to_body(plugin.void(parse_request(body)?).await?),
+        PluginFlow::Psync => to_body(plugin.psync(parse_request(body)?).await?),
+        PluginFlow::Refund => to_body(plugin.refund(parse_request(body)?).await?),
+        PluginFlow::Rsync => to_body(plugin.rsync(parse_request(body)?).await?),
+    }
+}
+
+fn parse_request<T: DeserializeOwned>(body: &[u8]) -> Result<PluginRequest<T>, PluginError> {
+    let request: PluginRequest<T> =
+        serde_json::from_slice(body).map_err(PluginError::invalid_request)?;
+    if request.protocol_version != PROTOCOL_VERSION {
+        return Err(PluginError::unsupported_protocol_version(
+            &request.protocol_version,
+        ));
+    }
+    Ok(request)
+}
+
+fn to_body<T: Serialize>(response: T) -> Result<Vec<u8>, PluginError> {
+    serde_json::to_vec(&response).map_err(PluginError::internal)
+}
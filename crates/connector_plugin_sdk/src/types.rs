@@ -0,0 +1,355 @@
+//! Request and response types of the plugin protocol
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+/// The flows which can be implemented by a plugin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginFlow {
+    /// Authorizes, and optionally captures, a payment
+    Authorize,
+    /// Captures an authorized payment
+    Capture,
+    /// Voids an authorized payment
+    Void,
+    /// Retrieves the status of a payment
+    Psync,
+    /// Refunds a captured payment
+    Refund,
+    /// Retrieves the status of a refund
+    Rsync,
+}
+
+impl PluginFlow {
+    /// The path segment of the plugin url which serves the flow
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Authorize => "authorize",
+            Self::Capture => "capture",
+            Self::Void => "void",
+            Self::Psync => "psync",
+            Self::Refund => "refund",
+            Self::Rsync => "rsync",
+        }
+    }
+}
+
+impl fmt::Display for PluginFlow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PluginFlow {
+    type Err = PluginError;
+
+    fn from_str(flow: &str) -> Result<Self, Self::Err> {
+        match flow {
+            "authorize" => Ok(Self::Authorize),
+            "capture" => Ok(Self::Capture),
+            "void" => Ok(Self::Void),
+            "psync" => Ok(Self::Psync),
+            "refund" => Ok(Self::Refund),
+            "rsync" => Ok(Self::Rsync),
+            _ => Err(PluginError::flow_not_supported(flow)),
+        }
+    }
+}
+
+/// The envelope of every request sent to a plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginRequest<T> {
+    /// The version of the plugin protocol used for the request
+    pub protocol_version: String,
+    /// The merchant on whose behalf the request is made
+    pub merchant_id: String,
+    /// The payment for which the request is made
+    pub payment_id: String,
+    /// The payment attempt for which the request is made
+    pub attempt_id: String,
+    /// The reference to be sent to the processor for the payment
+    pub reference: String,
+    /// The credentials configured in the merchant connector account
+    pub auth: PluginConnectorAuth,
+    /// The metadata configured in the merchant connector account
+    pub metadata: Option<Secret<serde_json::Value>>,
+    /// The flow specific data of the request
+    pub data: T,
+}
+
+/// The credentials configured in the merchant connector account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "auth_type", rename_all = "snake_case")]
+pub enum PluginConnectorAuth {
+    /// A single api key
+    HeaderKey {
+        /// api key
+        api_key: Secret<String>,
+    },
+    /// An api key along with an additional key
+    BodyKey {
+        /// api key
+        api_key: Secret<String>,
+        /// additional key
+        key1: Secret<String>,
+    },
+    /// An api key along with an additional key and a secret
+    SignatureKey {
+        /// api key
+        api_key: Secret<String>,
+        /// additional key
+        key1: Secret<String>,
+        /// api secret
+        api_secret: Secret<String>,
+    },
+    /// An api key along with two additional keys and a secret
+    MultiAuthKey {
+        /// api key
+        api_key: Secret<String>,
+        /// additional key
+        key1: Secret<String>,
+        /// api secret
+        api_secret: Secret<String>,
+        /// second additional key
+        key2: Secret<String>,
+    },
+    /// No credentials
+    NoKey,
+}
+
+/// Whether the funds are captured along with the authorization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureMethod {
+    /// The payment is captured along with the authorization
+    Automatic,
+    /// The payment is captured by a subsequent capture request
+    Manual,
+}
+
+/// Data of the authorize flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizeData {
+    /// The amount in the minor unit of the currency
+    pub amount: i64,
+    /// ISO 4217 code of the currency
+    pub currency: String,
+    /// Whether the payment is to be captured along with the authorization
+    pub capture_method: CaptureMethod,
+    /// The payment method used for the payment
+    pub payment_method_data: PaymentMethodData,
+    /// Email of the customer
+    pub email: Option<Secret<String>>,
+    /// Name of the customer
+    pub customer_name: Option<Secret<String>>,
+    /// Description of the payment
+    pub description: Option<String>,
+    /// The url to which the customer is to be redirected after authentication
+    pub return_url: Option<String>,
+}
+
+/// The payment method used for the payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaymentMethodData {
+    /// Card details
+    Card(CardData),
+}
+
+/// Card details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardData {
+    /// The card number
+    pub number: Secret<String>,
+    /// The two digit expiry month
+    pub exp_month: Secret<String>,
+    /// The four digit expiry year
+    pub exp_year: Secret<String>,
+    /// The card verification code
+    pub cvc: Secret<String>,
+    /// The name of the card holder
+    pub holder_name: Option<Secret<String>>,
+}
+
+/// Data of the capture flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureData {
+    /// The identifier of the payment at the processor
+    pub connector_transaction_id: String,
+    /// The amount to be captured in the minor unit of the currency
+    pub amount: i64,
+    /// ISO 4217 code of the currency
+    pub currency: String,
+}
+
+/// Data of the void flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoidData {
+    /// The identifier of the payment at the processor
+    pub connector_transaction_id: String,
+    /// The reason for voiding the payment
+    pub cancellation_reason: Option<String>,
+}
+
+/// Data of the payment sync flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentSyncData {
+    /// The identifier of the payment at the processor
+    pub connector_transaction_id: String,
+}
+
+/// Data of the refund flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundData {
+    /// The identifier of the payment at the processor
+    pub connector_transaction_id: String,
+    /// The identifier of the refund, to be used as the reference at the processor
+    pub refund_id: String,
+    /// The amount to be refunded in the minor unit of the currency
+    pub amount: i64,
+    /// ISO 4217 code of the currency
+    pub currency: String,
+    /// The reason for the refund
+    pub reason: Option<String>,
+}
+
+/// Data of the refund sync flow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundSyncData {
+    /// The identifier of the payment at the processor
+    pub connector_transaction_id: String,
+    /// The identifier of the refund
+    pub refund_id: String,
+    /// The identifier of the refund at the processor
+    pub connector_refund_id: Option<String>,
+}
+
+/// The status of a payment at the processor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentStatus {
+    /// The payment is authorized and awaits capture
+    Authorized,
+    /// The payment is captured
+    Charged,
+    /// The payment is being processed
+    Pending,
+    /// The customer is to be redirected to authenticate the payment
+    AuthenticationPending,
+    /// The payment is voided
+    Voided,
+    /// The payment failed
+    Failed,
+}
+
+/// Response of the authorize, capture, void and payment sync flows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentResponse {
+    /// The status of the payment
+    pub status: PaymentStatus,
+    /// The identifier of the payment at the processor
+    pub connector_transaction_id: Option<String>,
+    /// The reference of the payment at the processor
+    pub connector_response_reference_id: Option<String>,
+    /// The redirection required to authenticate the payment
+    pub redirection: Option<Redirection>,
+    /// The reason for the failure of the payment
+    pub error: Option<PluginError>,
+}
+
+/// A redirection of the customer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Redirection {
+    /// The url to which the customer is redirected
+    pub url: String,
+    /// The http method used for the redirection
+    pub method: RedirectionMethod,
+    /// The form fields submitted along with the redirection
+    #[serde(default)]
+    pub form_fields: HashMap<String, String>,
+}
+
+/// The http method used for a redirection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RedirectionMethod {
+    /// GET
+    Get,
+    /// POST
+    Post,
+}
+
+/// The status of a refund at the processor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    /// The refund succeeded
+    Succeeded,
+    /// The refund is being processed
+    Pending,
+    /// The refund failed
+    Failed,
+}
+
+/// Response of the refund and refund sync flows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundResponse {
+    /// The status of the refund
+    pub status: RefundStatus,
+    /// The identifier of the refund at the processor
+    pub connector_refund_id: String,
+    /// The reason for the failure of the refund
+    pub error: Option<PluginError>,
+}
+
+/// An error reported by the plugin or the processor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginError {
+    /// The error code
+    pub code: String,
+    /// The error message
+    pub message: String,
+    /// The detailed reason for the error
+    pub reason: Option<String>,
+}
+
+impl PluginError {
+    /// The request was for a flow which is not implemented by the plugin
+    pub fn flow_not_supported(flow: impl fmt::Display) -> Self {
+        Self {
+            code: "flow_not_supported".to_string(),
+            message: format!("The {flow} flow is not supported by the plugin"),
+            reason: None,
+        }
+    }
+
+    /// The request could not be deserialized
+    pub fn invalid_request(reason: impl fmt::Display) -> Self {
+        Self {
+            code: "invalid_request".to_string(),
+            message: "The request could not be parsed".to_string(),
+            reason: Some(reason.to_string()),
+        }
+    }
+
+    /// The request was made with a version of the protocol which is not supported by the plugin
+    pub fn unsupported_protocol_version(version: &str) -> Self {
+        Self {
+            code: "unsupported_protocol_version".to_string(),
+            message: format!("Version {version} of the plugin protocol is not supported"),
+            reason: None,
+        }
+    }
+
+    /// The response could not be serialized
+    pub fn internal(reason: impl fmt::Display) -> Self {
+        Self {
+            code: "internal_error".to_string(),
+            message: "The plugin failed to process the request".to_string(),
+            reason: Some(reason.to_string()),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{api_keys::HashedApiKey, schema::admin_api_keys};
+
+/// An admin API key which is only permitted to access the admin endpoints allowed by its admin
+/// role, unlike the admin API key configured for the application.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = admin_api_keys, primary_key(key_id), check_for_backend(diesel::pg::Pg))]
+pub struct AdminApiKey {
+    pub key_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub hashed_api_key: HashedApiKey,
+    pub prefix: String,
+    pub role_id: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = admin_api_keys)]
+pub struct AdminApiKeyNew {
+    pub key_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub hashed_api_key: HashedApiKey,
+    pub prefix: String,
+    pub role_id: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AdminApiKeyUpdate {
+    RoleUpdate { role_id: String },
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = admin_api_keys)]
+pub(crate) struct AdminApiKeyUpdateInternal {
+    pub role_id: Option<String>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<AdminApiKeyUpdate> for AdminApiKeyUpdateInternal {
+    fn from(admin_api_key_update: AdminApiKeyUpdate) -> Self {
+        match admin_api_key_update {
+            AdminApiKeyUpdate::RoleUpdate { role_id } => Self {
+                role_id: Some(role_id),
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
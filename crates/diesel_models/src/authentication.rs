@@ -47,6 +47,7 @@ pub struct Authentication {
     pub ds_trans_id: Option<String>,
     pub directory_server_id: Option<String>,
     pub acquirer_country_code: Option<String>,
+    pub connector_failover_history: Option<AuthenticationConnectorFailoverHistory>,
 }
 
 impl Authentication {
@@ -94,6 +95,24 @@ pub struct AuthenticationNew {
     pub ds_trans_id: Option<String>,
     pub directory_server_id: Option<String>,
     pub acquirer_country_code: Option<String>,
+    pub connector_failover_history: Option<AuthenticationConnectorFailoverHistory>,
+}
+
+/// The authentication connectors which were failed over from, in the order they were attempted
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct AuthenticationConnectorFailoverHistory(pub Vec<AuthenticationConnectorFailover>);
+
+common_utils::impl_to_sql_from_sql_json!(AuthenticationConnectorFailoverHistory);
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticationConnectorFailover {
+    pub authentication_connector: String,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub failed_at: time::PrimitiveDateTime,
 }
 
 #[derive(Debug)]
@@ -152,6 +171,11 @@ pub enum AuthenticationUpdate {
     PostAuthorizationUpdate {
         authentication_lifecycle_status: common_enums::AuthenticationLifecycleStatus,
     },
+    ConnectorFailoverUpdate {
+        authentication_connector: String,
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        connector_failover_history: AuthenticationConnectorFailoverHistory,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, Serialize, Deserialize)]
@@ -186,6 +210,9 @@ pub struct AuthenticationUpdateInternal {
     pub ds_trans_id: Option<String>,
     pub directory_server_id: Option<String>,
     pub acquirer_country_code: Option<String>,
+    pub authentication_connector: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub connector_failover_history: Option<AuthenticationConnectorFailoverHistory>,
 }
 
 impl Default for AuthenticationUpdateInternal {
@@ -219,6 +246,9 @@ impl Default for AuthenticationUpdateInternal {
             ds_trans_id: Default::default(),
             directory_server_id: Default::default(),
             acquirer_country_code: Default::default(),
+            authentication_connector: Default::default(),
+            merchant_connector_id: Default::default(),
+            connector_failover_history: Default::default(),
         }
     }
 }
@@ -254,6 +284,9 @@ impl AuthenticationUpdateInternal {
             ds_trans_id,
             directory_server_id,
             acquirer_country_code,
+            authentication_connector,
+            merchant_connector_id,
+            connector_failover_history,
         } = self;
         Authentication {
             connector_authentication_id: connector_authentication_id
@@ -288,6 +321,11 @@ impl AuthenticationUpdateInternal {
             ds_trans_id: ds_trans_id.or(source.ds_trans_id),
             directory_server_id: directory_server_id.or(source.directory_server_id),
             acquirer_country_code: acquirer_country_code.or(source.acquirer_country_code),
+            authentication_connector: authentication_connector
+                .unwrap_or(source.authentication_connector),
+            merchant_connector_id: merchant_connector_id.unwrap_or(source.merchant_connector_id),
+            connector_failover_history: connector_failover_history
+                .or(source.connector_failover_history),
             ..source
         }
     }
@@ -418,6 +456,16 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 connector_metadata,
                 ..Default::default()
             },
+            AuthenticationUpdate::ConnectorFailoverUpdate {
+                authentication_connector,
+                merchant_connector_id,
+                connector_failover_history,
+            } => Self {
+                authentication_connector: Some(authentication_connector),
+                merchant_connector_id: Some(merchant_connector_id),
+                connector_failover_history: Some(connector_failover_history),
+                ..Default::default()
+            },
         }
     }
 }
@@ -1,9 +1,15 @@
 use std::collections::{HashMap, HashSet};
 
-use common_enums::{AuthenticationConnectors, UIWidgetFormLayout};
+use common_enums::{
+    AccountNameMismatchAction, AuthenticationConnectors, AuthenticationType, AvsResult,
+    CanaryRolloutStatus, CaptureMethod, Currency, CvvResult, ErrorCategory, EventClass,
+    FutureUsage, InvoiceFormat, PaymentMethodType, PayoutRetryType, PayoutType, PostAuthAction,
+    UIWidgetFormLayout, WebhookPayloadVersion,
+};
 use common_utils::{encryption::Encryption, pii};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
+use time::{Duration, PrimitiveDateTime, Time, Weekday};
 
 #[cfg(feature = "v1")]
 use crate::schema::business_profile;
@@ -58,6 +64,30 @@ pub struct Profile {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<EventClass>>,
+    pub webhook_payload_version: Option<WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: Option<Encryption>,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v1")]
@@ -102,6 +132,30 @@ pub struct ProfileNew {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<EventClass>>,
+    pub webhook_payload_version: Option<WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: Option<Encryption>,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v1")]
@@ -143,6 +197,30 @@ pub struct ProfileUpdateInternal {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: Option<bool>,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<EventClass>>,
+    pub webhook_payload_version: Option<WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: Option<Encryption>,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v1")]
@@ -183,6 +261,30 @@ impl ProfileUpdateInternal {
             is_auto_retries_enabled,
             max_auto_retries_enabled,
             is_click_to_pay_enabled,
+            customer_portal_link_config,
+            invoice_config,
+            enabled_webhook_events,
+            webhook_payload_version,
+            statement_descriptor_config,
+            dispute_representment_config,
+            outgoing_webhook_auth_config,
+            redirect_config,
+            client_origin_config,
+            is_client_secret_single_use,
+            post_auth_rules_config,
+            allowed_currencies,
+            tags,
+            payout_retry_config,
+            payout_fulfillment_config,
+            webhook_secret_rotation,
+            payment_defaults_config,
+            capture_on_shipment_config,
+            dcc_config,
+            payout_fee_config,
+            payout_balance_config,
+            operation_fallback_config,
+            account_name_mismatch_action,
+            connector_canary_rollout,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -244,6 +346,39 @@ impl ProfileUpdateInternal {
             max_auto_retries_enabled: max_auto_retries_enabled.or(source.max_auto_retries_enabled),
             is_click_to_pay_enabled: is_click_to_pay_enabled
                 .unwrap_or(source.is_click_to_pay_enabled),
+            customer_portal_link_config: customer_portal_link_config
+                .or(source.customer_portal_link_config),
+            invoice_config: invoice_config.or(source.invoice_config),
+            enabled_webhook_events: enabled_webhook_events.or(source.enabled_webhook_events),
+            webhook_payload_version: webhook_payload_version.or(source.webhook_payload_version),
+            statement_descriptor_config: statement_descriptor_config
+                .or(source.statement_descriptor_config),
+            dispute_representment_config: dispute_representment_config
+                .or(source.dispute_representment_config),
+            outgoing_webhook_auth_config: outgoing_webhook_auth_config
+                .or(source.outgoing_webhook_auth_config),
+            redirect_config: redirect_config.or(source.redirect_config),
+            client_origin_config: client_origin_config.or(source.client_origin_config),
+            is_client_secret_single_use: is_client_secret_single_use
+                .or(source.is_client_secret_single_use),
+            post_auth_rules_config: post_auth_rules_config.or(source.post_auth_rules_config),
+            allowed_currencies: allowed_currencies.or(source.allowed_currencies),
+            tags: tags.or(source.tags),
+            payout_retry_config: payout_retry_config.or(source.payout_retry_config),
+            payout_fulfillment_config: payout_fulfillment_config
+                .or(source.payout_fulfillment_config),
+            webhook_secret_rotation: webhook_secret_rotation.or(source.webhook_secret_rotation),
+            payment_defaults_config: payment_defaults_config.or(source.payment_defaults_config),
+            capture_on_shipment_config: capture_on_shipment_config
+                .or(source.capture_on_shipment_config),
+            dcc_config: dcc_config.or(source.dcc_config),
+            payout_fee_config: payout_fee_config.or(source.payout_fee_config),
+            payout_balance_config: payout_balance_config.or(source.payout_balance_config),
+            operation_fallback_config: operation_fallback_config
+                .or(source.operation_fallback_config),
+            account_name_mismatch_action: account_name_mismatch_action
+                .or(source.account_name_mismatch_action),
+            connector_canary_rollout: connector_canary_rollout.or(source.connector_canary_rollout),
         }
     }
 }
@@ -299,6 +434,30 @@ pub struct Profile {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<EventClass>>,
+    pub webhook_payload_version: Option<WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: Option<Encryption>,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 impl Profile {
@@ -358,6 +517,30 @@ pub struct ProfileNew {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<EventClass>>,
+    pub webhook_payload_version: Option<WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: Option<Encryption>,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v2")]
@@ -401,6 +584,30 @@ pub struct ProfileUpdateInternal {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: Option<bool>,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<EventClass>>,
+    pub webhook_payload_version: Option<WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: Option<Encryption>,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v2")]
@@ -443,6 +650,30 @@ impl ProfileUpdateInternal {
             is_auto_retries_enabled,
             max_auto_retries_enabled,
             is_click_to_pay_enabled,
+            customer_portal_link_config,
+            invoice_config,
+            enabled_webhook_events,
+            webhook_payload_version,
+            statement_descriptor_config,
+            dispute_representment_config,
+            outgoing_webhook_auth_config,
+            redirect_config,
+            client_origin_config,
+            is_client_secret_single_use,
+            post_auth_rules_config,
+            allowed_currencies,
+            tags,
+            payout_retry_config,
+            payout_fulfillment_config,
+            webhook_secret_rotation,
+            payment_defaults_config,
+            capture_on_shipment_config,
+            dcc_config,
+            payout_fee_config,
+            payout_balance_config,
+            operation_fallback_config,
+            account_name_mismatch_action,
+            connector_canary_rollout,
         } = self;
         Profile {
             id: source.id,
@@ -509,6 +740,39 @@ impl ProfileUpdateInternal {
             max_auto_retries_enabled: max_auto_retries_enabled.or(source.max_auto_retries_enabled),
             is_click_to_pay_enabled: is_click_to_pay_enabled
                 .unwrap_or(source.is_click_to_pay_enabled),
+            customer_portal_link_config: customer_portal_link_config
+                .or(source.customer_portal_link_config),
+            invoice_config: invoice_config.or(source.invoice_config),
+            enabled_webhook_events: enabled_webhook_events.or(source.enabled_webhook_events),
+            webhook_payload_version: webhook_payload_version.or(source.webhook_payload_version),
+            statement_descriptor_config: statement_descriptor_config
+                .or(source.statement_descriptor_config),
+            dispute_representment_config: dispute_representment_config
+                .or(source.dispute_representment_config),
+            outgoing_webhook_auth_config: outgoing_webhook_auth_config
+                .or(source.outgoing_webhook_auth_config),
+            redirect_config: redirect_config.or(source.redirect_config),
+            client_origin_config: client_origin_config.or(source.client_origin_config),
+            is_client_secret_single_use: is_client_secret_single_use
+                .or(source.is_client_secret_single_use),
+            post_auth_rules_config: post_auth_rules_config.or(source.post_auth_rules_config),
+            allowed_currencies: allowed_currencies.or(source.allowed_currencies),
+            tags: tags.or(source.tags),
+            payout_retry_config: payout_retry_config.or(source.payout_retry_config),
+            payout_fulfillment_config: payout_fulfillment_config
+                .or(source.payout_fulfillment_config),
+            webhook_secret_rotation: webhook_secret_rotation.or(source.webhook_secret_rotation),
+            payment_defaults_config: payment_defaults_config.or(source.payment_defaults_config),
+            capture_on_shipment_config: capture_on_shipment_config
+                .or(source.capture_on_shipment_config),
+            dcc_config: dcc_config.or(source.dcc_config),
+            payout_fee_config: payout_fee_config.or(source.payout_fee_config),
+            payout_balance_config: payout_balance_config.or(source.payout_balance_config),
+            operation_fallback_config: operation_fallback_config
+                .or(source.operation_fallback_config),
+            account_name_mismatch_action: account_name_mismatch_action
+                .or(source.account_name_mismatch_action),
+            connector_canary_rollout: connector_canary_rollout.or(source.connector_canary_rollout),
         }
     }
 }
@@ -544,6 +808,13 @@ pub struct BusinessPaymentLinkConfig {
     pub default_config: Option<PaymentLinkConfigRequest>,
     pub business_specific_configs: Option<HashMap<String, PaymentLinkConfigRequest>>,
     pub allowed_domains: Option<HashSet<String>>,
+    pub abandoned_cart_config: Option<PaymentLinkAbandonedCartConfig>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentLinkAbandonedCartConfig {
+    pub send_recovery_email: bool,
+    pub recovery_link_expiry: Option<u32>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -569,7 +840,8 @@ pub struct BusinessPayoutLinkConfig {
     pub payout_test_mode: Option<bool>,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct BusinessGenericLinkConfig {
     pub domain_name: Option<String>,
     pub allowed_domains: HashSet<String>,
@@ -577,4 +849,448 @@ pub struct BusinessGenericLinkConfig {
     pub ui_config: common_utils::link_utils::GenericLinkUiConfig,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessInvoiceConfig {
+    pub send_email_on_payment_success: bool,
+    pub format: InvoiceFormat,
+    pub footer_note: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessStatementDescriptorConfig {
+    pub name: Option<String>,
+    pub suffix: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessDisputeRepresentmentConfig {
+    pub max_dispute_amount: common_utils::types::MinorUnit,
+    pub evidence_templates: DisputeEvidenceTemplates,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct DisputeEvidenceTemplates {
+    pub product_description: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessRedirectConfig {
+    #[serde(flatten)]
+    pub ui_config: common_utils::link_utils::GenericLinkUiConfig,
+    pub locale: Option<String>,
+    pub auto_redirect_timeout_ms: Option<u32>,
+    pub allowed_return_url_domains: Option<HashSet<String>>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessClientOriginConfig {
+    pub allowed_origins: HashSet<String>,
+    pub enforcement_mode: common_enums::OriginEnforcementMode,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessPostAuthRulesConfig {
+    pub rules: Vec<PostAuthRule>,
+}
+
+/// A rule evaluated against the AVS and CVV results of an attempt after it has been authorized.
+/// The rule matches if every condition that has been configured matches
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PostAuthRule {
+    pub avs_results: Option<HashSet<AvsResult>>,
+    pub cvv_results: Option<HashSet<CvvResult>>,
+    pub action: PostAuthAction,
+}
+
+impl PostAuthRule {
+    pub fn is_match(&self, avs_result: Option<AvsResult>, cvv_result: Option<CvvResult>) -> bool {
+        let is_avs_match = self.avs_results.as_ref().map_or(true, |avs_results| {
+            avs_result.is_some_and(|avs_result| avs_results.contains(&avs_result))
+        });
+        let is_cvv_match = self.cvv_results.as_ref().map_or(true, |cvv_results| {
+            cvv_result.is_some_and(|cvv_result| cvv_results.contains(&cvv_result))
+        });
+        is_avs_match && is_cvv_match
+    }
+}
+
+impl BusinessPostAuthRulesConfig {
+    /// The action of the first rule which matches the AVS and CVV results of the attempt
+    pub fn get_action(
+        &self,
+        avs_result: Option<AvsResult>,
+        cvv_result: Option<CvvResult>,
+    ) -> Option<PostAuthAction> {
+        self.rules
+            .iter()
+            .find(|rule| rule.is_match(avs_result, cvv_result))
+            .map(|rule| rule.action)
+    }
+}
+
+/// Retry policy for the payouts of a profile, which takes precedence over the payout retry
+/// configs of the merchant
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessPayoutRetryConfig {
+    pub max_single_connector_retries: u8,
+    pub max_multi_connector_retries: u8,
+    pub retryable_error_categories: Option<Vec<ErrorCategory>>,
+    pub backoff_in_millis: Option<u32>,
+}
+
+impl BusinessPayoutRetryConfig {
+    pub fn get_max_retries(&self, retry_type: PayoutRetryType) -> u8 {
+        match retry_type {
+            PayoutRetryType::SingleConnector => self.max_single_connector_retries,
+            PayoutRetryType::MultiConnector => self.max_multi_connector_retries,
+        }
+    }
+
+    /// All errors are retried if no error categories are configured, otherwise only the errors
+    /// mapped to one of the configured categories are retried
+    pub fn is_error_category_retryable(&self, error_category: Option<ErrorCategory>) -> bool {
+        self.retryable_error_categories
+            .as_ref()
+            .map_or(true, |retryable_error_categories| {
+                error_category.is_some_and(|error_category| {
+                    retryable_error_categories.contains(&error_category)
+                })
+            })
+    }
+
+    /// The delay before a retry, which is doubled for every retry already made for the payout
+    pub fn get_backoff_duration(&self, retries_made: u32) -> Option<std::time::Duration> {
+        self.backoff_in_millis.map(|backoff_in_millis| {
+            std::time::Duration::from_millis(
+                u64::from(backoff_in_millis).saturating_mul(2_u64.saturating_pow(retries_made)),
+            )
+        })
+    }
+}
+
+/// Fee schedules of the payout connector accounts of a profile, from which the fees of the payouts
+/// are estimated when the connector does not report the fee it charged
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessPayoutFeeConfig {
+    pub fee_schedules: Vec<PayoutFeeSchedule>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutFeeSchedule {
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// The schedule applies to payouts in all currencies if not set
+    pub currency: Option<Currency>,
+    /// In the minor unit of the currency of the schedule
+    pub fixed_fee: common_utils::types::MinorUnit,
+    pub percentage_in_basis_points: u16,
+}
+
+impl BusinessPayoutFeeConfig {
+    /// The fee of a payout estimated from the first fee schedule which applies to the connector
+    /// account and currency of the payout. The proportional fee is rounded half up to the minor
+    /// unit of the currency.
+    pub fn estimate_fee(
+        &self,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        amount: common_utils::types::MinorUnit,
+        currency: Currency,
+    ) -> Option<common_utils::types::MinorUnit> {
+        self.fee_schedules
+            .iter()
+            .find(|fee_schedule| {
+                fee_schedule.merchant_connector_id == *merchant_connector_id
+                    && fee_schedule
+                        .currency
+                        .map_or(true, |schedule_currency| schedule_currency == currency)
+            })
+            .map(|fee_schedule| {
+                let proportional_fee = (i128::from(amount.get_amount_as_i64())
+                    * i128::from(fee_schedule.percentage_in_basis_points)
+                    + 5_000)
+                    / 10_000;
+                common_utils::types::MinorUnit::new(
+                    fee_schedule
+                        .fixed_fee
+                        .get_amount_as_i64()
+                        .saturating_add(i64::try_from(proportional_fee).unwrap_or(i64::MAX)),
+                )
+            })
+    }
+}
+
+/// Rules which keep the payouts of a profile from driving the connector balance of its ledger
+/// negative
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessPayoutBalanceConfig {
+    pub holdback_percentage_in_basis_points: u16,
+}
+
+impl BusinessPayoutBalanceConfig {
+    /// The amount held back in the connector balance for a payout, rounded half up to the minor
+    /// unit of the currency of the payout
+    pub fn get_holdback_amount(
+        &self,
+        amount: common_utils::types::MinorUnit,
+    ) -> common_utils::types::MinorUnit {
+        let holdback_amount = (i128::from(amount.get_amount_as_i64())
+            * i128::from(self.holdback_percentage_in_basis_points)
+            + 5_000)
+            / 10_000;
+        common_utils::types::MinorUnit::new(i64::try_from(holdback_amount).unwrap_or(i64::MAX))
+    }
+}
+
+/// Defaults applied to the payments created under the profile, when the corresponding fields are
+/// not passed in the payment request
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessPaymentDefaultsConfig {
+    pub capture_method: Option<CaptureMethod>,
+    pub setup_future_usage: Option<FutureUsage>,
+    pub authentication_type: Option<AuthenticationType>,
+}
+
+/// Capture of the authorized payments of the profile when their shipment is notified by the
+/// order management system of the merchant
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessCaptureOnShipmentConfig {
+    pub enabled: bool,
+    pub partial_capture_enabled: bool,
+}
+
+/// Dynamic currency conversion offered to the customers of the profile, which is applied through
+/// the connectors supporting it
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessDccConfig {
+    pub enabled: bool,
+    pub markup_in_basis_points: u16,
+}
+
+/// Groups of connector accounts of the same connector which hold the same merchant account
+/// credentials, through which the operations on a payment can be processed in place of each other
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessOperationFallbackConfig {
+    pub groups: Vec<OperationFallbackGroup>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct OperationFallbackGroup {
+    pub merchant_connector_ids: Vec<common_utils::id_type::MerchantConnectorAccountId>,
+}
+
+impl BusinessOperationFallbackConfig {
+    /// The other connector accounts in the group of the given connector account, in the order of
+    /// preference
+    pub fn get_fallback_merchant_connector_ids(
+        &self,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> Vec<&common_utils::id_type::MerchantConnectorAccountId> {
+        self.groups
+            .iter()
+            .find(|group| group.merchant_connector_ids.contains(merchant_connector_id))
+            .map(|group| {
+                group
+                    .merchant_connector_ids
+                    .iter()
+                    .filter(|fallback_merchant_connector_id| {
+                        *fallback_merchant_connector_id != merchant_connector_id
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// The gradual rollout of the traffic of the incumbent connector account of the profile to a
+/// canary connector account
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ConnectorCanaryRollout {
+    pub canary_merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub incumbent_merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub traffic_percentage_stages: Vec<u8>,
+    pub stage_duration_in_secs: u32,
+    pub rollback_margin: f64,
+    pub min_sample_size: u32,
+    pub status: CanaryRolloutStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub rolled_back_at: Option<PrimitiveDateTime>,
+}
+
+impl ConnectorCanaryRollout {
+    /// The percentage of the traffic of the incumbent connector account which is routed to the
+    /// canary connector account at the given time, as per the stage the rollout has reached
+    pub fn get_canary_traffic_percentage(&self, now: PrimitiveDateTime) -> u8 {
+        if self.status == CanaryRolloutStatus::RolledBack {
+            return 0;
+        }
+        let elapsed_in_secs = (now - self.started_at).whole_seconds().max(0);
+        let stage =
+            usize::try_from(elapsed_in_secs / i64::from(self.stage_duration_in_secs.max(1)))
+                .unwrap_or(usize::MAX);
+        self.traffic_percentage_stages
+            .get(stage)
+            .or(self.traffic_percentage_stages.last())
+            .copied()
+            .unwrap_or(100)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BusinessPayoutFulfillmentConfig {
+    pub windows: Vec<PayoutFulfillmentWindow>,
+}
+
+/// A time of the day during which payouts are auto fulfilled. The window applies to a payout if
+/// every condition that has been configured matches
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutFulfillmentWindow {
+    pub payout_types: Option<Vec<PayoutType>>,
+    pub payout_method_types: Option<Vec<PaymentMethodType>>,
+    /// Start of the window in the `HH:MM` format, in the time zone of `utc_offset_in_minutes`
+    pub start_time: String,
+    /// End of the window in the `HH:MM` format, in the time zone of `utc_offset_in_minutes`
+    pub end_time: String,
+    pub utc_offset_in_minutes: i16,
+    /// Whether payouts are fulfilled only from Monday to Friday
+    pub banking_days_only: bool,
+}
+
+/// Parses a time of the day in the `HH:MM` format
+pub fn parse_time_of_day(time_of_day: &str) -> Option<Time> {
+    let (hour, minute) = time_of_day.split_once(':')?;
+    Time::from_hms(hour.parse().ok()?, minute.parse().ok()?, 0).ok()
+}
+
+impl PayoutFulfillmentWindow {
+    pub fn is_match(
+        &self,
+        payout_type: Option<PayoutType>,
+        payout_method_type: Option<PaymentMethodType>,
+    ) -> bool {
+        let is_payout_type_match = self.payout_types.as_ref().map_or(true, |payout_types| {
+            payout_type.is_some_and(|payout_type| payout_types.contains(&payout_type))
+        });
+        let is_payout_method_type_match =
+            self.payout_method_types
+                .as_ref()
+                .map_or(true, |payout_method_types| {
+                    payout_method_type.is_some_and(|payout_method_type| {
+                        payout_method_types.contains(&payout_method_type)
+                    })
+                });
+        is_payout_type_match && is_payout_method_type_match
+    }
+
+    /// The earliest time at or after `now` which falls within the window, both in UTC
+    pub fn get_next_open_time(&self, now: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let start_time = parse_time_of_day(&self.start_time)?;
+        let end_time = parse_time_of_day(&self.end_time)?;
+        let utc_offset = Duration::minutes(i64::from(self.utc_offset_in_minutes));
+        let local_now = now.checked_add(utc_offset)?;
+
+        // A banking day is always reached within a week
+        (0..=7)
+            .filter_map(|days| local_now.date().checked_add(Duration::days(days)))
+            .filter(|date| {
+                !self.banking_days_only
+                    || !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+            })
+            .find_map(|date| {
+                let window_start = date.with_time(start_time);
+                let window_end = date.with_time(end_time);
+                if local_now < window_start {
+                    Some(window_start)
+                } else if local_now < window_end {
+                    Some(local_now)
+                } else {
+                    None
+                }
+            })
+            .and_then(|local_open_time| local_open_time.checked_sub(utc_offset))
+    }
+}
+
+impl BusinessPayoutFulfillmentConfig {
+    /// The first window which applies to the payout type and payout method type of the payout
+    pub fn get_window(
+        &self,
+        payout_type: Option<PayoutType>,
+        payout_method_type: Option<PaymentMethodType>,
+    ) -> Option<&PayoutFulfillmentWindow> {
+        self.windows
+            .iter()
+            .find(|window| window.is_match(payout_type, payout_method_type))
+    }
+}
+
+/// The signing secret of the outgoing webhooks which was replaced by a rotation. Webhooks are
+/// signed with both the previous and the current secret until the rotation expires
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct WebhookSecretRotation {
+    pub previous_payment_response_hash_key: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl WebhookSecretRotation {
+    /// The previous signing secret, if the rotation has not expired
+    pub fn get_active_previous_secret(&self, now: PrimitiveDateTime) -> Option<&str> {
+        (now < self.expires_at).then_some(self.previous_payment_response_hash_key.as_str())
+    }
+}
+
 common_utils::impl_to_sql_from_sql_json!(BusinessPayoutLinkConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessGenericLinkConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessInvoiceConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessStatementDescriptorConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessDisputeRepresentmentConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessRedirectConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessClientOriginConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessPostAuthRulesConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessPayoutRetryConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessPayoutFeeConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessPayoutBalanceConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessPayoutFulfillmentConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessPaymentDefaultsConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessCaptureOnShipmentConfig);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessDccConfig);
+
+common_utils::impl_to_sql_from_sql_json!(ConnectorCanaryRollout);
+
+common_utils::impl_to_sql_from_sql_json!(BusinessOperationFallbackConfig);
+
+common_utils::impl_to_sql_from_sql_json!(WebhookSecretRotation);
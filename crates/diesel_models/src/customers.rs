@@ -1,4 +1,4 @@
-use common_enums::ApiVersion;
+use common_enums::{ApiVersion, RecipientKycStatus};
 use common_utils::{encryption::Encryption, pii, types::Description};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use time::PrimitiveDateTime;
@@ -58,6 +58,7 @@ impl From<CustomerNew> for Customer {
             default_payment_method_id: None,
             updated_by: customer_new.updated_by,
             version: customer_new.version,
+            recipient_kyc_status: None,
         }
     }
 }
@@ -123,6 +124,7 @@ impl From<CustomerNew> for Customer {
             id: customer_new.id,
             version: customer_new.version,
             status: customer_new.status,
+            recipient_kyc_status: None,
         }
     }
 }
@@ -148,6 +150,7 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: ApiVersion,
+    pub recipient_kyc_status: Option<RecipientKycStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -174,6 +177,7 @@ pub struct Customer {
     pub default_shipping_address: Option<Encryption>,
     pub status: DeleteStatus,
     pub id: String,
+    pub recipient_kyc_status: Option<RecipientKycStatus>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -193,6 +197,7 @@ pub struct CustomerUpdateInternal {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
     pub updated_by: Option<String>,
+    pub recipient_kyc_status: Option<RecipientKycStatus>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -208,6 +213,7 @@ impl CustomerUpdateInternal {
             connector_customer,
             address_id,
             default_payment_method_id,
+            recipient_kyc_status,
             ..
         } = self;
 
@@ -224,6 +230,7 @@ impl CustomerUpdateInternal {
             default_payment_method_id: default_payment_method_id
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
+            recipient_kyc_status: recipient_kyc_status.map_or(source.recipient_kyc_status, Some),
             ..source
         }
     }
@@ -248,6 +255,7 @@ pub struct CustomerUpdateInternal {
     pub default_billing_address: Option<Encryption>,
     pub default_shipping_address: Option<Encryption>,
     pub status: Option<DeleteStatus>,
+    pub recipient_kyc_status: Option<RecipientKycStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -265,6 +273,7 @@ impl CustomerUpdateInternal {
             default_billing_address,
             default_shipping_address,
             status,
+            recipient_kyc_status,
             ..
         } = self;
 
@@ -285,6 +294,7 @@ impl CustomerUpdateInternal {
             default_shipping_address: default_shipping_address
                 .map_or(source.default_shipping_address, Some),
             status: status.unwrap_or(source.status),
+            recipient_kyc_status: recipient_kyc_status.map_or(source.recipient_kyc_status, Some),
             ..source
         }
     }
@@ -0,0 +1,87 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::email_template};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = email_template, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct EmailTemplate {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub email_type: storage_enums::EmailTemplateType,
+    pub locale: String,
+    pub subject: String,
+    pub body: Option<String>,
+    pub logo_url: Option<String>,
+    pub primary_color: Option<String>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = email_template)]
+pub struct EmailTemplateNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub email_type: storage_enums::EmailTemplateType,
+    pub locale: String,
+    pub subject: String,
+    pub body: Option<String>,
+    pub logo_url: Option<String>,
+    pub primary_color: Option<String>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum EmailTemplateUpdate {
+    Update {
+        subject: Option<String>,
+        body: Option<String>,
+        logo_url: Option<String>,
+        primary_color: Option<String>,
+        is_active: Option<bool>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = email_template)]
+pub struct EmailTemplateUpdateInternal {
+    pub subject: Option<String>,
+    pub body: Option<String>,
+    pub logo_url: Option<String>,
+    pub primary_color: Option<String>,
+    pub is_active: Option<bool>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<EmailTemplateUpdate> for EmailTemplateUpdateInternal {
+    fn from(email_template_update: EmailTemplateUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match email_template_update {
+            EmailTemplateUpdate::Update {
+                subject,
+                body,
+                logo_url,
+                primary_color,
+                is_active,
+            } => Self {
+                subject,
+                body,
+                logo_url,
+                primary_color,
+                is_active,
+                modified_at,
+            },
+        }
+    }
+}
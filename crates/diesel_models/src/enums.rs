@@ -73,6 +73,8 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PaymentMethodDetails,
+    ConnectorCredentialsExpiryDetails,
 }
 
 #[derive(
@@ -10,6 +10,8 @@ pub enum DatabaseError {
     NoFieldsToUpdate,
     #[error("An error occurred when generating typed SQL query")]
     QueryGenerationFailed,
+    #[error("A serialization failure or deadlock occurred with a concurrent transaction")]
+    SerializationFailure,
     // InsertFailed,
     #[error("An unknown error occurred")]
     Others,
@@ -24,7 +26,34 @@ impl From<diesel::result::Error> for DatabaseError {
             ) => Self::UniqueViolation,
             diesel::result::Error::NotFound => Self::NotFound,
             diesel::result::Error::QueryBuilderError(_) => Self::QueryGenerationFailed,
+            error if is_serialization_failure(&error) => Self::SerializationFailure,
             _ => Self::Others,
         }
     }
 }
+
+impl DatabaseError {
+    /// The error of a failed write, distinguishing the conflicts with concurrent transactions,
+    /// which may succeed when the write is retried
+    pub fn from_write_error(error: &diesel::result::Error) -> Self {
+        if is_serialization_failure(error) {
+            Self::SerializationFailure
+        } else {
+            Self::Others
+        }
+    }
+}
+
+fn is_serialization_failure(error: &diesel::result::Error) -> bool {
+    match error {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::SerializationFailure,
+            _,
+        ) => true,
+        // Deadlocks are not classified by diesel, and are identified by the message of PostgreSQL
+        diesel::result::Error::DatabaseError(_, information) => {
+            information.message().contains("deadlock detected")
+        }
+        _ => false,
+    }
+}
@@ -1,8 +1,10 @@
+use std::collections::HashSet;
+
 use common_utils::{
     consts,
     link_utils::{
-        EnabledPaymentMethod, GenericLinkStatus, GenericLinkUiConfig, PaymentMethodCollectStatus,
-        PayoutLinkData, PayoutLinkStatus,
+        CustomerPortalStatus, EnabledPaymentMethod, GenericLinkStatus, GenericLinkUiConfig,
+        PaymentMethodCollectStatus, PayoutLinkData, PayoutLinkStatus,
     },
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
@@ -104,6 +106,7 @@ impl Default for GenericLinkNew {
 pub enum GenericLinkData {
     PaymentMethodCollect(PaymentMethodCollectLinkData),
     PayoutLink(PayoutLinkData),
+    CustomerPortal(CustomerPortalLinkData),
 }
 
 impl GenericLinkData {
@@ -149,6 +152,7 @@ pub struct PaymentMethodCollectLinkData {
     #[serde(flatten)]
     pub ui_config: GenericLinkUiConfig,
     pub enabled_payment_methods: Option<Vec<EnabledPaymentMethod>>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 #[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
@@ -171,6 +175,49 @@ pub struct PayoutLink {
     pub return_url: Option<String>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomerPortalLink {
+    pub link_id: String,
+    pub primary_reference: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expiry: PrimitiveDateTime,
+    pub link_data: CustomerPortalLinkData,
+    pub link_status: CustomerPortalStatus,
+    pub link_type: storage_enums::GenericLinkType,
+    pub url: Secret<String>,
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerPortalLinkData {
+    pub customer_portal_link_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub link: Secret<String>,
+    pub client_secret: Secret<String>,
+    pub session_expiry: u32,
+    #[serde(flatten)]
+    pub ui_config: GenericLinkUiConfig,
+    pub allowed_domains: HashSet<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CustomerPortalLinkUpdate {
+    StatusUpdate { link_status: CustomerPortalStatus },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PaymentMethodCollectLinkUpdate {
+    StatusUpdate {
+        link_status: PaymentMethodCollectStatus,
+    },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PayoutLinkUpdate {
     StatusUpdate { link_status: PayoutLinkStatus },
@@ -191,3 +238,23 @@ impl From<PayoutLinkUpdate> for GenericLinkUpdateInternal {
         }
     }
 }
+
+impl From<PaymentMethodCollectLinkUpdate> for GenericLinkUpdateInternal {
+    fn from(generic_link_update: PaymentMethodCollectLinkUpdate) -> Self {
+        match generic_link_update {
+            PaymentMethodCollectLinkUpdate::StatusUpdate { link_status } => Self {
+                link_status: Some(GenericLinkStatus::PaymentMethodCollect(link_status)),
+            },
+        }
+    }
+}
+
+impl From<CustomerPortalLinkUpdate> for GenericLinkUpdateInternal {
+    fn from(generic_link_update: CustomerPortalLinkUpdate) -> Self {
+        match generic_link_update {
+            CustomerPortalLinkUpdate::StatusUpdate { link_status } => Self {
+                link_status: Some(GenericLinkStatus::CustomerPortal(link_status)),
+            },
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::installment_plan};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = installment_plan, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct InstallmentPlan {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub mandate_id: String,
+    pub status: storage_enums::InstallmentPlanStatus,
+    pub currency: storage_enums::Currency,
+    pub total_amount: MinorUnit,
+    pub installment_count: i32,
+    pub interval_days: i32,
+    pub installments: InstallmentSchedule,
+    pub max_retries_per_installment: i32,
+    pub retry_interval_hours: i32,
+    pub max_missed_installments: i32,
+    pub missed_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_charge_at: Option<PrimitiveDateTime>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = installment_plan)]
+pub struct InstallmentPlanNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub mandate_id: String,
+    pub status: storage_enums::InstallmentPlanStatus,
+    pub currency: storage_enums::Currency,
+    pub total_amount: MinorUnit,
+    pub installment_count: i32,
+    pub interval_days: i32,
+    pub installments: InstallmentSchedule,
+    pub max_retries_per_installment: i32,
+    pub retry_interval_hours: i32,
+    pub max_missed_installments: i32,
+    pub missed_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_charge_at: Option<PrimitiveDateTime>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A single installment of the plan, charged as a separate payment against the mandate
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Installment {
+    /// The position of the installment in the plan, starting from 1
+    pub installment_number: i32,
+    pub amount: MinorUnit,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_at: PrimitiveDateTime,
+    pub status: storage_enums::InstallmentStatus,
+    /// The number of payments attempted for the installment
+    pub attempt_count: i32,
+    /// The payment created by the latest attempt to charge the installment
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct InstallmentSchedule(pub Vec<Installment>);
+
+impl std::ops::Deref for InstallmentSchedule {
+    type Target = Vec<Installment>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for InstallmentSchedule {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(InstallmentSchedule);
+
+impl InstallmentSchedule {
+    /// The earliest installment which is yet to be charged
+    pub fn get_next_scheduled_installment(&self) -> Option<&Installment> {
+        self.iter()
+            .find(|installment| installment.status == storage_enums::InstallmentStatus::Scheduled)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum InstallmentPlanUpdate {
+    /// Records the outcome of charging an installment, along with the time at which the plan is
+    /// charged next
+    ScheduleUpdate {
+        status: storage_enums::InstallmentPlanStatus,
+        installments: InstallmentSchedule,
+        missed_count: i32,
+        next_charge_at: Option<PrimitiveDateTime>,
+    },
+    /// Stops the plan, no further installments are scheduled to be charged
+    StatusUpdate {
+        status: storage_enums::InstallmentPlanStatus,
+        installments: InstallmentSchedule,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = installment_plan)]
+pub struct InstallmentPlanUpdateInternal {
+    pub status: Option<storage_enums::InstallmentPlanStatus>,
+    pub installments: Option<InstallmentSchedule>,
+    pub missed_count: Option<i32>,
+    pub next_charge_at: Option<Option<PrimitiveDateTime>>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<InstallmentPlanUpdate> for InstallmentPlanUpdateInternal {
+    fn from(installment_plan_update: InstallmentPlanUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match installment_plan_update {
+            InstallmentPlanUpdate::ScheduleUpdate {
+                status,
+                installments,
+                missed_count,
+                next_charge_at,
+            } => Self {
+                status: Some(status),
+                installments: Some(installments),
+                missed_count: Some(missed_count),
+                next_charge_at: Some(next_charge_at),
+                modified_at,
+            },
+            InstallmentPlanUpdate::StatusUpdate {
+                status,
+                installments,
+            } => Self {
+                status: Some(status),
+                installments: Some(installments),
+                missed_count: None,
+                next_charge_at: Some(None),
+                modified_at,
+            },
+        }
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
     payouts::{Payouts, PayoutsNew, PayoutsUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    status_history::{StatusHistory, StatusHistoryNew},
     Mandate, MandateNew, MandateUpdateInternal, PaymentIntent, PaymentMethod, PaymentMethodNew,
     PaymentMethodUpdateInternal, PgPooledConn,
 };
@@ -46,6 +47,7 @@ impl DBOperation {
                 Insertable::ReverseLookUp(_) => "reverse_lookup",
                 Insertable::PaymentMethod(_) => "payment_method",
                 Insertable::Mandate(_) => "mandate",
+                Insertable::StatusHistory(_) => "status_history",
             },
             Self::Update { updatable } => match **updatable {
                 Updateable::PaymentIntentUpdate(_) => "payment_intent",
@@ -74,6 +76,7 @@ pub enum DBResult {
     PayoutAttempt(Box<PayoutAttempt>),
     PaymentMethod(Box<PaymentMethod>),
     Mandate(Box<Mandate>),
+    StatusHistory(Box<StatusHistory>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -108,6 +111,9 @@ impl DBOperation {
                     DBResult::PaymentMethod(Box::new(rev.insert(conn).await?))
                 }
                 Insertable::Mandate(m) => DBResult::Mandate(Box::new(m.insert(conn).await?)),
+                Insertable::StatusHistory(s) => {
+                    DBResult::StatusHistory(Box::new(s.insert(conn).await?))
+                }
             },
             Self::Update { updatable } => match *updatable {
                 #[cfg(feature = "v1")]
@@ -220,6 +226,7 @@ pub enum Insertable {
     PayoutAttempt(PayoutAttemptNew),
     PaymentMethod(PaymentMethodNew),
     Mandate(MandateNew),
+    StatusHistory(StatusHistoryNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,51 @@
+use common_utils::types::MinorUnit;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::ledger_entry};
+
+/// A debit or credit posted to an account of the ledger of a profile. The entries of a ledger
+/// transaction share its `transaction_id`, and their debits and credits balance.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = ledger_entry, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct LedgerEntry {
+    pub id: String,
+    pub transaction_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub account: storage_enums::LedgerAccount,
+    pub direction: storage_enums::LedgerEntryDirection,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub source_type: storage_enums::LedgerSourceType,
+    pub source_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = ledger_entry)]
+pub struct LedgerEntryNew {
+    pub id: String,
+    pub transaction_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub account: storage_enums::LedgerAccount,
+    pub direction: storage_enums::LedgerEntryDirection,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub source_type: storage_enums::LedgerSourceType,
+    pub source_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The net of the debits and credits posted to an account of the ledger of a profile in a currency
+#[derive(Clone, Debug)]
+pub struct LedgerAccountNet {
+    pub account: storage_enums::LedgerAccount,
+    pub currency: storage_enums::Currency,
+    /// The debits posted to the account less the credits posted to it
+    pub net_debit: i64,
+}
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod admin_api_key;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
@@ -12,6 +13,7 @@ pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod customers;
 pub mod dispute;
+pub mod email_template;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
@@ -21,8 +23,11 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod installment_plan;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
+pub mod link_delivery;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -32,7 +37,10 @@ pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
+pub mod payment_link_template;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_template;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
@@ -41,6 +49,10 @@ pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim_config;
+pub mod shared_payment_method;
+pub mod shipment_capture;
+pub mod status_history;
 pub mod types;
 pub mod unified_translations;
 
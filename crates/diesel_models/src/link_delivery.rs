@@ -0,0 +1,98 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::link_delivery};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = link_delivery, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct LinkDelivery {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub link_id: String,
+    pub link_type: storage_enums::DeliveredLinkType,
+    pub channel: storage_enums::MessagingChannel,
+    pub provider: String,
+    pub provider_message_id: Option<String>,
+    pub masked_recipient: String,
+    pub status: storage_enums::LinkDeliveryStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = link_delivery)]
+pub struct LinkDeliveryNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub link_id: String,
+    pub link_type: storage_enums::DeliveredLinkType,
+    pub channel: storage_enums::MessagingChannel,
+    pub provider: String,
+    pub provider_message_id: Option<String>,
+    pub masked_recipient: String,
+    pub status: storage_enums::LinkDeliveryStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum LinkDeliveryUpdate {
+    MessageSent {
+        provider_message_id: String,
+        status: storage_enums::LinkDeliveryStatus,
+    },
+    StatusUpdate {
+        status: storage_enums::LinkDeliveryStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = link_delivery)]
+pub struct LinkDeliveryUpdateInternal {
+    pub provider_message_id: Option<String>,
+    pub status: Option<storage_enums::LinkDeliveryStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<LinkDeliveryUpdate> for LinkDeliveryUpdateInternal {
+    fn from(link_delivery_update: LinkDeliveryUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match link_delivery_update {
+            LinkDeliveryUpdate::MessageSent {
+                provider_message_id,
+                status,
+            } => Self {
+                provider_message_id: Some(provider_message_id),
+                status: Some(status),
+                modified_at,
+                ..Default::default()
+            },
+            LinkDeliveryUpdate::StatusUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
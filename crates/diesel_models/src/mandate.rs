@@ -35,6 +35,7 @@ pub struct Mandate {
     pub original_payment_id: Option<common_utils::id_type::PaymentId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub updated_by: Option<String>,
+    pub signed_mandate_file_id: Option<String>,
 }
 
 #[derive(
@@ -73,6 +74,7 @@ pub struct MandateNew {
     pub original_payment_id: Option<common_utils::id_type::PaymentId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub updated_by: Option<String>,
+    pub signed_mandate_file_id: Option<String>,
 }
 
 impl MandateNew {
@@ -234,6 +236,7 @@ impl From<&MandateNew> for Mandate {
             original_payment_id: mandate_new.original_payment_id.clone(),
             merchant_connector_id: mandate_new.merchant_connector_id.clone(),
             updated_by: mandate_new.updated_by.clone(),
+            signed_mandate_file_id: mandate_new.signed_mandate_file_id.clone(),
         }
     }
 }
@@ -51,6 +51,10 @@ pub struct MerchantAccount {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub id_prefixes: Option<serde_json::Value>,
+    pub data_retention_policy: Option<serde_json::Value>,
+    pub previous_publishable_key: Option<String>,
+    pub previous_publishable_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -82,6 +86,10 @@ pub struct MerchantAccountSetter {
     pub recon_status: storage_enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
+    pub id_prefixes: Option<serde_json::Value>,
+    pub data_retention_policy: Option<serde_json::Value>,
+    pub previous_publishable_key: Option<String>,
+    pub previous_publishable_key_expires_at: Option<time::PrimitiveDateTime>,
     pub version: common_enums::ApiVersion,
 }
 
@@ -116,6 +124,10 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             recon_status: item.recon_status,
             payment_link_config: item.payment_link_config,
             pm_collect_link_config: item.pm_collect_link_config,
+            id_prefixes: item.id_prefixes,
+            data_retention_policy: item.data_retention_policy,
+            previous_publishable_key: item.previous_publishable_key,
+            previous_publishable_key_expires_at: item.previous_publishable_key_expires_at,
             version: item.version,
         }
     }
@@ -195,6 +207,22 @@ impl MerchantAccount {
     pub fn get_id(&self) -> &common_utils::id_type::MerchantId {
         &self.id
     }
+
+    #[cfg(feature = "v1")]
+    /// Check whether the publishable key is the current publishable key of the merchant, or the
+    /// previous one whose overlap window after a key rotation has not yet elapsed
+    pub fn is_publishable_key_valid(&self, publishable_key: &str) -> bool {
+        self.publishable_key.as_deref() == Some(publishable_key)
+            || (self.previous_publishable_key.as_deref() == Some(publishable_key)
+                && self
+                    .previous_publishable_key_expires_at
+                    .is_some_and(|expires_at| expires_at > common_utils::date_time::now()))
+    }
+
+    #[cfg(feature = "v2")]
+    pub fn is_publishable_key_valid(&self, publishable_key: &str) -> bool {
+        self.publishable_key.as_deref() == Some(publishable_key)
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -227,6 +255,10 @@ pub struct MerchantAccountNew {
     pub recon_status: storage_enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
+    pub id_prefixes: Option<serde_json::Value>,
+    pub data_retention_policy: Option<serde_json::Value>,
+    pub previous_publishable_key: Option<String>,
+    pub previous_publishable_key_expires_at: Option<time::PrimitiveDateTime>,
     pub version: common_enums::ApiVersion,
 }
 
@@ -319,6 +351,10 @@ pub struct MerchantAccountUpdateInternal {
     pub recon_status: Option<storage_enums::ReconStatus>,
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
+    pub id_prefixes: Option<serde_json::Value>,
+    pub data_retention_policy: Option<serde_json::Value>,
+    pub previous_publishable_key: Option<String>,
+    pub previous_publishable_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -350,6 +386,10 @@ impl MerchantAccountUpdateInternal {
             recon_status,
             payment_link_config,
             pm_collect_link_config,
+            id_prefixes,
+            data_retention_policy,
+            previous_publishable_key,
+            previous_publishable_key_expires_at,
         } = self;
 
         MerchantAccount {
@@ -384,6 +424,11 @@ impl MerchantAccountUpdateInternal {
             recon_status: recon_status.unwrap_or(source.recon_status),
             payment_link_config: payment_link_config.or(source.payment_link_config),
             pm_collect_link_config: pm_collect_link_config.or(source.pm_collect_link_config),
+            id_prefixes: id_prefixes.or(source.id_prefixes),
+            data_retention_policy: data_retention_policy.or(source.data_retention_policy),
+            previous_publishable_key: previous_publishable_key.or(source.previous_publishable_key),
+            previous_publishable_key_expires_at: previous_publishable_key_expires_at
+                .or(source.previous_publishable_key_expires_at),
             version: source.version,
         }
     }
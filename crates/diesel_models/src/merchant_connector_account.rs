@@ -2,6 +2,7 @@ use std::fmt::Debug;
 
 use common_utils::{encryption::Encryption, id_type, pii};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::{Duration, PrimitiveDateTime};
 
 use crate::enums as storage_enums;
 #[cfg(feature = "v1")]
@@ -46,6 +47,8 @@ pub struct MerchantConnectorAccount {
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: storage_enums::ConnectorStatus,
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
@@ -90,6 +93,8 @@ pub struct MerchantConnectorAccount {
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: storage_enums::ConnectorStatus,
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
@@ -131,6 +136,8 @@ pub struct MerchantConnectorAccountNew {
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: storage_enums::ConnectorStatus,
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
@@ -158,6 +165,8 @@ pub struct MerchantConnectorAccountNew {
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: storage_enums::ConnectorStatus,
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
@@ -186,6 +195,8 @@ pub struct MerchantConnectorAccountUpdateInternal {
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
@@ -208,6 +219,8 @@ pub struct MerchantConnectorAccountUpdateInternal {
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
@@ -234,6 +247,8 @@ impl MerchantConnectorAccountUpdateInternal {
             frm_config: self.frm_config,
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
+            tags: self.tags.or(source.tags),
+            credentials_expiry: self.credentials_expiry.or(source.credentials_expiry),
             status: self.status.unwrap_or(source.status),
 
             ..source
@@ -257,9 +272,62 @@ impl MerchantConnectorAccountUpdateInternal {
             frm_config: self.frm_config,
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
+            tags: self.tags.or(source.tags),
+            credentials_expiry: self.credentials_expiry.or(source.credentials_expiry),
             status: self.status.unwrap_or(source.status),
 
             ..source
         }
     }
 }
+
+/// The expiry of the credentials of a connector account, which have to be renewed with the
+/// connector before they expire
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ConnectorCredentialsExpiry {
+    pub credentials: Vec<ConnectorCredentialExpiry>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorCredentialExpiry {
+    pub credential_type: common_enums::ConnectorCredentialType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    pub description: Option<String>,
+}
+
+impl ConnectorCredentialExpiry {
+    /// The number of whole days left until the credential expires
+    pub fn get_days_to_expiry(&self, now: PrimitiveDateTime) -> i64 {
+        (self.expires_at - now).whole_days()
+    }
+
+    pub fn is_expired(&self, now: PrimitiveDateTime) -> bool {
+        self.expires_at <= now
+    }
+}
+
+impl ConnectorCredentialsExpiry {
+    /// The credentials which have not expired yet, and expire within the given number of days
+    pub fn get_credentials_expiring_within(
+        &self,
+        now: PrimitiveDateTime,
+        days: i64,
+    ) -> Vec<&ConnectorCredentialExpiry> {
+        self.credentials
+            .iter()
+            .filter(|credential| {
+                !credential.is_expired(now) && credential.expires_at <= now + Duration::days(days)
+            })
+            .collect()
+    }
+
+    pub fn has_unexpired_credentials(&self, now: PrimitiveDateTime) -> bool {
+        self.credentials
+            .iter()
+            .any(|credential| !credential.is_expired(now))
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(ConnectorCredentialsExpiry);
@@ -28,6 +28,7 @@ pub struct Organization {
     id: Option<id_type::OrganizationId>,
     #[allow(dead_code)]
     organization_name: Option<String>,
+    pub is_token_sharing_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -44,6 +45,7 @@ pub struct Organization {
     pub modified_at: time::PrimitiveDateTime,
     id: id_type::OrganizationId,
     organization_name: Option<String>,
+    pub is_token_sharing_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -58,6 +60,7 @@ impl Organization {
             modified_at,
             id: _,
             organization_name: _,
+            is_token_sharing_enabled,
         } = org_new;
         Self {
             id: Some(org_id.clone()),
@@ -68,6 +71,7 @@ impl Organization {
             metadata,
             created_at,
             modified_at,
+            is_token_sharing_enabled,
         }
     }
 }
@@ -82,6 +86,7 @@ impl Organization {
             metadata,
             created_at,
             modified_at,
+            is_token_sharing_enabled,
         } = org_new;
         Self {
             id,
@@ -90,6 +95,7 @@ impl Organization {
             metadata,
             created_at,
             modified_at,
+            is_token_sharing_enabled,
         }
     }
 }
@@ -106,6 +112,7 @@ pub struct OrganizationNew {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
+    pub is_token_sharing_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -118,6 +125,7 @@ pub struct OrganizationNew {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
+    pub is_token_sharing_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -132,6 +140,7 @@ impl OrganizationNew {
             metadata: None,
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
+            is_token_sharing_enabled: None,
         }
     }
 }
@@ -146,6 +155,7 @@ impl OrganizationNew {
             metadata: None,
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
+            is_token_sharing_enabled: None,
         }
     }
 }
@@ -159,6 +169,7 @@ pub struct OrganizationUpdateInternal {
     organization_details: Option<pii::SecretSerdeValue>,
     metadata: Option<pii::SecretSerdeValue>,
     modified_at: time::PrimitiveDateTime,
+    is_token_sharing_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -169,6 +180,7 @@ pub struct OrganizationUpdateInternal {
     organization_details: Option<pii::SecretSerdeValue>,
     metadata: Option<pii::SecretSerdeValue>,
     modified_at: time::PrimitiveDateTime,
+    is_token_sharing_enabled: Option<bool>,
 }
 
 pub enum OrganizationUpdate {
@@ -176,6 +188,7 @@ pub enum OrganizationUpdate {
         organization_name: Option<String>,
         organization_details: Option<pii::SecretSerdeValue>,
         metadata: Option<pii::SecretSerdeValue>,
+        is_token_sharing_enabled: Option<bool>,
     },
 }
 
@@ -187,12 +200,14 @@ impl From<OrganizationUpdate> for OrganizationUpdateInternal {
                 organization_name,
                 organization_details,
                 metadata,
+                is_token_sharing_enabled,
             } => Self {
                 org_name: organization_name.clone(),
                 organization_name,
                 organization_details,
                 metadata,
                 modified_at: common_utils::date_time::now(),
+                is_token_sharing_enabled,
             },
         }
     }
@@ -206,11 +221,13 @@ impl From<OrganizationUpdate> for OrganizationUpdateInternal {
                 organization_name,
                 organization_details,
                 metadata,
+                is_token_sharing_enabled,
             } => Self {
                 organization_name,
                 organization_details,
                 metadata,
                 modified_at: common_utils::date_time::now(),
+                is_token_sharing_enabled,
             },
         }
     }
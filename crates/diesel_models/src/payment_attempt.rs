@@ -30,6 +30,63 @@ impl ConnectorMandateReferenceId {
     }
 }
 
+common_utils::impl_to_sql_from_sql_json!(ConnectorResponseCodes);
+
+/// Response codes of the connector as received, before they are mapped to unified codes
+#[derive(
+    Clone, Debug, Default, serde::Deserialize, serde::Serialize, Eq, PartialEq, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ConnectorResponseCodes {
+    pub decline_code: Option<String>,
+    pub avs_result: Option<String>,
+    pub cvv_result: Option<String>,
+    pub acquirer_response_code: Option<String>,
+}
+
+impl ConnectorResponseCodes {
+    /// The AVS result of the connector, normalized across connectors
+    pub fn get_avs_result(&self) -> Option<storage_enums::AvsResult> {
+        self.avs_result
+            .as_deref()
+            .map(storage_enums::AvsResult::from_network_code)
+    }
+
+    /// The CVV result of the connector, normalized across connectors
+    pub fn get_cvv_result(&self) -> Option<storage_enums::CvvResult> {
+        self.cvv_result
+            .as_deref()
+            .map(storage_enums::CvvResult::from_network_code)
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(DccDetails);
+
+/// The dynamic currency conversion offered to the customer for an attempt, and the choice of the
+/// customer. The offer is applied at authorization only if it was accepted by the customer and the
+/// connector of the attempt supports dynamic currency conversion.
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, Eq, PartialEq, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct DccDetails {
+    pub offer_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub presentment_amount: MinorUnit,
+    pub presentment_currency: storage_enums::Currency,
+    /// The exchange rate from the currency of the payment to the presentment currency at the forex
+    /// rates, before the markup
+    pub base_exchange_rate: String,
+    pub markup_in_basis_points: u16,
+    /// The exchange rate offered to the customer, which includes the markup
+    pub exchange_rate: String,
+    pub accepted: bool,
+    pub applied: bool,
+    pub offered_at: PrimitiveDateTime,
+    pub decided_at: PrimitiveDateTime,
+}
+
 #[cfg(feature = "v2")]
 #[derive(
     Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable,
@@ -172,6 +229,16 @@ pub struct PaymentAttempt {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub connector_response_codes: Option<ConnectorResponseCodes>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub exchange_rate: Option<String>,
+    pub dcc_details: Option<DccDetails>,
+    /// Incremented on every update of the attempt, to detect concurrent updates of the attempt
+    #[serde(default)]
+    pub update_version: i32,
 }
 
 #[cfg(feature = "v1")]
@@ -351,10 +418,14 @@ pub struct PaymentAttemptNew {
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub exchange_rate: Option<String>,
 }
 
 #[cfg(feature = "v1")]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum PaymentAttemptUpdate {
     Update {
         amount: MinorUnit,
@@ -423,6 +494,10 @@ pub enum PaymentAttemptUpdate {
         shipping_cost: Option<MinorUnit>,
         order_tax_amount: Option<MinorUnit>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+        presentment_currency: Option<storage_enums::Currency>,
+        presentment_amount: Option<MinorUnit>,
+        exchange_rate: Option<String>,
+        dcc_details: Option<DccDetails>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -471,6 +546,11 @@ pub enum PaymentAttemptUpdate {
         payment_method_data: Option<serde_json::Value>,
         charge_id: Option<String>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+        connector_response_codes: Option<ConnectorResponseCodes>,
+        avs_result: Option<storage_enums::AvsResult>,
+        cvv_result: Option<storage_enums::CvvResult>,
+        presentment_amount: Option<MinorUnit>,
+        exchange_rate: Option<String>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -500,6 +580,7 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        connector_response_codes: Option<ConnectorResponseCodes>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -848,6 +929,14 @@ pub struct PaymentAttemptUpdateInternal {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub connector_response_codes: Option<ConnectorResponseCodes>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub exchange_rate: Option<String>,
+    pub dcc_details: Option<DccDetails>,
+    pub update_version: Option<i32>,
 }
 
 #[cfg(feature = "v1")]
@@ -873,6 +962,7 @@ impl PaymentAttemptUpdateInternal {
                     .or(source.order_tax_amount)
                     .unwrap_or(MinorUnit::new(0)),
         );
+        update_internal.update_version = Some(source.update_version + 1);
         update_internal.card_network = update_internal
             .payment_method_data
             .as_ref()
@@ -1031,6 +1121,14 @@ impl PaymentAttemptUpdate {
             order_tax_amount,
             connector_transaction_data,
             connector_mandate_detail,
+            connector_response_codes,
+            avs_result,
+            cvv_result,
+            presentment_currency,
+            presentment_amount,
+            exchange_rate,
+            dcc_details,
+            update_version,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1089,6 +1187,14 @@ impl PaymentAttemptUpdate {
             connector_transaction_data: connector_transaction_data
                 .or(source.connector_transaction_data),
             connector_mandate_detail: connector_mandate_detail.or(source.connector_mandate_detail),
+            connector_response_codes: connector_response_codes.or(source.connector_response_codes),
+            avs_result: avs_result.or(source.avs_result),
+            cvv_result: cvv_result.or(source.cvv_result),
+            presentment_currency: presentment_currency.or(source.presentment_currency),
+            presentment_amount: presentment_amount.or(source.presentment_amount),
+            exchange_rate: exchange_rate.or(source.exchange_rate),
+            dcc_details: dcc_details.or(source.dcc_details),
+            update_version: update_version.unwrap_or(source.update_version),
             ..source
         }
     }
@@ -2141,6 +2247,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2197,6 +2311,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2232,6 +2354,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 shipping_cost,
                 order_tax_amount,
                 connector_mandate_detail,
+                presentment_currency,
+                presentment_amount,
+                exchange_rate,
+                dcc_details,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2284,6 +2410,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount,
                 connector_transaction_data: None,
                 connector_mandate_detail,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency,
+                presentment_amount,
+                exchange_rate,
+                dcc_details,
+                update_version: None,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2341,6 +2475,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2399,6 +2541,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2457,6 +2607,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -2513,6 +2671,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2569,6 +2735,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2592,6 +2766,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                connector_response_codes,
+                avs_result,
+                cvv_result,
+                presentment_amount,
+                exchange_rate,
             } => {
                 let (connector_transaction_id, connector_transaction_data) =
                     connector_transaction_id
@@ -2650,6 +2829,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail,
+                    connector_response_codes,
+                    avs_result,
+                    cvv_result,
+                    presentment_currency: None,
+                    presentment_amount,
+                    exchange_rate,
+                    dcc_details: None,
+                    update_version: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -2665,6 +2852,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_response_codes,
             } => {
                 let (connector_transaction_id, connector_transaction_data) =
                     connector_transaction_id
@@ -2723,6 +2911,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    connector_response_codes,
+                    avs_result: None,
+                    cvv_result: None,
+                    presentment_currency: None,
+                    presentment_amount: None,
+                    exchange_rate: None,
+                    dcc_details: None,
+                    update_version: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -2777,6 +2973,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -2839,6 +3043,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -2908,6 +3120,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    connector_response_codes: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    presentment_currency: None,
+                    presentment_amount: None,
+                    exchange_rate: None,
+                    dcc_details: None,
+                    update_version: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -2976,6 +3196,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    connector_response_codes: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    presentment_currency: None,
+                    presentment_amount: None,
+                    exchange_rate: None,
+                    dcc_details: None,
+                    update_version: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3034,6 +3262,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3091,6 +3327,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3157,6 +3401,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    connector_response_codes: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    presentment_currency: None,
+                    presentment_amount: None,
+                    exchange_rate: None,
+                    dcc_details: None,
+                    update_version: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3214,6 +3466,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3273,6 +3533,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3341,6 +3609,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    connector_response_codes: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    presentment_currency: None,
+                    presentment_amount: None,
+                    exchange_rate: None,
+                    dcc_details: None,
+                    update_version: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -3398,6 +3674,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                connector_response_codes: None,
+                avs_result: None,
+                cvv_result: None,
+                presentment_currency: None,
+                presentment_amount: None,
+                exchange_rate: None,
+                dcc_details: None,
+                update_version: None,
             },
         }
     }
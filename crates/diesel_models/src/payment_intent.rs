@@ -140,6 +140,24 @@ pub struct PaymentIntent {
     pub psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
 }
 
+/// Classes of data stored on payments, which are purged once the retention period configured by
+/// the merchant for the class has elapsed
+#[derive(Clone, Copy, Debug)]
+pub enum PaymentDataClass {
+    Pii,
+    Metadata,
+}
+
+impl PaymentDataClass {
+    /// The columns of the `payment_intent` table which hold data of the class
+    pub fn get_columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Pii => &["customer_details", "billing_details", "shipping_details"],
+            Self::Metadata => &["metadata", "order_details", "frm_metadata"],
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq)]
 pub struct PaymentLinkConfigRequestForPayments {
     /// custom theme for the payment link
@@ -376,7 +394,8 @@ pub enum PaymentIntentUpdate {
 }
 
 #[cfg(feature = "v1")]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum PaymentIntentUpdate {
     ResponseUpdate {
         status: storage_enums::IntentStatus,
@@ -455,6 +474,14 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryption>,
     },
+    SessionExpiryUpdate {
+        session_expiry: PrimitiveDateTime,
+        updated_by: String,
+    },
+    ClientSecretUpdate {
+        client_secret: String,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -587,6 +614,7 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<TaxDetails>,
+    pub client_secret: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -689,6 +717,7 @@ impl PaymentIntentUpdate {
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
+            client_secret,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -734,6 +763,7 @@ impl PaymentIntentUpdate {
             is_payment_processor_token_flow: is_payment_processor_token_flow
                 .or(source.is_payment_processor_token_flow),
             tax_details: tax_details.or(source.tax_details),
+            client_secret: client_secret.or(source.client_secret),
             ..source
         }
     }
@@ -807,6 +837,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
@@ -846,6 +877,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 authorization_count: None,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
                 return_url,
@@ -892,6 +924,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::PGStatusUpdate {
                 status,
@@ -934,6 +967,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -977,6 +1011,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::ResponseUpdate {
                 // amount,
@@ -1028,6 +1063,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -1070,6 +1106,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::StatusAndAttemptUpdate {
                 status,
@@ -1113,6 +1150,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::ApproveUpdate {
                 status,
@@ -1155,6 +1193,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::RejectUpdate {
                 status,
@@ -1197,6 +1236,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::SurchargeApplicableUpdate {
                 surcharge_applicable,
@@ -1238,6 +1278,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
                 amount: Some(amount),
@@ -1276,6 +1317,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
@@ -1316,6 +1358,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1356,6 +1399,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => Self {
                 status,
@@ -1394,6 +1438,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                client_secret: None,
             },
             PaymentIntentUpdate::SessionResponseUpdate {
                 tax_details,
@@ -1404,6 +1449,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_address_id,
                 amount: None,
                 tax_details: Some(tax_details),
+                client_secret: None,
                 currency: None,
                 status: None,
                 amount_captured: None,
@@ -1438,6 +1484,90 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 is_payment_processor_token_flow: None,
             },
+            PaymentIntentUpdate::SessionExpiryUpdate {
+                session_expiry,
+                updated_by,
+            } => Self {
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                updated_by,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: Some(session_expiry),
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                client_secret: None,
+            },
+            PaymentIntentUpdate::ClientSecretUpdate {
+                client_secret,
+                updated_by,
+            } => Self {
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                updated_by,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                client_secret: Some(client_secret),
+            },
         }
     }
 }
@@ -1,5 +1,5 @@
 use common_utils::types::MinorUnit;
-use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -25,6 +25,10 @@ pub struct PaymentLink {
     pub description: Option<String>,
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub secure_link: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub deactivated_at: Option<PrimitiveDateTime>,
+    pub link_token: Option<String>,
+    pub payment_link_template_id: Option<String>,
 }
 
 #[derive(
@@ -56,4 +60,79 @@ pub struct PaymentLinkNew {
     pub description: Option<String>,
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub secure_link: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub deactivated_at: Option<PrimitiveDateTime>,
+    pub link_token: Option<String>,
+    pub payment_link_template_id: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub enum PaymentLinkUpdate {
+    ExpiryUpdate {
+        fulfilment_time: PrimitiveDateTime,
+    },
+    Deactivate {
+        deactivated_at: PrimitiveDateTime,
+        fulfilment_time: PrimitiveDateTime,
+    },
+    LinkRegenerate {
+        link_to_pay: String,
+        secure_link: Option<String>,
+        link_token: String,
+    },
+    TemplateIdUpdate {
+        payment_link_template_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    pub last_modified_at: Option<PrimitiveDateTime>,
+    pub fulfilment_time: Option<PrimitiveDateTime>,
+    pub deactivated_at: Option<PrimitiveDateTime>,
+    pub link_to_pay: Option<String>,
+    pub secure_link: Option<String>,
+    pub link_token: Option<String>,
+    pub payment_link_template_id: Option<String>,
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        let last_modified_at = Some(common_utils::date_time::now());
+        match payment_link_update {
+            PaymentLinkUpdate::ExpiryUpdate { fulfilment_time } => Self {
+                last_modified_at,
+                fulfilment_time: Some(fulfilment_time),
+                ..Default::default()
+            },
+            PaymentLinkUpdate::Deactivate {
+                deactivated_at,
+                fulfilment_time,
+            } => Self {
+                last_modified_at,
+                fulfilment_time: Some(fulfilment_time),
+                deactivated_at: Some(deactivated_at),
+                ..Default::default()
+            },
+            PaymentLinkUpdate::LinkRegenerate {
+                link_to_pay,
+                secure_link,
+                link_token,
+            } => Self {
+                last_modified_at,
+                link_to_pay: Some(link_to_pay),
+                secure_link,
+                link_token: Some(link_token),
+                ..Default::default()
+            },
+            PaymentLinkUpdate::TemplateIdUpdate {
+                payment_link_template_id,
+            } => Self {
+                last_modified_at,
+                payment_link_template_id: Some(payment_link_template_id),
+                ..Default::default()
+            },
+        }
+    }
 }
@@ -0,0 +1,106 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_link_template};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = payment_link_template, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentLinkTemplate {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub link_to_pay: String,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub session_expiry: Option<i32>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = payment_link_template)]
+pub struct PaymentLinkTemplateNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub link_to_pay: String,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub session_expiry: Option<i32>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum PaymentLinkTemplateUpdate {
+    Update {
+        description: Option<String>,
+        usage_limit: Option<i32>,
+        is_active: Option<bool>,
+        expires_at: Option<PrimitiveDateTime>,
+    },
+    UsageCountUpdate {
+        usage_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link_template)]
+pub struct PaymentLinkTemplateUpdateInternal {
+    pub description: Option<String>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: Option<i32>,
+    pub is_active: Option<bool>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentLinkTemplateUpdate> for PaymentLinkTemplateUpdateInternal {
+    fn from(payment_link_template_update: PaymentLinkTemplateUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match payment_link_template_update {
+            PaymentLinkTemplateUpdate::Update {
+                description,
+                usage_limit,
+                is_active,
+                expires_at,
+            } => Self {
+                description,
+                usage_limit,
+                is_active,
+                expires_at,
+                modified_at,
+                ..Default::default()
+            },
+            PaymentLinkTemplateUpdate::UsageCountUpdate { usage_count } => Self {
+                usage_count: Some(usage_count),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use common_utils::pii;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_method_consent_event};
+
+/// Evidence of the consent of a customer to store a payment method for future usage, recorded
+/// when the payment method is saved with `setup_future_usage`.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(
+    table_name = payment_method_consent_event,
+    primary_key(id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentMethodConsentEvent {
+    pub id: i32,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub payment_method_id: String,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub setup_future_usage: storage_enums::FutureUsage,
+    pub consent_text_version: Option<String>,
+    pub ip_address: Option<Secret<String, pii::IpAddress>>,
+    pub user_agent: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub accepted_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = payment_method_consent_event)]
+pub struct PaymentMethodConsentEventNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub payment_method_id: String,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub setup_future_usage: storage_enums::FutureUsage,
+    pub consent_text_version: Option<String>,
+    pub ip_address: Option<Secret<String, pii::IpAddress>>,
+    pub user_agent: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub accepted_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
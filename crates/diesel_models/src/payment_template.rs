@@ -0,0 +1,107 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_template};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = payment_template, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentTemplate {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub currency: Option<storage_enums::Currency>,
+    pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub metadata: Option<serde_json::Value>,
+    pub statement_descriptor_name: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    pub allowed_payment_method_types: Option<AllowedPaymentMethodTypes>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = payment_template)]
+pub struct PaymentTemplateNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub currency: Option<storage_enums::Currency>,
+    pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub metadata: Option<serde_json::Value>,
+    pub statement_descriptor_name: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    pub allowed_payment_method_types: Option<AllowedPaymentMethodTypes>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum PaymentTemplateUpdate {
+    Update {
+        name: Option<String>,
+        currency: Option<storage_enums::Currency>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        metadata: Option<serde_json::Value>,
+        statement_descriptor_name: Option<String>,
+        statement_descriptor_suffix: Option<String>,
+        allowed_payment_method_types: Option<AllowedPaymentMethodTypes>,
+        is_active: Option<bool>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_template)]
+pub struct PaymentTemplateUpdateInternal {
+    pub name: Option<String>,
+    pub currency: Option<storage_enums::Currency>,
+    pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub metadata: Option<serde_json::Value>,
+    pub statement_descriptor_name: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    pub allowed_payment_method_types: Option<AllowedPaymentMethodTypes>,
+    pub is_active: Option<bool>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentTemplateUpdate> for PaymentTemplateUpdateInternal {
+    fn from(payment_template_update: PaymentTemplateUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match payment_template_update {
+            PaymentTemplateUpdate::Update {
+                name,
+                currency,
+                capture_method,
+                metadata,
+                statement_descriptor_name,
+                statement_descriptor_suffix,
+                allowed_payment_method_types,
+                is_active,
+            } => Self {
+                name,
+                currency,
+                capture_method,
+                metadata,
+                statement_descriptor_name,
+                statement_descriptor_suffix,
+                allowed_payment_method_types,
+                is_active,
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct AllowedPaymentMethodTypes(pub Vec<storage_enums::PaymentMethodType>);
+
+common_utils::impl_to_sql_from_sql_json!(AllowedPaymentMethodTypes);
@@ -1,6 +1,6 @@
 use common_utils::{
     payout_method_utils,
-    types::{UnifiedCode, UnifiedMessage},
+    types::{MinorUnit, UnifiedCode, UnifiedMessage},
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{self, Deserialize, Serialize};
@@ -37,6 +37,15 @@ pub struct PayoutAttempt {
     pub unified_code: Option<UnifiedCode>,
     pub unified_message: Option<UnifiedMessage>,
     pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub account_name_match_result: Option<storage_enums::AccountNameMatchResult>,
+    /// The fee charged by the connector for the payout, in the currency of the payout
+    pub fee: Option<MinorUnit>,
+    /// The amount of the payout net of the fee
+    pub net_amount: Option<MinorUnit>,
+    pub fee_source: Option<storage_enums::PayoutFeeSource>,
+    /// Whether the payout is allowed by an admin to be fulfilled regardless of the balance rules of
+    /// the profile
+    pub balance_rules_overridden: Option<bool>,
 }
 
 #[derive(
@@ -78,7 +87,8 @@ pub struct PayoutAttemptNew {
     pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum PayoutAttemptUpdate {
     StatusUpdate {
         connector_payout_id: Option<String>,
@@ -106,6 +116,17 @@ pub enum PayoutAttemptUpdate {
     AdditionalPayoutMethodDataUpdate {
         additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
     },
+    AccountNameMatchResultUpdate {
+        account_name_match_result: storage_enums::AccountNameMatchResult,
+    },
+    FeeUpdate {
+        fee: MinorUnit,
+        net_amount: MinorUnit,
+        fee_source: storage_enums::PayoutFeeSource,
+    },
+    BalanceRulesOverrideUpdate {
+        balance_rules_overridden: bool,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -128,6 +149,11 @@ pub struct PayoutAttemptUpdateInternal {
     pub unified_code: Option<UnifiedCode>,
     pub unified_message: Option<UnifiedMessage>,
     pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub account_name_match_result: Option<storage_enums::AccountNameMatchResult>,
+    pub fee: Option<MinorUnit>,
+    pub net_amount: Option<MinorUnit>,
+    pub fee_source: Option<storage_enums::PayoutFeeSource>,
+    pub balance_rules_overridden: Option<bool>,
 }
 
 impl Default for PayoutAttemptUpdateInternal {
@@ -150,6 +176,11 @@ impl Default for PayoutAttemptUpdateInternal {
             unified_code: None,
             unified_message: None,
             additional_payout_method_data: None,
+            account_name_match_result: None,
+            fee: None,
+            net_amount: None,
+            fee_source: None,
+            balance_rules_overridden: None,
         }
     }
 }
@@ -207,6 +238,28 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 additional_payout_method_data,
                 ..Default::default()
             },
+            PayoutAttemptUpdate::AccountNameMatchResultUpdate {
+                account_name_match_result,
+            } => Self {
+                account_name_match_result: Some(account_name_match_result),
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::FeeUpdate {
+                fee,
+                net_amount,
+                fee_source,
+            } => Self {
+                fee: Some(fee),
+                net_amount: Some(net_amount),
+                fee_source: Some(fee_source),
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::BalanceRulesOverrideUpdate {
+                balance_rules_overridden,
+            } => Self {
+                balance_rules_overridden: Some(balance_rules_overridden),
+                ..Default::default()
+            },
         }
     }
 }
@@ -231,6 +284,11 @@ impl PayoutAttemptUpdate {
             unified_code,
             unified_message,
             additional_payout_method_data,
+            account_name_match_result,
+            fee,
+            net_amount,
+            fee_source,
+            balance_rules_overridden,
         } = self.into();
         PayoutAttempt {
             payout_token: payout_token.or(source.payout_token),
@@ -251,6 +309,12 @@ impl PayoutAttemptUpdate {
             unified_message: unified_message.or(source.unified_message),
             additional_payout_method_data: additional_payout_method_data
                 .or(source.additional_payout_method_data),
+            account_name_match_result: account_name_match_result
+                .or(source.account_name_match_result),
+            fee: fee.or(source.fee),
+            net_amount: net_amount.or(source.net_amount),
+            fee_source: fee_source.or(source.fee_source),
+            balance_rules_overridden: balance_rules_overridden.or(source.balance_rules_overridden),
             ..source
         }
     }
@@ -210,6 +210,17 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    LockerMigrationWorkflow,
+    PaymentArchivalWorkflow,
+    DataRetentionWorkflow,
+    PaymentsAutoCaptureWorkflow,
+    InstallmentPlanChargeWorkflow,
+    PaymentLinkExpiryWorkflow,
+    PayoutRecipientKycStatusWorkflow,
+    PayoutFulfillmentWorkflow,
+    PayoutLinkExpiryWorkflow,
+    ConnectorCredentialsExpiryWorkflow,
+    VoucherExpiryWorkflow,
 }
 
 #[cfg(test)]
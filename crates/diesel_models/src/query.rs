@@ -1,4 +1,5 @@
 pub mod address;
+pub mod admin_api_key;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
@@ -13,12 +14,16 @@ pub mod blocklist_fingerprint;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
+pub mod email_template;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod installment_plan;
+pub mod ledger_entry;
+pub mod link_delivery;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -28,7 +33,10 @@ pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
+pub mod payment_link_template;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_template;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
@@ -36,6 +44,10 @@ pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim_config;
+pub mod shared_payment_method;
+pub mod shipment_capture;
+pub mod status_history;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -0,0 +1,73 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    admin_api_key::{AdminApiKey, AdminApiKeyNew, AdminApiKeyUpdate, AdminApiKeyUpdateInternal},
+    api_keys::HashedApiKey,
+    schema::admin_api_keys::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AdminApiKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AdminApiKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AdminApiKey {
+    pub async fn find_by_key_id(conn: &PgPooledConn, key_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::key_id.eq(key_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_hashed_api_key(
+        conn: &PgPooledConn,
+        hashed_api_key: HashedApiKey,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::hashed_api_key.eq(hashed_api_key),
+        )
+        .await
+    }
+
+    pub async fn list(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::key_id.ne_all(vec!["".to_string()]),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_key_id(
+        conn: &PgPooledConn,
+        key_id: &str,
+        admin_api_key_update: AdminApiKeyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::key_id.eq(key_id.to_owned()),
+            AdminApiKeyUpdateInternal::from(admin_api_key_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_key_id(conn: &PgPooledConn, key_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::key_id.eq(key_id.to_owned()),
+        )
+        .await
+    }
+}
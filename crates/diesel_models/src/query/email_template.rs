@@ -0,0 +1,100 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    email_template::{
+        EmailTemplate, EmailTemplateNew, EmailTemplateUpdate, EmailTemplateUpdateInternal,
+    },
+    enums as storage_enums,
+    schema::email_template::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl EmailTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<EmailTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl EmailTemplate {
+    pub async fn find_by_profile_id_template_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_active_by_profile_id_email_type(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        email_type: storage_enums::EmailTemplateType,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::email_type.eq(email_type))
+                .and(dsl::is_active.eq(true)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_template_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+        email_template_update: EmailTemplateUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+            EmailTemplateUpdateInternal::from(email_template_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_profile_id_template_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+}
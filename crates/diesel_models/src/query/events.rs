@@ -1,9 +1,13 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
-    associations::HasTable, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods,
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, QueryDsl,
 };
+use error_stack::ResultExt;
 
 use super::generics;
 use crate::{
+    errors,
     events::{Event, EventNew, EventUpdateInternal},
     schema::events::dsl,
     PgPooledConn, StorageResult,
@@ -222,4 +226,51 @@ impl Event {
         )
         .await
     }
+
+    pub async fn delete_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(<Self as HasTable>::table()).filter(
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.lt(created_before)),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            generics::db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting events")
+    }
+
+    pub async fn get_count_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let filter = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::created_at.lt(created_before)),
+            )
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of events")
+    }
 }
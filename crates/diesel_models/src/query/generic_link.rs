@@ -1,13 +1,18 @@
-use common_utils::{errors, ext_traits::ValueExt, link_utils::GenericLinkStatus};
-use diesel::{associations::HasTable, ExpressionMethods};
+use common_utils::{
+    errors,
+    ext_traits::ValueExt,
+    link_utils::{GenericLinkStatus, PayoutLinkStatus},
+};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use error_stack::{report, Report, ResultExt};
 
 use super::generics;
 use crate::{
     errors as db_errors,
     generic_link::{
-        GenericLink, GenericLinkData, GenericLinkNew, GenericLinkState, GenericLinkUpdateInternal,
-        PaymentMethodCollectLink, PayoutLink, PayoutLinkUpdate,
+        CustomerPortalLink, CustomerPortalLinkUpdate, GenericLink, GenericLinkData, GenericLinkNew,
+        GenericLinkState, GenericLinkUpdateInternal, PaymentMethodCollectLink,
+        PaymentMethodCollectLinkUpdate, PayoutLink, PayoutLinkUpdate,
     },
     schema::generic_link::dsl,
     PgPooledConn, StorageResult,
@@ -46,6 +51,19 @@ impl GenericLinkNew {
                     .attach_printable("failed to parse payout link data from DB")
             })
     }
+
+    pub async fn insert_customer_portal_link(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<CustomerPortalLink> {
+        generics::generic_insert(conn, self)
+            .await
+            .and_then(|res: GenericLink| {
+                CustomerPortalLink::try_from(res)
+                    .change_context(db_errors::DatabaseError::Others)
+                    .attach_printable("failed to parse customer portal link data from DB")
+            })
+    }
 }
 
 impl GenericLink {
@@ -96,6 +114,51 @@ impl GenericLink {
                 .attach_printable("failed to parse payout link data from DB")
         })
     }
+
+    /// Payout links of the merchant which have not been submitted or invalidated, in the order of
+    /// their expiry
+    pub async fn find_initiated_payout_links_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> StorageResult<Vec<PayoutLink>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::link_type.eq(common_enums::GenericLinkType::PayoutLink))
+                .and(
+                    dsl::link_status.eq(GenericLinkStatus::PayoutLink(PayoutLinkStatus::Initiated)),
+                ),
+            Some(limit),
+            None,
+            Some(dsl::expiry.asc()),
+        )
+        .await?
+        .into_iter()
+        .map(|generic_link| {
+            PayoutLink::try_from(generic_link)
+                .change_context(db_errors::DatabaseError::Others)
+                .attach_printable("failed to parse payout link data from DB")
+        })
+        .collect()
+    }
+
+    pub async fn find_customer_portal_link_by_link_id(
+        conn: &PgPooledConn,
+        link_id: &str,
+    ) -> StorageResult<CustomerPortalLink> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::link_id.eq(link_id.to_owned()),
+        )
+        .await
+        .and_then(|res: Self| {
+            CustomerPortalLink::try_from(res)
+                .change_context(db_errors::DatabaseError::Others)
+                .attach_printable("failed to parse customer portal link data from DB")
+        })
+    }
 }
 
 impl PayoutLink {
@@ -122,6 +185,64 @@ impl PayoutLink {
     }
 }
 
+impl PaymentMethodCollectLink {
+    pub async fn update_pm_collect_link(
+        self,
+        conn: &PgPooledConn,
+        pm_collect_link_update: PaymentMethodCollectLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<GenericLink as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::link_id.eq(self.link_id.to_owned()),
+            GenericLinkUpdateInternal::from(pm_collect_link_update),
+        )
+        .await
+        .and_then(|mut generic_links: Vec<GenericLink>| {
+            generic_links
+                .pop()
+                .ok_or(error_stack::report!(db_errors::DatabaseError::NotFound))
+        })
+        .and_then(|res| {
+            Self::try_from(res)
+                .change_context(db_errors::DatabaseError::Others)
+                .attach_printable("failed to parse payment method collect link data from DB")
+        })
+        .or_else(|error| match error.current_context() {
+            db_errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+            _ => Err(error),
+        })
+    }
+}
+
+impl CustomerPortalLink {
+    pub async fn update_customer_portal_link(
+        self,
+        conn: &PgPooledConn,
+        customer_portal_link_update: CustomerPortalLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<GenericLink as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::link_id.eq(self.link_id.to_owned()),
+            GenericLinkUpdateInternal::from(customer_portal_link_update),
+        )
+        .await
+        .and_then(|mut generic_links: Vec<GenericLink>| {
+            generic_links
+                .pop()
+                .ok_or(error_stack::report!(db_errors::DatabaseError::NotFound))
+        })
+        .and_then(|res| {
+            Self::try_from(res)
+                .change_context(db_errors::DatabaseError::Others)
+                .attach_printable("failed to parse customer portal link data from DB")
+        })
+        .or_else(|error| match error.current_context() {
+            db_errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+            _ => Err(error),
+        })
+    }
+}
+
 impl TryFrom<GenericLink> for GenericLinkState {
     type Error = Report<errors::ParsingError>;
     fn try_from(db_val: GenericLink) -> Result<Self, Self::Error> {
@@ -136,6 +257,10 @@ impl TryFrom<GenericLink> for GenericLinkState {
                 let link_data = db_val.link_data.parse_value("PayoutLinkData")?;
                 GenericLinkData::PayoutLink(link_data)
             }
+            common_enums::GenericLinkType::CustomerPortal => {
+                let link_data = db_val.link_data.parse_value("CustomerPortalLinkData")?;
+                GenericLinkData::CustomerPortal(link_data)
+            }
         };
 
         Ok(Self {
@@ -237,3 +362,47 @@ impl TryFrom<GenericLink> for PayoutLink {
         })
     }
 }
+
+impl TryFrom<GenericLink> for CustomerPortalLink {
+    type Error = Report<errors::ParsingError>;
+    fn try_from(db_val: GenericLink) -> Result<Self, Self::Error> {
+        let (link_data, link_status) = match db_val.link_type {
+            common_enums::GenericLinkType::CustomerPortal => {
+                let link_data = db_val.link_data.parse_value("CustomerPortalLinkData")?;
+                let link_status = match db_val.link_status {
+                    GenericLinkStatus::CustomerPortal(status) => Ok(status),
+                    _ => Err(report!(errors::ParsingError::EnumParseFailure(
+                        "GenericLinkStatus"
+                    )))
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Invalid status for CustomerPortalLink - {:?}",
+                            db_val.link_status
+                        )
+                    }),
+                }?;
+                (link_data, link_status)
+            }
+            _ => Err(report!(errors::ParsingError::UnknownError)).attach_printable_lazy(|| {
+                format!(
+                    "Invalid link_type for CustomerPortalLink - {}",
+                    db_val.link_type
+                )
+            })?,
+        };
+
+        Ok(Self {
+            link_id: db_val.link_id,
+            primary_reference: db_val.primary_reference,
+            merchant_id: db_val.merchant_id,
+            created_at: db_val.created_at,
+            last_modified_at: db_val.last_modified_at,
+            expiry: db_val.expiry,
+            link_data,
+            link_status,
+            link_type: db_val.link_type,
+            url: db_val.url,
+            return_url: db_val.return_url,
+        })
+    }
+}
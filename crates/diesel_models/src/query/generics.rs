@@ -92,7 +92,10 @@ where
             DieselError::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
                 Err(report!(err)).change_context(errors::DatabaseError::UniqueViolation)
             }
-            _ => Err(report!(err)).change_context(errors::DatabaseError::Others),
+            _ => {
+                let database_error = errors::DatabaseError::from_write_error(&err);
+                Err(report!(err)).change_context(database_error)
+            }
         },
     }
     .attach_printable_lazy(|| format!("Error while inserting {debug_values}"))
@@ -120,7 +123,10 @@ where
 
     track_database_call::<T, _, _>(query.execute_async(conn), DatabaseOperation::Update)
         .await
-        .change_context(errors::DatabaseError::Others)
+        .map_err(|error| {
+            let database_error = errors::DatabaseError::from_write_error(&error);
+            report!(error).change_context(database_error)
+        })
         .attach_printable_lazy(|| format!("Error while updating {debug_values}"))
 }
 
@@ -166,9 +172,12 @@ where
         }
         Err(DieselError::NotFound) => Err(report!(errors::DatabaseError::NotFound))
             .attach_printable_lazy(|| format!("Error while updating {debug_values}")),
-        Err(error) => Err(error)
-            .change_context(errors::DatabaseError::Others)
-            .attach_printable_lazy(|| format!("Error while updating {debug_values}")),
+        Err(error) => {
+            let database_error = errors::DatabaseError::from_write_error(&error);
+            Err(report!(error))
+                .change_context(database_error)
+                .attach_printable_lazy(|| format!("Error while updating {debug_values}"))
+        }
     }
 }
 
@@ -253,9 +262,12 @@ where
         }
         Err(DieselError::NotFound) => Err(report!(errors::DatabaseError::NotFound))
             .attach_printable_lazy(|| format!("Error while updating by ID {debug_values}")),
-        Err(error) => Err(error)
-            .change_context(errors::DatabaseError::Others)
-            .attach_printable_lazy(|| format!("Error while updating by ID {debug_values}")),
+        Err(error) => {
+            let database_error = errors::DatabaseError::from_write_error(&error);
+            Err(report!(error))
+                .change_context(database_error)
+                .attach_printable_lazy(|| format!("Error while updating by ID {debug_values}"))
+        }
     }
 }
 
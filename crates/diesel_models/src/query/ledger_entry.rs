@@ -0,0 +1,138 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, dsl::sql, pg::Pg, sql_types::BigInt,
+    BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+use crate::{
+    enums as storage_enums,
+    errors::DatabaseError,
+    ledger_entry::{LedgerAccountNet, LedgerEntry, LedgerEntryNew},
+    schema::ledger_entry::dsl,
+    PgPooledConn, StorageResult,
+};
+
+const NET_DEBIT_SQL: &str =
+    "CAST(SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END) AS BIGINT)";
+
+impl LedgerEntryNew {
+    /// Inserts the entries of a ledger transaction in a single statement, so that either all or
+    /// none of them are recorded
+    pub async fn insert_batch(
+        conn: &PgPooledConn,
+        entries: Vec<Self>,
+    ) -> StorageResult<Vec<LedgerEntry>> {
+        let query = diesel::insert_into(<LedgerEntry>::table()).values(entries);
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        match track_database_call::<LedgerEntry, _, _>(
+            query.get_results_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        {
+            Ok(value) => Ok(value),
+            Err(err) => match err {
+                diesel::result::Error::DatabaseError(
+                    diesel::result::DatabaseErrorKind::UniqueViolation,
+                    _,
+                ) => Err(report!(err)).change_context(DatabaseError::UniqueViolation),
+                _ => {
+                    let database_error = DatabaseError::from_write_error(&err);
+                    Err(report!(err)).change_context(database_error)
+                }
+            },
+        }
+        .attach_printable("Error while inserting ledger entries")
+    }
+}
+
+impl LedgerEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_by_profile_id_account_currency(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        account: storage_enums::LedgerAccount,
+        currency: storage_enums::Currency,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(
+                dsl::profile_id
+                    .eq(profile_id.to_owned())
+                    .and(dsl::account.eq(account))
+                    .and(dsl::currency.eq(currency)),
+            )
+            .order((dsl::created_at.asc(), dsl::id.asc()))
+            .into_boxed();
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.lt(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error filtering ledger entries")
+    }
+
+    /// The net of the entries posted to each account of the ledger of the profile, in each
+    /// currency, before the given time
+    pub async fn get_account_nets_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<LedgerAccountNet>> {
+        let query = Self::table()
+            .filter(
+                dsl::profile_id
+                    .eq(profile_id.to_owned())
+                    .and(dsl::created_at.lt(created_before)),
+            )
+            .group_by((dsl::account, dsl::currency))
+            .select((dsl::account, dsl::currency, sql::<BigInt>(NET_DEBIT_SQL)));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query
+                .get_results_async::<(storage_enums::LedgerAccount, storage_enums::Currency, i64)>(
+                    conn,
+                ),
+            DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error aggregating ledger entries")
+        .map(|account_nets| {
+            account_nets
+                .into_iter()
+                .map(|(account, currency, net_debit)| LedgerAccountNet {
+                    account,
+                    currency,
+                    net_debit,
+                })
+                .collect()
+        })
+    }
+}
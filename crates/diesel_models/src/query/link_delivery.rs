@@ -0,0 +1,78 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    link_delivery::{
+        LinkDelivery, LinkDeliveryNew, LinkDeliveryUpdate, LinkDeliveryUpdateInternal,
+    },
+    schema::link_delivery::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl LinkDeliveryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LinkDelivery> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LinkDelivery {
+    pub async fn find_by_delivery_id(
+        conn: &PgPooledConn,
+        delivery_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(delivery_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_delivery_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        delivery_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(delivery_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::link_id.eq(link_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_delivery_id(
+        conn: &PgPooledConn,
+        delivery_id: &str,
+        link_delivery_update: LinkDeliveryUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(delivery_id.to_owned()),
+            LinkDeliveryUpdateInternal::from(link_delivery_update),
+        )
+        .await
+    }
+}
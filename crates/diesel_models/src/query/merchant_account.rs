@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods, Table};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 
 use super::generics;
 #[cfg(feature = "v1")]
@@ -78,6 +78,25 @@ impl MerchantAccount {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn find_by_publishable_key(
+        conn: &PgPooledConn,
+        publishable_key: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::publishable_key
+                .eq(publishable_key.to_owned())
+                .or(dsl::previous_publishable_key
+                    .eq(publishable_key.to_owned())
+                    .and(
+                        dsl::previous_publishable_key_expires_at.gt(common_utils::date_time::now()),
+                    )),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v2")]
     pub async fn find_by_publishable_key(
         conn: &PgPooledConn,
         publishable_key: &str,
@@ -9,7 +9,7 @@ use error_stack::{report, ResultExt};
 
 use super::generics;
 #[cfg(feature = "v1")]
-use crate::schema::payment_attempt::dsl;
+use crate::schema::{payment_attempt::dsl, payment_attempt_archive};
 #[cfg(feature = "v2")]
 use crate::schema_v2::payment_attempt::dsl;
 use crate::{
@@ -57,6 +57,32 @@ impl PaymentAttempt {
         }
     }
 
+    /// Updates the attempt only if its `update_version` is unchanged since it was fetched,
+    /// returning `None` if it was modified by a concurrent update
+    #[cfg(feature = "v1")]
+    pub async fn update_with_attempt_id_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Option<Self>> {
+        match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::attempt_id
+                .eq(self.attempt_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::update_version.eq(self.update_version)),
+            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+        )
+        .await
+        {
+            Ok(mut payment_attempts) => Ok(payment_attempts.pop()),
+            Err(error) => match error.current_context() {
+                DatabaseError::NoFieldsToUpdate => Ok(Some(self)),
+                _ => Err(error),
+            },
+        }
+    }
+
     #[cfg(feature = "v2")]
     pub async fn update_with_attempt_id(
         self,
@@ -221,13 +247,28 @@ impl PaymentAttempt {
         merchant_id: &common_utils::id_type::MerchantId,
         attempt_id: &str,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
-        )
-        .await
+        let payment_attempt =
+            generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+                conn,
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            )
+            .await?;
+
+        match payment_attempt {
+            Some(payment_attempt) => Ok(payment_attempt),
+            // Attempts of payments past the retention period are moved to the archive
+            None => {
+                generics::generic_find_one::<payment_attempt_archive::table, _, _>(
+                    conn,
+                    payment_attempt_archive::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(payment_attempt_archive::attempt_id.eq(attempt_id.to_owned())),
+                )
+                .await
+            }
+        }
     }
 
     #[cfg(feature = "v2")]
@@ -264,15 +305,34 @@ impl PaymentAttempt {
         merchant_id: &common_utils::id_type::MerchantId,
         attempt_id: &str,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::payment_id.eq(payment_id.to_owned()).and(
-                dsl::merchant_id
-                    .eq(merchant_id.to_owned())
-                    .and(dsl::attempt_id.eq(attempt_id.to_owned())),
-            ),
-        )
-        .await
+        let payment_attempt =
+            generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+                conn,
+                dsl::payment_id.eq(payment_id.to_owned()).and(
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+                ),
+            )
+            .await?;
+
+        match payment_attempt {
+            Some(payment_attempt) => Ok(payment_attempt),
+            // Attempts of payments past the retention period are moved to the archive
+            None => {
+                generics::generic_find_one::<payment_attempt_archive::table, _, _>(
+                    conn,
+                    payment_attempt_archive::payment_id
+                        .eq(payment_id.to_owned())
+                        .and(
+                            payment_attempt_archive::merchant_id
+                                .eq(merchant_id.to_owned())
+                                .and(payment_attempt_archive::attempt_id.eq(attempt_id.to_owned())),
+                        ),
+                )
+                .await
+            }
+        }
     }
 
     #[cfg(feature = "v1")]
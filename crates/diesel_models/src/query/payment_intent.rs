@@ -1,8 +1,14 @@
+#[cfg(feature = "v1")]
+use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
 
 use super::generics;
 #[cfg(feature = "v1")]
-use crate::schema::payment_intent::dsl;
+use super::generics::db_metrics;
+#[cfg(feature = "v1")]
+use crate::schema::{payment_intent::dsl, payment_intent_archive};
 #[cfg(feature = "v2")]
 use crate::schema_v2::payment_intent::dsl;
 use crate::{
@@ -93,13 +99,134 @@ impl PaymentIntent {
         payment_id: &common_utils::id_type::PaymentId,
         merchant_id: &common_utils::id_type::MerchantId,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+        let payment_intent =
+            generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+                conn,
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::payment_id.eq(payment_id.to_owned())),
+            )
+            .await?;
+
+        match payment_intent {
+            Some(payment_intent) => Ok(payment_intent),
+            // Payments past the retention period are moved to the archive by the archival job
+            None => {
+                Self::find_archived_by_payment_id_merchant_id(conn, payment_id, merchant_id).await
+            }
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn find_archived_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<payment_intent_archive::table, _, _>(
             conn,
-            dsl::merchant_id
+            payment_intent_archive::merchant_id
                 .eq(merchant_id.to_owned())
-                .and(dsl::payment_id.eq(payment_id.to_owned())),
+                .and(payment_intent_archive::payment_id.eq(payment_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Moves a batch of the payments created before `created_before`, which are in a terminal
+    /// status, along with their attempts to the archive tables. Returns the number of payments
+    /// archived.
+    #[cfg(feature = "v1")]
+    pub async fn archive_created_before(
+        conn: &PgPooledConn,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i64,
+    ) -> StorageResult<usize> {
+        let query = diesel::sql_query(ARCHIVE_PAYMENTS_QUERY)
+            .bind::<diesel::sql_types::Timestamp, _>(created_before)
+            .bind::<diesel::sql_types::BigInt, _>(batch_size);
+
+        let archived_payments = db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<PaymentsCount>(conn),
+            db_metrics::DatabaseOperation::DeleteWithResult,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while archiving payments")?;
+
+        usize::try_from(archived_payments.count)
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Invalid count of archived payments")
+    }
+
+    /// Clears the data of the class from the payments of the merchant created before
+    /// `created_before`, including the archived payments. Returns the number of payments purged.
+    #[cfg(feature = "v1")]
+    pub async fn purge_data_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        data_class: payment_intent::PaymentDataClass,
+    ) -> StorageResult<usize> {
+        let set_clause = data_class
+            .get_columns()
+            .iter()
+            .map(|column| format!("{column} = NULL"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let predicate = get_purge_data_predicate(data_class);
+        let query = diesel::sql_query(format!(
+            "WITH purged_intents AS (
+                UPDATE payment_intent SET {set_clause} WHERE {predicate} RETURNING 1
+            ), purged_archived_intents AS (
+                UPDATE payment_intent_archive SET {set_clause} WHERE {predicate} RETURNING 1
+            )
+            SELECT (SELECT COUNT(*) FROM purged_intents)
+                + (SELECT COUNT(*) FROM purged_archived_intents) AS count"
+        ))
+        .bind::<diesel::sql_types::Text, _>(merchant_id.get_string_repr().to_owned())
+        .bind::<diesel::sql_types::Timestamp, _>(created_before);
+
+        let purged_payments = db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<PaymentsCount>(conn),
+            db_metrics::DatabaseOperation::UpdateWithResults,
         )
         .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while purging data of payments")?;
+
+        usize::try_from(purged_payments.count)
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Invalid count of purged payments")
+    }
+
+    /// Returns the number of payments of the merchant created before `created_before`, including
+    /// the archived payments, which hold data of the class that is yet to be purged
+    #[cfg(feature = "v1")]
+    pub async fn get_count_of_data_to_purge_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        data_class: payment_intent::PaymentDataClass,
+    ) -> StorageResult<usize> {
+        let predicate = get_purge_data_predicate(data_class);
+        let query = diesel::sql_query(format!(
+            "SELECT (SELECT COUNT(*) FROM payment_intent WHERE {predicate})
+                + (SELECT COUNT(*) FROM payment_intent_archive WHERE {predicate}) AS count"
+        ))
+        .bind::<diesel::sql_types::Text, _>(merchant_id.get_string_repr().to_owned())
+        .bind::<diesel::sql_types::Timestamp, _>(created_before);
+
+        let payments = db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<PaymentsCount>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while counting payments with data to be purged")?;
+
+        usize::try_from(payments.count)
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Invalid count of payments with data to be purged")
     }
 
     #[cfg(feature = "v2")]
@@ -132,3 +259,49 @@ impl PaymentIntent {
         .await
     }
 }
+
+/// The attempts and intents are deleted and inserted into the archive tables in a single statement,
+/// so that a payment is never partially archived
+#[cfg(feature = "v1")]
+const ARCHIVE_PAYMENTS_QUERY: &str = r#"
+WITH archived_intents AS (
+    DELETE FROM payment_intent
+    WHERE (payment_id, merchant_id) IN (
+        SELECT payment_id, merchant_id FROM payment_intent
+        WHERE created_at < $1
+            AND status IN ('succeeded', 'failed', 'cancelled', 'partially_captured')
+        ORDER BY created_at
+        LIMIT $2
+    )
+    RETURNING *
+), archived_attempts AS (
+    DELETE FROM payment_attempt
+    WHERE (payment_id, merchant_id) IN (SELECT payment_id, merchant_id FROM archived_intents)
+    RETURNING *
+), inserted_attempts AS (
+    INSERT INTO payment_attempt_archive SELECT * FROM archived_attempts
+), inserted_intents AS (
+    INSERT INTO payment_intent_archive SELECT * FROM archived_intents
+)
+SELECT COUNT(*) AS count FROM archived_intents
+"#;
+
+/// Matches the payments of the merchant (`$1`) created before `$2` which hold data of the class
+#[cfg(feature = "v1")]
+fn get_purge_data_predicate(data_class: payment_intent::PaymentDataClass) -> String {
+    let has_data = data_class
+        .get_columns()
+        .iter()
+        .map(|column| format!("{column} IS NOT NULL"))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+
+    format!("merchant_id = $1 AND created_at < $2 AND ({has_data})")
+}
+
+#[cfg(feature = "v1")]
+#[derive(diesel::QueryableByName)]
+struct PaymentsCount {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    count: i64,
+}
@@ -1,8 +1,8 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    payment_link::{PaymentLink, PaymentLinkNew},
+    payment_link::{PaymentLink, PaymentLinkNew, PaymentLinkUpdate, PaymentLinkUpdateInternal},
     schema::payment_link::dsl,
     PgPooledConn, StorageResult,
 };
@@ -24,4 +24,39 @@ impl PaymentLink {
         )
         .await
     }
+
+    pub async fn update_by_payment_link_id(
+        conn: &PgPooledConn,
+        payment_link_id: &str,
+        payment_link_update: PaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_link_id.eq(payment_link_id.to_owned()),
+            PaymentLinkUpdateInternal::from(payment_link_update),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_link_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_link_template_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_link_template_id.eq(payment_link_template_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
 }
@@ -0,0 +1,96 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_link_template::{
+        PaymentLinkTemplate, PaymentLinkTemplateNew, PaymentLinkTemplateUpdate,
+        PaymentLinkTemplateUpdateInternal,
+    },
+    schema::payment_link_template::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentLinkTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentLinkTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentLinkTemplate {
+    pub async fn find_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        payment_link_template_update: PaymentLinkTemplateUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+            PaymentLinkTemplateUpdateInternal::from(payment_link_template_update),
+        )
+        .await
+    }
+
+    /// Increments the usage count only if it has not been modified since it was read, so that
+    /// concurrent visits cannot consume the template beyond its usage limit
+    pub async fn increment_usage_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        current_usage_count: i32,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned()))
+                .and(dsl::usage_count.eq(current_usage_count)),
+            PaymentLinkTemplateUpdateInternal::from(PaymentLinkTemplateUpdate::UsageCountUpdate {
+                usage_count: current_usage_count.saturating_add(1),
+            }),
+        )
+        .await
+    }
+}
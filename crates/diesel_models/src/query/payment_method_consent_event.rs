@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_method_consent_event::{PaymentMethodConsentEvent, PaymentMethodConsentEventNew},
+    schema::payment_method_consent_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentMethodConsentEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodConsentEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodConsentEvent {
+    pub async fn find_by_merchant_id_payment_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,95 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_template::{
+        PaymentTemplate, PaymentTemplateNew, PaymentTemplateUpdate, PaymentTemplateUpdateInternal,
+    },
+    schema::payment_template::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentTemplate {
+    pub async fn find_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_profile_id_template_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_template_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+        payment_template_update: PaymentTemplateUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+            PaymentTemplateUpdateInternal::from(payment_template_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_profile_id_template_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -93,6 +93,27 @@ impl Payouts {
         .await
     }
 
+    pub async fn filter_by_merchant_id_profile_id_source_currency_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        source_currency: enums::Currency,
+        status: Vec<enums::PayoutStatus>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned()))
+                .and(dsl::source_currency.eq(source_currency))
+                .and(dsl::status.eq_any(status)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn get_total_count_of_payouts(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -1,4 +1,9 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable,
+    dsl::sql,
+    sql_types::{Bool, Text},
+    BoolExpressionMethods, ExpressionMethods, Table,
+};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
@@ -6,7 +11,8 @@ use super::generics;
 use crate::{
     enums, errors,
     process_tracker::{
-        ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
+        ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner, ProcessTrackerUpdate,
+        ProcessTrackerUpdateInternal,
     },
     schema::process_tracker::dsl,
     PgPooledConn, StorageResult,
@@ -89,6 +95,42 @@ impl ProcessTracker {
         .await
     }
 
+    /// Finds the processes of the runners which are scheduled within the time range for the
+    /// merchant, as identified by the `merchant_id` in the tracking data of the processes
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_merchant_id_runners_time_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        runners: &[ProcessTrackerRunner],
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::schedule_time
+                .between(time_lower_limit, time_upper_limit)
+                .and(dsl::status.eq(status))
+                .and(
+                    dsl::runner.eq_any(runners.iter().map(ToString::to_string).collect::<Vec<_>>()),
+                )
+                .and(
+                    sql::<Bool>("tracking_data ->> 'merchant_id' = ")
+                        .bind::<Text, _>(merchant_id.get_string_repr().to_owned()),
+                ),
+            limit,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
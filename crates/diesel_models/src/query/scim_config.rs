@@ -0,0 +1,69 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    api_keys::HashedApiKey,
+    schema::scim_configs::dsl,
+    scim_config::{ScimConfig, ScimConfigNew, ScimConfigUpdate, ScimConfigUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl ScimConfigNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ScimConfig> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ScimConfig {
+    pub async fn find_optional_by_hashed_token(
+        conn: &PgPooledConn,
+        hashed_token: HashedApiKey,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::hashed_token.eq(hashed_token),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update_by_id(
+        conn: &PgPooledConn,
+        id: &str,
+        scim_config_update: ScimConfigUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(id.to_owned()),
+            ScimConfigUpdateInternal::from(scim_config_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,63 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::shared_payment_method::dsl,
+    shared_payment_method::{SharedPaymentMethod, SharedPaymentMethodNew},
+    PgPooledConn, StorageResult,
+};
+
+impl SharedPaymentMethodNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SharedPaymentMethod> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SharedPaymentMethod {
+    pub async fn list_by_source_merchant_id_payment_method_id(
+        conn: &PgPooledConn,
+        source_merchant_id: &common_utils::id_type::MerchantId,
+        source_payment_method_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::source_merchant_id
+                .eq(source_merchant_id.to_owned())
+                .and(dsl::source_payment_method_id.eq(source_payment_method_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Finds the latest share of a payment method of the customer with the target merchant
+    /// account, which records the customer the source customer is mapped to
+    pub async fn find_latest_by_source_merchant_id_customer_id_target_merchant_id(
+        conn: &PgPooledConn,
+        source_merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        target_merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::source_merchant_id
+                .eq(source_merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::target_merchant_id.eq(target_merchant_id.to_owned())),
+            Some(1),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+        .map(|shares| shares.into_iter().next())
+    }
+
+    pub async fn delete_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id.to_owned()),
+        )
+        .await
+    }
+}
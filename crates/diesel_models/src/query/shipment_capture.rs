@@ -0,0 +1,49 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::shipment_capture::dsl,
+    shipment_capture::{ShipmentCapture, ShipmentCaptureNew},
+    PgPooledConn, StorageResult,
+};
+
+impl ShipmentCaptureNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ShipmentCapture> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ShipmentCapture {
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_payment_id_shipment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+        shipment_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::shipment_id.eq(shipment_id.to_owned())),
+        )
+        .await
+    }
+}
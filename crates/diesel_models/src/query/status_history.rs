@@ -0,0 +1,36 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::status_history::dsl,
+    status_history::{StatusHistory, StatusHistoryNew},
+    PgPooledConn, StorageResult,
+};
+
+impl StatusHistoryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<StatusHistory> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl StatusHistory {
+    pub async fn find_by_merchant_id_entity_type_entity_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: storage_enums::StatusHistoryEntityType,
+        entity_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::entity_type.eq(entity_type))
+                .and(dsl::entity_id.eq(entity_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
@@ -3,6 +3,7 @@ use diesel::{associations::HasTable, ExpressionMethods};
 
 pub mod sample_data;
 pub mod theme;
+pub mod webauthn_credential;
 
 use crate::{
     query::generics, schema::users::dsl as users_dsl, user::*, PgPooledConn, StorageResult,
@@ -0,0 +1,73 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    query::generics,
+    schema::user_webauthn_credentials::dsl,
+    user::webauthn_credential::{
+        UserWebauthnCredential, UserWebauthnCredentialNew, UserWebauthnCredentialUpdate,
+        UserWebauthnCredentialUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl UserWebauthnCredentialNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<UserWebauthnCredential> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl UserWebauthnCredential {
+    pub async fn list_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_user_id_and_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        id: &str,
+        update: UserWebauthnCredentialUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+            UserWebauthnCredentialUpdateInternal::from(update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_user_id_and_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+        )
+        .await
+    }
+}
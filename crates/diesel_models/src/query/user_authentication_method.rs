@@ -47,6 +47,17 @@ impl UserAuthenticationMethod {
         .await
     }
 
+    pub async fn find_optional_by_email_domain(
+        conn: &PgPooledConn,
+        email_domain: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::email_domain.eq(email_domain.to_owned()),
+        )
+        .await
+    }
+
     pub async fn update_user_authentication_method(
         conn: &PgPooledConn,
         id: &str,
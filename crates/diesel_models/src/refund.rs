@@ -53,6 +53,12 @@ pub struct Refund {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub connector_refund_data: Option<String>,
     pub connector_transaction_data: Option<String>,
+    pub fallback_payout_id: Option<String>,
+    pub capture_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_settlement_date: Option<PrimitiveDateTime>,
+    pub status_reason: Option<storage_enums::RefundStatusReason>,
+    pub line_items: Option<RefundLineItems>,
 }
 
 #[derive(
@@ -98,9 +104,31 @@ pub struct RefundNew {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub connector_refund_data: Option<String>,
     pub connector_transaction_data: Option<String>,
+    pub capture_id: Option<String>,
+    pub line_items: Option<RefundLineItems>,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// A line item of the order details of a payment refunded by a refund
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RefundLineItem {
+    pub product_name: String,
+    pub product_id: Option<String>,
+    /// The quantity of the product refunded
+    pub quantity: u16,
+    /// The amount per quantity of the product
+    pub amount: MinorUnit,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct RefundLineItems(pub Vec<RefundLineItem>);
+
+common_utils::impl_to_sql_from_sql_json!(RefundLineItems);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum RefundUpdate {
     Update {
         connector_refund_id: ConnectorTransactionId,
@@ -110,6 +138,8 @@ pub enum RefundUpdate {
         refund_arn: String,
         updated_by: String,
         connector_refund_data: Option<String>,
+        expected_settlement_date: Option<PrimitiveDateTime>,
+        status_reason: Option<storage_enums::RefundStatusReason>,
     },
     MetadataAndReasonUpdate {
         metadata: Option<pii::SecretSerdeValue>,
@@ -130,6 +160,7 @@ pub enum RefundUpdate {
         updated_by: String,
         connector_refund_id: Option<ConnectorTransactionId>,
         connector_refund_data: Option<String>,
+        status_reason: Option<storage_enums::RefundStatusReason>,
     },
     ManualUpdate {
         refund_status: Option<storage_enums::RefundStatus>,
@@ -137,6 +168,10 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    FallbackPayoutUpdate {
+        fallback_payout_id: String,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -153,6 +188,9 @@ pub struct RefundUpdateInternal {
     updated_by: String,
     modified_at: PrimitiveDateTime,
     connector_refund_data: Option<String>,
+    fallback_payout_id: Option<String>,
+    expected_settlement_date: Option<PrimitiveDateTime>,
+    status_reason: Option<storage_enums::RefundStatusReason>,
 }
 
 impl RefundUpdateInternal {
@@ -169,6 +207,11 @@ impl RefundUpdateInternal {
             updated_by: self.updated_by,
             modified_at: self.modified_at,
             connector_refund_data: self.connector_refund_data,
+            fallback_payout_id: self.fallback_payout_id.or(source.fallback_payout_id),
+            expected_settlement_date: self
+                .expected_settlement_date
+                .or(source.expected_settlement_date),
+            status_reason: self.status_reason.or(source.status_reason),
             ..source
         }
     }
@@ -185,6 +228,8 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn,
                 updated_by,
                 connector_refund_data,
+                expected_settlement_date,
+                status_reason,
             } => Self {
                 connector_refund_id: Some(connector_refund_id),
                 refund_status: Some(refund_status),
@@ -197,6 +242,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                fallback_payout_id: None,
+                expected_settlement_date,
+                status_reason,
             },
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
@@ -213,7 +261,10 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                fallback_payout_id: None,
                 connector_refund_data: None,
+                expected_settlement_date: None,
+                status_reason: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -233,6 +284,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                fallback_payout_id: None,
+                expected_settlement_date: None,
+                status_reason: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -241,6 +295,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 updated_by,
                 connector_refund_id,
                 connector_refund_data,
+                status_reason,
             } => Self {
                 refund_status,
                 refund_error_message,
@@ -253,6 +308,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                fallback_payout_id: None,
+                expected_settlement_date: None,
+                status_reason,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -270,7 +328,29 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                fallback_payout_id: None,
+                connector_refund_data: None,
+                expected_settlement_date: None,
+                status_reason: None,
+            },
+            RefundUpdate::FallbackPayoutUpdate {
+                fallback_payout_id,
+                updated_by,
+            } => Self {
+                fallback_payout_id: Some(fallback_payout_id),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
                 connector_refund_data: None,
+                expected_settlement_date: None,
+                status_reason: None,
             },
         }
     }
@@ -290,6 +370,9 @@ impl RefundUpdate {
             updated_by,
             modified_at: _,
             connector_refund_data,
+            fallback_payout_id,
+            expected_settlement_date,
+            status_reason,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -303,6 +386,9 @@ impl RefundUpdate {
             updated_by,
             modified_at: common_utils::date_time::now(),
             connector_refund_data: connector_refund_data.or(source.connector_refund_data),
+            fallback_payout_id: fallback_payout_id.or(source.fallback_payout_id),
+            expected_settlement_date: expected_settlement_date.or(source.expected_settlement_date),
+            status_reason: status_reason.or(source.status_reason),
             ..source
         }
     }
@@ -34,6 +34,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    admin_api_keys (key_id) {
+        #[max_length = 64]
+        key_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 256]
+        description -> Nullable<Varchar>,
+        #[max_length = 128]
+        hashed_api_key -> Varchar,
+        #[max_length = 16]
+        prefix -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -54,6 +77,7 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -120,6 +144,7 @@ diesel::table! {
         directory_server_id -> Nullable<Varchar>,
         #[max_length = 64]
         acquirer_country_code -> Nullable<Varchar>,
+        connector_failover_history -> Nullable<Jsonb>,
     }
 }
 
@@ -214,6 +239,32 @@ diesel::table! {
         is_auto_retries_enabled -> Nullable<Bool>,
         max_auto_retries_enabled -> Nullable<Int2>,
         is_click_to_pay_enabled -> Bool,
+        customer_portal_link_config -> Nullable<Jsonb>,
+        invoice_config -> Nullable<Jsonb>,
+        enabled_webhook_events -> Nullable<Array<Nullable<EventClass>>>,
+        #[max_length = 16]
+        webhook_payload_version -> Nullable<Varchar>,
+        statement_descriptor_config -> Nullable<Jsonb>,
+        dispute_representment_config -> Nullable<Jsonb>,
+        outgoing_webhook_auth_config -> Nullable<Bytea>,
+        redirect_config -> Nullable<Jsonb>,
+        client_origin_config -> Nullable<Jsonb>,
+        is_client_secret_single_use -> Nullable<Bool>,
+        post_auth_rules_config -> Nullable<Jsonb>,
+        allowed_currencies -> Nullable<Array<Nullable<Currency>>>,
+        tags -> Nullable<Array<Nullable<Text>>>,
+        payout_retry_config -> Nullable<Jsonb>,
+        payout_fulfillment_config -> Nullable<Jsonb>,
+        webhook_secret_rotation -> Nullable<Jsonb>,
+        payment_defaults_config -> Nullable<Jsonb>,
+        capture_on_shipment_config -> Nullable<Jsonb>,
+        dcc_config -> Nullable<Jsonb>,
+        payout_fee_config -> Nullable<Jsonb>,
+        payout_balance_config -> Nullable<Jsonb>,
+        operation_fallback_config -> Nullable<Jsonb>,
+        connector_canary_rollout -> Nullable<Jsonb>,
+        #[max_length = 64]
+        account_name_mismatch_action -> Nullable<Varchar>,
     }
 }
 
@@ -317,6 +368,8 @@ diesel::table! {
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
         version -> ApiVersion,
+        #[max_length = 32]
+        recipient_kyc_status -> Nullable<Varchar>,
     }
 }
 
@@ -389,6 +442,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    email_template (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        email_type -> Varchar,
+        #[max_length = 32]
+        locale -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        body -> Nullable<Text>,
+        #[max_length = 2048]
+        logo_url -> Nullable<Varchar>,
+        #[max_length = 32]
+        primary_color -> Nullable<Varchar>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -561,6 +642,101 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    installment_plan (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        currency -> Currency,
+        total_amount -> Int8,
+        installment_count -> Int4,
+        interval_days -> Int4,
+        installments -> Jsonb,
+        max_retries_per_installment -> Int4,
+        retry_interval_hours -> Int4,
+        max_missed_installments -> Int4,
+        missed_count -> Int4,
+        next_charge_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        transaction_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        account -> Varchar,
+        #[max_length = 16]
+        direction -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        source_type -> Varchar,
+        #[max_length = 64]
+        source_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    link_delivery (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        link_id -> Varchar,
+        #[max_length = 32]
+        link_type -> Varchar,
+        #[max_length = 32]
+        channel -> Varchar,
+        #[max_length = 64]
+        provider -> Varchar,
+        #[max_length = 128]
+        provider_message_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        masked_recipient -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -640,6 +816,8 @@ diesel::table! {
         merchant_connector_id -> Nullable<Varchar>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        #[max_length = 64]
+        signed_mandate_file_id -> Nullable<Varchar>,
     }
 }
 
@@ -684,6 +862,11 @@ diesel::table! {
         payment_link_config -> Nullable<Jsonb>,
         pm_collect_link_config -> Nullable<Jsonb>,
         version -> ApiVersion,
+        id_prefixes -> Nullable<Jsonb>,
+        data_retention_policy -> Nullable<Jsonb>,
+        #[max_length = 128]
+        previous_publishable_key -> Nullable<Varchar>,
+        previous_publishable_key_expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -724,6 +907,8 @@ diesel::table! {
         additional_merchant_data -> Nullable<Bytea>,
         connector_wallets_details -> Nullable<Bytea>,
         version -> ApiVersion,
+        tags -> Nullable<Array<Nullable<Text>>>,
+        credentials_expiry -> Nullable<Jsonb>,
     }
 }
 
@@ -754,6 +939,7 @@ diesel::table! {
         #[max_length = 32]
         id -> Nullable<Varchar>,
         organization_name -> Nullable<Text>,
+        is_token_sharing_enabled -> Nullable<Bool>,
     }
 }
 
@@ -855,6 +1041,129 @@ diesel::table! {
         #[max_length = 512]
         connector_transaction_data -> Nullable<Varchar>,
         connector_mandate_detail -> Nullable<Jsonb>,
+        connector_response_codes -> Nullable<Jsonb>,
+        #[max_length = 32]
+        avs_result -> Nullable<Varchar>,
+        #[max_length = 32]
+        cvv_result -> Nullable<Varchar>,
+        presentment_currency -> Nullable<Currency>,
+        presentment_amount -> Nullable<Int8>,
+        #[max_length = 32]
+        exchange_rate -> Nullable<Varchar>,
+        dcc_details -> Nullable<Jsonb>,
+        update_version -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_attempt_archive (attempt_id, merchant_id) {
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        status -> AttemptStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        save_to_locker -> Nullable<Bool>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        offer_amount -> Nullable<Int8>,
+        surcharge_amount -> Nullable<Int8>,
+        tax_amount -> Nullable<Int8>,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        payment_method -> Nullable<Varchar>,
+        #[max_length = 128]
+        connector_transaction_id -> Nullable<Varchar>,
+        capture_method -> Nullable<CaptureMethod>,
+        capture_on -> Nullable<Timestamp>,
+        confirm -> Bool,
+        authentication_type -> Nullable<AuthenticationType>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        last_synced -> Nullable<Timestamp>,
+        #[max_length = 255]
+        cancellation_reason -> Nullable<Varchar>,
+        amount_to_capture -> Nullable<Int8>,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        browser_info -> Nullable<Jsonb>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        #[max_length = 128]
+        payment_token -> Nullable<Varchar>,
+        connector_metadata -> Nullable<Jsonb>,
+        #[max_length = 50]
+        payment_experience -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_type -> Nullable<Varchar>,
+        payment_method_data -> Nullable<Jsonb>,
+        #[max_length = 64]
+        business_sub_label -> Nullable<Varchar>,
+        straight_through_algorithm -> Nullable<Jsonb>,
+        preprocessing_step_id -> Nullable<Varchar>,
+        mandate_details -> Nullable<Jsonb>,
+        error_reason -> Nullable<Text>,
+        multiple_capture_count -> Nullable<Int2>,
+        #[max_length = 128]
+        connector_response_reference_id -> Nullable<Varchar>,
+        amount_capturable -> Int8,
+        #[max_length = 32]
+        updated_by -> Varchar,
+        #[max_length = 32]
+        merchant_connector_id -> Nullable<Varchar>,
+        authentication_data -> Nullable<Json>,
+        encoded_data -> Nullable<Text>,
+        #[max_length = 255]
+        unified_code -> Nullable<Varchar>,
+        #[max_length = 1024]
+        unified_message -> Nullable<Varchar>,
+        net_amount -> Nullable<Int8>,
+        external_three_ds_authentication_attempted -> Nullable<Bool>,
+        #[max_length = 64]
+        authentication_connector -> Nullable<Varchar>,
+        #[max_length = 64]
+        authentication_id -> Nullable<Varchar>,
+        mandate_data -> Nullable<Jsonb>,
+        #[max_length = 64]
+        fingerprint_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_billing_address_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        charge_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        client_source -> Nullable<Varchar>,
+        #[max_length = 64]
+        client_version -> Nullable<Varchar>,
+        customer_acceptance -> Nullable<Jsonb>,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 32]
+        card_network -> Nullable<Varchar>,
+        shipping_cost -> Nullable<Int8>,
+        order_tax_amount -> Nullable<Int8>,
+        #[max_length = 512]
+        connector_transaction_data -> Nullable<Varchar>,
+        connector_mandate_detail -> Nullable<Jsonb>,
+        connector_response_codes -> Nullable<Jsonb>,
+        #[max_length = 32]
+        avs_result -> Nullable<Varchar>,
+        #[max_length = 32]
+        cvv_result -> Nullable<Varchar>,
+        presentment_currency -> Nullable<Currency>,
+        presentment_amount -> Nullable<Int8>,
+        #[max_length = 32]
+        exchange_rate -> Nullable<Varchar>,
+        dcc_details -> Nullable<Jsonb>,
+        update_version -> Int4,
     }
 }
 
@@ -939,6 +1248,87 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_intent_archive (payment_id, merchant_id) {
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        status -> IntentStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        amount_captured -> Nullable<Int8>,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 255]
+        return_url -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        shipping_address_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        billing_address_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        statement_descriptor_name -> Nullable<Varchar>,
+        #[max_length = 255]
+        statement_descriptor_suffix -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        last_synced -> Nullable<Timestamp>,
+        setup_future_usage -> Nullable<FutureUsage>,
+        off_session -> Nullable<Bool>,
+        #[max_length = 128]
+        client_secret -> Nullable<Varchar>,
+        #[max_length = 64]
+        active_attempt_id -> Varchar,
+        business_country -> Nullable<CountryAlpha2>,
+        #[max_length = 64]
+        business_label -> Nullable<Varchar>,
+        order_details -> Nullable<Array<Nullable<Jsonb>>>,
+        allowed_payment_method_types -> Nullable<Json>,
+        connector_metadata -> Nullable<Json>,
+        feature_metadata -> Nullable<Json>,
+        attempt_count -> Int2,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_decision -> Nullable<Varchar>,
+        #[max_length = 255]
+        payment_link_id -> Nullable<Varchar>,
+        payment_confirm_source -> Nullable<PaymentSource>,
+        #[max_length = 32]
+        updated_by -> Varchar,
+        surcharge_applicable -> Nullable<Bool>,
+        request_incremental_authorization -> Nullable<RequestIncrementalAuthorization>,
+        incremental_authorization_allowed -> Nullable<Bool>,
+        authorization_count -> Nullable<Int4>,
+        session_expiry -> Nullable<Timestamp>,
+        #[max_length = 64]
+        fingerprint_id -> Nullable<Varchar>,
+        request_external_three_ds_authentication -> Nullable<Bool>,
+        charges -> Nullable<Jsonb>,
+        frm_metadata -> Nullable<Jsonb>,
+        customer_details -> Nullable<Bytea>,
+        billing_details -> Nullable<Bytea>,
+        #[max_length = 255]
+        merchant_order_reference_id -> Nullable<Varchar>,
+        shipping_details -> Nullable<Bytea>,
+        is_payment_processor_token_flow -> Nullable<Bool>,
+        shipping_cost -> Nullable<Int8>,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        tax_details -> Nullable<Jsonb>,
+        skip_external_tax_calculation -> Nullable<Bool>,
+        psd2_sca_exemption_type -> Nullable<ScaExemptionType>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -966,6 +1356,67 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 255]
         secure_link -> Nullable<Varchar>,
+        deactivated_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        link_token -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_link_template_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_link_template (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        link_to_pay -> Varchar,
+        amount -> Nullable<Int8>,
+        min_amount -> Nullable<Int8>,
+        max_amount -> Nullable<Int8>,
+        currency -> Currency,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        payment_link_config -> Nullable<Jsonb>,
+        session_expiry -> Nullable<Int4>,
+        usage_limit -> Nullable<Int4>,
+        usage_count -> Int4,
+        is_active -> Bool,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_consent_event (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        setup_future_usage -> FutureUsage,
+        #[max_length = 64]
+        consent_text_version -> Nullable<Varchar>,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        #[max_length = 255]
+        user_agent -> Nullable<Varchar>,
+        accepted_at -> Timestamp,
+        created_at -> Timestamp,
     }
 }
 
@@ -1030,6 +1481,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_template (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        currency -> Nullable<Currency>,
+        capture_method -> Nullable<CaptureMethod>,
+        metadata -> Nullable<Jsonb>,
+        #[max_length = 255]
+        statement_descriptor_name -> Nullable<Varchar>,
+        #[max_length = 255]
+        statement_descriptor_suffix -> Nullable<Varchar>,
+        allowed_payment_method_types -> Nullable<Jsonb>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1071,6 +1549,13 @@ diesel::table! {
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
         additional_payout_method_data -> Nullable<Jsonb>,
+        #[max_length = 64]
+        account_name_match_result -> Nullable<Varchar>,
+        fee -> Nullable<Int8>,
+        net_amount -> Nullable<Int8>,
+        #[max_length = 32]
+        fee_source -> Nullable<Varchar>,
+        balance_rules_overridden -> Nullable<Bool>,
     }
 }
 
@@ -1197,6 +1682,14 @@ diesel::table! {
         connector_refund_data -> Nullable<Varchar>,
         #[max_length = 512]
         connector_transaction_data -> Nullable<Varchar>,
+        #[max_length = 64]
+        fallback_payout_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        capture_id -> Nullable<Varchar>,
+        expected_settlement_date -> Nullable<Timestamp>,
+        #[max_length = 64]
+        status_reason -> Nullable<Varchar>,
+        line_items -> Nullable<Jsonb>,
     }
 }
 
@@ -1267,6 +1760,101 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    scim_configs (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 128]
+        hashed_token -> Varchar,
+        #[max_length = 16]
+        token_prefix -> Varchar,
+        group_role_mappings -> Jsonb,
+        #[max_length = 64]
+        default_role_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    shared_payment_method (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        source_merchant_id -> Varchar,
+        #[max_length = 64]
+        source_payment_method_id -> Varchar,
+        #[max_length = 64]
+        target_merchant_id -> Varchar,
+        #[max_length = 64]
+        target_payment_method_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        created_at -> Timestamp,
+        #[max_length = 64]
+        target_customer_id -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    shipment_capture (merchant_id, payment_id, shipment_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        shipment_id -> Varchar,
+        line_items -> Nullable<Jsonb>,
+        amount_to_capture -> Nullable<Int8>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    status_history (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        entity_type -> Varchar,
+        #[max_length = 64]
+        entity_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        previous_status -> Nullable<Varchar>,
+        #[max_length = 64]
+        status -> Varchar,
+        #[max_length = 64]
+        trigger -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1329,6 +1917,8 @@ diesel::table! {
         allow_signup -> Bool,
         created_at -> Timestamp,
         last_modified_at -> Timestamp,
+        #[max_length = 255]
+        email_domain -> Nullable<Varchar>,
     }
 }
 
@@ -1377,6 +1967,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_webauthn_credentials (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 1024]
+        credential_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        credential -> Jsonb,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1402,6 +2011,7 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    admin_api_keys,
     api_keys,
     authentication,
     blocklist,
@@ -1414,12 +2024,16 @@ diesel::allow_tables_to_appear_in_same_query!(
     customers,
     dashboard_metadata,
     dispute,
+    email_template,
     events,
     file_metadata,
     fraud_check,
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    installment_plan,
+    ledger_entry,
+    link_delivery,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1427,9 +2041,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     merchant_key_store,
     organization,
     payment_attempt,
+    payment_attempt_archive,
     payment_intent,
+    payment_intent_archive,
     payment_link,
+    payment_link_template,
+    payment_method_consent_event,
     payment_methods,
+    payment_template,
     payout_attempt,
     payouts,
     process_tracker,
@@ -1437,10 +2056,15 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    scim_configs,
+    shared_payment_method,
+    shipment_capture,
+    status_history,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
+    user_webauthn_credentials,
     users,
 );
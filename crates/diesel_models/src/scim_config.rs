@@ -0,0 +1,89 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{api_keys::HashedApiKey, schema::scim_configs};
+
+/// The SCIM provisioning configuration of a merchant, used by identity providers to provision
+/// dashboard users in the merchant, and to assign them roles based on their groups.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = scim_configs, check_for_backend(diesel::pg::Pg))]
+pub struct ScimConfig {
+    pub id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub hashed_token: HashedApiKey,
+    pub token_prefix: String,
+    pub group_role_mappings: serde_json::Value,
+    pub default_role_id: Option<String>,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = scim_configs)]
+pub struct ScimConfigNew {
+    pub id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub hashed_token: HashedApiKey,
+    pub token_prefix: String,
+    pub group_role_mappings: serde_json::Value,
+    pub default_role_id: Option<String>,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ScimConfigUpdate {
+    MappingsUpdate {
+        group_role_mappings: serde_json::Value,
+        default_role_id: Option<String>,
+    },
+    TokenRotation {
+        hashed_token: HashedApiKey,
+        token_prefix: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = scim_configs)]
+pub(crate) struct ScimConfigUpdateInternal {
+    hashed_token: Option<HashedApiKey>,
+    token_prefix: Option<String>,
+    group_role_mappings: Option<serde_json::Value>,
+    default_role_id: Option<Option<String>>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<ScimConfigUpdate> for ScimConfigUpdateInternal {
+    fn from(scim_config_update: ScimConfigUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match scim_config_update {
+            ScimConfigUpdate::MappingsUpdate {
+                group_role_mappings,
+                default_role_id,
+            } => Self {
+                hashed_token: None,
+                token_prefix: None,
+                group_role_mappings: Some(group_role_mappings),
+                default_role_id: Some(default_role_id),
+                last_modified_at,
+            },
+            ScimConfigUpdate::TokenRotation {
+                hashed_token,
+                token_prefix,
+            } => Self {
+                hashed_token: Some(hashed_token),
+                token_prefix: Some(token_prefix),
+                group_role_mappings: None,
+                default_role_id: None,
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::shared_payment_method;
+
+/// A payment method of a merchant account shared with another merchant account of the same
+/// organization, mapping the payment method of the source merchant account to the copy of the
+/// payment method created for the target merchant account. The customer of the source merchant
+/// account is mapped to a customer created for the target merchant account, since customer IDs
+/// are chosen by each merchant account.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = shared_payment_method, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct SharedPaymentMethod {
+    pub id: String,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub source_merchant_id: common_utils::id_type::MerchantId,
+    pub source_payment_method_id: String,
+    pub target_merchant_id: common_utils::id_type::MerchantId,
+    pub target_payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub target_customer_id: common_utils::id_type::CustomerId,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = shared_payment_method)]
+pub struct SharedPaymentMethodNew {
+    pub id: String,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub source_merchant_id: common_utils::id_type::MerchantId,
+    pub source_payment_method_id: String,
+    pub target_merchant_id: common_utils::id_type::MerchantId,
+    pub target_payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub target_customer_id: common_utils::id_type::CustomerId,
+}
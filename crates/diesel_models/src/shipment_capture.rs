@@ -0,0 +1,58 @@
+use common_utils::types::MinorUnit;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::shipment_capture;
+
+/// A shipment of a payment notified by the order management system of the merchant, for which
+/// the payment is captured
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(
+    table_name = shipment_capture,
+    primary_key(merchant_id, payment_id, shipment_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ShipmentCapture {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub shipment_id: String,
+    /// The line items shipped, if the amount of the shipped line items is captured
+    pub line_items: Option<ShipmentCaptureLineItems>,
+    pub amount_to_capture: Option<MinorUnit>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = shipment_capture)]
+pub struct ShipmentCaptureNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub shipment_id: String,
+    pub line_items: Option<ShipmentCaptureLineItems>,
+    pub amount_to_capture: Option<MinorUnit>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// A line item of the order details of a payment captured on a shipment
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ShipmentCaptureLineItem {
+    pub product_name: String,
+    pub product_id: Option<String>,
+    /// The quantity of the product shipped
+    pub quantity: u16,
+    /// The amount per quantity of the product
+    pub amount: MinorUnit,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ShipmentCaptureLineItems(pub Vec<ShipmentCaptureLineItem>);
+
+common_utils::impl_to_sql_from_sql_json!(ShipmentCaptureLineItems);
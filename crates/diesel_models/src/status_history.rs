@@ -0,0 +1,38 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::status_history};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = status_history, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct StatusHistory {
+    pub id: i32,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub entity_type: storage_enums::StatusHistoryEntityType,
+    pub entity_id: String,
+    pub attempt_id: Option<String>,
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub trigger: String,
+    pub connector: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = status_history)]
+pub struct StatusHistoryNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub entity_type: storage_enums::StatusHistoryEntityType,
+    pub entity_id: String,
+    pub attempt_id: Option<String>,
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub trigger: String,
+    pub connector: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -8,6 +8,7 @@ use crate::{diesel_impl::OptionalDieselArray, enums::TotpStatus, schema::users};
 pub mod dashboard_metadata;
 pub mod sample_data;
 pub mod theme;
+pub mod webauthn_credential;
 
 #[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
 #[diesel(table_name = users, primary_key(user_id), check_for_backend(diesel::pg::Pg))]
@@ -52,8 +53,8 @@ pub struct UserUpdateInternal {
     is_verified: Option<bool>,
     last_modified_at: PrimitiveDateTime,
     totp_status: Option<TotpStatus>,
-    totp_secret: Option<Encryption>,
-    totp_recovery_codes: Option<Vec<Secret<String>>>,
+    totp_secret: Option<Option<Encryption>>,
+    totp_recovery_codes: Option<Option<Vec<Secret<String>>>>,
     last_password_modified_at: Option<PrimitiveDateTime>,
 }
 
@@ -72,6 +73,9 @@ pub enum UserUpdate {
     PasswordUpdate {
         password: Secret<String>,
     },
+    /// Removes the TOTP secret and the recovery codes of the user, so that the user sets up two
+    /// factor authentication again
+    TwoFactorAuthReset,
 }
 
 impl From<UserUpdate> for UserUpdateInternal {
@@ -108,8 +112,8 @@ impl From<UserUpdate> for UserUpdateInternal {
                 is_verified: None,
                 last_modified_at,
                 totp_status,
-                totp_secret,
-                totp_recovery_codes,
+                totp_secret: totp_secret.map(Some),
+                totp_recovery_codes: totp_recovery_codes.map(Some),
                 last_password_modified_at: None,
             },
             UserUpdate::PasswordUpdate { password } => Self {
@@ -122,6 +126,16 @@ impl From<UserUpdate> for UserUpdateInternal {
                 totp_secret: None,
                 totp_recovery_codes: None,
             },
+            UserUpdate::TwoFactorAuthReset => Self {
+                name: None,
+                password: None,
+                is_verified: None,
+                last_modified_at,
+                totp_status: Some(TotpStatus::NotSet),
+                totp_secret: Some(None),
+                totp_recovery_codes: Some(None),
+                last_password_modified_at: None,
+            },
         }
     }
 }
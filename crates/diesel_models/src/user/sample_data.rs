@@ -203,6 +203,9 @@ pub struct PaymentAttemptBatchNew {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub presentment_currency: Option<Currency>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub exchange_rate: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -282,6 +285,9 @@ impl PaymentAttemptBatchNew {
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
             connector_mandate_detail: self.connector_mandate_detail,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            exchange_rate: self.exchange_rate,
         }
     }
 }
@@ -0,0 +1,52 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::user_webauthn_credentials;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = user_webauthn_credentials, check_for_backend(diesel::pg::Pg))]
+pub struct UserWebauthnCredential {
+    pub id: String,
+    pub user_id: String,
+    pub credential_id: String,
+    pub name: String,
+    pub credential: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_webauthn_credentials)]
+pub struct UserWebauthnCredentialNew {
+    pub id: String,
+    pub user_id: String,
+    pub credential_id: String,
+    pub name: String,
+    pub credential: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum UserWebauthnCredentialUpdate {
+    /// The signature counter of the credential is updated on every authentication
+    CredentialUsed { credential: serde_json::Value },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_webauthn_credentials)]
+pub struct UserWebauthnCredentialUpdateInternal {
+    credential: Option<serde_json::Value>,
+    last_used_at: Option<PrimitiveDateTime>,
+}
+
+impl From<UserWebauthnCredentialUpdate> for UserWebauthnCredentialUpdateInternal {
+    fn from(value: UserWebauthnCredentialUpdate) -> Self {
+        match value {
+            UserWebauthnCredentialUpdate::CredentialUsed { credential } => Self {
+                credential: Some(credential),
+                last_used_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -17,6 +17,7 @@ pub struct UserAuthenticationMethod {
     pub allow_signup: bool,
     pub created_at: PrimitiveDateTime,
     pub last_modified_at: PrimitiveDateTime,
+    pub email_domain: Option<String>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -32,6 +33,7 @@ pub struct UserAuthenticationMethodNew {
     pub allow_signup: bool,
     pub created_at: PrimitiveDateTime,
     pub last_modified_at: PrimitiveDateTime,
+    pub email_domain: Option<String>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -39,6 +41,7 @@ pub struct UserAuthenticationMethodNew {
 pub struct OrgAuthenticationMethodUpdateInternal {
     pub private_config: Option<Encryption>,
     pub public_config: Option<serde_json::Value>,
+    pub email_domain: Option<String>,
     pub last_modified_at: PrimitiveDateTime,
 }
 
@@ -46,6 +49,7 @@ pub enum UserAuthenticationMethodUpdate {
     UpdateConfig {
         private_config: Option<Encryption>,
         public_config: Option<serde_json::Value>,
+        email_domain: Option<String>,
     },
 }
 
@@ -56,9 +60,11 @@ impl From<UserAuthenticationMethodUpdate> for OrgAuthenticationMethodUpdateInter
             UserAuthenticationMethodUpdate::UpdateConfig {
                 private_config,
                 public_config,
+                email_domain,
             } => Self {
                 private_config,
                 public_config,
+                email_domain,
                 last_modified_at,
             },
         }
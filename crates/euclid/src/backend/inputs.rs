@@ -15,6 +15,10 @@ pub struct PaymentMethodInput {
     pub payment_method: Option<enums::PaymentMethod>,
     pub payment_method_type: Option<enums::PaymentMethodType>,
     pub card_network: Option<enums::CardNetwork>,
+    /// Issuer details of the card sourced from the BIN data, matched by `card_issuer` and
+    /// `issuer_card_type` comparisons
+    pub card_issuer: Option<String>,
+    pub issuer_card_type: Option<enums::IssuerCardType>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +32,9 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    /// Tags of the connector account the program is evaluated for, matched by `connector_tag`
+    /// comparisons
+    pub connector_tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +11,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
+    types::EuclidKey,
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -55,6 +56,26 @@ where
             .any(|stmt| Self::eval_statement(stmt, ctx))
     }
 
+    fn statement_uses_key(stmt: &vir::ValuedIfStatement, key: &EuclidKey) -> bool {
+        stmt.condition
+            .iter()
+            .any(|comp| comp.values.iter().any(|value| value.get_key() == *key))
+            || stmt.nested.as_ref().is_some_and(|nested_stmts| {
+                nested_stmts
+                    .iter()
+                    .any(|s| Self::statement_uses_key(s, key))
+            })
+    }
+
+    /// Checks whether a comparison on the key is made in any rule of the program
+    pub fn uses_key(&self, key: &EuclidKey) -> bool {
+        self.program.rules.iter().any(|rule| {
+            rule.statements
+                .iter()
+                .any(|stmt| Self::statement_uses_key(stmt, key))
+        })
+    }
+
     fn eval_program(
         program: &vir::ValuedProgram<O>,
         ctx: &types::Context,
@@ -140,11 +161,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -180,11 +204,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -221,11 +248,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -262,11 +292,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -303,11 +336,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: Some(enums::MandateAcceptanceType::Online),
@@ -344,11 +380,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -385,11 +424,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -426,11 +468,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -467,11 +512,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -510,11 +558,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -551,11 +602,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -601,11 +655,14 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                connector_tags: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -627,4 +684,117 @@ mod test {
             "rule_1"
         );
     }
+    #[test]
+    fn test_connector_tag() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           connector_tag = "high_risk"
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_untagged = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                connector_tags: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+                card_issuer: None,
+                issuer_card_type: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_tagged = inp_untagged.clone();
+        inp_tagged.payment.connector_tags = Some(vec!["eu".to_string(), "high_risk".to_string()]);
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        assert!(backend.uses_key(&EuclidKey::ConnectorTag));
+        let result_untagged = backend.execute(inp_untagged).expect("Execution");
+        let result_tagged = backend.execute(inp_tagged).expect("Execution");
+        assert!(result_untagged.rule_name.is_none());
+        assert_eq!(
+            result_tagged.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+    }
+    #[test]
+    fn test_card_issuer_details() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           issuer_card_type = debit & card_bin_number >= 400000 & card_bin_number <= 499999
+        }
+
+        rule_2: ["stripe"]
+        {
+           card_issuer = "Chase Bank"
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_debit = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: Some("424242".to_string()),
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                connector_tags: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Debit),
+                card_network: None,
+                card_issuer: Some("Chase Bank".to_string()),
+                issuer_card_type: Some(enums::IssuerCardType::Debit),
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_out_of_range = inp_debit.clone();
+        inp_out_of_range.payment.card_bin = Some("510510".to_string());
+        let mut inp_prepaid = inp_debit.clone();
+        inp_prepaid.payment_method.issuer_card_type = Some(enums::IssuerCardType::Prepaid);
+        inp_prepaid.payment_method.card_issuer = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_debit = backend.execute(inp_debit).expect("Execution");
+        let result_out_of_range = backend.execute(inp_out_of_range).expect("Execution");
+        let result_prepaid = backend.execute(inp_prepaid).expect("Execution");
+        assert_eq!(
+            result_debit.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_out_of_range.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert!(result_prepaid.rule_name.is_none());
+    }
 }
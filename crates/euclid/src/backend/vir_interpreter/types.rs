@@ -57,6 +57,13 @@ impl Context {
         let meta_data = input.metadata;
         let payment_mandate = input.mandate;
 
+        // The BIN is compared as a number as well, so that ranges of BINs can be matched
+        let card_bin_number = payment
+            .card_bin
+            .as_ref()
+            .and_then(|card_bin| card_bin.parse::<i64>().ok())
+            .map(common_utils::types::MinorUnit::new);
+
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
 
@@ -78,6 +85,14 @@ impl Context {
             enum_values.insert(EuclidValue::CardNetwork(card_network));
         }
 
+        if let Some(card_issuer) = payment_method.card_issuer {
+            enum_values.insert(EuclidValue::CardIssuer(StrValue { value: card_issuer }));
+        }
+
+        if let Some(issuer_card_type) = payment_method.issuer_card_type {
+            enum_values.insert(EuclidValue::IssuerCardType(issuer_card_type));
+        }
+
         if let Some(at) = payment.authentication_type {
             enum_values.insert(EuclidValue::AuthenticationType(at));
         }
@@ -104,6 +119,11 @@ impl Context {
         if let Some(setup_future_usage) = payment.setup_future_usage {
             enum_values.insert(EuclidValue::SetupFutureUsage(setup_future_usage));
         }
+        for connector_tag in payment.connector_tags.unwrap_or_default() {
+            enum_values.insert(EuclidValue::ConnectorTag(StrValue {
+                value: connector_tag,
+            }));
+        }
         if let Some(payment_type) = payment_mandate.payment_type {
             enum_values.insert(EuclidValue::PaymentType(payment_type));
         }
@@ -114,13 +134,22 @@ impl Context {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(card_bin_number) = card_bin_number {
+            numeric_values.insert(
+                EuclidKey::CardBinNumber,
+                EuclidValue::CardBinNumber(types::NumValue {
+                    number: card_bin_number,
+                    refinement: None,
+                }),
+            );
+        }
 
         Self {
             atomic_values: enum_values,
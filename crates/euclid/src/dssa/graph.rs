@@ -44,6 +44,9 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CardBin(bin) => bin.value.clone(),
             Self::CardType(ct) => ct.to_string(),
             Self::CardNetwork(cn) => cn.to_string(),
+            Self::CardBinNumber(bin) => bin.number.to_string(),
+            Self::CardIssuer(ci) => ci.value.clone(),
+            Self::IssuerCardType(ict) => ict.to_string(),
             Self::PayLaterType(plt) => plt.to_string(),
             Self::WalletType(wt) => wt.to_string(),
             Self::UpiType(ut) => ut.to_string(),
@@ -66,6 +69,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::VoucherType(vt) => vt.to_string(),
             Self::GiftCardType(gct) => gct.to_string(),
             Self::BusinessLabel(bl) => bl.value.to_string(),
+            Self::ConnectorTag(ct) => ct.value.to_string(),
             Self::SetupFutureUsage(sfu) => sfu.to_string(),
             Self::CardRedirectType(crt) => crt.to_string(),
             Self::RealTimePaymentType(rtpt) => rtpt.to_string(),
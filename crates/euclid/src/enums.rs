@@ -34,6 +34,7 @@ collect_variants!(CaptureMethod);
 collect_variants!(Currency);
 collect_variants!(Country);
 collect_variants!(SetupFutureUsage);
+collect_variants!(IssuerCardType);
 #[cfg(feature = "payouts")]
 collect_variants!(PayoutType);
 #[cfg(feature = "payouts")]
@@ -84,6 +85,29 @@ pub enum PaymentType {
     PptMandate,
 }
 
+/// Type of the payment card as per the BIN data, which is parsed case insensitively since the
+/// BIN data reports it in upper case
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum IssuerCardType {
+    Credit,
+    Debit,
+    Prepaid,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -289,12 +289,20 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
             lower_str!(CardBin, value, validation_closure)
         }
 
+        dir::DirKeyKind::CardIssuer => lower_str!(CardIssuer, value),
+
+        dir::DirKeyKind::IssuerCardType => lower_enum!(IssuerCardType, value),
+
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
 
+        dir::DirKeyKind::ConnectorTag => lower_str!(ConnectorTag, value),
+
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::CardBinNumber => lower_number!(CardBinNumber, value, comparison),
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
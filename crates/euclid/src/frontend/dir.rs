@@ -100,6 +100,27 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_network")]
     CardNetwork,
+    #[strum(
+        serialize = "card_bin_number",
+        detailed_message = "First 6 digits of a payment card number as a number, for matching ranges of BINs",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_bin_number")]
+    CardBinNumber,
+    #[strum(
+        serialize = "card_issuer",
+        detailed_message = "Name of the issuer of the payment card, as per the BIN data",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_issuer")]
+    CardIssuer,
+    #[strum(
+        serialize = "issuer_card_type",
+        detailed_message = "Type of the payment card as per the BIN data - eg. credit, debit, prepaid",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "issuer_card_type")]
+    IssuerCardType,
     #[strum(
         serialize = "pay_later",
         detailed_message = "Supported types of Pay Later payment method",
@@ -250,6 +271,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "business_label")]
     BusinessLabel,
+    #[strum(
+        serialize = "connector_tag",
+        detailed_message = "Tag of the merchant connector account",
+        props(Category = "Merchant")
+    )]
+    #[serde(rename = "connector_tag")]
+    ConnectorTag,
     #[strum(
         serialize = "setup_future_usage",
         detailed_message = "Identifier for recurring payments",
@@ -308,6 +336,9 @@ impl DirKeyKind {
             Self::CardBin => types::DataType::StrValue,
             Self::CardType => types::DataType::EnumVariant,
             Self::CardNetwork => types::DataType::EnumVariant,
+            Self::CardBinNumber => types::DataType::Number,
+            Self::CardIssuer => types::DataType::StrValue,
+            Self::IssuerCardType => types::DataType::EnumVariant,
             Self::MetaData => types::DataType::MetadataValue,
             Self::MandateType => types::DataType::EnumVariant,
             Self::PaymentType => types::DataType::EnumVariant,
@@ -330,6 +361,7 @@ impl DirKeyKind {
             Self::Connector => types::DataType::EnumVariant,
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
+            Self::ConnectorTag => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
             Self::CardRedirectType => types::DataType::EnumVariant,
             Self::RealTimePaymentType => types::DataType::EnumVariant,
@@ -346,6 +378,13 @@ impl DirKeyKind {
             ),
             Self::CardBin => None,
             Self::CardType => Some(enums::CardType::iter().map(DirValue::CardType).collect()),
+            Self::CardBinNumber => None,
+            Self::CardIssuer => None,
+            Self::IssuerCardType => Some(
+                euclid_enums::IssuerCardType::iter()
+                    .map(DirValue::IssuerCardType)
+                    .collect(),
+            ),
             Self::MandateAcceptanceType => Some(
                 euclid_enums::MandateAcceptanceType::iter()
                     .map(DirValue::MandateAcceptanceType)
@@ -447,6 +486,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::BusinessLabel => None,
+            Self::ConnectorTag => None,
             Self::SetupFutureUsage => Some(
                 enums::SetupFutureUsage::iter()
                     .map(DirValue::SetupFutureUsage)
@@ -489,6 +529,12 @@ pub enum DirValue {
     CardType(enums::CardType),
     #[serde(rename = "card_network")]
     CardNetwork(enums::CardNetwork),
+    #[serde(rename = "card_bin_number")]
+    CardBinNumber(types::NumValue),
+    #[serde(rename = "card_issuer")]
+    CardIssuer(types::StrValue),
+    #[serde(rename = "issuer_card_type")]
+    IssuerCardType(euclid_enums::IssuerCardType),
     #[serde(rename = "metadata")]
     MetaData(types::MetadataValue),
     #[serde(rename = "pay_later")]
@@ -533,6 +579,8 @@ pub enum DirValue {
     Connector(Box<ast::ConnectorChoice>),
     #[serde(rename = "business_label")]
     BusinessLabel(types::StrValue),
+    #[serde(rename = "connector_tag")]
+    ConnectorTag(types::StrValue),
     #[serde(rename = "setup_future_usage")]
     SetupFutureUsage(enums::SetupFutureUsage),
     #[serde(rename = "card_redirect")]
@@ -557,6 +605,9 @@ impl DirValue {
             Self::UpiType(_) => (DirKeyKind::UpiType, None),
             Self::CardType(_) => (DirKeyKind::CardType, None),
             Self::CardNetwork(_) => (DirKeyKind::CardNetwork, None),
+            Self::CardBinNumber(_) => (DirKeyKind::CardBinNumber, None),
+            Self::CardIssuer(_) => (DirKeyKind::CardIssuer, None),
+            Self::IssuerCardType(_) => (DirKeyKind::IssuerCardType, None),
             Self::MetaData(met) => (DirKeyKind::MetaData, Some(met.key.clone())),
             Self::PayLaterType(_) => (DirKeyKind::PayLaterType, None),
             Self::WalletType(_) => (DirKeyKind::WalletType, None),
@@ -572,6 +623,7 @@ impl DirValue {
             Self::MandateType(_) => (DirKeyKind::MandateType, None),
             Self::PaymentType(_) => (DirKeyKind::PaymentType, None),
             Self::BusinessLabel(_) => (DirKeyKind::BusinessLabel, None),
+            Self::ConnectorTag(_) => (DirKeyKind::ConnectorTag, None),
             Self::SetupFutureUsage(_) => (DirKeyKind::SetupFutureUsage, None),
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
@@ -590,6 +642,9 @@ impl DirValue {
             Self::CardBin(_) => None,
             Self::CardType(_) => None,
             Self::CardNetwork(_) => None,
+            Self::CardBinNumber(_) => None,
+            Self::CardIssuer(_) => None,
+            Self::IssuerCardType(_) => None,
             Self::PayLaterType(_) => None,
             Self::WalletType(_) => None,
             Self::BankRedirectType(_) => None,
@@ -611,6 +666,7 @@ impl DirValue {
             Self::MandateType(_) => None,
             Self::PaymentType(_) => None,
             Self::BusinessLabel(_) => None,
+            Self::ConnectorTag(_) => None,
             Self::SetupFutureUsage(_) => None,
             Self::CardRedirectType(_) => None,
             Self::RealTimePaymentType(_) => None,
@@ -622,6 +678,7 @@ impl DirValue {
     pub fn get_str_val(&self) -> Option<types::StrValue> {
         match self {
             Self::CardBin(val) => Some(val.clone()),
+            Self::CardIssuer(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -629,6 +686,7 @@ impl DirValue {
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::CardBinNumber(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -638,6 +696,8 @@ impl DirValue {
             (Self::PaymentMethod(pm1), Self::PaymentMethod(pm2)) => pm1 == pm2,
             (Self::CardType(ct1), Self::CardType(ct2)) => ct1 == ct2,
             (Self::CardNetwork(cn1), Self::CardNetwork(cn2)) => cn1 == cn2,
+            (Self::CardIssuer(ci1), Self::CardIssuer(ci2)) => ci1 == ci2,
+            (Self::IssuerCardType(ict1), Self::IssuerCardType(ict2)) => ict1 == ict2,
             (Self::MetaData(md1), Self::MetaData(md2)) => md1 == md2,
             (Self::PayLaterType(plt1), Self::PayLaterType(plt2)) => plt1 == plt2,
             (Self::WalletType(wt1), Self::WalletType(wt2)) => wt1 == wt2,
@@ -658,6 +718,7 @@ impl DirValue {
             (Self::RealTimePaymentType(rtp1), Self::RealTimePaymentType(rtp2)) => rtp1 == rtp2,
             (Self::Connector(c1), Self::Connector(c2)) => c1 == c2,
             (Self::BusinessLabel(bl1), Self::BusinessLabel(bl2)) => bl1 == bl2,
+            (Self::ConnectorTag(ct1), Self::ConnectorTag(ct2)) => ct1 == ct2,
             (Self::SetupFutureUsage(sfu1), Self::SetupFutureUsage(sfu2)) => sfu1 == sfu2,
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
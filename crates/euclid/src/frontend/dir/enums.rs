@@ -3,8 +3,9 @@ use strum::VariantNames;
 use crate::enums::collect_variants;
 pub use crate::enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
-    Country as BillingCountry, CountryAlpha2, Currency as PaymentCurrency, MandateAcceptanceType,
-    MandateType, PaymentMethod, PaymentType, RoutableConnectors, SetupFutureUsage,
+    Country as BillingCountry, CountryAlpha2, Currency as PaymentCurrency, IssuerCardType,
+    MandateAcceptanceType, MandateType, PaymentMethod, PaymentType, RoutableConnectors,
+    SetupFutureUsage,
 };
 #[cfg(feature = "payouts")]
 pub use crate::enums::{PayoutBankTransferType, PayoutType, PayoutWalletType};
@@ -211,6 +212,7 @@ pub enum BankTransferType {
     Pix,
     Pse,
     LocalBankTransfer,
+    VirtualAccount,
 }
 
 #[derive(
@@ -120,6 +120,7 @@ impl From<enums::BankTransferType> for global_enums::PaymentMethodType {
             enums::BankTransferType::MandiriVa => Self::MandiriVa,
             enums::BankTransferType::PermataBankTransfer => Self::PermataBankTransfer,
             enums::BankTransferType::LocalBankTransfer => Self::LocalBankTransfer,
+            enums::BankTransferType::VirtualAccount => Self::VirtualAccount,
         }
     }
 }
@@ -228,6 +229,9 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::CardBin(ci) => EuclidValue::CardBin(ci),
         dir::DirValue::CardType(ct) => EuclidValue::PaymentMethodType(ct.into()),
         dir::DirValue::CardNetwork(cn) => EuclidValue::CardNetwork(cn),
+        dir::DirValue::CardBinNumber(bin) => EuclidValue::CardBinNumber(bin),
+        dir::DirValue::CardIssuer(ci) => EuclidValue::CardIssuer(ci),
+        dir::DirValue::IssuerCardType(ict) => EuclidValue::IssuerCardType(ict),
         dir::DirValue::MetaData(md) => EuclidValue::Metadata(md),
         dir::DirValue::PayLaterType(plt) => EuclidValue::PaymentMethodType(plt.into()),
         dir::DirValue::WalletType(wt) => EuclidValue::PaymentMethodType(wt.into()),
@@ -254,6 +258,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::BankDebitType(bdt) => EuclidValue::PaymentMethodType(bdt.into()),
         dir::DirValue::RewardType(rt) => EuclidValue::PaymentMethodType(rt.into()),
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
+        dir::DirValue::ConnectorTag(ct) => EuclidValue::ConnectorTag(ct),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
         dir::DirValue::OpenBankingType(ob) => EuclidValue::PaymentMethodType(ob.into()),
         dir::DirValue::MobilePaymentType(mp) => EuclidValue::PaymentMethodType(mp.into()),
@@ -165,6 +165,9 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
             global_enums::PaymentMethodType::LocalBankTransfer => {
                 Ok(dirval!(BankTransferType = LocalBankTransfer))
             }
+            global_enums::PaymentMethodType::VirtualAccount => {
+                Ok(dirval!(BankTransferType = VirtualAccount))
+            }
             global_enums::PaymentMethodType::PermataBankTransfer => {
                 Ok(dirval!(BankTransferType = PermataBankTransfer))
             }
@@ -44,6 +44,12 @@ pub enum EuclidKey {
     PaymentMethodType,
     #[strum(serialize = "card_network")]
     CardNetwork,
+    #[strum(serialize = "card_bin_number")]
+    CardBinNumber,
+    #[strum(serialize = "card_issuer")]
+    CardIssuer,
+    #[strum(serialize = "issuer_card_type")]
+    IssuerCardType,
     #[strum(serialize = "authentication_type")]
     AuthenticationType,
     #[strum(serialize = "capture_method")]
@@ -58,6 +64,8 @@ pub enum EuclidKey {
     BillingCountry,
     #[strum(serialize = "business_label")]
     BusinessLabel,
+    #[strum(serialize = "connector_tag")]
+    ConnectorTag,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
 }
@@ -77,6 +85,10 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::ConnectorTag,
+        DirKeyKind::CardBinNumber,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IssuerCardType,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -127,6 +139,9 @@ impl EuclidKey {
             Self::Metadata => DataType::MetadataValue,
             Self::PaymentMethodType => DataType::EnumVariant,
             Self::CardNetwork => DataType::EnumVariant,
+            Self::CardBinNumber => DataType::Number,
+            Self::CardIssuer => DataType::StrValue,
+            Self::IssuerCardType => DataType::EnumVariant,
             Self::AuthenticationType => DataType::EnumVariant,
             Self::CaptureMethod => DataType::EnumVariant,
             Self::PaymentAmount => DataType::Number,
@@ -137,6 +152,7 @@ impl EuclidKey {
             Self::MandateAcceptanceType => DataType::EnumVariant,
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
+            Self::ConnectorTag => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
         }
     }
@@ -242,6 +258,9 @@ pub enum EuclidValue {
     Metadata(MetadataValue),
     PaymentMethodType(enums::PaymentMethodType),
     CardNetwork(enums::CardNetwork),
+    CardBinNumber(NumValue),
+    CardIssuer(StrValue),
+    IssuerCardType(enums::IssuerCardType),
     AuthenticationType(enums::AuthenticationType),
     CaptureMethod(enums::CaptureMethod),
     PaymentType(enums::PaymentType),
@@ -252,6 +271,7 @@ pub enum EuclidValue {
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
+    ConnectorTag(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
 }
 
@@ -259,6 +279,7 @@ impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::CardBinNumber(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -273,6 +294,9 @@ impl EuclidValue {
             Self::PaymentType(_) => EuclidKey::PaymentType,
             Self::MandateAcceptanceType(_) => EuclidKey::MandateAcceptanceType,
             Self::CardNetwork(_) => EuclidKey::CardNetwork,
+            Self::CardBinNumber(_) => EuclidKey::CardBinNumber,
+            Self::CardIssuer(_) => EuclidKey::CardIssuer,
+            Self::IssuerCardType(_) => EuclidKey::IssuerCardType,
             Self::AuthenticationType(_) => EuclidKey::AuthenticationType,
             Self::CaptureMethod(_) => EuclidKey::CaptureMethod,
             Self::PaymentAmount(_) => EuclidKey::PaymentAmount,
@@ -280,6 +304,7 @@ impl EuclidValue {
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
+            Self::ConnectorTag(_) => EuclidKey::ConnectorTag,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
         }
     }
@@ -255,6 +255,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::SetupFutureUsage => dir_enums::SetupFutureUsage::VARIANTS,
         dir::DirKeyKind::PaymentType => dir_enums::PaymentType::VARIANTS,
         dir::DirKeyKind::MandateType => dir_enums::MandateType::VARIANTS,
+        dir::DirKeyKind::IssuerCardType => dir_enums::IssuerCardType::VARIANTS,
         dir::DirKeyKind::MandateAcceptanceType => dir_enums::MandateAcceptanceType::VARIANTS,
         dir::DirKeyKind::CardRedirectType => dir_enums::CardRedirectType::VARIANTS,
         dir::DirKeyKind::GiftCardType => dir_enums::GiftCardType::VARIANTS,
@@ -267,7 +268,10 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
+        | dir::DirKeyKind::CardBinNumber
+        | dir::DirKeyKind::CardIssuer
         | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::ConnectorTag
         | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
     };
 
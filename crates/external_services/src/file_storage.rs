@@ -3,6 +3,7 @@
 use std::{
     fmt::{Display, Formatter},
     sync::Arc,
+    time::Duration,
 };
 
 use common_utils::errors::CustomResult;
@@ -64,6 +65,14 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
 
     /// Retrieves a file from the selected storage scheme.
     async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    /// Generates a short-lived URL from which the file can be downloaded directly from the
+    /// selected storage scheme. Returns `None` if the storage scheme does not support such URLs.
+    async fn get_download_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<Option<String>, FileStorageError>;
 }
 
 dyn_clone::clone_trait_object!(FileStorageInterface);
@@ -94,4 +103,8 @@ pub enum FileStorageError {
     /// Indicates that the file deletion operation failed.
     #[error("Failed to delete file")]
     DeleteFailed,
+
+    /// Indicates that generating the download URL for the file failed.
+    #[error("Failed to generate download URL for file")]
+    DownloadUrlGenerationFailed,
 }
@@ -1,8 +1,11 @@
+use std::time::Duration;
+
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
     operation::{
         delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
     },
+    presigning::{PresigningConfig, PresigningConfigError},
     Client,
 };
 use aws_sdk_sts::config::Region;
@@ -104,6 +107,25 @@ impl AwsFileStorageClient {
             .map_err(AwsS3StorageError::UnknownError)?
             .to_vec())
     }
+
+    /// Generates a presigned URL for downloading a file from AWS S3.
+    async fn get_presigned_download_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, AwsS3StorageError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(AwsS3StorageError::PresigningConfigFailure)?;
+        let presigned_request = self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .presigned(presigning_config)
+            .await
+            .map_err(AwsS3StorageError::PresignFailure)?;
+        Ok(presigned_request.uri().to_string())
+    }
 }
 
 #[async_trait::async_trait]
@@ -135,6 +157,18 @@ impl FileStorageInterface for AwsFileStorageClient {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Generates a presigned URL for downloading a file from AWS S3.
+    async fn get_download_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<Option<String>, FileStorageError> {
+        self.get_presigned_download_url(file_key, expires_in)
+            .await
+            .change_context(FileStorageError::DownloadUrlGenerationFailed)
+            .map(Some)
+    }
 }
 
 /// Enum representing errors that can occur during AWS S3 file storage operations.
@@ -152,6 +186,14 @@ enum AwsS3StorageError {
     #[error("File delete from S3 failed: {0:?}")]
     DeleteFailure(aws_smithy_client::SdkError<DeleteObjectError>),
 
+    /// Error indicating that the presigning configuration is invalid.
+    #[error("Invalid presigning configuration for S3: {0:?}")]
+    PresigningConfigFailure(PresigningConfigError),
+
+    /// Error indicating that presigning the S3 request failed.
+    #[error("Presigning the S3 request failed: {0:?}")]
+    PresignFailure(aws_smithy_client::SdkError<GetObjectError>),
+
     /// Unknown error occurred.
     #[error("Unknown error occurred: {0:?}")]
     UnknownError(aws_sdk_s3::primitives::ByteStreamError),
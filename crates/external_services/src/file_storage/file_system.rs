@@ -4,6 +4,7 @@ use std::{
     fs::{remove_file, File},
     io::{Read, Write},
     path::PathBuf,
+    time::Duration,
 };
 
 use common_utils::errors::CustomResult;
@@ -100,6 +101,15 @@ impl FileStorageInterface for FileSystem {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Files stored in the local file system can only be downloaded through the application.
+    async fn get_download_url(
+        &self,
+        _file_key: &str,
+        _expires_in: Duration,
+    ) -> CustomResult<Option<String>, FileStorageError> {
+        Ok(None)
+    }
 }
 
 /// Represents an error that can occur during local file system storage operations.
@@ -19,6 +19,8 @@ pub mod grpc_client;
 
 pub mod managers;
 
+pub mod messaging;
+
 /// Crate specific constants
 #[cfg(feature = "aws_kms")]
 pub mod consts {
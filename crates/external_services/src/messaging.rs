@@ -0,0 +1,159 @@
+//! Module for sending messages to customers over channels such as SMS and WhatsApp, with support
+//! for multiple messaging providers.
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use common_enums::{LinkDeliveryStatus, MessagingChannel};
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+/// Implementation of the messaging client when messaging support is disabled
+mod no_messaging;
+
+/// Implementation of the Twilio messaging client
+mod twilio;
+
+/// Enum representing the supported messaging providers
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(tag = "messaging_provider")]
+#[serde(rename_all = "snake_case")]
+pub enum MessagingConfig {
+    /// Messaging is disabled
+    #[default]
+    NoMessaging,
+    /// Twilio messaging configuration
+    Twilio {
+        /// Configuration for sending messages via Twilio
+        twilio: twilio::TwilioConfig,
+    },
+}
+
+impl MessagingConfig {
+    /// Validates the messaging configuration.
+    pub fn validate(&self) -> Result<(), InvalidMessagingConfig> {
+        match self {
+            Self::NoMessaging => Ok(()),
+            Self::Twilio { twilio } => twilio.validate(),
+        }
+    }
+
+    /// Returns whether a messaging provider has been configured
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::NoMessaging)
+    }
+
+    /// Retrieves the appropriate messaging client based on the messaging configuration.
+    pub fn get_messaging_client(&self) -> Arc<dyn MessagingInterface> {
+        match self {
+            Self::NoMessaging => Arc::new(no_messaging::NoMessagingClient),
+            Self::Twilio { twilio } => Arc::new(twilio::TwilioClient::new(twilio.clone())),
+        }
+    }
+}
+
+/// A message to be sent to a customer
+#[derive(Debug, Clone)]
+pub struct Message {
+    /// Phone number of the recipient in the E.164 format
+    pub recipient: Secret<String>,
+    /// Text of the message
+    pub body: String,
+    /// Channel over which the message is sent
+    pub channel: MessagingChannel,
+    /// URL to which the messaging provider sends updates of the delivery status of the message
+    pub status_callback_url: Option<String>,
+}
+
+/// Response of the messaging provider once a message is accepted for delivery
+#[derive(Debug, Clone)]
+pub struct MessageSendResponse {
+    /// Identifier of the message at the messaging provider
+    pub provider_message_id: String,
+    /// Delivery status of the message
+    pub status: LinkDeliveryStatus,
+}
+
+/// A status callback received from the messaging provider
+#[derive(Debug, Clone)]
+pub struct MessageStatusCallback {
+    /// The full URL the callback was received on
+    pub url: String,
+    /// Value of the signature header of the callback, if present
+    pub signature: Option<String>,
+    /// Raw body of the callback
+    pub body: Vec<u8>,
+}
+
+/// Update of the delivery status of a message, parsed from a status callback
+#[derive(Debug, Clone)]
+pub struct MessageStatusUpdate {
+    /// Identifier of the message at the messaging provider
+    pub provider_message_id: String,
+    /// Delivery status of the message
+    pub status: LinkDeliveryStatus,
+    /// Error code returned by the messaging provider, if the delivery failed
+    pub error_code: Option<String>,
+}
+
+/// Trait for messaging operations
+#[async_trait::async_trait]
+pub trait MessagingInterface: dyn_clone::DynClone + Sync + Send {
+    /// Name of the messaging provider
+    fn provider_name(&self) -> &'static str;
+
+    /// Name of the header which carries the signature of status callbacks
+    fn status_callback_signature_header(&self) -> &'static str;
+
+    /// Sends the message to the recipient.
+    async fn send_message(
+        &self,
+        message: Message,
+    ) -> CustomResult<MessageSendResponse, MessagingError>;
+
+    /// Verifies the signature of a status callback and parses the delivery status update from it.
+    fn verify_and_parse_status_callback(
+        &self,
+        callback: &MessageStatusCallback,
+    ) -> CustomResult<MessageStatusUpdate, MessagingError>;
+}
+
+dyn_clone::clone_trait_object!(MessagingInterface);
+
+/// Error thrown when the messaging config is invalid
+#[derive(Debug, Clone)]
+pub struct InvalidMessagingConfig(&'static str);
+
+impl std::error::Error for InvalidMessagingConfig {}
+
+impl Display for InvalidMessagingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "messaging: {}", self.0)
+    }
+}
+
+/// Errors that could occur from the messaging client.
+#[derive(Debug, thiserror::Error)]
+pub enum MessagingError {
+    /// Messaging is not enabled
+    #[error("Messaging is not enabled")]
+    NotEnabled,
+
+    /// The channel is not configured for the messaging provider
+    #[error("The {0} channel is not configured")]
+    ChannelNotConfigured(MessagingChannel),
+
+    /// An error occurred when sending the message
+    #[error("Failed to send message: {0}")]
+    SendingFailure(String),
+
+    /// The signature of the status callback is missing or invalid
+    #[error("Invalid status callback signature")]
+    InvalidSignature,
+
+    /// The status callback could not be parsed
+    #[error("Failed to parse status callback")]
+    CallbackParsingFailure,
+}
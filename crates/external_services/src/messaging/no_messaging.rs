@@ -0,0 +1,36 @@
+use common_utils::errors::CustomResult;
+use error_stack::report;
+
+use crate::messaging::{
+    Message, MessageSendResponse, MessageStatusCallback, MessageStatusUpdate, MessagingError,
+    MessagingInterface,
+};
+
+/// Client when messaging support is disabled
+#[derive(Debug, Clone)]
+pub(super) struct NoMessagingClient;
+
+#[async_trait::async_trait]
+impl MessagingInterface for NoMessagingClient {
+    fn provider_name(&self) -> &'static str {
+        "no_messaging"
+    }
+
+    fn status_callback_signature_header(&self) -> &'static str {
+        ""
+    }
+
+    async fn send_message(
+        &self,
+        _message: Message,
+    ) -> CustomResult<MessageSendResponse, MessagingError> {
+        Err(report!(MessagingError::NotEnabled))
+    }
+
+    fn verify_and_parse_status_callback(
+        &self,
+        _callback: &MessageStatusCallback,
+    ) -> CustomResult<MessageStatusUpdate, MessagingError> {
+        Err(report!(MessagingError::NotEnabled))
+    }
+}
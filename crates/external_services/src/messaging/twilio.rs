@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+
+use base64::Engine;
+use common_enums::{LinkDeliveryStatus, MessagingChannel};
+use common_utils::{
+    consts::BASE64_ENGINE,
+    crypto::{HmacSha1, SignMessage},
+    errors::CustomResult,
+    ext_traits::ConfigExt,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+
+use super::InvalidMessagingConfig;
+use crate::messaging::{
+    Message, MessageSendResponse, MessageStatusCallback, MessageStatusUpdate, MessagingError,
+    MessagingInterface,
+};
+
+/// Name of the header carrying the signature of Twilio status callbacks
+const TWILIO_SIGNATURE_HEADER: &str = "X-Twilio-Signature";
+
+/// Prefix used by Twilio to address WhatsApp numbers
+const WHATSAPP_ADDRESS_PREFIX: &str = "whatsapp:";
+
+/// Configuration for sending messages via Twilio.
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct TwilioConfig {
+    /// The Twilio account SID
+    account_sid: String,
+    /// The auth token of the Twilio account, also used to verify status callbacks
+    auth_token: Secret<String>,
+    /// The phone number from which SMS messages are sent
+    sms_from_number: String,
+    /// The WhatsApp enabled phone number from which WhatsApp messages are sent
+    whatsapp_from_number: Option<String>,
+    /// The base URL of the Twilio API
+    base_url: String,
+}
+
+impl Default for TwilioConfig {
+    fn default() -> Self {
+        Self {
+            account_sid: String::default(),
+            auth_token: Secret::default(),
+            sms_from_number: String::default(),
+            whatsapp_from_number: None,
+            base_url: "https://api.twilio.com".to_string(),
+        }
+    }
+}
+
+impl TwilioConfig {
+    /// Validates the Twilio messaging configuration.
+    pub(super) fn validate(&self) -> Result<(), InvalidMessagingConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.account_sid.is_default_or_empty(), || {
+            Err(InvalidMessagingConfig(
+                "twilio account sid must not be empty",
+            ))
+        })?;
+
+        when(self.auth_token.is_default_or_empty(), || {
+            Err(InvalidMessagingConfig(
+                "twilio auth token must not be empty",
+            ))
+        })?;
+
+        when(self.sms_from_number.is_default_or_empty(), || {
+            Err(InvalidMessagingConfig(
+                "twilio sms from number must not be empty",
+            ))
+        })?;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(InvalidMessagingConfig("twilio base url must not be empty"))
+        })
+    }
+}
+
+/// Twilio messaging client.
+#[derive(Debug, Clone)]
+pub(super) struct TwilioClient {
+    config: TwilioConfig,
+    client: reqwest::Client,
+}
+
+impl TwilioClient {
+    /// Creates a new Twilio messaging client.
+    pub(super) fn new(config: TwilioConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn get_addresses(
+        &self,
+        channel: MessagingChannel,
+        recipient: &str,
+    ) -> CustomResult<(String, String), MessagingError> {
+        match channel {
+            MessagingChannel::Sms => {
+                Ok((self.config.sms_from_number.clone(), recipient.to_string()))
+            }
+            MessagingChannel::Whatsapp => {
+                let from_number = self
+                    .config
+                    .whatsapp_from_number
+                    .as_ref()
+                    .ok_or(MessagingError::ChannelNotConfigured(channel))?;
+                Ok((
+                    format!("{WHATSAPP_ADDRESS_PREFIX}{from_number}"),
+                    format!("{WHATSAPP_ADDRESS_PREFIX}{recipient}"),
+                ))
+            }
+        }
+    }
+}
+
+/// Response of the Twilio API when a message is created
+#[derive(Debug, serde::Deserialize)]
+struct TwilioMessageResponse {
+    sid: String,
+    status: String,
+}
+
+/// Error response of the Twilio API
+#[derive(Debug, serde::Deserialize)]
+struct TwilioErrorResponse {
+    code: Option<i64>,
+    message: Option<String>,
+}
+
+/// Converts the status of a Twilio message to the delivery status of the link
+fn get_delivery_status(status: &str) -> LinkDeliveryStatus {
+    match status {
+        "accepted" | "scheduled" | "queued" => LinkDeliveryStatus::Queued,
+        "sending" | "sent" => LinkDeliveryStatus::Sent,
+        "delivered" | "read" => LinkDeliveryStatus::Delivered,
+        "failed" | "undelivered" | "canceled" => LinkDeliveryStatus::Failed,
+        _ => LinkDeliveryStatus::Pending,
+    }
+}
+
+/// Computes the signature of a Twilio request: the Base64 encoded HMAC-SHA1 of the full URL
+/// followed by every form parameter name and value, sorted by name, keyed with the auth token.
+fn compute_signature(
+    auth_token: &str,
+    url: &str,
+    params: &BTreeMap<String, String>,
+) -> CustomResult<String, MessagingError> {
+    let payload = params
+        .iter()
+        .fold(url.to_string(), |mut payload, (key, value)| {
+            payload.push_str(key);
+            payload.push_str(value);
+            payload
+        });
+
+    HmacSha1
+        .sign_message(auth_token.as_bytes(), payload.as_bytes())
+        .change_context(MessagingError::InvalidSignature)
+        .map(|signature| BASE64_ENGINE.encode(signature))
+}
+
+#[async_trait::async_trait]
+impl MessagingInterface for TwilioClient {
+    fn provider_name(&self) -> &'static str {
+        "twilio"
+    }
+
+    fn status_callback_signature_header(&self) -> &'static str {
+        TWILIO_SIGNATURE_HEADER
+    }
+
+    async fn send_message(
+        &self,
+        message: Message,
+    ) -> CustomResult<MessageSendResponse, MessagingError> {
+        let (from, to) = self.get_addresses(message.channel, message.recipient.peek())?;
+
+        let mut form = vec![("To", to), ("From", from), ("Body", message.body)];
+        if let Some(status_callback_url) = message.status_callback_url {
+            form.push(("StatusCallback", status_callback_url));
+        }
+
+        let url = format!(
+            "{}/2010-04-01/Accounts/{}/Messages.json",
+            self.config.base_url.trim_end_matches('/'),
+            self.config.account_sid
+        );
+
+        let response = self
+            .client
+            .post(url)
+            .basic_auth(
+                &self.config.account_sid,
+                Some(self.config.auth_token.clone().expose()),
+            )
+            .form(&form)
+            .send()
+            .await
+            .map_err(|error| report!(MessagingError::SendingFailure(error.to_string())))?;
+
+        if !response.status().is_success() {
+            let status_code = response.status();
+            let error = response.json::<TwilioErrorResponse>().await.ok();
+            return Err(report!(MessagingError::SendingFailure(format!(
+                "status code: {status_code}, error code: {:?}, error message: {:?}",
+                error.as_ref().and_then(|error| error.code),
+                error.and_then(|error| error.message)
+            ))));
+        }
+
+        let response = response
+            .json::<TwilioMessageResponse>()
+            .await
+            .map_err(|error| report!(MessagingError::SendingFailure(error.to_string())))?;
+
+        Ok(MessageSendResponse {
+            provider_message_id: response.sid,
+            status: get_delivery_status(&response.status),
+        })
+    }
+
+    fn verify_and_parse_status_callback(
+        &self,
+        callback: &MessageStatusCallback,
+    ) -> CustomResult<MessageStatusUpdate, MessagingError> {
+        let params: BTreeMap<String, String> = serde_urlencoded::from_bytes(&callback.body)
+            .change_context(MessagingError::CallbackParsingFailure)?;
+
+        let expected_signature =
+            compute_signature(self.config.auth_token.peek(), &callback.url, &params)?;
+
+        if callback.signature.as_deref() != Some(expected_signature.as_str()) {
+            return Err(report!(MessagingError::InvalidSignature));
+        }
+
+        let provider_message_id = params
+            .get("MessageSid")
+            .cloned()
+            .ok_or(MessagingError::CallbackParsingFailure)?;
+        let status = params
+            .get("MessageStatus")
+            .map(|status| get_delivery_status(status))
+            .ok_or(MessagingError::CallbackParsingFailure)?;
+
+        Ok(MessageStatusUpdate {
+            provider_message_id,
+            status,
+            error_code: params.get("ErrorCode").cloned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_compute_signature() {
+        let params = BTreeMap::from(
+            [
+                ("CallSid", "CA1234567890ABCDE"),
+                ("Caller", "+12349013030"),
+                ("Digits", "1234"),
+                ("From", "+12349013030"),
+                ("To", "+18005551212"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+
+        let signature = compute_signature(
+            "12345",
+            "https://mycompany.com/myapp.php?foo=1&bar=2",
+            &params,
+        )
+        .expect("failed to compute signature");
+
+        assert_eq!(signature, "0/KCTR6DLpKmkAf8muzZqo1nDgQ=");
+    }
+
+    #[test]
+    fn test_get_delivery_status() {
+        assert_eq!(get_delivery_status("queued"), LinkDeliveryStatus::Queued);
+        assert_eq!(get_delivery_status("sent"), LinkDeliveryStatus::Sent);
+        assert_eq!(get_delivery_status("read"), LinkDeliveryStatus::Delivered);
+        assert_eq!(
+            get_delivery_status("undelivered"),
+            LinkDeliveryStatus::Failed
+        );
+    }
+}
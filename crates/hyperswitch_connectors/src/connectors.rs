@@ -16,6 +16,7 @@ pub mod fiserv;
 pub mod fiservemea;
 pub mod fiuu;
 pub mod forte;
+pub mod generichttp;
 pub mod globepay;
 pub mod gocardless;
 pub mod helcim;
@@ -29,6 +30,7 @@ pub mod nomupay;
 pub mod novalnet;
 pub mod payeezy;
 pub mod payu;
+pub mod plugin;
 pub mod powertranz;
 pub mod prophetpay;
 pub mod rapyd;
@@ -52,11 +54,11 @@ pub use self::{
     billwerk::Billwerk, bitpay::Bitpay, boku::Boku, cashtocode::Cashtocode, coinbase::Coinbase,
     cryptopay::Cryptopay, deutschebank::Deutschebank, digitalvirgo::Digitalvirgo, dlocal::Dlocal,
     elavon::Elavon, fiserv::Fiserv, fiservemea::Fiservemea, fiuu::Fiuu, forte::Forte,
-    globepay::Globepay, gocardless::Gocardless, helcim::Helcim, inespay::Inespay,
-    jpmorgan::Jpmorgan, mollie::Mollie, multisafepay::Multisafepay, nexinets::Nexinets,
-    nexixpay::Nexixpay, nomupay::Nomupay, novalnet::Novalnet, payeezy::Payeezy, payu::Payu,
-    powertranz::Powertranz, prophetpay::Prophetpay, rapyd::Rapyd, razorpay::Razorpay,
-    redsys::Redsys, shift4::Shift4, square::Square, stax::Stax, taxjar::Taxjar, thunes::Thunes,
-    tsys::Tsys, volt::Volt, worldline::Worldline, worldpay::Worldpay, xendit::Xendit, zen::Zen,
-    zsl::Zsl,
+    generichttp::Generichttp, globepay::Globepay, gocardless::Gocardless, helcim::Helcim,
+    inespay::Inespay, jpmorgan::Jpmorgan, mollie::Mollie, multisafepay::Multisafepay,
+    nexinets::Nexinets, nexixpay::Nexixpay, nomupay::Nomupay, novalnet::Novalnet, payeezy::Payeezy,
+    payu::Payu, plugin::Plugin, powertranz::Powertranz, prophetpay::Prophetpay, rapyd::Rapyd,
+    razorpay::Razorpay, redsys::Redsys, shift4::Shift4, square::Square, stax::Stax, taxjar::Taxjar,
+    thunes::Thunes, tsys::Tsys, volt::Volt, worldline::Worldline, worldpay::Worldpay,
+    xendit::Xendit, zen::Zen, zsl::Zsl,
 };
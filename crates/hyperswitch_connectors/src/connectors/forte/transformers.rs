@@ -3,7 +3,7 @@ use common_enums::enums;
 use common_utils::types::FloatMajorUnit;
 use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
-    router_data::{ConnectorAuthType, RouterData},
+    router_data::{ConnectorAuthType, ConnectorResponseCodes, ConnectorResponseData, RouterData},
     router_flow_types::refunds::{Execute, RSync},
     router_request_types::ResponseId,
     router_response_types::{PaymentsResponseData, RefundsResponseData},
@@ -296,8 +296,16 @@ impl<F, T> TryFrom<ResponseRouterData<F, FortePaymentsResponse, T, PaymentsRespo
         let response_code = item.response.response.response_code;
         let action = item.response.action;
         let transaction_id = &item.response.transaction_id;
+        let connector_response =
+            ConnectorResponseData::with_response_codes(ConnectorResponseCodes {
+                decline_code: None,
+                avs_result: item.response.response.avs_result,
+                cvv_result: item.response.response.cvv_result,
+                acquirer_response_code: None,
+            });
         Ok(Self {
             status: get_status(response_code, action),
+            connector_response: Some(connector_response),
             response: Ok(PaymentsResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId(transaction_id.to_string()),
                 redirection_data: Box::new(None),
@@ -0,0 +1,762 @@
+pub mod transformers;
+
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::BytesExt,
+    request::{Request, RequestBuilder, RequestContent},
+    types::{AmountConvertor, MinorUnit, MinorUnitForConnector},
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::{
+    router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::{
+        access_token_auth::AccessTokenAuth,
+        payments::{Authorize, Capture, PSync, PaymentMethodToken, Session, SetupMandate, Void},
+        refunds::{Execute, RSync},
+    },
+    router_request_types::{
+        AccessTokenRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
+        PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData,
+        RefundsData, SetupMandateRequestData,
+    },
+    router_response_types::{PaymentsResponseData, RefundsResponseData},
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
+        PaymentsSyncRouterData, RefundSyncRouterData, RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{
+    api::{self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorValidation},
+    configs::Connectors,
+    consts, errors,
+    events::connector_api_logs::ConnectorEvent,
+    types::Response,
+    webhooks,
+};
+use masking::{ExposeInterface, Mask};
+use transformers as generichttp;
+
+use crate::{constants::headers, types::ResponseRouterData, utils};
+
+/// A connector for processors which are not natively supported. The endpoints of the processor,
+/// along with the request templates and response mappings, are described by the merchant in the
+/// metadata of the merchant connector account.
+#[derive(Clone)]
+pub struct Generichttp {
+    amount_converter: &'static (dyn AmountConvertor<Output = MinorUnit> + Sync),
+}
+
+impl Generichttp {
+    pub fn new() -> &'static Self {
+        &Self {
+            amount_converter: &MinorUnitForConnector,
+        }
+    }
+
+    fn get_endpoint<Flow, Req, Res>(
+        req: &RouterData<Flow, Req, Res>,
+        flow: generichttp::GenerichttpFlow,
+    ) -> CustomResult<generichttp::GenerichttpEndpointConfig, errors::ConnectorError> {
+        let metadata = generichttp::GenerichttpConnectorMetadataObject::try_from(
+            req.connector_meta_data.as_ref(),
+        )?;
+        metadata.get_endpoint(flow).cloned()
+    }
+
+    fn build_flow_headers<Flow, Req, Res>(
+        &self,
+        req: &RouterData<Flow, Req, Res>,
+        connectors: &Connectors,
+        flow: generichttp::GenerichttpFlow,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError>
+    where
+        Self: ConnectorIntegration<Flow, Req, Res>,
+    {
+        let endpoint = Self::get_endpoint(req, flow)?;
+        let mut header = self.build_headers(req, connectors)?;
+        header.extend(
+            endpoint
+                .headers
+                .into_iter()
+                .map(|(name, value)| (name, value.into())),
+        );
+        Ok(header)
+    }
+
+    fn build_flow_request<Flow, Req, Res>(
+        &self,
+        req: &RouterData<Flow, Req, Res>,
+        connectors: &Connectors,
+        flow: generichttp::GenerichttpFlow,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError>
+    where
+        Self: ConnectorIntegration<Flow, Req, Res>,
+    {
+        let endpoint = Self::get_endpoint(req, flow)?;
+        let request_builder = RequestBuilder::new()
+            .method(endpoint.method)
+            .url(&ConnectorIntegration::<Flow, Req, Res>::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(ConnectorIntegration::<Flow, Req, Res>::get_headers(
+                self, req, connectors,
+            )?);
+        let request_builder = match endpoint.request_template {
+            Some(_) => request_builder.set_body(
+                ConnectorIntegration::<Flow, Req, Res>::get_request_body(self, req, connectors)?,
+            ),
+            None => request_builder,
+        };
+        Ok(Some(request_builder.build()))
+    }
+
+    fn parse_flow_response<Flow, Req, Res>(
+        req: &RouterData<Flow, Req, Res>,
+        flow: generichttp::GenerichttpFlow,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: &Response,
+    ) -> CustomResult<generichttp::GenerichttpResponse, errors::ConnectorError> {
+        let endpoint = Self::get_endpoint(req, flow)?;
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("Generichttp Response")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        Ok(generichttp::GenerichttpResponse::from_mapping(
+            &response,
+            &endpoint.response_mapping,
+        ))
+    }
+}
+
+impl api::Payment for Generichttp {}
+impl api::PaymentSession for Generichttp {}
+impl api::ConnectorAccessToken for Generichttp {}
+impl api::MandateSetup for Generichttp {}
+impl api::PaymentAuthorize for Generichttp {}
+impl api::PaymentSync for Generichttp {}
+impl api::PaymentCapture for Generichttp {}
+impl api::PaymentVoid for Generichttp {}
+impl api::Refund for Generichttp {}
+impl api::RefundExecute for Generichttp {}
+impl api::RefundSync for Generichttp {}
+impl api::PaymentToken for Generichttp {}
+
+impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
+    for Generichttp
+{
+    // Not Implemented (R)
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Generichttp
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &RouterData<Flow, Request, Response>,
+        _connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let metadata = generichttp::GenerichttpConnectorMetadataObject::try_from(
+            req.connector_meta_data.as_ref(),
+        )?;
+        let auth = generichttp::GenerichttpAuthType::try_from(&req.connector_auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth_value = format!(
+            "{}{}",
+            metadata.authentication.value_prefix.unwrap_or_default(),
+            auth.api_key.expose()
+        );
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string().into(),
+            ),
+            (
+                metadata.authentication.header_name,
+                auth_value.into_masked(),
+            ),
+        ])
+    }
+}
+
+impl ConnectorCommon for Generichttp {
+    fn id(&self) -> &'static str {
+        "generichttp"
+    }
+
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    // The urls of the endpoints are configured by the merchant in the connector metadata
+    fn base_url<'a>(&self, _connectors: &'a Connectors) -> &'a str {
+        ""
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let auth = generichttp::GenerichttpAuthType::try_from(auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            auth.api_key.expose().into_masked(),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: Option<serde_json::Value> =
+            res.response.parse_struct("GenerichttpErrorResponse").ok();
+
+        let error = match &response {
+            Some(response) => {
+                event_builder.map(|i| i.set_error_response_body(response));
+                router_env::logger::info!(connector_response=?response);
+                generichttp::GenerichttpErrorResponse::from(response)
+            }
+            None => generichttp::GenerichttpErrorResponse {
+                code: None,
+                message: None,
+            },
+        };
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: error
+                .message
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: error.message,
+            attempt_status: None,
+            connector_transaction_id: None,
+        })
+    }
+}
+
+impl ConnectorValidation for Generichttp {}
+
+impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Generichttp {}
+
+impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken> for Generichttp {}
+
+impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData>
+    for Generichttp
+{
+    fn build_request(
+        &self,
+        _req: &RouterData<SetupMandate, SetupMandateRequestData, PaymentsResponseData>,
+        _connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented(
+            "Setup Mandate flow for Generichttp".to_string(),
+        )
+        .into())
+    }
+}
+
+impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData> for Generichttp {
+    fn get_headers(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_flow_headers(req, connectors, generichttp::GenerichttpFlow::Authorize)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        Self::get_endpoint(req, generichttp::GenerichttpFlow::Authorize)?
+            .render_url(&context, &connectors.generichttp.allowed_hosts)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        let connector_req = Self::get_endpoint(req, generichttp::GenerichttpFlow::Authorize)?
+            .render_request(&context)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        self.build_flow_request(req, connectors, generichttp::GenerichttpFlow::Authorize)
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsAuthorizeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        let response = Self::parse_flow_response(
+            data,
+            generichttp::GenerichttpFlow::Authorize,
+            event_builder,
+            &res,
+        )?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Generichttp {
+    fn get_headers(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_flow_headers(req, connectors, generichttp::GenerichttpFlow::Psync)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let context = generichttp::GenerichttpTemplateContext::try_from(req)?;
+        Self::get_endpoint(req, generichttp::GenerichttpFlow::Psync)?
+            .render_url(&context, &connectors.generichttp.allowed_hosts)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let context = generichttp::GenerichttpTemplateContext::try_from(req)?;
+        let connector_req = Self::get_endpoint(req, generichttp::GenerichttpFlow::Psync)?
+            .render_request(&context)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        self.build_flow_request(req, connectors, generichttp::GenerichttpFlow::Psync)
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsSyncRouterData, errors::ConnectorError> {
+        let response = Self::parse_flow_response(
+            data,
+            generichttp::GenerichttpFlow::Psync,
+            event_builder,
+            &res,
+        )?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData> for Generichttp {
+    fn get_headers(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_flow_headers(req, connectors, generichttp::GenerichttpFlow::Capture)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_amount_to_capture,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        Self::get_endpoint(req, generichttp::GenerichttpFlow::Capture)?
+            .render_url(&context, &connectors.generichttp.allowed_hosts)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_amount_to_capture,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        let connector_req = Self::get_endpoint(req, generichttp::GenerichttpFlow::Capture)?
+            .render_request(&context)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        self.build_flow_request(req, connectors, generichttp::GenerichttpFlow::Capture)
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsCaptureRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsCaptureRouterData, errors::ConnectorError> {
+        let response = Self::parse_flow_response(
+            data,
+            generichttp::GenerichttpFlow::Capture,
+            event_builder,
+            &res,
+        )?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Generichttp {
+    fn get_headers(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_flow_headers(req, connectors, generichttp::GenerichttpFlow::Void)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let context = generichttp::GenerichttpTemplateContext::try_from(req)?;
+        Self::get_endpoint(req, generichttp::GenerichttpFlow::Void)?
+            .render_url(&context, &connectors.generichttp.allowed_hosts)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsCancelRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let context = generichttp::GenerichttpTemplateContext::try_from(req)?;
+        let connector_req = Self::get_endpoint(req, generichttp::GenerichttpFlow::Void)?
+            .render_request(&context)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        self.build_flow_request(req, connectors, generichttp::GenerichttpFlow::Void)
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsCancelRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsCancelRouterData, errors::ConnectorError> {
+        let response = Self::parse_flow_response(
+            data,
+            generichttp::GenerichttpFlow::Void,
+            event_builder,
+            &res,
+        )?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Generichttp {
+    fn get_headers(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_flow_headers(req, connectors, generichttp::GenerichttpFlow::Refund)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let refund_amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_refund_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((refund_amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        Self::get_endpoint(req, generichttp::GenerichttpFlow::Refund)?
+            .render_url(&context, &connectors.generichttp.allowed_hosts)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let refund_amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_refund_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((refund_amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        let connector_req = Self::get_endpoint(req, generichttp::GenerichttpFlow::Refund)?
+            .render_request(&context)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        self.build_flow_request(req, connectors, generichttp::GenerichttpFlow::Refund)
+    }
+
+    fn handle_response(
+        &self,
+        data: &RefundsRouterData<Execute>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<RefundsRouterData<Execute>, errors::ConnectorError> {
+        let response = Self::parse_flow_response(
+            data,
+            generichttp::GenerichttpFlow::Refund,
+            event_builder,
+            &res,
+        )?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Generichttp {
+    fn get_headers(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_flow_headers(req, connectors, generichttp::GenerichttpFlow::Rsync)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let refund_amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_refund_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((refund_amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        Self::get_endpoint(req, generichttp::GenerichttpFlow::Rsync)?
+            .render_url(&context, &connectors.generichttp.allowed_hosts)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RefundSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let refund_amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_refund_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = generichttp::GenerichttpRouterData::from((refund_amount, req));
+        let context = generichttp::GenerichttpTemplateContext::try_from(&connector_router_data)?;
+        let connector_req = Self::get_endpoint(req, generichttp::GenerichttpFlow::Rsync)?
+            .render_request(&context)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        self.build_flow_request(req, connectors, generichttp::GenerichttpFlow::Rsync)
+    }
+
+    fn handle_response(
+        &self,
+        data: &RefundSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<RefundSyncRouterData, errors::ConnectorError> {
+        let response = Self::parse_flow_response(
+            data,
+            generichttp::GenerichttpFlow::Rsync,
+            event_builder,
+            &res,
+        )?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[async_trait::async_trait]
+impl webhooks::IncomingWebhook for Generichttp {
+    fn get_webhook_object_reference_id(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+}
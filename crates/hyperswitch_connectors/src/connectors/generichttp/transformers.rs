@@ -0,0 +1,714 @@
+use std::collections::{HashMap, HashSet};
+
+use common_enums::enums;
+use common_utils::{
+    pii,
+    request::Method,
+    types::{MinorUnit, StringMajorUnitForConnector},
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payment_method_data::PaymentMethodData,
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::refunds::{Execute, RSync},
+    router_request_types::ResponseId,
+    router_response_types::{PaymentsResponseData, RefundsResponseData},
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
+        PaymentsSyncRouterData, RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{consts, errors};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    types::{RefundsResponseRouterData, ResponseRouterData},
+    utils::{self, CardData, PaymentsAuthorizeRequestData, PaymentsSyncRequestData},
+};
+
+const PLACEHOLDER_START: &str = "{{";
+const PLACEHOLDER_END: &str = "}}";
+/// Host names which resolve to the host itself or to internal services of the deployment
+const BLOCKED_HOSTS: [&str; 2] = ["localhost", "metadata.google.internal"];
+const BLOCKED_HOST_SUFFIXES: [&str; 3] = [".localhost", ".local", ".internal"];
+
+pub struct GenerichttpRouterData<T> {
+    pub amount: MinorUnit,
+    pub router_data: T,
+}
+
+impl<T> From<(MinorUnit, T)> for GenerichttpRouterData<T> {
+    fn from((amount, item): (MinorUnit, T)) -> Self {
+        Self {
+            amount,
+            router_data: item,
+        }
+    }
+}
+
+/// The flows that can be configured for the generic HTTP connector
+#[derive(Debug, Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum GenerichttpFlow {
+    Authorize,
+    Capture,
+    Void,
+    Psync,
+    Refund,
+    Rsync,
+}
+
+/// Describes the processor API of the merchant, configured through the connector metadata of the
+/// merchant connector account
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerichttpConnectorMetadataObject {
+    #[serde(default)]
+    pub authentication: GenerichttpAuthenticationConfig,
+    pub authorize: GenerichttpEndpointConfig,
+    pub capture: Option<GenerichttpEndpointConfig>,
+    pub void: Option<GenerichttpEndpointConfig>,
+    pub psync: Option<GenerichttpEndpointConfig>,
+    pub refund: Option<GenerichttpEndpointConfig>,
+    pub rsync: Option<GenerichttpEndpointConfig>,
+}
+
+impl TryFrom<Option<&pii::SecretSerdeValue>> for GenerichttpConnectorMetadataObject {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: Option<&pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        let metadata: Self = utils::to_connector_meta_from_secret::<Self>(meta_data.cloned())
+            .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata",
+            })?;
+        Ok(metadata)
+    }
+}
+
+impl GenerichttpConnectorMetadataObject {
+    pub fn get_endpoint(
+        &self,
+        flow: GenerichttpFlow,
+    ) -> Result<&GenerichttpEndpointConfig, error_stack::Report<errors::ConnectorError>> {
+        let endpoint = match flow {
+            GenerichttpFlow::Authorize => Some(&self.authorize),
+            GenerichttpFlow::Capture => self.capture.as_ref(),
+            GenerichttpFlow::Void => self.void.as_ref(),
+            GenerichttpFlow::Psync => self.psync.as_ref(),
+            GenerichttpFlow::Refund => self.refund.as_ref(),
+            GenerichttpFlow::Rsync => self.rsync.as_ref(),
+        };
+        endpoint.ok_or_else(|| {
+            errors::ConnectorError::FlowNotSupported {
+                flow: flow.to_string(),
+                connector: "generichttp".to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Validates that the urls of all the configured endpoints point to hosts which the
+    /// application may send requests to. Placeholders are only allowed after the host of a url.
+    pub fn validate_urls(
+        &self,
+        allowed_hosts: &HashSet<String>,
+    ) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+        [
+            Some(&self.authorize),
+            self.capture.as_ref(),
+            self.void.as_ref(),
+            self.psync.as_ref(),
+            self.refund.as_ref(),
+            self.rsync.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .try_for_each(|endpoint| {
+            let authority = endpoint
+                .url
+                .strip_prefix("https://")
+                .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or(rest))
+                .ok_or(errors::ConnectorError::InvalidConnectorConfig { config: "url" })
+                .attach_printable("The url of the endpoint must use https")?;
+            if authority.contains(PLACEHOLDER_START) {
+                Err(errors::ConnectorError::InvalidConnectorConfig { config: "url" })
+                    .attach_printable("The host of the endpoint must not contain placeholders")?;
+            }
+            let url = endpoint
+                .url
+                .replace(PLACEHOLDER_START, "")
+                .replace(PLACEHOLDER_END, "");
+            validate_endpoint_url(&url, allowed_hosts).map(|_| ())
+        })
+    }
+}
+
+/// Parses the url of an endpoint, and validates that it uses https and points to one of the hosts
+/// allowed by the configuration of the connector. As the urls are configured by merchants and a
+/// public host name could resolve to an internal address of the deployment, no host is allowed
+/// unless the allowed hosts are configured.
+fn validate_endpoint_url(
+    url: &str,
+    allowed_hosts: &HashSet<String>,
+) -> Result<url::Url, error_stack::Report<errors::ConnectorError>> {
+    let invalid_url = || errors::ConnectorError::InvalidConnectorConfig { config: "url" };
+    let url = url::Url::parse(url)
+        .change_context(invalid_url())
+        .attach_printable("Failed to parse the url of the endpoint")?;
+
+    if url.scheme() != "https" {
+        Err(invalid_url()).attach_printable("The url of the endpoint must use https")?;
+    }
+    if !url.username().is_empty() || url.password().is_some() {
+        Err(invalid_url()).attach_printable("The url of the endpoint must not have credentials")?;
+    }
+    let host = match url.host() {
+        Some(url::Host::Domain(host)) => host.trim_end_matches('.').to_lowercase(),
+        Some(url::Host::Ipv4(_)) | Some(url::Host::Ipv6(_)) | None => {
+            Err(invalid_url()).attach_printable("The host of the endpoint must be a domain name")?
+        }
+    };
+    if BLOCKED_HOSTS.contains(&host.as_str())
+        || BLOCKED_HOST_SUFFIXES
+            .iter()
+            .any(|suffix| host.ends_with(suffix))
+    {
+        Err(invalid_url()).attach_printable("The host of the endpoint is not a public host")?;
+    }
+    if allowed_hosts.is_empty() {
+        Err(invalid_url()).attach_printable(
+            "The allowed hosts of the generic HTTP connector must be configured before it is used",
+        )?;
+    }
+    let is_allowed_host = allowed_hosts
+        .iter()
+        .any(|allowed_host| host == *allowed_host || host.ends_with(&format!(".{allowed_host}")));
+    if !is_allowed_host {
+        Err(invalid_url()).attach_printable_lazy(|| format!("The host `{host}` is not allowed"))?;
+    }
+    Ok(url)
+}
+
+/// How the api key of the merchant connector account is sent to the processor
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerichttpAuthenticationConfig {
+    pub header_name: String,
+    pub value_prefix: Option<String>,
+}
+
+impl Default for GenerichttpAuthenticationConfig {
+    fn default() -> Self {
+        Self {
+            header_name: crate::constants::headers::AUTHORIZATION.to_string(),
+            value_prefix: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerichttpEndpointConfig {
+    /// The url of the endpoint, which may contain placeholders such as `{{connector_transaction_id}}`
+    pub url: String,
+    pub method: Method,
+    /// Additional static headers to be sent to the endpoint
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// The JSON body sent to the endpoint, in which string values may contain placeholders
+    pub request_template: Option<Value>,
+    pub response_mapping: GenerichttpResponseMapping,
+}
+
+impl GenerichttpEndpointConfig {
+    /// Renders the url of the endpoint, the values of the placeholders being percent-encoded, and
+    /// validates it again before the request is sent
+    pub fn render_url(
+        &self,
+        context: &GenerichttpTemplateContext,
+        allowed_hosts: &HashSet<String>,
+    ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+        let url =
+            context.interpolate_with(&self.url, |value| urlencoding::encode(value).into_owned())?;
+        validate_endpoint_url(&url, allowed_hosts).map(String::from)
+    }
+
+    pub fn render_request(
+        &self,
+        context: &GenerichttpTemplateContext,
+    ) -> Result<GenerichttpRequest, error_stack::Report<errors::ConnectorError>> {
+        let template = self.request_template.as_ref().ok_or(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "request_template",
+            },
+        )?;
+        Ok(GenerichttpRequest(Secret::new(context.render(template)?)))
+    }
+}
+
+/// JSON pointers (RFC 6901) into the processor response, along with the mapping of the processor
+/// statuses to the statuses understood by the connector
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerichttpResponseMapping {
+    pub status: String,
+    pub status_map: HashMap<String, GenerichttpStatus>,
+    pub resource_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GenerichttpStatus {
+    Succeeded,
+    Authorized,
+    Pending,
+    Failed,
+    Voided,
+}
+
+impl From<GenerichttpStatus> for common_enums::AttemptStatus {
+    fn from(item: GenerichttpStatus) -> Self {
+        match item {
+            GenerichttpStatus::Succeeded => Self::Charged,
+            GenerichttpStatus::Authorized => Self::Authorized,
+            GenerichttpStatus::Pending => Self::Pending,
+            GenerichttpStatus::Failed => Self::Failure,
+            GenerichttpStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+impl From<GenerichttpStatus> for enums::RefundStatus {
+    fn from(item: GenerichttpStatus) -> Self {
+        match item {
+            GenerichttpStatus::Succeeded => Self::Success,
+            GenerichttpStatus::Failed | GenerichttpStatus::Voided => Self::Failure,
+            GenerichttpStatus::Authorized | GenerichttpStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+/// The values which can be referenced by placeholders in the url and request template of an
+/// endpoint
+#[derive(Debug, Default)]
+pub struct GenerichttpTemplateContext(HashMap<&'static str, Value>);
+
+impl GenerichttpTemplateContext {
+    fn insert(&mut self, name: &'static str, value: impl Into<Value>) {
+        self.0.insert(name, value.into());
+    }
+
+    fn insert_optional(&mut self, name: &'static str, value: Option<impl Into<Value>>) {
+        self.0
+            .insert(name, value.map(Into::into).unwrap_or(Value::Null));
+    }
+
+    fn insert_amount(
+        &mut self,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+        let amount_major = utils::convert_amount(&StringMajorUnitForConnector, amount, currency)?;
+        self.insert("amount", amount.get_amount_as_i64());
+        self.insert("amount_major", amount_major.get_amount_as_string());
+        self.insert("currency", currency.to_string());
+        Ok(())
+    }
+
+    fn get(&self, name: &str) -> Result<&Value, error_stack::Report<errors::ConnectorError>> {
+        self.0
+            .get(name)
+            .ok_or(errors::ConnectorError::InvalidConnectorConfig {
+                config: "request_template",
+            })
+            .attach_printable_lazy(|| format!("Unknown placeholder `{name}` in template"))
+    }
+
+    /// Replaces the placeholders in the template. A string consisting of a single placeholder is
+    /// replaced by the value as is, so that numbers and nulls retain their JSON type.
+    fn render(
+        &self,
+        template: &Value,
+    ) -> Result<Value, error_stack::Report<errors::ConnectorError>> {
+        match template {
+            Value::String(template) => match get_exact_placeholder(template) {
+                Some(name) => self.get(name).cloned(),
+                None => self.interpolate(template).map(Value::String),
+            },
+            Value::Array(items) => items
+                .iter()
+                .map(|item| self.render(item))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            Value::Object(fields) => fields
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), self.render(value)?)))
+                .collect::<Result<serde_json::Map<_, _>, _>>()
+                .map(Value::Object),
+            Value::Null | Value::Bool(_) | Value::Number(_) => Ok(template.clone()),
+        }
+    }
+
+    fn interpolate(
+        &self,
+        template: &str,
+    ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+        self.interpolate_with(template, str::to_owned)
+    }
+
+    fn interpolate_with(
+        &self,
+        template: &str,
+        encode: impl Fn(&str) -> String,
+    ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+        let mut rendered = String::with_capacity(template.len());
+        let mut remaining = template;
+        while let Some((prefix, rest)) = remaining.split_once(PLACEHOLDER_START) {
+            let (name, suffix) = rest.split_once(PLACEHOLDER_END).ok_or(
+                errors::ConnectorError::InvalidConnectorConfig {
+                    config: "request_template",
+                },
+            )?;
+            rendered.push_str(prefix);
+            match self.get(name.trim())? {
+                Value::Null => (),
+                Value::String(value) => rendered.push_str(&encode(value)),
+                value => rendered.push_str(&encode(&value.to_string())),
+            }
+            remaining = suffix;
+        }
+        rendered.push_str(remaining);
+        Ok(rendered)
+    }
+}
+
+fn get_exact_placeholder(template: &str) -> Option<&str> {
+    template
+        .strip_prefix(PLACEHOLDER_START)
+        .and_then(|rest| rest.strip_suffix(PLACEHOLDER_END))
+        .filter(|name| !name.contains(PLACEHOLDER_START) && !name.contains(PLACEHOLDER_END))
+        .map(str::trim)
+}
+
+impl TryFrom<&GenerichttpRouterData<&PaymentsAuthorizeRouterData>> for GenerichttpTemplateContext {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &GenerichttpRouterData<&PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        let mut context = Self::default();
+        context.insert_amount(item.amount, router_data.request.currency)?;
+        context.insert(
+            "reference",
+            router_data.connector_request_reference_id.clone(),
+        );
+        context.insert(
+            "capture_method",
+            if router_data.request.is_auto_capture()? {
+                "automatic"
+            } else {
+                "manual"
+            },
+        );
+        context.insert_optional("description", router_data.description.clone());
+        context.insert_optional(
+            "email",
+            router_data
+                .request
+                .email
+                .clone()
+                .map(|email| email.expose().expose()),
+        );
+        context.insert_optional(
+            "customer_name",
+            router_data
+                .request
+                .customer_name
+                .clone()
+                .map(ExposeInterface::expose),
+        );
+        context.insert_optional("return_url", router_data.request.router_return_url.clone());
+        match &router_data.request.payment_method_data {
+            PaymentMethodData::Card(card) => {
+                context.insert("card_number", card.card_number.get_card_no());
+                context.insert("card_exp_month", card.card_exp_month.peek().clone());
+                context.insert("card_exp_year", card.get_expiry_year_4_digit().expose());
+                context.insert("card_cvc", card.card_cvc.peek().clone());
+                context.insert_optional(
+                    "card_holder_name",
+                    card.card_holder_name.clone().map(ExposeInterface::expose),
+                );
+                Ok(context)
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("generichttp"),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TryFrom<&GenerichttpRouterData<&PaymentsCaptureRouterData>> for GenerichttpTemplateContext {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &GenerichttpRouterData<&PaymentsCaptureRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        let mut context = Self::default();
+        context.insert_amount(item.amount, router_data.request.currency)?;
+        context.insert(
+            "reference",
+            router_data.connector_request_reference_id.clone(),
+        );
+        context.insert(
+            "connector_transaction_id",
+            router_data.request.connector_transaction_id.clone(),
+        );
+        Ok(context)
+    }
+}
+
+impl TryFrom<&PaymentsCancelRouterData> for GenerichttpTemplateContext {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        let mut context = Self::default();
+        context.insert("reference", item.connector_request_reference_id.clone());
+        context.insert(
+            "connector_transaction_id",
+            item.request.connector_transaction_id.clone(),
+        );
+        context.insert_optional(
+            "cancellation_reason",
+            item.request.cancellation_reason.clone(),
+        );
+        Ok(context)
+    }
+}
+
+impl TryFrom<&PaymentsSyncRouterData> for GenerichttpTemplateContext {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let mut context = Self::default();
+        context.insert("reference", item.connector_request_reference_id.clone());
+        context.insert(
+            "connector_transaction_id",
+            item.request.get_connector_transaction_id()?,
+        );
+        Ok(context)
+    }
+}
+
+impl<F> TryFrom<&GenerichttpRouterData<&RefundsRouterData<F>>> for GenerichttpTemplateContext {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &GenerichttpRouterData<&RefundsRouterData<F>>) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        let mut context = Self::default();
+        context.insert_amount(item.amount, router_data.request.currency)?;
+        context.insert(
+            "reference",
+            router_data.connector_request_reference_id.clone(),
+        );
+        context.insert(
+            "connector_transaction_id",
+            router_data.request.connector_transaction_id.clone(),
+        );
+        context.insert("refund_id", router_data.request.refund_id.clone());
+        context.insert_optional(
+            "connector_refund_id",
+            router_data.request.connector_refund_id.clone(),
+        );
+        context.insert_optional("reason", router_data.request.reason.clone());
+        Ok(context)
+    }
+}
+
+/// The rendered request template, kept as a secret since it may contain card details
+#[derive(Debug, Serialize)]
+pub struct GenerichttpRequest(Secret<Value>);
+
+// Auth Struct
+pub struct GenerichttpAuthType {
+    pub(super) api_key: Secret<String>,
+}
+
+impl TryFrom<&ConnectorAuthType> for GenerichttpAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// The processor response, reduced to the fields described by the response mapping
+#[derive(Debug, Clone)]
+pub struct GenerichttpResponse {
+    pub status: GenerichttpStatus,
+    pub resource_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl GenerichttpResponse {
+    pub fn from_mapping(response: &Value, mapping: &GenerichttpResponseMapping) -> Self {
+        let processor_status = get_string_by_pointer(response, Some(&mapping.status));
+        let status = processor_status
+            .as_ref()
+            .and_then(|processor_status| mapping.status_map.get(processor_status))
+            .copied()
+            .unwrap_or_else(|| {
+                router_env::logger::warn!(
+                    ?processor_status,
+                    "Unmapped processor status, treating the transaction as pending"
+                );
+                GenerichttpStatus::Pending
+            });
+        Self {
+            status,
+            resource_id: get_string_by_pointer(response, mapping.resource_id.as_deref()),
+            error_code: get_string_by_pointer(response, mapping.error_code.as_deref()),
+            error_message: get_string_by_pointer(response, mapping.error_message.as_deref()),
+        }
+    }
+
+    fn get_error_response(&self, http_code: u16) -> ErrorResponse {
+        ErrorResponse {
+            status_code: http_code,
+            code: self
+                .error_code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: self
+                .error_message
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: self.error_message.clone(),
+            attempt_status: None,
+            connector_transaction_id: self.resource_id.clone(),
+        }
+    }
+}
+
+fn get_string_by_pointer(response: &Value, pointer: Option<&str>) -> Option<String> {
+    pointer
+        .and_then(|pointer| response.pointer(pointer))
+        .and_then(|value| match value {
+            Value::Null | Value::Array(_) | Value::Object(_) => None,
+            Value::String(value) => Some(value.clone()),
+            Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        })
+}
+
+impl<F, T> TryFrom<ResponseRouterData<F, GenerichttpResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, GenerichttpResponse, T, PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = common_enums::AttemptStatus::from(item.response.status);
+        let response = if item.response.status == GenerichttpStatus::Failed {
+            Err(ErrorResponse {
+                attempt_status: Some(status),
+                ..item.response.get_error_response(item.http_code)
+            })
+        } else {
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: item
+                    .response
+                    .resource_id
+                    .clone()
+                    .map(ResponseId::ConnectorTransactionId)
+                    .unwrap_or(ResponseId::NoResponseId),
+                redirection_data: Box::new(None),
+                mandate_reference: Box::new(None),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                charge_id: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<RefundsResponseRouterData<Execute, GenerichttpResponse>>
+    for RefundsRouterData<Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: RefundsResponseRouterData<Execute, GenerichttpResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = item
+            .response
+            .resource_id
+            .clone()
+            .unwrap_or_else(|| item.data.request.refund_id.clone());
+        Ok(Self {
+            response: get_refunds_response(&item.response, connector_refund_id, item.http_code),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<RefundsResponseRouterData<RSync, GenerichttpResponse>> for RefundsRouterData<RSync> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: RefundsResponseRouterData<RSync, GenerichttpResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = item
+            .response
+            .resource_id
+            .clone()
+            .or_else(|| item.data.request.connector_refund_id.clone())
+            .unwrap_or_else(|| item.data.request.refund_id.clone());
+        Ok(Self {
+            response: get_refunds_response(&item.response, connector_refund_id, item.http_code),
+            ..item.data
+        })
+    }
+}
+
+fn get_refunds_response(
+    response: &GenerichttpResponse,
+    connector_refund_id: String,
+    http_code: u16,
+) -> Result<RefundsResponseData, ErrorResponse> {
+    let refund_status = enums::RefundStatus::from(response.status);
+    if refund_status == enums::RefundStatus::Failure {
+        Err(response.get_error_response(http_code))
+    } else {
+        Ok(RefundsResponseData {
+            connector_refund_id,
+            refund_status,
+        })
+    }
+}
+
+/// Error responses are not described by the metadata, hence the commonly used fields are looked up
+#[derive(Debug)]
+pub struct GenerichttpErrorResponse {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+impl From<&Value> for GenerichttpErrorResponse {
+    fn from(response: &Value) -> Self {
+        let find = |pointers: &[&str]| {
+            pointers
+                .iter()
+                .find_map(|pointer| get_string_by_pointer(response, Some(*pointer)))
+        };
+        Self {
+            code: find(&["/error/code", "/error_code", "/code"]),
+            message: find(&["/error/message", "/error_message", "/message", "/error"]),
+        }
+    }
+}
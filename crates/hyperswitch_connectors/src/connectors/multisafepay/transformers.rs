@@ -9,9 +9,10 @@ use hyperswitch_domain_models::{
     payment_method_data::{BankRedirectData, PayLaterData, PaymentMethodData, WalletData},
     router_data::{ConnectorAuthType, ErrorResponse, RouterData},
     router_flow_types::refunds::{Execute, RSync},
-    router_request_types::ResponseId,
+    router_request_types::{BankListRequestData, ResponseId},
     router_response_types::{
-        MandateReference, PaymentsResponseData, RedirectForm, RefundsResponseData,
+        BankListResponseData, MandateReference, PaymentsResponseData, RedirectForm,
+        RefundsResponseData,
     },
     types::{self},
 };
@@ -196,7 +197,7 @@ pub struct IdealInfo {
     pub issuer_id: MultisafepayBankNames,
 }
 
-#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum MultisafepayBankNames {
     #[serde(rename = "0031")]
     AbnAmro,
@@ -230,6 +231,28 @@ pub enum MultisafepayBankNames {
     Handelsbanken,
 }
 
+impl From<MultisafepayBankNames> for BankNames {
+    fn from(bank: MultisafepayBankNames) -> Self {
+        match bank {
+            MultisafepayBankNames::AbnAmro => Self::AbnAmro,
+            MultisafepayBankNames::AsnBank => Self::AsnBank,
+            MultisafepayBankNames::Bunq => Self::Bunq,
+            MultisafepayBankNames::Ing => Self::Ing,
+            MultisafepayBankNames::Knab => Self::Knab,
+            MultisafepayBankNames::N26 => Self::N26,
+            MultisafepayBankNames::NationaleNederlanden => Self::NationaleNederlanden,
+            MultisafepayBankNames::Rabobank => Self::Rabobank,
+            MultisafepayBankNames::Regiobank => Self::Regiobank,
+            MultisafepayBankNames::Revolut => Self::Revolut,
+            MultisafepayBankNames::SnsBank => Self::SnsBank,
+            MultisafepayBankNames::TriodosBank => Self::TriodosBank,
+            MultisafepayBankNames::VanLanschot => Self::VanLanschot,
+            MultisafepayBankNames::Yoursafe => Self::Yoursafe,
+            MultisafepayBankNames::Handelsbanken => Self::Handelsbanken,
+        }
+    }
+}
+
 impl TryFrom<&BankNames> for MultisafepayBankNames {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(bank: &BankNames) -> Result<Self, Self::Error> {
@@ -1239,3 +1262,69 @@ impl From<MultisafepayErrorResponse> for Option<AttemptStatus> {
         }
     }
 }
+
+// Issuers
+pub fn get_issuers_gateway(
+    payment_method_type: enums::PaymentMethodType,
+) -> Result<&'static str, errors::ConnectorError> {
+    match payment_method_type {
+        enums::PaymentMethodType::Ideal => Ok("IDEAL"),
+        _ => Err(errors::ConnectorError::NotSupported {
+            message: format!("Fetching the issuers of {payment_method_type}"),
+            connector: "multisafepay",
+        }),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultisafepayIssuersResponse {
+    pub success: bool,
+    pub data: Vec<MultisafepayIssuer>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultisafepayIssuer {
+    pub code: String,
+    pub description: String,
+}
+
+impl<F>
+    TryFrom<
+        ResponseRouterData<
+            F,
+            MultisafepayIssuersResponse,
+            BankListRequestData,
+            BankListResponseData,
+        >,
+    > for RouterData<F, BankListRequestData, BankListResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            F,
+            MultisafepayIssuersResponse,
+            BankListRequestData,
+            BankListResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Issuers which are not mapped to a bank name cannot be selected by the customer, so they
+        // are left out of the list
+        let banks = item
+            .response
+            .data
+            .into_iter()
+            .filter_map(|issuer| {
+                serde_json::from_value::<MultisafepayBankNames>(serde_json::Value::String(
+                    issuer.code,
+                ))
+                .ok()
+            })
+            .map(BankNames::from)
+            .collect();
+
+        Ok(Self {
+            response: Ok(BankListResponseData { banks }),
+            ..item.data
+        })
+    }
+}
@@ -0,0 +1,393 @@
+use common_enums::enums;
+use common_utils::{pii, request::Method, types::MinorUnit};
+use connector_plugin_sdk as sdk;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payment_method_data::PaymentMethodData,
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::refunds::{Execute, RSync},
+    router_request_types::ResponseId,
+    router_response_types::{PaymentsResponseData, RedirectForm, RefundsResponseData},
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
+        PaymentsSyncRouterData, RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{
+    configs::{ConnectorPluginConfig, Connectors},
+    errors,
+};
+use masking::{ExposeInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{RefundsResponseRouterData, ResponseRouterData},
+    utils::{self, CardData, PaymentsAuthorizeRequestData, PaymentsSyncRequestData},
+};
+
+pub struct PluginRouterData<T> {
+    pub amount: MinorUnit,
+    pub router_data: T,
+}
+
+impl<T> From<(MinorUnit, T)> for PluginRouterData<T> {
+    fn from((amount, item): (MinorUnit, T)) -> Self {
+        Self {
+            amount,
+            router_data: item,
+        }
+    }
+}
+
+/// Selects the plugin serving the merchant connector account
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginConnectorMetadataObject {
+    pub plugin_name: String,
+}
+
+impl TryFrom<Option<&pii::SecretSerdeValue>> for PluginConnectorMetadataObject {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: Option<&pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        let metadata: Self = utils::to_connector_meta_from_secret::<Self>(meta_data.cloned())
+            .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata",
+            })?;
+        Ok(metadata)
+    }
+}
+
+impl PluginConnectorMetadataObject {
+    pub fn get_plugin_config<'a>(
+        &self,
+        connectors: &'a Connectors,
+    ) -> Result<&'a ConnectorPluginConfig, error_stack::Report<errors::ConnectorError>> {
+        connectors
+            .plugin
+            .plugins
+            .get(&self.plugin_name)
+            .ok_or(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.plugin_name",
+            })
+            .attach_printable_lazy(|| {
+                format!("Connector plugin `{}` is not registered", self.plugin_name)
+            })
+    }
+}
+
+// Auth Struct
+pub struct PluginAuthType(pub(super) sdk::PluginConnectorAuth);
+
+impl TryFrom<&ConnectorAuthType> for PluginAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &ConnectorAuthType) -> Result<Self, Self::Error> {
+        let auth = match auth_type {
+            ConnectorAuthType::HeaderKey { api_key } => sdk::PluginConnectorAuth::HeaderKey {
+                api_key: api_key.to_owned(),
+            },
+            ConnectorAuthType::BodyKey { api_key, key1 } => sdk::PluginConnectorAuth::BodyKey {
+                api_key: api_key.to_owned(),
+                key1: key1.to_owned(),
+            },
+            ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => sdk::PluginConnectorAuth::SignatureKey {
+                api_key: api_key.to_owned(),
+                key1: key1.to_owned(),
+                api_secret: api_secret.to_owned(),
+            },
+            ConnectorAuthType::MultiAuthKey {
+                api_key,
+                key1,
+                api_secret,
+                key2,
+            } => sdk::PluginConnectorAuth::MultiAuthKey {
+                api_key: api_key.to_owned(),
+                key1: key1.to_owned(),
+                api_secret: api_secret.to_owned(),
+                key2: key2.to_owned(),
+            },
+            ConnectorAuthType::NoKey => sdk::PluginConnectorAuth::NoKey,
+            ConnectorAuthType::TemporaryAuth
+            | ConnectorAuthType::CurrencyAuthKey { .. }
+            | ConnectorAuthType::CertificateAuth { .. } => {
+                Err(errors::ConnectorError::FailedToObtainAuthType)?
+            }
+        };
+        Ok(Self(auth))
+    }
+}
+
+/// The request sent to the plugin, in the wire format defined by the plugin sdk
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct PluginRequest<T>(sdk::PluginRequest<T>);
+
+fn get_plugin_request<F, Req, Res, T>(
+    item: &RouterData<F, Req, Res>,
+    data: T,
+) -> Result<PluginRequest<T>, error_stack::Report<errors::ConnectorError>> {
+    let auth = PluginAuthType::try_from(&item.connector_auth_type)?;
+    Ok(PluginRequest(sdk::PluginRequest {
+        protocol_version: sdk::PROTOCOL_VERSION.to_string(),
+        merchant_id: item.merchant_id.get_string_repr().to_string(),
+        payment_id: item.payment_id.clone(),
+        attempt_id: item.attempt_id.clone(),
+        reference: item.connector_request_reference_id.clone(),
+        auth: auth.0,
+        metadata: item.connector_meta_data.clone(),
+        data,
+    }))
+}
+
+impl TryFrom<&PluginRouterData<&PaymentsAuthorizeRouterData>>
+    for PluginRequest<sdk::AuthorizeData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &PluginRouterData<&PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        let payment_method_data = match &router_data.request.payment_method_data {
+            PaymentMethodData::Card(card) => sdk::PaymentMethodData::Card(sdk::CardData {
+                number: Secret::new(card.card_number.get_card_no()),
+                exp_month: card.card_exp_month.clone(),
+                exp_year: card.get_expiry_year_4_digit(),
+                cvc: card.card_cvc.clone(),
+                holder_name: card.card_holder_name.clone(),
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("plugin"),
+            ))?,
+        };
+        let capture_method = if router_data.request.is_auto_capture()? {
+            sdk::CaptureMethod::Automatic
+        } else {
+            sdk::CaptureMethod::Manual
+        };
+        get_plugin_request(
+            router_data,
+            sdk::AuthorizeData {
+                amount: item.amount.get_amount_as_i64(),
+                currency: router_data.request.currency.to_string(),
+                capture_method,
+                payment_method_data,
+                email: router_data
+                    .request
+                    .email
+                    .clone()
+                    .map(|email| Secret::new(email.expose().expose())),
+                customer_name: router_data.request.customer_name.clone(),
+                description: router_data.description.clone(),
+                return_url: router_data.request.router_return_url.clone(),
+            },
+        )
+    }
+}
+
+impl TryFrom<&PluginRouterData<&PaymentsCaptureRouterData>> for PluginRequest<sdk::CaptureData> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &PluginRouterData<&PaymentsCaptureRouterData>) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        get_plugin_request(
+            router_data,
+            sdk::CaptureData {
+                connector_transaction_id: router_data.request.connector_transaction_id.clone(),
+                amount: item.amount.get_amount_as_i64(),
+                currency: router_data.request.currency.to_string(),
+            },
+        )
+    }
+}
+
+impl TryFrom<&PaymentsCancelRouterData> for PluginRequest<sdk::VoidData> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        get_plugin_request(
+            item,
+            sdk::VoidData {
+                connector_transaction_id: item.request.connector_transaction_id.clone(),
+                cancellation_reason: item.request.cancellation_reason.clone(),
+            },
+        )
+    }
+}
+
+impl TryFrom<&PaymentsSyncRouterData> for PluginRequest<sdk::PaymentSyncData> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        get_plugin_request(
+            item,
+            sdk::PaymentSyncData {
+                connector_transaction_id: item.request.get_connector_transaction_id()?,
+            },
+        )
+    }
+}
+
+impl<F> TryFrom<&PluginRouterData<&RefundsRouterData<F>>> for PluginRequest<sdk::RefundData> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &PluginRouterData<&RefundsRouterData<F>>) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        get_plugin_request(
+            router_data,
+            sdk::RefundData {
+                connector_transaction_id: router_data.request.connector_transaction_id.clone(),
+                refund_id: router_data.request.refund_id.clone(),
+                amount: item.amount.get_amount_as_i64(),
+                currency: router_data.request.currency.to_string(),
+                reason: router_data.request.reason.clone(),
+            },
+        )
+    }
+}
+
+impl<F> TryFrom<&RefundsRouterData<F>> for PluginRequest<sdk::RefundSyncData> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        get_plugin_request(
+            item,
+            sdk::RefundSyncData {
+                connector_transaction_id: item.request.connector_transaction_id.clone(),
+                refund_id: item.request.refund_id.clone(),
+                connector_refund_id: item.request.connector_refund_id.clone(),
+            },
+        )
+    }
+}
+
+fn get_attempt_status(status: sdk::PaymentStatus) -> enums::AttemptStatus {
+    match status {
+        sdk::PaymentStatus::Authorized => enums::AttemptStatus::Authorized,
+        sdk::PaymentStatus::Charged => enums::AttemptStatus::Charged,
+        sdk::PaymentStatus::Pending => enums::AttemptStatus::Pending,
+        sdk::PaymentStatus::AuthenticationPending => enums::AttemptStatus::AuthenticationPending,
+        sdk::PaymentStatus::Voided => enums::AttemptStatus::Voided,
+        sdk::PaymentStatus::Failed => enums::AttemptStatus::Failure,
+    }
+}
+
+fn get_refund_status(status: sdk::RefundStatus) -> enums::RefundStatus {
+    match status {
+        sdk::RefundStatus::Succeeded => enums::RefundStatus::Success,
+        sdk::RefundStatus::Pending => enums::RefundStatus::Pending,
+        sdk::RefundStatus::Failed => enums::RefundStatus::Failure,
+    }
+}
+
+pub(super) fn get_error_response(
+    error: Option<sdk::PluginError>,
+    status_code: u16,
+    connector_transaction_id: Option<String>,
+) -> ErrorResponse {
+    let (code, message, reason) = match error {
+        Some(error) => (error.code, error.message, error.reason),
+        None => (
+            hyperswitch_interfaces::consts::NO_ERROR_CODE.to_string(),
+            hyperswitch_interfaces::consts::NO_ERROR_MESSAGE.to_string(),
+            None,
+        ),
+    };
+    ErrorResponse {
+        status_code,
+        code,
+        message,
+        reason,
+        attempt_status: None,
+        connector_transaction_id,
+    }
+}
+
+impl<F, T> TryFrom<ResponseRouterData<F, sdk::PaymentResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, sdk::PaymentResponse, T, PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = get_attempt_status(item.response.status);
+        let response = if item.response.status == sdk::PaymentStatus::Failed {
+            Err(ErrorResponse {
+                attempt_status: Some(status),
+                ..get_error_response(
+                    item.response.error,
+                    item.http_code,
+                    item.response.connector_transaction_id,
+                )
+            })
+        } else {
+            let redirection_data =
+                item.response
+                    .redirection
+                    .map(|redirection| RedirectForm::Form {
+                        endpoint: redirection.url,
+                        method: match redirection.method {
+                            sdk::RedirectionMethod::Get => Method::Get,
+                            sdk::RedirectionMethod::Post => Method::Post,
+                        },
+                        form_fields: redirection.form_fields,
+                    });
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: item
+                    .response
+                    .connector_transaction_id
+                    .map(ResponseId::ConnectorTransactionId)
+                    .unwrap_or(ResponseId::NoResponseId),
+                redirection_data: Box::new(redirection_data),
+                mandate_reference: Box::new(None),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: item.response.connector_response_reference_id,
+                incremental_authorization_allowed: None,
+                charge_id: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+fn get_refunds_response(
+    response: sdk::RefundResponse,
+    http_code: u16,
+) -> Result<RefundsResponseData, ErrorResponse> {
+    let refund_status = get_refund_status(response.status);
+    if refund_status == enums::RefundStatus::Failure {
+        Err(get_error_response(response.error, http_code, None))
+    } else {
+        Ok(RefundsResponseData {
+            connector_refund_id: response.connector_refund_id,
+            refund_status,
+        })
+    }
+}
+
+impl TryFrom<RefundsResponseRouterData<Execute, sdk::RefundResponse>>
+    for RefundsRouterData<Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: RefundsResponseRouterData<Execute, sdk::RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: get_refunds_response(item.response, item.http_code),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<RefundsResponseRouterData<RSync, sdk::RefundResponse>> for RefundsRouterData<RSync> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: RefundsResponseRouterData<RSync, sdk::RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: get_refunds_response(item.response, item.http_code),
+            ..item.data
+        })
+    }
+}
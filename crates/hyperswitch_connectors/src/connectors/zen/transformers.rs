@@ -345,6 +345,7 @@ impl
             | BankTransferData::BriVaBankTransfer { .. }
             | BankTransferData::CimbVaBankTransfer { .. }
             | BankTransferData::DanamonVaBankTransfer { .. }
+            | BankTransferData::VirtualAccountBankTransfer {}
             | BankTransferData::LocalBankTransfer { .. }
             | BankTransferData::MandiriVaBankTransfer { .. } => {
                 Err(errors::ConnectorError::NotImplemented(
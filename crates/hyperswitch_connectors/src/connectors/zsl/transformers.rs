@@ -160,6 +160,7 @@ impl TryFrom<&ZslRouterData<&types::PaymentsAuthorizeRouterData>> for ZslPayment
                 | BankTransferData::BriVaBankTransfer { .. }
                 | BankTransferData::CimbVaBankTransfer { .. }
                 | BankTransferData::DanamonVaBankTransfer { .. }
+                | BankTransferData::VirtualAccountBankTransfer {}
                 | BankTransferData::MandiriVaBankTransfer { .. }
                 | BankTransferData::Pix { .. }
                 | BankTransferData::Pse {} => Err(errors::ConnectorError::NotImplemented(
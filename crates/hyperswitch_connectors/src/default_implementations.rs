@@ -16,11 +16,13 @@ use hyperswitch_domain_models::{
 #[cfg(feature = "payouts")]
 use hyperswitch_domain_models::{
     router_flow_types::payouts::{
-        PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount,
-        PoSync,
+        PoBalance, PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient,
+        PoRecipientAccount, PoRecipientStatus, PoSync,
+    },
+    router_request_types::{PayoutBalanceRequestData, PayoutsData},
+    router_response_types::{
+        PayoutBalanceResponseData, PayoutRecipientStatusResponseData, PayoutsResponseData,
     },
-    router_request_types::PayoutsData,
-    router_response_types::PayoutsResponseData,
 };
 use hyperswitch_domain_models::{
     router_flow_types::{
@@ -28,24 +30,26 @@ use hyperswitch_domain_models::{
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Approve, AuthorizeSessionToken, CalculateTax, CompleteAuthorize,
+            Approve, AuthorizeSessionToken, BankList, CalculateTax, CompleteAuthorize,
             CreateConnectorCustomer, IncrementalAuthorization, PostProcessing, PostSessionTokens,
             PreProcessing, Reject, SdkSessionUpdate,
         },
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
-        AcceptDisputeRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, DefendDisputeRequestData, MandateRevokeRequestData,
-        PaymentsApproveData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
-        PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
-        PaymentsTaxCalculationData, RetrieveFileRequestData, SdkPaymentsSessionUpdateData,
-        SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
+        AcceptDisputeRequestData, AuthorizeSessionTokenData, BankListRequestData,
+        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
+        MandateRevokeRequestData, PaymentsApproveData, PaymentsIncrementalAuthorizationData,
+        PaymentsPostProcessingData, PaymentsPostSessionTokensData, PaymentsPreProcessingData,
+        PaymentsRejectData, PaymentsTaxCalculationData, RetrieveFileRequestData,
+        SdkPaymentsSessionUpdateData, SubmitEvidenceRequestData, UploadFileRequestData,
+        VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, BankListResponseData, DefendDisputeResponse,
+        MandateRevokeResponseData, PaymentsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "frm")]
@@ -55,8 +59,8 @@ use hyperswitch_interfaces::api::fraud_check::{
 };
 #[cfg(feature = "payouts")]
 use hyperswitch_interfaces::api::payouts::{
-    PayoutCancel, PayoutCreate, PayoutEligibility, PayoutFulfill, PayoutQuote, PayoutRecipient,
-    PayoutRecipientAccount, PayoutSync,
+    PayoutBalance, PayoutCancel, PayoutCreate, PayoutEligibility, PayoutFulfill, PayoutQuote,
+    PayoutRecipient, PayoutRecipientAccount, PayoutRecipientStatus, PayoutSync,
 };
 use hyperswitch_interfaces::{
     api::{
@@ -64,7 +68,7 @@ use hyperswitch_interfaces::{
         disputes::{AcceptDispute, DefendDispute, Dispute, SubmitEvidence},
         files::{FileUpload, RetrieveFile, UploadFile},
         payments::{
-            ConnectorCustomer, PaymentApprove, PaymentAuthorizeSessionToken,
+            ConnectorCustomer, PaymentApprove, PaymentAuthorizeSessionToken, PaymentBankList,
             PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject,
             PaymentSessionUpdate, PaymentsCompleteAuthorize, PaymentsPostProcessing,
             PaymentsPreProcessing, TaxCalculation,
@@ -107,6 +111,7 @@ default_imp_for_authorize_session_token!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -118,6 +123,7 @@ default_imp_for_authorize_session_token!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -171,6 +177,7 @@ default_imp_for_calculate_tax!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -184,6 +191,7 @@ default_imp_for_calculate_tax!(
     connectors::Novalnet,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Rapyd,
@@ -233,9 +241,11 @@ default_imp_for_session_update!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Helcim,
     connectors::Inespay,
     connectors::Jpmorgan,
+    connectors::Plugin,
     connectors::Rapyd,
     connectors::Razorpay,
     connectors::Redsys,
@@ -299,9 +309,11 @@ default_imp_for_post_session_tokens!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Helcim,
     connectors::Inespay,
     connectors::Jpmorgan,
+    connectors::Plugin,
     connectors::Rapyd,
     connectors::Razorpay,
     connectors::Redsys,
@@ -332,6 +344,72 @@ default_imp_for_post_session_tokens!(
     connectors::Zsl
 );
 
+macro_rules! default_imp_for_bank_list {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl PaymentBankList for $path::$connector {}
+            impl
+            ConnectorIntegration<
+                BankList,
+                BankListRequestData,
+                BankListResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_bank_list!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Bamboraapac,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Boku,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Generichttp,
+    connectors::Globepay,
+    connectors::Gocardless,
+    connectors::Helcim,
+    connectors::Inespay,
+    connectors::Jpmorgan,
+    connectors::Mollie,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Plugin,
+    connectors::Powertranz,
+    connectors::Prophetpay,
+    connectors::Rapyd,
+    connectors::Razorpay,
+    connectors::Redsys,
+    connectors::Shift4,
+    connectors::Square,
+    connectors::Stax,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Volt,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Xendit,
+    connectors::Zen,
+    connectors::Zsl
+);
+
 use crate::connectors;
 macro_rules! default_imp_for_complete_authorize {
     ($($path:ident::$connector:ident),*) => {
@@ -363,6 +441,7 @@ default_imp_for_complete_authorize!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -374,6 +453,7 @@ default_imp_for_complete_authorize!(
     connectors::Nexinets,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Rapyd,
     connectors::Razorpay,
     connectors::Redsys,
@@ -423,6 +503,7 @@ default_imp_for_incremental_authorization!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -434,6 +515,7 @@ default_imp_for_incremental_authorization!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -489,6 +571,7 @@ default_imp_for_create_customer!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Helcim,
     connectors::Inespay,
@@ -501,6 +584,7 @@ default_imp_for_create_customer!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Rapyd,
@@ -552,6 +636,7 @@ default_imp_for_connector_redirect_response!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -563,6 +648,7 @@ default_imp_for_connector_redirect_response!(
     connectors::Nomupay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Rapyd,
@@ -613,6 +699,7 @@ default_imp_for_pre_processing_steps!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Helcim,
     connectors::Inespay,
@@ -622,6 +709,7 @@ default_imp_for_pre_processing_steps!(
     connectors::Nexinets,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -676,6 +764,7 @@ default_imp_for_post_processing_steps!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -687,6 +776,7 @@ default_imp_for_post_processing_steps!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -742,6 +832,7 @@ default_imp_for_approve!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -753,6 +844,7 @@ default_imp_for_approve!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -808,6 +900,7 @@ default_imp_for_reject!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -819,6 +912,7 @@ default_imp_for_reject!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -874,6 +968,7 @@ default_imp_for_webhook_source_verification!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -885,6 +980,7 @@ default_imp_for_webhook_source_verification!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -941,6 +1037,7 @@ default_imp_for_accept_dispute!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -952,6 +1049,7 @@ default_imp_for_accept_dispute!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1007,6 +1105,7 @@ default_imp_for_submit_evidence!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1018,6 +1117,7 @@ default_imp_for_submit_evidence!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1073,6 +1173,7 @@ default_imp_for_defend_dispute!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Inespay,
@@ -1084,6 +1185,7 @@ default_imp_for_defend_dispute!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1148,6 +1250,7 @@ default_imp_for_file_upload!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1159,6 +1262,7 @@ default_imp_for_file_upload!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1207,6 +1311,7 @@ default_imp_for_payouts!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1220,6 +1325,7 @@ default_imp_for_payouts!(
     connectors::Novalnet,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Rapyd,
@@ -1274,6 +1380,7 @@ default_imp_for_payouts_create!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1285,6 +1392,7 @@ default_imp_for_payouts_create!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1342,6 +1450,147 @@ default_imp_for_payouts_retrieve!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
+    connectors::Globepay,
+    connectors::Gocardless,
+    connectors::Helcim,
+    connectors::Inespay,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Plugin,
+    connectors::Powertranz,
+    connectors::Prophetpay,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Rapyd,
+    connectors::Razorpay,
+    connectors::Redsys,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Volt,
+    connectors::Xendit,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_payouts_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PayoutBalance for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            PoBalance,
+            PayoutBalanceRequestData,
+            PayoutBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_payouts_balance!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Bamboraapac,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Boku,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Generichttp,
+    connectors::Globepay,
+    connectors::Gocardless,
+    connectors::Helcim,
+    connectors::Inespay,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Plugin,
+    connectors::Powertranz,
+    connectors::Prophetpay,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Rapyd,
+    connectors::Razorpay,
+    connectors::Redsys,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Volt,
+    connectors::Xendit,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_payouts_recipient_status {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PayoutRecipientStatus for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            PoRecipientStatus,
+            PayoutsData,
+            PayoutRecipientStatusResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_payouts_recipient_status!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Bamboraapac,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Boku,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1353,6 +1602,7 @@ default_imp_for_payouts_retrieve!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1410,6 +1660,7 @@ default_imp_for_payouts_eligibility!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1421,6 +1672,7 @@ default_imp_for_payouts_eligibility!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1478,6 +1730,7 @@ default_imp_for_payouts_fulfill!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1489,6 +1742,7 @@ default_imp_for_payouts_fulfill!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1546,6 +1800,7 @@ default_imp_for_payouts_cancel!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1557,6 +1812,7 @@ default_imp_for_payouts_cancel!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1614,6 +1870,7 @@ default_imp_for_payouts_quote!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1625,6 +1882,7 @@ default_imp_for_payouts_quote!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1682,6 +1940,7 @@ default_imp_for_payouts_recipient!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1693,6 +1952,7 @@ default_imp_for_payouts_recipient!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1750,6 +2010,7 @@ default_imp_for_payouts_recipient_account!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1761,6 +2022,7 @@ default_imp_for_payouts_recipient_account!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1818,6 +2080,7 @@ default_imp_for_frm_sale!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1829,6 +2092,7 @@ default_imp_for_frm_sale!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1886,6 +2150,7 @@ default_imp_for_frm_checkout!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1897,6 +2162,7 @@ default_imp_for_frm_checkout!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1954,6 +2220,7 @@ default_imp_for_frm_transaction!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1965,6 +2232,7 @@ default_imp_for_frm_transaction!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -2022,6 +2290,7 @@ default_imp_for_frm_fulfillment!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -2033,6 +2302,7 @@ default_imp_for_frm_fulfillment!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -2090,6 +2360,7 @@ default_imp_for_frm_record_return!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -2101,6 +2372,7 @@ default_imp_for_frm_record_return!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -2155,6 +2427,7 @@ default_imp_for_revoking_mandates!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -2166,6 +2439,7 @@ default_imp_for_revoking_mandates!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -12,10 +12,10 @@ use hyperswitch_domain_models::{
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CompleteAuthorize,
-            CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-            PostProcessing, PostSessionTokens, PreProcessing, Reject, SdkSessionUpdate, Session,
-            SetupMandate, Void,
+            Approve, Authorize, AuthorizeSessionToken, BankList, CalculateTax, Capture,
+            CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, PSync,
+            PaymentMethodToken, PostProcessing, PostSessionTokens, PreProcessing, Reject,
+            SdkSessionUpdate, Session, SetupMandate, Void,
         },
         refunds::{Execute, RSync},
         webhooks::VerifyWebhookSource,
@@ -23,9 +23,9 @@ use hyperswitch_domain_models::{
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
-        MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
-        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        BankListRequestData, CompleteAuthorizeData, ConnectorCustomerData,
+        DefendDisputeRequestData, MandateRevokeRequestData, PaymentMethodTokenizationData,
+        PaymentsApproveData, PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
         PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData, RefundsData,
@@ -33,9 +33,10 @@ use hyperswitch_domain_models::{
         SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, BankListResponseData, DefendDisputeResponse,
+        MandateRevokeResponseData, PaymentsResponseData, RefundsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "frm")]
@@ -52,11 +53,13 @@ use hyperswitch_domain_models::{
 use hyperswitch_domain_models::{
     router_data_v2::PayoutFlowData,
     router_flow_types::payouts::{
-        PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount,
-        PoSync,
+        PoBalance, PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient,
+        PoRecipientAccount, PoRecipientStatus, PoSync,
+    },
+    router_request_types::{PayoutBalanceRequestData, PayoutsData},
+    router_response_types::{
+        PayoutBalanceResponseData, PayoutRecipientStatusResponseData, PayoutsResponseData,
     },
-    router_request_types::PayoutsData,
-    router_response_types::PayoutsResponseData,
 };
 #[cfg(feature = "frm")]
 use hyperswitch_interfaces::api::fraud_check_v2::{
@@ -65,8 +68,9 @@ use hyperswitch_interfaces::api::fraud_check_v2::{
 };
 #[cfg(feature = "payouts")]
 use hyperswitch_interfaces::api::payouts_v2::{
-    PayoutCancelV2, PayoutCreateV2, PayoutEligibilityV2, PayoutFulfillV2, PayoutQuoteV2,
-    PayoutRecipientAccountV2, PayoutRecipientV2, PayoutSyncV2,
+    PayoutBalanceV2, PayoutCancelV2, PayoutCreateV2, PayoutEligibilityV2, PayoutFulfillV2,
+    PayoutQuoteV2, PayoutRecipientAccountV2, PayoutRecipientStatusV2, PayoutRecipientV2,
+    PayoutSyncV2,
 };
 use hyperswitch_interfaces::{
     api::{
@@ -74,10 +78,11 @@ use hyperswitch_interfaces::{
         files_v2::{FileUploadV2, RetrieveFileV2, UploadFileV2},
         payments_v2::{
             ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
-            PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
-            PaymentPostSessionTokensV2, PaymentRejectV2, PaymentSessionUpdateV2, PaymentSessionV2,
-            PaymentSyncV2, PaymentTokenV2, PaymentV2, PaymentVoidV2, PaymentsCompleteAuthorizeV2,
-            PaymentsPostProcessingV2, PaymentsPreProcessingV2, TaxCalculationV2,
+            PaymentAuthorizeV2, PaymentBankListV2, PaymentCaptureV2,
+            PaymentIncrementalAuthorizationV2, PaymentPostSessionTokensV2, PaymentRejectV2,
+            PaymentSessionUpdateV2, PaymentSessionV2, PaymentSyncV2, PaymentTokenV2, PaymentV2,
+            PaymentVoidV2, PaymentsCompleteAuthorizeV2, PaymentsPostProcessingV2,
+            PaymentsPreProcessingV2, TaxCalculationV2,
         },
         refunds_v2::{RefundExecuteV2, RefundSyncV2, RefundV2},
         ConnectorAccessTokenV2, ConnectorMandateRevokeV2, ConnectorVerifyWebhookSourceV2,
@@ -109,6 +114,7 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl TaxCalculationV2 for $path::$connector{}
             impl PaymentSessionUpdateV2 for $path::$connector{}
             impl PaymentPostSessionTokensV2 for $path::$connector{}
+            impl PaymentBankListV2 for $path::$connector{}
             impl
             ConnectorIntegrationV2<Authorize,PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData>
             for $path::$connector{}
@@ -200,6 +206,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             PaymentsPostSessionTokensData,
             PaymentsResponseData,
             > for $path::$connector{}
+        impl
+            ConnectorIntegrationV2<
+            BankList,
+            PaymentFlowData,
+            BankListRequestData,
+            BankListResponseData,
+            > for $path::$connector{}
     )*
     };
 }
@@ -223,6 +236,7 @@ default_imp_for_new_connector_integration_payment!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -234,6 +248,7 @@ default_imp_for_new_connector_integration_payment!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -290,6 +305,7 @@ default_imp_for_new_connector_integration_refund!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -301,6 +317,7 @@ default_imp_for_new_connector_integration_refund!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -352,6 +369,7 @@ default_imp_for_new_connector_integration_connector_access_token!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -363,6 +381,7 @@ default_imp_for_new_connector_integration_connector_access_token!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -420,6 +439,7 @@ default_imp_for_new_connector_integration_accept_dispute!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -431,6 +451,7 @@ default_imp_for_new_connector_integration_accept_dispute!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -487,6 +508,7 @@ default_imp_for_new_connector_integration_submit_evidence!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -498,6 +520,7 @@ default_imp_for_new_connector_integration_submit_evidence!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -554,6 +577,7 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -565,6 +589,7 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -631,6 +656,7 @@ default_imp_for_new_connector_integration_file_upload!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -642,6 +668,7 @@ default_imp_for_new_connector_integration_file_upload!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -700,6 +727,7 @@ default_imp_for_new_connector_integration_payouts_create!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -711,6 +739,7 @@ default_imp_for_new_connector_integration_payouts_create!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -769,6 +798,7 @@ default_imp_for_new_connector_integration_payouts_eligibility!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -780,6 +810,7 @@ default_imp_for_new_connector_integration_payouts_eligibility!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -838,6 +869,7 @@ default_imp_for_new_connector_integration_payouts_fulfill!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -849,6 +881,7 @@ default_imp_for_new_connector_integration_payouts_fulfill!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -907,6 +940,7 @@ default_imp_for_new_connector_integration_payouts_cancel!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -918,6 +952,7 @@ default_imp_for_new_connector_integration_payouts_cancel!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -976,6 +1011,7 @@ default_imp_for_new_connector_integration_payouts_quote!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -987,6 +1023,7 @@ default_imp_for_new_connector_integration_payouts_quote!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1045,6 +1082,149 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
+    connectors::Globepay,
+    connectors::Gocardless,
+    connectors::Helcim,
+    connectors::Inespay,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Plugin,
+    connectors::Powertranz,
+    connectors::Prophetpay,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Rapyd,
+    connectors::Razorpay,
+    connectors::Redsys,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Volt,
+    connectors::Worldpay,
+    connectors::Xendit,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_new_connector_integration_payouts_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PayoutBalanceV2 for $path::$connector {}
+            impl
+            ConnectorIntegrationV2<
+            PoBalance,
+            PayoutFlowData,
+            PayoutBalanceRequestData,
+            PayoutBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_new_connector_integration_payouts_balance!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Bamboraapac,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Boku,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Generichttp,
+    connectors::Globepay,
+    connectors::Gocardless,
+    connectors::Helcim,
+    connectors::Inespay,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Plugin,
+    connectors::Powertranz,
+    connectors::Prophetpay,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Rapyd,
+    connectors::Razorpay,
+    connectors::Redsys,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Volt,
+    connectors::Worldpay,
+    connectors::Xendit,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_new_connector_integration_payouts_recipient_status {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PayoutRecipientStatusV2 for $path::$connector {}
+            impl
+            ConnectorIntegrationV2<
+            PoRecipientStatus,
+            PayoutFlowData,
+            PayoutsData,
+            PayoutRecipientStatusResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_new_connector_integration_payouts_recipient_status!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Bamboraapac,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Boku,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1056,6 +1236,7 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1114,6 +1295,7 @@ default_imp_for_new_connector_integration_payouts_sync!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1125,6 +1307,7 @@ default_imp_for_new_connector_integration_payouts_sync!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1183,6 +1366,7 @@ default_imp_for_new_connector_integration_payouts_recipient_account!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1194,6 +1378,7 @@ default_imp_for_new_connector_integration_payouts_recipient_account!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1250,6 +1435,7 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1261,6 +1447,7 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1319,6 +1506,7 @@ default_imp_for_new_connector_integration_frm_sale!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1330,6 +1518,7 @@ default_imp_for_new_connector_integration_frm_sale!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1388,6 +1577,7 @@ default_imp_for_new_connector_integration_frm_checkout!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1399,6 +1589,7 @@ default_imp_for_new_connector_integration_frm_checkout!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1457,6 +1648,7 @@ default_imp_for_new_connector_integration_frm_transaction!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1468,6 +1660,7 @@ default_imp_for_new_connector_integration_frm_transaction!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1526,6 +1719,7 @@ default_imp_for_new_connector_integration_frm_fulfillment!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1537,6 +1731,7 @@ default_imp_for_new_connector_integration_frm_fulfillment!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1595,6 +1790,7 @@ default_imp_for_new_connector_integration_frm_record_return!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1606,6 +1802,7 @@ default_imp_for_new_connector_integration_frm_record_return!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -1661,6 +1858,7 @@ default_imp_for_new_connector_integration_revoking_mandates!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Generichttp,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Helcim,
@@ -1672,6 +1870,7 @@ default_imp_for_new_connector_integration_revoking_mandates!(
     connectors::Nexixpay,
     connectors::Payeezy,
     connectors::Payu,
+    connectors::Plugin,
     connectors::Powertranz,
     connectors::Prophetpay,
     connectors::Mollie,
@@ -2034,6 +2034,7 @@ pub enum PaymentMethodDataType {
     PaySafeCar,
     CardToken,
     LocalBankTransfer,
+    VirtualAccountBankTransfer,
     Mifinity,
     Fps,
     PromptPay,
@@ -2190,6 +2191,9 @@ impl From<PaymentMethodData> for PaymentMethodDataType {
                      hyperswitch_domain_models::payment_method_data::BankTransferData::LocalBankTransfer { .. } => {
                         Self::LocalBankTransfer
                     }
+                     hyperswitch_domain_models::payment_method_data::BankTransferData::VirtualAccountBankTransfer {} => {
+                        Self::VirtualAccountBankTransfer
+                    }
                 }
             }
             PaymentMethodData::Crypto(_) => Self::Crypto,
@@ -18,6 +18,7 @@ pub enum ApplicationResponse<R> {
     FileData((Vec<u8>, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
+    JsonAccepted(R),
 }
 
 impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
@@ -25,6 +26,7 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
         match self {
             Self::Json(r) => r.get_api_event_type(),
             Self::JsonWithHeaders((r, _)) => r.get_api_event_type(),
+            Self::JsonAccepted(r) => r.get_api_event_type(),
             _ => None,
         }
     }
@@ -38,6 +40,17 @@ pub struct RedirectionFormData {
     pub payment_method_data: Option<PaymentMethodData>,
     pub amount: String,
     pub currency: String,
+    pub page_config: Option<RedirectionPageConfig>,
+}
+
+/// Branding and behaviour of the intermediate page shown while redirecting the customer
+#[derive(Debug, Eq, PartialEq)]
+pub struct RedirectionPageConfig {
+    pub logo: Option<String>,
+    pub merchant_name: Option<String>,
+    pub theme: Option<String>,
+    pub message: String,
+    pub auto_redirect_timeout_ms: u32,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -75,6 +88,7 @@ pub enum GenericLinksData {
     PayoutLinkStatus(GenericLinkStatusData),
     PaymentMethodCollectStatus(GenericLinkStatusData),
     SecurePaymentLink(PaymentLinkFormData),
+    CustomerPortal(GenericLinkStatusData),
 }
 
 impl Display for GenericLinksData {
@@ -89,6 +103,7 @@ impl Display for GenericLinksData {
                 Self::PayoutLinkStatus(_) => "PayoutLinkStatus",
                 Self::PaymentMethodCollectStatus(_) => "PaymentMethodCollectStatus",
                 Self::SecurePaymentLink(_) => "SecurePaymentLink",
+                Self::CustomerPortal(_) => "CustomerPortal",
             }
         )
     }
@@ -7,8 +7,13 @@ use common_utils::{
     types::keymanager,
 };
 use diesel_models::business_profile::{
-    AuthenticationConnectorDetails, BusinessPaymentLinkConfig, BusinessPayoutLinkConfig,
-    ProfileUpdateInternal, WebhookDetails,
+    AuthenticationConnectorDetails, BusinessCaptureOnShipmentConfig, BusinessClientOriginConfig,
+    BusinessDccConfig, BusinessDisputeRepresentmentConfig, BusinessGenericLinkConfig,
+    BusinessInvoiceConfig, BusinessOperationFallbackConfig, BusinessPaymentDefaultsConfig,
+    BusinessPaymentLinkConfig, BusinessPayoutBalanceConfig, BusinessPayoutFeeConfig,
+    BusinessPayoutFulfillmentConfig, BusinessPayoutLinkConfig, BusinessPayoutRetryConfig,
+    BusinessPostAuthRulesConfig, BusinessRedirectConfig, BusinessStatementDescriptorConfig,
+    ConnectorCanaryRollout, ProfileUpdateInternal, WebhookDetails, WebhookSecretRotation,
 };
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
@@ -59,6 +64,30 @@ pub struct Profile {
     pub is_auto_retries_enabled: bool,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<common_enums::EventClass>>,
+    pub webhook_payload_version: Option<common_enums::WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: OptionalEncryptableValue,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<common_enums::Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<common_enums::AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
 }
 
 #[cfg(feature = "v1")]
@@ -100,6 +129,30 @@ pub struct ProfileSetter {
     pub is_auto_retries_enabled: bool,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<common_enums::EventClass>>,
+    pub webhook_payload_version: Option<common_enums::WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: OptionalEncryptableValue,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<common_enums::Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<common_enums::AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
 }
 
 #[cfg(feature = "v1")]
@@ -148,6 +201,30 @@ impl From<ProfileSetter> for Profile {
             is_auto_retries_enabled: value.is_auto_retries_enabled,
             max_auto_retries_enabled: value.max_auto_retries_enabled,
             is_click_to_pay_enabled: value.is_click_to_pay_enabled,
+            customer_portal_link_config: value.customer_portal_link_config,
+            invoice_config: value.invoice_config,
+            enabled_webhook_events: value.enabled_webhook_events,
+            webhook_payload_version: value.webhook_payload_version,
+            statement_descriptor_config: value.statement_descriptor_config,
+            dispute_representment_config: value.dispute_representment_config,
+            outgoing_webhook_auth_config: value.outgoing_webhook_auth_config,
+            redirect_config: value.redirect_config,
+            client_origin_config: value.client_origin_config,
+            is_client_secret_single_use: value.is_client_secret_single_use,
+            post_auth_rules_config: value.post_auth_rules_config,
+            allowed_currencies: value.allowed_currencies,
+            tags: value.tags,
+            payout_retry_config: value.payout_retry_config,
+            payout_fulfillment_config: value.payout_fulfillment_config,
+            payment_defaults_config: value.payment_defaults_config,
+            capture_on_shipment_config: value.capture_on_shipment_config,
+            dcc_config: value.dcc_config,
+            payout_fee_config: value.payout_fee_config,
+            payout_balance_config: value.payout_balance_config,
+            operation_fallback_config: value.operation_fallback_config,
+            account_name_mismatch_action: value.account_name_mismatch_action,
+            connector_canary_rollout: value.connector_canary_rollout,
+            webhook_secret_rotation: value.webhook_secret_rotation,
         }
     }
 }
@@ -198,6 +275,29 @@ pub struct ProfileGeneralUpdate {
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: Option<bool>,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<common_enums::EventClass>>,
+    pub webhook_payload_version: Option<common_enums::WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: OptionalEncryptableValue,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<common_enums::Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<common_enums::AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v1")]
@@ -220,6 +320,17 @@ pub enum ProfileUpdate {
     NetworkTokenizationUpdate {
         is_network_tokenization_enabled: bool,
     },
+    /// Replaces the signing secret of the outgoing webhooks, retaining the previous secret until
+    /// the rotation expires
+    WebhookSecretUpdate {
+        payment_response_hash_key: String,
+        webhook_secret_rotation: Option<WebhookSecretRotation>,
+    },
+    /// Updates the state of the canary rollout of a connector account, such as when it is rolled
+    /// back
+    ConnectorCanaryRolloutUpdate {
+        connector_canary_rollout: ConnectorCanaryRollout,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -261,6 +372,29 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_auto_retries_enabled,
                     max_auto_retries_enabled,
                     is_click_to_pay_enabled,
+                    customer_portal_link_config,
+                    invoice_config,
+                    enabled_webhook_events,
+                    webhook_payload_version,
+                    statement_descriptor_config,
+                    dispute_representment_config,
+                    outgoing_webhook_auth_config,
+                    redirect_config,
+                    client_origin_config,
+                    is_client_secret_single_use,
+                    post_auth_rules_config,
+                    allowed_currencies,
+                    tags,
+                    payout_retry_config,
+                    payout_fulfillment_config,
+                    payment_defaults_config,
+                    capture_on_shipment_config,
+                    dcc_config,
+                    payout_fee_config,
+                    payout_balance_config,
+                    operation_fallback_config,
+                    account_name_mismatch_action,
+                    connector_canary_rollout,
                 } = *update;
 
                 Self {
@@ -299,6 +433,31 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_auto_retries_enabled,
                     max_auto_retries_enabled,
                     is_click_to_pay_enabled,
+                    customer_portal_link_config,
+                    invoice_config,
+                    enabled_webhook_events,
+                    webhook_payload_version,
+                    statement_descriptor_config,
+                    dispute_representment_config,
+                    outgoing_webhook_auth_config: outgoing_webhook_auth_config
+                        .map(Encryption::from),
+                    redirect_config,
+                    client_origin_config,
+                    is_client_secret_single_use,
+                    post_auth_rules_config,
+                    allowed_currencies,
+                    tags,
+                    payout_retry_config,
+                    payout_fulfillment_config,
+                    payment_defaults_config,
+                    capture_on_shipment_config,
+                    dcc_config,
+                    payout_fee_config,
+                    payout_balance_config,
+                    operation_fallback_config,
+                    account_name_mismatch_action,
+                    connector_canary_rollout,
+                    webhook_secret_rotation: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -339,6 +498,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -377,6 +560,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -415,6 +622,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -453,6 +684,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -491,6 +746,155 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
+            },
+            ProfileUpdate::WebhookSecretUpdate {
+                payment_response_hash_key,
+                webhook_secret_rotation,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation,
+            },
+            ProfileUpdate::ConnectorCanaryRolloutUpdate {
+                connector_canary_rollout,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: Some(connector_canary_rollout),
+                webhook_secret_rotation: None,
             },
         }
     }
@@ -548,6 +952,30 @@ impl super::behaviour::Conversion for Profile {
             is_auto_retries_enabled: Some(self.is_auto_retries_enabled),
             max_auto_retries_enabled: self.max_auto_retries_enabled,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+            customer_portal_link_config: self.customer_portal_link_config,
+            invoice_config: self.invoice_config,
+            enabled_webhook_events: self.enabled_webhook_events,
+            webhook_payload_version: self.webhook_payload_version,
+            statement_descriptor_config: self.statement_descriptor_config,
+            dispute_representment_config: self.dispute_representment_config,
+            outgoing_webhook_auth_config: self.outgoing_webhook_auth_config.map(Encryption::from),
+            redirect_config: self.redirect_config,
+            client_origin_config: self.client_origin_config,
+            is_client_secret_single_use: self.is_client_secret_single_use,
+            post_auth_rules_config: self.post_auth_rules_config,
+            allowed_currencies: self.allowed_currencies,
+            tags: self.tags,
+            payout_retry_config: self.payout_retry_config,
+            payout_fulfillment_config: self.payout_fulfillment_config,
+            payment_defaults_config: self.payment_defaults_config,
+            capture_on_shipment_config: self.capture_on_shipment_config,
+            dcc_config: self.dcc_config,
+            payout_fee_config: self.payout_fee_config,
+            payout_balance_config: self.payout_balance_config,
+            operation_fallback_config: self.operation_fallback_config,
+            account_name_mismatch_action: self.account_name_mismatch_action,
+            connector_canary_rollout: self.connector_canary_rollout,
+            webhook_secret_rotation: self.webhook_secret_rotation,
         })
     }
 
@@ -617,6 +1045,43 @@ impl super::behaviour::Conversion for Profile {
                 is_auto_retries_enabled: item.is_auto_retries_enabled.unwrap_or(false),
                 max_auto_retries_enabled: item.max_auto_retries_enabled,
                 is_click_to_pay_enabled: item.is_click_to_pay_enabled,
+                customer_portal_link_config: item.customer_portal_link_config,
+                invoice_config: item.invoice_config,
+                enabled_webhook_events: item.enabled_webhook_events,
+                webhook_payload_version: item.webhook_payload_version,
+                statement_descriptor_config: item.statement_descriptor_config,
+                dispute_representment_config: item.dispute_representment_config,
+                outgoing_webhook_auth_config: item
+                    .outgoing_webhook_auth_config
+                    .async_lift(|inner| async {
+                        crypto_operation(
+                            state,
+                            type_name!(Self::DstType),
+                            CryptoOperation::DecryptOptional(inner),
+                            key_manager_identifier.clone(),
+                            key.peek(),
+                        )
+                        .await
+                        .and_then(|val| val.try_into_optionaloperation())
+                    })
+                    .await?,
+                redirect_config: item.redirect_config,
+                client_origin_config: item.client_origin_config,
+                is_client_secret_single_use: item.is_client_secret_single_use,
+                post_auth_rules_config: item.post_auth_rules_config,
+                allowed_currencies: item.allowed_currencies,
+                tags: item.tags,
+                payout_retry_config: item.payout_retry_config,
+                payout_fulfillment_config: item.payout_fulfillment_config,
+                payment_defaults_config: item.payment_defaults_config,
+                capture_on_shipment_config: item.capture_on_shipment_config,
+                dcc_config: item.dcc_config,
+                payout_fee_config: item.payout_fee_config,
+                payout_balance_config: item.payout_balance_config,
+                operation_fallback_config: item.operation_fallback_config,
+                account_name_mismatch_action: item.account_name_mismatch_action,
+                connector_canary_rollout: item.connector_canary_rollout,
+                webhook_secret_rotation: item.webhook_secret_rotation,
             })
         }
         .await
@@ -670,6 +1135,30 @@ impl super::behaviour::Conversion for Profile {
             is_auto_retries_enabled: Some(self.is_auto_retries_enabled),
             max_auto_retries_enabled: self.max_auto_retries_enabled,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+            customer_portal_link_config: self.customer_portal_link_config,
+            invoice_config: self.invoice_config,
+            enabled_webhook_events: self.enabled_webhook_events,
+            webhook_payload_version: self.webhook_payload_version,
+            statement_descriptor_config: self.statement_descriptor_config,
+            dispute_representment_config: self.dispute_representment_config,
+            outgoing_webhook_auth_config: self.outgoing_webhook_auth_config.map(Encryption::from),
+            redirect_config: self.redirect_config,
+            client_origin_config: self.client_origin_config,
+            is_client_secret_single_use: self.is_client_secret_single_use,
+            post_auth_rules_config: self.post_auth_rules_config,
+            allowed_currencies: self.allowed_currencies,
+            tags: self.tags,
+            payout_retry_config: self.payout_retry_config,
+            payout_fulfillment_config: self.payout_fulfillment_config,
+            payment_defaults_config: self.payment_defaults_config,
+            capture_on_shipment_config: self.capture_on_shipment_config,
+            dcc_config: self.dcc_config,
+            payout_fee_config: self.payout_fee_config,
+            payout_balance_config: self.payout_balance_config,
+            operation_fallback_config: self.operation_fallback_config,
+            account_name_mismatch_action: self.account_name_mismatch_action,
+            connector_canary_rollout: self.connector_canary_rollout,
+            webhook_secret_rotation: self.webhook_secret_rotation,
         })
     }
 }
@@ -715,6 +1204,30 @@ pub struct Profile {
     pub version: common_enums::ApiVersion,
     pub is_network_tokenization_enabled: bool,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<common_enums::EventClass>>,
+    pub webhook_payload_version: Option<common_enums::WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: OptionalEncryptableValue,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<common_enums::Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<common_enums::AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
 }
 
 #[cfg(feature = "v2")]
@@ -756,6 +1269,30 @@ pub struct ProfileSetter {
     pub is_tax_connector_enabled: bool,
     pub is_network_tokenization_enabled: bool,
     pub is_click_to_pay_enabled: bool,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<common_enums::EventClass>>,
+    pub webhook_payload_version: Option<common_enums::WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: OptionalEncryptableValue,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<common_enums::Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<common_enums::AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
+    pub webhook_secret_rotation: Option<WebhookSecretRotation>,
 }
 
 #[cfg(feature = "v2")]
@@ -804,6 +1341,30 @@ impl From<ProfileSetter> for Profile {
             version: consts::API_VERSION,
             is_network_tokenization_enabled: value.is_network_tokenization_enabled,
             is_click_to_pay_enabled: value.is_click_to_pay_enabled,
+            customer_portal_link_config: value.customer_portal_link_config,
+            invoice_config: value.invoice_config,
+            enabled_webhook_events: value.enabled_webhook_events,
+            webhook_payload_version: value.webhook_payload_version,
+            statement_descriptor_config: value.statement_descriptor_config,
+            dispute_representment_config: value.dispute_representment_config,
+            outgoing_webhook_auth_config: value.outgoing_webhook_auth_config,
+            redirect_config: value.redirect_config,
+            client_origin_config: value.client_origin_config,
+            is_client_secret_single_use: value.is_client_secret_single_use,
+            post_auth_rules_config: value.post_auth_rules_config,
+            allowed_currencies: value.allowed_currencies,
+            tags: value.tags,
+            payout_retry_config: value.payout_retry_config,
+            payout_fulfillment_config: value.payout_fulfillment_config,
+            payment_defaults_config: value.payment_defaults_config,
+            capture_on_shipment_config: value.capture_on_shipment_config,
+            dcc_config: value.dcc_config,
+            payout_fee_config: value.payout_fee_config,
+            payout_balance_config: value.payout_balance_config,
+            operation_fallback_config: value.operation_fallback_config,
+            account_name_mismatch_action: value.account_name_mismatch_action,
+            connector_canary_rollout: value.connector_canary_rollout,
+            webhook_secret_rotation: value.webhook_secret_rotation,
         }
     }
 }
@@ -826,6 +1387,26 @@ impl Profile {
     pub fn get_order_fulfillment_time(&self) -> Option<i64> {
         self.order_fulfillment_time
     }
+
+    /// Outgoing webhooks of all event classes are enabled if the profile does not specify the
+    /// enabled webhook events
+    pub fn is_webhook_event_enabled(&self, event_class: common_enums::EventClass) -> bool {
+        self.enabled_webhook_events
+            .as_ref()
+            .map_or(true, |enabled_webhook_events| {
+                enabled_webhook_events.contains(&event_class)
+            })
+    }
+
+    /// Payments and payouts of all currencies are allowed if the profile does not specify the
+    /// allowed currencies
+    pub fn is_currency_allowed(&self, currency: common_enums::Currency) -> bool {
+        self.allowed_currencies
+            .as_ref()
+            .map_or(true, |allowed_currencies| {
+                allowed_currencies.contains(&currency)
+            })
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -855,6 +1436,29 @@ pub struct ProfileGeneralUpdate {
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
     pub is_network_tokenization_enabled: Option<bool>,
     pub is_click_to_pay_enabled: Option<bool>,
+    pub customer_portal_link_config: Option<BusinessGenericLinkConfig>,
+    pub invoice_config: Option<BusinessInvoiceConfig>,
+    pub enabled_webhook_events: Option<Vec<common_enums::EventClass>>,
+    pub webhook_payload_version: Option<common_enums::WebhookPayloadVersion>,
+    pub statement_descriptor_config: Option<BusinessStatementDescriptorConfig>,
+    pub dispute_representment_config: Option<BusinessDisputeRepresentmentConfig>,
+    pub outgoing_webhook_auth_config: OptionalEncryptableValue,
+    pub redirect_config: Option<BusinessRedirectConfig>,
+    pub client_origin_config: Option<BusinessClientOriginConfig>,
+    pub is_client_secret_single_use: Option<bool>,
+    pub post_auth_rules_config: Option<BusinessPostAuthRulesConfig>,
+    pub allowed_currencies: Option<Vec<common_enums::Currency>>,
+    pub tags: Option<Vec<String>>,
+    pub payout_retry_config: Option<BusinessPayoutRetryConfig>,
+    pub payout_fulfillment_config: Option<BusinessPayoutFulfillmentConfig>,
+    pub payment_defaults_config: Option<BusinessPaymentDefaultsConfig>,
+    pub capture_on_shipment_config: Option<BusinessCaptureOnShipmentConfig>,
+    pub dcc_config: Option<BusinessDccConfig>,
+    pub payout_fee_config: Option<BusinessPayoutFeeConfig>,
+    pub payout_balance_config: Option<BusinessPayoutBalanceConfig>,
+    pub operation_fallback_config: Option<BusinessOperationFallbackConfig>,
+    pub account_name_mismatch_action: Option<common_enums::AccountNameMismatchAction>,
+    pub connector_canary_rollout: Option<ConnectorCanaryRollout>,
 }
 
 #[cfg(feature = "v2")]
@@ -880,6 +1484,17 @@ pub enum ProfileUpdate {
     CollectCvvDuringPaymentUpdate {
         should_collect_cvv_during_payment: bool,
     },
+    /// Replaces the signing secret of the outgoing webhooks, retaining the previous secret until
+    /// the rotation expires
+    WebhookSecretUpdate {
+        payment_response_hash_key: String,
+        webhook_secret_rotation: Option<WebhookSecretRotation>,
+    },
+    /// Updates the state of the canary rollout of a connector account, such as when it is rolled
+    /// back
+    ConnectorCanaryRolloutUpdate {
+        connector_canary_rollout: ConnectorCanaryRollout,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -914,6 +1529,29 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     order_fulfillment_time_origin,
                     is_network_tokenization_enabled,
                     is_click_to_pay_enabled,
+                    customer_portal_link_config,
+                    invoice_config,
+                    enabled_webhook_events,
+                    webhook_payload_version,
+                    statement_descriptor_config,
+                    dispute_representment_config,
+                    outgoing_webhook_auth_config,
+                    redirect_config,
+                    client_origin_config,
+                    is_client_secret_single_use,
+                    post_auth_rules_config,
+                    allowed_currencies,
+                    tags,
+                    payout_retry_config,
+                    payout_fulfillment_config,
+                    payment_defaults_config,
+                    capture_on_shipment_config,
+                    dcc_config,
+                    payout_fee_config,
+                    payout_balance_config,
+                    operation_fallback_config,
+                    account_name_mismatch_action,
+                    connector_canary_rollout,
                 } = *update;
                 Self {
                     profile_name,
@@ -953,6 +1591,31 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_auto_retries_enabled: None,
                     max_auto_retries_enabled: None,
                     is_click_to_pay_enabled,
+                    customer_portal_link_config,
+                    invoice_config,
+                    enabled_webhook_events,
+                    webhook_payload_version,
+                    statement_descriptor_config,
+                    dispute_representment_config,
+                    outgoing_webhook_auth_config: outgoing_webhook_auth_config
+                        .map(Encryption::from),
+                    redirect_config,
+                    client_origin_config,
+                    is_client_secret_single_use,
+                    post_auth_rules_config,
+                    allowed_currencies,
+                    tags,
+                    payout_retry_config,
+                    payout_fulfillment_config,
+                    payment_defaults_config,
+                    capture_on_shipment_config,
+                    dcc_config,
+                    payout_fee_config,
+                    payout_balance_config,
+                    operation_fallback_config,
+                    account_name_mismatch_action,
+                    connector_canary_rollout,
+                    webhook_secret_rotation: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -995,6 +1658,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1035,6 +1722,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1075,6 +1786,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1115,6 +1850,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1155,6 +1914,30 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
             },
             ProfileUpdate::CollectCvvDuringPaymentUpdate {
                 should_collect_cvv_during_payment,
@@ -1195,6 +1978,159 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation: None,
+            },
+            ProfileUpdate::WebhookSecretUpdate {
+                payment_response_hash_key,
+                webhook_secret_rotation,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                routing_algorithm_id: None,
+                payout_routing_algorithm_id: None,
+                order_fulfillment_time: None,
+                order_fulfillment_time_origin: None,
+                frm_routing_algorithm_id: None,
+                default_fallback_routing: None,
+                should_collect_cvv_during_payment: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: None,
+                webhook_secret_rotation,
+            },
+            ProfileUpdate::ConnectorCanaryRolloutUpdate {
+                connector_canary_rollout,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                routing_algorithm_id: None,
+                payout_routing_algorithm_id: None,
+                order_fulfillment_time: None,
+                order_fulfillment_time_origin: None,
+                frm_routing_algorithm_id: None,
+                default_fallback_routing: None,
+                should_collect_cvv_during_payment: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_click_to_pay_enabled: None,
+                customer_portal_link_config: None,
+                invoice_config: None,
+                enabled_webhook_events: None,
+                webhook_payload_version: None,
+                statement_descriptor_config: None,
+                dispute_representment_config: None,
+                outgoing_webhook_auth_config: None,
+                redirect_config: None,
+                client_origin_config: None,
+                is_client_secret_single_use: None,
+                post_auth_rules_config: None,
+                allowed_currencies: None,
+                tags: None,
+                payout_retry_config: None,
+                payout_fulfillment_config: None,
+                payment_defaults_config: None,
+                capture_on_shipment_config: None,
+                dcc_config: None,
+                payout_fee_config: None,
+                payout_balance_config: None,
+                operation_fallback_config: None,
+                account_name_mismatch_action: None,
+                connector_canary_rollout: Some(connector_canary_rollout),
+                webhook_secret_rotation: None,
             },
         }
     }
@@ -1255,6 +2191,30 @@ impl super::behaviour::Conversion for Profile {
             is_auto_retries_enabled: None,
             max_auto_retries_enabled: None,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+            customer_portal_link_config: self.customer_portal_link_config,
+            invoice_config: self.invoice_config,
+            enabled_webhook_events: self.enabled_webhook_events,
+            webhook_payload_version: self.webhook_payload_version,
+            statement_descriptor_config: self.statement_descriptor_config,
+            dispute_representment_config: self.dispute_representment_config,
+            outgoing_webhook_auth_config: self.outgoing_webhook_auth_config.map(Encryption::from),
+            redirect_config: self.redirect_config,
+            client_origin_config: self.client_origin_config,
+            is_client_secret_single_use: self.is_client_secret_single_use,
+            post_auth_rules_config: self.post_auth_rules_config,
+            allowed_currencies: self.allowed_currencies,
+            tags: self.tags,
+            payout_retry_config: self.payout_retry_config,
+            payout_fulfillment_config: self.payout_fulfillment_config,
+            payment_defaults_config: self.payment_defaults_config,
+            capture_on_shipment_config: self.capture_on_shipment_config,
+            dcc_config: self.dcc_config,
+            payout_fee_config: self.payout_fee_config,
+            payout_balance_config: self.payout_balance_config,
+            operation_fallback_config: self.operation_fallback_config,
+            account_name_mismatch_action: self.account_name_mismatch_action,
+            connector_canary_rollout: self.connector_canary_rollout,
+            webhook_secret_rotation: self.webhook_secret_rotation,
         })
     }
 
@@ -1324,6 +2284,43 @@ impl super::behaviour::Conversion for Profile {
                 version: item.version,
                 is_network_tokenization_enabled: item.is_network_tokenization_enabled,
                 is_click_to_pay_enabled: item.is_click_to_pay_enabled,
+                customer_portal_link_config: item.customer_portal_link_config,
+                invoice_config: item.invoice_config,
+                enabled_webhook_events: item.enabled_webhook_events,
+                webhook_payload_version: item.webhook_payload_version,
+                statement_descriptor_config: item.statement_descriptor_config,
+                dispute_representment_config: item.dispute_representment_config,
+                outgoing_webhook_auth_config: item
+                    .outgoing_webhook_auth_config
+                    .async_lift(|inner| async {
+                        crypto_operation(
+                            state,
+                            type_name!(Self::DstType),
+                            CryptoOperation::DecryptOptional(inner),
+                            key_manager_identifier.clone(),
+                            key.peek(),
+                        )
+                        .await
+                        .and_then(|val| val.try_into_optionaloperation())
+                    })
+                    .await?,
+                redirect_config: item.redirect_config,
+                client_origin_config: item.client_origin_config,
+                is_client_secret_single_use: item.is_client_secret_single_use,
+                post_auth_rules_config: item.post_auth_rules_config,
+                allowed_currencies: item.allowed_currencies,
+                tags: item.tags,
+                payout_retry_config: item.payout_retry_config,
+                payout_fulfillment_config: item.payout_fulfillment_config,
+                payment_defaults_config: item.payment_defaults_config,
+                capture_on_shipment_config: item.capture_on_shipment_config,
+                dcc_config: item.dcc_config,
+                payout_fee_config: item.payout_fee_config,
+                payout_balance_config: item.payout_balance_config,
+                operation_fallback_config: item.operation_fallback_config,
+                account_name_mismatch_action: item.account_name_mismatch_action,
+                connector_canary_rollout: item.connector_canary_rollout,
+                webhook_secret_rotation: item.webhook_secret_rotation,
             })
         }
         .await
@@ -1380,6 +2377,30 @@ impl super::behaviour::Conversion for Profile {
             is_auto_retries_enabled: None,
             max_auto_retries_enabled: None,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+            customer_portal_link_config: self.customer_portal_link_config,
+            invoice_config: self.invoice_config,
+            enabled_webhook_events: self.enabled_webhook_events,
+            webhook_payload_version: self.webhook_payload_version,
+            statement_descriptor_config: self.statement_descriptor_config,
+            dispute_representment_config: self.dispute_representment_config,
+            outgoing_webhook_auth_config: self.outgoing_webhook_auth_config.map(Encryption::from),
+            redirect_config: self.redirect_config,
+            client_origin_config: self.client_origin_config,
+            is_client_secret_single_use: self.is_client_secret_single_use,
+            post_auth_rules_config: self.post_auth_rules_config,
+            allowed_currencies: self.allowed_currencies,
+            tags: self.tags,
+            payout_retry_config: self.payout_retry_config,
+            payout_fulfillment_config: self.payout_fulfillment_config,
+            payment_defaults_config: self.payment_defaults_config,
+            capture_on_shipment_config: self.capture_on_shipment_config,
+            dcc_config: self.dcc_config,
+            payout_fee_config: self.payout_fee_config,
+            payout_balance_config: self.payout_balance_config,
+            operation_fallback_config: self.operation_fallback_config,
+            account_name_mismatch_action: self.account_name_mismatch_action,
+            connector_canary_rollout: self.connector_canary_rollout,
+            webhook_secret_rotation: self.webhook_secret_rotation,
         })
     }
 }
@@ -40,6 +40,7 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: common_enums::ApiVersion,
+    pub recipient_kyc_status: Option<common_enums::RecipientKycStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -66,6 +67,7 @@ pub struct Customer {
     pub id: String,
     pub version: common_enums::ApiVersion,
     pub status: DeleteStatus,
+    pub recipient_kyc_status: Option<common_enums::RecipientKycStatus>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -90,6 +92,7 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: self.default_payment_method_id,
             updated_by: self.updated_by,
             version: self.version,
+            recipient_kyc_status: self.recipient_kyc_status,
         })
     }
 
@@ -148,6 +151,7 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: item.default_payment_method_id,
             updated_by: item.updated_by,
             version: item.version,
+            recipient_kyc_status: item.recipient_kyc_status,
         })
     }
 
@@ -197,6 +201,7 @@ impl super::behaviour::Conversion for Customer {
             default_shipping_address: self.default_shipping_address.map(Encryption::from),
             version: self.version,
             status: self.status,
+            recipient_kyc_status: self.recipient_kyc_status,
         })
     }
 
@@ -258,6 +263,7 @@ impl super::behaviour::Conversion for Customer {
             default_shipping_address: item.default_shipping_address,
             version: item.version,
             status: item.status,
+            recipient_kyc_status: item.recipient_kyc_status,
         })
     }
 
@@ -308,6 +314,9 @@ pub enum CustomerUpdate {
     UpdateDefaultPaymentMethod {
         default_payment_method_id: Option<Option<String>>,
     },
+    RecipientKycStatusUpdate {
+        recipient_kyc_status: common_enums::RecipientKycStatus,
+    },
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -340,6 +349,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_payment_method_id,
                 updated_by: None,
                 status,
+                recipient_kyc_status: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -355,6 +365,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_billing_address: None,
                 default_shipping_address: None,
                 status: None,
+                recipient_kyc_status: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -372,6 +383,25 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_billing_address: None,
                 default_shipping_address: None,
                 status: None,
+                recipient_kyc_status: None,
+            },
+            CustomerUpdate::RecipientKycStatusUpdate {
+                recipient_kyc_status,
+            } => Self {
+                recipient_kyc_status: Some(recipient_kyc_status),
+                modified_at: date_time::now(),
+                name: None,
+                email: None,
+                phone: None,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                default_payment_method_id: None,
+                updated_by: None,
+                default_billing_address: None,
+                default_shipping_address: None,
+                status: None,
             },
         }
     }
@@ -396,6 +426,9 @@ pub enum CustomerUpdate {
     UpdateDefaultPaymentMethod {
         default_payment_method_id: Option<Option<String>>,
     },
+    RecipientKycStatusUpdate {
+        recipient_kyc_status: common_enums::RecipientKycStatus,
+    },
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -423,6 +456,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 address_id,
                 default_payment_method_id: None,
                 updated_by: None,
+                recipient_kyc_status: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -436,6 +470,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_payment_method_id: None,
                 updated_by: None,
                 address_id: None,
+                recipient_kyc_status: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -451,6 +486,23 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer: None,
                 updated_by: None,
                 address_id: None,
+                recipient_kyc_status: None,
+            },
+            CustomerUpdate::RecipientKycStatusUpdate {
+                recipient_kyc_status,
+            } => Self {
+                recipient_kyc_status: Some(recipient_kyc_status),
+                modified_at: date_time::now(),
+                name: None,
+                email: None,
+                phone: None,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                default_payment_method_id: None,
+                updated_by: None,
+                address_id: None,
             },
         }
     }
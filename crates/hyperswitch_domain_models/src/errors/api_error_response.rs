@@ -279,6 +279,8 @@ pub enum ApiErrorResponse {
         message = "Cookies are not found in the request"
     )]
     CookieNotFound,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_43", message = "Insufficient balance with {connector} to disburse the payout in {currency}")]
+    InsufficientPayoutBalance { connector: String, currency: String },
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
@@ -635,6 +637,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::CookieNotFound => {
                 AER::Unauthorized(ApiError::new("IR", 42, "Cookies are not found in the request", None))
             },
+            Self::InsufficientPayoutBalance { connector, currency } => {
+                AER::BadRequest(ApiError::new("IR", 43, format!("Insufficient balance with {connector} to disburse the payout in {currency}"), None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
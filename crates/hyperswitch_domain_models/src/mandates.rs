@@ -68,6 +68,10 @@ pub struct CustomerAcceptance {
     pub accepted_at: Option<PrimitiveDateTime>,
     /// Information required for online mandate generation
     pub online: Option<OnlineMandate>,
+    /// The version of the consent text which was presented to the customer
+    pub consent_text_version: Option<String>,
+    /// The identifier of the mandate document signed by the customer
+    pub signed_mandate_file_id: Option<String>,
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Clone, serde::Deserialize, serde::Serialize)]
@@ -174,6 +178,8 @@ impl From<ApiCustomerAcceptance> for CustomerAcceptance {
             acceptance_type: value.acceptance_type.into(),
             accepted_at: value.accepted_at,
             online: value.online.map(|d| d.into()),
+            consent_text_version: value.consent_text_version,
+            signed_mandate_file_id: value.signed_mandate_file_id,
         }
     }
 }
@@ -184,6 +190,8 @@ impl From<CustomerAcceptance> for ApiCustomerAcceptance {
             acceptance_type: value.acceptance_type.into(),
             accepted_at: value.accepted_at,
             online: value.online.map(|d| d.into()),
+            consent_text_version: value.consent_text_version,
+            signed_mandate_file_id: value.signed_mandate_file_id,
         }
     }
 }
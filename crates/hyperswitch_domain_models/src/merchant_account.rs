@@ -46,6 +46,10 @@ pub struct MerchantAccount {
     pub recon_status: diesel_models::enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
+    pub id_prefixes: Option<serde_json::Value>,
+    pub data_retention_policy: Option<serde_json::Value>,
+    pub previous_publishable_key: Option<String>,
+    pub previous_publishable_key_expires_at: Option<time::PrimitiveDateTime>,
     pub version: common_enums::ApiVersion,
 }
 
@@ -80,6 +84,10 @@ pub struct MerchantAccountSetter {
     pub recon_status: diesel_models::enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
+    pub id_prefixes: Option<serde_json::Value>,
+    pub data_retention_policy: Option<serde_json::Value>,
+    pub previous_publishable_key: Option<String>,
+    pub previous_publishable_key_expires_at: Option<time::PrimitiveDateTime>,
     pub version: common_enums::ApiVersion,
 }
 
@@ -114,6 +122,10 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             recon_status: item.recon_status,
             payment_link_config: item.payment_link_config,
             pm_collect_link_config: item.pm_collect_link_config,
+            id_prefixes: item.id_prefixes,
+            data_retention_policy: item.data_retention_policy,
+            previous_publishable_key: item.previous_publishable_key,
+            previous_publishable_key_expires_at: item.previous_publishable_key_expires_at,
             version: item.version,
         }
     }
@@ -224,6 +236,8 @@ pub enum MerchantAccountUpdate {
         default_profile: Option<Option<common_utils::id_type::ProfileId>>,
         payment_link_config: Option<serde_json::Value>,
         pm_collect_link_config: Option<serde_json::Value>,
+        id_prefixes: Option<serde_json::Value>,
+        data_retention_policy: Option<serde_json::Value>,
     },
     StorageSchemeUpdate {
         storage_scheme: MerchantStorageScheme,
@@ -233,6 +247,11 @@ pub enum MerchantAccountUpdate {
     },
     UnsetDefaultProfile,
     ModifiedAtUpdate,
+    PublishableKeyRotation {
+        publishable_key: String,
+        previous_publishable_key: String,
+        previous_publishable_key_expires_at: time::PrimitiveDateTime,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -281,6 +300,8 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile,
                 payment_link_config,
                 pm_collect_link_config,
+                id_prefixes,
+                data_retention_policy,
             } => Self {
                 merchant_name: merchant_name.map(Encryption::from),
                 merchant_details: merchant_details.map(Encryption::from),
@@ -303,6 +324,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile,
                 payment_link_config,
                 pm_collect_link_config,
+                id_prefixes,
+                data_retention_policy,
+                previous_publishable_key: None,
+                previous_publishable_key_expires_at: None,
                 storage_scheme: None,
                 organization_id: None,
                 is_recon_enabled: None,
@@ -334,6 +359,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                id_prefixes: None,
+                data_retention_policy: None,
+                previous_publishable_key: None,
+                previous_publishable_key_expires_at: None,
             },
             MerchantAccountUpdate::ReconUpdate { recon_status } => Self {
                 recon_status: Some(recon_status),
@@ -361,6 +390,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                id_prefixes: None,
+                data_retention_policy: None,
+                previous_publishable_key: None,
+                previous_publishable_key_expires_at: None,
             },
             MerchantAccountUpdate::UnsetDefaultProfile => Self {
                 default_profile: Some(None),
@@ -388,6 +421,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                id_prefixes: None,
+                data_retention_policy: None,
+                previous_publishable_key: None,
+                previous_publishable_key_expires_at: None,
             },
             MerchantAccountUpdate::ModifiedAtUpdate => Self {
                 modified_at: now,
@@ -415,6 +452,45 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                id_prefixes: None,
+                data_retention_policy: None,
+                previous_publishable_key: None,
+                previous_publishable_key_expires_at: None,
+            },
+            MerchantAccountUpdate::PublishableKeyRotation {
+                publishable_key,
+                previous_publishable_key,
+                previous_publishable_key_expires_at,
+            } => Self {
+                publishable_key: Some(publishable_key),
+                previous_publishable_key: Some(previous_publishable_key),
+                previous_publishable_key_expires_at: Some(previous_publishable_key_expires_at),
+                modified_at: now,
+                merchant_name: None,
+                merchant_details: None,
+                return_url: None,
+                webhook_details: None,
+                sub_merchants_enabled: None,
+                parent_merchant_id: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                storage_scheme: None,
+                locker_id: None,
+                metadata: None,
+                routing_algorithm: None,
+                primary_business_details: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                organization_id: None,
+                is_recon_enabled: None,
+                default_profile: None,
+                recon_status: None,
+                payment_link_config: None,
+                pm_collect_link_config: None,
+                id_prefixes: None,
+                data_retention_policy: None,
             },
         }
     }
@@ -613,6 +689,10 @@ impl super::behaviour::Conversion for MerchantAccount {
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
             pm_collect_link_config: self.pm_collect_link_config,
+            id_prefixes: self.id_prefixes,
+            data_retention_policy: self.data_retention_policy,
+            previous_publishable_key: self.previous_publishable_key,
+            previous_publishable_key_expires_at: self.previous_publishable_key_expires_at,
             version: self.version,
         };
 
@@ -690,6 +770,10 @@ impl super::behaviour::Conversion for MerchantAccount {
                 recon_status: item.recon_status,
                 payment_link_config: item.payment_link_config,
                 pm_collect_link_config: item.pm_collect_link_config,
+                id_prefixes: item.id_prefixes,
+                data_retention_policy: item.data_retention_policy,
+                previous_publishable_key: item.previous_publishable_key,
+                previous_publishable_key_expires_at: item.previous_publishable_key_expires_at,
                 version: item.version,
             })
         }
@@ -728,6 +812,10 @@ impl super::behaviour::Conversion for MerchantAccount {
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
             pm_collect_link_config: self.pm_collect_link_config,
+            id_prefixes: self.id_prefixes,
+            data_retention_policy: self.data_retention_policy,
+            previous_publishable_key: self.previous_publishable_key,
+            previous_publishable_key_expires_at: self.previous_publishable_key_expires_at,
             version: crate::consts::API_VERSION,
         })
     }
@@ -744,4 +832,44 @@ impl MerchantAccount {
             });
         metadata.and_then(|a| a.compatible_connector)
     }
+
+    #[cfg(feature = "v1")]
+    pub fn get_id_prefixes(&self) -> Option<api_models::admin::MerchantIdPrefixes> {
+        self.id_prefixes.as_ref().and_then(|id_prefixes| {
+            id_prefixes
+                .clone()
+                .parse_value("MerchantIdPrefixes")
+                .map_err(|err| logger::error!("Failed to deserialize {:?}", err))
+                .ok()
+        })
+    }
+
+    /// Id prefixes are not configurable for v2 merchant accounts
+    #[cfg(feature = "v2")]
+    pub fn get_id_prefixes(&self) -> Option<api_models::admin::MerchantIdPrefixes> {
+        None
+    }
+
+    #[cfg(feature = "v1")]
+    pub fn get_data_retention_policy(
+        &self,
+    ) -> Option<api_models::admin::MerchantDataRetentionPolicy> {
+        self.data_retention_policy
+            .as_ref()
+            .and_then(|data_retention_policy| {
+                data_retention_policy
+                    .clone()
+                    .parse_value("MerchantDataRetentionPolicy")
+                    .map_err(|err| logger::error!("Failed to deserialize {:?}", err))
+                    .ok()
+            })
+    }
+
+    /// Data retention policies are not configurable for v2 merchant accounts
+    #[cfg(feature = "v2")]
+    pub fn get_data_retention_policy(
+        &self,
+    ) -> Option<api_models::admin::MerchantDataRetentionPolicy> {
+        None
+    }
 }
@@ -10,7 +10,12 @@ use common_utils::{
     id_type, pii, type_name,
     types::keymanager::{Identifier, KeyManagerState, ToEncryptable},
 };
-use diesel_models::{enums, merchant_connector_account::MerchantConnectorAccountUpdateInternal};
+use diesel_models::{
+    enums,
+    merchant_connector_account::{
+        ConnectorCredentialsExpiry, MerchantConnectorAccountUpdateInternal,
+    },
+};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 #[cfg(feature = "v2")]
@@ -49,6 +54,8 @@ pub struct MerchantConnectorAccount {
     pub profile_id: id_type::ProfileId,
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: enums::ConnectorStatus,
     #[encrypt]
     pub connector_wallets_details: Option<Encryptable<Secret<Value>>>,
@@ -84,6 +91,8 @@ pub struct MerchantConnectorAccount {
     pub profile_id: id_type::ProfileId,
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
+    pub tags: Option<Vec<String>>,
+    pub credentials_expiry: Option<ConnectorCredentialsExpiry>,
     pub status: enums::ConnectorStatus,
     #[encrypt]
     pub connector_wallets_details: Option<Encryptable<Secret<Value>>>,
@@ -152,6 +161,8 @@ pub enum MerchantConnectorAccountUpdate {
         connector_webhook_details: Box<Option<pii::SecretSerdeValue>>,
         applepay_verified_domains: Option<Vec<String>>,
         pm_auth_config: Box<Option<pii::SecretSerdeValue>>,
+        tags: Option<Vec<String>>,
+        credentials_expiry: Option<ConnectorCredentialsExpiry>,
         connector_label: Option<String>,
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
@@ -160,6 +171,10 @@ pub enum MerchantConnectorAccountUpdate {
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
     },
+    StatusUpdate {
+        disabled: Option<bool>,
+        status: enums::ConnectorStatus,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -175,6 +190,8 @@ pub enum MerchantConnectorAccountUpdate {
         connector_webhook_details: Option<pii::SecretSerdeValue>,
         applepay_verified_domains: Option<Vec<String>>,
         pm_auth_config: Box<Option<pii::SecretSerdeValue>>,
+        tags: Option<Vec<String>>,
+        credentials_expiry: Option<ConnectorCredentialsExpiry>,
         connector_label: Option<String>,
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
@@ -183,6 +200,10 @@ pub enum MerchantConnectorAccountUpdate {
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
     },
+    StatusUpdate {
+        disabled: Option<bool>,
+        status: enums::ConnectorStatus,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -215,6 +236,8 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 profile_id: Some(self.profile_id),
                 applepay_verified_domains: self.applepay_verified_domains,
                 pm_auth_config: self.pm_auth_config,
+                tags: self.tags,
+                credentials_expiry: self.credentials_expiry,
                 status: self.status,
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
@@ -280,6 +303,8 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 })?,
             applepay_verified_domains: other.applepay_verified_domains,
             pm_auth_config: other.pm_auth_config,
+            tags: other.tags,
+            credentials_expiry: other.credentials_expiry,
             status: other.status,
             connector_wallets_details: decrypted_data.connector_wallets_details,
             additional_merchant_data: decrypted_data.additional_merchant_data,
@@ -311,6 +336,8 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: Some(self.profile_id),
             applepay_verified_domains: self.applepay_verified_domains,
             pm_auth_config: self.pm_auth_config,
+            tags: self.tags,
+            credentials_expiry: self.credentials_expiry,
             status: self.status,
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
@@ -344,6 +371,8 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 profile_id: self.profile_id,
                 applepay_verified_domains: self.applepay_verified_domains,
                 pm_auth_config: self.pm_auth_config,
+                tags: self.tags,
+                credentials_expiry: self.credentials_expiry,
                 status: self.status,
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
@@ -402,6 +431,8 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: other.profile_id,
             applepay_verified_domains: other.applepay_verified_domains,
             pm_auth_config: other.pm_auth_config,
+            tags: other.tags,
+            credentials_expiry: other.credentials_expiry,
             status: other.status,
             connector_wallets_details: decrypted_data.connector_wallets_details,
             additional_merchant_data: decrypted_data.additional_merchant_data,
@@ -428,6 +459,8 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: self.profile_id,
             applepay_verified_domains: self.applepay_verified_domains,
             pm_auth_config: self.pm_auth_config,
+            tags: self.tags,
+            credentials_expiry: self.credentials_expiry,
             status: self.status,
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
@@ -453,6 +486,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_webhook_details,
                 applepay_verified_domains,
                 pm_auth_config,
+                tags,
+                credentials_expiry,
                 connector_label,
                 status,
                 connector_wallets_details,
@@ -472,6 +507,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_webhook_details: *connector_webhook_details,
                 applepay_verified_domains,
                 pm_auth_config: *pm_auth_config,
+                tags,
+                credentials_expiry,
                 connector_label,
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
@@ -496,9 +533,33 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 frm_config: None,
                 applepay_verified_domains: None,
                 pm_auth_config: None,
+                tags: None,
+                credentials_expiry: None,
                 status: None,
                 additional_merchant_data: None,
             },
+            MerchantConnectorAccountUpdate::StatusUpdate { disabled, status } => Self {
+                disabled,
+                status: Some(status),
+                modified_at: Some(date_time::now()),
+                connector_wallets_details: None,
+                connector_type: None,
+                connector_name: None,
+                connector_account_details: None,
+                connector_label: None,
+                test_mode: None,
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                frm_configs: None,
+                metadata: None,
+                connector_webhook_details: None,
+                frm_config: None,
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                tags: None,
+                credentials_expiry: None,
+                additional_merchant_data: None,
+            },
         }
     }
 }
@@ -517,6 +578,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_webhook_details,
                 applepay_verified_domains,
                 pm_auth_config,
+                tags,
+                credentials_expiry,
                 connector_label,
                 status,
                 connector_wallets_details,
@@ -532,6 +595,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_webhook_details,
                 applepay_verified_domains,
                 pm_auth_config: *pm_auth_config,
+                tags,
+                credentials_expiry,
                 connector_label,
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
@@ -552,9 +617,29 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 frm_config: None,
                 applepay_verified_domains: None,
                 pm_auth_config: None,
+                tags: None,
+                credentials_expiry: None,
                 status: None,
                 additional_merchant_data: None,
             },
+            MerchantConnectorAccountUpdate::StatusUpdate { disabled, status } => Self {
+                disabled,
+                status: Some(status),
+                modified_at: Some(date_time::now()),
+                connector_wallets_details: None,
+                connector_type: None,
+                connector_account_details: None,
+                connector_label: None,
+                payment_methods_enabled: None,
+                metadata: None,
+                connector_webhook_details: None,
+                frm_config: None,
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                tags: None,
+                credentials_expiry: None,
+                additional_merchant_data: None,
+            },
         }
     }
 }
@@ -571,6 +571,7 @@ pub enum BankTransferData {
     LocalBankTransfer {
         bank_code: Option<String>,
     },
+    VirtualAccountBankTransfer {},
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1335,6 +1336,9 @@ impl From<api_models::payments::BankTransferData> for BankTransferData {
             api_models::payments::BankTransferData::LocalBankTransfer { bank_code } => {
                 Self::LocalBankTransfer { bank_code }
             }
+            api_models::payments::BankTransferData::VirtualAccountBankTransfer {} => {
+                Self::VirtualAccountBankTransfer {}
+            }
         }
     }
 }
@@ -1366,6 +1370,7 @@ impl From<BankTransferData> for api_models::payments::additional_info::BankTrans
                     bank_code: bank_code.map(MaskedBankAccount::from),
                 },
             )),
+            BankTransferData::VirtualAccountBankTransfer {} => Self::VirtualAccount {},
         }
     }
 }
@@ -1613,6 +1618,7 @@ impl GetPaymentMethodType for BankTransferData {
             Self::Pix { .. } => api_enums::PaymentMethodType::Pix,
             Self::Pse {} => api_enums::PaymentMethodType::Pse,
             Self::LocalBankTransfer { .. } => api_enums::PaymentMethodType::LocalBankTransfer,
+            Self::VirtualAccountBankTransfer {} => api_enums::PaymentMethodType::VirtualAccount,
         }
     }
 }
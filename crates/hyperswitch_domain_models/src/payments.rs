@@ -531,6 +531,8 @@ pub struct HeaderPayload {
     pub locale: Option<String>,
     pub x_app_id: Option<String>,
     pub x_redirect_uri: Option<String>,
+    /// The origin of the request, taken from the `Origin` header or else the `Referer` header
+    pub origin: Option<String>,
 }
 
 // TODO: uncomment fields as necessary
@@ -548,6 +550,8 @@ pub struct HeaderPayload {
     pub locale: Option<String>,
     pub x_app_id: Option<String>,
     pub x_redirect_uri: Option<String>,
+    /// The origin of the request, taken from the `Origin` header or else the `Referer` header
+    pub origin: Option<String>,
     pub client_secret: Option<common_utils::types::ClientSecret>,
 }
 
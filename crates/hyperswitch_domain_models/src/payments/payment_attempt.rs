@@ -15,8 +15,8 @@ use common_utils::{
     },
 };
 use diesel_models::{
-    ConnectorMandateReferenceId, PaymentAttempt as DieselPaymentAttempt,
-    PaymentAttemptNew as DieselPaymentAttemptNew,
+    ConnectorMandateReferenceId, ConnectorResponseCodes, DccDetails,
+    PaymentAttempt as DieselPaymentAttempt, PaymentAttemptNew as DieselPaymentAttemptNew,
     PaymentAttemptUpdate as DieselPaymentAttemptUpdate,
 };
 use error_stack::ResultExt;
@@ -68,6 +68,17 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentAttempt, errors::StorageError>;
 
+    /// Updates the attempt only if it has not been modified since `this` was fetched, as tracked by
+    /// its `update_version`. Returns `None` if the attempt was modified concurrently, in which
+    /// case it is left unchanged.
+    #[cfg(feature = "v1")]
+    async fn update_payment_attempt_with_attempt_id_if_unmodified(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PaymentAttempt>, errors::StorageError>;
+
     #[cfg(feature = "v2")]
     async fn update_payment_attempt(
         &self,
@@ -508,6 +519,16 @@ pub struct PaymentAttempt {
     pub profile_id: id_type::ProfileId,
     pub organization_id: id_type::OrganizationId,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub connector_response_codes: Option<ConnectorResponseCodes>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub exchange_rate: Option<String>,
+    pub dcc_details: Option<DccDetails>,
+    /// Incremented on every update of the attempt, to detect concurrent updates of the attempt
+    #[serde(default)]
+    pub update_version: i32,
 }
 
 #[cfg(feature = "v1")]
@@ -753,6 +774,9 @@ pub struct PaymentAttemptNew {
     pub profile_id: id_type::ProfileId,
     pub organization_id: id_type::OrganizationId,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub exchange_rate: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -819,6 +843,10 @@ pub enum PaymentAttemptUpdate {
         client_version: Option<String>,
         customer_acceptance: Option<pii::SecretSerdeValue>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+        presentment_currency: Option<storage_enums::Currency>,
+        presentment_amount: Option<MinorUnit>,
+        exchange_rate: Option<String>,
+        dcc_details: Option<DccDetails>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -867,6 +895,11 @@ pub enum PaymentAttemptUpdate {
         payment_method_data: Option<serde_json::Value>,
         charge_id: Option<String>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+        connector_response_codes: Option<ConnectorResponseCodes>,
+        avs_result: Option<storage_enums::AvsResult>,
+        cvv_result: Option<storage_enums::CvvResult>,
+        presentment_amount: Option<MinorUnit>,
+        exchange_rate: Option<String>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -896,6 +929,7 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        connector_response_codes: Option<ConnectorResponseCodes>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -1071,6 +1105,10 @@ impl PaymentAttemptUpdate {
                 client_version,
                 customer_acceptance,
                 connector_mandate_detail,
+                presentment_currency,
+                presentment_amount,
+                exchange_rate,
+                dcc_details,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1105,6 +1143,10 @@ impl PaymentAttemptUpdate {
                 shipping_cost: net_amount.get_shipping_cost(),
                 order_tax_amount: net_amount.get_order_tax_amount(),
                 connector_mandate_detail,
+                presentment_currency,
+                presentment_amount,
+                exchange_rate,
+                dcc_details,
             },
             Self::VoidUpdate {
                 status,
@@ -1137,6 +1179,11 @@ impl PaymentAttemptUpdate {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                connector_response_codes,
+                avs_result,
+                cvv_result,
+                presentment_amount,
+                exchange_rate,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1159,6 +1206,11 @@ impl PaymentAttemptUpdate {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                connector_response_codes,
+                avs_result,
+                cvv_result,
+                presentment_amount,
+                exchange_rate,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1197,6 +1249,7 @@ impl PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_response_codes,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1210,6 +1263,7 @@ impl PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_response_codes,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -1455,6 +1509,14 @@ impl behaviour::Conversion for PaymentAttempt {
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             shipping_cost: self.net_amount.get_shipping_cost(),
             connector_mandate_detail: self.connector_mandate_detail,
+            connector_response_codes: self.connector_response_codes,
+            avs_result: self.avs_result,
+            cvv_result: self.cvv_result,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            exchange_rate: self.exchange_rate,
+            dcc_details: self.dcc_details,
+            update_version: self.update_version,
         })
     }
 
@@ -1536,6 +1598,14 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 connector_mandate_detail: storage_model.connector_mandate_detail,
+                connector_response_codes: storage_model.connector_response_codes,
+                avs_result: storage_model.avs_result,
+                cvv_result: storage_model.cvv_result,
+                presentment_currency: storage_model.presentment_currency,
+                presentment_amount: storage_model.presentment_amount,
+                exchange_rate: storage_model.exchange_rate,
+                dcc_details: storage_model.dcc_details,
+                update_version: storage_model.update_version,
             })
         }
         .await
@@ -1618,6 +1688,9 @@ impl behaviour::Conversion for PaymentAttempt {
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             shipping_cost: self.net_amount.get_shipping_cost(),
             connector_mandate_detail: self.connector_mandate_detail,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            exchange_rate: self.exchange_rate,
         })
     }
 }
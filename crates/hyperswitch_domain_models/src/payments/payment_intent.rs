@@ -266,6 +266,14 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     },
+    SessionExpiryUpdate {
+        session_expiry: PrimitiveDateTime,
+        updated_by: String,
+    },
+    ClientSecretUpdate {
+        client_secret: String,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -364,6 +372,7 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<diesel_models::TaxDetails>,
+    pub client_secret: Option<String>,
 }
 
 // This conversion is used in the `update_payment_intent` function
@@ -619,6 +628,24 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 ..Default::default()
             },
+            PaymentIntentUpdate::SessionExpiryUpdate {
+                session_expiry,
+                updated_by,
+            } => Self {
+                session_expiry: Some(session_expiry),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::ClientSecretUpdate {
+                client_secret,
+                updated_by,
+            } => Self {
+                client_secret: Some(client_secret),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -810,6 +837,20 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 updated_by,
                 shipping_details: shipping_details.map(Encryption::from),
             },
+            PaymentIntentUpdate::SessionExpiryUpdate {
+                session_expiry,
+                updated_by,
+            } => Self::SessionExpiryUpdate {
+                session_expiry,
+                updated_by,
+            },
+            PaymentIntentUpdate::ClientSecretUpdate {
+                client_secret,
+                updated_by,
+            } => Self::ClientSecretUpdate {
+                client_secret,
+                updated_by,
+            },
         }
     }
 }
@@ -924,6 +965,7 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
+            client_secret,
         } = value;
         Self {
             amount,
@@ -962,6 +1004,7 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             shipping_details: shipping_details.map(Encryption::from),
             is_payment_processor_token_flow,
             tax_details,
+            client_secret,
         }
     }
 }
@@ -2,7 +2,7 @@ use api_models::enums::PayoutConnectors;
 use common_enums as storage_enums;
 use common_utils::{
     id_type, payout_method_utils,
-    types::{UnifiedCode, UnifiedMessage},
+    types::{MinorUnit, UnifiedCode, UnifiedMessage},
 };
 use serde::{Deserialize, Serialize};
 use storage_enums::MerchantStorageScheme;
@@ -42,6 +42,13 @@ pub trait PayoutAttemptInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError>;
 
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError>;
+
     async fn get_filters_for_payouts(
         &self,
         _payout: &[Payouts],
@@ -84,6 +91,11 @@ pub struct PayoutAttempt {
     pub unified_code: Option<UnifiedCode>,
     pub unified_message: Option<UnifiedMessage>,
     pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub account_name_match_result: Option<storage_enums::AccountNameMatchResult>,
+    pub fee: Option<MinorUnit>,
+    pub net_amount: Option<MinorUnit>,
+    pub fee_source: Option<storage_enums::PayoutFeeSource>,
+    pub balance_rules_overridden: Option<bool>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -141,6 +153,17 @@ pub enum PayoutAttemptUpdate {
     AdditionalPayoutMethodDataUpdate {
         additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
     },
+    AccountNameMatchResultUpdate {
+        account_name_match_result: storage_enums::AccountNameMatchResult,
+    },
+    FeeUpdate {
+        fee: MinorUnit,
+        net_amount: MinorUnit,
+        fee_source: storage_enums::PayoutFeeSource,
+    },
+    BalanceRulesOverrideUpdate {
+        balance_rules_overridden: bool,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -161,6 +184,11 @@ pub struct PayoutAttemptUpdateInternal {
     pub unified_code: Option<UnifiedCode>,
     pub unified_message: Option<UnifiedMessage>,
     pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub account_name_match_result: Option<storage_enums::AccountNameMatchResult>,
+    pub fee: Option<MinorUnit>,
+    pub net_amount: Option<MinorUnit>,
+    pub fee_source: Option<storage_enums::PayoutFeeSource>,
+    pub balance_rules_overridden: Option<bool>,
 }
 
 impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
@@ -216,6 +244,28 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
                 additional_payout_method_data,
                 ..Default::default()
             },
+            PayoutAttemptUpdate::AccountNameMatchResultUpdate {
+                account_name_match_result,
+            } => Self {
+                account_name_match_result: Some(account_name_match_result),
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::FeeUpdate {
+                fee,
+                net_amount,
+                fee_source,
+            } => Self {
+                fee: Some(fee),
+                net_amount: Some(net_amount),
+                fee_source: Some(fee_source),
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::BalanceRulesOverrideUpdate {
+                balance_rules_overridden,
+            } => Self {
+                balance_rules_overridden: Some(balance_rules_overridden),
+                ..Default::default()
+            },
         }
     }
 }
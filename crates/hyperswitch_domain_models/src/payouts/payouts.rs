@@ -39,6 +39,16 @@ pub trait PayoutsInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Option<Payouts>, errors::StorageError>;
 
+    /// Fetches the payouts of the profile funded in the currency, which have any of the statuses.
+    /// The payouts are read from the database, so the status of a payout stored in redis may lag.
+    async fn filter_payouts_by_profile_id_source_currency_status(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _profile_id: &id_type::ProfileId,
+        _source_currency: storage_enums::Currency,
+        _status: Vec<storage_enums::PayoutStatus>,
+    ) -> error_stack::Result<Vec<Payouts>, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
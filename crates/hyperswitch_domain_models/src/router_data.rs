@@ -6,6 +6,7 @@ use common_utils::{
     id_type,
     types::MinorUnit,
 };
+pub use diesel_models::ConnectorResponseCodes;
 use error_stack::ResultExt;
 use masking::{ExposeInterface, Secret};
 
@@ -327,6 +328,13 @@ pub struct PaymentMethodBalance {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+    /// Raw response codes returned by the connector, such as the AVS and CVV results
+    pub response_codes: Option<ConnectorResponseCodes>,
+    /// Details of the refund returned by the connector, such as the expected settlement date
+    pub refund_details: Option<ConnectorRefundDetails>,
+    /// Details of the currency conversion done by the connector, such as the dynamic currency
+    /// conversion of the amount to the currency of the card of the customer
+    pub currency_conversion: Option<ConnectorCurrencyConversion>,
 }
 
 impl ConnectorResponseData {
@@ -335,8 +343,72 @@ impl ConnectorResponseData {
     ) -> Self {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
+            response_codes: None,
+            refund_details: None,
+            currency_conversion: None,
         }
     }
+
+    pub fn with_response_codes(response_codes: ConnectorResponseCodes) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            response_codes: Some(response_codes),
+            refund_details: None,
+            currency_conversion: None,
+        }
+    }
+
+    pub fn with_refund_details(refund_details: ConnectorRefundDetails) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            response_codes: None,
+            refund_details: Some(refund_details),
+            currency_conversion: None,
+        }
+    }
+
+    pub fn with_currency_conversion(currency_conversion: ConnectorCurrencyConversion) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            response_codes: None,
+            refund_details: None,
+            currency_conversion: Some(currency_conversion),
+        }
+    }
+
+    pub fn get_response_codes(&self) -> Option<ConnectorResponseCodes> {
+        self.response_codes.clone()
+    }
+
+    pub fn get_refund_details(&self) -> Option<ConnectorRefundDetails> {
+        self.refund_details.clone()
+    }
+
+    pub fn get_currency_conversion(&self) -> Option<ConnectorCurrencyConversion> {
+        self.currency_conversion.clone()
+    }
+}
+
+/// A currency conversion done by the connector, where the customer is charged in a presentment
+/// currency other than the currency of the payment which is settled to the merchant
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorCurrencyConversion {
+    /// The currency in which the customer is charged
+    pub presentment_currency: common_enums::enums::Currency,
+    /// The amount charged to the customer in the presentment currency
+    pub presentment_amount: MinorUnit,
+    /// The exchange rate applied from the currency of the payment to the presentment currency
+    pub exchange_rate: String,
+}
+
+/// Details of a refund which are provided only by some of the connectors
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorRefundDetails {
+    /// The date by which the refunded amount is expected to reach the customer
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_settlement_date: Option<time::PrimitiveDateTime>,
+    /// The reason for the current status of the refund, normalized across connectors
+    pub status_reason: Option<common_enums::RefundStatusReason>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -64,3 +64,7 @@ pub struct PaymentGetIntent;
 
 #[derive(Debug, Clone)]
 pub struct PostSessionTokens;
+
+// Used to fetch the banks offered by the connector for a bank redirect payment method
+#[derive(Debug, Clone)]
+pub struct BankList;
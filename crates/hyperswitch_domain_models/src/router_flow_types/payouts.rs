@@ -1,3 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct PoBalance;
+
 #[derive(Debug, Clone)]
 pub struct PoCancel;
 
@@ -19,5 +22,8 @@ pub struct PoRecipient;
 #[derive(Debug, Clone)]
 pub struct PoRecipientAccount;
 
+#[derive(Debug, Clone)]
+pub struct PoRecipientStatus;
+
 #[derive(Debug, Clone)]
 pub struct PoSync;
@@ -74,6 +74,15 @@ pub struct PaymentsAuthorizeData {
     pub integrity_object: Option<AuthoriseIntegrityObject>,
     pub shipping_cost: Option<MinorUnit>,
     pub additional_payment_method_data: Option<AdditionalPaymentData>,
+    /// The dynamic currency conversion accepted by the customer, which the connector applies to
+    /// charge the customer in the presentment currency
+    pub currency_conversion: Option<router_data::ConnectorCurrencyConversion>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BankListRequestData {
+    /// The bank redirect payment method type for which the banks are fetched
+    pub payment_method_type: storage_enums::PaymentMethodType,
 }
 
 #[derive(Debug, Clone)]
@@ -619,6 +628,8 @@ pub struct RefundsData {
     pub minor_refund_amount: MinorUnit,
     pub integrity_object: Option<RefundIntegrityObject>,
     pub refund_status: storage_enums::RefundStatus,
+    /// The line items of the order details refunded, along with the quantity refunded of each
+    pub line_items: Option<Vec<OrderDetailsWithAmount>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -797,6 +808,13 @@ pub struct PayoutsData {
     pub priority: Option<storage_enums::PayoutSendPriority>,
 }
 
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Default)]
+pub struct PayoutBalanceRequestData {
+    /// Restricts the balances fetched from the connector to this currency
+    pub currency: Option<storage_enums::Currency>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct CustomerDetails {
     pub customer_id: Option<id_type::CustomerId>,
@@ -91,6 +91,14 @@ pub struct ConnectorAuthenticationRequestData {
     pub threeds_method_comp_ind: api_models::payments::ThreeDsCompletionIndicator,
     pub three_ds_requestor_url: String,
     pub webhook_url: String,
+    /// The reason of the authentication, in case of a 3DS Requestor Initiated (3RI) authentication
+    pub three_ri_indicator: Option<ThreeRiIndicator>,
+}
+
+/// Indicates the reason a 3DS Requestor Initiated (3RI) authentication is performed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreeRiIndicator {
+    RecurringTransaction,
 }
 
 #[derive(Clone, serde::Deserialize, Debug, serde::Serialize, PartialEq, Eq)]
@@ -78,6 +78,11 @@ pub struct TaxCalculationResponseData {
     pub order_tax_amount: MinorUnit,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct BankListResponseData {
+    pub banks: Vec<common_enums::BankNames>,
+}
+
 #[derive(serde::Serialize, Debug, Clone)]
 pub struct MandateReference {
     pub connector_mandate_id: Option<String>,
@@ -365,6 +370,31 @@ pub struct PayoutsResponseData {
     pub should_add_next_step_to_process_tracker: bool,
     pub error_code: Option<String>,
     pub error_message: Option<String>,
+    /// The fee charged by the connector for the payout, in the minor unit of the currency of the
+    /// payout
+    pub connector_fee: Option<common_utils::types::MinorUnit>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, Default)]
+pub struct PayoutRecipientStatusResponseData {
+    pub kyc_status: common_enums::RecipientKycStatus,
+    /// The reason provided by the connector for the recipient not being verified
+    pub status_reason: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, Default)]
+pub struct PayoutBalanceResponseData {
+    pub balances: Vec<PayoutConnectorBalance>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug)]
+pub struct PayoutConnectorBalance {
+    pub currency: common_enums::Currency,
+    pub available_amount: MinorUnit,
+    pub pending_amount: Option<MinorUnit>,
 }
 
 #[derive(Debug, Clone)]
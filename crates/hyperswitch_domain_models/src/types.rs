@@ -3,19 +3,19 @@ pub use diesel_models::types::OrderDetailsWithAmount;
 use crate::{
     router_data::{AccessToken, RouterData},
     router_flow_types::{
-        AccessTokenAuth, Authorize, AuthorizeSessionToken, CalculateTax, Capture,
+        AccessTokenAuth, Authorize, AuthorizeSessionToken, BankList, CalculateTax, Capture,
         CompleteAuthorize, CreateConnectorCustomer, Execute, PSync, PaymentMethodToken,
         PostSessionTokens, PreProcessing, RSync, Session, SetupMandate, Void,
     },
     router_request_types::{
-        AccessTokenRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsPostSessionTokensData,
-        PaymentsPreProcessingData, PaymentsSessionData, PaymentsSyncData,
-        PaymentsTaxCalculationData, RefundsData, SetupMandateRequestData,
+        AccessTokenRequestData, AuthorizeSessionTokenData, BankListRequestData,
+        CompleteAuthorizeData, ConnectorCustomerData, PaymentMethodTokenizationData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsSessionData,
+        PaymentsSyncData, PaymentsTaxCalculationData, RefundsData, SetupMandateRequestData,
     },
     router_response_types::{
-        PaymentsResponseData, RefundsResponseData, TaxCalculationResponseData,
+        BankListResponseData, PaymentsResponseData, RefundsResponseData, TaxCalculationResponseData,
     },
 };
 
@@ -45,3 +45,4 @@ pub type RefreshTokenRouterData = RouterData<AccessTokenAuth, AccessTokenRequest
 pub type PaymentsPostSessionTokensRouterData =
     RouterData<PostSessionTokens, PaymentsPostSessionTokensData, PaymentsResponseData>;
 pub type PaymentsSessionRouterData = RouterData<Session, PaymentsSessionData, PaymentsResponseData>;
+pub type BankListRouterData = RouterData<BankList, BankListRequestData, BankListResponseData>;
@@ -20,6 +20,7 @@ use common_enums::enums::{CallConnectorAction, CaptureMethod, PaymentAction, Pay
 use common_utils::{
     errors::CustomResult,
     request::{Method, Request, RequestContent},
+    validation::StatementDescriptorRules,
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
@@ -394,12 +395,52 @@ pub trait ConnectorValidation: ConnectorCommon {
             .map(|_| ())
     }
 
+    /// fn validate_statement_descriptor
+    fn validate_statement_descriptor(
+        &self,
+        statement_descriptor: Option<&str>,
+        statement_descriptor_suffix: Option<&str>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        validate_statement_descriptor_against_rules(
+            statement_descriptor,
+            statement_descriptor_suffix,
+            &StatementDescriptorRules::CARD_SCHEME,
+            &StatementDescriptorRules::CARD_SCHEME,
+        )
+    }
+
     /// fn is_webhook_source_verification_mandatory
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
 }
 
+/// Validates the statement descriptor and its suffix sent to the connector against the rules of the connector
+pub fn validate_statement_descriptor_against_rules(
+    statement_descriptor: Option<&str>,
+    statement_descriptor_suffix: Option<&str>,
+    statement_descriptor_rules: &StatementDescriptorRules,
+    statement_descriptor_suffix_rules: &StatementDescriptorRules,
+) -> CustomResult<(), errors::ConnectorError> {
+    if statement_descriptor
+        .is_some_and(|descriptor| !statement_descriptor_rules.is_valid(descriptor))
+    {
+        return Err(errors::ConnectorError::InvalidDataFormat {
+            field_name: "statement_descriptor_name",
+        }
+        .into());
+    }
+    if statement_descriptor_suffix
+        .is_some_and(|suffix| !statement_descriptor_suffix_rules.is_valid(suffix))
+    {
+        return Err(errors::ConnectorError::InvalidDataFormat {
+            field_name: "statement_descriptor_suffix",
+        }
+        .into());
+    }
+    Ok(())
+}
+
 /// trait ConnectorRedirectResponse
 pub trait ConnectorRedirectResponse {
     /// fn get_flow_type
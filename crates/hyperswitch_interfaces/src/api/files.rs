@@ -18,6 +18,8 @@ use crate::{
 pub enum FilePurpose {
     /// DisputeEvidence
     DisputeEvidence,
+    /// MandateEvidence
+    MandateEvidence,
 }
 
 /// trait UploadFile
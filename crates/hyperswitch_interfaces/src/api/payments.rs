@@ -2,20 +2,23 @@
 
 use hyperswitch_domain_models::{
     router_flow_types::payments::{
-        Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CompleteAuthorize,
-        CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-        PostProcessing, PostSessionTokens, PreProcessing, Reject, SdkSessionUpdate, Session,
-        SetupMandate, Void,
+        Approve, Authorize, AuthorizeSessionToken, BankList, CalculateTax, Capture,
+        CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, PSync,
+        PaymentMethodToken, PostProcessing, PostSessionTokens, PreProcessing, Reject,
+        SdkSessionUpdate, Session, SetupMandate, Void,
     },
     router_request_types::{
-        AuthorizeSessionTokenData, CompleteAuthorizeData, ConnectorCustomerData,
-        PaymentMethodTokenizationData, PaymentsApproveData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
-        PaymentsPostProcessingData, PaymentsPostSessionTokensData, PaymentsPreProcessingData,
-        PaymentsRejectData, PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData,
+        AuthorizeSessionTokenData, BankListRequestData, CompleteAuthorizeData,
+        ConnectorCustomerData, PaymentMethodTokenizationData, PaymentsApproveData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
+        PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
+        PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData,
         SdkPaymentsSessionUpdateData, SetupMandateRequestData,
     },
-    router_response_types::{PaymentsResponseData, TaxCalculationResponseData},
+    router_response_types::{
+        BankListResponseData, PaymentsResponseData, TaxCalculationResponseData,
+    },
 };
 
 use crate::api;
@@ -41,6 +44,7 @@ pub trait Payment:
     + PaymentIncrementalAuthorization
     + PaymentSessionUpdate
     + PaymentPostSessionTokens
+    + PaymentBankList
 {
 }
 
@@ -132,6 +136,12 @@ pub trait PaymentPostSessionTokens:
 {
 }
 
+/// trait PaymentBankList
+pub trait PaymentBankList:
+    api::ConnectorIntegration<BankList, BankListRequestData, BankListResponseData>
+{
+}
+
 /// trait PaymentsCompleteAuthorize
 pub trait PaymentsCompleteAuthorize:
     api::ConnectorIntegration<CompleteAuthorize, CompleteAuthorizeData, PaymentsResponseData>
@@ -2,16 +2,24 @@
 
 use hyperswitch_domain_models::{
     router_flow_types::payouts::{
-        PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount,
-        PoSync,
+        PoBalance, PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient,
+        PoRecipientAccount, PoRecipientStatus, PoSync,
+    },
+    router_request_types::{PayoutBalanceRequestData, PayoutsData},
+    router_response_types::{
+        PayoutBalanceResponseData, PayoutRecipientStatusResponseData, PayoutsResponseData,
     },
-    router_request_types::PayoutsData,
-    router_response_types::PayoutsResponseData,
 };
 
 use super::ConnectorCommon;
 use crate::api::ConnectorIntegration;
 
+/// trait PayoutBalance
+pub trait PayoutBalance:
+    ConnectorIntegration<PoBalance, PayoutBalanceRequestData, PayoutBalanceResponseData>
+{
+}
+
 /// trait PayoutCancel
 pub trait PayoutCancel: ConnectorIntegration<PoCancel, PayoutsData, PayoutsResponseData> {}
 
@@ -42,12 +50,19 @@ pub trait PayoutRecipientAccount:
 {
 }
 
+/// trait PayoutRecipientStatus
+pub trait PayoutRecipientStatus:
+    ConnectorIntegration<PoRecipientStatus, PayoutsData, PayoutRecipientStatusResponseData>
+{
+}
+
 /// trait PayoutSync
 pub trait PayoutSync: ConnectorIntegration<PoSync, PayoutsData, PayoutsResponseData> {}
 
 /// trait Payouts
 pub trait Payouts:
     ConnectorCommon
+    + PayoutBalance
     + PayoutCancel
     + PayoutCreate
     + PayoutEligibility
@@ -55,6 +70,7 @@ pub trait Payouts:
     + PayoutQuote
     + PayoutRecipient
     + PayoutRecipientAccount
+    + PayoutRecipientStatus
     + PayoutSync
 {
 }
@@ -2,16 +2,29 @@
 use hyperswitch_domain_models::{
     router_data_v2::flow_common_types::PayoutFlowData,
     router_flow_types::payouts::{
-        PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount,
-        PoSync,
+        PoBalance, PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient,
+        PoRecipientAccount, PoRecipientStatus, PoSync,
+    },
+    router_request_types::{PayoutBalanceRequestData, PayoutsData},
+    router_response_types::{
+        PayoutBalanceResponseData, PayoutRecipientStatusResponseData, PayoutsResponseData,
     },
-    router_request_types::PayoutsData,
-    router_response_types::PayoutsResponseData,
 };
 
 use super::ConnectorCommon;
 use crate::api::ConnectorIntegrationV2;
 
+/// trait PayoutBalanceV2
+pub trait PayoutBalanceV2:
+    ConnectorIntegrationV2<
+    PoBalance,
+    PayoutFlowData,
+    PayoutBalanceRequestData,
+    PayoutBalanceResponseData,
+>
+{
+}
+
 /// trait PayoutCancelV2
 pub trait PayoutCancelV2:
     ConnectorIntegrationV2<PoCancel, PayoutFlowData, PayoutsData, PayoutsResponseData>
@@ -54,6 +67,17 @@ pub trait PayoutRecipientAccountV2:
 {
 }
 
+/// trait PayoutRecipientStatusV2
+pub trait PayoutRecipientStatusV2:
+    ConnectorIntegrationV2<
+    PoRecipientStatus,
+    PayoutFlowData,
+    PayoutsData,
+    PayoutRecipientStatusResponseData,
+>
+{
+}
+
 /// trait PayoutSyncV2
 pub trait PayoutSyncV2:
     ConnectorIntegrationV2<PoSync, PayoutFlowData, PayoutsData, PayoutsResponseData>
@@ -63,6 +87,7 @@ pub trait PayoutSyncV2:
 /// trait Payouts
 pub trait PayoutsV2:
     ConnectorCommon
+    + PayoutBalanceV2
     + PayoutCancelV2
     + PayoutCreateV2
     + PayoutEligibilityV2
@@ -70,6 +95,7 @@ pub trait PayoutsV2:
     + PayoutQuoteV2
     + PayoutRecipientV2
     + PayoutRecipientAccountV2
+    + PayoutRecipientStatusV2
     + PayoutSyncV2
 {
 }
@@ -1,4 +1,6 @@
 //! Configs interface
+use std::collections::{HashMap, HashSet};
+
 use common_enums::ApplicationError;
 use masking::Secret;
 use router_derive;
@@ -41,6 +43,7 @@ pub struct Connectors {
     pub fiservemea: ConnectorParams,
     pub fiuu: ConnectorParamsWithThreeUrls,
     pub forte: ConnectorParams,
+    pub generichttp: GenerichttpConnectorParams,
     pub globalpay: ConnectorParams,
     pub globepay: ConnectorParams,
     pub gocardless: ConnectorParams,
@@ -72,6 +75,7 @@ pub struct Connectors {
     pub payu: ConnectorParams,
     pub placetopay: ConnectorParams,
     pub plaid: ConnectorParams,
+    pub plugin: ConnectorPluginParams,
     pub powertranz: ConnectorParams,
     pub prophetpay: ConnectorParams,
     pub rapyd: ConnectorParams,
@@ -109,6 +113,50 @@ pub struct ConnectorParams {
     pub secondary_base_url: Option<String>,
 }
 
+/// struct GenerichttpConnectorParams
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct GenerichttpConnectorParams {
+    /// hosts to which the endpoints configured by merchants may send requests, along with their
+    /// subdomains. The connector cannot be used until the hosts are configured, since a public
+    /// host name could resolve to an internal address
+    pub allowed_hosts: HashSet<String>,
+}
+
+impl GenerichttpConnectorParams {
+    /// The endpoints of the connector are configured by merchants, so there is nothing to validate
+    pub fn validate(&self, _parent_field: &str) -> Result<(), ApplicationError> {
+        Ok(())
+    }
+}
+
+/// struct ConnectorPluginParams
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorPluginParams {
+    /// out-of-process connector plugins available to merchants, keyed by the plugin name
+    pub plugins: HashMap<String, ConnectorPluginConfig>,
+}
+
+impl ConnectorPluginParams {
+    /// Validates the configuration of each of the registered plugins
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        self.plugins.iter().try_for_each(|(plugin_name, plugin)| {
+            plugin.validate(&format!("{parent_field}.plugins.{plugin_name}"))
+        })
+    }
+}
+
+/// struct ConnectorPluginConfig
+#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+#[serde(default)]
+pub struct ConnectorPluginConfig {
+    /// base url of the plugin
+    pub base_url: String,
+    /// api key sent to the plugin to authenticate the application
+    pub api_key: Option<Secret<String>>,
+}
+
 /// struct ConnectorParamsWithKeys
 #[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
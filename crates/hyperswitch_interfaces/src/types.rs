@@ -7,38 +7,42 @@ use hyperswitch_domain_models::{
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture, CompleteAuthorize,
-            CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync,
-            PaymentMethodToken, PostProcessing, PostSessionTokens, PreProcessing, SdkSessionUpdate,
-            Session, SetupMandate, Void,
+            Authorize, AuthorizeSessionToken, Balance, BankList, CalculateTax, Capture,
+            CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, InitPayment,
+            PSync, PaymentMethodToken, PostProcessing, PostSessionTokens, PreProcessing,
+            SdkSessionUpdate, Session, SetupMandate, Void,
         },
         refunds::{Execute, RSync},
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
-        MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
-        PaymentsPostProcessingData, PaymentsPostSessionTokensData, PaymentsPreProcessingData,
-        PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData, RefundsData,
-        RetrieveFileRequestData, SdkPaymentsSessionUpdateData, SetupMandateRequestData,
-        SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
+        BankListRequestData, CompleteAuthorizeData, ConnectorCustomerData,
+        DefendDisputeRequestData, MandateRevokeRequestData, PaymentMethodTokenizationData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
+        PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsSessionData,
+        PaymentsSyncData, PaymentsTaxCalculationData, RefundsData, RetrieveFileRequestData,
+        SdkPaymentsSessionUpdateData, SetupMandateRequestData, SubmitEvidenceRequestData,
+        UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, BankListResponseData, DefendDisputeResponse,
+        MandateRevokeResponseData, PaymentsResponseData, RefundsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "payouts")]
 use hyperswitch_domain_models::{
     router_flow_types::payouts::{
-        PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount,
-        PoSync,
+        PoBalance, PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient,
+        PoRecipientAccount, PoRecipientStatus, PoSync,
+    },
+    router_request_types::{PayoutBalanceRequestData, PayoutsData},
+    router_response_types::{
+        PayoutBalanceResponseData, PayoutRecipientStatusResponseData, PayoutsResponseData,
     },
-    router_request_types::PayoutsData,
-    router_response_types::PayoutsResponseData,
 };
 
 use crate::api::ConnectorIntegration;
@@ -65,6 +69,9 @@ pub type PaymentsPostSessionTokensType = dyn ConnectorIntegration<
     PaymentsPostSessionTokensData,
     PaymentsResponseData,
 >;
+/// Type alias for `ConnectorIntegration<BankList, BankListRequestData, BankListResponseData>`
+pub type PaymentsBankListType =
+    dyn ConnectorIntegration<BankList, BankListRequestData, BankListResponseData>;
 /// Type alias for `ConnectorIntegration<SdkSessionUpdate, SdkPaymentsSessionUpdateData, PaymentsResponseData>`
 pub type SdkSessionUpdateType =
     dyn ConnectorIntegration<SdkSessionUpdate, SdkPaymentsSessionUpdateData, PaymentsResponseData>;
@@ -129,6 +136,10 @@ pub type RefundExecuteType = dyn ConnectorIntegration<Execute, RefundsData, Refu
 /// Type alias for `ConnectorIntegration<RSync, RefundsData, RefundsResponseData>`
 pub type RefundSyncType = dyn ConnectorIntegration<RSync, RefundsData, RefundsResponseData>;
 
+/// Type alias for `ConnectorIntegration<PoBalance, PayoutBalanceRequestData, PayoutBalanceResponseData>`
+#[cfg(feature = "payouts")]
+pub type PayoutBalanceType =
+    dyn ConnectorIntegration<PoBalance, PayoutBalanceRequestData, PayoutBalanceResponseData>;
 /// Type alias for `ConnectorIntegration<PoCancel, PayoutsData, PayoutsResponseData>`
 #[cfg(feature = "payouts")]
 pub type PayoutCancelType = dyn ConnectorIntegration<PoCancel, PayoutsData, PayoutsResponseData>;
@@ -150,6 +161,10 @@ pub type PayoutRecipientType =
 #[cfg(feature = "payouts")]
 pub type PayoutRecipientAccountType =
     dyn ConnectorIntegration<PoRecipientAccount, PayoutsData, PayoutsResponseData>;
+/// Type alias for `ConnectorIntegration<PoRecipientStatus, PayoutsData, PayoutRecipientStatusResponseData>`
+#[cfg(feature = "payouts")]
+pub type PayoutRecipientStatusType =
+    dyn ConnectorIntegration<PoRecipientStatus, PayoutsData, PayoutRecipientStatusResponseData>;
 /// Type alias for `ConnectorIntegration<PoQuote, PayoutsData, PayoutsResponseData>`
 #[cfg(feature = "payouts")]
 pub type PayoutQuoteType = dyn ConnectorIntegration<PoQuote, PayoutsData, PayoutsResponseData>;
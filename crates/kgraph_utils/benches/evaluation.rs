@@ -69,6 +69,8 @@ fn build_test_data(
         profile_id,
         applepay_verified_domains: None,
         pm_auth_config: None,
+        tags: None,
+        credentials_expiry: None,
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
         connector_wallets_details: None,
@@ -94,6 +96,8 @@ fn build_test_data(
         profile_id,
         applepay_verified_domains: None,
         pm_auth_config: None,
+        tags: None,
+        credentials_expiry: None,
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
         connector_wallets_details: None,
@@ -116,6 +116,9 @@ fn get_dir_value_payment_method(
         api_enums::PaymentMethodType::LocalBankTransfer => {
             Ok(dirval!(BankTransferType = LocalBankTransfer))
         }
+        api_enums::PaymentMethodType::VirtualAccount => {
+            Ok(dirval!(BankTransferType = VirtualAccount))
+        }
         api_enums::PaymentMethodType::PermataBankTransfer => {
             Ok(dirval!(BankTransferType = PermataBankTransfer))
         }
@@ -762,6 +765,8 @@ mod tests {
             profile_id,
             applepay_verified_domains: None,
             pm_auth_config: None,
+            tags: None,
+            credentials_expiry: None,
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
             connector_wallets_details: None,
@@ -822,6 +827,8 @@ mod tests {
             profile_id,
             applepay_verified_domains: None,
             pm_auth_config: None,
+            tags: None,
+            credentials_expiry: None,
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
             connector_wallets_details: None,
@@ -58,9 +58,20 @@ impl IntoContext for BackendInput {
         if let Some(card_network) = self.payment_method.card_network {
             ctx.push(dir::DirValue::CardNetwork(card_network));
         }
+        if let Some(card_issuer) = self.payment_method.card_issuer {
+            ctx.push(dir::DirValue::CardIssuer(StrValue { value: card_issuer }));
+        }
+        if let Some(issuer_card_type) = self.payment_method.issuer_card_type {
+            ctx.push(dir::DirValue::IssuerCardType(issuer_card_type));
+        }
         if let Some(setup_future_usage) = self.payment.setup_future_usage {
             ctx.push(dir::DirValue::SetupFutureUsage(setup_future_usage));
         }
+        for connector_tag in self.payment.connector_tags.unwrap_or_default() {
+            ctx.push(dir::DirValue::ConnectorTag(StrValue {
+                value: connector_tag,
+            }));
+        }
         if let Some(mandate_acceptance_type) = self.mandate.mandate_acceptance_type {
             ctx.push(dir::DirValue::MandateAcceptanceType(
                 mandate_acceptance_type,
@@ -281,6 +292,9 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
             api_enums::PaymentMethodType::LocalBankTransfer => {
                 Ok(dirval!(BankTransferType = LocalBankTransfer))
             }
+            api_enums::PaymentMethodType::VirtualAccount => {
+                Ok(dirval!(BankTransferType = VirtualAccount))
+            }
             api_enums::PaymentMethodType::PermataBankTransfer => {
                 Ok(dirval!(BankTransferType = PermataBankTransfer))
             }
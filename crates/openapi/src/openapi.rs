@@ -58,8 +58,11 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Installment Plans", description = "Charge an amount in installments against a mandate"),
+        (name = "Test Clocks", description = "Simulate the passage of time for scheduled tasks in the sandbox environment"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
+        (name = "Vault", description = "Vault cards without creating payments using scope restricted API keys"),
         (name = "Disputes", description = "Manage disputes"),
         (name = "API Key", description = "Create and manage API Keys"),
         (name = "Payouts", description = "Create and manage payouts"),
@@ -75,11 +78,24 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_confirm,
         routes::payments::payments_retrieve,
         routes::payments::payments_capture,
+        routes::payments::payments_capture_on_shipment,
+        routes::payments::payments_dcc_offer,
         routes::payments::payments_connector_session,
+        routes::payments::payments_prewarm_session_tokens,
         routes::payments::payments_cancel,
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
+        routes::payments::payments_generate_invoice,
+        routes::payments::payments_rotate_client_secret,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::payment_link_deactivate,
+        routes::payment_link::payment_link_expiry_update,
+        routes::payment_link::payment_link_regenerate,
+        routes::payment_link::payment_link_template_create,
+        routes::payment_link::payment_link_template_list,
+        routes::payment_link::payment_link_template_retrieve,
+        routes::payment_link::payment_link_template_update,
+        routes::payment_link::payment_link_template_usage_list,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
@@ -94,6 +110,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::organization::organization_create,
         routes::organization::organization_retrieve,
         routes::organization::organization_update,
+        routes::organization::merchant_account_search,
+        routes::organization::organization_usage_export,
 
         // Routes for merchant account
         routes::merchant_account::merchant_account_create,
@@ -101,12 +119,20 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::merchant_account_usage,
+        routes::merchant_account::rotate_publishable_key,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::connector_create,
         routes::merchant_connector_account::connector_retrieve,
         routes::merchant_connector_account::connector_list,
         routes::merchant_connector_account::connector_update,
+        routes::merchant_connector_account::connector_bulk_toggle,
+        routes::merchant_connector_account::connector_webhook_health,
+        routes::merchant_connector_account::connector_expiring_credentials_list,
+        routes::merchant_connector_account::connector_maintenance_window_create,
+        routes::merchant_connector_account::connector_maintenance_window_list,
+        routes::merchant_connector_account::connector_maintenance_window_delete,
         routes::merchant_connector_account::connector_delete,
 
         //Routes for gsm
@@ -126,6 +152,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customer_portal_link_create,
+        routes::customers::customer_portal_payment_method_delete,
+        routes::customers::customer_portal_billing_address_update,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -136,6 +165,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::share_payment_method_api,
+        routes::payment_method::list_shared_payment_methods_api,
+        routes::payment_method::revoke_shared_payment_method_api,
+        routes::payment_method::list_payment_method_consent_events_api,
+        routes::payment_method::payment_method_collect_link_create,
+        routes::payment_method::payment_method_collect_link_render,
+        routes::payment_method::payment_method_collect_link_submit,
+        routes::payment_method::vault_tokenize_api,
+        routes::payment_method::vault_detokenize_api,
 
         // Routes for Profile
         routes::profile::profile_create,
@@ -143,6 +181,26 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::email_template_create,
+        routes::profile::email_templates_list,
+        routes::profile::email_template_retrieve,
+        routes::profile::email_template_update,
+        routes::profile::email_template_delete,
+        routes::profile::payment_template_create,
+        routes::profile::payment_templates_list,
+        routes::profile::payment_template_retrieve,
+        routes::profile::payment_template_update,
+        routes::profile::payment_template_delete,
+        routes::profile::ledger_balances_retrieve,
+        routes::profile::ledger_statement_retrieve,
+        routes::profile::payout_balance_override_create,
+        routes::profile::payout_balance_override_delete,
+        routes::profile::embed_token_create,
+        routes::profile::toggle_extended_card_info,
+        routes::profile::toggle_connector_agnostic_mit,
+        routes::profile::webhook_secret_retrieve,
+        routes::profile::webhook_secret_generate,
+        routes::profile::webhook_secret_rotate,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -170,6 +228,7 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for payouts
         routes::payouts::payouts_create,
         routes::payouts::payouts_retrieve,
+        routes::payouts::payouts_attempts_list,
         routes::payouts::payouts_update,
         routes::payouts::payouts_cancel,
         routes::payouts::payouts_fulfill,
@@ -177,6 +236,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payouts_balance,
+        routes::payouts::payout_link_render,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -189,15 +250,36 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::preview_webhook_payload,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
+
+        // Routes for link deliveries
+        routes::link_delivery::link_delivery_retrieve,
+        routes::link_delivery::link_deliveries_list,
+
+        // Routes for installment plans
+        routes::installment_plans::installment_plan_create,
+        routes::installment_plans::installment_plan_retrieve,
+        routes::installment_plans::installment_plans_list,
+        routes::installment_plans::installment_plan_cancel,
+
+        // Routes for test clocks
+        routes::test_clocks::test_clock_create,
+        routes::test_clocks::test_clock_retrieve,
+        routes::test_clocks::test_clocks_list,
+        routes::test_clocks::test_clock_attach,
+        routes::test_clocks::test_clock_detach,
+        routes::test_clocks::test_clock_advance,
+        routes::test_clocks::test_clock_delete,
     ),
     components(schemas(
         common_utils::types::MinorUnit,
         common_utils::types::TimeRange,
         common_utils::link_utils::GenericLinkUiConfig,
         common_utils::link_utils::EnabledPaymentMethod,
+        common_utils::link_utils::PaymentMethodCollectStatus,
         common_utils::payout_method_utils::AdditionalPayoutMethodData,
         common_utils::payout_method_utils::CardAdditionalData,
         common_utils::payout_method_utils::BankAdditionalData,
@@ -211,6 +293,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
+        api_models::refunds::RefundLineItemRequest,
+        api_models::refunds::RefundLineItemResponse,
+        api_models::status_history::StatusHistoryResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
         api_models::organization::OrganizationCreateRequest,
@@ -220,16 +305,107 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
+        api_models::admin::MerchantConnectorBulkToggleRequest,
+        api_models::admin::MerchantConnectorBulkToggleResponse,
+        api_models::admin::MerchantConnectorToggleResponse,
+        api_models::admin::MerchantConnectorWebhookHealthResponse,
+        api_models::admin::ConnectorCredentialsExpiry,
+        api_models::admin::ConnectorCredentialExpiry,
+        api_models::admin::ExpiringConnectorCredentialsListConstraints,
+        api_models::admin::ExpiringConnectorCredentialsResponse,
+        api_models::admin::ExpiringConnectorCredential,
+        api_models::admin::ConnectorMaintenanceWindowCreateRequest,
+        api_models::admin::ConnectorMaintenanceWindowResponse,
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
+        api_models::admin::ExtendedCardInfoChoice,
+        api_models::admin::ConnectorAgnosticMitChoice,
+        api_models::admin::RotateWebhookSecretRequest,
+        api_models::admin::WebhookSecretResponse,
         api_models::admin::BusinessGenericLinkConfig,
+        api_models::admin::BusinessInvoiceConfig,
+        api_models::admin::BusinessStatementDescriptorConfig,
+        api_models::admin::BusinessDisputeRepresentmentConfig,
+        api_models::admin::BusinessRedirectConfig,
+        api_models::admin::BusinessClientOriginConfig,
+        api_models::admin::BusinessPostAuthRulesConfig,
+        api_models::admin::BusinessPayoutRetryConfig,
+        api_models::admin::BusinessPayoutFeeConfig,
+        api_models::admin::PayoutFeeSchedule,
+        api_models::admin::BusinessPayoutBalanceConfig,
+        api_models::admin::BusinessPayoutFulfillmentConfig,
+        api_models::admin::PayoutFulfillmentWindow,
+        api_models::admin::BusinessPaymentDefaultsConfig,
+        api_models::admin::BusinessCaptureOnShipmentConfig,
+        api_models::admin::BusinessDccConfig,
+        api_models::admin::BusinessOperationFallbackConfig,
+        api_models::admin::OperationFallbackGroup,
+        api_models::admin::ConnectorCanaryRolloutConfig,
+        api_models::admin::ConnectorCanaryRolloutResponse,
+        api_models::admin::PostAuthRule,
+        api_models::admin::DisputeEvidenceTemplates,
+        api_models::admin::OutgoingWebhookAuthConfig,
+        api_models::admin::OutgoingWebhookMutualTls,
+        api_models::admin::OutgoingWebhookOAuth2ClientCredentials,
+        api_models::admin::OutgoingWebhookAuthConfigResponse,
+        api_models::email_templates::EmailTemplateCreateRequest,
+        api_models::email_templates::EmailTemplateUpdateRequest,
+        api_models::email_templates::EmailTemplateResponse,
+        api_models::email_templates::EmailTemplateDeleteResponse,
+        api_models::payment_templates::PaymentTemplateCreateRequest,
+        api_models::payment_templates::PaymentTemplateUpdateRequest,
+        api_models::payment_templates::PaymentTemplateResponse,
+        api_models::payment_templates::PaymentTemplateDeleteResponse,
+        api_models::ledger::LedgerAccountBalance,
+        api_models::ledger::LedgerBalancesResponse,
+        api_models::ledger::LedgerStatementRequest,
+        api_models::ledger::LedgerEntryResponse,
+        api_models::ledger::LedgerStatementResponse,
+        api_models::ledger::PayoutBalanceOverrideResponse,
+        api_models::embed_tokens::EmbedTokenScope,
+        api_models::embed_tokens::EmbedTokenCreateRequest,
+        api_models::embed_tokens::EmbedTokenResponse,
+        api_models::enums::EmailTemplateType,
+        api_models::link_delivery::LinkDeliveryRequest,
+        api_models::link_delivery::LinkDeliveryResponse,
+        api_models::enums::MessagingChannel,
+        api_models::enums::LinkDeliveryStatus,
+        api_models::enums::DeliveredLinkType,
+        api_models::installment_plans::InstallmentPlanCreateRequest,
+        api_models::installment_plans::InstallmentPlanResponse,
+        api_models::installment_plans::InstallmentPlanListResponse,
+        api_models::installment_plans::InstallmentResponse,
+        api_models::test_clocks::TestClockCreateRequest,
+        api_models::test_clocks::TestClockAdvanceRequest,
+        api_models::test_clocks::TestClockResponse,
+        api_models::test_clocks::TestClockListResponse,
+        api_models::test_clocks::TestClockDeleteResponse,
+        api_models::enums::InstallmentPlanStatus,
+        api_models::enums::InstallmentStatus,
         api_models::admin::BusinessCollectLinkConfig,
+        api_models::admin::MerchantIdPrefixes,
+        api_models::admin::MerchantDataRetentionPolicy,
+        api_models::admin::RotatePublishableKeyRequest,
+        api_models::admin::RotatePublishableKeyResponse,
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerPortalLinkRequest,
+        api_models::customers::CustomerPortalLinkResponse,
+        api_models::customers::CustomerPortalPaymentMethodDeleteRequest,
+        api_models::customers::CustomerPortalBillingAddressUpdateRequest,
+        api_models::customers::CustomerPortalBillingAddressResponse,
         api_models::payment_methods::PaymentMethodCreate,
+        api_models::payment_methods::VaultTokenizeRequest,
+        api_models::payment_methods::VaultTokenizeResponse,
+        api_models::payment_methods::VaultDetokenizeRequest,
+        api_models::payment_methods::VaultDetokenizeResponse,
+        api_models::payment_methods::VaultCardDetails,
+        api_models::payment_methods::SharePaymentMethodRequest,
+        api_models::payment_methods::SharedPaymentMethodResponse,
+        api_models::payment_methods::PaymentMethodConsentEventResponse,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::CustomerPaymentMethod,
         api_models::payment_methods::PaymentMethodListResponse,
@@ -254,6 +430,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::PaymentType,
+        api_models::enums::ExpandableObject,
         api_models::enums::ScaExemptionType,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
@@ -305,12 +482,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
         api_models::admin::BusinessPaymentLinkConfig,
+        api_models::admin::PaymentLinkAbandonedCartConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
         api_models::admin::PaymentLinkTransactionDetails,
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::DeliveryDetails,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -393,6 +572,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::PaymentsShipmentRequest,
+        api_models::payments::PaymentsDccOfferRequest,
+        api_models::payments::PaymentsDccOfferResponse,
+        api_models::payments::DccChoice,
+        api_models::payments::ShipmentLineItem,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::PazeWalletData,
@@ -451,6 +635,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ReceiverDetails,
         api_models::payments::AchTransfer,
         api_models::payments::MultibancoTransferInstructions,
+        api_models::payments::VirtualAccountTransferInstructions,
         api_models::payments::DokuBankTransferInstructions,
         api_models::payments::ApplePayRedirectData,
         api_models::payments::ApplePayThirdPartySdkData,
@@ -471,8 +656,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentAttemptResponse,
+        api_models::payments::ConnectorResponseCodes,
+        api_models::payments::DccDetails,
+        api_models::enums::AvsResult,
+        api_models::enums::CvvResult,
+        api_models::enums::RefundStatusReason,
+        api_models::enums::PostAuthAction,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
+        api_models::invoices::InvoiceCreateRequest,
+        api_models::invoices::InvoiceLineItem,
+        api_models::invoices::InvoiceResponse,
+        api_models::enums::InvoiceFormat,
+        api_models::enums::CanaryRolloutStatus,
         api_models::payments::IncrementalAuthorizationResponse,
         api_models::payments::PaymentsCompleteAuthorizeRequest,
         api_models::payments::PaymentsExternalAuthenticationRequest,
@@ -497,6 +693,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::SurchargePercentage,
         api_models::payment_methods::PaymentMethodCollectLinkRequest,
         api_models::payment_methods::PaymentMethodCollectLinkResponse,
+        api_models::payment_methods::PaymentMethodCollectLinkSubmitRequest,
+        api_models::payment_methods::PaymentMethodCollectLinkSubmitResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
         api_models::payments::AmountFilter,
@@ -529,6 +727,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutFulfillRequest,
         api_models::payouts::PayoutRetrieveRequest,
         api_models::payouts::PayoutAttemptResponse,
+        api_models::payouts::PayoutAttemptListResponse,
         api_models::payouts::PayoutCreateResponse,
         api_models::payouts::PayoutListConstraints,
         api_models::payouts::PayoutListFilters,
@@ -538,27 +737,44 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutMethodData,
         api_models::payouts::PayoutMethodDataResponse,
         api_models::payouts::PayoutLinkResponse,
+        api_models::payouts::PayoutBalanceResponse,
+        api_models::payouts::PayoutConnectorBalanceResponse,
+        api_models::payouts::PayoutCurrencyBalance,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::RecipientKycStatus,
+        api_models::enums::AccountNameMatchResult,
+        api_models::enums::AccountNameMismatchAction,
+        api_models::enums::PayoutFeeSource,
+        api_models::enums::LedgerAccount,
+        api_models::enums::LedgerEntryDirection,
+        api_models::enums::LedgerSourceType,
+        api_models::enums::ConnectorCredentialType,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
+        api_models::webhooks::OutgoingWebhookV2,
+        api_models::enums::WebhookPayloadVersion,
+        api_models::enums::OriginEnforcementMode,
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
         api_models::enums::AuthenticationStatus,
         api_models::admin::MerchantAccountResponse,
+        api_models::admin::MerchantAccountSearchResponse,
+        api_models::usage::MerchantUsageResponse,
         api_models::admin::MerchantConnectorId,
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::enums::ApiKeyScope,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -568,8 +784,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
+        api_models::payments::PaymentLinkExpiryUpdateRequest,
+        api_models::payments::PaymentLinkTemplateCreateRequest,
+        api_models::payments::PaymentLinkTemplateUpdateRequest,
+        api_models::payments::PaymentLinkTemplateResponse,
+        api_models::payments::PaymentLinkTemplateListResponse,
+        api_models::payments::PaymentLinkTemplateUsageResponse,
         api_models::payouts::PayoutLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
+        api_models::payments::PaymentsClientSecretRotateResponse,
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
@@ -610,6 +833,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookPayloadPreviewResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -655,6 +879,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::DisplayAmountOnSdk,
         api_models::payments::PaymentsPostSessionTokensRequest,
         api_models::payments::PaymentsPostSessionTokensResponse,
+        api_models::payments::PaymentsAsyncConfirmResponse,
     )),
     modifiers(&SecurityAddon)
 )]
@@ -162,6 +162,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundErrorDetails,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
+        api_models::status_history::StatusHistoryResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
         api_models::organization::OrganizationCreateRequest,
@@ -176,6 +177,31 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
+        api_models::admin::BusinessInvoiceConfig,
+        api_models::admin::BusinessStatementDescriptorConfig,
+        api_models::admin::BusinessDisputeRepresentmentConfig,
+        api_models::admin::BusinessRedirectConfig,
+        api_models::admin::BusinessClientOriginConfig,
+        api_models::admin::BusinessPostAuthRulesConfig,
+        api_models::admin::BusinessPayoutRetryConfig,
+        api_models::admin::BusinessPayoutFeeConfig,
+        api_models::admin::PayoutFeeSchedule,
+        api_models::admin::BusinessPayoutBalanceConfig,
+        api_models::admin::BusinessPayoutFulfillmentConfig,
+        api_models::admin::PayoutFulfillmentWindow,
+        api_models::admin::BusinessPaymentDefaultsConfig,
+        api_models::admin::BusinessCaptureOnShipmentConfig,
+        api_models::admin::BusinessDccConfig,
+        api_models::admin::BusinessOperationFallbackConfig,
+        api_models::admin::OperationFallbackGroup,
+        api_models::admin::ConnectorCanaryRolloutConfig,
+        api_models::admin::ConnectorCanaryRolloutResponse,
+        api_models::admin::PostAuthRule,
+        api_models::admin::DisputeEvidenceTemplates,
+        api_models::admin::OutgoingWebhookAuthConfig,
+        api_models::admin::OutgoingWebhookMutualTls,
+        api_models::admin::OutgoingWebhookOAuth2ClientCredentials,
+        api_models::admin::OutgoingWebhookAuthConfigResponse,
         api_models::admin::BusinessCollectLinkConfig,
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::customers::CustomerRequest,
@@ -212,8 +238,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
+        api_models::enums::InvoiceFormat,
+        api_models::enums::CanaryRolloutStatus,
         api_models::enums::ProductType,
         api_models::enums::PaymentType,
+        api_models::enums::ExpandableObject,
         api_models::enums::ScaExemptionType,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
@@ -250,6 +279,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::OrderFulfillmentTimeOrigin,
         api_models::enums::UIWidgetFormLayout,
         api_models::admin::MerchantConnectorCreate,
+        api_models::admin::ConnectorCredentialsExpiry,
+        api_models::admin::ConnectorCredentialExpiry,
         api_models::admin::AdditionalMerchantData,
         api_models::admin::ConnectorWalletDetails,
         api_models::admin::MerchantRecipientData,
@@ -266,6 +297,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
         api_models::admin::BusinessPaymentLinkConfig,
+        api_models::admin::PaymentLinkAbandonedCartConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
         api_models::admin::PaymentLinkTransactionDetails,
@@ -416,6 +448,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ReceiverDetails,
         api_models::payments::AchTransfer,
         api_models::payments::MultibancoTransferInstructions,
+        api_models::payments::VirtualAccountTransferInstructions,
         api_models::payments::DokuBankTransferInstructions,
         api_models::payments::ApplePayRedirectData,
         api_models::payments::ApplePayThirdPartySdkData,
@@ -431,6 +464,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentAttemptResponse,
+        api_models::payments::ConnectorResponseCodes,
+        api_models::payments::DccDetails,
+        api_models::enums::AvsResult,
+        api_models::enums::CvvResult,
+        api_models::enums::PostAuthAction,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::IncrementalAuthorizationResponse,
@@ -443,6 +481,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::MifinityData,
         api_models::enums::TransactionStatus,
         api_models::payments::PaymentCreatePaymentLinkConfig,
+        api_models::link_delivery::LinkDeliveryRequest,
+        api_models::enums::MessagingChannel,
         api_models::payments::ThreeDsData,
         api_models::payments::ThreeDsMethodData,
         api_models::payments::PollConfigResponse,
@@ -502,6 +542,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::RecipientKycStatus,
+        api_models::enums::AccountNameMatchResult,
+        api_models::enums::AccountNameMismatchAction,
+        api_models::enums::PayoutFeeSource,
+        api_models::enums::ConnectorCredentialType,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::enums::PresenceOfCustomerDuringPayment,
@@ -513,6 +558,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::SurchargeCalculationOverride,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
+        api_models::webhooks::OutgoingWebhookV2,
+        api_models::enums::WebhookPayloadVersion,
+        api_models::enums::OriginEnforcementMode,
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
         api_models::enums::EventType,
@@ -525,6 +573,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::enums::ApiKeyScope,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -536,6 +585,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentLinkInitiateRequest,
         api_models::payouts::PayoutLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
+        api_models::payments::PaymentsClientSecretRotateResponse,
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
@@ -5,6 +5,8 @@ pub mod blocklist;
 pub mod customers;
 pub mod disputes;
 pub mod gsm;
+pub mod installment_plans;
+pub mod link_delivery;
 pub mod mandates;
 pub mod merchant_account;
 pub mod merchant_connector_account;
@@ -17,6 +19,7 @@ pub mod poll;
 pub mod profile;
 pub mod refunds;
 pub mod routing;
+pub mod test_clocks;
 pub mod webhook_events;
 
 pub use self::{
@@ -32,7 +32,10 @@ pub async fn customers_create() {}
 #[utoipa::path(
     get,
     path = "/customers/{customer_id}",
-    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("expand" = Option<String>, Query, description = "The comma separated list of related objects to be expanded in the response")
+    ),
     responses(
         (status = 200, description = "Customer Retrieved", body = CustomerResponse),
         (status = 404, description = "Customer was not found")
@@ -106,6 +109,67 @@ pub async fn customers_delete() {}
 #[cfg(feature = "v1")]
 pub async fn customers_list() {}
 
+/// Customers - Create Portal Link
+///
+/// Creates a hosted portal link for a customer to view and remove their saved payment methods and update their billing address.
+#[utoipa::path(
+    post,
+    path = "/customers/portal_link",
+    request_body = CustomerPortalLinkRequest,
+    responses(
+        (status = 200, description = "Customer portal link created", body = CustomerPortalLinkResponse),
+        (status = 404, description = "Customer or profile was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Create a Customer Portal Link",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customer_portal_link_create() {}
+
+/// Customers - Portal Delete Payment Method
+///
+/// Deletes a saved payment method of the customer from the customer portal.
+#[utoipa::path(
+    post,
+    path = "/customers/portal_link/{merchant_id}/{customer_portal_link_id}/payment_methods/{payment_method_id}/delete",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant"),
+        ("customer_portal_link_id" = String, Path, description = "The unique identifier for the Customer Portal Link"),
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method")
+    ),
+    request_body = CustomerPortalPaymentMethodDeleteRequest,
+    responses(
+        (status = 200, description = "Payment Method deleted", body = PaymentMethodDeleteResponse),
+        (status = 404, description = "Customer portal link or payment method was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Delete a Payment Method from the Customer Portal"
+)]
+#[cfg(feature = "v1")]
+pub async fn customer_portal_payment_method_delete() {}
+
+/// Customers - Portal Update Billing Address
+///
+/// Updates the customer's billing address from the customer portal.
+#[utoipa::path(
+    post,
+    path = "/customers/portal_link/{merchant_id}/{customer_portal_link_id}/billing_address",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant"),
+        ("customer_portal_link_id" = String, Path, description = "The unique identifier for the Customer Portal Link")
+    ),
+    request_body = CustomerPortalBillingAddressUpdateRequest,
+    responses(
+        (status = 200, description = "Billing address updated", body = CustomerPortalBillingAddressResponse),
+        (status = 404, description = "Customer portal link was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Update Billing Address from the Customer Portal"
+)]
+#[cfg(feature = "v1")]
+pub async fn customer_portal_billing_address_update() {}
+
 /// Customers - Create
 ///
 /// Creates a customer object and stores the customer details to be reused for future payments.
@@ -0,0 +1,75 @@
+/// Installment Plans - Create
+///
+/// Creates a plan that splits the total amount into installments, which are charged as off session payments against a mandate on the schedule of the plan
+#[utoipa::path(
+    post,
+    path = "/installment_plans",
+    request_body = InstallmentPlanCreateRequest,
+    responses(
+        (status = 200, description = "Installment plan created", body = InstallmentPlanResponse),
+        (status = 400, description = "Invalid data or mandate cannot be used for the installments")
+    ),
+    tag = "Installment Plans",
+    operation_id = "Create an Installment Plan",
+    security(("api_key" = []))
+)]
+pub async fn installment_plan_create() {}
+
+/// Installment Plans - Retrieve
+///
+/// Retrieves an installment plan along with the status of each of its installments
+#[utoipa::path(
+    get,
+    path = "/installment_plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the installment plan")
+    ),
+    responses(
+        (status = 200, description = "Installment plan retrieved", body = InstallmentPlanResponse),
+        (status = 404, description = "Installment plan not found")
+    ),
+    tag = "Installment Plans",
+    operation_id = "Retrieve an Installment Plan",
+    security(("api_key" = []))
+)]
+pub async fn installment_plan_retrieve() {}
+
+/// Installment Plans - List
+///
+/// Lists the installment plans of the merchant
+#[utoipa::path(
+    get,
+    path = "/installment_plans",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only list the installment plans of this customer"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of installment plans to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of installment plans to skip"),
+    ),
+    responses(
+        (status = 200, description = "Installment plans retrieved", body = InstallmentPlanListResponse)
+    ),
+    tag = "Installment Plans",
+    operation_id = "List all Installment Plans",
+    security(("api_key" = []))
+)]
+pub async fn installment_plans_list() {}
+
+/// Installment Plans - Cancel
+///
+/// Cancels an active installment plan. The remaining installments of the plan are not charged
+#[utoipa::path(
+    post,
+    path = "/installment_plans/{plan_id}/cancel",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the installment plan")
+    ),
+    responses(
+        (status = 200, description = "Installment plan cancelled", body = InstallmentPlanResponse),
+        (status = 404, description = "Installment plan not found"),
+        (status = 400, description = "Installment plan is not active")
+    ),
+    tag = "Installment Plans",
+    operation_id = "Cancel an Installment Plan",
+    security(("api_key" = []))
+)]
+pub async fn installment_plan_cancel() {}
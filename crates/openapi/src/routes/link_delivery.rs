@@ -0,0 +1,36 @@
+/// Link Delivery - Retrieve
+///
+/// Retrieve the delivery status of a payment link or payout link sent to the customer over SMS or WhatsApp
+#[utoipa::path(
+    get,
+    path = "/link_deliveries/{delivery_id}",
+    params(
+        ("delivery_id" = String, Path, description = "The identifier for the link delivery")
+    ),
+    responses(
+        (status = 200, description = "Link delivery retrieved", body = LinkDeliveryResponse),
+        (status = 404, description = "Link delivery not found")
+    ),
+    tag = "Link Delivery",
+    operation_id = "Retrieve a Link Delivery",
+    security(("api_key" = []))
+)]
+pub async fn link_delivery_retrieve() {}
+
+/// Link Delivery - List
+///
+/// List the deliveries of a payment link or payout link sent to the customer over SMS or WhatsApp
+#[utoipa::path(
+    get,
+    path = "/link_deliveries",
+    params(
+        ("link_id" = String, Query, description = "The identifier of the payment link or payout link")
+    ),
+    responses(
+        (status = 200, description = "Link deliveries retrieved", body = Vec<LinkDeliveryResponse>)
+    ),
+    tag = "Link Delivery",
+    operation_id = "List Link Deliveries",
+    security(("api_key" = []))
+)]
+pub async fn link_deliveries_list() {}
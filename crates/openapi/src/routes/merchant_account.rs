@@ -274,6 +274,48 @@ pub async fn delete_merchant_account() {}
 )]
 pub async fn merchant_account_kv_status() {}
 
+#[cfg(feature = "v1")]
+/// Merchant Account - Usage
+///
+/// Retrieve the billable operations performed by the Merchant Account in a month
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/usage",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("month" = Option<String>, Query, description = "The month for which the usage is retrieved, in the format YYYY-MM. Defaults to the current month")
+    ),
+    responses(
+        (status = 200, description = "Merchant Account Usage Retrieved", body = MerchantUsageResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the Usage of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_usage() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Rotate Publishable Key
+///
+/// Issue a new publishable key for the Merchant Account. The previous publishable key remains valid for the overlap period, after which it is revoked
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/rotate_publishable_key",
+    request_body = RotatePublishableKeyRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Publishable Key Rotated", body = RotatePublishableKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rotate the Publishable Key of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn rotate_publishable_key() {}
+
 /// Merchant Connector - List
 ///
 /// List Merchant Connector Details for the merchant
@@ -282,6 +324,7 @@ pub async fn merchant_account_kv_status() {}
     path = "/accounts/{account_id}/profile/connectors",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("tag" = Option<String>, Query, description = "Only list the connector accounts tagged with this tag"),
     ),
     responses(
         (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorResponse>),
@@ -175,6 +175,7 @@ pub async fn connector_retrieve() {}
     path = "/accounts/{account_id}/connectors",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("tag" = Option<String>, Query, description = "Only list the connector accounts tagged with this tag"),
     ),
     responses(
         (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorListResponse>),
@@ -235,6 +236,169 @@ pub async fn connector_list() {}
 )]
 pub async fn connector_update() {}
 
+/// Merchant Connector - Bulk Toggle
+///
+/// To enable or disable many Merchant Connector accounts at once, either all the accounts of a connector across profiles or all the accounts of a profile
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/bulk_toggle",
+    request_body(
+        content = MerchantConnectorBulkToggleRequest,
+        examples(
+            (
+                "Disable a connector across all profiles" = (
+                    value = json! ({
+                        "connector_name": "stripe",
+                        "disabled": true
+                    })
+                )
+            ),
+            (
+                "Enable all connectors of a profile" = (
+                    value = json! ({
+                        "profile_id": "pro_abcdefghijklmnop",
+                        "disabled": false
+                    })
+                )
+            )
+        ),
+    ),
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors Toggled", body = MerchantConnectorBulkToggleResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Bulk Toggle Merchant Connectors",
+   security(("admin_api_key" = []))
+)]
+pub async fn connector_bulk_toggle() {}
+
+/// Merchant Connector - Webhook Health
+///
+/// Retrieve the health of the incoming webhooks of each Merchant Connector account: when the last webhook was received and how many webhooks failed source verification or processing. Helpful in detecting a connector that has stopped sending webhooks
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/webhook_health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors Webhook Health Retrieved", body = Vec<MerchantConnectorWebhookHealthResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Retrieve Merchant Connectors Webhook Health",
+   security(("admin_api_key" = []))
+)]
+pub async fn connector_webhook_health() {}
+
+/// Merchant Connector - List Expiring Credentials
+///
+/// List the credentials of the Merchant Connector accounts which expire within the given number of days, such as API keys and Apple Pay certificates, so that they can be renewed with the connectors in time
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/credentials/expiring",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("within_days" = Option<u16>, Query, description = "The credentials expiring within this many days are listed, defaults to 30 days"),
+    ),
+    responses(
+        (status = 200, description = "Expiring Merchant Connector Credentials Retrieved", body = Vec<ExpiringConnectorCredentialsResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "List Expiring Merchant Connector Credentials",
+   security(("admin_api_key" = []))
+)]
+pub async fn connector_expiring_credentials_list() {}
+
+/// Merchant Connector - Create Maintenance Window
+///
+/// Schedule a maintenance window for a Merchant Connector account. While the window is active, routing skips the connector account and transactions fall through to the other connectors
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows",
+    request_body(
+        content = ConnectorMaintenanceWindowCreateRequest,
+        examples(
+            (
+                "Schedule a maintenance window in IST" = (
+                    value = json! ({
+                        "start_time": "2024-11-10T02:00:00",
+                        "end_time": "2024-11-10T04:30:00",
+                        "timezone": "+05:30",
+                        "description": "Scheduled processor upgrade"
+                    })
+                )
+            )
+        ),
+    ),
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Maintenance Window Created", body = ConnectorMaintenanceWindowResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Create a Merchant Connector Maintenance Window",
+   security(("admin_api_key" = []))
+)]
+pub async fn connector_maintenance_window_create() {}
+
+/// Merchant Connector - List Maintenance Windows
+///
+/// List the maintenance windows of a Merchant Connector account which have not ended yet
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Maintenance Windows Retrieved", body = Vec<ConnectorMaintenanceWindowResponse>),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "List Merchant Connector Maintenance Windows",
+   security(("admin_api_key" = []))
+)]
+pub async fn connector_maintenance_window_list() {}
+
+/// Merchant Connector - Delete Maintenance Window
+///
+/// Delete a maintenance window of a Merchant Connector account
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows/{maintenance_window_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector"),
+        ("maintenance_window_id" = String, Path, description = "The unique identifier for the Maintenance Window")
+    ),
+    responses(
+        (status = 200, description = "Maintenance Window Deleted", body = ConnectorMaintenanceWindowResponse),
+        (status = 404, description = "Maintenance Window does not exist in records")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Delete a Merchant Connector Maintenance Window",
+   security(("admin_api_key" = []))
+)]
+pub async fn connector_maintenance_window_delete() {}
+
 /// Connector Account - Update
 ///
 /// To update an existing Connector account. Helpful in enabling/disabling different payment methods and other settings for the connector
@@ -71,6 +71,54 @@ pub async fn organization_retrieve() {}
 )]
 pub async fn organization_update() {}
 
+#[cfg(feature = "v1")]
+/// Organization - Search Merchant Accounts
+///
+/// Search the merchant accounts of an organization by merchant name, publishable key, creation time or recon status
+#[utoipa::path(
+    get,
+    path = "/organization/{id}/merchant_accounts/search",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Organization"),
+        ("merchant_name" = Option<String>, Query, description = "Case-insensitive substring to be matched against the merchant name"),
+        ("publishable_key" = Option<String>, Query, description = "The publishable key of the merchant account"),
+        ("created.gte" = Option<PrimitiveDateTime>, Query, description = "Only return merchant accounts created at or after this time"),
+        ("created.lte" = Option<PrimitiveDateTime>, Query, description = "Only return merchant accounts created at or before this time"),
+        ("recon_status" = Option<ReconStatus>, Query, description = "The reconciliation status of the merchant account"),
+        ("limit" = Option<u32>, Query, description = "Limit on the number of merchant accounts to return"),
+        ("offset" = Option<u32>, Query, description = "The number of merchant accounts to skip")
+    ),
+    responses(
+        (status = 200, description = "Merchant Accounts Found", body = MerchantAccountSearchResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Organization",
+    operation_id = "Search Merchant Accounts of an Organization",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_search() {}
+
+#[cfg(feature = "v1")]
+/// Organization - Usage Export
+///
+/// Export the billable operations performed by every merchant account of an organization in a month as CSV
+#[utoipa::path(
+    get,
+    path = "/organization/{id}/usage/export",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Organization"),
+        ("month" = Option<String>, Query, description = "The month for which the usage is exported, in the format YYYY-MM. Defaults to the current month")
+    ),
+    responses(
+        (status = 200, description = "Usage of the merchant accounts exported as CSV", content_type = "text/csv"),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Organization",
+    operation_id = "Export the Usage of an Organization",
+    security(("admin_api_key" = []))
+)]
+pub async fn organization_usage_export() {}
+
 #[cfg(feature = "v2")]
 /// Organization - Create
 ///
@@ -17,3 +17,158 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Payment Link - Deactivate
+///
+/// Deactivates a Payment Link. A deactivated payment link can no longer be used to make a payment
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/deactivate",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "Payment link deactivated", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Deactivate a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_deactivate() {}
+
+/// Payment Link - Update Expiry
+///
+/// Extends or shortens the expiry of a Payment Link along with the session expiry of its payment
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/expiry",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    request_body = PaymentLinkExpiryUpdateRequest,
+    responses(
+        (status = 200, description = "Payment link expiry updated", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Invalid session expiry or payment link has been deactivated"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Update the expiry of a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_expiry_update() {}
+
+/// Payment Link - Regenerate
+///
+/// Generates new URLs for a Payment Link. The previously issued URLs of the payment link stop working
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/regenerate",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "Payment link regenerated", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Payment link has been deactivated"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Regenerate a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_regenerate() {}
+
+/// Payment Link Template - Create
+///
+/// Creates a reusable payment link. Every visit of the link creates a new payment from the template
+#[utoipa::path(
+    post,
+    path = "/payment_link/templates",
+    request_body = PaymentLinkTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Payment link template created", body = PaymentLinkTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Link",
+    operation_id = "Create a Payment Link Template",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_template_create() {}
+
+/// Payment Link Template - List
+///
+/// Lists the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/templates/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of templates to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of templates to skip"),
+    ),
+    responses(
+        (status = 200, description = "Payment link templates retrieved", body = PaymentLinkTemplateListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Payment Link",
+    operation_id = "List all Payment Link Templates",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_template_list() {}
+
+/// Payment Link Template - Retrieve
+///
+/// Retrieves a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for payment link template")
+    ),
+    responses(
+        (status = 200, description = "Payment link template retrieved", body = PaymentLinkTemplateResponse),
+        (status = 404, description = "No payment link template found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Payment Link Template",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_template_retrieve() {}
+
+/// Payment Link Template - Update
+///
+/// Updates the usage limit, expiry or status of a reusable payment link
+#[utoipa::path(
+    post,
+    path = "/payment_link/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for payment link template")
+    ),
+    request_body = PaymentLinkTemplateUpdateRequest,
+    responses(
+        (status = 200, description = "Payment link template updated", body = PaymentLinkTemplateResponse),
+        (status = 404, description = "No payment link template found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Update a Payment Link Template",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_template_update() {}
+
+/// Payment Link Template - List Usages
+///
+/// Lists the payment links created on every use of a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/templates/{template_id}/usages",
+    params(
+        ("template_id" = String, Path, description = "The identifier for payment link template")
+    ),
+    responses(
+        (status = 200, description = "Payment link template usages retrieved", body = PaymentLinkTemplateUsageResponse),
+        (status = 404, description = "No payment link template found")
+    ),
+    tag = "Payment Link",
+    operation_id = "List the usages of a Payment Link Template",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_template_usage_list() {}
@@ -177,6 +177,88 @@ pub async fn payment_method_update_api() {}
 #[cfg(feature = "v1")]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method - Share
+///
+/// Shares a card saved against a customer with another merchant account of the same organization. The card is saved against a customer created for the other merchant account when a card of the customer is first shared with it, and against the same customer afterwards. Token sharing must be enabled for the organization, and the other merchant account must have opted in to receiving shared payment methods.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{method_id}/share",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = SharePaymentMethodRequest,
+    responses(
+        (status = 200, description = "Payment Method shared", body = SharedPaymentMethodResponse),
+        (status = 404, description = "Payment Method or Merchant Account does not exist in records"),
+        (status = 412, description = "Token sharing is not enabled for the organization, or the merchant account has not opted in to receiving shared payment methods")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Share a Payment method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn share_payment_method_api() {}
+
+/// Payment Method - List Shares
+///
+/// Lists the merchant accounts of the organization a payment method has been shared with.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/{method_id}/share",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method shares retrieved", body = Vec<SharedPaymentMethodResponse>),
+    ),
+    tag = "Payment Methods",
+    operation_id = "List the shares of a Payment method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_shared_payment_methods_api() {}
+
+/// Payment Method - Revoke Share
+///
+/// Revokes the access of a merchant account to a payment method shared with it, deleting the payment method from the merchant account.
+#[utoipa::path(
+    delete,
+    path = "/payment_methods/{method_id}/share/{target_merchant_id}",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+        ("target_merchant_id" = String, Path, description = "The unique identifier for the Merchant Account the Payment Method was shared with"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method share revoked", body = SharedPaymentMethodResponse),
+        (status = 404, description = "Payment Method is not shared with the Merchant Account")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Revoke the share of a Payment method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn revoke_shared_payment_method_api() {}
+
+/// Payment Method - List Consent Events
+///
+/// Lists the consents given by the customer to store a payment method for future usage, along with the time, IP address and consent text version of each consent.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/{method_id}/consent_events",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method consent events retrieved", body = Vec<PaymentMethodConsentEventResponse>),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "List the consent events of a Payment method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_payment_method_consent_events_api() {}
+
 /// Payment Method - Set Default Payment Method for Customer
 ///
 /// Set the Payment Method as Default for the Customer.
@@ -198,6 +280,105 @@ pub async fn payment_method_delete_api() {}
 )]
 pub async fn default_payment_method_set_api() {}
 
+/// Payment Method - Create Collect Link
+///
+/// Creates a link for collecting the payment method details of a customer.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/collect",
+    request_body = PaymentMethodCollectLinkRequest,
+    responses(
+        (status = 200, description = "Payment Method Collect Link created", body = PaymentMethodCollectLinkResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Customer does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Create a Payment Method Collect Link",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_collect_link_create() {}
+
+/// Payment Method - Render Collect Link
+///
+/// Renders the link for collecting the payment method details of a customer.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/collect/{merchant_id}/{collect_id}",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant"),
+        ("collect_id" = String, Path, description = "The unique identifier for the collect link"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method Collect Link rendered", content_type = "text/html", body = String),
+        (status = 404, description = "Payment Method Collect Link does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Render a Payment Method Collect Link"
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_collect_link_render() {}
+
+/// Payment Method - Submit Collect Link
+///
+/// Submits the payment method details collected through the link. The details are stored in the vault against the customer of the link, and the merchant is notified through the `payment_method_collected` webhook.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/collect/{merchant_id}/{collect_id}",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant"),
+        ("collect_id" = String, Path, description = "The unique identifier for the collect link"),
+    ),
+    request_body = PaymentMethodCollectLinkSubmitRequest,
+    responses(
+        (status = 200, description = "Payment method details submitted", body = PaymentMethodCollectLinkSubmitResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Payment Method Collect Link does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Submit a Payment Method Collect Link"
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_collect_link_submit() {}
+
+/// Vault - Tokenize
+///
+/// Vaults a card against a customer without creating a payment, and returns a multi-use token referencing the vaulted card. The token can be used as the `payment_method_id` in the `recurring_details` of a payment. This API can only be accessed using an API key restricted to the `tokenize` scope.
+#[utoipa::path(
+    post,
+    path = "/vault/tokenize",
+    request_body = VaultTokenizeRequest,
+    responses(
+        (status = 200, description = "Card vaulted", body = VaultTokenizeResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 403, description = "API key not permitted the tokenize scope")
+    ),
+    tag = "Vault",
+    operation_id = "Tokenize a Card",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn vault_tokenize_api() {}
+
+/// Vault - Detokenize
+///
+/// Retrieves the card referenced by a token obtained from the tokenize API. This API can only be accessed using an API key restricted to the `detokenize` scope.
+#[utoipa::path(
+    post,
+    path = "/vault/detokenize",
+    request_body = VaultDetokenizeRequest,
+    responses(
+        (status = 200, description = "Card retrieved", body = VaultDetokenizeResponse),
+        (status = 404, description = "Payment Method does not exist in records"),
+        (status = 403, description = "API key not permitted the detokenize scope")
+    ),
+    tag = "Vault",
+    operation_id = "Detokenize a Card",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn vault_detokenize_api() {}
+
 /// Payment Method - Create Intent
 ///
 /// Creates a payment method for customer with billing information and other metadata.
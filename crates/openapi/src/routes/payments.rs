@@ -235,6 +235,8 @@ pub fn payments_retrieve() {}
 /// Payments - Update
 ///
 /// To update the properties of a *PaymentIntent* object. This may include attaching a payment method, or attaching customer object or metadata fields after the Payment is created
+///
+/// The amount of a payment in `requires_capture` status can be increased when incremental authorization is allowed for it, the additional amount is then authorized at the connector
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}",
@@ -339,6 +341,7 @@ pub fn payments_update() {}
     ),
     responses(
         (status = 200, description = "Payment confirmed", body = PaymentsCreateResponseOpenApi),
+        (status = 202, description = "Payment confirm accepted, when `async_confirm` is set", body = PaymentsAsyncConfirmResponse),
         (status = 400, description = "Missing mandatory fields")
     ),
     tag = "Payments",
@@ -381,6 +384,77 @@ pub fn payments_confirm() {}
 )]
 pub fn payments_capture() {}
 
+#[cfg(feature = "v1")]
+/// Payments - Capture on Shipment
+///
+/// Notifies a shipment of the order of an authorized payment, to capture the payment when capture on shipment is enabled for its profile. The full amount is captured, unless partial capture on shipment is enabled for the profile and the shipped line items are passed, in which case the amount of the shipped line items is captured. The line items shipped earlier in other shipments of the payment cannot be shipped again.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/shipments",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body (
+        content = PaymentsShipmentRequest,
+        examples(
+            (
+                "Ship the whole order" = (
+                    value = json!({"shipment_id": "shipment_1234"})
+                )
+            ),
+            (
+                "Ship a part of the line items" = (
+                    value = json!({
+                        "shipment_id": "shipment_1234",
+                        "line_items": [{"product_name": "shirt", "quantity": 1}]
+                    })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Payment captured", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields, or capture on shipment is not enabled for the profile of the payment")
+    ),
+    tag = "Payments",
+    operation_id = "Capture a Payment on Shipment",
+    security(("api_key" = []))
+)]
+pub fn payments_capture_on_shipment() {}
+
+#[cfg(feature = "v1")]
+/// Payments - DCC Offer
+///
+/// Quotes a dynamic currency conversion offer for a payment, for the customer to pay in the currency of their card instead of the currency of the payment. The offer is made at the forex rate with the markup configured for the profile of the payment, and is accepted or declined by passing the `dcc_choice` in the confirm request of the payment before the offer expires.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/dcc_offer",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body (
+        content = PaymentsDccOfferRequest,
+        examples(
+            (
+                "Quote an offer in EUR" = (
+                    value = json!({
+                        "client_secret": "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo",
+                        "presentment_currency": "EUR"
+                    })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Dynamic currency conversion offer quoted", body = PaymentsDccOfferResponse),
+        (status = 400, description = "Missing mandatory fields, or dynamic currency conversion is not enabled for the profile of the payment")
+    ),
+    tag = "Payments",
+    operation_id = "Quote a DCC offer for a Payment",
+    security(("publishable_key" = []))
+)]
+pub fn payments_dcc_offer() {}
+
 #[cfg(feature = "v1")]
 /// Payments - Session token
 ///
@@ -399,6 +473,24 @@ pub fn payments_capture() {}
 )]
 pub fn payments_connector_session() {}
 
+#[cfg(feature = "v1")]
+/// Payments - Pre-warm Session tokens
+///
+/// Creates the session tokens of a payment before the customer selects a payment method. The connector session tokens, such as those of Klarna and PayPal, are cached against the payment, and returned by the subsequent session tokens calls without calling the connector again, as long as the amount and currency of the payment are unchanged.
+#[utoipa::path(
+  post,
+  path = "/payments/session_tokens/prewarm",
+  request_body=PaymentsSessionRequest,
+  responses(
+      (status = 200, description = "Payment session object created and the connector session tokens were cached", body = PaymentsSessionResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Pre-warm Session tokens for a Payment",
+  security(("publishable_key" = []))
+)]
+pub fn payments_prewarm_session_tokens() {}
+
 #[cfg(feature = "v2")]
 /// Payments - Session token
 ///
@@ -528,6 +620,47 @@ pub async fn profile_payments_list() {}
 )]
 pub fn payments_incremental_authorization() {}
 
+/// Payments - Generate Invoice
+///
+/// Generates an invoice for a payment in status: succeeded or partially_captured, and optionally emails it to the customer
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/invoice",
+  request_body=InvoiceCreateRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Invoice generated", body = InvoiceResponse),
+      (status = 400, description = "Invoice cannot be generated for the payment in its current status"),
+      (status = 404, description = "Payment not found")
+  ),
+  tag = "Payments",
+  operation_id = "Generate an Invoice for a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_generate_invoice() {}
+
+/// Payments - Rotate Client Secret
+///
+/// Issues a new client secret for a payment which is not yet in a terminal status. The previous client secret is invalidated immediately
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/client_secret/rotate",
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Client secret rotated", body = PaymentsClientSecretRotateResponse),
+      (status = 400, description = "Client secret cannot be rotated for the payment in its current status"),
+      (status = 404, description = "Payment not found")
+  ),
+  tag = "Payments",
+  operation_id = "Rotate the Client Secret of a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_rotate_client_secret() {}
+
 /// Payments - External 3DS Authentication
 ///
 /// External 3DS Authentication is performed and returns the AuthenticationResponse
@@ -19,7 +19,8 @@ pub async fn payouts_create() {}
     path = "/payouts/{payout_id}",
     params(
         ("payout_id" = String, Path, description = "The identifier for payout"),
-        ("force_sync" = Option<bool>, Query, description = "Sync with the connector to get the payout details (defaults to false)")
+        ("force_sync" = Option<bool>, Query, description = "Sync with the connector to get the payout details (defaults to false)"),
+        ("expand" = Option<String>, Query, description = "The comma separated list of related objects to be expanded in the response")
     ),
     responses(
         (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
@@ -31,6 +32,23 @@ pub async fn payouts_create() {}
 )]
 pub async fn payouts_retrieve() {}
 
+/// Payouts - List Attempts
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}/attempts",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout attempts retrieved", body = PayoutAttemptListResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List Payout Attempts",
+    security(("api_key" = []))
+)]
+pub async fn payouts_attempts_list() {}
+
 /// Payouts - Update
 #[utoipa::path(
     post,
@@ -190,3 +208,40 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Balance
+#[utoipa::path(
+    get,
+    path = "/payouts/balance",
+    params(
+        ("merchant_connector_id" = Option<String>, Query, description = "The identifier of the payout connector account to fetch the balance for"),
+        ("currency" = Option<Currency>, Query, description = "Only balances held in this currency are returned")
+    ),
+    responses(
+        (status = 200, description = "Payout balances retrieved", body = PayoutBalanceResponse),
+        (status = 404, description = "Merchant connector account does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve payout balances",
+    security(("api_key" = []))
+)]
+pub async fn payouts_balance() {}
+
+/// Payout Link - Render
+///
+/// Renders the payout link, where the customer submits the payout method details for a payout
+#[utoipa::path(
+    get,
+    path = "/payout_link/{merchant_id}/{payout_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("payout_id" = String, Path, description = "The identifier for the payout")
+    ),
+    responses(
+        (status = 200, description = "Payout link rendered", content_type = "text/html", body = String),
+        (status = 404, description = "Payout link does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Render a Payout Link"
+)]
+pub async fn payout_link_render() {}
@@ -90,6 +90,458 @@ pub async fn profile_update() {}
 )]
 pub async fn profile_retrieve() {}
 
+#[cfg(feature = "v1")]
+/// Profile - Create Email Template
+///
+/// Create a branded email template used for customer notifications of the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/email_templates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = EmailTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Email Template Created", body = EmailTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Create an Email Template for the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn email_template_create() {}
+
+#[cfg(feature = "v1")]
+/// Profile - List Email Templates
+///
+/// List all the email templates of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/email_templates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Email Templates Retrieved", body = Vec<EmailTemplateResponse>)
+    ),
+    tag = "Profile",
+    operation_id = "List Email Templates of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn email_templates_list() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Email Template
+///
+/// Retrieve an email template of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/email_templates/{template_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("template_id" = String, Path, description = "The unique identifier for the email template")
+    ),
+    responses(
+        (status = 200, description = "Email Template Retrieved", body = EmailTemplateResponse),
+        (status = 404, description = "Email Template not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve an Email Template of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn email_template_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Update Email Template
+///
+/// Update an email template of the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/email_templates/{template_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("template_id" = String, Path, description = "The unique identifier for the email template")
+    ),
+    request_body = EmailTemplateUpdateRequest,
+    responses(
+        (status = 200, description = "Email Template Updated", body = EmailTemplateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Email Template not found")
+    ),
+    tag = "Profile",
+    operation_id = "Update an Email Template of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn email_template_update() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Email Template
+///
+/// Delete an email template of the *profile*. Emails of the template's type are no longer sent
+/// unless another template is configured
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/email_templates/{template_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("template_id" = String, Path, description = "The unique identifier for the email template")
+    ),
+    responses(
+        (status = 200, description = "Email Template Deleted", body = EmailTemplateDeleteResponse),
+        (status = 404, description = "Email Template not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete an Email Template of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn email_template_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Create Payment Template
+///
+/// Create a payment template of the *profile*, which can be referenced by `template_id` when
+/// creating a payment to fill in the fields not passed in the request
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_templates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = PaymentTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Payment Template Created", body = PaymentTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Create a Payment Template for the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_template_create() {}
+
+#[cfg(feature = "v1")]
+/// Profile - List Payment Templates
+///
+/// List all the payment templates of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_templates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Payment Templates Retrieved", body = Vec<PaymentTemplateResponse>)
+    ),
+    tag = "Profile",
+    operation_id = "List Payment Templates of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_templates_list() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Payment Template
+///
+/// Retrieve a payment template of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_templates/{template_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("template_id" = String, Path, description = "The unique identifier for the payment template")
+    ),
+    responses(
+        (status = 200, description = "Payment Template Retrieved", body = PaymentTemplateResponse),
+        (status = 404, description = "Payment Template not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve a Payment Template of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_template_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Update Payment Template
+///
+/// Update a payment template of the *profile*. Payments created before the update are not changed
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_templates/{template_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("template_id" = String, Path, description = "The unique identifier for the payment template")
+    ),
+    request_body = PaymentTemplateUpdateRequest,
+    responses(
+        (status = 200, description = "Payment Template Updated", body = PaymentTemplateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Payment Template not found")
+    ),
+    tag = "Profile",
+    operation_id = "Update a Payment Template of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_template_update() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Payment Template
+///
+/// Delete a payment template of the *profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_templates/{template_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("template_id" = String, Path, description = "The unique identifier for the payment template")
+    ),
+    responses(
+        (status = 200, description = "Payment Template Deleted", body = PaymentTemplateDeleteResponse),
+        (status = 404, description = "Payment Template not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete a Payment Template of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_template_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Ledger Balances
+///
+/// Retrieve the balances of the accounts of the ledger of the *profile*, in which the payments, refunds, payouts and chargebacks of the profile are recorded as balanced debits and credits
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/ledger/balances",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Ledger Balances Retrieved", body = LedgerBalancesResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Ledger Balances of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn ledger_balances_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Ledger Statement
+///
+/// Retrieve the statement of an account of the ledger of the *profile* in a currency, with its opening and closing balances and the entries posted to it in the period
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/ledger/statement",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("account" = LedgerAccount, Query, description = "The ledger account of the statement"),
+        ("currency" = Currency, Query, description = "The three letter ISO currency code of the statement"),
+        ("start_time" = Option<PrimitiveDateTime>, Query, description = "The start of the period of the statement, inclusive"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "The end of the period of the statement, exclusive"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of entries to return"),
+        ("offset" = Option<u32>, Query, description = "The number of entries to skip")
+    ),
+    responses(
+        (status = 200, description = "Ledger Statement Retrieved", body = LedgerStatementResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve a Ledger Statement of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn ledger_statement_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Override Payout Balance Rules
+///
+/// Allow a payout of the *profile* to be fulfilled regardless of the balance rules of the profile, even if the connector balance of the ledger of the profile does not cover the payout and the amount held back for it. The payout is not fulfilled by the override
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/ledger/payouts/{payout_id}/balance_override",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("payout_id" = String, Path, description = "The unique identifier for the payout")
+    ),
+    responses(
+        (status = 200, description = "Payout Balance Rules Overridden", body = PayoutBalanceOverrideResponse),
+        (status = 400, description = "Payout is in a terminal state"),
+        (status = 404, description = "Payout not found")
+    ),
+    tag = "Profile",
+    operation_id = "Override the Balance Rules of the Profile for a Payout",
+    security(("admin_api_key" = []))
+)]
+pub async fn payout_balance_override_create() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Revoke Payout Balance Rules Override
+///
+/// Revoke the override of the balance rules of the *profile* for a payout, so that the payout is fulfilled only if the connector balance of the ledger of the profile covers it
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/ledger/payouts/{payout_id}/balance_override",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("payout_id" = String, Path, description = "The unique identifier for the payout")
+    ),
+    responses(
+        (status = 200, description = "Payout Balance Rules Override Revoked", body = PayoutBalanceOverrideResponse),
+        (status = 400, description = "Payout is in a terminal state"),
+        (status = 404, description = "Payout not found")
+    ),
+    tag = "Profile",
+    operation_id = "Revoke the Override of the Balance Rules of the Profile for a Payout",
+    security(("admin_api_key" = []))
+)]
+pub async fn payout_balance_override_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Create Embed Token
+///
+/// Create a short lived token for a dashboard widget embedded in the portal of a platform, which can only call the routes of its scopes for the data of the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/embed_token",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = EmbedTokenCreateRequest,
+    responses(
+        (status = 200, description = "Embed Token Created", body = EmbedTokenResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Create an Embed Token for the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn embed_token_create() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Toggle Extended Card Info
+///
+/// Enable or disable the collection of extended card info for the payments of the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/toggle_extended_card_info",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = ExtendedCardInfoChoice,
+    responses(
+        (status = 200, description = "Extended Card Info Toggled", body = ExtendedCardInfoChoice),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Toggle Extended Card Info for the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn toggle_extended_card_info() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Toggle Connector Agnostic MIT
+///
+/// Enable or disable processing merchant initiated transactions of the *profile* through a
+/// connector other than the one the mandate was created with
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/toggle_connector_agnostic_mit",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = ConnectorAgnosticMitChoice,
+    responses(
+        (status = 200, description = "Connector Agnostic MIT Toggled", body = ConnectorAgnosticMitChoice),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Toggle Connector Agnostic MIT for the Profile",
+    security(("api_key" = []))
+)]
+pub async fn toggle_connector_agnostic_mit() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Webhook Secret
+///
+/// Retrieve the secret the outgoing webhooks of the *profile* are signed with
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Secret Retrieved", body = WebhookSecretResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Webhook Secret of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_secret_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Generate Webhook Secret
+///
+/// Replace the secret the outgoing webhooks of the *profile* are signed with by a newly generated
+/// secret. The previous secret is discarded immediately
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret/generate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Secret Generated", body = WebhookSecretResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Generate a Webhook Secret for the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_secret_generate() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Rotate Webhook Secret
+///
+/// Replace the secret the outgoing webhooks of the *profile* are signed with by a newly generated
+/// secret. The webhooks are signed with both the previous and the new secret for the overlap
+/// period, in the `X-Webhook-Signature-512-Previous` and `X-Webhook-Signature-512` headers
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret/rotate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = RotateWebhookSecretRequest,
+    responses(
+        (status = 200, description = "Webhook Secret Rotated", body = WebhookSecretResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Rotate the Webhook Secret of the Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_secret_rotate() {}
+
 // ******************************************** Common profile routes ******************************************** //
 
 /// Profile - Delete
@@ -120,6 +572,7 @@ pub async fn profile_delete() {}
     path = "/account/{account_id}/business_profile",
     params (
         ("account_id" = String, Path, description = "Merchant Identifier"),
+        ("tag" = Option<String>, Query, description = "Only list the business profiles tagged with this tag"),
     ),
     responses(
         (status = 200, description = "Profiles Retrieved", body = Vec<ProfileResponse>)
@@ -356,6 +809,7 @@ pub async fn routing_retrieve_default_config() {}
     path = "/v2/profiles/{id}/connector-accounts",
     params(
         ("id" = String, Path, description = "The unique identifier for the business profile"),
+        ("tag" = Option<String>, Query, description = "Only list the connector accounts tagged with this tag"),
         (
             "X-Merchant-Id" = String, Header,
             description = "Merchant ID of the profile.",
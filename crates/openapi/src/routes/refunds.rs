@@ -54,7 +54,8 @@ pub async fn refunds_create() {}
     get,
     path = "/refunds/{refund_id}",
     params(
-        ("refund_id" = String, Path, description = "The identifier for refund")
+        ("refund_id" = String, Path, description = "The identifier for refund"),
+        ("expand" = Option<String>, Query, description = "The comma separated list of related objects to be expanded in the response")
     ),
     responses(
         (status = 200, description = "Refund retrieved", body = RefundResponse),
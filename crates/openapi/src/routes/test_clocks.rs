@@ -0,0 +1,129 @@
+/// Test Clocks - Create
+///
+/// Creates a test clock frozen at the given time. Once attached to the merchant, advancing the clock runs the scheduled captures, installment charges and expiries of payment links, payout links and vouchers of the merchant which fall due before the time of the clock. Only available in the sandbox environment
+#[utoipa::path(
+    post,
+    path = "/test_clocks",
+    request_body = TestClockCreateRequest,
+    responses(
+        (status = 200, description = "Test clock created", body = TestClockResponse),
+        (status = 400, description = "Test clocks are not supported in this environment")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Create a Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn test_clock_create() {}
+
+/// Test Clocks - Retrieve
+///
+/// Retrieves a test clock
+#[utoipa::path(
+    get,
+    path = "/test_clocks/{clock_id}",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    responses(
+        (status = 200, description = "Test clock retrieved", body = TestClockResponse),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Retrieve a Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn test_clock_retrieve() {}
+
+/// Test Clocks - List
+///
+/// Lists the test clocks of the merchant
+#[utoipa::path(
+    get,
+    path = "/test_clocks",
+    responses(
+        (status = 200, description = "Test clocks retrieved", body = TestClockListResponse)
+    ),
+    tag = "Test Clocks",
+    operation_id = "List all Test Clocks",
+    security(("api_key" = []))
+)]
+pub async fn test_clocks_list() {}
+
+/// Test Clocks - Attach
+///
+/// Attaches the test clock to the merchant, replacing the clock attached previously. The scheduled captures, installment charges and expiries of the merchant which fall due before the time of the clock are run right away
+#[utoipa::path(
+    post,
+    path = "/test_clocks/{clock_id}/attach",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    responses(
+        (status = 200, description = "Test clock attached", body = TestClockResponse),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Attach a Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn test_clock_attach() {}
+
+/// Test Clocks - Detach
+///
+/// Detaches the test clock from the merchant. Tasks which have already been run are not reverted
+#[utoipa::path(
+    post,
+    path = "/test_clocks/{clock_id}/detach",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    responses(
+        (status = 200, description = "Test clock detached", body = TestClockResponse),
+        (status = 404, description = "Test clock not found"),
+        (status = 412, description = "Test clock is not attached to the merchant")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Detach a Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn test_clock_detach() {}
+
+/// Test Clocks - Advance
+///
+/// Advances the time of the test clock. If the clock is attached to the merchant, the scheduled captures, installment charges and expiries of the merchant which fall due before the new time of the clock are run right away
+#[utoipa::path(
+    post,
+    path = "/test_clocks/{clock_id}/advance",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    request_body = TestClockAdvanceRequest,
+    responses(
+        (status = 200, description = "Test clock advanced", body = TestClockResponse),
+        (status = 400, description = "The new time is not later than the current time of the clock"),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Advance a Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn test_clock_advance() {}
+
+/// Test Clocks - Delete
+///
+/// Deletes the test clock, detaching it from the merchant if attached
+#[utoipa::path(
+    delete,
+    path = "/test_clocks/{clock_id}",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    responses(
+        (status = 200, description = "Test clock deleted", body = TestClockDeleteResponse),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Delete a Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn test_clock_delete() {}
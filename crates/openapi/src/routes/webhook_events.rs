@@ -97,3 +97,28 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Preview Payload
+///
+/// Preview the outgoing webhook payload of the specified Event with a specific payload version.
+/// The payload is built from the current state of the object the Event corresponds to.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/{event_id}/preview",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        (
+            "payload_version" = Option<WebhookPayloadVersion>,
+            Query,
+            description = "The version of the payload schema to preview. Defaults to the version configured for the business profile."
+        ),
+    ),
+    responses(
+        (status = 200, description = "The webhook payload of the Event was built successfully", body = WebhookPayloadPreviewResponse),
+    ),
+    tag = "Event",
+    operation_id = "Preview the webhook payload of an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn preview_webhook_payload() {}
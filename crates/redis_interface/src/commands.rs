@@ -13,7 +13,10 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -29,6 +32,21 @@ use crate::{
     types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SaddReply, SetnxReply},
 };
 
+/// Sets the field of the hash if it is not set, or if the version in the JSON value stored in the
+/// field matches. KEYS[1] is the hash, ARGV holds the field, the version field, the expected
+/// version and the value.
+const SET_HASH_FIELD_IF_VERSION_MATCHES_SCRIPT: &str = r#"
+local current = redis.call('HGET', KEYS[1], ARGV[1])
+if current then
+    local version = cjson.decode(current)[ARGV[2]] or 0
+    if version ~= tonumber(ARGV[3]) then
+        return 0
+    end
+end
+redis.call('HSET', KEYS[1], ARGV[1], ARGV[4])
+return 1
+"#;
+
 impl super::RedisConnectionPool {
     pub fn add_prefix(&self, key: &str) -> String {
         if self.key_prefix.is_empty() {
@@ -299,6 +317,14 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_ttl(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .ttl(self.add_prefix(key))
+            .await
+            .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_fields<V>(
         &self,
@@ -350,6 +376,42 @@ impl super::RedisConnectionPool {
             .await
     }
 
+    /// Sets the field of the hash to the value only if the field is not set, or if the JSON value
+    /// stored in the field has the expected version, returning whether the field was set. The
+    /// version is compared and the field is set atomically, so that a concurrent update of the
+    /// field is never lost.
+    #[instrument(level = "DEBUG", skip(self, value))]
+    pub async fn set_hash_field_if_version_matches(
+        &self,
+        key: &str,
+        field: &str,
+        value: String,
+        version_field: &str,
+        expected_version: i64,
+        ttl: Option<u32>,
+    ) -> CustomResult<bool, errors::RedisError> {
+        let is_set: i64 = self
+            .pool
+            .eval(
+                SET_HASH_FIELD_IF_VERSION_MATCHES_SCRIPT,
+                vec![self.add_prefix(key)],
+                vec![
+                    field.to_string(),
+                    version_field.to_string(),
+                    expected_version.to_string(),
+                    value,
+                ],
+            )
+            .await
+            .change_context(errors::RedisError::SetHashFieldFailed)?;
+
+        if is_set == 1 {
+            self.set_expiry(key, ttl.unwrap_or(self.config.default_hash_ttl).into())
+                .await?;
+        }
+        Ok(is_set == 1)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn serialize_and_set_hash_field_if_not_exist<V>(
         &self,
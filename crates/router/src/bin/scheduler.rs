@@ -329,6 +329,81 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::LockerMigrationWorkflow => Ok(Box::new(
+                    workflows::locker_migration::LockerMigrationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentArchivalWorkflow => Ok(Box::new(
+                    workflows::payment_archival::PaymentArchivalWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DataRetentionWorkflow => {
+                    Ok(Box::new(workflows::data_retention::DataRetentionWorkflow))
+                }
+                storage::ProcessTrackerRunner::PaymentsAutoCaptureWorkflow => Ok(Box::new(
+                    workflows::payments_auto_capture::PaymentsAutoCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::InstallmentPlanChargeWorkflow => Ok(Box::new(
+                    workflows::installment_plan_charge::InstallmentPlanChargeWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentLinkExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_link_expiry::PaymentLinkExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutRecipientKycStatusWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::payout_recipient_kyc_status::PayoutRecipientKycStatusWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(
+                            error_stack::report!(ProcessTrackerError::UnexpectedFlow),
+                        )
+                        .attach_printable(
+                            "Cannot run payout recipient KYC status workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutFulfillmentWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::payout_fulfillment::PayoutFulfillmentWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(
+                            error_stack::report!(ProcessTrackerError::UnexpectedFlow),
+                        )
+                        .attach_printable(
+                            "Cannot run payout fulfillment workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutLinkExpiryWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::payout_link_expiry::PayoutLinkExpiryWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(
+                            error_stack::report!(ProcessTrackerError::UnexpectedFlow),
+                        )
+                        .attach_printable(
+                            "Cannot run payout link expiry workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow => Ok(Box::new(
+                    workflows::connector_credentials_expiry::ConnectorCredentialsExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::VoucherExpiryWorkflow => {
+                    Ok(Box::new(workflows::voucher_expiry::VoucherExpiryWorkflow))
+                }
             }
         };
 
@@ -74,7 +74,10 @@ pub async fn customer_retrieve(
     req: HttpRequest,
     path: web::Path<id_type::CustomerId>,
 ) -> HttpResponse {
-    let payload = customer_types::CustomerId::new_customer_id_struct(path.into_inner());
+    let payload = customer_types::CustomerRetrieveRequest {
+        customer_id: path.into_inner(),
+        expand: None,
+    };
 
     let flow = Flow::CustomersRetrieve;
 
@@ -16,7 +16,9 @@ use crate::{
 };
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::{
-    core::api_locking::GetLockingInput, logger, routes::payments::get_or_generate_payment_id,
+    core::api_locking::GetLockingInput,
+    logger,
+    routes::payments::{get_or_generate_payment_id, set_payment_id_for_merchant},
     types::api as api_types,
 };
 
@@ -52,6 +54,7 @@ pub async fn payment_intents_create(
         Err(err) => return api::log_and_return_error_response(err),
     };
 
+    let is_payment_id_generated = create_payment_req.payment_id.is_none();
     if let Err(err) = get_or_generate_payment_id(&mut create_payment_req) {
         return api::log_and_return_error_response(err);
     }
@@ -71,7 +74,10 @@ pub async fn payment_intents_create(
         state.into_inner(),
         &req,
         create_payment_req,
-        |state, auth: auth::AuthenticationData, req, req_state| {
+        |state, auth: auth::AuthenticationData, mut req, req_state| {
+            if is_payment_id_generated {
+                set_payment_id_for_merchant(&mut req, &auth.merchant_account);
+            }
             let eligible_connectors = req.connector.clone();
             payments::payments_core::<
                 api_types::Authorize,
@@ -118,6 +124,7 @@ pub async fn payment_intents_retrieve(
         client_secret: query_payload.client_secret.clone(),
         expand_attempts: None,
         expand_captures: None,
+        expand: None,
     };
 
     let (auth_type, auth_flow) =
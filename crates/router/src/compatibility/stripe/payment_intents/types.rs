@@ -778,6 +778,8 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                         ip_address: Some(online.ip_address),
                         user_agent: online.user_agent,
                     }),
+                consent_text_version: None,
+                signed_mandate_file_id: None,
             }),
             update_mandate_id: None,
         });
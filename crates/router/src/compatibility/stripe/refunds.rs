@@ -118,6 +118,7 @@ pub async fn refund_retrieve(
         refund_id: path.into_inner(),
         force_sync: Some(true),
         merchant_connector_details: None,
+        expand: None,
     };
 
     let flow = Flow::RefundsRetrieveForceSync;
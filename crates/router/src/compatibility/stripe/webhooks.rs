@@ -12,7 +12,8 @@ use router_env::logger;
 use serde::Serialize;
 
 use super::{
-    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    customers::types::PaymentMethodData, payment_intents::types::StripePaymentIntentResponse,
+    refunds::types::StripeRefundResponse,
 };
 use crate::{
     core::{
@@ -76,6 +77,30 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             signature.into(),
         ))
     }
+
+    fn add_previous_secret_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        // Stripe lists the signatures computed with each of the active secrets in the same header
+        let previous_v1 = signature
+            .split(',')
+            .find_map(|element| element.strip_prefix("v1="))
+            .map(ToOwned::to_owned);
+        let existing_header = header
+            .iter_mut()
+            .find(|(name, _)| name == headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE);
+
+        match (existing_header, previous_v1) {
+            (Some((_, value)), Some(previous_v1)) => {
+                *value = format!("{},v1={previous_v1}", value.clone().into_inner()).into();
+            }
+            _ => header.push((
+                headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
+                signature.into(),
+            )),
+        }
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -87,6 +112,9 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    PaymentMethod(PaymentMethodData),
+    #[cfg(feature = "v1")]
+    ConnectorCredentialsExpiry(Box<api_models::admin::ExpiringConnectorCredentialsResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -296,6 +324,11 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PaymentMethodCollected => "payment_method.attached",
+        api_models::enums::EventType::PaymentLinkAbandoned => "checkout.session.expired",
+        api_models::enums::EventType::PayoutRecipientKycUpdated => "account.updated",
+        api_models::enums::EventType::PayoutLinkExpired => "payout.canceled",
+        api_models::enums::EventType::ConnectorCredentialsExpiring => "account.updated",
     }
 }
 
@@ -338,6 +371,14 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::ConnectorCredentialsExpiryDetails(credentials_expiry) => {
+                Self::ConnectorCredentialsExpiry(credentials_expiry)
+            }
         }
     }
 }
@@ -108,6 +108,28 @@ where
                 ),
             }
         }
+        Ok(api::ApplicationResponse::JsonAccepted(response)) => {
+            let response = S::try_from(response);
+            match response {
+                Ok(response) => match serde_json::to_string(&response) {
+                    Ok(res) => api::http_response_json_accepted(res),
+                    Err(_) => api::http_response_err(
+                        r#"{
+                                "error": {
+                                    "message": "Error serializing response from connector"
+                                }
+                            }"#,
+                    ),
+                },
+                Err(_) => api::http_response_err(
+                    r#"{
+                        "error": {
+                            "message": "Error converting juspay response to stripe response"
+                        }
+                    }"#,
+                ),
+            }
+        }
         Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
         Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
@@ -132,6 +154,7 @@ where
                 redirection_data.payment_method_data,
                 redirection_data.amount,
                 redirection_data.currency,
+                redirection_data.page_config,
                 config,
             )
             .respond_to(request)
@@ -76,6 +76,8 @@ impl Default for super::settings::Locker {
             //Time to live for storage entries in locker
             ttl_for_storage_in_secs: 60 * 60 * 24 * 365 * 7,
             decryption_scheme: Default::default(),
+            //Store cards in the redis temp locker if the locker is unreachable
+            outage_fallback_enabled: false,
         }
     }
 }
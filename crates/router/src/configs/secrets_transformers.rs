@@ -343,6 +343,27 @@ impl SecretsHandler for settings::NetworkTokenizationService {
     }
 }
 
+#[cfg(feature = "payouts")]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ConfirmationOfPayeeService {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let confirmation_of_payee_service = value.get_inner();
+        let api_key = secret_management_client
+            .get_secret(confirmation_of_payee_service.api_key.clone())
+            .await?;
+
+        Ok(
+            value.transition_state(|confirmation_of_payee_service| Self {
+                api_key,
+                ..confirmation_of_payee_service
+            }),
+        )
+    }
+}
+
 /// # Panics
 ///
 /// Will panic even if kms decryption fails for at least one field
@@ -465,9 +486,25 @@ pub(crate) async fn fetch_raw_secrets(
         })
         .await;
 
+    #[cfg(feature = "payouts")]
+    #[allow(clippy::expect_used)]
+    let confirmation_of_payee_service = conf
+        .confirmation_of_payee_service
+        .async_map(|confirmation_of_payee_service| async {
+            settings::ConfirmationOfPayeeService::convert_to_raw_secret(
+                confirmation_of_payee_service,
+                secret_management_client,
+            )
+            .await
+            .expect("Failed to decrypt confirmation of payee service configs")
+        })
+        .await;
+
     Settings {
         server: conf.server,
         master_database,
+        conflict_retry: conf.conflict_retry,
+        partitioning: conf.partitioning,
         redis: conf.redis,
         log: conf.log,
         #[cfg(feature = "kv_store")]
@@ -493,6 +530,8 @@ pub(crate) async fn fetch_raw_secrets(
         bank_config: conf.bank_config,
         api_keys,
         file_storage: conf.file_storage,
+        files: conf.files,
+        messaging: conf.messaging,
         tokenization: conf.tokenization,
         connector_customer: conf.connector_customer,
         #[cfg(feature = "dummy_connector")]
@@ -505,16 +544,27 @@ pub(crate) async fn fetch_raw_secrets(
             .network_transaction_id_supported_connectors,
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
+        session_token_cache: conf.session_token_cache,
+        bank_list_cache: conf.bank_list_cache,
         webhook_source_verification_call: conf.webhook_source_verification_call,
         payment_method_auth,
         connector_request_reference_id_config: conf.connector_request_reference_id_config,
         #[cfg(feature = "payouts")]
         payouts: conf.payouts,
+        #[cfg(feature = "payouts")]
+        confirmation_of_payee_service,
         applepay_decrypt_keys,
         paze_decrypt_keys,
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        request_timeout_budget: conf.request_timeout_budget,
+        connector_slo: conf.connector_slo,
+        connector_credentials_expiry: conf.connector_credentials_expiry,
+        merchant_metrics_labels: conf.merchant_metrics_labels,
+        payload_logging: conf.payload_logging,
+        traffic_mirroring: conf.traffic_mirroring,
+        dcc: conf.dcc,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
@@ -18,6 +18,7 @@ use external_services::{
         encryption_management::EncryptionManagementConfig,
         secrets_management::SecretsManagementConfig,
     },
+    messaging::MessagingConfig,
 };
 pub use hyperswitch_interfaces::configs::Connectors;
 use hyperswitch_interfaces::secrets_interface::secret_state::{
@@ -29,7 +30,7 @@ pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use rust_decimal::Decimal;
 use scheduler::SchedulerSettings;
 use serde::Deserialize;
-use storage_impl::config::QueueStrategy;
+use storage_impl::config::{ConflictRetryConfig, PartitioningConfig, QueueStrategy};
 
 #[cfg(feature = "olap")]
 use crate::analytics::AnalyticsConfig;
@@ -57,6 +58,8 @@ pub struct Settings<S: SecretState> {
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
+    pub conflict_retry: ConflictRetryConfig,
+    pub partitioning: PartitioningConfig,
     pub redis: RedisSettings,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
@@ -75,6 +78,8 @@ pub struct Settings<S: SecretState> {
     pub bank_config: BankRedirectConfig,
     pub api_keys: SecretStateContainer<ApiKeys, S>,
     pub file_storage: FileStorageConfig,
+    pub files: FilesConfig,
+    pub messaging: MessagingConfig,
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub tokenization: TokenizationConfig,
@@ -89,17 +94,28 @@ pub struct Settings<S: SecretState> {
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
+    pub session_token_cache: SessionTokenCacheConfig,
+    pub bank_list_cache: BankListCacheConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
     #[cfg(feature = "payouts")]
     pub payouts: Payouts,
+    #[cfg(feature = "payouts")]
+    pub confirmation_of_payee_service: Option<SecretStateContainer<ConfirmationOfPayeeService, S>>,
     pub payout_method_filters: ConnectorFilters,
     pub applepay_decrypt_keys: SecretStateContainer<ApplePayDecryptConfig, S>,
     pub paze_decrypt_keys: Option<SecretStateContainer<PazeDecryptConfig, S>>,
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub request_timeout_budget: RequestTimeoutBudget,
+    pub connector_slo: ConnectorSloConfig,
+    pub connector_credentials_expiry: ConnectorCredentialsExpiryConfig,
+    pub merchant_metrics_labels: MerchantMetricsLabelsConfig,
+    pub payload_logging: PayloadLoggingConfig,
+    pub traffic_mirroring: TrafficMirroring,
+    pub dcc: DccConfig,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -235,6 +251,7 @@ pub struct KeyManagerConfig {
 pub struct GenericLink {
     pub payment_method_collect: GenericLinkEnvConfig,
     pub payout_link: GenericLinkEnvConfig,
+    pub customer_portal: CustomerPortalLinkEnvConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -259,6 +276,21 @@ impl Default for GenericLinkEnvConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomerPortalLinkEnvConfig {
+    pub expiry: u32,
+    pub ui_config: GenericLinkEnvUiConfig,
+}
+
+impl Default for CustomerPortalLinkEnvConfig {
+    fn default() -> Self {
+        Self {
+            expiry: 900,
+            ui_config: GenericLinkEnvUiConfig::default(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct GenericLinkEnvUiConfig {
     pub logo: url::Url,
@@ -572,6 +604,7 @@ pub struct Locker {
     pub locker_enabled: bool,
     pub ttl_for_storage_in_secs: i64,
     pub decryption_scheme: DecryptionScheme,
+    pub outage_fallback_enabled: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -716,6 +749,289 @@ pub struct DelayedSessionConfig {
     pub connectors_with_delayed_session_response: HashSet<enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SessionTokenCacheConfig {
+    /// Whether wallet session objects are cached in redis
+    pub enabled: bool,
+    /// Upper bound on how long an Apple Pay merchant session is reused, in seconds
+    pub apple_pay_merchant_session_ttl_in_secs: i64,
+}
+
+impl Default for SessionTokenCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // Apple Pay merchant sessions are valid for five minutes
+            apple_pay_merchant_session_ttl_in_secs: 240,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FilesConfig {
+    /// Maximum size of a file uploaded through the files API, in bytes
+    pub max_file_size_in_bytes: i32,
+    /// Content types of files which can be uploaded through the files API
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub allowed_file_types: HashSet<String>,
+    /// Validity of the download URLs issued for files, in seconds
+    pub download_url_expiry_in_secs: u32,
+}
+
+impl Default for FilesConfig {
+    fn default() -> Self {
+        Self {
+            max_file_size_in_bytes: 10 * 1024 * 1024,
+            allowed_file_types: HashSet::from([
+                mime::APPLICATION_PDF.to_string(),
+                mime::IMAGE_JPEG.to_string(),
+                mime::IMAGE_PNG.to_string(),
+            ]),
+            download_url_expiry_in_secs: 300,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BankListCacheConfig {
+    /// Whether the banks of bank redirect payment methods are fetched from the connectors which
+    /// support it and cached in redis, instead of being read from the bank config alone
+    pub enabled: bool,
+    /// How long a bank list fetched from a connector is cached, in seconds
+    pub default_ttl_in_secs: i64,
+    /// Overrides of the cache duration for specific connectors, in seconds
+    pub connector_ttls: HashMap<enums::Connector, i64>,
+}
+
+impl BankListCacheConfig {
+    pub fn get_ttl_in_secs(&self, connector: enums::Connector) -> i64 {
+        self.connector_ttls
+            .get(&connector)
+            .copied()
+            .unwrap_or(self.default_ttl_in_secs)
+    }
+}
+
+impl Default for BankListCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // Issuer lists change rarely, so they are refreshed once a day
+            default_ttl_in_secs: 86400,
+            connector_ttls: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RequestTimeoutBudget {
+    /// Whether a deadline is set for each request, which bounds the calls made to the connectors
+    pub enabled: bool,
+    /// The time within which a request is expected to be served, in milliseconds
+    pub budget_in_millis: u64,
+    /// The time kept aside from the budget for recording the outcome of a connector call, in
+    /// milliseconds
+    pub post_connector_call_reserve_in_millis: u64,
+    /// The least time a connector call is allowed, in milliseconds. The connector is not called
+    /// when less time is left in the budget.
+    pub min_connector_call_budget_in_millis: u64,
+}
+
+impl Default for RequestTimeoutBudget {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            budget_in_millis: 30000,
+            post_connector_call_reserve_in_millis: 3000,
+            min_connector_call_budget_in_millis: 2000,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorSloConfig {
+    /// Whether the latency and error rate of the connector calls are evaluated against the
+    /// thresholds, raising an alert when they are breached
+    pub enabled: bool,
+    /// The duration over which the connector calls are evaluated, in seconds
+    pub evaluation_window_in_secs: u64,
+    /// The least number of calls to a connector flow within the window for it to be evaluated
+    pub min_sample_size: usize,
+    /// The time for which further alerts are not raised for a connector flow once an alert is
+    /// raised, in seconds
+    pub alert_cooldown_in_secs: u64,
+    /// The URL of the operators to which the alerts are posted, the alerts are only logged if not
+    /// present
+    pub alert_webhook_url: Option<String>,
+    pub authorize: Option<ConnectorFlowSloThresholds>,
+    pub capture: Option<ConnectorFlowSloThresholds>,
+    pub refund: Option<ConnectorFlowSloThresholds>,
+    pub payout_create: Option<ConnectorFlowSloThresholds>,
+}
+
+impl Default for ConnectorSloConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            evaluation_window_in_secs: 300,
+            min_sample_size: 50,
+            alert_cooldown_in_secs: 900,
+            alert_webhook_url: None,
+            authorize: None,
+            capture: None,
+            refund: None,
+            payout_create: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCredentialsExpiryConfig {
+    /// The number of days before the expiry of a credential of a connector account on which the
+    /// merchant is alerted
+    pub reminder_days: Vec<u8>,
+}
+
+impl Default for ConnectorCredentialsExpiryConfig {
+    fn default() -> Self {
+        Self {
+            reminder_days: vec![30, 7, 1],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MerchantMetricsLabelsConfig {
+    /// Whether the request and connector call metrics are labelled by the merchant and the
+    /// profile they were made for
+    pub enabled: bool,
+    /// The most merchants labelled by an instance, the metrics of the merchants seen after the
+    /// limit is reached are labelled as `other`
+    pub max_merchants: usize,
+    /// The most profiles labelled by an instance, the metrics of the profiles seen after the limit
+    /// is reached are labelled as `other`
+    pub max_profiles: usize,
+    /// The merchants which are always labelled, regardless of the limit
+    pub merchant_ids: HashSet<id_type::MerchantId>,
+}
+
+impl Default for MerchantMetricsLabelsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_merchants: 100,
+            max_profiles: 500,
+            merchant_ids: HashSet::new(),
+        }
+    }
+}
+
+/// The service level objectives of a connector flow. Only the configured thresholds are evaluated.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorFlowSloThresholds {
+    pub p50_latency_in_millis: Option<u64>,
+    pub p95_latency_in_millis: Option<u64>,
+    pub p99_latency_in_millis: Option<u64>,
+    /// The highest fraction of calls which may fail with a server error or without a response,
+    /// between 0 and 1
+    pub max_error_rate: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PayloadLoggingConfig {
+    /// Whether the redacted payloads of the requests made to the admin and payment APIs are
+    /// logged, for them to be retrieved when debugging
+    pub enabled: bool,
+    /// The duration for which the payloads of a request are retained, in seconds
+    pub retention_in_secs: i64,
+    /// The most requests of a merchant whose payloads are retained, the payloads of the oldest
+    /// requests are dropped once the limit is reached
+    pub max_entries_per_merchant: usize,
+}
+
+impl Default for PayloadLoggingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_in_secs: 259200,
+            max_entries_per_merchant: 1000,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TrafficMirroring {
+    /// Whether a sample of the read only requests is duplicated to a canary deployment, whose
+    /// responses are compared with the responses served
+    pub enabled: bool,
+    /// The base URL of the canary deployment the requests are duplicated to
+    pub canary_base_url: Option<String>,
+    /// The percentage of the eligible requests which are duplicated, between 0 and 100
+    pub sample_percentage: f64,
+    /// The time the canary deployment is given to respond to a duplicated request, in seconds
+    pub timeout_in_secs: u64,
+    /// The route patterns of the `GET` endpoints which only read data without any side effects,
+    /// and are duplicated. Requests which sync with the connector are never duplicated.
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub read_only_get_routes: HashSet<String>,
+    /// The route patterns of the `POST` endpoints which only validate the request or read data
+    /// without any side effects, and are duplicated along with the `GET` requests
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub read_only_post_routes: HashSet<String>,
+    /// Whether the canary deployment is trusted with the credentials of the requests. The
+    /// authorization, api key and cookie headers are not forwarded to an untrusted canary.
+    pub trusted_canary: bool,
+    /// The fields of the responses which are expected to differ between the deployments, and are
+    /// not compared
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub ignored_response_fields: HashSet<String>,
+}
+
+impl Default for TrafficMirroring {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            canary_base_url: None,
+            sample_percentage: 0.0,
+            timeout_in_secs: 5,
+            read_only_get_routes: HashSet::new(),
+            read_only_post_routes: HashSet::new(),
+            trusted_canary: false,
+            ignored_response_fields: HashSet::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DccConfig {
+    /// The connectors which apply the dynamic currency conversion offered to the customer at
+    /// authorization
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+    /// The duration for which a dynamic currency conversion offer can be accepted, in seconds
+    pub offer_validity_in_secs: i64,
+}
+
+impl Default for DccConfig {
+    fn default() -> Self {
+        Self {
+            connector_list: HashSet::new(),
+            offer_validity_in_secs: 900,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct WebhookSourceVerificationCall {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -853,7 +1169,18 @@ impl Settings<SecuredSecret> {
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
+        self.messaging
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+
         self.lock_settings.validate()?;
+        self.request_timeout_budget.validate()?;
+        self.connector_slo.validate()?;
+        self.connector_credentials_expiry.validate()?;
+        self.merchant_metrics_labels.validate()?;
+        self.payload_logging.validate()?;
+        self.traffic_mirroring.validate()?;
+        self.dcc.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -876,6 +1203,12 @@ impl Settings<SecuredSecret> {
             .map(|x| x.get_inner().validate())
             .transpose()?;
 
+        #[cfg(feature = "payouts")]
+        self.confirmation_of_payee_service
+            .as_ref()
+            .map(|x| x.get_inner().validate())
+            .transpose()?;
+
         self.paze_decrypt_keys
             .as_ref()
             .map(|x| x.get_inner().validate())
@@ -915,6 +1248,15 @@ pub struct Payouts {
     pub required_fields: PayoutRequiredFields,
 }
 
+/// The Confirmation of Payee service which verifies the name of the holder of the UK and EU bank
+/// accounts to which the payouts are disbursed
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConfirmationOfPayeeService {
+    pub verify_account_name_url: url::Url,
+    pub api_key: Secret<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LockSettings {
     pub redis_lock_expiry_seconds: u32,
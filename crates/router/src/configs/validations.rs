@@ -181,6 +181,134 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::RequestTimeoutBudget {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled
+                && self.budget_in_millis
+                    <= self.post_connector_call_reserve_in_millis
+                        + self.min_connector_call_budget_in_millis,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "request timeout budget must be greater than the post connector call reserve and the minimum connector call budget together".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorCredentialsExpiryConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.reminder_days.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector credentials expiry reminder days must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::MerchantMetricsLabelsConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.enabled && (self.max_merchants == 0 || self.max_profiles == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "merchant metrics labels maximum merchants and profiles must not be 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::PayloadLoggingConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.enabled && (self.retention_in_secs <= 0 || self.max_entries_per_merchant == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "payload logging retention and maximum entries per merchant must be positive"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::DccConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.offer_validity_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dcc offer validity must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorSloConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && (self.evaluation_window_in_secs == 0 || self.min_sample_size == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector SLO evaluation window and minimum sample size must not be 0".into(),
+                ))
+            },
+        )?;
+
+        [
+            &self.authorize,
+            &self.capture,
+            &self.refund,
+            &self.payout_create,
+        ]
+        .into_iter()
+        .flatten()
+        .try_for_each(|thresholds| {
+            when(
+                thresholds
+                    .max_error_rate
+                    .is_some_and(|max_error_rate| !(0.0..=1.0).contains(&max_error_rate)),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "connector SLO max_error_rate must be between 0 and 1".into(),
+                    ))
+                },
+            )
+        })
+    }
+}
+
+impl super::settings::TrafficMirroring {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled
+                && self
+                    .canary_base_url
+                    .as_ref()
+                    .map_or(true, |canary_base_url| {
+                        canary_base_url.is_default_or_empty()
+                    }),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "canary_base_url must be set when traffic mirroring is enabled".into(),
+                ))
+            },
+        )?;
+
+        when(!(0.0..=100.0).contains(&self.sample_percentage), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "traffic mirroring sample_percentage must be between 0 and 100".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -206,6 +334,19 @@ impl super::settings::CellInformation {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl super::settings::ConfirmationOfPayeeService {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.api_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "api_key of the confirmation of payee service must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::NetworkTokenizationService {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
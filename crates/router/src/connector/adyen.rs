@@ -4,6 +4,7 @@ use base64::Engine;
 use common_utils::{
     request::RequestContent,
     types::{AmountConvertor, MinorUnit, MinorUnitForConnector},
+    validation::StatementDescriptorRules,
 };
 use diesel_models::{enums as storage_enums, enums};
 use error_stack::{report, ResultExt};
@@ -219,6 +220,7 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::Becs
                 | PaymentMethodType::ClassicReward
                 | PaymentMethodType::Pse
+                | PaymentMethodType::VirtualAccount
                 | PaymentMethodType::LocalBankTransfer
                 | PaymentMethodType::Efecty
                 | PaymentMethodType::PagoEfectivo
@@ -296,6 +298,32 @@ impl ConnectorValidation for Adyen {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         true
     }
+
+    fn validate_statement_descriptor(
+        &self,
+        statement_descriptor: Option<&str>,
+        statement_descriptor_suffix: Option<&str>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // The statement descriptor is sent as the `shopperStatement`, which Adyen truncates for
+        // card payments according to the rules of the card scheme
+        fn is_allowed_character(character: char) -> bool {
+            character.is_ascii_alphanumeric()
+                || matches!(
+                    character,
+                    ' ' | '.' | ',' | '\'' | '_' | '-' | '?' | '+' | '*' | '/'
+                )
+        }
+        hyperswitch_interfaces::api::validate_statement_descriptor_against_rules(
+            statement_descriptor,
+            statement_descriptor_suffix,
+            &StatementDescriptorRules {
+                min_length: 1,
+                max_length: 135,
+                is_allowed_character,
+            },
+            &StatementDescriptorRules::CARD_SCHEME,
+        )
+    }
 }
 
 impl api::Payment for Adyen {}
@@ -2260,6 +2288,11 @@ impl api::FileUpload for Adyen {
                     })?
                 }
             }
+            api::FilePurpose::MandateEvidence => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "mandate evidence is not uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
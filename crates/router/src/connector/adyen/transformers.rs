@@ -2539,6 +2539,7 @@ impl
             | domain::BankTransferData::SepaBankTransfer { .. }
             | domain::BankTransferData::BacsBankTransfer { .. }
             | domain::BankTransferData::MultibancoBankTransfer { .. }
+            | domain::BankTransferData::VirtualAccountBankTransfer {}
             | domain::BankTransferData::LocalBankTransfer { .. }
             | domain::BankTransferData::Pse {} => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("Adyen"),
@@ -3931,6 +3932,7 @@ pub fn get_present_to_shopper_metadata(
         | PaymentType::Indomaret
         | PaymentType::BoletoBancario
         | PaymentType::Oxxo
+        | PaymentType::SevenEleven
         | PaymentType::Lawson
         | PaymentType::MiniStop
         | PaymentType::FamilyMart
@@ -4016,7 +4018,6 @@ pub fn get_present_to_shopper_metadata(
         | PaymentType::Vipps
         | PaymentType::Swish
         | PaymentType::PaySafeCard
-        | PaymentType::SevenEleven
         | PaymentType::Pix => Ok(None),
     }
 }
@@ -4294,6 +4295,8 @@ pub enum WebhookEventCode {
     SecondChargeback,
     PrearbitrationWon,
     PrearbitrationLost,
+    /// Sent when a payment offer, such as a voucher, expires before the shopper pays it
+    OfferClosed,
     #[cfg(feature = "payouts")]
     PayoutThirdparty,
     #[cfg(feature = "payouts")]
@@ -4307,7 +4310,10 @@ pub enum WebhookEventCode {
 }
 
 pub fn is_transaction_event(event_code: &WebhookEventCode) -> bool {
-    matches!(event_code, WebhookEventCode::Authorisation)
+    matches!(
+        event_code,
+        WebhookEventCode::Authorisation | WebhookEventCode::OfferClosed
+    )
 }
 
 pub fn is_capture_or_cancel_event(event_code: &WebhookEventCode) -> bool {
@@ -4370,6 +4376,7 @@ impl ForeignFrom<(WebhookEventCode, String, Option<DisputeStatus>)>
                     Self::PaymentIntentFailure
                 }
             }
+            WebhookEventCode::OfferClosed => Self::PaymentIntentFailure,
             WebhookEventCode::Refund | WebhookEventCode::CancelOrRefund => {
                 if is_success_scenario(is_success) {
                     Self::RefundSuccess
@@ -4515,6 +4522,7 @@ impl From<AdyenNotificationRequestItemWH> for AdyenWebhookResponse {
                 #[cfg(feature = "payouts")]
                 WebhookEventCode::PayoutReversed => AdyenWebhookStatus::Reversed,
                 WebhookEventCode::CaptureFailed => AdyenWebhookStatus::CaptureFailed,
+                WebhookEventCode::OfferClosed => AdyenWebhookStatus::AuthorisationFailed,
                 WebhookEventCode::CancelOrRefund
                 | WebhookEventCode::Refund
                 | WebhookEventCode::RefundFailed
@@ -5027,6 +5035,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
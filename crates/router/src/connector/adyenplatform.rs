@@ -16,7 +16,7 @@ use http::HeaderName;
 use hyperswitch_interfaces::webhooks::IncomingWebhookFlowError;
 use masking::Maskable;
 #[cfg(feature = "payouts")]
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 #[cfg(feature = "payouts")]
 use ring::hmac;
 #[cfg(feature = "payouts")]
@@ -291,6 +291,89 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
     }
 }
 
+#[cfg(feature = "payouts")]
+impl api::PayoutBalance for Adyenplatform {}
+
+#[cfg(feature = "payouts")]
+impl
+    services::ConnectorIntegration<
+        api::PoBalance,
+        types::PayoutBalanceRequestData,
+        types::PayoutBalanceResponseData,
+    > for Adyenplatform
+{
+    fn get_url(
+        &self,
+        req: &types::PayoutBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let balance_account_id = adyenplatform::AdyenPlatformConnectorMetadataObject::try_from(
+            &req.connector_meta_data,
+        )?
+        .get_source_balance_account()?;
+        Ok(format!(
+            "{}bcl/v2/balanceAccounts/{}",
+            connectors.adyenplatform.base_url,
+            balance_account_id.peek()
+        ))
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PayoutBalanceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.get_auth_header(&req.connector_auth_type)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutBalanceType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutBalanceType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutBalanceRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutBalanceRouterData, errors::ConnectorError> {
+        let response: adyenplatform::AdyenBalanceAccountResponse = res
+            .response
+            .parse_struct("AdyenBalanceAccountResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl api::Refund for Adyenplatform {}
 impl api::RefundExecute for Adyenplatform {}
 impl api::RefundSync for Adyenplatform {}
@@ -1,6 +1,6 @@
 #[cfg(feature = "payouts")]
 use api_models::webhooks;
-use common_utils::pii;
+use common_utils::{pii, types::MinorUnit};
 use error_stack::{report, ResultExt};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
@@ -12,7 +12,12 @@ use crate::{
         utils::{self, PayoutsData, RouterData},
     },
     core::errors,
-    types::{self, api::payouts, storage::enums, transformers::ForeignFrom},
+    types::{
+        self,
+        api::{self, payouts},
+        storage::enums,
+        transformers::ForeignFrom,
+    },
 };
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -31,6 +36,16 @@ impl TryFrom<&Option<pii::SecretSerdeValue>> for AdyenPlatformConnectorMetadataO
     }
 }
 
+impl AdyenPlatformConnectorMetadataObject {
+    pub fn get_source_balance_account(&self) -> Result<Secret<String>, Error> {
+        self.source_balance_account.clone().ok_or(report!(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.source_balance_account",
+            }
+        ))
+    }
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -39,7 +54,7 @@ pub struct AdyenTransferRequest {
     balance_account_id: Secret<String>,
     category: AdyenPayoutMethod,
     counterparty: AdyenPayoutMethodDetails,
-    priority: AdyenPayoutPriority,
+    priority: Option<AdyenPayoutPriority>,
     reference: String,
     reference_for_beneficiary: String,
     description: Option<String>,
@@ -49,24 +64,54 @@ pub struct AdyenTransferRequest {
 #[serde(rename_all = "camelCase")]
 pub enum AdyenPayoutMethod {
     Bank,
+    Card,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AdyenPayoutMethodDetails {
+    BankAccount(AdyenBankAccountDetailsWrapper),
+    Card(AdyenCardDetailsWrapper),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AdyenPayoutMethodDetails {
+pub struct AdyenBankAccountDetailsWrapper {
     bank_account: AdyenBankAccountDetails,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCardDetailsWrapper {
+    card: AdyenCardDetails,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCardDetails {
+    card_holder: AdyenAccountHolder,
+    card_identification: AdyenCardIdentification,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCardIdentification {
+    #[serde(rename = "number")]
+    card_number: cards::CardNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenBankAccountDetails {
-    account_holder: AdyenBankAccountHolder,
+    account_holder: AdyenAccountHolder,
     account_identification: AdyenBankAccountIdentification,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AdyenBankAccountHolder {
+pub struct AdyenAccountHolder {
     address: Option<adyen::Address>,
     full_name: Secret<String>,
     #[serde(rename = "reference")]
@@ -139,7 +184,7 @@ pub struct AdyenPlatformAccountHolder {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AdyenCategoryData {
-    priority: AdyenPayoutPriority,
+    priority: Option<AdyenPayoutPriority>,
     #[serde(rename = "type")]
     category: AdyenPayoutMethod,
 }
@@ -180,11 +225,38 @@ impl<F> TryFrom<&AdyenPlatformRouterData<&types::PayoutsRouterData<F>>> for Adye
         item: &AdyenPlatformRouterData<&types::PayoutsRouterData<F>>,
     ) -> Result<Self, Self::Error> {
         let request = item.router_data.request.to_owned();
-        match item.router_data.get_payout_method_data()? {
-            payouts::PayoutMethodData::Card(_) | payouts::PayoutMethodData::Wallet(_) => {
-                Err(errors::ConnectorError::NotImplemented(
-                    utils::get_unimplemented_payment_method_error_message("Adyenplatform"),
-                ))?
+        let billing_address = item.router_data.get_optional_billing();
+        let address = adyen::get_address_info(billing_address).transpose()?;
+        let account_holder = AdyenAccountHolder {
+            address,
+            full_name: item.router_data.get_billing_full_name()?,
+            customer_id: Some(
+                item.router_data
+                    .get_customer_id()?
+                    .get_string_repr()
+                    .to_owned(),
+            ),
+            entity_type: Some(EntityType::from(request.entity_type)),
+        };
+        let (counterparty, priority) = match item.router_data.get_payout_method_data()? {
+            payouts::PayoutMethodData::Wallet(_) => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("Adyenplatform"),
+            ))?,
+
+            // Push-to-card payouts are routed over the card scheme rails (Visa Direct /
+            // Mastercard Send), which do not take a transfer priority
+            payouts::PayoutMethodData::Card(card) => {
+                let counterparty = AdyenPayoutMethodDetails::Card(AdyenCardDetailsWrapper {
+                    card: AdyenCardDetails {
+                        card_holder: account_holder,
+                        card_identification: AdyenCardIdentification {
+                            card_number: card.card_number,
+                            expiry_month: card.expiry_month,
+                            expiry_year: card.expiry_year,
+                        },
+                    },
+                });
+                (counterparty, None)
             }
 
             payouts::PayoutMethodData::Bank(bd) => {
@@ -208,57 +280,40 @@ impl<F> TryFrom<&AdyenPlatformRouterData<&types::PayoutsRouterData<F>>> for Adye
                         connector: "Adyenplatform",
                     })?,
                 };
-                let billing_address = item.router_data.get_optional_billing();
-                let address = adyen::get_address_info(billing_address).transpose()?;
-                let account_holder = AdyenBankAccountHolder {
-                    address,
-                    full_name: item.router_data.get_billing_full_name()?,
-                    customer_id: Some(
-                        item.router_data
-                            .get_customer_id()?
-                            .get_string_repr()
-                            .to_owned(),
-                    ),
-                    entity_type: Some(EntityType::from(request.entity_type)),
-                };
-                let counterparty = AdyenPayoutMethodDetails {
-                    bank_account: AdyenBankAccountDetails {
-                        account_holder,
-                        account_identification: bank_details,
-                    },
-                };
-
-                let adyen_connector_metadata_object =
-                    AdyenPlatformConnectorMetadataObject::try_from(
-                        &item.router_data.connector_meta_data,
-                    )?;
-                let balance_account_id = adyen_connector_metadata_object
-                    .source_balance_account
-                    .ok_or(errors::ConnectorError::InvalidConnectorConfig {
-                        config: "metadata.source_balance_account",
-                    })?;
+                let counterparty =
+                    AdyenPayoutMethodDetails::BankAccount(AdyenBankAccountDetailsWrapper {
+                        bank_account: AdyenBankAccountDetails {
+                            account_holder,
+                            account_identification: bank_details,
+                        },
+                    });
                 let priority =
                     request
                         .priority
                         .ok_or(errors::ConnectorError::MissingRequiredField {
                             field_name: "priority",
                         })?;
-                let payout_type = request.get_payout_type()?;
-                Ok(Self {
-                    amount: adyen::Amount {
-                        value: item.amount,
-                        currency: request.destination_currency,
-                    },
-                    balance_account_id,
-                    category: AdyenPayoutMethod::try_from(payout_type)?,
-                    counterparty,
-                    priority: AdyenPayoutPriority::from(priority),
-                    reference: item.router_data.connector_request_reference_id.clone(),
-                    reference_for_beneficiary: request.payout_id,
-                    description: item.router_data.description.clone(),
-                })
+                (counterparty, Some(AdyenPayoutPriority::from(priority)))
             }
-        }
+        };
+
+        let adyen_connector_metadata_object =
+            AdyenPlatformConnectorMetadataObject::try_from(&item.router_data.connector_meta_data)?;
+        let balance_account_id = adyen_connector_metadata_object.get_source_balance_account()?;
+        let payout_type = request.get_payout_type()?;
+        Ok(Self {
+            amount: adyen::Amount {
+                value: item.amount,
+                currency: request.destination_currency,
+            },
+            balance_account_id,
+            category: AdyenPayoutMethod::try_from(payout_type)?,
+            counterparty,
+            priority,
+            reference: item.router_data.connector_request_reference_id.clone(),
+            reference_for_beneficiary: request.payout_id,
+            description: item.router_data.description.clone(),
+        })
     }
 }
 
@@ -285,12 +340,71 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenTransferResponse>>
                 should_add_next_step_to_process_tracker: false,
                 error_code,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBalanceAccountResponse {
+    id: String,
+    balances: Vec<AdyenBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBalance {
+    currency: enums::Currency,
+    available: MinorUnit,
+    balance: MinorUnit,
+    reserved: Option<MinorUnit>,
+    pending: Option<MinorUnit>,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::PoBalance,
+            AdyenBalanceAccountResponse,
+            types::PayoutBalanceRequestData,
+            types::PayoutBalanceResponseData,
+        >,
+    > for types::PayoutBalanceRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::PoBalance,
+            AdyenBalanceAccountResponse,
+            types::PayoutBalanceRequestData,
+            types::PayoutBalanceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let requested_currency = item.data.request.currency;
+        let balances = item
+            .response
+            .balances
+            .into_iter()
+            .filter(|balance| {
+                requested_currency.map_or(true, |currency| currency == balance.currency)
+            })
+            .map(|balance| types::PayoutConnectorBalance {
+                currency: balance.currency,
+                available_amount: balance.available,
+                pending_amount: balance.pending,
+            })
+            .collect();
+
+        Ok(Self {
+            response: Ok(types::PayoutBalanceResponseData { balances }),
+            ..item.data
+        })
+    }
+}
+
 impl From<AdyenTransferStatus> for enums::PayoutStatus {
     fn from(adyen_status: AdyenTransferStatus) -> Self {
         match adyen_status {
@@ -334,12 +448,11 @@ impl TryFrom<enums::PayoutType> for AdyenPayoutMethod {
     fn try_from(payout_type: enums::PayoutType) -> Result<Self, Self::Error> {
         match payout_type {
             enums::PayoutType::Bank => Ok(Self::Bank),
-            enums::PayoutType::Card | enums::PayoutType::Wallet => {
-                Err(report!(errors::ConnectorError::NotSupported {
-                    message: "Card or wallet payouts".to_string(),
-                    connector: "Adyenplatform",
-                }))
-            }
+            enums::PayoutType::Card => Ok(Self::Card),
+            enums::PayoutType::Wallet => Err(report!(errors::ConnectorError::NotSupported {
+                message: "Wallet payouts".to_string(),
+                connector: "Adyenplatform",
+            })),
         }
     }
 }
@@ -963,6 +963,11 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::MandateEvidence => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "mandate evidence is not uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
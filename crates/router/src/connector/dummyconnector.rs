@@ -2,9 +2,9 @@ pub mod transformers;
 
 use std::fmt::Debug;
 
-use common_utils::request::RequestContent;
+use common_utils::{crypto, request::RequestContent};
 use diesel_models::enums;
-use error_stack::{report, ResultExt};
+use error_stack::ResultExt;
 
 use super::utils::RefundsRequestData;
 use crate::{
@@ -590,24 +590,68 @@ impl<const T: u8> ConnectorIntegration<api::RSync, types::RefundsData, types::Re
 
 #[async_trait::async_trait]
 impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = connector_utils::get_header_key_value(
+            headers::X_DUMMY_CONNECTOR_SIGNATURE,
+            request.headers,
+        )?;
+
+        hex::decode(signature).change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook_body: transformers::DummyConnectorWebhookBody = request
+            .body
+            .parse_struct("DummyConnectorWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(webhook_body.get_object_reference_id())
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Ok(api::IncomingWebhookEvent::EventNotSupported)
+        let webhook_body: transformers::DummyConnectorWebhookBody = request
+            .body
+            .parse_struct("DummyConnectorWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(&webhook_body))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook_body: transformers::DummyConnectorWebhookBody = request
+            .body
+            .parse_struct("DummyConnectorWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        match webhook_body {
+            transformers::DummyConnectorWebhookBody::Payment(payment) => Ok(Box::new(payment)),
+            transformers::DummyConnectorWebhookBody::Refund(refund) => Ok(Box::new(refund)),
+        }
     }
 }
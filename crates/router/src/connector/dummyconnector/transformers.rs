@@ -327,6 +327,21 @@ pub struct RefundResponse {
     created: String,
     payment_amount: i64,
     refund_amount: i64,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    expected_settlement_date: Option<time::PrimitiveDateTime>,
+}
+
+impl RefundResponse {
+    fn get_connector_response(&self) -> types::ConnectorResponseData {
+        let status_reason = match self.status {
+            RefundStatus::Processing => Some(enums::RefundStatusReason::ProcessingAtConnector),
+            RefundStatus::Succeeded | RefundStatus::Failed => None,
+        };
+        types::ConnectorResponseData::with_refund_details(types::ConnectorRefundDetails {
+            expected_settlement_date: self.expected_settlement_date,
+            status_reason,
+        })
+    }
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -336,11 +351,13 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
     ) -> Result<Self, Self::Error> {
+        let connector_response = item.response.get_connector_response();
         Ok(Self {
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status),
             }),
+            connector_response: Some(connector_response),
             ..item.data
         })
     }
@@ -353,16 +370,58 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
     ) -> Result<Self, Self::Error> {
+        let connector_response = item.response.get_connector_response();
         Ok(Self {
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status),
             }),
+            connector_response: Some(connector_response),
             ..item.data
         })
     }
 }
 
+// WEBHOOKS :
+// Webhooks are sent with the payment or refund object in its latest state, signed with the
+// webhook secret of the merchant connector account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "object", content = "data", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookBody {
+    Payment(PaymentsResponse),
+    Refund(RefundResponse),
+}
+
+impl DummyConnectorWebhookBody {
+    pub fn get_object_reference_id(&self) -> api_models::webhooks::ObjectReferenceId {
+        match self {
+            Self::Payment(payment) => api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(payment.id.clone()),
+            ),
+            Self::Refund(refund) => api_models::webhooks::ObjectReferenceId::RefundId(
+                api_models::webhooks::RefundIdType::ConnectorRefundId(refund.id.clone()),
+            ),
+        }
+    }
+}
+
+impl From<&DummyConnectorWebhookBody> for api::IncomingWebhookEvent {
+    fn from(webhook_body: &DummyConnectorWebhookBody) -> Self {
+        match webhook_body {
+            DummyConnectorWebhookBody::Payment(payment) => match payment.status {
+                DummyConnectorPaymentStatus::Succeeded => Self::PaymentIntentSuccess,
+                DummyConnectorPaymentStatus::Failed => Self::PaymentIntentFailure,
+                DummyConnectorPaymentStatus::Processing => Self::PaymentIntentProcessing,
+            },
+            DummyConnectorWebhookBody::Refund(refund) => match refund.status {
+                RefundStatus::Succeeded => Self::RefundSuccess,
+                RefundStatus::Failed => Self::RefundFailure,
+                RefundStatus::Processing => Self::EventNotSupported,
+            },
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
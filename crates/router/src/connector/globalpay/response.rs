@@ -33,7 +33,7 @@ pub struct GlobalpayPaymentsResponse {
     /// The currency of the amount in ISO-4217(alpha-3)
     pub currency: Option<Currency>,
     /// Information relating to a currency conversion.
-    pub currency_conversion: Option<requests::CurrencyConversion>,
+    pub currency_conversion: Option<CurrencyConversion>,
     /// A unique identifier generated by Global Payments to identify the transaction.
     pub id: String,
     /// A unique identifier for the merchant set by Global Payments.
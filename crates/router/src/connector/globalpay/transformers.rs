@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use common_utils::{
     crypto::{self, GenerateDigest},
     types::{AmountConvertor, MinorUnit, StringMinorUnit, StringMinorUnitForConnector},
@@ -14,7 +16,10 @@ use super::{
         GlobalpayPaymentsRequest, GlobalpayRefreshTokenRequest, Initiator, PaymentMethodData,
         Sequence, StoredCredential,
     },
-    response::{GlobalpayPaymentStatus, GlobalpayPaymentsResponse, GlobalpayRefreshTokenResponse},
+    response::{
+        CurrencyConversion, GlobalpayPaymentStatus, GlobalpayPaymentsResponse,
+        GlobalpayRefreshTokenResponse,
+    },
 };
 use crate::{
     connector::utils::{self, CardData, PaymentsAuthorizeRequestData, RouterData, WalletData},
@@ -249,6 +254,30 @@ impl From<Option<enums::CaptureMethod>> for requests::CaptureMode {
     }
 }
 
+/// The currency conversion of the payment, if the payer was charged in the currency of their card
+/// through the dynamic currency conversion of Global Payments
+fn get_connector_currency_conversion(
+    currency_conversion: &CurrencyConversion,
+) -> Option<types::ConnectorCurrencyConversion> {
+    let presentment_currency = currency_conversion
+        .payer_currency
+        .as_deref()
+        .and_then(|payer_currency| enums::Currency::from_str(payer_currency).ok())?;
+    let presentment_amount = currency_conversion
+        .payer_amount
+        .clone()
+        .and_then(|payer_amount| {
+            StringMinorUnitForConnector
+                .convert_back(payer_amount, presentment_currency)
+                .ok()
+        })?;
+    Some(types::ConnectorCurrencyConversion {
+        presentment_currency,
+        presentment_amount,
+        exchange_rate: currency_conversion.conversion_rate.clone()?,
+    })
+}
+
 fn get_payment_response(
     status: enums::AttemptStatus,
     response: GlobalpayPaymentsResponse,
@@ -317,9 +346,16 @@ impl<F, T>
             .transpose()?;
         let redirection_data =
             redirect_url.map(|url| RedirectForm::from((url, services::Method::Get)));
+        let connector_response = item
+            .response
+            .currency_conversion
+            .as_ref()
+            .and_then(get_connector_currency_conversion)
+            .map(types::ConnectorResponseData::with_currency_conversion);
         Ok(Self {
             status,
             response: get_payment_response(status, item.response, redirection_data),
+            connector_response: connector_response.or(item.data.connector_response),
             ..item.data
         })
     }
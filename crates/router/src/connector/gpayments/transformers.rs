@@ -163,6 +163,12 @@ impl TryFrom<&GpaymentsRouterData<&types::authentication::ConnectorAuthenticatio
         item: &GpaymentsRouterData<&types::authentication::ConnectorAuthenticationRouterData>,
     ) -> Result<Self, Self::Error> {
         let request = &item.router_data.request;
+        if request.device_channel == DeviceChannel::ThreeDsRequestorInitiated {
+            Err(errors::ConnectorError::NotSupported {
+                message: "3DS Requestor Initiated authentication is not supported".to_string(),
+                connector: "Gpayments",
+            })?
+        }
         let browser_details = match request.browser_details.clone() {
             Some(details) => Ok::<Option<types::BrowserInformation>, Self::Error>(Some(details)),
             None => {
@@ -97,6 +97,7 @@ impl TryFrom<&ItaubankRouterData<&types::PaymentsAuthorizeRouterData>> for Itaub
                     | domain::BankTransferData::DanamonVaBankTransfer {}
                     | domain::BankTransferData::MandiriVaBankTransfer {}
                     | domain::BankTransferData::Pse {}
+                    | domain::BankTransferData::VirtualAccountBankTransfer {}
                     | domain::BankTransferData::LocalBankTransfer { .. } => {
                         Err(errors::ConnectorError::NotImplemented(
                             "Selected payment method through itaubank".to_string(),
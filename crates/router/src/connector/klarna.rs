@@ -634,6 +634,7 @@ impl
                         | common_enums::PaymentMethodType::WeChatPay
                         | common_enums::PaymentMethodType::SevenEleven
                         | common_enums::PaymentMethodType::Lawson
+                        | common_enums::PaymentMethodType::VirtualAccount
                         | common_enums::PaymentMethodType::LocalBankTransfer
                         | common_enums::PaymentMethodType::MiniStop
                         | common_enums::PaymentMethodType::FamilyMart
@@ -483,6 +483,8 @@ impl<F>
 pub struct KlarnaRefundRequest {
     refunded_amount: MinorUnit,
     reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_lines: Option<Vec<OrderLines>>,
 }
 
 impl<F> TryFrom<&KlarnaRouterData<&types::RefundsRouterData<F>>> for KlarnaRefundRequest {
@@ -494,6 +496,17 @@ impl<F> TryFrom<&KlarnaRouterData<&types::RefundsRouterData<F>>> for KlarnaRefun
         Ok(Self {
             refunded_amount: item.amount,
             reference: Some(request.refund_id.clone()),
+            order_lines: request.line_items.as_ref().map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|data| OrderLines {
+                        name: data.product_name.clone(),
+                        quantity: data.quantity,
+                        unit_price: data.amount,
+                        total_amount: data.amount * data.quantity,
+                    })
+                    .collect()
+            }),
         })
     }
 }
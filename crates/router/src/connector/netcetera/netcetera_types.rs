@@ -8,7 +8,7 @@ use unidecode::unidecode;
 use crate::{
     connector::utils::{AddressDetailsData, PhoneDetailsData},
     errors,
-    types::api::MessageCategory,
+    types::{self, api::MessageCategory},
 };
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -43,6 +43,9 @@ impl From<api_models::payments::DeviceChannel> for NetceteraDeviceChannel {
         match value {
             api_models::payments::DeviceChannel::App => Self::AppBased,
             api_models::payments::DeviceChannel::Browser => Self::Browser,
+            api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => {
+                Self::ThreeDsRequestorInitiated
+            }
         }
     }
 }
@@ -64,6 +67,23 @@ impl From<MessageCategory> for NetceteraMessageCategory {
     }
 }
 
+/// Indicates the type of 3RI request.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub enum ThreeRiIndicator {
+    #[serde(rename = "01")]
+    RecurringTransaction,
+}
+
+impl From<types::authentication::ThreeRiIndicator> for ThreeRiIndicator {
+    fn from(value: types::authentication::ThreeRiIndicator) -> Self {
+        match value {
+            types::authentication::ThreeRiIndicator::RecurringTransaction => {
+                Self::RecurringTransaction
+            }
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum ThreeDSMethodCompletionIndicator {
     /// Successfully completed
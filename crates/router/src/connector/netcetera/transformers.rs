@@ -1,5 +1,5 @@
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 
 use super::netcetera_types;
@@ -437,7 +437,7 @@ pub struct NetceteraAuthenticationRequest {
     pub challenge_message_extension: Option<Vec<netcetera_types::MessageExtensionAttribute>>,
     pub browser_information: Option<netcetera_types::Browser>,
     #[serde(rename = "threeRIInd")]
-    pub three_ri_ind: Option<String>,
+    pub three_ri_ind: Option<netcetera_types::ThreeRiIndicator>,
     pub sdk_information: Option<netcetera_types::Sdk>,
     pub device: Option<String>,
     pub multi_transaction: Option<String>,
@@ -468,7 +468,8 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
             acct_id: None,
             pay_token_ind: None,
             pay_token_info: None,
-            card_security_code: Some(card.card_cvc),
+            // The card security code is not available for 3DS Requestor Initiated authentications
+            card_security_code: Some(card.card_cvc).filter(|cvc| !cvc.peek().is_empty()),
         };
         let currency = request
             .currency
@@ -531,13 +532,15 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
             api_models::payments::DeviceChannel::Browser => {
                 request.browser_details.map(netcetera_types::Browser::from)
             }
-            api_models::payments::DeviceChannel::App => None,
+            api_models::payments::DeviceChannel::App
+            | api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         let sdk_information = match request.device_channel {
             api_models::payments::DeviceChannel::App => {
                 request.sdk_information.map(netcetera_types::Sdk::from)
             }
-            api_models::payments::DeviceChannel::Browser => None,
+            api_models::payments::DeviceChannel::Browser
+            | api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         let device_render_options = match request.device_channel {
             api_models::payments::DeviceChannel::App => {
@@ -553,8 +556,12 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
                     ],
                 })
             }
-            api_models::payments::DeviceChannel::Browser => None,
+            api_models::payments::DeviceChannel::Browser
+            | api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => None,
         };
+        let three_ri_ind = request
+            .three_ri_indicator
+            .map(netcetera_types::ThreeRiIndicator::from);
         Ok(Self {
             preferred_protocol_version: Some(pre_authn_data.message_version),
             enforce_preferred_protocol_version: None,
@@ -582,7 +589,7 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
             message_extension: None,
             challenge_message_extension: None,
             browser_information,
-            three_ri_ind: None,
+            three_ri_ind,
             sdk_information,
             device: None,
             multi_transaction: None,
@@ -268,6 +268,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, PayonePayoutFulfillResponse>
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
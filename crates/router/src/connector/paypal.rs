@@ -603,9 +603,9 @@ impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsRespons
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<types::PayoutsRouterData<api::PoSync>, errors::ConnectorError> {
-        let response: paypal::PaypalFulfillResponse = res
+        let response: paypal::PaypalPayoutSyncResponse = res
             .response
-            .parse_struct("PaypalFulfillResponse")
+            .parse_struct("PaypalPayoutSyncResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
         event_builder.map(|i| i.set_response_body(&response));
@@ -1,8 +1,8 @@
 use api_models::enums;
 use base64::Engine;
-#[cfg(feature = "payouts")]
-use common_utils::pii::Email;
 use common_utils::{errors::CustomResult, types::StringMajorUnit};
+#[cfg(feature = "payouts")]
+use common_utils::{pii::Email, types::StringMajorUnitForConnector};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::router_response_types::MandateReference;
 use masking::{ExposeInterface, Secret};
@@ -79,6 +79,7 @@ pub mod auth_headers {
 }
 
 const ORDER_QUANTITY: u16 = 1;
+const PAYPAL_NOTE_TO_PAYER_MAX_LENGTH: usize = 255;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -1200,6 +1201,7 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                     | enums::PaymentMethodType::FamilyMart
                     | enums::PaymentMethodType::Seicomart
                     | enums::PaymentMethodType::PayEasy
+                    | enums::PaymentMethodType::VirtualAccount
                     | enums::PaymentMethodType::LocalBankTransfer
                     | enums::PaymentMethodType::Mifinity
                     | enums::PaymentMethodType::Paze => {
@@ -1305,6 +1307,7 @@ impl TryFrom<&domain::BankTransferData> for PaypalPaymentsRequest {
             | domain::BankTransferData::MandiriVaBankTransfer { .. }
             | domain::BankTransferData::Pix { .. }
             | domain::BankTransferData::Pse {}
+            | domain::BankTransferData::VirtualAccountBankTransfer {}
             | domain::BankTransferData::LocalBankTransfer { .. } => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Paypal"),
@@ -2296,7 +2299,7 @@ pub enum PaypalPayoutDataType {
 }
 
 #[cfg(feature = "payouts")]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PayoutAmount {
     value: StringMajorUnit,
     currency: storage_enums::Currency,
@@ -2441,6 +2444,131 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, PaypalFulfillResponse>>
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalPayoutSyncResponse {
+    batch_header: PaypalBatchResponse,
+    items: Option<Vec<PaypalPayoutItemResponse>>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalPayoutItemResponse {
+    payout_item_id: String,
+    transaction_status: Option<PaypalPayoutItemStatus>,
+    payout_item_fee: Option<PayoutAmount>,
+    errors: Option<PaypalPayoutItemError>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalPayoutItemError {
+    name: String,
+    message: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalPayoutItemStatus {
+    Success,
+    Failed,
+    Pending,
+    Unclaimed,
+    Returned,
+    Onhold,
+    Blocked,
+    Refunded,
+    Reversed,
+}
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<PaypalPayoutItemStatus> for storage_enums::PayoutStatus {
+    fn foreign_from(status: PaypalPayoutItemStatus) -> Self {
+        match status {
+            PaypalPayoutItemStatus::Success => Self::Success,
+            PaypalPayoutItemStatus::Failed | PaypalPayoutItemStatus::Blocked => Self::Failed,
+            // Unclaimed payouts stay with PayPal until the recipient signs up for an account,
+            // and are returned to the sender if they are not claimed within 30 days
+            PaypalPayoutItemStatus::Pending
+            | PaypalPayoutItemStatus::Onhold
+            | PaypalPayoutItemStatus::Unclaimed => Self::Pending,
+            PaypalPayoutItemStatus::Returned
+            | PaypalPayoutItemStatus::Refunded
+            | PaypalPayoutItemStatus::Reversed => Self::Reversed,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, PaypalPayoutSyncResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, PaypalPayoutSyncResponse>,
+    ) -> Result<Self, Self::Error> {
+        // A batch is created with a single item, whose status is more granular than the batch's
+        let payout_item = item
+            .response
+            .items
+            .and_then(|items| items.into_iter().next());
+        let connector_fee = payout_item
+            .as_ref()
+            .and_then(|payout_item| payout_item.payout_item_fee.as_ref())
+            .map(|fee| {
+                utils::convert_back_amount_to_minor_units(
+                    &StringMajorUnitForConnector,
+                    fee.value.clone(),
+                    fee.currency,
+                )
+            })
+            .transpose()?;
+        let (status, error_code, error_message) = match payout_item {
+            Some(PaypalPayoutItemResponse {
+                transaction_status: Some(PaypalPayoutItemStatus::Unclaimed),
+                ..
+            }) => (
+                storage_enums::PayoutStatus::Pending,
+                Some("UNCLAIMED".to_string()),
+                Some("Payout has not been claimed by the recipient yet".to_string()),
+            ),
+            Some(PaypalPayoutItemResponse {
+                transaction_status: Some(transaction_status),
+                errors,
+                ..
+            }) => (
+                storage_enums::PayoutStatus::foreign_from(transaction_status),
+                errors.as_ref().map(|error| error.name.clone()),
+                errors.and_then(|error| error.message),
+            ),
+            Some(PaypalPayoutItemResponse {
+                transaction_status: None,
+                ..
+            })
+            | None => (
+                storage_enums::PayoutStatus::foreign_from(item.response.batch_header.batch_status),
+                None,
+                None,
+            ),
+        };
+
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(status),
+                connector_payout_id: Some(item.response.batch_header.payout_batch_id),
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+                error_code,
+                error_message,
+                connector_fee,
             }),
             ..item.data
         })
@@ -2635,6 +2763,8 @@ impl<F, T>
 #[derive(Default, Debug, Serialize)]
 pub struct PaypalRefundRequest {
     pub amount: OrderAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_to_payer: Option<String>,
 }
 
 impl<F> TryFrom<&PaypalRouterData<&types::RefundsRouterData<F>>> for PaypalRefundRequest {
@@ -2642,11 +2772,30 @@ impl<F> TryFrom<&PaypalRouterData<&types::RefundsRouterData<F>>> for PaypalRefun
     fn try_from(
         item: &PaypalRouterData<&types::RefundsRouterData<F>>,
     ) -> Result<Self, Self::Error> {
+        // The refunded line items are listed in the note to the payer, since the refunds of
+        // PayPal do not accept items
+        let note_to_payer = item
+            .router_data
+            .request
+            .line_items
+            .as_ref()
+            .map(|line_items| {
+                let items = line_items
+                    .iter()
+                    .map(|line_item| format!("{} x {}", line_item.quantity, line_item.product_name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("Refund for {items}")
+                    .chars()
+                    .take(PAYPAL_NOTE_TO_PAYER_MAX_LENGTH)
+                    .collect()
+            });
         Ok(Self {
             amount: OrderAmount {
                 currency_code: item.router_data.request.currency,
                 value: item.amount.clone(),
             },
+            note_to_payer,
         })
     }
 }
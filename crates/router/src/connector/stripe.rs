@@ -5,6 +5,7 @@ use std::{collections::HashMap, ops::Deref};
 use common_utils::{
     request::RequestContent,
     types::{AmountConvertor, MinorUnit, MinorUnitForConnector},
+    validation::StatementDescriptorRules,
 };
 use diesel_models::enums;
 use error_stack::ResultExt;
@@ -166,6 +167,33 @@ impl ConnectorValidation for Stripe {
         ]);
         connector_utils::is_mandate_supported(pm_data, pm_type, mandate_supported_pmd, self.id())
     }
+
+    fn validate_statement_descriptor(
+        &self,
+        statement_descriptor: Option<&str>,
+        statement_descriptor_suffix: Option<&str>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // Stripe requires the statement descriptor to have at least 5 characters and does not
+        // allow `*` as it is used to separate the descriptor prefix from the suffix
+        fn is_allowed_character(character: char) -> bool {
+            (character.is_ascii_graphic() || character == ' ')
+                && !matches!(character, '<' | '>' | '\\' | '\'' | '"' | '*')
+        }
+        hyperswitch_interfaces::api::validate_statement_descriptor_against_rules(
+            statement_descriptor,
+            statement_descriptor_suffix,
+            &StatementDescriptorRules {
+                min_length: 5,
+                max_length: 22,
+                is_allowed_character,
+            },
+            &StatementDescriptorRules {
+                min_length: 1,
+                max_length: 22,
+                is_allowed_character,
+            },
+        )
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -1765,6 +1793,11 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::MandateEvidence => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "mandate evidence is not uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -2482,6 +2515,8 @@ impl api::PayoutFulfill for Stripe {}
 impl api::PayoutRecipient for Stripe {}
 #[cfg(feature = "payouts")]
 impl api::PayoutRecipientAccount for Stripe {}
+#[cfg(feature = "payouts")]
+impl api::PayoutRecipientStatus for Stripe {}
 
 #[cfg(feature = "payouts")]
 impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
@@ -2901,3 +2936,77 @@ impl
         self.build_error_response(res, event_builder)
     }
 }
+
+#[cfg(feature = "payouts")]
+impl
+    services::ConnectorIntegration<
+        api::PoRecipientStatus,
+        types::PayoutsData,
+        types::PayoutRecipientStatusResponseData,
+    > for Stripe
+{
+    fn get_url(
+        &self,
+        req: &types::PayoutRecipientStatusRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let connector_customer_id = req.get_connector_customer_id()?;
+        Ok(format!(
+            "{}v1/accounts/{}",
+            connectors.stripe.base_url, connector_customer_id
+        ))
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PayoutRecipientStatusRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutRecipientStatusRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutRecipientStatusType::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(types::PayoutRecipientStatusType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutRecipientStatusRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutRecipientStatusRouterData, errors::ConnectorError> {
+        let response: stripe::StripeConnectRecipientStatusResponse = res
+            .response
+            .parse_struct("StripeConnectRecipientStatusResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
@@ -237,6 +237,12 @@ pub struct StripePayLaterData {
     pub payment_method_data_type: StripePaymentMethodType,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeVoucherData {
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct TokenRequest {
     #[serde(flatten)]
@@ -430,6 +436,18 @@ pub struct SepaBankTransferData {
     pub country: api_models::enums::CountryAlpha2,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct VirtualAccountBankTransferData {
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_options[customer_balance][bank_transfer][type]")]
+    pub bank_transfer_type: BankTransferType,
+    #[serde(rename = "payment_method_options[customer_balance][funding_type]")]
+    pub balance_funding_type: BankTransferType,
+    #[serde(rename = "payment_method_types[0]")]
+    pub payment_method_type: StripePaymentMethodType,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum StripeCreditTransferSourceRequest {
@@ -468,6 +486,7 @@ pub enum StripePaymentMethodData {
     BankRedirect(StripeBankRedirectData),
     BankDebit(StripeBankDebitData),
     BankTransfer(StripeBankTransferData),
+    Voucher(StripeVoucherData),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -521,6 +540,7 @@ pub enum StripeBankTransferData {
     SepaBankTransfer(Box<SepaBankTransferData>),
     BacsBankTransfers(Box<BacsBankTransferData>),
     MultibancoBankTransfers(Box<MultibancoTransferData>),
+    VirtualAccountBankTransfer(Box<VirtualAccountBankTransferData>),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -641,6 +661,8 @@ pub enum StripePaymentMethodType {
     Wechatpay,
     #[serde(rename = "cashapp")]
     Cashapp,
+    Oxxo,
+    Konbini,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -749,6 +771,7 @@ impl TryFrom<enums::PaymentMethodType> for StripePaymentMethodType {
             | enums::PaymentMethodType::Seicomart
             | enums::PaymentMethodType::PayEasy
             | enums::PaymentMethodType::LocalBankTransfer
+            | enums::PaymentMethodType::VirtualAccount
             | enums::PaymentMethodType::Walley
             | enums::PaymentMethodType::Fps
             | enums::PaymentMethodType::DuitNow
@@ -767,6 +790,7 @@ impl TryFrom<enums::PaymentMethodType> for StripePaymentMethodType {
 pub enum BankTransferType {
     GbBankTransfer,
     EuBankTransfer,
+    UsBankTransfer,
     #[serde(rename = "bank_transfer")]
     BankTransfers,
 }
@@ -1002,6 +1026,30 @@ impl TryFrom<&domain::payments::PayLaterData> for StripePaymentMethodType {
     }
 }
 
+impl TryFrom<&domain::VoucherData> for StripePaymentMethodType {
+    type Error = errors::ConnectorError;
+    fn try_from(voucher_data: &domain::VoucherData) -> Result<Self, Self::Error> {
+        match voucher_data {
+            domain::VoucherData::Oxxo => Ok(Self::Oxxo),
+            domain::VoucherData::SevenEleven(_)
+            | domain::VoucherData::Lawson(_)
+            | domain::VoucherData::MiniStop(_)
+            | domain::VoucherData::FamilyMart(_)
+            | domain::VoucherData::Seicomart(_) => Ok(Self::Konbini),
+            domain::VoucherData::Boleto(_)
+            | domain::VoucherData::Alfamart(_)
+            | domain::VoucherData::Efecty
+            | domain::VoucherData::PagoEfectivo
+            | domain::VoucherData::RedCompra
+            | domain::VoucherData::RedPagos
+            | domain::VoucherData::Indomaret(_)
+            | domain::VoucherData::PayEasy(_) => Err(errors::ConnectorError::NotImplemented(
+                connector_util::get_unimplemented_payment_method_error_message("stripe"),
+            )),
+        }
+    }
+}
+
 impl TryFrom<&domain::BankRedirectData> for StripePaymentMethodType {
     type Error = errors::ConnectorError;
     fn try_from(bank_redirect_data: &domain::BankRedirectData) -> Result<Self, Self::Error> {
@@ -1268,6 +1316,20 @@ fn create_stripe_payment_method(
                     Some(StripePaymentMethodType::CustomerBalance),
                     billing_address,
                 )),
+                domain::BankTransferData::VirtualAccountBankTransfer {} => Ok((
+                    StripePaymentMethodData::BankTransfer(
+                        StripeBankTransferData::VirtualAccountBankTransfer(Box::new(
+                            VirtualAccountBankTransferData {
+                                payment_method_data_type: StripePaymentMethodType::CustomerBalance,
+                                bank_transfer_type: BankTransferType::UsBankTransfer,
+                                balance_funding_type: BankTransferType::BankTransfers,
+                                payment_method_type: StripePaymentMethodType::CustomerBalance,
+                            },
+                        )),
+                    ),
+                    Some(StripePaymentMethodType::CustomerBalance),
+                    billing_address,
+                )),
                 domain::BankTransferData::Pix { .. } => {
                     Err(errors::ConnectorError::NotImplemented(
                         connector_util::get_unimplemented_payment_method_error_message("stripe"),
@@ -1319,29 +1381,31 @@ fn create_stripe_payment_method(
         )
         .into()),
 
-        domain::PaymentMethodData::Voucher(voucher_data) => match voucher_data {
-            domain::VoucherData::Boleto(_) | domain::VoucherData::Oxxo => {
-                Err(errors::ConnectorError::NotImplemented(
-                    connector_util::get_unimplemented_payment_method_error_message("stripe"),
-                )
-                .into())
-            }
-            domain::VoucherData::Alfamart(_)
-            | domain::VoucherData::Efecty
-            | domain::VoucherData::PagoEfectivo
-            | domain::VoucherData::RedCompra
-            | domain::VoucherData::RedPagos
-            | domain::VoucherData::Indomaret(_)
-            | domain::VoucherData::SevenEleven(_)
-            | domain::VoucherData::Lawson(_)
-            | domain::VoucherData::MiniStop(_)
-            | domain::VoucherData::FamilyMart(_)
-            | domain::VoucherData::Seicomart(_)
-            | domain::VoucherData::PayEasy(_) => Err(errors::ConnectorError::NotImplemented(
-                connector_util::get_unimplemented_payment_method_error_message("stripe"),
-            )
-            .into()),
-        },
+        domain::PaymentMethodData::Voucher(voucher_data) => {
+            let stripe_pm_type = StripePaymentMethodType::try_from(voucher_data)?;
+            // Stripe requires the name and email of the customer to issue the voucher
+            let billing_address = StripeBillingAddress {
+                name: Some(billing_address.name.clone().ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "billing_address.first_name",
+                    },
+                )?),
+                email: Some(billing_address.email.clone().ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "billing_address.email",
+                    },
+                )?),
+                ..billing_address
+            };
+
+            Ok((
+                StripePaymentMethodData::Voucher(StripeVoucherData {
+                    payment_method_data_type: stripe_pm_type,
+                }),
+                Some(stripe_pm_type),
+                billing_address,
+            ))
+        }
 
         domain::PaymentMethodData::Upi(_)
         | domain::PaymentMethodData::RealTimePayment(_)
@@ -2291,6 +2355,8 @@ pub enum StripePaymentMethodDetailsResponse {
     Wechatpay,
     Alipay,
     CustomerBalance,
+    Oxxo,
+    Konbini,
 }
 
 pub struct AdditionalPaymentMethodDetails {
@@ -2333,6 +2399,8 @@ impl StripePaymentMethodDetailsResponse {
             | Self::Wechatpay
             | Self::Alipay
             | Self::CustomerBalance
+            | Self::Oxxo
+            | Self::Konbini
             | Self::Cashapp { .. } => None,
         }
     }
@@ -2553,11 +2621,49 @@ pub fn get_connector_metadata(
                         )
                     });
 
+                // Stripe reports the amount received only once the payment is fully funded, so the
+                // amount credited so far is derived from the amount remaining to be transferred
+                let amount_received = amount - response.amount_remaining;
+
+                // The financial address of the virtual account generated for US bank transfers
+                if let Some(aba_financial_details) = response
+                    .financial_addresses
+                    .iter()
+                    .find_map(|financial_address| financial_address.aba.to_owned())
+                {
+                    let virtual_account_instructions =
+                        api_models::payments::VirtualAccountTransferInstructions {
+                            account_number: aba_financial_details.account_number,
+                            account_holder_name: None,
+                            bank_name: aba_financial_details.bank_name,
+                            bank_code: Some(aba_financial_details.routing_number),
+                            reference: response.reference.to_owned().map(Secret::new),
+                            expires_at: None,
+                        };
+                    return Some(
+                        api_models::payments::BankTransferNextStepsData {
+                            bank_transfer_instructions:
+                                api_models::payments::BankTransferInstructions::VirtualAccount(
+                                    Box::new(virtual_account_instructions),
+                                ),
+                            receiver: Some(api_models::payments::ReceiverDetails {
+                                amount_received: amount_received.get_amount_as_i64(),
+                                amount_charged: None,
+                                amount_remaining: Some(
+                                    response.amount_remaining.get_amount_as_i64(),
+                                ),
+                                amount_overpaid: None,
+                            }),
+                        }
+                        .encode_to_value(),
+                    );
+                }
+
                 let bank_transfer_instructions = SepaAndBacsBankTransferInstructions {
                     sepa_bank_instructions,
                     bacs_bank_instructions,
                     receiver: SepaAndBacsReceiver {
-                        amount_received: amount - response.amount_remaining,
+                        amount_received,
                         amount_remaining: response.amount_remaining,
                     },
                 };
@@ -2579,6 +2685,28 @@ pub fn get_connector_metadata(
                 };
                 Some(cashapp_qr_instructions.encode_to_value())
             }
+            StripeNextActionResponse::OxxoDisplayDetails(response) => {
+                response.number.to_owned().map(|reference| {
+                    api_models::payments::VoucherNextStepData {
+                        expires_at: response.expires_after.map(|expiry| expiry * 1000),
+                        reference,
+                        download_url: None,
+                        instructions_url: response.hosted_voucher_url.to_owned(),
+                    }
+                    .encode_to_value()
+                })
+            }
+            StripeNextActionResponse::KonbiniDisplayDetails(response) => {
+                response.stores.get_payment_code().map(|reference| {
+                    api_models::payments::VoucherNextStepData {
+                        expires_at: response.expires_at.map(|expiry| expiry * 1000),
+                        reference,
+                        download_url: None,
+                        instructions_url: response.hosted_voucher_url.to_owned(),
+                    }
+                    .encode_to_value()
+                })
+            }
             _ => None,
         })
         .transpose()
@@ -2649,6 +2777,8 @@ impl<F, T>
                             | Some(StripePaymentMethodDetailsResponse::Wechatpay)
                             | Some(StripePaymentMethodDetailsResponse::Alipay)
                             | Some(StripePaymentMethodDetailsResponse::CustomerBalance)
+                            | Some(StripePaymentMethodDetailsResponse::Oxxo)
+                            | Some(StripePaymentMethodDetailsResponse::Konbini)
                             | Some(StripePaymentMethodDetailsResponse::Cashapp { .. })
                             | None => payment_method_id.expose(),
                         }
@@ -2827,6 +2957,8 @@ pub enum StripeNextActionResponse {
     VerifyWithMicrodeposits(StripeVerifyWithMicroDepositsResponse),
     WechatPayDisplayQrCode(WechatPayRedirectToQr),
     DisplayBankTransferInstructions(StripeBankTransferDetails),
+    OxxoDisplayDetails(StripeOxxoDisplayDetails),
+    KonbiniDisplayDetails(StripeKonbiniDisplayDetails),
     NoNextActionBody,
 }
 
@@ -2842,6 +2974,7 @@ impl StripeNextActionResponse {
             }
             Self::CashappHandleRedirectOrDisplayQrCode(_) => None,
             Self::DisplayBankTransferInstructions(_) => None,
+            Self::OxxoDisplayDetails(_) | Self::KonbiniDisplayDetails(_) => None,
             Self::NoNextActionBody => None,
         }
     }
@@ -2891,6 +3024,8 @@ impl Serialize for StripeNextActionResponse {
             Self::VerifyWithMicrodeposits(ref i) => Serialize::serialize(i, serializer),
             Self::WechatPayDisplayQrCode(ref i) => Serialize::serialize(i, serializer),
             Self::DisplayBankTransferInstructions(ref i) => Serialize::serialize(i, serializer),
+            Self::OxxoDisplayDetails(ref i) => Serialize::serialize(i, serializer),
+            Self::KonbiniDisplayDetails(ref i) => Serialize::serialize(i, serializer),
             Self::NoNextActionBody => Serialize::serialize("NoNextActionBody", serializer),
         }
     }
@@ -2927,6 +3062,50 @@ pub struct StripeBankTransferDetails {
     pub bank_transfer_type: Option<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeOxxoDisplayDetails {
+    // Unix timestamp (in seconds) after which the voucher can no longer be paid
+    pub expires_after: Option<i64>,
+    pub hosted_voucher_url: Option<Url>,
+    pub number: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeKonbiniDisplayDetails {
+    // Unix timestamp (in seconds) after which the voucher can no longer be paid
+    pub expires_at: Option<i64>,
+    pub hosted_voucher_url: Option<Url>,
+    pub stores: StripeKonbiniStores,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeKonbiniStores {
+    pub familymart: Option<StripeKonbiniStoreDetails>,
+    pub lawson: Option<StripeKonbiniStoreDetails>,
+    pub ministop: Option<StripeKonbiniStoreDetails>,
+    pub seicomart: Option<StripeKonbiniStoreDetails>,
+}
+
+impl StripeKonbiniStores {
+    // Stripe issues the payment code per store, the first one available is shown to the customer
+    fn get_payment_code(&self) -> Option<String> {
+        [
+            &self.familymart,
+            &self.lawson,
+            &self.ministop,
+            &self.seicomart,
+        ]
+        .into_iter()
+        .find_map(|store| store.as_ref().map(|store| store.payment_code.clone()))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeKonbiniStoreDetails {
+    pub confirmation_number: Option<String>,
+    pub payment_code: String,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct StripeCashappQrResponse {
     pub mobile_auth_url: Url,
@@ -2945,6 +3124,7 @@ pub struct QrCodeResponse {
 pub struct StripeFinancialInformation {
     pub iban: Option<SepaFinancialDetails>,
     pub sort_code: Option<BacsFinancialDetails>,
+    pub aba: Option<AbaFinancialDetails>,
     pub supported_networks: Vec<String>,
     #[serde(rename = "type")]
     pub financial_info_type: String,
@@ -2966,6 +3146,13 @@ pub struct BacsFinancialDetails {
     pub sort_code: Secret<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct AbaFinancialDetails {
+    pub account_number: Secret<String>,
+    pub bank_name: Option<String>,
+    pub routing_number: Secret<String>,
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -3071,6 +3258,65 @@ pub struct RefundResponse {
     pub payment_intent: String,
     pub status: RefundStatus,
     pub failure_reason: Option<String>,
+    pub pending_reason: Option<RefundPendingReason>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundPendingReason {
+    Processing,
+    InsufficientFunds,
+    ChargePending,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<&RefundPendingReason> for enums::RefundStatusReason {
+    fn from(item: &RefundPendingReason) -> Self {
+        match item {
+            RefundPendingReason::Processing => Self::ProcessingAtConnector,
+            RefundPendingReason::InsufficientFunds => Self::InsufficientMerchantBalance,
+            RefundPendingReason::ChargePending => Self::AwaitingPaymentSettlement,
+            RefundPendingReason::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl RefundResponse {
+    fn get_connector_response(&self) -> Option<types::ConnectorResponseData> {
+        let status_reason = match self.status {
+            RefundStatus::Pending | RefundStatus::RequiresAction => self
+                .pending_reason
+                .as_ref()
+                .map(enums::RefundStatusReason::from),
+            RefundStatus::Failed => {
+                self.failure_reason
+                    .as_deref()
+                    .map(|failure_reason| match failure_reason {
+                        "lost_or_stolen_card" => enums::RefundStatusReason::LostOrStolenCard,
+                        "expired_or_canceled_card" => {
+                            enums::RefundStatusReason::ExpiredOrClosedAccount
+                        }
+                        "charge_for_pending_refund_disputed" => {
+                            enums::RefundStatusReason::PaymentDisputed
+                        }
+                        "insufficient_funds" => {
+                            enums::RefundStatusReason::InsufficientMerchantBalance
+                        }
+                        "declined" => enums::RefundStatusReason::Declined,
+                        "merchant_request" => enums::RefundStatusReason::CanceledByMerchant,
+                        _ => enums::RefundStatusReason::Unknown,
+                    })
+            }
+            RefundStatus::Succeeded => None,
+        };
+        status_reason.map(|status_reason| {
+            types::ConnectorResponseData::with_refund_details(types::ConnectorRefundDetails {
+                expected_settlement_date: None,
+                status_reason: Some(status_reason),
+            })
+        })
+    }
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -3080,6 +3326,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
     ) -> Result<Self, Self::Error> {
+        let connector_response = item.response.get_connector_response();
         let refund_status = enums::RefundStatus::from(item.response.status);
         let response = if connector_util::is_refund_failure(refund_status) {
             Err(types::ErrorResponse {
@@ -3103,6 +3350,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
 
         Ok(Self {
             response,
+            connector_response,
             ..item.data
         })
     }
@@ -3115,6 +3363,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
     ) -> Result<Self, Self::Error> {
+        let connector_response = item.response.get_connector_response();
         let refund_status = enums::RefundStatus::from(item.response.status);
         let response = if connector_util::is_refund_failure(refund_status) {
             Err(types::ErrorResponse {
@@ -3138,6 +3387,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
 
         Ok(Self {
             response,
+            connector_response,
             ..item.data
         })
     }
@@ -3358,6 +3608,7 @@ impl
                     | domain::BankTransferData::DanamonVaBankTransfer { .. }
                     | domain::BankTransferData::MandiriVaBankTransfer { .. }
                     | domain::BankTransferData::LocalBankTransfer { .. }
+                    | domain::BankTransferData::VirtualAccountBankTransfer {}
                     | domain::BankTransferData::Pix { .. }
                     | domain::BankTransferData::Pse { .. } => {
                         Err(errors::ConnectorError::NotImplemented(
@@ -3816,6 +4067,16 @@ impl
                         payment_method_type: StripePaymentMethodType::CustomerBalance,
                     })),
                 )),
+                domain::BankTransferData::VirtualAccountBankTransfer {} => Ok(Self::BankTransfer(
+                    StripeBankTransferData::VirtualAccountBankTransfer(Box::new(
+                        VirtualAccountBankTransferData {
+                            payment_method_data_type: StripePaymentMethodType::CustomerBalance,
+                            bank_transfer_type: BankTransferType::UsBankTransfer,
+                            balance_funding_type: BankTransferType::BankTransfers,
+                            payment_method_type: StripePaymentMethodType::CustomerBalance,
+                        },
+                    )),
+                )),
                 domain::BankTransferData::Pix { .. }
                 | domain::BankTransferData::Pse {}
                 | domain::BankTransferData::PermataBankTransfer { .. }
@@ -57,6 +57,15 @@ pub struct TransferReversals {
 pub struct StripeConnectPayoutFulfillRequest {
     amount: i64,
     currency: enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method: Option<StripeConnectPayoutMethod>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeConnectPayoutMethod {
+    Standard,
+    Instant,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -181,6 +190,10 @@ pub struct RecipientCardAccountRequest {
     external_account_exp_month: Secret<String>,
     #[serde(rename = "external_account[exp_year]")]
     external_account_exp_year: Secret<String>,
+    #[serde(rename = "external_account[currency]")]
+    external_account_currency: enums::Currency,
+    #[serde(rename = "external_account[name]")]
+    external_account_name: Option<Secret<String>>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -206,6 +219,22 @@ pub struct StripeConnectRecipientAccountCreateResponse {
     id: String,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StripeConnectRecipientStatusResponse {
+    id: String,
+    payouts_enabled: bool,
+    requirements: Option<StripeConnectAccountRequirements>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StripeConnectAccountRequirements {
+    #[serde(default)]
+    currently_due: Vec<String>,
+    #[serde(default)]
+    past_due: Vec<String>,
+    disabled_reason: Option<String>,
+}
+
 // Payouts create/transfer request transform
 impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectPayoutCreateRequest {
     type Error = Error;
@@ -239,6 +268,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectPayoutCreateRes
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
@@ -250,9 +280,15 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectPayoutFulfillRequ
     type Error = Error;
     fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
         let request = item.request.to_owned();
+        // Card payouts are pushed to the debit card instantly (Visa Direct / Mastercard Send)
+        let method = match request.payout_type {
+            Some(enums::PayoutType::Card) => Some(StripeConnectPayoutMethod::Instant),
+            Some(enums::PayoutType::Bank) | Some(enums::PayoutType::Wallet) | None => None,
+        };
         Ok(Self {
             amount: request.amount,
             currency: request.destination_currency,
+            method,
         })
     }
 }
@@ -275,6 +311,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectPayoutFulfillRe
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
@@ -307,6 +344,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectReversalRespons
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
@@ -382,6 +420,7 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectRecipientCreate
                 should_add_next_step_to_process_tracker: true,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
             }),
             ..item.data
         })
@@ -398,9 +437,14 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectRecipientAccountC
         let customer_name = customer_details.get_name()?;
         let payout_vendor_details = request.get_vendor_details()?;
         match payout_method_data {
-            api_models::payouts::PayoutMethodData::Card(_) => {
-                Ok(Self::Token(RecipientTokenRequest {
-                    external_account: "tok_visa_debit".to_string(),
+            api_models::payouts::PayoutMethodData::Card(card) => {
+                Ok(Self::Card(RecipientCardAccountRequest {
+                    external_account_object: "card".to_string(),
+                    external_account_number: Secret::new(card.card_number.get_card_no()),
+                    external_account_exp_month: card.expiry_month,
+                    external_account_exp_year: card.expiry_year,
+                    external_account_currency: request.destination_currency.to_owned(),
+                    external_account_name: card.card_holder_name.or(Some(customer_name)),
                 }))
             }
             api_models::payouts::PayoutMethodData::Bank(bank) => match bank {
@@ -465,6 +509,53 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, StripeConnectRecipientAccoun
                 should_add_next_step_to_process_tracker: false,
                 error_code: None,
                 error_message: None,
+                connector_fee: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Recipient account's status response
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripeConnectRecipientStatusResponse,
+            T,
+            types::PayoutRecipientStatusResponseData,
+        >,
+    > for types::RouterData<F, T, types::PayoutRecipientStatusResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeConnectRecipientStatusResponse,
+            T,
+            types::PayoutRecipientStatusResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let requirements = item.response.requirements.unwrap_or_default();
+        let disabled_reason = requirements.disabled_reason;
+        // Stripe prefixes the reason with `rejected.` when the account is rejected, in which case
+        // payouts are never enabled for the account
+        let kyc_status = if disabled_reason
+            .as_deref()
+            .is_some_and(|reason| reason.starts_with("rejected"))
+        {
+            enums::RecipientKycStatus::Rejected
+        } else if item.response.payouts_enabled {
+            enums::RecipientKycStatus::Verified
+        } else if !requirements.currently_due.is_empty() || !requirements.past_due.is_empty() {
+            enums::RecipientKycStatus::RequiresInformation
+        } else {
+            enums::RecipientKycStatus::Pending
+        };
+        Ok(Self {
+            response: Ok(types::PayoutRecipientStatusResponseData {
+                kyc_status,
+                status_reason: disabled_reason,
             }),
             ..item.data
         })
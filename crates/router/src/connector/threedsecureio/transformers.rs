@@ -301,7 +301,7 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
                     field_name: "sdk_information",
                 },
             )?),
-            DeviceChannel::Browser => None,
+            DeviceChannel::Browser | DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         let (acquirer_bin, acquirer_merchant_id) = pre_authentication_data
             .acquirer_bin
@@ -361,6 +361,7 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
             device_channel: match item.router_data.request.device_channel.clone() {
                 DeviceChannel::App => "01",
                 DeviceChannel::Browser => "02",
+                DeviceChannel::ThreeDsRequestorInitiated => "03",
             }
             .to_string(),
             message_category: match item.router_data.request.message_category.clone() {
@@ -438,10 +439,18 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
                     // UI types that the device supports for displaying specific challenge user interfaces within the SDK, 01 for Text
                     sdk_ui_type: vec!["01".to_string()],
                 }),
-                DeviceChannel::Browser => None,
+                DeviceChannel::Browser | DeviceChannel::ThreeDsRequestorInitiated => None,
             },
             cardholder_name: card_holder_name,
             email: request.email.clone(),
+            // Indicates the type of 3RI request, "01" for Recurring transaction
+            three_ri_ind: request.three_ri_indicator.map(|three_ri_indicator| {
+                match three_ri_indicator {
+                    types::authentication::ThreeRiIndicator::RecurringTransaction => {
+                        "01".to_string()
+                    }
+                }
+            }),
         })
     }
 }
@@ -565,6 +574,8 @@ pub struct ThreedsecureioAuthenticationRequest {
     pub trans_type: String,
     pub sdk_max_timeout: Option<String>,
     pub device_render_options: Option<DeviceRenderOptions>,
+    #[serde(rename = "threeRIInd", skip_serializing_if = "Option::is_none")]
+    pub three_ri_ind: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
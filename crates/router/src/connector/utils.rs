@@ -2832,6 +2832,7 @@ pub enum PaymentMethodDataType {
     PaySafeCar,
     CardToken,
     LocalBankTransfer,
+    VirtualAccountBankTransfer,
     Mifinity,
     Fps,
     PromptPay,
@@ -2987,6 +2988,9 @@ impl From<domain::payments::PaymentMethodData> for PaymentMethodDataType {
                     domain::payments::BankTransferData::LocalBankTransfer { .. } => {
                         Self::LocalBankTransfer
                     }
+                    domain::payments::BankTransferData::VirtualAccountBankTransfer {} => {
+                        Self::VirtualAccountBankTransfer
+                    }
                 }
             }
             domain::payments::PaymentMethodData::Crypto(_) => Self::Crypto,
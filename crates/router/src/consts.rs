@@ -10,6 +10,8 @@ pub use hyperswitch_interfaces::consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE};
 // ID generation
 pub(crate) const ID_LENGTH: usize = 20;
 pub(crate) const MAX_ID_LENGTH: usize = 64;
+/// Maximum length of the id prefixes which can be configured by a merchant
+pub(crate) const MAX_ID_PREFIX_LENGTH: usize = 16;
 #[rustfmt::skip]
 pub(crate) const ALPHABETS: [char; 62] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
@@ -37,6 +39,12 @@ pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
 pub const DEFAULT_LIST_API_LIMIT: u16 = 10;
 
+/// Maximum number of objects of a list included when it is expanded in a retrieve response
+pub const MAX_EXPANDED_LIST_LENGTH: i64 = 10;
+
+/// Maximum number of merchant accounts returned by a single merchant account search
+pub const MERCHANT_ACCOUNT_SEARCH_MAX_LIMIT: u32 = 100;
+
 // String literals
 pub(crate) const UNSUPPORTED_ERROR_MESSAGE: &str = "Unsupported response type";
 pub(crate) const LOW_BALANCE_ERROR_MESSAGE: &str = "Insufficient balance in the payment method";
@@ -58,6 +66,12 @@ pub(crate) const API_KEY_LENGTH: usize = 64;
 pub(crate) const APPLEPAY_VALIDATION_URL: &str =
     "https://apple-pay-gateway-cert.apple.com/paymentservices/startSession";
 
+// Prefix for the redis key of a cached Apple Pay merchant session
+pub(crate) const APPLE_PAY_MERCHANT_SESSION_CACHE_PREFIX: &str = "APPLE_PAY_MERCHANT_SESSION_";
+
+// Prefix for the redis key of a bank list fetched from a connector
+pub(crate) const BANK_LIST_CACHE_PREFIX: &str = "BANK_LIST_";
+
 // OID (Object Identifier) for the merchant ID field extension.
 pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32";
 
@@ -76,6 +90,12 @@ pub const SINGLE_PURPOSE_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24 * 2; // 2 days
 
 pub const JWT_TOKEN_COOKIE_NAME: &str = "login_token";
 
+/// Default time after which the token of an embedded dashboard widget expires
+pub const EMBED_TOKEN_DEFAULT_EXPIRY_IN_SECS: u32 = 60 * 15; // 15 minutes
+
+/// Maximum time after which the token of an embedded dashboard widget expires
+pub const EMBED_TOKEN_MAX_EXPIRY_IN_SECS: u32 = 60 * 60; // 1 hour
+
 pub const USER_BLACKLIST_PREFIX: &str = "BU_";
 
 pub const ROLE_BLACKLIST_PREFIX: &str = "BR_";
@@ -87,9 +107,57 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_CONNECTOR_CREDENTIALS_EXPIRY: &str = "Connector Credentials Expiry Notice";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
+pub const EMAIL_SUBJECT_INVOICE: &str = "Invoice for your payment";
+pub const EMAIL_SUBJECT_PAYMENT_RECEIPT: &str = "Your receipt from {{merchant_name}}";
+pub const EMAIL_SUBJECT_REFUND_NOTIFICATION: &str =
+    "Your refund from {{merchant_name}} is complete";
+pub const EMAIL_SUBJECT_PAYOUT_LINK: &str = "You have a payout from {{merchant_name}}";
+pub const EMAIL_SUBJECT_PAYMENT_LINK_RECOVERY: &str = "Complete your payment to {{merchant_name}}";
+
+/// Prefix of the redis key used to ensure an invoice is emailed only once for a payment
+pub const INVOICE_SENT_REDIS_KEY_PREFIX: &str = "INVOICE_SENT_";
+
+/// Expiry of the redis key used to ensure an invoice is emailed only once for a payment (30 days)
+pub const INVOICE_SENT_REDIS_KEY_EXPIRY_SECONDS: i64 = 60 * 60 * 24 * 30;
+
+/// Prefix of the redis key holding the file referenced by a file download token
+pub const FILE_DOWNLOAD_TOKEN_REDIS_KEY_PREFIX: &str = "FILE_DOWNLOAD_TOKEN_";
+
+/// Prefix of the redis key used to ensure a notification email is sent only once for an object
+pub const EMAIL_NOTIFICATION_SENT_REDIS_KEY_PREFIX: &str = "EMAIL_NOTIFICATION_SENT_";
+
+/// Expiry of the redis key used to ensure a notification email is sent only once for an object (30 days)
+pub const EMAIL_NOTIFICATION_SENT_REDIS_KEY_EXPIRY_SECONDS: i64 = 60 * 60 * 24 * 30;
+
+/// Expiry of the redis key tracking the health of the incoming webhooks of a connector account,
+/// refreshed whenever a webhook is received (30 days)
+pub const INCOMING_WEBHOOK_HEALTH_REDIS_KEY_EXPIRY_SECONDS: i64 = 60 * 60 * 24 * 30;
+
+/// Expiry of the redis key counting the payment outcomes of the connector accounts in a canary
+/// rollout, refreshed whenever an outcome is recorded (30 days)
+pub const CONNECTOR_CANARY_ROLLOUT_REDIS_KEY_EXPIRY_SECONDS: i64 = 60 * 60 * 24 * 30;
+
+/// Expiry of the redis key caching the connector session tokens pre-created for a payment, after
+/// which they are created again on the next session tokens call (15 minutes)
+pub const PREWARMED_SESSION_TOKENS_REDIS_KEY_EXPIRY_SECONDS: i64 = 60 * 15;
+
+/// Prefix of the redis key storing a dynamic currency conversion offer, until it expires
+pub const DCC_OFFER_REDIS_KEY_PREFIX: &str = "DCC_OFFER_";
+
+/// Expiry of the redis key metering the billable operations of a merchant in a month, long enough
+/// for the usage to be exported after the month ends (400 days)
+pub const USAGE_METERING_REDIS_KEY_EXPIRY_SECONDS: i64 = 60 * 60 * 24 * 400;
+
+/// Prefix of the redis key caching the access token used to authenticate outgoing webhooks
+pub const OUTGOING_WEBHOOK_OAUTH2_ACCESS_TOKEN_REDIS_KEY_PREFIX: &str =
+    "OUTGOING_WEBHOOK_ACCESS_TOKEN_";
+
+/// Time before the expiry of an outgoing webhook access token after which a new one is obtained
+pub const OUTGOING_WEBHOOK_OAUTH2_ACCESS_TOKEN_EXPIRY_BUFFER_SECONDS: i64 = 60;
 
 pub const ROLE_INFO_CACHE_PREFIX: &str = "CR_INFO_";
 
@@ -107,12 +175,21 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Default number of seconds for which a payment link regenerated for an abandoned payment is active
+pub const DEFAULT_PAYMENT_LINK_RECOVERY_EXPIRY: u32 = 24 * 60 * 60;
+
 /// Max payment intent fulfillment expiry
 pub const MAX_INTENT_FULFILLMENT_EXPIRY: u32 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_INTENT_FULFILLMENT_EXPIRY: u32 = 60;
 
+/// Default time after which the customer is redirected from the intermediate redirection page
+pub const DEFAULT_AUTO_REDIRECT_TIMEOUT_MS: u32 = 300;
+
+/// Default message shown on the intermediate redirection page
+pub const DEFAULT_REDIRECTION_PAGE_MESSAGE: &str = "Please wait while we process your payment...";
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
@@ -129,6 +206,18 @@ pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
 
 // Number of seconds to subtract from access token expiry
 pub(crate) const REDUCE_ACCESS_TOKEN_EXPIRY_TIME: u8 = 15;
+
+// Number of seconds before expiry within which the access token is refreshed
+pub(crate) const ACCESS_TOKEN_PREEMPTIVE_REFRESH_TIME: i64 = 30;
+
+// Number of seconds after which the lock held while refreshing the access token expires
+pub(crate) const ACCESS_TOKEN_LOCK_EXPIRY_TIME: i64 = 30;
+
+// Delay between checks for the access token while it is being refreshed by another request
+pub(crate) const ACCESS_TOKEN_LOCK_RETRY_DELAY_IN_MILLIS: u64 = 100;
+
+// Maximum number of checks for the access token while it is being refreshed by another request
+pub(crate) const ACCESS_TOKEN_LOCK_MAX_RETRIES: u16 = 100;
 pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
 
 //max_amount allowed is 999999999 in minor units
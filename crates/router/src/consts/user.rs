@@ -19,6 +19,9 @@ pub const TOTP_TOLERANCE: u8 = 1;
 pub const TOTP_MAX_ATTEMPTS: u8 = 4;
 /// Number of maximum attempts user has for recovery code
 pub const RECOVERY_CODE_MAX_ATTEMPTS: u8 = 4;
+/// Number of maximum attempts user has for webauthn
+pub const WEBAUTHN_MAX_ATTEMPTS: u8 = 4;
+pub const MAX_WEBAUTHN_CREDENTIAL_NAME_LENGTH: usize = 64;
 
 pub const MAX_PASSWORD_LENGTH: usize = 70;
 pub const MIN_PASSWORD_LENGTH: usize = 8;
@@ -31,6 +34,12 @@ pub const REDIS_TOTP_ATTEMPTS_PREFIX: &str = "TOTP_ATTEMPTS_";
 pub const REDIS_RECOVERY_CODE_ATTEMPTS_PREFIX: &str = "RC_ATTEMPTS_";
 pub const REDIS_TOTP_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
 pub const REDIS_RECOVERY_CODE_ATTEMPTS_TTL_IN_SECS: i64 = 10 * 60; // 10 mins
+pub const REDIS_WEBAUTHN_PREFIX: &str = "WEBAUTHN_";
+pub const REDIS_WEBAUTHN_REGISTRATION_PREFIX: &str = "WEBAUTHN_REG_";
+pub const REDIS_WEBAUTHN_AUTHENTICATION_PREFIX: &str = "WEBAUTHN_AUTH_";
+pub const REDIS_WEBAUTHN_CEREMONY_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
+pub const REDIS_WEBAUTHN_ATTEMPTS_PREFIX: &str = "WEBAUTHN_ATTEMPTS_";
+pub const REDIS_WEBAUTHN_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
 
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
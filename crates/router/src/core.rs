@@ -1,4 +1,5 @@
 pub mod admin;
+pub mod admin_api_keys;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
@@ -9,13 +10,24 @@ pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod config_reconciliation;
 pub mod configs;
+#[cfg(feature = "v1")]
+pub mod connector_credentials_expiry;
+pub mod connector_maintenance;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod connector_slo;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+pub mod data_retention;
 pub mod disputes;
+#[cfg(feature = "v1")]
+pub mod email_templates;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod embed_tokens;
 pub mod encryption;
 pub mod errors;
 pub mod files;
@@ -24,11 +36,24 @@ pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
 #[cfg(feature = "v1")]
+pub mod installment_plans;
+#[cfg(feature = "v1")]
+pub mod invoices;
+#[cfg(feature = "v1")]
+pub mod ledger;
+#[cfg(feature = "v1")]
+pub mod link_delivery;
+#[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
+#[cfg(feature = "v1")]
+pub mod payload_logs;
+pub mod payment_archival;
 pub mod payment_link;
 pub mod payment_methods;
+#[cfg(feature = "v1")]
+pub mod payment_templates;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payout_link;
@@ -42,6 +67,9 @@ pub mod recon;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_config;
+#[cfg(feature = "v1")]
+pub mod test_clocks;
+pub mod usage_metering;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
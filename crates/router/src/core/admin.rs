@@ -9,6 +9,7 @@ use common_utils::{
     ext_traits::{AsyncExt, Encode, OptionExt, ValueExt},
     id_type, pii, type_name,
     types::keymanager::{self as km_types, KeyManagerState, ToEncryptable},
+    validation::bank_account,
 };
 use diesel_models::configs;
 #[cfg(all(any(feature = "v1", feature = "v2"), feature = "olap"))]
@@ -19,15 +20,18 @@ use hyperswitch_domain_models::merchant_connector_account::{
 };
 use masking::{ExposeInterface, PeekInterface, Secret};
 use pm_auth::{connector::plaid::transformers::PlaidAuthType, types as pm_auth_types};
-use regex::Regex;
 use router_env::metrics::add_attributes;
 use uuid::Uuid;
 
+#[cfg(feature = "v1")]
+use crate::core::connector_credentials_expiry;
 #[cfg(any(feature = "v1", feature = "v2"))]
 use crate::types::transformers::ForeignFrom;
 use crate::{
+    configs::settings,
     consts,
     core::{
+        data_retention,
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::{cards, transformers},
@@ -55,9 +59,19 @@ use crate::{
     utils,
 };
 
-const IBAN_MAX_LENGTH: usize = 34;
-const BACS_SORT_CODE_LENGTH: usize = 6;
-const BACS_MAX_ACCOUNT_NUMBER_LENGTH: usize = 8;
+/// The period for which the previous publishable key remains valid after a rotation, unless
+/// specified otherwise
+const DEFAULT_PUBLISHABLE_KEY_OVERLAP_PERIOD_IN_SECS: u32 = 24 * 60 * 60;
+
+/// The maximum period for which the previous publishable key can remain valid after a rotation
+const MAX_PUBLISHABLE_KEY_OVERLAP_PERIOD_IN_SECS: u32 = 7 * 24 * 60 * 60;
+
+/// The period for which the outgoing webhooks are signed with the previous secret after a
+/// rotation, unless specified otherwise
+const DEFAULT_WEBHOOK_SECRET_OVERLAP_PERIOD_IN_SECS: u32 = 24 * 60 * 60;
+
+/// The maximum period for which the outgoing webhooks can be signed with the previous secret
+const MAX_WEBHOOK_SECRET_OVERLAP_PERIOD_IN_SECS: u32 = 7 * 24 * 60 * 60;
 
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
@@ -142,6 +156,7 @@ pub async fn update_organization(
         organization_name: req.organization_name,
         organization_details: req.organization_details,
         metadata: req.metadata,
+        is_token_sharing_enabled: req.is_token_sharing_enabled,
     };
     state
         .store
@@ -257,6 +272,13 @@ pub async fn create_merchant_account(
 
     insert_merchant_configs(db, &merchant_id).await?;
 
+    if merchant_account
+        .get_data_retention_policy()
+        .is_some_and(|data_retention_policy| !data_retention_policy.is_empty())
+    {
+        data_retention::schedule_data_retention_purge(&state, &merchant_id).await?;
+    }
+
     Ok(service_api::ApplicationResponse::Json(
         api::MerchantAccountResponse::foreign_try_from(merchant_account)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -301,6 +323,26 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
             },
         )?;
 
+        if let Some(id_prefixes) = &self.id_prefixes {
+            core_utils::validate_merchant_id_prefixes(id_prefixes)?;
+        }
+
+        let id_prefixes = self.get_id_prefixes_as_value().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "id_prefixes",
+            },
+        )?;
+
+        if let Some(data_retention_policy) = &self.data_retention_policy {
+            core_utils::validate_merchant_data_retention_policy(data_retention_policy)?;
+        }
+
+        let data_retention_policy = self.get_data_retention_policy_as_value().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "data_retention_policy",
+            },
+        )?;
+
         let merchant_details = self.get_merchant_details_as_secret().change_context(
             errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "merchant_details",
@@ -403,6 +445,10 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     payment_link_config: None,
                     pm_collect_link_config,
                     version: hyperswitch_domain_models::consts::API_VERSION,
+                    id_prefixes,
+                    data_retention_policy,
+                    previous_publishable_key: None,
+                    previous_publishable_key_expires_at: None,
                 },
             )
         }
@@ -732,6 +778,87 @@ pub async fn list_merchant_account(
     Ok(services::ApplicationResponse::Json(merchant_accounts))
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn search_merchant_accounts(
+    state: SessionState,
+    organization_id: id_type::OrganizationId,
+    req: admin_types::MerchantAccountSearchRequest,
+) -> RouterResponse<admin_types::MerchantAccountSearchResponse> {
+    if req.limit > consts::MERCHANT_ACCOUNT_SEARCH_MAX_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be less than or equal to {}",
+                consts::MERCHANT_ACCOUNT_SEARCH_MAX_LIMIT
+            ),
+        }
+        .into());
+    }
+
+    // Merchant names are stored encrypted, so the accounts are decrypted and matched here
+    let merchant_accounts = state
+        .store
+        .list_merchant_accounts_by_organization_id(&(&state).into(), &organization_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_name = req
+        .merchant_name
+        .as_ref()
+        .map(|merchant_name| merchant_name.trim().to_lowercase());
+
+    let mut merchant_accounts = merchant_accounts
+        .into_iter()
+        .filter(|merchant_account| {
+            merchant_name.as_ref().map_or(true, |merchant_name| {
+                merchant_account.merchant_name.as_ref().is_some_and(|name| {
+                    name.get_inner()
+                        .peek()
+                        .to_lowercase()
+                        .contains(merchant_name.as_str())
+                })
+            }) && req
+                .publishable_key
+                .as_ref()
+                .map_or(true, |publishable_key| {
+                    merchant_account.publishable_key == *publishable_key
+                })
+                && req.recon_status.map_or(true, |recon_status| {
+                    merchant_account.recon_status == recon_status
+                })
+                && req.created_gte.map_or(true, |created_gte| {
+                    merchant_account.created_at >= created_gte
+                })
+                && req.created_lte.map_or(true, |created_lte| {
+                    merchant_account.created_at <= created_lte
+                })
+        })
+        .collect::<Vec<_>>();
+
+    merchant_accounts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    let total_count = merchant_accounts.len();
+
+    let data = merchant_accounts
+        .into_iter()
+        .skip(usize::try_from(req.offset).unwrap_or(usize::MAX))
+        .take(usize::try_from(req.limit).unwrap_or(usize::MAX))
+        .map(|merchant_account| {
+            api::MerchantAccountResponse::foreign_try_from(merchant_account).change_context(
+                errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "merchant_account",
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        admin_types::MerchantAccountSearchResponse {
+            count: data.len(),
+            total_count,
+            data,
+        },
+    ))
+}
+
 pub async fn get_merchant_account(
     state: SessionState,
     req: api::MerchantId,
@@ -865,6 +992,26 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
             },
         )?;
 
+        if let Some(id_prefixes) = &self.id_prefixes {
+            core_utils::validate_merchant_id_prefixes(id_prefixes)?;
+        }
+
+        let id_prefixes = self.get_id_prefixes_as_value().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "id_prefixes",
+            },
+        )?;
+
+        if let Some(data_retention_policy) = &self.data_retention_policy {
+            core_utils::validate_merchant_data_retention_policy(data_retention_policy)?;
+        }
+
+        let data_retention_policy = self.get_data_retention_policy_as_value().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "data_retention_policy",
+            },
+        )?;
+
         let merchant_details = self.get_merchant_details_as_secret().change_context(
             errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "merchant_details",
@@ -974,6 +1121,8 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
             default_profile: business_profile_id_update,
             payment_link_config: None,
             pm_collect_link_config,
+            id_prefixes,
+            data_retention_policy,
             routing_algorithm: self.routing_algorithm,
         })
     }
@@ -1075,6 +1224,13 @@ pub async fn merchant_account_update(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    if response
+        .get_data_retention_policy()
+        .is_some_and(|data_retention_policy| !data_retention_policy.is_empty())
+    {
+        data_retention::schedule_data_retention_purge(&state, merchant_id).await?;
+    }
+
     Ok(service_api::ApplicationResponse::Json(
         api::MerchantAccountResponse::foreign_try_from(response)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1152,6 +1308,98 @@ pub async fn merchant_account_delete(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Issues a new publishable key for the merchant account. The previous publishable key remains
+/// valid for the overlap period, after which it is rejected by the application and removed from
+/// the decision service.
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn rotate_publishable_key(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: api::RotatePublishableKeyRequest,
+) -> RouterResponse<api::RotatePublishableKeyResponse> {
+    let overlap_period_in_secs = req
+        .overlap_period_in_secs
+        .unwrap_or(DEFAULT_PUBLISHABLE_KEY_OVERLAP_PERIOD_IN_SECS);
+    if overlap_period_in_secs > MAX_PUBLISHABLE_KEY_OVERLAP_PERIOD_IN_SECS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "overlap_period_in_secs must not be greater than {MAX_PUBLISHABLE_KEY_OVERLAP_PERIOD_IN_SECS}"
+            ),
+        }));
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let previous_publishable_key = merchant_account.publishable_key.clone();
+    let previous_publishable_key_expires_at =
+        date_time::now() + time::Duration::seconds(i64::from(overlap_period_in_secs));
+
+    let updated_merchant_account = db
+        .update_merchant(
+            key_manager_state,
+            merchant_account,
+            storage::MerchantAccountUpdate::PublishableKeyRotation {
+                publishable_key: create_merchant_publishable_key(),
+                previous_publishable_key: previous_publishable_key.clone(),
+                previous_publishable_key_expires_at,
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Failed to rotate the publishable key of the merchant account")?;
+
+    add_publishable_key_to_decision_service(&state, &updated_merchant_account);
+
+    // The previous publishable key is registered again with an expiry, so that the decision
+    // service removes it once the overlap period has elapsed
+    let decision_state = state.clone();
+    let previous_key = previous_publishable_key.clone();
+    if overlap_period_in_secs == 0 {
+        authentication::decision::spawn_tracked_job(
+            async move {
+                authentication::decision::revoke_api_key(&decision_state, previous_key.into()).await
+            },
+            authentication::decision::REVOKE,
+        );
+    } else {
+        authentication::decision::spawn_tracked_job(
+            async move {
+                authentication::decision::add_publishable_key(
+                    &decision_state,
+                    previous_key.into(),
+                    merchant_id,
+                    Some(u64::from(overlap_period_in_secs)),
+                )
+                .await
+            },
+            authentication::decision::ADD,
+        );
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::RotatePublishableKeyResponse {
+            merchant_id: updated_merchant_account.get_id().to_owned(),
+            publishable_key: updated_merchant_account.publishable_key,
+            previous_publishable_key,
+            previous_publishable_key_expires_at,
+        },
+    ))
+}
+
 #[cfg(feature = "v1")]
 async fn get_parent_merchant(
     state: &SessionState,
@@ -1197,6 +1445,7 @@ struct ConnectorAuthTypeAndMetadataValidation<'a> {
     connector_name: &'a api_models::enums::Connector,
     auth_type: &'a types::ConnectorAuthType,
     connector_meta_data: &'a Option<pii::SecretSerdeValue>,
+    connectors: &'a settings::Connectors,
 }
 
 impl ConnectorAuthTypeAndMetadataValidation<'_> {
@@ -1207,6 +1456,7 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
             auth_type: self.auth_type,
         };
         connector_auth_type_validation.validate_connector_auth_type()?;
+        self.validate_metadata_against_connector_schema()?;
         self.validate_auth_and_metadata_type_with_connector()
             .map_err(|err| match *err.current_context() {
                 errors::ConnectorError::InvalidConnectorName => {
@@ -1368,6 +1618,14 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
                 forte::transformers::ForteAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Generichttp => {
+                generichttp::transformers::GenerichttpAuthType::try_from(self.auth_type)?;
+                generichttp::transformers::GenerichttpConnectorMetadataObject::try_from(
+                    self.connector_meta_data.as_ref(),
+                )?
+                .validate_urls(&self.connectors.generichttp.allowed_hosts)?;
+                Ok(())
+            }
             api_enums::Connector::Globalpay => {
                 globalpay::transformers::GlobalpayAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -1480,6 +1738,13 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
                 placetopay::transformers::PlacetopayAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Plugin => {
+                plugin::transformers::PluginAuthType::try_from(self.auth_type)?;
+                plugin::transformers::PluginConnectorMetadataObject::try_from(
+                    self.connector_meta_data.as_ref(),
+                )?;
+                Ok(())
+            }
             api_enums::Connector::Powertranz => {
                 powertranz::transformers::PowertranzAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -1572,6 +1837,218 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
     }
 }
 
+/// The type of value expected for a key of the connector metadata
+#[derive(Clone, Copy, Debug)]
+enum ConnectorMetadataFieldType {
+    String,
+    Boolean,
+    Object,
+    OneOf(&'static [&'static str]),
+}
+
+impl ConnectorMetadataFieldType {
+    fn is_valid(&self, value: &serde_json::Value) -> bool {
+        match self {
+            Self::String => value.as_str().is_some_and(|value| !value.trim().is_empty()),
+            Self::Boolean => value.is_boolean(),
+            Self::Object => value.is_object(),
+            Self::OneOf(variants) => value
+                .as_str()
+                .is_some_and(|value| variants.contains(&value)),
+        }
+    }
+
+    fn expected_format(&self) -> String {
+        match self {
+            Self::String => "a non empty string".to_string(),
+            Self::Boolean => "a boolean".to_string(),
+            Self::Object => "an object".to_string(),
+            Self::OneOf(variants) => format!("one of {}", variants.join(", ")),
+        }
+    }
+}
+
+/// A key of the connector metadata which the connector reads
+struct ConnectorMetadataField {
+    key: &'static str,
+    field_type: ConnectorMetadataFieldType,
+    required: bool,
+}
+
+impl ConnectorMetadataField {
+    const fn required(key: &'static str, field_type: ConnectorMetadataFieldType) -> Self {
+        Self {
+            key,
+            field_type,
+            required: true,
+        }
+    }
+
+    const fn optional(key: &'static str, field_type: ConnectorMetadataFieldType) -> Self {
+        Self {
+            key,
+            field_type,
+            required: false,
+        }
+    }
+}
+
+const ADYEN_METADATA_SCHEMA: &[ConnectorMetadataField] = &[ConnectorMetadataField::optional(
+    "endpoint_prefix",
+    ConnectorMetadataFieldType::String,
+)];
+
+const BRAINTREE_METADATA_SCHEMA: &[ConnectorMetadataField] = &[
+    ConnectorMetadataField::required("merchant_account_id", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required(
+        "merchant_config_currency",
+        ConnectorMetadataFieldType::String,
+    ),
+];
+
+const COINBASE_METADATA_SCHEMA: &[ConnectorMetadataField] = &[ConnectorMetadataField::required(
+    "pricing_type",
+    ConnectorMetadataFieldType::String,
+)];
+
+const CYBERSOURCE_METADATA_SCHEMA: &[ConnectorMetadataField] = &[
+    ConnectorMetadataField::optional("disable_avs", ConnectorMetadataFieldType::Boolean),
+    ConnectorMetadataField::optional("disable_cvn", ConnectorMetadataFieldType::Boolean),
+];
+
+const FISERV_METADATA_SCHEMA: &[ConnectorMetadataField] = &[ConnectorMetadataField::required(
+    "terminal_id",
+    ConnectorMetadataFieldType::String,
+)];
+
+const GENERICHTTP_METADATA_SCHEMA: &[ConnectorMetadataField] = &[
+    ConnectorMetadataField::optional("authentication", ConnectorMetadataFieldType::Object),
+    ConnectorMetadataField::required("authorize", ConnectorMetadataFieldType::Object),
+    ConnectorMetadataField::optional("capture", ConnectorMetadataFieldType::Object),
+    ConnectorMetadataField::optional("void", ConnectorMetadataFieldType::Object),
+    ConnectorMetadataField::optional("psync", ConnectorMetadataFieldType::Object),
+    ConnectorMetadataField::optional("refund", ConnectorMetadataFieldType::Object),
+    ConnectorMetadataField::optional("rsync", ConnectorMetadataFieldType::Object),
+];
+
+const GPAYMENTS_METADATA_SCHEMA: &[ConnectorMetadataField] = &[
+    ConnectorMetadataField::required("endpoint_prefix", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required("merchant_id", ConnectorMetadataFieldType::String),
+];
+
+const KLARNA_METADATA_SCHEMA: &[ConnectorMetadataField] = &[ConnectorMetadataField::optional(
+    "klarna_region",
+    ConnectorMetadataFieldType::OneOf(&["Europe", "NorthAmerica", "Oceania"]),
+)];
+
+const MIFINITY_METADATA_SCHEMA: &[ConnectorMetadataField] = &[
+    ConnectorMetadataField::required("brand_id", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required(
+        "destination_account_number",
+        ConnectorMetadataFieldType::String,
+    ),
+];
+
+const NETCETERA_METADATA_SCHEMA: &[ConnectorMetadataField] = &[
+    ConnectorMetadataField::required("mcc", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required("merchant_country_code", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required("merchant_name", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required("endpoint_prefix", ConnectorMetadataFieldType::String),
+    ConnectorMetadataField::required(
+        "three_ds_requestor_name",
+        ConnectorMetadataFieldType::String,
+    ),
+    ConnectorMetadataField::required("three_ds_requestor_id", ConnectorMetadataFieldType::String),
+];
+
+const PLUGIN_METADATA_SCHEMA: &[ConnectorMetadataField] = &[ConnectorMetadataField::required(
+    "plugin_name",
+    ConnectorMetadataFieldType::String,
+)];
+
+impl ConnectorAuthTypeAndMetadataValidation<'_> {
+    /// The keys of the connector metadata read by the connector, `None` if the connector does not
+    /// read the connector metadata
+    fn get_connector_metadata_schema(&self) -> Option<&'static [ConnectorMetadataField]> {
+        match self.connector_name {
+            api_enums::Connector::Adyen => Some(ADYEN_METADATA_SCHEMA),
+            api_enums::Connector::Braintree => Some(BRAINTREE_METADATA_SCHEMA),
+            api_enums::Connector::Coinbase => Some(COINBASE_METADATA_SCHEMA),
+            api_enums::Connector::Cybersource => Some(CYBERSOURCE_METADATA_SCHEMA),
+            api_enums::Connector::Fiserv => Some(FISERV_METADATA_SCHEMA),
+            api_enums::Connector::Generichttp => Some(GENERICHTTP_METADATA_SCHEMA),
+            api_enums::Connector::Gpayments => Some(GPAYMENTS_METADATA_SCHEMA),
+            api_enums::Connector::Klarna => Some(KLARNA_METADATA_SCHEMA),
+            api_enums::Connector::Mifinity => Some(MIFINITY_METADATA_SCHEMA),
+            api_enums::Connector::Netcetera => Some(NETCETERA_METADATA_SCHEMA),
+            api_enums::Connector::Plugin => Some(PLUGIN_METADATA_SCHEMA),
+            _ => None,
+        }
+    }
+
+    /// Validates the connector metadata against the schema of the connector, listing every key
+    /// which is missing or does not hold a value of the expected type
+    fn validate_metadata_against_connector_schema(
+        &self,
+    ) -> Result<(), error_stack::Report<errors::ApiErrorResponse>> {
+        let Some(schema) = self.get_connector_metadata_schema() else {
+            return Ok(());
+        };
+        let metadata = self
+            .connector_meta_data
+            .as_ref()
+            .map(|metadata| metadata.peek());
+
+        utils::when(
+            metadata.is_some_and(|metadata| !metadata.is_object()),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "metadata".to_string(),
+                    expected_format: "an object".to_string(),
+                }))
+            },
+        )?;
+
+        let mut missing_keys = Vec::new();
+        let mut malformed_keys = Vec::new();
+        for field in schema {
+            match metadata
+                .and_then(|metadata| metadata.get(field.key))
+                .filter(|value| !value.is_null())
+            {
+                None if field.required => missing_keys.push(format!("metadata.{}", field.key)),
+                None => (),
+                Some(value) if !field.field_type.is_valid(value) => malformed_keys.push(format!(
+                    "metadata.{} (expected {})",
+                    field.key,
+                    field.field_type.expected_format()
+                )),
+                Some(_) => (),
+            }
+        }
+
+        let metadata_errors = [
+            (!missing_keys.is_empty())
+                .then(|| format!("missing keys: {}", missing_keys.join(", "))),
+            (!malformed_keys.is_empty())
+                .then(|| format!("malformed keys: {}", malformed_keys.join(", "))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        utils::when(!metadata_errors.is_empty(), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The metadata is invalid for the connector {}, {}",
+                    self.connector_name,
+                    metadata_errors.join("; ")
+                ),
+            }))
+        })
+    }
+}
+
 struct ConnectorAuthTypeValidation<'a> {
     auth_type: &'a types::ConnectorAuthType,
 }
@@ -2052,6 +2529,7 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             connector_name: &connector_enum,
             auth_type: &auth,
             connector_meta_data: &metadata,
+            connectors: &state.conf.connectors,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -2147,6 +2625,8 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             },
             applepay_verified_domains: None,
             pm_auth_config: Box::new(self.pm_auth_config),
+            tags: self.tags,
+            credentials_expiry: self.credentials_expiry.map(ForeignInto::foreign_into),
             status: Some(connector_status),
             additional_merchant_data: Box::new(encrypted_data.additional_merchant_data),
             connector_wallets_details: Box::new(encrypted_data.connector_wallets_details),
@@ -2220,6 +2700,7 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             connector_name: &connector_enum,
             auth_type: &auth,
             connector_meta_data: &metadata,
+            connectors: &state.conf.connectors,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -2322,6 +2803,8 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             },
             applepay_verified_domains: None,
             pm_auth_config: Box::new(self.pm_auth_config),
+            tags: self.tags,
+            credentials_expiry: self.credentials_expiry.map(ForeignInto::foreign_into),
             status: Some(connector_status),
             additional_merchant_data: Box::new(encrypted_data.additional_merchant_data),
             connector_wallets_details: Box::new(encrypted_data.connector_wallets_details),
@@ -2379,6 +2862,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             connector_name: &self.connector_name,
             auth_type: &auth,
             connector_meta_data: &self.metadata,
+            connectors: &state.conf.connectors,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -2475,6 +2959,11 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             profile_id: business_profile.get_id().to_owned(),
             applepay_verified_domains: None,
             pm_auth_config: self.pm_auth_config.clone(),
+            tags: self.tags.clone(),
+            credentials_expiry: self
+                .credentials_expiry
+                .clone()
+                .map(ForeignInto::foreign_into),
             status: connector_status,
             connector_wallets_details: encrypted_data.connector_wallets_details,
             additional_merchant_data: encrypted_data.additional_merchant_data,
@@ -2551,6 +3040,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             connector_name: &self.connector_name,
             auth_type: &auth,
             connector_meta_data: &self.metadata,
+            connectors: &state.conf.connectors,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -2647,6 +3137,11 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             profile_id: business_profile.get_id().to_owned(),
             applepay_verified_domains: None,
             pm_auth_config: self.pm_auth_config.clone(),
+            tags: self.tags.clone(),
+            credentials_expiry: self
+                .credentials_expiry
+                .clone()
+                .map(ForeignInto::foreign_into),
             status: connector_status,
             connector_wallets_details: encrypted_data.connector_wallets_details,
             test_mode: self.test_mode,
@@ -2843,6 +3338,15 @@ pub async fn create_connector(
         ]),
     );
 
+    #[cfg(feature = "v1")]
+    if mca.credentials_expiry.is_some() {
+        connector_credentials_expiry::schedule_connector_credentials_expiry_reminders(
+            &state,
+            merchant_id,
+        )
+        .await?;
+    }
+
     let mca_response = mca.foreign_try_into()?;
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
@@ -2978,6 +3482,7 @@ pub async fn list_connectors_for_a_profile(
     state: SessionState,
     key_store: domain::MerchantKeyStore,
     profile_id: id_type::ProfileId,
+    tag: Option<String>,
 ) -> RouterResponse<Vec<api_models::admin::MerchantConnectorListResponse>> {
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -2986,6 +3491,8 @@ pub async fn list_connectors_for_a_profile(
         .list_connector_account_by_profile_id(key_manager_state, &profile_id, &key_store)
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+    let merchant_connector_accounts =
+        core_utils::filter_objects_based_on_tag(tag, merchant_connector_accounts);
     let mut response = vec![];
 
     for mca in merchant_connector_accounts.into_iter() {
@@ -2999,6 +3506,7 @@ pub async fn list_payment_connectors(
     state: SessionState,
     merchant_id: id_type::MerchantId,
     profile_id_list: Option<Vec<id_type::ProfileId>>,
+    tag: Option<String>,
 ) -> RouterResponse<Vec<api_models::admin::MerchantConnectorListResponse>> {
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -3030,6 +3538,8 @@ pub async fn list_payment_connectors(
         profile_id_list,
         merchant_connector_accounts,
     );
+    let merchant_connector_accounts =
+        core_utils::filter_objects_based_on_tag(tag, merchant_connector_accounts);
     let mut response = vec![];
 
     // The can be eliminated once [#79711](https://github.com/rust-lang/rust/issues/79711) is stabilized
@@ -3112,11 +3622,133 @@ pub async fn update_connector(
             )
         })?;
 
+    #[cfg(feature = "v1")]
+    if updated_mca.credentials_expiry.is_some() {
+        connector_credentials_expiry::schedule_connector_credentials_expiry_reminders(
+            &state,
+            merchant_id,
+        )
+        .await?;
+    }
+
     let response = updated_mca.foreign_try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn bulk_toggle_connectors(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    req: api_models::admin::MerchantConnectorBulkToggleRequest,
+) -> RouterResponse<api_models::admin::MerchantConnectorBulkToggleResponse> {
+    if req.connector_name.is_none() && req.profile_id.is_none() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Either connector_name or profile_id must be provided".to_string(),
+        }
+        .into());
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_id,
+            true,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    let merchant_connector_accounts = core_utils::filter_objects_based_on_profile_id_list(
+        profile_id_list,
+        merchant_connector_accounts,
+    )
+    .into_iter()
+    .filter(|mca| {
+        req.connector_name.map_or(true, |connector_name| {
+            mca.connector_name == connector_name.to_string()
+        }) && req
+            .profile_id
+            .as_ref()
+            .map_or(true, |profile_id| mca.profile_id == *profile_id)
+    })
+    .collect::<Vec<_>>();
+
+    // Validate all the connector accounts before updating any of them, so that the
+    // accounts are either all toggled or none of them are
+    let mut mca_to_update = Vec::with_capacity(merchant_connector_accounts.len());
+    let mut response = Vec::with_capacity(merchant_connector_accounts.len());
+    for mca in merchant_connector_accounts {
+        let auth: types::ConnectorAuthType = mca
+            .connector_account_details
+            .clone()
+            .into_inner()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to parse connector account details of {:?}",
+                    mca.get_id()
+                )
+            })?;
+
+        let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
+            status: &None,
+            disabled: &Some(req.disabled),
+            auth: &auth,
+            current_status: &mca.status,
+        };
+        let (connector_status, disabled) = connector_status_and_disabled_validation
+            .validate_status_and_disabled()
+            .attach_printable_lazy(|| {
+                format!(
+                    "Unable to toggle merchant connector account {:?}",
+                    mca.get_id()
+                )
+            })?;
+
+        response.push(api_models::admin::MerchantConnectorToggleResponse {
+            merchant_connector_id: mca.get_id(),
+            connector_name: mca.connector_name.clone(),
+            profile_id: mca.profile_id.clone(),
+            disabled,
+            status: connector_status,
+        });
+        mca_to_update.push((
+            mca,
+            storage::MerchantConnectorAccountUpdate::StatusUpdate {
+                disabled,
+                status: connector_status,
+            }
+            .into(),
+        ));
+    }
+
+    db.update_multiple_merchant_connector_accounts(mca_to_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while toggling merchant connector accounts")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::MerchantConnectorBulkToggleResponse {
+            count: response.len(),
+            data: response,
+        },
+    ))
+}
+
 #[cfg(feature = "v1")]
 pub async fn delete_connector(
     state: SessionState,
@@ -3486,7 +4118,190 @@ impl ProfileCreateBridge for api::ProfileCreate {
             })
             .transpose()?;
 
-        Ok(domain::Profile::from(domain::ProfileSetter {
+        let customer_portal_link_config = self
+            .customer_portal_link_config
+            .map(|portal_conf| match portal_conf.validate() {
+                Ok(_) => Ok(portal_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let statement_descriptor_config = self
+            .statement_descriptor_config
+            .map(|descriptor_conf| match descriptor_conf.validate() {
+                Ok(_) => Ok(descriptor_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let dispute_representment_config = self
+            .dispute_representment_config
+            .map(|representment_conf| match representment_conf.validate() {
+                Ok(_) => Ok(representment_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let redirect_config = self
+            .redirect_config
+            .map(|redirect_conf| match redirect_conf.validate() {
+                Ok(_) => Ok(redirect_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let post_auth_rules_config = self
+            .post_auth_rules_config
+            .map(
+                |post_auth_rules_conf| match post_auth_rules_conf.validate() {
+                    Ok(_) => Ok(post_auth_rules_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let payout_retry_config = self
+            .payout_retry_config
+            .map(|payout_retry_conf| match payout_retry_conf.validate() {
+                Ok(_) => Ok(payout_retry_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fulfillment_config = self
+            .payout_fulfillment_config
+            .map(
+                |payout_fulfillment_conf| match payout_fulfillment_conf.validate() {
+                    Ok(_) => Ok(payout_fulfillment_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let connector_canary_rollout = self
+            .connector_canary_rollout
+            .map(
+                |connector_canary_rollout_conf| match connector_canary_rollout_conf.validate() {
+                    Ok(_) => Ok(connector_canary_rollout_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let operation_fallback_config = self
+            .operation_fallback_config
+            .map(
+                |operation_fallback_conf| match operation_fallback_conf.validate() {
+                    Ok(_) => Ok(operation_fallback_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let dcc_config = self
+            .dcc_config
+            .map(|dcc_conf| match dcc_conf.validate() {
+                Ok(_) => Ok(dcc_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fee_config = self
+            .payout_fee_config
+            .map(|payout_fee_conf| match payout_fee_conf.validate() {
+                Ok(_) => Ok(payout_fee_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_balance_config = self
+            .payout_balance_config
+            .map(|payout_balance_conf| match payout_balance_conf.validate() {
+                Ok(_) => Ok(payout_balance_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let client_origin_config = self
+            .client_origin_config
+            .map(|client_origin_conf| match client_origin_conf.validate() {
+                Ok(_) => Ok(client_origin_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let outgoing_webhook_auth_config = self
+            .outgoing_webhook_auth
+            .map(|auth_conf| match auth_conf.validate() {
+                Ok(_) => Ok(auth_conf),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?
+            .async_map(|auth_conf| {
+                cards::create_encrypted_data(&key_manager_state, key_store, auth_conf)
+            })
+            .await
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt outgoing webhook authentication config")?;
+
+        Ok(domain::Profile::from(domain::ProfileSetter {
             profile_id,
             merchant_id: merchant_account.get_id().clone(),
             profile_name,
@@ -3556,6 +4371,32 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_auto_retries_enabled: self.is_auto_retries_enabled.unwrap_or_default(),
             max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+            customer_portal_link_config,
+            invoice_config: self.invoice_config.map(ForeignInto::foreign_into),
+            enabled_webhook_events: self.enabled_webhook_events,
+            webhook_payload_version: self.webhook_payload_version,
+            statement_descriptor_config,
+            dispute_representment_config,
+            outgoing_webhook_auth_config: outgoing_webhook_auth_config.map(Into::into),
+            redirect_config,
+            client_origin_config,
+            is_client_secret_single_use: self.is_client_secret_single_use,
+            post_auth_rules_config,
+            allowed_currencies: self.allowed_currencies,
+            tags: self.tags,
+            payout_retry_config,
+            payout_fulfillment_config,
+            payment_defaults_config: self.payment_defaults_config.map(ForeignInto::foreign_into),
+            capture_on_shipment_config: self
+                .capture_on_shipment_config
+                .map(ForeignInto::foreign_into),
+            dcc_config,
+            payout_fee_config,
+            payout_balance_config,
+            account_name_mismatch_action: self.account_name_mismatch_action,
+            connector_canary_rollout,
+            operation_fallback_config,
+            webhook_secret_rotation: None,
         }))
     }
 
@@ -3607,6 +4448,189 @@ impl ProfileCreateBridge for api::ProfileCreate {
             })
             .transpose()?;
 
+        let customer_portal_link_config = self
+            .customer_portal_link_config
+            .map(|portal_conf| match portal_conf.validate() {
+                Ok(_) => Ok(portal_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let statement_descriptor_config = self
+            .statement_descriptor_config
+            .map(|descriptor_conf| match descriptor_conf.validate() {
+                Ok(_) => Ok(descriptor_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let dispute_representment_config = self
+            .dispute_representment_config
+            .map(|representment_conf| match representment_conf.validate() {
+                Ok(_) => Ok(representment_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let redirect_config = self
+            .redirect_config
+            .map(|redirect_conf| match redirect_conf.validate() {
+                Ok(_) => Ok(redirect_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let post_auth_rules_config = self
+            .post_auth_rules_config
+            .map(
+                |post_auth_rules_conf| match post_auth_rules_conf.validate() {
+                    Ok(_) => Ok(post_auth_rules_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let payout_retry_config = self
+            .payout_retry_config
+            .map(|payout_retry_conf| match payout_retry_conf.validate() {
+                Ok(_) => Ok(payout_retry_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fulfillment_config = self
+            .payout_fulfillment_config
+            .map(
+                |payout_fulfillment_conf| match payout_fulfillment_conf.validate() {
+                    Ok(_) => Ok(payout_fulfillment_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let connector_canary_rollout = self
+            .connector_canary_rollout
+            .map(
+                |connector_canary_rollout_conf| match connector_canary_rollout_conf.validate() {
+                    Ok(_) => Ok(connector_canary_rollout_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let operation_fallback_config = self
+            .operation_fallback_config
+            .map(
+                |operation_fallback_conf| match operation_fallback_conf.validate() {
+                    Ok(_) => Ok(operation_fallback_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let dcc_config = self
+            .dcc_config
+            .map(|dcc_conf| match dcc_conf.validate() {
+                Ok(_) => Ok(dcc_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fee_config = self
+            .payout_fee_config
+            .map(|payout_fee_conf| match payout_fee_conf.validate() {
+                Ok(_) => Ok(payout_fee_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_balance_config = self
+            .payout_balance_config
+            .map(|payout_balance_conf| match payout_balance_conf.validate() {
+                Ok(_) => Ok(payout_balance_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let client_origin_config = self
+            .client_origin_config
+            .map(|client_origin_conf| match client_origin_conf.validate() {
+                Ok(_) => Ok(client_origin_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let outgoing_webhook_auth_config = self
+            .outgoing_webhook_auth
+            .map(|auth_conf| match auth_conf.validate() {
+                Ok(_) => Ok(auth_conf),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?
+            .async_map(|auth_conf| {
+                cards::create_encrypted_data(&key_manager_state, key_store, auth_conf)
+            })
+            .await
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt outgoing webhook authentication config")?;
+
         Ok(domain::Profile::from(domain::ProfileSetter {
             id: profile_id,
             merchant_id: merchant_id.clone(),
@@ -3664,6 +4688,32 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_tax_connector_enabled: self.is_tax_connector_enabled,
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+            customer_portal_link_config,
+            invoice_config: self.invoice_config.map(ForeignInto::foreign_into),
+            enabled_webhook_events: self.enabled_webhook_events,
+            webhook_payload_version: self.webhook_payload_version,
+            statement_descriptor_config,
+            dispute_representment_config,
+            outgoing_webhook_auth_config: outgoing_webhook_auth_config.map(Into::into),
+            redirect_config,
+            client_origin_config,
+            is_client_secret_single_use: self.is_client_secret_single_use,
+            post_auth_rules_config,
+            allowed_currencies: self.allowed_currencies,
+            tags: self.tags,
+            payout_retry_config,
+            payout_fulfillment_config,
+            payment_defaults_config: self.payment_defaults_config.map(ForeignInto::foreign_into),
+            capture_on_shipment_config: self
+                .capture_on_shipment_config
+                .map(ForeignInto::foreign_into),
+            dcc_config,
+            payout_fee_config,
+            payout_balance_config,
+            account_name_mismatch_action: self.account_name_mismatch_action,
+            connector_canary_rollout,
+            operation_fallback_config,
+            webhook_secret_rotation: None,
         }))
     }
 }
@@ -3726,6 +4776,7 @@ pub async fn list_profile(
     state: SessionState,
     merchant_id: id_type::MerchantId,
     profile_id_list: Option<Vec<id_type::ProfileId>>,
+    tag: Option<String>,
 ) -> RouterResponse<Vec<api_models::admin::ProfileResponse>> {
     let db = state.store.as_ref();
     let key_store = db
@@ -3742,6 +4793,7 @@ pub async fn list_profile(
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?
         .clone();
     let profiles = core_utils::filter_objects_based_on_profile_id_list(profile_id_list, profiles);
+    let profiles = core_utils::filter_objects_based_on_tag(tag, profiles);
     let mut business_profiles = Vec::new();
     for profile in profiles {
         let business_profile = api_models::admin::ProfileResponse::foreign_try_from(profile)
@@ -3828,48 +4880,229 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 .attach_printable("Invalid routing algorithm given")?;
         }
 
-        let payment_link_config = self
-            .payment_link_config
-            .map(|payment_link_conf| match payment_link_conf.validate() {
-                Ok(_) => Ok(payment_link_conf.foreign_into()),
-                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-                    message: e.to_string()
-                })),
+        let payment_link_config = self
+            .payment_link_config
+            .map(|payment_link_conf| match payment_link_conf.validate() {
+                Ok(_) => Ok(payment_link_conf.foreign_into()),
+                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                })),
+            })
+            .transpose()?;
+
+        let extended_card_info_config = self
+            .extended_card_info_config
+            .as_ref()
+            .map(|config| {
+                config.encode_to_value().change_context(
+                    errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "extended_card_info_config",
+                    },
+                )
+            })
+            .transpose()?
+            .map(Secret::new);
+        let key_manager_state = state.into();
+        let outgoing_webhook_custom_http_headers = self
+            .outgoing_webhook_custom_http_headers
+            .async_map(|headers| {
+                cards::create_encrypted_data(&key_manager_state, key_store, headers)
+            })
+            .await
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
+
+        let payout_link_config = self
+            .payout_link_config
+            .map(|payout_conf| match payout_conf.config.validate() {
+                Ok(_) => Ok(payout_conf.foreign_into()),
+                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                })),
+            })
+            .transpose()?;
+
+        let customer_portal_link_config = self
+            .customer_portal_link_config
+            .map(|portal_conf| match portal_conf.validate() {
+                Ok(_) => Ok(portal_conf.foreign_into()),
+                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                })),
+            })
+            .transpose()?;
+
+        let statement_descriptor_config = self
+            .statement_descriptor_config
+            .map(|descriptor_conf| match descriptor_conf.validate() {
+                Ok(_) => Ok(descriptor_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let dispute_representment_config = self
+            .dispute_representment_config
+            .map(|representment_conf| match representment_conf.validate() {
+                Ok(_) => Ok(representment_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let redirect_config = self
+            .redirect_config
+            .map(|redirect_conf| match redirect_conf.validate() {
+                Ok(_) => Ok(redirect_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let post_auth_rules_config = self
+            .post_auth_rules_config
+            .map(
+                |post_auth_rules_conf| match post_auth_rules_conf.validate() {
+                    Ok(_) => Ok(post_auth_rules_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let payout_retry_config = self
+            .payout_retry_config
+            .map(|payout_retry_conf| match payout_retry_conf.validate() {
+                Ok(_) => Ok(payout_retry_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fulfillment_config = self
+            .payout_fulfillment_config
+            .map(
+                |payout_fulfillment_conf| match payout_fulfillment_conf.validate() {
+                    Ok(_) => Ok(payout_fulfillment_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let connector_canary_rollout = self
+            .connector_canary_rollout
+            .map(
+                |connector_canary_rollout_conf| match connector_canary_rollout_conf.validate() {
+                    Ok(_) => Ok(connector_canary_rollout_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let operation_fallback_config = self
+            .operation_fallback_config
+            .map(
+                |operation_fallback_conf| match operation_fallback_conf.validate() {
+                    Ok(_) => Ok(operation_fallback_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let dcc_config = self
+            .dcc_config
+            .map(|dcc_conf| match dcc_conf.validate() {
+                Ok(_) => Ok(dcc_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fee_config = self
+            .payout_fee_config
+            .map(|payout_fee_conf| match payout_fee_conf.validate() {
+                Ok(_) => Ok(payout_fee_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_balance_config = self
+            .payout_balance_config
+            .map(|payout_balance_conf| match payout_balance_conf.validate() {
+                Ok(_) => Ok(payout_balance_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let client_origin_config = self
+            .client_origin_config
+            .map(|client_origin_conf| match client_origin_conf.validate() {
+                Ok(_) => Ok(client_origin_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
             })
             .transpose()?;
 
-        let extended_card_info_config = self
-            .extended_card_info_config
-            .as_ref()
-            .map(|config| {
-                config.encode_to_value().change_context(
-                    errors::ApiErrorResponse::InvalidDataValue {
-                        field_name: "extended_card_info_config",
-                    },
-                )
+        let outgoing_webhook_auth_config = self
+            .outgoing_webhook_auth
+            .map(|auth_conf| match auth_conf.validate() {
+                Ok(_) => Ok(auth_conf),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
             })
             .transpose()?
-            .map(Secret::new);
-        let key_manager_state = state.into();
-        let outgoing_webhook_custom_http_headers = self
-            .outgoing_webhook_custom_http_headers
-            .async_map(|headers| {
-                cards::create_encrypted_data(&key_manager_state, key_store, headers)
+            .async_map(|auth_conf| {
+                cards::create_encrypted_data(&key_manager_state, key_store, auth_conf)
             })
             .await
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
-
-        let payout_link_config = self
-            .payout_link_config
-            .map(|payout_conf| match payout_conf.config.validate() {
-                Ok(_) => Ok(payout_conf.foreign_into()),
-                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-                    message: e.to_string()
-                })),
-            })
-            .transpose()?;
+            .attach_printable("Unable to encrypt outgoing webhook authentication config")?;
 
         Ok(domain::ProfileUpdate::Update(Box::new(
             domain::ProfileGeneralUpdate {
@@ -3914,6 +5147,33 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 is_auto_retries_enabled: self.is_auto_retries_enabled,
                 max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+                customer_portal_link_config,
+                invoice_config: self.invoice_config.map(ForeignInto::foreign_into),
+                enabled_webhook_events: self.enabled_webhook_events,
+                webhook_payload_version: self.webhook_payload_version,
+                statement_descriptor_config,
+                dispute_representment_config,
+                outgoing_webhook_auth_config: outgoing_webhook_auth_config.map(Into::into),
+                redirect_config,
+                client_origin_config,
+                is_client_secret_single_use: self.is_client_secret_single_use,
+                post_auth_rules_config,
+                allowed_currencies: self.allowed_currencies,
+                tags: self.tags,
+                payout_retry_config,
+                payout_fulfillment_config,
+                payment_defaults_config: self
+                    .payment_defaults_config
+                    .map(ForeignInto::foreign_into),
+                capture_on_shipment_config: self
+                    .capture_on_shipment_config
+                    .map(ForeignInto::foreign_into),
+                dcc_config,
+                payout_fee_config,
+                payout_balance_config,
+                account_name_mismatch_action: self.account_name_mismatch_action,
+                connector_canary_rollout,
+                operation_fallback_config,
             },
         )))
     }
@@ -3976,6 +5236,187 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        let customer_portal_link_config = self
+            .customer_portal_link_config
+            .map(|portal_conf| match portal_conf.validate() {
+                Ok(_) => Ok(portal_conf.foreign_into()),
+                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                })),
+            })
+            .transpose()?;
+
+        let statement_descriptor_config = self
+            .statement_descriptor_config
+            .map(|descriptor_conf| match descriptor_conf.validate() {
+                Ok(_) => Ok(descriptor_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let dispute_representment_config = self
+            .dispute_representment_config
+            .map(|representment_conf| match representment_conf.validate() {
+                Ok(_) => Ok(representment_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let redirect_config = self
+            .redirect_config
+            .map(|redirect_conf| match redirect_conf.validate() {
+                Ok(_) => Ok(redirect_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let post_auth_rules_config = self
+            .post_auth_rules_config
+            .map(
+                |post_auth_rules_conf| match post_auth_rules_conf.validate() {
+                    Ok(_) => Ok(post_auth_rules_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let payout_retry_config = self
+            .payout_retry_config
+            .map(|payout_retry_conf| match payout_retry_conf.validate() {
+                Ok(_) => Ok(payout_retry_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fulfillment_config = self
+            .payout_fulfillment_config
+            .map(
+                |payout_fulfillment_conf| match payout_fulfillment_conf.validate() {
+                    Ok(_) => Ok(payout_fulfillment_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let connector_canary_rollout = self
+            .connector_canary_rollout
+            .map(
+                |connector_canary_rollout_conf| match connector_canary_rollout_conf.validate() {
+                    Ok(_) => Ok(connector_canary_rollout_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let operation_fallback_config = self
+            .operation_fallback_config
+            .map(
+                |operation_fallback_conf| match operation_fallback_conf.validate() {
+                    Ok(_) => Ok(operation_fallback_conf.foreign_into()),
+                    Err(e) => Err(error_stack::report!(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: e.to_string()
+                        }
+                    )),
+                },
+            )
+            .transpose()?;
+
+        let dcc_config = self
+            .dcc_config
+            .map(|dcc_conf| match dcc_conf.validate() {
+                Ok(_) => Ok(dcc_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_fee_config = self
+            .payout_fee_config
+            .map(|payout_fee_conf| match payout_fee_conf.validate() {
+                Ok(_) => Ok(payout_fee_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let payout_balance_config = self
+            .payout_balance_config
+            .map(|payout_balance_conf| match payout_balance_conf.validate() {
+                Ok(_) => Ok(payout_balance_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let client_origin_config = self
+            .client_origin_config
+            .map(|client_origin_conf| match client_origin_conf.validate() {
+                Ok(_) => Ok(client_origin_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?;
+
+        let outgoing_webhook_auth_config = self
+            .outgoing_webhook_auth
+            .map(|auth_conf| match auth_conf.validate() {
+                Ok(_) => Ok(auth_conf),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            })
+            .transpose()?
+            .async_map(|auth_conf| {
+                cards::create_encrypted_data(&key_manager_state, key_store, auth_conf)
+            })
+            .await
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt outgoing webhook authentication config")?;
+
         Ok(domain::ProfileUpdate::Update(Box::new(
             domain::ProfileGeneralUpdate {
                 profile_name: self.profile_name,
@@ -4011,6 +5452,33 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                     .always_collect_shipping_details_from_wallet_connector,
                 is_network_tokenization_enabled: self.is_network_tokenization_enabled,
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
+                customer_portal_link_config,
+                invoice_config: self.invoice_config.map(ForeignInto::foreign_into),
+                enabled_webhook_events: self.enabled_webhook_events,
+                webhook_payload_version: self.webhook_payload_version,
+                statement_descriptor_config,
+                dispute_representment_config,
+                outgoing_webhook_auth_config: outgoing_webhook_auth_config.map(Into::into),
+                redirect_config,
+                client_origin_config,
+                is_client_secret_single_use: self.is_client_secret_single_use,
+                post_auth_rules_config,
+                allowed_currencies: self.allowed_currencies,
+                tags: self.tags,
+                payout_retry_config,
+                payout_fulfillment_config,
+                payment_defaults_config: self
+                    .payment_defaults_config
+                    .map(ForeignInto::foreign_into),
+                capture_on_shipment_config: self
+                    .capture_on_shipment_config
+                    .map(ForeignInto::foreign_into),
+                dcc_config,
+                payout_fee_config,
+                payout_balance_config,
+                account_name_mismatch_action: self.account_name_mismatch_action,
+                connector_canary_rollout,
+                operation_fallback_config,
             },
         )))
     }
@@ -4300,6 +5768,150 @@ pub async fn connector_agnostic_mit_toggle(
     ))
 }
 
+/// Retrieves the signing secret of the outgoing webhooks of the profile, along with the expiry of
+/// the previous secret if a rotation is in its overlap period
+pub async fn retrieve_webhook_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<admin_types::WebhookSecretResponse> {
+    let business_profile = core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_webhook_secret_response(business_profile),
+    ))
+}
+
+/// Replaces the signing secret of the outgoing webhooks of the profile with a newly generated
+/// secret. The webhooks are signed with both the previous and the new secret for the overlap
+/// period, so that the merchant can update the secret used for verifying the webhooks without
+/// rejecting any of them. A previous secret which is still within the overlap period of an earlier
+/// rotation is discarded.
+pub async fn rotate_webhook_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    req: admin_types::RotateWebhookSecretRequest,
+) -> RouterResponse<admin_types::WebhookSecretResponse> {
+    let overlap_period_in_secs = req
+        .overlap_period_in_secs
+        .unwrap_or(DEFAULT_WEBHOOK_SECRET_OVERLAP_PERIOD_IN_SECS);
+    if overlap_period_in_secs > MAX_WEBHOOK_SECRET_OVERLAP_PERIOD_IN_SECS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "overlap_period_in_secs must not be greater than {MAX_WEBHOOK_SECRET_OVERLAP_PERIOD_IN_SECS}"
+            ),
+        }));
+    }
+
+    update_webhook_secret(
+        state,
+        merchant_account,
+        key_store,
+        profile_id,
+        overlap_period_in_secs,
+    )
+    .await
+}
+
+/// Replaces the signing secret of the outgoing webhooks of the profile with a newly generated
+/// secret, without an overlap period. The previous secret is discarded immediately.
+pub async fn generate_webhook_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<admin_types::WebhookSecretResponse> {
+    update_webhook_secret(state, merchant_account, key_store, profile_id, 0).await
+}
+
+async fn update_webhook_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    overlap_period_in_secs: u32,
+) -> RouterResponse<admin_types::WebhookSecretResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    // Without an overlap period the rotation expires immediately, which also discards the previous
+    // secret of an earlier rotation
+    let webhook_secret_rotation = business_profile.payment_response_hash_key.clone().map(
+        |previous_payment_response_hash_key| {
+            diesel_models::business_profile::WebhookSecretRotation {
+                previous_payment_response_hash_key,
+                expires_at: date_time::now()
+                    + time::Duration::seconds(i64::from(overlap_period_in_secs)),
+            }
+        },
+    );
+    let profile_update = domain::ProfileUpdate::WebhookSecretUpdate {
+        payment_response_hash_key:
+            common_utils::crypto::generate_cryptographically_secure_random_string(64),
+        webhook_secret_rotation,
+    };
+
+    let updated_business_profile = db
+        .update_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            business_profile,
+            profile_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .attach_printable("Failed to update the webhook secret of the profile")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_webhook_secret_response(updated_business_profile),
+    ))
+}
+
+fn get_webhook_secret_response(
+    business_profile: domain::Profile,
+) -> admin_types::WebhookSecretResponse {
+    let now = date_time::now();
+    let previous_secret_expires_at = business_profile
+        .webhook_secret_rotation
+        .as_ref()
+        .filter(|rotation| rotation.get_active_previous_secret(now).is_some())
+        .map(|rotation| rotation.expires_at);
+
+    admin_types::WebhookSecretResponse {
+        profile_id: business_profile.get_id().to_owned(),
+        payment_response_hash_key: business_profile.payment_response_hash_key,
+        previous_secret_expires_at,
+    }
+}
+
 pub async fn transfer_key_store_to_key_manager(
     state: SessionState,
     req: admin_types::MerchantKeyTransferRequest,
@@ -4397,84 +6009,34 @@ async fn process_open_banking_connectors(
 }
 
 fn validate_bank_account_data(data: &types::MerchantAccountData) -> RouterResult<()> {
-    match data {
-        types::MerchantAccountData::Iban { iban, .. } => {
-            // IBAN check algorithm
-            if iban.peek().len() > IBAN_MAX_LENGTH {
-                return Err(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "IBAN length must be up to 34 characters".to_string(),
-                }
-                .into());
-            }
-            let pattern = Regex::new(r"^[A-Z0-9]*$")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to create regex pattern")?;
-
-            let mut iban = iban.peek().to_string();
-
-            if !pattern.is_match(iban.as_str()) {
-                return Err(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "IBAN data must be alphanumeric".to_string(),
-                }
-                .into());
-            }
-
-            // MOD check
-            let first_4 = iban.chars().take(4).collect::<String>();
-            iban.push_str(first_4.as_str());
-            let len = iban.len();
-
-            let rearranged_iban = iban
-                .chars()
-                .rev()
-                .take(len - 4)
-                .collect::<String>()
-                .chars()
-                .rev()
-                .collect::<String>();
-
-            let mut result = String::new();
-
-            rearranged_iban.chars().for_each(|c| {
-                if c.is_ascii_uppercase() {
-                    let digit = (u32::from(c) - u32::from('A')) + 10;
-                    result.push_str(&format!("{:02}", digit));
-                } else {
-                    result.push(c);
-                }
-            });
-
-            let num = result
-                .parse::<u128>()
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to validate IBAN")?;
-
-            if num % 97 != 1 {
-                return Err(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "Invalid IBAN".to_string(),
-                }
-                .into());
-            }
-
-            Ok(())
-        }
+    let result = match data {
+        types::MerchantAccountData::Iban { iban, .. } => bank_account::validate_iban(iban.peek()),
         types::MerchantAccountData::Bacs {
             account_number,
             sort_code,
             ..
-        } => {
-            if account_number.peek().len() > BACS_MAX_ACCOUNT_NUMBER_LENGTH
-                || sort_code.peek().len() != BACS_SORT_CODE_LENGTH
-            {
-                return Err(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "Invalid BACS numbers".to_string(),
-                }
-                .into());
-            }
+        } => bank_account::validate_sort_code_and_account_number(
+            sort_code.peek(),
+            account_number.peek(),
+        ),
+    };
 
-            Ok(())
-        }
-    }
+    result.map_err(|error| {
+        let field_name = match error.field {
+            bank_account::BankAccountField::AccountNumber => "account_number",
+            bank_account::BankAccountField::SortCode => "sort_code",
+            bank_account::BankAccountField::Iban => "iban",
+            bank_account::BankAccountField::RoutingNumber
+            | bank_account::BankAccountField::Clabe
+            | bank_account::BankAccountField::Ifsc => "account_data",
+        };
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Invalid value provided for `account_data.{field_name}`: {}",
+                error.reason
+            ),
+        })
+    })
 }
 
 async fn connector_recipient_create_call(
@@ -0,0 +1,160 @@
+use api_models::admin_api_keys as admin_api_keys_api;
+use common_utils::date_time;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        api_keys::PlaintextApiKey,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::SessionState,
+    services::{authorization::admin_roles, ApplicationResponse},
+    types::{storage, transformers::ForeignInto},
+    utils,
+};
+
+const ADMIN_API_KEY_ID_PREFIX: &str = "adm_key";
+
+fn validate_admin_role(role_id: &str) -> RouterResult<()> {
+    if !admin_roles::PREDEFINED_ADMIN_ROLES.contains_key(role_id) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Admin role `{role_id}` does not exist"),
+        }));
+    }
+    Ok(())
+}
+
+fn admin_api_key_not_found() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Admin API key does not exist".to_string(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_admin_api_key(
+    state: SessionState,
+    req: admin_api_keys_api::CreateAdminApiKeyRequest,
+) -> RouterResponse<admin_api_keys_api::CreateAdminApiKeyResponse> {
+    validate_admin_role(&req.role_id)?;
+
+    let hash_key = state.conf.api_keys.get_inner().get_hash_key()?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let now = date_time::now();
+    let admin_api_key = storage::AdminApiKeyNew {
+        key_id: utils::generate_id(consts::ID_LENGTH, ADMIN_API_KEY_ID_PREFIX),
+        name: req.name,
+        description: req.description,
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        prefix: plaintext_api_key.prefix(),
+        role_id: req.role_id,
+        created_at: now,
+        expires_at: req.expiration.into(),
+        last_modified_at: now,
+    };
+
+    let admin_api_key = state
+        .store
+        .insert_admin_api_key(admin_api_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert new admin API key")?;
+
+    Ok(ApplicationResponse::Json(
+        (admin_api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_admin_api_key(
+    state: SessionState,
+    key_id: &str,
+) -> RouterResponse<admin_api_keys_api::RetrieveAdminApiKeyResponse> {
+    let admin_api_key = state
+        .store
+        .find_admin_api_key_by_key_id(key_id)
+        .await
+        .to_not_found_response(admin_api_key_not_found())?;
+
+    Ok(ApplicationResponse::Json(admin_api_key.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_admin_api_keys(
+    state: SessionState,
+) -> RouterResponse<Vec<admin_api_keys_api::RetrieveAdminApiKeyResponse>> {
+    let admin_api_keys = state
+        .store
+        .list_admin_api_keys()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list admin API keys")?;
+
+    Ok(ApplicationResponse::Json(
+        admin_api_keys
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+/// Assigns the admin role to the admin API key, replacing the admin role assigned to it earlier.
+/// The new admin role takes effect from the next request made with the admin API key.
+#[instrument(skip_all)]
+pub async fn assign_admin_role(
+    state: SessionState,
+    key_id: &str,
+    req: admin_api_keys_api::AdminRoleAssignmentRequest,
+) -> RouterResponse<admin_api_keys_api::RetrieveAdminApiKeyResponse> {
+    validate_admin_role(&req.role_id)?;
+
+    let admin_api_key = state
+        .store
+        .update_admin_api_key(
+            key_id,
+            storage::AdminApiKeyUpdate::RoleUpdate {
+                role_id: req.role_id,
+            },
+        )
+        .await
+        .to_not_found_response(admin_api_key_not_found())?;
+
+    Ok(ApplicationResponse::Json(admin_api_key.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_admin_api_key(
+    state: SessionState,
+    key_id: &str,
+) -> RouterResponse<admin_api_keys_api::RevokeAdminApiKeyResponse> {
+    let revoked = state
+        .store
+        .revoke_admin_api_key(key_id)
+        .await
+        .to_not_found_response(admin_api_key_not_found())?;
+
+    Ok(ApplicationResponse::Json(
+        admin_api_keys_api::RevokeAdminApiKeyResponse {
+            key_id: key_id.to_owned(),
+            revoked,
+        },
+    ))
+}
+
+pub async fn list_admin_roles(
+    _state: SessionState,
+) -> RouterResponse<Vec<admin_api_keys_api::AdminRoleResponse>> {
+    let mut admin_roles = admin_roles::PREDEFINED_ADMIN_ROLES
+        .values()
+        .map(|role_info| admin_api_keys_api::AdminRoleResponse {
+            role_id: role_info.get_role_id().to_string(),
+            role_name: role_info.get_role_name().to_string(),
+            permissions: role_info.get_permissions().to_vec(),
+        })
+        .collect::<Vec<_>>();
+    admin_roles.sort_by(|a, b| a.role_id.cmp(&b.role_id));
+
+    Ok(ApplicationResponse::Json(admin_roles))
+}
@@ -131,6 +131,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key.scopes.filter(|scopes| !scopes.is_empty()),
     };
 
     let api_key = store
@@ -8,13 +8,14 @@ use common_enums::Currency;
 use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 use masking::ExposeInterface;
+use router_env::logger;
 
 use super::errors::StorageErrorExt;
 use crate::{
     core::{errors::ApiErrorResponse, payments as payments_core},
     routes::SessionState,
     types::{self as core_types, api, domain, storage},
-    utils::check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata,
+    utils::{check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata, OptionExt},
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -62,6 +63,7 @@ pub async fn perform_authentication(
         webhook_url,
         three_ds_requestor_url,
         psd2_sca_exemption_type,
+        None,
     )?;
     let response = Box::pin(utils::do_auth_connector_call(
         state,
@@ -89,14 +91,6 @@ pub async fn perform_post_authentication(
     business_profile: domain::Profile,
     authentication_id: String,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
-    let (authentication_connector, three_ds_connector_account) =
-        utils::get_authentication_connector_data(state, key_store, &business_profile).await?;
-    let is_pull_mechanism_enabled =
-        check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
-            three_ds_connector_account
-                .get_metadata()
-                .map(|metadata| metadata.expose()),
-        );
     let authentication = state
         .store
         .find_authentication_by_merchant_id_authentication_id(
@@ -106,6 +100,20 @@ pub async fn perform_post_authentication(
         .await
         .to_not_found_response(ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))?;
+    let (authentication_connector, three_ds_connector_account) =
+        utils::get_authentication_connector_data_from_authentication(
+            state,
+            key_store,
+            &business_profile,
+            &authentication,
+        )
+        .await?;
+    let is_pull_mechanism_enabled =
+        check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
+            three_ds_connector_account
+                .get_metadata()
+                .map(|metadata| metadata.expose()),
+        );
     if !authentication.authentication_status.is_terminal_status() && is_pull_mechanism_enabled {
         let router_data = transformers::construct_post_authentication_router_data(
             authentication_connector.to_string(),
@@ -122,6 +130,11 @@ pub async fn perform_post_authentication(
     }
 }
 
+/// Performs the pre authentication on the authentication connectors of the profile in order. If
+/// the 3DS server of a connector could not be reached, timed out or failed with a server error,
+/// the pre authentication is retried on the next connector, and the failover is recorded on the
+/// authentication. The authentication steps which follow are performed on the connector which
+/// completed the pre authentication, since they are bound to the transaction of its 3DS server.
 pub async fn perform_pre_authentication(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -131,45 +144,139 @@ pub async fn perform_pre_authentication(
     acquirer_details: Option<types::AcquirerDetails>,
     payment_id: Option<common_utils::id_type::PaymentId>,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
-    let (authentication_connector, three_ds_connector_account) =
-        utils::get_authentication_connector_data(state, key_store, business_profile).await?;
-    let authentication_connector_name = authentication_connector.to_string();
-    let authentication = utils::create_new_authentication(
-        state,
-        business_profile.merchant_id.clone(),
-        authentication_connector_name.clone(),
-        token,
-        business_profile.get_id().to_owned(),
-        payment_id,
-        three_ds_connector_account
+    let mut authentication_connectors = utils::get_authentication_connectors(business_profile)?
+        .into_iter()
+        .peekable();
+    let mut failed_over_authentication = None;
+
+    while let Some(authentication_connector) = authentication_connectors.next() {
+        let authentication_connector_name = authentication_connector.to_string();
+        let three_ds_connector_account = utils::get_authentication_connector_account(
+            state,
+            key_store,
+            business_profile,
+            &authentication_connector_name,
+            None,
+        )
+        .await?;
+        let merchant_connector_id = three_ds_connector_account
             .get_mca_id()
             .ok_or(ApiErrorResponse::InternalServerError)
-            .attach_printable("Error while finding mca_id from merchant_connector_account")?,
-    )
-    .await?;
+            .attach_printable("Error while finding mca_id from merchant_connector_account")?;
+        let authentication = match failed_over_authentication.take() {
+            Some((authentication, failover)) => {
+                utils::failover_authentication_connector(
+                    state,
+                    authentication,
+                    failover,
+                    authentication_connector_name,
+                    merchant_connector_id,
+                )
+                .await?
+            }
+            None => {
+                utils::create_new_authentication(
+                    state,
+                    business_profile.merchant_id.clone(),
+                    authentication_connector_name,
+                    token.clone(),
+                    business_profile.get_id().to_owned(),
+                    payment_id.clone(),
+                    merchant_connector_id,
+                )
+                .await?
+            }
+        };
+
+        let is_failover_available = authentication_connectors.peek().is_some();
+        match perform_pre_authentication_with_connector(
+            state,
+            authentication_connector,
+            &three_ds_connector_account,
+            card_number.clone(),
+            authentication,
+            acquirer_details.clone(),
+            business_profile.merchant_id.clone(),
+            is_failover_available,
+        )
+        .await?
+        {
+            PreAuthenticationOutcome::Completed(authentication) => return Ok(authentication),
+            PreAuthenticationOutcome::ConnectorUnavailable(authentication, failover) => {
+                logger::warn!(
+                    authentication_id = %authentication.authentication_id,
+                    authentication_connector = %failover.authentication_connector,
+                    error_code = ?failover.error_code,
+                    error_message = ?failover.error_message,
+                    "Authentication connector is unavailable, failing over to the next authentication connector"
+                );
+                failed_over_authentication = Some((authentication, failover));
+            }
+        }
+    }
+
+    Err(ApiErrorResponse::InternalServerError)
+        .attach_printable("Pre authentication was not completed on any authentication connector")
+}
+
+/// Outcome of the pre authentication on one of the authentication connectors of the profile
+enum PreAuthenticationOutcome {
+    Completed(storage::Authentication),
+    /// The 3DS server of the connector was unavailable, and the pre authentication is to be
+    /// retried on the next authentication connector
+    ConnectorUnavailable(
+        storage::Authentication,
+        storage::AuthenticationConnectorFailover,
+    ),
+}
 
+#[allow(clippy::too_many_arguments)]
+async fn perform_pre_authentication_with_connector(
+    state: &SessionState,
+    authentication_connector: common_enums::AuthenticationConnectors,
+    three_ds_connector_account: &payments_core::helpers::MerchantConnectorAccountType,
+    card_number: cards::CardNumber,
+    authentication: storage::Authentication,
+    acquirer_details: Option<types::AcquirerDetails>,
+    merchant_id: common_utils::id_type::MerchantId,
+    is_failover_available: bool,
+) -> CustomResult<PreAuthenticationOutcome, ApiErrorResponse> {
+    let authentication_connector_name = authentication_connector.to_string();
     let authentication = if authentication_connector.is_separate_version_call_required() {
         let router_data: core_types::authentication::PreAuthNVersionCallRouterData =
             transformers::construct_pre_authentication_router_data(
                 authentication_connector_name.clone(),
                 card_number.clone(),
-                &three_ds_connector_account,
-                business_profile.merchant_id.clone(),
+                three_ds_connector_account,
+                merchant_id.clone(),
             )?;
         let router_data = utils::do_auth_connector_call(
             state,
             authentication_connector_name.clone(),
             router_data,
         )
-        .await?;
+        .await;
+        if let Some(failover) = is_failover_available
+            .then(|| utils::get_authentication_connector_failover(&authentication, &router_data))
+            .flatten()
+        {
+            return Ok(PreAuthenticationOutcome::ConnectorUnavailable(
+                authentication,
+                failover,
+            ));
+        }
 
-        let updated_authentication =
-            utils::update_trackers(state, router_data, authentication, acquirer_details.clone())
-                .await?;
+        let updated_authentication = utils::update_trackers(
+            state,
+            router_data?,
+            authentication,
+            acquirer_details.clone(),
+        )
+        .await?;
         // from version call response, we will get to know the maximum supported 3ds version.
         // If the version is not greater than or equal to 3DS 2.0, We should not do the successive pre authentication call.
         if !updated_authentication.is_separate_authn_required() {
-            return Ok(updated_authentication);
+            return Ok(PreAuthenticationOutcome::Completed(updated_authentication));
         }
         updated_authentication
     } else {
@@ -180,11 +287,120 @@ pub async fn perform_pre_authentication(
         transformers::construct_pre_authentication_router_data(
             authentication_connector_name.clone(),
             card_number,
-            &three_ds_connector_account,
-            business_profile.merchant_id.clone(),
+            three_ds_connector_account,
+            merchant_id,
         )?;
     let router_data =
-        utils::do_auth_connector_call(state, authentication_connector_name, router_data).await?;
+        utils::do_auth_connector_call(state, authentication_connector_name, router_data).await;
+    if let Some(failover) = is_failover_available
+        .then(|| utils::get_authentication_connector_failover(&authentication, &router_data))
+        .flatten()
+    {
+        return Ok(PreAuthenticationOutcome::ConnectorUnavailable(
+            authentication,
+            failover,
+        ));
+    }
+
+    utils::update_trackers(state, router_data?, authentication, acquirer_details)
+        .await
+        .map(PreAuthenticationOutcome::Completed)
+}
+
+/// Performs a 3DS Requestor Initiated (3RI) authentication for a merchant initiated payment, in
+/// which the cardholder is not present. The pre authentication is followed by the authentication
+/// right away, since the cardholder can neither complete the 3DS method nor a challenge. The
+/// authentication values obtained are attached to the authorization of the payment.
+#[allow(clippy::too_many_arguments)]
+pub async fn perform_three_ri_authentication(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    card_number: cards::CardNumber,
+    token: String,
+    acquirer_details: types::AcquirerDetails,
+    payment_method_data: domain::PaymentMethodData,
+    billing_address: hyperswitch_domain_models::address::Address,
+    shipping_address: Option<hyperswitch_domain_models::address::Address>,
+    amount: common_utils::types::MinorUnit,
+    currency: Option<Currency>,
+    email: Option<common_utils::pii::Email>,
+    payment_id: common_utils::id_type::PaymentId,
+    return_url: String,
+) -> CustomResult<storage::Authentication, ApiErrorResponse> {
+    let authentication = perform_pre_authentication(
+        state,
+        key_store,
+        card_number,
+        token,
+        business_profile,
+        Some(acquirer_details),
+        Some(payment_id),
+    )
+    .await?;
+
+    // 3RI authentications of payments are supported only from EMV 3DS 2.2.0 onwards
+    let is_three_ri_supported = authentication
+        .message_version
+        .as_ref()
+        .is_some_and(|version| *version >= common_utils::types::SemanticVersion::new(2, 2, 0));
+    if !authentication.is_separate_authn_required()
+        || authentication.authentication_status.is_failed()
+        || !is_three_ri_supported
+    {
+        return Ok(authentication);
+    }
+
+    // The authentication is performed on the connector which completed the pre authentication
+    let (authentication_connector, three_ds_connector_account) =
+        utils::get_authentication_connector_data_from_authentication(
+            state,
+            key_store,
+            business_profile,
+            &authentication,
+        )
+        .await?;
+    let authentication_connector_name = authentication_connector.to_string();
+    let authentication_details = business_profile
+        .authentication_connector_details
+        .clone()
+        .get_required_value("authentication_connector_details")
+        .attach_printable("authentication_connector_details not configured by the merchant")?;
+    let webhook_url = payments_core::helpers::create_webhook_url(
+        &state.base_url,
+        &business_profile.merchant_id,
+        &authentication_connector_name,
+    );
+
+    let router_data = transformers::construct_authentication_router_data(
+        business_profile.merchant_id.clone(),
+        authentication_connector_name.clone(),
+        payment_method_data,
+        common_enums::PaymentMethod::Card,
+        billing_address,
+        shipping_address,
+        None,
+        Some(amount),
+        currency,
+        api::authentication::MessageCategory::Payment,
+        payments::DeviceChannel::ThreeDsRequestorInitiated,
+        three_ds_connector_account,
+        authentication.clone(),
+        Some(return_url),
+        None,
+        payments::ThreeDsCompletionIndicator::NotAvailable,
+        email,
+        webhook_url,
+        authentication_details.three_ds_requestor_url,
+        None,
+        Some(core_types::authentication::ThreeRiIndicator::RecurringTransaction),
+    )?;
+    let router_data = Box::pin(utils::do_auth_connector_call(
+        state,
+        authentication_connector_name,
+        router_data,
+    ))
+    .await?;
 
-    utils::update_trackers(state, router_data, authentication, acquirer_details).await
+    utils::update_trackers(state, router_data, authentication, None).await
 }
@@ -44,6 +44,7 @@ pub fn construct_authentication_router_data(
     webhook_url: String,
     three_ds_requestor_url: String,
     psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+    three_ri_indicator: Option<types::authentication::ThreeRiIndicator>,
 ) -> RouterResult<types::authentication::ConnectorAuthenticationRouterData> {
     let router_request = types::authentication::ConnectorAuthenticationRequestData {
         payment_method_data,
@@ -63,6 +64,7 @@ pub fn construct_authentication_router_data(
         three_ds_requestor_url,
         threeds_method_comp_ind,
         webhook_url,
+        three_ri_indicator,
     };
     construct_router_data(
         authentication_connector,
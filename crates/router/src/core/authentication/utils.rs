@@ -219,6 +219,7 @@ pub async fn create_new_authentication(
         ds_trans_id: None,
         directory_server_id: None,
         acquirer_country_code: None,
+        connector_failover_history: None,
     };
     state
         .store
@@ -264,14 +265,10 @@ where
     Ok(router_data)
 }
 
-pub async fn get_authentication_connector_data(
-    state: &SessionState,
-    key_store: &domain::MerchantKeyStore,
+/// Returns the authentication connectors of the profile, in the order they are to be attempted
+pub fn get_authentication_connectors(
     business_profile: &domain::Profile,
-) -> RouterResult<(
-    common_enums::AuthenticationConnectors,
-    payments::helpers::MerchantConnectorAccountType,
-)> {
+) -> RouterResult<Vec<common_enums::AuthenticationConnectors>> {
     let authentication_details = business_profile
         .authentication_connector_details
         .clone()
@@ -280,10 +277,8 @@ pub async fn get_authentication_connector_data(
             message: "authentication_connector_details is not available in business profile".into(),
         })
         .attach_printable("authentication_connector_details not configured by the merchant")?;
-    let authentication_connector = authentication_details
-        .authentication_connectors
-        .first()
-        .ok_or(errors::ApiErrorResponse::UnprocessableEntity {
+    if authentication_details.authentication_connectors.is_empty() {
+        return Err(errors::ApiErrorResponse::UnprocessableEntity {
             message: format!(
                 "No authentication_connector found for profile_id {:?}",
                 business_profile.get_id()
@@ -291,18 +286,115 @@ pub async fn get_authentication_connector_data(
         })
         .attach_printable(
             "No authentication_connector found from merchant_account.authentication_details",
-        )?
-        .to_owned();
-    let profile_id = business_profile.get_id();
-    let authentication_connector_mca = payments::helpers::get_merchant_connector_account(
+        );
+    }
+    Ok(authentication_details.authentication_connectors)
+}
+
+pub async fn get_authentication_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    authentication_connector: &str,
+    merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+) -> RouterResult<payments::helpers::MerchantConnectorAccountType> {
+    payments::helpers::get_merchant_connector_account(
         state,
         &business_profile.merchant_id,
         None,
         key_store,
-        profile_id,
-        authentication_connector.to_string().as_str(),
-        None,
+        business_profile.get_id(),
+        authentication_connector,
+        merchant_connector_id,
+    )
+    .await
+}
+
+/// Returns the authentication connector recorded on the authentication, along with its account.
+/// The connector may differ from the primary authentication connector of the profile, in case
+/// the authentication was failed over to one of the other connectors.
+pub async fn get_authentication_connector_data_from_authentication(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    authentication: &storage::Authentication,
+) -> RouterResult<(
+    common_enums::AuthenticationConnectors,
+    payments::helpers::MerchantConnectorAccountType,
+)> {
+    let authentication_connector = authentication
+        .authentication_connector
+        .parse::<common_enums::AuthenticationConnectors>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Invalid authentication_connector {} found in authentication",
+                authentication.authentication_connector
+            )
+        })?;
+    let authentication_connector_mca = get_authentication_connector_account(
+        state,
+        key_store,
+        business_profile,
+        &authentication.authentication_connector,
+        Some(&authentication.merchant_connector_id),
     )
     .await?;
     Ok((authentication_connector, authentication_connector_mca))
 }
+
+/// Returns the failover from the authentication connector of the authentication, if the call to
+/// its 3DS server could not be completed, timed out or failed with a server error
+pub fn get_authentication_connector_failover<F, Req>(
+    authentication: &storage::Authentication,
+    router_data: &RouterResult<RouterData<F, Req, AuthenticationResponseData>>,
+) -> Option<storage::AuthenticationConnectorFailover> {
+    let (error_code, error_message) = match router_data {
+        Err(error) => (None, Some(error.current_context().to_string())),
+        Ok(router_data) => match &router_data.response {
+            Err(error) if error.status_code >= 500 => {
+                (Some(error.code.clone()), Some(error.message.clone()))
+            }
+            _ => return None,
+        },
+    };
+    Some(storage::AuthenticationConnectorFailover {
+        authentication_connector: authentication.authentication_connector.clone(),
+        merchant_connector_id: authentication.merchant_connector_id.clone(),
+        error_code,
+        error_message,
+        failed_at: common_utils::date_time::now(),
+    })
+}
+
+/// Moves the authentication over to the next authentication connector, recording the failover
+/// from the previous connector on the authentication
+pub async fn failover_authentication_connector(
+    state: &SessionState,
+    authentication: storage::Authentication,
+    failover: storage::AuthenticationConnectorFailover,
+    authentication_connector: String,
+    merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+) -> RouterResult<storage::Authentication> {
+    let mut connector_failover_history = authentication
+        .connector_failover_history
+        .clone()
+        .map(|history| history.0)
+        .unwrap_or_default();
+    connector_failover_history.push(failover);
+    state
+        .store
+        .update_authentication_by_merchant_id_authentication_id(
+            authentication,
+            storage::AuthenticationUpdate::ConnectorFailoverUpdate {
+                authentication_connector,
+                merchant_connector_id,
+                connector_failover_history: storage::AuthenticationConnectorFailoverHistory(
+                    connector_failover_history,
+                ),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while updating authentication")
+}
@@ -51,6 +51,7 @@ async fn generate_fingerprint_request<'a>(
     let jwe_payload = generate_jwe_payload_for_request(jwekey, &jws, locker_choice).await?;
     let mut url = match locker_choice {
         api_enums::LockerChoice::HyperswitchCardVault => locker.host.to_owned(),
+        api_enums::LockerChoice::RustCardVault => locker.host_rs.to_owned(),
     };
     url.push_str(LOCKER_FINGERPRINT_PATH);
     let mut request = services::Request::new(services::Method::Post, &url);
@@ -82,7 +83,7 @@ async fn generate_jwe_payload_for_request(
         .change_context(errors::VaultError::GenerateFingerprintFailed)?;
 
     let public_key = match locker_choice {
-        api_enums::LockerChoice::HyperswitchCardVault => {
+        api_enums::LockerChoice::HyperswitchCardVault | api_enums::LockerChoice::RustCardVault => {
             jwekey.vault_encryption_key.peek().as_bytes()
         }
     };
@@ -171,7 +172,7 @@ async fn decrypt_generate_fingerprint_response_payload(
     let target_locker = locker_choice.unwrap_or(api_enums::LockerChoice::HyperswitchCardVault);
 
     let public_key = match target_locker {
-        api_enums::LockerChoice::HyperswitchCardVault => {
+        api_enums::LockerChoice::HyperswitchCardVault | api_enums::LockerChoice::RustCardVault => {
             jwekey.vault_encryption_key.peek().as_bytes()
         }
     };
@@ -0,0 +1,833 @@
+//! Reconciles the live configuration of merchants with a declarative document describing their
+//! merchant accounts, business profiles, connector accounts and routing algorithms. The document
+//! is compared with the live configuration to build a plan of the resources to be created or
+//! updated, which is applied unless the reconciliation is a dry run.
+
+use std::{collections::HashSet, str::FromStr};
+
+use api_models::{
+    config_reconciliation::{
+        ConnectorDeclaration, ConnectorReference, MerchantConfigDocument,
+        MerchantConfigReconcileRequest, MerchantConfigReconcileResponse, MerchantDeclaration,
+        ProfileDeclaration, ReconcileAction, ReconcilePlanEntry, ReconciledResourceType,
+        RoutingAlgorithmDeclaration, RoutingDeclaration,
+    },
+    enums as api_enums,
+    routing::{self as routing_types, RoutableChoiceKind, RoutableConnectorChoice},
+};
+use common_utils::{ext_traits::ValueExt, id_type};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        routing,
+    },
+    routes::SessionState,
+    services,
+    types::{api, domain, transformers::ForeignTryFrom},
+};
+
+/// The fields of a resource declared in the document, in the format of the API requests
+#[derive(Debug, Clone, Default)]
+struct DeclaredFields(serde_json::Map<String, serde_json::Value>);
+
+impl DeclaredFields {
+    fn insert<T: serde::Serialize>(&mut self, key: &str, value: &Option<T>) -> RouterResult<()> {
+        if let Some(value) = value {
+            let value = serde_json::to_value(value)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Failed to serialize the declared {key}"))?;
+            self.0.insert(key.to_string(), value);
+        }
+        Ok(())
+    }
+
+    /// Returns the declared fields which differ from the fields of the live resource
+    fn get_changed_fields(&self, live: &serde_json::Value) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|(key, value)| live.get(key.as_str()) != Some(*value))
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    fn into_request<T: serde::de::DeserializeOwned>(
+        self,
+        type_name: &'static str,
+    ) -> RouterResult<T> {
+        serde_json::Value::Object(self.0)
+            .parse_value(type_name)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to build {type_name} from the document"))
+    }
+}
+
+/// A connector account of a profile, which routing algorithms can refer to
+#[derive(Debug, Clone)]
+struct ProfileConnector {
+    connector_name: String,
+    connector_label: Option<String>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+}
+
+/// The merchant whose profiles are being reconciled
+struct MerchantContext<'a> {
+    merchant_account: &'a domain::MerchantAccount,
+    key_store: &'a domain::MerchantKeyStore,
+    /// The path of the merchant in the document
+    resource: &'a str,
+}
+
+struct Reconciler<'a> {
+    state: &'a SessionState,
+    dry_run: bool,
+    plan: Vec<ReconcilePlanEntry>,
+}
+
+#[instrument(skip_all)]
+pub async fn reconcile_merchant_config(
+    state: SessionState,
+    request: MerchantConfigReconcileRequest,
+) -> RouterResponse<MerchantConfigReconcileResponse> {
+    let document = parse_document(request.document.peek())?;
+    validate_document(&document)?;
+
+    let mut reconciler = Reconciler {
+        state: &state,
+        dry_run: request.dry_run,
+        plan: Vec::new(),
+    };
+    for merchant in document.merchants {
+        Box::pin(reconciler.reconcile_merchant(merchant)).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        MerchantConfigReconcileResponse {
+            dry_run: request.dry_run,
+            plan: reconciler.plan,
+        },
+    ))
+}
+
+fn parse_document(document: &str) -> RouterResult<MerchantConfigDocument> {
+    config::Config::builder()
+        .add_source(config::File::from_str(document, config::FileFormat::Yaml))
+        .build()
+        .and_then(|document| document.try_deserialize())
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid merchant config document: {error}"),
+            })
+        })
+}
+
+/// Validates that every resource is declared only once in the document
+fn validate_document(document: &MerchantConfigDocument) -> RouterResult<()> {
+    let mut merchant_ids = HashSet::new();
+    for merchant in &document.merchants {
+        let merchant_id = merchant.merchant_id.get_string_repr();
+        if !merchant_ids.insert(merchant_id) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Merchant {merchant_id} is declared more than once"),
+            }));
+        }
+
+        let mut profile_names = HashSet::new();
+        for profile in &merchant.profiles {
+            if !profile_names.insert(profile.profile_name.as_str()) {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Profile {} of merchant {merchant_id} is declared more than once",
+                        profile.profile_name
+                    ),
+                }));
+            }
+
+            let mut connectors = HashSet::new();
+            for connector in &profile.connectors {
+                if !connectors.insert((
+                    connector.connector_name,
+                    connector.connector_label.as_deref(),
+                )) {
+                    return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Connector {} of profile {} is declared more than once",
+                            get_connector_resource(
+                                connector.connector_name,
+                                connector.connector_label.as_deref()
+                            ),
+                            profile.profile_name
+                        ),
+                    }));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_connector_resource(
+    connector_name: api_enums::Connector,
+    connector_label: Option<&str>,
+) -> String {
+    match connector_label {
+        Some(connector_label) => format!("{connector_name}:{connector_label}"),
+        None => connector_name.to_string(),
+    }
+}
+
+/// Finds the connector account of a profile referred to by its connector name and label. The
+/// label may be omitted only when the profile has a single account of the connector.
+fn find_profile_connector<'a>(
+    connectors: &'a [ProfileConnector],
+    connector_name: api_enums::Connector,
+    connector_label: Option<&str>,
+) -> RouterResult<Option<&'a ProfileConnector>> {
+    let connector_name_string = connector_name.to_string();
+    let mut matching_connectors = connectors.iter().filter(|connector| {
+        connector.connector_name == connector_name_string
+            && connector_label.map_or(true, |connector_label| {
+                connector.connector_label.as_deref() == Some(connector_label)
+            })
+    });
+    let connector = matching_connectors.next();
+
+    if connector_label.is_none() && matching_connectors.next().is_some() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "connector_label is required to identify the {connector_name} connector account, since the profile has more than one"
+            ),
+        }));
+    }
+    Ok(connector)
+}
+
+fn get_json_response<T>(response: services::ApplicationResponse<T>) -> RouterResult<T> {
+    match response {
+        services::ApplicationResponse::Json(response)
+        | services::ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while applying the merchant config"),
+    }
+}
+
+fn to_live_value<T: serde::Serialize>(resource: T) -> RouterResult<serde_json::Value> {
+    serde_json::to_value(resource)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the live configuration")
+}
+
+fn get_merchant_fields(declaration: &MerchantDeclaration) -> RouterResult<DeclaredFields> {
+    let mut fields = DeclaredFields::default();
+    fields.insert("merchant_name", &declaration.merchant_name)?;
+    fields.insert("merchant_details", &declaration.merchant_details)?;
+    fields.insert("webhook_details", &declaration.webhook_details)?;
+    fields.insert("metadata", &declaration.metadata)?;
+    Ok(fields)
+}
+
+fn get_profile_fields(declaration: &ProfileDeclaration) -> RouterResult<DeclaredFields> {
+    let mut fields = DeclaredFields::default();
+    fields.insert("return_url", &declaration.return_url)?;
+    fields.insert("webhook_details", &declaration.webhook_details)?;
+    fields.insert(
+        "enable_payment_response_hash",
+        &declaration.enable_payment_response_hash,
+    )?;
+    fields.insert(
+        "redirect_to_merchant_with_http_post",
+        &declaration.redirect_to_merchant_with_http_post,
+    )?;
+    fields.insert("metadata", &declaration.metadata)?;
+    Ok(fields)
+}
+
+/// The connector account details are not part of the fields, since they are masked in the
+/// responses and are compared separately
+fn get_connector_fields(declaration: &ConnectorDeclaration) -> RouterResult<DeclaredFields> {
+    let mut fields = DeclaredFields::default();
+    fields.insert("connector_type", &Some(declaration.connector_type))?;
+    fields.insert(
+        "payment_methods_enabled",
+        &declaration.payment_methods_enabled,
+    )?;
+    fields.insert("metadata", &declaration.metadata)?;
+    fields.insert("test_mode", &declaration.test_mode)?;
+    fields.insert("disabled", &declaration.disabled)?;
+    Ok(fields)
+}
+
+fn resolve_connector_choice(
+    connectors: &[ProfileConnector],
+    reference: &ConnectorReference,
+) -> RouterResult<Option<RoutableConnectorChoice>> {
+    let Some(connector) = find_profile_connector(
+        connectors,
+        reference.connector_name,
+        reference.connector_label.as_deref(),
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let routable_connector = api_enums::RoutableConnectors::from_str(&connector.connector_name)
+        .map_err(|_| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Connector {} cannot be used in routing algorithms",
+                    connector.connector_name
+                ),
+            })
+        })?;
+
+    Ok(Some(RoutableConnectorChoice {
+        choice_kind: RoutableChoiceKind::FullStruct,
+        connector: routable_connector,
+        merchant_connector_id: Some(connector.merchant_connector_id.clone()),
+    }))
+}
+
+/// Builds the routing algorithm referring to the connector accounts of the profile. Returns `None`
+/// if a referred connector account does not exist yet, which is the case in a dry run when the
+/// connector account is planned to be created.
+fn resolve_routing_algorithm(
+    connectors: &[ProfileConnector],
+    declaration: &RoutingAlgorithmDeclaration,
+) -> RouterResult<Option<routing_types::RoutingAlgorithm>> {
+    Ok(match declaration {
+        RoutingAlgorithmDeclaration::Priority(references) => references
+            .iter()
+            .map(|reference| resolve_connector_choice(connectors, reference))
+            .collect::<RouterResult<Option<Vec<_>>>>()?
+            .map(routing_types::RoutingAlgorithm::Priority),
+        RoutingAlgorithmDeclaration::VolumeSplit(splits) => splits
+            .iter()
+            .map(|split| {
+                Ok(
+                    resolve_connector_choice(connectors, &split.connector)?.map(|connector| {
+                        routing_types::ConnectorVolumeSplit {
+                            connector,
+                            split: split.split,
+                        }
+                    }),
+                )
+            })
+            .collect::<RouterResult<Option<Vec<_>>>>()?
+            .map(routing_types::RoutingAlgorithm::VolumeSplit),
+    })
+}
+
+impl Reconciler<'_> {
+    fn add_plan_entry(
+        &mut self,
+        resource_type: ReconciledResourceType,
+        resource: String,
+        action: ReconcileAction,
+        changed_fields: Vec<String>,
+    ) {
+        self.plan.push(ReconcilePlanEntry {
+            resource_type,
+            resource,
+            action,
+            changed_fields,
+        });
+    }
+
+    async fn find_merchant(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> RouterResult<Option<(domain::MerchantAccount, domain::MerchantKeyStore)>> {
+        let db = self.state.store.as_ref();
+        let key_manager_state = &self.state.into();
+        let key_store = match db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+        {
+            Ok(key_store) => key_store,
+            Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the merchant key store")
+            }
+        };
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+        Ok(Some((merchant_account, key_store)))
+    }
+
+    async fn reconcile_merchant(&mut self, declaration: MerchantDeclaration) -> RouterResult<()> {
+        let merchant_id = declaration.merchant_id.clone();
+        let resource = merchant_id.get_string_repr().to_owned();
+        let fields = get_merchant_fields(&declaration)?;
+
+        let merchant = match self.find_merchant(&merchant_id).await? {
+            Some((merchant_account, key_store)) => {
+                let live = to_live_value(
+                    api::MerchantAccountResponse::foreign_try_from(merchant_account.clone())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                )?;
+                let changed_fields = fields.get_changed_fields(&live);
+                if changed_fields.is_empty() {
+                    self.add_plan_entry(
+                        ReconciledResourceType::MerchantAccount,
+                        resource.clone(),
+                        ReconcileAction::NoOp,
+                        changed_fields,
+                    );
+                } else {
+                    if !self.dry_run {
+                        let mut request = fields;
+                        request.insert("merchant_id", &Some(&merchant_id))?;
+                        admin::merchant_account_update(
+                            self.state.clone(),
+                            &merchant_id,
+                            None,
+                            request.into_request("MerchantAccountUpdate")?,
+                        )
+                        .await?;
+                    }
+                    self.add_plan_entry(
+                        ReconciledResourceType::MerchantAccount,
+                        resource.clone(),
+                        ReconcileAction::Update,
+                        changed_fields,
+                    );
+                }
+                Some((merchant_account, key_store))
+            }
+            None => {
+                self.add_plan_entry(
+                    ReconciledResourceType::MerchantAccount,
+                    resource.clone(),
+                    ReconcileAction::Create,
+                    fields.0.keys().cloned().collect(),
+                );
+                if self.dry_run {
+                    None
+                } else {
+                    let mut request = fields;
+                    request.insert("merchant_id", &Some(&merchant_id))?;
+                    request.insert("organization_id", &declaration.organization_id)?;
+                    admin::create_merchant_account(
+                        self.state.clone(),
+                        request.into_request("MerchantAccountCreate")?,
+                    )
+                    .await?;
+                    self.find_merchant(&merchant_id).await?
+                }
+            }
+        };
+
+        let Some((merchant_account, key_store)) = merchant else {
+            // The merchant is yet to be created in a dry run, so are all of its resources
+            for profile in &declaration.profiles {
+                self.plan_profile_creation(&resource, profile);
+            }
+            return Ok(());
+        };
+
+        let merchant = MerchantContext {
+            merchant_account: &merchant_account,
+            key_store: &key_store,
+            resource: &resource,
+        };
+        let db = self.state.store.as_ref();
+        let key_manager_state = &self.state.into();
+        let live_profiles = db
+            .list_profile_by_merchant_id(key_manager_state, &key_store, &merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the profiles of the merchant")?;
+        let live_connectors = db
+            .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+                key_manager_state,
+                &merchant_id,
+                true,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the connector accounts of the merchant")?;
+
+        for profile in declaration.profiles {
+            Box::pin(self.reconcile_profile(&merchant, &live_profiles, &live_connectors, profile))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Adds the creation of a profile and all of its resources to the plan
+    fn plan_profile_creation(&mut self, merchant_resource: &str, declaration: &ProfileDeclaration) {
+        let resource = format!("{merchant_resource}/{}", declaration.profile_name);
+        self.add_plan_entry(
+            ReconciledResourceType::Profile,
+            resource.clone(),
+            ReconcileAction::Create,
+            Vec::new(),
+        );
+        for connector in &declaration.connectors {
+            self.add_plan_entry(
+                ReconciledResourceType::MerchantConnectorAccount,
+                format!(
+                    "{resource}/{}",
+                    get_connector_resource(
+                        connector.connector_name,
+                        connector.connector_label.as_deref()
+                    )
+                ),
+                ReconcileAction::Create,
+                Vec::new(),
+            );
+        }
+        if let Some(routing) = &declaration.routing {
+            self.add_plan_entry(
+                ReconciledResourceType::RoutingAlgorithm,
+                format!("{resource}/routing/{}", routing.name),
+                ReconcileAction::Create,
+                Vec::new(),
+            );
+        }
+    }
+
+    async fn reconcile_profile(
+        &mut self,
+        merchant: &MerchantContext<'_>,
+        live_profiles: &[domain::Profile],
+        live_connectors: &[domain::MerchantConnectorAccount],
+        declaration: ProfileDeclaration,
+    ) -> RouterResult<()> {
+        let resource = format!("{}/{}", merchant.resource, declaration.profile_name);
+        let fields = get_profile_fields(&declaration)?;
+
+        let live_profile = live_profiles
+            .iter()
+            .find(|profile| profile.profile_name == declaration.profile_name);
+        let profile = match live_profile {
+            Some(profile) => {
+                let live = to_live_value(
+                    api::ProfileResponse::foreign_try_from(profile.clone())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                )?;
+                let changed_fields = fields.get_changed_fields(&live);
+                let action = if changed_fields.is_empty() {
+                    ReconcileAction::NoOp
+                } else {
+                    ReconcileAction::Update
+                };
+                if action == ReconcileAction::Update && !self.dry_run {
+                    admin::update_profile(
+                        self.state.clone(),
+                        profile.get_id(),
+                        merchant.key_store.clone(),
+                        fields.into_request("ProfileUpdate")?,
+                    )
+                    .await?;
+                }
+                self.add_plan_entry(
+                    ReconciledResourceType::Profile,
+                    resource.clone(),
+                    action,
+                    changed_fields,
+                );
+                profile.clone()
+            }
+            None if self.dry_run => {
+                self.plan_profile_creation(merchant.resource, &declaration);
+                return Ok(());
+            }
+            None => {
+                self.add_plan_entry(
+                    ReconciledResourceType::Profile,
+                    resource.clone(),
+                    ReconcileAction::Create,
+                    fields.0.keys().cloned().collect(),
+                );
+                let mut request = fields;
+                request.insert("profile_name", &Some(&declaration.profile_name))?;
+                let response = get_json_response(
+                    admin::create_profile(
+                        self.state.clone(),
+                        request.into_request("ProfileCreate")?,
+                        merchant.merchant_account.clone(),
+                        merchant.key_store.clone(),
+                    )
+                    .await?,
+                )?;
+                self.state
+                    .store
+                    .find_business_profile_by_profile_id(
+                        &self.state.into(),
+                        merchant.key_store,
+                        &response.profile_id,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                        id: response.profile_id.get_string_repr().to_owned(),
+                    })?
+            }
+        };
+
+        let mut profile_connectors = live_connectors
+            .iter()
+            .filter(|connector| &connector.profile_id == profile.get_id())
+            .map(|connector| ProfileConnector {
+                connector_name: connector.connector_name.clone(),
+                connector_label: connector.connector_label.clone(),
+                merchant_connector_id: connector.get_id(),
+            })
+            .collect::<Vec<_>>();
+
+        for connector in declaration.connectors {
+            self.reconcile_connector(
+                merchant,
+                &profile,
+                &resource,
+                live_connectors,
+                &mut profile_connectors,
+                connector,
+            )
+            .await?;
+        }
+
+        if let Some(routing) = declaration.routing {
+            self.reconcile_routing(merchant, &profile, &resource, &profile_connectors, routing)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn reconcile_connector(
+        &mut self,
+        merchant: &MerchantContext<'_>,
+        profile: &domain::Profile,
+        profile_resource: &str,
+        live_connectors: &[domain::MerchantConnectorAccount],
+        profile_connectors: &mut Vec<ProfileConnector>,
+        declaration: ConnectorDeclaration,
+    ) -> RouterResult<()> {
+        let resource = format!(
+            "{profile_resource}/{}",
+            get_connector_resource(
+                declaration.connector_name,
+                declaration.connector_label.as_deref()
+            )
+        );
+        let mut fields = get_connector_fields(&declaration)?;
+
+        let live_connector = find_profile_connector(
+            profile_connectors,
+            declaration.connector_name,
+            declaration.connector_label.as_deref(),
+        )?
+        .and_then(|profile_connector| {
+            live_connectors
+                .iter()
+                .find(|connector| connector.get_id() == profile_connector.merchant_connector_id)
+        });
+
+        match live_connector {
+            Some(connector) => {
+                let live = to_live_value(
+                    api_models::admin::MerchantConnectorResponse::foreign_try_from(
+                        connector.clone(),
+                    )?,
+                )?;
+                let mut changed_fields = fields.get_changed_fields(&live);
+                if declaration.connector_account_details.as_ref().is_some_and(
+                    |connector_account_details| {
+                        connector_account_details.peek()
+                            != connector.connector_account_details.get_inner().peek()
+                    },
+                ) {
+                    changed_fields.push("connector_account_details".to_string());
+                }
+
+                let action = if changed_fields.is_empty() {
+                    ReconcileAction::NoOp
+                } else {
+                    ReconcileAction::Update
+                };
+                if action == ReconcileAction::Update && !self.dry_run {
+                    fields.insert(
+                        "connector_account_details",
+                        &declaration.connector_account_details,
+                    )?;
+                    admin::update_connector(
+                        self.state.clone(),
+                        merchant.merchant_account.get_id(),
+                        None,
+                        &connector.get_id(),
+                        fields.into_request("MerchantConnectorUpdate")?,
+                    )
+                    .await?;
+                }
+                self.add_plan_entry(
+                    ReconciledResourceType::MerchantConnectorAccount,
+                    resource,
+                    action,
+                    changed_fields,
+                );
+            }
+            None => {
+                let mut changed_fields = fields.0.keys().cloned().collect::<Vec<_>>();
+                if declaration.connector_account_details.is_some() {
+                    changed_fields.push("connector_account_details".to_string());
+                }
+                self.add_plan_entry(
+                    ReconciledResourceType::MerchantConnectorAccount,
+                    resource,
+                    ReconcileAction::Create,
+                    changed_fields,
+                );
+                if self.dry_run {
+                    return Ok(());
+                }
+
+                fields.insert("connector_name", &Some(declaration.connector_name))?;
+                fields.insert("connector_label", &declaration.connector_label)?;
+                fields.insert(
+                    "connector_account_details",
+                    &declaration.connector_account_details,
+                )?;
+                fields.insert("profile_id", &Some(profile.get_id()))?;
+                let response = get_json_response(
+                    admin::create_connector(
+                        self.state.clone(),
+                        fields.into_request("MerchantConnectorCreate")?,
+                        merchant.merchant_account.clone(),
+                        None,
+                        merchant.key_store.clone(),
+                    )
+                    .await?,
+                )?;
+                profile_connectors.push(ProfileConnector {
+                    connector_name: response.connector_name.to_string(),
+                    connector_label: response.connector_label,
+                    merchant_connector_id: response.merchant_connector_id,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    async fn reconcile_routing(
+        &mut self,
+        merchant: &MerchantContext<'_>,
+        profile: &domain::Profile,
+        profile_resource: &str,
+        profile_connectors: &[ProfileConnector],
+        declaration: RoutingDeclaration,
+    ) -> RouterResult<()> {
+        let resource = format!("{profile_resource}/routing/{}", declaration.name);
+        let changed_fields = vec!["algorithm".to_string()];
+
+        let Some(algorithm) =
+            resolve_routing_algorithm(profile_connectors, &declaration.algorithm)?
+        else {
+            if !self.dry_run {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Routing algorithm {resource} refers to a connector account which is not declared"
+                    ),
+                }));
+            }
+            self.add_plan_entry(
+                ReconciledResourceType::RoutingAlgorithm,
+                resource,
+                ReconcileAction::Create,
+                changed_fields,
+            );
+            return Ok(());
+        };
+
+        let active_algorithm_id = profile
+            .routing_algorithm
+            .clone()
+            .map(|routing_algorithm| {
+                routing_algorithm
+                    .parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to deserialize routing algorithm ref from business profile")?
+            .and_then(|routing_algorithm_ref| routing_algorithm_ref.algorithm_id);
+        let active_algorithm_data = match active_algorithm_id {
+            Some(algorithm_id) => Some(
+                self.state
+                    .store
+                    .find_routing_algorithm_by_profile_id_algorithm_id(
+                        profile.get_id(),
+                        &algorithm_id,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the active routing algorithm")?
+                    .algorithm_data,
+            ),
+            None => None,
+        };
+
+        let declared_algorithm_data = to_live_value(&algorithm)?;
+        let action = match active_algorithm_data {
+            Some(active_algorithm_data) if active_algorithm_data == declared_algorithm_data => {
+                ReconcileAction::NoOp
+            }
+            Some(_) => ReconcileAction::Update,
+            None => ReconcileAction::Create,
+        };
+
+        if action != ReconcileAction::NoOp && !self.dry_run {
+            let transaction_type = api_enums::TransactionType::Payment;
+            let record = get_json_response(
+                routing::create_routing_algorithm_under_profile(
+                    self.state.clone(),
+                    merchant.merchant_account.clone(),
+                    merchant.key_store.clone(),
+                    None,
+                    routing_types::RoutingConfigRequest {
+                        name: Some(declaration.name),
+                        description: Some(declaration.description.unwrap_or_default()),
+                        algorithm: Some(algorithm),
+                        profile_id: Some(profile.get_id().to_owned()),
+                    },
+                    &transaction_type,
+                )
+                .await?,
+            )?;
+            routing::link_routing_config(
+                self.state.clone(),
+                merchant.merchant_account.clone(),
+                merchant.key_store.clone(),
+                None,
+                record.id,
+                &transaction_type,
+            )
+            .await?;
+        }
+
+        self.add_plan_entry(
+            ReconciledResourceType::RoutingAlgorithm,
+            resource,
+            action,
+            if action == ReconcileAction::NoOp {
+                Vec::new()
+            } else {
+                changed_fields
+            },
+        );
+        Ok(())
+    }
+}
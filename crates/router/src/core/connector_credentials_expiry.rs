@@ -0,0 +1,459 @@
+use api_models::admin as admin_api;
+use common_utils::{date_time, id_type};
+use diesel_models::{
+    enums as storage_enums, merchant_connector_account::ConnectorCredentialExpiry,
+    process_tracker::business_status,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::errors::StorageErrorExt;
+#[cfg(feature = "email")]
+use crate::{consts, services::email::types as email_types, types::api, utils::OptionExt};
+#[cfg(feature = "olap")]
+use crate::{core::utils as core_utils, services};
+use crate::{
+    core::webhooks,
+    errors,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+const CONNECTOR_CREDENTIALS_EXPIRY_TASK: &str = "CONNECTOR_CREDENTIALS_EXPIRY";
+const CONNECTOR_CREDENTIALS_EXPIRY_TAG: &str = "CONNECTOR_CREDENTIALS_EXPIRY";
+
+/// The interval between consecutive checks for the connector credentials of a merchant which are
+/// due a reminder of their expiry
+pub const CONNECTOR_CREDENTIALS_EXPIRY_CHECK_INTERVAL_IN_HOURS: i64 = 24;
+
+/// The number of days within which the expiring credentials are listed, if not specified
+const DEFAULT_EXPIRING_CREDENTIALS_WITHIN_DAYS: u16 = 30;
+
+pub fn generate_task_id_for_connector_credentials_expiry_workflow(
+    merchant_id: &id_type::MerchantId,
+) -> String {
+    format!(
+        "{}_{CONNECTOR_CREDENTIALS_EXPIRY_TASK}_{}",
+        storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// The ID of the reminder sent for the credentials of the connector account on the given day. The
+/// reminders of a connector account are only sent once a day, so the ID is used as the primary
+/// object ID of the outgoing webhook.
+fn get_reminder_id(
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    now: PrimitiveDateTime,
+) -> String {
+    format!("{}_{}", merchant_connector_id.get_string_repr(), now.date())
+}
+
+/// The connector account for which the reminder with the given ID was sent
+pub fn get_merchant_connector_id_from_reminder_id(
+    reminder_id: &str,
+) -> errors::RouterResult<id_type::MerchantConnectorAccountId> {
+    reminder_id
+        .rsplit_once('_')
+        .and_then(|(merchant_connector_id, _)| {
+            id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.to_owned()).ok()
+        })
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Invalid connector credentials expiry reminder ID: {reminder_id}")
+        })
+}
+
+fn get_expiring_connector_credentials_response(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    credentials: &[&ConnectorCredentialExpiry],
+    now: PrimitiveDateTime,
+) -> admin_api::ExpiringConnectorCredentialsResponse {
+    admin_api::ExpiringConnectorCredentialsResponse {
+        merchant_connector_id: merchant_connector_account.get_id(),
+        connector_name: merchant_connector_account.connector_name.clone(),
+        connector_label: merchant_connector_account.connector_label.clone(),
+        profile_id: merchant_connector_account.profile_id.clone(),
+        credentials: credentials
+            .iter()
+            .map(|credential| admin_api::ExpiringConnectorCredential {
+                credential_type: credential.credential_type,
+                expires_at: credential.expires_at,
+                description: credential.description.clone(),
+                days_to_expiry: credential.get_days_to_expiry(now),
+            })
+            .collect(),
+    }
+}
+
+/// Lists the connector accounts of the merchant whose credentials expire within the requested
+/// number of days, along with those credentials
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn list_expiring_connector_credentials(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    constraints: admin_api::ExpiringConnectorCredentialsListConstraints,
+) -> errors::RouterResponse<Vec<admin_api::ExpiringConnectorCredentialsResponse>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &merchant_id,
+            true,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+    let merchant_connector_accounts = core_utils::filter_objects_based_on_profile_id_list(
+        profile_id_list,
+        merchant_connector_accounts,
+    );
+
+    let now = date_time::now();
+    let within_days = i64::from(
+        constraints
+            .within_days
+            .unwrap_or(DEFAULT_EXPIRING_CREDENTIALS_WITHIN_DAYS),
+    );
+    let response = merchant_connector_accounts
+        .iter()
+        .filter_map(|merchant_connector_account| {
+            let credentials = merchant_connector_account
+                .credentials_expiry
+                .as_ref()?
+                .get_credentials_expiring_within(now, within_days);
+            (!credentials.is_empty()).then(|| {
+                get_expiring_connector_credentials_response(
+                    merchant_connector_account,
+                    &credentials,
+                    now,
+                )
+            })
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// The content of the outgoing webhook of the reminder with the given ID, with the credentials of
+/// the connector account which expire within the earliest configured reminder. The credentials are
+/// empty if they have been renewed since the reminder was sent.
+pub async fn get_connector_credentials_expiry_reminder_content(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    reminder_id: &str,
+) -> errors::RouterResult<admin_api::ExpiringConnectorCredentialsResponse> {
+    let merchant_connector_id = get_merchant_connector_id_from_reminder_id(reminder_id)?;
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_id,
+            &merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    let now = date_time::now();
+    let within_days = state
+        .conf
+        .connector_credentials_expiry
+        .reminder_days
+        .iter()
+        .max()
+        .copied()
+        .map(i64::from)
+        .unwrap_or_default();
+    let credentials = merchant_connector_account
+        .credentials_expiry
+        .as_ref()
+        .map(|credentials_expiry| {
+            credentials_expiry.get_credentials_expiring_within(now, within_days)
+        })
+        .unwrap_or_default();
+    Ok(get_expiring_connector_credentials_response(
+        &merchant_connector_account,
+        &credentials,
+        now,
+    ))
+}
+
+/// The unexpired credentials of the connector account whose number of days left until expiry is
+/// one of the configured reminder days
+fn get_credentials_due_reminder<'a>(
+    state: &SessionState,
+    merchant_connector_account: &'a domain::MerchantConnectorAccount,
+    now: PrimitiveDateTime,
+) -> Vec<&'a ConnectorCredentialExpiry> {
+    let reminder_days = &state.conf.connector_credentials_expiry.reminder_days;
+    merchant_connector_account
+        .credentials_expiry
+        .iter()
+        .flat_map(|credentials_expiry| credentials_expiry.credentials.iter())
+        .filter(|credential| {
+            !credential.is_expired(now)
+                && reminder_days.iter().any(|reminder_day| {
+                    i64::from(*reminder_day) == credential.get_days_to_expiry(now)
+                })
+        })
+        .collect()
+}
+
+/// Notifies the merchant of the connector credentials which are due a reminder of their expiry,
+/// with an outgoing webhook per connector account and, if emails are enabled, an email per
+/// credential. Failures to notify the merchant are only logged, so that the reminders of the
+/// other connector accounts are still sent. Returns whether any of the connector accounts of the
+/// merchant still has unexpired credentials which are to be reminded of later.
+#[instrument(skip_all)]
+pub async fn send_connector_credentials_expiry_reminders(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<bool> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_account.get_id(),
+            true,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector accounts of the merchant")?;
+
+    let now = date_time::now();
+    let mut has_unexpired_credentials = false;
+    for merchant_connector_account in &merchant_connector_accounts {
+        has_unexpired_credentials |= merchant_connector_account
+            .credentials_expiry
+            .as_ref()
+            .is_some_and(|credentials_expiry| credentials_expiry.has_unexpired_credentials(now));
+
+        let credentials = get_credentials_due_reminder(state, merchant_connector_account, now);
+        if credentials.is_empty() {
+            continue;
+        }
+
+        let response = get_expiring_connector_credentials_response(
+            merchant_connector_account,
+            &credentials,
+            now,
+        );
+        if let Err(error) = trigger_connector_credentials_expiry_webhook(
+            state,
+            merchant_account,
+            key_store,
+            merchant_connector_account,
+            response,
+            now,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                merchant_connector_id = ?merchant_connector_account.get_id(),
+                "Failed to send the outgoing webhook of the expiry of connector credentials"
+            );
+        }
+
+        #[cfg(feature = "email")]
+        if let Err(error) = send_connector_credentials_expiry_emails(
+            state,
+            merchant_account,
+            merchant_connector_account,
+            &credentials,
+            now,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                merchant_connector_id = ?merchant_connector_account.get_id(),
+                "Failed to send the email of the expiry of connector credentials"
+            );
+        }
+    }
+
+    Ok(has_unexpired_credentials)
+}
+
+async fn trigger_connector_credentials_expiry_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    response: admin_api::ExpiringConnectorCredentialsResponse,
+    now: PrimitiveDateTime,
+) -> errors::RouterResult<()> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            key_store,
+            &merchant_connector_account.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: merchant_connector_account
+                .profile_id
+                .get_string_repr()
+                .to_owned(),
+        })?;
+
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        storage_enums::EventType::ConnectorCredentialsExpiring,
+        storage_enums::EventClass::ConnectorAccounts,
+        get_reminder_id(&merchant_connector_account.get_id(), now),
+        storage_enums::EventObjectType::ConnectorCredentialsExpiryDetails,
+        api_models::webhooks::OutgoingWebhookContent::ConnectorCredentialsExpiryDetails(Box::new(
+            response,
+        )),
+        Some(merchant_connector_account.created_at),
+    ))
+    .await
+}
+
+#[cfg(feature = "email")]
+async fn send_connector_credentials_expiry_emails(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    credentials: &[&ConnectorCredentialExpiry],
+    now: PrimitiveDateTime,
+) -> errors::RouterResult<()> {
+    let Some(email) = merchant_account
+        .merchant_details
+        .clone()
+        .parse_value::<api::MerchantDetails>("MerchantDetails")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the merchant details")?
+        .primary_email
+    else {
+        logger::warn!("Skipping the email of the expiry of connector credentials, as the merchant has no primary email");
+        return Ok(());
+    };
+    let recipient_email = domain::UserEmail::from_pii_email(email)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the primary email of the merchant to UserEmail")?;
+
+    for credential in credentials {
+        let email_contents = email_types::ConnectorCredentialsExpiryReminder {
+            recipient_email: recipient_email.clone(),
+            subject: consts::EMAIL_SUBJECT_CONNECTOR_CREDENTIALS_EXPIRY,
+            expires_in: credential.get_days_to_expiry(now),
+            credential_type: credential.credential_type,
+            connector_name: merchant_connector_account.connector_name.clone(),
+            connector_label: merchant_connector_account.connector_label.clone(),
+            merchant_connector_id: merchant_connector_account.get_id(),
+        };
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to send the email of the expiry of connector credentials")?;
+    }
+
+    Ok(())
+}
+
+/// Schedules the daily job which reminds the merchant of the connector credentials which are about
+/// to expire. The job reads the credentials of the connector accounts of the merchant on every run,
+/// so a job which is already scheduled is left untouched.
+pub async fn schedule_connector_credentials_expiry_reminders(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> errors::RouterResult<()> {
+    let db = state.store.as_ref();
+    let process_tracker_id =
+        generate_task_id_for_connector_credentials_expiry_workflow(merchant_id);
+    let schedule_time = date_time::now();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to retrieve connector credentials expiry task from process tracker",
+        )?;
+
+    match existing_process {
+        Some(process) if process.status != storage_enums::ProcessTrackerStatus::Finish => {}
+        // The job is finished once all the credentials of the merchant have expired, and is
+        // rescheduled when the expiry of credentials is configured again
+        Some(process) => {
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(schedule_time),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to reschedule connector credentials expiry task in process tracker",
+            )?;
+        }
+        None => {
+            let tracking_data = storage::ConnectorCredentialsExpiryTrackingData {
+                merchant_id: merchant_id.to_owned(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                CONNECTOR_CREDENTIALS_EXPIRY_TASK,
+                storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow,
+                [CONNECTOR_CREDENTIALS_EXPIRY_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to construct CONNECTOR_CREDENTIALS_EXPIRY process tracker task",
+            )?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting CONNECTOR_CREDENTIALS_EXPIRY task to process_tracker for merchant_id: {}",
+                        merchant_id.get_string_repr()
+                    )
+                })?;
+        }
+    };
+
+    Ok(())
+}
@@ -0,0 +1,365 @@
+use api_models::admin as admin_types;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use common_utils::ext_traits::Encode;
+use common_utils::{date_time, ext_traits::StringExt, id_type};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use time::{format_description, PrimitiveDateTime, UtcOffset};
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{
+    consts,
+    core::{
+        errors::{RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    services, utils,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::api::routing as routing_types,
+};
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+const UTC_TIMEZONE: &str = "+00:00";
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Parses a UTC offset of the form `+05:30`. `UTC` and `Z` are accepted as aliases of `+00:00`.
+fn parse_utc_offset(timezone: &str) -> RouterResult<UtcOffset> {
+    if timezone.eq_ignore_ascii_case("utc") || timezone.eq_ignore_ascii_case("z") {
+        return Ok(UtcOffset::UTC);
+    }
+
+    let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid UTC offset format description")?;
+
+    UtcOffset::parse(timezone, &format).change_context(
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "timezone".to_string(),
+            expected_format: "a UTC offset such as +05:30".to_string(),
+        },
+    )
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+fn to_utc(date_time: PrimitiveDateTime, offset: UtcOffset) -> PrimitiveDateTime {
+    let utc_date_time = date_time.assume_offset(offset).to_offset(UtcOffset::UTC);
+    PrimitiveDateTime::new(utc_date_time.date(), utc_date_time.time())
+}
+
+fn parse_maintenance_windows(
+    config: &str,
+) -> RouterResult<Vec<admin_types::ConnectorMaintenanceWindowResponse>> {
+    config
+        .to_string()
+        .parse_struct("Vec<ConnectorMaintenanceWindowResponse>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Connector maintenance windows config has invalid structure")
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Fetches the maintenance windows of the merchant from the database, bypassing the cache
+async fn get_maintenance_windows_from_db(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<Vec<admin_types::ConnectorMaintenanceWindowResponse>>> {
+    match db
+        .find_config_by_key_from_db(&merchant_id.get_connector_maintenance_windows_key())
+        .await
+    {
+        Ok(config) => parse_maintenance_windows(&config.config).map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching connector maintenance windows"),
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+async fn save_maintenance_windows(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    maintenance_windows: &[admin_types::ConnectorMaintenanceWindowResponse],
+    config_exists: bool,
+) -> RouterResult<()> {
+    let key = merchant_id.get_connector_maintenance_windows_key();
+    let config = maintenance_windows
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize connector maintenance windows")?;
+
+    if config_exists {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating connector maintenance windows")?;
+    } else {
+        db.insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error inserting connector maintenance windows")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn create_maintenance_window(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    req: admin_types::ConnectorMaintenanceWindowCreateRequest,
+) -> RouterResponse<admin_types::ConnectorMaintenanceWindowResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let timezone = req.timezone.unwrap_or_else(|| UTC_TIMEZONE.to_string());
+    let offset = parse_utc_offset(&timezone)?;
+    let start_time = to_utc(req.start_time, offset);
+    let end_time = to_utc(req.end_time, offset);
+    let now = date_time::now();
+
+    if end_time <= start_time {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_time must be later than start_time".to_string(),
+        }
+        .into());
+    }
+    if end_time <= now {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_time must be in the future".to_string(),
+        }
+        .into());
+    }
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    let existing_windows = get_maintenance_windows_from_db(db, &merchant_id).await?;
+    let config_exists = existing_windows.is_some();
+
+    let maintenance_window = admin_types::ConnectorMaintenanceWindowResponse {
+        maintenance_window_id: utils::generate_id(consts::ID_LENGTH, "mw"),
+        merchant_connector_id,
+        connector_name: mca.connector_name,
+        start_time,
+        end_time,
+        timezone,
+        description: req.description,
+        created_at: now,
+    };
+
+    // Windows which have already ended are dropped whenever the list is written
+    let mut maintenance_windows = existing_windows
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|window| !window.is_expired_at(now))
+        .collect::<Vec<_>>();
+    maintenance_windows.push(maintenance_window.clone());
+
+    save_maintenance_windows(db, &merchant_id, &maintenance_windows, config_exists).await?;
+
+    Ok(services::ApplicationResponse::Json(maintenance_window))
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn list_maintenance_windows(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<Vec<admin_types::ConnectorMaintenanceWindowResponse>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    let now = date_time::now();
+    let maintenance_windows = get_maintenance_windows_from_db(db, &merchant_id)
+        .await?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|window| {
+            window.merchant_connector_id == merchant_connector_id && !window.is_expired_at(now)
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(maintenance_windows))
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn delete_maintenance_window(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    maintenance_window_id: String,
+) -> RouterResponse<admin_types::ConnectorMaintenanceWindowResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    let maintenance_window_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Maintenance window {maintenance_window_id} does not exist"),
+    };
+
+    let mut maintenance_windows = get_maintenance_windows_from_db(db, &merchant_id)
+        .await?
+        .ok_or_else(maintenance_window_not_found)?;
+
+    let position = maintenance_windows
+        .iter()
+        .position(|window| {
+            window.maintenance_window_id == maintenance_window_id
+                && window.merchant_connector_id == merchant_connector_id
+        })
+        .ok_or_else(maintenance_window_not_found)?;
+    let deleted_window = maintenance_windows.remove(position);
+
+    let now = date_time::now();
+    maintenance_windows.retain(|window| !window.is_expired_at(now));
+
+    save_maintenance_windows(db, &merchant_id, &maintenance_windows, true).await?;
+
+    Ok(services::ApplicationResponse::Json(deleted_window))
+}
+
+/// Removes the connectors which are under maintenance at present from the routing result, so that
+/// the transaction falls through to the remaining connectors.
+///
+/// A choice which does not carry a merchant connector id is skipped if any account of the same
+/// connector is under maintenance.
+#[instrument(skip_all)]
+pub async fn filter_connectors_under_maintenance(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let maintenance_windows = match state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_connector_maintenance_windows_key(),
+            Some("[]".to_string()),
+        )
+        .await
+    {
+        Ok(config) => parse_maintenance_windows(&config.config),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching connector maintenance windows"),
+    };
+
+    // Routing should not fail because the maintenance windows could not be read
+    let maintenance_windows = match maintenance_windows {
+        Ok(maintenance_windows) => maintenance_windows,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch connector maintenance windows");
+            return connectors;
+        }
+    };
+
+    let now = date_time::now();
+    let active_windows = maintenance_windows
+        .iter()
+        .filter(|window| window.is_active_at(now))
+        .collect::<Vec<_>>();
+
+    if active_windows.is_empty() {
+        return connectors;
+    }
+
+    connectors
+        .into_iter()
+        .filter(|choice| {
+            let is_under_maintenance = active_windows.iter().any(|window| {
+                choice.merchant_connector_id.as_ref().map_or_else(
+                    || window.connector_name == choice.connector.to_string(),
+                    |merchant_connector_id| window.merchant_connector_id == *merchant_connector_id,
+                )
+            });
+            if is_under_maintenance {
+                logger::info!(
+                    connector = ?choice.connector,
+                    merchant_connector_id = ?choice.merchant_connector_id,
+                    "Skipping connector under maintenance"
+                );
+            }
+            !is_under_maintenance
+        })
+        .collect()
+}
@@ -160,6 +160,8 @@ pub async fn update_mca(
         frm_configs: None,
         connector_webhook_details: None,
         pm_auth_config: None,
+        tags: None,
+        credentials_expiry: None,
         test_mode: None,
         additional_merchant_data: None,
         connector_wallets_details: None,
@@ -176,6 +178,8 @@ pub async fn update_mca(
         frm_configs: None,
         connector_webhook_details: None,
         pm_auth_config: None,
+        tags: None,
+        credentials_expiry: None,
         merchant_id: merchant_id.clone(),
         additional_merchant_data: None,
         connector_wallets_details: None,
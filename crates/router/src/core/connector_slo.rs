@@ -0,0 +1,370 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use common_utils::{date_time, request::RequestContent};
+use error_stack::ResultExt;
+use once_cell::sync::Lazy;
+use router_env::{
+    instrument, logger,
+    metrics::add_attributes,
+    tracing::{self, Instrument},
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::{ConnectorFlowSloThresholds, ConnectorSloConfig},
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+    services,
+};
+
+const CONNECTOR_SLO_ALERT_TIMEOUT_SECS: u64 = 5;
+
+/// The calls retained per connector flow are bounded, so that a burst of calls does not grow the
+/// window without limit
+const MAX_SAMPLES_PER_CONNECTOR_FLOW: usize = 10_000;
+
+/// The connector calls of the current window, tracked per connector and flow within this instance
+static CONNECTOR_FLOW_WINDOWS: Lazy<
+    Mutex<HashMap<(String, ConnectorSloFlow), ConnectorFlowWindow>>,
+> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The connector flows for which service level objectives can be configured
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorSloFlow {
+    Authorize,
+    Capture,
+    Refund,
+    PayoutCreate,
+}
+
+impl ConnectorSloFlow {
+    /// Maps the name of the flow type of a connector call to the flow it is evaluated under
+    fn from_flow_name(flow_name: &str) -> Option<Self> {
+        match flow_name {
+            "Authorize" => Some(Self::Authorize),
+            "Capture" => Some(Self::Capture),
+            "Execute" => Some(Self::Refund),
+            "PoCreate" => Some(Self::PayoutCreate),
+            _ => None,
+        }
+    }
+
+    fn get_thresholds(self, config: &ConnectorSloConfig) -> Option<&ConnectorFlowSloThresholds> {
+        match self {
+            Self::Authorize => config.authorize.as_ref(),
+            Self::Capture => config.capture.as_ref(),
+            Self::Refund => config.refund.as_ref(),
+            Self::PayoutCreate => config.payout_create.as_ref(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorSloIndicator {
+    P50Latency,
+    P95Latency,
+    P99Latency,
+    ErrorRate,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ConnectorSloBreach {
+    pub indicator: ConnectorSloIndicator,
+    /// The observed value, in milliseconds for latencies and as a fraction for the error rate
+    pub observed: f64,
+    pub threshold: f64,
+}
+
+/// Alert raised to the operators when a connector flow breaches its service level objectives
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ConnectorSloAlert {
+    pub connector: String,
+    pub flow: ConnectorSloFlow,
+    pub evaluation_window_in_secs: u64,
+    pub sample_count: usize,
+    pub p50_latency_in_millis: u128,
+    pub p95_latency_in_millis: u128,
+    pub p99_latency_in_millis: u128,
+    pub error_rate: f64,
+    pub breaches: Vec<ConnectorSloBreach>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub raised_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ConnectorCallSample {
+    recorded_at: Instant,
+    latency_in_millis: u128,
+    is_error: bool,
+}
+
+#[derive(Debug, Default)]
+struct ConnectorFlowWindow {
+    samples: VecDeque<ConnectorCallSample>,
+    last_alerted_at: Option<Instant>,
+}
+
+struct ConnectorFlowSummary {
+    sample_count: usize,
+    p50_latency_in_millis: u128,
+    p95_latency_in_millis: u128,
+    p99_latency_in_millis: u128,
+    error_rate: f64,
+}
+
+impl ConnectorFlowWindow {
+    fn record(&mut self, sample: ConnectorCallSample, window: Duration) {
+        while self
+            .samples
+            .front()
+            .is_some_and(|oldest| sample.recorded_at.duration_since(oldest.recorded_at) > window)
+            || self.samples.len() >= MAX_SAMPLES_PER_CONNECTOR_FLOW
+        {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    fn is_in_cooldown(&self, now: Instant, cooldown: Duration) -> bool {
+        self.last_alerted_at
+            .is_some_and(|last_alerted_at| now.duration_since(last_alerted_at) < cooldown)
+    }
+
+    #[allow(clippy::as_conversions)]
+    fn summarize(&self) -> ConnectorFlowSummary {
+        let mut latencies = self
+            .samples
+            .iter()
+            .map(|sample| sample.latency_in_millis)
+            .collect::<Vec<_>>();
+        latencies.sort_unstable();
+        let error_count = self.samples.iter().filter(|sample| sample.is_error).count();
+
+        ConnectorFlowSummary {
+            sample_count: latencies.len(),
+            p50_latency_in_millis: get_percentile(&latencies, 50),
+            p95_latency_in_millis: get_percentile(&latencies, 95),
+            p99_latency_in_millis: get_percentile(&latencies, 99),
+            error_rate: error_count as f64 / latencies.len().max(1) as f64,
+        }
+    }
+}
+
+/// Nearest rank percentile of latencies sorted in ascending order
+fn get_percentile(sorted_latencies: &[u128], percentile: usize) -> u128 {
+    let rank = (percentile * sorted_latencies.len()).div_ceil(100);
+    sorted_latencies
+        .get(rank.saturating_sub(1))
+        .copied()
+        .unwrap_or_default()
+}
+
+impl ConnectorFlowSummary {
+    #[allow(clippy::as_conversions)]
+    fn get_breaches(&self, thresholds: &ConnectorFlowSloThresholds) -> Vec<ConnectorSloBreach> {
+        [
+            (
+                ConnectorSloIndicator::P50Latency,
+                self.p50_latency_in_millis as f64,
+                thresholds.p50_latency_in_millis.map(|value| value as f64),
+            ),
+            (
+                ConnectorSloIndicator::P95Latency,
+                self.p95_latency_in_millis as f64,
+                thresholds.p95_latency_in_millis.map(|value| value as f64),
+            ),
+            (
+                ConnectorSloIndicator::P99Latency,
+                self.p99_latency_in_millis as f64,
+                thresholds.p99_latency_in_millis.map(|value| value as f64),
+            ),
+            (
+                ConnectorSloIndicator::ErrorRate,
+                self.error_rate,
+                thresholds.max_error_rate,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(indicator, observed, threshold)| {
+            threshold
+                .filter(|threshold| observed > *threshold)
+                .map(|threshold| ConnectorSloBreach {
+                    indicator,
+                    observed,
+                    threshold,
+                })
+        })
+        .collect()
+    }
+}
+
+/// Records the latency and outcome of a call made to a connector. Calls which failed with a
+/// server error or without a response count as errors. If the flow of the call has service level
+/// objectives configured, the calls of the current window are evaluated against them and an alert
+/// is raised when they are breached. The merchant attributes are added to the recorded metrics.
+pub fn record_connector_call(
+    state: &SessionState,
+    connector: &str,
+    flow_name: &str,
+    merchant_attributes: &[(&'static str, String)],
+    latency_in_millis: u128,
+    is_error: bool,
+) {
+    let attributes = add_attributes(
+        [
+            ("connector", connector.to_string()),
+            ("flow", flow_name.to_string()),
+        ]
+        .into_iter()
+        .chain(merchant_attributes.iter().cloned()),
+    );
+    #[allow(clippy::as_conversions)]
+    metrics::CONNECTOR_REQUEST_TIME.record(
+        &metrics::CONTEXT,
+        latency_in_millis as f64 / 1000.0,
+        &attributes,
+    );
+    if is_error {
+        metrics::CONNECTOR_CALL_FAILURE_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+    }
+
+    let config = &state.conf.connector_slo;
+    let Some((flow, thresholds)) = ConnectorSloFlow::from_flow_name(flow_name)
+        .filter(|_| config.enabled)
+        .and_then(|flow| {
+            flow.get_thresholds(config)
+                .map(|thresholds| (flow, thresholds))
+        })
+    else {
+        return;
+    };
+
+    let sample = ConnectorCallSample {
+        recorded_at: Instant::now(),
+        latency_in_millis,
+        is_error,
+    };
+    if let Some(alert) = evaluate_connector_flow(config, thresholds, connector, flow, sample) {
+        raise_connector_slo_alert(state, alert);
+    }
+}
+
+fn evaluate_connector_flow(
+    config: &ConnectorSloConfig,
+    thresholds: &ConnectorFlowSloThresholds,
+    connector: &str,
+    flow: ConnectorSloFlow,
+    sample: ConnectorCallSample,
+) -> Option<ConnectorSloAlert> {
+    let mut windows = CONNECTOR_FLOW_WINDOWS
+        .lock()
+        .map_err(|error| logger::error!(?error, "Connector SLO windows lock is poisoned"))
+        .ok()?;
+    let window = windows.entry((connector.to_string(), flow)).or_default();
+    window.record(
+        sample,
+        Duration::from_secs(config.evaluation_window_in_secs),
+    );
+
+    if window.samples.len() < config.min_sample_size
+        || window.is_in_cooldown(
+            sample.recorded_at,
+            Duration::from_secs(config.alert_cooldown_in_secs),
+        )
+    {
+        return None;
+    }
+
+    let summary = window.summarize();
+    let breaches = summary.get_breaches(thresholds);
+    if breaches.is_empty() {
+        return None;
+    }
+    window.last_alerted_at = Some(sample.recorded_at);
+
+    Some(ConnectorSloAlert {
+        connector: connector.to_string(),
+        flow,
+        evaluation_window_in_secs: config.evaluation_window_in_secs,
+        sample_count: summary.sample_count,
+        p50_latency_in_millis: summary.p50_latency_in_millis,
+        p95_latency_in_millis: summary.p95_latency_in_millis,
+        p99_latency_in_millis: summary.p99_latency_in_millis,
+        error_rate: summary.error_rate,
+        breaches,
+        raised_at: date_time::now(),
+    })
+}
+
+fn raise_connector_slo_alert(state: &SessionState, alert: ConnectorSloAlert) {
+    metrics::CONNECTOR_SLO_BREACH_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("connector", alert.connector.clone()),
+            ("flow", alert.flow.to_string()),
+        ]),
+    );
+    logger::warn!(
+        connector_slo_alert = %serde_json::to_string(&alert).unwrap_or_default(),
+        connector = %alert.connector,
+        flow = %alert.flow,
+        "Connector breached its service level objectives"
+    );
+
+    if let Some(alert_webhook_url) = state.conf.connector_slo.alert_webhook_url.clone() {
+        let state = state.clone();
+        // The alert is delivered in the background so that the connector call is not delayed
+        tokio::spawn(
+            async move {
+                send_connector_slo_alert(&state, &alert_webhook_url, alert)
+                    .await
+                    .map_err(|error| logger::error!(connector_slo_alert_error=?error))
+                    .ok();
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+#[instrument(skip_all)]
+async fn send_connector_slo_alert(
+    state: &SessionState,
+    alert_webhook_url: &str,
+    alert: ConnectorSloAlert,
+) -> RouterResult<()> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(alert_webhook_url)
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(alert)))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(
+            state,
+            request,
+            Some(CONNECTOR_SLO_ALERT_TIMEOUT_SECS),
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send connector SLO alert")?;
+
+    if !response.status().is_success() {
+        logger::warn!(
+            status_code = %response.status(),
+            "Connector SLO alert was not accepted by the alert webhook"
+        );
+    }
+
+    Ok(())
+}
@@ -1,3 +1,10 @@
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod portal;
+
 use common_utils::{
     crypto::Encryptable,
     errors::ReportSwitchExt,
@@ -189,6 +196,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             default_payment_method_id: None,
             updated_by: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
+            recipient_kyc_status: None,
         })
     }
 
@@ -288,6 +296,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             default_shipping_address: encrypted_customer_shipping_address.map(Into::into),
             version: hyperswitch_domain_models::consts::API_VERSION,
             status: common_enums::DeleteStatus::Active,
+            recipient_kyc_status: None,
         })
     }
 
@@ -457,11 +466,17 @@ pub async fn retrieve_customer(
     merchant_account: domain::MerchantAccount,
     _profile_id: Option<id_type::ProfileId>,
     key_store: domain::MerchantKeyStore,
-    req: customers::CustomerId,
+    req: customers::CustomerRetrieveRequest,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
+    crate::core::utils::validate_expandable_objects(
+        req.expand.as_deref(),
+        &[api_models::enums::ExpandableObject::PaymentMethod],
+    )
+    .switch()?;
+
     let response = db
         .find_customer_by_customer_id_merchant_id(
             key_manager_state,
@@ -480,9 +495,29 @@ pub async fn retrieve_customer(
         )),
         None => None,
     };
-    Ok(services::ApplicationResponse::Json(
-        customers::CustomerResponse::foreign_from((response, address)),
-    ))
+    let payment_methods = if req.should_expand(api_models::enums::ExpandableObject::PaymentMethod) {
+        let payment_methods = db
+            .find_payment_method_by_customer_id_merchant_id_list(
+                key_manager_state,
+                &key_store,
+                &req.customer_id,
+                merchant_account.get_id(),
+                Some(crate::consts::MAX_EXPANDED_LIST_LENGTH),
+            )
+            .await
+            .switch()?;
+        Some(
+            payment_methods
+                .iter()
+                .map(crate::core::payment_methods::transformers::generate_payment_method_response)
+                .collect(),
+        )
+    } else {
+        None
+    };
+    let mut customer_response = customers::CustomerResponse::foreign_from((response, address));
+    customer_response.0.payment_methods = payment_methods;
+    Ok(services::ApplicationResponse::Json(customer_response))
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
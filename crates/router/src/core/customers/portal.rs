@@ -0,0 +1,436 @@
+use api_models::{customers as customer_api, payment_methods as payment_methods_api};
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    ext_traits::Encode,
+    fp_utils::when,
+    id_type,
+    link_utils::{self, CustomerPortalStatus, GenericLinkStatus},
+};
+use diesel_models::{CustomerPortalLinkData, CustomerPortalLinkUpdate, GenericLinkNew};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use time::Duration;
+
+use crate::{
+    consts,
+    core::{
+        customers,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, customers as customer_types},
+        domain, storage,
+    },
+    utils::{self, OptionExt},
+};
+
+#[instrument(skip_all)]
+pub async fn create_customer_portal_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customer_api::CustomerPortalLinkRequest,
+) -> RouterResponse<customer_api::CustomerPortalLinkResponse> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let profile_id = req
+        .profile_id
+        .clone()
+        .or(merchant_account.default_profile.clone())
+        .get_required_value("profile_id")?;
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            merchant_id,
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Request config takes precedence over the profile's config
+    let default_config = &state.conf.generic_link.customer_portal;
+    let profile_config = business_profile.customer_portal_link_config.as_ref();
+    let ui_config = req
+        .ui_config
+        .clone()
+        .or(profile_config.map(|config| config.ui_config.clone()))
+        .unwrap_or(link_utils::GenericLinkUiConfig {
+            logo: None,
+            merchant_name: None,
+            theme: None,
+        });
+    let allowed_domains = profile_config
+        .map(|config| config.allowed_domains.clone())
+        .unwrap_or_default();
+    let base_url = profile_config
+        .and_then(|config| config.domain_name.as_ref())
+        .map(|domain| format!("https://{}", domain))
+        .unwrap_or(state.base_url.clone());
+
+    let customer_portal_link_id = core_utils::get_or_generate_id(
+        "customer_portal_link_id",
+        &req.customer_portal_link_id,
+        "customer_portal_link",
+    )?;
+    let session_expiry = req.session_expiry.unwrap_or(default_config.expiry);
+    let url = format!(
+        "{base_url}/customers/portal_link/{}/{customer_portal_link_id}",
+        merchant_id.get_string_repr()
+    );
+    let link = url::Url::parse(&url)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to form customer portal link URL - {}", url))?;
+
+    let link_data = CustomerPortalLinkData {
+        customer_portal_link_id: customer_portal_link_id.clone(),
+        customer_id: req.customer_id.clone(),
+        profile_id: profile_id.clone(),
+        link: Secret::new(link.to_string()),
+        client_secret: Secret::new(utils::generate_id(
+            consts::ID_LENGTH,
+            "customer_portal_secret",
+        )),
+        session_expiry,
+        ui_config,
+        allowed_domains,
+    };
+
+    let customer_portal_link = GenericLinkNew {
+        link_id: customer_portal_link_id,
+        primary_reference: req.customer_id.get_string_repr().to_string(),
+        merchant_id: merchant_id.to_owned(),
+        link_type: common_enums::GenericLinkType::CustomerPortal,
+        link_status: GenericLinkStatus::CustomerPortal(CustomerPortalStatus::Initiated),
+        link_data: serde_json::to_value(&link_data)
+            .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to convert CustomerPortalLinkData to Value")?,
+        url: link_data.link.clone(),
+        return_url: req.return_url,
+        expiry: common_utils::date_time::now() + Duration::seconds(session_expiry.into()),
+        ..Default::default()
+    };
+
+    let customer_portal_link = db
+        .insert_customer_portal_link(customer_portal_link)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "customer portal link already exists".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_api::CustomerPortalLinkResponse {
+            customer_portal_link_id: customer_portal_link.link_id,
+            customer_id: customer_portal_link.link_data.customer_id,
+            profile_id: customer_portal_link.link_data.profile_id,
+            expiry: customer_portal_link.expiry,
+            link: link.into(),
+            return_url: customer_portal_link.return_url,
+            ui_config: customer_portal_link.link_data.ui_config,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn render_customer_portal_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customer_api::CustomerPortalLinkRenderRequest,
+) -> RouterResponse<services::GenericLinkFormData> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let customer_portal_link =
+        find_customer_portal_link(db, &req.merchant_id, &req.customer_portal_link_id).await?;
+
+    let has_expired = common_utils::date_time::now() > customer_portal_link.expiry;
+    let status = customer_portal_link.link_status.clone();
+    let link_data = customer_portal_link.link_data.clone();
+    let allowed_domains = link_data.allowed_domains.clone();
+    let default_ui_config = state.conf.generic_link.customer_portal.ui_config.clone();
+
+    if has_expired || status == CustomerPortalStatus::Invalidated {
+        if status != CustomerPortalStatus::Invalidated {
+            let customer_portal_link_update = CustomerPortalLinkUpdate::StatusUpdate {
+                link_status: CustomerPortalStatus::Invalidated,
+            };
+            db.update_customer_portal_link(customer_portal_link, customer_portal_link_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error updating customer portal link in db")?;
+        }
+
+        let expired_link_data = services::GenericExpiredLinkData {
+            title: "Customer portal link has expired".to_string(),
+            message: "This customer portal link has expired.".to_string(),
+            theme: link_data.ui_config.theme.unwrap_or(default_ui_config.theme),
+        };
+        return Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
+            GenericLinks {
+                allowed_domains,
+                data: GenericLinksData::ExpiredLink(expired_link_data),
+                locale: DEFAULT_LOCALE.to_string(),
+            },
+        )));
+    }
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &link_data.customer_id,
+            &req.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Customer [{}] not found for link_id - {}",
+                customer_portal_link.primary_reference, customer_portal_link.link_id
+            ),
+        })?;
+
+    let billing_address = match &customer.address_id {
+        Some(address_id) => Some(api_models::payments::AddressDetails::from(
+            db.find_address_by_address_id(key_manager_state, address_id, &key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch customer's billing address")?,
+        )),
+        None => None,
+    };
+
+    let payment_methods = match cards::list_customer_payment_method(
+        &state,
+        merchant_account.clone(),
+        key_store.clone(),
+        None,
+        &customer.customer_id,
+        None,
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(response) => response.customer_payment_methods,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while listing customer payment methods")?,
+    }
+    .into_iter()
+    .map(get_customer_portal_payment_method)
+    .collect();
+
+    let js_data = customer_api::CustomerPortalLinkDetails {
+        customer_portal_link_id: customer_portal_link.link_id,
+        merchant_id: req.merchant_id,
+        client_secret: link_data.client_secret,
+        customer_id: customer.customer_id,
+        session_expiry: customer_portal_link.expiry,
+        return_url: customer_portal_link.return_url,
+        ui_config: link_utils::GenericLinkUiConfigFormData {
+            merchant_name: link_data
+                .ui_config
+                .merchant_name
+                .unwrap_or(default_ui_config.merchant_name),
+            logo: link_data.ui_config.logo.unwrap_or(default_ui_config.logo),
+            theme: link_data.ui_config.theme.unwrap_or(default_ui_config.theme),
+        },
+        payment_methods,
+        billing_address,
+    };
+
+    let serialized_js_content = format!(
+        "window.__CUSTOMER_PORTAL_DETAILS = {}",
+        js_data
+            .encode_to_string_of_json()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize CustomerPortalLinkDetails")?
+    );
+
+    Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
+        GenericLinks {
+            allowed_domains,
+            data: GenericLinksData::CustomerPortal(services::GenericLinkStatusData {
+                js_data: serialized_js_content,
+                css_data: String::new(),
+            }),
+            locale: DEFAULT_LOCALE.to_string(),
+        },
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_customer_portal_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customer_api::CustomerPortalPaymentMethodDeleteRequest,
+) -> RouterResponse<payment_methods_api::PaymentMethodDeleteResponse> {
+    let db: &dyn StorageInterface = &*state.store;
+
+    let customer_portal_link = validate_and_get_active_customer_portal_link(
+        db,
+        &req.merchant_id,
+        &req.customer_portal_link_id,
+        &req.client_secret,
+    )
+    .await?;
+
+    // Only payment methods of the customer the link was created for can be deleted
+    let payment_method = db
+        .find_payment_method(
+            &(&state).into(),
+            &key_store,
+            &req.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    when(
+        payment_method.customer_id != customer_portal_link.link_data.customer_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+
+    cards::delete_payment_method(
+        state,
+        merchant_account,
+        api::PaymentMethodId {
+            payment_method_id: req.payment_method_id,
+        },
+        key_store,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn update_customer_portal_billing_address(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customer_api::CustomerPortalBillingAddressUpdateRequest,
+) -> RouterResponse<customer_api::CustomerPortalBillingAddressResponse> {
+    let db: &dyn StorageInterface = &*state.store;
+
+    let customer_portal_link = validate_and_get_active_customer_portal_link(
+        db,
+        &req.merchant_id,
+        &req.customer_portal_link_id,
+        &req.client_secret,
+    )
+    .await?;
+    let customer_id = customer_portal_link.link_data.customer_id;
+
+    let update_customer = customer_types::CustomerUpdateRequest {
+        customer_id: Some(customer_id.clone()),
+        merchant_id: req.merchant_id,
+        address: Some(req.address),
+        ..Default::default()
+    };
+    let customer = match customers::update_customer(
+        state,
+        merchant_account,
+        update_customer,
+        key_store,
+        customer_types::UpdateCustomerId::new(customer_id.get_string_repr().to_owned()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update customer's billing address")?
+    {
+        services::ApplicationResponse::Json(customer) => customer,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while updating customer")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        customer_api::CustomerPortalBillingAddressResponse {
+            customer_portal_link_id: customer_portal_link.link_id,
+            customer_id,
+            address: customer.0.address,
+        },
+    ))
+}
+
+async fn find_customer_portal_link(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_portal_link_id: &str,
+) -> RouterResult<storage::CustomerPortalLink> {
+    let customer_portal_link = db
+        .find_customer_portal_link_by_link_id(customer_portal_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "customer portal link not found".to_string(),
+        })?;
+
+    when(&customer_portal_link.merchant_id != merchant_id, || {
+        Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "customer portal link not found".to_string(),
+        }))
+    })?;
+
+    Ok(customer_portal_link)
+}
+
+async fn validate_and_get_active_customer_portal_link(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_portal_link_id: &str,
+    client_secret: &Secret<String>,
+) -> RouterResult<storage::CustomerPortalLink> {
+    let customer_portal_link =
+        find_customer_portal_link(db, merchant_id, customer_portal_link_id).await?;
+
+    when(
+        customer_portal_link.link_data.client_secret.peek() != client_secret.peek(),
+        || Err(report!(errors::ApiErrorResponse::ClientSecretInvalid)),
+    )?;
+
+    let has_expired = common_utils::date_time::now() > customer_portal_link.expiry;
+    when(
+        has_expired || customer_portal_link.link_status == CustomerPortalStatus::Invalidated,
+        || Err(report!(errors::ApiErrorResponse::ClientSecretExpired)),
+    )?;
+
+    Ok(customer_portal_link)
+}
+
+fn get_customer_portal_payment_method(
+    payment_method: payment_methods_api::CustomerPaymentMethod,
+) -> customer_api::CustomerPortalPaymentMethod {
+    customer_api::CustomerPortalPaymentMethod {
+        payment_method_id: payment_method.payment_method_id,
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        card: payment_method
+            .card
+            .map(|card| customer_api::CustomerPortalCardDetails {
+                last4_digits: card.last4_digits,
+                card_network: card.card_network,
+                expiry_month: card.expiry_month,
+                expiry_year: card.expiry_year,
+                card_holder_name: card.card_holder_name,
+            }),
+        is_default: payment_method.default_payment_method_set,
+    }
+}
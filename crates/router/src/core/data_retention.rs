@@ -0,0 +1,212 @@
+use api_models::{admin::MerchantDataRetentionPolicy, data_retention as data_retention_api};
+use common_utils::id_type;
+use diesel_models::{
+    enums as storage_enums, payment_intent::PaymentDataClass, process_tracker::business_status,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::errors::StorageErrorExt;
+use crate::{
+    errors,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+const DATA_RETENTION_TASK: &str = "DATA_RETENTION";
+const DATA_RETENTION_TAG: &str = "DATA_RETENTION";
+
+/// The interval between consecutive purges of the data of a merchant past its retention period
+pub const DATA_RETENTION_PURGE_INTERVAL_IN_HOURS: i64 = 24;
+
+pub fn generate_task_id_for_data_retention_workflow(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_{DATA_RETENTION_TASK}_{}",
+        storage::ProcessTrackerRunner::DataRetentionWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Reports the data of the merchant which is past the retention period of its class, and purges
+/// it unless a dry run is requested
+#[instrument(skip(state))]
+pub async fn purge_merchant_data(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: data_retention_api::DataRetentionPurgeRequest,
+) -> errors::RouterResponse<data_retention_api::DataRetentionPurgeReport> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let data_retention_policy = merchant_account
+        .get_data_retention_policy()
+        .filter(|data_retention_policy| !data_retention_policy.is_empty())
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No data retention policy is configured for the merchant".to_string(),
+        })?;
+
+    purge_expired_data(
+        &state,
+        &merchant_account,
+        &data_retention_policy,
+        req.dry_run.unwrap_or(true),
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+/// Purges the data of each class, for which a retention period is configured in the policy, stored
+/// before the retention period of the class
+pub async fn purge_expired_data(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    data_retention_policy: &MerchantDataRetentionPolicy,
+    dry_run: bool,
+) -> errors::RouterResult<data_retention_api::DataRetentionPurgeReport> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let now = common_utils::date_time::now();
+
+    let retention_periods = [
+        (
+            data_retention_api::DataClass::Pii,
+            data_retention_policy.pii_retention_period_in_days,
+        ),
+        (
+            data_retention_api::DataClass::PaymentMetadata,
+            data_retention_policy.payment_metadata_retention_period_in_days,
+        ),
+        (
+            data_retention_api::DataClass::Logs,
+            data_retention_policy.logs_retention_period_in_days,
+        ),
+    ];
+
+    let mut data_classes = Vec::with_capacity(retention_periods.len());
+    for (data_class, retention_period_in_days) in retention_periods {
+        let Some(retention_period_in_days) = retention_period_in_days else {
+            continue;
+        };
+        let purge_before = now - time::Duration::days(i64::from(retention_period_in_days));
+
+        let records = match data_class {
+            data_retention_api::DataClass::Pii => {
+                db.purge_payment_data_created_before(
+                    merchant_id,
+                    PaymentDataClass::Pii,
+                    purge_before,
+                    dry_run,
+                )
+                .await
+            }
+            data_retention_api::DataClass::PaymentMetadata => {
+                db.purge_payment_data_created_before(
+                    merchant_id,
+                    PaymentDataClass::Metadata,
+                    purge_before,
+                    dry_run,
+                )
+                .await
+            }
+            data_retention_api::DataClass::Logs => {
+                db.purge_events_created_before(merchant_id, purge_before, dry_run)
+                    .await
+            }
+        }
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to purge data of class {data_class:?}"))?;
+
+        data_classes.push(data_retention_api::DataClassPurgeReport {
+            data_class,
+            retention_period_in_days,
+            purge_before,
+            records,
+        });
+    }
+
+    Ok(data_retention_api::DataRetentionPurgeReport {
+        merchant_id: merchant_id.to_owned(),
+        dry_run,
+        data_classes,
+    })
+}
+
+/// Schedules the daily job which purges the data of the merchant past its retention period. The
+/// job reads the data retention policy of the merchant on every run, so a job which is already
+/// scheduled is left untouched.
+pub async fn schedule_data_retention_purge(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> errors::RouterResult<()> {
+    let db = state.store.as_ref();
+    let process_tracker_id = generate_task_id_for_data_retention_workflow(merchant_id);
+    let schedule_time = common_utils::date_time::now();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve data retention task from process tracker")?;
+
+    match existing_process {
+        Some(process) if process.status != storage_enums::ProcessTrackerStatus::Finish => {}
+        // The job is finished once the data retention policy of the merchant is removed, and is
+        // rescheduled when a policy is configured again
+        Some(process) => {
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(schedule_time),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule data retention task in process tracker")?;
+        }
+        None => {
+            let tracking_data = storage::DataRetentionTrackingData {
+                merchant_id: merchant_id.to_owned(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                DATA_RETENTION_TASK,
+                storage::ProcessTrackerRunner::DataRetentionWorkflow,
+                [DATA_RETENTION_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct DATA_RETENTION process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting DATA_RETENTION task to process_tracker for merchant_id: {}",
+                        merchant_id.get_string_repr()
+                    )
+                })?;
+        }
+    };
+
+    Ok(())
+}
@@ -7,12 +7,16 @@ use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod representment;
 pub mod transformers;
 
 use super::{
     errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
     metrics,
 };
+#[cfg(feature = "v1")]
+use crate::core::ledger;
 use crate::{
     core::{files, payments, utils as core_utils},
     routes::SessionState,
@@ -90,6 +94,7 @@ pub async fn get_filters_for_disputes(
             state,
             merchant_account.get_id().to_owned(),
             profile_id_list,
+            None,
         )
         .await?
     {
@@ -235,6 +240,7 @@ pub async fn accept_dispute(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
+    ledger::record_dispute(&state, &updated_dispute).await;
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
@@ -260,7 +266,6 @@ pub async fn submit_evidence(
     key_store: domain::MerchantKeyStore,
     req: dispute_models::SubmitEvidenceRequest,
 ) -> RouterResponse<dispute_models::DisputeResponse> {
-    let db = &state.store;
     let dispute = state
         .store
         .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
@@ -269,7 +274,6 @@ pub async fn submit_evidence(
             dispute_id: req.dispute_id.clone(),
         })?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
-    let dispute_id = dispute.dispute_id.clone();
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
             && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
@@ -287,21 +291,35 @@ pub async fn submit_evidence(
             })
         },
     )?;
-    let submit_evidence_request_data = transformers::get_evidence_request_data(
-        &state,
-        &merchant_account,
-        &key_store,
-        req,
-        &dispute,
-    )
-    .await?;
+    let updated_dispute =
+        submit_evidence_to_connector(&state, &merchant_account, &key_store, req, &dispute).await?;
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+/// Submits the evidence to the connector, defending the dispute separately if the connector
+/// requires it, and updates the status of the dispute
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn submit_evidence_to_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: dispute_models::SubmitEvidenceRequest,
+    dispute: &diesel_models::dispute::Dispute,
+) -> errors::RouterResult<diesel_models::dispute::Dispute> {
+    let db = &state.store;
+    let dispute_id = dispute.dispute_id.clone();
+    let submit_evidence_request_data =
+        transformers::get_evidence_request_data(state, merchant_account, key_store, req, dispute)
+            .await?;
 
     let payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
-            &(&state).into(),
+            &state.into(),
             &dispute.payment_id,
             merchant_account.get_id(),
-            &key_store,
+            key_store,
             merchant_account.storage_scheme,
         )
         .await
@@ -328,17 +346,17 @@ pub async fn submit_evidence(
         SubmitEvidenceResponse,
     > = connector_data.connector.get_connector_integration();
     let router_data = core_utils::construct_submit_evidence_router_data(
-        &state,
+        state,
         &payment_intent,
         &payment_attempt,
-        &merchant_account,
-        &key_store,
-        &dispute,
+        merchant_account,
+        key_store,
+        dispute,
         submit_evidence_request_data,
     )
     .await?;
     let response = services::execute_connector_processing_step(
-        &state,
+        state,
         connector_integration,
         &router_data,
         payments::CallConnectorAction::Trigger,
@@ -368,16 +386,16 @@ pub async fn submit_evidence(
                 DefendDisputeResponse,
             > = connector_data.connector.get_connector_integration();
         let defend_dispute_router_data = core_utils::construct_defend_dispute_router_data(
-            &state,
+            state,
             &payment_intent,
             &payment_attempt,
-            &merchant_account,
-            &key_store,
-            &dispute,
+            merchant_account,
+            key_store,
+            dispute,
         )
         .await?;
         let defend_response = services::execute_connector_processing_step(
-            &state,
+            state,
             connector_integration_defend_dispute,
             &defend_dispute_router_data,
             payments::CallConnectorAction::Trigger,
@@ -418,8 +436,7 @@ pub async fn submit_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
-    Ok(services::ApplicationResponse::Json(dispute_response))
+    Ok(updated_dispute)
 }
 
 pub async fn attach_evidence(
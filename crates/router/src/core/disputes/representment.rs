@@ -0,0 +1,293 @@
+use api_models::{
+    disputes::{DeliveryDetails, EvidenceTemplatePlaceholder, SubmitEvidenceRequest},
+    payments::{AdditionalCardInfo, AdditionalPaymentData},
+};
+use common_utils::{ext_traits::ValueExt, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics,
+    },
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Value used in place of a placeholder whose payment detail is not available
+const UNAVAILABLE_PLACEHOLDER_VALUE: &str = "unavailable";
+
+/// Keys under which connectors report the result of the address verification in the payment checks
+const AVS_RESULT_KEYS: [&str; 3] = ["avs_response", "avs_result", "address_line1_check"];
+
+/// Keys under which connectors report the result of the card verification in the payment checks
+const CVV_RESULT_KEYS: [&str; 3] = ["card_verification", "cvv_result", "cvc_check"];
+
+/// Represents the dispute with evidence assembled from the payment if the profile has automatic
+/// representment configured and the disputed amount is within the configured limit.
+/// Returns the updated dispute if the evidence was submitted to the connector.
+#[instrument(skip_all)]
+pub async fn auto_represent_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    dispute: &diesel_models::dispute::Dispute,
+) -> RouterResult<Option<diesel_models::dispute::Dispute>> {
+    let Some(representment_config) = business_profile.dispute_representment_config.as_ref() else {
+        return Ok(None);
+    };
+
+    let is_dispute_open = dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened;
+    if !is_dispute_open
+        || MinorUnit::new(dispute.dispute_amount) > representment_config.max_dispute_amount
+    {
+        return Ok(None);
+    }
+
+    let db = &state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &dispute.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let evidence_context =
+        EvidenceTemplateContext::try_new(dispute, &payment_intent, &payment_attempt)?;
+    let evidence_templates = &representment_config.evidence_templates;
+    let render = |template: &Option<String>| {
+        template
+            .as_deref()
+            .map(|template| evidence_context.render(template))
+    };
+    let delivery_details = evidence_context.delivery_details.as_ref();
+    let evidence_request = SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        product_description: render(&evidence_templates.product_description),
+        refund_refusal_explanation: render(&evidence_templates.refund_refusal_explanation),
+        cancellation_rebuttal: render(&evidence_templates.cancellation_rebuttal),
+        uncategorized_text: render(&evidence_templates.uncategorized_text),
+        shipping_carrier: delivery_details.and_then(|details| details.shipping_carrier.clone()),
+        shipping_tracking_number: delivery_details
+            .and_then(|details| details.shipping_tracking_number.clone()),
+        shipping_date: delivery_details.and_then(|details| details.shipping_date.clone()),
+        ..Default::default()
+    };
+
+    let updated_dispute = super::submit_evidence_to_connector(
+        state,
+        merchant_account,
+        key_store,
+        evidence_request,
+        dispute,
+    )
+    .await?;
+    metrics::DISPUTE_AUTO_REPRESENTMENT_METRIC.add(&metrics::CONTEXT, 1, &[]);
+    logger::info!(
+        dispute_id = %dispute.dispute_id,
+        "Dispute represented automatically with templated evidence"
+    );
+
+    Ok(Some(updated_dispute))
+}
+
+/// Payment details from which the placeholders of the evidence templates are filled
+struct EvidenceTemplateContext<'a> {
+    dispute: &'a diesel_models::dispute::Dispute,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &'a storage::PaymentAttempt,
+    card_info: Option<AdditionalCardInfo>,
+    delivery_details: Option<DeliveryDetails>,
+}
+
+impl<'a> EvidenceTemplateContext<'a> {
+    fn try_new(
+        dispute: &'a diesel_models::dispute::Dispute,
+        payment_intent: &'a storage::PaymentIntent,
+        payment_attempt: &'a storage::PaymentAttempt,
+    ) -> RouterResult<Self> {
+        let additional_payment_data: Option<AdditionalPaymentData> = payment_attempt
+            .payment_method_data
+            .clone()
+            .and_then(|data| match data {
+                serde_json::Value::Null => None,
+                _ => Some(data.parse_value("AdditionalPaymentData")),
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to parse the AdditionalPaymentData from payment_attempt.payment_method_data",
+            )?;
+        let card_info = match additional_payment_data {
+            Some(AdditionalPaymentData::Card(card_info)) => Some(*card_info),
+            _ => None,
+        };
+
+        let delivery_details = payment_intent
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("delivery_details"))
+            .cloned()
+            .map(|details| details.parse_value::<DeliveryDetails>("DeliveryDetails"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "metadata.delivery_details",
+            })?;
+
+        Ok(Self {
+            dispute,
+            payment_intent,
+            payment_attempt,
+            card_info,
+            delivery_details,
+        })
+    }
+
+    fn get_value(&self, placeholder: EvidenceTemplatePlaceholder) -> Option<String> {
+        let payment_checks = self
+            .card_info
+            .as_ref()
+            .and_then(|card_info| card_info.payment_checks.as_ref());
+        let authentication_data = self
+            .card_info
+            .as_ref()
+            .and_then(|card_info| card_info.authentication_data.as_ref());
+
+        match placeholder {
+            EvidenceTemplatePlaceholder::PaymentId => {
+                Some(self.payment_intent.payment_id.get_string_repr().to_owned())
+            }
+            EvidenceTemplatePlaceholder::Amount => Some(self.dispute.amount.clone()),
+            EvidenceTemplatePlaceholder::Currency => Some(self.dispute.currency.clone()),
+            EvidenceTemplatePlaceholder::PaymentCreatedAt => {
+                Some(self.payment_intent.created_at.date().to_string())
+            }
+            EvidenceTemplatePlaceholder::CardNetwork => self
+                .card_info
+                .as_ref()
+                .and_then(|card_info| card_info.card_network.as_ref())
+                .map(ToString::to_string),
+            EvidenceTemplatePlaceholder::CardLast4 => self
+                .card_info
+                .as_ref()
+                .and_then(|card_info| card_info.last4.clone()),
+            EvidenceTemplatePlaceholder::AvsResult => {
+                get_check_result(payment_checks, &AVS_RESULT_KEYS)
+            }
+            EvidenceTemplatePlaceholder::CvvResult => {
+                get_check_result(payment_checks, &CVV_RESULT_KEYS)
+            }
+            EvidenceTemplatePlaceholder::ThreeDsAuthenticated => self
+                .payment_attempt
+                .authentication_type
+                .map(|authentication_type| match authentication_type {
+                    storage_enums::AuthenticationType::ThreeDs => "yes".to_string(),
+                    storage_enums::AuthenticationType::NoThreeDs => "no".to_string(),
+                }),
+            EvidenceTemplatePlaceholder::Eci => get_check_result(payment_checks, &["eci"])
+                .or_else(|| get_check_result(authentication_data, &["eci"])),
+            EvidenceTemplatePlaceholder::ShippingCarrier => self
+                .delivery_details
+                .as_ref()
+                .and_then(|details| details.shipping_carrier.clone()),
+            EvidenceTemplatePlaceholder::ShippingTrackingNumber => self
+                .delivery_details
+                .as_ref()
+                .and_then(|details| details.shipping_tracking_number.clone()),
+            EvidenceTemplatePlaceholder::ShippingDate => self
+                .delivery_details
+                .as_ref()
+                .and_then(|details| details.shipping_date.clone()),
+        }
+    }
+
+    fn render(&self, template: &str) -> String {
+        render_evidence_template(template, |placeholder| self.get_value(placeholder))
+    }
+}
+
+fn render_evidence_template(
+    template: &str,
+    get_value: impl Fn(EvidenceTemplatePlaceholder) -> Option<String>,
+) -> String {
+    EvidenceTemplatePlaceholder::iter().fold(template.to_owned(), |rendered, placeholder| {
+        let template_key = placeholder.get_template_key();
+        if rendered.contains(&template_key) {
+            let value =
+                get_value(placeholder).unwrap_or_else(|| UNAVAILABLE_PLACEHOLDER_VALUE.to_string());
+            rendered.replace(&template_key, &value)
+        } else {
+            rendered
+        }
+    })
+}
+
+/// Reads the result of a check from the free form payment checks reported by the connector
+fn get_check_result(checks: Option<&serde_json::Value>, keys: &[&str]) -> Option<String> {
+    let checks = checks?;
+    keys.iter()
+        .find_map(|key| checks.get(key).filter(|result| !result.is_null()))
+        .map(|result| match result {
+            serde_json::Value::String(result) => result.clone(),
+            result => result.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_render_evidence_template() {
+        let rendered = render_evidence_template(
+            "Payment {{payment_id}} passed CVV check: {{cvv_result}}, AVS check: {{avs_result}}",
+            |placeholder| match placeholder {
+                EvidenceTemplatePlaceholder::PaymentId => Some("pay_123".to_string()),
+                EvidenceTemplatePlaceholder::CvvResult => Some("pass".to_string()),
+                _ => None,
+            },
+        );
+        assert_eq!(
+            rendered,
+            "Payment pay_123 passed CVV check: pass, AVS check: unavailable"
+        );
+    }
+
+    #[test]
+    fn test_get_check_result() {
+        let checks = serde_json::json!({
+            "avs_response": { "code": "Y" },
+            "card_verification": null,
+            "cvc_check": "pass",
+        });
+        assert_eq!(
+            get_check_result(Some(&checks), &CVV_RESULT_KEYS).unwrap(),
+            "pass"
+        );
+        assert_eq!(
+            get_check_result(Some(&checks), &AVS_RESULT_KEYS).unwrap(),
+            r#"{"code":"Y"}"#
+        );
+        assert_eq!(get_check_result(None, &AVS_RESULT_KEYS), None);
+    }
+}
@@ -0,0 +1,908 @@
+use std::collections::HashMap;
+
+use api_models::email_templates as email_template_api;
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    fp_utils::when,
+    id_type, pii,
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForCore},
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use tera::{Context, Tera};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+const TEMPLATE_VARIABLE_START: &str = "{{";
+const TEMPLATE_VARIABLE_END: &str = "}}";
+
+/// Variables which can be used in the templates of all email types
+const COMMON_TEMPLATE_VARIABLES: &[&str] = &[
+    "merchant_name",
+    "customer_name",
+    "logo_url",
+    "primary_color",
+];
+
+/// Returns the variables which can be substituted in the subject and body of a template of the
+/// given email type
+pub fn get_available_variables(email_type: enums::EmailTemplateType) -> Vec<&'static str> {
+    let email_type_variables: &[&str] = match email_type {
+        enums::EmailTemplateType::PaymentReceipt => {
+            &["payment_id", "amount", "currency", "description"]
+        }
+        enums::EmailTemplateType::RefundNotification => {
+            &["refund_id", "payment_id", "amount", "currency", "reason"]
+        }
+        enums::EmailTemplateType::PayoutLink => &[
+            "payout_id",
+            "amount",
+            "currency",
+            "payout_link",
+            "link_expiry",
+        ],
+        enums::EmailTemplateType::PaymentLinkRecovery => &[
+            "payment_id",
+            "amount",
+            "currency",
+            "description",
+            "payment_link",
+            "link_expiry",
+        ],
+    };
+    COMMON_TEMPLATE_VARIABLES
+        .iter()
+        .chain(email_type_variables)
+        .copied()
+        .collect()
+}
+
+fn get_default_subject(email_type: enums::EmailTemplateType) -> &'static str {
+    match email_type {
+        enums::EmailTemplateType::PaymentReceipt => consts::EMAIL_SUBJECT_PAYMENT_RECEIPT,
+        enums::EmailTemplateType::RefundNotification => consts::EMAIL_SUBJECT_REFUND_NOTIFICATION,
+        enums::EmailTemplateType::PayoutLink => consts::EMAIL_SUBJECT_PAYOUT_LINK,
+        enums::EmailTemplateType::PaymentLinkRecovery => {
+            consts::EMAIL_SUBJECT_PAYMENT_LINK_RECOVERY
+        }
+    }
+}
+
+/// Returns the names of all the `{{variable_name}}` placeholders in the template
+fn get_template_variables(template: &str) -> Vec<&str> {
+    let mut variables = Vec::new();
+    let mut remaining = template;
+    while let Some((_, after_start)) = remaining.split_once(TEMPLATE_VARIABLE_START) {
+        let Some((variable, after_end)) = after_start.split_once(TEMPLATE_VARIABLE_END) else {
+            break;
+        };
+        variables.push(variable.trim());
+        remaining = after_end;
+    }
+    variables
+}
+
+/// Replaces the `{{variable_name}}` placeholders in the template with the values of the
+/// variables, HTML escaping the values if required. Placeholders of variables which do not have
+/// a value are removed.
+fn substitute_template_variables(
+    template: &str,
+    variables: &HashMap<&str, String>,
+    escape_html: bool,
+) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut remaining = template;
+    while let Some((before_start, after_start)) = remaining.split_once(TEMPLATE_VARIABLE_START) {
+        let Some((variable, after_end)) = after_start.split_once(TEMPLATE_VARIABLE_END) else {
+            break;
+        };
+        rendered.push_str(before_start);
+        if let Some(value) = variables.get(variable.trim()) {
+            if escape_html {
+                rendered.push_str(&tera::escape_html(value));
+            } else {
+                rendered.push_str(value);
+            }
+        }
+        remaining = after_end;
+    }
+    rendered.push_str(remaining);
+    rendered
+}
+
+fn render_system_template(
+    email_type: enums::EmailTemplateType,
+    variables: &HashMap<&str, String>,
+) -> RouterResult<String> {
+    let (template_name, template) = match email_type {
+        enums::EmailTemplateType::PaymentReceipt => (
+            "payment_receipt.html",
+            include_str!("email_templates/payment_receipt.html"),
+        ),
+        enums::EmailTemplateType::RefundNotification => (
+            "refund_notification.html",
+            include_str!("email_templates/refund_notification.html"),
+        ),
+        enums::EmailTemplateType::PayoutLink => (
+            "payout_link.html",
+            include_str!("email_templates/payout_link.html"),
+        ),
+        enums::EmailTemplateType::PaymentLinkRecovery => (
+            "payment_link_recovery.html",
+            include_str!("email_templates/payment_link_recovery.html"),
+        ),
+    };
+
+    let mut tera = Tera::default();
+    // The template names have an `.html` extension so that the variables are auto-escaped
+    tera.add_raw_template(template_name, template)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add system email template")?;
+    let mut context = Context::new();
+    for (variable, value) in variables {
+        context.insert(*variable, value);
+    }
+
+    tera.render(template_name, &context)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to render system email template")
+}
+
+fn validate_template_variables(
+    email_type: enums::EmailTemplateType,
+    field_name: &str,
+    template: &str,
+) -> RouterResult<()> {
+    let available_variables = get_available_variables(email_type);
+    match get_template_variables(template)
+        .into_iter()
+        .find(|variable| !available_variables.contains(variable))
+    {
+        Some(unknown_variable) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{field_name} contains unknown variable `{unknown_variable}`, the variables available for {email_type} emails are {}",
+                available_variables.join(", ")
+            ),
+        })),
+        None => Ok(()),
+    }
+}
+
+fn validate_subject(email_type: enums::EmailTemplateType, subject: &str) -> RouterResult<()> {
+    when(subject.trim().is_empty() || subject.len() > 255, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "subject must be between 1 and 255 characters".to_string(),
+        }))
+    })?;
+    validate_template_variables(email_type, "subject", subject)
+}
+
+fn validate_logo_url(logo_url: &str) -> RouterResult<()> {
+    let is_valid = url::Url::parse(logo_url)
+        .map(|url| matches!(url.scheme(), "http" | "https"))
+        .unwrap_or(false);
+    when(!is_valid || logo_url.len() > 2048, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "logo_url must be a valid http or https URL".to_string(),
+        }))
+    })
+}
+
+fn validate_primary_color(primary_color: &str) -> RouterResult<()> {
+    let is_valid = primary_color.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "primary_color must be a hex color code such as #4285F4".to_string(),
+        }))
+    })
+}
+
+fn validate_locale(locale: &str) -> RouterResult<()> {
+    let is_valid = !locale.is_empty()
+        && locale.len() <= 32
+        && locale
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "locale must be a valid language tag such as en or fr-CA".to_string(),
+        }))
+    })
+}
+
+async fn validate_and_get_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_account.get_id(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn create_email_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    req: email_template_api::EmailTemplateCreateRequest,
+) -> RouterResponse<email_template_api::EmailTemplateResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let locale = req.locale.unwrap_or(DEFAULT_LOCALE.to_string());
+    validate_locale(&locale)?;
+    let subject = req
+        .subject
+        .unwrap_or(get_default_subject(req.email_type).to_string());
+    validate_subject(req.email_type, &subject)?;
+    if let Some(body) = &req.body {
+        validate_template_variables(req.email_type, "body", body)?;
+    }
+    if let Some(logo_url) = &req.logo_url {
+        validate_logo_url(logo_url)?;
+    }
+    if let Some(primary_color) = &req.primary_color {
+        validate_primary_color(primary_color)?;
+    }
+
+    let now = common_utils::date_time::now();
+    let email_template_new = storage::EmailTemplateNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "email_template"),
+        merchant_id: merchant_account.get_id().clone(),
+        profile_id,
+        email_type: req.email_type,
+        locale,
+        subject,
+        body: req.body,
+        logo_url: req.logo_url,
+        primary_color: req.primary_color,
+        is_active: req.is_active.unwrap_or(true),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let email_template = state
+        .store
+        .insert_email_template(email_template_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "email template already exists for the email_type and locale".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        email_template_api::EmailTemplateResponse::foreign_from(email_template),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_email_templates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<email_template_api::EmailTemplateResponse>> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let email_templates = state
+        .store
+        .list_email_templates_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list email templates")?;
+
+    Ok(services::ApplicationResponse::Json(
+        email_templates
+            .into_iter()
+            .map(email_template_api::EmailTemplateResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_email_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    template_id: String,
+) -> RouterResponse<email_template_api::EmailTemplateResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let email_template = state
+        .store
+        .find_email_template_by_profile_id_template_id(&profile_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "email template not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        email_template_api::EmailTemplateResponse::foreign_from(email_template),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_email_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    template_id: String,
+    req: email_template_api::EmailTemplateUpdateRequest,
+) -> RouterResponse<email_template_api::EmailTemplateResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let db = &*state.store;
+    let email_template = db
+        .find_email_template_by_profile_id_template_id(&profile_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "email template not found".to_string(),
+        })?;
+
+    if let Some(subject) = &req.subject {
+        validate_subject(email_template.email_type, subject)?;
+    }
+    if let Some(body) = &req.body {
+        validate_template_variables(email_template.email_type, "body", body)?;
+    }
+    if let Some(logo_url) = &req.logo_url {
+        validate_logo_url(logo_url)?;
+    }
+    if let Some(primary_color) = &req.primary_color {
+        validate_primary_color(primary_color)?;
+    }
+
+    let email_template = db
+        .update_email_template_by_profile_id_template_id(
+            &profile_id,
+            &template_id,
+            storage::EmailTemplateUpdate::Update {
+                subject: req.subject,
+                body: req.body,
+                logo_url: req.logo_url,
+                primary_color: req.primary_color,
+                is_active: req.is_active,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update email template")?;
+
+    Ok(services::ApplicationResponse::Json(
+        email_template_api::EmailTemplateResponse::foreign_from(email_template),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_email_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    template_id: String,
+) -> RouterResponse<email_template_api::EmailTemplateDeleteResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let email_template = state
+        .store
+        .delete_email_template_by_profile_id_template_id(&profile_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "email template not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        email_template_api::EmailTemplateDeleteResponse {
+            template_id: email_template.id,
+            deleted: true,
+        },
+    ))
+}
+
+/// Picks the template of the requested locale, falling back to the template of the default
+/// locale
+fn select_template(
+    email_templates: Vec<storage::EmailTemplate>,
+    locale: Option<&str>,
+) -> Option<storage::EmailTemplate> {
+    let requested_locale = locale.unwrap_or(DEFAULT_LOCALE);
+    let (mut requested_locale_templates, default_locale_templates): (Vec<_>, Vec<_>) =
+        email_templates
+            .into_iter()
+            .filter(|email_template| {
+                email_template.locale.eq_ignore_ascii_case(requested_locale)
+                    || email_template.locale.eq_ignore_ascii_case(DEFAULT_LOCALE)
+            })
+            .partition(|email_template| {
+                email_template.locale.eq_ignore_ascii_case(requested_locale)
+            });
+    requested_locale_templates
+        .pop()
+        .or(default_locale_templates.into_iter().next())
+}
+
+/// An email to be sent to a customer using the template configured in the business profile
+struct EmailNotification<'a> {
+    email_type: enums::EmailTemplateType,
+    recipient_email: pii::Email,
+    locale: Option<&'a str>,
+    variables: HashMap<&'static str, String>,
+}
+
+/// Sends the email using the active template of the business profile for the email type.
+/// Emails are only sent when the business profile has configured a template for the email type.
+async fn send_email_notification(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    notification: EmailNotification<'_>,
+) -> RouterResult<()> {
+    let email_templates = state
+        .store
+        .list_active_email_templates_by_profile_id_email_type(profile_id, notification.email_type)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch email templates of the business profile")?;
+    let Some(email_template) = select_template(email_templates, notification.locale) else {
+        logger::debug!(
+            email_type = %notification.email_type,
+            "Email not sent as the business profile does not have an active template"
+        );
+        return Ok(());
+    };
+
+    let mut variables = notification.variables;
+    if let Some(logo_url) = email_template.logo_url {
+        variables.insert("logo_url", logo_url);
+    }
+    if let Some(primary_color) = email_template.primary_color {
+        variables.insert("primary_color", primary_color);
+    }
+
+    let subject = substitute_template_variables(&email_template.subject, &variables, false);
+    let body = match email_template.body {
+        Some(body) => substitute_template_variables(&body, &variables, true),
+        None => render_system_template(notification.email_type, &variables)?,
+    };
+
+    send_email(state, notification.recipient_email, subject, body).await
+}
+
+#[cfg(feature = "email")]
+async fn send_email(
+    state: &SessionState,
+    recipient_email: pii::Email,
+    subject: String,
+    body: String,
+) -> RouterResult<()> {
+    let email_contents = services::email::types::ProfileNotificationEmail {
+        recipient_email,
+        subject,
+        body,
+    };
+
+    state
+        .email_client
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send notification email")?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_email(
+    _state: &SessionState,
+    _recipient_email: pii::Email,
+    _subject: String,
+    _body: String,
+) -> RouterResult<()> {
+    logger::warn!("Notification email not sent as the email feature is not enabled");
+    Ok(())
+}
+
+/// Returns `true` if the notification was not already sent for the object, so that retried
+/// webhooks do not notify the customer more than once
+async fn is_first_notification(
+    state: &SessionState,
+    email_type: enums::EmailTemplateType,
+    merchant_id: &id_type::MerchantId,
+    object_id: &str,
+) -> RouterResult<bool> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_if_not_exists_with_expiry(
+            &format!(
+                "{}{email_type}_{}_{object_id}",
+                consts::EMAIL_NOTIFICATION_SENT_REDIS_KEY_PREFIX,
+                merchant_id.get_string_repr(),
+            ),
+            "",
+            Some(consts::EMAIL_NOTIFICATION_SENT_REDIS_KEY_EXPIRY_SECONDS),
+        )
+        .await
+        .map(|reply| matches!(reply, redis_interface::SetnxReply::KeySet))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check if the notification email was already sent")
+}
+
+fn get_common_variables(
+    merchant_account: &domain::MerchantAccount,
+    customer: Option<&domain::Customer>,
+) -> HashMap<&'static str, String> {
+    let mut variables = HashMap::from([(
+        "merchant_name",
+        merchant_account
+            .merchant_name
+            .clone()
+            .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+            .unwrap_or(merchant_account.get_id().get_string_repr().to_owned()),
+    )]);
+    if let Some(customer_name) = customer.and_then(|customer| customer.name.clone()) {
+        variables.insert("customer_name", customer_name.into_inner().expose());
+    }
+    variables
+}
+
+fn get_customer_email(customer: Option<&domain::Customer>) -> Option<pii::Email> {
+    customer.and_then(|customer| customer.email.clone().map(pii::Email::from))
+}
+
+fn to_major_unit(amount: MinorUnit, currency: enums::Currency) -> RouterResult<String> {
+    StringMajorUnitForCore
+        .convert(amount, currency)
+        .map(|amount| amount.get_amount_as_string())
+        .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+            amount_type: "StringMajorUnit",
+        })
+}
+
+/// Sends a receipt to the customer once the payment succeeds
+pub async fn send_payment_receipt(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_intent: hyperswitch_domain_models::payments::PaymentIntent,
+    customer: Option<domain::Customer>,
+) -> RouterResult<()> {
+    let email_type = enums::EmailTemplateType::PaymentReceipt;
+    if payment_intent.status != enums::IntentStatus::Succeeded {
+        return Ok(());
+    }
+    let (Some(profile_id), Some(recipient_email)) = (
+        payment_intent.profile_id.as_ref(),
+        get_customer_email(customer.as_ref()),
+    ) else {
+        return Ok(());
+    };
+    if !is_first_notification(
+        &state,
+        email_type,
+        merchant_account.get_id(),
+        payment_intent.payment_id.get_string_repr(),
+    )
+    .await?
+    {
+        return Ok(());
+    }
+
+    let currency = payment_intent.currency.get_required_value("currency")?;
+    let mut variables = get_common_variables(&merchant_account, customer.as_ref());
+    variables.insert(
+        "payment_id",
+        payment_intent.payment_id.get_string_repr().to_owned(),
+    );
+    variables.insert(
+        "amount",
+        to_major_unit(
+            payment_intent
+                .amount_captured
+                .unwrap_or(payment_intent.amount),
+            currency,
+        )?,
+    );
+    variables.insert("currency", currency.to_string());
+    if let Some(description) = payment_intent.description {
+        variables.insert("description", description);
+    }
+
+    send_email_notification(
+        &state,
+        profile_id,
+        EmailNotification {
+            email_type,
+            recipient_email,
+            locale: None,
+            variables,
+        },
+    )
+    .await
+}
+
+/// Notifies the customer once the refund succeeds
+pub async fn send_refund_notification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    refund: diesel_models::Refund,
+) -> RouterResult<()> {
+    let email_type = enums::EmailTemplateType::RefundNotification;
+    if refund.refund_status != enums::RefundStatus::Success {
+        return Ok(());
+    }
+    let Some(profile_id) = refund.profile_id.as_ref() else {
+        return Ok(());
+    };
+
+    let key_manager_state = &(&state).into();
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &refund.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let customer = match &payment_intent.customer_id {
+        Some(customer_id) => state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                key_manager_state,
+                customer_id,
+                merchant_account.get_id(),
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer of the payment")?,
+        None => None,
+    };
+    let Some(recipient_email) = get_customer_email(customer.as_ref()) else {
+        return Ok(());
+    };
+    if !is_first_notification(
+        &state,
+        email_type,
+        merchant_account.get_id(),
+        &refund.refund_id,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+
+    let mut variables = get_common_variables(&merchant_account, customer.as_ref());
+    variables.insert("refund_id", refund.refund_id.clone());
+    variables.insert("payment_id", refund.payment_id.get_string_repr().to_owned());
+    variables.insert(
+        "amount",
+        to_major_unit(refund.refund_amount, refund.currency)?,
+    );
+    variables.insert("currency", refund.currency.to_string());
+    if let Some(reason) = refund.refund_reason {
+        variables.insert("reason", reason);
+    }
+
+    send_email_notification(
+        &state,
+        profile_id,
+        EmailNotification {
+            email_type,
+            recipient_email,
+            locale: None,
+            variables,
+        },
+    )
+    .await
+}
+
+/// Delivers the payout link to the customer once it is created
+#[cfg(feature = "payouts")]
+pub async fn send_payout_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: id_type::ProfileId,
+    payout_link: storage::PayoutLink,
+    customer: Option<domain::Customer>,
+    locale: String,
+) -> RouterResult<()> {
+    let Some(recipient_email) = get_customer_email(customer.as_ref()) else {
+        return Ok(());
+    };
+
+    let link_data = payout_link.link_data;
+    let mut variables = get_common_variables(&merchant_account, customer.as_ref());
+    variables.insert("payout_id", link_data.payout_id);
+    variables.insert(
+        "amount",
+        to_major_unit(link_data.amount, link_data.currency)?,
+    );
+    variables.insert("currency", link_data.currency.to_string());
+    variables.insert("payout_link", payout_link.url.peek().to_owned());
+    variables.insert("link_expiry", payout_link.expiry.to_string());
+
+    send_email_notification(
+        &state,
+        &profile_id,
+        EmailNotification {
+            email_type: enums::EmailTemplateType::PayoutLink,
+            recipient_email,
+            locale: Some(&locale),
+            variables,
+        },
+    )
+    .await
+}
+
+/// Sends the customer the regenerated payment link of a payment which was abandoned
+#[cfg(feature = "v1")]
+pub async fn send_payment_link_recovery(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_link: storage::PaymentLink,
+    customer: Option<domain::Customer>,
+) -> RouterResult<()> {
+    let email_type = enums::EmailTemplateType::PaymentLinkRecovery;
+    let (Some(profile_id), Some(recipient_email)) = (
+        payment_link.profile_id.as_ref(),
+        get_customer_email(customer.as_ref()),
+    ) else {
+        return Ok(());
+    };
+    if !is_first_notification(
+        &state,
+        email_type,
+        merchant_account.get_id(),
+        &payment_link.payment_link_id,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+
+    let currency = payment_link.currency.get_required_value("currency")?;
+    let mut variables = get_common_variables(&merchant_account, customer.as_ref());
+    variables.insert(
+        "payment_id",
+        payment_link.payment_id.get_string_repr().to_owned(),
+    );
+    variables.insert("amount", to_major_unit(payment_link.amount, currency)?);
+    variables.insert("currency", currency.to_string());
+    if let Some(description) = payment_link.description {
+        variables.insert("description", description);
+    }
+    variables.insert("payment_link", payment_link.link_to_pay);
+    if let Some(fulfilment_time) = payment_link.fulfilment_time {
+        variables.insert("link_expiry", fulfilment_time.to_string());
+    }
+
+    send_email_notification(
+        &state,
+        profile_id,
+        EmailNotification {
+            email_type,
+            recipient_email,
+            locale: None,
+            variables,
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_template_variables() {
+        assert_eq!(
+            get_template_variables("Hi {{ customer_name }}, you paid {{amount}} {{currency"),
+            vec!["customer_name", "amount"]
+        );
+        assert!(get_template_variables("No variables").is_empty());
+    }
+
+    #[test]
+    fn test_substitute_template_variables() {
+        let variables = HashMap::from([
+            ("merchant_name", "Tom & Jerry".to_string()),
+            ("amount", "10.00".to_string()),
+        ]);
+
+        assert_eq!(
+            substitute_template_variables(
+                "<p>{{merchant_name}} received {{ amount }}{{ customer_name }}</p>",
+                &variables,
+                true
+            ),
+            "<p>Tom &amp; Jerry received 10.00</p>"
+        );
+        assert_eq!(
+            substitute_template_variables("Receipt from {{merchant_name}}", &variables, false),
+            "Receipt from Tom & Jerry"
+        );
+    }
+
+    #[test]
+    fn test_render_system_template_uses_defaults_for_missing_variables() {
+        let variables = HashMap::from([
+            ("merchant_name", "Shop".to_string()),
+            ("payment_id", "pay_123".to_string()),
+            ("amount", "10.00".to_string()),
+            ("currency", "USD".to_string()),
+        ]);
+        let body =
+            render_system_template(enums::EmailTemplateType::PaymentReceipt, &variables).unwrap();
+
+        assert!(body.contains("Hi there,"));
+        assert!(body.contains("pay_123"));
+        assert!(body.contains("#006df9"));
+        assert!(!body.contains("<img"));
+    }
+
+    #[test]
+    fn test_select_template_falls_back_to_default_locale() {
+        let now = common_utils::date_time::now();
+        let email_template = |locale: &str| storage::EmailTemplate {
+            id: format!("email_template_{locale}"),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test")).unwrap(),
+            email_type: enums::EmailTemplateType::PaymentReceipt,
+            locale: locale.to_string(),
+            subject: "Receipt".to_string(),
+            body: None,
+            logo_url: None,
+            primary_color: None,
+            is_active: true,
+            created_at: now,
+            modified_at: now,
+        };
+        let email_templates = vec![email_template("en"), email_template("fr")];
+
+        assert_eq!(
+            select_template(email_templates.clone(), Some("fr"))
+                .unwrap()
+                .locale,
+            "fr"
+        );
+        assert_eq!(
+            select_template(email_templates.clone(), Some("de"))
+                .unwrap()
+                .locale,
+            "en"
+        );
+        assert!(select_template(vec![email_template("fr")], None).is_none());
+    }
+}
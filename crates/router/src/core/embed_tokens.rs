@@ -0,0 +1,77 @@
+use api_models::embed_tokens as embed_token_api;
+use common_utils::{fp_utils::when, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services::{self, authentication as auth},
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn create_embed_token(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    req: embed_token_api::EmbedTokenCreateRequest,
+) -> RouterResponse<embed_token_api::EmbedTokenResponse> {
+    when(req.scopes.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one scope is required".to_string(),
+        })
+    })?;
+    let expires_in = req
+        .expires_in
+        .unwrap_or(consts::EMBED_TOKEN_DEFAULT_EXPIRY_IN_SECS);
+    when(
+        expires_in == 0 || expires_in > consts::EMBED_TOKEN_MAX_EXPIRY_IN_SECS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "expires_in must be between 1 and {} seconds",
+                    consts::EMBED_TOKEN_MAX_EXPIRY_IN_SECS
+                ),
+            })
+        },
+    )?;
+
+    // The token is only minted for the profiles of the merchant
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &(&state).into(),
+            &key_store,
+            merchant_account.get_id(),
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let scopes = req.scopes;
+    let token = auth::EmbedToken::new_token(
+        merchant_account.get_id().clone(),
+        profile_id.clone(),
+        scopes.clone(),
+        std::time::Duration::from_secs(expires_in.into()),
+        &state.conf,
+        Some(state.tenant.tenant_id.clone()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create embed token")?;
+
+    Ok(services::ApplicationResponse::Json(
+        embed_token_api::EmbedTokenResponse {
+            token: masking::Secret::new(token),
+            profile_id,
+            scopes,
+            expires_at: common_utils::date_time::now() + time::Duration::seconds(expires_in.into()),
+        },
+    ))
+}
@@ -156,6 +156,8 @@ pub enum VaultError {
     VaultAPIError,
     #[error("Failed while calling locker API")]
     ApiError,
+    #[error("The locker could not be reached or failed with a server error")]
+    LockerUnavailable,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -308,6 +310,8 @@ pub enum RoutingError {
     DslFinalConnectorSelectionFailed,
     #[error("[DSL] Received incorrect selection algorithm as DSL output")]
     DslIncorrectSelectionAlgorithm,
+    #[error("[DSL] Failed to fetch the tagged connector accounts of the profile")]
+    DslConnectorTagsFetchFailed,
     #[error("there was an error saving/retrieving values from the kgraph cache")]
     KgraphCacheFailure,
     #[error("failed to refresh the kgraph cache")]
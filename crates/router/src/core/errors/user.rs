@@ -106,6 +106,24 @@ pub enum UserErrors {
     ThemeAlreadyExists,
     #[error("Invalid field: {0} in lineage")]
     InvalidThemeLineage(String),
+    #[error("SCIM configuration not found")]
+    ScimConfigNotFound,
+    #[error("SCIM resource not found")]
+    ScimResourceNotFound,
+    #[error("Invalid SCIM request: {0}")]
+    InvalidScimRequest(String),
+    #[error("SSO is not configured for the email domain")]
+    SSONotConfiguredForEmailDomain,
+    #[error("WebAuthn not setup")]
+    WebauthnNotSetup,
+    #[error("WebAuthn challenge not found")]
+    WebauthnChallengeNotFound,
+    #[error("Invalid WebAuthn credential")]
+    InvalidWebauthnCredential,
+    #[error("Maximum attempts reached for WebAuthn")]
+    MaxWebauthnAttemptsReached,
+    #[error("WebAuthn credential not found")]
+    WebauthnCredentialNotFound,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -275,6 +293,33 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::InvalidThemeLineage(_) => {
                 AER::BadRequest(ApiError::new(sub_code, 55, self.get_error_message(), None))
             }
+            Self::ScimConfigNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 56, self.get_error_message(), None))
+            }
+            Self::ScimResourceNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 57, self.get_error_message(), None))
+            }
+            Self::InvalidScimRequest(_) => {
+                AER::BadRequest(ApiError::new(sub_code, 58, self.get_error_message(), None))
+            }
+            Self::SSONotConfiguredForEmailDomain => {
+                AER::NotFound(ApiError::new(sub_code, 59, self.get_error_message(), None))
+            }
+            Self::WebauthnNotSetup => {
+                AER::BadRequest(ApiError::new(sub_code, 60, self.get_error_message(), None))
+            }
+            Self::WebauthnChallengeNotFound => {
+                AER::BadRequest(ApiError::new(sub_code, 61, self.get_error_message(), None))
+            }
+            Self::InvalidWebauthnCredential => {
+                AER::BadRequest(ApiError::new(sub_code, 62, self.get_error_message(), None))
+            }
+            Self::MaxWebauthnAttemptsReached => {
+                AER::BadRequest(ApiError::new(sub_code, 63, self.get_error_message(), None))
+            }
+            Self::WebauthnCredentialNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 64, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -341,6 +386,19 @@ impl UserErrors {
             Self::InvalidThemeLineage(field_name) => {
                 format!("Invalid field: {} in lineage", field_name)
             }
+            Self::ScimConfigNotFound => "SCIM configuration not found".to_string(),
+            Self::ScimResourceNotFound => "SCIM resource not found".to_string(),
+            Self::InvalidScimRequest(error_message) => {
+                format!("Invalid SCIM request: {error_message}")
+            }
+            Self::SSONotConfiguredForEmailDomain => {
+                "SSO is not configured for the email domain".to_string()
+            }
+            Self::WebauthnNotSetup => "WebAuthn not setup".to_string(),
+            Self::WebauthnChallengeNotFound => "WebAuthn challenge not found".to_string(),
+            Self::InvalidWebauthnCredential => "Invalid WebAuthn credential".to_string(),
+            Self::MaxWebauthnAttemptsReached => "Maximum attempts reached for WebAuthn".to_string(),
+            Self::WebauthnCredentialNotFound => "WebAuthn credential not found".to_string(),
         }
     }
 }
@@ -131,3 +131,49 @@ pub async fn files_retrieve_core(
         content_type,
     )))
 }
+
+pub async fn files_download_url_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: api::FileId,
+) -> RouterResponse<files::FileDownloadUrlResponse> {
+    let file_metadata_object = state
+        .store
+        .as_ref()
+        .find_file_metadata_by_merchant_id_file_id(merchant_account.get_id(), &req.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Unable to retrieve file_metadata")?;
+    let response = helpers::get_file_download_url(&state, &file_metadata_object).await?;
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn files_download_core(
+    state: SessionState,
+    token: String,
+) -> RouterResponse<serde_json::Value> {
+    let token_data = helpers::get_file_download_token_data(&state, &token).await?;
+    let file_metadata_object = state
+        .store
+        .as_ref()
+        .find_file_metadata_by_merchant_id_file_id(&token_data.merchant_id, &token_data.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Unable to retrieve file_metadata")?;
+    let provider_file_id = file_metadata_object
+        .provider_file_id
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Missing provider_file_id in file_metadata")?;
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve file")?;
+    let content_type = file_metadata_object
+        .file_type
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse file content type")?;
+    Ok(ApplicationResponse::FileData((file_data, content_type)))
+}
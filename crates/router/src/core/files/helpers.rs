@@ -1,12 +1,14 @@
 use actix_multipart::Field;
-use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use api_models::files as files_api;
+use common_utils::{errors::CustomResult, fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
 use futures::TryStreamExt;
 use hyperswitch_domain_models::router_response_types::disputes::FileInfo;
 
 use crate::{
+    consts,
     core::{
-        errors::{self, StorageErrorExt},
+        errors::{self, utils::RedisErrorExt, StorageErrorExt},
         payments, utils,
     },
     routes::SessionState,
@@ -14,6 +16,17 @@ use crate::{
     types::{self, api, domain, transformers::ForeignTryFrom},
 };
 
+/// The file referenced by a file download token
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct FileDownloadTokenData {
+    pub merchant_id: id_type::MerchantId,
+    pub file_id: String,
+}
+
+fn get_file_download_token_key(token: &str) -> String {
+    format!("{}{token}", consts::FILE_DOWNLOAD_TOKEN_REDIS_KEY_PREFIX)
+}
+
 pub async fn read_string(field: &mut Field) -> Option<String> {
     let bytes = field.try_next().await;
     if let Ok(Some(bytes)) = bytes {
@@ -27,6 +40,7 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("mandate_evidence") => Some(api::FilePurpose::MandateEvidence),
         _ => None,
     }
 }
@@ -36,6 +50,32 @@ pub async fn validate_file_upload(
     merchant_account: domain::MerchantAccount,
     create_file_request: api::CreateFileRequest,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
+    let files_config = &state.conf.files;
+    when(
+        create_file_request.file_size > files_config.max_file_size_in_bytes,
+        || {
+            Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file size must not exceed {} bytes",
+                    files_config.max_file_size_in_bytes
+                ),
+            }))
+        },
+    )?;
+    when(
+        !files_config
+            .allowed_file_types
+            .contains(create_file_request.file_type.essence_str()),
+        || {
+            Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file type `{}` is not supported",
+                    create_file_request.file_type.essence_str()
+                ),
+            }))
+        },
+    )?;
+
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
@@ -78,6 +118,15 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        // The signed mandate is stored by the router, to be retrieved for mandate audits
+        api::FilePurpose::MandateEvidence => when(
+            create_file_request.file_type.essence_str() != mime::APPLICATION_PDF.essence_str(),
+            || {
+                Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "signed mandate must be a PDF document".to_string(),
+                }))
+            },
+        ),
     }
 }
 
@@ -371,5 +420,105 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::MandateEvidence => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
+
+/// Issues a short-lived URL for downloading a file stored by the router. A presigned URL is issued
+/// if the file storage supports it, otherwise the URL contains a token which is exchanged for the
+/// file through the files API.
+pub async fn get_file_download_url(
+    state: &SessionState,
+    file_metadata: &diesel_models::file::FileMetadata,
+) -> CustomResult<files_api::FileDownloadUrlResponse, errors::ApiErrorResponse> {
+    let provider_file_id = match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id.as_ref(),
+        file_metadata.available,
+    ) {
+        (Some(diesel_models::enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            provider_file_id
+        }
+        (Some(diesel_models::enums::FileUploadProvider::Router), _, _) | (None, _, _) => {
+            Err(errors::ApiErrorResponse::FileNotAvailable)
+                .attach_printable("File not available")?
+        }
+        (Some(_), _, _) => Err(errors::ApiErrorResponse::FileProviderNotSupported {
+            message: "Download URLs are only supported for files stored by the router".to_string(),
+        })?,
+    };
+
+    let expiry_in_secs = state.conf.files.download_url_expiry_in_secs;
+    let expires_at = common_utils::date_time::now()
+        .saturating_add(time::Duration::seconds(i64::from(expiry_in_secs)));
+
+    let presigned_url = state
+        .file_storage_client
+        .get_download_url(
+            provider_file_id,
+            std::time::Duration::from_secs(u64::from(expiry_in_secs)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate download URL for file")?;
+
+    let url = match presigned_url {
+        Some(url) => url,
+        None => {
+            let token = common_utils::generate_id(consts::ID_LENGTH, "fdt");
+            let token_data = FileDownloadTokenData {
+                merchant_id: file_metadata.merchant_id.clone(),
+                file_id: file_metadata.file_id.clone(),
+            };
+            state
+                .store
+                .get_redis_conn()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get redis connection")?
+                .serialize_and_set_key_with_expiry(
+                    &get_file_download_token_key(&token),
+                    token_data,
+                    i64::from(expiry_in_secs),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to store file download token")?;
+            format!("{}/files/download/{token}", state.base_url)
+        }
+    };
+
+    Ok(files_api::FileDownloadUrlResponse {
+        file_id: file_metadata.file_id.clone(),
+        url,
+        expires_at,
+    })
+}
+
+/// Retrieves the file referenced by a file download token issued by [`get_file_download_url`]
+pub async fn get_file_download_token_data(
+    state: &SessionState,
+    token: &str,
+) -> CustomResult<FileDownloadTokenData, errors::ApiErrorResponse> {
+    let key = get_file_download_token_key(token);
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<FileDownloadTokenData>(&key, "FileDownloadTokenData")
+        .await
+        .map_err(|error| error.to_redis_failed_response(&key))
+        .to_not_found_response(errors::ApiErrorResponse::FileNotFound)
+}
@@ -0,0 +1,270 @@
+// @ts-check
+/**
+ * Trigger - init
+ * Uses
+ *  - Update document's icon and theme
+ *  - Render saved payment methods and billing address
+ */
+function boot() {
+  // @ts-ignore
+  var portalDetails = window.__CUSTOMER_PORTAL_DETAILS;
+
+  // Attach document icon
+  if (typeof portalDetails.logo === "string") {
+    var link = document.createElement("link");
+    link.rel = "icon";
+    link.href = portalDetails.logo;
+    link.type = "image/x-icon";
+    document.head.appendChild(link);
+  }
+  if (typeof portalDetails.theme === "string") {
+    document.documentElement.style.setProperty(
+      "--primary-color",
+      portalDetails.theme
+    );
+  }
+
+  renderMerchantHeader(portalDetails);
+  renderPaymentMethods(portalDetails);
+  renderBillingAddress(portalDetails);
+  renderReturnButton(portalDetails);
+}
+boot();
+
+/**
+ * Trigger - on boot
+ * Uses
+ *  - Render merchant name and logo
+ */
+function renderMerchantHeader(portalDetails) {
+  var merchantHeaderNode = document.getElementById("merchant-header");
+  if (!(merchantHeaderNode instanceof HTMLDivElement)) {
+    return;
+  }
+  if (typeof portalDetails.merchant_name === "string") {
+    var merchantNameNode = document.createElement("div");
+    merchantNameNode.innerText = portalDetails.merchant_name;
+    merchantHeaderNode.appendChild(merchantNameNode);
+  }
+  if (typeof portalDetails.logo === "string") {
+    var merchantLogoNode = document.createElement("img");
+    merchantLogoNode.src = portalDetails.logo;
+    merchantHeaderNode.appendChild(merchantLogoNode);
+  }
+}
+
+/**
+ * Trigger - on boot and after deleting a payment method
+ * Uses
+ *  - Render saved payment methods along with a delete action
+ */
+function renderPaymentMethods(portalDetails) {
+  var paymentMethodsNode = document.getElementById("payment-methods");
+  if (!(paymentMethodsNode instanceof HTMLDivElement)) {
+    return;
+  }
+  paymentMethodsNode.innerHTML = "";
+
+  if (portalDetails.payment_methods.length === 0) {
+    var emptyNode = document.createElement("div");
+    emptyNode.innerText = "No saved payment methods";
+    paymentMethodsNode.appendChild(emptyNode);
+    return;
+  }
+
+  portalDetails.payment_methods.forEach(function (paymentMethod) {
+    var paymentMethodNode = document.createElement("div");
+    paymentMethodNode.className = "payment-method";
+
+    var detailsNode = document.createElement("div");
+    detailsNode.className = "payment-method-details";
+    var titleNode = document.createElement("div");
+    var subtitleNode = document.createElement("div");
+    var card = paymentMethod.card;
+    if (card) {
+      titleNode.innerText =
+        (card.card_network || "Card") + " •••• " + (card.last4_digits || "");
+      if (card.expiry_month && card.expiry_year) {
+        subtitleNode.innerText =
+          "Expires " + card.expiry_month + "/" + card.expiry_year;
+      }
+    } else {
+      titleNode.innerText =
+        paymentMethod.payment_method_type || paymentMethod.payment_method;
+    }
+    if (paymentMethod.is_default) {
+      var defaultNode = document.createElement("span");
+      defaultNode.className = "default-badge";
+      defaultNode.innerText = "Default";
+      titleNode.appendChild(defaultNode);
+    }
+    detailsNode.appendChild(titleNode);
+    detailsNode.appendChild(subtitleNode);
+
+    var deleteButtonNode = document.createElement("button");
+    deleteButtonNode.innerText = "Remove";
+    deleteButtonNode.onclick = function () {
+      deleteButtonNode.disabled = true;
+      deletePaymentMethod(portalDetails, paymentMethod.payment_method_id)
+        .then(function () {
+          portalDetails.payment_methods = portalDetails.payment_methods.filter(
+            function (pm) {
+              return pm.payment_method_id !== paymentMethod.payment_method_id;
+            }
+          );
+          renderPaymentMethods(portalDetails);
+          showMessage("Payment method removed");
+        })
+        .catch(function (error) {
+          deleteButtonNode.disabled = false;
+          showMessage(error.message);
+        });
+    };
+
+    paymentMethodNode.appendChild(detailsNode);
+    paymentMethodNode.appendChild(deleteButtonNode);
+    paymentMethodsNode.appendChild(paymentMethodNode);
+  });
+}
+
+/**
+ * Trigger - on boot
+ * Uses
+ *  - Render billing address form prefilled with the customer's address
+ */
+function renderBillingAddress(portalDetails) {
+  var formNode = document.getElementById("billing-address-form");
+  if (!(formNode instanceof HTMLFormElement)) {
+    return;
+  }
+  var address = portalDetails.billing_address || {};
+  var fields = {
+    first_name: "First name",
+    last_name: "Last name",
+    line1: "Address line 1",
+    line2: "Address line 2",
+    city: "City",
+    state: "State",
+    zip: "Postal code",
+    country: "Country (ISO alpha-2)",
+  };
+  for (var key in fields) {
+    if (fields.hasOwnProperty(key)) {
+      var inputNode = document.createElement("input");
+      inputNode.name = key;
+      inputNode.placeholder = fields[key];
+      inputNode.value = typeof address[key] === "string" ? address[key] : "";
+      formNode.appendChild(inputNode);
+    }
+  }
+  var submitNode = document.createElement("button");
+  submitNode.type = "submit";
+  submitNode.innerText = "Save address";
+  formNode.appendChild(submitNode);
+
+  formNode.onsubmit = function (event) {
+    event.preventDefault();
+    var updatedAddress = {};
+    new FormData(formNode).forEach(function (value, key) {
+      if (typeof value === "string" && value.trim() !== "") {
+        updatedAddress[key] = value.trim();
+      }
+    });
+    submitNode.disabled = true;
+    updateBillingAddress(portalDetails, updatedAddress)
+      .then(function () {
+        showMessage("Billing address updated");
+      })
+      .catch(function (error) {
+        showMessage(error.message);
+      })
+      .finally(function () {
+        submitNode.disabled = false;
+      });
+  };
+}
+
+/**
+ * Trigger - if return_url was specified during customer portal link creation
+ * Uses
+ *  - Render a button for navigating back to the merchant
+ */
+function renderReturnButton(portalDetails) {
+  var returnButtonNode = document.getElementById("return-button");
+  if (
+    typeof portalDetails.return_url !== "string" ||
+    !(returnButtonNode instanceof HTMLButtonElement)
+  ) {
+    return;
+  }
+  returnButtonNode.classList.remove("hidden");
+  returnButtonNode.onclick = function () {
+    try {
+      window.top.location.href = portalDetails.return_url;
+    } catch (error) {
+      console.error(
+        "CRITICAL ERROR",
+        "Failed to redirect top document. Error - ",
+        error
+      );
+      console.info("Redirecting in current document");
+      window.location.href = portalDetails.return_url;
+    }
+  };
+}
+
+/**
+ * Uses
+ *  - Delete a saved payment method of the customer
+ */
+function deletePaymentMethod(portalDetails, paymentMethodId) {
+  return postToPortal(
+    portalDetails,
+    "/payment_methods/" + paymentMethodId + "/delete",
+    { client_secret: portalDetails.client_secret }
+  );
+}
+
+/**
+ * Uses
+ *  - Update the customer's billing address
+ */
+function updateBillingAddress(portalDetails, address) {
+  return postToPortal(portalDetails, "/billing_address", {
+    client_secret: portalDetails.client_secret,
+    address: address,
+  });
+}
+
+function postToPortal(portalDetails, path, body) {
+  var url =
+    window.location.origin +
+    "/customers/portal_link/" +
+    portalDetails.merchant_id +
+    "/" +
+    portalDetails.customer_portal_link_id +
+    path;
+  return fetch(url, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify(body),
+  }).then(function (res) {
+    return res.json().then(function (data) {
+      if (!res.ok) {
+        var message =
+          data && data.error && typeof data.error.message === "string"
+            ? data.error.message
+            : "Something went wrong";
+        throw new Error(message);
+      }
+      return data;
+    });
+  });
+}
+
+function showMessage(message) {
+  var messageNode = document.getElementById("portal-message");
+  if (messageNode instanceof HTMLDivElement) {
+    messageNode.innerText = message;
+  }
+}
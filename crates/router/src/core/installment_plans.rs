@@ -0,0 +1,498 @@
+use api_models::installment_plans::{
+    InstallmentPlanCreateRequest, InstallmentPlanListConstraints, InstallmentPlanListResponse,
+    InstallmentPlanResponse,
+};
+use common_utils::{fp_utils::when, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+const INSTALLMENT_PLAN_CHARGE_TASK: &str = "INSTALLMENT_PLAN_CHARGE";
+const MAX_INSTALLMENT_COUNT: u16 = 60;
+const MAX_RETRIES_PER_INSTALLMENT: u8 = 10;
+const DEFAULT_MAX_RETRIES_PER_INSTALLMENT: u8 = 3;
+const DEFAULT_RETRY_INTERVAL_HOURS: u16 = 24;
+
+fn validate_installment_plan_create_request(
+    req: &InstallmentPlanCreateRequest,
+) -> RouterResult<()> {
+    when(
+        !(2..=MAX_INSTALLMENT_COUNT).contains(&req.installment_count),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("installment_count must be between 2 and {MAX_INSTALLMENT_COUNT}"),
+            }))
+        },
+    )?;
+
+    when(req.interval_days == 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_days must be greater than 0".to_string(),
+        }))
+    })?;
+
+    when(
+        req.total_amount < MinorUnit::new(i64::from(req.installment_count)),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "total_amount must be at least 1 for every installment".to_string(),
+            }))
+        },
+    )?;
+
+    when(
+        req.max_retries_per_installment
+            .is_some_and(|max_retries| max_retries > MAX_RETRIES_PER_INSTALLMENT),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "max_retries_per_installment must not be greater than {MAX_RETRIES_PER_INSTALLMENT}"
+                ),
+            }))
+        },
+    )?;
+
+    when(req.retry_interval_hours == Some(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retry_interval_hours must be greater than 0".to_string(),
+        }))
+    })?;
+
+    when(
+        req.first_charge_at
+            .is_some_and(|first_charge_at| first_charge_at < common_utils::date_time::now()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "first_charge_at must not be in the past".to_string(),
+            }))
+        },
+    )
+}
+
+/// Splits the total amount into installments due every `interval_days` days from the first
+/// charge. The remainder of the split is charged with the first installment.
+fn build_installment_schedule(
+    total_amount: MinorUnit,
+    installment_count: u16,
+    interval_days: u16,
+    first_charge_at: time::PrimitiveDateTime,
+) -> storage::InstallmentSchedule {
+    let total_amount = total_amount.get_amount_as_i64();
+    let installment_amount = total_amount / i64::from(installment_count);
+    let remainder = total_amount % i64::from(installment_count);
+
+    storage::InstallmentSchedule(
+        (0..installment_count)
+            .map(|index| storage::Installment {
+                installment_number: i32::from(index) + 1,
+                amount: MinorUnit::new(if index == 0 {
+                    installment_amount + remainder
+                } else {
+                    installment_amount
+                }),
+                due_at: first_charge_at
+                    + time::Duration::days(i64::from(index) * i64::from(interval_days)),
+                status: enums::InstallmentStatus::Scheduled,
+                attempt_count: 0,
+                payment_id: None,
+            })
+            .collect(),
+    )
+}
+
+/// Validates that every installment of the schedule can be charged against the mandate
+fn validate_mandate_for_installment_plan(
+    mandate: &storage::Mandate,
+    currency: enums::Currency,
+    installments: &storage::InstallmentSchedule,
+) -> RouterResult<()> {
+    let validation_error = |reason: &str| {
+        Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: reason.to_string(),
+        }))
+    };
+
+    if mandate.mandate_status != enums::MandateStatus::Active {
+        return validation_error("mandate is not active");
+    }
+
+    if mandate.mandate_type != enums::MandateType::MultiUse {
+        return validation_error("installments can only be charged against a multi use mandate");
+    }
+
+    if mandate
+        .mandate_currency
+        .is_some_and(|mandate_currency| mandate_currency != currency)
+    {
+        return validation_error("currency of the plan does not match the currency of the mandate");
+    }
+
+    let max_installment_amount = installments
+        .iter()
+        .map(|installment| installment.amount.get_amount_as_i64())
+        .max()
+        .unwrap_or_default();
+    if mandate
+        .mandate_amount
+        .is_some_and(|mandate_amount| max_installment_amount > mandate_amount)
+    {
+        return validation_error("installment amount exceeds the amount allowed by the mandate");
+    }
+
+    let last_due_at = installments.last().map(|installment| installment.due_at);
+    if mandate
+        .end_date
+        .zip(last_due_at)
+        .is_some_and(|(end_date, last_due_at)| last_due_at > end_date)
+    {
+        return validation_error("mandate expires before the last installment is due");
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_installment_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InstallmentPlanCreateRequest,
+) -> RouterResponse<InstallmentPlanResponse> {
+    validate_installment_plan_create_request(&req)?;
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_account.get_id(),
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    let now = common_utils::date_time::now();
+    let first_charge_at = req.first_charge_at.unwrap_or(now);
+    let installments = build_installment_schedule(
+        req.total_amount,
+        req.installment_count,
+        req.interval_days,
+        first_charge_at,
+    );
+
+    validate_mandate_for_installment_plan(&mandate, req.currency, &installments)?;
+
+    let installment_plan_new = storage::InstallmentPlanNew {
+        id: utils::generate_id(consts::ID_LENGTH, "inst"),
+        merchant_id: merchant_account.get_id().to_owned(),
+        profile_id,
+        customer_id: mandate.customer_id,
+        mandate_id: mandate.mandate_id,
+        status: enums::InstallmentPlanStatus::Active,
+        currency: req.currency,
+        total_amount: req.total_amount,
+        installment_count: i32::from(req.installment_count),
+        interval_days: i32::from(req.interval_days),
+        installments,
+        max_retries_per_installment: i32::from(
+            req.max_retries_per_installment
+                .unwrap_or(DEFAULT_MAX_RETRIES_PER_INSTALLMENT),
+        ),
+        retry_interval_hours: i32::from(
+            req.retry_interval_hours
+                .unwrap_or(DEFAULT_RETRY_INTERVAL_HOURS),
+        ),
+        max_missed_installments: i32::from(req.max_missed_installments.unwrap_or_default()),
+        missed_count: 0,
+        next_charge_at: Some(first_charge_at),
+        description: req.description,
+        metadata: req.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let installment_plan = db
+        .insert_installment_plan(installment_plan_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "installment plan already exists".to_string(),
+        })?;
+
+    add_installment_plan_charge_task(db, &installment_plan, first_charge_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding installment plan charge task to process tracker")?;
+
+    Ok(services::ApplicationResponse::Json(
+        InstallmentPlanResponse::foreign_from(installment_plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_installment_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    plan_id: String,
+) -> RouterResponse<InstallmentPlanResponse> {
+    let installment_plan = state
+        .store
+        .find_installment_plan_by_merchant_id_plan_id(merchant_account.get_id(), &plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Installment plan does not exist in our records".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        InstallmentPlanResponse::foreign_from(installment_plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_installment_plans(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: InstallmentPlanListConstraints,
+) -> RouterResponse<InstallmentPlanListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into())
+        .min(consts::DEFAULT_LIST_API_LIMIT.into());
+
+    let installment_plans = state
+        .store
+        .list_installment_plans_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.customer_id.as_ref(),
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list installment plans")?;
+
+    let data: Vec<_> = installment_plans
+        .into_iter()
+        .map(InstallmentPlanResponse::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        InstallmentPlanListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Cancels an active installment plan. The installments which have already been charged are not
+/// refunded, the remaining installments are not charged.
+#[instrument(skip_all)]
+pub async fn cancel_installment_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    plan_id: String,
+) -> RouterResponse<InstallmentPlanResponse> {
+    let db = state.store.as_ref();
+    let installment_plan = db
+        .find_installment_plan_by_merchant_id_plan_id(merchant_account.get_id(), &plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Installment plan does not exist in our records".to_string(),
+        })?;
+
+    when(
+        installment_plan.status != enums::InstallmentPlanStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Installment plan cannot be cancelled as it is {}",
+                    installment_plan.status
+                ),
+            }))
+        },
+    )?;
+
+    revoke_installment_plan_charge_task(db, &installment_plan)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while revoking installment plan charge task")?;
+
+    let installment_plan = db
+        .update_installment_plan_by_merchant_id_plan_id(
+            merchant_account.get_id(),
+            &plan_id,
+            storage::InstallmentPlanUpdate::StatusUpdate {
+                status: enums::InstallmentPlanStatus::Cancelled,
+                installments: void_scheduled_installments(installment_plan.installments),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel installment plan")?;
+
+    Ok(services::ApplicationResponse::Json(
+        InstallmentPlanResponse::foreign_from(installment_plan),
+    ))
+}
+
+/// Marks the installments which are yet to be charged as void, once the plan is stopped
+pub fn void_scheduled_installments(
+    mut installments: storage::InstallmentSchedule,
+) -> storage::InstallmentSchedule {
+    installments
+        .iter_mut()
+        .filter(|installment| installment.status == enums::InstallmentStatus::Scheduled)
+        .for_each(|installment| installment.status = enums::InstallmentStatus::Void);
+    installments
+}
+
+/// Creates an off session payment for the installment against the mandate of the plan
+#[instrument(skip_all)]
+pub async fn charge_installment(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    installment_plan: &storage::InstallmentPlan,
+    installment: &storage::Installment,
+) -> RouterResult<api::PaymentsResponse> {
+    let description = match &installment_plan.description {
+        Some(description) => format!(
+            "{description} - installment {} of {}",
+            installment.installment_number, installment_plan.installment_count
+        ),
+        None => format!(
+            "Installment {} of {}",
+            installment.installment_number, installment_plan.installment_count
+        ),
+    };
+
+    let payments_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(
+            common_utils::id_type::PaymentId::default(),
+        )),
+        amount: Some(installment.amount.into()),
+        currency: Some(installment_plan.currency),
+        customer_id: Some(installment_plan.customer_id.clone()),
+        profile_id: Some(installment_plan.profile_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(api_models::mandates::RecurringDetails::MandateId(
+            installment_plan.mandate_id.clone(),
+        )),
+        description: Some(description),
+        metadata: Some(serde_json::json!({
+            "installment_plan_id": installment_plan.id,
+            "installment_number": installment.installment_number,
+        })),
+        ..Default::default()
+    };
+
+    // TODO: Add support for ReqState in PT flows
+    match Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        None,
+        key_store,
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response)
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while charging installment"),
+    }
+}
+
+fn get_installment_plan_charge_task_id(installment_plan: &storage::InstallmentPlan) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::InstallmentPlanChargeWorkflow,
+        INSTALLMENT_PLAN_CHARGE_TASK,
+        &installment_plan.id,
+        &installment_plan.merchant_id,
+    )
+}
+
+/// Schedules the installments of the plan to be charged, starting at `schedule_time`. The same
+/// task charges every installment of the plan, and is rescheduled after each charge.
+pub async fn add_installment_plan_charge_task(
+    db: &dyn StorageInterface,
+    installment_plan: &storage::InstallmentPlan,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::InstallmentPlanChargeTrackingData {
+        merchant_id: installment_plan.merchant_id.clone(),
+        installment_plan_id: installment_plan.id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_installment_plan_charge_task_id(installment_plan),
+        INSTALLMENT_PLAN_CHARGE_TASK,
+        storage::ProcessTrackerRunner::InstallmentPlanChargeWorkflow,
+        ["INSTALLMENT_PLAN", "MANDATE"],
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+async fn revoke_installment_plan_charge_task(
+    db: &dyn StorageInterface,
+    installment_plan: &storage::InstallmentPlan,
+) -> CustomResult<(), errors::StorageError> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_installment_plan_charge_task_id(installment_plan)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,532 @@
+pub mod pdf;
+
+use api_models::{invoices as invoice_api, payments::OrderDetailsWithAmount};
+use common_utils::{
+    ext_traits::ValueExt,
+    id_type, pii,
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForCore},
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use tera::{Context, Tera};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files,
+        payments::helpers as payment_helpers,
+    },
+    routes::SessionState,
+    services,
+    types::{domain, storage::enums},
+    utils::OptionExt,
+};
+
+/// Details which are rendered on the invoice
+#[derive(Debug, serde::Serialize)]
+struct InvoiceDetails {
+    invoice_number: String,
+    merchant_name: String,
+    issued_on: String,
+    payment_id: String,
+    bill_to: Option<String>,
+    line_items: Vec<InvoiceLineItemDetails>,
+    currency: String,
+    total_amount: String,
+    amount_paid: String,
+    footer_note: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct InvoiceLineItemDetails {
+    description: String,
+    quantity: u16,
+    unit_amount: String,
+    amount: String,
+}
+
+/// A generated invoice which has been stored in the files subsystem
+struct StoredInvoice {
+    invoice_number: String,
+    file_metadata: diesel_models::file::FileMetadata,
+    format: enums::InvoiceFormat,
+    total_amount: MinorUnit,
+    currency: enums::Currency,
+    html: String,
+    created_at: time::PrimitiveDateTime,
+}
+
+#[instrument(skip_all)]
+pub async fn generate_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: invoice_api::InvoiceCreateRequest,
+) -> RouterResponse<invoice_api::InvoiceResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::PartiallyCaptured,
+        ],
+        "generate an invoice for",
+    )?;
+
+    let invoice_config = match &payment_intent.profile_id {
+        Some(profile_id) => {
+            db.find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                    id: profile_id.get_string_repr().to_owned(),
+                })?
+                .invoice_config
+        }
+        None => None,
+    };
+
+    let customer = find_customer(&state, &merchant_account, &key_store, &payment_intent).await?;
+    let recipient_email = req.recipient_email.clone().or(customer
+        .as_ref()
+        .and_then(|customer| customer.email.clone().map(pii::Email::from)));
+
+    let line_items = match req.line_items {
+        Some(line_items) => {
+            validate_line_items(&line_items)?;
+            line_items
+        }
+        None => get_line_items_from_payment_intent(&payment_intent)?,
+    };
+    let format = req
+        .format
+        .or(invoice_config.as_ref().map(|config| config.format))
+        .unwrap_or_default();
+
+    let invoice = build_and_store_invoice(
+        &state,
+        &merchant_account,
+        &payment_intent,
+        customer.as_ref(),
+        recipient_email.as_ref(),
+        line_items,
+        format,
+        invoice_config.and_then(|config| config.footer_note),
+    )
+    .await?;
+
+    let email_sent = if req.send_email.unwrap_or(false) {
+        let recipient_email = recipient_email.get_required_value("recipient_email")?;
+        send_invoice_email(&state, recipient_email, invoice.html.clone()).await?
+    } else {
+        false
+    };
+
+    // The invoice has already been stored, so failing to issue a download URL must not fail the
+    // request; the invoice can still be retrieved from the files API using the file ID
+    let download_url = files::helpers::get_file_download_url(&state, &invoice.file_metadata)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to create invoice download URL"))
+        .ok()
+        .map(|response| response.url);
+
+    Ok(services::ApplicationResponse::Json(
+        invoice_api::InvoiceResponse {
+            invoice_number: invoice.invoice_number,
+            payment_id: payment_intent.payment_id,
+            file_id: invoice.file_metadata.file_id,
+            download_url,
+            format: invoice.format,
+            total_amount: invoice.total_amount,
+            currency: invoice.currency,
+            email_sent,
+            created_at: invoice.created_at,
+        },
+    ))
+}
+
+/// Generates an invoice for a successful payment and emails it to the customer, if the business
+/// profile of the payment is configured to do so. An invoice is sent at most once for a payment.
+#[instrument(skip_all)]
+pub async fn send_invoice_on_payment_success(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: domain::Profile,
+    payment_intent: hyperswitch_domain_models::payments::PaymentIntent,
+    customer: Option<domain::Customer>,
+) -> RouterResult<()> {
+    let Some(invoice_config) = business_profile
+        .invoice_config
+        .filter(|config| config.send_email_on_payment_success)
+    else {
+        return Ok(());
+    };
+    if payment_intent.status != enums::IntentStatus::Succeeded {
+        return Ok(());
+    }
+
+    let Some(recipient_email) = customer
+        .as_ref()
+        .and_then(|customer| customer.email.clone().map(pii::Email::from))
+    else {
+        logger::info!("Invoice not sent as the customer does not have an email");
+        return Ok(());
+    };
+
+    let is_first_attempt = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_if_not_exists_with_expiry(
+            &format!(
+                "{}{}_{}",
+                consts::INVOICE_SENT_REDIS_KEY_PREFIX,
+                merchant_account.get_id().get_string_repr(),
+                payment_intent.payment_id.get_string_repr()
+            ),
+            "",
+            Some(consts::INVOICE_SENT_REDIS_KEY_EXPIRY_SECONDS),
+        )
+        .await
+        .map(|reply| matches!(reply, redis_interface::SetnxReply::KeySet))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check if the invoice was already sent")?;
+    if !is_first_attempt {
+        return Ok(());
+    }
+
+    let line_items = get_line_items_from_payment_intent(&payment_intent)?;
+    let invoice = build_and_store_invoice(
+        &state,
+        &merchant_account,
+        &payment_intent,
+        customer.as_ref(),
+        Some(&recipient_email),
+        line_items,
+        invoice_config.format,
+        invoice_config.footer_note,
+    )
+    .await?;
+    send_invoice_email(&state, recipient_email, invoice.html).await?;
+
+    Ok(())
+}
+
+async fn find_customer(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &hyperswitch_domain_models::payments::PaymentIntent,
+) -> RouterResult<Option<domain::Customer>> {
+    match &payment_intent.customer_id {
+        Some(customer_id) => state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &state.into(),
+                customer_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer of the payment"),
+        None => Ok(None),
+    }
+}
+
+fn validate_line_items(line_items: &[invoice_api::InvoiceLineItem]) -> RouterResult<()> {
+    if line_items.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "line_items must contain at least one item".to_string(),
+        }));
+    }
+    if line_items
+        .iter()
+        .any(|line_item| line_item.quantity == 0 || line_item.unit_amount < MinorUnit::zero())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "line_items must have a positive quantity and a non-negative unit_amount"
+                .to_string(),
+        }));
+    }
+    Ok(())
+}
+
+/// Uses the order details of the payment as line items, falling back to a single line item for
+/// the whole amount of the payment
+fn get_line_items_from_payment_intent(
+    payment_intent: &hyperswitch_domain_models::payments::PaymentIntent,
+) -> RouterResult<Vec<invoice_api::InvoiceLineItem>> {
+    let order_details = payment_intent
+        .order_details
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|order_detail| {
+            order_detail
+                .expose()
+                .parse_value::<OrderDetailsWithAmount>("OrderDetailsWithAmount")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the order details of the payment")
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    if order_details.is_empty() {
+        return Ok(vec![invoice_api::InvoiceLineItem {
+            description: payment_intent.description.clone().unwrap_or(format!(
+                "Payment {}",
+                payment_intent.payment_id.get_string_repr()
+            )),
+            quantity: 1,
+            unit_amount: payment_intent.amount,
+        }]);
+    }
+
+    Ok(order_details
+        .into_iter()
+        .map(|order_detail| invoice_api::InvoiceLineItem {
+            description: order_detail.product_name,
+            quantity: order_detail.quantity,
+            unit_amount: order_detail.amount,
+        })
+        .collect())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn build_and_store_invoice(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &hyperswitch_domain_models::payments::PaymentIntent,
+    customer: Option<&domain::Customer>,
+    recipient_email: Option<&pii::Email>,
+    line_items: Vec<invoice_api::InvoiceLineItem>,
+    format: enums::InvoiceFormat,
+    footer_note: Option<String>,
+) -> RouterResult<StoredInvoice> {
+    let currency = payment_intent.currency.get_required_value("currency")?;
+    let invoice_number = common_utils::generate_id(consts::ID_LENGTH, "inv");
+    let created_at = common_utils::date_time::now();
+
+    let amount_converter = StringMajorUnitForCore;
+    let to_major_unit = |amount: MinorUnit| {
+        amount_converter
+            .convert(amount, currency)
+            .map(|amount| amount.get_amount_as_string())
+            .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+                amount_type: "StringMajorUnit",
+            })
+    };
+
+    let mut total_amount = MinorUnit::zero();
+    let mut line_item_details = Vec::with_capacity(line_items.len());
+    for line_item in line_items {
+        let amount = line_item.unit_amount * line_item.quantity;
+        total_amount = total_amount + amount;
+        line_item_details.push(InvoiceLineItemDetails {
+            description: line_item.description,
+            quantity: line_item.quantity,
+            unit_amount: to_major_unit(line_item.unit_amount)?,
+            amount: to_major_unit(amount)?,
+        });
+    }
+
+    let invoice_details = InvoiceDetails {
+        invoice_number: invoice_number.clone(),
+        merchant_name: merchant_account
+            .merchant_name
+            .clone()
+            .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+            .unwrap_or(merchant_account.get_id().get_string_repr().to_owned()),
+        issued_on: created_at.date().to_string(),
+        payment_id: payment_intent.payment_id.get_string_repr().to_owned(),
+        bill_to: customer
+            .and_then(|customer| customer.name.clone())
+            .map(|name| name.into_inner().expose())
+            .or(recipient_email.map(|email| email.peek().to_owned())),
+        line_items: line_item_details,
+        currency: currency.to_string(),
+        total_amount: to_major_unit(total_amount)?,
+        amount_paid: to_major_unit(
+            payment_intent
+                .amount_captured
+                .unwrap_or(payment_intent.amount),
+        )?,
+        footer_note,
+    };
+
+    let html = render_invoice_html(&invoice_details)?;
+    let (file_data, file_type, file_extension) = match format {
+        enums::InvoiceFormat::Html => (html.clone().into_bytes(), mime::TEXT_HTML, "html"),
+        enums::InvoiceFormat::Pdf => (
+            pdf::build_text_pdf(&get_invoice_text_lines(&invoice_details)),
+            mime::APPLICATION_PDF,
+            "pdf",
+        ),
+    };
+
+    let file_metadata = store_invoice(
+        state,
+        merchant_account,
+        payment_intent.profile_id.clone(),
+        format!("{invoice_number}.{file_extension}"),
+        file_type,
+        file_data,
+    )
+    .await?;
+
+    Ok(StoredInvoice {
+        invoice_number,
+        file_metadata,
+        format,
+        total_amount,
+        currency,
+        html,
+        created_at,
+    })
+}
+
+fn render_invoice_html(invoice_details: &InvoiceDetails) -> RouterResult<String> {
+    let mut tera = Tera::default();
+    // The template name has an `.html` extension so that the details are auto-escaped
+    tera.add_raw_template("invoice.html", include_str!("invoices/invoice.html"))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add invoice HTML template")?;
+    let context = Context::from_serialize(invoice_details)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build context for invoice HTML template")?;
+
+    tera.render("invoice.html", &context)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to render invoice HTML template")
+}
+
+fn get_invoice_text_lines(invoice_details: &InvoiceDetails) -> Vec<String> {
+    let mut lines = vec![
+        invoice_details.merchant_name.clone(),
+        String::new(),
+        format!("Invoice {}", invoice_details.invoice_number),
+        format!("Issued on {}", invoice_details.issued_on),
+        format!("Payment {}", invoice_details.payment_id),
+    ];
+    if let Some(bill_to) = &invoice_details.bill_to {
+        lines.push(format!("Billed to {bill_to}"));
+    }
+    lines.push(String::new());
+    lines.extend(invoice_details.line_items.iter().map(|line_item| {
+        format!(
+            "{}  x{} @ {}  =  {}",
+            line_item.description, line_item.quantity, line_item.unit_amount, line_item.amount
+        )
+    }));
+    lines.push(String::new());
+    lines.push(format!(
+        "Total: {} {}",
+        invoice_details.currency, invoice_details.total_amount
+    ));
+    lines.push(format!(
+        "Amount paid: {} {}",
+        invoice_details.currency, invoice_details.amount_paid
+    ));
+    if let Some(footer_note) = &invoice_details.footer_note {
+        lines.push(String::new());
+        lines.push(footer_note.clone());
+    }
+    lines
+}
+
+async fn store_invoice(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    file_name: String,
+    file_type: mime::Mime,
+    file_data: Vec<u8>,
+) -> RouterResult<diesel_models::file::FileMetadata> {
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!(
+        "{}/{}",
+        merchant_account.get_id().get_string_repr(),
+        file_id
+    );
+    let file_size = i32::try_from(file_data.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invoice file size exceeds the maximum supported size")?;
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, file_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload invoice to file storage")?;
+
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id,
+        merchant_id: merchant_account.get_id().clone(),
+        file_name: Some(file_name),
+        file_size,
+        file_type: file_type.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id,
+        merchant_connector_id: None,
+    };
+    state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata for invoice")
+}
+
+#[cfg(feature = "email")]
+async fn send_invoice_email(
+    state: &SessionState,
+    recipient_email: pii::Email,
+    invoice_html: String,
+) -> RouterResult<bool> {
+    let email_contents = services::email::types::InvoiceEmail {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_INVOICE,
+        invoice_html,
+    };
+
+    state
+        .email_client
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send invoice email")?;
+
+    Ok(true)
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_invoice_email(
+    _state: &SessionState,
+    _recipient_email: pii::Email,
+    _invoice_html: String,
+) -> RouterResult<bool> {
+    logger::warn!("Invoice email not sent as the email feature is not enabled");
+    Ok(false)
+}
@@ -0,0 +1,140 @@
+const PAGE_WIDTH: usize = 612;
+const PAGE_HEIGHT: usize = 792;
+const PAGE_MARGIN: usize = 50;
+const FONT_SIZE: usize = 11;
+const LINE_HEIGHT: usize = 16;
+
+/// Builds a PDF document which lists the provided lines of text one below the other, using the
+/// standard Helvetica font. Lines which do not fit on a page are continued on the next page.
+pub fn build_text_pdf(lines: &[String]) -> Vec<u8> {
+    let lines_per_page = (PAGE_HEIGHT - 2 * PAGE_MARGIN) / LINE_HEIGHT;
+    let pages: Vec<&[String]> = if lines.is_empty() {
+        vec![&[]]
+    } else {
+        lines.chunks(lines_per_page).collect()
+    };
+
+    // Object 1 is the catalog, 2 is the page tree and 3 is the font, followed by a page object
+    // and its content stream for every page
+    let page_object_ids = (0..pages.len())
+        .map(|page_index| 4 + 2 * page_index)
+        .collect::<Vec<_>>();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_object_ids
+                .iter()
+                .map(|id| format!("{id} 0 R"))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    for (page_lines, page_object_id) in pages.iter().zip(page_object_ids.iter()) {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+             /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            page_object_id + 1
+        ));
+        let content = build_page_content(page_lines);
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{content}\nendstream",
+            content.len()
+        ));
+    }
+
+    // All the content is ASCII, so the string length is the same as the byte offset
+    let mut document = String::from("%PDF-1.4\n");
+    let mut object_offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        object_offsets.push(document.len());
+        document.push_str(&format!("{} 0 obj\n{object}\nendobj\n", index + 1));
+    }
+    let xref_offset = document.len();
+    document.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in object_offsets {
+        document.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    document.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    ));
+
+    document.into_bytes()
+}
+
+fn build_page_content(lines: &[String]) -> String {
+    let mut content = format!(
+        "BT\n/F1 {FONT_SIZE} Tf\n{LINE_HEIGHT} TL\n{PAGE_MARGIN} {} Td\n",
+        PAGE_HEIGHT - PAGE_MARGIN
+    );
+    for line in lines {
+        content.push_str(&format!("({}) Tj\nT*\n", escape_text(line)));
+    }
+    content.push_str("ET");
+    content
+}
+
+/// Escapes the characters which have a special meaning in PDF strings, and replaces characters
+/// which cannot be rendered using the standard fonts
+fn escape_text(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut escaped, c| {
+            match c {
+                '\\' | '(' | ')' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                c if c.is_ascii() && !c.is_ascii_control() => escaped.push(c),
+                _ => escaped.push('?'),
+            }
+            escaped
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use super::*;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("Total (USD)"), "Total \\(USD\\)");
+        assert_eq!(escape_text("a\\b"), "a\\\\b");
+        assert_eq!(escape_text("Café\n"), "Caf??");
+    }
+
+    #[test]
+    fn test_build_text_pdf_splits_lines_into_pages() {
+        let lines = (0..100)
+            .map(|line| format!("Line {line}"))
+            .collect::<Vec<_>>();
+        let document = String::from_utf8(build_text_pdf(&lines)).unwrap();
+
+        assert!(document.starts_with("%PDF-1.4\n"));
+        assert!(document.ends_with("%%EOF\n"));
+        assert!(document.contains("/Count 3"));
+        assert!(document.contains("(Line 99) Tj"));
+    }
+
+    #[test]
+    fn test_build_text_pdf_xref_offsets() {
+        let document = String::from_utf8(build_text_pdf(&["Invoice".to_string()])).unwrap();
+        let xref_offset = document
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|offset| offset.lines().next())
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+
+        assert!(document[xref_offset..].starts_with("xref\n"));
+        assert!(document.contains("1 0 obj\n<< /Type /Catalog"));
+    }
+}
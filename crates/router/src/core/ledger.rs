@@ -0,0 +1,808 @@
+use api_models::ledger as ledger_api;
+use common_utils::{date_time, fp_utils::when, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+#[cfg(feature = "payouts")]
+use super::payouts::PayoutData;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums, transformers::ForeignFrom},
+};
+
+const LEDGER_STATEMENT_DEFAULT_LIMIT: u32 = 100;
+const LEDGER_STATEMENT_MAX_LIMIT: u32 = 1000;
+
+/// A debit or credit of a ledger transaction, before it is recorded
+struct LedgerPosting {
+    account: enums::LedgerAccount,
+    direction: enums::LedgerEntryDirection,
+    amount: MinorUnit,
+}
+
+impl LedgerPosting {
+    fn debit(account: enums::LedgerAccount, amount: MinorUnit) -> Self {
+        Self {
+            account,
+            direction: enums::LedgerEntryDirection::Debit,
+            amount,
+        }
+    }
+
+    fn credit(account: enums::LedgerAccount, amount: MinorUnit) -> Self {
+        Self {
+            account,
+            direction: enums::LedgerEntryDirection::Credit,
+            amount,
+        }
+    }
+}
+
+/// The operation whose balance movement is recorded by a ledger transaction. An operation is
+/// recorded only once, so that the balance movement of an operation reported more than once, such
+/// as by both a sync and a webhook, is not recorded twice.
+struct LedgerSource<'a> {
+    merchant_id: &'a id_type::MerchantId,
+    profile_id: &'a id_type::ProfileId,
+    source_type: enums::LedgerSourceType,
+    source_id: String,
+    currency: enums::Currency,
+}
+
+/// Records the postings of a ledger transaction, whose debits must balance its credits. Postings
+/// of a zero amount are left out.
+async fn record_transaction(
+    state: &SessionState,
+    source: LedgerSource<'_>,
+    postings: Vec<LedgerPosting>,
+) -> RouterResult<()> {
+    let postings = postings
+        .into_iter()
+        .filter(|posting| posting.amount != MinorUnit::zero())
+        .collect::<Vec<_>>();
+    if postings.is_empty() {
+        return Ok(());
+    }
+
+    when(
+        postings
+            .iter()
+            .any(|posting| posting.amount < MinorUnit::zero()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Ledger postings must not have a negative amount")
+        },
+    )?;
+    let get_total = |direction| {
+        postings
+            .iter()
+            .filter(|posting| posting.direction == direction)
+            .fold(MinorUnit::zero(), |total, posting| total + posting.amount)
+    };
+    let debits = get_total(enums::LedgerEntryDirection::Debit);
+    let credits = get_total(enums::LedgerEntryDirection::Credit);
+    when(debits != credits, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(format!(
+            "Debits of {debits} do not balance credits of {credits} of the ledger transaction"
+        ))
+    })?;
+
+    let transaction_id = common_utils::generate_id(consts::ID_LENGTH, "ledger_txn");
+    let created_at = date_time::now();
+    let ledger_entries = postings
+        .into_iter()
+        .map(|posting| storage::LedgerEntryNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "ledger_entry"),
+            transaction_id: transaction_id.clone(),
+            merchant_id: source.merchant_id.clone(),
+            profile_id: source.profile_id.clone(),
+            account: posting.account,
+            direction: posting.direction,
+            amount: posting.amount,
+            currency: source.currency,
+            source_type: source.source_type,
+            source_id: source.source_id.clone(),
+            created_at,
+        })
+        .collect();
+
+    match state.store.insert_ledger_entries(ledger_entries).await {
+        Ok(_) => {
+            logger::info!(
+                %transaction_id,
+                source_type = %source.source_type,
+                source_id = %source.source_id,
+                "Recorded ledger transaction"
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!(
+                source_type = %source.source_type,
+                source_id = %source.source_id,
+                "Ledger transaction is already recorded"
+            );
+            Ok(())
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert ledger entries")),
+    }
+}
+
+/// Records the amount captured from a payment once the payment has succeeded, as funds held by the
+/// connector. Failures are only logged, since the ledger must not affect the payment being
+/// recorded.
+#[instrument(skip_all)]
+pub async fn record_payment(state: &SessionState, payment_intent: &storage::PaymentIntent) {
+    if !matches!(
+        payment_intent.status,
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+    ) {
+        return;
+    }
+    let (Some(amount_captured), Some(currency), Some(profile_id)) = (
+        payment_intent.amount_captured,
+        payment_intent.currency,
+        payment_intent.profile_id.as_ref(),
+    ) else {
+        logger::warn!("Payment is not recorded in the ledger, as its amount is not known");
+        return;
+    };
+
+    let source = LedgerSource {
+        merchant_id: &payment_intent.merchant_id,
+        profile_id,
+        source_type: enums::LedgerSourceType::Payment,
+        source_id: payment_intent.payment_id.get_string_repr().to_owned(),
+        currency,
+    };
+    let postings = vec![
+        LedgerPosting::debit(enums::LedgerAccount::ConnectorBalance, amount_captured),
+        LedgerPosting::credit(enums::LedgerAccount::Payments, amount_captured),
+    ];
+    if let Err(error) = record_transaction(state, source, postings).await {
+        logger::error!(?error, "Failed to record the payment in the ledger");
+    }
+}
+
+/// Records the amount refunded once the refund has succeeded, as funds leaving the connector.
+/// Failures are only logged, since the ledger must not affect the refund being recorded.
+#[instrument(skip_all)]
+pub async fn record_refund(state: &SessionState, refund: &storage::Refund) {
+    if refund.refund_status != enums::RefundStatus::Success {
+        return;
+    }
+    let Some(profile_id) = refund.profile_id.as_ref() else {
+        logger::warn!("Refund is not recorded in the ledger, as its profile is not known");
+        return;
+    };
+
+    let source = LedgerSource {
+        merchant_id: &refund.merchant_id,
+        profile_id,
+        source_type: enums::LedgerSourceType::Refund,
+        source_id: refund.refund_id.clone(),
+        currency: refund.currency,
+    };
+    let postings = vec![
+        LedgerPosting::debit(enums::LedgerAccount::Refunds, refund.refund_amount),
+        LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, refund.refund_amount),
+    ];
+    if let Err(error) = record_transaction(state, source, postings).await {
+        logger::error!(?error, "Failed to record the refund in the ledger");
+    }
+}
+
+/// Records the amount of a payout once the payout has succeeded, as funds leaving the connector
+/// which are paid out to the recipient net of the fee charged by the connector. Failures are only
+/// logged, since the ledger must not affect the payout being recorded.
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn record_payout(state: &SessionState, payout_data: &PayoutData) {
+    if payout_data.payout_attempt.status != enums::PayoutStatus::Success {
+        return;
+    }
+    let amount = payout_data.payouts.amount;
+    let fee = payout_data.payout_attempt.fee.unwrap_or(MinorUnit::zero());
+
+    let source = LedgerSource {
+        merchant_id: &payout_data.payouts.merchant_id,
+        profile_id: &payout_data.payouts.profile_id,
+        source_type: enums::LedgerSourceType::Payout,
+        source_id: payout_data.payouts.payout_id.clone(),
+        currency: payout_data.payouts.source_currency,
+    };
+    let postings = vec![
+        LedgerPosting::debit(enums::LedgerAccount::Payouts, amount - fee),
+        LedgerPosting::debit(enums::LedgerAccount::Fees, fee),
+        LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, amount),
+    ];
+    if let Err(error) = record_transaction(state, source, postings).await {
+        logger::error!(?error, "Failed to record the payout in the ledger");
+    }
+}
+
+/// Records the amount of a chargeback once the dispute is lost or accepted, as funds taken back
+/// from the connector balance. Failures are only logged, since the ledger must not affect the
+/// dispute being recorded.
+#[instrument(skip_all)]
+pub async fn record_dispute(state: &SessionState, dispute: &storage::Dispute) {
+    if !matches!(
+        dispute.dispute_status,
+        enums::DisputeStatus::DisputeLost | enums::DisputeStatus::DisputeAccepted
+    ) {
+        return;
+    }
+    let (Some(currency), Some(profile_id)) =
+        (dispute.dispute_currency, dispute.profile_id.as_ref())
+    else {
+        logger::warn!("Dispute is not recorded in the ledger, as its currency is not known");
+        return;
+    };
+    let amount = MinorUnit::new(dispute.dispute_amount);
+
+    let source = LedgerSource {
+        merchant_id: &dispute.merchant_id,
+        profile_id,
+        source_type: enums::LedgerSourceType::Dispute,
+        source_id: dispute.dispute_id.clone(),
+        currency,
+    };
+    let postings = vec![
+        LedgerPosting::debit(enums::LedgerAccount::Chargebacks, amount),
+        LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, amount),
+    ];
+    if let Err(error) = record_transaction(state, source, postings).await {
+        logger::error!(?error, "Failed to record the dispute in the ledger");
+    }
+}
+
+/// The balance of an account on its normal side, from the net of the debits and credits posted to
+/// it
+fn get_balance(account: enums::LedgerAccount, net_debit: i64) -> MinorUnit {
+    match account.get_normal_balance() {
+        enums::LedgerEntryDirection::Debit => MinorUnit::new(net_debit),
+        enums::LedgerEntryDirection::Credit => MinorUnit::new(net_debit.saturating_neg()),
+    }
+}
+
+async fn get_account_balance(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    account: enums::LedgerAccount,
+    currency: enums::Currency,
+    as_of: time::PrimitiveDateTime,
+) -> RouterResult<MinorUnit> {
+    let net_debit = state
+        .store
+        .get_ledger_account_nets_by_profile_id(profile_id, as_of)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to aggregate ledger entries")?
+        .into_iter()
+        .find(|account_net| account_net.account == account && account_net.currency == currency)
+        .map(|account_net| account_net.net_debit)
+        .unwrap_or_default();
+
+    Ok(get_balance(account, net_debit))
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_account.get_id(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_ledger_balances(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<ledger_api::LedgerBalancesResponse> {
+    validate_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let as_of = date_time::now();
+    let mut balances = state
+        .store
+        .get_ledger_account_nets_by_profile_id(&profile_id, as_of)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to aggregate ledger entries")?
+        .into_iter()
+        .map(|account_net| ledger_api::LedgerAccountBalance {
+            account: account_net.account,
+            currency: account_net.currency,
+            balance: get_balance(account_net.account, account_net.net_debit),
+        })
+        .collect::<Vec<_>>();
+    balances.sort_by_key(|balance| (balance.currency.to_string(), balance.account.to_string()));
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_api::LedgerBalancesResponse {
+            profile_id,
+            balances,
+            as_of,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_ledger_statement(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    req: ledger_api::LedgerStatementRequest,
+) -> RouterResponse<ledger_api::LedgerStatementResponse> {
+    validate_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let limit = req.limit.unwrap_or(LEDGER_STATEMENT_DEFAULT_LIMIT);
+    when(limit == 0 || limit > LEDGER_STATEMENT_MAX_LIMIT, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit must be between 1 and {LEDGER_STATEMENT_MAX_LIMIT}"),
+        }))
+    })?;
+    let end_time = req.end_time.unwrap_or_else(date_time::now);
+    when(
+        req.start_time
+            .is_some_and(|start_time| start_time >= end_time),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "start_time must be before end_time".to_string(),
+            }))
+        },
+    )?;
+
+    let opening_balance = match req.start_time {
+        Some(start_time) => {
+            get_account_balance(&state, &profile_id, req.account, req.currency, start_time).await?
+        }
+        None => MinorUnit::zero(),
+    };
+    let closing_balance =
+        get_account_balance(&state, &profile_id, req.account, req.currency, end_time).await?;
+
+    let entries = state
+        .store
+        .list_ledger_entries_by_profile_id_account_currency(
+            &profile_id,
+            req.account,
+            req.currency,
+            req.start_time,
+            Some(end_time),
+            Some(i64::from(limit)),
+            req.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list ledger entries")?
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_api::LedgerStatementResponse {
+            profile_id,
+            account: req.account,
+            currency: req.currency,
+            start_time: req.start_time,
+            end_time,
+            opening_balance,
+            closing_balance,
+            entries,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::{borrow::Cow, sync::Arc};
+
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, routes};
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                || {},
+            )
+            .unwrap()
+    }
+
+    fn get_profile_id(profile_id: &'static str) -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from(profile_id)).unwrap()
+    }
+
+    fn get_source<'a>(
+        merchant_id: &'a id_type::MerchantId,
+        profile_id: &'a id_type::ProfileId,
+        source_type: enums::LedgerSourceType,
+        source_id: &str,
+        currency: enums::Currency,
+    ) -> LedgerSource<'a> {
+        LedgerSource {
+            merchant_id,
+            profile_id,
+            source_type,
+            source_id: source_id.to_string(),
+            currency,
+        }
+    }
+
+    /// Records a payment of the amount, funding the connector balance
+    async fn record_test_payment(
+        state: &SessionState,
+        profile_id: &id_type::ProfileId,
+        payment_id: &str,
+        amount: i64,
+        currency: enums::Currency,
+    ) -> RouterResult<()> {
+        let merchant_id = id_type::MerchantId::default();
+        let amount = MinorUnit::new(amount);
+        record_transaction(
+            state,
+            get_source(
+                &merchant_id,
+                profile_id,
+                enums::LedgerSourceType::Payment,
+                payment_id,
+                currency,
+            ),
+            vec![
+                LedgerPosting::debit(enums::LedgerAccount::ConnectorBalance, amount),
+                LedgerPosting::credit(enums::LedgerAccount::Payments, amount),
+            ],
+        )
+        .await
+    }
+
+    async fn list_entries(
+        state: &SessionState,
+        profile_id: &id_type::ProfileId,
+        account: enums::LedgerAccount,
+    ) -> Vec<storage::LedgerEntry> {
+        state
+            .store
+            .list_ledger_entries_by_profile_id_account_currency(
+                profile_id,
+                account,
+                enums::Currency::USD,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap()
+    }
+
+    fn get_time_after_recording() -> time::PrimitiveDateTime {
+        date_time::now() + time::Duration::seconds(1)
+    }
+
+    fn is_internal_server_error(result: RouterResult<()>) -> bool {
+        matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InternalServerError
+        )
+    }
+
+    #[tokio::test]
+    async fn test_record_transaction_rejects_unbalanced_postings() {
+        let state = get_session_state().await;
+        let merchant_id = id_type::MerchantId::default();
+        let profile_id = get_profile_id("pro_unbalanced");
+
+        let result = record_transaction(
+            &state,
+            get_source(
+                &merchant_id,
+                &profile_id,
+                enums::LedgerSourceType::Payout,
+                "payout_1",
+                enums::Currency::USD,
+            ),
+            vec![
+                LedgerPosting::debit(enums::LedgerAccount::Payouts, MinorUnit::new(90)),
+                LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, MinorUnit::new(100)),
+            ],
+        )
+        .await;
+
+        assert!(is_internal_server_error(result));
+        assert!(state
+            .store
+            .get_ledger_account_nets_by_profile_id(&profile_id, get_time_after_recording())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_record_transaction_rejects_negative_amounts() {
+        let state = get_session_state().await;
+        let merchant_id = id_type::MerchantId::default();
+        let profile_id = get_profile_id("pro_negative");
+
+        // The postings balance, but debit and credit the accounts the wrong way around
+        let result = record_transaction(
+            &state,
+            get_source(
+                &merchant_id,
+                &profile_id,
+                enums::LedgerSourceType::Refund,
+                "refund_1",
+                enums::Currency::USD,
+            ),
+            vec![
+                LedgerPosting::debit(enums::LedgerAccount::Refunds, MinorUnit::new(-100)),
+                LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, MinorUnit::new(-100)),
+            ],
+        )
+        .await;
+
+        assert!(is_internal_server_error(result));
+        assert!(
+            list_entries(&state, &profile_id, enums::LedgerAccount::Refunds)
+                .await
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_transaction_leaves_out_zero_postings() {
+        let state = get_session_state().await;
+        let merchant_id = id_type::MerchantId::default();
+        let profile_id = get_profile_id("pro_zero");
+
+        record_transaction(
+            &state,
+            get_source(
+                &merchant_id,
+                &profile_id,
+                enums::LedgerSourceType::Payout,
+                "payout_1",
+                enums::Currency::USD,
+            ),
+            vec![
+                LedgerPosting::debit(enums::LedgerAccount::Payouts, MinorUnit::new(100)),
+                LedgerPosting::debit(enums::LedgerAccount::Fees, MinorUnit::zero()),
+                LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, MinorUnit::new(100)),
+            ],
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            list_entries(&state, &profile_id, enums::LedgerAccount::Fees)
+                .await
+                .is_empty()
+        );
+        let payout_entries = list_entries(&state, &profile_id, enums::LedgerAccount::Payouts).await;
+        let connector_balance_entries =
+            list_entries(&state, &profile_id, enums::LedgerAccount::ConnectorBalance).await;
+        assert_eq!(payout_entries.len(), 1);
+        assert_eq!(connector_balance_entries.len(), 1);
+        assert_eq!(
+            payout_entries[0].transaction_id,
+            connector_balance_entries[0].transaction_id
+        );
+
+        // A transaction whose postings are all of a zero amount is not recorded at all
+        record_transaction(
+            &state,
+            get_source(
+                &merchant_id,
+                &profile_id,
+                enums::LedgerSourceType::Payout,
+                "payout_2",
+                enums::Currency::USD,
+            ),
+            vec![
+                LedgerPosting::debit(enums::LedgerAccount::Payouts, MinorUnit::zero()),
+                LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, MinorUnit::zero()),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            list_entries(&state, &profile_id, enums::LedgerAccount::Payouts)
+                .await
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_transaction_records_a_source_once() {
+        let state = get_session_state().await;
+        let profile_id = get_profile_id("pro_idempotent");
+
+        // The same payment reported twice, such as by both a sync and a webhook
+        record_test_payment(&state, &profile_id, "pay_1", 1000, enums::Currency::USD)
+            .await
+            .unwrap();
+        record_test_payment(&state, &profile_id, "pay_1", 1000, enums::Currency::USD)
+            .await
+            .unwrap();
+        record_test_payment(&state, &profile_id, "pay_2", 500, enums::Currency::USD)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            list_entries(&state, &profile_id, enums::LedgerAccount::ConnectorBalance)
+                .await
+                .len(),
+            2
+        );
+        assert_eq!(
+            get_account_balance(
+                &state,
+                &profile_id,
+                enums::LedgerAccount::ConnectorBalance,
+                enums::Currency::USD,
+                get_time_after_recording(),
+            )
+            .await
+            .unwrap(),
+            MinorUnit::new(1500)
+        );
+    }
+
+    #[test]
+    fn test_get_balance() {
+        // Accounts with a normal debit balance are increased by debits
+        assert_eq!(
+            get_balance(enums::LedgerAccount::ConnectorBalance, 700),
+            MinorUnit::new(700)
+        );
+        assert_eq!(
+            get_balance(enums::LedgerAccount::ConnectorBalance, -300),
+            MinorUnit::new(-300)
+        );
+        assert_eq!(
+            get_balance(enums::LedgerAccount::Refunds, 300),
+            MinorUnit::new(300)
+        );
+        // Accounts with a normal credit balance are increased by credits
+        assert_eq!(
+            get_balance(enums::LedgerAccount::Payments, -1000),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            get_balance(enums::LedgerAccount::Payments, i64::MIN),
+            MinorUnit::new(i64::MAX)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_account_balance() {
+        let state = get_session_state().await;
+        let merchant_id = id_type::MerchantId::default();
+        let profile_id = get_profile_id("pro_balances");
+        let other_profile_id = get_profile_id("pro_other");
+        let before_recording = date_time::now() - time::Duration::seconds(1);
+
+        record_test_payment(&state, &profile_id, "pay_1", 1000, enums::Currency::USD)
+            .await
+            .unwrap();
+        record_test_payment(&state, &profile_id, "pay_2", 500, enums::Currency::EUR)
+            .await
+            .unwrap();
+        record_test_payment(
+            &state,
+            &other_profile_id,
+            "pay_3",
+            900,
+            enums::Currency::USD,
+        )
+        .await
+        .unwrap();
+        record_transaction(
+            &state,
+            get_source(
+                &merchant_id,
+                &profile_id,
+                enums::LedgerSourceType::Refund,
+                "refund_1",
+                enums::Currency::USD,
+            ),
+            vec![
+                LedgerPosting::debit(enums::LedgerAccount::Refunds, MinorUnit::new(300)),
+                LedgerPosting::credit(enums::LedgerAccount::ConnectorBalance, MinorUnit::new(300)),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let as_of = get_time_after_recording();
+        let (state, profile_id) = (&state, &profile_id);
+        let get_test_balance = move |account, currency, as_of| {
+            get_account_balance(state, profile_id, account, currency, as_of)
+        };
+        assert_eq!(
+            get_test_balance(
+                enums::LedgerAccount::ConnectorBalance,
+                enums::Currency::USD,
+                as_of
+            )
+            .await
+            .unwrap(),
+            MinorUnit::new(700)
+        );
+        assert_eq!(
+            get_test_balance(enums::LedgerAccount::Payments, enums::Currency::USD, as_of)
+                .await
+                .unwrap(),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            get_test_balance(enums::LedgerAccount::Refunds, enums::Currency::USD, as_of)
+                .await
+                .unwrap(),
+            MinorUnit::new(300)
+        );
+        assert_eq!(
+            get_test_balance(
+                enums::LedgerAccount::ConnectorBalance,
+                enums::Currency::EUR,
+                as_of
+            )
+            .await
+            .unwrap(),
+            MinorUnit::new(500)
+        );
+        // Accounts without entries have a zero balance
+        assert_eq!(
+            get_test_balance(enums::LedgerAccount::Fees, enums::Currency::USD, as_of)
+                .await
+                .unwrap(),
+            MinorUnit::zero()
+        );
+        // Entries recorded after the time of the balance are left out
+        assert_eq!(
+            get_test_balance(
+                enums::LedgerAccount::ConnectorBalance,
+                enums::Currency::USD,
+                before_recording
+            )
+            .await
+            .unwrap(),
+            MinorUnit::zero()
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use api_models::link_delivery as link_delivery_api;
+use common_utils::{fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
+use external_services::messaging::{Message, MessageStatusCallback, MessagingError};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+/// Number of trailing digits of the recipient's phone number left unmasked
+const UNMASKED_PHONE_DIGITS: usize = 4;
+
+/// Details of the link to be delivered to the customer
+#[derive(Debug, Clone)]
+pub struct LinkDeliveryDetails {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub link_id: String,
+    pub link_type: enums::DeliveredLinkType,
+    pub link_url: String,
+    pub merchant_name: String,
+}
+
+/// Phone number of the customer, used when the delivery request does not specify a phone number
+#[derive(Debug, Clone)]
+pub struct CustomerPhone {
+    pub phone: Secret<String>,
+    pub phone_country_code: Option<String>,
+}
+
+fn get_status_callback_url(base_url: &str, delivery_id: &str) -> String {
+    format!("{base_url}/link_deliveries/{delivery_id}/status_callback")
+}
+
+fn get_message_body(link_details: &LinkDeliveryDetails) -> String {
+    let LinkDeliveryDetails {
+        merchant_name,
+        link_url,
+        ..
+    } = link_details;
+    match link_details.link_type {
+        enums::DeliveredLinkType::PaymentLink => {
+            format!("{merchant_name} has requested a payment. Pay securely here: {link_url}")
+        }
+        enums::DeliveredLinkType::PayoutLink => {
+            format!("{merchant_name} has sent you a payout. Claim it here: {link_url}")
+        }
+    }
+}
+
+/// Converts the phone number to the E.164 format, prefixing the country code if the number is not
+/// already in international format
+fn get_e164_phone_number(phone: &str, phone_country_code: Option<&str>) -> RouterResult<String> {
+    let phone = phone
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-' | '(' | ')'))
+        .collect::<String>();
+
+    let phone_number = if phone.starts_with('+') {
+        phone
+    } else {
+        let phone_country_code =
+            phone_country_code.get_required_value("link_delivery.phone_country_code")?;
+        format!(
+            "+{}{phone}",
+            phone_country_code.trim().trim_start_matches('+')
+        )
+    };
+
+    let is_valid = phone_number.strip_prefix('+').is_some_and(|digits| {
+        (8..=15).contains(&digits.len()) && digits.chars().all(|digit| digit.is_ascii_digit())
+    });
+    when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "link_delivery.phone must be a valid phone number".to_string(),
+        }))
+    })?;
+
+    Ok(phone_number)
+}
+
+/// Masks all but the last few digits of the phone number
+fn mask_phone_number(phone_number: &str) -> String {
+    let masked_digits = phone_number
+        .trim_start_matches('+')
+        .len()
+        .saturating_sub(UNMASKED_PHONE_DIGITS);
+    phone_number
+        .chars()
+        .filter(|character| *character != '+')
+        .enumerate()
+        .fold("+".to_string(), |mut masked, (index, digit)| {
+            masked.push(if index < masked_digits { '*' } else { digit });
+            masked
+        })
+}
+
+/// Validates the delivery request and delivers the link to the customer in the background.
+/// The returned record tracks the delivery status of the link.
+#[instrument(skip_all)]
+pub async fn deliver_link(
+    state: &SessionState,
+    link_details: LinkDeliveryDetails,
+    request: link_delivery_api::LinkDeliveryRequest,
+    customer_phone: Option<CustomerPhone>,
+) -> RouterResult<storage::LinkDelivery> {
+    when(!state.conf.messaging.is_enabled(), || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Delivering links over SMS or WhatsApp".to_string(),
+        }))
+    })?;
+
+    let (phone, phone_country_code) = match request.phone {
+        Some(phone) => (phone, request.phone_country_code),
+        None => customer_phone
+            .map(|customer_phone| {
+                (
+                    customer_phone.phone,
+                    request
+                        .phone_country_code
+                        .or(customer_phone.phone_country_code),
+                )
+            })
+            .get_required_value("link_delivery.phone")?,
+    };
+    let recipient = get_e164_phone_number(phone.peek(), phone_country_code.as_deref())?;
+
+    let now = common_utils::date_time::now();
+    let link_delivery_new = storage::LinkDeliveryNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "link_delivery"),
+        merchant_id: link_details.merchant_id.clone(),
+        profile_id: link_details.profile_id.clone(),
+        link_id: link_details.link_id.clone(),
+        link_type: link_details.link_type,
+        channel: request.channel,
+        provider: state.messaging_client.provider_name().to_string(),
+        provider_message_id: None,
+        masked_recipient: mask_phone_number(&recipient),
+        status: enums::LinkDeliveryStatus::Pending,
+        error_code: None,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let link_delivery = state
+        .store
+        .insert_link_delivery(link_delivery_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert link delivery")?;
+
+    let message = Message {
+        recipient: Secret::new(recipient),
+        body: get_message_body(&link_details),
+        channel: request.channel,
+        status_callback_url: Some(get_status_callback_url(&state.base_url, &link_delivery.id)),
+    };
+    let send_future = send_link_message(state.clone(), link_delivery.id.clone(), message);
+    tokio::spawn(
+        async move {
+            send_future
+                .await
+                .map_err(|error| logger::error!(link_delivery_error=?error))
+                .ok();
+        }
+        .in_current_span(),
+    );
+
+    Ok(link_delivery)
+}
+
+async fn send_link_message(
+    state: SessionState,
+    delivery_id: String,
+    message: Message,
+) -> RouterResult<()> {
+    let link_delivery_update = match state.messaging_client.send_message(message).await {
+        Ok(response) => storage::LinkDeliveryUpdate::MessageSent {
+            provider_message_id: response.provider_message_id,
+            status: response.status,
+        },
+        Err(error) => {
+            logger::error!(link_delivery_send_error=?error);
+            storage::LinkDeliveryUpdate::StatusUpdate {
+                status: enums::LinkDeliveryStatus::Failed,
+                error_code: None,
+                error_message: Some(error.current_context().to_string()),
+            }
+        }
+    };
+
+    state
+        .store
+        .update_link_delivery_by_delivery_id(&delivery_id, link_delivery_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update link delivery")?;
+
+    Ok(())
+}
+
+pub async fn retrieve_link_delivery(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    delivery_id: String,
+) -> RouterResponse<link_delivery_api::LinkDeliveryResponse> {
+    let link_delivery = state
+        .store
+        .find_link_delivery_by_merchant_id_delivery_id(merchant_account.get_id(), &delivery_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Link delivery not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        link_delivery_api::LinkDeliveryResponse::foreign_from(link_delivery),
+    ))
+}
+
+pub async fn list_link_deliveries(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: link_delivery_api::LinkDeliveryListConstraints,
+) -> RouterResponse<Vec<link_delivery_api::LinkDeliveryResponse>> {
+    let link_deliveries = state
+        .store
+        .list_link_deliveries_by_merchant_id_link_id(
+            merchant_account.get_id(),
+            &constraints.link_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list link deliveries")?;
+
+    Ok(services::ApplicationResponse::Json(
+        link_deliveries
+            .into_iter()
+            .map(link_delivery_api::LinkDeliveryResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Updates the delivery status of a link from a status callback of the messaging provider
+#[instrument(skip_all)]
+pub async fn handle_status_callback(
+    state: SessionState,
+    req: &actix_web::HttpRequest,
+    delivery_id: String,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<()> {
+    let db = &*state.store;
+    let link_delivery = db
+        .find_link_delivery_by_delivery_id(&delivery_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let signature = req
+        .headers()
+        .get(state.messaging_client.status_callback_signature_header())
+        .and_then(|signature| signature.to_str().ok())
+        .map(ToOwned::to_owned);
+    let callback = MessageStatusCallback {
+        url: get_status_callback_url(&state.base_url, &delivery_id),
+        signature,
+        body: body.to_vec(),
+    };
+
+    let status_update = state
+        .messaging_client
+        .verify_and_parse_status_callback(&callback)
+        .map_err(|error| {
+            let api_error = match error.current_context() {
+                MessagingError::InvalidSignature => {
+                    errors::ApiErrorResponse::WebhookAuthenticationFailed
+                }
+                _ => errors::ApiErrorResponse::WebhookBadRequest,
+            };
+            error.change_context(api_error)
+        })?;
+
+    when(
+        link_delivery
+            .provider_message_id
+            .as_ref()
+            .is_some_and(|provider_message_id| {
+                *provider_message_id != status_update.provider_message_id
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::WebhookBadRequest))
+                .attach_printable("Provider message id does not match the link delivery")
+        },
+    )?;
+
+    // Callbacks may arrive out of order, so a final status is never overwritten
+    if matches!(
+        link_delivery.status,
+        enums::LinkDeliveryStatus::Delivered | enums::LinkDeliveryStatus::Failed
+    ) {
+        return Ok(services::ApplicationResponse::StatusOk);
+    }
+
+    db.update_link_delivery_by_delivery_id(
+        &delivery_id,
+        storage::LinkDeliveryUpdate::StatusUpdate {
+            status: status_update.status,
+            error_code: status_update.error_code,
+            error_message: None,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update link delivery status")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_e164_phone_number() {
+        assert_eq!(
+            get_e164_phone_number("912 345-6789", Some("+1")).unwrap(),
+            "+19123456789"
+        );
+        assert_eq!(
+            get_e164_phone_number("+919123456789", None).unwrap(),
+            "+919123456789"
+        );
+        assert!(get_e164_phone_number("9123456789", None).is_err());
+        assert!(get_e164_phone_number("91234abc89", Some("1")).is_err());
+    }
+
+    #[test]
+    fn test_mask_phone_number() {
+        assert_eq!(mask_phone_number("+19123456789"), "+*******6789");
+    }
+}
@@ -3,20 +3,19 @@
     not(feature = "payment_methods_v2")
 ))]
 use api_models::enums as api_enums;
-use api_models::locker_migration::MigrateCardResponse;
-use common_utils::{errors::CustomResult, id_type};
-#[cfg(all(
-    any(feature = "v1", feature = "v2"),
-    not(feature = "payment_methods_v2")
-))]
-use diesel_models::enums as storage_enums;
+use api_models::locker_migration::{self as locker_migration_api, MigrateCardResponse};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{Encode, ValueExt},
+    id_type,
+};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use error_stack::FutureExt;
 use error_stack::ResultExt;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use futures::TryFutureExt;
 
-#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use super::errors::StorageErrorExt;
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
@@ -33,7 +32,12 @@ use crate::services::logger;
     not(feature = "payment_methods_v2")
 ))]
 use crate::types::api;
-use crate::{errors, routes::SessionState, services, types::domain};
+use crate::{
+    errors,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
 
 #[cfg(all(
     feature = "v2",
@@ -160,37 +164,7 @@ pub async fn call_to_locker(
             }
         };
 
-        let card_details = api::CardDetail {
-            card_number: card.card_number,
-            card_exp_month: card.card_exp_month,
-            card_exp_year: card.card_exp_year,
-            card_holder_name: card.name_on_card,
-            nick_name: card.nick_name.map(masking::Secret::new),
-            card_issuing_country: None,
-            card_network: None,
-            card_issuer: None,
-            card_type: None,
-        };
-
-        let pm_create = api::PaymentMethodCreate {
-            payment_method: pm.get_payment_method_type(),
-            payment_method_type: pm.get_payment_method_subtype(),
-            payment_method_issuer: pm.payment_method_issuer,
-            payment_method_issuer_code: pm.payment_method_issuer_code,
-            card: Some(card_details.clone()),
-            #[cfg(feature = "payouts")]
-            wallet: None,
-            #[cfg(feature = "payouts")]
-            bank_transfer: None,
-            metadata: pm.metadata,
-            customer_id: Some(pm.customer_id),
-            card_network: card.card_brand,
-            client_secret: None,
-            payment_method_data: None,
-            billing: None,
-            connector_mandate_details: None,
-            network_transaction_id: None,
-        };
+        let (pm_create, card_details) = get_payment_method_create_for_card(&pm, card);
 
         let add_card_result = cards::add_card_hs(
                 state,
@@ -238,3 +212,390 @@ pub async fn call_to_locker(
 ) -> CustomResult<usize, errors::ApiErrorResponse> {
     todo!()
 }
+
+const LOCKER_MIGRATION_TASK: &str = "LOCKER_MIGRATION";
+const LOCKER_MIGRATION_TAG: &str = "LOCKER_MIGRATION";
+
+/// The number of customers whose cards are migrated in a single batch, unless specified otherwise
+const DEFAULT_LOCKER_MIGRATION_BATCH_SIZE: u16 = 100;
+
+pub fn generate_task_id_for_locker_migration_workflow(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_{LOCKER_MIGRATION_TASK}_{}",
+        storage::ProcessTrackerRunner::LockerMigrationWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Schedules the migration of the cards stored for the customers of a merchant from the card
+/// vault to the Rust card vault. The cards are migrated in batches of customers by the
+/// process tracker, with the progress of the migration being recorded in the tracking data of the
+/// process.
+pub async fn schedule_locker_migration(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+    req: locker_migration_api::LockerMigrationRequest,
+) -> errors::RouterResponse<locker_migration_api::LockerMigrationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    db.get_merchant_key_store_by_merchant_id(
+        key_manager_state,
+        merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let tracking_data = storage::LockerMigrationTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        batch_size: req
+            .batch_size
+            .filter(|batch_size| *batch_size > 0)
+            .unwrap_or(DEFAULT_LOCKER_MIGRATION_BATCH_SIZE),
+        customers_processed: 0,
+        cards_moved: 0,
+        cards_failed: 0,
+    };
+    let process_tracker_id = generate_task_id_for_locker_migration_workflow(merchant_id);
+    let schedule_time = common_utils::date_time::now();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve locker migration task from process tracker")?;
+
+    let process = match existing_process {
+        Some(process) if process.status != storage_enums::ProcessTrackerStatus::Finish => {
+            return Err(errors::ApiErrorResponse::GenericDuplicateError {
+                message: "A locker migration is already in progress for the merchant".to_string(),
+            }
+            .into());
+        }
+        // The previous migration has finished, so the same task is rescheduled from the start
+        Some(process) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize locker migration tracking data")?;
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(schedule_time),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule locker migration task in process tracker")?
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                LOCKER_MIGRATION_TASK,
+                storage::ProcessTrackerRunner::LockerMigrationWorkflow,
+                [LOCKER_MIGRATION_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct LOCKER_MIGRATION process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting LOCKER_MIGRATION task to process_tracker for merchant_id: {}",
+                        merchant_id.get_string_repr()
+                    )
+                })?
+        }
+    };
+
+    get_locker_migration_response(process).map(services::ApplicationResponse::Json)
+}
+
+/// Retrieves the progress of the locker migration scheduled for a merchant
+pub async fn retrieve_locker_migration_status(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> errors::RouterResponse<locker_migration_api::LockerMigrationResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&generate_task_id_for_locker_migration_workflow(merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve locker migration task from process tracker")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No locker migration has been scheduled for the merchant".to_string(),
+        })?;
+
+    get_locker_migration_response(process).map(services::ApplicationResponse::Json)
+}
+
+fn get_locker_migration_response(
+    process: storage::ProcessTracker,
+) -> errors::RouterResult<locker_migration_api::LockerMigrationResponse> {
+    let tracking_data: storage::LockerMigrationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("LockerMigrationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse locker migration tracking data")?;
+
+    let status = match process.status {
+        storage_enums::ProcessTrackerStatus::Finish
+            if process.business_status == business_status::COMPLETED_BY_PT =>
+        {
+            locker_migration_api::LockerMigrationStatus::Completed
+        }
+        storage_enums::ProcessTrackerStatus::Finish => {
+            locker_migration_api::LockerMigrationStatus::Failed
+        }
+        _ if tracking_data.customers_processed == 0 => {
+            locker_migration_api::LockerMigrationStatus::Scheduled
+        }
+        _ => locker_migration_api::LockerMigrationStatus::InProgress,
+    };
+
+    Ok(locker_migration_api::LockerMigrationResponse {
+        merchant_id: tracking_data.merchant_id,
+        status,
+        customers_processed: tracking_data.customers_processed,
+        cards_moved: tracking_data.cards_moved,
+        cards_failed: tracking_data.cards_failed,
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    })
+}
+
+/// The outcome of migrating the cards of a batch of customers
+pub struct LockerMigrationBatchResult {
+    pub customers_processed: u32,
+    pub cards_moved: usize,
+    pub cards_failed: usize,
+}
+
+/// Copies the cards of the next batch of customers of the merchant to the Rust card vault,
+/// updating the locker references of the payment methods whose cards were stored under a
+/// different reference.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn migrate_locker_batch(
+    state: &SessionState,
+    tracking_data: &storage::LockerMigrationTrackingData,
+) -> errors::RouterResult<LockerMigrationBatchResult> {
+    use crate::db::customers::CustomerListConstraints;
+
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = &tracking_data.merchant_id;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let constraints = CustomerListConstraints {
+        limit: tracking_data.batch_size,
+        offset: Some(tracking_data.customers_processed),
+    };
+    let customers = db
+        .list_customers_by_merchant_id(key_manager_state, merchant_id, &key_store, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list customers for locker migration")?;
+
+    let customers_processed = u32::try_from(customers.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of customers in the batch exceeds the supported range")?;
+    let mut cards_moved = 0;
+    let mut cards_failed = 0;
+
+    for customer in customers {
+        let payment_methods = db
+            .find_payment_method_by_customer_id_merchant_id_list(
+                key_manager_state,
+                &key_store,
+                &customer.customer_id,
+                merchant_id,
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list payment methods for locker migration")?;
+
+        for payment_method in payment_methods.into_iter().filter(|pm| {
+            matches!(
+                pm.get_payment_method_type(),
+                Some(storage_enums::PaymentMethod::Card)
+            )
+        }) {
+            let payment_method_id = payment_method.payment_method_id.clone();
+            match migrate_card_to_rust_locker(
+                state,
+                &key_store,
+                &merchant_account,
+                &customer.customer_id,
+                payment_method,
+            )
+            .await
+            {
+                Ok(()) => cards_moved += 1,
+                Err(error) => {
+                    cards_failed += 1;
+                    logger::error!(
+                        ?error,
+                        "Failed to migrate card to Rust locker for merchant_id: {merchant_id:?}, payment_method_id: {payment_method_id}"
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(LockerMigrationBatchResult {
+        customers_processed,
+        cards_moved,
+        cards_failed,
+    })
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+pub async fn migrate_locker_batch(
+    _state: &SessionState,
+    _tracking_data: &storage::LockerMigrationTrackingData,
+) -> errors::RouterResult<LockerMigrationBatchResult> {
+    todo!()
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+async fn migrate_card_to_rust_locker(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &id_type::CustomerId,
+    payment_method: domain::PaymentMethod,
+) -> errors::RouterResult<()> {
+    let card_reference = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.payment_method_id.clone());
+
+    let card = cards::get_card_from_hs_locker(
+        state,
+        customer_id,
+        merchant_account.get_id(),
+        &card_reference,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch card from the card vault")?;
+
+    let (pm_create, card_details) = get_payment_method_create_for_card(&payment_method, card);
+
+    let (add_card_resp, _duplication_check) = cards::add_card_hs(
+        state,
+        pm_create,
+        &card_details,
+        customer_id,
+        merchant_account,
+        api_enums::LockerChoice::RustCardVault,
+        Some(&card_reference),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add card to the Rust card vault")?;
+
+    // The Rust card vault returns the reference of the card stored previously if the card is a
+    // duplicate, in which case the payment method has to refer to that card
+    if add_card_resp.payment_method_id != card_reference {
+        let pm_update = storage::PaymentMethodUpdate::AdditionalDataUpdate {
+            payment_method_data: None,
+            status: None,
+            locker_id: Some(add_card_resp.payment_method_id),
+            payment_method: None,
+            payment_method_type: None,
+            payment_method_issuer: None,
+            network_token_requestor_reference_id: None,
+            network_token_locker_id: None,
+            network_token_payment_method_data: None,
+        };
+        state
+            .store
+            .update_payment_method(
+                &state.into(),
+                key_store,
+                payment_method,
+                pm_update,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the locker reference of the payment method")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_payment_method_create_for_card(
+    payment_method: &domain::PaymentMethod,
+    card: api_models::payment_methods::Card,
+) -> (api::PaymentMethodCreate, api::CardDetail) {
+    let card_details = api::CardDetail {
+        card_number: card.card_number,
+        card_exp_month: card.card_exp_month,
+        card_exp_year: card.card_exp_year,
+        card_holder_name: card.name_on_card,
+        nick_name: card.nick_name.map(masking::Secret::new),
+        card_issuing_country: None,
+        card_network: None,
+        card_issuer: None,
+        card_type: None,
+    };
+
+    let pm_create = api::PaymentMethodCreate {
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        card: Some(card_details.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        metadata: payment_method.metadata.clone(),
+        customer_id: Some(payment_method.customer_id.clone()),
+        card_network: card.card_brand,
+        client_secret: None,
+        payment_method_data: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    (pm_create, card_details)
+}
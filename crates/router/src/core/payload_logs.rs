@@ -0,0 +1,214 @@
+use api_models::payload_logs::PayloadLogResponse;
+#[cfg(feature = "olap")]
+use api_models::payload_logs::{
+    PayloadLogListConstraints, PayloadLogListResponse, PayloadLogRetrieveRequest,
+};
+use common_utils::events::ApiEventsType;
+#[cfg(feature = "olap")]
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+
+#[cfg(feature = "olap")]
+use crate::{core::errors::RouterResponse, services};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    services::authentication::AuthenticationType,
+};
+
+#[cfg(feature = "olap")]
+const DEFAULT_PAYLOAD_LOG_LIST_LIMIT: u16 = 20;
+#[cfg(feature = "olap")]
+const MAX_PAYLOAD_LOG_LIST_LIMIT: u16 = 100;
+
+/// Whether the payloads of a request are logged, which is the case for the requests made to the
+/// payment APIs and to the admin APIs, other than those retrieving the payload logs themselves
+pub fn is_payload_logged(event_type: &ApiEventsType, auth_type: &AuthenticationType) -> bool {
+    if matches!(event_type, ApiEventsType::PayloadLog) {
+        return false;
+    }
+
+    matches!(
+        event_type,
+        ApiEventsType::Payment { .. }
+            | ApiEventsType::Refund { .. }
+            | ApiEventsType::PaymentRedirectionResponse { .. }
+            | ApiEventsType::BusinessProfile { .. }
+    ) || matches!(
+        auth_type,
+        AuthenticationType::AdminApiKey
+            | AuthenticationType::AdminApiAuthWithMerchantId { .. }
+            | AuthenticationType::ScopedAdminApiKey { .. }
+    )
+}
+
+/// Logs the redacted payloads of a request in the background, so that the request is not delayed.
+/// Failures are only logged, since logging must not affect the request being logged.
+pub fn record_payload_log(state: &SessionState, payload_log: PayloadLogResponse) {
+    let state = state.clone();
+    tokio::spawn(
+        async move {
+            store_payload_log(&state, payload_log)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to store payload log"))
+                .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+#[instrument(skip_all)]
+async fn store_payload_log(
+    state: &SessionState,
+    payload_log: PayloadLogResponse,
+) -> RouterResult<()> {
+    let config = &state.conf.payload_logging;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let merchant_id = payload_log.merchant_id.clone();
+    let request_id = payload_log.request_id.clone();
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &merchant_id.get_payload_log_key(&request_id),
+            payload_log,
+            config.retention_in_secs,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store payload log")?;
+
+    let index_key = merchant_id.get_payload_log_index_key();
+    redis_conn
+        .append_elements_to_list(&index_key, vec![request_id])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payload log to the index of the merchant")?;
+    redis_conn
+        .set_expiry(&index_key, config.retention_in_secs)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set expiry of payload log index")?;
+
+    let index_length = redis_conn
+        .get_list_length(&index_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch length of payload log index")?;
+    if index_length > config.max_entries_per_merchant {
+        let dropped_request_ids = redis_conn
+            .lpop_list_elements(
+                &index_key,
+                Some(index_length - config.max_entries_per_merchant),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to drop the oldest payload logs from the index")?;
+        redis_conn
+            .delete_multiple_keys(
+                dropped_request_ids
+                    .iter()
+                    .map(|request_id| merchant_id.get_payload_log_key(request_id))
+                    .collect(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the oldest payload logs")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn retrieve_payload_log(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: PayloadLogRetrieveRequest,
+) -> RouterResponse<PayloadLogResponse> {
+    let request_id = request.request_id;
+    let payload_log = get_payload_log(&state, &merchant_id, &request_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payload log of request {request_id} not found"),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(payload_log))
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn list_payload_logs(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    constraints: PayloadLogListConstraints,
+) -> RouterResponse<PayloadLogListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(DEFAULT_PAYLOAD_LOG_LIST_LIMIT)
+        .clamp(1, MAX_PAYLOAD_LOG_LIST_LIMIT);
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let request_ids = redis_conn
+        .get_list_elements(
+            &merchant_id.get_payload_log_index_key(),
+            -i64::from(limit),
+            -1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payload log index")?;
+
+    let mut payload_logs = Vec::with_capacity(request_ids.len());
+    for request_id in request_ids.iter().rev() {
+        // The payload logs past their retention are skipped, which may still be in the index
+        if let Some(payload_log) = get_payload_log(&state, &merchant_id, request_id).await? {
+            payload_logs.push(payload_log);
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        PayloadLogListResponse {
+            count: payload_logs.len(),
+            data: payload_logs,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+async fn get_payload_log(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request_id: &str,
+) -> RouterResult<Option<PayloadLogResponse>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    match redis_conn
+        .get_and_deserialize_key::<PayloadLogResponse>(
+            &merchant_id.get_payload_log_key(request_id),
+            "PayloadLogResponse",
+        )
+        .await
+    {
+        Ok(payload_log) => Ok(Some(payload_log)),
+        Err(error) if error.current_context() == &redis_interface::errors::RedisError::NotFound => {
+            Ok(None)
+        }
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to fetch payload log"),
+    }
+}
@@ -0,0 +1,172 @@
+use api_models::archival as archival_api;
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::ResultExt;
+
+use crate::{errors, routes::SessionState, services, types::storage};
+
+const PAYMENT_ARCHIVAL_TASK: &str = "PAYMENT_ARCHIVAL";
+const PAYMENT_ARCHIVAL_TAG: &str = "PAYMENT_ARCHIVAL";
+
+/// The number of payments archived in a single batch, unless specified otherwise
+const DEFAULT_PAYMENT_ARCHIVAL_BATCH_SIZE: u16 = 1000;
+
+/// The interval between consecutive runs of the archival job, unless specified otherwise
+const DEFAULT_PAYMENT_ARCHIVAL_INTERVAL_IN_HOURS: u16 = 24;
+
+pub fn generate_task_id_for_payment_archival_workflow() -> String {
+    format!(
+        "{}_{PAYMENT_ARCHIVAL_TASK}",
+        storage::ProcessTrackerRunner::PaymentArchivalWorkflow
+    )
+}
+
+/// Schedules the recurring job which moves the payments in a terminal status, created before the
+/// retention period, to the archive tables. If the job has already been scheduled, its
+/// configuration is updated and the job is run immediately, retaining the progress recorded so far.
+pub async fn schedule_payment_archival(
+    state: SessionState,
+    req: archival_api::PaymentArchivalRequest,
+) -> errors::RouterResponse<archival_api::PaymentArchivalResponse> {
+    if req.retention_period_in_days == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retention_period_in_days must be greater than zero".to_string(),
+        }
+        .into());
+    }
+
+    let db = state.store.as_ref();
+    let process_tracker_id = generate_task_id_for_payment_archival_workflow();
+    let schedule_time = common_utils::date_time::now();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payment archival task from process tracker")?;
+
+    let mut tracking_data = storage::PaymentArchivalTrackingData {
+        retention_period_in_days: req.retention_period_in_days,
+        batch_size: req
+            .batch_size
+            .filter(|batch_size| *batch_size > 0)
+            .unwrap_or(DEFAULT_PAYMENT_ARCHIVAL_BATCH_SIZE),
+        interval_in_hours: req
+            .interval_in_hours
+            .filter(|interval_in_hours| *interval_in_hours > 0)
+            .unwrap_or(DEFAULT_PAYMENT_ARCHIVAL_INTERVAL_IN_HOURS),
+        payments_archived: 0,
+        last_completed_run_at: None,
+    };
+
+    let process = match existing_process {
+        Some(process)
+            if matches!(
+                process.status,
+                storage_enums::ProcessTrackerStatus::Processing
+                    | storage_enums::ProcessTrackerStatus::ProcessStarted
+            ) =>
+        {
+            return Err(errors::ApiErrorResponse::GenericDuplicateError {
+                message: "The payment archival job is currently running, please retry later"
+                    .to_string(),
+            }
+            .into());
+        }
+        Some(process) => {
+            let existing_tracking_data = parse_tracking_data(&process)?;
+            tracking_data.payments_archived = existing_tracking_data.payments_archived;
+            tracking_data.last_completed_run_at = existing_tracking_data.last_completed_run_at;
+
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize payment archival tracking data")?;
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(schedule_time),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule payment archival task in process tracker")?
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                PAYMENT_ARCHIVAL_TASK,
+                storage::ProcessTrackerRunner::PaymentArchivalWorkflow,
+                [PAYMENT_ARCHIVAL_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct PAYMENT_ARCHIVAL process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting PAYMENT_ARCHIVAL task to process_tracker",
+                )?
+        }
+    };
+
+    get_payment_archival_response(process).map(services::ApplicationResponse::Json)
+}
+
+/// Retrieves the configuration and progress of the payment archival job
+pub async fn retrieve_payment_archival_status(
+    state: SessionState,
+) -> errors::RouterResponse<archival_api::PaymentArchivalResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&generate_task_id_for_payment_archival_workflow())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payment archival task from process tracker")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "The payment archival job has not been scheduled".to_string(),
+        })?;
+
+    get_payment_archival_response(process).map(services::ApplicationResponse::Json)
+}
+
+fn parse_tracking_data(
+    process: &storage::ProcessTracker,
+) -> errors::RouterResult<storage::PaymentArchivalTrackingData> {
+    process
+        .tracking_data
+        .clone()
+        .parse_value("PaymentArchivalTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment archival tracking data")
+}
+
+fn get_payment_archival_response(
+    process: storage::ProcessTracker,
+) -> errors::RouterResult<archival_api::PaymentArchivalResponse> {
+    let tracking_data = parse_tracking_data(&process)?;
+
+    // The job is only finished when it has exceeded its retries, and is not run again unless it
+    // is rescheduled
+    let next_run_at = (process.status != storage_enums::ProcessTrackerStatus::Finish)
+        .then_some(process.schedule_time)
+        .flatten();
+
+    Ok(archival_api::PaymentArchivalResponse {
+        retention_period_in_days: tracking_data.retention_period_in_days,
+        batch_size: tracking_data.batch_size,
+        interval_in_hours: tracking_data.interval_in_hours,
+        payments_archived: tracking_data.payments_archived,
+        last_completed_run_at: tracking_data.last_completed_run_at,
+        next_run_at,
+        created_at: process.created_at,
+    })
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod template;
 pub mod validator;
 use actix_web::http::header;
 use api_models::{
@@ -14,12 +16,18 @@ use futures::future;
 use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
 use masking::{PeekInterface, Secret};
 use router_env::logger;
+#[cfg(feature = "v1")]
+use scheduler::utils as pt_utils;
 use time::PrimitiveDateTime;
 
+#[cfg(feature = "v1")]
+use super::email_templates;
 use super::{
     errors::{self, RouterResult, StorageErrorExt},
     payments::helpers,
 };
+#[cfg(feature = "v1")]
+use crate::db::StorageInterface;
 use crate::{
     consts::{
         self, DEFAULT_ALLOWED_DOMAINS, DEFAULT_BACKGROUND_COLOR, DEFAULT_DISPLAY_SDK_ONLY,
@@ -34,9 +42,10 @@ use crate::{
     types::{
         api::payment_link::PaymentLinkResponseExt,
         domain,
-        storage::{enums as storage_enums, payment_link::PaymentLink},
+        storage::{self, enums as storage_enums, payment_link::PaymentLink},
         transformers::ForeignFrom,
     },
+    utils,
 };
 
 pub async fn retrieve_payment_link(
@@ -286,6 +295,7 @@ pub async fn initiate_secure_payment_link_flow(
     key_store: domain::MerchantKeyStore,
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
+    link_token: Option<String>,
     request_headers: &header::HeaderMap,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), request_headers)?
@@ -300,6 +310,8 @@ pub async fn initiate_secure_payment_link_flow(
     )
     .await?;
 
+    validator::validate_payment_link_token(&payment_link, link_token.as_deref())?;
+
     validator::validate_secure_payment_link_render_request(
         request_headers,
         &payment_link,
@@ -387,11 +399,12 @@ pub async fn initiate_payment_link_flow(
     key_store: domain::MerchantKeyStore,
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
+    link_token: Option<String>,
     request_headers: &header::HeaderMap,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), request_headers)?
         .map(|val| val.to_string());
-    let (_, payment_details, payment_link_config) = form_payment_link_data(
+    let (payment_link, payment_details, payment_link_config) = form_payment_link_data(
         &state,
         merchant_account,
         key_store,
@@ -401,6 +414,8 @@ pub async fn initiate_payment_link_flow(
     )
     .await?;
 
+    validator::validate_payment_link_token(&payment_link, link_token.as_deref())?;
+
     let css_script = get_color_scheme_css(&payment_link_config);
     let js_script = get_js_script(&payment_details)?;
 
@@ -501,6 +516,311 @@ pub async fn list_payment_link(
     Ok(services::ApplicationResponse::Json(payment_link_list))
 }
 
+async fn find_merchant_payment_link(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_link_id: &str,
+) -> RouterResult<PaymentLink> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    if &payment_link.merchant_id != merchant_account.get_id() {
+        return Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound))
+            .attach_printable("Payment link does not belong to the merchant making the request");
+    }
+
+    Ok(payment_link)
+}
+
+fn validate_payment_link_not_deactivated(payment_link: &PaymentLink) -> RouterResult<()> {
+    if payment_link.deactivated_at.is_some() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payment link {} has been deactivated",
+                payment_link.payment_link_id
+            ),
+        }));
+    }
+    Ok(())
+}
+
+/// Appends the link token to a payment link URL, replacing any token from a previous regeneration
+fn add_link_token_to_url(link: &str, link_token: &str) -> RouterResult<String> {
+    let mut url = url::Url::parse(link)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment link URL")?;
+
+    let query_pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "link_token")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(query_pairs)
+        .append_pair("link_token", link_token);
+
+    Ok(url.to_string())
+}
+
+pub async fn deactivate_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let payment_link =
+        find_merchant_payment_link(&state, &merchant_account, &payment_link_id).await?;
+    validate_payment_link_not_deactivated(&payment_link)?;
+
+    // Pulling in the fulfilment time marks the link as expired for both the render and status flows
+    let deactivated_at = common_utils::date_time::now();
+    let fulfilment_time = payment_link
+        .fulfilment_time
+        .map_or(deactivated_at, |fulfilment_time| {
+            fulfilment_time.min(deactivated_at)
+        });
+
+    let updated_payment_link = state
+        .store
+        .update_payment_link_by_payment_link_id(
+            &payment_link_id,
+            storage::PaymentLinkUpdate::Deactivate {
+                deactivated_at,
+                fulfilment_time,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let response = api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(
+        updated_payment_link,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v1")]
+pub async fn update_payment_link_expiry(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_link_id: String,
+    req: api_models::payments::PaymentLinkExpiryUpdateRequest,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    helpers::validate_session_expiry(req.session_expiry)?;
+
+    let payment_link =
+        find_merchant_payment_link(&state, &merchant_account, &payment_link_id).await?;
+    validate_payment_link_not_deactivated(&payment_link)?;
+
+    let updated_payment_link = extend_payment_link_expiry(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_link,
+        req.session_expiry,
+    )
+    .await?;
+
+    let response = api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(
+        updated_payment_link,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Moves the expiry of the payment link to `session_expiry` seconds from now
+#[cfg(feature = "v1")]
+async fn extend_payment_link_expiry(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_link: &PaymentLink,
+    session_expiry: u32,
+) -> RouterResult<PaymentLink> {
+    let db = &*state.store;
+    let session_expiry = common_utils::date_time::now()
+        .saturating_add(time::Duration::seconds(i64::from(session_expiry)));
+
+    // The client secret of the payment intent is validated against its session expiry, so it
+    // has to be moved along with the payment link for the link to remain usable
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_link.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_intent(
+        &state.into(),
+        payment_intent,
+        storage::PaymentIntentUpdate::SessionExpiryUpdate {
+            session_expiry,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_link_by_payment_link_id(
+        &payment_link.payment_link_id,
+        storage::PaymentLinkUpdate::ExpiryUpdate {
+            fulfilment_time: session_expiry,
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+}
+
+pub async fn regenerate_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let payment_link =
+        find_merchant_payment_link(&state, &merchant_account, &payment_link_id).await?;
+    validate_payment_link_not_deactivated(&payment_link)?;
+
+    let updated_payment_link = regenerate_link_token(&state, &payment_link).await?;
+
+    let response = api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(
+        updated_payment_link,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Issues a new link token for the payment link, so that the previously shared URLs of the link
+/// can no longer be used
+async fn regenerate_link_token(
+    state: &SessionState,
+    payment_link: &PaymentLink,
+) -> RouterResult<PaymentLink> {
+    let link_token = utils::generate_id(consts::ID_LENGTH, "pltkn");
+    let link_to_pay = add_link_token_to_url(&payment_link.link_to_pay, &link_token)?;
+    let secure_link = payment_link
+        .secure_link
+        .as_deref()
+        .map(|secure_link| add_link_token_to_url(secure_link, &link_token))
+        .transpose()?;
+
+    state
+        .store
+        .update_payment_link_by_payment_link_id(
+            &payment_link.payment_link_id,
+            storage::PaymentLinkUpdate::LinkRegenerate {
+                link_to_pay,
+                secure_link,
+                link_token,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+}
+
+/// Schedules the task which emits the `payment_link_abandoned` event if the payment link expires
+/// without the payment being completed
+#[cfg(feature = "v1")]
+pub async fn add_payment_link_expiry_task(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_id: &common_utils::id_type::PaymentId,
+    payment_link_id: &str,
+    expires_at: PrimitiveDateTime,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::PaymentLinkExpiryTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        payment_id: payment_id.to_owned(),
+        payment_link_id: payment_link_id.to_owned(),
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentLinkExpiryWorkflow;
+    let task = "PAYMENT_LINK_EXPIRY";
+    let tag = ["PAYMENT_LINK", "PAYMENT"];
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, payment_link_id, merchant_id);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        expires_at,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Regenerates an abandoned payment link with a fresh expiry and emails it to the customer. The
+/// link is only regenerated if the customer of the payment has an email address to send it to.
+#[cfg(feature = "v1")]
+pub async fn recover_abandoned_payment_link(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    abandoned_cart_config: &diesel_models::business_profile::PaymentLinkAbandonedCartConfig,
+    payment_link: &PaymentLink,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+) -> RouterResult<()> {
+    let customer = match customer_id {
+        Some(customer_id) => state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &state.into(),
+                customer_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer of the payment")?,
+        None => None,
+    };
+    if customer
+        .as_ref()
+        .and_then(|customer| customer.email.as_ref())
+        .is_none()
+    {
+        logger::debug!(
+            payment_link_id = %payment_link.payment_link_id,
+            "Abandoned payment link not recovered as the customer does not have an email address"
+        );
+        return Ok(());
+    }
+
+    let payment_link = regenerate_link_token(state, payment_link).await?;
+    let payment_link = extend_payment_link_expiry(
+        state,
+        merchant_account,
+        key_store,
+        &payment_link,
+        abandoned_cart_config
+            .recovery_link_expiry
+            .unwrap_or(consts::DEFAULT_PAYMENT_LINK_RECOVERY_EXPIRY),
+    )
+    .await?;
+
+    email_templates::send_payment_link_recovery(
+        state.clone(),
+        merchant_account.clone(),
+        payment_link,
+        customer,
+    )
+    .await
+}
+
 pub fn check_payment_link_status(
     payment_link_expiry: PrimitiveDateTime,
 ) -> api_models::payments::PaymentLinkStatus {
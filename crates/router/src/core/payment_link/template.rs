@@ -0,0 +1,542 @@
+use api_models::payments::{
+    PaymentLinkTemplateCreateRequest, PaymentLinkTemplateListConstraints,
+    PaymentLinkTemplateListResponse, PaymentLinkTemplateResponse, PaymentLinkTemplateUpdateRequest,
+    PaymentLinkTemplateUsageResponse, RetrievePaymentLinkResponse,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    fp_utils::when,
+    types::{AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForCore},
+};
+use error_stack::{report, ResultExt};
+use futures::future;
+use masking::PeekInterface;
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_link,
+        payments::{self, helpers},
+        utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api::{self, payment_link::PaymentLinkResponseExt},
+        domain, storage,
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+fn validate_payment_link_template_create_request(
+    req: &PaymentLinkTemplateCreateRequest,
+) -> RouterResult<()> {
+    match req.amount {
+        Some(amount) => {
+            when(amount <= MinorUnit::zero(), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "amount should be greater than 0".to_string(),
+                }))
+            })?;
+            when(req.min_amount.is_some() || req.max_amount.is_some(), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "min_amount and max_amount can be provided only when amount is not fixed"
+                            .to_string(),
+                }))
+            })?;
+        }
+        None => {
+            when(
+                req.min_amount
+                    .is_some_and(|min_amount| min_amount <= MinorUnit::zero()),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "min_amount should be greater than 0".to_string(),
+                    }))
+                },
+            )?;
+            when(
+                req.min_amount
+                    .zip(req.max_amount)
+                    .is_some_and(|(min_amount, max_amount)| min_amount > max_amount),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "min_amount should not be greater than max_amount".to_string(),
+                    }))
+                },
+            )?;
+        }
+    }
+
+    if let Some(session_expiry) = req.session_expiry {
+        helpers::validate_session_expiry(session_expiry)?;
+    }
+
+    validate_usage_limit_and_expiry(req.usage_limit, req.expires_at)
+}
+
+fn validate_usage_limit_and_expiry(
+    usage_limit: Option<u32>,
+    expires_at: Option<time::PrimitiveDateTime>,
+) -> RouterResult<()> {
+    when(usage_limit == Some(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "usage_limit should be greater than 0".to_string(),
+        }))
+    })?;
+
+    when(
+        expires_at.is_some_and(|expires_at| expires_at <= common_utils::date_time::now()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "expires_at should be a time in the future".to_string(),
+            }))
+        },
+    )
+}
+
+fn convert_usage_limit(usage_limit: Option<u32>) -> RouterResult<Option<i32>> {
+    usage_limit.map(i32::try_from).transpose().change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "usage_limit",
+        },
+    )
+}
+
+pub async fn create_payment_link_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentLinkTemplateCreateRequest,
+) -> RouterResponse<PaymentLinkTemplateResponse> {
+    validate_payment_link_template_create_request(&req)?;
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|name| name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+
+    let (_, domain_name) = payment_link::get_payment_link_config_based_on_priority(
+        req.payment_link_config.clone(),
+        business_profile.payment_link_config,
+        merchant_name,
+        state.base_url.clone(),
+        None,
+    )?;
+
+    let template_id = utils::generate_id(consts::ID_LENGTH, "plt");
+    let link_to_pay = format!(
+        "{}/payment_link/t/{}/{}",
+        domain_name,
+        merchant_account.get_id().get_string_repr(),
+        template_id,
+    );
+
+    let payment_link_config = req
+        .payment_link_config
+        .as_ref()
+        .map(|payment_link_config| payment_link_config.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        })?;
+
+    let now = common_utils::date_time::now();
+    let payment_link_template_new = storage::PaymentLinkTemplateNew {
+        id: template_id,
+        merchant_id: merchant_account.get_id().to_owned(),
+        profile_id,
+        link_to_pay,
+        amount: req.amount,
+        min_amount: req.min_amount,
+        max_amount: req.max_amount,
+        currency: req.currency,
+        description: req.description,
+        payment_link_config,
+        session_expiry: req
+            .session_expiry
+            .map(i32::try_from)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "session_expiry",
+            })?,
+        usage_limit: convert_usage_limit(req.usage_limit)?,
+        usage_count: 0,
+        is_active: true,
+        expires_at: req.expires_at,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let payment_link_template = db
+        .insert_payment_link_template(payment_link_template_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "payment link template already exists".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentLinkTemplateResponse::foreign_from(payment_link_template),
+    ))
+}
+
+pub async fn retrieve_payment_link_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    template_id: String,
+) -> RouterResponse<PaymentLinkTemplateResponse> {
+    let payment_link_template = state
+        .store
+        .find_payment_link_template_by_merchant_id_template_id(
+            merchant_account.get_id(),
+            &template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentLinkTemplateResponse::foreign_from(payment_link_template),
+    ))
+}
+
+pub async fn list_payment_link_templates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: PaymentLinkTemplateListConstraints,
+) -> RouterResponse<PaymentLinkTemplateListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into())
+        .min(consts::DEFAULT_LIST_API_LIMIT.into());
+
+    let payment_link_templates = state
+        .store
+        .list_payment_link_templates_by_merchant_id(
+            merchant_account.get_id(),
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment link templates")?;
+
+    let data: Vec<_> = payment_link_templates
+        .into_iter()
+        .map(PaymentLinkTemplateResponse::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentLinkTemplateListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+pub async fn update_payment_link_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    template_id: String,
+    req: PaymentLinkTemplateUpdateRequest,
+) -> RouterResponse<PaymentLinkTemplateResponse> {
+    validate_usage_limit_and_expiry(req.usage_limit, req.expires_at)?;
+
+    let payment_link_template = state
+        .store
+        .update_payment_link_template_by_merchant_id_template_id(
+            merchant_account.get_id(),
+            &template_id,
+            storage::PaymentLinkTemplateUpdate::Update {
+                description: req.description,
+                usage_limit: convert_usage_limit(req.usage_limit)?,
+                is_active: req.is_active,
+                expires_at: req.expires_at,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentLinkTemplateResponse::foreign_from(payment_link_template),
+    ))
+}
+
+pub async fn list_payment_link_template_usages(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    template_id: String,
+) -> RouterResponse<PaymentLinkTemplateUsageResponse> {
+    let db = state.store.as_ref();
+
+    let payment_link_template = db
+        .find_payment_link_template_by_merchant_id_template_id(
+            merchant_account.get_id(),
+            &template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let payment_links = db
+        .find_payment_links_by_merchant_id_template_id(
+            merchant_account.get_id(),
+            &payment_link_template.id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment links created from the template")?;
+
+    let data = future::try_join_all(
+        payment_links
+            .into_iter()
+            .map(RetrievePaymentLinkResponse::from_db_payment_link),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentLinkTemplateUsageResponse {
+            template_id: payment_link_template.id,
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+fn validate_payment_link_template_usable(
+    payment_link_template: &storage::PaymentLinkTemplate,
+) -> RouterResult<()> {
+    let message = if !payment_link_template.is_active {
+        Some("This payment link is no longer active")
+    } else if payment_link_template
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= common_utils::date_time::now())
+    {
+        Some("This payment link has expired")
+    } else if payment_link_template
+        .usage_limit
+        .is_some_and(|usage_limit| payment_link_template.usage_count >= usage_limit)
+    {
+        Some("This payment link has reached its usage limit")
+    } else {
+        None
+    };
+
+    message.map_or(Ok(()), |message| {
+        Err(report!(errors::ApiErrorResponse::LinkConfigurationError {
+            message: message.to_string(),
+        }))
+    })
+}
+
+fn get_payment_amount_for_template(
+    payment_link_template: &storage::PaymentLinkTemplate,
+    amount: Option<StringMajorUnit>,
+) -> RouterResult<Option<MinorUnit>> {
+    if let Some(amount) = payment_link_template.amount {
+        return Ok(Some(amount));
+    }
+
+    let Some(amount) = amount else {
+        return Ok(None);
+    };
+
+    let amount = StringMajorUnitForCore
+        .convert_back(amount, payment_link_template.currency)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })?;
+
+    let is_below_minimum = payment_link_template
+        .min_amount
+        .map_or(amount <= MinorUnit::zero(), |min_amount| {
+            amount < min_amount
+        });
+    let is_above_maximum = payment_link_template
+        .max_amount
+        .is_some_and(|max_amount| amount > max_amount);
+
+    when(is_below_minimum || is_above_maximum, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount is not within the range allowed for this payment link".to_string(),
+        }))
+    })?;
+
+    Ok(Some(amount))
+}
+
+fn get_amount_entry_form(payment_link_template: &storage::PaymentLinkTemplate) -> Vec<u8> {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Enter amount</title>
+</head>
+<body>
+<form method="get">
+<label for="amount">Amount ({currency})</label>
+<input id="amount" name="amount" type="number" step="any" min="0" required>
+<button type="submit">Pay</button>
+</form>
+</body>
+</html>"#,
+        currency = payment_link_template.currency,
+    )
+    .into_bytes()
+}
+
+/// Creates a new payment for every visit of a payment link template and redirects the customer
+/// to the payment link generated for it
+pub async fn initiate_payment_link_template_flow(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    template_id: String,
+    amount: Option<StringMajorUnit>,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id().to_owned();
+
+    let payment_link_template = db
+        .find_payment_link_template_by_merchant_id_template_id(&merchant_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    validate_payment_link_template_usable(&payment_link_template)?;
+
+    let Some(amount) = get_payment_amount_for_template(&payment_link_template, amount)? else {
+        return Ok(services::ApplicationResponse::FileData((
+            get_amount_entry_form(&payment_link_template),
+            mime::TEXT_HTML_UTF_8,
+        )));
+    };
+
+    // Reserve a use of the template before creating the payment, the update fails if another
+    // visit consumed the same use concurrently
+    db.increment_payment_link_template_usage_count(
+        &merchant_id,
+        &payment_link_template.id,
+        payment_link_template.usage_count,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::LinkConfigurationError {
+        message: "This payment link is being used concurrently, please try again".to_string(),
+    })?;
+
+    let payment_link_config = payment_link_template
+        .payment_link_config
+        .clone()
+        .map(|payment_link_config| {
+            payment_link_config.parse_value::<api_models::payments::PaymentCreatePaymentLinkConfig>(
+                "PaymentCreatePaymentLinkConfig",
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment link config of the template")?;
+
+    let payments_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(
+            common_utils::id_type::PaymentId::default(),
+        )),
+        amount: Some(amount.into()),
+        currency: Some(payment_link_template.currency),
+        profile_id: Some(payment_link_template.profile_id.clone()),
+        description: payment_link_template.description.clone(),
+        confirm: Some(false),
+        payment_link: Some(true),
+        payment_link_config,
+        session_expiry: payment_link_template
+            .session_expiry
+            .and_then(|session_expiry| u32::try_from(session_expiry).ok()),
+        ..Default::default()
+    };
+
+    let payments_response = match Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        None,
+        key_store,
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response)
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while creating payment from the template"),
+    }?;
+
+    let created_payment_link = payments_response
+        .payment_link
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment link was not created for the payment")?;
+
+    db.update_payment_link_by_payment_link_id(
+        &created_payment_link.payment_link_id,
+        storage::PaymentLinkUpdate::TemplateIdUpdate {
+            payment_link_template_id: payment_link_template.id.clone(),
+        },
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to associate payment link with payment link template"
+        )
+    })
+    .ok();
+
+    Ok(services::ApplicationResponse::JsonForRedirection(
+        api::RedirectionResponse {
+            return_url: String::new(),
+            params: vec![],
+            return_url_with_query_params: created_payment_link.link,
+            http_method: "GET".to_string(),
+            headers: vec![],
+        },
+    ))
+}
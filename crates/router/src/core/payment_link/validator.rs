@@ -116,3 +116,21 @@ pub fn validate_secure_payment_link_render_request(
         })
     }
 }
+
+pub fn validate_payment_link_token(
+    payment_link: &PaymentLink,
+    link_token: Option<&str>,
+) -> RouterResult<()> {
+    // Once a payment link is regenerated, only the URL carrying the latest token can be rendered
+    if payment_link.link_token.as_deref() != link_token {
+        return Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound)).attach_printable_lazy(
+            || {
+                format!(
+                    "Payment link token mismatch for {}, the requested URL has been invalidated",
+                    payment_link.payment_link_id
+                )
+            },
+        );
+    }
+    Ok(())
+}
@@ -1,7 +1,20 @@
+pub mod bank_list;
 pub mod cards;
 pub mod migration;
 pub mod network_tokenization;
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+pub mod sharing;
 pub mod surcharge_decision_configs;
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+pub mod tokenization;
 pub mod transformers;
 pub mod utils;
 mod validator;
@@ -21,6 +34,11 @@ use api_models::payment_methods;
 pub use api_models::{enums::PayoutConnectors, payouts as payout_types};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use common_utils::ext_traits::Encode;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use common_utils::fp_utils::when;
 use common_utils::{consts::DEFAULT_LOCALE, id_type};
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use common_utils::{
@@ -41,6 +59,11 @@ use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, Payme
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use masking::ExposeInterface;
 use masking::{PeekInterface, Secret};
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use router_env::logger;
 use router_env::{instrument, tracing};
 use time::Duration;
 
@@ -48,6 +71,8 @@ use super::{
     errors::{RouterResponse, StorageErrorExt},
     pm_auth,
 };
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+use crate::core::webhooks;
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use crate::{
     configs::settings,
@@ -431,6 +456,233 @@ pub async fn render_pm_collect_link(
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all)]
+pub async fn submit_pm_collect_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_methods::PaymentMethodCollectLinkSubmitRequest,
+) -> RouterResponse<payment_methods::PaymentMethodCollectLinkSubmitResponse> {
+    let db: &dyn StorageInterface = &*state.store;
+
+    // Fetch pm collect link
+    let pm_collect_link = db
+        .find_pm_collect_link_by_link_id(&req.pm_collect_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment method collect link not found".to_string(),
+        })?;
+    when(
+        &pm_collect_link.merchant_id != merchant_account.get_id(),
+        || {
+            Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "payment method collect link not found".to_string(),
+            }))
+        },
+    )?;
+
+    // Validate the link
+    let link_data = &pm_collect_link.link_data;
+    when(
+        link_data.client_secret.peek() != req.client_secret.peek(),
+        || Err(report!(errors::ApiErrorResponse::ClientSecretInvalid)),
+    )?;
+    let has_expired = common_utils::date_time::now() > pm_collect_link.expiry;
+    when(
+        has_expired
+            || pm_collect_link.link_status
+                == common_utils::link_utils::PaymentMethodCollectStatus::Invalidated,
+        || Err(report!(errors::ApiErrorResponse::ClientSecretExpired)),
+    )?;
+    when(
+        pm_collect_link.link_status
+            == common_utils::link_utils::PaymentMethodCollectStatus::Submitted,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "payment method details were already submitted for this link".to_string(),
+            }))
+        },
+    )?;
+    validate_pm_collect_link_submit_request(&req, link_data)?;
+
+    // Store the payment method against the customer
+    let customer_id = link_data.customer_id.clone();
+    let payment_method_create = payment_methods::PaymentMethodCreate {
+        payment_method: Some(req.payment_method),
+        payment_method_type: Some(req.payment_method_type),
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        card: req.card,
+        metadata: None,
+        customer_id: Some(customer_id.clone()),
+        card_network: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: req.bank_transfer,
+        #[cfg(feature = "payouts")]
+        wallet: req.wallet,
+        client_secret: None,
+        payment_method_data: None,
+        billing: req.billing,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+    let payment_method = match cards::add_payment_method(
+        &state,
+        payment_method_create,
+        &merchant_account,
+        &key_store,
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(payment_method) => payment_method,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while storing payment method")?,
+    };
+
+    let pm_collect_link = db
+        .update_pm_collect_link(
+            pm_collect_link,
+            storage::PaymentMethodCollectLinkUpdate::StatusUpdate {
+                link_status: common_utils::link_utils::PaymentMethodCollectStatus::Submitted,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update status of payment method collect link")?;
+
+    trigger_pm_collected_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &pm_collect_link,
+        payment_method.clone(),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods::PaymentMethodCollectLinkSubmitResponse {
+            pm_collect_link_id: pm_collect_link.link_id,
+            customer_id,
+            payment_method_id: payment_method.payment_method_id,
+            status: pm_collect_link.link_status,
+            return_url: pm_collect_link.return_url,
+        },
+    ))
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn validate_pm_collect_link_submit_request(
+    req: &payment_methods::PaymentMethodCollectLinkSubmitRequest,
+    link_data: &PaymentMethodCollectLinkData,
+) -> RouterResult<()> {
+    let is_enabled =
+        link_data
+            .enabled_payment_methods
+            .as_ref()
+            .map_or(true, |enabled_payment_methods| {
+                enabled_payment_methods
+                    .iter()
+                    .any(|enabled_payment_method| {
+                        enabled_payment_method.payment_method == req.payment_method
+                            && enabled_payment_method
+                                .payment_method_types
+                                .contains(&req.payment_method_type)
+                    })
+            });
+    when(!is_enabled, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payment method type {} of {} is not enabled for this link",
+                req.payment_method_type, req.payment_method
+            ),
+        }))
+    })?;
+
+    let (has_details, field_name) = match req.payment_method {
+        enums::PaymentMethod::Card => (req.card.is_some(), "card"),
+        #[cfg(feature = "payouts")]
+        enums::PaymentMethod::BankTransfer => (req.bank_transfer.is_some(), "bank_transfer"),
+        #[cfg(feature = "payouts")]
+        enums::PaymentMethod::Wallet => (req.wallet.is_some(), "wallet"),
+        _ => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "collecting {} through payment method collect links",
+                req.payment_method
+            ),
+        }))?,
+    };
+    when(!has_details, || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name
+        }))
+    })
+}
+
+/// Notifies the merchant of the payment method submitted through the collect link. Failures are
+/// only logged, since the payment method is already stored against the customer.
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+async fn trigger_pm_collected_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    pm_collect_link: &PaymentMethodCollectLink,
+    payment_method: payment_methods::PaymentMethodResponse,
+) {
+    let Some(profile_id) = pm_collect_link
+        .link_data
+        .profile_id
+        .as_ref()
+        .or(merchant_account.default_profile.as_ref())
+    else {
+        logger::warn!(
+            pm_collect_link_id = %pm_collect_link.link_id,
+            "Outgoing webhook not sent because of missing profile for payment method collect link"
+        );
+        return;
+    };
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch business profile for payment method collect webhook"
+            );
+            return;
+        }
+    };
+
+    let primary_object_created_at = payment_method.created;
+    if let Err(error) = Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        enums::EventType::PaymentMethodCollected,
+        enums::EventClass::PaymentMethods,
+        payment_method.payment_method_id.clone(),
+        enums::EventObjectType::PaymentMethodDetails,
+        api_models::webhooks::OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+            payment_method,
+        )),
+        primary_object_created_at,
+    ))
+    .await
+    {
+        logger::error!(?error, "Failed to trigger payment method collect webhook");
+    }
+}
+
 fn generate_task_id_for_payment_method_status_update_workflow(
     key_id: &str,
     runner: &storage::ProcessTrackerRunner,
@@ -0,0 +1,200 @@
+use std::{collections::HashSet, str::FromStr};
+
+use api_models::enums as api_enums;
+use common_enums::BankNames;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult},
+        payments::{self, helpers},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{self, api, domain},
+};
+
+/// Issuer lists do not depend on the merchant, so they are cached per connector and payment method
+/// type
+fn get_bank_list_cache_key(
+    connector: api_enums::Connector,
+    payment_method_type: api_enums::PaymentMethodType,
+) -> String {
+    format!(
+        "{}{connector}_{payment_method_type}",
+        consts::BANK_LIST_CACHE_PREFIX
+    )
+}
+
+/// Returns the banks offered by the connector for the payment method type, from the cache if
+/// present and from the connector otherwise. `None` is returned when the banks are not fetched
+/// from the connector, in which case the banks in the bank config are used.
+pub async fn get_connector_bank_names(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_method_type: api_enums::PaymentMethodType,
+) -> Option<HashSet<BankNames>> {
+    if !state.conf.bank_list_cache.enabled {
+        return None;
+    }
+    let connector = api_enums::Connector::from_str(&merchant_connector_account.connector_name)
+        .ok()
+        .filter(|connector| connector.supports_bank_list(payment_method_type))?;
+
+    let cache_key = get_bank_list_cache_key(connector, payment_method_type);
+    if let Some(banks) = get_cached_bank_list(state, &cache_key).await {
+        return Some(banks.into_iter().collect());
+    }
+
+    let banks = fetch_connector_bank_list(
+        state,
+        merchant_account,
+        connector,
+        merchant_connector_account,
+        payment_method_type,
+    )
+    .await
+    .map_err(|error| logger::error!(bank_list_fetch_error=?error, %connector))
+    .ok()
+    .filter(|banks| !banks.is_empty())?;
+
+    cache_bank_list(
+        state,
+        &cache_key,
+        &banks,
+        state.conf.bank_list_cache.get_ttl_in_secs(connector),
+    )
+    .await;
+
+    Some(banks.into_iter().collect())
+}
+
+#[instrument(skip_all)]
+async fn fetch_connector_bank_list(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: api_enums::Connector,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_method_type: api_enums::PaymentMethodType,
+) -> RouterResult<Vec<BankNames>> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector.to_string(),
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+    let merchant_connector_account =
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(merchant_connector_account.clone()));
+
+    // 1. Form Router data
+    let router_data = core_utils::construct_bank_list_router_data(
+        merchant_account,
+        &connector.to_string(),
+        &merchant_connector_account,
+        payment_method_type,
+    )?;
+
+    // 2. Fetch connector integration details
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::BankList,
+        types::BankListRequestData,
+        types::BankListResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // 3. Call connector service
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payment_failed_response()?;
+
+    // 4. Process data returned by the connector
+    router_data_resp
+        .response
+        .map(|bank_list_response| bank_list_response.banks)
+        .map_err(|err| {
+            report!(errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })
+        })
+}
+
+async fn get_cached_bank_list(state: &SessionState, cache_key: &str) -> Option<Vec<BankNames>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<Vec<BankNames>>(cache_key, "Vec<BankNames>")
+        .await
+        .map_err(|error| {
+            if error.current_context() != &redis_interface::errors::RedisError::NotFound {
+                logger::error!(bank_list_cache_error=?error);
+            }
+        })
+        .ok()
+}
+
+async fn cache_bank_list(state: &SessionState, cache_key: &str, banks: &[BankNames], ttl: i64) {
+    if ttl <= 0 {
+        return;
+    }
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .serialize_and_set_key_with_expiry(cache_key, banks, ttl)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(bank_list_cache_error=?error);
+    }
+}
+
+/// Removes the cached bank lists of the connector, so that they are fetched from the connector
+/// again on the next payment method list
+#[instrument(skip(state))]
+pub async fn invalidate_bank_list_cache(
+    state: SessionState,
+    connector: api_enums::Connector,
+) -> RouterResponse<()> {
+    let cache_keys = api_enums::PaymentMethodType::iter()
+        .filter(|payment_method_type| connector.supports_bank_list(*payment_method_type))
+        .map(|payment_method_type| get_bank_list_cache_key(connector, payment_method_type))
+        .collect::<Vec<_>>();
+
+    if cache_keys.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Fetching bank lists is not supported by {connector}"),
+        }));
+    }
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_multiple_keys(cache_keys)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to invalidate bank list cache of {connector}"))?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
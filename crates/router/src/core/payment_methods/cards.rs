@@ -58,6 +58,7 @@ use router_env::{instrument, metrics::add_attributes, tracing};
 use strum::IntoEnumIterator;
 
 use super::{
+    bank_list,
     migration::RecordMigrationStatusBuilder,
     surcharge_decision_configs::{
         perform_surcharge_decision_management_for_payment_method_list,
@@ -86,7 +87,7 @@ use crate::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
         },
-        utils as core_utils,
+        usage_metering, utils as core_utils,
     },
     db, logger,
     pii::prelude::*,
@@ -2710,6 +2711,12 @@ pub async fn add_card_to_hs_locker(
         .payload
         .get_required_value("StoreCardRespPayload")
         .change_context(errors::VaultError::SaveCardFailed)?;
+    usage_metering::record_billable_operation(
+        state,
+        payload.get_merchant_id(),
+        usage_metering::BillableOperation::VaultTokenStored,
+    )
+    .await;
     Ok(stored_card)
 }
 
@@ -2727,9 +2734,19 @@ where
     let jwekey = state.conf.jwekey.get_inner();
     let response_type_name = type_name!(T);
 
+    // The request not reaching the locker, such as on a connection failure or a timeout, and server
+    // errors of the locker are reported as the locker being unavailable
     let response = services::call_connector_api(state, request, flow_name)
         .await
-        .change_context(errors::VaultError::ApiError)?;
+        .change_context(errors::VaultError::LockerUnavailable)?;
+    if let Err(error_response) = response.as_ref() {
+        if error_response.status_code >= 500 {
+            return Err(report!(errors::VaultError::LockerUnavailable)).attach_printable(format!(
+                "Locker responded with status code {}",
+                error_response.status_code
+            ));
+        }
+    }
 
     let is_locker_call_succeeded = response.is_ok();
 
@@ -3108,8 +3125,10 @@ pub async fn mock_delete_card<'a>(
     })
 }
 //------------------------------------------------------------------------------
-pub fn get_banks(
+pub async fn get_banks(
     state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_accounts: &[domain::MerchantConnectorAccount],
     pm_type: common_enums::enums::PaymentMethodType,
     connectors: Vec<String>,
 ) -> Result<Vec<BankCodeResponse>, errors::ApiErrorResponse> {
@@ -3127,7 +3146,19 @@ pub fn get_banks(
     } else {
         let mut bank_code_responses = vec![];
         for connector in &connectors {
-            if let Some(connector_bank_names) = state.conf.bank_config.0.get(&pm_type) {
+            // Banks fetched from the connector take precedence over the ones in the bank config
+            let connector_bank_names = match merchant_connector_accounts
+                .iter()
+                .find(|mca| mca.connector_name == *connector)
+            {
+                Some(mca) => {
+                    bank_list::get_connector_bank_names(state, merchant_account, mca, pm_type).await
+                }
+                None => None,
+            };
+            if let Some(connector_bank_names) = connector_bank_names {
+                bank_names_hm.insert(connector.clone(), connector_bank_names);
+            } else if let Some(connector_bank_names) = state.conf.bank_config.0.get(&pm_type) {
                 if let Some(connector_hash_set) = connector_bank_names.0.get(connector) {
                     bank_names_hm.insert(connector.clone(), connector_hash_set.banks.clone());
                 } else {
@@ -3199,6 +3230,8 @@ pub async fn list_payment_methods(
     _merchant_account: domain::MerchantAccount,
     _key_store: domain::MerchantKeyStore,
     mut _req: api::PaymentMethodListRequest,
+    _auth_flow: services::AuthFlow,
+    _request_origin: Option<String>,
 ) -> errors::RouterResponse<api::PaymentMethodListResponse> {
     todo!()
 }
@@ -3213,6 +3246,8 @@ pub async fn list_payment_methods(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     mut req: api::PaymentMethodListRequest,
+    auth_flow: services::AuthFlow,
+    request_origin: Option<String>,
 ) -> errors::RouterResponse<api::PaymentMethodListResponse> {
     let db = &*state.store;
     let pm_config_mapping = &state.conf.pm_filters;
@@ -3361,6 +3396,18 @@ pub async fn list_payment_methods(
     )
     .await?;
 
+    business_profile
+        .as_ref()
+        .map(|business_profile| {
+            helpers::validate_client_origin_for_profile(
+                auth_flow,
+                request_origin.as_deref(),
+                business_profile,
+                "payment_methods_list",
+            )
+        })
+        .transpose()?;
+
     let profile_id = profile_id
         .clone()
         .get_required_value("profile_id")
@@ -4063,7 +4110,14 @@ pub async fn list_payment_methods(
     for key in banks_consolidated_hm.iter() {
         let payment_method_type = *key.0;
         let connectors = key.1.clone();
-        let bank_names = get_banks(&state, payment_method_type, connectors)?;
+        let bank_names = get_banks(
+            &state,
+            &merchant_account,
+            &filtered_mcas,
+            payment_method_type,
+            connectors,
+        )
+        .await?;
         bank_redirect_payment_method_types.push({
             ResponsePaymentMethodTypes {
                 payment_method_type,
@@ -5838,6 +5892,49 @@ pub async fn retrieve_payment_method(
     ))
 }
 
+/// Lists the consents given by the customer to store the payment method for future usage, in the
+/// order they were recorded.
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+#[instrument(skip_all)]
+pub async fn list_payment_method_consent_events(
+    state: routes::SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+) -> errors::RouterResponse<Vec<api_models::payment_methods::PaymentMethodConsentEventResponse>> {
+    let db = state.store.as_ref();
+    let payment_method = db
+        .find_payment_method(
+            &((&state).into()),
+            &key_store,
+            &payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        &payment_method.merchant_id != merchant_account.get_id(),
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    let consent_events = db
+        .find_payment_method_consent_events_by_merchant_id_payment_method_id(
+            merchant_account.get_id(),
+            &payment_method.payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the consent events of the payment method")?;
+
+    Ok(services::ApplicationResponse::Json(
+        consent_events
+            .into_iter()
+            .map(ForeignFrom::foreign_from)
+            .collect(),
+    ))
+}
+
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 #[instrument(skip_all)]
 pub async fn delete_payment_method(
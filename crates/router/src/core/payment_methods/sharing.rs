@@ -0,0 +1,443 @@
+use api_models::{customers as customers_api, payment_methods as payment_methods_api};
+use common_utils::{generate_id, id_type, pii};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        customers,
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    routes::SessionState,
+    services,
+    types::{api, domain, storage, storage::enums, transformers::ForeignFrom},
+};
+
+/// Shares a card saved by a merchant account with another merchant account of the same
+/// organization, which has opted in to receiving shared payment methods. The card is vaulted and
+/// saved against the customer the source customer is mapped to in the target merchant account.
+#[instrument(skip_all)]
+pub async fn share_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+    req: payment_methods_api::SharePaymentMethodRequest,
+) -> errors::RouterResponse<payment_methods_api::SharedPaymentMethodResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    validate_token_sharing_enabled(&state, &merchant_account.organization_id).await?;
+
+    let (target_merchant_account, target_key_store) =
+        get_target_merchant_account(&state, &merchant_account, &req.target_merchant_id).await?;
+
+    validate_shared_payment_methods_accepted(&state, target_merchant_account.get_id()).await?;
+
+    let payment_method = db
+        .find_payment_method(
+            key_manager_state,
+            &key_store,
+            &payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.get_payment_method_type() != Some(enums::PaymentMethod::Card)
+        || payment_method.status != enums::PaymentMethodStatus::Active
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only active card payment methods can be shared".to_string(),
+        }));
+    }
+
+    let existing_shares = db
+        .list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+            merchant_account.get_id(),
+            &payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the shares of the payment method")?;
+
+    if existing_shares
+        .iter()
+        .any(|share| share.target_merchant_id == req.target_merchant_id)
+    {
+        return Err(report!(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payment method is already shared with the merchant account".to_string(),
+        }));
+    }
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &payment_method.customer_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let target_customer_id = get_or_create_customer_in_target_merchant_account(
+        &state,
+        merchant_account.get_id(),
+        &target_merchant_account,
+        &target_key_store,
+        customer,
+    )
+    .await?;
+
+    let card = cards::get_card_from_locker(
+        &state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await
+    .attach_printable("Failed to retrieve the card of the payment method to be shared")?;
+
+    let payment_method_create = api::PaymentMethodCreate {
+        payment_method: Some(enums::PaymentMethod::Card),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        card: Some(api::CardDetail {
+            card_number: card.card_number,
+            card_exp_month: card.card_exp_month,
+            card_exp_year: card.card_exp_year,
+            card_holder_name: card.name_on_card,
+            nick_name: card.nick_name.map(masking::Secret::new),
+            card_issuing_country: None,
+            card_network: None,
+            card_issuer: None,
+            card_type: None,
+        }),
+        metadata: payment_method.metadata.clone(),
+        customer_id: Some(target_customer_id.clone()),
+        card_network: card.card_brand,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        client_secret: None,
+        payment_method_data: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    let target_payment_method = if let services::ApplicationResponse::Json(response) =
+        Box::pin(cards::add_payment_method(
+            &state,
+            payment_method_create,
+            &target_merchant_account,
+            &target_key_store,
+        ))
+        .await?
+    {
+        response
+    } else {
+        return Err(errors::ApiErrorResponse::InternalServerError.into());
+    };
+
+    let shared_payment_method = storage::SharedPaymentMethodNew {
+        id: generate_id(consts::ID_LENGTH, "spm"),
+        organization_id: merchant_account.organization_id.clone(),
+        source_merchant_id: merchant_account.get_id().to_owned(),
+        source_payment_method_id: payment_method.payment_method_id,
+        target_merchant_id: req.target_merchant_id,
+        target_payment_method_id: target_payment_method.payment_method_id,
+        customer_id: payment_method.customer_id,
+        created_at: common_utils::date_time::now(),
+        target_customer_id,
+    };
+
+    let shared_payment_method = db
+        .insert_shared_payment_method(shared_payment_method)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Payment method is already shared with the merchant account".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods_api::SharedPaymentMethodResponse::foreign_from(shared_payment_method),
+    ))
+}
+
+/// Lists the merchant accounts a payment method has been shared with.
+#[instrument(skip_all)]
+pub async fn list_shared_payment_methods(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_method_id: String,
+) -> errors::RouterResponse<Vec<payment_methods_api::SharedPaymentMethodResponse>> {
+    let shared_payment_methods = state
+        .store
+        .list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+            merchant_account.get_id(),
+            &payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the shares of the payment method")?;
+
+    Ok(services::ApplicationResponse::Json(
+        shared_payment_methods
+            .into_iter()
+            .map(ForeignFrom::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Revokes the access of a merchant account to a payment method shared with it, deleting the
+/// copy of the payment method saved in the merchant account. Shares can be revoked even after
+/// token sharing has been disabled for the organization.
+#[instrument(skip_all)]
+pub async fn revoke_shared_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_method_id: String,
+    target_merchant_id: id_type::MerchantId,
+) -> errors::RouterResponse<payment_methods_api::SharedPaymentMethodResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let shared_payment_method = db
+        .list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+            merchant_account.get_id(),
+            &payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the shares of the payment method")?
+        .into_iter()
+        .find(|share| share.target_merchant_id == target_merchant_id)
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment method is not shared with the merchant account".to_string(),
+        })?;
+
+    let target_key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &target_merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let target_merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &target_merchant_id,
+            &target_key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    match Box::pin(cards::delete_payment_method(
+        state.clone(),
+        target_merchant_account,
+        api::PaymentMethodId {
+            payment_method_id: shared_payment_method.target_payment_method_id.clone(),
+        },
+        target_key_store,
+    ))
+    .await
+    {
+        Ok(_) => {}
+        // The payment method may have been deleted by the target merchant account already
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::PaymentMethodNotFound
+            ) => {}
+        Err(error) => return Err(error),
+    }
+
+    let shared_payment_method = db
+        .delete_shared_payment_method_by_id(&shared_payment_method.id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the share of the payment method")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods_api::SharedPaymentMethodResponse::foreign_from(shared_payment_method),
+    ))
+}
+
+async fn validate_token_sharing_enabled(
+    state: &SessionState,
+    organization_id: &id_type::OrganizationId,
+) -> RouterResult<()> {
+    let organization = state
+        .store
+        .find_organization_by_org_id(organization_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Organization not found".to_string(),
+        })?;
+
+    if !organization.is_token_sharing_enabled.unwrap_or(false) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Token sharing is not enabled for the organization".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Fetches the merchant account to share payment methods with, ensuring that it belongs to the
+/// same organization as the merchant account sharing the payment methods.
+async fn get_target_merchant_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    target_merchant_id: &id_type::MerchantId,
+) -> RouterResult<(domain::MerchantAccount, domain::MerchantKeyStore)> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    if target_merchant_id == merchant_account.get_id() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Payment methods cannot be shared with the same merchant account".to_string(),
+        }));
+    }
+
+    let target_key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            target_merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let target_merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            target_merchant_id,
+            &target_key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // Merchant accounts of other organizations are reported as not found, to avoid disclosing
+    // their existence
+    if target_merchant_account.organization_id != merchant_account.organization_id {
+        return Err(report!(errors::ApiErrorResponse::MerchantAccountNotFound));
+    }
+
+    Ok((target_merchant_account, target_key_store))
+}
+
+/// Ensures that the target merchant account has opted in to receiving payment methods shared by
+/// other merchant accounts of the organization, on top of token sharing being enabled for the
+/// organization.
+async fn validate_shared_payment_methods_accepted(
+    state: &SessionState,
+    target_merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let key = target_merchant_id.get_accept_shared_payment_methods_key();
+    let is_accepted = match state.store.find_config_by_key(&key).await {
+        Ok(config) => config.config == "true",
+        Err(error) if error.current_context().is_db_not_found() => false,
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch the shared payment methods config")
+        }
+    };
+
+    if !is_accepted {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The merchant account has not opted in to receiving shared payment methods"
+                .to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Fetches the customer of the target merchant account that the customer was mapped to when a
+/// payment method of the customer was first shared with the merchant account. Otherwise, a
+/// customer with a new customer ID is created in the target merchant account, since customer IDs
+/// are chosen by each merchant account and a customer with the same ID may be someone else. The
+/// details of the customer are encrypted using the key of the target merchant account.
+async fn get_or_create_customer_in_target_merchant_account(
+    state: &SessionState,
+    source_merchant_id: &id_type::MerchantId,
+    target_merchant_account: &domain::MerchantAccount,
+    target_key_store: &domain::MerchantKeyStore,
+    customer: domain::Customer,
+) -> RouterResult<id_type::CustomerId> {
+    let db = state.store.as_ref();
+
+    let mapped_customer_id = db
+        .find_latest_shared_payment_method_by_source_merchant_id_customer_id_target_merchant_id(
+            source_merchant_id,
+            &customer.customer_id,
+            target_merchant_account.get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the customer mapped in the target merchant account")?
+        .map(|share| share.target_customer_id);
+
+    if let Some(target_customer_id) = mapped_customer_id {
+        match db
+            .find_customer_optional_by_customer_id_merchant_id(
+                &state.into(),
+                &target_customer_id,
+                target_merchant_account.get_id(),
+                target_key_store,
+                target_merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(Some(_)) => return Ok(target_customer_id),
+            // The mapped customer has been deleted by the target merchant account, the customer
+            // is mapped to a new customer instead
+            Ok(None) => {}
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::StorageError::CustomerRedacted
+                ) => {}
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Failed to find customer in the target merchant account")
+            }
+        }
+    }
+
+    let target_customer_id = common_utils::generate_customer_id_of_default_length();
+    let customer_request = customers_api::CustomerRequest {
+        customer_id: Some(target_customer_id.clone()),
+        merchant_id: target_merchant_account.get_id().to_owned(),
+        name: customer.name.map(|name| name.into_inner()),
+        email: customer.email.map(pii::Email::from),
+        phone: customer.phone.map(|phone| phone.into_inner()),
+        description: customer.description,
+        phone_country_code: customer.phone_country_code,
+        address: None,
+        metadata: customer.metadata,
+    };
+
+    Box::pin(customers::create_customer(
+        state.clone(),
+        target_merchant_account.clone(),
+        target_key_store.clone(),
+        customer_request,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create customer in the target merchant account")?;
+
+    Ok(target_customer_id)
+}
@@ -0,0 +1,120 @@
+use api_models::payment_methods as payment_methods_api;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, StorageErrorExt},
+        payment_methods::cards,
+    },
+    routes::SessionState,
+    services,
+    types::{api, domain, storage::enums},
+};
+
+/// Vaults the card in the request without creating a payment, returning a multi-use token which
+/// references the vaulted card.
+#[instrument(skip_all)]
+pub async fn tokenize_card(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_methods_api::VaultTokenizeRequest,
+) -> errors::RouterResponse<payment_methods_api::VaultTokenizeResponse> {
+    let payment_method_create = api::PaymentMethodCreate {
+        payment_method: Some(enums::PaymentMethod::Card),
+        payment_method_type: None,
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        card: Some(req.card),
+        metadata: req.metadata,
+        customer_id: Some(req.customer_id),
+        card_network: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        client_secret: None,
+        payment_method_data: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    let payment_method_response = if let services::ApplicationResponse::Json(response) = Box::pin(
+        cards::add_payment_method(&state, payment_method_create, &merchant_account, &key_store),
+    )
+    .await?
+    {
+        response
+    } else {
+        return Err(errors::ApiErrorResponse::InternalServerError.into());
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods_api::VaultTokenizeResponse {
+            token: payment_method_response.payment_method_id,
+            customer_id: payment_method_response
+                .customer_id
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Customer ID not found in the payment method response")?,
+            card: payment_method_response.card,
+            created: payment_method_response.created,
+        },
+    ))
+}
+
+/// Retrieves the card referenced by a token from the vault.
+#[instrument(skip_all)]
+pub async fn detokenize_card(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_methods_api::VaultDetokenizeRequest,
+) -> errors::RouterResponse<payment_methods_api::VaultDetokenizeResponse> {
+    let db = state.store.as_ref();
+
+    let payment_method = db
+        .find_payment_method(
+            &(&state).into(),
+            &key_store,
+            &req.token,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.merchant_id != *merchant_account.get_id()
+        || payment_method.get_payment_method_type() != Some(enums::PaymentMethod::Card)
+        || payment_method.status != enums::PaymentMethodStatus::Active
+    {
+        return Err(errors::ApiErrorResponse::PaymentMethodNotFound.into());
+    }
+
+    let card = cards::get_card_from_locker(
+        &state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await
+    .attach_printable("Failed to retrieve the card referenced by the token")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods_api::VaultDetokenizeResponse {
+            token: payment_method.payment_method_id,
+            customer_id: payment_method.customer_id,
+            card: payment_methods_api::VaultCardDetails {
+                card_number: card.card_number,
+                card_exp_month: card.card_exp_month,
+                card_exp_year: card.card_exp_year,
+                card_holder_name: card.name_on_card,
+                nick_name: card.nick_name.map(masking::Secret::new),
+                card_network: card.card_brand,
+            },
+        },
+    ))
+}
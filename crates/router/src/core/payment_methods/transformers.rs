@@ -35,6 +35,13 @@ pub enum StoreLockerReq {
 }
 
 impl StoreLockerReq {
+    pub fn get_merchant_id(&self) -> &id_type::MerchantId {
+        match self {
+            Self::LockerCard(c) => &c.merchant_id,
+            Self::LockerGeneric(g) => &g.merchant_id,
+        }
+    }
+
     pub fn update_requestor_card_reference(&mut self, card_reference: Option<String>) {
         match self {
             Self::LockerCard(c) => c.requestor_card_reference = card_reference,
@@ -220,7 +227,7 @@ pub async fn get_decrypted_response_payload(
     let target_locker = locker_choice.unwrap_or(api_enums::LockerChoice::HyperswitchCardVault);
 
     let public_key = match target_locker {
-        api_enums::LockerChoice::HyperswitchCardVault => {
+        api_enums::LockerChoice::HyperswitchCardVault | api_enums::LockerChoice::RustCardVault => {
             jwekey.vault_encryption_key.peek().as_bytes()
         }
     };
@@ -357,7 +364,7 @@ pub async fn mk_basilisk_req(
         .change_context(errors::VaultError::SaveCardFailed)?;
 
     let public_key = match locker_choice {
-        api_enums::LockerChoice::HyperswitchCardVault => {
+        api_enums::LockerChoice::HyperswitchCardVault | api_enums::LockerChoice::RustCardVault => {
             jwekey.vault_encryption_key.peek().as_bytes()
         }
     };
@@ -405,6 +412,7 @@ pub async fn mk_add_locker_request_hs(
     let jwe_payload = mk_basilisk_req(jwekey, &jws, locker_choice).await?;
     let mut url = match locker_choice {
         api_enums::LockerChoice::HyperswitchCardVault => locker.host.to_owned(),
+        api_enums::LockerChoice::RustCardVault => locker.host_rs.to_owned(),
     };
     url.push_str("/cards/add");
     let mut request = services::Request::new(services::Method::Post, &url);
@@ -525,6 +533,46 @@ pub fn mk_add_card_response_hs(
     todo!()
 }
 
+/// Builds the payment method response using only the card details stored in the payment
+/// methods table, without falling back to the locker.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub fn generate_payment_method_response(pm: &domain::PaymentMethod) -> api::PaymentMethodResponse {
+    let card = pm
+        .payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .and_then(|data| serde_json::from_value::<api::PaymentMethodsData>(data).ok())
+        .and_then(|data| match data {
+            api::PaymentMethodsData::Card(card) => Some(api::CardDetailFromLocker::from(card)),
+            _ => None,
+        })
+        .map(|mut card| {
+            card.scheme.clone_from(&pm.scheme);
+            card
+        });
+
+    api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id.clone(),
+        customer_id: Some(pm.customer_id.clone()),
+        payment_method_id: pm.payment_method_id.clone(),
+        payment_method: pm.get_payment_method_type(),
+        payment_method_type: pm.get_payment_method_subtype(),
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: pm.metadata.clone(),
+        created: Some(pm.created_at),
+        recurring_enabled: false,
+        installment_payment_enabled: false,
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+        last_used_at: Some(pm.last_used_at),
+        client_secret: None,
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 pub fn generate_pm_vaulting_req_from_update_request(
     pm_create: pm_types::PaymentMethodVaultingData,
@@ -611,6 +659,7 @@ pub async fn mk_get_card_request_hs(
     let jwe_payload = mk_basilisk_req(jwekey, &jws, target_locker).await?;
     let mut url = match target_locker {
         api_enums::LockerChoice::HyperswitchCardVault => locker.host.to_owned(),
+        api_enums::LockerChoice::RustCardVault => locker.host_rs.to_owned(),
     };
     url.push_str("/cards/retrieve");
     let mut request = services::Request::new(services::Method::Post, &url);
@@ -65,6 +65,21 @@ pub async fn validate_request_and_initiate_payment_method_collect_link(
         }
     }?;
 
+    // Validate profile_id, falling back to the merchant's default profile
+    let key_manager_state = &state.into();
+    core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        key_store,
+        req.profile_id.as_ref(),
+        &merchant_id,
+    )
+    .await?;
+    let profile_id = req
+        .profile_id
+        .clone()
+        .or(merchant_account.default_profile.clone());
+
     // Create payment method collect link ID
     let pm_collect_link_id = core_utils::get_or_generate_id(
         "pm_collect_link_id",
@@ -151,5 +166,6 @@ pub async fn validate_request_and_initiate_payment_method_collect_link(
         session_expiry,
         ui_config: pm_collect_link_config,
         enabled_payment_methods: Some(enabled_payment_methods),
+        profile_id,
     })
 }
@@ -0,0 +1,335 @@
+use api_models::payment_templates as payment_template_api;
+use common_utils::{fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{api, domain, storage, storage::enums, transformers::ForeignFrom},
+};
+
+const PAYMENT_TEMPLATE_NAME_MAX_LENGTH: usize = 64;
+const STATEMENT_DESCRIPTOR_MAX_LENGTH: usize = 255;
+
+fn validate_name(name: &str) -> RouterResult<()> {
+    when(
+        name.trim().is_empty() || name.len() > PAYMENT_TEMPLATE_NAME_MAX_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "name must be between 1 and {PAYMENT_TEMPLATE_NAME_MAX_LENGTH} characters"
+                ),
+            }))
+        },
+    )
+}
+
+fn validate_capture_method(capture_method: enums::CaptureMethod) -> RouterResult<()> {
+    when(capture_method == enums::CaptureMethod::Scheduled, || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "scheduled capture method is not supported for payment templates".to_string(),
+        }))
+    })
+}
+
+fn validate_metadata(metadata: &serde_json::Value) -> RouterResult<()> {
+    when(!metadata.is_object(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "metadata must be a JSON object".to_string(),
+        }))
+    })
+}
+
+fn validate_statement_descriptor(field_name: &str, statement_descriptor: &str) -> RouterResult<()> {
+    when(
+        statement_descriptor.len() > STATEMENT_DESCRIPTOR_MAX_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{field_name} must be at most {STATEMENT_DESCRIPTOR_MAX_LENGTH} characters"
+                ),
+            }))
+        },
+    )
+}
+
+fn validate_template_fields(
+    capture_method: Option<enums::CaptureMethod>,
+    metadata: Option<&serde_json::Value>,
+    statement_descriptor_name: Option<&String>,
+    statement_descriptor_suffix: Option<&String>,
+) -> RouterResult<()> {
+    if let Some(capture_method) = capture_method {
+        validate_capture_method(capture_method)?;
+    }
+    if let Some(metadata) = metadata {
+        validate_metadata(metadata)?;
+    }
+    if let Some(statement_descriptor_name) = statement_descriptor_name {
+        validate_statement_descriptor("statement_descriptor_name", statement_descriptor_name)?;
+    }
+    if let Some(statement_descriptor_suffix) = statement_descriptor_suffix {
+        validate_statement_descriptor("statement_descriptor_suffix", statement_descriptor_suffix)?;
+    }
+    Ok(())
+}
+
+async fn validate_and_get_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_account.get_id(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn create_payment_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    req: payment_template_api::PaymentTemplateCreateRequest,
+) -> RouterResponse<payment_template_api::PaymentTemplateResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    validate_name(&req.name)?;
+    validate_template_fields(
+        req.capture_method,
+        req.metadata.as_ref(),
+        req.statement_descriptor_name.as_ref(),
+        req.statement_descriptor_suffix.as_ref(),
+    )?;
+
+    let now = common_utils::date_time::now();
+    let payment_template_new = storage::PaymentTemplateNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "payment_template"),
+        merchant_id: merchant_account.get_id().clone(),
+        profile_id,
+        name: req.name,
+        currency: req.currency,
+        capture_method: req.capture_method,
+        metadata: req.metadata,
+        statement_descriptor_name: req.statement_descriptor_name,
+        statement_descriptor_suffix: req.statement_descriptor_suffix,
+        allowed_payment_method_types: req
+            .allowed_payment_method_types
+            .map(storage::AllowedPaymentMethodTypes),
+        is_active: req.is_active.unwrap_or(true),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let payment_template = state
+        .store
+        .insert_payment_template(payment_template_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "payment template with the name already exists for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_template_api::PaymentTemplateResponse::foreign_from(payment_template),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_payment_templates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<payment_template_api::PaymentTemplateResponse>> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let payment_templates = state
+        .store
+        .list_payment_templates_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment templates")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_templates
+            .into_iter()
+            .map(payment_template_api::PaymentTemplateResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    template_id: String,
+) -> RouterResponse<payment_template_api::PaymentTemplateResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let payment_template = state
+        .store
+        .find_payment_template_by_profile_id_template_id(&profile_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment template not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_template_api::PaymentTemplateResponse::foreign_from(payment_template),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_payment_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    template_id: String,
+    req: payment_template_api::PaymentTemplateUpdateRequest,
+) -> RouterResponse<payment_template_api::PaymentTemplateResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    if let Some(name) = &req.name {
+        validate_name(name)?;
+    }
+    validate_template_fields(
+        req.capture_method,
+        req.metadata.as_ref(),
+        req.statement_descriptor_name.as_ref(),
+        req.statement_descriptor_suffix.as_ref(),
+    )?;
+
+    let payment_template = state
+        .store
+        .update_payment_template_by_profile_id_template_id(
+            &profile_id,
+            &template_id,
+            storage::PaymentTemplateUpdate::Update {
+                name: req.name,
+                currency: req.currency,
+                capture_method: req.capture_method,
+                metadata: req.metadata,
+                statement_descriptor_name: req.statement_descriptor_name,
+                statement_descriptor_suffix: req.statement_descriptor_suffix,
+                allowed_payment_method_types: req
+                    .allowed_payment_method_types
+                    .map(storage::AllowedPaymentMethodTypes),
+                is_active: req.is_active,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment template not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_template_api::PaymentTemplateResponse::foreign_from(payment_template),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_payment_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    template_id: String,
+) -> RouterResponse<payment_template_api::PaymentTemplateDeleteResponse> {
+    validate_and_get_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    let payment_template = state
+        .store
+        .delete_payment_template_by_profile_id_template_id(&profile_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment template not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_template_api::PaymentTemplateDeleteResponse {
+            template_id: payment_template.id,
+            deleted: true,
+        },
+    ))
+}
+
+/// Fills in the currency, capture method, metadata, statement descriptors and allowed payment
+/// method types which are not passed in the payment request from the payment template referenced
+/// by the request. The values passed in the request take precedence over the values of the
+/// template, and the metadata of the request is merged over the metadata of the template.
+#[instrument(skip_all)]
+pub async fn apply_payment_template(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    mut request: api::PaymentsRequest,
+) -> RouterResult<api::PaymentsRequest> {
+    let Some(template_id) = request.template_id.clone() else {
+        return Ok(request);
+    };
+
+    let payment_template = state
+        .store
+        .find_payment_template_by_merchant_id_template_id(merchant_id, &template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment template not found".to_string(),
+        })?;
+    when(!payment_template.is_active, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment template is not active".to_string(),
+        }))
+    })?;
+
+    // The payment is created under the profile of the template, unless another profile is passed
+    match request.profile_id.as_ref() {
+        Some(profile_id) if profile_id != &payment_template.profile_id => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment template does not belong to the profile_id passed in the request"
+                    .to_string(),
+            }));
+        }
+        Some(_) => {}
+        None => request.profile_id = Some(payment_template.profile_id),
+    }
+
+    request.currency = request.currency.or(payment_template.currency);
+    request.capture_method = request.capture_method.or(payment_template.capture_method);
+    request.statement_descriptor_name = request
+        .statement_descriptor_name
+        .or(payment_template.statement_descriptor_name);
+    request.statement_descriptor_suffix = request
+        .statement_descriptor_suffix
+        .or(payment_template.statement_descriptor_suffix);
+    request.allowed_payment_method_types =
+        request.allowed_payment_method_types.or(payment_template
+            .allowed_payment_method_types
+            .map(|allowed_payment_method_types| allowed_payment_method_types.0));
+    request.metadata = match (payment_template.metadata, request.metadata) {
+        (
+            Some(serde_json::Value::Object(mut template_metadata)),
+            Some(serde_json::Value::Object(request_metadata)),
+        ) => {
+            template_metadata.extend(request_metadata);
+            Some(serde_json::Value::Object(template_metadata))
+        }
+        (template_metadata, request_metadata) => request_metadata.or(template_metadata),
+    };
+
+    Ok(request)
+}
@@ -1,18 +1,34 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod capture_on_shipment;
 pub mod conditional_configs;
 pub mod connector_integration_v2_impls;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod dcc;
 pub mod flows;
 pub mod helpers;
+#[cfg(feature = "v1")]
+pub mod operation_fallback;
 pub mod operations;
+#[cfg(feature = "v1")]
+pub mod post_auth_rules;
+#[cfg(feature = "v1")]
+pub mod presentment_currency;
+#[cfg(feature = "v1")]
+pub mod profile_selection;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
 #[cfg(feature = "v2")]
 pub mod session_operation;
+#[cfg(feature = "v1")]
+pub mod session_prewarm;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
+#[cfg(feature = "v1")]
+pub mod virtual_account;
 #[cfg(feature = "olap")]
 use std::collections::HashMap;
 use std::{
@@ -89,6 +105,7 @@ use crate::{
     configs::settings::{ApplePayPreDecryptFlow, PaymentMethodTypeTokenFilter},
     connector::utils::missing_field_err,
     core::{
+        connector_maintenance,
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payment_methods::{cards, network_tokenization},
         payouts,
@@ -682,12 +699,23 @@ where
                 }
             };
 
+            add_auto_capture_task_if_required(state, &operation, &payment_data).await?;
+            add_voucher_expiry_task_if_required(state, &operation, &payment_data).await?;
+
+            routing::canary_rollout::record_connector_canary_rollout_outcome(
+                state,
+                &key_store,
+                &business_profile,
+                payment_data.get_payment_attempt(),
+            )
+            .await;
+
             #[cfg(feature = "frm")]
             if let Some(fraud_info) = &mut frm_info {
                 #[cfg(feature = "v1")]
                 Box::pin(frm_core::post_payment_frm_core(
                     state,
-                    req_state,
+                    req_state.clone(),
                     &merchant_account,
                     &mut payment_data,
                     fraud_info,
@@ -703,6 +731,19 @@ where
                 ))
                 .await?;
             }
+
+            #[cfg(feature = "v1")]
+            if is_operation_confirm(&operation) {
+                Box::pin(post_auth_rules::evaluate_post_auth_rules(
+                    state,
+                    req_state,
+                    &merchant_account,
+                    &key_store,
+                    &business_profile,
+                    &mut payment_data,
+                ))
+                .await?;
+            }
         } else {
             (_, payment_data) = operation
                 .to_update_tracker()?
@@ -1339,7 +1380,7 @@ where
             req_state,
             merchant_account,
             profile_id,
-            key_store,
+            key_store.clone(),
             operation.clone(),
             req,
             call_connector_action,
@@ -1348,8 +1389,9 @@ where
             header_payload.clone(),
         )
         .await?;
+    let payment_profile_id = payment_data.get_payment_intent_profile_id().cloned();
 
-    Res::generate_response(
+    let response = Res::generate_response(
         payment_data,
         customer,
         auth_flow,
@@ -1359,7 +1401,82 @@ where
         connector_http_status_code,
         external_latency,
         header_payload.x_hs_latency,
-    )
+    )?;
+
+    match (response, payment_profile_id) {
+        (services::ApplicationResponse::Form(mut redirection_data), Some(profile_id)) => {
+            redirection_data.page_config = helpers::get_redirection_page_config(
+                &state,
+                &key_store,
+                &profile_id,
+                header_payload.locale.as_ref(),
+            )
+            .await?;
+            Ok(services::ApplicationResponse::Form(redirection_data))
+        }
+        (response, _) => Ok(response),
+    }
+}
+
+/// Validates a confirm request which is processed asynchronously, before it is accepted. The payment
+/// is confirmed in the background afterwards, where the request goes through the complete
+/// validation of the confirm operation.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn validate_async_confirm_request(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &api::PaymentsRequest,
+    auth_flow: services::AuthFlow,
+) -> RouterResult<payments_api::PaymentsAsyncConfirmResponse> {
+    // The outcome of an asynchronous confirm is only reported to the merchant, through webhooks
+    utils::when(auth_flow != services::AuthFlow::Merchant, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message:
+                "async_confirm is only supported when the payment is confirmed using the API key"
+                    .to_string(),
+        })
+    })?;
+
+    let (_operation, validate_result) =
+        Operation::<api::Authorize, api::PaymentsRequest>::to_validate_request(
+            &operations::PaymentConfirm,
+        )?
+        .validate_request(req, merchant_account)?;
+    let payment_id = api::PaymentIdTypeExt::get_payment_intent_id(&validate_result.payment_id)
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_id,
+            merchant_account.get_id(),
+            key_store,
+            validate_result.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::validate_payment_status_against_not_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::IntentStatus::Processing,
+            storage_enums::IntentStatus::RequiresCapture,
+            storage_enums::IntentStatus::RequiresMerchantAction,
+            storage_enums::IntentStatus::RequiresCustomerAction,
+        ],
+        "confirm",
+    )?;
+
+    Ok(payments_api::PaymentsAsyncConfirmResponse {
+        payment_id,
+        merchant_id: merchant_account.get_id().to_owned(),
+        status: payment_intent.status,
+    })
 }
 
 #[cfg(feature = "v1")]
@@ -1901,6 +2018,7 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
             client_secret: None,
             expand_attempts: None,
             expand_captures: None,
+            expand: None,
         };
         let response = Box::pin(
             payments_core::<api::PSync, api::PaymentsResponse, _, _, _, _>(
@@ -2257,6 +2375,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                 client_secret: None,
                 expand_attempts: None,
                 expand_captures: None,
+                expand: None,
             };
             Box::pin(
                 payments_core::<api::PSync, api::PaymentsResponse, _, _, _, _>(
@@ -2363,6 +2482,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                 payment_method_data: None,
                 amount: payments_response.amount.to_string(),
                 currency: payments_response.currency.clone(),
+                page_config: None,
             },
         )))
     }
@@ -2566,6 +2686,14 @@ where
         (None, false)
     };
 
+    // The payment trackers are left untouched when the connector cannot be called within the
+    // timeout budget of the request, so that the request can be retried as is
+    if should_continue_further && !state.has_connector_call_budget() {
+        return Err(report!(errors::ApiErrorResponse::ResourceBusy)).attach_printable(
+            "Timeout budget of the request is exhausted before the connector call",
+        );
+    }
+
     if should_add_task_to_process_tracker(payment_data) {
         operation
             .to_domain()?
@@ -3256,6 +3384,16 @@ where
         services::api::ConnectorIntegration<F, Req, router_types::PaymentsResponseData>,
 {
     let call_connectors_start_time = Instant::now();
+    let (connectors, prewarmed_session_tokens) = session_prewarm::get_prewarmed_session_tokens(
+        state,
+        payment_data.get_payment_intent(),
+        connectors,
+    )
+    .await;
+    for session_token in prewarmed_session_tokens {
+        payment_data.push_sessions_token(session_token);
+    }
+
     let mut join_handlers = Vec::with_capacity(connectors.len());
     for session_connector_data in connectors.iter() {
         let connector_id = session_connector_data.connector.connector.id();
@@ -4229,6 +4367,7 @@ where
     pub refunds: Vec<storage::Refund>,
     pub disputes: Vec<storage::Dispute>,
     pub attempts: Option<Vec<storage::PaymentAttempt>>,
+    pub status_history: Option<Vec<storage::StatusHistory>>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<Secret<String>>,
     pub email: Option<pii::Email>,
@@ -4249,6 +4388,11 @@ where
     pub poll_config: Option<router_types::PollConfig>,
     pub tax_data: Option<TaxData>,
     pub session_id: Option<String>,
+    /// Token of the card stored in the temp locker when the locker was unreachable
+    pub vault_fallback_token: Option<String>,
+    /// Whether the client secret is to be rotated once the connector accepts the payment, for
+    /// profiles with single use client secrets
+    pub rotate_client_secret: bool,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -4612,8 +4756,13 @@ pub async fn get_payment_filters(
     profile_id_list: Option<Vec<id_type::ProfileId>>,
 ) -> RouterResponse<api::PaymentListFiltersV2> {
     let merchant_connector_accounts = if let services::ApplicationResponse::Json(data) =
-        super::admin::list_payment_connectors(state, merchant.get_id().to_owned(), profile_id_list)
-            .await?
+        super::admin::list_payment_connectors(
+            state,
+            merchant.get_id().to_owned(),
+            profile_id_list,
+            None,
+        )
+        .await?
     {
         data
     } else {
@@ -4780,6 +4929,190 @@ pub async fn reset_process_sync_task(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+pub async fn add_auto_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_on: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::PaymentsAutoCaptureTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.get_id().to_owned(),
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentsAutoCaptureWorkflow;
+    let task = "PAYMENTS_AUTO_CAPTURE";
+    let tag = ["CAPTURE", "PAYMENT"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        capture_on,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Revokes the auto capture task of the payment attempt, so that a payment which has been voided
+/// is not captured at its `capture_on` time
+#[cfg(feature = "v1")]
+pub async fn revoke_auto_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PaymentsAutoCaptureWorkflow,
+        "PAYMENTS_AUTO_CAPTURE",
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    );
+    db.process_tracker_update_process_status_by_ids(
+        vec![process_tracker_id],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Schedules the capture of a manually captured payment which has been authorized by the
+/// operation, if the payment was created with a `capture_on` time in the future
+#[cfg(feature = "v1")]
+async fn add_auto_capture_task_if_required<F, Op, D>(
+    state: &SessionState,
+    operation: &Op,
+    payment_data: &D,
+) -> RouterResult<()>
+where
+    F: Send + Clone,
+    Op: Debug,
+    D: OperationSessionGetters<F>,
+{
+    let is_authorizing_operation = matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentCreate" | "PaymentConfirm" | "CompleteAuthorize"
+    );
+    if !is_authorizing_operation {
+        return Ok(());
+    }
+
+    let payment_attempt = payment_data.get_payment_attempt();
+    // The capture is scheduled according to the time of the test clock attached to the merchant,
+    // if any
+    let clock_offset =
+        super::test_clocks::get_merchant_time_offset(state, &payment_attempt.merchant_id).await?;
+    match (
+        payment_attempt.status,
+        payment_attempt.capture_method,
+        payment_attempt.capture_on,
+    ) {
+        (
+            storage_enums::AttemptStatus::Authorized,
+            Some(storage_enums::CaptureMethod::Manual),
+            Some(capture_on),
+        ) if capture_on > common_utils::date_time::now() + clock_offset => {
+            add_auto_capture_task(&*state.store, payment_attempt, capture_on - clock_offset)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding auto capture task to process tracker")
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn add_voucher_expiry_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    expires_at: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::VoucherExpiryTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.get_id().to_owned(),
+    };
+    let runner = storage::ProcessTrackerRunner::VoucherExpiryWorkflow;
+    let task = "VOUCHER_EXPIRY";
+    let tag = ["VOUCHER", "PAYMENT"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        expires_at,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Schedules the cancellation of a voucher payment at the expiry of the voucher issued by the
+/// connector, so that a voucher which is not paid by the customer does not leave the payment
+/// awaiting customer action indefinitely
+#[cfg(feature = "v1")]
+async fn add_voucher_expiry_task_if_required<F, Op, D>(
+    state: &SessionState,
+    operation: &Op,
+    payment_data: &D,
+) -> RouterResult<()>
+where
+    F: Send + Clone,
+    Op: Debug,
+    D: OperationSessionGetters<F>,
+{
+    let is_authorizing_operation = matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentCreate" | "PaymentConfirm" | "CompleteAuthorize"
+    );
+    let payment_attempt = payment_data.get_payment_attempt();
+    if !is_authorizing_operation
+        || payment_attempt.payment_method != Some(storage_enums::PaymentMethod::Voucher)
+        || payment_attempt.status != storage_enums::AttemptStatus::AuthenticationPending
+    {
+        return Ok(());
+    }
+
+    let expires_at = transformers::voucher_next_steps_check(payment_attempt.clone())?
+        .and_then(|voucher_next_steps| voucher_next_steps.expires_at)
+        .and_then(|expires_at| {
+            time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(expires_at) * 1_000_000).ok()
+        })
+        .map(common_utils::date_time::convert_to_pdt);
+    // The expiry is scheduled according to the time of the test clock attached to the merchant,
+    // if any
+    let clock_offset =
+        super::test_clocks::get_merchant_time_offset(state, &payment_attempt.merchant_id).await?;
+    match expires_at {
+        Some(expires_at) if expires_at > common_utils::date_time::now() + clock_offset => {
+            add_voucher_expiry_task(&*state.store, payment_attempt, expires_at - clock_offset)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding voucher expiry task to process tracker")
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(feature = "v1")]
 pub fn update_straight_through_routing<F, D>(
     payment_data: &mut D,
@@ -5279,6 +5612,13 @@ where
             .attach_printable("failed eligibility analysis and fallback")?;
         }
 
+        let connectors = connector_maintenance::filter_connectors_under_maintenance(
+            &state,
+            merchant_account.get_id(),
+            connectors,
+        )
+        .await;
+
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -5337,6 +5677,13 @@ where
             .attach_printable("failed eligibility analysis and fallback")?;
         }
 
+        let connectors = connector_maintenance::filter_connectors_under_maintenance(
+            &state,
+            merchant_account.get_id(),
+            connectors,
+        )
+        .await;
+
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -5363,7 +5710,15 @@ where
         .await;
     }
 
-    let new_pd = payment_data.clone();
+    let mut new_pd = payment_data.clone();
+    if let Some(payment_method_data) = routing::get_payment_method_data_with_card_bin_details(
+        &state,
+        new_pd.get_payment_method_data(),
+    )
+    .await
+    {
+        new_pd.set_payment_method_data(Some(payment_method_data));
+    }
     let transaction_data = core_routing::PaymentsDslInput::new(
         new_pd.get_setup_mandate(),
         new_pd.get_payment_attempt(),
@@ -6004,6 +6359,7 @@ where
     let connectors = routing::perform_static_routing_v1(
         state,
         merchant_account.get_id(),
+        key_store,
         routing_algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payment(transaction_data.clone()),
@@ -6023,6 +6379,20 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors = connector_maintenance::filter_connectors_under_maintenance(
+        state,
+        merchant_account.get_id(),
+        connectors,
+    )
+    .await;
+
+    let connectors = match business_profile.connector_canary_rollout.as_ref() {
+        Some(rollout) => routing::canary_rollout::perform_canary_rollout_split(connectors, rollout)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to perform canary rollout split")?,
+        None => connectors,
+    };
+
     // dynamic success based connector selection
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
     let connectors = {
@@ -6168,6 +6538,7 @@ pub async fn route_connector_v1_for_payouts(
     let connectors = routing::perform_static_routing_v1(
         state,
         merchant_account.get_id(),
+        key_store,
         routing_algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payout(transaction_data),
@@ -6186,6 +6557,13 @@ pub async fn route_connector_v1_for_payouts(
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors = connector_maintenance::filter_connectors_under_maintenance(
+        state,
+        merchant_account.get_id(),
+        connectors,
+    )
+    .await;
+
     let first_connector_choice = connectors
         .first()
         .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -6236,6 +6614,16 @@ pub async fn payment_external_authentication(
 
     let merchant_id = merchant_account.get_id();
     let storage_scheme = merchant_account.storage_scheme;
+    // 3RI authentications are performed only for merchant initiated payments during confirm
+    utils::when(
+        req.device_channel == api_models::payments::DeviceChannel::ThreeDsRequestorInitiated,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "device_channel 3RI is not supported for external authentication"
+                    .to_string(),
+            })
+        },
+    )?;
     let payment_id = req.payment_id;
     let payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
@@ -6496,6 +6884,7 @@ pub async fn payment_start_redirection(
             payment_method_data: None,
             amount: payment_attempt.amount_details.net_amount.to_string(),
             currency: payment_intent.amount_details.currency.to_string(),
+            page_config: None,
         },
     )))
 }
@@ -6523,6 +6912,57 @@ pub async fn get_extended_card_info(
     ))
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn rotate_client_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<payments_api::PaymentsClientSecretRotateResponse> {
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    helpers::validate_payment_status_against_not_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::IntentStatus::Failed,
+            storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::PartiallyCaptured,
+        ],
+        "rotate client secret",
+    )?;
+
+    let payment_intent =
+        helpers::rotate_client_secret(&state, payment_intent, &key_store, storage_scheme).await?;
+    let client_secret = payment_intent
+        .client_secret
+        .get_required_value("client_secret")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Client secret not found after rotation")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payments_api::PaymentsClientSecretRotateResponse {
+            payment_id: payment_intent.payment_id,
+            client_secret: client_secret.into(),
+        },
+    ))
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub async fn payments_manual_update(
     state: SessionState,
@@ -6708,6 +7148,7 @@ pub trait OperationSessionGetters<F> {
     fn get_disputes(&self) -> Vec<storage::Dispute>;
     fn get_authorizations(&self) -> Vec<diesel_models::authorization::Authorization>;
     fn get_attempts(&self) -> Option<Vec<storage::PaymentAttempt>>;
+    fn get_status_history(&self) -> Option<Vec<storage::StatusHistory>>;
     fn get_recurring_details(&self) -> Option<&RecurringDetails>;
     // TODO: this should be a mandatory field, should we throw an error instead of returning an Option?
     fn get_payment_intent_profile_id(&self) -> Option<&id_type::ProfileId>;
@@ -6721,6 +7162,7 @@ pub trait OperationSessionGetters<F> {
     fn get_mandate_connector(&self) -> Option<&MandateConnectorDetails>;
     fn get_force_sync(&self) -> Option<bool>;
     fn get_capture_method(&self) -> Option<enums::CaptureMethod>;
+    fn get_vault_fallback_token(&self) -> Option<&str>;
 
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt>;
@@ -6843,6 +7285,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.attempts.clone()
     }
 
+    fn get_status_history(&self) -> Option<Vec<storage::StatusHistory>> {
+        self.status_history.clone()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         self.recurring_details.as_ref()
     }
@@ -6898,6 +7344,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.payment_attempt.capture_method
     }
 
+    fn get_vault_fallback_token(&self) -> Option<&str> {
+        self.vault_fallback_token.as_deref()
+    }
+
     // #[cfg(feature = "v2")]
     // fn get_capture_method(&self) -> Option<enums::CaptureMethod> {
     //     Some(self.payment_intent.capture_method)
@@ -7091,6 +7541,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentIntentData<F> {
         todo!()
     }
 
+    fn get_status_history(&self) -> Option<Vec<storage::StatusHistory>> {
+        todo!()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         todo!()
     }
@@ -7139,6 +7593,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentIntentData<F> {
         todo!()
     }
 
+    fn get_vault_fallback_token(&self) -> Option<&str> {
+        None
+    }
+
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         todo!();
     }
@@ -7303,6 +7761,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentConfirmData<F> {
         todo!()
     }
 
+    fn get_status_history(&self) -> Option<Vec<storage::StatusHistory>> {
+        todo!()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         todo!()
     }
@@ -7351,6 +7813,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentConfirmData<F> {
         todo!()
     }
 
+    fn get_vault_fallback_token(&self) -> Option<&str> {
+        None
+    }
+
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         Some(&self.payment_attempt)
     }
@@ -7517,6 +7983,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentStatusData<F> {
         todo!()
     }
 
+    fn get_status_history(&self) -> Option<Vec<storage::StatusHistory>> {
+        todo!()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         todo!()
     }
@@ -7565,6 +8035,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentStatusData<F> {
         todo!()
     }
 
+    fn get_vault_fallback_token(&self) -> Option<&str> {
+        None
+    }
+
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         self.payment_attempt.as_ref()
     }
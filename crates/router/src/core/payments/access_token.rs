@@ -64,9 +64,6 @@ pub async fn add_access_token<
         .connector_name
         .supports_access_token(router_data.payment_method)
     {
-        let merchant_id = merchant_account.get_id();
-        let store = &*state.store;
-
         // `merchant_connector_id` may not be present in the below cases
         // - when straight through routing is used without passing the `merchant_connector_id`
         // - when creds identifier is passed
@@ -80,104 +77,14 @@ pub async fn add_access_token<
             .or(creds_identifier.map(|id| id.to_string()))
             .unwrap_or(connector.connector_name.to_string());
 
-        let old_access_token = store
-            .get_access_token(merchant_id, &merchant_connector_id_or_connector_name)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("DB error when accessing the access token")?;
-
-        let res = match old_access_token {
-            Some(access_token) => {
-                router_env::logger::debug!(
-                    "Access token found in redis for merchant_id: {:?}, payment_id: {:?}, connector: {} which has expiry of: {} seconds",
-                    merchant_account.get_id(),
-                    router_data.payment_id,
-                    connector.connector_name,
-                    access_token.expires
-                );
-                metrics::ACCESS_TOKEN_CACHE_HIT.add(
-                    &metrics::CONTEXT,
-                    1,
-                    &add_attributes([("connector", connector.connector_name.to_string())]),
-                );
-                Ok(Some(access_token))
-            }
-            None => {
-                metrics::ACCESS_TOKEN_CACHE_MISS.add(
-                    &metrics::CONTEXT,
-                    1,
-                    &add_attributes([("connector", connector.connector_name.to_string())]),
-                );
-
-                let cloned_router_data = router_data.clone();
-                let refresh_token_request_data = types::AccessTokenRequestData::try_from(
-                    router_data.connector_auth_type.clone(),
-                )
-                .attach_printable(
-                    "Could not create access token request, invalid connector account credentials",
-                )?;
-
-                let refresh_token_response_data: Result<types::AccessToken, types::ErrorResponse> =
-                    Err(types::ErrorResponse::default());
-                let refresh_token_router_data = payments::helpers::router_data_type_conversion::<
-                    _,
-                    api_types::AccessTokenAuth,
-                    _,
-                    _,
-                    _,
-                    _,
-                >(
-                    cloned_router_data,
-                    refresh_token_request_data,
-                    refresh_token_response_data,
-                );
-                refresh_connector_auth(
-                    state,
-                    connector,
-                    merchant_account,
-                    &refresh_token_router_data,
-                )
-                .await?
-                .async_map(|access_token| async move {
-                    let store = &*state.store;
-
-                    // The expiry should be adjusted for network delays from the connector
-                    // The access token might not have been expired when request is sent
-                    // But once it reaches the connector, it might expire because of the network delay
-                    // Subtract few seconds from the expiry in order to account for these network delays
-                    // This will reduce the expiry time by `REDUCE_ACCESS_TOKEN_EXPIRY_TIME` seconds
-                    let modified_access_token_with_expiry = types::AccessToken {
-                        expires: access_token
-                            .expires
-                            .saturating_sub(consts::REDUCE_ACCESS_TOKEN_EXPIRY_TIME.into()),
-                        ..access_token
-                    };
-
-                    logger::debug!(
-                        access_token_expiry_after_modification =
-                            modified_access_token_with_expiry.expires
-                    );
-
-                    if let Err(access_token_set_error) = store
-                        .set_access_token(
-                            merchant_id,
-                            &merchant_connector_id_or_connector_name,
-                            modified_access_token_with_expiry.clone(),
-                        )
-                        .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("DB error when setting the access token")
-                    {
-                        // If we are not able to set the access token in redis, the error should just be logged and proceed with the payment
-                        // Payments should not fail, once the access token is successfully created
-                        // The next request will create new access token, if required
-                        logger::error!(access_token_set_error=?access_token_set_error);
-                    }
-                    Some(modified_access_token_with_expiry)
-                })
-                .await
-            }
-        };
+        let res = get_or_refresh_access_token(
+            state,
+            connector,
+            merchant_account,
+            router_data,
+            &merchant_connector_id_or_connector_name,
+        )
+        .await?;
 
         Ok(types::AddAccessTokenResult {
             access_token_result: res,
@@ -191,6 +98,237 @@ pub async fn add_access_token<
     }
 }
 
+/// Returns `true` if the access token is about to expire and should be refreshed
+fn should_refresh_access_token(access_token: &types::AccessToken) -> bool {
+    access_token.expires <= consts::ACCESS_TOKEN_PREEMPTIVE_REFRESH_TIME
+}
+
+/// Gets the access token of the connector from the cache, refreshing it with the connector if it
+/// is missing or about to expire.
+///
+/// The refresh is guarded by a distributed lock per connector, so that concurrent requests wait for
+/// a single request to refresh the access token instead of each of them calling the connector.
+pub async fn get_or_refresh_access_token<
+    F: Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Res: Debug + Clone + 'static,
+>(
+    state: &SessionState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    router_data: &types::RouterData<F, Req, Res>,
+    merchant_connector_id_or_connector_name: &str,
+) -> RouterResult<Result<Option<types::AccessToken>, types::ErrorResponse>> {
+    let merchant_id = merchant_account.get_id();
+    let store = &*state.store;
+
+    let cached_access_token = store
+        .get_access_token(merchant_id, merchant_connector_id_or_connector_name)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("DB error when accessing the access token")?;
+
+    if let Some(access_token) = cached_access_token
+        .as_ref()
+        .filter(|access_token| !should_refresh_access_token(access_token))
+    {
+        logger::debug!(
+            "Access token found in redis for merchant_id: {:?}, payment_id: {:?}, connector: {} which has expiry of: {} seconds",
+            merchant_id,
+            router_data.payment_id,
+            connector.connector_name,
+            access_token.expires
+        );
+        metrics::ACCESS_TOKEN_CACHE_HIT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("connector", connector.connector_name.to_string())]),
+        );
+        return Ok(Ok(Some(access_token.clone())));
+    }
+
+    metrics::ACCESS_TOKEN_CACHE_MISS.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("connector", connector.connector_name.to_string())]),
+    );
+
+    // If the lock cannot be acquired because of a redis error, the access token is refreshed
+    // without holding the lock, as the payment should not fail because of the lock
+    let is_lock_acquired = store
+        .acquire_access_token_lock(
+            merchant_id,
+            merchant_connector_id_or_connector_name,
+            consts::ACCESS_TOKEN_LOCK_EXPIRY_TIME,
+        )
+        .await
+        .map_err(|error| logger::error!(access_token_lock_error=?error))
+        .unwrap_or(true);
+
+    if !is_lock_acquired {
+        // The access token is being refreshed by another request, the cached access token can
+        // still be used as it has not expired yet
+        if let Some(access_token) = cached_access_token {
+            return Ok(Ok(Some(access_token)));
+        }
+
+        if let Some(access_token) = wait_for_access_token_refresh(
+            state,
+            merchant_id,
+            merchant_connector_id_or_connector_name,
+        )
+        .await?
+        {
+            return Ok(Ok(Some(access_token)));
+        }
+
+        logger::warn!(
+            "Timed out waiting for the access token to be refreshed, refreshing the access token for connector: {}",
+            connector.connector_name
+        );
+    }
+
+    let refresh_result = refresh_and_store_access_token(
+        state,
+        connector,
+        merchant_account,
+        router_data,
+        merchant_connector_id_or_connector_name,
+    )
+    .await;
+
+    if is_lock_acquired {
+        // The lock expires on its own, so the error is only logged
+        store
+            .release_access_token_lock(merchant_id, merchant_connector_id_or_connector_name)
+            .await
+            .map_err(|error| logger::error!(access_token_lock_release_error=?error))
+            .ok();
+    }
+
+    match refresh_result? {
+        Ok(access_token) => Ok(Ok(Some(access_token))),
+        // If the connector could not issue a new access token, the cached access token can still be
+        // used until it expires
+        Err(error_response) => match cached_access_token {
+            Some(access_token) => {
+                logger::warn!(access_token_refresh_error=?error_response);
+                Ok(Ok(Some(access_token)))
+            }
+            None => Ok(Err(error_response)),
+        },
+    }
+}
+
+/// Polls the cache for the access token while it is being refreshed by another request
+async fn wait_for_access_token_refresh(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_connector_id_or_connector_name: &str,
+) -> RouterResult<Option<types::AccessToken>> {
+    for _retry in 0..consts::ACCESS_TOKEN_LOCK_MAX_RETRIES {
+        tokio::time::sleep(std::time::Duration::from_millis(
+            consts::ACCESS_TOKEN_LOCK_RETRY_DELAY_IN_MILLIS,
+        ))
+        .await;
+
+        let access_token = state
+            .store
+            .get_access_token(merchant_id, merchant_connector_id_or_connector_name)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("DB error when accessing the access token")?;
+
+        if access_token.is_some() {
+            return Ok(access_token);
+        }
+    }
+
+    Ok(None)
+}
+
+async fn refresh_and_store_access_token<
+    F: Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Res: Debug + Clone + 'static,
+>(
+    state: &SessionState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    router_data: &types::RouterData<F, Req, Res>,
+    merchant_connector_id_or_connector_name: &str,
+) -> RouterResult<Result<types::AccessToken, types::ErrorResponse>> {
+    let cloned_router_data = router_data.clone();
+    let refresh_token_request_data =
+        types::AccessTokenRequestData::try_from(router_data.connector_auth_type.clone())
+            .attach_printable(
+                "Could not create access token request, invalid connector account credentials",
+            )?;
+
+    let refresh_token_response_data: Result<types::AccessToken, types::ErrorResponse> =
+        Err(types::ErrorResponse::default());
+    let refresh_token_router_data = payments::helpers::router_data_type_conversion::<
+        _,
+        api_types::AccessTokenAuth,
+        _,
+        _,
+        _,
+        _,
+    >(
+        cloned_router_data,
+        refresh_token_request_data,
+        refresh_token_response_data,
+    );
+
+    let refresh_token_result = refresh_connector_auth(
+        state,
+        connector,
+        merchant_account,
+        &refresh_token_router_data,
+    )
+    .await?;
+
+    let access_token_result = refresh_token_result
+        .async_map(|access_token| async move {
+            // The expiry should be adjusted for network delays from the connector
+            // The access token might not have been expired when request is sent
+            // But once it reaches the connector, it might expire because of the network delay
+            // Subtract few seconds from the expiry in order to account for these network delays
+            // This will reduce the expiry time by `REDUCE_ACCESS_TOKEN_EXPIRY_TIME` seconds
+            let modified_access_token_with_expiry = types::AccessToken {
+                expires: access_token
+                    .expires
+                    .saturating_sub(consts::REDUCE_ACCESS_TOKEN_EXPIRY_TIME.into()),
+                ..access_token
+            };
+
+            logger::debug!(
+                access_token_expiry_after_modification = modified_access_token_with_expiry.expires
+            );
+
+            if let Err(access_token_set_error) = state
+                .store
+                .set_access_token(
+                    merchant_account.get_id(),
+                    merchant_connector_id_or_connector_name,
+                    modified_access_token_with_expiry.clone(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("DB error when setting the access token")
+            {
+                // If we are not able to set the access token in redis, the error should just be logged and proceed with the payment
+                // Payments should not fail, once the access token is successfully created
+                // The next request will create new access token, if required
+                logger::error!(access_token_set_error=?access_token_set_error);
+            }
+            modified_access_token_with_expiry
+        })
+        .await;
+
+    Ok(access_token_result)
+}
+
 pub async fn refresh_connector_auth(
     state: &SessionState,
     connector: &api_types::ConnectorData,
@@ -0,0 +1,269 @@
+use api_models::payments::{
+    PaymentsCaptureRequest, PaymentsResponse, PaymentsShipmentRequest, ShipmentLineItem,
+};
+use common_utils::{date_time, fp_utils::when, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::{HeaderPayload, PaymentIntent};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, CallConnectorAction},
+        utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain, storage},
+};
+
+const SHIPMENT_ID_MAX_LENGTH: usize = 64;
+
+/// Captures an authorized payment when its shipment is notified by the order management system
+/// of the merchant, if capture on shipment is enabled for the profile of the payment. The full
+/// amount capturable is captured, unless partial capture is enabled for the profile and the
+/// shipment is of a part of the line items, in which case the amount of the shipped line items is
+/// captured. The shipments are recorded, so that a payment is captured only once for a shipment
+/// and the line items shipped earlier are not captured again.
+#[instrument(skip_all, fields(shipment_id = %req.shipment_id))]
+pub async fn capture_on_shipment(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsShipmentRequest,
+) -> RouterResponse<PaymentsResponse> {
+    when(
+        req.shipment_id.trim().is_empty() || req.shipment_id.len() > SHIPMENT_ID_MAX_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "shipment_id must be between 1 and {SHIPMENT_ID_MAX_LENGTH} characters"
+                ),
+            }))
+        },
+    )?;
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), &payment_intent)?;
+
+    let payment_profile_id = payment_intent
+        .profile_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&(&state).into(), &key_store, &payment_profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: payment_profile_id.get_string_repr().to_owned(),
+        })?;
+    let capture_on_shipment_config = business_profile
+        .capture_on_shipment_config
+        .filter(|config| config.enabled)
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "capture on shipment is not enabled for the profile of the payment"
+                .to_string(),
+        }))?;
+
+    let shipped_line_items = match req.line_items.as_deref() {
+        Some(line_items) if capture_on_shipment_config.partial_capture_enabled => Some(
+            get_shipped_line_items(
+                &state,
+                &merchant_account,
+                &payment_intent,
+                &req.shipment_id,
+                line_items,
+            )
+            .await?,
+        ),
+        Some(_) | None => None,
+    };
+    let amount_to_capture = shipped_line_items.as_ref().map(|shipped_line_items| {
+        shipped_line_items
+            .0
+            .iter()
+            .map(|line_item| line_item.amount * line_item.quantity)
+            .sum::<MinorUnit>()
+    });
+
+    state
+        .store
+        .insert_shipment_capture(storage::ShipmentCaptureNew {
+            merchant_id: merchant_account.get_id().clone(),
+            payment_id: req.payment_id.clone(),
+            shipment_id: req.shipment_id.clone(),
+            line_items: shipped_line_items,
+            amount_to_capture,
+            created_at: date_time::now(),
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "payment is already captured for the shipment".to_string(),
+        })?;
+
+    logger::info!(
+        carrier = ?req.carrier,
+        tracking_number = ?req.tracking_number,
+        "Capturing payment on shipment"
+    );
+
+    let capture_request = PaymentsCaptureRequest {
+        payment_id: req.payment_id.clone(),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        amount_to_capture,
+        ..Default::default()
+    };
+    let response = Box::pin(payments::payments_core::<
+        api::Capture,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Capture>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        profile_id,
+        key_store,
+        payments::PaymentCapture,
+        capture_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    // The shipment can be notified again to retry the capture, if the capture was not initiated
+    if response.is_err() {
+        state
+            .store
+            .delete_shipment_capture_by_merchant_id_payment_id_shipment_id(
+                merchant_account.get_id(),
+                &req.payment_id,
+                &req.shipment_id,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete the shipment capture"))
+            .ok();
+    }
+
+    response
+}
+
+/// Resolves the line items of the order details of the payment which are shipped, along with the
+/// amount per quantity of each line item. The quantity of each line item is validated against the
+/// quantity ordered less the quantity shipped earlier.
+async fn get_shipped_line_items(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &PaymentIntent,
+    shipment_id: &str,
+    line_items: &[ShipmentLineItem],
+) -> RouterResult<storage::ShipmentCaptureLineItems> {
+    let order_details = core_utils::get_order_details_of_line_items(payment_intent, "shipped")?;
+    let requested_order_details = line_items
+        .iter()
+        .map(|line_item| {
+            core_utils::find_order_detail_of_line_item(
+                &order_details,
+                &line_item.product_name,
+                line_item.product_id.as_deref(),
+            )
+            .map(|order_detail| (order_detail, line_item))
+            .map_err(|error| {
+                let message = match error {
+                    core_utils::LineItemResolutionError::NotFound => format!(
+                        "line item {} is not in the order_details of the payment",
+                        line_item.product_name
+                    ),
+                    core_utils::LineItemResolutionError::Ambiguous => format!(
+                        "product_id is required for line item {}, as several order_details have its product_name",
+                        line_item.product_name
+                    ),
+                };
+                report!(errors::ApiErrorResponse::InvalidRequestData { message })
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let shipment_captures = state
+        .store
+        .find_shipment_captures_by_merchant_id_payment_id(
+            merchant_account.get_id(),
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the shipment captures of the payment")?;
+    // A shipment notified again is reported as a duplicate rather than as exceeding the quantities
+    when(
+        shipment_captures
+            .iter()
+            .any(|shipment_capture| shipment_capture.shipment_id == shipment_id),
+        || {
+            Err(report!(errors::ApiErrorResponse::GenericDuplicateError {
+                message: "payment is already captured for the shipment".to_string(),
+            }))
+        },
+    )?;
+
+    requested_order_details
+        .iter()
+        .map(|(order_detail, line_item)| {
+            let shipped_quantity: u32 = shipment_captures
+                .iter()
+                .filter_map(|shipment_capture| shipment_capture.line_items.as_ref())
+                .flat_map(|shipped_line_items| shipped_line_items.0.iter())
+                .filter(|shipped_line_item| {
+                    core_utils::is_line_item_of_order_detail(
+                        order_detail,
+                        &shipped_line_item.product_name,
+                        shipped_line_item.product_id.as_deref(),
+                    )
+                })
+                .map(|shipped_line_item| u32::from(shipped_line_item.quantity))
+                .sum();
+            let requested_quantity: u32 = requested_order_details
+                .iter()
+                .filter(|(other_order_detail, _)| std::ptr::eq(*other_order_detail, *order_detail))
+                .map(|(_, other_line_item)| u32::from(other_line_item.quantity))
+                .sum();
+
+            when(
+                line_item.quantity == 0
+                    || shipped_quantity + requested_quantity > u32::from(order_detail.quantity),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "quantity of line item {} exceeds the quantity left to be shipped",
+                            line_item.product_name
+                        ),
+                    }))
+                },
+            )?;
+
+            Ok(storage::ShipmentCaptureLineItem {
+                product_name: order_detail.product_name.clone(),
+                product_id: order_detail.product_id.clone(),
+                quantity: line_item.quantity,
+                amount: order_detail.amount,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()
+        .map(storage::ShipmentCaptureLineItems)
+}
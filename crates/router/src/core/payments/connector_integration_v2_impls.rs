@@ -48,6 +48,8 @@ mod dummy_connector_default_impl {
 
     impl<const T: u8> api::PaymentPostSessionTokensV2 for connector::DummyConnector<T> {}
 
+    impl<const T: u8> api::PaymentBankListV2 for connector::DummyConnector<T> {}
+
     impl<const T: u8>
         services::ConnectorIntegrationV2<
             api::Authorize,
@@ -214,6 +216,15 @@ mod dummy_connector_default_impl {
         > for connector::DummyConnector<T>
     {
     }
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::BankList,
+            types::PaymentFlowData,
+            types::BankListRequestData,
+            types::BankListResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
 
     impl<const T: u8>
         services::ConnectorIntegrationV2<
@@ -403,6 +414,32 @@ mod dummy_connector_default_impl {
     {
     }
 
+    #[cfg(feature = "payouts")]
+    impl<const T: u8> api::PayoutBalanceV2 for connector::DummyConnector<T> {}
+    #[cfg(feature = "payouts")]
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::PoBalance,
+            types::PayoutFlowData,
+            types::PayoutBalanceRequestData,
+            types::PayoutBalanceResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
+    #[cfg(feature = "payouts")]
+    impl<const T: u8> api::PayoutRecipientStatusV2 for connector::DummyConnector<T> {}
+    #[cfg(feature = "payouts")]
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::PoRecipientStatus,
+            types::PayoutFlowData,
+            types::PayoutsData,
+            types::PayoutRecipientStatusResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
     #[cfg(feature = "payouts")]
     impl<const T: u8> api::PayoutSyncV2 for connector::DummyConnector<T> {}
     #[cfg(feature = "payouts")]
@@ -593,6 +630,7 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl api::TaxCalculationV2 for $path::$connector{}
             impl api::PaymentSessionUpdateV2 for $path::$connector{}
             impl api::PaymentPostSessionTokensV2 for $path::$connector{}
+            impl api::PaymentBankListV2 for $path::$connector{}
             impl
             services::ConnectorIntegrationV2<api::Authorize,types::PaymentFlowData, types::PaymentsAuthorizeData, types::PaymentsResponseData>
             for $path::$connector{}
@@ -685,6 +723,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::PaymentsPostSessionTokensData,
                 types::PaymentsResponseData,
                 > for $path::$connector{}
+
+            impl services::ConnectorIntegrationV2<
+            api::BankList,
+            types::PaymentFlowData,
+                types::BankListRequestData,
+                types::BankListResponseData,
+                > for $path::$connector{}
     )*
     };
 }
@@ -1101,6 +1146,7 @@ default_imp_for_new_connector_integration_payouts!(
     connector::Fiservemea,
     connector::Fiuu,
     connector::Forte,
+    connector::Generichttp,
     connector::Globalpay,
     connector::Globepay,
     connector::Gpayments,
@@ -1130,6 +1176,7 @@ default_imp_for_new_connector_integration_payouts!(
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
+    connector::Plugin,
     connector::Powertranz,
     connector::Rapyd,
     connector::Razorpay,
@@ -1496,6 +1543,120 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connector::Plaid
 );
 
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_new_connector_integration_payouts_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutBalanceV2 for $path::$connector {}
+            impl
+            services::ConnectorIntegrationV2<
+            api::PoBalance,
+            types::PayoutFlowData,
+            types::PayoutBalanceRequestData,
+            types::PayoutBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_new_connector_integration_payouts_balance!(
+    connector::Adyenplatform,
+    connector::Aci,
+    connector::Adyen,
+    connector::Authorizedotnet,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wise,
+    connector::Plaid
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_new_connector_integration_payouts_recipient_status {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutRecipientStatusV2 for $path::$connector {}
+            impl
+            services::ConnectorIntegrationV2<
+            api::PoRecipientStatus,
+            types::PayoutFlowData,
+            types::PayoutsData,
+            types::PayoutRecipientStatusResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_new_connector_integration_payouts_recipient_status!(
+    connector::Adyenplatform,
+    connector::Aci,
+    connector::Adyen,
+    connector::Authorizedotnet,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wise,
+    connector::Plaid
+);
+
 #[cfg(feature = "payouts")]
 macro_rules! default_imp_for_new_connector_integration_payouts_sync {
     ($($path:ident::$connector:ident),*) => {
@@ -1702,6 +1863,7 @@ default_imp_for_new_connector_integration_frm!(
     connector::Fiserv,
     connector::Fiservemea,
     connector::Forte,
+    connector::Generichttp,
     connector::Fiuu,
     connector::Globalpay,
     connector::Globepay,
@@ -1732,6 +1894,7 @@ default_imp_for_new_connector_integration_frm!(
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
+    connector::Plugin,
     connector::Powertranz,
     connector::Rapyd,
     connector::Razorpay,
@@ -2167,6 +2330,7 @@ default_imp_for_new_connector_integration_connector_authentication!(
     connector::Fiserv,
     connector::Fiservemea,
     connector::Forte,
+    connector::Generichttp,
     connector::Fiuu,
     connector::Globalpay,
     connector::Globepay,
@@ -2198,6 +2362,7 @@ default_imp_for_new_connector_integration_connector_authentication!(
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
+    connector::Plugin,
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
@@ -0,0 +1,291 @@
+use std::str::FromStr;
+
+use api_models::payments::{DccChoice, PaymentsDccOfferRequest, PaymentsDccOfferResponse};
+use common_utils::{date_time, fp_utils::when, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use rust_decimal::Decimal;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{helpers, presentment_currency},
+    },
+    routes::SessionState,
+    services,
+    types::{self, api, domain, storage, storage::enums},
+    utils,
+};
+
+/// A dynamic currency conversion offer, stored until it expires or is chosen by the customer at
+/// confirm
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct DccOffer {
+    payment_id: common_utils::id_type::PaymentId,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    presentment_amount: MinorUnit,
+    presentment_currency: enums::Currency,
+    base_exchange_rate: String,
+    markup_in_basis_points: u16,
+    exchange_rate: String,
+    offered_at: PrimitiveDateTime,
+}
+
+fn get_dcc_offer_key(merchant_id: &common_utils::id_type::MerchantId, offer_id: &str) -> String {
+    format!(
+        "{}{}_{}",
+        consts::DCC_OFFER_REDIS_KEY_PREFIX,
+        merchant_id.get_string_repr(),
+        offer_id
+    )
+}
+
+/// Offers the customer to pay a payment in the currency of their card, at the current forex rates
+/// with the markup configured for the profile of the payment. The offer is valid for the duration
+/// configured, and is accepted or declined by the customer when the payment is confirmed.
+#[instrument(skip_all, fields(presentment_currency = %req.presentment_currency))]
+pub async fn create_dcc_offer(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsDccOfferRequest,
+) -> RouterResponse<PaymentsDccOfferResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    helpers::authenticate_client_secret(Some(&req.client_secret), &payment_intent)?;
+
+    when(
+        !matches!(
+            payment_intent.status,
+            enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "dynamic currency conversion cannot be offered for a payment in {} status",
+                    payment_intent.status
+                ),
+            }))
+        },
+    )?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&(&state).into(), &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    let dcc_config = business_profile
+        .dcc_config
+        .filter(|config| config.enabled)
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "dynamic currency conversion is not enabled for the profile of the payment"
+                .to_string(),
+        }))?;
+
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let amount = payment_attempt.net_amount.get_total_amount();
+    let currency = payment_attempt
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("currency is not set in payment_attempt")?;
+    when(currency == req.presentment_currency, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "presentment_currency must be different from the currency of the payment"
+                .to_string(),
+        }))
+    })?;
+
+    let base_exchange_rate =
+        presentment_currency::get_exchange_rate(&state, currency, req.presentment_currency).await?;
+    let exchange_rate = presentment_currency::round_exchange_rate(
+        base_exchange_rate
+            * (Decimal::ONE + Decimal::new(i64::from(dcc_config.markup_in_basis_points), 4)),
+    );
+    let presentment_amount = presentment_currency::convert_amount(
+        amount,
+        currency,
+        req.presentment_currency,
+        exchange_rate,
+    )?;
+
+    let offered_at = date_time::now();
+    let offer_validity = state.conf.dcc.offer_validity_in_secs;
+    let offer_id = utils::generate_id(consts::ID_LENGTH, "dcc_offer");
+    let offer = DccOffer {
+        payment_id: payment_intent.payment_id.clone(),
+        amount,
+        currency,
+        presentment_amount,
+        presentment_currency: req.presentment_currency,
+        base_exchange_rate: presentment_currency::format_exchange_rate(base_exchange_rate),
+        markup_in_basis_points: dcc_config.markup_in_basis_points,
+        exchange_rate: exchange_rate.to_string(),
+        offered_at,
+    };
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_dcc_offer_key(merchant_account.get_id(), &offer_id),
+            &offer,
+            offer_validity,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the dynamic currency conversion offer")?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsDccOfferResponse {
+            offer_id,
+            payment_id: offer.payment_id,
+            amount,
+            currency,
+            presentment_amount,
+            presentment_currency: offer.presentment_currency,
+            exchange_rate: offer.exchange_rate,
+            markup_in_basis_points: offer.markup_in_basis_points,
+            expires_at: offered_at.saturating_add(time::Duration::seconds(offer_validity)),
+        },
+    ))
+}
+
+/// The details of the dynamic currency conversion offer chosen by the customer at confirm, which
+/// must be an unexpired offer for the payment and its current amount
+#[instrument(skip_all, fields(offer_id = %dcc_choice.offer_id))]
+pub async fn get_dcc_details(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_attempt: &storage::PaymentAttempt,
+    dcc_choice: &DccChoice,
+) -> RouterResult<diesel_models::DccDetails> {
+    let offer = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<DccOffer>(
+            &get_dcc_offer_key(merchant_id, &dcc_choice.offer_id),
+            "DccOffer",
+        )
+        .await
+        .map_err(|error| match error.current_context() {
+            redis_interface::errors::RedisError::NotFound => {
+                error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "dynamic currency conversion offer is not found or has expired"
+                        .to_string(),
+                })
+            }
+            _ => error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get the dynamic currency conversion offer"),
+        })?;
+
+    when(offer.payment_id != payment_attempt.payment_id, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "dynamic currency conversion offer is not for the payment".to_string(),
+        }))
+    })?;
+    when(
+        offer.amount != payment_attempt.net_amount.get_total_amount()
+            || Some(offer.currency) != payment_attempt.currency,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount of the payment changed after the offer was made".to_string(),
+            }))
+        },
+    )?;
+
+    Ok(diesel_models::DccDetails {
+        offer_id: dcc_choice.offer_id.clone(),
+        amount: offer.amount,
+        currency: offer.currency,
+        presentment_amount: offer.presentment_amount,
+        presentment_currency: offer.presentment_currency,
+        base_exchange_rate: offer.base_exchange_rate,
+        markup_in_basis_points: offer.markup_in_basis_points,
+        exchange_rate: offer.exchange_rate,
+        accepted: dcc_choice.accepted,
+        applied: false,
+        offered_at: offer.offered_at,
+        decided_at: date_time::now(),
+    })
+}
+
+/// Whether the dynamic currency conversion accepted by the customer is applied by the connector of
+/// the attempt, which is only the case if the connector supports it and the amount of the attempt
+/// is the amount offered
+pub fn is_dcc_applicable(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_name: &str,
+) -> bool {
+    let Some(dcc_details) = payment_attempt
+        .dcc_details
+        .as_ref()
+        .filter(|dcc_details| dcc_details.accepted)
+    else {
+        return false;
+    };
+
+    let is_connector_supported = api::enums::Connector::from_str(connector_name)
+        .map(|connector| state.conf.dcc.connector_list.contains(&connector))
+        .unwrap_or(false);
+    if !is_connector_supported {
+        logger::info!(
+            connector = connector_name,
+            "Dynamic currency conversion is not supported by the connector"
+        );
+    }
+
+    is_connector_supported
+        && dcc_details.amount == payment_attempt.net_amount.get_total_amount()
+        && Some(dcc_details.currency) == payment_attempt.currency
+}
+
+/// The currency conversion to be applied by the connector at authorization, if the customer
+/// accepted a dynamic currency conversion applicable to the attempt
+pub fn get_connector_currency_conversion(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_name: &str,
+) -> Option<types::ConnectorCurrencyConversion> {
+    is_dcc_applicable(state, payment_attempt, connector_name)
+        .then_some(payment_attempt.dcc_details.as_ref())
+        .flatten()
+        .map(|dcc_details| types::ConnectorCurrencyConversion {
+            presentment_currency: dcc_details.presentment_currency,
+            presentment_amount: dcc_details.presentment_amount,
+            exchange_rate: dcc_details.exchange_rate.clone(),
+        })
+}
@@ -460,6 +460,7 @@ default_imp_for_connector_request_id!(
     connector::Fiservemea,
     connector::Fiuu,
     connector::Forte,
+    connector::Generichttp,
     connector::Globalpay,
     connector::Globepay,
     connector::Gocardless,
@@ -489,6 +490,7 @@ default_imp_for_connector_request_id!(
     connector::Payu,
     connector::Placetopay,
     connector::Plaid,
+    connector::Plugin,
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
@@ -1095,6 +1097,146 @@ default_imp_for_payouts_retrieve!(
     connector::Wise
 );
 
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_payouts_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutBalance for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PoBalance,
+            types::PayoutBalanceRequestData,
+            types::PayoutBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutBalance for connector::DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::PoBalance,
+        types::PayoutBalanceRequestData,
+        types::PayoutBalanceResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_payouts_balance!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Authorizedotnet,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_payouts_recipient_status {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutRecipientStatus for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PoRecipientStatus,
+            types::PayoutsData,
+            types::PayoutRecipientStatusResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutRecipientStatus for connector::DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::PoRecipientStatus,
+        types::PayoutsData,
+        types::PayoutRecipientStatusResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_payouts_recipient_status!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Authorizedotnet,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
+
 #[cfg(feature = "payouts")]
 macro_rules! default_imp_for_payouts_eligibility {
     ($($path:ident::$connector:ident),*) => {
@@ -1663,6 +1805,7 @@ default_imp_for_fraud_check!(
     connector::Fiservemea,
     connector::Fiuu,
     connector::Forte,
+    connector::Generichttp,
     connector::Globalpay,
     connector::Globepay,
     connector::Gocardless,
@@ -1694,6 +1837,7 @@ default_imp_for_fraud_check!(
     connector::Payu,
     connector::Placetopay,
     connector::Plaid,
+    connector::Plugin,
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
@@ -2294,6 +2438,7 @@ default_imp_for_connector_authentication!(
     connector::Fiservemea,
     connector::Fiuu,
     connector::Forte,
+    connector::Generichttp,
     connector::Globalpay,
     connector::Globepay,
     connector::Gocardless,
@@ -2323,6 +2468,7 @@ default_imp_for_connector_authentication!(
     connector::Payu,
     connector::Placetopay,
     connector::Plaid,
+    connector::Plugin,
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
@@ -2602,3 +2748,68 @@ default_imp_for_post_session_tokens!(
     connector::Wellsfargopayout,
     connector::Wise
 );
+
+macro_rules! default_imp_for_bank_list {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl api::PaymentBankList for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+                api::BankList,
+                types::BankListRequestData,
+                types::BankListResponseData
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentBankList for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::BankList,
+        types::BankListRequestData,
+        types::BankListResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_bank_list!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Authorizedotnet,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
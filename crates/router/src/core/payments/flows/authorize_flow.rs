@@ -317,6 +317,14 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                     )
                     .to_payment_failed_response()?;
 
+                connector
+                    .connector
+                    .validate_statement_descriptor(
+                        self.request.statement_descriptor.as_deref(),
+                        self.request.statement_descriptor_suffix.as_deref(),
+                    )
+                    .to_payment_failed_response()?;
+
                 if crate::connector::utils::PaymentsAuthorizeRequestData::is_customer_initiated_mandate_payment(
                     &self.request,
                 ) {
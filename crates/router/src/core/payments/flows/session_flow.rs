@@ -13,6 +13,7 @@ use router_env::metrics::add_attributes;
 
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
+    consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResult},
         payments::{self, access_token, helpers, transformers, PaymentData},
@@ -445,6 +446,34 @@ async fn create_applepay_session_token(
             | (None, None) => {
                 let apple_pay_session_request = apple_pay_session_request_optional
                     .attach_printable("Failed to obtain apple pay session request")?;
+
+                let cache_key = connector
+                    .merchant_connector_id
+                    .as_ref()
+                    .filter(|_| state.conf.session_token_cache.enabled)
+                    .map(|merchant_connector_id| {
+                        get_apple_pay_merchant_session_cache_key(
+                            merchant_connector_id,
+                            &apple_pay_session_request,
+                        )
+                    });
+                if let Some(cached_session) =
+                    get_cached_apple_pay_merchant_session(state, cache_key.as_deref()).await
+                {
+                    logger::debug!("Using cached apple pay merchant session");
+                    return create_apple_pay_session_response(
+                        router_data,
+                        Some(payment_types::ApplePaySessionResponse::NoThirdPartySdk(
+                            cached_session,
+                        )),
+                        Some(applepay_payment_request),
+                        connector.connector_name.to_string(),
+                        delayed_response,
+                        payment_types::NextActionCall::Confirm,
+                        header_payload,
+                    );
+                }
+
                 let applepay_session_request = build_apple_pay_session_request(
                     state,
                     apple_pay_session_request.clone(),
@@ -493,26 +522,29 @@ async fn create_applepay_session_token(
 
                 // logging the error if present in session call response
                 log_session_response_if_error(&updated_response);
-                updated_response
+                let apple_pay_session_response_body = updated_response
                     .ok()
-                    .and_then(|apple_pay_res| {
-                        apple_pay_res
-                            .map(|res| {
-                                let response: Result<
-                                    payment_types::NoThirdPartySdkSessionResponse,
-                                    Report<common_utils::errors::ParsingError>,
-                                > = res.response.parse_struct("NoThirdPartySdkSessionResponse");
-
-                                // logging the parsing failed error
-                                if let Err(error) = response.as_ref() {
-                                    logger::error!(?error);
-                                };
-
-                                response.ok()
-                            })
-                            .ok()
-                    })
-                    .flatten()
+                    .and_then(|apple_pay_res| apple_pay_res.ok())
+                    .map(|res| res.response);
+                let apple_pay_session_response = apple_pay_session_response_body
+                    .as_deref()
+                    .and_then(parse_apple_pay_merchant_session);
+
+                if let (Some(cache_key), Some(session), Some(session_response_body)) = (
+                    cache_key.as_deref(),
+                    apple_pay_session_response.as_ref(),
+                    apple_pay_session_response_body.as_deref(),
+                ) {
+                    cache_apple_pay_merchant_session(
+                        state,
+                        cache_key,
+                        session,
+                        session_response_body,
+                    )
+                    .await;
+                }
+
+                apple_pay_session_response
             }
             _ => {
                 logger::debug!("Skipping apple pay session call based on the browser name");
@@ -535,6 +567,91 @@ async fn create_applepay_session_token(
     }
 }
 
+fn parse_apple_pay_merchant_session(
+    response_body: &[u8],
+) -> Option<payment_types::NoThirdPartySdkSessionResponse> {
+    let response: Result<
+        payment_types::NoThirdPartySdkSessionResponse,
+        Report<common_utils::errors::ParsingError>,
+    > = response_body.parse_struct("NoThirdPartySdkSessionResponse");
+
+    // logging the parsing failed error
+    if let Err(error) = response.as_ref() {
+        logger::error!(?error);
+    };
+
+    response.ok()
+}
+
+/// Apple Pay merchant sessions do not depend on the payment, so they are cached per merchant
+/// connector account and session request
+fn get_apple_pay_merchant_session_cache_key(
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    apple_pay_session_request: &payment_types::ApplepaySessionRequest,
+) -> String {
+    format!(
+        "{}{}_{}_{}_{}",
+        consts::APPLE_PAY_MERCHANT_SESSION_CACHE_PREFIX,
+        merchant_connector_id.get_string_repr(),
+        apple_pay_session_request.merchant_identifier,
+        apple_pay_session_request.initiative_context,
+        apple_pay_session_request.display_name,
+    )
+}
+
+async fn get_cached_apple_pay_merchant_session(
+    state: &routes::SessionState,
+    cache_key: Option<&str>,
+) -> Option<payment_types::NoThirdPartySdkSessionResponse> {
+    let cache_key = cache_key?;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error))
+        .ok()?;
+
+    redis_conn
+        .get_key::<Option<Vec<u8>>>(cache_key)
+        .await
+        .map_err(|error| logger::error!(?error))
+        .ok()
+        .flatten()
+        .as_deref()
+        .and_then(parse_apple_pay_merchant_session)
+}
+
+/// Caches the raw Apple Pay merchant session until shortly before it expires
+async fn cache_apple_pay_merchant_session(
+    state: &routes::SessionState,
+    cache_key: &str,
+    session: &payment_types::NoThirdPartySdkSessionResponse,
+    session_response_body: &[u8],
+) {
+    let expires_in = i64::try_from(session.expires_at / 1000)
+        .unwrap_or(i64::MAX)
+        .saturating_sub(common_utils::date_time::now_unix_timestamp());
+    let ttl = state
+        .conf
+        .session_token_cache
+        .apple_pay_merchant_session_ttl_in_secs
+        .min(expires_in);
+    if ttl <= 0 {
+        return;
+    }
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(cache_key, session_response_body.to_vec(), ttl)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(apple_pay_merchant_session_cache_error=?error);
+    }
+}
+
 fn create_paze_session_token(
     router_data: &types::PaymentsSessionRouterData,
     _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
@@ -449,15 +449,18 @@ pub async fn get_token_pm_type_mandate_details(
         mandate_connector_details,
         payment_method_info,
     ) = match mandate_type {
-        Some(api::MandateTransactionType::NewMandateTransaction) => (
-            request.payment_token.to_owned(),
-            request.payment_method,
-            request.payment_method_type,
-            mandate_data.clone(),
-            None,
-            None,
-            None,
-        ),
+        Some(api::MandateTransactionType::NewMandateTransaction) => {
+            validate_signed_mandate_file(state, request, merchant_account).await?;
+            (
+                request.payment_token.to_owned(),
+                request.payment_method,
+                request.payment_method_type,
+                mandate_data.clone(),
+                None,
+                None,
+                None,
+            )
+        }
         Some(api::MandateTransactionType::RecurringMandateTransaction) => {
             match &request.recurring_details {
                 Some(recurring_details) => {
@@ -1165,6 +1168,53 @@ fn validate_new_mandate_request(
     Ok(())
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+/// Validates the mandate document signed by the customer, which is stored as evidence of the
+/// e-mandate of a bank debit payment
+async fn validate_signed_mandate_file(
+    state: &SessionState,
+    request: &api::PaymentsRequest,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<()> {
+    let Some(signed_mandate_file_id) = request
+        .mandate_data
+        .as_ref()
+        .and_then(|mandate_data| mandate_data.customer_acceptance.as_ref())
+        .and_then(|customer_acceptance| customer_acceptance.signed_mandate_file_id.as_ref())
+    else {
+        return Ok(());
+    };
+
+    utils::when(
+        request
+            .payment_method
+            .is_some_and(|payment_method| payment_method != api_enums::PaymentMethod::BankDebit),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`mandate_data.customer_acceptance.signed_mandate_file_id` is applicable \
+                          only to bank debit mandates"
+                    .into()
+            }))
+        },
+    )?;
+
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(
+            merchant_account.get_id(),
+            signed_mandate_file_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Signed mandate file not found")?;
+    utils::when(!file_metadata.available, || {
+        Err(report!(errors::ApiErrorResponse::FileNotAvailable))
+    })
+}
+
 pub fn validate_customer_id_mandatory_cases(
     has_setup_future_usage: bool,
     customer_id: Option<&id_type::CustomerId>,
@@ -1719,6 +1769,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                         default_payment_method_id: None,
                         updated_by: None,
                         version: hyperswitch_domain_models::consts::API_VERSION,
+                        recipient_kyc_status: None,
                     };
                     metrics::CUSTOMER_CREATED.add(&metrics::CONTEXT, 1, &[]);
                     db.insert_customer(new_customer, key_manager_state, key_store, storage_scheme)
@@ -2762,6 +2813,7 @@ pub fn validate_payment_method_type_against_payment_method(
                 | api_enums::PaymentMethodType::DanamonVa
                 | api_enums::PaymentMethodType::MandiriVa
                 | api_enums::PaymentMethodType::LocalBankTransfer
+                | api_enums::PaymentMethodType::VirtualAccount
         ),
         api_enums::PaymentMethod::BankDebit => matches!(
             payment_method_type,
@@ -3158,6 +3210,7 @@ pub fn generate_mandate(
                 )
                 .set_customer_user_agent(customer_acceptance.get_user_agent())
                 .set_customer_accepted_at(Some(customer_acceptance.get_accepted_at()))
+                .set_signed_mandate_file_id(customer_acceptance.signed_mandate_file_id.clone())
                 .set_metadata(payment_method_data_option.map(|payment_method_data| {
                     pii::SecretSerdeValue::new(
                         serde_json::to_value(payment_method_data).unwrap_or_default(),
@@ -3281,6 +3334,151 @@ pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     })
 }
 
+/// The amount of a payment which has been authorized cannot be updated by the `PaymentUpdate`
+/// operation, it is updated by incrementing the authorization at the connector instead. Returns the
+/// incremental authorization request for the updated amount if the payment has been authorized,
+/// and `None` otherwise. Other fields cannot be updated along with the amount of an authorized
+/// payment.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn get_incremental_authorization_request_for_amount_update(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    auth_flow: services::AuthFlow,
+    request: &api::PaymentsRequest,
+) -> RouterResult<Option<api_models::payments::PaymentsIncrementalAuthorizationRequest>> {
+    use crate::types::api::PaymentIdTypeExt;
+
+    let Some(amount) = request.amount else {
+        return Ok(None);
+    };
+    let payment_id = request
+        .payment_id
+        .as_ref()
+        .get_required_value("payment_id")?
+        .get_payment_intent_id()
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if !matches!(
+        payment_intent.status,
+        storage_enums::IntentStatus::RequiresCapture
+            | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+    ) {
+        return Ok(None);
+    }
+
+    fp_utils::when(auth_flow != services::AuthFlow::Merchant, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The amount of an authorized payment can only be updated using the API key"
+                .to_string(),
+        })
+    })?;
+    validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[storage_enums::IntentStatus::RequiresCapture],
+        "update the amount of",
+    )?;
+    fp_utils::when(request.confirm == Some(true), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "An authorized payment cannot be confirmed again".to_string(),
+        })
+    })?;
+    fp_utils::when(request.surcharge_details.is_some(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The surcharge of an authorized payment cannot be updated".to_string(),
+        })
+    })?;
+
+    // The incremental authorization only updates the amount of the payment, so the other fields
+    // of the request cannot be updated along with it
+    let other_fields = api::PaymentsRequest {
+        amount: None,
+        currency: None,
+        payment_id: None,
+        merchant_id: None,
+        confirm: None,
+        ..request.clone()
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode the payments update request")?;
+    let no_fields = api::PaymentsRequest::default()
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payments update request")?;
+    fp_utils::when(other_fields != no_fields, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The amount of an authorized payment cannot be updated along with other fields, update them in a separate request".to_string(),
+        })
+    })?;
+
+    fp_utils::when(
+        payment_intent.incremental_authorization_allowed != Some(true),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The amount of this payment cannot be updated because the connector does not support incremental authorization for it".to_string(),
+            })
+        },
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    fp_utils::when(
+        request
+            .currency
+            .is_some_and(|currency| Some(currency) != payment_attempt.currency),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The currency of an authorized payment cannot be updated".to_string(),
+            })
+        },
+    )?;
+
+    // The surcharge and taxes which were authorized along with the order amount are
+    // authorized again along with the updated order amount
+    let mut net_amount = payment_attempt.net_amount.clone();
+    net_amount.set_order_amount(amount.into());
+    let total_amount = net_amount.get_total_amount();
+
+    fp_utils::when(
+        total_amount <= payment_attempt.net_amount.get_total_amount(),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The amount of an authorized payment can only be increased".to_string(),
+            })
+        },
+    )?;
+
+    Ok(Some(
+        api_models::payments::PaymentsIncrementalAuthorizationRequest {
+            payment_id,
+            amount: total_amount,
+            reason: Some("Amount updated through payments update".to_string()),
+        },
+    ))
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_pm_or_token_given(
     payment_method: &Option<api_enums::PaymentMethod>,
@@ -4150,6 +4348,9 @@ impl AttemptType {
             organization_id: old_payment_attempt.organization_id,
             profile_id: old_payment_attempt.profile_id,
             connector_mandate_detail: None,
+            presentment_currency: old_payment_attempt.presentment_currency,
+            presentment_amount: old_payment_attempt.presentment_amount,
+            exchange_rate: old_payment_attempt.exchange_rate,
         }
     }
 
@@ -5548,6 +5749,204 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+// This function validates the return_url against the domains allowed in the redirect config of the profile
+pub fn validate_return_url_for_profile(
+    return_url: Option<&url::Url>,
+    business_profile: &domain::Profile,
+) -> Result<(), errors::ApiErrorResponse> {
+    let allowed_domains = business_profile
+        .redirect_config
+        .as_ref()
+        .and_then(|redirect_config| redirect_config.allowed_return_url_domains.as_ref());
+
+    match (return_url, allowed_domains) {
+        (Some(return_url), Some(allowed_domains)) => {
+            let is_allowed = return_url.host_str().is_some_and(|host| {
+                common_utils::link_utils::is_host_allowed(host, allowed_domains)
+            });
+            fp_utils::when(!is_allowed, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "return_url domain is not allowed for the profile".to_string(),
+                })
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates the currency of a payment or payout against the currencies allowed for the profile
+pub fn validate_currency_for_profile(
+    currency: storage_enums::Currency,
+    business_profile: &domain::Profile,
+) -> Result<(), errors::ApiErrorResponse> {
+    fp_utils::when(!business_profile.is_currency_allowed(currency), || {
+        Err(errors::ApiErrorResponse::CurrencyNotSupported {
+            message: format!(
+                "Currency {currency} is not allowed for the profile {}. Allowed currencies are: {}",
+                business_profile.get_id().get_string_repr(),
+                business_profile
+                    .allowed_currencies
+                    .iter()
+                    .flatten()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        })
+    })
+}
+
+/// Fills in the capture method, setup future usage and authentication type which are not passed in
+/// the payment request from the payment defaults of the profile. The values passed in the request
+/// take precedence over the defaults of the profile.
+pub fn apply_profile_payment_defaults(
+    mut request: api::PaymentsRequest,
+    business_profile: &domain::Profile,
+) -> api::PaymentsRequest {
+    if let Some(payment_defaults) = business_profile.payment_defaults_config.as_ref() {
+        request.capture_method = request.capture_method.or(payment_defaults.capture_method);
+        request.setup_future_usage = request
+            .setup_future_usage
+            .or(payment_defaults.setup_future_usage);
+        request.authentication_type = request
+            .authentication_type
+            .or(payment_defaults.authentication_type);
+    }
+    request
+}
+
+/// Validates the origin of a client side request, authenticated with the client secret, against the
+/// origins allowed for the profile. Requests from other origins are only logged in report only mode.
+pub fn validate_client_origin_for_profile(
+    auth_flow: services::AuthFlow,
+    request_origin: Option<&str>,
+    business_profile: &domain::Profile,
+    resource: &str,
+) -> RouterResult<()> {
+    let client_origin_config = match (auth_flow, business_profile.client_origin_config.as_ref()) {
+        (services::AuthFlow::Client, Some(client_origin_config)) => client_origin_config,
+        _ => return Ok(()),
+    };
+
+    let domain_in_req = request_origin
+        .and_then(|origin| url::Url::parse(origin).ok())
+        .and_then(|url| {
+            url.host_str().map(|host| match url.port() {
+                Some(port) => format!("{}:{}", host, port),
+                None => host.to_string(),
+            })
+        });
+    let is_origin_allowed = domain_in_req.as_deref().is_some_and(|domain| {
+        common_utils::validation::validate_domain_against_allowed_domains(
+            domain,
+            client_origin_config.allowed_origins.clone(),
+        )
+    });
+    if is_origin_allowed {
+        return Ok(());
+    }
+
+    metrics::CLIENT_ORIGIN_NOT_ALLOWED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("resource", resource.to_string()),
+            (
+                "enforcement_mode",
+                client_origin_config.enforcement_mode.to_string(),
+            ),
+        ]),
+    );
+    match client_origin_config.enforcement_mode {
+        enums::OriginEnforcementMode::ReportOnly => {
+            logger::warn!(
+                profile_id = ?business_profile.get_id(),
+                requestor = ?domain_in_req,
+                "Access to {} from an origin which is not allowed for the profile",
+                resource
+            );
+            Ok(())
+        }
+        enums::OriginEnforcementMode::Enforce => {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: resource.to_string(),
+            }))
+            .attach_printable_lazy(|| {
+                format!(
+                    "Access to {} is forbidden from requestor - {:?}",
+                    resource, domain_in_req
+                )
+            })
+        }
+    }
+}
+
+/// Issues a new client secret for the payment. The previous client secret can no longer be used to
+/// authenticate any client side request for the payment.
+#[cfg(feature = "v1")]
+pub async fn rotate_client_secret(
+    state: &SessionState,
+    payment_intent: PaymentIntent,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<PaymentIntent> {
+    let client_secret = payment_intent.payment_id.generate_client_secret();
+    state
+        .store
+        .update_payment_intent(
+            &state.into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::ClientSecretUpdate {
+                client_secret,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Failed to update the client secret of the payment intent")
+}
+
+/// Builds the branding of the intermediate redirection page from the redirect config of the profile.
+/// The locale of the config takes precedence over the locale of the request.
+pub async fn get_redirection_page_config(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    request_locale: Option<&String>,
+) -> RouterResult<Option<services::RedirectionPageConfig>> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(business_profile.redirect_config.map(|redirect_config| {
+        let locale = redirect_config
+            .locale
+            .as_ref()
+            .or(request_locale)
+            .map(String::as_str)
+            .unwrap_or("en");
+        services::RedirectionPageConfig {
+            logo: redirect_config.ui_config.logo.map(|logo| logo.to_string()),
+            merchant_name: redirect_config
+                .ui_config
+                .merchant_name
+                .map(|merchant_name| merchant_name.expose()),
+            theme: redirect_config.ui_config.theme,
+            message: rust_i18n::t!("redirection_page.processing_payment", locale = locale)
+                .to_string(),
+            auto_redirect_timeout_ms: redirect_config
+                .auto_redirect_timeout_ms
+                .unwrap_or(consts::DEFAULT_AUTO_REDIRECT_TIMEOUT_MS),
+        }
+    }))
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
@@ -5839,6 +6238,13 @@ pub enum PaymentExternalAuthenticationFlow {
     PostAuthenticationFlow {
         authentication_id: String,
     },
+    /// 3DS Requestor Initiated (3RI) authentication of a merchant initiated payment
+    ThreeDsRequestorInitiatedFlow {
+        acquirer_details: authentication::types::AcquirerDetails,
+        card_number: ::cards::CardNumber,
+        token: String,
+        payment_connector: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -5861,6 +6267,8 @@ pub async fn get_payment_external_authentication_flow_during_confirm<F: Clone>(
         .payment_attempt
         .external_three_ds_authentication_attempted
         .unwrap_or(false);
+    let is_recurring_mandate_transaction = mandate_type
+        == Some(api_models::payments::MandateTransactionType::RecurringMandateTransaction);
     let connector_supports_separate_authn =
         authentication::utils::get_connector_data_if_separate_authn_supported(connector_call_type);
     logger::info!("is_pre_authn_call {:?}", authentication_id.is_none());
@@ -5884,9 +6292,7 @@ pub async fn get_payment_external_authentication_flow_during_confirm<F: Clone>(
             PaymentExternalAuthenticationFlow::PostAuthenticationFlow { authentication_id }
         })
     } else if separate_authentication_requested
-        && is_authentication_type_3ds
-        && mandate_type
-            != Some(api_models::payments::MandateTransactionType::RecurringMandateTransaction)
+        && (is_authentication_type_3ds || is_recurring_mandate_transaction)
     {
         if let Some((connector_data, card_number)) =
             connector_supports_separate_authn.zip(card_number)
@@ -5920,10 +6326,20 @@ pub async fn get_payment_external_authentication_flow_during_confirm<F: Clone>(
                         "acquirer_bin and acquirer_merchant_id not found in Payment Connector's Metadata"
                             .to_string(),
                 })?;
-            Some(PaymentExternalAuthenticationFlow::PreAuthenticationFlow {
-                card_number,
-                token,
-                acquirer_details,
+            // Merchant initiated payments are authenticated without the cardholder, using 3RI
+            Some(if is_recurring_mandate_transaction {
+                PaymentExternalAuthenticationFlow::ThreeDsRequestorInitiatedFlow {
+                    card_number,
+                    token,
+                    acquirer_details,
+                    payment_connector: connector_data.connector_name.to_string(),
+                }
+            } else {
+                PaymentExternalAuthenticationFlow::PreAuthenticationFlow {
+                    card_number,
+                    token,
+                    acquirer_details,
+                }
             })
         } else {
             None
@@ -5944,6 +6360,17 @@ pub fn get_redis_key_for_extended_card_info(
     )
 }
 
+pub fn get_redis_key_for_prewarmed_session_tokens(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "{}_{}_prewarmed_session_tokens",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
 pub fn check_integrity_based_on_flow<T, Request>(
     request: &Request,
     payment_response_data: &Result<PaymentsResponseData, ErrorResponse>,
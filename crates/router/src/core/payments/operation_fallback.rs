@@ -0,0 +1,139 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+/// Routes an operation on an existing payment, such as a capture, void or refund, through another
+/// connector account of the same connector in the operation fallback group of the connector account
+/// which processed the payment, if that connector account has been disabled or deleted since. The
+/// connector account of the attempt is replaced only for the operation, and is left unchanged when
+/// none of the connector accounts in the group can be used.
+#[instrument(skip_all)]
+pub async fn apply_operation_fallback_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    payment_attempt: &mut storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let (Some(operation_fallback_config), Some(merchant_connector_id), Some(connector_name)) = (
+        business_profile.operation_fallback_config.as_ref(),
+        payment_attempt.merchant_connector_id.as_ref(),
+        payment_attempt.connector.as_ref(),
+    ) else {
+        return Ok(());
+    };
+    let fallback_merchant_connector_ids =
+        operation_fallback_config.get_fallback_merchant_connector_ids(merchant_connector_id);
+    if fallback_merchant_connector_ids.is_empty()
+        || get_enabled_merchant_connector_account(
+            state,
+            key_store,
+            &payment_attempt.merchant_id,
+            merchant_connector_id,
+            connector_name,
+        )
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    for fallback_merchant_connector_id in fallback_merchant_connector_ids {
+        if let Some(fallback_merchant_connector_account) = get_enabled_merchant_connector_account(
+            state,
+            key_store,
+            &payment_attempt.merchant_id,
+            fallback_merchant_connector_id,
+            connector_name,
+        )
+        .await?
+        {
+            logger::info!(
+                ?merchant_connector_id,
+                ?fallback_merchant_connector_id,
+                "Processing the operation through the fallback connector account"
+            );
+            payment_attempt.merchant_connector_id =
+                Some(fallback_merchant_connector_account.get_id());
+            return Ok(());
+        }
+    }
+
+    logger::warn!(
+        ?merchant_connector_id,
+        "None of the connector accounts in the operation fallback group are enabled"
+    );
+    Ok(())
+}
+
+/// The payment attempt with the connector account through which a refund of the payment is
+/// processed, as per the operation fallback groups of the profile of the payment
+#[instrument(skip_all)]
+pub async fn get_payment_attempt_for_refund(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    creds_identifier: Option<&str>,
+) -> RouterResult<storage::PaymentAttempt> {
+    let mut payment_attempt = payment_attempt.clone();
+    // The connector account of the payment is not used when its details are passed in the request
+    let Some(profile_id) = payment_intent
+        .profile_id
+        .as_ref()
+        .filter(|_| creds_identifier.is_none() && payment_attempt.merchant_connector_id.is_some())
+    else {
+        return Ok(payment_attempt);
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    apply_operation_fallback_connector_account(
+        state,
+        key_store,
+        &business_profile,
+        &mut payment_attempt,
+    )
+    .await?;
+
+    Ok(payment_attempt)
+}
+
+/// The connector account with the given ID, if it exists, is enabled and is of the given connector
+async fn get_enabled_merchant_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    connector_name: &str,
+) -> RouterResult<Option<domain::MerchantConnectorAccount>> {
+    match state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_id,
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => Ok(Some(merchant_connector_account).filter(
+            |merchant_connector_account| {
+                merchant_connector_account.disabled != Some(true)
+                    && merchant_connector_account.connector_name == connector_name
+            },
+        )),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to fetch the connector account of the operation"),
+    }
+}
@@ -175,6 +175,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier: None,
@@ -194,6 +195,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
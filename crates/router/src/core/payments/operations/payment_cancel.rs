@@ -11,7 +11,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, PaymentData},
+        payments::{self, helpers, operations, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
     routes::{app::ReqState, SessionState},
@@ -158,6 +158,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                 id: profile_id.get_string_repr().to_owned(),
             })?;
 
+        // The connector account of the payment is not used when its details are passed in the request
+        if creds_identifier.is_none() {
+            payments::operation_fallback::apply_operation_fallback_connector_account(
+                state,
+                key_store,
+                &business_profile,
+                &mut payment_attempt,
+            )
+            .await?;
+        }
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -184,6 +195,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier,
@@ -203,6 +215,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -275,6 +289,14 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest> for
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        if payment_data.payment_attempt.capture_on.is_some() {
+            payments::revoke_auto_capture_task(&*state.store, &payment_data.payment_attempt)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to revoke the auto capture task of the payment")?;
+        }
+
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentCancelled {
@@ -209,6 +209,17 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 id: profile_id.get_string_repr().to_owned(),
             })?;
 
+        // The connector account of the payment is not used when its details are passed in the request
+        if creds_identifier.is_none() {
+            payments::operation_fallback::apply_operation_fallback_connector_account(
+                state,
+                key_store,
+                &business_profile,
+                &mut payment_attempt,
+            )
+            .await?;
+        }
+
         let payment_data = payments::PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -235,6 +246,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier,
@@ -254,6 +266,8 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
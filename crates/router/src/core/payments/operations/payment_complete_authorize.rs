@@ -327,6 +327,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             sessions_token: vec![],
             card_cvc: request.card_cvc.clone(),
             creds_identifier: None,
@@ -346,6 +347,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret: false,
         };
 
         let customer_details = Some(CustomerDetails {
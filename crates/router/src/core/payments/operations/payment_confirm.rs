@@ -343,6 +343,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 }
             };
 
+        helpers::validate_client_origin_for_profile(
+            auth_flow,
+            header_payload.origin.as_deref(),
+            &business_profile,
+            "payments_confirm",
+        )?;
+
+        // A client secret which was used to confirm the payment is not accepted again once the
+        // connector accepts the payment, the rotated client secret is sent back in the confirm
+        // response
+        let rotate_client_secret = auth_flow == services::AuthFlow::Client
+            && business_profile.is_client_secret_single_use == Some(true);
+
         payment_intent.order_details = request
             .get_order_details_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -409,6 +422,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
+        if let Some(dcc_choice) = request.dcc_choice.as_ref() {
+            payment_attempt.dcc_details = Some(
+                payments::dcc::get_dcc_details(state, merchant_id, &payment_attempt, dcc_choice)
+                    .await?,
+            );
+        }
+
         helpers::validate_customer_id_mandatory_cases(
             request.setup_future_usage.is_some(),
             payment_intent
@@ -791,6 +811,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             sessions_token: vec![],
             card_cvc: request.card_cvc.clone(),
             creds_identifier,
@@ -810,6 +831,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1020,6 +1043,63 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
                 }
                 Some(authentication)
             }
+            Some(helpers::PaymentExternalAuthenticationFlow::ThreeDsRequestorInitiatedFlow {
+                acquirer_details,
+                card_number,
+                token,
+                payment_connector,
+            }) => {
+                let return_url = helpers::create_authorize_url(
+                    &state.base_url,
+                    &payment_data.payment_attempt,
+                    payment_connector,
+                );
+                let authentication = Box::pin(authentication::perform_three_ri_authentication(
+                    state,
+                    key_store,
+                    business_profile,
+                    card_number,
+                    token,
+                    acquirer_details,
+                    payment_data
+                        .payment_method_data
+                        .clone()
+                        .get_required_value("payment_method_data")?,
+                    payment_data
+                        .address
+                        .get_payment_method_billing()
+                        .cloned()
+                        .unwrap_or_default(),
+                    payment_data.address.get_shipping().cloned(),
+                    payment_data.payment_attempt.get_total_amount(),
+                    Some(payment_data.currency),
+                    payment_data.email.clone(),
+                    payment_data.payment_attempt.payment_id.clone(),
+                    return_url,
+                ))
+                .await;
+                // The merchant initiated payment is authorized without the authentication values,
+                // as it would have been otherwise, if the 3RI authentication is not successful
+                match authentication {
+                    Ok(authentication)
+                        if authentication.authentication_status
+                            == api_models::enums::AuthenticationStatus::Success =>
+                    {
+                        Some(authentication)
+                    }
+                    Ok(authentication) => {
+                        logger::info!(
+                            authentication_status = ?authentication.authentication_status,
+                            "3RI authentication was not successful"
+                        );
+                        None
+                    }
+                    Err(error) => {
+                        logger::error!(?error, "Failed to perform 3RI authentication");
+                        None
+                    }
+                }
+            }
             None => None,
         };
         Ok(())
@@ -1284,6 +1364,32 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let frm_metadata = payment_data.payment_intent.frm_metadata.clone();
         let authorized_amount = payment_data.payment_attempt.get_total_amount();
 
+        // The dynamic currency conversion accepted by the customer is applied if the connector
+        // of the attempt supports it, in which case the attempt is authorized in the presentment
+        // currency of the offer
+        let is_dcc_applied = connector.as_deref().is_some_and(|connector| {
+            payments::dcc::is_dcc_applicable(state, &payment_data.payment_attempt, connector)
+        });
+        let dcc_details = payment_data
+            .payment_attempt
+            .dcc_details
+            .clone()
+            .map(|dcc_details| diesel_models::DccDetails {
+                applied: is_dcc_applied,
+                ..dcc_details
+            });
+        let (presentment_currency, presentment_amount, exchange_rate) = dcc_details
+            .as_ref()
+            .filter(|dcc_details| dcc_details.applied)
+            .map(|dcc_details| {
+                (
+                    Some(dcc_details.presentment_currency),
+                    Some(dcc_details.presentment_amount),
+                    Some(dcc_details.exchange_rate.clone()),
+                )
+            })
+            .unwrap_or_default();
+
         let client_source = header_payload
             .client_source
             .clone()
@@ -1384,6 +1490,10 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         connector_mandate_detail: payment_data
                             .payment_attempt
                             .connector_mandate_detail,
+                        presentment_currency,
+                        presentment_amount,
+                        exchange_rate,
+                        dcc_details,
                     },
                     storage_scheme,
                 )
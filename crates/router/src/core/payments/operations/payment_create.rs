@@ -31,6 +31,8 @@ use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+#[cfg(feature = "v1")]
+use crate::core::{link_delivery, payment_templates};
 use crate::{
     consts,
     core::{
@@ -39,7 +41,7 @@ use crate::{
         payment_link,
         payment_methods::cards::create_encrypted_data,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
-        utils as core_utils,
+        usage_metering, utils as core_utils,
     },
     db::StorageInterface,
     events::audit_events::{AuditEvent, AuditEventType},
@@ -86,6 +88,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let merchant_id = merchant_account.get_id();
         let storage_scheme = merchant_account.storage_scheme;
 
+        // The fields which are not passed in the request are taken from the payment template
+        // referenced by the request, before the request is validated
+        #[cfg(feature = "v1")]
+        let request =
+            &payment_templates::apply_payment_template(state, merchant_id, request.clone()).await?;
+
         let money @ (amount, currency) = payments_create_request_validation(request)?;
 
         let payment_id = payment_id
@@ -99,6 +107,24 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_account,
         )?;
 
+        #[cfg(feature = "v1")]
+        let request_profile_id = match (request.profile_id.as_ref(), request.auto_select_profile) {
+            (None, Some(true)) => Some(
+                payments::profile_selection::select_profile_id(
+                    state,
+                    merchant_id,
+                    request
+                        .billing
+                        .as_ref()
+                        .and_then(|billing| billing.address.as_ref())
+                        .and_then(|address| address.country),
+                    currency,
+                )
+                .await?,
+            ),
+            _ => request.profile_id.clone(),
+        };
+
         // If profile id is not passed, get it from the business_country and business_label
         #[cfg(feature = "v1")]
         let profile_id = core_utils::get_profile_id_from_business_details(
@@ -107,7 +133,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             request.business_country,
             request.business_label.as_ref(),
             merchant_account,
-            request.profile_id.as_ref(),
+            request_profile_id.as_ref(),
             &*state.store,
             true,
         )
@@ -145,6 +171,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 id: profile_id.get_string_repr().to_owned(),
             })?
         };
+
+        helpers::validate_return_url_for_profile(request.return_url.as_ref(), &business_profile)?;
+        helpers::validate_currency_for_profile(currency, &business_profile)?;
+        // The fields which are not passed in the request default to the payment defaults of the
+        // profile, and are validated again with the defaults applied
+        let request = &helpers::apply_profile_payment_defaults(request.clone(), &business_profile);
+        helpers::validate_amount_to_capture_and_capture_method(None, request)?;
+
         let customer_acceptance = request.customer_acceptance.clone().map(From::from);
 
         let recurring_details = request.recurring_details.clone();
@@ -287,6 +321,70 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             _ => None,
         };
 
+        #[cfg(feature = "v1")]
+        if let (Some(payment_link_data), Some(link_delivery_request)) = (
+            payment_link_data.as_ref(),
+            request
+                .payment_link_config
+                .as_ref()
+                .and_then(|payment_link_config| payment_link_config.link_delivery.clone()),
+        ) {
+            let customer_phone = request
+                .customer
+                .as_ref()
+                .and_then(|customer| {
+                    customer
+                        .phone
+                        .clone()
+                        .map(|phone| link_delivery::CustomerPhone {
+                            phone,
+                            phone_country_code: customer.phone_country_code.clone(),
+                        })
+                })
+                .or_else(|| {
+                    request
+                        .phone
+                        .clone()
+                        .map(|phone| link_delivery::CustomerPhone {
+                            phone,
+                            phone_country_code: request.phone_country_code.clone(),
+                        })
+                });
+
+            link_delivery::deliver_link(
+                state,
+                link_delivery::LinkDeliveryDetails {
+                    merchant_id: merchant_id.clone(),
+                    profile_id: Some(profile_id.clone()),
+                    link_id: payment_link_data.payment_link_id.clone(),
+                    link_type: enums::DeliveredLinkType::PaymentLink,
+                    link_url: payment_link_data.link.clone(),
+                    merchant_name: merchant_account
+                        .merchant_name
+                        .clone()
+                        .map(|name| name.into_inner().peek().to_owned())
+                        .unwrap_or_else(|| merchant_id.get_string_repr().to_owned()),
+                },
+                link_delivery_request,
+                customer_phone,
+            )
+            .await?;
+        }
+
+        #[cfg(feature = "v1")]
+        if let Some(payment_link_data) = payment_link_data.as_ref() {
+            payment_link::add_payment_link_expiry_task(
+                db,
+                merchant_id,
+                &payment_id,
+                &payment_link_data.payment_link_id,
+                session_expiry,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding payment link expiry task to process tracker")?;
+        }
+
         let payment_intent_new = Self::make_payment_intent(
             state,
             &payment_id,
@@ -304,6 +402,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             attempt_id,
             profile_id.clone(),
             session_expiry,
+            business_profile.statement_descriptor_config.as_ref(),
         )
         .await?;
 
@@ -339,6 +438,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_id: payment_id.clone(),
             })?;
 
+        usage_metering::record_billable_operation(
+            state,
+            merchant_id,
+            usage_metering::BillableOperation::PaymentCreated,
+        )
+        .await;
+
         if let Some(order_details) = &request.order_details {
             helpers::validate_order_details_amount(
                 order_details.to_owned(),
@@ -592,6 +698,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             force_sync: None,
             sessions_token: vec![],
             card_cvc: request.card_cvc.clone(),
@@ -612,6 +719,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1207,6 +1316,37 @@ impl PaymentCreate {
             additional_pm_data.as_ref(),
         ));
 
+        let net_amount =
+            hyperswitch_domain_models::payments::payment_attempt::NetAmount::from_payments_request(
+                request,
+                MinorUnit::from(amount),
+            );
+
+        // The amount in the presentment currency is quoted at the current forex rates, and is
+        // updated with the amount converted when the payment is authorized
+        let presentment_currency = request
+            .presentment_currency
+            .filter(|presentment_currency| Some(*presentment_currency) != currency);
+        let presentment_amount = match (presentment_currency, currency) {
+            (Some(presentment_currency), Some(currency)) => {
+                payments::presentment_currency::convert_to_presentment_currency(
+                    state,
+                    net_amount.get_total_amount(),
+                    currency,
+                    presentment_currency,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to convert amount to the presentment currency"
+                    )
+                })
+                .ok()
+            }
+            _ => None,
+        };
+
         Ok((
             storage::PaymentAttemptNew {
                 payment_id: payment_id.to_owned(),
@@ -1237,10 +1377,7 @@ impl PaymentCreate {
                 external_three_ds_authentication_attempted: None,
                 mandate_data,
                 payment_method_billing_address_id,
-                net_amount: hyperswitch_domain_models::payments::payment_attempt::NetAmount::from_payments_request(
-                    request,
-                    MinorUnit::from(amount),
-                ),
+                net_amount,
                 save_to_locker: None,
                 connector: None,
                 error_message: None,
@@ -1279,6 +1416,12 @@ impl PaymentCreate {
                 organization_id: organization_id.clone(),
                 profile_id,
                 connector_mandate_detail: None,
+                presentment_currency,
+                presentment_amount: presentment_amount
+                    .as_ref()
+                    .map(|presentment_amount| presentment_amount.amount),
+                exchange_rate: presentment_amount
+                    .map(|presentment_amount| presentment_amount.exchange_rate),
             },
             additional_pm_data,
 
@@ -1300,6 +1443,9 @@ impl PaymentCreate {
         active_attempt_id: String,
         profile_id: common_utils::id_type::ProfileId,
         session_expiry: PrimitiveDateTime,
+        statement_descriptor_config: Option<
+            &diesel_models::business_profile::BusinessStatementDescriptorConfig,
+        >,
     ) -> RouterResult<storage::PaymentIntent> {
         let created_at @ modified_at @ last_synced = common_utils::date_time::now();
 
@@ -1446,8 +1592,14 @@ impl PaymentCreate {
             return_url: request.return_url.as_ref().map(|a| a.to_string()),
             shipping_address_id,
             billing_address_id,
-            statement_descriptor_name: request.statement_descriptor_name.clone(),
-            statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
+            statement_descriptor_name: request
+                .statement_descriptor_name
+                .clone()
+                .or_else(|| statement_descriptor_config.and_then(|config| config.name.clone())),
+            statement_descriptor_suffix: request
+                .statement_descriptor_suffix
+                .clone()
+                .or_else(|| statement_descriptor_config.and_then(|config| config.suffix.clone())),
             metadata: request.metadata.clone(),
             business_country: request.business_country,
             business_label: request.business_label.clone(),
@@ -1581,6 +1733,9 @@ async fn create_payment_link(
         payment_link_config: Some(payment_link_config_encoded_value),
         profile_id: Some(profile_id),
         secure_link,
+        deactivated_at: None,
+        link_token: None,
+        payment_link_template_id: None,
     };
     let payment_link_db = db
         .insert_payment_link(payment_link_req)
@@ -24,6 +24,11 @@ use tracing_futures::Instrument;
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(feature = "v1")]
+use crate::core::{
+    ledger,
+    payments::{presentment_currency, virtual_account},
+};
 use crate::{
     connector::utils::PaymentResponseRouterData,
     consts,
@@ -217,21 +222,30 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
         if is_legacy_mandate {
             // Mandate is created on the application side and at the connector.
             let tokenization::SavePaymentMethodDataResponse {
-                payment_method_id, ..
+                payment_method_id,
+                vault_fallback_token,
+                ..
             } = save_payment_call_future.await?;
 
-            let mandate_id = mandate::mandate_procedure(
-                state,
-                resp,
-                &customer_id.clone(),
-                payment_method_id.clone(),
-                merchant_connector_id.clone(),
-                merchant_account.storage_scheme,
-                payment_data.payment_intent.get_id(),
-            )
-            .await?;
+            // A mandate cannot be created for a card which was not saved in the locker
+            let mandate_id = if vault_fallback_token.is_some() {
+                logger::warn!("Skipping mandate creation as the card was not saved in the locker");
+                None
+            } else {
+                mandate::mandate_procedure(
+                    state,
+                    resp,
+                    &customer_id.clone(),
+                    payment_method_id.clone(),
+                    merchant_connector_id.clone(),
+                    merchant_account.storage_scheme,
+                    payment_data.payment_intent.get_id(),
+                )
+                .await?
+            };
             payment_data.payment_attempt.payment_method_id = payment_method_id;
             payment_data.payment_attempt.mandate_id = mandate_id;
+            payment_data.vault_fallback_token = vault_fallback_token;
 
             Ok(())
         } else if is_connector_mandate {
@@ -239,8 +253,10 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
             let tokenization::SavePaymentMethodDataResponse {
                 payment_method_id,
                 connector_mandate_reference_id,
+                vault_fallback_token,
                 ..
             } = save_payment_call_future.await?;
+            payment_data.vault_fallback_token = vault_fallback_token;
             payment_data.payment_method_info = if let Some(payment_method_id) = &payment_method_id {
                 match state
                     .store
@@ -1086,6 +1102,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::SetupMandateRequestDa
         let tokenization::SavePaymentMethodDataResponse {
             payment_method_id,
             connector_mandate_reference_id,
+            vault_fallback_token,
             ..
         } = Box::pin(tokenization::save_payment_method(
             state,
@@ -1130,18 +1147,25 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::SetupMandateRequestDa
         } else {
             None
         };
-        let mandate_id = mandate::mandate_procedure(
-            state,
-            resp,
-            &customer_id,
-            payment_method_id.clone(),
-            merchant_connector_id.clone(),
-            merchant_account.storage_scheme,
-            payment_data.payment_intent.get_id(),
-        )
-        .await?;
+        // A mandate cannot be created for a card which was not saved in the locker
+        let mandate_id = if vault_fallback_token.is_some() {
+            logger::warn!("Skipping mandate creation as the card was not saved in the locker");
+            None
+        } else {
+            mandate::mandate_procedure(
+                state,
+                resp,
+                &customer_id,
+                payment_method_id.clone(),
+                merchant_connector_id.clone(),
+                merchant_account.storage_scheme,
+                payment_data.payment_intent.get_id(),
+            )
+            .await?
+        };
         payment_data.payment_attempt.payment_method_id = payment_method_id;
         payment_data.payment_attempt.mandate_id = mandate_id;
+        payment_data.vault_fallback_token = vault_fallback_token;
         payment_data.payment_attempt.connector_mandate_detail = connector_mandate_reference_id
             .clone()
             .map(ForeignFrom::foreign_from);
@@ -1251,7 +1275,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     state: &SessionState,
     mut payment_data: PaymentData<F>,
-    router_data: types::RouterData<F, T, types::PaymentsResponseData>,
+    mut router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     key_store: &domain::MerchantKeyStore,
     storage_scheme: enums::MerchantStorageScheme,
     locale: &Option<String>,
@@ -1260,6 +1284,10 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     >,
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))] business_profile: &domain::Profile,
 ) -> RouterResult<PaymentData<F>> {
+    // Reconcile the amount credited to the virtual account of the payment with its amount, before
+    // the status and the captured amount reported by the connector are used to update the payment
+    virtual_account::reconcile_credited_amount(&payment_data.payment_attempt, &mut router_data)?;
+
     // Update additional payment data with the payment method response that we received from connector
     // This is for details like whether 3ds was upgraded and which version of 3ds was used
     // also some connectors might send card network details in the response, which is captured and stored
@@ -1418,7 +1446,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector: None,
                             status,
                             error_message: Some(Some(err.message)),
-                            error_code: Some(Some(err.code)),
+                            error_code: Some(Some(err.code.clone())),
                             error_reason: Some(err.reason),
                             amount_capturable: router_data
                                 .request
@@ -1430,6 +1458,10 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
                             authentication_type: auth_update,
+                            connector_response_codes: Some(diesel_models::ConnectorResponseCodes {
+                                decline_code: Some(err.code),
+                                ..Default::default()
+                            }),
                         }),
                     )
                 }
@@ -1467,6 +1499,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id,
                             payment_method_data: None,
                             authentication_type: auth_update,
+                            connector_response_codes: None,
                         }),
                     )
                 }
@@ -1667,6 +1700,22 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 payment_data.payment_attempt.connector.clone(),
                                 payment_data.payment_attempt.merchant_id.clone(),
                             );
+                            let connector_response_codes =
+                                router_data.connector_response.as_ref().and_then(
+                                    |connector_response| connector_response.get_response_codes(),
+                                );
+                            let presentment_amount =
+                                presentment_currency::get_authorized_presentment_amount(
+                                    state,
+                                    &payment_data.payment_attempt,
+                                    router_data.connector_response.as_ref().and_then(
+                                        |connector_response| {
+                                            connector_response.get_currency_conversion()
+                                        },
+                                    ),
+                                    updated_attempt_status,
+                                )
+                                .await;
                             let (capture_updates, payment_attempt_update) = match payment_data
                                 .multiple_capture_data
                             {
@@ -1733,6 +1782,19 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                             .payment_attempt
                                             .connector_mandate_detail
                                             .clone(),
+                                        avs_result: connector_response_codes
+                                            .as_ref()
+                                            .and_then(|codes| codes.get_avs_result()),
+                                        cvv_result: connector_response_codes
+                                            .as_ref()
+                                            .and_then(|codes| codes.get_cvv_result()),
+                                        connector_response_codes,
+                                        presentment_amount: presentment_amount
+                                            .as_ref()
+                                            .map(|presentment_amount| presentment_amount.amount),
+                                        exchange_rate: presentment_amount.map(
+                                            |presentment_amount| presentment_amount.exchange_rate,
+                                        ),
                                     }),
                                 ),
                             };
@@ -1824,45 +1886,17 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     // Stage 1
 
-    let payment_attempt = payment_data.payment_attempt.clone();
-
-    let m_db = state.clone().store;
-    let m_payment_attempt_update = payment_attempt_update.clone();
-    let m_payment_attempt = payment_attempt.clone();
-
-    let payment_attempt = payment_attempt_update
-        .map(|payment_attempt_update| {
-            PaymentAttempt::from_storage_model(
-                payment_attempt_update
-                    .to_storage_model()
-                    .apply_changeset(payment_attempt.clone().to_storage_model()),
-            )
-        })
-        .unwrap_or_else(|| payment_attempt);
-
-    let payment_attempt_fut = tokio::spawn(
-        async move {
-            Box::pin(async move {
-                Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(
-                    match m_payment_attempt_update {
-                        Some(payment_attempt_update) => m_db
-                            .update_payment_attempt_with_attempt_id(
-                                m_payment_attempt,
-                                payment_attempt_update,
-                                storage_scheme,
-                            )
-                            .await
-                            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?,
-                        None => m_payment_attempt,
-                    },
-                )
-            })
-            .await
-        }
-        .in_current_span(),
-    );
-
-    payment_data.payment_attempt = payment_attempt;
+    // The attempt is updated ahead of the intent, so that the status of the intent follows the
+    // attempt as resolved against any concurrent update of the attempt
+    if let Some(payment_attempt_update) = payment_attempt_update {
+        payment_data.payment_attempt = update_payment_attempt_resolving_conflicts(
+            state,
+            payment_data.payment_attempt.clone(),
+            payment_attempt_update,
+            storage_scheme,
+        )
+        .await?;
+    }
 
     payment_data.authentication = match payment_data.authentication {
         Some(authentication) => {
@@ -1961,10 +1995,9 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         .in_current_span(),
     );
 
-    let (payment_intent, _, payment_attempt) = futures::try_join!(
+    let (payment_intent, _) = futures::try_join!(
         utils::flatten_join_error(payment_intent_fut),
-        utils::flatten_join_error(mandate_update_fut),
-        utils::flatten_join_error(payment_attempt_fut)
+        utils::flatten_join_error(mandate_update_fut)
     )?;
 
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -1972,7 +2005,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         if business_profile.dynamic_routing_algorithm.is_some() {
             let state = state.clone();
             let business_profile = business_profile.clone();
-            let payment_attempt = payment_attempt.clone();
+            let payment_attempt = payment_data.payment_attempt.clone();
             let success_based_routing_config_params_interpolator =
                 routing_helpers::SuccessBasedRoutingConfigParamsInterpolator::new(
                     payment_attempt.payment_method,
@@ -2021,8 +2054,24 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         }
     }
 
+    let is_intent_status_changed = payment_data.payment_intent.status != payment_intent.status;
     payment_data.payment_intent = payment_intent;
-    payment_data.payment_attempt = payment_attempt;
+    if is_intent_status_changed {
+        ledger::record_payment(state, &payment_data.payment_intent).await;
+    }
+
+    // The client secret used to confirm the payment is only rotated once the connector accepts
+    // the payment, so that the customer can retry the confirmation after a failure
+    if payment_data.rotate_client_secret && router_data.response.is_ok() {
+        payment_data.payment_intent = payments_helpers::rotate_client_secret(
+            state,
+            payment_data.payment_intent,
+            key_store,
+            storage_scheme,
+        )
+        .await?;
+        payment_data.rotate_client_secret = false;
+    }
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
@@ -2072,6 +2121,81 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     }
 }
 
+/// The number of times an update of an attempt is applied on the latest attempt, when it conflicts
+/// with concurrent updates of the attempt
+#[cfg(feature = "v1")]
+const MAX_PAYMENT_ATTEMPT_UPDATE_CONFLICT_RETRIES: u8 = 3;
+
+/// Updates the attempt, resolving any conflict with a concurrent update of the attempt, such as when
+/// a webhook of the payment is processed while the payment is being synced. On a conflict, the
+/// update is applied on the latest attempt, unless the status of the latest attempt takes precedence
+/// over the status set by the update, in which case the latest attempt is kept as is. The latest
+/// attempt is also kept if the update keeps conflicting after the retries.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+async fn update_payment_attempt_resolving_conflicts(
+    state: &SessionState,
+    payment_attempt: PaymentAttempt,
+    payment_attempt_update: storage::PaymentAttemptUpdate,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentAttempt> {
+    let mut payment_attempt = payment_attempt;
+    for _ in 0..MAX_PAYMENT_ATTEMPT_UPDATE_CONFLICT_RETRIES {
+        if let Some(updated_payment_attempt) = state
+            .store
+            .update_payment_attempt_with_attempt_id_if_unmodified(
+                payment_attempt.clone(),
+                payment_attempt_update.clone(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?
+        {
+            return Ok(updated_payment_attempt);
+        }
+
+        metrics::PAYMENT_ATTEMPT_UPDATE_CONFLICT_COUNT.add(&metrics::CONTEXT, 1, &[]);
+        let latest_payment_attempt = state
+            .store
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &payment_attempt.attempt_id,
+                &payment_attempt.merchant_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        let updated_status = payment_attempt_update
+            .clone()
+            .to_storage_model()
+            .apply_changeset(latest_payment_attempt.clone().to_storage_model())
+            .status;
+
+        if latest_payment_attempt
+            .status
+            .has_precedence_over(updated_status)
+        {
+            logger::info!(
+                latest_status = ?latest_payment_attempt.status,
+                discarded_status = ?updated_status,
+                "Discarding the update of the attempt, which was concurrently updated to a status of higher precedence"
+            );
+            metrics::PAYMENT_ATTEMPT_STALE_UPDATE_DISCARDED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+            return Ok(latest_payment_attempt);
+        }
+
+        payment_attempt = latest_payment_attempt;
+    }
+
+    // Applying the update unconditionally could move the attempt back from a status set by a
+    // concurrent update, so the update is discarded in favour of the latest attempt
+    logger::warn!(
+        latest_status = ?payment_attempt.status,
+        "Discarding the update of the attempt, which kept conflicting with concurrent updates"
+    );
+    metrics::PAYMENT_ATTEMPT_STALE_UPDATE_DISCARDED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+    Ok(payment_attempt)
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 async fn update_payment_method_status_and_ntid<F: Clone>(
     state: &SessionState,
@@ -42,8 +42,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
         request: &api::PaymentsSessionRequest,
         merchant_account: &domain::MerchantAccount,
         key_store: &domain::MerchantKeyStore,
-        _auth_flow: services::AuthFlow,
-        _header_payload: &hyperswitch_domain_models::payments::HeaderPayload,
+        auth_flow: services::AuthFlow,
+        header_payload: &hyperswitch_domain_models::payments::HeaderPayload,
     ) -> RouterResult<
         operations::GetTrackerResponse<'a, F, api::PaymentsSessionRequest, PaymentData<F>>,
     > {
@@ -167,6 +167,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 id: profile_id.get_string_repr().to_owned(),
             })?;
 
+        helpers::validate_client_origin_for_profile(
+            auth_flow,
+            header_payload.origin.as_deref(),
+            &business_profile,
+            "payments_session",
+        )?;
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -193,6 +200,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            status_history: None,
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier,
@@ -212,6 +220,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             poll_config: None,
             tax_data: None,
             session_id: None,
+            vault_fallback_token: None,
+            rotate_client_secret: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use api_models::enums::FrmSuggestion;
+use api_models::enums::{ExpandableObject, FrmSuggestion};
 use async_trait::async_trait;
 use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
 use error_stack::ResultExt;
@@ -16,6 +16,7 @@ use crate::{
             PaymentData,
         },
     },
+    db::StorageInterface,
     routes::{app::ReqState, SessionState},
     services,
     types::{
@@ -300,17 +301,55 @@ async fn get_tracker_for_sync<
     payment_attempt.encoded_data.clone_from(&request.param);
     let db = &*state.store;
     let key_manager_state = &state.into();
-    let attempts = match request.expand_attempts {
-        Some(true) => {
-            Some(db
-                .find_attempts_by_merchant_id_payment_id(merchant_account.get_id(), &payment_id, storage_scheme)
-                .await
-                .change_context(errors::ApiErrorResponse::PaymentNotFound)
-                .attach_printable_lazy(|| {
-                    format!("Error while retrieving attempt list for, merchant_id: {:?}, payment_id: {payment_id:?}",merchant_account.get_id())
-                })?)
-        },
-        _ => None,
+    let attempts = if request.expand_attempts == Some(true)
+        || request.should_expand(ExpandableObject::Attempts)
+    {
+        Some(db
+            .find_attempts_by_merchant_id_payment_id(merchant_account.get_id(), &payment_id, storage_scheme)
+            .await
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)
+            .attach_printable_lazy(|| {
+                format!("Error while retrieving attempt list for, merchant_id: {:?}, payment_id: {payment_id:?}",merchant_account.get_id())
+            })?)
+    } else {
+        None
+    };
+
+    let attempts = match attempts {
+        Some(attempts)
+            if !is_raw_connector_response_codes_exposed(db, merchant_account.get_id()).await =>
+        {
+            Some(
+                attempts
+                    .into_iter()
+                    .map(|attempt| storage::PaymentAttempt {
+                        connector_response_codes: None,
+                        ..attempt
+                    })
+                    .collect(),
+            )
+        }
+        attempts => attempts,
+    };
+
+    let status_history = if request.should_expand(ExpandableObject::StatusHistory) {
+        Some(
+            db.find_status_history_by_merchant_id_entity_id(
+                merchant_account.get_id(),
+                enums::StatusHistoryEntityType::Payment,
+                payment_id.get_string_repr(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Error while retrieving status history for, merchant_id: {:?}, payment_id: {payment_id:?}",
+                    merchant_account.get_id()
+                )
+            })?,
+        )
+    } else {
+        None
     };
 
     let multiple_capture_data = if payment_attempt.multiple_capture_count > Some(0) {
@@ -487,6 +526,7 @@ async fn get_tracker_for_sync<
         refunds,
         disputes,
         attempts,
+        status_history,
         sessions_token: vec![],
         card_cvc: None,
         creds_identifier,
@@ -506,6 +546,8 @@ async fn get_tracker_for_sync<
         poll_config: None,
         tax_data: None,
         session_id: None,
+        vault_fallback_token: None,
+        rotate_client_secret: false,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -639,3 +681,22 @@ pub async fn get_payment_intent_payment_attempt(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
 }
+
+/// Raw connector response codes are only exposed on the attempts of merchants which have opted in
+/// through the `expose_raw_connector_response_codes` config
+#[cfg(feature = "v1")]
+async fn is_raw_connector_response_codes_exposed(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> bool {
+    db.find_config_by_key_unwrap_or(
+        &merchant_id.get_expose_raw_connector_response_codes_key(),
+        Some("false".to_string()),
+    )
+    .await
+    .map(|config| config.config == "true")
+    .unwrap_or_else(|error| {
+        logger::error!(?error);
+        false
+    })
+}
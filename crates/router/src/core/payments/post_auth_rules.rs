@@ -0,0 +1,112 @@
+use api_models::payments::PaymentsCancelRequest;
+use common_enums::PostAuthAction;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing};
+
+use super::{OperationSessionGetters, OperationSessionSetters};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments,
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api::{self, payments as payment_types, Void},
+        domain,
+        storage::enums,
+    },
+};
+
+/// Evaluates the post authorization rules of the profile against the AVS and CVV results of the
+/// attempt, and takes the action of the first matching rule. The rules are only evaluated for
+/// attempts which have been authorized and not captured yet.
+#[instrument(skip_all)]
+pub async fn evaluate_post_auth_rules<F, D>(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    payment_data: &mut D,
+) -> RouterResult<()>
+where
+    F: Send + Clone + Sync,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let Some(post_auth_rules_config) = business_profile.post_auth_rules_config.as_ref() else {
+        return Ok(());
+    };
+
+    let payment_attempt = payment_data.get_payment_attempt();
+    if payment_attempt.status != enums::AttemptStatus::Authorized {
+        return Ok(());
+    }
+
+    let avs_result = payment_attempt.avs_result;
+    let cvv_result = payment_attempt.cvv_result;
+    let Some(action) = post_auth_rules_config.get_action(avs_result, cvv_result) else {
+        return Ok(());
+    };
+
+    logger::info!(
+        ?avs_result,
+        ?cvv_result,
+        ?action,
+        "Post authorization rule matched for the payment"
+    );
+
+    match action {
+        PostAuthAction::Void => {
+            let cancel_request = PaymentsCancelRequest {
+                payment_id: payment_data.get_payment_intent().get_id().to_owned(),
+                cancellation_reason: Some(format!(
+                    "Voided by post authorization rule, avs_result: {}, cvv_result: {}",
+                    avs_result.map_or("none".to_string(), |result| result.to_string()),
+                    cvv_result.map_or("none".to_string(), |result| result.to_string()),
+                )),
+                merchant_connector_details: None,
+            };
+            let cancel_response = Box::pin(payments::payments_core::<
+                Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<Void>,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account.clone(),
+                None,
+                key_store.clone(),
+                payments::PaymentCancel,
+                cancel_request,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            ))
+            .await?;
+
+            if let services::ApplicationResponse::JsonWithHeaders((payments_response, _)) =
+                cancel_response
+            {
+                payment_data.set_payment_intent_status(payments_response.status);
+            }
+
+            let payment_attempt = state
+                .store
+                .find_payment_attempt_by_attempt_id_merchant_id(
+                    &payment_data.get_payment_attempt().attempt_id,
+                    merchant_account.get_id(),
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            payment_data.set_payment_attempt(payment_attempt);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use common_utils::types::MinorUnit;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::{self, storage, storage::enums},
+    utils::currency,
+};
+
+/// The number of decimal places to which the exchange rates are rounded
+const EXCHANGE_RATE_DECIMAL_PLACES: u32 = 6;
+
+/// The amount of an attempt in the currency presented to the customer
+#[derive(Debug, Clone)]
+pub struct PresentmentAmount {
+    pub amount: MinorUnit,
+    /// The exchange rate from the currency of the payment to the presentment currency
+    pub exchange_rate: String,
+}
+
+impl From<types::ConnectorCurrencyConversion> for PresentmentAmount {
+    fn from(currency_conversion: types::ConnectorCurrencyConversion) -> Self {
+        Self {
+            amount: currency_conversion.presentment_amount,
+            exchange_rate: currency_conversion.exchange_rate,
+        }
+    }
+}
+
+/// Converts the amount of a payment to the presentment currency at the current forex rates
+#[instrument(skip_all)]
+pub async fn convert_to_presentment_currency(
+    state: &SessionState,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    presentment_currency: enums::Currency,
+) -> RouterResult<PresentmentAmount> {
+    let exchange_rate = get_exchange_rate(state, currency, presentment_currency).await?;
+
+    Ok(PresentmentAmount {
+        amount: convert_amount(amount, currency, presentment_currency, exchange_rate)?,
+        exchange_rate: format_exchange_rate(exchange_rate),
+    })
+}
+
+/// The exchange rate from the currency of a payment to the presentment currency at the current
+/// forex rates, which is the amount in the major unit of the presentment currency of a major unit
+/// of the currency of the payment
+#[instrument(skip_all)]
+pub async fn get_exchange_rate(
+    state: &SessionState,
+    currency: enums::Currency,
+    presentment_currency: enums::Currency,
+) -> RouterResult<Decimal> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(
+        state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch forex rates")?;
+
+    currency_conversion::conversion::convert(
+        &rates.data,
+        currency,
+        presentment_currency,
+        get_minor_units_per_major_unit(currency),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!("Failed to convert amount from {currency} to {presentment_currency}")
+    })
+}
+
+/// Converts an amount to the presentment currency at the exchange rate
+pub fn convert_amount(
+    amount: MinorUnit,
+    currency: enums::Currency,
+    presentment_currency: enums::Currency,
+    exchange_rate: Decimal,
+) -> RouterResult<MinorUnit> {
+    (Decimal::from(amount.get_amount_as_i64())
+        * exchange_rate
+        * Decimal::from(get_minor_units_per_major_unit(presentment_currency))
+        / Decimal::from(get_minor_units_per_major_unit(currency)))
+    .round()
+    .to_i64()
+    .map(MinorUnit::new)
+    .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+    .attach_printable("Amount in the presentment currency is out of range")
+}
+
+/// Rounds an exchange rate to the decimal places to which the exchange rates are recorded
+pub fn round_exchange_rate(exchange_rate: Decimal) -> Decimal {
+    exchange_rate
+        .round_dp(EXCHANGE_RATE_DECIMAL_PLACES)
+        .normalize()
+}
+
+/// Formats an exchange rate as it is recorded on the attempts
+pub fn format_exchange_rate(exchange_rate: Decimal) -> String {
+    round_exchange_rate(exchange_rate).to_string()
+}
+
+fn get_minor_units_per_major_unit(currency: enums::Currency) -> i64 {
+    10_i64.pow(u32::from(currency.number_of_digits_after_decimal_point()))
+}
+
+/// The amount in the presentment currency of an attempt which is authorized, which is the amount
+/// converted by the connector if it converted the amount to the presentment currency, or the
+/// amount of the dynamic currency conversion applied to the attempt, and is otherwise converted at
+/// the forex rates at the time of authorization. Failures to convert the amount are only logged,
+/// so that the amount converted when the attempt was created is retained.
+#[instrument(skip_all)]
+pub async fn get_authorized_presentment_amount(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_currency_conversion: Option<types::ConnectorCurrencyConversion>,
+    status: enums::AttemptStatus,
+) -> Option<PresentmentAmount> {
+    let presentment_currency = payment_attempt.presentment_currency?;
+    if !matches!(
+        status,
+        enums::AttemptStatus::Authorized | enums::AttemptStatus::Charged
+    ) {
+        return None;
+    }
+
+    match connector_currency_conversion {
+        Some(currency_conversion)
+            if currency_conversion.presentment_currency == presentment_currency =>
+        {
+            Some(currency_conversion.into())
+        }
+        // The amount of the dynamic currency conversion accepted by the customer is locked
+        Some(_) | None
+            if payment_attempt
+                .dcc_details
+                .as_ref()
+                .is_some_and(|dcc_details| {
+                    dcc_details.applied && dcc_details.presentment_currency == presentment_currency
+                }) =>
+        {
+            payment_attempt
+                .dcc_details
+                .clone()
+                .map(|dcc_details| PresentmentAmount {
+                    amount: dcc_details.presentment_amount,
+                    exchange_rate: dcc_details.exchange_rate,
+                })
+        }
+        Some(_) | None => convert_to_presentment_currency(
+            state,
+            payment_attempt.net_amount.get_total_amount(),
+            payment_attempt.currency?,
+            presentment_currency,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to convert amount to the presentment currency"
+            )
+        })
+        .ok(),
+    }
+}
@@ -0,0 +1,112 @@
+use api_models::enums as api_enums;
+use common_utils::{ext_traits::StringExt, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+};
+
+/// Merchant config mapping the billing country and currency of a payment to the business profile
+/// the payment is created under, used for payments created with `auto_select_profile`
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ProfileSelectionConfig {
+    /// The rules are evaluated in order, and the profile of the first matching rule is selected
+    pub rules: Vec<ProfileSelectionRule>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ProfileSelectionRule {
+    /// The billing countries matched by the rule, payments from all billing countries are matched
+    /// if not present
+    pub billing_countries: Option<Vec<api_enums::CountryAlpha2>>,
+    /// The currencies matched by the rule, payments of all currencies are matched if not present
+    pub currencies: Option<Vec<api_enums::Currency>>,
+    /// The business profile selected for the payments matched by the rule
+    pub profile_id: id_type::ProfileId,
+}
+
+impl ProfileSelectionRule {
+    fn is_match(
+        &self,
+        billing_country: Option<api_enums::CountryAlpha2>,
+        currency: api_enums::Currency,
+    ) -> bool {
+        let is_country_match = self.billing_countries.as_ref().map_or(true, |countries| {
+            billing_country.is_some_and(|billing_country| countries.contains(&billing_country))
+        });
+        let is_currency_match = self
+            .currencies
+            .as_ref()
+            .map_or(true, |currencies| currencies.contains(&currency));
+        is_country_match && is_currency_match
+    }
+}
+
+impl ProfileSelectionConfig {
+    fn get_profile_id(
+        &self,
+        billing_country: Option<api_enums::CountryAlpha2>,
+        currency: api_enums::Currency,
+    ) -> Option<&id_type::ProfileId> {
+        self.rules
+            .iter()
+            .find(|rule| rule.is_match(billing_country, currency))
+            .map(|rule| &rule.profile_id)
+    }
+}
+
+async fn get_profile_selection_config(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<ProfileSelectionConfig>> {
+    let key = merchant_id.get_profile_selection_rules_key();
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct::<ProfileSelectionConfig>("ProfileSelectionConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Profile selection rules config has invalid structure")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching profile selection rules config"),
+    }
+}
+
+/// Selects the business profile of a payment from its billing country and currency, using the
+/// profile selection rules configured for the merchant
+#[instrument(skip_all)]
+pub async fn select_profile_id(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    billing_country: Option<api_enums::CountryAlpha2>,
+    currency: api_enums::Currency,
+) -> RouterResult<id_type::ProfileId> {
+    let profile_selection_config = get_profile_selection_config(state, merchant_id)
+        .await?
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "auto_select_profile requires profile selection rules to be configured for the merchant".to_string(),
+        }))?;
+
+    let profile_id = profile_selection_config
+        .get_profile_id(billing_country, currency)
+        .cloned()
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "No business profile could be selected for the billing country {} and currency {currency}, pass the profile_id instead",
+                billing_country.map_or("none".to_string(), |country| country.to_string()),
+            ),
+        }))?;
+
+    logger::debug!(
+        ?billing_country,
+        %currency,
+        profile_id = %profile_id.get_string_repr(),
+        "Selected business profile for the payment"
+    );
+
+    Ok(profile_id)
+}
@@ -432,6 +432,24 @@ where
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Could not parse the connector response")?;
 
+            let connector_response_codes = router_data
+                .connector_response
+                .as_ref()
+                .and_then(|connector_response| connector_response.get_response_codes());
+            let presentment_amount =
+                payments::presentment_currency::get_authorized_presentment_amount(
+                    state,
+                    payment_data.get_payment_attempt(),
+                    router_data
+                        .connector_response
+                        .as_ref()
+                        .and_then(|connector_response| {
+                            connector_response.get_currency_conversion()
+                        }),
+                    router_data.status,
+                )
+                .await;
+
             let payment_attempt_update = storage::PaymentAttemptUpdate::ResponseUpdate {
                 status: router_data.status,
                 connector: None,
@@ -467,6 +485,18 @@ where
                 payment_method_data: additional_payment_method_data,
                 charge_id,
                 connector_mandate_detail: None,
+                avs_result: connector_response_codes
+                    .as_ref()
+                    .and_then(|codes| codes.get_avs_result()),
+                cvv_result: connector_response_codes
+                    .as_ref()
+                    .and_then(|codes| codes.get_cvv_result()),
+                connector_response_codes,
+                presentment_amount: presentment_amount
+                    .as_ref()
+                    .map(|presentment_amount| presentment_amount.amount),
+                exchange_rate: presentment_amount
+                    .map(|presentment_amount| presentment_amount.exchange_rate),
             };
 
             #[cfg(feature = "v1")]
@@ -516,6 +546,10 @@ where
                 connector_transaction_id: error_response.connector_transaction_id.clone(),
                 payment_method_data: additional_payment_method_data,
                 authentication_type: auth_update,
+                connector_response_codes: Some(diesel_models::ConnectorResponseCodes {
+                    decline_code: Some(error_response.code.clone()),
+                    ..Default::default()
+                }),
             };
 
             #[cfg(feature = "v1")]
@@ -654,6 +688,9 @@ pub fn make_new_payment_attempt(
         charge_id: Default::default(),
         customer_acceptance: Default::default(),
         connector_mandate_detail: Default::default(),
+        presentment_currency: old_payment_attempt.presentment_currency,
+        presentment_amount: old_payment_attempt.presentment_amount,
+        exchange_rate: old_payment_attempt.exchange_rate,
     }
 }
 
@@ -1,3 +1,4 @@
+pub mod canary_rollout;
 mod transformers;
 
 use std::{
@@ -144,6 +145,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        connector_tags: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -155,6 +157,8 @@ pub fn make_dsl_input_for_payouts(
             .clone()
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
+        card_issuer: None,
+        issuer_card_type: None,
     };
     Ok(dsl_inputs::BackendInput {
         mandate,
@@ -235,6 +239,23 @@ pub fn make_dsl_input(
 
                 _ => None,
             }),
+        card_issuer: payments_dsl_input
+            .payment_method_data
+            .as_ref()
+            .and_then(|pm_data| match pm_data {
+                domain::PaymentMethodData::Card(card) => card.card_issuer.clone(),
+                _ => None,
+            }),
+        issuer_card_type: payments_dsl_input
+            .payment_method_data
+            .as_ref()
+            .and_then(|pm_data| match pm_data {
+                domain::PaymentMethodData::Card(card) => card
+                    .card_type
+                    .as_ref()
+                    .and_then(|card_type| card_type.parse().ok()),
+                _ => None,
+            }),
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -265,6 +286,7 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
+        connector_tags: None,
     };
 
     let metadata = payments_dsl_input
@@ -285,9 +307,41 @@ pub fn make_dsl_input(
     })
 }
 
+/// Returns the card of the payment method data with its issuer details filled from the BIN data,
+/// if they are not already known, so that the routing rules on the issuer of the card can be
+/// evaluated. This is the case for cards whose details were not looked up during the confirm.
+#[cfg(feature = "v1")]
+pub async fn get_payment_method_data_with_card_bin_details(
+    state: &SessionState,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> Option<domain::PaymentMethodData> {
+    let card = match payment_method_data? {
+        domain::PaymentMethodData::Card(card)
+            if card.card_issuer.is_none() || card.card_type.is_none() =>
+        {
+            card
+        }
+        _ => return None,
+    };
+    let card_info = state
+        .store
+        .get_card_info(&card.card_number.get_card_isin())
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()?;
+
+    Some(domain::PaymentMethodData::Card(domain::Card {
+        card_issuer: card.card_issuer.clone().or(card_info.card_issuer),
+        card_type: card.card_type.clone().or(card_info.card_type),
+        ..card.clone()
+    }))
+}
+
 pub async fn perform_static_routing_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
     algorithm_id: Option<&common_utils::id_type::RoutingId>,
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
@@ -336,7 +390,19 @@ pub async fn perform_static_routing_v1(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            if interpreter.uses_key(&euclid::types::EuclidKey::ConnectorTag) {
+                execute_dsl_with_connector_tags_v1(
+                    state,
+                    key_store,
+                    business_profile,
+                    &api_enums::TransactionType::from(transaction_data),
+                    backend_input,
+                    interpreter,
+                )
+                .await?
+            } else {
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            }
         }
     })
 }
@@ -424,12 +490,20 @@ fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
-        .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
-        .change_context(errors::RoutingError::DslExecutionError)?;
+    execute_dsl_and_get_rule_v1(backend_input, interpreter).map(|(_, connectors)| connectors)
+}
 
-    Ok(match routing_output {
+fn execute_dsl_and_get_rule_v1(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+) -> RoutingResult<(Option<String>, Vec<routing_types::RoutableConnectorChoice>)> {
+    let (rule_name, routing_output): (Option<String>, routing_types::RoutingAlgorithm) =
+        interpreter
+            .execute(backend_input)
+            .map(|out| (out.rule_name, out.connector_selection.foreign_into()))
+            .change_context(errors::RoutingError::DslExecutionError)?;
+
+    let connectors = match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
@@ -437,7 +511,92 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((rule_name, connectors))
+}
+
+/// `connector_tag` comparisons are made against the tags of the connector account being routed
+/// to, so the program is executed once without any connector tags, and once with the tags of each
+/// tagged connector account of the profile. When a different rule is matched with the tags of an
+/// account, the account is selected if its connector is in the output of that rule. The selected
+/// tagged accounts are preferred over the output of the program without connector tags.
+async fn execute_dsl_with_connector_tags_v1(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    transaction_type: &api_enums::TransactionType,
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let (rule_name, connectors) = execute_dsl_and_get_rule_v1(backend_input.clone(), interpreter)?;
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            &key_store.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::RoutingError::DslConnectorTagsFetchFailed)?;
+
+    let connector_type = match transaction_type {
+        api_enums::TransactionType::Payment => common_enums::ConnectorType::PaymentProcessor,
+        #[cfg(feature = "payouts")]
+        api_enums::TransactionType::Payout => common_enums::ConnectorType::PayoutProcessor,
+    };
+
+    let tagged_merchant_connector_accounts =
+        payments_oss::helpers::filter_mca_based_on_profile_and_connector_type(
+            merchant_connector_accounts,
+            business_profile.get_id(),
+            connector_type,
+        )
+        .into_iter()
+        .filter(|mca| mca.tags.as_ref().is_some_and(|tags| !tags.is_empty()));
+
+    let mut tagged_connectors: Vec<routing_types::RoutableConnectorChoice> = Vec::new();
+    for mca in tagged_merchant_connector_accounts {
+        let mut tagged_backend_input = backend_input.clone();
+        tagged_backend_input.payment.connector_tags = mca.tags.clone();
+        let (tagged_rule_name, tagged_rule_connectors) =
+            execute_dsl_and_get_rule_v1(tagged_backend_input, interpreter)?;
+
+        // The matched rule does not depend on the tags of the account
+        if tagged_rule_name == rule_name {
+            continue;
+        }
+
+        let merchant_connector_id = mca.get_id();
+        let tagged_connector = tagged_rule_connectors.into_iter().find(|choice| {
+            choice.connector.to_string() == mca.connector_name
+                && choice
+                    .merchant_connector_id
+                    .as_ref()
+                    .map_or(true, |id| *id == merchant_connector_id)
+        });
+        if let Some(tagged_connector) = tagged_connector {
+            logger::debug!(
+                merchant_connector_id = %merchant_connector_id.get_string_repr(),
+                rule_name = ?tagged_rule_name,
+                "Connector account selected by connector tag routing rule"
+            );
+            tagged_connectors.push(routing_types::RoutableConnectorChoice {
+                merchant_connector_id: Some(merchant_connector_id),
+                ..tagged_connector
+            });
+        }
+    }
+
+    let untagged_connectors = connectors
+        .into_iter()
+        .filter(|choice| !tagged_connectors.contains(choice))
+        .collect::<Vec<_>>();
+    tagged_connectors.extend(untagged_connectors);
+
+    Ok(tagged_connectors)
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -920,6 +1079,8 @@ pub async fn perform_session_flow_routing(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_issuer: None,
+        issuer_card_type: None,
     };
 
     #[cfg(feature = "v1")]
@@ -947,6 +1108,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        connector_tags: None,
     };
 
     #[cfg(feature = "v2")]
@@ -1240,6 +1402,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        connector_tags: None,
     };
 
     let metadata = payment_intent
@@ -1254,6 +1417,8 @@ pub fn make_dsl_input_for_surcharge(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_issuer: None,
+        issuer_card_type: None,
     };
     let backend_input = dsl_inputs::BackendInput {
         metadata,
@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use common_utils::date_time;
+use diesel_models::{business_profile::ConnectorCanaryRollout, enums as storage_enums};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::RoutingResult;
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    types::{api::routing as routing_types, domain, storage},
+    SessionState,
+};
+
+const TOTAL_COUNT_SUFFIX: &str = "total_count";
+const SUCCESS_COUNT_SUFFIX: &str = "success_count";
+
+/// Diverts the share of the traffic of the incumbent connector account which is due to the canary
+/// connector account in the current stage of the rollout, by placing the canary ahead of the
+/// incumbent. The rest of the traffic is routed to the incumbent with the canary as a fallback,
+/// while a rolled back canary is not routed to at all.
+pub fn perform_canary_rollout_split(
+    mut connectors: Vec<routing_types::RoutableConnectorChoice>,
+    rollout: &ConnectorCanaryRollout,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let get_position =
+        |connectors: &[routing_types::RoutableConnectorChoice],
+         merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId| {
+            connectors.iter().position(|connector| {
+                connector.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+            })
+        };
+
+    // The rollout only applies to the payments which are eligible for both connector accounts
+    let Some(canary_position) = get_position(&connectors, &rollout.canary_merchant_connector_id)
+    else {
+        return Ok(connectors);
+    };
+    let canary = connectors.remove(canary_position);
+    let Some(incumbent_position) =
+        get_position(&connectors, &rollout.incumbent_merchant_connector_id)
+    else {
+        connectors.insert(canary_position, canary);
+        return Ok(connectors);
+    };
+
+    let traffic_percentage = rollout.get_canary_traffic_percentage(date_time::now());
+    let is_canary_chosen = if traffic_percentage == 0 {
+        false
+    } else if traffic_percentage >= consts::DYNAMIC_ROUTING_MAX_VOLUME {
+        true
+    } else {
+        let incumbent = connectors
+            .get(incumbent_position)
+            .cloned()
+            .ok_or(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Incumbent connector of the canary rollout not found")?;
+        let splits = vec![
+            routing_types::ConnectorVolumeSplit {
+                connector: canary.clone(),
+                split: traffic_percentage,
+            },
+            routing_types::ConnectorVolumeSplit {
+                connector: incumbent,
+                split: consts::DYNAMIC_ROUTING_MAX_VOLUME - traffic_percentage,
+            },
+        ];
+        super::perform_volume_split(splits, None)?
+            .first()
+            .is_some_and(|choice| choice.merchant_connector_id == canary.merchant_connector_id)
+    };
+
+    if is_canary_chosen {
+        connectors.insert(incumbent_position, canary);
+    } else if rollout.status != storage_enums::CanaryRolloutStatus::RolledBack {
+        connectors.insert(incumbent_position + 1, canary);
+    }
+
+    Ok(connectors)
+}
+
+/// Records the outcome of a payment processed by either of the connector accounts of an in
+/// progress canary rollout, and rolls the canary back if its success rate has dropped below that
+/// of the incumbent by more than the configured margin. Failures are only logged, since the
+/// rollout must not affect the processing of the payment.
+#[instrument(skip_all)]
+pub async fn record_connector_canary_rollout_outcome(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let Some(rollout) = business_profile
+        .connector_canary_rollout
+        .as_ref()
+        .filter(|rollout| rollout.status == storage_enums::CanaryRolloutStatus::InProgress)
+    else {
+        return;
+    };
+
+    if let Err(error) = update_connector_canary_rollout(
+        state,
+        key_store,
+        business_profile,
+        rollout,
+        payment_attempt,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to record the outcome of the canary rollout");
+    }
+}
+
+async fn update_connector_canary_rollout(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    rollout: &ConnectorCanaryRollout,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(merchant_connector_id) =
+        payment_attempt
+            .merchant_connector_id
+            .as_ref()
+            .filter(|merchant_connector_id| {
+                **merchant_connector_id == rollout.canary_merchant_connector_id
+                    || **merchant_connector_id == rollout.incumbent_merchant_connector_id
+            })
+    else {
+        return Ok(());
+    };
+    let Some(is_success) = get_payment_outcome(payment_attempt.status) else {
+        return Ok(());
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    // The outcomes are tracked per rollout, so that restarting a rollout starts afresh
+    let key = format!(
+        "connector_canary_rollout_{}_{}",
+        business_profile.get_id().get_string_repr(),
+        rollout.started_at.assume_utc().unix_timestamp()
+    );
+
+    let mut fields_to_increment = vec![(
+        get_count_field(merchant_connector_id, TOTAL_COUNT_SUFFIX),
+        1,
+    )];
+    if is_success {
+        fields_to_increment.push((
+            get_count_field(merchant_connector_id, SUCCESS_COUNT_SUFFIX),
+            1,
+        ));
+    }
+    redis_conn
+        .increment_fields_in_hash(&key, &fields_to_increment)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment the outcome counts of the canary rollout")?;
+    redis_conn
+        .set_expiry(
+            &key,
+            consts::CONNECTOR_CANARY_ROLLOUT_REDIS_KEY_EXPIRY_SECONDS,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the expiry of the outcome counts of the canary rollout")?;
+
+    let counts = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the outcome counts of the canary rollout")?;
+    let (Some(canary_success_rate), Some(incumbent_success_rate)) = (
+        get_success_rate(
+            &counts,
+            &rollout.canary_merchant_connector_id,
+            rollout.min_sample_size,
+        ),
+        get_success_rate(
+            &counts,
+            &rollout.incumbent_merchant_connector_id,
+            rollout.min_sample_size,
+        ),
+    ) else {
+        return Ok(());
+    };
+
+    if canary_success_rate + rollout.rollback_margin >= incumbent_success_rate {
+        return Ok(());
+    }
+
+    logger::info!(
+        canary_success_rate,
+        incumbent_success_rate,
+        "Rolling back the canary rollout of the connector account"
+    );
+    let rolled_back_rollout = ConnectorCanaryRollout {
+        status: storage_enums::CanaryRolloutStatus::RolledBack,
+        rolled_back_at: Some(date_time::now()),
+        ..rollout.clone()
+    };
+    state
+        .store
+        .update_profile_by_profile_id(
+            &state.into(),
+            key_store,
+            business_profile.clone(),
+            domain::ProfileUpdate::ConnectorCanaryRolloutUpdate {
+                connector_canary_rollout: rolled_back_rollout,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to roll back the canary rollout of the business profile")?;
+
+    Ok(())
+}
+
+/// Whether the payment succeeded, if it has reached a conclusive status
+fn get_payment_outcome(status: storage_enums::AttemptStatus) -> Option<bool> {
+    match status {
+        storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable => Some(true),
+        storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::RouterDeclined => Some(false),
+        _ => None,
+    }
+}
+
+fn get_count_field(
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    suffix: &str,
+) -> String {
+    format!("{}_{suffix}", merchant_connector_id.get_string_repr())
+}
+
+/// The success rate of the connector account in percentage, once it has processed at least the
+/// minimum number of payments
+#[allow(clippy::as_conversions)]
+fn get_success_rate(
+    counts: &HashMap<String, String>,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    min_sample_size: u32,
+) -> Option<f64> {
+    let get_count = |suffix| {
+        counts
+            .get(&get_count_field(merchant_connector_id, suffix))
+            .and_then(|count| count.parse::<u64>().ok())
+            .unwrap_or_default()
+    };
+    let total_count = get_count(TOTAL_COUNT_SUFFIX);
+
+    (total_count > 0 && total_count >= u64::from(min_sample_size))
+        .then(|| get_count(SUCCESS_COUNT_SUFFIX) as f64 * 100.0 / total_count as f64)
+}
@@ -0,0 +1,239 @@
+use api_models::payments::{
+    KlarnaSessionTokenResponse, PaymentsSessionRequest, PaymentsSessionResponse,
+    PaypalSessionTokenResponse, SessionToken,
+};
+use common_utils::types::MinorUnit;
+use hyperswitch_domain_models::payments::{HeaderPayload, PaymentIntent};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        payments::{self, helpers, CallConnectorAction},
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        domain,
+    },
+};
+
+/// The connector session tokens which are cached against the payment intent when they are
+/// pre-created, along with the amount and currency of the intent they were created for
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct PrewarmedSessionTokens {
+    amount: MinorUnit,
+    currency: Option<api_enums::Currency>,
+    session_tokens: Vec<PrewarmedSessionToken>,
+}
+
+/// The session tokens which are created by a call to the connector, and can be reused until they
+/// expire
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "wallet_name")]
+#[serde(rename_all = "snake_case")]
+enum PrewarmedSessionToken {
+    Klarna(Box<KlarnaSessionTokenResponse>),
+    Paypal(Box<PaypalSessionTokenResponse>),
+}
+
+impl PrewarmedSessionToken {
+    fn from_session_token(session_token: &SessionToken) -> Option<Self> {
+        match session_token {
+            SessionToken::Klarna(klarna) => Some(Self::Klarna(klarna.clone())),
+            SessionToken::Paypal(paypal) => Some(Self::Paypal(paypal.clone())),
+            SessionToken::GooglePay(_)
+            | SessionToken::SamsungPay(_)
+            | SessionToken::ApplePay(_)
+            | SessionToken::OpenBanking(_)
+            | SessionToken::Paze(_)
+            | SessionToken::NoSessionTokenReceived => None,
+        }
+    }
+
+    fn get_payment_method_type(&self) -> api_enums::PaymentMethodType {
+        match self {
+            Self::Klarna(_) => api_enums::PaymentMethodType::Klarna,
+            Self::Paypal(_) => api_enums::PaymentMethodType::Paypal,
+        }
+    }
+}
+
+impl From<PrewarmedSessionToken> for SessionToken {
+    fn from(session_token: PrewarmedSessionToken) -> Self {
+        match session_token {
+            PrewarmedSessionToken::Klarna(klarna) => Self::Klarna(klarna),
+            PrewarmedSessionToken::Paypal(paypal) => Self::Paypal(paypal),
+        }
+    }
+}
+
+/// Creates the session tokens of the payment ahead of the customer selecting a payment method, and
+/// caches the connector session tokens against the payment intent, so that the session tokens
+/// call at checkout can return them without calling the connectors again.
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn prewarm_session_tokens(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsSessionRequest,
+    header_payload: HeaderPayload,
+) -> RouterResponse<PaymentsSessionResponse> {
+    let payment_id = req.payment_id.clone();
+    let response = Box::pin(payments::payments_core::<
+        api::Session,
+        PaymentsSessionResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Session>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        profile_id,
+        key_store.clone(),
+        payments::PaymentSession,
+        req,
+        services::AuthFlow::Client,
+        CallConnectorAction::Trigger,
+        None,
+        header_payload,
+    ))
+    .await?;
+
+    if let services::ApplicationResponse::Json(session_response) = &response {
+        let payment_intent = state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                &(&state).into(),
+                &payment_id,
+                merchant_account.get_id(),
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        cache_prewarmed_session_tokens(&state, &payment_intent, &session_response.session_token)
+            .await;
+    }
+
+    Ok(response)
+}
+
+async fn cache_prewarmed_session_tokens(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    session_tokens: &[SessionToken],
+) {
+    let session_tokens = session_tokens
+        .iter()
+        .filter_map(PrewarmedSessionToken::from_session_token)
+        .collect::<Vec<_>>();
+    if session_tokens.is_empty() {
+        return;
+    }
+
+    let prewarmed_session_tokens = PrewarmedSessionTokens {
+        amount: payment_intent.amount,
+        currency: payment_intent.currency,
+        session_tokens,
+    };
+    let key = helpers::get_redis_key_for_prewarmed_session_tokens(
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+    );
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &key,
+                    prewarmed_session_tokens,
+                    consts::PREWARMED_SESSION_TOKENS_REDIS_KEY_EXPIRY_SECONDS,
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(prewarmed_session_tokens_cache_error=?error);
+    }
+}
+
+/// Takes the session tokens which were pre-created for the payment out of the connectors which
+/// are to be called for session tokens. The tokens are only used while the amount and currency of
+/// the payment are unchanged since they were created.
+pub async fn get_prewarmed_session_tokens(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    connectors: Vec<api::SessionConnectorData>,
+) -> (Vec<api::SessionConnectorData>, Vec<SessionToken>) {
+    let Some(prewarmed_session_tokens) = get_cached_prewarmed_session_tokens(state, payment_intent)
+        .await
+        .filter(|prewarmed_session_tokens| {
+            prewarmed_session_tokens.amount == payment_intent.amount
+                && prewarmed_session_tokens.currency == payment_intent.currency
+        })
+    else {
+        return (connectors, Vec::new());
+    };
+
+    let (prewarmed_connectors, connectors): (Vec<_>, Vec<_>) =
+        connectors.into_iter().partition(|connector| {
+            prewarmed_session_tokens
+                .session_tokens
+                .iter()
+                .any(|session_token| {
+                    session_token.get_payment_method_type() == connector.payment_method_type
+                })
+        });
+    logger::debug!(
+        prewarmed_connectors_count = prewarmed_connectors.len(),
+        "Using pre-created session tokens"
+    );
+
+    let session_tokens = prewarmed_session_tokens
+        .session_tokens
+        .into_iter()
+        .filter(|session_token| {
+            prewarmed_connectors.iter().any(|connector| {
+                session_token.get_payment_method_type() == connector.payment_method_type
+            })
+        })
+        .map(SessionToken::from)
+        .collect();
+
+    (connectors, session_tokens)
+}
+
+async fn get_cached_prewarmed_session_tokens(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+) -> Option<PrewarmedSessionTokens> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<PrewarmedSessionTokens>(
+            &helpers::get_redis_key_for_prewarmed_session_tokens(
+                &payment_intent.merchant_id,
+                &payment_intent.payment_id,
+            ),
+            "PrewarmedSessionTokens",
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context() != &redis_interface::errors::RedisError::NotFound {
+                logger::error!(prewarmed_session_tokens_cache_error=?error);
+            }
+        })
+        .ok()
+}
@@ -24,23 +24,24 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
         mandate,
-        payment_methods::{self, cards::create_encrypted_data, network_tokenization},
+        payment_methods::{self, cards::create_encrypted_data, network_tokenization, vault},
         payments,
     },
     logger,
-    routes::{metrics, SessionState},
+    routes::{metrics, payment_methods as payment_methods_handler, SessionState},
     services,
     types::{
         self,
         api::{self, CardDetailFromLocker, CardDetailsPaymentMethod, PaymentMethodCreateExt},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
     },
     utils::{generate_id, OptionExt},
 };
 
 pub struct SavePaymentMethodData<Req> {
     request: Req,
+    payment_id: String,
     response: Result<types::PaymentsResponseData, types::ErrorResponse>,
     payment_method_token: Option<types::PaymentMethodToken>,
     payment_method: PaymentMethod,
@@ -53,6 +54,7 @@ impl<F, Req: Clone> From<&types::RouterData<F, Req, types::PaymentsResponseData>
     fn from(router_data: &types::RouterData<F, Req, types::PaymentsResponseData>) -> Self {
         Self {
             request: router_data.request.clone(),
+            payment_id: router_data.payment_id.clone(),
             response: router_data.response.clone(),
             payment_method_token: router_data.payment_method_token.clone(),
             payment_method: router_data.payment_method,
@@ -64,6 +66,9 @@ pub struct SavePaymentMethodDataResponse {
     pub payment_method_id: Option<String>,
     pub payment_method_status: Option<common_enums::PaymentMethodStatus>,
     pub connector_mandate_reference_id: Option<ConnectorMandateReferenceId>,
+    /// The short lived token of the card stored in the redis temp locker, if the locker was
+    /// unreachable while saving the card
+    pub vault_fallback_token: Option<String>,
 }
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
@@ -149,10 +154,13 @@ where
                 .get_setup_mandate_details()
                 .and_then(|mandate_data| mandate_data.customer_acceptance.clone());
 
-            let customer_acceptance = save_payment_method_data
+            let api_customer_acceptance = save_payment_method_data
                 .request
                 .get_customer_acceptance()
-                .or(mandate_data_customer_acceptance.clone().map(From::from))
+                .or(mandate_data_customer_acceptance.clone().map(From::from));
+
+            let customer_acceptance = api_customer_acceptance
+                .as_ref()
                 .map(|ca| ca.encode_to_value())
                 .transpose()
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -176,6 +184,30 @@ where
                     _ => (None, None, None),
                 };
 
+            // check if there needs to be a config if yes then remove it to a different place
+            let connector_mandate_reference_id = if connector_mandate_id.is_some() {
+                if let Some(ref mut record) = original_connector_mandate_reference_id {
+                    record.update(
+                        connector_mandate_id,
+                        None,
+                        None,
+                        mandate_metadata,
+                        connector_mandate_request_reference_id,
+                    );
+                    Some(record.clone())
+                } else {
+                    Some(ConnectorMandateReferenceId::new(
+                        connector_mandate_id,
+                        None,
+                        None,
+                        mandate_metadata,
+                        connector_mandate_request_reference_id,
+                    ))
+                }
+            } else {
+                None
+            };
+
             let pm_id = if customer_acceptance.is_some() {
                 let payment_method_create_request =
                     payment_methods::get_payment_method_create_request(
@@ -205,12 +237,42 @@ where
                     pm_status = Some(common_enums::PaymentMethodStatus::from(
                         save_payment_method_data.attempt_status,
                     ));
-                    let (res, dc) = Box::pin(save_in_locker(
+                    let (res, dc) = match Box::pin(save_in_locker(
                         state,
                         merchant_account,
                         payment_method_create_request.to_owned(),
                     ))
-                    .await?;
+                    .await
+                    {
+                        Ok(locker_response) => locker_response,
+                        Err(error)
+                            if state.conf.locker.outage_fallback_enabled
+                                && save_payment_method_data.payment_method
+                                    == PaymentMethod::Card
+                                && is_locker_unavailable(&error) =>
+                        {
+                            logger::error!(
+                                ?error,
+                                "Locker is unreachable, storing the card in the temp locker"
+                            );
+                            let vault_fallback_token = store_in_temp_locker_on_outage(
+                                state,
+                                &save_payment_method_data.request.get_payment_method_data(),
+                                Some(customer_id),
+                                save_payment_method_data.payment_method,
+                                key_store,
+                                business_profile,
+                            )
+                            .await?;
+                            return Ok(SavePaymentMethodDataResponse {
+                                payment_method_id: None,
+                                payment_method_status: None,
+                                connector_mandate_reference_id,
+                                vault_fallback_token: Some(vault_fallback_token),
+                            });
+                        }
+                        Err(error) => return Err(error),
+                    };
 
                     if is_network_tokenization_enabled {
                         let pm_data = &save_payment_method_data.request.get_payment_method_data();
@@ -704,44 +766,89 @@ where
             } else {
                 None
             };
-            // check if there needs to be a config if yes then remove it to a different place
-            let connector_mandate_reference_id = if connector_mandate_id.is_some() {
-                if let Some(ref mut record) = original_connector_mandate_reference_id {
-                    record.update(
-                        connector_mandate_id,
-                        None,
-                        None,
-                        mandate_metadata,
-                        connector_mandate_request_reference_id,
-                    );
-                    Some(record.clone())
-                } else {
-                    Some(ConnectorMandateReferenceId::new(
-                        connector_mandate_id,
-                        None,
-                        None,
-                        mandate_metadata,
-                        connector_mandate_request_reference_id,
-                    ))
-                }
-            } else {
-                None
-            };
+
+            if let (Some(payment_method_id), Some(setup_future_usage), Some(customer_acceptance)) = (
+                pm_id.as_ref(),
+                save_payment_method_data.request.get_setup_future_usage(),
+                api_customer_acceptance,
+            ) {
+                record_payment_method_consent_event(
+                    state,
+                    merchant_account,
+                    customer_id,
+                    payment_method_id,
+                    &save_payment_method_data.payment_id,
+                    setup_future_usage,
+                    customer_acceptance,
+                )
+                .await;
+            }
 
             Ok(SavePaymentMethodDataResponse {
                 payment_method_id: pm_id,
                 payment_method_status: pm_status,
                 connector_mandate_reference_id,
+                vault_fallback_token: None,
             })
         }
         Err(_) => Ok(SavePaymentMethodDataResponse {
             payment_method_id: None,
             payment_method_status: None,
             connector_mandate_reference_id: None,
+            vault_fallback_token: None,
         }),
     }
 }
 
+/// Records the consent of the customer to store the payment method for future usage, along with
+/// the time, IP address and consent text version of the acceptance. Failures are only logged, so
+/// that the payment is not failed because of the audit trail.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all)]
+async fn record_payment_method_consent_event(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: Option<id_type::CustomerId>,
+    payment_method_id: &str,
+    payment_id: &str,
+    setup_future_usage: storage_enums::FutureUsage,
+    customer_acceptance: api::CustomerAcceptance,
+) {
+    let now = common_utils::date_time::now();
+    let (ip_address, user_agent) = customer_acceptance
+        .online
+        .map(|online| (online.ip_address, Some(online.user_agent)))
+        .unwrap_or_default();
+
+    let consent_event = storage::PaymentMethodConsentEventNew {
+        merchant_id: merchant_account.get_id().to_owned(),
+        customer_id,
+        payment_method_id: payment_method_id.to_string(),
+        payment_id: id_type::PaymentId::wrap(payment_id.to_string()).ok(),
+        setup_future_usage,
+        consent_text_version: customer_acceptance.consent_text_version,
+        ip_address,
+        user_agent,
+        accepted_at: customer_acceptance.accepted_at.unwrap_or(now),
+        created_at: now,
+    };
+
+    state
+        .store
+        .insert_payment_method_consent_event(consent_event)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to record the consent event of the payment method"
+            )
+        })
+        .ok();
+}
+
 // check in review
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 #[instrument(skip_all)]
@@ -765,6 +872,69 @@ where
     todo!()
 }
 
+/// Whether the card could not be saved because the locker is unreachable, timed out or failed with
+/// a server error, rather than the locker rejecting the card
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn is_locker_unavailable(error: &error_stack::Report<errors::ApiErrorResponse>) -> bool {
+    error.frames().any(|frame| {
+        matches!(
+            frame.downcast_ref::<errors::VaultError>(),
+            Some(errors::VaultError::LockerUnavailable)
+        )
+    })
+}
+
+/// Stores the card in the redis temp locker when the locker is unreachable, so that the payment
+/// can proceed in a degraded mode. The card is not persisted, and the returned token is only
+/// valid for the order fulfillment time of the profile.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+async fn store_in_temp_locker_on_outage(
+    state: &SessionState,
+    payment_method_data: &domain::PaymentMethodData,
+    customer_id: Option<id_type::CustomerId>,
+    payment_method: PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+) -> RouterResult<String> {
+    let router_token = vault::Vault::store_payment_method_data_in_locker(
+        state,
+        None,
+        payment_method_data,
+        customer_id,
+        payment_method,
+        key_store,
+    )
+    .await?;
+
+    let vault_fallback_token = generate_id(consts::ID_LENGTH, "token");
+    let intent_fulfillment_time = business_profile
+        .get_order_fulfillment_time()
+        .unwrap_or(consts::DEFAULT_FULFILLMENT_TIME);
+    payment_methods_handler::ParentPaymentMethodToken::create_key_for_token((
+        &vault_fallback_token,
+        payment_method,
+    ))
+    .insert(
+        intent_fulfillment_time,
+        storage::PaymentTokenData::temporary_generic(router_token),
+        state,
+    )
+    .await?;
+
+    metrics::LOCKER_OUTAGE_FALLBACK_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("payment_method", payment_method.to_string())]),
+    );
+    Ok(vault_fallback_token)
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
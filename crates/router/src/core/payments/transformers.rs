@@ -288,6 +288,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         integrity_object: None,
         shipping_cost: payment_data.payment_intent.amount_details.shipping_cost,
         additional_payment_method_data: None,
+        currency_conversion: None,
     };
     let connector_mandate_request_reference_id = payment_data
         .payment_attempt
@@ -1588,6 +1589,13 @@ where
             .collect()
     });
 
+    let status_history_response = payment_data.get_status_history().map(|status_history| {
+        status_history
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    });
+
     let merchant_id = payment_attempt.merchant_id.to_owned();
     let payment_method_type = payment_attempt
         .payment_method_type
@@ -1737,6 +1745,7 @@ where
             payment_method_data: payment_data.get_payment_method_data().cloned(),
             amount,
             currency: currency.to_string(),
+            page_config: None,
         }))
     } else {
         let mut next_action_response = None;
@@ -1912,6 +1921,8 @@ where
                         ip_address: d.ip_address,
                         user_agent: d.user_agent,
                     }),
+                    consent_text_version: d.consent_text_version,
+                    signed_mandate_file_id: d.signed_mandate_file_id,
                 }),
             mandate_type: d.mandate_type.clone().map(|d| match d {
                 hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -1989,6 +2000,7 @@ where
             disputes: disputes_response,
             attempts: attempts_response,
             captures: captures_response,
+            status_history: status_history_response,
             mandate_id,
             mandate_data,
             setup_future_usage: payment_intent.setup_future_usage,
@@ -1997,7 +2009,13 @@ where
             capture_method: payment_attempt.capture_method,
             payment_method: payment_attempt.payment_method,
             payment_method_data: payment_method_data_response,
-            payment_token: payment_attempt.payment_token,
+            payment_token: payment_attempt.payment_token.or(payment_data
+                .get_vault_fallback_token()
+                .map(ToString::to_string)),
+            vault_fallback_applied: payment_data
+                .get_vault_fallback_token()
+                .is_some()
+                .then_some(true),
             shipping: payment_data
                 .get_address()
                 .get_shipping()
@@ -2078,6 +2096,9 @@ where
             order_tax_amount,
             connector_mandate_id,
             shipping_cost: payment_intent.shipping_cost,
+            presentment_currency: payment_attempt.presentment_currency,
+            presentment_amount: payment_attempt.presentment_amount,
+            exchange_rate: payment_attempt.exchange_rate,
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -2290,11 +2311,13 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             disputes: None,
             attempts: None,
             captures: None,
+            status_history: None,
             mandate_id: None,
             mandate_data: None,
             off_session: None,
             capture_on: None,
             payment_token: None,
+            vault_fallback_applied: None,
             email: None,
             name: None,
             phone: None,
@@ -2335,6 +2358,9 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             order_tax_amount: None,
             connector_mandate_id:None,
             shipping_cost: None,
+            presentment_currency: pa.presentment_currency,
+            presentment_amount: pa.presentment_amount,
+            exchange_rate: pa.exchange_rate,
         }
     }
 }
@@ -2619,6 +2645,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .merchant_order_reference_id
             .clone();
         let shipping_cost = payment_data.payment_intent.shipping_cost;
+        let currency_conversion = payments::dcc::get_connector_currency_conversion(
+            additional_data.state,
+            &payment_data.payment_attempt,
+            connector_name,
+        );
 
         Ok(Self {
             payment_method_data: (payment_method_data.get_required_value("payment_method_data")?),
@@ -2667,6 +2698,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             integrity_object: None,
             additional_payment_method_data,
             shipping_cost,
+            currency_conversion,
         })
     }
 }
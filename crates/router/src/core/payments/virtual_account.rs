@@ -0,0 +1,277 @@
+use std::cmp::Ordering;
+
+use api_models::payments::{BankTransferInstructions, BankTransferNextStepsData, ReceiverDetails};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::{self, storage, storage::enums},
+};
+
+/// Reconciles the amount credited to the virtual account of a payment, as reported by the
+/// connector in the response to the payment or to its sync, with the amount of the payment. A
+/// partial credit leaves the payment awaiting the transfer of the remaining amount by the
+/// customer. A credit in excess of the amount completes the payment for its amount, and the
+/// excess is surfaced in the receiver details of the bank transfer instructions, so that the
+/// merchant can return it to the customer.
+#[instrument(skip_all)]
+pub fn reconcile_credited_amount<F, T>(
+    payment_attempt: &storage::PaymentAttempt,
+    router_data: &mut types::RouterData<F, T, types::PaymentsResponseData>,
+) -> RouterResult<()> {
+    if payment_attempt.payment_method_type != Some(enums::PaymentMethodType::VirtualAccount) {
+        return Ok(());
+    }
+
+    let mut bank_transfer_next_steps = match router_data.response.as_ref() {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            connector_metadata, ..
+        }) => connector_metadata
+            .clone()
+            .or(payment_attempt.connector_metadata.clone())
+            .and_then(|metadata| {
+                metadata
+                    .parse_value::<BankTransferNextStepsData>("NextStepsRequirements")
+                    .map_err(|error| {
+                        logger::warn!(?error, "Failed to parse the bank transfer instructions")
+                    })
+                    .ok()
+            })
+            .filter(|next_steps| {
+                matches!(
+                    next_steps.bank_transfer_instructions,
+                    BankTransferInstructions::VirtualAccount(_)
+                )
+            }),
+        _ => None,
+    };
+
+    // Connectors such as Stripe only report the amount received once the payment is fully
+    // funded, so until then the amount credited so far is taken from the receiver details of the
+    // transfer instructions
+    let Some(amount_received) = router_data
+        .minor_amount_captured
+        .filter(|amount_received| *amount_received > MinorUnit::zero())
+        .or_else(|| {
+            bank_transfer_next_steps
+                .as_ref()
+                .and_then(|next_steps| next_steps.receiver.as_ref())
+                .map(|receiver| MinorUnit::new(receiver.amount_received))
+        })
+        .filter(|amount_received| *amount_received > MinorUnit::zero())
+    else {
+        return Ok(());
+    };
+
+    let amount = payment_attempt.net_amount.get_total_amount();
+    let Some(ReconciledCredit {
+        status,
+        amount_captured,
+        amount_overpaid,
+    }) = reconcile_credit(router_data.status, amount_received, amount)
+    else {
+        return Ok(());
+    };
+    logger::info!(
+        amount_received = amount_received.get_amount_as_i64(),
+        ?status,
+        "Reconciled the amount credited to the virtual account"
+    );
+    router_data.status = status;
+    router_data.amount_captured = amount_captured.map(|amount| amount.get_amount_as_i64());
+    router_data.minor_amount_captured = amount_captured;
+
+    // The receiver details are only updated if the connector provided the instructions for the
+    // transfer to the virtual account
+    if let (
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            connector_metadata, ..
+        }),
+        Some(bank_transfer_next_steps),
+    ) = (
+        router_data.response.as_mut(),
+        bank_transfer_next_steps.as_mut(),
+    ) {
+        let amount_charged = bank_transfer_next_steps
+            .receiver
+            .as_ref()
+            .and_then(|receiver| receiver.amount_charged);
+        bank_transfer_next_steps.receiver = Some(ReceiverDetails {
+            amount_received: amount_received.get_amount_as_i64(),
+            amount_charged,
+            amount_remaining: Some((amount - amount_received).get_amount_as_i64().max(0)),
+            amount_overpaid: amount_overpaid.map(|amount| amount.get_amount_as_i64()),
+        });
+        *connector_metadata = Some(
+            bank_transfer_next_steps
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode the bank transfer instructions")?,
+        );
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct ReconciledCredit {
+    status: enums::AttemptStatus,
+    amount_captured: Option<MinorUnit>,
+    amount_overpaid: Option<MinorUnit>,
+}
+
+/// Determines the status and the captured amount of a payment from the amount credited to its
+/// virtual account. A `Charged` or `Pending` status reported by the connector is never
+/// overridden, and the captured amount never exceeds the amount of the payment. Returns `None`
+/// if the status reported by the connector is not related to the funding of the payment.
+fn reconcile_credit(
+    connector_status: enums::AttemptStatus,
+    amount_received: MinorUnit,
+    amount: MinorUnit,
+) -> Option<ReconciledCredit> {
+    let awaiting_funds = match connector_status {
+        enums::AttemptStatus::PartialCharged | enums::AttemptStatus::AuthenticationPending => true,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::Pending => false,
+        _ => return None,
+    };
+
+    let (status, amount_overpaid) = match amount_received
+        .get_amount_as_i64()
+        .cmp(&amount.get_amount_as_i64())
+    {
+        Ordering::Less if awaiting_funds => (enums::AttemptStatus::AuthenticationPending, None),
+        // The connector has completed or is processing the payment, so the amount credited so
+        // far is only informational
+        Ordering::Less => (connector_status, None),
+        Ordering::Equal if awaiting_funds => (enums::AttemptStatus::Charged, None),
+        Ordering::Equal => (connector_status, None),
+        Ordering::Greater => {
+            let amount_overpaid = amount_received - amount;
+            logger::warn!(
+                amount_overpaid = amount_overpaid.get_amount_as_i64(),
+                "Amount credited to the virtual account exceeds the amount of the payment"
+            );
+            let status = if awaiting_funds {
+                enums::AttemptStatus::Charged
+            } else {
+                connector_status
+            };
+            (status, Some(amount_overpaid))
+        }
+    };
+    let amount_captured = (status == enums::AttemptStatus::Charged).then(|| {
+        if amount_overpaid.is_some() {
+            amount
+        } else {
+            amount_received
+        }
+    });
+
+    Some(ReconciledCredit {
+        status,
+        amount_captured,
+        amount_overpaid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconcile_partial_credit() {
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::PartialCharged,
+                MinorUnit::new(400),
+                MinorUnit::new(1000)
+            ),
+            Some(ReconciledCredit {
+                status: enums::AttemptStatus::AuthenticationPending,
+                amount_captured: None,
+                amount_overpaid: None,
+            })
+        );
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::Pending,
+                MinorUnit::new(400),
+                MinorUnit::new(1000)
+            ),
+            Some(ReconciledCredit {
+                status: enums::AttemptStatus::Pending,
+                amount_captured: None,
+                amount_overpaid: None,
+            })
+        );
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::Charged,
+                MinorUnit::new(400),
+                MinorUnit::new(1000)
+            ),
+            Some(ReconciledCredit {
+                status: enums::AttemptStatus::Charged,
+                amount_captured: Some(MinorUnit::new(400)),
+                amount_overpaid: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_reconcile_exact_credit() {
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::AuthenticationPending,
+                MinorUnit::new(1000),
+                MinorUnit::new(1000)
+            ),
+            Some(ReconciledCredit {
+                status: enums::AttemptStatus::Charged,
+                amount_captured: Some(MinorUnit::new(1000)),
+                amount_overpaid: None,
+            })
+        );
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::Pending,
+                MinorUnit::new(1000),
+                MinorUnit::new(1000)
+            ),
+            Some(ReconciledCredit {
+                status: enums::AttemptStatus::Pending,
+                amount_captured: None,
+                amount_overpaid: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_reconcile_excess_credit() {
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::Charged,
+                MinorUnit::new(1250),
+                MinorUnit::new(1000)
+            ),
+            Some(ReconciledCredit {
+                status: enums::AttemptStatus::Charged,
+                amount_captured: Some(MinorUnit::new(1000)),
+                amount_overpaid: Some(MinorUnit::new(250)),
+            })
+        );
+        assert_eq!(
+            reconcile_credit(
+                enums::AttemptStatus::Failure,
+                MinorUnit::new(1250),
+                MinorUnit::new(1000)
+            ),
+            None
+        );
+    }
+}
@@ -9,10 +9,13 @@ use common_utils::{
     ext_traits::{AsyncExt, Encode, OptionExt},
     link_utils,
     types::{AmountConvertor, StringMajorUnitForConnector},
+    validation::bank_account,
 };
-use diesel_models::PayoutLinkUpdate;
+use diesel_models::{enums as storage_enums, process_tracker::business_status, PayoutLinkUpdate};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
+use rust_i18n::t;
+use time::PrimitiveDateTime;
 
 use super::errors::{RouterResponse, StorageErrorExt};
 use crate::{
@@ -20,11 +23,17 @@ use crate::{
     core::{
         payments::helpers as payment_helpers,
         payouts::{helpers as payout_helpers, validator},
+        test_clocks,
     },
     errors,
     routes::{app::StorageInterface, SessionState},
     services,
-    types::{api, domain, transformers::ForeignFrom},
+    types::{api, domain, storage, transformers::ForeignFrom},
+};
+#[cfg(feature = "v1")]
+use crate::{
+    core::{payouts as payouts_core, webhooks as webhooks_core},
+    logger,
 };
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -114,6 +123,19 @@ pub async fn initiate_payout_link(
                 theme: link_data.ui_config.theme.unwrap_or(default_ui_config.theme),
             };
 
+            // The payout is cancelled along with the link, if it is still awaiting the payout
+            // method details
+            #[cfg(feature = "v1")]
+            if status != link_utils::PayoutLinkStatus::Invalidated {
+                Box::pin(expire_payout_link(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    payout_link,
+                ))
+                .await?;
+            }
+            #[cfg(not(feature = "v1"))]
             if status != link_utils::PayoutLinkStatus::Invalidated {
                 let payout_link_update = PayoutLinkUpdate::StatusUpdate {
                     link_status: link_utils::PayoutLinkStatus::Invalidated,
@@ -248,6 +270,10 @@ pub async fn initiate_payout_link(
                 locale: locale.clone(),
                 form_layout: link_data.form_layout,
                 test_mode: link_data.test_mode.unwrap_or(false),
+                bank_account_form: address
+                    .as_ref()
+                    .and_then(|address| address.country)
+                    .and_then(|country| get_bank_account_form(country, &locale)),
             };
 
             let serialized_css_content = String::new();
@@ -329,6 +355,54 @@ pub async fn initiate_payout_link(
     }
 }
 
+/// The form for the bank account details of the country, with the labels of its fields in the
+/// locale of the payout link
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+fn get_bank_account_form(
+    country: common_enums::CountryAlpha2,
+    locale: &str,
+) -> Option<payouts::PayoutLinkBankAccountForm> {
+    let scheme = bank_account::get_bank_account_scheme(country)?;
+    let fields = bank_account::get_bank_account_field_rules(country)?
+        .into_iter()
+        .map(|rule| payouts::PayoutLinkBankAccountFieldInfo {
+            label: get_bank_account_field_label(rule.field, locale),
+            rule,
+        })
+        .collect();
+
+    Some(payouts::PayoutLinkBankAccountForm {
+        country,
+        scheme,
+        fields,
+    })
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+fn get_bank_account_field_label(field: bank_account::BankAccountField, locale: &str) -> String {
+    match field {
+        bank_account::BankAccountField::AccountNumber => {
+            t!("payout_link.bank_account.account_number", locale = locale)
+        }
+        bank_account::BankAccountField::RoutingNumber => {
+            t!("payout_link.bank_account.routing_number", locale = locale)
+        }
+        bank_account::BankAccountField::SortCode => {
+            t!("payout_link.bank_account.sort_code", locale = locale)
+        }
+        bank_account::BankAccountField::Iban => {
+            t!("payout_link.bank_account.iban", locale = locale)
+        }
+        bank_account::BankAccountField::Clabe => {
+            t!("payout_link.bank_account.clabe", locale = locale)
+        }
+        bank_account::BankAccountField::Ifsc => {
+            t!("payout_link.bank_account.ifsc", locale = locale)
+        }
+    }
+    .to_string()
+}
+
 #[cfg(feature = "payouts")]
 pub async fn filter_payout_methods(
     state: &SessionState,
@@ -481,3 +555,246 @@ pub fn check_currency_country_filters(
         Ok(currency_filter.or(country_filter))
     }
 }
+
+const PAYOUT_LINK_EXPIRY_TASK: &str = "PAYOUT_LINK_EXPIRY";
+const PAYOUT_LINK_EXPIRY_TAG: &str = "PAYOUT_LINK";
+
+/// The maximum number of payout links expired in a single run of the expiry sweep of a merchant
+pub const PAYOUT_LINK_EXPIRY_BATCH_SIZE: i64 = 100;
+
+pub fn generate_task_id_for_payout_link_expiry_workflow(
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    format!(
+        "{}_{PAYOUT_LINK_EXPIRY_TASK}_{}",
+        storage::ProcessTrackerRunner::PayoutLinkExpiryWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Schedules the sweep which expires the payout links of the merchant to run once the payout link
+/// expires. A sweep which is already scheduled to run before the expiry is left untouched, as the
+/// sweep reschedules itself for the next payout link to expire.
+pub async fn schedule_payout_link_expiry_sweep(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    expiry: PrimitiveDateTime,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let process_tracker_id = generate_task_id_for_payout_link_expiry_workflow(merchant_id);
+    let schedule_time = expiry - test_clocks::get_merchant_time_offset(state, merchant_id).await?;
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payout link expiry task from process tracker")?;
+
+    match existing_process {
+        Some(process)
+            if process.status != storage_enums::ProcessTrackerStatus::Finish
+                && process
+                    .schedule_time
+                    .is_some_and(|scheduled_at| scheduled_at <= schedule_time) => {}
+        // The sweep is finished once the merchant has no payout links awaiting submission
+        Some(process) => {
+            let is_finished = process.status == storage_enums::ProcessTrackerStatus::Finish;
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: is_finished.then_some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: is_finished.then(|| String::from(business_status::PENDING)),
+                    status: is_finished.then_some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule payout link expiry task in process tracker")?;
+        }
+        None => {
+            let tracking_data = storage::PayoutLinkExpiryTrackingData {
+                merchant_id: merchant_id.to_owned(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                PAYOUT_LINK_EXPIRY_TASK,
+                storage::ProcessTrackerRunner::PayoutLinkExpiryWorkflow,
+                [PAYOUT_LINK_EXPIRY_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct PAYOUT_LINK_EXPIRY process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting PAYOUT_LINK_EXPIRY task to process_tracker for merchant_id: {}",
+                        merchant_id.get_string_repr()
+                    )
+                })?;
+        }
+    };
+
+    Ok(())
+}
+
+/// Expires the payout links of the merchant which were not submitted before their expiry, and
+/// returns the time at which the sweep is to run next, if any payout links await submission
+#[cfg(feature = "v1")]
+pub async fn expire_payout_links(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<Option<PrimitiveDateTime>> {
+    let payout_links = state
+        .store
+        .find_initiated_payout_links_by_merchant_id(
+            merchant_account.get_id(),
+            PAYOUT_LINK_EXPIRY_BATCH_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout links awaiting submission")?;
+
+    let is_batch_full = i64::try_from(payout_links.len())
+        .is_ok_and(|batch_size| batch_size >= PAYOUT_LINK_EXPIRY_BATCH_SIZE);
+    // Payout links are expired according to the time of the test clock attached to the merchant,
+    // if any
+    let clock_offset =
+        test_clocks::get_merchant_time_offset(state, merchant_account.get_id()).await?;
+    let now = common_utils::date_time::now();
+    let (expired_payout_links, active_payout_links): (Vec<_>, Vec<_>) = payout_links
+        .into_iter()
+        .partition(|payout_link| payout_link.expiry <= now + clock_offset);
+
+    let mut failed_payout_links = 0;
+    for payout_link in expired_payout_links {
+        let payout_link_id = payout_link.link_id.clone();
+        if let Err(error) = Box::pin(expire_payout_link(
+            state,
+            merchant_account,
+            key_store,
+            payout_link,
+        ))
+        .await
+        {
+            logger::error!(?error, %payout_link_id, "Failed to expire payout link");
+            failed_payout_links += 1;
+        }
+    }
+
+    // The payout links which could not be expired are retried by the process tracker
+    if failed_payout_links > 0 {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::InternalServerError
+        ))
+        .attach_printable(format!(
+            "Failed to expire {failed_payout_links} payout links"
+        ));
+    }
+
+    // The payout links are ordered by their expiry, so the remaining payout links of a full batch
+    // may have expired already
+    Ok(match active_payout_links.first() {
+        Some(payout_link) => Some(payout_link.expiry - clock_offset),
+        None => is_batch_full.then_some(now),
+    })
+}
+
+/// Cancels the payout of an expired payout link if it is still awaiting the payout method details,
+/// invalidates the link and emits the `payout_link_expired` webhook
+#[cfg(feature = "v1")]
+async fn expire_payout_link(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_link: storage::PayoutLink,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let mut payout_data = payouts_core::make_payout_data(
+        state,
+        merchant_account,
+        None,
+        key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: payout_link.primary_reference.clone(),
+        }),
+        common_utils::consts::DEFAULT_LOCALE,
+    )
+    .await?;
+
+    let is_cancelled =
+        payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresPayoutMethodData;
+    if is_cancelled {
+        let status = storage_enums::PayoutStatus::Cancelled;
+        let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+            connector_payout_id: payout_data.payout_attempt.connector_payout_id.to_owned(),
+            status,
+            error_message: Some("Payout link expired".to_string()),
+            error_code: None,
+            is_eligible: None,
+            unified_code: None,
+            unified_message: None,
+        };
+        payout_data.payout_attempt = db
+            .update_payout_attempt(
+                &payout_data.payout_attempt,
+                updated_payout_attempt,
+                &payout_data.payouts,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payout_attempt in db")?;
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                storage::PayoutsUpdate::StatusUpdate { status },
+                &payout_data.payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payouts in db")?;
+    }
+
+    db.update_payout_link(
+        payout_link,
+        PayoutLinkUpdate::StatusUpdate {
+            link_status: link_utils::PayoutLinkStatus::Invalidated,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating payout links in db")?;
+
+    // The payout has progressed past the payout link if it is no longer awaiting the payout
+    // method details
+    if is_cancelled {
+        let router_response =
+            payouts_core::response_handler(state, merchant_account, &payout_data).await?;
+        if let services::ApplicationResponse::Json(payout_response) = router_response {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account.clone(),
+                payout_data.business_profile.clone(),
+                key_store,
+                storage_enums::EventType::PayoutLinkExpired,
+                storage_enums::EventClass::Payouts,
+                payout_data.payouts.payout_id.clone(),
+                storage_enums::EventObjectType::PayoutDetails,
+                api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+                Some(payout_data.payout_attempt.created_at),
+            ))
+            .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
 pub mod access_token;
+pub mod account_name_verification;
+pub mod balance_rules;
+pub mod fees;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -32,7 +35,11 @@ use router_env::{instrument, logger, tracing, Env};
 use scheduler::utils as pt_utils;
 use serde_json;
 use time::Duration;
+#[cfg(feature = "v1")]
+use tracing_futures::Instrument;
 
+#[cfg(feature = "v1")]
+use crate::core::{email_templates, ledger, link_delivery, webhooks as webhooks_core};
 #[cfg(feature = "olap")]
 use crate::types::domain::behaviour::Conversion;
 #[cfg(feature = "olap")]
@@ -43,7 +50,7 @@ use crate::{
             self, ConnectorErrorExt, CustomResult, RouterResponse, RouterResult, StorageErrorExt,
         },
         payments::{self, customers, helpers as payment_helpers},
-        utils as core_utils,
+        payout_link, usage_metering, utils as core_utils,
     },
     db::StorageInterface,
     routes::SessionState,
@@ -53,7 +60,7 @@ use crate::{
         api::{self, payments as payment_api_types, payouts},
         domain,
         storage::{self, PaymentRoutingInfo},
-        transformers::ForeignFrom,
+        transformers::{ForeignFrom, ForeignInto},
     },
     utils::{self, OptionExt},
 };
@@ -72,6 +79,8 @@ pub struct PayoutData {
     pub should_terminate: bool,
     pub payout_link: Option<PayoutLink>,
     pub current_locale: String,
+    pub attempts: Option<Vec<storage::PayoutAttempt>>,
+    pub status_history: Option<Vec<storage::StatusHistory>>,
 }
 
 // ********************************************** CORE FLOWS **********************************************
@@ -183,6 +192,7 @@ pub async fn make_connector_decision(
                 let config_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_account.get_id(),
+                    payout_data.business_profile.payout_retry_config.as_ref(),
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -220,6 +230,7 @@ pub async fn make_connector_decision(
                 let config_multiple_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_account.get_id(),
+                    payout_data.business_profile.payout_retry_config.as_ref(),
                     PayoutRetryType::MultiConnector,
                 )
                 .await;
@@ -239,6 +250,7 @@ pub async fn make_connector_decision(
                 let config_single_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_account.get_id(),
+                    payout_data.business_profile.payout_retry_config.as_ref(),
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -465,6 +477,9 @@ pub async fn payouts_update_core(
             ),
         }));
     }
+    if let Some(currency) = req.currency {
+        payment_helpers::validate_currency_for_profile(currency, &payout_data.business_profile)?;
+    }
     helpers::update_payouts_and_payout_attempt(
         &mut payout_data,
         &merchant_account,
@@ -526,6 +541,15 @@ pub async fn payouts_retrieve_core(
     req: payouts::PayoutRetrieveRequest,
     locale: &str,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    core_utils::validate_expandable_objects(
+        req.expand.as_deref(),
+        &[
+            api_enums::ExpandableObject::Attempts,
+            api_enums::ExpandableObject::Customer,
+            api_enums::ExpandableObject::PaymentMethod,
+            api_enums::ExpandableObject::StatusHistory,
+        ],
+    )?;
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
@@ -560,9 +584,84 @@ pub async fn payouts_retrieve_core(
         .await?;
     }
 
+    if req.should_expand(api_enums::ExpandableObject::Attempts) {
+        let attempts = state
+            .store
+            .find_payout_attempts_by_merchant_id_payout_id(
+                merchant_account.get_id(),
+                &payout_data.payouts.payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while fetching payout attempts for payout_id: {}",
+                    payout_data.payouts.payout_id
+                )
+            })?;
+        payout_data.attempts = Some(attempts);
+    }
+
+    if req.should_expand(api_enums::ExpandableObject::StatusHistory) {
+        let status_history = state
+            .store
+            .find_status_history_by_merchant_id_entity_id(
+                merchant_account.get_id(),
+                storage_enums::StatusHistoryEntityType::Payout,
+                &payout_data.payouts.payout_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while fetching status history for payout_id: {}",
+                    payout_data.payouts.payout_id
+                )
+            })?;
+        payout_data.status_history = Some(status_history);
+    }
+
     response_handler(&state, &merchant_account, &payout_data).await
 }
 
+#[instrument(skip_all)]
+pub async fn payouts_attempts_list_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: payouts::PayoutAttemptListRequest,
+) -> RouterResponse<payouts::PayoutAttemptListResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payouts = db
+        .find_payout_by_merchant_id_payout_id(merchant_id, &req.payout_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payouts)?;
+
+    let mut payout_attempts = db
+        .find_payout_attempts_by_merchant_id_payout_id(merchant_id, &req.payout_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+    payout_attempts.sort_by_key(|payout_attempt| payout_attempt.created_at);
+
+    let data = payout_attempts
+        .into_iter()
+        .map(|payout_attempt| api::PayoutAttemptResponse::foreign_from((&payouts, payout_attempt)))
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutAttemptListResponse {
+            payout_id: payouts.payout_id,
+            total_count: data.len(),
+            data,
+        },
+    ))
+}
+
 #[instrument(skip_all)]
 pub async fn payouts_cancel_core(
     state: SessionState,
@@ -730,6 +829,26 @@ pub async fn payouts_fulfill_core(
         .await?
         .get_required_value("payout_method_data")?,
     );
+
+    // Verify if the recipient is verified by the connector
+    complete_recipient_kyc_status_check(
+        &state,
+        &merchant_account,
+        &key_store,
+        &connector_data,
+        &mut payout_data,
+    )
+    .await?;
+    helpers::validate_recipient_kyc_status(&connector_data, &payout_data)?;
+
+    // Verify if the name of the recipient matches the name of the holder of the bank account
+    account_name_verification::verify_account_name(&state, &merchant_account, &mut payout_data)
+        .await?;
+    account_name_verification::validate_account_name_match(&payout_data)?;
+
+    // Verify if the ledger balance of the profile covers the payout
+    balance_rules::validate_payout_balance(&state, &payout_data).await?;
+
     Box::pin(fulfill_payout(
         &state,
         &merchant_account,
@@ -1137,18 +1256,71 @@ pub async fn call_connector_payout(
     ))
     .await?;
 
+    // Recipient KYC status flow
+    complete_recipient_kyc_status_check(
+        state,
+        merchant_account,
+        key_store,
+        connector_data,
+        payout_data,
+    )
+    .await?;
+
+    // Account name verification flow
+    if payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresFulfillment {
+        account_name_verification::verify_account_name(state, merchant_account, payout_data)
+            .await?;
+    }
+
     // Auto fulfillment flow
     let status = payout_data.payout_attempt.status;
     if payouts.auto_fulfill && status == storage_enums::PayoutStatus::RequiresFulfillment {
-        Box::pin(fulfill_payout(
-            state,
-            merchant_account,
-            key_store,
-            connector_data,
-            payout_data,
-        ))
-        .await
-        .attach_printable("Payout fulfillment failed for given Payout request")?;
+        if !helpers::is_recipient_kyc_verified(connector_data, payout_data) {
+            // Fulfillment is triggered by the process tracker once the recipient is verified
+            add_recipient_kyc_status_task(
+                &*state.store,
+                payout_data,
+                common_utils::date_time::now().saturating_add(Duration::seconds(
+                    consts::RECIPIENT_KYC_STATUS_POLL_DELAY_IN_SECONDS,
+                )),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to add payout_recipient_kyc_status workflow to process tracker"
+                )
+            })
+            .ok();
+        } else if let Err(error) =
+            account_name_verification::validate_account_name_match(payout_data)
+        {
+            // The payout is left to be fulfilled or cancelled by the merchant
+            logger::info!(?error, "Payout was not auto fulfilled");
+        } else if let Some(fulfillment_time) = helpers::get_deferred_fulfillment_time(payout_data) {
+            // The payout is fulfilled by the process tracker once the fulfillment window opens
+            add_payout_fulfillment_task(&*state.store, payout_data, fulfillment_time)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while adding payout_fulfillment workflow to process tracker",
+                )?;
+        } else if let Err(error) = balance_rules::validate_payout_balance(state, payout_data).await
+        {
+            // The payout is left to be fulfilled by the merchant once the balance covers it, or
+            // to be cancelled
+            logger::info!(?error, "Payout was not auto fulfilled");
+        } else {
+            Box::pin(fulfill_payout(
+                state,
+                merchant_account,
+                key_store,
+                connector_data,
+                payout_data,
+            ))
+            .await
+            .attach_printable("Payout fulfillment failed for given Payout request")?;
+        }
     }
 
     Ok(())
@@ -1529,6 +1701,14 @@ pub async fn complete_create_payout(
                 | storage_enums::PayoutStatus::RequiresPayoutMethodData
         )
     {
+        // Fail fast if the connector does not hold enough funds to disburse the payout
+        if connector_data.connector_name.supports_payout_balance()
+            && helpers::config_should_check_payout_balance(&*state.store, merchant_account.get_id())
+                .await
+        {
+            validate_payout_balance(state, merchant_account, connector_data, payout_data).await?;
+        }
+
         if connector_data
             .connector_name
             .supports_instant_payout(payout_data.payouts.payout_type)
@@ -1661,6 +1841,15 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            fees::update_payout_fee(
+                state,
+                merchant_account,
+                payout_data,
+                payout_response_data.connector_fee,
+            )
+            .await?;
+            #[cfg(feature = "v1")]
+            ledger::record_payout(state, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1726,6 +1915,198 @@ pub async fn create_payout(
     Ok(())
 }
 
+pub async fn validate_payout_balance(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector_data: &api::ConnectorData,
+    payout_data: &PayoutData,
+) -> RouterResult<()> {
+    let merchant_connector_account = payout_data
+        .merchant_connector_account
+        .as_ref()
+        .get_required_value("merchant_connector_account")?;
+    let currency = payout_data.payouts.source_currency;
+    let balances = get_payout_connector_balance(
+        state,
+        merchant_account,
+        connector_data,
+        merchant_connector_account,
+        Some(currency),
+    )
+    .await?;
+
+    let available_amount = balances
+        .iter()
+        .find(|balance| balance.currency == currency)
+        .map(|balance| balance.available_amount)
+        .unwrap_or(MinorUnit::new(0));
+
+    utils::when(available_amount < payout_data.payouts.amount, || {
+        Err(report!(
+            errors::ApiErrorResponse::InsufficientPayoutBalance {
+                connector: connector_data.connector_name.to_string(),
+                currency: currency.to_string(),
+            }
+        ))
+        .attach_printable_lazy(|| {
+            format!(
+                "Available balance {} is lower than the payout amount {}",
+                available_amount, payout_data.payouts.amount
+            )
+        })
+    })
+}
+
+pub async fn get_payout_connector_balance(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector_data: &api::ConnectorData,
+    merchant_connector_account: &payment_helpers::MerchantConnectorAccountType,
+    currency: Option<storage_enums::Currency>,
+) -> RouterResult<Vec<types::PayoutConnectorBalance>> {
+    // 1. Form Router data
+    let router_data = core_utils::construct_payout_balance_router_data(
+        merchant_account,
+        &connector_data.connector_name.to_string(),
+        merchant_connector_account,
+        currency,
+    )?;
+
+    // 2. Fetch connector integration details
+    let connector_integration: services::BoxedPayoutConnectorIntegrationInterface<
+        api::PoBalance,
+        types::PayoutBalanceRequestData,
+        types::PayoutBalanceResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // 3. Call connector service
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payout_failed_response()?;
+
+    // 4. Process data returned by the connector
+    router_data_resp
+        .response
+        .map(|balance_response| balance_response.balances)
+        .map_err(|err| {
+            report!(errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector_data.connector_name.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_balance_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBalanceRequest,
+) -> RouterResponse<payouts::PayoutBalanceResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let payout_mcas = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_account.get_id(),
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .into_iter()
+        .filter(|mca| mca.connector_type == common_enums::ConnectorType::PayoutProcessor)
+        .filter(|mca| {
+            req.merchant_connector_id
+                .as_ref()
+                .map_or(true, |merchant_connector_id| {
+                    *merchant_connector_id == mca.get_id()
+                })
+        })
+        .filter(|mca| {
+            profile_id_list
+                .as_ref()
+                .map_or(true, |profile_ids| profile_ids.contains(&mca.profile_id))
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(merchant_connector_id) = req.merchant_connector_id.as_ref() {
+        utils::when(payout_mcas.is_empty(), || {
+            Err(report!(
+                errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                    id: merchant_connector_id.get_string_repr().to_string(),
+                }
+            ))
+        })?;
+    }
+
+    let mut data = Vec::new();
+    for mca in payout_mcas {
+        let connector_data = api::ConnectorData::get_payout_connector_by_name(
+            &state.conf.connectors,
+            &mca.connector_name,
+            api::GetToken::Connector,
+            Some(mca.get_id()),
+        )?;
+        if !connector_data.connector_name.supports_payout_balance() {
+            // Report unsupported connectors only when they are explicitly asked for
+            if req.merchant_connector_id.is_some() {
+                return Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: format!(
+                        "Fetching payout balance is not supported by {}",
+                        connector_data.connector_name
+                    ),
+                }));
+            }
+            continue;
+        }
+        let connector = api_enums::PayoutConnectors::try_from(connector_data.connector_name)
+            .map_err(|err| {
+                report!(errors::ApiErrorResponse::InternalServerError).attach_printable(err)
+            })?;
+        let merchant_connector_id = mca.get_id();
+        let profile_id = mca.profile_id.clone();
+        let merchant_connector_account =
+            payment_helpers::MerchantConnectorAccountType::DbVal(Box::new(mca));
+        let balances = get_payout_connector_balance(
+            &state,
+            &merchant_account,
+            &connector_data,
+            &merchant_connector_account,
+            req.currency,
+        )
+        .await?;
+
+        data.push(payouts::PayoutConnectorBalanceResponse {
+            merchant_connector_id,
+            connector,
+            profile_id,
+            balances: balances
+                .into_iter()
+                .map(|balance| payouts::PayoutCurrencyBalance {
+                    currency: balance.currency,
+                    available_amount: balance.available_amount,
+                    pending_amount: balance.pending_amount,
+                })
+                .collect(),
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBalanceResponse { data },
+    ))
+}
+
 async fn complete_payout_quote_steps_if_required<F>(
     state: &SessionState,
     connector_data: &api::ConnectorData,
@@ -1920,6 +2301,15 @@ pub async fn update_retrieve_payout_tracker<F, T>(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            fees::update_payout_fee(
+                state,
+                merchant_account,
+                payout_data,
+                payout_response_data.connector_fee,
+            )
+            .await?;
+            #[cfg(feature = "v1")]
+            ledger::record_payout(state, payout_data).await;
         }
         Err(err) => {
             // log in case of error in retrieval
@@ -2055,6 +2445,169 @@ pub async fn create_recipient_disburse_account(
     Ok(())
 }
 
+pub async fn complete_recipient_kyc_status_check(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_data: &api::ConnectorData,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    if !payout_data.should_terminate
+        && payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresFulfillment
+        && connector_data
+            .connector_name
+            .supports_payout_recipient_status()
+    {
+        check_recipient_kyc_status(
+            state,
+            merchant_account,
+            key_store,
+            connector_data,
+            payout_data,
+        )
+        .await
+        .attach_printable("Retrieval of recipient's KYC status failed")?;
+    }
+    Ok(())
+}
+
+/// Fetches the KYC status of the recipient from the connector, and updates it in the customer if
+/// it has changed. A `payout_recipient_kyc_updated` event is emitted for the payout on a change.
+pub async fn check_recipient_kyc_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_data: &api::ConnectorData,
+    payout_data: &mut PayoutData,
+) -> RouterResult<Option<storage_enums::RecipientKycStatus>> {
+    let Some(customer) = payout_data.customer_details.to_owned() else {
+        return Ok(None);
+    };
+
+    // Verification of the recipient is final, the connector is not called again
+    if customer
+        .recipient_kyc_status
+        .is_some_and(|recipient_kyc_status| recipient_kyc_status.is_verified())
+    {
+        return Ok(customer.recipient_kyc_status);
+    }
+
+    // 1. Form Router data
+    let payout_router_data: types::PayoutsRouterData<api::PoRecipientStatus> =
+        core_utils::construct_payout_router_data(connector_data, merchant_account, payout_data)
+            .await?;
+    let request = payout_router_data.request.clone();
+    let router_data: types::PayoutRecipientStatusRouterData =
+        payment_helpers::router_data_type_conversion(
+            payout_router_data,
+            request,
+            Ok(types::PayoutRecipientStatusResponseData::default()),
+        );
+
+    // 2. Fetch connector integration details
+    let connector_integration: services::BoxedPayoutConnectorIntegrationInterface<
+        api::PoRecipientStatus,
+        types::PayoutsData,
+        types::PayoutRecipientStatusResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // 3. Call connector service
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payout_failed_response()?;
+
+    // 4. Process data returned by the connector
+    let recipient_status = router_data_resp.response.map_err(|err| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector_data.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })
+    })?;
+    let recipient_kyc_status = recipient_status.kyc_status;
+    if customer.recipient_kyc_status == Some(recipient_kyc_status) {
+        return Ok(Some(recipient_kyc_status));
+    }
+
+    logger::info!(
+        previous_status = ?customer.recipient_kyc_status,
+        ?recipient_kyc_status,
+        status_reason = ?recipient_status.status_reason,
+        "KYC status of the payout recipient was updated"
+    );
+    let db = &*state.store;
+    let updated_customer = storage::CustomerUpdate::RecipientKycStatusUpdate {
+        recipient_kyc_status,
+    };
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    {
+        let customer_id = customer.customer_id.to_owned();
+        payout_data.customer_details = Some(
+            db.update_customer_by_customer_id_merchant_id(
+                &state.into(),
+                customer_id,
+                merchant_account.get_id().to_owned(),
+                customer,
+                updated_customer,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating customers in db")?,
+        );
+    }
+
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
+    {
+        let global_id = "temp_id".to_string();
+        payout_data.customer_details = Some(
+            db.update_customer_by_global_id(
+                &state.into(),
+                global_id,
+                customer,
+                merchant_account.get_id(),
+                updated_customer,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating customers in db")?,
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    {
+        let router_response = response_handler(state, merchant_account, payout_data).await?;
+        if let services::ApplicationResponse::Json(payout_response) = router_response {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account.clone(),
+                payout_data.business_profile.clone(),
+                key_store,
+                storage_enums::EventType::PayoutRecipientKycUpdated,
+                storage_enums::EventClass::Payouts,
+                payout_data.payouts.payout_id.clone(),
+                storage_enums::EventObjectType::PayoutDetails,
+                api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+                Some(payout_data.payout_attempt.created_at),
+            ))
+            .await?;
+        }
+    }
+
+    Ok(Some(recipient_kyc_status))
+}
+
 pub async fn cancel_payout(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -2255,6 +2808,23 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            fees::update_payout_fee(
+                state,
+                merchant_account,
+                payout_data,
+                payout_response_data.connector_fee,
+            )
+            .await?;
+            #[cfg(feature = "v1")]
+            ledger::record_payout(state, payout_data).await;
+            if !helpers::is_payout_err_state(status) {
+                usage_metering::record_billable_operation(
+                    state,
+                    merchant_account.get_id(),
+                    usage_metering::BillableOperation::PayoutProcessed,
+                )
+                .await;
+            }
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -2352,6 +2922,12 @@ pub async fn response_handler(
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let payouts = payout_data.payouts.to_owned();
+    let attempts = payout_data.attempts.to_owned().map(|attempts| {
+        attempts
+            .into_iter()
+            .map(|attempt| api::PayoutAttemptResponse::foreign_from((&payouts, attempt)))
+            .collect()
+    });
     let payout_link = payout_data.payout_link.to_owned();
     let billing_address = payout_data.billing_address.to_owned();
     let customer_details = payout_data.customer_details.to_owned();
@@ -2374,6 +2950,13 @@ pub async fn response_handler(
     let payout_method_data =
         additional_payout_method_data.map(payouts::PayoutMethodDataResponse::from);
 
+    let status_history = payout_data.status_history.to_owned().map(|status_history| {
+        status_history
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    });
+
     let response = api::PayoutCreateResponse {
         payout_id: payouts.payout_id.to_owned(),
         merchant_id: merchant_account.get_id().to_owned(),
@@ -2394,6 +2977,13 @@ pub async fn response_handler(
         customer: customer_details
             .as_ref()
             .map(payment_api_types::CustomerDetailsResponse::foreign_from),
+        recipient_kyc_status: customer_details
+            .as_ref()
+            .and_then(|c| c.recipient_kyc_status),
+        account_name_match_result: payout_attempt.account_name_match_result,
+        fee: payout_attempt.fee,
+        net_amount: payout_attempt.net_amount,
+        fee_source: payout_attempt.fee_source,
         client_secret: payouts.client_secret.to_owned(),
         return_url: payouts.return_url.to_owned(),
         business_country: payout_attempt.business_country,
@@ -2410,7 +3000,8 @@ pub async fn response_handler(
         created: Some(payouts.created_at),
         connector_transaction_id: payout_attempt.connector_payout_id,
         priority: payouts.priority,
-        attempts: None,
+        attempts,
+        status_history,
         unified_code: payout_attempt.unified_code,
         unified_message: translated_unified_message,
         payout_link: payout_link
@@ -2465,6 +3056,10 @@ pub async fn payout_create_db_entries(
     let business_profile =
         validate_and_get_business_profile(state, key_store, profile_id, merchant_id).await?;
 
+    if let Some(currency) = req.currency {
+        payment_helpers::validate_currency_for_profile(currency, &business_profile)?;
+    }
+
     let payout_link = match req.payout_link {
         Some(true) => Some(
             create_payout_link(
@@ -2483,6 +3078,69 @@ pub async fn payout_create_db_entries(
         _ => None,
     };
 
+    if let Some(payout_link) = &payout_link {
+        payout_link::schedule_payout_link_expiry_sweep(state, merchant_id, payout_link.expiry)
+            .await?;
+    }
+
+    #[cfg(feature = "v1")]
+    if let Some(payout_link) = &payout_link {
+        let email_future = email_templates::send_payout_link(
+            state.clone(),
+            merchant_account.clone(),
+            profile_id.clone(),
+            payout_link.clone(),
+            customer.cloned(),
+            locale.to_string(),
+        );
+        tokio::spawn(
+            async move {
+                email_future
+                    .await
+                    .map_err(|error| logger::error!(payout_link_email_error=?error))
+                    .ok();
+            }
+            .in_current_span(),
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    if let (Some(payout_link), Some(link_delivery_request)) = (
+        &payout_link,
+        req.payout_link_config
+            .as_ref()
+            .and_then(|payout_link_config| payout_link_config.link_delivery.clone()),
+    ) {
+        let customer_phone = customer.and_then(|customer| {
+            customer
+                .phone
+                .clone()
+                .map(|phone| link_delivery::CustomerPhone {
+                    phone: phone.into_inner(),
+                    phone_country_code: customer.phone_country_code.clone(),
+                })
+        });
+
+        link_delivery::deliver_link(
+            state,
+            link_delivery::LinkDeliveryDetails {
+                merchant_id: merchant_id.clone(),
+                profile_id: Some(profile_id.clone()),
+                link_id: payout_link.link_id.clone(),
+                link_type: storage::enums::DeliveredLinkType::PayoutLink,
+                link_url: payout_link.url.peek().to_owned(),
+                merchant_name: merchant_account
+                    .merchant_name
+                    .clone()
+                    .map(|name| name.into_inner().peek().to_owned())
+                    .unwrap_or_else(|| merchant_id.get_string_repr().to_owned()),
+            },
+            link_delivery_request,
+            customer_phone,
+        )
+        .await?;
+    }
+
     // We have to do this because the function that is being used to create / get address is from payments
     // which expects a payment_id
     let payout_id_as_payment_id_type =
@@ -2633,6 +3291,8 @@ pub async fn payout_create_db_entries(
         profile_id: profile_id.to_owned(),
         payout_link,
         current_locale: locale.to_string(),
+        attempts: None,
+        status_history: None,
     })
 }
 
@@ -2834,6 +3494,8 @@ pub async fn make_payout_data(
         profile_id,
         payout_link,
         current_locale: locale.to_string(),
+        attempts: None,
+        status_history: None,
     })
 }
 
@@ -2855,6 +3517,75 @@ pub async fn add_external_account_addition_task(
         payout_id: payout_data.payouts.payout_id.to_owned(),
         force_sync: None,
         merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+        expand: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub async fn add_recipient_kyc_status_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutRecipientKycStatusWorkflow;
+    let task = "PAYOUT_RECIPIENT_KYC_STATUS";
+    let tag = ["PAYOUTS", "RECIPIENT", "KYC"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_data.payout_attempt.payout_attempt_id,
+        &payout_data.payout_attempt.merchant_id,
+    );
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+        expand: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub async fn add_payout_fulfillment_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutFulfillmentWorkflow;
+    let task = "PAYOUT_FULFILLMENT";
+    let tag = ["PAYOUTS", "FULFILLMENT"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_data.payout_attempt.payout_attempt_id,
+        &payout_data.payout_attempt.merchant_id,
+    );
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+        expand: None,
     };
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
@@ -0,0 +1,213 @@
+use api_models::enums as api_enums;
+use common_utils::{ext_traits::BytesExt, fp_utils, request::RequestContent};
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::PayoutData;
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    headers,
+    routes::SessionState,
+    services,
+    types::{api::payouts, domain, storage},
+    utils::OptionExt,
+};
+
+#[derive(Debug, serde::Serialize)]
+struct AccountNameVerificationRequest {
+    name: Secret<String>,
+    #[serde(flatten)]
+    account: BankAccountDetails,
+}
+
+/// The details of the UK and EU bank accounts whose name can be verified
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "account_type", rename_all = "snake_case")]
+enum BankAccountDetails {
+    SortCode {
+        sort_code: Secret<String>,
+        account_number: Secret<String>,
+    },
+    Iban {
+        iban: Secret<String>,
+        bic: Option<Secret<String>>,
+    },
+}
+
+impl BankAccountDetails {
+    fn from_payout_method_data(payout_method_data: &payouts::PayoutMethodData) -> Option<Self> {
+        match payout_method_data {
+            payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(bacs)) => {
+                Some(Self::SortCode {
+                    sort_code: bacs.bank_sort_code.clone(),
+                    account_number: bacs.bank_account_number.clone(),
+                })
+            }
+            payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(sepa)) => Some(Self::Iban {
+                iban: sepa.iban.clone(),
+                bic: sepa.bic.clone(),
+            }),
+            payouts::PayoutMethodData::Bank(
+                payouts::BankPayout::Ach(_) | payouts::BankPayout::Pix(_),
+            )
+            | payouts::PayoutMethodData::Card(_)
+            | payouts::PayoutMethodData::Wallet(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AccountNameVerificationResponse {
+    match_result: api_enums::AccountNameMatchResult,
+}
+
+/// Verifies the name of the recipient against the name of the holder of the UK or EU bank account
+/// of the payout with the Confirmation of Payee service, if the profile of the payout requires it,
+/// and records the result of the match against the payout attempt. The name is verified only once
+/// for an attempt.
+#[instrument(skip_all)]
+pub async fn verify_account_name(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let Some(mismatch_action) = payout_data.business_profile.account_name_mismatch_action else {
+        return Ok(());
+    };
+    if payout_data
+        .payout_attempt
+        .account_name_match_result
+        .is_some()
+    {
+        return Ok(());
+    }
+    let Some(account) = payout_data
+        .payout_method_data
+        .as_ref()
+        .and_then(BankAccountDetails::from_payout_method_data)
+    else {
+        return Ok(());
+    };
+    let Some(confirmation_of_payee_service) = state.conf.confirmation_of_payee_service.as_ref()
+    else {
+        logger::warn!("Skipping the verification of the account name, as the confirmation of payee service is not configured");
+        return Ok(());
+    };
+
+    let name = get_recipient_name(payout_data).get_required_value("billing.address.first_name")?;
+    let match_result = match call_confirmation_of_payee_service(
+        state,
+        confirmation_of_payee_service.get_inner(),
+        AccountNameVerificationRequest { name, account },
+    )
+    .await
+    {
+        Ok(match_result) => match_result,
+        // The payouts are not held up by the service when the result is only recorded
+        Err(error) if mismatch_action == api_enums::AccountNameMismatchAction::Record => {
+            logger::error!(?error, "Failed to verify the account name of the payout");
+            return Ok(());
+        }
+        Err(error) => return Err(error),
+    };
+
+    logger::info!(?match_result, "Verified the account name of the payout");
+    payout_data.payout_attempt = state
+        .store
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            storage::PayoutAttemptUpdate::AccountNameMatchResultUpdate {
+                account_name_match_result: match_result,
+            },
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating account name match result in payout_attempt")?;
+
+    Ok(())
+}
+
+/// Ensures that the payout is not fulfilled if the name of the recipient does not match the name
+/// of the holder of the bank account, as per the profile of the payout
+pub fn validate_account_name_match(
+    payout_data: &PayoutData,
+) -> Result<(), errors::ApiErrorResponse> {
+    let (Some(mismatch_action), Some(match_result)) = (
+        payout_data.business_profile.account_name_mismatch_action,
+        payout_data.payout_attempt.account_name_match_result,
+    ) else {
+        return Ok(());
+    };
+
+    fp_utils::when(mismatch_action.is_blocked(match_result), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "You cannot fulfill this payout because the name of the recipient does not match the name of the holder of the bank account, the result of the match is {match_result}",
+            ),
+        })
+    })
+}
+
+/// The name of the recipient as in the billing address of the payout, or else as in the customer
+fn get_recipient_name(payout_data: &PayoutData) -> Option<Secret<String>> {
+    payout_data
+        .billing_address
+        .as_ref()
+        .map(hyperswitch_domain_models::address::Address::from)
+        .and_then(|billing_address| billing_address.address)
+        .and_then(|address_details| address_details.get_optional_full_name())
+        .or_else(|| {
+            payout_data
+                .customer_details
+                .as_ref()
+                .and_then(|customer| customer.name.clone())
+                .map(|name| name.into_inner())
+        })
+}
+
+async fn call_confirmation_of_payee_service(
+    state: &SessionState,
+    confirmation_of_payee_service: &settings::ConfirmationOfPayeeService,
+    payload: AccountNameVerificationRequest,
+) -> RouterResult<api_enums::AccountNameMatchResult> {
+    let mut request = services::Request::new(
+        services::Method::Post,
+        confirmation_of_payee_service
+            .verify_account_name_url
+            .as_str(),
+    );
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        confirmation_of_payee_service
+            .api_key
+            .peek()
+            .clone()
+            .into_masked(),
+    );
+    request.add_default_headers();
+    request.set_body(RequestContent::Json(Box::new(payload)));
+
+    let response = services::call_connector_api(state, request, "verify_account_name")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the confirmation of payee service")?
+        .map_err(|error_response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Confirmation of payee service responded with status code {}",
+                error_response.status_code
+            ))
+        })?;
+
+    let verification_response: AccountNameVerificationResponse = response
+        .response
+        .parse_struct("AccountNameVerificationResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the response of the confirmation of payee service")?;
+
+    Ok(verification_response.match_result)
+}
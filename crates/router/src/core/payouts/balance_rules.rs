@@ -0,0 +1,376 @@
+use api_models::ledger as ledger_api;
+use common_utils::{date_time, fp_utils, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::{helpers, PayoutData};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums as storage_enums},
+    utils,
+};
+
+/// The connector balance of the ledger of the profile in the currency, from the payments, refunds,
+/// payouts and chargebacks recorded in the ledger
+async fn get_connector_balance(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    currency: storage_enums::Currency,
+) -> RouterResult<MinorUnit> {
+    let net_debit = state
+        .store
+        .get_ledger_account_nets_by_profile_id(profile_id, date_time::now())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to aggregate ledger entries")?
+        .into_iter()
+        .find(|account_net| {
+            account_net.account == storage_enums::LedgerAccount::ConnectorBalance
+                && account_net.currency == currency
+        })
+        .map(|account_net| account_net.net_debit)
+        .unwrap_or_default();
+
+    // The connector balance is increased by debits
+    Ok(MinorUnit::new(net_debit))
+}
+
+/// The amount reserved by the other payouts of the profile in the currency, which are in progress
+/// and therefore not yet recorded in the ledger
+async fn get_reserved_amount(
+    state: &SessionState,
+    payout_data: &PayoutData,
+    currency: storage_enums::Currency,
+) -> RouterResult<MinorUnit> {
+    let status = vec![
+        storage_enums::PayoutStatus::RequiresCreation,
+        storage_enums::PayoutStatus::RequiresConfirmation,
+        storage_enums::PayoutStatus::RequiresPayoutMethodData,
+        storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+        storage_enums::PayoutStatus::Pending,
+        storage_enums::PayoutStatus::Initiated,
+        storage_enums::PayoutStatus::RequiresFulfillment,
+    ];
+    let reserved_amount = state
+        .store
+        .filter_payouts_by_profile_id_source_currency_status(
+            &payout_data.payouts.merchant_id,
+            &payout_data.profile_id,
+            currency,
+            status,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payouts in progress of the profile")?
+        .into_iter()
+        .filter(|payouts| payouts.payout_id != payout_data.payouts.payout_id)
+        .fold(MinorUnit::zero(), |reserved_amount, payouts| {
+            reserved_amount + payouts.amount
+        });
+
+    Ok(reserved_amount)
+}
+
+/// Ensures that the connector balance, less the amount reserved by the payouts in progress, covers
+/// both the amount of the payout and the amount held back for it
+fn validate_connector_balance(
+    connector_balance: MinorUnit,
+    reserved_amount: MinorUnit,
+    amount: MinorUnit,
+    holdback_amount: MinorUnit,
+    currency: storage_enums::Currency,
+) -> RouterResult<()> {
+    fp_utils::when(
+        connector_balance - reserved_amount - amount - holdback_amount < MinorUnit::zero(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot fulfill this payout because the connector balance of {connector_balance} {currency} of the profile, less the amount of {reserved_amount} {currency} reserved by the payouts in progress, does not cover the amount of the payout and the amount of {holdback_amount} {currency} held back for it",
+                ),
+            }))
+        },
+    )
+}
+
+/// Ensures that the payout is not fulfilled if the connector balance of the ledger of its profile,
+/// less the amounts reserved by the other payouts in progress, does not cover both the amount of
+/// the payout and the amount held back for it, as per the profile of the payout, so that refunds
+/// and chargebacks do not drive the balance negative. Payouts are funded in their source
+/// currency. The rules do not apply to a payout for which they have been overridden by an admin.
+#[instrument(skip_all)]
+pub async fn validate_payout_balance(
+    state: &SessionState,
+    payout_data: &PayoutData,
+) -> RouterResult<()> {
+    let Some(payout_balance_config) = payout_data.business_profile.payout_balance_config.as_ref()
+    else {
+        return Ok(());
+    };
+    if payout_data.payout_attempt.balance_rules_overridden == Some(true) {
+        logger::info!("Skipping the balance rules, as they are overridden for the payout");
+        return Ok(());
+    }
+
+    let amount = payout_data.payouts.amount;
+    let currency = payout_data.payouts.source_currency;
+    let holdback_amount = payout_balance_config.get_holdback_amount(amount);
+    let connector_balance = get_connector_balance(state, &payout_data.profile_id, currency).await?;
+    let reserved_amount = get_reserved_amount(state, payout_data, currency).await?;
+
+    validate_connector_balance(
+        connector_balance,
+        reserved_amount,
+        amount,
+        holdback_amount,
+        currency,
+    )
+}
+
+/// Allows an admin to have a payout fulfilled regardless of the balance rules of its profile, or
+/// revokes such an override. The payout is not fulfilled by the override, it has to be fulfilled
+/// by the merchant.
+#[instrument(skip_all)]
+pub async fn update_payout_balance_override(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: id_type::ProfileId,
+    payout_id: String,
+    balance_rules_overridden: bool,
+) -> RouterResponse<ledger_api::PayoutBalanceOverrideResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let payouts = db
+        .find_payout_by_merchant_id_payout_id(
+            merchant_id,
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+    fp_utils::when(payouts.profile_id != profile_id, || {
+        Err(report!(errors::ApiErrorResponse::PayoutNotFound))
+    })?;
+
+    let payout_attempt_id = utils::get_payout_attempt_id(&payout_id, payouts.attempt_count);
+    let payout_attempt = db
+        .find_payout_attempt_by_merchant_id_payout_attempt_id(
+            merchant_id,
+            &payout_attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+    fp_utils::when(
+        helpers::is_payout_terminal_state(payout_attempt.status),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The balance rules cannot be overridden for payout {} with status {}",
+                    payout_id, payout_attempt.status
+                ),
+            }))
+        },
+    )?;
+
+    let payout_attempt = db
+        .update_payout_attempt(
+            &payout_attempt,
+            storage::PayoutAttemptUpdate::BalanceRulesOverrideUpdate {
+                balance_rules_overridden,
+            },
+            &payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating balance rules override in payout_attempt")?;
+    logger::info!(
+        balance_rules_overridden,
+        "Updated the override of the balance rules for the payout"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_api::PayoutBalanceOverrideResponse {
+            payout_id,
+            profile_id,
+            status: payout_attempt.status,
+            balance_rules_overridden,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::{borrow::Cow, sync::Arc};
+
+    use diesel_models::business_profile::BusinessPayoutBalanceConfig;
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, routes};
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                || {},
+            )
+            .unwrap()
+    }
+
+    fn ledger_entry(
+        profile_id: &id_type::ProfileId,
+        source_id: &str,
+        account: storage_enums::LedgerAccount,
+        direction: storage_enums::LedgerEntryDirection,
+        amount: i64,
+        currency: storage_enums::Currency,
+    ) -> storage::LedgerEntryNew {
+        storage::LedgerEntryNew {
+            id: format!("ledger_entry_{source_id}_{account}"),
+            transaction_id: format!("ledger_txn_{source_id}"),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: profile_id.clone(),
+            account,
+            direction,
+            amount: MinorUnit::new(amount),
+            currency,
+            source_type: storage_enums::LedgerSourceType::Payment,
+            source_id: source_id.to_string(),
+            created_at: date_time::now() - time::Duration::seconds(1),
+        }
+    }
+
+    fn is_precondition_failed(result: RouterResult<()>) -> bool {
+        matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        )
+    }
+
+    #[test]
+    fn test_validate_connector_balance() {
+        let currency = storage_enums::Currency::USD;
+        let amount = MinorUnit::new(1000);
+        let holdback_amount = BusinessPayoutBalanceConfig {
+            holdback_percentage_in_basis_points: 1000,
+        }
+        .get_holdback_amount(amount);
+        assert_eq!(holdback_amount, MinorUnit::new(100));
+
+        // The connector balance exactly covers the amount of the payout and the holdback amount
+        assert!(validate_connector_balance(
+            MinorUnit::new(1100),
+            MinorUnit::zero(),
+            amount,
+            holdback_amount,
+            currency,
+        )
+        .is_ok());
+        assert!(is_precondition_failed(validate_connector_balance(
+            MinorUnit::new(1099),
+            MinorUnit::zero(),
+            amount,
+            holdback_amount,
+            currency,
+        )));
+    }
+
+    #[test]
+    fn test_validate_connector_balance_less_reserved_amount() {
+        let currency = storage_enums::Currency::USD;
+        let amount = MinorUnit::new(1000);
+
+        // The balance covers the payout on its own, but not alongside the payouts in progress
+        assert!(is_precondition_failed(validate_connector_balance(
+            MinorUnit::new(1500),
+            MinorUnit::new(600),
+            amount,
+            MinorUnit::zero(),
+            currency,
+        )));
+        assert!(validate_connector_balance(
+            MinorUnit::new(1600),
+            MinorUnit::new(600),
+            amount,
+            MinorUnit::zero(),
+            currency,
+        )
+        .is_ok());
+        // A negative connector balance never funds a payout
+        assert!(is_precondition_failed(validate_connector_balance(
+            MinorUnit::new(-100),
+            MinorUnit::zero(),
+            MinorUnit::new(1),
+            MinorUnit::zero(),
+            currency,
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_get_connector_balance() {
+        let state = get_session_state().await;
+        let profile_id = id_type::ProfileId::try_from(Cow::from("pro_balance_rules")).unwrap();
+        let other_profile_id = id_type::ProfileId::try_from(Cow::from("pro_other")).unwrap();
+        let usd = storage_enums::Currency::USD;
+        let eur = storage_enums::Currency::EUR;
+        let debit = storage_enums::LedgerEntryDirection::Debit;
+        let credit = storage_enums::LedgerEntryDirection::Credit;
+        let connector_balance = storage_enums::LedgerAccount::ConnectorBalance;
+        let payments = storage_enums::LedgerAccount::Payments;
+        let refunds = storage_enums::LedgerAccount::Refunds;
+
+        state
+            .store
+            .insert_ledger_entries(vec![
+                ledger_entry(&profile_id, "pay_1", connector_balance, debit, 1000, usd),
+                ledger_entry(&profile_id, "pay_1", payments, credit, 1000, usd),
+                ledger_entry(&profile_id, "ref_1", refunds, debit, 300, usd),
+                ledger_entry(&profile_id, "ref_1", connector_balance, credit, 300, usd),
+                ledger_entry(&profile_id, "pay_2", connector_balance, debit, 500, eur),
+                ledger_entry(&profile_id, "pay_2", payments, credit, 500, eur),
+                ledger_entry(
+                    &other_profile_id,
+                    "pay_3",
+                    connector_balance,
+                    debit,
+                    900,
+                    usd,
+                ),
+                ledger_entry(&other_profile_id, "pay_3", payments, credit, 900, usd),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            get_connector_balance(&state, &profile_id, usd)
+                .await
+                .unwrap(),
+            MinorUnit::new(700)
+        );
+        assert_eq!(
+            get_connector_balance(&state, &profile_id, eur)
+                .await
+                .unwrap(),
+            MinorUnit::new(500)
+        );
+        assert_eq!(
+            get_connector_balance(&state, &profile_id, storage_enums::Currency::GBP)
+                .await
+                .unwrap(),
+            MinorUnit::zero()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::PayoutData;
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+/// Records the fee of the payout attempt and the amount net of the fee. The fee reported by the
+/// connector takes precedence over any fee recorded earlier, otherwise the fee is estimated once
+/// from the fee schedules of the profile of the payout, if the attempt has not failed.
+#[instrument(skip_all)]
+pub async fn update_payout_fee(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    connector_fee: Option<MinorUnit>,
+) -> RouterResult<()> {
+    let payout_attempt = &payout_data.payout_attempt;
+    let (fee, fee_source) = match connector_fee {
+        Some(fee)
+            if payout_attempt.fee != Some(fee)
+                || payout_attempt.fee_source != Some(storage_enums::PayoutFeeSource::Connector) =>
+        {
+            (fee, storage_enums::PayoutFeeSource::Connector)
+        }
+        Some(_) => return Ok(()),
+        None => {
+            if payout_attempt.fee.is_some()
+                || !matches!(
+                    payout_attempt.status,
+                    storage_enums::PayoutStatus::Success
+                        | storage_enums::PayoutStatus::Pending
+                        | storage_enums::PayoutStatus::Initiated
+                )
+            {
+                return Ok(());
+            }
+            let estimated_fee = payout_data
+                .business_profile
+                .payout_fee_config
+                .as_ref()
+                .zip(payout_attempt.merchant_connector_id.as_ref())
+                .and_then(|(payout_fee_config, merchant_connector_id)| {
+                    payout_fee_config.estimate_fee(
+                        merchant_connector_id,
+                        payout_data.payouts.amount,
+                        payout_data.payouts.destination_currency,
+                    )
+                });
+            let Some(fee) = estimated_fee else {
+                return Ok(());
+            };
+            (fee, storage_enums::PayoutFeeSource::Estimated)
+        }
+    };
+
+    logger::info!(?fee, ?fee_source, "Recording the fee of the payout");
+    let net_amount = payout_data.payouts.amount - fee;
+    payout_data.payout_attempt = state
+        .store
+        .update_payout_attempt(
+            payout_attempt,
+            storage::PayoutAttemptUpdate::FeeUpdate {
+                fee,
+                net_amount,
+                fee_source,
+            },
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating fee in payout_attempt")?;
+
+    Ok(())
+}
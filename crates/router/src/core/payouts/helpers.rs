@@ -16,6 +16,7 @@ use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
 use masking::{ExposeInterface, PeekInterface, Secret, SwitchStrategy};
 use router_env::logger;
+use time::PrimitiveDateTime;
 
 use super::PayoutData;
 #[cfg(feature = "payouts")]
@@ -23,6 +24,7 @@ use crate::core::payments::route_connector_v1_for_payouts;
 use crate::{
     consts,
     core::{
+        connector_maintenance,
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::{
             cards,
@@ -742,6 +744,7 @@ pub(super) async fn get_or_create_customer_details(
                     default_payment_method_id: None,
                     updated_by: None,
                     version: hyperswitch_domain_models::consts::API_VERSION,
+                    recipient_kyc_status: None,
                 };
 
                 Ok(Some(
@@ -827,6 +830,13 @@ pub async fn decide_payout_connector(
             .attach_printable("failed eligibility analysis and fallback")?;
         }
 
+        let connectors = connector_maintenance::filter_connectors_under_maintenance(
+            state,
+            merchant_account.get_id(),
+            connectors,
+        )
+        .await;
+
         let first_connector_choice = connectors
             .first()
             .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -875,6 +885,13 @@ pub async fn decide_payout_connector(
             .attach_printable("failed eligibility analysis and fallback")?;
         }
 
+        let mut connectors = connector_maintenance::filter_connectors_under_maintenance(
+            state,
+            merchant_account.get_id(),
+            connectors,
+        )
+        .await;
+
         let first_connector_choice = connectors
             .first()
             .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -998,6 +1015,23 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+pub async fn config_should_check_payout_balance(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> bool {
+    let key = merchant_id.get_should_check_payout_balance_key();
+    let config = db
+        .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(error) => {
+            logger::error!(?error);
+            false
+        }
+    }
+}
+
 pub fn is_payout_initiated(status: api_enums::PayoutStatus) -> bool {
     !matches!(
         status,
@@ -1053,6 +1087,67 @@ pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+/// Payouts via connectors which report the KYC status of the recipient can be fulfilled only
+/// once the recipient has been verified by the connector
+pub fn is_recipient_kyc_verified(connector: &api::ConnectorData, payout_data: &PayoutData) -> bool {
+    !connector.connector_name.supports_payout_recipient_status()
+        || payout_data
+            .customer_details
+            .as_ref()
+            .and_then(|customer| customer.recipient_kyc_status)
+            .is_some_and(|recipient_kyc_status| recipient_kyc_status.is_verified())
+}
+
+pub fn get_payout_method_type(
+    payout_method_data: &api::PayoutMethodData,
+) -> Option<api_enums::PaymentMethodType> {
+    match payout_method_data {
+        api::PayoutMethodData::Card(_) => None,
+        api::PayoutMethodData::Bank(bank) => {
+            Some(api_enums::PaymentMethodType::foreign_from(bank.to_owned()))
+        }
+        api::PayoutMethodData::Wallet(wallet) => Some(api_enums::PaymentMethodType::foreign_from(
+            wallet.to_owned(),
+        )),
+    }
+}
+
+/// Returns the time at which the payout is to be auto fulfilled, if it is outside the fulfillment
+/// window of the profile which applies to the payout
+pub fn get_deferred_fulfillment_time(payout_data: &PayoutData) -> Option<PrimitiveDateTime> {
+    let payout_fulfillment_config = payout_data
+        .business_profile
+        .payout_fulfillment_config
+        .as_ref()?;
+    let payout_method_type = payout_data
+        .payout_method_data
+        .as_ref()
+        .and_then(get_payout_method_type);
+    let now = common_utils::date_time::now();
+    payout_fulfillment_config
+        .get_window(payout_data.payouts.payout_type, payout_method_type)?
+        .get_next_open_time(now)
+        .filter(|fulfillment_time| *fulfillment_time > now)
+}
+
+pub(crate) fn validate_recipient_kyc_status(
+    connector: &api::ConnectorData,
+    payout_data: &PayoutData,
+) -> Result<(), errors::ApiErrorResponse> {
+    fp_utils::when(!is_recipient_kyc_verified(connector, payout_data), || {
+        let recipient_kyc_status = payout_data
+            .customer_details
+            .as_ref()
+            .and_then(|customer| customer.recipient_kyc_status)
+            .unwrap_or_default();
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "You cannot fulfill this payout because the KYC status of the recipient is {recipient_kyc_status}",
+            ),
+        })
+    })
+}
+
 pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
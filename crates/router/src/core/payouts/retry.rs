@@ -1,6 +1,7 @@
 use std::{cmp::Ordering, str::FromStr, vec::IntoIter};
 
 use common_enums::PayoutRetryType;
+use diesel_models::business_profile::BusinessPayoutRetryConfig;
 use error_stack::{report, ResultExt};
 use router_env::{
     logger,
@@ -30,6 +31,8 @@ pub async fn do_gsm_multiple_connector_actions(
     key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<()> {
     let mut retries = None;
+    let mut retries_made = 0;
+    let payout_retry_config = payout_data.business_profile.payout_retry_config.clone();
 
     metrics::AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
@@ -37,13 +40,23 @@ pub async fn do_gsm_multiple_connector_actions(
 
     loop {
         let gsm = get_gsm(state, &connector, payout_data).await?;
+        let error_category = gsm.as_ref().and_then(|gsm| gsm.error_category);
 
         match get_gsm_decision(gsm) {
             api_models::gsm::GsmDecision::Retry => {
+                if !is_error_category_retryable(payout_retry_config.as_ref(), error_category) {
+                    logger::info!(
+                        ?error_category,
+                        "error category is not retryable for auto_retry payout"
+                    );
+                    break;
+                }
+
                 retries = get_retries(
                     state,
                     retries,
                     merchant_account.get_id(),
+                    payout_retry_config.as_ref(),
                     PayoutRetryType::MultiConnector,
                 )
                 .await;
@@ -62,6 +75,8 @@ pub async fn do_gsm_multiple_connector_actions(
 
                 connector = super::get_next_connector(&mut connectors)?;
 
+                wait_for_backoff(payout_retry_config.as_ref(), retries_made).await;
+
                 Box::pin(do_retry(
                     &state.clone(),
                     connector.to_owned(),
@@ -72,6 +87,7 @@ pub async fn do_gsm_multiple_connector_actions(
                 .await?;
 
                 retries = retries.map(|i| i - 1);
+                retries_made += 1;
             }
             api_models::gsm::GsmDecision::Requeue => {
                 Err(report!(errors::ApiErrorResponse::NotImplemented {
@@ -96,6 +112,8 @@ pub async fn do_gsm_single_connector_actions(
     key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<()> {
     let mut retries = None;
+    let mut retries_made = 0;
+    let payout_retry_config = payout_data.business_profile.payout_retry_config.clone();
 
     metrics::AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
@@ -109,13 +127,23 @@ pub async fn do_gsm_single_connector_actions(
             break;
         }
         previous_gsm.clone_from(&gsm);
+        let error_category = gsm.as_ref().and_then(|gsm| gsm.error_category);
 
         match get_gsm_decision(gsm) {
             api_models::gsm::GsmDecision::Retry => {
+                if !is_error_category_retryable(payout_retry_config.as_ref(), error_category) {
+                    logger::info!(
+                        ?error_category,
+                        "error category is not retryable for auto_retry payout"
+                    );
+                    break;
+                }
+
                 retries = get_retries(
                     state,
                     retries,
                     merchant_account.get_id(),
+                    payout_retry_config.as_ref(),
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -126,6 +154,8 @@ pub async fn do_gsm_single_connector_actions(
                     break;
                 }
 
+                wait_for_backoff(payout_retry_config.as_ref(), retries_made).await;
+
                 Box::pin(do_retry(
                     &state.clone(),
                     original_connector_data.to_owned(),
@@ -136,6 +166,7 @@ pub async fn do_gsm_single_connector_actions(
                 .await?;
 
                 retries = retries.map(|i| i - 1);
+                retries_made += 1;
             }
             api_models::gsm::GsmDecision::Requeue => {
                 Err(report!(errors::ApiErrorResponse::NotImplemented {
@@ -155,11 +186,16 @@ pub async fn get_retries(
     state: &app::SessionState,
     retries: Option<i32>,
     merchant_id: &common_utils::id_type::MerchantId,
+    payout_retry_config: Option<&BusinessPayoutRetryConfig>,
     retry_type: PayoutRetryType,
 ) -> Option<i32> {
-    match retries {
-        Some(retries) => Some(retries),
-        None => {
+    match (retries, payout_retry_config) {
+        (Some(retries), _) => Some(retries),
+        // The retry policy of the profile takes precedence over the configs of the merchant
+        (None, Some(payout_retry_config)) => {
+            Some(i32::from(payout_retry_config.get_max_retries(retry_type)))
+        }
+        (None, None) => {
             let key = merchant_id.get_max_auto_single_connector_payout_retries_enabled(retry_type);
             let db = &*state.store;
             db.find_config_by_key(key.as_str())
@@ -181,6 +217,26 @@ pub async fn get_retries(
     }
 }
 
+fn is_error_category_retryable(
+    payout_retry_config: Option<&BusinessPayoutRetryConfig>,
+    error_category: Option<common_enums::ErrorCategory>,
+) -> bool {
+    payout_retry_config.map_or(true, |payout_retry_config| {
+        payout_retry_config.is_error_category_retryable(error_category)
+    })
+}
+
+async fn wait_for_backoff(
+    payout_retry_config: Option<&BusinessPayoutRetryConfig>,
+    retries_made: u32,
+) {
+    if let Some(backoff_duration) = payout_retry_config
+        .and_then(|payout_retry_config| payout_retry_config.get_backoff_duration(retries_made))
+    {
+        tokio::time::sleep(backoff_duration).await;
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn get_gsm(
     state: &app::SessionState,
@@ -323,8 +379,14 @@ pub async fn modify_trackers(
 pub async fn config_should_call_gsm_payout(
     db: &dyn StorageInterface,
     merchant_id: &common_utils::id_type::MerchantId,
+    payout_retry_config: Option<&BusinessPayoutRetryConfig>,
     retry_type: PayoutRetryType,
 ) -> bool {
+    // The retry policy of the profile takes precedence over the configs of the merchant
+    if let Some(payout_retry_config) = payout_retry_config {
+        return payout_retry_config.get_max_retries(retry_type) > 0;
+    }
+
     let key = merchant_id.get_should_call_gsm_payout_key(retry_type);
     let config = db
         .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
@@ -9,12 +9,41 @@ use common_utils::link_utils::EnabledPaymentMethod;
 ))]
 use crate::types::transformers::ForeignInto;
 #[cfg(feature = "olap")]
-use crate::types::{api::payments, domain, storage};
+use crate::types::{api::payments, domain};
 use crate::{
     settings::PayoutRequiredFields,
-    types::{api, transformers::ForeignFrom},
+    types::{api, storage, transformers::ForeignFrom},
 };
 
+impl ForeignFrom<(&storage::Payouts, storage::PayoutAttempt)> for api::PayoutAttemptResponse {
+    fn foreign_from(item: (&storage::Payouts, storage::PayoutAttempt)) -> Self {
+        let (payout, payout_attempt) = item;
+        Self {
+            attempt_id: payout_attempt.payout_attempt_id,
+            status: payout_attempt.status,
+            amount: payout.amount,
+            currency: Some(payout.destination_currency),
+            connector: payout_attempt.connector,
+            error_code: payout_attempt.error_code,
+            error_message: payout_attempt.error_message,
+            payment_method: payout.payout_type,
+            payout_method_type: None,
+            connector_transaction_id: payout_attempt.connector_payout_id,
+            cancellation_reason: None,
+            merchant_connector_id: payout_attempt.merchant_connector_id,
+            routing_info: payout_attempt.routing_info,
+            created_at: Some(payout_attempt.created_at),
+            last_modified_at: Some(payout_attempt.last_modified_at),
+            account_name_match_result: payout_attempt.account_name_match_result,
+            fee: payout_attempt.fee,
+            net_amount: payout_attempt.net_amount,
+            fee_source: payout_attempt.fee_source,
+            unified_code: payout_attempt.unified_code,
+            unified_message: payout_attempt.unified_message,
+        }
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "customer_v2", feature = "olap"))]
 impl
     ForeignFrom<(
@@ -59,19 +88,9 @@ impl
     ) -> Self {
         let (payout, payout_attempt, customer, address) = item;
         let attempt = api::PayoutAttemptResponse {
-            attempt_id: payout_attempt.payout_attempt_id,
-            status: payout_attempt.status,
-            amount: payout.amount,
-            currency: Some(payout.destination_currency),
-            connector: payout_attempt.connector.clone(),
-            error_code: payout_attempt.error_code.clone(),
-            error_message: payout_attempt.error_message.clone(),
-            payment_method: payout.payout_type,
-            payout_method_type: None,
-            connector_transaction_id: payout_attempt.connector_payout_id,
-            cancellation_reason: None,
             unified_code: None,
             unified_message: None,
+            ..api::PayoutAttemptResponse::foreign_from((&payout, payout_attempt.clone()))
         };
         Self {
             payout_id: payout.payout_id,
@@ -84,6 +103,13 @@ impl
             auto_fulfill: payout.auto_fulfill,
             customer_id: customer.as_ref().map(|cust| cust.customer_id.clone()),
             customer: customer.as_ref().map(|cust| cust.foreign_into()),
+            recipient_kyc_status: customer
+                .as_ref()
+                .and_then(|customer| customer.recipient_kyc_status),
+            account_name_match_result: payout_attempt.account_name_match_result,
+            fee: payout_attempt.fee,
+            net_amount: payout_attempt.net_amount,
+            fee_source: payout_attempt.fee_source,
             return_url: payout.return_url,
             business_country: payout_attempt.business_country,
             business_label: payout_attempt.business_label,
@@ -105,6 +131,7 @@ impl
             unified_code: attempt.unified_code.clone(),
             unified_message: attempt.unified_message.clone(),
             attempts: Some(vec![attempt]),
+            status_history: None,
             email: customer
                 .as_ref()
                 .and_then(|customer| customer.email.clone()),
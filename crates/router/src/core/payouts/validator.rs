@@ -3,10 +3,11 @@ use std::collections::HashSet;
 use actix_web::http::header;
 #[cfg(feature = "olap")]
 use common_utils::errors::CustomResult;
-use common_utils::validation::validate_domain_against_allowed_domains;
+use common_utils::validation::{bank_account, validate_domain_against_allowed_domains};
 use diesel_models::generic_link::PayoutLink;
 use error_stack::{report, ResultExt};
 pub use hyperswitch_domain_models::errors::StorageError;
+use masking::PeekInterface;
 use router_env::{instrument, tracing, which as router_env_which, Env};
 use url::Url;
 
@@ -85,6 +86,10 @@ pub async fn validate_create_request(
         }
     };
 
+    if let Some(payout_method_data) = req.payout_method_data.as_ref() {
+        validate_payout_method_data(payout_method_data)?;
+    }
+
     // Merchant ID
     let predicate = req.merchant_id.as_ref().map(|mid| mid != merchant_id);
     utils::when(predicate.unwrap_or(false), || {
@@ -97,7 +102,8 @@ pub async fn validate_create_request(
 
     // Payout ID
     let db: &dyn StorageInterface = &*state.store;
-    let payout_id = core_utils::get_or_generate_uuid("payout_id", req.payout_id.as_ref())?;
+    let payout_id =
+        core_utils::get_or_generate_payout_id(merchant_account, req.payout_id.as_ref())?;
     match validate_uniqueness_of_payout_id_against_merchant_id(
         db,
         &payout_id,
@@ -203,6 +209,72 @@ pub fn validate_payout_link_request(
     Ok(())
 }
 
+/// Validates that the recipient details required for disbursing to a wallet are present
+pub fn validate_payout_method_data(
+    payout_method_data: &payouts::PayoutMethodData,
+) -> Result<(), errors::ApiErrorResponse> {
+    match payout_method_data {
+        payouts::PayoutMethodData::Wallet(payouts::WalletPayout::Paypal(paypal)) => utils::when(
+            paypal.email.is_none()
+                && paypal.telephone_number.is_none()
+                && paypal.paypal_id.is_none(),
+            || {
+                Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "payout_method_data.wallet.paypal.email",
+                })
+            },
+        ),
+        payouts::PayoutMethodData::Wallet(payouts::WalletPayout::Venmo(venmo)) => {
+            utils::when(venmo.telephone_number.is_none(), || {
+                Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "payout_method_data.wallet.venmo.telephone_number",
+                })
+            })
+        }
+        payouts::PayoutMethodData::Bank(bank) => {
+            validate_bank_details(bank, "payout_method_data.bank")
+        }
+        payouts::PayoutMethodData::Card(_) => Ok(()),
+    }
+}
+
+/// Validates the account details of the bank, returning the error against the field of the bank
+/// details which failed the validation
+pub fn validate_bank_details(
+    bank: &payouts::BankPayout,
+    field_prefix: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    let result = match bank {
+        payouts::BankPayout::Ach(ach) => bank_account::validate_aba_routing_number(
+            ach.bank_routing_number.peek(),
+        )
+        .and_then(|()| bank_account::validate_us_account_number(ach.bank_account_number.peek())),
+        payouts::BankPayout::Bacs(bacs) => bank_account::validate_sort_code_and_account_number(
+            bacs.bank_sort_code.peek(),
+            bacs.bank_account_number.peek(),
+        ),
+        payouts::BankPayout::Sepa(sepa) => bank_account::validate_iban(sepa.iban.peek()),
+        payouts::BankPayout::Pix(_) => Ok(()),
+    };
+
+    result.map_err(|error| {
+        let field_name = match error.field {
+            bank_account::BankAccountField::AccountNumber => "bank_account_number",
+            bank_account::BankAccountField::RoutingNumber => "bank_routing_number",
+            bank_account::BankAccountField::SortCode => "bank_sort_code",
+            bank_account::BankAccountField::Iban => "iban",
+            bank_account::BankAccountField::Clabe => "clabe",
+            bank_account::BankAccountField::Ifsc => "ifsc",
+        };
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Invalid value provided for `{field_prefix}.{field_name}`: {}",
+                error.reason
+            ),
+        }
+    })
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payout_list_request(
     req: &payouts::PayoutListConstraints,
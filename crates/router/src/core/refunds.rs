@@ -1,3 +1,5 @@
+#[cfg(feature = "payouts")]
+pub mod payout_fallback;
 pub mod transformers;
 pub mod validator;
 
@@ -24,6 +26,7 @@ use crate::{
     consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        ledger,
         payments::{self, access_token, types::PaymentCharges},
         utils as core_utils,
     },
@@ -82,12 +85,36 @@ pub async fn refund_create_core(
         },
     )?;
 
+    let refund_line_items = match req.line_items.as_deref() {
+        Some(line_items) => Some(
+            get_refund_line_items(&state, &merchant_account, &payment_intent, line_items).await?,
+        ),
+        None => None,
+    };
+
     // Amount is not passed in request refer from payment intent.
-    amount = req
-        .amount
-        .or(payment_intent.amount_captured)
-        .ok_or(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("amount captured is none in a successful payment")?;
+    amount = match refund_line_items.as_ref() {
+        Some(refund_line_items) => {
+            let line_items_amount = refund_line_items
+                .iter()
+                .map(|line_item| line_item.amount * line_item.quantity)
+                .sum::<MinorUnit>();
+            utils::when(
+                req.amount.is_some_and(|amount| amount != line_items_amount),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "amount does not match the amount of the line items".to_string(),
+                    }))
+                },
+            )?;
+            line_items_amount
+        }
+        None => req
+            .amount
+            .or(payment_intent.amount_captured)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("amount captured is none in a successful payment")?,
+    };
 
     //[#299]: Can we change the flow based on some workflow idea
     utils::when(amount <= MinorUnit::new(0), || {
@@ -128,11 +155,51 @@ pub async fn refund_create_core(
         amount,
         req,
         creds_identifier,
+        refund_line_items,
     ))
     .await
     .map(services::ApplicationResponse::Json)
 }
 
+/// Resolves the line items of the order details of the payment which are refunded, along with the
+/// amount per quantity of each line item
+#[instrument(skip_all)]
+async fn get_refund_line_items(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    line_items: &[refunds::RefundLineItemRequest],
+) -> RouterResult<Vec<storage::RefundLineItem>> {
+    let order_details = core_utils::get_order_details_of_line_items(payment_intent, "refunded")?;
+
+    let all_refunds = state
+        .store
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    validator::validate_refund_line_items(&order_details, &all_refunds, line_items).map_err(
+        |error| {
+            let message = match error.current_context() {
+                validator::RefundValidationError::LineItemNotFound => {
+                    "line_items contain a product which is not in the order_details of the payment"
+                }
+                validator::RefundValidationError::AmbiguousLineItem => {
+                    "product_id is required for line_items whose product_name is shared by several order_details"
+                }
+                _ => "quantity of line_items exceeds the quantity left to be refunded",
+            };
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: message.to_string(),
+            })
+        },
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub async fn trigger_refund_to_gateway(
@@ -145,6 +212,14 @@ pub async fn trigger_refund_to_gateway(
     creds_identifier: Option<String>,
     charges: Option<ChargeRefunds>,
 ) -> RouterResult<storage::Refund> {
+    let payment_attempt = &payments::operation_fallback::get_payment_attempt_for_refund(
+        state,
+        key_store,
+        payment_intent,
+        payment_attempt,
+        creds_identifier.as_deref(),
+    )
+    .await?;
     let routed_through = payment_attempt
         .connector
         .clone()
@@ -236,6 +311,7 @@ pub async fn trigger_refund_to_gateway(
                             updated_by: storage_scheme.to_string(),
                             connector_refund_id: None,
                             connector_refund_data: None,
+                            status_reason: None,
                         })
                     }
                     errors::ConnectorError::NotSupported { message, connector } => {
@@ -248,6 +324,7 @@ pub async fn trigger_refund_to_gateway(
                             updated_by: storage_scheme.to_string(),
                             connector_refund_id: None,
                             connector_refund_data: None,
+                            status_reason: None,
                         })
                     }
                     _ => None,
@@ -282,6 +359,11 @@ pub async fn trigger_refund_to_gateway(
         router_data
     };
 
+    let refund_details = router_data_res
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.get_refund_details())
+        .unwrap_or_default();
     let refund_update = match router_data_res.response {
         Err(err) => storage::RefundUpdate::ErrorUpdate {
             refund_status: Some(enums::RefundStatus::Failure),
@@ -290,6 +372,7 @@ pub async fn trigger_refund_to_gateway(
             updated_by: storage_scheme.to_string(),
             connector_refund_id: None,
             connector_refund_data: None,
+            status_reason: refund_details.status_reason,
         },
         Ok(response) => {
             // match on connector integrity checks
@@ -322,6 +405,7 @@ pub async fn trigger_refund_to_gateway(
                         updated_by: storage_scheme.to_string(),
                         connector_refund_id: refund_connector_transaction_id,
                         connector_refund_data,
+                        status_reason: None,
                     }
                 }
                 Ok(()) => {
@@ -342,6 +426,8 @@ pub async fn trigger_refund_to_gateway(
                         refund_arn: "".to_string(),
                         updated_by: storage_scheme.to_string(),
                         connector_refund_data,
+                        expected_settlement_date: refund_details.expected_settlement_date,
+                        status_reason: refund_details.status_reason,
                     }
                 }
             }
@@ -363,6 +449,21 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    add_overdue_refund_sync_task(&*state.store, &response)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to schedule the overdue refund sync task"))
+        .ok();
+    ledger::record_refund(state, &response).await;
+    #[cfg(feature = "payouts")]
+    let response = payout_fallback::trigger_refund_payout_fallback(
+        state,
+        response,
+        merchant_account,
+        key_store,
+        payment_attempt,
+        payment_intent,
+    )
+    .await;
     utils::trigger_refund_outgoing_webhook(
         state,
         merchant_account,
@@ -524,6 +625,14 @@ pub async fn sync_refund_with_gateway(
     creds_identifier: Option<String>,
     charges: Option<ChargeRefunds>,
 ) -> RouterResult<storage::Refund> {
+    let payment_attempt = &payments::operation_fallback::get_payment_attempt_for_refund(
+        state,
+        key_store,
+        payment_intent,
+        payment_attempt,
+        creds_identifier.as_deref(),
+    )
+    .await?;
     let connector_id = refund.connector.to_string();
     let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
@@ -600,6 +709,11 @@ pub async fn sync_refund_with_gateway(
         router_data
     };
 
+    let refund_details = router_data_res
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.get_refund_details())
+        .unwrap_or_default();
     let refund_update = match router_data_res.response {
         Err(error_message) => {
             let refund_status = match error_message.status_code {
@@ -614,6 +728,7 @@ pub async fn sync_refund_with_gateway(
                 updated_by: storage_scheme.to_string(),
                 connector_refund_id: None,
                 connector_refund_data: None,
+                status_reason: refund_details.status_reason,
             }
         }
         Ok(response) => match router_data_res.integrity_check.clone() {
@@ -646,6 +761,7 @@ pub async fn sync_refund_with_gateway(
                     updated_by: storage_scheme.to_string(),
                     connector_refund_id: refund_connector_transaction_id,
                     connector_refund_data,
+                    status_reason: None,
                 }
             }
             Ok(()) => {
@@ -659,6 +775,8 @@ pub async fn sync_refund_with_gateway(
                     refund_arn: "".to_string(),
                     updated_by: storage_scheme.to_string(),
                     connector_refund_data,
+                    expected_settlement_date: refund_details.expected_settlement_date,
+                    status_reason: refund_details.status_reason,
                 }
             }
         },
@@ -679,6 +797,21 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    add_overdue_refund_sync_task(&*state.store, &response)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to schedule the overdue refund sync task"))
+        .ok();
+    ledger::record_refund(state, &response).await;
+    #[cfg(feature = "payouts")]
+    let response = payout_fallback::trigger_refund_payout_fallback(
+        state,
+        response,
+        merchant_account,
+        key_store,
+        payment_attempt,
+        payment_intent,
+    )
+    .await;
     utils::trigger_refund_outgoing_webhook(
         state,
         merchant_account,
@@ -741,6 +874,7 @@ pub async fn validate_and_create_refund(
     refund_amount: MinorUnit,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
+    refund_line_items: Option<Vec<storage::RefundLineItem>>,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
 
@@ -782,7 +916,7 @@ pub async fn validate_and_create_refund(
 
     // If Refund Id not passed in request Generate one.
 
-    let refund_id = core_utils::get_or_generate_id("refund_id", &req.refund_id, "ref")?;
+    let refund_id = core_utils::get_or_generate_refund_id(merchant_account, &req.refund_id)?;
 
     let predicate = req
         .merchant_id
@@ -834,6 +968,52 @@ pub async fn validate_and_create_refund(
     )
     .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
+    let capture_id = if payment_attempt.multiple_capture_count > Some(0) {
+        let captures = db
+            .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                &payment_attempt.merchant_id,
+                &payment_attempt.payment_id,
+                &payment_attempt.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching captures of the payment attempt")?;
+
+        let capture = validator::validate_refund_against_captures(
+            &captures,
+            &all_refunds,
+            refund_amount.get_amount_as_i64(),
+            req.capture_id.as_deref(),
+        )
+        .map_err(|error| {
+            let api_error = match error.current_context() {
+                validator::RefundValidationError::CaptureNotFound => {
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: "capture_id does not belong to a charged capture of the payment"
+                            .to_string(),
+                    }
+                }
+                validator::RefundValidationError::RefundAmountExceedsCaptureAmount => {
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: "refund amount exceeds the amount refundable on any single capture, initiate separate refunds with a capture_id for each capture".to_string(),
+                    }
+                }
+                _ => errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount,
+            };
+            error.change_context(api_error)
+        })?;
+        Some(capture.capture_id.clone())
+    } else {
+        utils::when(req.capture_id.is_some(), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "capture_id can only be passed for payments captured in multiple parts"
+                    .to_string(),
+            }))
+        })?;
+        None
+    };
+
     validator::validate_maximum_refund_against_payment_attempt(
         &all_refunds,
         state.conf.refund.max_attempts,
@@ -876,6 +1056,8 @@ pub async fn validate_and_create_refund(
         organization_id: merchant_account.organization_id.clone(),
         connector_refund_data: None,
         connector_transaction_data,
+        capture_id,
+        line_items: refund_line_items.map(storage::RefundLineItems),
     };
 
     let refund = match db
@@ -1005,6 +1187,7 @@ pub async fn refund_retrieve_core_with_internal_reference_id(
         refund_id: refund.refund_id.clone(),
         force_sync,
         merchant_connector_details: None,
+        expand: None,
     };
 
     Box::pin(refund_retrieve_core(
@@ -1050,6 +1233,63 @@ pub async fn refund_retrieve_core_with_refund_id(
     .await
 }
 
+#[instrument(skip_all)]
+pub async fn refund_retrieve_with_expansions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: refunds::RefundsRetrieveRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    core_utils::validate_expandable_objects(
+        request.expand.as_deref(),
+        &[api_models::enums::ExpandableObject::StatusHistory],
+    )?;
+    let should_expand_status_history =
+        request.should_expand(api_models::enums::ExpandableObject::StatusHistory);
+    let refund = Box::pin(refund_retrieve_core_with_refund_id(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id,
+        key_store,
+        request,
+    ))
+    .await?;
+
+    let status_history = if should_expand_status_history {
+        let status_history = state
+            .store
+            .find_status_history_by_merchant_id_entity_id(
+                merchant_account.get_id(),
+                enums::StatusHistoryEntityType::Refund,
+                &refund.refund_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while fetching status history for refund_id: {}",
+                    refund.refund_id
+                )
+            })?;
+        Some(
+            status_history
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    } else {
+        None
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        refunds::RefundResponse {
+            status_history,
+            ..refund.foreign_into()
+        },
+    ))
+}
+
 #[instrument(skip_all)]
 #[cfg(feature = "olap")]
 pub async fn refund_manual_update(
@@ -1118,6 +1358,7 @@ pub async fn get_filters_for_refunds(
             state,
             merchant_account.get_id().to_owned(),
             profile_id_list,
+            None,
         )
         .await?
     {
@@ -1206,12 +1447,31 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
             charges: refund.charges,
+            fallback_payout_id: refund.fallback_payout_id,
+            capture_id: refund.capture_id,
+            expected_settlement_date: refund.expected_settlement_date,
+            status_reason: refund.status_reason,
+            line_items: refund.line_items.map(|line_items| {
+                line_items
+                    .0
+                    .into_iter()
+                    .map(|line_item| api::RefundLineItemResponse {
+                        product_name: line_item.product_name,
+                        product_id: line_item.product_id,
+                        quantity: line_item.quantity,
+                        amount: line_item.amount,
+                    })
+                    .collect()
+            }),
+            status_history: None,
         }
     }
 }
 
 // ********************************************** PROCESS TRACKER **********************************************
 
+const OVERDUE_REFUND_SYNC_TASK: &str = "SYNC_OVERDUE_REFUND";
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn schedule_refund_execution(
@@ -1379,6 +1639,91 @@ pub async fn sync_refund_with_gateway_workflow(
     Ok(())
 }
 
+/// Syncs a refund which is still pending at the settlement date expected by the connector. The
+/// sync is repeated as per the refund sync schedule of the connector until the refund reaches a
+/// terminal status, and follows the expected settlement date if the connector revises it.
+#[instrument(skip_all)]
+pub async fn sync_overdue_refund_workflow(
+    state: &SessionState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let key_manager_state = &state.into();
+    let refund_core =
+        serde_json::from_value::<storage::RefundCoreWorkflow>(refund_tracker.tracking_data.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "unable to convert into refund_core {:?}",
+                    refund_tracker.tracking_data
+                )
+            })?;
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &refund_core.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &refund_core.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let response = Box::pin(refund_retrieve_core_with_internal_reference_id(
+        state.clone(),
+        merchant_account,
+        None,
+        key_store,
+        refund_core.refund_internal_reference_id,
+        Some(true),
+    ))
+    .await?;
+
+    let revised_settlement_date =
+        response
+            .expected_settlement_date
+            .filter(|expected_settlement_date| {
+                *expected_settlement_date > common_utils::date_time::now()
+            });
+    match (response.refund_status, revised_settlement_date) {
+        (enums::RefundStatus::Pending, Some(expected_settlement_date)) => {
+            state
+                .store
+                .as_scheduler()
+                .reset_process(refund_tracker.clone(), expected_settlement_date)
+                .await?
+        }
+        (enums::RefundStatus::Pending, None) => {
+            _ = payment_sync::retry_sync_task(
+                &*state.store,
+                response.connector,
+                response.merchant_id,
+                refund_tracker.to_owned(),
+            )
+            .await?;
+        }
+        _ => {
+            state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    refund_tracker.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?
+        }
+    }
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn start_refund_workflow(
     state: &SessionState,
@@ -1391,6 +1736,9 @@ pub async fn start_refund_workflow(
         Some("SYNC_REFUND") => {
             Box::pin(sync_refund_with_gateway_workflow(state, refund_tracker)).await
         }
+        Some(OVERDUE_REFUND_SYNC_TASK) => {
+            Box::pin(sync_overdue_refund_workflow(state, refund_tracker)).await
+        }
         _ => Err(errors::ProcessTrackerError::JobNotFound),
     }
 }
@@ -1595,6 +1943,64 @@ pub async fn add_refund_sync_task(
     Ok(response)
 }
 
+/// Schedules a sync of a pending refund at the settlement date expected by the connector, if the
+/// sync has not been scheduled already
+#[instrument(skip_all)]
+pub async fn add_overdue_refund_sync_task(
+    db: &dyn db::StorageInterface,
+    refund: &storage::Refund,
+) -> RouterResult<()> {
+    let Some(expected_settlement_date) = refund
+        .expected_settlement_date
+        .filter(|_| refund.refund_status == enums::RefundStatus::Pending)
+    else {
+        return Ok(());
+    };
+
+    let runner = storage::ProcessTrackerRunner::RefundWorkflowRouter;
+    let process_tracker_id = format!(
+        "{runner}_{OVERDUE_REFUND_SYNC_TASK}_{}",
+        refund.internal_reference_id
+    );
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the overdue refund sync task")?;
+    // A revised settlement date is picked up by the task when it runs
+    if existing_process.is_some() {
+        return Ok(());
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        OVERDUE_REFUND_SYNC_TASK,
+        runner,
+        ["REFUND"],
+        refund_to_refund_core_workflow_model(refund),
+        expected_settlement_date,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct overdue refund sync process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting overdue refund sync task in process_tracker: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "OverdueRefundSync")]),
+    );
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn add_refund_execute_task(
     db: &dyn db::StorageInterface,
@@ -0,0 +1,270 @@
+use api_models::{enums as api_enums, payouts as payout_api};
+use common_utils::ext_traits::StringExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        payouts,
+    },
+    routes::SessionState,
+    types::{domain, storage},
+    utils::OptionExt,
+};
+
+/// Merchant config enabling payouts to the saved payment method of the customer, when refunds are
+/// refused by the connector
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct RefundPayoutFallbackConfig {
+    /// The refund error codes returned by the connectors, for which the payout is issued. For
+    /// example, the error codes of refunds attempted after the refund window of the payment.
+    pub error_codes: Vec<String>,
+    /// The payout connectors to be used for the payout, the payout routing of the profile is used
+    /// if not present
+    pub connectors: Option<Vec<api_enums::PayoutConnectors>>,
+}
+
+impl RefundPayoutFallbackConfig {
+    fn should_fallback_to_payout(&self, refund: &storage::Refund) -> bool {
+        refund.refund_status == storage::enums::RefundStatus::Failure
+            && refund
+                .refund_error_code
+                .as_ref()
+                .is_some_and(|error_code| self.error_codes.contains(error_code))
+    }
+}
+
+async fn get_refund_payout_fallback_config(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> Option<RefundPayoutFallbackConfig> {
+    let key = merchant_id.get_refund_payout_fallback_key();
+    state
+        .store
+        .find_config_by_key(&key)
+        .await
+        .map_err(|error| {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(refund_payout_fallback_config_error=?error);
+            }
+        })
+        .ok()?
+        .config
+        .parse_struct::<RefundPayoutFallbackConfig>("RefundPayoutFallbackConfig")
+        .map_err(|error| logger::error!(refund_payout_fallback_config_error=?error))
+        .ok()
+}
+
+/// Issues a payout of the refund amount to the card used for the payment, if the refund was
+/// refused by the connector with one of the error codes configured by the merchant. The payout is
+/// linked to the refund through the `fallback_payout_id` of the refund.
+///
+/// The ID of the payout is derived from the refund, so a single payout is issued for the refund
+/// even when the refund is executed and synced concurrently, or when the payout was created but
+/// its creation failed afterwards. Such a payout is linked to the refund on the next sync of the
+/// refund instead of being issued again.
+///
+/// The refund is returned unchanged if the fallback is not applicable or the payout could not be
+/// created, since the failure of the refund has already been recorded.
+#[instrument(skip_all)]
+pub async fn trigger_refund_payout_fallback(
+    state: &SessionState,
+    refund: storage::Refund,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+) -> storage::Refund {
+    if refund.fallback_payout_id.is_some() {
+        return refund;
+    }
+
+    let Some(fallback_config) =
+        get_refund_payout_fallback_config(state, merchant_account.get_id()).await
+    else {
+        return refund;
+    };
+
+    if !fallback_config.should_fallback_to_payout(&refund) {
+        return refund;
+    }
+
+    let fallback_payout_id = get_fallback_payout_id(&refund);
+
+    let existing_payout =
+        match find_fallback_payout(state, merchant_account, &fallback_payout_id).await {
+            Ok(existing_payout) => existing_payout,
+            Err(error) => {
+                logger::error!(refund_payout_fallback_error=?error, refund_id = %refund.refund_id);
+                return refund;
+            }
+        };
+
+    if existing_payout.is_none() {
+        if let Err(error) = create_fallback_payout(
+            state,
+            &refund,
+            &fallback_payout_id,
+            &fallback_config,
+            merchant_account,
+            key_store,
+            payment_attempt,
+            payment_intent,
+        )
+        .await
+        {
+            logger::error!(refund_payout_fallback_error=?error, refund_id = %refund.refund_id);
+            // The payout may have been created before the failure, or by a concurrent flow, in
+            // which case it is linked to the refund
+            let created_payout = find_fallback_payout(state, merchant_account, &fallback_payout_id)
+                .await
+                .map_err(|error| logger::error!(refund_payout_fallback_error=?error))
+                .ok()
+                .flatten();
+            if created_payout.is_none() {
+                return refund;
+            }
+        }
+    }
+
+    logger::info!(
+        refund_id = %refund.refund_id,
+        %fallback_payout_id,
+        "Issued payout for the refund refused by the connector"
+    );
+    state
+        .store
+        .update_refund(
+            refund.clone(),
+            storage::RefundUpdate::FallbackPayoutUpdate {
+                fallback_payout_id,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::error!(refund_payout_fallback_error=?error))
+        .unwrap_or(refund)
+}
+
+/// Derives the ID of the payout issued for the refund, payout IDs being UUIDs
+fn get_fallback_payout_id(refund: &storage::Refund) -> String {
+    uuid::Uuid::new_v5(
+        &uuid::Uuid::NAMESPACE_OID,
+        format!(
+            "refund_payout_fallback_{}_{}",
+            refund.merchant_id.get_string_repr(),
+            refund.refund_id
+        )
+        .as_bytes(),
+    )
+    .to_string()
+}
+
+async fn find_fallback_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    fallback_payout_id: &str,
+) -> RouterResult<Option<storage::Payouts>> {
+    state
+        .store
+        .find_optional_payout_by_merchant_id_payout_id(
+            merchant_account.get_id(),
+            fallback_payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payout issued for the refund")
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn create_fallback_payout(
+    state: &SessionState,
+    refund: &storage::Refund,
+    fallback_payout_id: &str,
+    fallback_config: &RefundPayoutFallbackConfig,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let customer_id = payment_intent
+        .customer_id
+        .clone()
+        .get_required_value("customer_id")
+        .attach_printable("Payout fallback requires the payment to be made by a customer")?;
+
+    let payment_method_id = payment_attempt
+        .payment_method_id
+        .as_ref()
+        .get_required_value("payment_method_id")
+        .attach_printable("Payout fallback requires the payment method to be saved")?;
+
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            key_store,
+            payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.get_payment_method_type() != Some(api_enums::PaymentMethod::Card)
+        || !state.conf.locker.locker_enabled
+    {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Payout fallback is only supported for cards saved in the locker".to_string(),
+        }));
+    }
+
+    let card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(payment_method.get_id()),
+    )
+    .await
+    .attach_printable("Failed to retrieve the card of the payment for the payout fallback")?;
+
+    let payout_create_request = payout_api::PayoutCreateRequest {
+        payout_id: Some(fallback_payout_id.to_owned()),
+        amount: Some(refund.refund_amount.into()),
+        currency: Some(refund.currency),
+        connector: fallback_config.connectors.clone(),
+        confirm: Some(true),
+        auto_fulfill: Some(true),
+        payout_type: Some(api_enums::PayoutType::Card),
+        payout_method_data: Some(payout_api::PayoutMethodData::Card(payout_api::CardPayout {
+            card_number: card.card_number,
+            expiry_month: card.card_exp_month,
+            expiry_year: card.card_exp_year,
+            card_holder_name: card.name_on_card,
+        })),
+        customer_id: Some(customer_id),
+        description: Some(format!(
+            "Payout for the refund {} refused by {}",
+            refund.refund_id, refund.connector
+        )),
+        profile_id: refund.profile_id.clone(),
+        ..Default::default()
+    };
+
+    Box::pin(payouts::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payout_create_request,
+        consts::DEFAULT_LOCALE,
+    ))
+    .await
+    .map(|_| ())
+}
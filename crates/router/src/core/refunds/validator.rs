@@ -3,7 +3,10 @@ use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
 use crate::{
-    core::errors::{self, CustomResult, RouterResult},
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        utils as core_utils,
+    },
     types::{
         self,
         api::enums as api_enums,
@@ -29,6 +32,16 @@ pub enum RefundValidationError {
     MaxRefundCountReached,
     #[error("There is already another refund request for this payment attempt")]
     DuplicateRefund,
+    #[error("The capture was not found among the charged captures of the payment attempt")]
+    CaptureNotFound,
+    #[error("The refund amount exceeds the amount left to be refunded on every capture")]
+    RefundAmountExceedsCaptureAmount,
+    #[error("The line item was not found in the order details of the payment")]
+    LineItemNotFound,
+    #[error("The product id of the line item is required, as its product name is not unique")]
+    AmbiguousLineItem,
+    #[error("The quantity of the line item exceeds the quantity left to be refunded")]
+    LineItemQuantityExceeded,
 }
 
 #[instrument(skip_all)]
@@ -42,6 +55,14 @@ pub fn validate_success_transaction(
     Ok(())
 }
 
+/// Whether the amount of the refund is, or may be, returned to the customer. A refund which failed
+/// at the connector is returned to the customer if a payout was issued for it as a fallback.
+fn is_refund_amount_returned(refund: &storage::Refund) -> bool {
+    (refund.refund_status != enums::RefundStatus::Failure
+        && refund.refund_status != enums::RefundStatus::TransactionFailure)
+        || refund.fallback_payout_id.is_some()
+}
+
 #[instrument(skip_all)]
 pub fn validate_refund_amount(
     amount_captured: i64,
@@ -50,15 +71,8 @@ pub fn validate_refund_amount(
 ) -> CustomResult<(), RefundValidationError> {
     let total_refunded_amount: i64 = all_refunds
         .iter()
-        .filter_map(|refund| {
-            if refund.refund_status != enums::RefundStatus::Failure
-                && refund.refund_status != enums::RefundStatus::TransactionFailure
-            {
-                Some(refund.refund_amount.get_amount_as_i64())
-            } else {
-                None
-            }
-        })
+        .filter(|refund| is_refund_amount_returned(refund))
+        .map(|refund| refund.refund_amount.get_amount_as_i64())
         .sum();
 
     utils::when(
@@ -71,6 +85,122 @@ pub fn validate_refund_amount(
     )
 }
 
+/// Selects the capture a refund is initiated against, for payment attempts captured in multiple
+/// parts. The refund amount is validated against the amount captured by the selected capture less
+/// the amount already refunded against it. When no capture is requested, the earliest capture with
+/// enough amount left to be refunded is selected.
+#[instrument(skip_all)]
+pub fn validate_refund_against_captures<'a>(
+    captures: &'a [storage::Capture],
+    all_refunds: &[storage::Refund],
+    refund_amount: i64,
+    capture_id: Option<&str>,
+) -> CustomResult<&'a storage::Capture, RefundValidationError> {
+    let mut charged_captures = captures
+        .iter()
+        .filter(|capture| capture.status == enums::CaptureStatus::Charged)
+        .collect::<Vec<_>>();
+    charged_captures.sort_by_key(|capture| capture.capture_sequence);
+
+    let validate_against_capture = |capture: &storage::Capture| {
+        let capture_refunds = all_refunds
+            .iter()
+            .filter(|refund| refund.capture_id.as_deref() == Some(capture.capture_id.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        validate_refund_amount(
+            capture.amount.get_amount_as_i64(),
+            &capture_refunds,
+            refund_amount,
+        )
+    };
+
+    match capture_id {
+        Some(capture_id) => {
+            let capture = charged_captures
+                .into_iter()
+                .find(|capture| capture.capture_id == capture_id)
+                .ok_or(report!(RefundValidationError::CaptureNotFound))?;
+            validate_against_capture(capture)?;
+            Ok(capture)
+        }
+        None => charged_captures
+            .into_iter()
+            .find(|capture| validate_against_capture(capture).is_ok())
+            .ok_or(report!(
+                RefundValidationError::RefundAmountExceedsCaptureAmount
+            )),
+    }
+}
+
+/// Resolves the line items of the order details of a payment which are refunded by a refund. The
+/// quantity of each line item is validated against the quantity ordered less the quantity already
+/// refunded by the refunds of the payment which have not failed.
+#[instrument(skip_all)]
+pub fn validate_refund_line_items(
+    order_details: &[api_models::payments::OrderDetailsWithAmount],
+    all_refunds: &[storage::Refund],
+    line_items: &[api_models::refunds::RefundLineItemRequest],
+) -> CustomResult<Vec<storage::RefundLineItem>, RefundValidationError> {
+    let requested_order_details = line_items
+        .iter()
+        .map(|line_item| {
+            core_utils::find_order_detail_of_line_item(
+                order_details,
+                &line_item.product_name,
+                line_item.product_id.as_deref(),
+            )
+            .map(|order_detail| (order_detail, line_item))
+            .map_err(|error| match error {
+                core_utils::LineItemResolutionError::NotFound => {
+                    report!(RefundValidationError::LineItemNotFound)
+                }
+                core_utils::LineItemResolutionError::Ambiguous => {
+                    report!(RefundValidationError::AmbiguousLineItem)
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    requested_order_details
+        .iter()
+        .map(|(order_detail, line_item)| {
+            let refunded_quantity: u32 = all_refunds
+                .iter()
+                .filter(|refund| is_refund_amount_returned(refund))
+                .filter_map(|refund| refund.line_items.as_ref())
+                .flat_map(|refund_line_items| refund_line_items.0.iter())
+                .filter(|refunded_line_item| {
+                    core_utils::is_line_item_of_order_detail(
+                        order_detail,
+                        &refunded_line_item.product_name,
+                        refunded_line_item.product_id.as_deref(),
+                    )
+                })
+                .map(|refunded_line_item| u32::from(refunded_line_item.quantity))
+                .sum();
+            let requested_quantity: u32 = requested_order_details
+                .iter()
+                .filter(|(other_order_detail, _)| std::ptr::eq(*other_order_detail, *order_detail))
+                .map(|(_, other_line_item)| u32::from(other_line_item.quantity))
+                .sum();
+
+            utils::when(
+                line_item.quantity == 0
+                    || refunded_quantity + requested_quantity > u32::from(order_detail.quantity),
+                || Err(report!(RefundValidationError::LineItemQuantityExceeded)),
+            )?;
+
+            Ok(storage::RefundLineItem {
+                product_name: order_detail.product_name.clone(),
+                product_id: order_detail.product_id.clone(),
+                quantity: line_item.quantity,
+                amount: order_detail.amount,
+            })
+        })
+        .collect()
+}
+
 #[instrument(skip_all)]
 pub fn validate_payment_order_age(
     created_at: &PrimitiveDateTime,
@@ -170,3 +300,301 @@ pub fn validate_charge_refund(
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use api_models::{payments::OrderDetailsWithAmount, refunds::RefundLineItemRequest};
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn order_detail(
+        product_name: &str,
+        product_id: Option<&str>,
+        quantity: u16,
+    ) -> OrderDetailsWithAmount {
+        OrderDetailsWithAmount {
+            product_name: product_name.to_string(),
+            product_id: product_id.map(str::to_string),
+            quantity,
+            amount: MinorUnit::new(1000),
+            ..Default::default()
+        }
+    }
+
+    fn line_item(
+        product_name: &str,
+        product_id: Option<&str>,
+        quantity: u16,
+    ) -> RefundLineItemRequest {
+        RefundLineItemRequest {
+            product_name: product_name.to_string(),
+            product_id: product_id.map(str::to_string),
+            quantity,
+        }
+    }
+
+    fn refund(
+        refund_amount: i64,
+        refund_status: enums::RefundStatus,
+        capture_id: Option<&str>,
+        line_items: Option<Vec<storage::RefundLineItem>>,
+    ) -> storage::Refund {
+        let now = common_utils::date_time::now();
+        storage::Refund {
+            internal_reference_id: "internal_reference_id".to_string(),
+            refund_id: "refund_id".to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            connector_transaction_id: "connector_transaction_id".to_string().into(),
+            connector: "stripe".to_string(),
+            connector_refund_id: None,
+            external_reference_id: None,
+            refund_type: enums::RefundType::InstantRefund,
+            total_amount: MinorUnit::new(2000),
+            currency: enums::Currency::USD,
+            refund_amount: MinorUnit::new(refund_amount),
+            refund_status,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at: now,
+            modified_at: now,
+            description: None,
+            attempt_id: "attempt_id".to_string(),
+            refund_reason: None,
+            refund_error_code: None,
+            profile_id: None,
+            updated_by: "admin".to_string(),
+            merchant_connector_id: None,
+            charges: None,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            connector_refund_data: None,
+            connector_transaction_data: None,
+            fallback_payout_id: None,
+            capture_id: capture_id.map(str::to_string),
+            expected_settlement_date: None,
+            status_reason: None,
+            line_items: line_items.map(storage::RefundLineItems),
+        }
+    }
+
+    fn refunded_line_item(
+        product_name: &str,
+        product_id: Option<&str>,
+        quantity: u16,
+    ) -> storage::RefundLineItem {
+        storage::RefundLineItem {
+            product_name: product_name.to_string(),
+            product_id: product_id.map(str::to_string),
+            quantity,
+            amount: MinorUnit::new(1000),
+        }
+    }
+
+    fn capture(
+        capture_id: &str,
+        capture_sequence: i16,
+        amount: i64,
+        status: enums::CaptureStatus,
+    ) -> storage::Capture {
+        let now = common_utils::date_time::now();
+        storage::Capture {
+            capture_id: capture_id.to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status,
+            amount: MinorUnit::new(amount),
+            currency: Some(enums::Currency::USD),
+            connector: "stripe".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "attempt_id".to_string(),
+            connector_capture_id: None,
+            capture_sequence,
+            connector_response_reference_id: None,
+            connector_capture_data: None,
+        }
+    }
+
+    #[test]
+    fn test_refund_line_items_over_refund() {
+        let order_details = [order_detail("shirt", None, 2)];
+        let all_refunds = [refund(
+            1000,
+            enums::RefundStatus::Success,
+            None,
+            Some(vec![refunded_line_item("shirt", None, 1)]),
+        )];
+
+        let error = validate_refund_line_items(
+            &order_details,
+            &all_refunds,
+            &[line_item("shirt", None, 2)],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::LineItemQuantityExceeded
+        ));
+
+        // The quantities of the line items of the same product in a request are added up
+        let error = validate_refund_line_items(
+            &order_details,
+            &all_refunds,
+            &[line_item("shirt", None, 1), line_item("shirt", None, 1)],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::LineItemQuantityExceeded
+        ));
+
+        let refund_line_items = validate_refund_line_items(
+            &order_details,
+            &all_refunds,
+            &[line_item("shirt", None, 1)],
+        )
+        .unwrap();
+        assert_eq!(
+            refund_line_items,
+            vec![refunded_line_item("shirt", None, 1)]
+        );
+    }
+
+    #[test]
+    fn test_refund_line_items_exclude_failed_refunds() {
+        let order_details = [order_detail("shirt", None, 2)];
+        let failed_refund = refund(
+            2000,
+            enums::RefundStatus::Failure,
+            None,
+            Some(vec![refunded_line_item("shirt", None, 2)]),
+        );
+
+        let refund_line_items = validate_refund_line_items(
+            &order_details,
+            &[failed_refund.clone()],
+            &[line_item("shirt", None, 2)],
+        )
+        .unwrap();
+        assert_eq!(
+            refund_line_items,
+            vec![refunded_line_item("shirt", None, 2)]
+        );
+
+        // A failed refund for which a payout was issued as a fallback is returned to the customer
+        let fallback_refund = storage::Refund {
+            fallback_payout_id: Some("payout_id".to_string()),
+            ..failed_refund
+        };
+        let error = validate_refund_line_items(
+            &order_details,
+            &[fallback_refund],
+            &[line_item("shirt", None, 1)],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::LineItemQuantityExceeded
+        ));
+    }
+
+    #[test]
+    fn test_refund_line_items_ambiguous_product() {
+        let order_details = [
+            order_detail("shirt", Some("shirt_s"), 1),
+            order_detail("shirt", Some("shirt_m"), 1),
+        ];
+        let all_refunds = [refund(
+            1000,
+            enums::RefundStatus::Success,
+            None,
+            Some(vec![refunded_line_item("shirt", Some("shirt_s"), 1)]),
+        )];
+
+        let error = validate_refund_line_items(
+            &order_details,
+            &all_refunds,
+            &[line_item("shirt", None, 1)],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::AmbiguousLineItem
+        ));
+
+        // The quantity refunded of another product with the same name is not counted
+        let refund_line_items = validate_refund_line_items(
+            &order_details,
+            &all_refunds,
+            &[line_item("shirt", Some("shirt_m"), 1)],
+        )
+        .unwrap();
+        assert_eq!(
+            refund_line_items,
+            vec![refunded_line_item("shirt", Some("shirt_m"), 1)]
+        );
+
+        let error = validate_refund_line_items(
+            &order_details,
+            &all_refunds,
+            &[line_item("shirt", Some("shirt_l"), 1)],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::LineItemNotFound
+        ));
+    }
+
+    #[test]
+    fn test_refund_against_captures() {
+        let captures = [
+            capture("capture_2", 2, 1000, enums::CaptureStatus::Charged),
+            capture("capture_1", 1, 1000, enums::CaptureStatus::Charged),
+            capture("capture_3", 3, 1000, enums::CaptureStatus::Failed),
+        ];
+        let all_refunds = [
+            refund(600, enums::RefundStatus::Success, Some("capture_1"), None),
+            refund(1000, enums::RefundStatus::Failure, Some("capture_2"), None),
+        ];
+
+        // The earliest capture with enough amount left to be refunded is selected
+        let selected_capture =
+            validate_refund_against_captures(&captures, &all_refunds, 400, None).unwrap();
+        assert_eq!(selected_capture.capture_id, "capture_1");
+        let selected_capture =
+            validate_refund_against_captures(&captures, &all_refunds, 500, None).unwrap();
+        assert_eq!(selected_capture.capture_id, "capture_2");
+
+        let error =
+            validate_refund_against_captures(&captures, &all_refunds, 1001, None).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::RefundAmountExceedsCaptureAmount
+        ));
+
+        let error =
+            validate_refund_against_captures(&captures, &all_refunds, 500, Some("capture_1"))
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::RefundAmountExceedsPaymentAmount
+        ));
+
+        let error =
+            validate_refund_against_captures(&captures, &all_refunds, 500, Some("capture_3"))
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::CaptureNotFound
+        ));
+    }
+}
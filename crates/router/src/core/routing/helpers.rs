@@ -174,6 +174,8 @@ pub async fn update_merchant_active_algorithm_ref(
         default_profile: None,
         payment_link_config: None,
         pm_collect_link_config: None,
+        id_prefixes: None,
+        data_retention_policy: None,
     };
 
     let db = &*state.store;
@@ -0,0 +1,424 @@
+use api_models::test_clocks::{
+    TestClockAdvanceRequest, TestClockCreateRequest, TestClockDeleteResponse,
+    TestClockListResponse, TestClockResponse,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    fp_utils::when,
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{env, instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+    utils,
+};
+
+/// The test clocks of a merchant, stored as a merchant config
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+struct TestClocksConfig {
+    /// The clock attached to the merchant, if any
+    attached_clock_id: Option<String>,
+    clocks: Vec<TestClock>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+struct TestClock {
+    clock_id: String,
+    name: Option<String>,
+    initial_time: PrimitiveDateTime,
+    frozen_time: PrimitiveDateTime,
+    created_at: PrimitiveDateTime,
+}
+
+impl TestClock {
+    /// The duration the clock has been advanced by since it was created
+    fn get_offset(&self) -> time::Duration {
+        self.frozen_time - self.initial_time
+    }
+}
+
+impl TestClocksConfig {
+    fn find_clock(&self, clock_id: &str) -> RouterResult<&TestClock> {
+        self.clocks
+            .iter()
+            .find(|clock| clock.clock_id == clock_id)
+            .ok_or(report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Test clock does not exist in our records".to_string(),
+            }))
+    }
+
+    fn find_clock_mut(&mut self, clock_id: &str) -> RouterResult<&mut TestClock> {
+        self.clocks
+            .iter_mut()
+            .find(|clock| clock.clock_id == clock_id)
+            .ok_or(report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Test clock does not exist in our records".to_string(),
+            }))
+    }
+
+    fn get_attached_clock(&self) -> Option<&TestClock> {
+        self.attached_clock_id
+            .as_ref()
+            .and_then(|clock_id| self.find_clock(clock_id).ok())
+    }
+
+    fn get_clock_response(&self, clock: &TestClock) -> TestClockResponse {
+        TestClockResponse {
+            clock_id: clock.clock_id.clone(),
+            name: clock.name.clone(),
+            initial_time: clock.initial_time,
+            frozen_time: clock.frozen_time,
+            attached: self.attached_clock_id.as_ref() == Some(&clock.clock_id),
+            created_at: clock.created_at,
+        }
+    }
+}
+
+fn validate_test_clocks_enabled() -> RouterResult<()> {
+    when(matches!(env::which(), env::Env::Production), || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Test clocks are only supported in the sandbox environment".to_string(),
+        }))
+    })
+}
+
+async fn get_test_clocks_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<TestClocksConfig>> {
+    match db
+        .find_config_by_key_from_db(&merchant_id.get_test_clocks_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct::<TestClocksConfig>("TestClocksConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Test clocks config has invalid structure")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching test clocks config"),
+    }
+}
+
+async fn save_test_clocks_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    test_clocks_config: &TestClocksConfig,
+    config_exists: bool,
+) -> RouterResult<()> {
+    let key = merchant_id.get_test_clocks_key();
+    let config = test_clocks_config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize test clocks config")?;
+
+    if config_exists {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating test clocks config")?;
+    } else {
+        db.insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error inserting test clocks config")?;
+    }
+
+    Ok(())
+}
+
+/// The workflows which are scheduled according to the time of the test clock attached to the
+/// merchant, if any
+const TEST_CLOCK_RUNNERS: [storage::ProcessTrackerRunner; 5] = [
+    storage::ProcessTrackerRunner::PaymentsAutoCaptureWorkflow,
+    storage::ProcessTrackerRunner::InstallmentPlanChargeWorkflow,
+    storage::ProcessTrackerRunner::PaymentLinkExpiryWorkflow,
+    storage::ProcessTrackerRunner::PayoutLinkExpiryWorkflow,
+    storage::ProcessTrackerRunner::VoucherExpiryWorkflow,
+];
+
+/// Runs the pending tasks of the time based workflows of the merchant which fall due before the
+/// time of the attached clock, by scheduling them at the current time
+async fn run_due_processes(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    clock_offset: time::Duration,
+) -> RouterResult<()> {
+    let now = date_time::now();
+    let due_processes = db
+        .as_scheduler()
+        .find_processes_by_merchant_id_runners_time_status(
+            merchant_id,
+            &TEST_CLOCK_RUNNERS,
+            now,
+            now + clock_offset,
+            storage::enums::ProcessTrackerStatus::New,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch scheduled processes")?;
+
+    for process in due_processes {
+        let process_id = process.id.clone();
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(now),
+                    tracking_data: None,
+                    business_status: None,
+                    status: None,
+                    updated_at: Some(now),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to reschedule process {process_id}"))?;
+        logger::debug!(%process_id, "Rescheduled process due before the test clock time");
+    }
+
+    Ok(())
+}
+
+/// Returns the duration the clock attached to the merchant has been advanced by. The tasks of the
+/// time based workflows of the merchant are scheduled early by this duration.
+#[instrument(skip_all)]
+pub async fn get_merchant_time_offset(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<time::Duration> {
+    if matches!(env::which(), env::Env::Production) {
+        return Ok(time::Duration::ZERO);
+    }
+
+    Ok(get_test_clocks_config(&*state.store, merchant_id)
+        .await?
+        .and_then(|test_clocks_config| {
+            test_clocks_config
+                .get_attached_clock()
+                .map(TestClock::get_offset)
+        })
+        .unwrap_or(time::Duration::ZERO))
+}
+
+#[instrument(skip_all)]
+pub async fn create_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: TestClockCreateRequest,
+) -> RouterResponse<TestClockResponse> {
+    validate_test_clocks_enabled()?;
+
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let test_clocks_config = get_test_clocks_config(db, merchant_id).await?;
+    let config_exists = test_clocks_config.is_some();
+    let mut test_clocks_config = test_clocks_config.unwrap_or_default();
+
+    let now = date_time::now();
+    let frozen_time = req.frozen_time.unwrap_or(now);
+    let test_clock = TestClock {
+        clock_id: utils::generate_id(consts::ID_LENGTH, "clock"),
+        name: req.name,
+        initial_time: frozen_time,
+        frozen_time,
+        created_at: now,
+    };
+    let response = test_clocks_config.get_clock_response(&test_clock);
+    test_clocks_config.clocks.push(test_clock);
+
+    save_test_clocks_config(db, merchant_id, &test_clocks_config, config_exists).await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+) -> RouterResponse<TestClockResponse> {
+    validate_test_clocks_enabled()?;
+
+    let test_clocks_config = get_test_clocks_config(&*state.store, merchant_account.get_id())
+        .await?
+        .unwrap_or_default();
+    let test_clock = test_clocks_config.find_clock(&clock_id)?;
+
+    Ok(services::ApplicationResponse::Json(
+        test_clocks_config.get_clock_response(test_clock),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_test_clocks(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<TestClockListResponse> {
+    validate_test_clocks_enabled()?;
+
+    let test_clocks_config = get_test_clocks_config(&*state.store, merchant_account.get_id())
+        .await?
+        .unwrap_or_default();
+    let data: Vec<_> = test_clocks_config
+        .clocks
+        .iter()
+        .map(|test_clock| test_clocks_config.get_clock_response(test_clock))
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(TestClockListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Attaches the clock to the merchant, replacing the clock attached previously. The pending
+/// tasks of the merchant which fall due before the time of the clock are run right away.
+#[instrument(skip_all)]
+pub async fn attach_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+) -> RouterResponse<TestClockResponse> {
+    validate_test_clocks_enabled()?;
+
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let mut test_clocks_config = get_test_clocks_config(db, merchant_id)
+        .await?
+        .unwrap_or_default();
+    let clock_offset = test_clocks_config.find_clock(&clock_id)?.get_offset();
+
+    test_clocks_config.attached_clock_id = Some(clock_id.clone());
+    save_test_clocks_config(db, merchant_id, &test_clocks_config, true).await?;
+    run_due_processes(db, merchant_id, clock_offset).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        test_clocks_config.get_clock_response(test_clocks_config.find_clock(&clock_id)?),
+    ))
+}
+
+/// Detaches the clock from the merchant. Tasks which have already been run are not reverted.
+#[instrument(skip_all)]
+pub async fn detach_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+) -> RouterResponse<TestClockResponse> {
+    validate_test_clocks_enabled()?;
+
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let mut test_clocks_config = get_test_clocks_config(db, merchant_id)
+        .await?
+        .unwrap_or_default();
+    test_clocks_config.find_clock(&clock_id)?;
+
+    when(
+        test_clocks_config.attached_clock_id.as_ref() != Some(&clock_id),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Test clock is not attached to the merchant".to_string(),
+            }))
+        },
+    )?;
+
+    test_clocks_config.attached_clock_id = None;
+    save_test_clocks_config(db, merchant_id, &test_clocks_config, true).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        test_clocks_config.get_clock_response(test_clocks_config.find_clock(&clock_id)?),
+    ))
+}
+
+/// Advances the time of the clock. If the clock is attached to the merchant, the pending tasks of
+/// the merchant which fall due before the new time of the clock are run right away.
+#[instrument(skip_all)]
+pub async fn advance_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+    req: TestClockAdvanceRequest,
+) -> RouterResponse<TestClockResponse> {
+    validate_test_clocks_enabled()?;
+
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let mut test_clocks_config = get_test_clocks_config(db, merchant_id)
+        .await?
+        .unwrap_or_default();
+    let is_attached = test_clocks_config.attached_clock_id.as_ref() == Some(&clock_id);
+    let test_clock = test_clocks_config.find_clock_mut(&clock_id)?;
+
+    when(req.frozen_time <= test_clock.frozen_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "frozen_time must be later than the current time of the test clock"
+                .to_string(),
+        }))
+    })?;
+
+    test_clock.frozen_time = req.frozen_time;
+    let clock_offset = test_clock.get_offset();
+    save_test_clocks_config(db, merchant_id, &test_clocks_config, true).await?;
+
+    if is_attached {
+        run_due_processes(db, merchant_id, clock_offset).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        test_clocks_config.get_clock_response(test_clocks_config.find_clock(&clock_id)?),
+    ))
+}
+
+/// Deletes the clock, detaching it from the merchant if attached
+#[instrument(skip_all)]
+pub async fn delete_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+) -> RouterResponse<TestClockDeleteResponse> {
+    validate_test_clocks_enabled()?;
+
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let mut test_clocks_config = get_test_clocks_config(db, merchant_id)
+        .await?
+        .unwrap_or_default();
+    test_clocks_config.find_clock(&clock_id)?;
+
+    test_clocks_config
+        .clocks
+        .retain(|test_clock| test_clock.clock_id != clock_id);
+    if test_clocks_config.attached_clock_id.as_ref() == Some(&clock_id) {
+        test_clocks_config.attached_clock_id = None;
+    }
+    save_test_clocks_config(db, merchant_id, &test_clocks_config, true).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        TestClockDeleteResponse {
+            clock_id,
+            deleted: true,
+        },
+    ))
+}
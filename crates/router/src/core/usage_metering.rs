@@ -0,0 +1,199 @@
+#[cfg(feature = "olap")]
+use std::collections::HashMap;
+
+#[cfg(feature = "olap")]
+use api_models::usage as usage_api;
+use common_utils::{date_time, id_type};
+#[cfg(feature = "olap")]
+use error_stack::report;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::{RouterResponse, StorageErrorExt},
+    services,
+};
+
+const PAYMENTS_CREATED: &str = "payments_created";
+const PAYOUTS_PROCESSED: &str = "payouts_processed";
+const VAULT_TOKENS_STORED: &str = "vault_tokens_stored";
+
+/// Operations which are billed to merchants, metered per merchant per month
+#[derive(Clone, Copy, Debug)]
+pub enum BillableOperation {
+    PaymentCreated,
+    PayoutProcessed,
+    VaultTokenStored,
+}
+
+impl BillableOperation {
+    fn get_usage_field(&self) -> &'static str {
+        match self {
+            Self::PaymentCreated => PAYMENTS_CREATED,
+            Self::PayoutProcessed => PAYOUTS_PROCESSED,
+            Self::VaultTokenStored => VAULT_TOKENS_STORED,
+        }
+    }
+}
+
+/// Records a billable operation performed by the merchant in the current month. Failures are only
+/// logged, since metering must not affect the operation being metered.
+#[instrument(skip_all)]
+pub async fn record_billable_operation(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    operation: BillableOperation,
+) {
+    if let Err(error) = increment_usage(state, merchant_id, operation).await {
+        logger::error!(?error, ?operation, "Failed to record billable operation");
+    }
+}
+
+async fn increment_usage(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    operation: BillableOperation,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = merchant_id.get_usage_metering_key(&get_billing_month(date_time::now()));
+
+    redis_conn
+        .increment_fields_in_hash(&key, &[(operation.get_usage_field(), 1)])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment usage of billable operation")?;
+    redis_conn
+        .set_expiry(&key, consts::USAGE_METERING_REDIS_KEY_EXPIRY_SECONDS)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set expiry of usage metering key")?;
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn retrieve_merchant_usage(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: usage_api::UsageRetrieveRequest,
+) -> RouterResponse<usage_api::MerchantUsageResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(
+        &(&state).into(),
+        &merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let month = parse_billing_month(request.month)?;
+    let usage = get_merchant_usage(&state, merchant_id, month).await?;
+
+    Ok(services::ApplicationResponse::Json(usage))
+}
+
+#[cfg(feature = "olap")]
+/// Exports the usage of every merchant account of the organization in a month as CSV
+#[instrument(skip(state))]
+pub async fn export_organization_usage(
+    state: SessionState,
+    organization_id: id_type::OrganizationId,
+    request: usage_api::UsageRetrieveRequest,
+) -> RouterResponse<()> {
+    let month = parse_billing_month(request.month)?;
+    let merchant_accounts = state
+        .store
+        .list_merchant_accounts_by_organization_id(&(&state).into(), &organization_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant accounts of the organization")?;
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for merchant_account in merchant_accounts {
+        let usage =
+            get_merchant_usage(&state, merchant_account.get_id().to_owned(), month.clone()).await?;
+        writer
+            .serialize(usage)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write usage to csv")?;
+    }
+    let usage_csv = writer
+        .into_inner()
+        .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Failed to flush usage csv")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        usage_csv,
+        mime::TEXT_CSV,
+    )))
+}
+
+#[cfg(feature = "olap")]
+async fn get_merchant_usage(
+    state: &SessionState,
+    merchant_id: id_type::MerchantId,
+    month: String,
+) -> RouterResult<usage_api::MerchantUsageResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let usage = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&merchant_id.get_usage_metering_key(&month))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch usage of billable operations")?;
+
+    Ok(usage_api::MerchantUsageResponse {
+        merchant_id,
+        month,
+        payments_created: get_count(&usage, PAYMENTS_CREATED),
+        payouts_processed: get_count(&usage, PAYOUTS_PROCESSED),
+        vault_tokens_stored: get_count(&usage, VAULT_TOKENS_STORED),
+    })
+}
+
+#[cfg(feature = "olap")]
+fn get_count(usage: &HashMap<String, String>, field: &str) -> u64 {
+    usage
+        .get(field)
+        .and_then(|count| count.parse().ok())
+        .unwrap_or_default()
+}
+
+fn get_billing_month(date_time: time::PrimitiveDateTime) -> String {
+    format!("{:04}-{:02}", date_time.year(), u8::from(date_time.month()))
+}
+
+#[cfg(feature = "olap")]
+fn parse_billing_month(month: Option<String>) -> RouterResult<String> {
+    let Some(month) = month else {
+        return Ok(get_billing_month(date_time::now()));
+    };
+
+    month
+        .split_once('-')
+        .and_then(|(year, month)| {
+            let year = year.parse::<i32>().ok()?;
+            let month = time::Month::try_from(month.parse::<u8>().ok()?).ok()?;
+            time::Date::from_calendar_date(year, month, 1).ok()
+        })
+        .map(|date| get_billing_month(date.midnight()))
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "month must be in the format YYYY-MM".to_string(),
+            })
+        })
+}
@@ -15,7 +15,9 @@ use diesel_models::{
     enums::{TotpStatus, UserRoleVersion, UserStatus},
     organization::OrganizationBridge,
     user as storage_user,
-    user_authentication_method::{UserAuthenticationMethodNew, UserAuthenticationMethodUpdate},
+    user_authentication_method::{
+        UserAuthenticationMethod, UserAuthenticationMethodNew, UserAuthenticationMethodUpdate,
+    },
 };
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface, Secret};
@@ -44,7 +46,9 @@ use crate::{
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 pub mod theme;
+pub mod webauthn;
 
 #[cfg(feature = "email")]
 pub async fn signup_with_merchant_id(
@@ -302,6 +306,7 @@ pub async fn signout(
 ) -> UserResponse<()> {
     tfa_utils::delete_totp_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_recovery_code_from_redis(&state, &user_from_token.user_id).await?;
+    tfa_utils::delete_webauthn_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_totp_secret_from_redis(&state, &user_from_token.user_id).await?;
 
     auth::blacklist::insert_user_in_blacklist(&state, &user_from_token.user_id).await?;
@@ -1702,9 +1707,7 @@ pub async fn reset_totp(
         return Err(UserErrors::TotpNotSetup.into());
     }
 
-    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
-        && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
-    {
+    if !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await? {
         return Err(UserErrors::TwoFactorAuthRequired.into());
     }
 
@@ -1748,10 +1751,11 @@ pub async fn verify_totp(
         return Err(UserErrors::MaxTotpAttemptsReached.into());
     }
 
+    // Two factor authentication can be setup with only passkeys, in which case there is no secret
     let user_totp_secret = user_from_db
         .decrypt_and_get_totp_secret(&state)
         .await?
-        .ok_or(UserErrors::InternalServerError)?;
+        .ok_or(UserErrors::TotpNotSetup)?;
 
     let totp = tfa_utils::generate_default_totp(
         user_from_db.get_email(),
@@ -1856,7 +1860,9 @@ pub async fn generate_recovery_codes(
     state: SessionState,
     user_token: auth::UserIdFromAuth,
 ) -> UserResponse<user_api::RecoveryCodes> {
-    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await? {
+    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
+        && !tfa_utils::check_webauthn_in_redis(&state, &user_token.user_id).await?
+    {
         return Err(UserErrors::TotpRequired.into());
     }
 
@@ -1983,9 +1989,7 @@ pub async fn terminate_two_factor_auth(
         .into();
 
     if state.conf.user.force_two_factor_auth || !skip_two_factor_auth {
-        if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
-            && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
-        {
+        if !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await? {
             return Err(UserErrors::TwoFactorAuthRequired.into());
         }
 
@@ -2019,6 +2023,9 @@ pub async fn terminate_two_factor_auth(
     let _ = tfa_utils::delete_recovery_code_attempts_from_redis(&state, &user_token.user_id)
         .await
         .inspect_err(|error| logger::error!(?error));
+    let _ = tfa_utils::delete_webauthn_attempts_from_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
 
     auth::cookies::set_cookie_response(
         user_api::TokenResponse {
@@ -2038,6 +2045,7 @@ pub async fn check_two_factor_auth_status(
             totp: tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?,
             recovery_code: tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id)
                 .await?,
+            webauthn: tfa_utils::check_webauthn_in_redis(&state, &user_token.user_id).await?,
         },
     ))
 }
@@ -2071,10 +2079,16 @@ pub async fn check_two_factor_auth_status_with_attempts(
         remaining_attempts: consts::user::RECOVERY_CODE_MAX_ATTEMPTS
             - tfa_utils::get_recovery_code_attempts_from_redis(&state, &user_token.user_id).await?,
     };
+    let webauthn = user_api::TwoFactorAuthAttempts {
+        is_completed: tfa_utils::check_webauthn_in_redis(&state, &user_token.user_id).await?,
+        remaining_attempts: consts::user::WEBAUTHN_MAX_ATTEMPTS
+            - tfa_utils::get_webauthn_attempts_from_redis(&state, &user_token.user_id).await?,
+    };
     Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
         status: Some(user_api::TwoFactorAuthStatusResponseWithAttempts {
             totp,
             recovery_code,
+            webauthn,
         }),
         is_skippable,
     }))
@@ -2103,6 +2117,8 @@ pub async fn create_user_authentication_method(
         id.clone(),
     )
     .await?;
+    let email_domain =
+        validate_sso_email_domain(&state, &req.auth_method, req.email_domain, &id).await?;
 
     let auth_methods = state
         .store
@@ -2154,6 +2170,7 @@ pub async fn create_user_authentication_method(
             allow_signup: req.allow_signup,
             created_at: now,
             last_modified_at: now,
+            email_domain,
         })
         .await
         .to_duplicate_response(UserErrors::UserAuthMethodAlreadyExists)?;
@@ -2184,6 +2201,8 @@ pub async fn update_user_authentication_method(
         req.id.clone(),
     )
     .await?;
+    let email_domain =
+        validate_sso_email_domain(&state, &req.auth_method, req.email_domain, &req.id).await?;
 
     state
         .store
@@ -2192,6 +2211,7 @@ pub async fn update_user_authentication_method(
             UserAuthenticationMethodUpdate::UpdateConfig {
                 private_config,
                 public_config,
+                email_domain,
             },
         )
         .await
@@ -2199,6 +2219,41 @@ pub async fn update_user_authentication_method(
     Ok(ApplicationResponse::StatusOk)
 }
 
+/// Validates the email domain used to route the users to the auth method on SSO. An email domain
+/// can only be routed to a single OpenID Connect auth method.
+async fn validate_sso_email_domain(
+    state: &SessionState,
+    auth_method: &user_api::AuthConfig,
+    email_domain: Option<String>,
+    id: &str,
+) -> UserResult<Option<String>> {
+    let Some(email_domain) = email_domain.map(|email_domain| email_domain.trim().to_lowercase())
+    else {
+        return Ok(None);
+    };
+
+    if !matches!(auth_method, user_api::AuthConfig::OpenIdConnect { .. }) {
+        return Err(report!(UserErrors::InvalidUserAuthMethodOperation))
+            .attach_printable("email_domain is only applicable to OpenID Connect");
+    }
+    if email_domain.is_empty() || email_domain.contains('@') || !email_domain.contains('.') {
+        return Err(report!(UserErrors::InvalidUserAuthMethodOperation))
+            .attach_printable("Invalid email_domain");
+    }
+
+    let existing_auth_method = state
+        .store
+        .get_user_authentication_method_by_email_domain(&email_domain)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    if existing_auth_method.is_some_and(|auth_method| auth_method.id != id) {
+        return Err(report!(UserErrors::UserAuthMethodAlreadyExists))
+            .attach_printable("email_domain is routed to another auth method");
+    }
+
+    Ok(Some(email_domain))
+}
+
 pub async fn list_user_authentication_methods(
     state: SessionState,
     req: user_api::GetUserAuthenticationMethodsRequest,
@@ -2239,6 +2294,7 @@ pub async fn list_user_authentication_methods(
                         auth_type: auth_method.auth_type,
                     },
                     allow_signup: auth_method.allow_signup,
+                    email_domain: auth_method.email_domain,
                 })
             })
             .collect::<UserResult<_>>()?,
@@ -2256,10 +2312,36 @@ pub async fn get_sso_auth_url(
         .await
         .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
 
+    get_sso_auth_url_for_auth_method(state, user_authentication_method).await
+}
+
+/// Routes the user to the OpenID Connect provider configured for the domain of their email
+#[cfg(feature = "v1")]
+pub async fn get_sso_auth_url_by_email(
+    state: SessionState,
+    request: user_api::GetSsoAuthUrlByEmailRequest,
+) -> UserResponse<()> {
+    let email = domain::UserEmail::from_pii_email(request.email)?;
+    let user_authentication_method = state
+        .store
+        .get_user_authentication_method_by_email_domain(email.get_domain()?)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .filter(|auth_method| auth_method.auth_type == common_enums::UserAuthType::OpenIdConnect)
+        .ok_or(UserErrors::SSONotConfiguredForEmailDomain)?;
+
+    get_sso_auth_url_for_auth_method(state, user_authentication_method).await
+}
+
+#[cfg(feature = "v1")]
+async fn get_sso_auth_url_for_auth_method(
+    state: SessionState,
+    user_authentication_method: UserAuthenticationMethod,
+) -> UserResponse<()> {
     let open_id_private_config = utils::user::decrypt_oidc_private_config(
         &state,
         user_authentication_method.private_config,
-        request.id.clone(),
+        user_authentication_method.id.clone(),
     )
     .await?;
 
@@ -2273,7 +2355,8 @@ pub async fn get_sso_auth_url(
     .attach_printable("Unable to parse OpenIdConnectPublicConfig")?;
 
     let oidc_state = Secret::new(nanoid::nanoid!());
-    utils::user::set_sso_id_in_redis(&state, oidc_state.clone(), request.id).await?;
+    utils::user::set_sso_id_in_redis(&state, oidc_state.clone(), user_authentication_method.id)
+        .await?;
 
     let redirect_url =
         utils::user::get_oidc_sso_redirect_url(&state, &open_id_public_config.name.to_string());
@@ -2340,6 +2423,14 @@ pub async fn sso_sign(
     )
     .await?;
 
+    // Users routed by their email domain must belong to the domain
+    if let Some(email_domain) = user_authentication_method.email_domain.as_deref() {
+        if email.get_domain()? != email_domain {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("Email domain of the user does not match the auth method");
+        }
+    }
+
     // TODO: Use config to handle not found error
     let user_from_db: domain::UserFromStorage = state
         .global_store
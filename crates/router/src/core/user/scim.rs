@@ -0,0 +1,1075 @@
+use std::collections::HashSet;
+
+use api_models::user::scim as scim_api;
+use common_enums::EntityType;
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::{
+    enums::{UserRoleVersion, UserStatus},
+    user as storage_user,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{
+        api_keys::PlaintextApiKey,
+        errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+    },
+    db::user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload},
+    routes::SessionState,
+    services::{authentication as auth, authorization::roles, ApplicationResponse},
+    types::{domain, storage},
+    utils,
+};
+
+const SCIM_CONFIG_ID_PREFIX: &str = "scim";
+const SCIM_USER_RESOURCE_TYPE: &str = "User";
+const SCIM_GROUP_RESOURCE_TYPE: &str = "Group";
+const SCIM_MEMBERS_PATH: &str = "members";
+const SCIM_ACTIVE_PATH: &str = "active";
+const DEFAULT_SCIM_PAGE_SIZE: usize = 100;
+
+pub async fn upsert_scim_config(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    req: scim_api::ScimConfigRequest,
+) -> UserResponse<scim_api::ScimConfigResponse> {
+    validate_scim_config_request(&state, &user_from_token, &req).await?;
+
+    let group_role_mappings = req
+        .group_role_mappings
+        .encode_to_value()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize SCIM group role mappings")?;
+
+    let existing_scim_config = state
+        .store
+        .find_scim_config_by_merchant_id(&user_from_token.merchant_id)
+        .await;
+
+    let (scim_config, token) = match existing_scim_config {
+        Ok(scim_config) => {
+            let scim_config = state
+                .store
+                .update_scim_config(
+                    &scim_config.id,
+                    storage::ScimConfigUpdate::MappingsUpdate {
+                        group_role_mappings,
+                        default_role_id: req.default_role_id,
+                    },
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to update SCIM configuration")?;
+            (scim_config, None)
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let (hashed_token, token) = generate_scim_token(&state)?;
+            let now = common_utils::date_time::now();
+            let scim_config = state
+                .store
+                .insert_scim_config(storage::ScimConfigNew {
+                    id: utils::generate_id(consts::ID_LENGTH, SCIM_CONFIG_ID_PREFIX),
+                    org_id: user_from_token.org_id,
+                    merchant_id: user_from_token.merchant_id,
+                    profile_id: user_from_token.profile_id,
+                    hashed_token,
+                    token_prefix: token.prefix(),
+                    group_role_mappings,
+                    default_role_id: req.default_role_id,
+                    created_by: user_from_token.user_id,
+                    created_at: now,
+                    last_modified_at: now,
+                })
+                .await
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to insert SCIM configuration")?;
+            (scim_config, Some(token))
+        }
+        Err(error) => Err(error.change_context(UserErrors::InternalServerError))?,
+    };
+
+    get_scim_config_response(scim_config, token).map(ApplicationResponse::Json)
+}
+
+pub async fn get_scim_config(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<scim_api::ScimConfigResponse> {
+    let scim_config = state
+        .store
+        .find_scim_config_by_merchant_id(&user_from_token.merchant_id)
+        .await
+        .to_not_found_response(UserErrors::ScimConfigNotFound)?;
+
+    get_scim_config_response(scim_config, None).map(ApplicationResponse::Json)
+}
+
+/// Replaces the bearer token of the SCIM configuration, after which the previous token can no
+/// longer be used by the identity provider
+pub async fn rotate_scim_token(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<scim_api::ScimConfigResponse> {
+    let scim_config = state
+        .store
+        .find_scim_config_by_merchant_id(&user_from_token.merchant_id)
+        .await
+        .to_not_found_response(UserErrors::ScimConfigNotFound)?;
+
+    let (hashed_token, token) = generate_scim_token(&state)?;
+    let scim_config = state
+        .store
+        .update_scim_config(
+            &scim_config.id,
+            storage::ScimConfigUpdate::TokenRotation {
+                hashed_token,
+                token_prefix: token.prefix(),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to rotate SCIM token")?;
+
+    get_scim_config_response(scim_config, Some(token)).map(ApplicationResponse::Json)
+}
+
+/// Removes the SCIM configuration, which stops the provisioning of users by the identity
+/// provider. The users provisioned so far, and their roles, are retained.
+pub async fn delete_scim_config(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<()> {
+    let is_deleted = state
+        .store
+        .delete_scim_config_by_merchant_id(&user_from_token.merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to delete SCIM configuration")?;
+
+    if !is_deleted {
+        return Err(report!(UserErrors::ScimConfigNotFound));
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Provisions the user in the merchant of the SCIM configuration. A user who already exists is
+/// provisioned as is, and is assigned the default role unless they already have a role in the
+/// merchant.
+pub async fn create_user(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    req: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let user_email = domain::UserEmail::from_pii_email(req.user_name.clone())?;
+    let user: domain::UserFromStorage =
+        match state.global_store.find_user_by_email(&user_email).await {
+            Ok(user) => user.into(),
+            Err(error) if error.current_context().is_db_not_found() => {
+                let new_user = domain::NewUser::try_from((
+                    req.clone(),
+                    scim_config.org_id.clone(),
+                    scim_config.merchant_id.clone(),
+                ))?;
+                let mut store_user: storage_user::UserNew = new_user.try_into()?;
+                // The email of the user is verified by the identity provider
+                store_user.set_is_verified(true);
+
+                state
+                    .global_store
+                    .insert_user(store_user)
+                    .await
+                    .map_err(|error| {
+                        if error.current_context().is_db_unique_violation() {
+                            error.change_context(UserErrors::UserExists)
+                        } else {
+                            error.change_context(UserErrors::InternalServerError)
+                        }
+                    })?
+                    .into()
+            }
+            Err(error) => Err(error.change_context(UserErrors::InternalServerError))?,
+        };
+
+    if req.active.unwrap_or(true) {
+        activate_user(&state, &scim_config, user.get_user_id()).await?;
+    }
+
+    get_scim_user_response(&state, &scim_config, user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn retrieve_user(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    user_id: String,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let user = find_user(&state, &scim_config, &user_id).await?;
+
+    get_scim_user_response(&state, &scim_config, user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Lists the users who have a role managed by the SCIM configuration in the merchant, or the user
+/// with the email in the `userName eq "<email>"` filter
+pub async fn list_users(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimUserListResponse> {
+    let users = match query.filter.as_deref() {
+        Some(filter) => {
+            let user_name = parse_eq_filter(filter, "userName")?;
+            let user_email = domain::UserEmail::from_pii_email(
+                common_utils::pii::Email::try_from(user_name)
+                    .change_context(UserErrors::EmailParsingError)?,
+            )?;
+            let user = match state.global_store.find_user_by_email(&user_email).await {
+                Ok(user) => Some(user),
+                Err(error) if error.current_context().is_db_not_found() => None,
+                Err(error) => Err(error.change_context(UserErrors::InternalServerError))?,
+            };
+            // The users who do not have a role in the merchant are not visible to the identity
+            // provider
+            match user {
+                Some(user)
+                    if find_user_role_in_merchant(&state, &scim_config, &user.user_id)
+                        .await?
+                        .is_some() =>
+                {
+                    vec![user]
+                }
+                Some(_) | None => Vec::new(),
+            }
+        }
+        None => {
+            let managed_role_ids = get_managed_role_ids(&scim_config)?;
+            let user_ids = state
+                .global_store
+                .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+                    user_id: None,
+                    tenant_id: &state.tenant.tenant_id,
+                    org_id: &scim_config.org_id,
+                    merchant_id: Some(&scim_config.merchant_id),
+                    profile_id: None,
+                    version: None,
+                    limit: None,
+                })
+                .await
+                .change_context(UserErrors::InternalServerError)?
+                .into_iter()
+                .filter(|user_role| {
+                    managed_role_ids.contains(&user_role.role_id)
+                        && user_role
+                            .profile_id
+                            .as_ref()
+                            .map_or(true, |profile_id| profile_id == &scim_config.profile_id)
+                })
+                .map(|user_role| user_role.user_id)
+                .collect::<HashSet<_>>();
+
+            let mut users = state
+                .global_store
+                .find_users_by_user_ids(user_ids.into_iter().collect())
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            users.sort_by(|user_a, user_b| user_a.created_at.cmp(&user_b.created_at));
+            users
+        }
+    };
+
+    let total_results = users.len();
+    let (start_index, count) = get_pagination(&query);
+    let mut resources = Vec::new();
+    for user in users.into_iter().skip(start_index - 1).take(count) {
+        resources.push(get_scim_user_response(&state, &scim_config, user.into()).await?);
+    }
+
+    Ok(ApplicationResponse::Json(scim_api::ScimListResponse {
+        schemas: vec![scim_api::SCIM_LIST_RESPONSE_SCHEMA],
+        total_results,
+        start_index,
+        items_per_page: resources.len(),
+        resources,
+    }))
+}
+
+pub async fn replace_user(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    user_id: String,
+    req: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let user = find_user(&state, &scim_config, &user_id).await?;
+
+    if let Some(name) = req.get_name() {
+        update_user_name(&state, &scim_config, user.get_user_id(), name).await?;
+    }
+    if let Some(active) = req.active {
+        set_user_active(&state, &scim_config, user.get_user_id(), active).await?;
+    }
+
+    // The user may have been deprovisioned by the request, so the user is not looked up in the
+    // merchant
+    let user = find_platform_user(&state, &user_id).await?;
+    get_scim_user_response(&state, &scim_config, user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Applies the operations on the `active` and name attributes of the user. Operations on the other
+/// attributes are ignored.
+pub async fn patch_user(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    user_id: String,
+    req: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let user = find_user(&state, &scim_config, &user_id).await?;
+
+    for operation in req.operations {
+        if operation.op == scim_api::ScimPatchOp::Remove {
+            continue;
+        }
+        // The attributes are present in the value when the path is absent
+        let attributes = match (operation.path.as_deref(), operation.value) {
+            (Some(path), Some(value)) => vec![(path.to_owned(), value)],
+            (None, Some(serde_json::Value::Object(attributes))) => attributes.into_iter().collect(),
+            _ => Vec::new(),
+        };
+
+        for (path, value) in attributes {
+            if path.eq_ignore_ascii_case(SCIM_ACTIVE_PATH) {
+                let active = parse_bool(&value)?;
+                set_user_active(&state, &scim_config, user.get_user_id(), active).await?;
+            } else if path.eq_ignore_ascii_case("displayName")
+                || path.eq_ignore_ascii_case("name.formatted")
+            {
+                if let Some(name) = value.as_str() {
+                    update_user_name(
+                        &state,
+                        &scim_config,
+                        user.get_user_id(),
+                        Secret::new(name.to_owned()),
+                    )
+                    .await?;
+                }
+            }
+        }
+    }
+
+    // The user may have been deprovisioned by the request, so the user is not looked up in the
+    // merchant
+    let user = find_platform_user(&state, &user_id).await?;
+    get_scim_user_response(&state, &scim_config, user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Deprovisions the user by removing their role in the merchant, if the role is managed by the
+/// SCIM configuration. The user itself is retained, as the user may have access to other
+/// merchants.
+pub async fn delete_user(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    user_id: String,
+) -> UserResponse<()> {
+    let user = find_user(&state, &scim_config, &user_id).await?;
+    set_user_active(&state, &scim_config, user.get_user_id(), false).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_groups(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimGroupListResponse> {
+    let mut group_role_mappings = get_group_role_mappings(&scim_config)?;
+    if let Some(filter) = query.filter.as_deref() {
+        let display_name = parse_eq_filter(filter, "displayName")?;
+        group_role_mappings
+            .retain(|mapping| mapping.group_name.eq_ignore_ascii_case(&display_name));
+    }
+
+    let total_results = group_role_mappings.len();
+    let (start_index, count) = get_pagination(&query);
+    let mut resources = Vec::new();
+    for mapping in group_role_mappings
+        .into_iter()
+        .skip(start_index - 1)
+        .take(count)
+    {
+        resources.push(get_scim_group_response(&state, &scim_config, mapping).await?);
+    }
+
+    Ok(ApplicationResponse::Json(scim_api::ScimListResponse {
+        schemas: vec![scim_api::SCIM_LIST_RESPONSE_SCHEMA],
+        total_results,
+        start_index,
+        items_per_page: resources.len(),
+        resources,
+    }))
+}
+
+pub async fn retrieve_group(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    group_id: String,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let mapping = find_group_role_mapping(&scim_config, &group_id)?;
+
+    get_scim_group_response(&state, &scim_config, mapping)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// The groups are defined by the group role mappings of the SCIM configuration, so only the groups
+/// which are mapped to a role can be pushed by the identity provider
+pub async fn create_group(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    req: scim_api::ScimGroupRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let mapping = get_group_role_mappings(&scim_config)?
+        .into_iter()
+        .find(|mapping| mapping.group_name.eq_ignore_ascii_case(&req.display_name))
+        .ok_or_else(|| {
+            report!(UserErrors::InvalidScimRequest(format!(
+                "Group `{}` is not mapped to a role",
+                req.display_name
+            )))
+        })?;
+
+    for member in &req.members {
+        assign_group_role(&state, &scim_config, &member.value, &mapping.role_id).await?;
+    }
+
+    get_scim_group_response(&state, &scim_config, mapping)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Applies the operations on the members of the group, by assigning the role of the group to the
+/// members added and removing it from the members removed
+pub async fn patch_group(
+    state: SessionState,
+    scim_config: storage::ScimConfig,
+    group_id: String,
+    req: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let mapping = find_group_role_mapping(&scim_config, &group_id)?;
+
+    for operation in req.operations {
+        let path = operation.path.unwrap_or_default();
+        if !path.starts_with(SCIM_MEMBERS_PATH) {
+            logger::info!(scim_patch_path = %path, "Ignoring SCIM group patch operation");
+            continue;
+        }
+        let mut member_ids = operation
+            .value
+            .map(|value| value.parse_value::<Vec<scim_api::ScimMember>>("ScimMembers"))
+            .transpose()
+            .change_context(UserErrors::InvalidScimRequest(
+                "members must be a list of members".to_string(),
+            ))?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|member| member.value)
+            .collect::<Vec<_>>();
+        // A single member to be removed can be specified in the path as `members[value eq "<id>"]`
+        if let Some(member_id) = path
+            .strip_prefix("members[")
+            .and_then(|filter| filter.strip_suffix(']'))
+        {
+            member_ids.push(parse_eq_filter(member_id, "value")?);
+        }
+
+        match operation.op {
+            scim_api::ScimPatchOp::Add => {
+                for member_id in member_ids {
+                    assign_group_role(&state, &scim_config, &member_id, &mapping.role_id).await?;
+                }
+            }
+            scim_api::ScimPatchOp::Remove => {
+                for member_id in member_ids {
+                    remove_group_role(&state, &scim_config, &member_id, &mapping.role_id).await?;
+                }
+            }
+            scim_api::ScimPatchOp::Replace => {
+                let existing_member_ids =
+                    list_group_member_ids(&state, &scim_config, &mapping).await?;
+                for member_id in existing_member_ids
+                    .iter()
+                    .filter(|member_id| !member_ids.contains(member_id))
+                {
+                    remove_group_role(&state, &scim_config, member_id, &mapping.role_id).await?;
+                }
+                for member_id in member_ids {
+                    assign_group_role(&state, &scim_config, &member_id, &mapping.role_id).await?;
+                }
+            }
+        }
+    }
+
+    get_scim_group_response(&state, &scim_config, mapping)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+async fn validate_scim_config_request(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    req: &scim_api::ScimConfigRequest,
+) -> UserResult<()> {
+    let mut group_names = HashSet::new();
+    let mut role_ids = HashSet::new();
+    for mapping in &req.group_role_mappings {
+        if mapping.group_name.trim().is_empty() {
+            return Err(report!(UserErrors::InvalidScimRequest(
+                "group_name cannot be empty".to_string()
+            )));
+        }
+        if !group_names.insert(mapping.group_name.to_lowercase()) {
+            return Err(report!(UserErrors::InvalidScimRequest(format!(
+                "Group `{}` is mapped more than once",
+                mapping.group_name
+            ))));
+        }
+        // The role is used as the id of the group, so that the group of a user can be derived
+        // from their role
+        if !role_ids.insert(mapping.role_id.as_str()) {
+            return Err(report!(UserErrors::InvalidScimRequest(format!(
+                "Role `{}` is mapped to more than one group",
+                mapping.role_id
+            ))));
+        }
+    }
+
+    for role_id in role_ids.into_iter().chain(req.default_role_id.as_deref()) {
+        let role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
+            state,
+            role_id,
+            &user_from_token.merchant_id,
+            &user_from_token.org_id,
+        )
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+        if !role_info.is_invitable() {
+            return Err(report!(UserErrors::InvalidRoleId))
+                .attach_printable(format!("role_id = {role_id} is not invitable"));
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_scim_token(
+    state: &SessionState,
+) -> UserResult<(storage::HashedApiKey, PlaintextApiKey)> {
+    let hash_key = state
+        .conf
+        .api_keys
+        .get_inner()
+        .get_hash_key()
+        .change_context(UserErrors::InternalServerError)?;
+    let token = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+
+    Ok((token.keyed_hash(hash_key.peek()).into(), token))
+}
+
+fn get_scim_config_response(
+    scim_config: storage::ScimConfig,
+    token: Option<PlaintextApiKey>,
+) -> UserResult<scim_api::ScimConfigResponse> {
+    let group_role_mappings = get_group_role_mappings(&scim_config)?;
+
+    Ok(scim_api::ScimConfigResponse {
+        id: scim_config.id,
+        merchant_id: scim_config.merchant_id,
+        profile_id: scim_config.profile_id,
+        group_role_mappings,
+        default_role_id: scim_config.default_role_id,
+        token_prefix: scim_config.token_prefix,
+        token: token.map(|token| Secret::new(token.peek().to_owned())),
+        created_at: scim_config.created_at,
+        last_modified_at: scim_config.last_modified_at,
+    })
+}
+
+fn get_group_role_mappings(
+    scim_config: &storage::ScimConfig,
+) -> UserResult<Vec<scim_api::ScimGroupRoleMapping>> {
+    scim_config
+        .group_role_mappings
+        .clone()
+        .parse_value("ScimGroupRoleMappings")
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse SCIM group role mappings")
+}
+
+/// The roles which are assigned and removed by the SCIM configuration. The other roles of the
+/// users are never modified by the identity provider.
+fn get_managed_role_ids(scim_config: &storage::ScimConfig) -> UserResult<HashSet<String>> {
+    Ok(get_group_role_mappings(scim_config)?
+        .into_iter()
+        .map(|mapping| mapping.role_id)
+        .chain(scim_config.default_role_id.clone())
+        .collect())
+}
+
+fn find_group_role_mapping(
+    scim_config: &storage::ScimConfig,
+    group_id: &str,
+) -> UserResult<scim_api::ScimGroupRoleMapping> {
+    get_group_role_mappings(scim_config)?
+        .into_iter()
+        .find(|mapping| mapping.role_id == group_id)
+        .ok_or_else(|| report!(UserErrors::ScimResourceNotFound))
+}
+
+/// Finds the user, if the user has a role in the merchant of the SCIM configuration. The users of
+/// the other merchants are not visible to the identity provider.
+async fn find_user(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+) -> UserResult<domain::UserFromStorage> {
+    let user = find_platform_user(state, user_id).await?;
+    if find_user_role_in_merchant(state, scim_config, user.get_user_id())
+        .await?
+        .is_none()
+    {
+        return Err(report!(UserErrors::ScimResourceNotFound));
+    }
+
+    Ok(user)
+}
+
+/// Finds the user irrespective of their roles, for assigning a role in the merchant to the user
+async fn find_platform_user(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<domain::UserFromStorage> {
+    state
+        .global_store
+        .find_user_by_id(user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)
+        .map(domain::UserFromStorage::from)
+}
+
+/// The user record is shared across the merchants of the user, so the name is only updated for
+/// the users whose roles are all in the merchant of the SCIM configuration
+async fn update_user_name(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+    name: Secret<String>,
+) -> UserResult<()> {
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let is_user_of_merchant_only = user_roles.iter().all(|user_role| {
+        user_role.org_id.as_ref() == Some(&scim_config.org_id)
+            && user_role.merchant_id.as_ref() == Some(&scim_config.merchant_id)
+    });
+    if !is_user_of_merchant_only {
+        logger::info!("Retaining the name of the user as the user has roles in other entities");
+        return Ok(());
+    }
+
+    let name = domain::UserName::new(name)?;
+    state
+        .global_store
+        .update_user_by_user_id(
+            user_id,
+            storage_user::UserUpdate::AccountUpdate {
+                name: Some(name.get_secret().expose()),
+                is_verified: None,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(())
+}
+
+async fn find_user_role_in_merchant(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+) -> UserResult<Option<storage::UserRole>> {
+    for version in [UserRoleVersion::V2, UserRoleVersion::V1] {
+        match state
+            .global_store
+            .find_user_role_by_user_id_and_lineage(
+                user_id,
+                &state.tenant.tenant_id,
+                &scim_config.org_id,
+                &scim_config.merchant_id,
+                &scim_config.profile_id,
+                version,
+            )
+            .await
+        {
+            Ok(user_role) => return Ok(Some(user_role)),
+            Err(error) if error.current_context().is_db_not_found() => continue,
+            Err(error) => Err(error.change_context(UserErrors::InternalServerError))?,
+        }
+    }
+
+    Ok(None)
+}
+
+async fn set_user_active(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+    active: bool,
+) -> UserResult<()> {
+    if active {
+        return activate_user(state, scim_config, user_id).await;
+    }
+
+    let managed_role_ids = get_managed_role_ids(scim_config)?;
+    match find_user_role_in_merchant(state, scim_config, user_id).await? {
+        Some(user_role) if managed_role_ids.contains(&user_role.role_id) => {
+            delete_user_role(state, scim_config, user_id).await
+        }
+        Some(user_role) => {
+            logger::info!(
+                role_id = %user_role.role_id,
+                "Retaining the role of the user as it is not managed by SCIM"
+            );
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Assigns the default role to the user, unless the user already has a role in the merchant
+async fn activate_user(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+) -> UserResult<()> {
+    let Some(default_role_id) = scim_config.default_role_id.as_deref() else {
+        return Ok(());
+    };
+    if find_user_role_in_merchant(state, scim_config, user_id)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    insert_user_role(state, scim_config, user_id, default_role_id).await
+}
+
+/// A user has a single role in the merchant, so assigning the role of a group to the user replaces
+/// the role of any other group the user is a member of
+async fn assign_group_role(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+    role_id: &str,
+) -> UserResult<()> {
+    let user = find_platform_user(state, user_id).await?;
+    let managed_role_ids = get_managed_role_ids(scim_config)?;
+
+    match find_user_role_in_merchant(state, scim_config, user.get_user_id()).await? {
+        Some(user_role) if user_role.role_id == role_id => return Ok(()),
+        Some(user_role) if managed_role_ids.contains(&user_role.role_id) => {
+            delete_user_role(state, scim_config, user.get_user_id()).await?;
+        }
+        Some(user_role) => {
+            return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+                "User has a role which is not managed by SCIM".to_string()
+            )))
+            .attach_printable(format!(
+                "role_id = {} is not managed by SCIM",
+                user_role.role_id
+            ));
+        }
+        None => {}
+    }
+
+    insert_user_role(state, scim_config, user.get_user_id(), role_id).await
+}
+
+/// Removes the role of the group from the user, after which the user is assigned the default role
+/// if configured
+async fn remove_group_role(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+    role_id: &str,
+) -> UserResult<()> {
+    match find_user_role_in_merchant(state, scim_config, user_id).await? {
+        Some(user_role) if user_role.role_id == role_id => {
+            delete_user_role(state, scim_config, user_id).await?;
+            activate_user(state, scim_config, user_id).await
+        }
+        _ => Ok(()),
+    }
+}
+
+async fn insert_user_role(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+    role_id: &str,
+) -> UserResult<()> {
+    let role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
+        state,
+        role_id,
+        &scim_config.merchant_id,
+        &scim_config.org_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    let now = common_utils::date_time::now();
+    let user_role = domain::NewUserRole {
+        user_id: user_id.to_owned(),
+        role_id: role_id.to_owned(),
+        status: UserStatus::Active,
+        created_by: scim_config.id.clone(),
+        last_modified_by: scim_config.id.clone(),
+        created_at: now,
+        last_modified: now,
+        entity: domain::NoLevel,
+    };
+
+    match role_info.get_entity_type() {
+        EntityType::Organization => {
+            user_role
+                .add_entity(domain::OrganizationLevel {
+                    tenant_id: state.tenant.tenant_id.clone(),
+                    org_id: scim_config.org_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?
+        }
+        EntityType::Merchant => {
+            user_role
+                .add_entity(domain::MerchantLevel {
+                    tenant_id: state.tenant.tenant_id.clone(),
+                    org_id: scim_config.org_id.clone(),
+                    merchant_id: scim_config.merchant_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?
+        }
+        EntityType::Profile => {
+            user_role
+                .add_entity(domain::ProfileLevel {
+                    tenant_id: state.tenant.tenant_id.clone(),
+                    org_id: scim_config.org_id.clone(),
+                    merchant_id: scim_config.merchant_id.clone(),
+                    profile_id: scim_config.profile_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?
+        }
+    };
+
+    Ok(())
+}
+
+async fn delete_user_role(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user_id: &str,
+) -> UserResult<()> {
+    for version in [UserRoleVersion::V2, UserRoleVersion::V1] {
+        match state
+            .global_store
+            .delete_user_role_by_user_id_and_lineage(
+                user_id,
+                &state.tenant.tenant_id,
+                &scim_config.org_id,
+                &scim_config.merchant_id,
+                &scim_config.profile_id,
+                version,
+            )
+            .await
+        {
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error.change_context(UserErrors::InternalServerError))
+                .attach_printable("Error while deleting user role")?,
+        }
+    }
+
+    Ok(())
+}
+
+async fn list_group_member_ids(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    mapping: &scim_api::ScimGroupRoleMapping,
+) -> UserResult<Vec<String>> {
+    let member_ids = state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: None,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: &scim_config.org_id,
+            merchant_id: None,
+            profile_id: None,
+            version: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .filter(|user_role| {
+            user_role.role_id == mapping.role_id
+                && user_role
+                    .merchant_id
+                    .as_ref()
+                    .map_or(true, |merchant_id| merchant_id == &scim_config.merchant_id)
+                && user_role
+                    .profile_id
+                    .as_ref()
+                    .map_or(true, |profile_id| profile_id == &scim_config.profile_id)
+        })
+        .map(|user_role| user_role.user_id)
+        .collect::<HashSet<_>>();
+
+    Ok(member_ids.into_iter().collect())
+}
+
+async fn get_scim_user_response(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    user: domain::UserFromStorage,
+) -> UserResult<scim_api::ScimUserResponse> {
+    let user_role = find_user_role_in_merchant(state, scim_config, user.get_user_id()).await?;
+    let groups = user_role
+        .as_ref()
+        .map(|user_role| {
+            get_group_role_mappings(scim_config).map(|mappings| {
+                mappings
+                    .into_iter()
+                    .filter(|mapping| mapping.role_id == user_role.role_id)
+                    .map(|mapping| scim_api::ScimMember {
+                        value: mapping.role_id,
+                        display: Some(mapping.group_name),
+                    })
+                    .collect()
+            })
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(scim_api::ScimUserResponse {
+        schemas: vec![scim_api::SCIM_USER_SCHEMA],
+        id: user.get_user_id().to_owned(),
+        user_name: user.get_email(),
+        name: scim_api::ScimName {
+            formatted: Some(user.get_name()),
+            given_name: None,
+            family_name: None,
+        },
+        display_name: user.get_name(),
+        emails: vec![scim_api::ScimEmail {
+            value: user.get_email(),
+            primary: true,
+        }],
+        active: user_role.is_some(),
+        groups,
+        meta: scim_api::ScimMeta {
+            resource_type: SCIM_USER_RESOURCE_TYPE,
+        },
+    })
+}
+
+async fn get_scim_group_response(
+    state: &SessionState,
+    scim_config: &storage::ScimConfig,
+    mapping: scim_api::ScimGroupRoleMapping,
+) -> UserResult<scim_api::ScimGroupResponse> {
+    let member_ids = list_group_member_ids(state, scim_config, &mapping).await?;
+    let members = state
+        .global_store
+        .find_users_by_user_ids(member_ids)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|user| scim_api::ScimMember {
+            value: user.user_id,
+            display: Some(user.email.peek().to_owned()),
+        })
+        .collect();
+
+    Ok(scim_api::ScimGroupResponse {
+        schemas: vec![scim_api::SCIM_GROUP_SCHEMA],
+        id: mapping.role_id,
+        display_name: mapping.group_name,
+        members,
+        meta: scim_api::ScimMeta {
+            resource_type: SCIM_GROUP_RESOURCE_TYPE,
+        },
+    })
+}
+
+/// Parses the value of a filter of the form `<attribute> eq "<value>"`
+fn parse_eq_filter(filter: &str, attribute: &str) -> UserResult<String> {
+    let mut filter_parts = filter.trim().splitn(3, ' ');
+    match (
+        filter_parts.next(),
+        filter_parts.next(),
+        filter_parts.next(),
+    ) {
+        (Some(filter_attribute), Some(operator), Some(value))
+            if filter_attribute.eq_ignore_ascii_case(attribute)
+                && operator.eq_ignore_ascii_case("eq") =>
+        {
+            Ok(value.trim().trim_matches('"').to_owned())
+        }
+        _ => Err(report!(UserErrors::InvalidScimRequest(format!(
+            "Only the filter `{attribute} eq \"<value>\"` is supported"
+        )))),
+    }
+}
+
+/// Some identity providers send booleans as strings
+fn parse_bool(value: &serde_json::Value) -> UserResult<bool> {
+    match value {
+        serde_json::Value::Bool(value) => Ok(*value),
+        serde_json::Value::String(value) if value.eq_ignore_ascii_case("true") => Ok(true),
+        serde_json::Value::String(value) if value.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(report!(UserErrors::InvalidScimRequest(
+            "active must be a boolean".to_string()
+        ))),
+    }
+}
+
+/// The SCIM start index is 1-based
+fn get_pagination(query: &scim_api::ScimListQuery) -> (usize, usize) {
+    (
+        query.start_index.unwrap_or(1).max(1),
+        query.count.unwrap_or(DEFAULT_SCIM_PAGE_SIZE),
+    )
+}
@@ -0,0 +1,382 @@
+use api_models::user as user_api;
+use base64::Engine;
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::{enums::TotpStatus, user::webauthn_credential as storage_webauthn};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::logger;
+use webauthn_rs::prelude::{Passkey, PublicKeyCredential, RegisterPublicKeyCredential};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResponse, UserResult},
+    routes::SessionState,
+    services::{authentication as auth, ApplicationResponse},
+    types::domain,
+    utils::{self, user::two_factor_auth as tfa_utils},
+};
+
+const WEBAUTHN_CREDENTIAL_ID_PREFIX: &str = "wac";
+
+pub async fn begin_webauthn_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::WebauthnChallengeResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    // Adding a passkey to an account which already has two factor authentication setup should
+    // not be possible without completing the two factor authentication
+    if user_from_db.get_totp_status() == TotpStatus::Set
+        && !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await?
+    {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    let existing_credential_ids = get_passkeys(&state, &user_token.user_id)
+        .await?
+        .iter()
+        .map(|passkey| passkey.cred_id().clone())
+        .collect::<Vec<_>>();
+
+    let user_unique_id = uuid::Uuid::parse_str(user_from_db.get_user_id())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse user id as uuid")?;
+    let email = user_from_db.get_email();
+    let email = email.peek();
+
+    let (challenge, registration) = tfa_utils::get_webauthn(&state)?
+        .start_passkey_registration(
+            user_unique_id,
+            email,
+            user_from_db.get_name().peek(),
+            Some(existing_credential_ids),
+        )
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey registration")?;
+
+    tfa_utils::insert_webauthn_registration_in_redis(&state, &user_token.user_id, &registration)
+        .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::WebauthnChallengeResponse {
+            options: challenge
+                .encode_to_value()
+                .change_context(UserErrors::InternalServerError)?,
+        },
+    ))
+}
+
+pub async fn finish_webauthn_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::WebauthnRegistrationRequest,
+) -> UserResponse<()> {
+    let name = req.name.trim();
+    if name.is_empty() || name.len() > consts::user::MAX_WEBAUTHN_CREDENTIAL_NAME_LENGTH {
+        return Err(
+            report!(UserErrors::InvalidWebauthnCredential).attach_printable("Invalid passkey name")
+        );
+    }
+
+    let credential: RegisterPublicKeyCredential = req
+        .credential
+        .parse_value("RegisterPublicKeyCredential")
+        .change_context(UserErrors::InvalidWebauthnCredential)?;
+
+    let registration =
+        tfa_utils::get_webauthn_registration_from_redis(&state, &user_token.user_id).await?;
+
+    let passkey = tfa_utils::get_webauthn(&state)?
+        .finish_passkey_registration(&credential, &registration)
+        .map_err(|error| {
+            report!(UserErrors::InvalidWebauthnCredential)
+                .attach_printable(format!("Failed to finish passkey registration: {error}"))
+        })?;
+
+    state
+        .global_store
+        .insert_user_webauthn_credential(storage_webauthn::UserWebauthnCredentialNew {
+            id: utils::generate_id(consts::ID_LENGTH, WEBAUTHN_CREDENTIAL_ID_PREFIX),
+            user_id: user_token.user_id.clone(),
+            credential_id: get_credential_id(&passkey),
+            name: name.to_string(),
+            credential: passkey
+                .encode_to_value()
+                .change_context(UserErrors::InternalServerError)?,
+            created_at: common_utils::date_time::now(),
+            last_used_at: None,
+        })
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(UserErrors::InvalidWebauthnCredential)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    let _ = tfa_utils::delete_webauthn_ceremonies_from_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    // Registering the passkey proves the possession of the authenticator, so the user is not
+    // asked to authenticate again in the same session
+    let _ = tfa_utils::insert_webauthn_in_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_webauthn_authentication(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::WebauthnChallengeResponse> {
+    let passkeys = get_passkeys(&state, &user_token.user_id).await?;
+    if passkeys.is_empty() {
+        return Err(UserErrors::WebauthnNotSetup.into());
+    }
+
+    let (challenge, authentication) = tfa_utils::get_webauthn(&state)?
+        .start_passkey_authentication(&passkeys)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey authentication")?;
+
+    tfa_utils::insert_webauthn_authentication_in_redis(
+        &state,
+        &user_token.user_id,
+        &authentication,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::WebauthnChallengeResponse {
+            options: challenge
+                .encode_to_value()
+                .change_context(UserErrors::InternalServerError)?,
+        },
+    ))
+}
+
+pub async fn verify_webauthn(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::WebauthnAuthenticationRequest,
+) -> UserResponse<()> {
+    let user_webauthn_attempts =
+        tfa_utils::get_webauthn_attempts_from_redis(&state, &user_token.user_id).await?;
+
+    if user_webauthn_attempts >= consts::user::WEBAUTHN_MAX_ATTEMPTS {
+        return Err(UserErrors::MaxWebauthnAttemptsReached.into());
+    }
+
+    let credential: PublicKeyCredential = req
+        .credential
+        .parse_value("PublicKeyCredential")
+        .change_context(UserErrors::InvalidWebauthnCredential)?;
+
+    let authentication =
+        tfa_utils::get_webauthn_authentication_from_redis(&state, &user_token.user_id).await?;
+
+    let authentication_result = match tfa_utils::get_webauthn(&state)?
+        .finish_passkey_authentication(&credential, &authentication)
+    {
+        Ok(authentication_result) => authentication_result,
+        Err(error) => {
+            let _ = tfa_utils::insert_webauthn_attempts_in_redis(
+                &state,
+                &user_token.user_id,
+                user_webauthn_attempts + 1,
+            )
+            .await
+            .inspect_err(|error| logger::error!(?error));
+            return Err(report!(UserErrors::InvalidWebauthnCredential)
+                .attach_printable(format!("Failed to verify passkey: {error}")));
+        }
+    };
+
+    let _ = tfa_utils::delete_webauthn_ceremonies_from_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    let stored_credentials = state
+        .global_store
+        .list_user_webauthn_credentials_by_user_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    for stored_credential in stored_credentials {
+        let mut passkey: Passkey = stored_credential
+            .credential
+            .parse_value("Passkey")
+            .change_context(UserErrors::InternalServerError)?;
+
+        // The credential is persisted on every use to record the usage, and the signature
+        // counter if it has changed
+        if passkey.update_credential(&authentication_result).is_some() {
+            state
+                .global_store
+                .update_user_webauthn_credential(
+                    &user_token.user_id,
+                    &stored_credential.id,
+                    storage_webauthn::UserWebauthnCredentialUpdate::CredentialUsed {
+                        credential: passkey
+                            .encode_to_value()
+                            .change_context(UserErrors::InternalServerError)?,
+                    },
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            break;
+        }
+    }
+
+    tfa_utils::insert_webauthn_in_redis(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_webauthn_credentials(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_api::WebauthnCredentialResponse>> {
+    let credentials = state
+        .global_store
+        .list_user_webauthn_credentials_by_user_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(
+        credentials
+            .into_iter()
+            .map(|credential| user_api::WebauthnCredentialResponse {
+                id: credential.id,
+                name: credential.name,
+                created_at: credential.created_at,
+                last_used_at: credential.last_used_at,
+            })
+            .collect(),
+    ))
+}
+
+pub async fn delete_webauthn_credential(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    req: user_api::WebauthnCredentialId,
+) -> UserResponse<()> {
+    if !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await? {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let credentials = state
+        .global_store
+        .list_user_webauthn_credentials_by_user_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if !credentials.iter().any(|credential| credential.id == req.id) {
+        return Err(UserErrors::WebauthnCredentialNotFound.into());
+    }
+
+    // The last passkey cannot be deleted if it is the only second factor of the user
+    if credentials.len() == 1
+        && user_from_db.get_totp_status() == TotpStatus::Set
+        && user_from_db
+            .decrypt_and_get_totp_secret(&state)
+            .await?
+            .is_none()
+    {
+        return Err(report!(UserErrors::InvalidDeleteOperation)
+            .attach_printable("Cannot delete the only second factor of the user"));
+    }
+
+    let is_deleted = state
+        .global_store
+        .delete_user_webauthn_credential(&user_token.user_id, &req.id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if !is_deleted {
+        return Err(UserErrors::WebauthnCredentialNotFound.into());
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Removes all the second factors of the user, so that the user is asked to setup two factor
+/// authentication again on the next sign in
+pub async fn reset_two_factor_auth(
+    state: SessionState,
+    req: user_api::ResetTwoFactorAuthRequest,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&domain::UserEmail::from_pii_email(req.email)?)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(UserErrors::UserNotFound)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })?
+        .into();
+    let user_id = user_from_db.get_user_id();
+
+    state
+        .global_store
+        .update_user_by_user_id(user_id, diesel_models::user::UserUpdate::TwoFactorAuthReset)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    state
+        .global_store
+        .delete_user_webauthn_credentials_by_user_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    tfa_utils::delete_totp_from_redis(&state, user_id).await?;
+    tfa_utils::delete_recovery_code_from_redis(&state, user_id).await?;
+    tfa_utils::delete_webauthn_from_redis(&state, user_id).await?;
+    tfa_utils::delete_totp_secret_from_redis(&state, user_id).await?;
+    tfa_utils::delete_webauthn_ceremonies_from_redis(&state, user_id).await?;
+
+    // The existing sessions of the user were authenticated with the removed second factors
+    let _ = auth::blacklist::insert_user_in_blacklist(&state, user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+async fn get_passkeys(state: &SessionState, user_id: &str) -> UserResult<Vec<Passkey>> {
+    state
+        .global_store
+        .list_user_webauthn_credentials_by_user_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|credential| {
+            credential
+                .credential
+                .parse_value("Passkey")
+                .change_context(UserErrors::InternalServerError)
+        })
+        .collect()
+}
+
+fn get_credential_id(passkey: &Passkey) -> String {
+    common_utils::consts::BASE64_ENGINE_URL_SAFE.encode(passkey.cred_id())
+}
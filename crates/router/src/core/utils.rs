@@ -49,6 +49,8 @@ pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DISPUTE_FLOW: &str =
 #[cfg(feature = "payouts")]
 pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_PAYOUTS_FLOW: &str =
     "irrelevant_connector_request_reference_id_in_payouts_flow";
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_BANK_LIST_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_bank_list_flow";
 const IRRELEVANT_ATTEMPT_ID_IN_DISPUTE_FLOW: &str = "irrelevant_attempt_id_in_dispute_flow";
 
 #[cfg(all(feature = "payouts", feature = "v2", feature = "customer_v2"))]
@@ -221,6 +223,73 @@ pub async fn construct_payout_router_data<'a, F>(
     Ok(router_data)
 }
 
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub fn construct_payout_balance_router_data(
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    currency: Option<enums::Currency>,
+) -> RouterResult<types::PayoutBalanceRouterData> {
+    let connector_auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.get_id().to_owned(),
+        customer_id: None,
+        connector_customer: None,
+        connector: connector_name.to_string(),
+        payment_id: common_utils::id_type::PaymentId::get_irrelevant_id("payout")
+            .get_string_repr()
+            .to_owned(),
+        attempt_id: "".to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        amount_captured: None,
+        minor_amount_captured: None,
+        payment_method_status: None,
+        request: types::PayoutBalanceRequestData { currency },
+        response: Err(ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        connector_request_reference_id: IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_PAYOUTS_FLOW
+            .to_string(),
+        payout_method_data: None,
+        quote_id: None,
+        test_mode: merchant_connector_account.is_test_mode_on(),
+        payment_method_balance: None,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+        psd2_sca_exemption_type: None,
+    };
+
+    Ok(router_data)
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
@@ -326,6 +395,27 @@ pub async fn construct_refund_router_data<'a, F>(
 
     let connector_refund_id = refund.get_optional_connector_refund_id().cloned();
 
+    // Refunds against a payment captured in multiple parts are routed to the capture they were
+    // initiated against
+    let connector_transaction_id = match refund.capture_id.as_ref() {
+        Some(capture_id) => state
+            .store
+            .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                &payment_attempt.merchant_id,
+                &payment_attempt.payment_id,
+                &payment_attempt.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching captures of the payment attempt")?
+            .iter()
+            .find(|capture| &capture.capture_id == capture_id)
+            .and_then(|capture| capture.get_optional_connector_transaction_id().cloned()),
+        None => None,
+    }
+    .unwrap_or_else(|| refund.get_connector_transaction_id().clone());
+
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.get_id().clone(),
@@ -350,7 +440,7 @@ pub async fn construct_refund_router_data<'a, F>(
         minor_amount_captured: payment_intent.amount_captured,
         request: types::RefundsData {
             refund_id: refund.refund_id.clone(),
-            connector_transaction_id: refund.get_connector_transaction_id().clone(),
+            connector_transaction_id,
             refund_amount: refund.refund_amount.get_amount_as_i64(),
             minor_refund_amount: refund.refund_amount,
             currency,
@@ -364,6 +454,25 @@ pub async fn construct_refund_router_data<'a, F>(
             charges,
             integrity_object: None,
             refund_status: refund.refund_status,
+            line_items: refund.line_items.clone().map(|line_items| {
+                line_items
+                    .0
+                    .into_iter()
+                    .map(|line_item| OrderDetailsWithAmount {
+                        product_name: line_item.product_name,
+                        quantity: line_item.quantity,
+                        amount: line_item.amount,
+                        requires_shipping: None,
+                        product_img_link: None,
+                        product_id: line_item.product_id,
+                        category: None,
+                        sub_category: None,
+                        brand: None,
+                        product_type: None,
+                        product_tax_code: None,
+                    })
+                    .collect()
+            }),
         },
 
         response: Ok(types::RefundsResponseData {
@@ -423,6 +532,104 @@ pub fn get_or_generate_uuid(
         .map_or(Ok(generate_uuid()), validate_id)
 }
 
+#[cfg(feature = "v1")]
+pub fn get_or_generate_refund_id(
+    merchant_account: &domain::MerchantAccount,
+    provided_id: &Option<String>,
+) -> Result<String, errors::ApiErrorResponse> {
+    let prefix = merchant_account
+        .get_id_prefixes()
+        .and_then(|id_prefixes| id_prefixes.refund_id)
+        .unwrap_or_else(|| "ref".to_string());
+    get_or_generate_id("refund_id", provided_id, &prefix)
+}
+
+pub fn get_or_generate_payout_id(
+    merchant_account: &domain::MerchantAccount,
+    provided_id: Option<&String>,
+) -> Result<String, errors::ApiErrorResponse> {
+    match (
+        provided_id,
+        merchant_account
+            .get_id_prefixes()
+            .and_then(|id_prefixes| id_prefixes.payout_id),
+    ) {
+        (None, Some(prefix)) => Ok(format!("{prefix}_{}", generate_uuid())),
+        (provided_id, _) => get_or_generate_uuid("payout_id", provided_id),
+    }
+}
+
+/// The prefixes are restricted to alphanumeric characters so that the `_` separating the prefix
+/// from the generated part of the id is unambiguous, and in length so that the generated ids stay
+/// within `MAX_ID_LENGTH` without truncating the randomly generated part
+#[cfg(feature = "v1")]
+pub fn validate_merchant_id_prefixes(
+    id_prefixes: &api_models::admin::MerchantIdPrefixes,
+) -> Result<(), errors::ApiErrorResponse> {
+    let prefixes = [
+        ("id_prefixes.payment_id", id_prefixes.payment_id.as_ref()),
+        ("id_prefixes.refund_id", id_prefixes.refund_id.as_ref()),
+        ("id_prefixes.payout_id", id_prefixes.payout_id.as_ref()),
+    ];
+
+    for (field_name, prefix) in prefixes {
+        let Some(prefix) = prefix else {
+            continue;
+        };
+        if prefix.is_empty()
+            || prefix.len() > consts::MAX_ID_PREFIX_LENGTH
+            || !prefix.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: field_name.to_string(),
+                expected_format: format!(
+                    "alphanumeric characters with length between 1 and {}",
+                    consts::MAX_ID_PREFIX_LENGTH
+                ),
+            });
+        }
+    }
+
+    if id_prefixes.payment_id.is_some() && id_prefixes.payment_id == id_prefixes.refund_id {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "id_prefixes.payment_id and id_prefixes.refund_id must be different"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that the retention periods configured for the merchant are non-zero, since a zero
+/// retention period would purge the data of a class as soon as it is stored
+#[cfg(feature = "v1")]
+pub fn validate_merchant_data_retention_policy(
+    data_retention_policy: &api_models::admin::MerchantDataRetentionPolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    let retention_periods = [
+        (
+            "data_retention_policy.pii_retention_period_in_days",
+            data_retention_policy.pii_retention_period_in_days,
+        ),
+        (
+            "data_retention_policy.payment_metadata_retention_period_in_days",
+            data_retention_policy.payment_metadata_retention_period_in_days,
+        ),
+        (
+            "data_retention_policy.logs_retention_period_in_days",
+            data_retention_policy.logs_retention_period_in_days,
+        ),
+    ];
+
+    for (field_name, retention_period) in retention_periods {
+        if retention_period == Some(0) {
+            return Err(errors::ApiErrorResponse::InvalidDataValue { field_name });
+        }
+    }
+
+    Ok(())
+}
+
 fn invalid_id_format_error(key: &str) -> errors::ApiErrorResponse {
     errors::ApiErrorResponse::InvalidDataFormat {
         field_name: key.to_string(),
@@ -472,6 +679,36 @@ mod tests {
         assert_eq!(result, payment_id);
     }
 
+    #[cfg(feature = "v1")]
+    #[test]
+    fn validate_merchant_id_prefixes_constraints() {
+        let valid = api_models::admin::MerchantIdPrefixes {
+            payment_id: Some("acme".to_string()),
+            refund_id: Some("acmeref".to_string()),
+            payout_id: None,
+        };
+        assert!(validate_merchant_id_prefixes(&valid).is_ok());
+
+        let invalid_characters = api_models::admin::MerchantIdPrefixes {
+            payment_id: Some("acme_pay".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_merchant_id_prefixes(&invalid_characters).is_err());
+
+        let too_long = api_models::admin::MerchantIdPrefixes {
+            payout_id: Some("a".repeat(consts::MAX_ID_PREFIX_LENGTH + 1)),
+            ..Default::default()
+        };
+        assert!(validate_merchant_id_prefixes(&too_long).is_err());
+
+        let same_prefixes = api_models::admin::MerchantIdPrefixes {
+            payment_id: Some("acme".to_string()),
+            refund_id: Some("acme".to_string()),
+            payout_id: None,
+        };
+        assert!(validate_merchant_id_prefixes(&same_prefixes).is_err());
+    }
+
     #[test]
     fn test_generate_id() {
         let generated_id = generate_id(consts::ID_LENGTH, "ref");
@@ -549,6 +786,54 @@ mod tests {
         ];
         assert_eq!(filtered_list, expected_result);
     }
+
+    #[test]
+    fn test_filter_objects_based_on_tag() {
+        #[derive(PartialEq, Debug, Clone)]
+        struct Object {
+            tags: Option<Vec<String>>,
+        }
+
+        impl Object {
+            pub fn new(tags: Option<&[&str]>) -> Self {
+                Self {
+                    tags: tags.map(|tags| tags.iter().map(|tag| tag.to_string()).collect()),
+                }
+            }
+        }
+
+        impl GetTags for Object {
+            fn get_tags(&self) -> Option<&Vec<String>> {
+                self.tags.as_ref()
+            }
+        }
+
+        let object_list = vec![
+            Object::new(Some(&["high_risk", "eu"])),
+            Object::new(Some(&["eu"])),
+            Object::new(Some(&[])),
+            Object::new(None),
+        ];
+
+        // objects tagged with the tag are retained
+        let filtered_list =
+            filter_objects_based_on_tag(Some("eu".to_string()), object_list.clone());
+        let expected_result = vec![
+            Object::new(Some(&["high_risk", "eu"])),
+            Object::new(Some(&["eu"])),
+        ];
+        assert_eq!(filtered_list, expected_result);
+
+        // no objects are tagged with the tag
+        let filtered_list =
+            filter_objects_based_on_tag(Some("us".to_string()), object_list.clone());
+        let expected_result = vec![];
+        assert_eq!(filtered_list, expected_result);
+
+        // all objects are retained without a tag
+        let filtered_list = filter_objects_based_on_tag(None, object_list.clone());
+        assert_eq!(filtered_list, object_list);
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration
@@ -1230,6 +1515,76 @@ pub async fn construct_retrieve_file_router_data<'a>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub fn construct_bank_list_router_data(
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    payment_method_type: enums::PaymentMethodType,
+) -> RouterResult<types::BankListRouterData> {
+    let connector_auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.get_id().to_owned(),
+        customer_id: None,
+        connector_customer: None,
+        connector: connector_name.to_string(),
+        payment_id: common_utils::id_type::PaymentId::get_irrelevant_id("bank_list")
+            .get_string_repr()
+            .to_owned(),
+        attempt_id: "".to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::BankRedirect,
+        connector_auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        amount_captured: None,
+        minor_amount_captured: None,
+        payment_method_status: None,
+        request: types::BankListRequestData {
+            payment_method_type,
+        },
+        response: Err(ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_request_reference_id: IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_BANK_LIST_FLOW
+            .to_string(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.is_test_mode_on(),
+        connector_api_version: None,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+        psd2_sca_exemption_type: None,
+    };
+
+    Ok(router_data)
+}
+
 pub fn is_merchant_enabled_for_payment_id_as_connector_request_id(
     conf: &Settings,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -1637,6 +1992,37 @@ pub(super) fn filter_objects_based_on_profile_id_list<T: GetProfileId>(
     }
 }
 
+pub(crate) trait GetTags {
+    fn get_tags(&self) -> Option<&Vec<String>>;
+}
+
+impl GetTags for MerchantConnectorAccount {
+    fn get_tags(&self) -> Option<&Vec<String>> {
+        self.tags.as_ref()
+    }
+}
+
+impl GetTags for domain::Profile {
+    fn get_tags(&self) -> Option<&Vec<String>> {
+        self.tags.as_ref()
+    }
+}
+
+/// Filter Objects based on a tag, objects are retained only if they have been tagged with it
+pub(super) fn filter_objects_based_on_tag<T: GetTags>(
+    tag: Option<String>,
+    object_list: Vec<T>,
+) -> Vec<T> {
+    if let Some(tag) = tag {
+        object_list
+            .into_iter()
+            .filter(|item| item.get_tags().is_some_and(|tags| tags.contains(&tag)))
+            .collect()
+    } else {
+        object_list
+    }
+}
+
 pub(crate) fn validate_profile_id_from_auth_layer<T: GetProfileId + std::fmt::Debug>(
     profile_id_auth_layer: Option<common_utils::id_type::ProfileId>,
     object: &T,
@@ -1661,3 +2047,91 @@ pub(crate) fn validate_profile_id_from_auth_layer<T: GetProfileId + std::fmt::De
         (None, None) | (None, Some(_)) => Ok(()),
     }
 }
+
+pub(crate) fn validate_expandable_objects(
+    expand: Option<&[api_models::enums::ExpandableObject]>,
+    supported_objects: &[api_models::enums::ExpandableObject],
+) -> RouterResult<()> {
+    expand
+        .unwrap_or_default()
+        .iter()
+        .find(|object| !supported_objects.contains(object))
+        .map_or(Ok(()), |object| {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Expanding `{object}` is not supported for this resource"),
+            }
+            .into())
+        })
+}
+
+/// Parses the order details of the payment, which the line items refunded or shipped refer to
+#[cfg(feature = "v1")]
+pub(crate) fn get_order_details_of_line_items(
+    payment_intent: &storage::PaymentIntent,
+    action: &str,
+) -> RouterResult<Vec<api_models::payments::OrderDetailsWithAmount>> {
+    payment_intent
+        .order_details
+        .as_ref()
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "line_items can only be {action} for payments created with order_details"
+            ),
+        }))?
+        .iter()
+        .map(|order_detail| {
+            order_detail
+                .to_owned()
+                .parse_value("OrderDetailsWithAmount")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse order details of the payment intent")
+        })
+        .collect()
+}
+
+/// The reason a requested line item could not be resolved to an order detail of the payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineItemResolutionError {
+    /// No order detail has the product of the line item
+    NotFound,
+    /// Several order details have the product name of the line item, which does not pass a
+    /// product id to tell them apart
+    Ambiguous,
+}
+
+/// Finds the order detail which a requested line item refers to, by the product name and, if
+/// passed, the product id of the line item. The product id is required when several order details
+/// have the product name, so that a line item is never resolved to an arbitrary order detail.
+#[cfg(feature = "v1")]
+pub(crate) fn find_order_detail_of_line_item<'a>(
+    order_details: &'a [api_models::payments::OrderDetailsWithAmount],
+    product_name: &str,
+    product_id: Option<&str>,
+) -> Result<&'a api_models::payments::OrderDetailsWithAmount, LineItemResolutionError> {
+    let mut matching_order_details = order_details.iter().filter(|order_detail| {
+        order_detail.product_name == product_name
+            && product_id.map_or(true, |product_id| {
+                order_detail.product_id.as_deref() == Some(product_id)
+            })
+    });
+    let order_detail = matching_order_details
+        .next()
+        .ok_or(LineItemResolutionError::NotFound)?;
+
+    match (product_id, matching_order_details.next()) {
+        (None, Some(_)) => Err(LineItemResolutionError::Ambiguous),
+        (Some(_), _) | (None, None) => Ok(order_detail),
+    }
+}
+
+/// Whether a line item stored for a refund or a shipment is of the order detail. Stored line items
+/// carry the product name and the product id of the order detail they were resolved to.
+#[cfg(feature = "v1")]
+pub(crate) fn is_line_item_of_order_detail(
+    order_detail: &api_models::payments::OrderDetailsWithAmount,
+    product_name: &str,
+    product_id: Option<&str>,
+) -> bool {
+    order_detail.product_name == product_name && order_detail.product_id.as_deref() == product_id
+}
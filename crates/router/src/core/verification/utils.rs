@@ -78,6 +78,8 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_webhook_details: Box::new(None),
         applepay_verified_domains: Some(already_verified_domains.clone()),
         pm_auth_config: Box::new(None),
+        tags: None,
+        credentials_expiry: None,
         connector_label: None,
         status: None,
         connector_wallets_details: Box::new(None),
@@ -94,6 +96,8 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_webhook_details: None,
         applepay_verified_domains: Some(already_verified_domains.clone()),
         pm_auth_config: Box::new(None),
+        tags: None,
+        credentials_expiry: None,
         connector_label: None,
         status: None,
         connector_wallets_details: Box::new(None),
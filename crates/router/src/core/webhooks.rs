@@ -8,6 +8,8 @@ pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
+#[cfg(feature = "v1")]
+pub mod webhook_health;
 
 #[cfg(feature = "v2")]
 pub(crate) use self::incoming_v2::incoming_webhooks_wrapper;
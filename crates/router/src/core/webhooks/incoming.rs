@@ -16,13 +16,13 @@ use hyperswitch_interfaces::webhooks::{IncomingWebhookFlowError, IncomingWebhook
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
-use super::{types, utils, MERCHANT_ID};
+use super::{types, utils, webhook_health, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payments,
+        ledger, metrics, payments,
         payments::tokenization,
         refunds, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
@@ -281,6 +281,12 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 }
             }
         };
+        webhook_health::record_incoming_webhook_health_event(
+            &state,
+            &merchant_connector_account,
+            webhook_health::IncomingWebhookHealthEvent::Received,
+        )
+        .await;
 
         let source_verified = if connectors_with_source_verification_call
             .connectors_with_webhook_source_verification_call
@@ -335,10 +341,19 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     merchant_account.get_id().clone(),
                 )],
             );
-        } else if connector.is_webhook_source_verification_mandatory() {
-            // if webhook consumption is mandatory for connector, fail webhook
-            // so that merchant can retrigger it after updating merchant_secret
-            return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
+        } else {
+            webhook_health::record_incoming_webhook_health_event(
+                &state,
+                &merchant_connector_account,
+                webhook_health::IncomingWebhookHealthEvent::VerificationFailed,
+            )
+            .await;
+
+            if connector.is_webhook_source_verification_mandatory() {
+                // if webhook consumption is mandatory for connector, fail webhook
+                // so that merchant can retrigger it after updating merchant_secret
+                return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
+            }
         }
 
         logger::info!(source_verified=?source_verified);
@@ -450,7 +465,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     &connector,
                     object_ref_id,
                     business_profile,
-                    merchant_connector_account,
+                    merchant_connector_account.clone(),
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for external authentication failed")
@@ -488,6 +503,12 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         match result_response {
             Ok(response) => response,
             Err(error) => {
+                webhook_health::record_incoming_webhook_health_event(
+                    &state,
+                    &merchant_connector_account,
+                    webhook_health::IncomingWebhookHealthEvent::ProcessingFailed,
+                )
+                .await;
                 return handle_incoming_webhook_error(
                     error,
                     &connector,
@@ -497,6 +518,14 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             }
         }
     } else {
+        if let Some(merchant_connector_account) = merchant_connector_account.as_ref() {
+            webhook_health::record_incoming_webhook_health_event(
+                &state,
+                merchant_connector_account,
+                webhook_health::IncomingWebhookHealthEvent::Received,
+            )
+            .await;
+        }
         metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
             &metrics::CONTEXT,
             1,
@@ -624,6 +653,7 @@ async fn payments_incoming_webhook_flow(
                     client_secret: None,
                     expand_attempts: None,
                     expand_captures: None,
+                    expand: None,
                 },
                 services::AuthFlow::Merchant,
                 consume_or_trigger_flow.clone(),
@@ -896,14 +926,19 @@ async fn refunds_incoming_webhook_flow(
             updated_by: merchant_account.storage_scheme.to_string(),
             connector_refund_data: None,
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating refund: refund_id: {refund_id}")
+            })?;
+        ledger::record_refund(&state, &updated_refund).await;
+        updated_refund
     } else {
         Box::pin(refunds::refund_retrieve_core_with_refund_id(
             state.clone(),
@@ -914,6 +949,7 @@ async fn refunds_incoming_webhook_flow(
                 refund_id: refund_id.to_owned(),
                 force_sync: Some(true),
                 merchant_connector_details: None,
+                expand: None,
             },
         ))
         .await
@@ -1479,6 +1515,22 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        let dispute_object = match disputes::representment::auto_represent_dispute(
+            &state,
+            &merchant_account,
+            &key_store,
+            &business_profile,
+            &dispute_object,
+        )
+        .await
+        {
+            Ok(represented_dispute) => represented_dispute.unwrap_or(dispute_object),
+            Err(error) => {
+                logger::error!(?error, "Failed to automatically represent the dispute");
+                dispute_object
+            }
+        };
+        ledger::record_dispute(&state, &dispute_object).await;
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -80,6 +80,17 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         return Ok(());
     }
 
+    if !business_profile.is_webhook_event_enabled(event_class) {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %idempotent_event_id,
+            %event_class,
+            "Merchant has not subscribed to outgoing webhooks for the event class; skipping \
+             outgoing webhooks for event"
+        );
+        return Ok(());
+    }
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -270,26 +281,64 @@ async fn trigger_webhook_to_merchant(
     }?;
 
     let event_id = event.event_id;
+    let outgoing_webhook_auth = get_outgoing_webhook_auth_config(&business_profile)?;
 
     let headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
-    let request = services::RequestBuilder::new()
+    let mut request_builder = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
         .attach_default_headers()
         .headers(headers)
         .set_body(RequestContent::RawBytes(
             request_content.body.expose().into_bytes(),
-        ))
-        .build();
+        ));
 
-    let response = state
-        .api_client
-        .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
-        .await;
+    if let Some(mutual_tls) = outgoing_webhook_auth
+        .as_ref()
+        .and_then(|auth| auth.mutual_tls.as_ref())
+    {
+        request_builder = request_builder
+            .add_certificate(Some(mutual_tls.certificate.clone()))
+            .add_certificate_key(Some(mutual_tls.certificate_key.clone()));
+    }
+
+    // Failure to obtain the access token is handled like a failed call to the merchant server,
+    // so that the delivery attempt is recorded and retried
+    let oauth2_access_token =
+        match outgoing_webhook_auth.and_then(|auth| auth.oauth2_client_credentials) {
+            Some(credentials) => {
+                get_outgoing_webhook_oauth2_access_token(&state, &business_profile, &credentials)
+                    .await
+                    .map(Some)
+            }
+            None => Ok(None),
+        };
+
+    let response = match oauth2_access_token {
+        Ok(access_token) => {
+            if let Some(access_token) = access_token {
+                request_builder = request_builder.headers(vec![(
+                    reqwest::header::AUTHORIZATION.to_string(),
+                    format!("Bearer {}", access_token.expose()).into_masked(),
+                )]);
+            }
+
+            state
+                .api_client
+                .send_request(
+                    &state,
+                    request_builder.build(),
+                    Some(OUTGOING_WEBHOOK_TIMEOUT_SECS),
+                    false,
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
@@ -597,6 +646,127 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+fn get_outgoing_webhook_auth_config(
+    business_profile: &domain::Profile,
+) -> CustomResult<Option<api_models::admin::OutgoingWebhookAuthConfig>, errors::WebhooksFlowError> {
+    business_profile
+        .outgoing_webhook_auth_config
+        .clone()
+        .map(|config| {
+            config
+                .into_inner()
+                .expose()
+                .parse_value::<api_models::admin::OutgoingWebhookAuthConfig>(
+                    "OutgoingWebhookAuthConfig",
+                )
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+                .attach_printable("Failed to deserialize outgoing webhook authentication config")
+        })
+        .transpose()
+}
+
+#[derive(Debug, serde::Serialize)]
+struct OAuth2ClientCredentialsTokenRequest {
+    grant_type: &'static str,
+    client_id: String,
+    client_secret: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OAuth2AccessTokenResponse {
+    access_token: Secret<String>,
+    expires_in: Option<i64>,
+}
+
+/// Obtains an access token from the authorization server of the merchant using the OAuth 2.0
+/// client credentials grant. Tokens are cached until shortly before they expire.
+#[instrument(skip_all)]
+async fn get_outgoing_webhook_oauth2_access_token(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    credentials: &api_models::admin::OutgoingWebhookOAuth2ClientCredentials,
+) -> CustomResult<Secret<String>, errors::ApiClientError> {
+    let redis_key = format!(
+        "{}{}_{}",
+        crate::consts::OUTGOING_WEBHOOK_OAUTH2_ACCESS_TOKEN_REDIS_KEY_PREFIX,
+        business_profile.get_id().get_string_repr(),
+        credentials.client_id
+    );
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok();
+
+    if let Some(redis_conn) = redis_conn.as_ref() {
+        match redis_conn.get_key::<Option<String>>(&redis_key).await {
+            Ok(Some(access_token)) => return Ok(Secret::new(access_token)),
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch cached outgoing webhook access token"
+                )
+            }
+        }
+    }
+
+    let token_request = OAuth2ClientCredentialsTokenRequest {
+        grant_type: "client_credentials",
+        client_id: credentials.client_id.clone(),
+        client_secret: credentials.client_secret.clone(),
+        scope: credentials.scope.clone(),
+    };
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(credentials.token_url.as_str())
+        .attach_default_headers()
+        .set_body(RequestContent::FormUrlEncoded(Box::new(token_request)))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
+        .await?;
+    if !response.status().is_success() {
+        return Err(report!(errors::ApiClientError::UnexpectedServerResponse)).attach_printable(
+            format!(
+                "Unexpected status code {} received from the authorization server",
+                response.status()
+            ),
+        );
+    }
+    let token_response = response
+        .json::<OAuth2AccessTokenResponse>()
+        .await
+        .change_context(errors::ApiClientError::ResponseDecodingFailed)
+        .attach_printable("Failed to deserialize outgoing webhook access token response")?;
+
+    let cache_expiry = token_response
+        .expires_in
+        .map(|expires_in| {
+            expires_in - crate::consts::OUTGOING_WEBHOOK_OAUTH2_ACCESS_TOKEN_EXPIRY_BUFFER_SECONDS
+        })
+        .filter(|expiry| *expiry > 0);
+    if let Some((redis_conn, expiry)) = redis_conn.zip(cache_expiry) {
+        redis_conn
+            .set_key_with_expiry(
+                &redis_key,
+                token_response.access_token.peek().as_str(),
+                expiry,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to cache outgoing webhook access token")
+            })
+            .ok();
+    }
+
+    Ok(token_response.access_token)
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
@@ -645,6 +815,24 @@ pub(crate) fn get_outgoing_webhook_request(
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
+        // The webhooks are signed with the previous secret as well until the rotation of the
+        // signing secret expires
+        if let Some(previous_secret) =
+            business_profile
+                .webhook_secret_rotation
+                .as_ref()
+                .and_then(|rotation| {
+                    rotation.get_active_previous_secret(common_utils::date_time::now())
+                })
+        {
+            if let Some(signature) = transformed_outgoing_webhook
+                .get_outgoing_webhooks_signature(Some(previous_secret))?
+                .signature
+            {
+                WebhookType::add_previous_secret_webhook_header(&mut headers, signature)
+            }
+        }
+
         Ok(OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
             headers: headers
@@ -659,10 +847,14 @@ pub(crate) fn get_outgoing_webhook_request(
         Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
             stripe_webhooks::StripeOutgoingWebhook,
         >(outgoing_webhook, business_profile),
-        _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
-            outgoing_webhook,
-            business_profile,
-        ),
+        _ => match business_profile.webhook_payload_version.unwrap_or_default() {
+            enums::WebhookPayloadVersion::V1 => get_outgoing_webhook_request_inner::<
+                webhooks::OutgoingWebhook,
+            >(outgoing_webhook, business_profile),
+            enums::WebhookPayloadVersion::V2 => get_outgoing_webhook_request_inner::<
+                webhooks::OutgoingWebhookV2,
+            >(outgoing_webhook, business_profile),
+        },
     }
 }
 
@@ -935,6 +1127,16 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::ConnectorCredentialsExpiryDetails(
+                credentials_expiry_response,
+            ) => Self::ConnectorAccount {
+                merchant_connector_id: credentials_expiry_response.merchant_connector_id.clone(),
+            },
         }
     }
 }
@@ -979,5 +1181,17 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::ConnectorAccount {
+            merchant_connector_id,
+        } => OutgoingWebhookEventContent::ConnectorAccount {
+            merchant_connector_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
@@ -20,6 +20,43 @@ pub trait OutgoingWebhookType:
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+
+    /// Adds the signature computed with the previous signing secret, while a rotation of the
+    /// signing secret is in its grace period
+    fn add_previous_secret_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    );
+}
+
+/// Signs the JSON encoded payload with HMAC-SHA512, used by all versions of the outgoing webhook
+/// payload
+fn get_hmac_sha512_signed_payload(
+    payload: &impl Serialize,
+    payment_response_hash_key: Option<impl AsRef<[u8]>>,
+) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+    let webhook_signature_payload = payload
+        .encode_to_string_of_json()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("failed encoding outgoing webhook payload")?;
+
+    let signature = payment_response_hash_key
+        .map(|key| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.as_ref(),
+                webhook_signature_payload.as_bytes(),
+            )
+        })
+        .transpose()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?
+        .map(hex::encode);
+
+    Ok(OutgoingWebhookPayloadWithSignature {
+        payload: webhook_signature_payload.into(),
+        signature,
+    })
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
@@ -27,33 +64,45 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
+        get_hmac_sha512_signed_payload(self, payment_response_hash_key)
+    }
+
+    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
+    }
 
-        let signature = payment_response_hash_key
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
+    fn add_previous_secret_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_PREVIOUS.to_string(),
+            signature.into(),
+        ))
+    }
+}
 
-        Ok(OutgoingWebhookPayloadWithSignature {
-            payload: webhook_signature_payload.into(),
-            signature,
-        })
+impl OutgoingWebhookType for webhooks::OutgoingWebhookV2 {
+    fn get_outgoing_webhooks_signature(
+        &self,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+        get_hmac_sha512_signed_payload(self, payment_response_hash_key)
     }
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
         header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
     }
+
+    fn add_previous_secret_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_PREVIOUS.to_string(),
+            signature.into(),
+        ))
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
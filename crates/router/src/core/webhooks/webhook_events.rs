@@ -1,7 +1,13 @@
+#[cfg(feature = "v1")]
+use error_stack::report;
 use error_stack::ResultExt;
 use masking::PeekInterface;
+#[cfg(feature = "v1")]
+use router_env::logger;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use super::types::OutgoingWebhookTrackingData;
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
@@ -9,6 +15,8 @@ use crate::{
     types::{api, domain, storage, transformers::ForeignTryFrom},
     utils::{OptionExt, StringExt},
 };
+#[cfg(feature = "v1")]
+use crate::{routes::app::ReqState, workflows::outgoing_webhook_retry};
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 
@@ -263,6 +271,108 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+/// Builds the outgoing webhook request of an event with the specified payload version, using the
+/// current state of the object the event corresponds to
+#[cfg(feature = "v1")]
+pub async fn preview_webhook_payload(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: common_utils::id_type::MerchantId,
+    event_id: String,
+    payload_version: Option<storage::enums::WebhookPayloadVersion>,
+) -> RouterResponse<api::webhook_events::WebhookPayloadPreviewResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let event = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            &event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let business_profile_id = event
+        .business_profile_id
+        .get_required_value("business_profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read business profile ID from event")?;
+    let mut business_profile = store
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &business_profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find business profile")?;
+
+    let tracking_data = OutgoingWebhookTrackingData {
+        merchant_id: merchant_id.clone(),
+        business_profile_id,
+        event_type: event.event_type,
+        event_class: event.event_class,
+        primary_object_id: event.primary_object_id,
+        primary_object_type: event.primary_object_type,
+        initial_attempt_id: event.initial_attempt_id,
+    };
+    let (content, _) = Box::pin(
+        outgoing_webhook_retry::get_outgoing_webhook_content_and_event_type(
+            state.clone(),
+            req_state,
+            merchant_account.clone(),
+            key_store,
+            &tracking_data,
+        ),
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(?error, "Failed to fetch the object for the event");
+        report!(errors::ApiErrorResponse::InternalServerError)
+    })?;
+
+    let payload_version = payload_version
+        .or(business_profile.webhook_payload_version)
+        .unwrap_or_default();
+    business_profile.webhook_payload_version = Some(payload_version);
+
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_id.clone(),
+        event_id: event.event_id.clone(),
+        event_type: event.event_type,
+        content,
+        timestamp: common_utils::date_time::now(),
+    };
+    let request = super::outgoing::get_outgoing_webhook_request(
+        &merchant_account,
+        outgoing_webhook,
+        &business_profile,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookPayloadPreviewResponse {
+            event_id: event.event_id,
+            merchant_id,
+            payload_version,
+            request,
+        },
+    ))
+}
+
 async fn get_account_and_key_store(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -0,0 +1,176 @@
+#[cfg(feature = "olap")]
+use std::collections::HashMap;
+
+use common_utils::date_time;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::domain,
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::{
+        errors::{RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    services,
+};
+
+const LAST_RECEIVED_AT: &str = "last_received_at";
+const RECEIVED_COUNT: &str = "received_count";
+const LAST_VERIFICATION_FAILURE_AT: &str = "last_verification_failure_at";
+const VERIFICATION_FAILURE_COUNT: &str = "verification_failure_count";
+const LAST_PROCESSING_ERROR_AT: &str = "last_processing_error_at";
+const PROCESSING_ERROR_COUNT: &str = "processing_error_count";
+
+/// Events in the lifecycle of an incoming webhook which are tracked per connector account
+#[derive(Clone, Copy, Debug)]
+pub enum IncomingWebhookHealthEvent {
+    Received,
+    VerificationFailed,
+    ProcessingFailed,
+}
+
+impl IncomingWebhookHealthEvent {
+    fn get_timestamp_and_count_fields(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Received => (LAST_RECEIVED_AT, RECEIVED_COUNT),
+            Self::VerificationFailed => (LAST_VERIFICATION_FAILURE_AT, VERIFICATION_FAILURE_COUNT),
+            Self::ProcessingFailed => (LAST_PROCESSING_ERROR_AT, PROCESSING_ERROR_COUNT),
+        }
+    }
+}
+
+/// Records an incoming webhook event against the connector account. Failures are only logged,
+/// since tracking the health of webhooks must not affect their processing.
+#[instrument(skip_all)]
+pub async fn record_incoming_webhook_health_event(
+    state: &SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    event: IncomingWebhookHealthEvent,
+) {
+    if let Err(error) =
+        update_incoming_webhook_health(state, merchant_connector_account, event).await
+    {
+        logger::error!(?error, ?event, "Failed to record incoming webhook health");
+    }
+}
+
+async fn update_incoming_webhook_health(
+    state: &SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    event: IncomingWebhookHealthEvent,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = merchant_connector_account
+        .merchant_id
+        .get_incoming_webhook_health_key(&merchant_connector_account.get_id());
+    let (timestamp_field, count_field) = event.get_timestamp_and_count_fields();
+
+    redis_conn
+        .set_hash_fields(
+            &key,
+            vec![(timestamp_field, date_time::now_unix_timestamp())],
+            Some(consts::INCOMING_WEBHOOK_HEALTH_REDIS_KEY_EXPIRY_SECONDS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set incoming webhook health timestamp")?;
+    redis_conn
+        .increment_fields_in_hash(&key, &[(count_field, 1)])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment incoming webhook health count")?;
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn list_incoming_webhook_health(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+) -> RouterResponse<Vec<api_models::admin::MerchantConnectorWebhookHealthResponse>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &merchant_id,
+            true,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+    let merchant_connector_accounts = core_utils::filter_objects_based_on_profile_id_list(
+        profile_id_list,
+        merchant_connector_accounts,
+    );
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut response = Vec::with_capacity(merchant_connector_accounts.len());
+    for merchant_connector_account in merchant_connector_accounts {
+        let merchant_connector_id = merchant_connector_account.get_id();
+        let health = redis_conn
+            .get_hash_fields::<HashMap<String, String>>(
+                &merchant_id.get_incoming_webhook_health_key(&merchant_connector_id),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch incoming webhook health")?;
+
+        response.push(api_models::admin::MerchantConnectorWebhookHealthResponse {
+            merchant_connector_id,
+            connector_name: merchant_connector_account.connector_name,
+            profile_id: merchant_connector_account.profile_id,
+            last_received_at: get_timestamp(&health, LAST_RECEIVED_AT),
+            received_count: get_count(&health, RECEIVED_COUNT),
+            verification_failure_count: get_count(&health, VERIFICATION_FAILURE_COUNT),
+            last_verification_failure_at: get_timestamp(&health, LAST_VERIFICATION_FAILURE_AT),
+            processing_error_count: get_count(&health, PROCESSING_ERROR_COUNT),
+            last_processing_error_at: get_timestamp(&health, LAST_PROCESSING_ERROR_AT),
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "olap")]
+fn get_count(health: &HashMap<String, String>, field: &str) -> u64 {
+    health
+        .get(field)
+        .and_then(|count| count.parse().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "olap")]
+fn get_timestamp(health: &HashMap<String, String>, field: &str) -> Option<time::PrimitiveDateTime> {
+    health
+        .get(field)
+        .and_then(|timestamp| timestamp.parse::<i64>().ok())
+        .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        .map(date_time::convert_to_pdt)
+}
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod admin_api_key;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -11,7 +12,9 @@ pub mod cards_info;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
+pub mod email_template;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
@@ -19,19 +22,30 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod installment_plan;
 pub mod kafka_store;
+pub mod ledger_entry;
+pub mod link_delivery;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod payment_archive;
 pub mod payment_link;
+pub mod payment_link_template;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_template;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim_config;
+pub mod shared_payment_method;
+pub mod shipment_capture;
+pub mod status_history;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -86,17 +100,22 @@ pub trait StorageInterface:
     + Sync
     + dyn_clone::DynClone
     + address::AddressInterface
+    + admin_api_key::AdminApiKeyInterface
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
+    + data_retention::DataRetentionInterface
     + dispute::DisputeInterface
+    + email_template::EmailTemplateInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + ledger_entry::LedgerEntryInterface
+    + link_delivery::LinkDeliveryInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -115,12 +134,21 @@ pub trait StorageInterface:
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
+    + payment_archive::PaymentArchiveInterface
     + payment_link::PaymentLinkInterface
+    + payment_link_template::PaymentLinkTemplateInterface
+    + payment_template::PaymentTemplateInterface
+    + installment_plan::InstallmentPlanInterface
+    + shared_payment_method::SharedPaymentMethodInterface
+    + shipment_capture::ShipmentCaptureInterface
+    + payment_method_consent_event::PaymentMethodConsentEventInterface
     + RedisConnInterface
     + RequestIdStore
     + business_profile::ProfileInterface
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + scim_config::ScimConfigInterface
+    + status_history::StatusHistoryInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
@@ -146,6 +174,7 @@ pub trait GlobalStorageInterface:
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
     + user::theme::ThemeInterface
+    + user::webauthn_credential::UserWebauthnCredentialInterface
     + 'static
 {
 }
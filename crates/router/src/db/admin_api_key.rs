@@ -0,0 +1,199 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AdminApiKeyInterface {
+    async fn insert_admin_api_key(
+        &self,
+        admin_api_key: storage::AdminApiKeyNew,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError>;
+
+    async fn find_admin_api_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError>;
+
+    async fn find_admin_api_key_by_hash_optional(
+        &self,
+        hashed_api_key: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::AdminApiKey>, errors::StorageError>;
+
+    async fn list_admin_api_keys(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminApiKey>, errors::StorageError>;
+
+    async fn update_admin_api_key(
+        &self,
+        key_id: &str,
+        admin_api_key_update: storage::AdminApiKeyUpdate,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError>;
+
+    async fn revoke_admin_api_key(&self, key_id: &str) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AdminApiKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_admin_api_key(
+        &self,
+        admin_api_key: storage::AdminApiKeyNew,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        admin_api_key
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_admin_api_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AdminApiKey::find_by_key_id(&conn, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_admin_api_key_by_hash_optional(
+        &self,
+        hashed_api_key: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::AdminApiKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AdminApiKey::find_optional_by_hashed_api_key(&conn, hashed_api_key)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_admin_api_keys(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminApiKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AdminApiKey::list(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_admin_api_key(
+        &self,
+        key_id: &str,
+        admin_api_key_update: storage::AdminApiKeyUpdate,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AdminApiKey::update_by_key_id(&conn, key_id, admin_api_key_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn revoke_admin_api_key(&self, key_id: &str) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AdminApiKey::delete_by_key_id(&conn, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AdminApiKeyInterface for MockDb {
+    async fn insert_admin_api_key(
+        &self,
+        admin_api_key: storage::AdminApiKeyNew,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        let mut locked_admin_api_keys = self.admin_api_keys.lock().await;
+        if locked_admin_api_keys
+            .iter()
+            .any(|key| key.key_id == admin_api_key.key_id)
+        {
+            Err(errors::StorageError::MockDbError)?;
+        }
+        let stored_key = storage::AdminApiKey {
+            key_id: admin_api_key.key_id,
+            name: admin_api_key.name,
+            description: admin_api_key.description,
+            hashed_api_key: admin_api_key.hashed_api_key,
+            prefix: admin_api_key.prefix,
+            role_id: admin_api_key.role_id,
+            created_at: admin_api_key.created_at,
+            expires_at: admin_api_key.expires_at,
+            last_modified_at: admin_api_key.last_modified_at,
+        };
+        locked_admin_api_keys.push(stored_key.clone());
+
+        Ok(stored_key)
+    }
+
+    async fn find_admin_api_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        self.admin_api_keys
+            .lock()
+            .await
+            .iter()
+            .find(|key| key.key_id == key_id)
+            .cloned()
+            .ok_or(report!(errors::StorageError::ValueNotFound(format!(
+                "No admin API key found for key_id = {key_id}"
+            ))))
+    }
+
+    async fn find_admin_api_key_by_hash_optional(
+        &self,
+        hashed_api_key: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::AdminApiKey>, errors::StorageError> {
+        Ok(self
+            .admin_api_keys
+            .lock()
+            .await
+            .iter()
+            .find(|key| key.hashed_api_key == hashed_api_key)
+            .cloned())
+    }
+
+    async fn list_admin_api_keys(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminApiKey>, errors::StorageError> {
+        Ok(self.admin_api_keys.lock().await.clone())
+    }
+
+    async fn update_admin_api_key(
+        &self,
+        key_id: &str,
+        admin_api_key_update: storage::AdminApiKeyUpdate,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        let mut locked_admin_api_keys = self.admin_api_keys.lock().await;
+        let key_to_update = locked_admin_api_keys
+            .iter_mut()
+            .find(|key| key.key_id == key_id)
+            .ok_or(errors::StorageError::MockDbError)?;
+
+        match admin_api_key_update {
+            storage::AdminApiKeyUpdate::RoleUpdate { role_id } => {
+                key_to_update.role_id = role_id;
+                key_to_update.last_modified_at = common_utils::date_time::now();
+            }
+        }
+
+        Ok(key_to_update.clone())
+    }
+
+    async fn revoke_admin_api_key(&self, key_id: &str) -> CustomResult<bool, errors::StorageError> {
+        let mut locked_admin_api_keys = self.admin_api_keys.lock().await;
+        let initial_len = locked_admin_api_keys.len();
+        locked_admin_api_keys.retain(|key| key.key_id != key_id);
+
+        Ok(locked_admin_api_keys.len() != initial_len)
+    }
+}
@@ -233,6 +233,7 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            scopes: api_key.scopes,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -417,6 +418,7 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                scopes: None,
             })
             .await
             .unwrap();
@@ -432,6 +434,7 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                scopes: None,
             })
             .await
             .unwrap();
@@ -513,6 +516,7 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            scopes: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
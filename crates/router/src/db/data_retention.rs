@@ -0,0 +1,141 @@
+use diesel_models::payment_intent::PaymentDataClass;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+};
+
+#[async_trait::async_trait]
+pub trait DataRetentionInterface {
+    /// Clears the data of the class from the payments of the merchant created before
+    /// `created_before`. Returns the number of payments whose data was purged, or would be purged
+    /// in case of a dry run.
+    async fn purge_payment_data_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_class: PaymentDataClass,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    /// Deletes the webhook events of the merchant created before `created_before`. Returns the
+    /// number of events deleted, or which would be deleted in case of a dry run.
+    async fn purge_events_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn purge_payment_data_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_class: PaymentDataClass,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        if dry_run {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_models::PaymentIntent::get_count_of_data_to_purge_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+                data_class,
+            )
+            .await
+        } else {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::PaymentIntent::purge_data_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+                data_class,
+            )
+            .await
+        }
+        .map_err(|error| error_stack::report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn purge_payment_data_created_before(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_class: PaymentDataClass,
+        _created_before: time::PrimitiveDateTime,
+        _dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        todo!()
+    }
+
+    #[instrument(skip_all)]
+    async fn purge_events_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        if dry_run {
+            let conn = connection::pg_connection_read(self).await?;
+            diesel_models::events::Event::get_count_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+            )
+            .await
+            .map(|count| usize::try_from(count).unwrap_or_default())
+        } else {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::events::Event::delete_by_merchant_id_created_before(
+                &conn,
+                merchant_id,
+                created_before,
+            )
+            .await
+        }
+        .map_err(|error| error_stack::report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for MockDb {
+    async fn purge_payment_data_created_before(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_class: PaymentDataClass,
+        _created_before: time::PrimitiveDateTime,
+        _dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn purge_events_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut locked_events = self.events.lock().await;
+        let is_expired = |event: &diesel_models::events::Event| {
+            event.merchant_id.as_ref() == Some(merchant_id) && event.created_at < created_before
+        };
+        let expired_events = locked_events
+            .iter()
+            .filter(|event| is_expired(event))
+            .count();
+
+        if !dry_run {
+            locked_events.retain(|event| !is_expired(event));
+        }
+
+        Ok(expired_events)
+    }
+}
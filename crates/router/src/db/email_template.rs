@@ -0,0 +1,183 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait EmailTemplateInterface {
+    async fn insert_email_template(
+        &self,
+        email_template: storage::EmailTemplateNew,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError>;
+
+    async fn find_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError>;
+
+    async fn list_email_templates_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError>;
+
+    async fn list_active_email_templates_by_profile_id_email_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        email_type: enums::EmailTemplateType,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError>;
+
+    async fn update_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+        email_template_update: storage::EmailTemplateUpdate,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError>;
+
+    async fn delete_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EmailTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_email_template(
+        &self,
+        email_template: storage::EmailTemplateNew,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        email_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EmailTemplate::find_by_profile_id_template_id(&conn, profile_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_email_templates_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EmailTemplate::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_active_email_templates_by_profile_id_email_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        email_type: enums::EmailTemplateType,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EmailTemplate::list_active_by_profile_id_email_type(&conn, profile_id, email_type)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+        email_template_update: storage::EmailTemplateUpdate,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EmailTemplate::update_by_profile_id_template_id(
+            &conn,
+            profile_id,
+            template_id,
+            email_template_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EmailTemplate::delete_by_profile_id_template_id(&conn, profile_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailTemplateInterface for MockDb {
+    async fn insert_email_template(
+        &self,
+        _email_template: storage::EmailTemplateNew,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_email_template_by_profile_id_template_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_email_templates_by_profile_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_active_email_templates_by_profile_id_email_type(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _email_type: enums::EmailTemplateType,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_email_template_by_profile_id_template_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _template_id: &str,
+        _email_template_update: storage::EmailTemplateUpdate,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_email_template_by_profile_id_template_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
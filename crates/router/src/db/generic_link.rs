@@ -36,6 +36,12 @@ pub trait GenericLinkInterface {
         _pm_collect_link: storage::GenericLinkNew,
     ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError>;
 
+    async fn update_pm_collect_link(
+        &self,
+        pm_collect_link: storage::PaymentMethodCollectLink,
+        pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError>;
+
     async fn insert_payout_link(
         &self,
         _payout_link: storage::GenericLinkNew,
@@ -46,6 +52,28 @@ pub trait GenericLinkInterface {
         payout_link: storage::PayoutLink,
         payout_link_update: storage::PayoutLinkUpdate,
     ) -> CustomResult<storage::PayoutLink, errors::StorageError>;
+
+    async fn find_initiated_payout_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutLink>, errors::StorageError>;
+
+    async fn find_customer_portal_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError>;
+
+    async fn insert_customer_portal_link(
+        &self,
+        _customer_portal_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError>;
+
+    async fn update_customer_portal_link(
+        &self,
+        customer_portal_link: storage::CustomerPortalLink,
+        customer_portal_link_update: storage::CustomerPortalLinkUpdate,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -107,6 +135,19 @@ impl GenericLinkInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn update_pm_collect_link(
+        &self,
+        pm_collect_link: storage::PaymentMethodCollectLink,
+        pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        pm_collect_link
+            .update_pm_collect_link(&conn, pm_collect_link_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn insert_payout_link(
         &self,
@@ -131,6 +172,54 @@ impl GenericLinkInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_initiated_payout_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GenericLink::find_initiated_payout_links_by_merchant_id(&conn, merchant_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_portal_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GenericLink::find_customer_portal_link_by_link_id(&conn, link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_customer_portal_link(
+        &self,
+        customer_portal_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_portal_link
+            .insert_customer_portal_link(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_portal_link(
+        &self,
+        customer_portal_link: storage::CustomerPortalLink,
+        customer_portal_link_update: storage::CustomerPortalLinkUpdate,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_portal_link
+            .update_customer_portal_link(&conn, customer_portal_link_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -175,6 +264,15 @@ impl GenericLinkInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_pm_collect_link(
+        &self,
+        _pm_collect_link: storage::PaymentMethodCollectLink,
+        _pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn insert_payout_link(
         &self,
         _pm_collect_link: storage::GenericLinkNew,
@@ -191,4 +289,38 @@ impl GenericLinkInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_initiated_payout_links_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutLink>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_customer_portal_link_by_link_id(
+        &self,
+        _generic_link_id: &str,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_customer_portal_link(
+        &self,
+        _customer_portal_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_customer_portal_link(
+        &self,
+        _customer_portal_link: storage::CustomerPortalLink,
+        _customer_portal_link_update: storage::CustomerPortalLinkUpdate,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,152 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait InstallmentPlanInterface {
+    async fn insert_installment_plan(
+        &self,
+        installment_plan: storage::InstallmentPlanNew,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError>;
+
+    async fn find_installment_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError>;
+
+    async fn list_installment_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::InstallmentPlan>, errors::StorageError>;
+
+    async fn update_installment_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        installment_plan_update: storage::InstallmentPlanUpdate,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl InstallmentPlanInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_installment_plan(
+        &self,
+        installment_plan: storage::InstallmentPlanNew,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        installment_plan
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_installment_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::InstallmentPlan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_installment_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::InstallmentPlan>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        match customer_id {
+            Some(customer_id) => {
+                storage::InstallmentPlan::list_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    customer_id,
+                    limit,
+                    offset,
+                )
+                .await
+            }
+            None => {
+                storage::InstallmentPlan::list_by_merchant_id(&conn, merchant_id, limit, offset)
+                    .await
+            }
+        }
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_installment_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        installment_plan_update: storage::InstallmentPlanUpdate,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::InstallmentPlan::update_by_merchant_id_plan_id(
+            &conn,
+            merchant_id,
+            plan_id,
+            installment_plan_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl InstallmentPlanInterface for MockDb {
+    async fn insert_installment_plan(
+        &self,
+        _installment_plan: storage::InstallmentPlanNew,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_installment_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_installment_plans_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: Option<&common_utils::id_type::CustomerId>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::InstallmentPlan>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_installment_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+        _installment_plan_update: storage::InstallmentPlanUpdate,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
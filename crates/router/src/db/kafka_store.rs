@@ -38,7 +38,10 @@ use time::PrimitiveDateTime;
 use super::{
     dashboard_metadata::DashboardMetadataInterface,
     role::RoleInterface,
-    user::{sample_data::BatchSampleDataInterface, theme::ThemeInterface, UserInterface},
+    user::{
+        sample_data::BatchSampleDataInterface, theme::ThemeInterface,
+        webauthn_credential::UserWebauthnCredentialInterface, UserInterface,
+    },
     user_authentication_method::UserAuthenticationMethodInterface,
     user_key_store::UserKeyStoreInterface,
     user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload, UserRoleInterface},
@@ -49,6 +52,7 @@ use crate::{
     core::errors::{self, ProcessTrackerError},
     db::{
         address::AddressInterface,
+        admin_api_key::AdminApiKeyInterface,
         api_keys::ApiKeyInterface,
         authentication::AuthenticationInterface,
         authorization::AuthorizationInterface,
@@ -57,23 +61,36 @@ use crate::{
         cards_info::CardsInfoInterface,
         configs::ConfigInterface,
         customers::CustomerInterface,
+        data_retention::DataRetentionInterface,
         dispute::DisputeInterface,
+        email_template::EmailTemplateInterface,
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
         file::FileMetadataInterface,
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        installment_plan::InstallmentPlanInterface,
+        ledger_entry::LedgerEntryInterface,
+        link_delivery::LinkDeliveryInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
         merchant_connector_account::{ConnectorAccessToken, MerchantConnectorAccountInterface},
         merchant_key_store::MerchantKeyStoreInterface,
+        payment_archive::PaymentArchiveInterface,
         payment_link::PaymentLinkInterface,
+        payment_link_template::PaymentLinkTemplateInterface,
         payment_method::PaymentMethodInterface,
+        payment_method_consent_event::PaymentMethodConsentEventInterface,
+        payment_template::PaymentTemplateInterface,
         refund::RefundInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        scim_config::ScimConfigInterface,
+        shared_payment_method::SharedPaymentMethodInterface,
+        shipment_capture::ShipmentCaptureInterface,
+        status_history::StatusHistoryInterface,
         unified_translations::UnifiedTranslationsInterface,
         CommonStorageInterface, GlobalStorageInterface, MasterKeyInterface, StorageInterface,
     },
@@ -218,6 +235,52 @@ impl AddressInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl AdminApiKeyInterface for KafkaStore {
+    async fn insert_admin_api_key(
+        &self,
+        admin_api_key: storage::AdminApiKeyNew,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        self.diesel_store.insert_admin_api_key(admin_api_key).await
+    }
+
+    async fn find_admin_api_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        self.diesel_store.find_admin_api_key_by_key_id(key_id).await
+    }
+
+    async fn find_admin_api_key_by_hash_optional(
+        &self,
+        hashed_api_key: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::AdminApiKey>, errors::StorageError> {
+        self.diesel_store
+            .find_admin_api_key_by_hash_optional(hashed_api_key)
+            .await
+    }
+
+    async fn list_admin_api_keys(
+        &self,
+    ) -> CustomResult<Vec<storage::AdminApiKey>, errors::StorageError> {
+        self.diesel_store.list_admin_api_keys().await
+    }
+
+    async fn update_admin_api_key(
+        &self,
+        key_id: &str,
+        admin_api_key_update: storage::AdminApiKeyUpdate,
+    ) -> CustomResult<storage::AdminApiKey, errors::StorageError> {
+        self.diesel_store
+            .update_admin_api_key(key_id, admin_api_key_update)
+            .await
+    }
+
+    async fn revoke_admin_api_key(&self, key_id: &str) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store.revoke_admin_api_key(key_id).await
+    }
+}
+
 #[async_trait::async_trait]
 impl ApiKeyInterface for KafkaStore {
     async fn insert_api_key(
@@ -548,6 +611,121 @@ impl CustomerInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl EmailTemplateInterface for KafkaStore {
+    async fn insert_email_template(
+        &self,
+        email_template: storage::EmailTemplateNew,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        self.diesel_store
+            .insert_email_template(email_template)
+            .await
+    }
+
+    async fn find_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_email_template_by_profile_id_template_id(profile_id, template_id)
+            .await
+    }
+
+    async fn list_email_templates_by_profile_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_email_templates_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn list_active_email_templates_by_profile_id_email_type(
+        &self,
+        profile_id: &id_type::ProfileId,
+        email_type: enums::EmailTemplateType,
+    ) -> CustomResult<Vec<storage::EmailTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_active_email_templates_by_profile_id_email_type(profile_id, email_type)
+            .await
+    }
+
+    async fn update_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        template_id: &str,
+        email_template_update: storage::EmailTemplateUpdate,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        self.diesel_store
+            .update_email_template_by_profile_id_template_id(
+                profile_id,
+                template_id,
+                email_template_update,
+            )
+            .await
+    }
+
+    async fn delete_email_template_by_profile_id_template_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::EmailTemplate, errors::StorageError> {
+        self.diesel_store
+            .delete_email_template_by_profile_id_template_id(profile_id, template_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl LinkDeliveryInterface for KafkaStore {
+    async fn insert_link_delivery(
+        &self,
+        link_delivery: storage::LinkDeliveryNew,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        self.diesel_store.insert_link_delivery(link_delivery).await
+    }
+
+    async fn find_link_delivery_by_delivery_id(
+        &self,
+        delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        self.diesel_store
+            .find_link_delivery_by_delivery_id(delivery_id)
+            .await
+    }
+
+    async fn find_link_delivery_by_merchant_id_delivery_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        self.diesel_store
+            .find_link_delivery_by_merchant_id_delivery_id(merchant_id, delivery_id)
+            .await
+    }
+
+    async fn list_link_deliveries_by_merchant_id_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        link_id: &str,
+    ) -> CustomResult<Vec<storage::LinkDelivery>, errors::StorageError> {
+        self.diesel_store
+            .list_link_deliveries_by_merchant_id_link_id(merchant_id, link_id)
+            .await
+    }
+
+    async fn update_link_delivery_by_delivery_id(
+        &self,
+        delivery_id: &str,
+        link_delivery_update: storage::LinkDeliveryUpdate,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        self.diesel_store
+            .update_link_delivery_by_delivery_id(delivery_id, link_delivery_update)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl DisputeInterface for KafkaStore {
     async fn insert_dispute(
@@ -898,54 +1076,408 @@ impl MandateInterface for KafkaStore {
             .await
     }
 
-    async fn find_mandates_by_merchant_id(
+    async fn find_mandates_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_constraints: api_models::mandates::MandateListConstraints,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .find_mandates_by_merchant_id(merchant_id, mandate_constraints)
+            .await
+    }
+
+    async fn insert_mandate(
+        &self,
+        mandate: storage::MandateNew,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        self.diesel_store
+            .insert_mandate(mandate, storage_scheme)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for KafkaStore {
+    async fn find_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.diesel_store
+            .find_payment_link_by_payment_link_id(payment_link_id)
+            .await
+    }
+
+    async fn insert_payment_link(
+        &self,
+        payment_link_object: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_link(payment_link_object)
+            .await
+    }
+
+    async fn list_payment_link_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_link_by_merchant_id(merchant_id, payment_link_constraints)
+            .await
+    }
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_payment_link_by_payment_link_id(payment_link_id, payment_link_update)
+            .await
+    }
+
+    async fn find_payment_links_by_merchant_id_template_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_link_template_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_links_by_merchant_id_template_id(merchant_id, payment_link_template_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ShipmentCaptureInterface for KafkaStore {
+    async fn insert_shipment_capture(
+        &self,
+        shipment_capture: storage::ShipmentCaptureNew,
+    ) -> CustomResult<storage::ShipmentCapture, errors::StorageError> {
+        self.diesel_store
+            .insert_shipment_capture(shipment_capture)
+            .await
+    }
+
+    async fn find_shipment_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ShipmentCapture>, errors::StorageError> {
+        self.diesel_store
+            .find_shipment_captures_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    async fn delete_shipment_capture_by_merchant_id_payment_id_shipment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        shipment_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_shipment_capture_by_merchant_id_payment_id_shipment_id(
+                merchant_id,
+                payment_id,
+                shipment_id,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SharedPaymentMethodInterface for KafkaStore {
+    async fn insert_shared_payment_method(
+        &self,
+        shared_payment_method: storage::SharedPaymentMethodNew,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .insert_shared_payment_method(shared_payment_method)
+            .await
+    }
+
+    async fn list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+        &self,
+        source_merchant_id: &id_type::MerchantId,
+        source_payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::SharedPaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+                source_merchant_id,
+                source_payment_method_id,
+            )
+            .await
+    }
+
+    async fn find_latest_shared_payment_method_by_source_merchant_id_customer_id_target_merchant_id(
+        &self,
+        source_merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        target_merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Option<storage::SharedPaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_latest_shared_payment_method_by_source_merchant_id_customer_id_target_merchant_id(
+                source_merchant_id,
+                customer_id,
+                target_merchant_id,
+            )
+            .await
+    }
+
+    async fn delete_shared_payment_method_by_id(
+        &self,
+        id: &str,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .delete_shared_payment_method_by_id(id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodConsentEventInterface for KafkaStore {
+    async fn insert_payment_method_consent_event(
+        &self,
+        consent_event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_consent_event(consent_event)
+            .await
+    }
+
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_consent_events_by_merchant_id_payment_method_id(
+                merchant_id,
+                payment_method_id,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkTemplateInterface for KafkaStore {
+    async fn insert_payment_link_template(
+        &self,
+        payment_link_template: storage::PaymentLinkTemplateNew,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_link_template(payment_link_template)
+            .await
+    }
+
+    async fn find_payment_link_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_payment_link_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+
+    async fn list_payment_link_templates_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentLinkTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_link_templates_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_payment_link_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        template_id: &str,
+        payment_link_template_update: storage::PaymentLinkTemplateUpdate,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        self.diesel_store
+            .update_payment_link_template_by_merchant_id_template_id(
+                merchant_id,
+                template_id,
+                payment_link_template_update,
+            )
+            .await
+    }
+
+    async fn increment_payment_link_template_usage_count(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        template_id: &str,
+        current_usage_count: i32,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        self.diesel_store
+            .increment_payment_link_template_usage_count(
+                merchant_id,
+                template_id,
+                current_usage_count,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentTemplateInterface for KafkaStore {
+    async fn insert_payment_template(
+        &self,
+        payment_template: storage::PaymentTemplateNew,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_template(payment_template)
+            .await
+    }
+
+    async fn find_payment_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_payment_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+
+    async fn find_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_payment_template_by_profile_id_template_id(profile_id, template_id)
+            .await
+    }
+
+    async fn list_payment_templates_by_profile_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::PaymentTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_templates_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn update_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        template_id: &str,
+        payment_template_update: storage::PaymentTemplateUpdate,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        self.diesel_store
+            .update_payment_template_by_profile_id_template_id(
+                profile_id,
+                template_id,
+                payment_template_update,
+            )
+            .await
+    }
+
+    async fn delete_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        self.diesel_store
+            .delete_payment_template_by_profile_id_template_id(profile_id, template_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for KafkaStore {
+    async fn insert_ledger_entries(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .insert_ledger_entries(ledger_entries)
+            .await
+    }
+
+    async fn list_ledger_entries_by_profile_id_account_currency(
         &self,
-        merchant_id: &id_type::MerchantId,
-        mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        profile_id: &id_type::ProfileId,
+        account: enums::LedgerAccount,
+        currency: enums::Currency,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
         self.diesel_store
-            .find_mandates_by_merchant_id(merchant_id, mandate_constraints)
+            .list_ledger_entries_by_profile_id_account_currency(
+                profile_id,
+                account,
+                currency,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
             .await
     }
 
-    async fn insert_mandate(
+    async fn get_ledger_account_nets_by_profile_id(
         &self,
-        mandate: storage::MandateNew,
-        storage_scheme: MerchantStorageScheme,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        profile_id: &id_type::ProfileId,
+        created_before: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::LedgerAccountNet>, errors::StorageError> {
         self.diesel_store
-            .insert_mandate(mandate, storage_scheme)
+            .get_ledger_account_nets_by_profile_id(profile_id, created_before)
             .await
     }
 }
 
 #[async_trait::async_trait]
-impl PaymentLinkInterface for KafkaStore {
-    async fn find_payment_link_by_payment_link_id(
+impl InstallmentPlanInterface for KafkaStore {
+    async fn insert_installment_plan(
         &self,
-        payment_link_id: &str,
-    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        installment_plan: storage::InstallmentPlanNew,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
         self.diesel_store
-            .find_payment_link_by_payment_link_id(payment_link_id)
+            .insert_installment_plan(installment_plan)
             .await
     }
 
-    async fn insert_payment_link(
+    async fn find_installment_plan_by_merchant_id_plan_id(
         &self,
-        payment_link_object: storage::PaymentLinkNew,
-    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
         self.diesel_store
-            .insert_payment_link(payment_link_object)
+            .find_installment_plan_by_merchant_id_plan_id(merchant_id, plan_id)
             .await
     }
 
-    async fn list_payment_link_by_merchant_id(
+    async fn list_installment_plans_by_merchant_id(
         &self,
         merchant_id: &id_type::MerchantId,
-        payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
-    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::InstallmentPlan>, errors::StorageError> {
         self.diesel_store
-            .list_payment_link_by_merchant_id(merchant_id, payment_link_constraints)
+            .list_installment_plans_by_merchant_id(merchant_id, customer_id, limit, offset)
+            .await
+    }
+
+    async fn update_installment_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        installment_plan_update: storage::InstallmentPlanUpdate,
+    ) -> CustomResult<storage::InstallmentPlan, errors::StorageError> {
+        self.diesel_store
+            .update_installment_plan_by_merchant_id_plan_id(
+                merchant_id,
+                plan_id,
+                installment_plan_update,
+            )
             .await
     }
 }
@@ -1072,6 +1604,27 @@ impl ConnectorAccessToken for KafkaStore {
             .set_access_token(merchant_id, merchant_connector_id, access_token)
             .await
     }
+
+    async fn acquire_access_token_lock(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &str,
+        lock_expiry_in_secs: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .acquire_access_token_lock(merchant_id, merchant_connector_id, lock_expiry_in_secs)
+            .await
+    }
+
+    async fn release_access_token_lock(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .release_access_token_lock(merchant_id, merchant_connector_id)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1420,6 +1973,35 @@ impl PaymentAttemptInterface for KafkaStore {
         Ok(attempt)
     }
 
+    #[cfg(feature = "v1")]
+    async fn update_payment_attempt_with_attempt_id_if_unmodified(
+        &self,
+        this: storage::PaymentAttempt,
+        payment_attempt: storage::PaymentAttemptUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage::PaymentAttempt>, errors::DataStorageError> {
+        let attempt = self
+            .diesel_store
+            .update_payment_attempt_with_attempt_id_if_unmodified(
+                this.clone(),
+                payment_attempt,
+                storage_scheme,
+            )
+            .await?;
+
+        if let Some(attempt) = attempt.as_ref() {
+            if let Err(er) = self
+                .kafka_producer
+                .log_payment_attempt(attempt, Some(this), self.tenant_id.clone())
+                .await
+            {
+                logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+            }
+        }
+
+        Ok(attempt)
+    }
+
     #[cfg(feature = "v2")]
     async fn update_payment_attempt(
         &self,
@@ -2079,6 +2661,17 @@ impl PayoutAttemptInterface for KafkaStore {
             .await
     }
 
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PayoutAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payout_attempts_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await
+    }
+
     async fn update_payout_attempt(
         &self,
         this: &storage::PayoutAttempt,
@@ -2208,6 +2801,23 @@ impl PayoutsInterface for KafkaStore {
             .await
     }
 
+    async fn filter_payouts_by_profile_id_source_currency_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        source_currency: enums::Currency,
+        status: Vec<enums::PayoutStatus>,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+        self.diesel_store
+            .filter_payouts_by_profile_id_source_currency_status(
+                merchant_id,
+                profile_id,
+                source_currency,
+                status,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -2367,6 +2977,27 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_merchant_id_runners_time_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        runners: &[storage::ProcessTrackerRunner],
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_merchant_id_runners_time_status(
+                merchant_id,
+                runners,
+                time_lower_limit,
+                time_upper_limit,
+                status,
+                limit,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2754,6 +3385,106 @@ impl ReverseLookupInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl PaymentArchiveInterface for KafkaStore {
+    async fn archive_payments_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .archive_payments_created_before(created_before, batch_size)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for KafkaStore {
+    async fn purge_payment_data_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        data_class: diesel_models::payment_intent::PaymentDataClass,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .purge_payment_data_created_before(merchant_id, data_class, created_before, dry_run)
+            .await
+    }
+
+    async fn purge_events_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+        dry_run: bool,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .purge_events_created_before(merchant_id, created_before, dry_run)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl StatusHistoryInterface for KafkaStore {
+    async fn find_status_history_by_merchant_id_entity_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entity_type: enums::StatusHistoryEntityType,
+        entity_id: &str,
+    ) -> CustomResult<Vec<storage::StatusHistory>, errors::StorageError> {
+        self.diesel_store
+            .find_status_history_by_merchant_id_entity_id(merchant_id, entity_type, entity_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ScimConfigInterface for KafkaStore {
+    async fn insert_scim_config(
+        &self,
+        scim_config: storage::ScimConfigNew,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        self.diesel_store.insert_scim_config(scim_config).await
+    }
+
+    async fn find_scim_config_by_hashed_token_optional(
+        &self,
+        hashed_token: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::ScimConfig>, errors::StorageError> {
+        self.diesel_store
+            .find_scim_config_by_hashed_token_optional(hashed_token)
+            .await
+    }
+
+    async fn find_scim_config_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        self.diesel_store
+            .find_scim_config_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_scim_config(
+        &self,
+        id: &str,
+        scim_config_update: storage::ScimConfigUpdate,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        self.diesel_store
+            .update_scim_config(id, scim_config_update)
+            .await
+    }
+
+    async fn delete_scim_config_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_scim_config_by_merchant_id(merchant_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl RoutingAlgorithmInterface for KafkaStore {
     async fn insert_routing_algorithm(
@@ -3610,6 +4341,16 @@ impl GenericLinkInterface for KafkaStore {
             .await
     }
 
+    async fn update_pm_collect_link(
+        &self,
+        pm_collect_link: storage::PaymentMethodCollectLink,
+        pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError> {
+        self.diesel_store
+            .update_pm_collect_link(pm_collect_link, pm_collect_link_update)
+            .await
+    }
+
     async fn insert_payout_link(
         &self,
         pm_collect_link: storage::GenericLinkNew,
@@ -3626,6 +4367,44 @@ impl GenericLinkInterface for KafkaStore {
             .update_payout_link(payout_link, payout_link_update)
             .await
     }
+
+    async fn find_initiated_payout_links_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutLink>, errors::StorageError> {
+        self.diesel_store
+            .find_initiated_payout_links_by_merchant_id(merchant_id, limit)
+            .await
+    }
+
+    async fn find_customer_portal_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        self.diesel_store
+            .find_customer_portal_link_by_link_id(link_id)
+            .await
+    }
+
+    async fn insert_customer_portal_link(
+        &self,
+        customer_portal_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        self.diesel_store
+            .insert_customer_portal_link(customer_portal_link)
+            .await
+    }
+
+    async fn update_customer_portal_link(
+        &self,
+        customer_portal_link: storage::CustomerPortalLink,
+        customer_portal_link_update: storage::CustomerPortalLinkUpdate,
+    ) -> CustomResult<storage::CustomerPortalLink, errors::StorageError> {
+        self.diesel_store
+            .update_customer_portal_link(customer_portal_link, customer_portal_link_update)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3703,6 +4482,15 @@ impl UserAuthenticationMethodInterface for KafkaStore {
             .await
     }
 
+    async fn get_user_authentication_method_by_email_domain(
+        &self,
+        email_domain: &str,
+    ) -> CustomResult<Option<storage::UserAuthenticationMethod>, errors::StorageError> {
+        self.diesel_store
+            .get_user_authentication_method_by_email_domain(email_domain)
+            .await
+    }
+
     async fn update_user_authentication_method(
         &self,
         id: &str,
@@ -3747,3 +4535,57 @@ impl ThemeInterface for KafkaStore {
             .await
     }
 }
+
+#[async_trait::async_trait]
+impl UserWebauthnCredentialInterface for KafkaStore {
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::webauthn_credential::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::webauthn_credential::UserWebauthnCredential, errors::StorageError>
+    {
+        self.diesel_store
+            .insert_user_webauthn_credential(credential)
+            .await
+    }
+
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::webauthn_credential::UserWebauthnCredential>, errors::StorageError>
+    {
+        self.diesel_store
+            .list_user_webauthn_credentials_by_user_id(user_id)
+            .await
+    }
+
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        id: &str,
+        credential_update: storage::webauthn_credential::UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::webauthn_credential::UserWebauthnCredential, errors::StorageError>
+    {
+        self.diesel_store
+            .update_user_webauthn_credential(user_id, id, credential_update)
+            .await
+    }
+
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_webauthn_credential(user_id, id)
+            .await
+    }
+
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_webauthn_credentials_by_user_id(user_id)
+            .await
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel_models::errors::DatabaseError;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait LedgerEntryInterface {
+    async fn insert_ledger_entries(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_ledger_entries_by_profile_id_account_currency(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        account: enums::LedgerAccount,
+        currency: enums::Currency,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn get_ledger_account_nets_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::LedgerAccountNet>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_ledger_entries(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LedgerEntryNew::insert_batch(&conn, ledger_entries)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_entries_by_profile_id_account_currency(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        account: enums::LedgerAccount,
+        currency: enums::Currency,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::list_by_profile_id_account_currency(
+            &conn,
+            profile_id,
+            account,
+            currency,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_ledger_account_nets_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::LedgerAccountNet>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::get_account_nets_by_profile_id(&conn, profile_id, created_before)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for MockDb {
+    async fn insert_ledger_entries(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let mut locked_ledger_entries = self.ledger_entries.lock().await;
+        // Entries are unique by the merchant, source and account, as enforced by the unique index
+        // of the table, and a batch is inserted only if none of its entries is a duplicate
+        let is_duplicate = {
+            let mut keys = locked_ledger_entries
+                .iter()
+                .map(|ledger_entry| {
+                    (
+                        &ledger_entry.merchant_id,
+                        ledger_entry.source_type,
+                        ledger_entry.source_id.as_str(),
+                        ledger_entry.account,
+                    )
+                })
+                .collect::<HashSet<_>>();
+            !ledger_entries.iter().all(|ledger_entry| {
+                keys.insert((
+                    &ledger_entry.merchant_id,
+                    ledger_entry.source_type,
+                    ledger_entry.source_id.as_str(),
+                    ledger_entry.account,
+                ))
+            })
+        };
+        if is_duplicate {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?;
+        }
+
+        let stored_entries = ledger_entries
+            .into_iter()
+            .map(|ledger_entry| storage::LedgerEntry {
+                id: ledger_entry.id,
+                transaction_id: ledger_entry.transaction_id,
+                merchant_id: ledger_entry.merchant_id,
+                profile_id: ledger_entry.profile_id,
+                account: ledger_entry.account,
+                direction: ledger_entry.direction,
+                amount: ledger_entry.amount,
+                currency: ledger_entry.currency,
+                source_type: ledger_entry.source_type,
+                source_id: ledger_entry.source_id,
+                created_at: ledger_entry.created_at,
+            })
+            .collect::<Vec<_>>();
+        locked_ledger_entries.extend(stored_entries.iter().cloned());
+
+        Ok(stored_entries)
+    }
+
+    async fn list_ledger_entries_by_profile_id_account_currency(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        account: enums::LedgerAccount,
+        currency: enums::Currency,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let mut ledger_entries = self
+            .ledger_entries
+            .lock()
+            .await
+            .iter()
+            .filter(|ledger_entry| {
+                ledger_entry.profile_id == *profile_id
+                    && ledger_entry.account == account
+                    && ledger_entry.currency == currency
+                    && created_after.map_or(true, |created_after| {
+                        ledger_entry.created_at >= created_after
+                    })
+                    && created_before.map_or(true, |created_before| {
+                        ledger_entry.created_at < created_before
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        ledger_entries.sort_by(|entry, other_entry| {
+            (entry.created_at, &entry.id).cmp(&(other_entry.created_at, &other_entry.id))
+        });
+
+        let offset = usize::try_from(offset.unwrap_or(0)).unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(ledger_entries
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn get_ledger_account_nets_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::LedgerAccountNet>, errors::StorageError> {
+        let mut account_nets = HashMap::<(enums::LedgerAccount, enums::Currency), i64>::new();
+        for ledger_entry in self
+            .ledger_entries
+            .lock()
+            .await
+            .iter()
+            .filter(|ledger_entry| {
+                ledger_entry.profile_id == *profile_id && ledger_entry.created_at < created_before
+            })
+        {
+            let amount = ledger_entry.amount.get_amount_as_i64();
+            let net_debit = match ledger_entry.direction {
+                enums::LedgerEntryDirection::Debit => amount,
+                enums::LedgerEntryDirection::Credit => amount.saturating_neg(),
+            };
+            *account_nets
+                .entry((ledger_entry.account, ledger_entry.currency))
+                .or_default() += net_debit;
+        }
+
+        Ok(account_nets
+            .into_iter()
+            .map(
+                |((account, currency), net_debit)| storage::LedgerAccountNet {
+                    account,
+                    currency,
+                    net_debit,
+                },
+            )
+            .collect())
+    }
+}
@@ -0,0 +1,148 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait LinkDeliveryInterface {
+    async fn insert_link_delivery(
+        &self,
+        link_delivery: storage::LinkDeliveryNew,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError>;
+
+    async fn find_link_delivery_by_delivery_id(
+        &self,
+        delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError>;
+
+    async fn find_link_delivery_by_merchant_id_delivery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError>;
+
+    async fn list_link_deliveries_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> CustomResult<Vec<storage::LinkDelivery>, errors::StorageError>;
+
+    async fn update_link_delivery_by_delivery_id(
+        &self,
+        delivery_id: &str,
+        link_delivery_update: storage::LinkDeliveryUpdate,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LinkDeliveryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_link_delivery(
+        &self,
+        link_delivery: storage::LinkDeliveryNew,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        link_delivery
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_link_delivery_by_delivery_id(
+        &self,
+        delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LinkDelivery::find_by_delivery_id(&conn, delivery_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_link_delivery_by_merchant_id_delivery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LinkDelivery::find_by_merchant_id_delivery_id(&conn, merchant_id, delivery_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_link_deliveries_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> CustomResult<Vec<storage::LinkDelivery>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LinkDelivery::list_by_merchant_id_link_id(&conn, merchant_id, link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_link_delivery_by_delivery_id(
+        &self,
+        delivery_id: &str,
+        link_delivery_update: storage::LinkDeliveryUpdate,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LinkDelivery::update_by_delivery_id(&conn, delivery_id, link_delivery_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LinkDeliveryInterface for MockDb {
+    async fn insert_link_delivery(
+        &self,
+        _link_delivery: storage::LinkDeliveryNew,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_link_delivery_by_delivery_id(
+        &self,
+        _delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_link_delivery_by_merchant_id_delivery_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _delivery_id: &str,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_link_deliveries_by_merchant_id_link_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _link_id: &str,
+    ) -> CustomResult<Vec<storage::LinkDelivery>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_link_delivery_by_delivery_id(
+        &self,
+        _delivery_id: &str,
+        _link_delivery_update: storage::LinkDeliveryUpdate,
+    ) -> CustomResult<storage::LinkDelivery, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -688,6 +688,7 @@ impl MandateInterface for MockDb {
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
             updated_by: mandate_new.updated_by,
+            signed_mandate_file_id: mandate_new.signed_mandate_file_id,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
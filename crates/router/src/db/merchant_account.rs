@@ -254,6 +254,14 @@ impl MerchantAccountInterface for Store {
             )
             .await?;
         }
+
+        // The cached merchant account may be keyed by a previous publishable key whose overlap
+        // window has elapsed since it was cached
+        if !merchant_account.is_publishable_key_valid(publishable_key) {
+            return Err(report!(errors::StorageError::ValueNotFound(format!(
+                "Publishable Key: {publishable_key} not found"
+            ))));
+        }
         let key_store = self
             .get_merchant_key_store_by_merchant_id(
                 state,
@@ -578,12 +586,7 @@ impl MerchantAccountInterface for MockDb {
         let accounts = self.merchant_accounts.lock().await;
         let account = accounts
             .iter()
-            .find(|account| {
-                account
-                    .publishable_key
-                    .as_ref()
-                    .is_some_and(|key| key == publishable_key)
-            })
+            .find(|account| account.is_publishable_key_valid(publishable_key))
             .ok_or(errors::StorageError::ValueNotFound(format!(
                 "Publishable Key: {} not found",
                 publishable_key
@@ -706,6 +709,15 @@ async fn publish_and_redact_merchant_account_cache(
         .as_ref()
         .map(|publishable_key| CacheKind::Accounts(publishable_key.into()));
 
+    #[cfg(feature = "v1")]
+    let previous_publishable_key = merchant_account
+        .previous_publishable_key
+        .as_ref()
+        .map(|publishable_key| CacheKind::Accounts(publishable_key.into()));
+
+    #[cfg(feature = "v2")]
+    let previous_publishable_key = None;
+
     #[cfg(feature = "v1")]
     let cgraph_key = merchant_account.default_profile.as_ref().map(|profile_id| {
         CacheKind::CGraph(
@@ -727,6 +739,7 @@ async fn publish_and_redact_merchant_account_cache(
     )];
 
     cache_keys.extend(publishable_key.into_iter());
+    cache_keys.extend(previous_publishable_key.into_iter());
     cache_keys.extend(cgraph_key.into_iter());
 
     cache::publish_into_redact_channel(store.get_cache_store().as_ref(), cache_keys).await?;
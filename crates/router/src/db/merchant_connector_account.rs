@@ -38,6 +38,21 @@ pub trait ConnectorAccessToken {
         merchant_connector_id_or_connector_name: &str,
         access_token: types::AccessToken,
     ) -> CustomResult<(), errors::StorageError>;
+
+    /// Acquires the lock held while the access token is being refreshed, returns `false` if the
+    /// lock is already held by another request
+    async fn acquire_access_token_lock(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id_or_connector_name: &str,
+        lock_expiry_in_secs: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn release_access_token_lock(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id_or_connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -48,17 +63,15 @@ impl ConnectorAccessToken for Store {
         merchant_id: &common_utils::id_type::MerchantId,
         merchant_connector_id_or_connector_name: &str,
     ) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
-        //TODO: Handle race condition
-        // This function should acquire a global lock on some resource, if access token is already
-        // being refreshed by other request then wait till it finishes and use the same access token
         let key = common_utils::access_token::create_access_token_key(
             merchant_id,
             merchant_connector_id_or_connector_name,
         );
-
-        let maybe_token = self
+        let redis_conn = self
             .get_redis_conn()
-            .map_err(Into::<errors::StorageError>::into)?
+            .map_err(Into::<errors::StorageError>::into)?;
+
+        let maybe_token = redis_conn
             .get_key::<Option<Vec<u8>>>(&key)
             .await
             .change_context(errors::StorageError::KVError)
@@ -69,7 +82,28 @@ impl ConnectorAccessToken for Store {
             .transpose()
             .change_context(errors::StorageError::DeserializationFailed)?;
 
-        Ok(access_token)
+        let Some(access_token) = access_token else {
+            return Ok(None);
+        };
+
+        // The stored expiry is the lifetime of the token when it was cached, the remaining
+        // lifetime is taken from the ttl of the key
+        let ttl = redis_conn
+            .get_ttl(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when getting access token expiry")?;
+
+        Ok(match ttl {
+            // The key does not have an expiry set
+            -1 => Some(access_token),
+            ttl if ttl > 0 => Some(types::AccessToken {
+                expires: ttl,
+                ..access_token
+            }),
+            // The key expired after it was read
+            _ => None,
+        })
     }
 
     #[instrument(skip_all)]
@@ -92,6 +126,48 @@ impl ConnectorAccessToken for Store {
             .await
             .change_context(errors::StorageError::KVError)
     }
+
+    #[instrument(skip_all)]
+    async fn acquire_access_token_lock(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id_or_connector_name: &str,
+        lock_expiry_in_secs: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let lock_key = common_utils::access_token::create_access_token_lock_key(
+            merchant_id,
+            merchant_connector_id_or_connector_name,
+        );
+        let reply = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .set_key_if_not_exists_with_expiry(&lock_key, "", Some(lock_expiry_in_secs))
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when acquiring access token lock")?;
+
+        Ok(matches!(reply, redis_interface::SetnxReply::KeySet))
+    }
+
+    #[instrument(skip_all)]
+    async fn release_access_token_lock(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id_or_connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        let lock_key = common_utils::access_token::create_access_token_lock_key(
+            merchant_id,
+            merchant_connector_id_or_connector_name,
+        );
+        self.get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .delete_key(&lock_key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when releasing access token lock")?;
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -112,6 +188,23 @@ impl ConnectorAccessToken for MockDb {
     ) -> CustomResult<(), errors::StorageError> {
         Ok(())
     }
+
+    async fn acquire_access_token_lock(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id_or_connector_name: &str,
+        _lock_expiry_in_secs: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Ok(true)
+    }
+
+    async fn release_access_token_lock(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id_or_connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -1188,6 +1281,8 @@ impl MerchantConnectorAccountInterface for MockDb {
             profile_id: Some(t.profile_id),
             applepay_verified_domains: t.applepay_verified_domains,
             pm_auth_config: t.pm_auth_config,
+            tags: t.tags,
+            credentials_expiry: t.credentials_expiry,
             status: t.status,
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
@@ -1229,6 +1324,8 @@ impl MerchantConnectorAccountInterface for MockDb {
             profile_id: t.profile_id,
             applepay_verified_domains: t.applepay_verified_domains,
             pm_auth_config: t.pm_auth_config,
+            tags: t.tags,
+            credentials_expiry: t.credentials_expiry,
             status: t.status,
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
@@ -1596,6 +1693,8 @@ mod merchant_connector_account_cache_tests {
             profile_id: profile_id.to_owned(),
             applepay_verified_domains: None,
             pm_auth_config: None,
+            tags: None,
+            credentials_expiry: None,
             status: common_enums::ConnectorStatus::Inactive,
             connector_wallets_details: Some(
                 domain::types::crypto_operation(
@@ -1773,6 +1872,8 @@ mod merchant_connector_account_cache_tests {
             profile_id: profile_id.to_owned(),
             applepay_verified_domains: None,
             pm_auth_config: None,
+            tags: None,
+            credentials_expiry: None,
             status: common_enums::ConnectorStatus::Inactive,
             connector_wallets_details: Some(
                 domain::types::crypto_operation(
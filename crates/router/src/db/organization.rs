@@ -119,12 +119,16 @@ impl OrganizationInterface for super::MockDb {
                     organization_name,
                     organization_details,
                     metadata,
+                    is_token_sharing_enabled,
                 } => {
                     organization_name
                         .as_ref()
                         .map(|org_name| org.set_organization_name(org_name.to_owned()));
                     organization_details.clone_into(&mut org.organization_details);
                     metadata.clone_into(&mut org.metadata);
+                    if is_token_sharing_enabled.is_some() {
+                        org.is_token_sharing_enabled = *is_token_sharing_enabled;
+                    }
                     org
                 }
             })
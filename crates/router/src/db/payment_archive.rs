@@ -0,0 +1,56 @@
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+#[cfg(feature = "v1")]
+use crate::connection;
+use crate::core::errors::{self, CustomResult};
+
+#[async_trait::async_trait]
+pub trait PaymentArchiveInterface {
+    /// Moves a batch of the payments created before `created_before`, which are in a terminal
+    /// status, along with their attempts to the archive tables. Returns the number of payments
+    /// archived.
+    async fn archive_payments_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentArchiveInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn archive_payments_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel_models::PaymentIntent::archive_created_before(&conn, created_before, batch_size)
+            .await
+            .map_err(|error| error_stack::report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn archive_payments_created_before(
+        &self,
+        _created_before: time::PrimitiveDateTime,
+        _batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        todo!()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentArchiveInterface for MockDb {
+    async fn archive_payments_created_before(
+        &self,
+        _created_before: time::PrimitiveDateTime,
+        _batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
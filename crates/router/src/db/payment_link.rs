@@ -26,6 +26,18 @@ pub trait PaymentLinkInterface {
         merchant_id: &common_utils::id_type::MerchantId,
         payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
     ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn find_payment_links_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_link_template_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -64,6 +76,34 @@ impl PaymentLinkInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentLink::update_by_payment_link_id(&conn, payment_link_id, payment_link_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_links_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_link_template_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLink::find_by_merchant_id_payment_link_template_id(
+            &conn,
+            merchant_id,
+            payment_link_template_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,4 +132,22 @@ impl PaymentLinkInterface for MockDb {
         // TODO: Implement function for `MockDb`x
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        _payment_link_id: &str,
+        _payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_links_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_link_template_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,174 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLinkTemplateInterface {
+    async fn insert_payment_link_template(
+        &self,
+        payment_link_template: storage::PaymentLinkTemplateNew,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError>;
+
+    async fn find_payment_link_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError>;
+
+    async fn list_payment_link_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentLinkTemplate>, errors::StorageError>;
+
+    async fn update_payment_link_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        payment_link_template_update: storage::PaymentLinkTemplateUpdate,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError>;
+
+    async fn increment_payment_link_template_usage_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        current_usage_count: i32,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_link_template(
+        &self,
+        payment_link_template: storage::PaymentLinkTemplateNew,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_link_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_link_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLinkTemplate::find_by_merchant_id_template_id(
+            &conn,
+            merchant_id,
+            template_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payment_link_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentLinkTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLinkTemplate::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_link_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        payment_link_template_update: storage::PaymentLinkTemplateUpdate,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentLinkTemplate::update_by_merchant_id_template_id(
+            &conn,
+            merchant_id,
+            template_id,
+            payment_link_template_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_payment_link_template_usage_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        current_usage_count: i32,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentLinkTemplate::increment_usage_count(
+            &conn,
+            merchant_id,
+            template_id,
+            current_usage_count,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkTemplateInterface for MockDb {
+    async fn insert_payment_link_template(
+        &self,
+        _payment_link_template: storage::PaymentLinkTemplateNew,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_link_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payment_link_templates_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentLinkTemplate>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_link_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+        _payment_link_template_update: storage::PaymentLinkTemplateUpdate,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn increment_payment_link_template_usage_count(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+        _current_usage_count: i32,
+    ) -> CustomResult<storage::PaymentLinkTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
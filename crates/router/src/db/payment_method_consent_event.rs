@@ -0,0 +1,74 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentMethodConsentEventInterface {
+    async fn insert_payment_method_consent_event(
+        &self,
+        consent_event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError>;
+
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodConsentEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_consent_event(
+        &self,
+        consent_event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        consent_event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodConsentEvent::find_by_merchant_id_payment_method_id(
+            &conn,
+            merchant_id,
+            payment_method_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodConsentEventInterface for MockDb {
+    async fn insert_payment_method_consent_event(
+        &self,
+        _consent_event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
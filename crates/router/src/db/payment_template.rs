@@ -0,0 +1,183 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentTemplateInterface {
+    async fn insert_payment_template(
+        &self,
+        payment_template: storage::PaymentTemplateNew,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError>;
+
+    async fn find_payment_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError>;
+
+    async fn find_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError>;
+
+    async fn list_payment_templates_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::PaymentTemplate>, errors::StorageError>;
+
+    async fn update_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+        payment_template_update: storage::PaymentTemplateUpdate,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError>;
+
+    async fn delete_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_template(
+        &self,
+        payment_template: storage::PaymentTemplateNew,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentTemplate::find_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentTemplate::find_by_profile_id_template_id(&conn, profile_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payment_templates_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::PaymentTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentTemplate::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+        payment_template_update: storage::PaymentTemplateUpdate,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentTemplate::update_by_profile_id_template_id(
+            &conn,
+            profile_id,
+            template_id,
+            payment_template_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payment_template_by_profile_id_template_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentTemplate::delete_by_profile_id_template_id(&conn, profile_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentTemplateInterface for MockDb {
+    async fn insert_payment_template(
+        &self,
+        _payment_template: storage::PaymentTemplateNew,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_template_by_profile_id_template_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payment_templates_by_profile_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::PaymentTemplate>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_template_by_profile_id_template_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _template_id: &str,
+        _payment_template_update: storage::PaymentTemplateUpdate,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_payment_template_by_profile_id_template_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _template_id: &str,
+    ) -> CustomResult<storage::PaymentTemplate, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
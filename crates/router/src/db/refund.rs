@@ -3,12 +3,20 @@ use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "olap")]
 use common_utils::types::{ConnectorTransactionIdTrait, MinorUnit};
-use diesel_models::{errors::DatabaseError, refund::RefundUpdateInternal};
+use diesel_models::{
+    errors::DatabaseError, refund::RefundUpdateInternal, status_history::StatusHistoryNew,
+};
 use hyperswitch_domain_models::refunds;
+use storage_impl::status_history::record_status_transition;
+#[cfg(feature = "kv_store")]
+use storage_impl::{
+    redis::kv_store::PartitionKey, status_history::push_status_transition_to_drainer,
+};
 
 use super::MockDb;
 use crate::{
     core::errors::{self, CustomResult},
+    services::Store,
     types::storage::{self as storage_types, enums},
 };
 
@@ -106,8 +114,9 @@ pub trait RefundInterface {
 mod storage {
     use error_stack::report;
     use router_env::{instrument, tracing};
+    use storage_impl::status_history::CREATE_TRIGGER;
 
-    use super::RefundInterface;
+    use super::{record_refund_status_transition, RefundInterface};
     use crate::{
         connection,
         core::errors::{self, CustomResult},
@@ -141,9 +150,12 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            new.insert(&conn)
+            let refund = new
+                .insert(&conn)
                 .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+            record_refund_status_transition(self, None, &refund, CREATE_TRIGGER).await;
+            Ok(refund)
         }
 
         #[instrument(skip_all)]
@@ -171,9 +183,14 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            this.update(&conn, refund)
+            let previous_status = this.refund_status;
+            let trigger: &'static str = (&refund).into();
+            let refund = this
+                .update(&conn, refund)
                 .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+            record_refund_status_transition(self, Some(previous_status), &refund, trigger).await;
+            Ok(refund)
         }
 
         #[instrument(skip_all)]
@@ -305,11 +322,14 @@ mod storage {
     use hyperswitch_domain_models::refunds;
     use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
-    use storage_impl::redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    use storage_impl::{
+        redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+        status_history::CREATE_TRIGGER,
     };
 
-    use super::RefundInterface;
+    use super::{
+        push_refund_status_transition_to_drainer, record_refund_status_transition, RefundInterface,
+    };
     use crate::{
         connection,
         core::errors::{self, utils::RedisErrorExt, CustomResult},
@@ -391,9 +411,12 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = connection::pg_connection_write(self).await?;
-                    new.insert(&conn)
+                    let refund = new
+                        .insert(&conn)
                         .await
-                        .map_err(|error| report!(errors::StorageError::from(error)))
+                        .map_err(|error| report!(errors::StorageError::from(error)))?;
+                    record_refund_status_transition(self, None, &refund, CREATE_TRIGGER).await;
+                    Ok(refund)
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let merchant_id = new.merchant_id.clone();
@@ -436,6 +459,11 @@ mod storage {
                         organization_id: new.organization_id.clone(),
                         connector_refund_data: new.connector_refund_data.clone(),
                         connector_transaction_data: new.connector_transaction_data.clone(),
+                        fallback_payout_id: None,
+                        capture_id: new.capture_id.clone(),
+                        expected_settlement_date: None,
+                        status_reason: None,
+                        line_items: new.line_items.clone(),
                     };
 
                     let field = format!(
@@ -514,7 +542,16 @@ mod storage {
                             key: Some(created_refund.refund_id),
                         }
                         .into()),
-                        Ok(HsetnxReply::KeySet) => Ok(created_refund),
+                        Ok(HsetnxReply::KeySet) => {
+                            push_refund_status_transition_to_drainer(
+                                self,
+                                None,
+                                &created_refund,
+                                CREATE_TRIGGER,
+                            )
+                            .await;
+                            Ok(created_refund)
+                        }
                         Err(er) => Err(er).change_context(errors::StorageError::KVError),
                     }
                 }
@@ -594,6 +631,8 @@ mod storage {
                 payment_id: &payment_id,
             };
             let field = format!("pa_{}_ref_{}", &this.attempt_id, &this.refund_id);
+            let previous_status = this.refund_status;
+            let trigger: &'static str = (&refund).into();
             let storage_scheme = Box::pin(decide_storage_scheme::<_, storage_types::Refund>(
                 self,
                 storage_scheme,
@@ -603,9 +642,13 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = connection::pg_connection_write(self).await?;
-                    this.update(&conn, refund)
+                    let refund = this
+                        .update(&conn, refund)
                         .await
-                        .map_err(|error| report!(errors::StorageError::from(error)))
+                        .map_err(|error| report!(errors::StorageError::from(error)))?;
+                    record_refund_status_transition(self, Some(previous_status), &refund, trigger)
+                        .await;
+                    Ok(refund)
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let key_str = key.to_string();
@@ -639,6 +682,14 @@ mod storage {
                     .try_into_hset()
                     .change_context(errors::StorageError::KVError)?;
 
+                    push_refund_status_transition_to_drainer(
+                        self,
+                        Some(previous_status),
+                        &updated_refund,
+                        trigger,
+                    )
+                    .await;
+
                     Ok(updated_refund)
                 }
             }
@@ -870,6 +921,55 @@ mod storage {
     }
 }
 
+fn refund_status_transition(
+    previous_status: Option<enums::RefundStatus>,
+    refund: &storage_types::Refund,
+    trigger: &str,
+) -> Option<StatusHistoryNew> {
+    (previous_status != Some(refund.refund_status)).then(|| StatusHistoryNew {
+        merchant_id: refund.merchant_id.clone(),
+        entity_type: enums::StatusHistoryEntityType::Refund,
+        entity_id: refund.refund_id.clone(),
+        attempt_id: None,
+        previous_status: previous_status.map(|status| status.to_string()),
+        status: refund.refund_status.to_string(),
+        trigger: trigger.to_owned(),
+        connector: Some(refund.connector.clone()),
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+async fn record_refund_status_transition(
+    store: &Store,
+    previous_status: Option<enums::RefundStatus>,
+    refund: &storage_types::Refund,
+    trigger: &str,
+) {
+    if let Some(status_history) = refund_status_transition(previous_status, refund, trigger) {
+        record_status_transition(store, status_history).await
+    }
+}
+
+#[cfg(feature = "kv_store")]
+async fn push_refund_status_transition_to_drainer(
+    store: &Store,
+    previous_status: Option<enums::RefundStatus>,
+    refund: &storage_types::Refund,
+    trigger: &str,
+) {
+    if let Some(status_history) = refund_status_transition(previous_status, refund, trigger) {
+        push_status_transition_to_drainer::<storage_types::Refund, _>(
+            store,
+            status_history,
+            PartitionKey::MerchantIdPaymentId {
+                merchant_id: &refund.merchant_id,
+                payment_id: &refund.payment_id,
+            },
+        )
+        .await
+    }
+}
+
 #[async_trait::async_trait]
 impl RefundInterface for MockDb {
     async fn find_refund_by_internal_reference_id_merchant_id(
@@ -930,6 +1030,11 @@ impl RefundInterface for MockDb {
             organization_id: new.organization_id,
             connector_refund_data: new.connector_refund_data,
             connector_transaction_data: new.connector_transaction_data,
+            fallback_payout_id: None,
+            capture_id: new.capture_id,
+            expected_settlement_date: None,
+            status_reason: None,
+            line_items: new.line_items,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -0,0 +1,143 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ScimConfigInterface {
+    async fn insert_scim_config(
+        &self,
+        scim_config: storage::ScimConfigNew,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError>;
+
+    async fn find_scim_config_by_hashed_token_optional(
+        &self,
+        hashed_token: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::ScimConfig>, errors::StorageError>;
+
+    async fn find_scim_config_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError>;
+
+    async fn update_scim_config(
+        &self,
+        id: &str,
+        scim_config_update: storage::ScimConfigUpdate,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError>;
+
+    async fn delete_scim_config_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ScimConfigInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_scim_config(
+        &self,
+        scim_config: storage::ScimConfigNew,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        scim_config
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_scim_config_by_hashed_token_optional(
+        &self,
+        hashed_token: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::ScimConfig>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimConfig::find_optional_by_hashed_token(&conn, hashed_token)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_scim_config_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimConfig::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_scim_config(
+        &self,
+        id: &str,
+        scim_config_update: storage::ScimConfigUpdate,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimConfig::update_by_id(&conn, id, scim_config_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_scim_config_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimConfig::delete_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ScimConfigInterface for MockDb {
+    async fn insert_scim_config(
+        &self,
+        _scim_config: storage::ScimConfigNew,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_scim_config_by_hashed_token_optional(
+        &self,
+        _hashed_token: storage::HashedApiKey,
+    ) -> CustomResult<Option<storage::ScimConfig>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_scim_config_by_merchant_id(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_scim_config(
+        &self,
+        _id: &str,
+        _scim_config_update: storage::ScimConfigUpdate,
+    ) -> CustomResult<storage::ScimConfig, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_scim_config_by_merchant_id(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
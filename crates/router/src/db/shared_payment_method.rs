@@ -0,0 +1,133 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SharedPaymentMethodInterface {
+    async fn insert_shared_payment_method(
+        &self,
+        shared_payment_method: storage::SharedPaymentMethodNew,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError>;
+
+    async fn list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+        &self,
+        source_merchant_id: &common_utils::id_type::MerchantId,
+        source_payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::SharedPaymentMethod>, errors::StorageError>;
+
+    async fn find_latest_shared_payment_method_by_source_merchant_id_customer_id_target_merchant_id(
+        &self,
+        source_merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        target_merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::SharedPaymentMethod>, errors::StorageError>;
+
+    async fn delete_shared_payment_method_by_id(
+        &self,
+        id: &str,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SharedPaymentMethodInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_shared_payment_method(
+        &self,
+        shared_payment_method: storage::SharedPaymentMethodNew,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        shared_payment_method
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+        &self,
+        source_merchant_id: &common_utils::id_type::MerchantId,
+        source_payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::SharedPaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SharedPaymentMethod::list_by_source_merchant_id_payment_method_id(
+            &conn,
+            source_merchant_id,
+            source_payment_method_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_shared_payment_method_by_source_merchant_id_customer_id_target_merchant_id(
+        &self,
+        source_merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        target_merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::SharedPaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SharedPaymentMethod::find_latest_by_source_merchant_id_customer_id_target_merchant_id(
+            &conn,
+            source_merchant_id,
+            customer_id,
+            target_merchant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_shared_payment_method_by_id(
+        &self,
+        id: &str,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SharedPaymentMethod::delete_by_id(&conn, id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SharedPaymentMethodInterface for MockDb {
+    async fn insert_shared_payment_method(
+        &self,
+        _shared_payment_method: storage::SharedPaymentMethodNew,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_shared_payment_methods_by_source_merchant_id_payment_method_id(
+        &self,
+        _source_merchant_id: &common_utils::id_type::MerchantId,
+        _source_payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::SharedPaymentMethod>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_latest_shared_payment_method_by_source_merchant_id_customer_id_target_merchant_id(
+        &self,
+        _source_merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _target_merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::SharedPaymentMethod>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_shared_payment_method_by_id(
+        &self,
+        _id: &str,
+    ) -> CustomResult<storage::SharedPaymentMethod, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,106 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ShipmentCaptureInterface {
+    async fn insert_shipment_capture(
+        &self,
+        shipment_capture: storage::ShipmentCaptureNew,
+    ) -> CustomResult<storage::ShipmentCapture, errors::StorageError>;
+
+    async fn find_shipment_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ShipmentCapture>, errors::StorageError>;
+
+    async fn delete_shipment_capture_by_merchant_id_payment_id_shipment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+        shipment_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ShipmentCaptureInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_shipment_capture(
+        &self,
+        shipment_capture: storage::ShipmentCaptureNew,
+    ) -> CustomResult<storage::ShipmentCapture, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        shipment_capture
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_shipment_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ShipmentCapture>, errors::StorageError> {
+        // Read from the master, as the captured line items are validated against
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ShipmentCapture::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_shipment_capture_by_merchant_id_payment_id_shipment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+        shipment_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ShipmentCapture::delete_by_merchant_id_payment_id_shipment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            shipment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ShipmentCaptureInterface for MockDb {
+    async fn insert_shipment_capture(
+        &self,
+        _shipment_capture: storage::ShipmentCaptureNew,
+    ) -> CustomResult<storage::ShipmentCapture, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_shipment_captures_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ShipmentCapture>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_shipment_capture_by_merchant_id_payment_id_shipment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+        _shipment_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
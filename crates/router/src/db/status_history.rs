@@ -0,0 +1,53 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait StatusHistoryInterface {
+    async fn find_status_history_by_merchant_id_entity_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: enums::StatusHistoryEntityType,
+        entity_id: &str,
+    ) -> CustomResult<Vec<storage::StatusHistory>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl StatusHistoryInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_status_history_by_merchant_id_entity_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: enums::StatusHistoryEntityType,
+        entity_id: &str,
+    ) -> CustomResult<Vec<storage::StatusHistory>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::StatusHistory::find_by_merchant_id_entity_type_entity_id(
+            &conn,
+            merchant_id,
+            entity_type,
+            entity_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl StatusHistoryInterface for MockDb {
+    async fn find_status_history_by_merchant_id_entity_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _entity_type: enums::StatusHistoryEntityType,
+        _entity_id: &str,
+    ) -> CustomResult<Vec<storage::StatusHistory>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -1,4 +1,4 @@
-use diesel_models::user as storage;
+use diesel_models::{enums::TotpStatus, user as storage};
 use error_stack::report;
 use masking::Secret;
 use router_env::{instrument, tracing};
@@ -11,6 +11,7 @@ use crate::{
 };
 pub mod sample_data;
 pub mod theme;
+pub mod webauthn_credential;
 
 #[async_trait::async_trait]
 pub trait UserInterface {
@@ -239,6 +240,12 @@ impl UserInterface for MockDb {
                         last_password_modified_at: Some(common_utils::date_time::now()),
                         ..user.to_owned()
                     },
+                    storage::UserUpdate::TwoFactorAuthReset => storage::User {
+                        totp_status: TotpStatus::NotSet,
+                        totp_secret: None,
+                        totp_recovery_codes: None,
+                        ..user.to_owned()
+                    },
                 };
                 user.to_owned()
             })
@@ -287,6 +294,12 @@ impl UserInterface for MockDb {
                         last_password_modified_at: Some(common_utils::date_time::now()),
                         ..user.to_owned()
                     },
+                    storage::UserUpdate::TwoFactorAuthReset => storage::User {
+                        totp_status: TotpStatus::NotSet,
+                        totp_secret: None,
+                        totp_recovery_codes: None,
+                        ..user.to_owned()
+                    },
                 };
                 user.to_owned()
             })
@@ -0,0 +1,154 @@
+use diesel_models::user::webauthn_credential as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait UserWebauthnCredentialInterface {
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError>;
+
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserWebauthnCredential>, errors::StorageError>;
+
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        id: &str,
+        credential_update: storage::UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError>;
+
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UserWebauthnCredentialInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        credential
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserWebauthnCredential>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserWebauthnCredential::list_by_user_id(&conn, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        id: &str,
+        credential_update: storage::UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserWebauthnCredential::update_by_user_id_and_id(
+            &conn,
+            user_id,
+            id,
+            credential_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserWebauthnCredential::delete_by_user_id_and_id(&conn, user_id, id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserWebauthnCredential::delete_by_user_id(&conn, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl UserWebauthnCredentialInterface for MockDb {
+    async fn insert_user_webauthn_credential(
+        &self,
+        _credential: storage::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        _user_id: &str,
+    ) -> CustomResult<Vec<storage::UserWebauthnCredential>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_user_webauthn_credential(
+        &self,
+        _user_id: &str,
+        _id: &str,
+        _credential_update: storage::UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_user_webauthn_credential(
+        &self,
+        _user_id: &str,
+        _id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        _user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
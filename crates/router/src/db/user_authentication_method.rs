@@ -31,6 +31,11 @@ pub trait UserAuthenticationMethodInterface {
         owner_id: &str,
     ) -> CustomResult<Vec<storage::UserAuthenticationMethod>, errors::StorageError>;
 
+    async fn get_user_authentication_method_by_email_domain(
+        &self,
+        email_domain: &str,
+    ) -> CustomResult<Option<storage::UserAuthenticationMethod>, errors::StorageError>;
+
     async fn update_user_authentication_method(
         &self,
         id: &str,
@@ -89,6 +94,17 @@ impl UserAuthenticationMethodInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn get_user_authentication_method_by_email_domain(
+        &self,
+        email_domain: &str,
+    ) -> CustomResult<Option<storage::UserAuthenticationMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserAuthenticationMethod::find_optional_by_email_domain(&conn, email_domain)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_user_authentication_method(
         &self,
@@ -130,6 +146,7 @@ impl UserAuthenticationMethodInterface for MockDb {
             allow_signup: user_authentication_method.allow_signup,
             created_at: user_authentication_method.created_at,
             last_modified_at: user_authentication_method.last_modified_at,
+            email_domain: user_authentication_method.email_domain,
         };
 
         user_authentication_methods.push(user_authentication_method.clone());
@@ -202,6 +219,20 @@ impl UserAuthenticationMethodInterface for MockDb {
         Ok(user_authentication_methods_list)
     }
 
+    async fn get_user_authentication_method_by_email_domain(
+        &self,
+        email_domain: &str,
+    ) -> CustomResult<Option<storage::UserAuthenticationMethod>, errors::StorageError> {
+        let user_authentication_methods = self.user_authentication_methods.lock().await;
+
+        Ok(user_authentication_methods
+            .iter()
+            .find(|auth_method_inner| {
+                auth_method_inner.email_domain.as_deref() == Some(email_domain)
+            })
+            .cloned())
+    }
+
     async fn update_user_authentication_method(
         &self,
         id: &str,
@@ -216,9 +247,11 @@ impl UserAuthenticationMethodInterface for MockDb {
                     storage::UserAuthenticationMethodUpdate::UpdateConfig {
                         private_config,
                         public_config,
+                        email_domain,
                     } => storage::UserAuthenticationMethod {
                         private_config,
                         public_config,
+                        email_domain: email_domain.or(auth_method_inner.email_domain.clone()),
                         last_modified_at: common_utils::date_time::now(),
                         ..auth_method_inner.to_owned()
                     },
@@ -59,6 +59,8 @@ pub mod headers {
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
     pub const NONCE: &str = "nonce";
+    pub const ORIGIN: &str = "Origin";
+    pub const REFERER: &str = "Referer";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
     pub const USER_AGENT: &str = "User-Agent";
@@ -74,7 +76,9 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_PREVIOUS: &str = "X-Webhook-Signature-512-Previous";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
+    pub const X_MIRRORED_REQUEST: &str = "x-mirrored-request";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -89,6 +93,8 @@ pub mod headers {
     pub const X_REDIRECT_URI: &str = "x-redirect-uri";
     pub const X_TENANT_ID: &str = "x-tenant-id";
     pub const X_CLIENT_SECRET: &str = "X-Client-Secret";
+    pub const X_DUMMY_CONNECTOR_SIGNATURE: &str = "X-Dummy-Connector-Signature";
+    pub const X_EMBED_TOKEN: &str = "X-Embed-Token";
 }
 
 pub mod pii {
@@ -150,7 +156,10 @@ pub fn mk_app(
         {
             server_app = server_app
                 .service(routes::Refunds::server(state.clone()))
-                .service(routes::Mandates::server(state.clone()));
+                .service(routes::Mandates::server(state.clone()))
+                .service(routes::InstallmentPlans::server(state.clone()))
+                .service(routes::TestClocks::server(state.clone()))
+                .service(routes::LinkDelivery::server(state.clone()));
         }
     }
 
@@ -165,6 +174,16 @@ pub fn mk_app(
             .service(routes::Poll::server(state.clone()))
     }
 
+    #[cfg(all(
+        feature = "oltp",
+        feature = "v1",
+        not(feature = "payment_methods_v2"),
+        not(feature = "customer_v2")
+    ))]
+    {
+        server_app = server_app.service(routes::Vault::server(state.clone()));
+    }
+
     #[cfg(feature = "olap")]
     {
         server_app = server_app
@@ -181,8 +200,12 @@ pub fn mk_app(
                 .service(routes::Blocklist::server(state.clone()))
                 .service(routes::Gsm::server(state.clone()))
                 .service(routes::ApplePayCertificatesMigration::server(state.clone()))
+                .service(routes::LockerMigration::server(state.clone()))
+                .service(routes::PaymentArchival::server(state.clone()))
+                .service(routes::AdminApiKeys::server(state.clone()))
                 .service(routes::PaymentLink::server(state.clone()))
                 .service(routes::User::server(state.clone()))
+                .service(routes::Scim::server(state.clone()))
                 .service(routes::ConnectorOnboarding::server(state.clone()))
                 .service(routes::Verify::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
@@ -91,6 +91,10 @@ pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
         // Max age of 1 year in seconds, equal to `60 * 60 * 24 * 365` seconds.
         .add((header::STRICT_TRANSPORT_SECURITY, "max-age=31536000"))
         .add((header::VIA, "HyperSwitch"))
+        .add((
+            crate::headers::X_API_VERSION,
+            crate::services::api::versioning::SERVED_API_VERSION.to_string(),
+        ))
 }
 
 /// Middleware to build a TOP level domain span for each request.
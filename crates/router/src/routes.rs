@@ -1,4 +1,6 @@
 pub mod admin;
+#[cfg(feature = "v1")]
+pub mod admin_api_keys;
 pub mod api_keys;
 pub mod app;
 #[cfg(feature = "v1")]
@@ -22,12 +24,18 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health;
+#[cfg(feature = "v1")]
+pub mod installment_plans;
+#[cfg(feature = "v1")]
+pub mod link_delivery;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandates;
 pub mod metrics;
 #[cfg(feature = "v1")]
+pub mod payment_archival;
+#[cfg(feature = "v1")]
 pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
@@ -46,6 +54,8 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod test_clocks;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -63,10 +73,11 @@ pub use self::app::DummyConnector;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
 pub use self::app::{
-    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
-    Customers, Disputes, EphemeralKey, Files, Forex, Gsm, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Profile, ProfileNew,
-    Refunds, SessionState, User, Webhooks,
+    AdminApiKeys, ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs,
+    ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Forex, Gsm, Health,
+    InstallmentPlans, LinkDelivery, LockerMigration, Mandates, MerchantAccount,
+    MerchantConnectorAccount, PaymentArchival, PaymentLink, PaymentMethods, Payments, Poll,
+    Profile, ProfileNew, Refunds, Scim, SessionState, TestClocks, User, Vault, Webhooks,
 };
 #[cfg(feature = "olap")]
 pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
@@ -1,9 +1,13 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+use common_enums::AdminPermission;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{
+        admin::*, api_locking, connector_credentials_expiry, connector_maintenance, data_retention,
+        payload_logs, usage_metering, webhooks::webhook_health,
+    },
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -106,7 +110,7 @@ pub async fn merchant_account_create(
         &req,
         json_payload.into_inner(),
         |state, _, req, _| create_merchant_account(state, req),
-        &auth::AdminApiAuth,
+        &auth::AdminApiAuthWithPermission(AdminPermission::MerchantAccountCreate),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -143,7 +147,7 @@ pub async fn merchant_account_create(
         &req,
         new_request_payload_with_org_id,
         |state, _, req, _| create_merchant_account(state, req),
-        &auth::AdminApiAuth,
+        &auth::AdminApiAuthWithPermission(AdminPermission::MerchantAccountCreate),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -217,6 +221,263 @@ pub async fn merchant_account_list(
     .await
 }
 
+/// Merchant Account - Search
+///
+/// To search the merchant accounts of an organization by name, publishable key, creation time or recon status
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantAccountSearch))]
+pub async fn merchant_account_search(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<common_utils::id_type::OrganizationId>,
+    query_params: web::Query<api_models::admin::MerchantAccountSearchRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantAccountSearch;
+    let organization_id = org_id.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, _, request, _| search_merchant_accounts(state, organization_id.clone(), request),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthOrganizationFromRoute {
+                organization_id: organization_id.clone(),
+                required_permission: Permission::OrganizationAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Usage
+///
+/// To retrieve the billable operations performed by a merchant account in a month
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantUsageRetrieve))]
+pub async fn merchant_account_usage(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::usage::UsageRetrieveRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantUsageRetrieve;
+    let merchant_id = mid.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, _, request, _| {
+            usage_metering::retrieve_merchant_usage(state, merchant_id.clone(), request)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Data Retention Purge
+///
+/// To report the data of a merchant account past the retention periods of its data retention
+/// policy, and purge it unless a dry run is requested
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantDataRetentionPurge))]
+pub async fn merchant_account_data_retention_purge(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::data_retention::DataRetentionPurgeRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantDataRetentionPurge;
+    let merchant_id = mid.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| {
+            data_retention::purge_merchant_data(state, merchant_id.clone(), request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Rotate Publishable Key
+///
+/// To issue a new publishable key for a merchant account, keeping the previous publishable key
+/// valid for an overlap period
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantPublishableKeyRotate))]
+pub async fn merchant_account_rotate_publishable_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::RotatePublishableKeyRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantPublishableKeyRotate;
+    let merchant_id = mid.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| rotate_publishable_key(state, merchant_id.clone(), request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Payload Log Retrieve
+///
+/// To retrieve the redacted payloads of a request made to the admin or payment APIs of a merchant
+/// account, if payload logging is enabled
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantPayloadLogRetrieve))]
+pub async fn merchant_account_payload_log_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::MerchantPayloadLogRetrieve;
+    let (merchant_id, request_id) = path.into_inner();
+    let payload = api_models::payload_logs::PayloadLogRetrieveRequest { request_id };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| {
+            payload_logs::retrieve_payload_log(state, merchant_id.clone(), request)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantPayloadLogRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Payload Log List
+///
+/// To list the redacted payloads of the most recent requests made to the admin and payment APIs
+/// of a merchant account, if payload logging is enabled
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantPayloadLogList))]
+pub async fn merchant_account_payload_log_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::payload_logs::PayloadLogListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::MerchantPayloadLogList;
+    let merchant_id = mid.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, _, constraints, _| {
+            payload_logs::list_payload_logs(state, merchant_id.clone(), constraints)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantPayloadLogRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Reconcile Config
+///
+/// To reconcile the live configuration of merchants with a YAML document declaring their merchant
+/// accounts, profiles, connector accounts and routing algorithms. The plan is only reported unless
+/// `dry_run` is set to `false`.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConfigReconcile))]
+pub async fn merchant_config_reconcile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::config_reconciliation::MerchantConfigReconcileQuery>,
+    body: String,
+) -> HttpResponse {
+    let flow = Flow::MerchantConfigReconcile;
+    let payload = api_models::config_reconciliation::MerchantConfigReconcileRequest {
+        dry_run: query_params.into_inner().dry_run.unwrap_or(true),
+        document: masking::Secret::new(body),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| {
+            crate::core::config_reconciliation::reconcile_merchant_config(state, request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Organization - Usage Export
+///
+/// To export the billable operations performed by every merchant account of an organization in a month as CSV
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationUsageExport))]
+pub async fn organization_usage_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    org_id: web::Path<common_utils::id_type::OrganizationId>,
+    query_params: web::Query<api_models::usage::UsageRetrieveRequest>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationUsageExport;
+    let organization_id = org_id.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, _, request, _| {
+            usage_metering::export_organization_usage(state, organization_id.clone(), request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?Flow::MerchantAccountList))]
 pub async fn merchant_account_list(
@@ -329,7 +590,10 @@ pub async fn connector_create(
             )
         },
         auth::auth_type(
-            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::AdminApiAuthWithPermissionAndMerchantIdFromRoute {
+                merchant_id: merchant_id.clone(),
+                permission: AdminPermission::MerchantConnectorManage,
+            },
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::ProfileConnectorWrite,
@@ -484,9 +748,11 @@ pub async fn connector_list(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::ProfileId>,
+    query_params: web::Query<api_models::admin::MerchantConnectorListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsList;
     let profile_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     api::server_wrap(
         flow,
@@ -494,7 +760,7 @@ pub async fn connector_list(
         &req,
         profile_id.to_owned(),
         |state, auth::AuthenticationData { key_store, .. }, _, _| {
-            list_connectors_for_a_profile(state, key_store, profile_id.clone())
+            list_connectors_for_a_profile(state, key_store, profile_id.clone(), tag.clone())
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
@@ -516,6 +782,7 @@ pub async fn connector_list(
     path = "/accounts/{account_id}/connectors",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("tag" = Option<String>, Query, description = "Only list the connector accounts tagged with this tag"),
     ),
     responses(
         (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorResponse>),
@@ -532,16 +799,20 @@ pub async fn connector_list(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::MerchantConnectorListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsList;
     let merchant_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     api::server_wrap(
         flow,
         state,
         &req,
         merchant_id.to_owned(),
-        |state, _auth, merchant_id, _| list_payment_connectors(state, merchant_id, None),
+        |state, _auth, merchant_id, _| {
+            list_payment_connectors(state, merchant_id, None, tag.clone())
+        },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
             &auth::JWTAuthMerchantFromRoute {
@@ -564,6 +835,7 @@ pub async fn connector_list(
     path = "/accounts/{account_id}/profile/connectors",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("tag" = Option<String>, Query, description = "Only list the connector accounts tagged with this tag"),
     ),
     responses(
         (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorResponse>),
@@ -579,9 +851,11 @@ pub async fn connector_list_profile(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::MerchantConnectorListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsList;
     let merchant_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     api::server_wrap(
         flow,
@@ -593,6 +867,7 @@ pub async fn connector_list_profile(
                 state,
                 merchant_id,
                 auth.profile_id.map(|profile_id| vec![profile_id]),
+                tag.clone(),
             )
         },
         auth::auth_type(
@@ -608,6 +883,351 @@ pub async fn connector_list_profile(
     .await
 }
 
+/// Merchant Connector - Bulk Toggle
+///
+/// To enable or disable many Merchant Connectors at once, either all the accounts of a connector across profiles or all the accounts of a profile. Helpful in taking a processor out of rotation during an outage.
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/bulk_toggle",
+    request_body = MerchantConnectorBulkToggleRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors Toggled", body = MerchantConnectorBulkToggleResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Bulk Toggle Merchant Connectors",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsBulkToggle))]
+pub async fn connector_bulk_toggle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::MerchantConnectorBulkToggleRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsBulkToggle;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            bulk_toggle_connectors(
+                state,
+                &merchant_id,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Webhook Health
+///
+/// To view the health of the incoming webhooks of each Merchant Connector, including when the last webhook was received and how many webhooks failed source verification or processing. Helpful in detecting a connector that has stopped sending webhooks.
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/webhook_health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors Webhook Health Retrieved", body = Vec<MerchantConnectorWebhookHealthResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Retrieve Merchant Connectors Webhook Health",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsWebhookHealth))]
+pub async fn connector_webhook_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsWebhookHealth;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.clone(),
+        |state, auth, merchant_id, _| {
+            webhook_health::list_incoming_webhook_health(
+                state,
+                merchant_id,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - List Expiring Credentials
+///
+/// List the credentials of the Merchant Connector accounts which expire within the given number of days, such as API keys and Apple Pay certificates, so that they can be renewed with the connectors in time
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/credentials/expiring",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("within_days" = Option<u16>, Query, description = "The credentials expiring within this many days are listed, defaults to 30 days"),
+    ),
+    responses(
+        (status = 200, description = "Expiring Merchant Connector Credentials Retrieved", body = Vec<ExpiringConnectorCredentialsResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "List Expiring Merchant Connector Credentials",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsExpiringCredentialsList))]
+pub async fn connector_expiring_credentials_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::ExpiringConnectorCredentialsListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsExpiringCredentialsList;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth, constraints, _| {
+            connector_credentials_expiry::list_expiring_connector_credentials(
+                state,
+                merchant_id.clone(),
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Create Maintenance Window
+///
+/// To schedule a maintenance window for a Merchant Connector, during which routing skips the connector and transactions fall through to the other connectors
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows",
+    request_body = ConnectorMaintenanceWindowCreateRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Maintenance Window Created", body = ConnectorMaintenanceWindowResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Create a Merchant Connector Maintenance Window",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMaintenanceWindowCreate))]
+pub async fn connector_maintenance_window_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<api_models::admin::ConnectorMaintenanceWindowCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMaintenanceWindowCreate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            connector_maintenance::create_maintenance_window(
+                state,
+                merchant_id.clone(),
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - List Maintenance Windows
+///
+/// To list the maintenance windows of a Merchant Connector which have not ended yet
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Maintenance Windows Retrieved", body = Vec<ConnectorMaintenanceWindowResponse>),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "List Merchant Connector Maintenance Windows",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMaintenanceWindowList))]
+pub async fn connector_maintenance_window_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMaintenanceWindowList;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            connector_maintenance::list_maintenance_windows(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Delete Maintenance Window
+///
+/// To delete a maintenance window of a Merchant Connector
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows/{maintenance_window_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector"),
+        ("maintenance_window_id" = String, Path, description = "The unique identifier for the Maintenance Window")
+    ),
+    responses(
+        (status = 200, description = "Maintenance Window Deleted", body = ConnectorMaintenanceWindowResponse),
+        (status = 404, description = "Maintenance Window does not exist in records")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Delete a Merchant Connector Maintenance Window",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMaintenanceWindowDelete))]
+pub async fn connector_maintenance_window_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMaintenanceWindowDelete;
+    let (merchant_id, merchant_connector_id, maintenance_window_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        maintenance_window_id,
+        |state, auth, maintenance_window_id, _| {
+            connector_maintenance::delete_maintenance_window(
+                state,
+                merchant_id.clone(),
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                maintenance_window_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Connector - Update
 ///
 /// To update an existing Merchant Connector. Helpful in enabling / disabling different payment methods and other settings for the connector etc.
@@ -657,7 +1277,9 @@ pub async fn connector_update(
             )
         },
         auth::auth_type(
-            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::AdminApiAuthWithPermissionAndMerchantIdFromHeader(
+                AdminPermission::MerchantConnectorManage,
+            ),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::ProfileConnectorWrite,
@@ -763,7 +1385,7 @@ pub async fn connector_delete(
         payload,
         |state, _, req, _| delete_connector(state, req.merchant_id, req.merchant_connector_id),
         auth::auth_type(
-            &auth::AdminApiAuth,
+            &auth::AdminApiAuthWithPermission(AdminPermission::MerchantConnectorManage),
             &auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: Permission::MerchantConnectorWrite,
@@ -832,7 +1454,7 @@ pub async fn merchant_account_toggle_kv(
         &req,
         payload,
         |state, _, payload, _| kv_for_merchant(state, payload.merchant_id, payload.kv_enabled),
-        &auth::AdminApiAuth,
+        &auth::AdminApiAuthWithPermission(AdminPermission::KvToggle),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -856,7 +1478,7 @@ pub async fn merchant_account_toggle_all_kv(
         &req,
         payload,
         |state, _, payload, _| toggle_kv_for_all_merchants(state, payload.kv_enabled),
-        &auth::AdminApiAuth,
+        &auth::AdminApiAuthWithPermission(AdminPermission::KvToggle),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -0,0 +1,128 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{admin_api_keys, api_locking},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminApiKeyCreate))]
+pub async fn admin_api_key_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::admin_api_keys::CreateAdminApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::AdminApiKeyCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| admin_api_keys::create_admin_api_key(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminApiKeyRetrieve))]
+pub async fn admin_api_key_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::AdminApiKeyRetrieve;
+    let payload = api_models::admin_api_keys::AdminApiKeyId {
+        key_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| admin_api_keys::retrieve_admin_api_key(state, &payload.key_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminApiKeyList))]
+pub async fn admin_api_key_list(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::AdminApiKeyList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| admin_api_keys::list_admin_api_keys(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminApiKeyRoleAssign))]
+pub async fn admin_api_key_assign_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::admin_api_keys::AdminRoleAssignmentRequest>,
+) -> impl Responder {
+    let flow = Flow::AdminApiKeyRoleAssign;
+    let key_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| admin_api_keys::assign_admin_role(state, &key_id, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminApiKeyRevoke))]
+pub async fn admin_api_key_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::AdminApiKeyRevoke;
+    let payload = api_models::admin_api_keys::AdminApiKeyId {
+        key_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| admin_api_keys::revoke_admin_api_key(state, &payload.key_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminRoleList))]
+pub async fn admin_role_list(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::AdminRoleList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| admin_api_keys::list_admin_roles(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use actix_web::{web, Scope};
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -12,7 +16,9 @@ use common_utils::id_type;
 use external_services::email::{
     no_email::NoEmailClient, ses::AwsSes, smtp::SmtpServer, EmailClientConfigs, EmailService,
 };
-use external_services::{file_storage::FileStorageInterface, grpc_client::GrpcClients};
+use external_services::{
+    file_storage::FileStorageInterface, grpc_client::GrpcClients, messaging::MessagingInterface,
+};
 use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
@@ -58,11 +64,14 @@ use super::{
     user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    admin_api_keys, apple_pay_certificates_migration, blocklist, locker_migration,
+    payment_archival, payment_link, webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
-use super::{mandates::*, refunds::*};
+use super::{installment_plans, link_delivery, mandates::*, refunds::*, test_clocks};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -83,6 +92,7 @@ pub use crate::{
 };
 use crate::{
     configs::{secrets_transformers, Settings},
+    consts,
     db::kafka_store::{KafkaStore, TenantID},
 };
 
@@ -104,6 +114,7 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub messaging_client: Arc<dyn MessagingInterface>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
@@ -111,6 +122,9 @@ pub struct SessionState {
     pub opensearch_client: Arc<OpenSearchClient>,
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
+    /// The instant by which the request is expected to be served, set when a timeout budget is
+    /// configured for requests
+    pub request_deadline: Option<Instant>,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -123,6 +137,59 @@ impl SessionState {
             event_context: events::EventContext::new(self.event_handler.clone()),
         }
     }
+
+    /// Sets the deadline of the request, which started at the given instant, from the timeout
+    /// budget configured for requests
+    pub fn set_request_deadline(&mut self, request_started_at: Instant) {
+        let request_timeout_budget = &self.conf.request_timeout_budget;
+        self.request_deadline = request_timeout_budget.enabled.then(|| {
+            request_started_at + Duration::from_millis(request_timeout_budget.budget_in_millis)
+        });
+    }
+
+    /// The time left for a connector call before the deadline of the request, after keeping aside
+    /// the time needed to record the outcome of the call
+    pub fn get_connector_call_budget(&self) -> Option<Duration> {
+        self.request_deadline.map(|request_deadline| {
+            request_deadline
+                .saturating_duration_since(Instant::now())
+                .saturating_sub(Duration::from_millis(
+                    self.conf
+                        .request_timeout_budget
+                        .post_connector_call_reserve_in_millis,
+                ))
+        })
+    }
+
+    /// Whether a connector call can be made within the timeout budget of the request
+    pub fn has_connector_call_budget(&self) -> bool {
+        self.get_connector_call_budget()
+            .map_or(true, |connector_call_budget| {
+                connector_call_budget
+                    >= Duration::from_millis(
+                        self.conf
+                            .request_timeout_budget
+                            .min_connector_call_budget_in_millis,
+                    )
+            })
+    }
+
+    /// The timeout of a connector call, which is bounded by the time left in the timeout budget of
+    /// the request. A connector call is allowed at least the minimum connector call budget.
+    pub fn get_connector_call_timeout_in_secs(&self) -> Option<u64> {
+        let min_connector_call_budget = Duration::from_millis(
+            self.conf
+                .request_timeout_budget
+                .min_connector_call_budget_in_millis,
+        );
+        self.get_connector_call_budget()
+            .map(|connector_call_budget| {
+                connector_call_budget
+                    .max(min_connector_call_budget)
+                    .as_secs()
+                    .clamp(1, consts::REQUEST_TIME_OUT)
+            })
+    }
 }
 
 pub trait SessionStateInfo {
@@ -207,6 +274,7 @@ pub struct AppState {
     pub opensearch_client: Arc<OpenSearchClient>,
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub messaging_client: Arc<dyn MessagingInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
@@ -370,6 +438,7 @@ impl AppState {
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
             let theme_storage_client = conf.theme_storage.get_file_storage_client().await;
+            let messaging_client = conf.messaging.get_messaging_client();
 
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
 
@@ -388,6 +457,7 @@ impl AppState {
                 opensearch_client,
                 request_id: None,
                 file_storage_client,
+                messaging_client,
                 encryption_client,
                 grpc_client,
                 theme_storage_client,
@@ -468,6 +538,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            messaging_client: self.messaging_client.clone(),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -477,6 +548,7 @@ impl AppState {
             opensearch_client: Arc::clone(&self.opensearch_client),
             grpc_client: Arc::clone(&self.grpc_client),
             theme_storage_client: self.theme_storage_client.clone(),
+            request_deadline: None,
         })
     }
 }
@@ -517,6 +589,13 @@ impl DummyConnector {
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
             );
+        #[cfg(feature = "dummy_connector_webhook_simulation")]
+        {
+            routes_with_restricted_access = routes_with_restricted_access.service(
+                web::resource("/webhooks/simulate")
+                    .route(web::post().to(dummy_connector_simulate_webhook)),
+            );
+        }
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
             .service(
@@ -628,6 +707,10 @@ impl Payments {
                     web::resource("/session_tokens")
                         .route(web::post().to(payments::payments_connector_session)),
                 )
+                .service(
+                    web::resource("/session_tokens/prewarm")
+                        .route(web::post().to(payments::payments_prewarm_session_tokens)),
+                )
                 .service(
                     web::resource("/sync")
                         .route(web::post().to(payments::payments_retrieve_with_gateway_creds)),
@@ -649,6 +732,14 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments::payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/shipments")
+                        .route(web::post().to(payments::payments_capture_on_shipment)),
+                )
+                .service(
+                    web::resource("/{payment_id}/dcc_offer")
+                        .route(web::post().to(payments::payments_dcc_offer)),
+                )
                 .service(
                     web::resource("/{payment_id}/approve")
                         .route(web::post().to(payments::payments_approve)),
@@ -684,6 +775,13 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/incremental_authorization").route(web::post().to(payments::payments_incremental_authorization)),
                 )
+                .service(
+                    web::resource("/{payment_id}/invoice").route(web::post().to(payments::payments_generate_invoice)),
+                )
+                .service(
+                    web::resource("/{payment_id}/client_secret/rotate")
+                        .route(web::post().to(payments::payments_rotate_client_secret)),
+                )
                 .service(
                     web::resource("/{payment_id}/{merchant_id}/authorize/{connector}").route(web::post().to(payments::post_3ds_payments_authorize)),
                 )
@@ -1013,6 +1111,25 @@ impl Customers {
                     web::resource("/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api_client)),
                 )
+                .service(
+                    web::resource("/portal_link").route(web::post().to(customer_portal_link_create)),
+                )
+                .service(
+                    web::resource("/portal_link/{merchant_id}/{customer_portal_link_id}")
+                        .route(web::get().to(customer_portal_link_render)),
+                )
+                .service(
+                    web::resource(
+                        "/portal_link/{merchant_id}/{customer_portal_link_id}/payment_methods/{payment_method_id}/delete",
+                    )
+                    .route(web::post().to(customer_portal_payment_method_delete)),
+                )
+                .service(
+                    web::resource(
+                        "/portal_link/{merchant_id}/{customer_portal_link_id}/billing_address",
+                    )
+                    .route(web::post().to(customer_portal_billing_address_update)),
+                )
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
@@ -1082,7 +1199,9 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/balance").route(web::get().to(payouts_balance)));
 
         #[cfg(feature = "olap")]
         {
@@ -1112,6 +1231,9 @@ impl Payouts {
                     .route(web::get().to(payouts_retrieve))
                     .route(web::put().to(payouts_update)),
             )
+            .service(
+                web::resource("/{payout_id}/attempts").route(web::get().to(payouts_attempts_list)),
+            )
             .service(web::resource("/{payout_id}/confirm").route(web::post().to(payouts_confirm)))
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
             .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)));
@@ -1179,7 +1301,8 @@ impl PaymentMethods {
                 )
                 .service(
                     web::resource("/collect/{merchant_id}/{collect_id}")
-                        .route(web::get().to(render_pm_collect_link)),
+                        .route(web::get().to(render_pm_collect_link))
+                        .route(web::post().to(submit_pm_collect_link)),
                 )
                 .service(
                     web::resource("/{payment_method_id}")
@@ -1199,12 +1322,47 @@ impl PaymentMethods {
                 )
                 .service(
                     web::resource("/auth/exchange").route(web::post().to(pm_auth::exchange_token)),
-                )
+                );
+
+            #[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+            {
+                route = route
+                    .service(
+                        web::resource("/{payment_method_id}/share")
+                            .route(web::post().to(share_payment_method_api))
+                            .route(web::get().to(list_shared_payment_methods_api)),
+                    )
+                    .service(
+                        web::resource("/{payment_method_id}/share/{target_merchant_id}")
+                            .route(web::delete().to(revoke_shared_payment_method_api)),
+                    )
+                    .service(
+                        web::resource("/{payment_method_id}/consent_events")
+                            .route(web::get().to(list_payment_method_consent_events_api)),
+                    );
+            }
         }
         route
     }
 }
 
+pub struct Vault;
+
+#[cfg(all(
+    feature = "oltp",
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+impl Vault {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/vault")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/tokenize").route(web::post().to(vault_tokenize_api)))
+            .service(web::resource("/detokenize").route(web::post().to(vault_detokenize_api)))
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub struct Recon;
 
@@ -1262,6 +1420,14 @@ impl Organization {
                     .route(web::get().to(admin::organization_retrieve))
                     .route(web::put().to(admin::organization_update)),
             )
+            .service(
+                web::resource("/{id}/merchant_accounts/search")
+                    .route(web::get().to(admin::merchant_account_search)),
+            )
+            .service(
+                web::resource("/{id}/usage/export")
+                    .route(web::get().to(admin::organization_usage_export)),
+            )
     }
 }
 
@@ -1315,6 +1481,10 @@ impl MerchantAccount {
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(admin::merchant_account_create)))
             .service(web::resource("/list").route(web::get().to(admin::merchant_account_list)))
+            .service(
+                web::resource("/config/reconcile")
+                    .route(web::post().to(admin::merchant_config_reconcile)),
+            )
             .service(
                 web::resource("/{id}/kv")
                     .route(web::post().to(admin::merchant_account_toggle_kv))
@@ -1327,6 +1497,25 @@ impl MerchantAccount {
             .service(
                 web::resource("/kv").route(web::post().to(admin::merchant_account_toggle_all_kv)),
             )
+            .service(
+                web::resource("/{id}/usage").route(web::get().to(admin::merchant_account_usage)),
+            )
+            .service(
+                web::resource("/{id}/data_retention/purge")
+                    .route(web::post().to(admin::merchant_account_data_retention_purge)),
+            )
+            .service(
+                web::resource("/{id}/rotate_publishable_key")
+                    .route(web::post().to(admin::merchant_account_rotate_publishable_key)),
+            )
+            .service(
+                web::resource("/{id}/payload_logs")
+                    .route(web::get().to(admin::merchant_account_payload_log_list)),
+            )
+            .service(
+                web::resource("/{id}/payload_logs/{request_id}")
+                    .route(web::get().to(admin::merchant_account_payload_log_retrieve)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(admin::retrieve_merchant_account))
@@ -1379,11 +1568,34 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(connector_create))
                         .route(web::get().to(connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/bulk_toggle")
+                        .route(web::post().to(connector_bulk_toggle)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/webhook_health")
+                        .route(web::get().to(connector_webhook_health)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/credentials/expiring")
+                        .route(web::get().to(connector_expiring_credentials_list)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/maintenance_windows",
+                    )
+                    .route(web::post().to(connector_maintenance_window_create))
+                    .route(web::get().to(connector_maintenance_window_list)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/maintenance_windows/{maintenance_window_id}")
+                        .route(web::delete().to(connector_maintenance_window_delete)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1434,6 +1646,80 @@ impl Mandates {
     }
 }
 
+pub struct InstallmentPlans;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl InstallmentPlans {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/installment_plans")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(installment_plans::installment_plan_create))
+                    .route(web::get().to(installment_plans::installment_plans_list)),
+            )
+            .service(
+                web::resource("/{plan_id}")
+                    .route(web::get().to(installment_plans::installment_plan_retrieve)),
+            )
+            .service(
+                web::resource("/{plan_id}/cancel")
+                    .route(web::post().to(installment_plans::installment_plan_cancel)),
+            )
+    }
+}
+
+pub struct TestClocks;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl TestClocks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/test_clocks")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(test_clocks::test_clock_create))
+                    .route(web::get().to(test_clocks::test_clocks_list)),
+            )
+            .service(
+                web::resource("/{clock_id}")
+                    .route(web::get().to(test_clocks::test_clock_retrieve))
+                    .route(web::delete().to(test_clocks::test_clock_delete)),
+            )
+            .service(
+                web::resource("/{clock_id}/attach")
+                    .route(web::post().to(test_clocks::test_clock_attach)),
+            )
+            .service(
+                web::resource("/{clock_id}/detach")
+                    .route(web::post().to(test_clocks::test_clock_detach)),
+            )
+            .service(
+                web::resource("/{clock_id}/advance")
+                    .route(web::post().to(test_clocks::test_clock_advance)),
+            )
+    }
+}
+
+pub struct LinkDelivery;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl LinkDelivery {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/link_deliveries")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(link_delivery::link_deliveries_list)))
+            .service(
+                web::resource("/{delivery_id}")
+                    .route(web::get().to(link_delivery::link_delivery_retrieve)),
+            )
+            .service(
+                web::resource("/{delivery_id}/status_callback")
+                    .route(web::post().to(link_delivery::link_delivery_status_callback)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -1520,6 +1806,45 @@ impl ApplePayCertificatesMigration {
     }
 }
 
+pub struct LockerMigration;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl LockerMigration {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/locker_migration/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::post().to(locker_migration::rust_locker_migration)),
+            )
+            .service(
+                web::resource("/schedule")
+                    .route(web::post().to(locker_migration::schedule_locker_migration)),
+            )
+            .service(
+                web::resource("/status")
+                    .route(web::get().to(locker_migration::retrieve_locker_migration_status)),
+            )
+    }
+}
+
+pub struct PaymentArchival;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl PaymentArchival {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/archival/payments")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/schedule")
+                    .route(web::post().to(payment_archival::schedule_payment_archival)),
+            )
+            .service(
+                web::resource("/status")
+                    .route(web::get().to(payment_archival::retrieve_payment_archival_status)),
+            )
+    }
+}
+
 pub struct Poll;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -1567,6 +1892,31 @@ impl ApiKeys {
     }
 }
 
+pub struct AdminApiKeys;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl AdminApiKeys {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/admin_api_keys")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(admin_api_keys::admin_api_key_create))
+                    .route(web::get().to(admin_api_keys::admin_api_key_list)),
+            )
+            .service(web::resource("/roles").route(web::get().to(admin_api_keys::admin_role_list)))
+            .service(
+                web::resource("/{key_id}")
+                    .route(web::get().to(admin_api_keys::admin_api_key_retrieve))
+                    .route(web::delete().to(admin_api_keys::admin_api_key_revoke)),
+            )
+            .service(
+                web::resource("/{key_id}/role")
+                    .route(web::post().to(admin_api_keys::admin_api_key_assign_role)),
+            )
+    }
+}
+
 pub struct Disputes;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -1630,6 +1980,11 @@ impl Files {
         web::scope("/files")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(files::files_create)))
+            .service(web::resource("/download/{token}").route(web::get().to(files::files_download)))
+            .service(
+                web::resource("/{file_id}/download_url")
+                    .route(web::get().to(files::files_download_url)),
+            )
             .service(
                 web::resource("/{file_id}")
                     .route(web::delete().to(files::files_delete))
@@ -1645,6 +2000,10 @@ impl Cache {
         web::scope("/cache")
             .app_data(web::Data::new(state))
             .service(web::resource("/invalidate/{key}").route(web::post().to(invalidate)))
+            .service(
+                web::resource("/bank_list/{connector}/invalidate")
+                    .route(web::post().to(invalidate_bank_list)),
+            )
     }
 }
 
@@ -1656,10 +2015,39 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payment_link::payments_link_list)))
+            .service(
+                web::resource("/templates")
+                    .route(web::post().to(payment_link::payment_link_template_create)),
+            )
+            .service(
+                web::resource("/templates/list")
+                    .route(web::get().to(payment_link::payment_link_template_list)),
+            )
+            .service(
+                web::resource("/templates/{template_id}")
+                    .route(web::get().to(payment_link::payment_link_template_retrieve))
+                    .route(web::post().to(payment_link::payment_link_template_update)),
+            )
+            .service(
+                web::resource("/templates/{template_id}/usages")
+                    .route(web::get().to(payment_link::payment_link_template_usage_list)),
+            )
             .service(
                 web::resource("/{payment_link_id}")
                     .route(web::get().to(payment_link::payment_link_retrieve)),
             )
+            .service(
+                web::resource("/{payment_link_id}/deactivate")
+                    .route(web::post().to(payment_link::payment_link_deactivate)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/expiry")
+                    .route(web::post().to(payment_link::payment_link_expiry_update)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/regenerate")
+                    .route(web::post().to(payment_link::payment_link_regenerate)),
+            )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
                     .route(web::get().to(payment_link::initiate_payment_link)),
@@ -1668,6 +2056,10 @@ impl PaymentLink {
                 web::resource("s/{merchant_id}/{payment_id}")
                     .route(web::get().to(payment_link::initiate_secure_payment_link)),
             )
+            .service(
+                web::resource("t/{merchant_id}/{template_id}")
+                    .route(web::get().to(payment_link::initiate_payment_link_template)),
+            )
             .service(
                 web::resource("status/{merchant_id}/{payment_id}")
                     .route(web::get().to(payment_link::payment_link_status)),
@@ -1792,6 +2184,15 @@ impl Profile {
             );
         }
 
+        #[cfg(feature = "payouts")]
+        {
+            route = route.service(
+                web::resource("/{profile_id}/ledger/payouts/{payout_id}/balance_override")
+                    .route(web::post().to(profiles::payout_balance_override_create))
+                    .route(web::delete().to(profiles::payout_balance_override_delete)),
+            );
+        }
+
         route = route.service(
             web::scope("/{profile_id}")
                 .service(
@@ -1804,9 +2205,67 @@ impl Profile {
                     web::resource("/toggle_extended_card_info")
                         .route(web::post().to(profiles::toggle_extended_card_info)),
                 )
+                .service(
+                    web::resource("/embed_token")
+                        .route(web::post().to(profiles::embed_token_create)),
+                )
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::scope("/webhook_secret")
+                        .service(
+                            web::resource("")
+                                .route(web::get().to(profiles::webhook_secret_retrieve)),
+                        )
+                        .service(
+                            web::resource("/generate")
+                                .route(web::post().to(profiles::webhook_secret_generate)),
+                        )
+                        .service(
+                            web::resource("/rotate")
+                                .route(web::post().to(profiles::webhook_secret_rotate)),
+                        ),
+                )
+                .service(
+                    web::scope("/email_templates")
+                        .service(
+                            web::resource("")
+                                .route(web::post().to(profiles::email_template_create))
+                                .route(web::get().to(profiles::email_templates_list)),
+                        )
+                        .service(
+                            web::resource("/{template_id}")
+                                .route(web::get().to(profiles::email_template_retrieve))
+                                .route(web::post().to(profiles::email_template_update))
+                                .route(web::delete().to(profiles::email_template_delete)),
+                        ),
+                )
+                .service(
+                    web::scope("/payment_templates")
+                        .service(
+                            web::resource("")
+                                .route(web::post().to(profiles::payment_template_create))
+                                .route(web::get().to(profiles::payment_templates_list)),
+                        )
+                        .service(
+                            web::resource("/{template_id}")
+                                .route(web::get().to(profiles::payment_template_retrieve))
+                                .route(web::post().to(profiles::payment_template_update))
+                                .route(web::delete().to(profiles::payment_template_delete)),
+                        ),
+                )
+                .service(
+                    web::scope("/ledger")
+                        .service(
+                            web::resource("/balances")
+                                .route(web::get().to(profiles::ledger_balances_retrieve)),
+                        )
+                        .service(
+                            web::resource("/statement")
+                                .route(web::get().to(profiles::ledger_statement_retrieve)),
+                        ),
                 ),
         );
 
@@ -1978,6 +2437,34 @@ impl User {
                                 .route(web::get().to(user::generate_recovery_codes)),
                         ),
                 )
+                .service(
+                    web::scope("/webauthn")
+                        .service(
+                            web::resource("/register/begin")
+                                .route(web::get().to(user::webauthn_register_begin)),
+                        )
+                        .service(
+                            web::resource("/register/finish")
+                                .route(web::post().to(user::webauthn_register_finish)),
+                        )
+                        .service(
+                            web::resource("/authenticate/begin")
+                                .route(web::get().to(user::webauthn_authenticate_begin)),
+                        )
+                        .service(
+                            web::resource("/authenticate/verify")
+                                .route(web::post().to(user::webauthn_verify)),
+                        )
+                        .service(
+                            web::resource("/credentials")
+                                .route(web::get().to(user::list_webauthn_credentials)),
+                        )
+                        .service(
+                            web::resource("/credentials/{credential_id}")
+                                .route(web::delete().to(user::delete_webauthn_credential)),
+                        ),
+                )
+                .service(web::resource("/reset").route(web::post().to(user::reset_two_factor_auth)))
                 .service(
                     web::resource("/terminate")
                         .route(web::get().to(user::terminate_two_factor_auth)),
@@ -1996,6 +2483,10 @@ impl User {
                         .route(web::get().to(user::list_user_authentication_methods)),
                 )
                 .service(web::resource("/url").route(web::get().to(user::get_sso_auth_url)))
+                .service(
+                    web::resource("/url_by_email")
+                        .route(web::post().to(user::get_sso_auth_url_by_email)),
+                )
                 .service(
                     web::resource("/select").route(web::post().to(user::terminate_auth_select)),
                 ),
@@ -2156,10 +2647,57 @@ impl User {
                 ),
         );
 
+        route = route.service(
+            web::scope("/scim_config")
+                .service(
+                    web::resource("")
+                        .route(web::get().to(user::scim::get_scim_config))
+                        .route(web::post().to(user::scim::upsert_scim_config))
+                        .route(web::delete().to(user::scim::delete_scim_config)),
+                )
+                .service(
+                    web::resource("/rotate_token")
+                        .route(web::post().to(user::scim::rotate_scim_token)),
+                ),
+        );
+
         route
     }
 }
 
+/// SCIM 2.0 endpoints used by identity providers to provision the users of a merchant
+pub struct Scim;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Scim {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/scim/v2")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/Users")
+                    .route(web::get().to(user::scim::list_users))
+                    .route(web::post().to(user::scim::create_user)),
+            )
+            .service(
+                web::resource("/Users/{user_id}")
+                    .route(web::get().to(user::scim::retrieve_user))
+                    .route(web::put().to(user::scim::replace_user))
+                    .route(web::patch().to(user::scim::patch_user))
+                    .route(web::delete().to(user::scim::delete_user)),
+            )
+            .service(
+                web::resource("/Groups")
+                    .route(web::get().to(user::scim::list_groups))
+                    .route(web::post().to(user::scim::create_group)),
+            )
+            .service(
+                web::resource("/Groups/{group_id}")
+                    .route(web::get().to(user::scim::retrieve_group))
+                    .route(web::patch().to(user::scim::patch_group)),
+            )
+    }
+}
+
 pub struct ConnectorOnboarding;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -2203,6 +2741,10 @@ impl WebhookEvents {
                     .service(
                         web::resource("retry")
                             .route(web::post().to(webhook_events::retry_webhook_delivery_attempt)),
+                    )
+                    .service(
+                        web::resource("preview")
+                            .route(web::get().to(webhook_events::preview_webhook_payload)),
                     ),
             )
     }
@@ -1,9 +1,10 @@
 use actix_web::{web, HttpRequest, Responder};
+use api_models::enums as api_enums;
 use router_env::{instrument, tracing, Flow};
 
 use super::AppState;
 use crate::{
-    core::{api_locking, cache},
+    core::{api_locking, cache, payment_methods::bank_list},
     services::{api, authentication as auth},
 };
 
@@ -28,3 +29,25 @@ pub async fn invalidate(
     )
     .await
 }
+
+#[instrument(skip_all)]
+pub async fn invalidate_bank_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::Connector>,
+) -> impl Responder {
+    let flow = Flow::BankListCacheInvalidate;
+
+    let connector = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        connector,
+        |state, _, connector, _| bank_list::invalidate_bank_list_cache(state, connector),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
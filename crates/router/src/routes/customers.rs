@@ -43,13 +43,14 @@ pub async fn customers_retrieve(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<id_type::CustomerId>,
+    query_params: web::Query<customers::CustomerRetrieveBody>,
 ) -> HttpResponse {
     let flow = Flow::CustomersRetrieve;
 
-    let payload = web::Json(customers::CustomerId::new_customer_id_struct(
-        path.into_inner(),
-    ))
-    .into_inner();
+    let payload = customers::CustomerRetrieveRequest {
+        customer_id: path.into_inner(),
+        expand: query_params.into_inner().expand,
+    };
 
     let auth = if auth::is_jwt_auth(req.headers()) {
         Box::new(auth::JWTAuth {
@@ -327,3 +328,140 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+/// Generate a portal link for a customer to manage their saved payment methods
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPortalLinkCreate))]
+pub async fn customer_portal_link_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::customers::CustomerPortalLinkRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPortalLinkCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            portal::create_customer_portal_link(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPortalLinkRender))]
+pub async fn customer_portal_link_render(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPortalLinkRender;
+    let (merchant_id, customer_portal_link_id) = path.into_inner();
+    let payload = api_models::customers::CustomerPortalLinkRenderRequest {
+        merchant_id: merchant_id.clone(),
+        customer_portal_link_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            portal::render_customer_portal_link(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPortalPaymentMethodDelete))]
+pub async fn customer_portal_payment_method_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String, String)>,
+    json_payload: web::Json<api_models::customers::CustomerPortalPaymentMethodDeleteRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPortalPaymentMethodDelete;
+    let (merchant_id, customer_portal_link_id, payment_method_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = merchant_id.clone();
+    payload.customer_portal_link_id = customer_portal_link_id;
+    payload.payment_method_id = payment_method_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            portal::delete_customer_portal_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPortalBillingAddressUpdate))]
+pub async fn customer_portal_billing_address_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+    json_payload: web::Json<api_models::customers::CustomerPortalBillingAddressUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPortalBillingAddressUpdate;
+    let (merchant_id, customer_portal_link_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = merchant_id.clone();
+    payload.customer_portal_link_id = customer_portal_link_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            portal::update_customer_portal_billing_address(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
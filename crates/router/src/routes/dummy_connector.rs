@@ -148,3 +148,24 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+
+#[cfg(all(feature = "dummy_connector_webhook_simulation", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyWebhookSimulate))]
+pub async fn dummy_connector_simulate_webhook(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorWebhookSimulateRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyWebhookSimulate;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::simulate_webhook(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,3 +1,4 @@
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const REFUND_SETTLEMENT_DURATION_IN_DAYS: i64 = 5;
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
@@ -227,3 +227,81 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+#[cfg(all(feature = "dummy_connector_webhook_simulation", feature = "v1"))]
+pub async fn simulate_webhook(
+    state: SessionState,
+    req: types::DummyConnectorWebhookSimulateRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorWebhookSimulateResponse> {
+    let webhook_body = match (req.payment_id, req.refund_id) {
+        (Some(payment_id), None) => {
+            let payment_data = utils::get_payment_data_from_payment_id(
+                &state,
+                payment_id.get_string_repr().to_owned(),
+            )
+            .await?;
+            let updated_payment_data = types::DummyConnectorPaymentData {
+                status: req.status,
+                next_action: None,
+                ..payment_data
+            };
+            utils::store_data_in_redis(
+                &state,
+                updated_payment_data.payment_id.get_string_repr().to_owned(),
+                updated_payment_data.clone(),
+                state.conf.dummy_connector.payment_ttl,
+            )
+            .await?;
+            types::DummyConnectorWebhookBody::Payment(updated_payment_data.into())
+        }
+        (None, Some(refund_id)) => {
+            let redis_conn = state
+                .store
+                .get_redis_conn()
+                .change_context(errors::DummyConnectorErrors::InternalServerError)
+                .attach_printable("Failed to get redis connection")?;
+            let refund_data = redis_conn
+                .get_and_deserialize_key::<types::DummyConnectorRefundResponse>(
+                    refund_id.as_str(),
+                    "DummyConnectorRefundResponse",
+                )
+                .await
+                .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
+            let updated_refund_data = types::DummyConnectorRefundResponse {
+                status: req.status,
+                ..refund_data
+            };
+            utils::store_data_in_redis(
+                &state,
+                refund_id,
+                updated_refund_data.clone(),
+                state.conf.dummy_connector.refund_ttl,
+            )
+            .await?;
+            types::DummyConnectorWebhookBody::Refund(updated_refund_data)
+        }
+        _ => {
+            return Err(error_stack::report!(
+                errors::DummyConnectorErrors::MissingRequiredField {
+                    field_name: "payment_id or refund_id",
+                }
+            ))
+        }
+    };
+
+    let status_code = utils::send_signed_webhook(
+        &state,
+        &req.merchant_id,
+        &req.merchant_connector_id,
+        &req.webhook_secret,
+        &webhook_body,
+    )
+    .await?;
+
+    Ok(api::ApplicationResponse::Json(
+        types::DummyConnectorWebhookSimulateResponse {
+            webhook_body,
+            status_code,
+        },
+    ))
+}
@@ -18,6 +18,7 @@ pub enum Flow {
     DummyPaymentComplete,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyWebhookSimulate,
 }
 
 impl FlowMetric for Flow {}
@@ -345,6 +346,8 @@ pub struct DummyConnectorRefundResponse {
     pub created: PrimitiveDateTime,
     pub payment_amount: i64,
     pub refund_amount: i64,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_settlement_date: Option<PrimitiveDateTime>,
 }
 
 impl DummyConnectorRefundResponse {
@@ -363,6 +366,9 @@ impl DummyConnectorRefundResponse {
             created,
             payment_amount,
             refund_amount,
+            expected_settlement_date: Some(created.saturating_add(time::Duration::days(
+                consts::REFUND_SETTLEMENT_DURATION_IN_DAYS,
+            ))),
         }
     }
 }
@@ -372,6 +378,36 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+/// Request for simulating a webhook of the dummy connector. The payment or refund is updated to
+/// the given status, and a webhook signed with the webhook secret is delivered to the merchant
+/// connector account
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorWebhookSimulateRequest {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// The webhook secret configured for the merchant connector account
+    pub webhook_secret: Secret<String>,
+    /// The dummy connector payment the webhook is sent for
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    /// The dummy connector refund the webhook is sent for
+    pub refund_id: Option<String>,
+    pub status: DummyConnectorStatus,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "object", content = "data", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookBody {
+    Payment(DummyConnectorPaymentResponse),
+    Refund(DummyConnectorRefundResponse),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorWebhookSimulateResponse {
+    pub webhook_body: DummyConnectorWebhookBody,
+    /// The status code of the response to the webhook
+    pub status_code: u16,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
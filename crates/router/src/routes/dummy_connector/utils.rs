@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 
 use common_utils::ext_traits::AsyncExt;
+#[cfg(all(feature = "dummy_connector_webhook_simulation", feature = "v1"))]
+use common_utils::{crypto::SignMessage, ext_traits::Encode, request::RequestContent};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
@@ -12,6 +14,8 @@ use super::{
     types::{self, GetPaymentMethodDetails},
 };
 use crate::{configs::settings, routes::SessionState};
+#[cfg(all(feature = "dummy_connector_webhook_simulation", feature = "v1"))]
+use crate::{headers, services};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
@@ -350,3 +354,50 @@ impl types::DummyConnectorPaymentData {
             .build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
     }
 }
+
+/// Signs the webhook body with the webhook secret of the merchant connector account, and delivers
+/// it to the incoming webhooks endpoint of the merchant connector account. Returns the status
+/// code of the response to the webhook.
+#[cfg(all(feature = "dummy_connector_webhook_simulation", feature = "v1"))]
+pub async fn send_signed_webhook(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    webhook_secret: &masking::Secret<String>,
+    webhook_body: &types::DummyConnectorWebhookBody,
+) -> types::DummyConnectorResult<u16> {
+    let body = webhook_body
+        .encode_to_string_of_json()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to serialize the webhook body")?;
+    let signature = common_utils::crypto::HmacSha256
+        .sign_message(webhook_secret.peek().as_bytes(), body.as_bytes())
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to sign the webhook body")?;
+
+    let webhook_url = format!(
+        "{}/webhooks/{}/{}",
+        state.base_url,
+        merchant_id.get_string_repr(),
+        merchant_connector_id.get_string_repr()
+    );
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&webhook_url)
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/json")
+        .header(
+            headers::X_DUMMY_CONNECTOR_SIGNATURE,
+            &hex::encode(signature),
+        )
+        .set_body(RequestContent::RawBytes(body.into_bytes()))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(state, request, None, false)
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to deliver the webhook")?;
+    Ok(response.status().as_u16())
+}
@@ -149,3 +149,70 @@ pub async fn files_retrieve(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+/// Files - Create Download URL
+///
+/// To create a short-lived URL for downloading a file
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}/download_url",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "Download URL created", body = FileDownloadUrlResponse),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Create a File Download URL",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateFileDownloadUrl))]
+pub async fn files_download_url(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CreateFileDownloadUrl;
+    let file_id = files::FileId {
+        file_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        file_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            files_download_url_core(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DownloadFile))]
+pub async fn files_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DownloadFile;
+    let token = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        token,
+        |state, _: (), token, _| files_download_core(state, token),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,128 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::installment_plans as installment_plans_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, installment_plans},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::InstallmentPlanCreate))]
+pub async fn installment_plan_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<installment_plans_api::InstallmentPlanCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::InstallmentPlanCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            installment_plans::create_installment_plan(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::InstallmentPlanRetrieve))]
+pub async fn installment_plan_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InstallmentPlanRetrieve;
+    let payload = installment_plans_api::InstallmentPlanId {
+        plan_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            installment_plans::retrieve_installment_plan(
+                state,
+                auth.merchant_account,
+                payload.plan_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::InstallmentPlanList))]
+pub async fn installment_plans_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<installment_plans_api::InstallmentPlanListConstraints>,
+) -> impl Responder {
+    let flow = Flow::InstallmentPlanList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            installment_plans::list_installment_plans(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::InstallmentPlanCancel))]
+pub async fn installment_plan_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InstallmentPlanCancel;
+    let payload = installment_plans_api::InstallmentPlanId {
+        plan_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            installment_plans::cancel_installment_plan(
+                state,
+                auth.merchant_account,
+                payload.plan_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,89 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, link_delivery},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::LinkDeliveryRetrieve))]
+pub async fn link_delivery_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::LinkDeliveryRetrieve;
+    let delivery_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        delivery_id,
+        |state, auth: auth::AuthenticationData, delivery_id, _| {
+            link_delivery::retrieve_link_delivery(state, auth.merchant_account, delivery_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LinkDeliveryList))]
+pub async fn link_deliveries_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_models::link_delivery::LinkDeliveryListConstraints>,
+) -> impl Responder {
+    let flow = Flow::LinkDeliveryList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            link_delivery::list_link_deliveries(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LinkDeliveryStatusCallback))]
+pub async fn link_delivery_status_callback(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::LinkDeliveryStatusCallback;
+    let delivery_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        delivery_id,
+        |state, _: (), delivery_id, _| {
+            link_delivery::handle_status_callback(state, &req, delivery_id, body.clone())
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -47,11 +47,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
-            | Flow::MerchantAccountList => Self::MerchantAccount,
-
-            Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
-                Self::Organization
-            }
+            | Flow::MerchantAccountList
+            | Flow::MerchantAccountSearch
+            | Flow::MerchantUsageRetrieve
+            | Flow::MerchantDataRetentionPurge
+            | Flow::MerchantPublishableKeyRotate
+            | Flow::MerchantPayloadLogRetrieve
+            | Flow::MerchantPayloadLogList
+            | Flow::MerchantConfigReconcile => Self::MerchantAccount,
+
+            Flow::OrganizationCreate
+            | Flow::OrganizationRetrieve
+            | Flow::OrganizationUpdate
+            | Flow::OrganizationUsageExport => Self::Organization,
 
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
@@ -80,6 +88,12 @@ impl From<Flow> for ApiIdentifier {
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
+            | Flow::MerchantConnectorsBulkToggle
+            | Flow::MerchantConnectorsWebhookHealth
+            | Flow::MerchantConnectorsExpiringCredentialsList
+            | Flow::ConnectorMaintenanceWindowCreate
+            | Flow::ConnectorMaintenanceWindowList
+            | Flow::ConnectorMaintenanceWindowDelete
             | Flow::MerchantConnectorsDelete
             | Flow::MerchantConnectorsList => Self::MerchantConnector,
 
@@ -94,21 +108,46 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
-            | Flow::CustomersList => Self::Customers,
+            | Flow::CustomersList
+            | Flow::CustomerPortalLinkCreate
+            | Flow::CustomerPortalLinkRender
+            | Flow::CustomerPortalPaymentMethodDelete
+            | Flow::CustomerPortalBillingAddressUpdate => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
-            Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
+            Flow::MandatesRetrieve
+            | Flow::MandatesRevoke
+            | Flow::MandatesList
+            | Flow::InstallmentPlanCreate
+            | Flow::InstallmentPlanRetrieve
+            | Flow::InstallmentPlanList
+            | Flow::InstallmentPlanCancel => Self::Mandates,
+
+            Flow::TestClockCreate
+            | Flow::TestClockRetrieve
+            | Flow::TestClockList
+            | Flow::TestClockAttach
+            | Flow::TestClockDetach
+            | Flow::TestClockAdvance
+            | Flow::TestClockDelete => Self::MerchantAccount,
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
+            | Flow::VaultTokenize
+            | Flow::VaultDetokenize
+            | Flow::PaymentMethodsShare
+            | Flow::PaymentMethodsShareList
+            | Flow::PaymentMethodsShareRevoke
+            | Flow::PaymentMethodsConsentEventsList
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
             | Flow::PaymentMethodCollectLink
+            | Flow::PaymentMethodCollectLinkSubmit
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
@@ -122,16 +161,21 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
+            | Flow::PaymentsCaptureOnShipment
+            | Flow::PaymentsDccOffer
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
+            | Flow::PaymentsSessionTokenPrewarm
             | Flow::PaymentsStart
             | Flow::PaymentsList
             | Flow::PaymentsFilters
             | Flow::PaymentsAggregate
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
+            | Flow::PaymentsInvoiceGenerate
+            | Flow::PaymentsClientSecretRotate
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
@@ -142,7 +186,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsCreateIntent
             | Flow::PaymentsGetIntent
             | Flow::PaymentsPostSessionTokens
-            | Flow::PaymentStartRedirection => Self::Payments,
+            | Flow::PaymentStartRedirection
+            | Flow::SchedulePaymentArchival
+            | Flow::RetrievePaymentArchivalStatus => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -152,8 +198,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsList
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
+            | Flow::PayoutsBalance
+            | Flow::PayoutAttemptsList
             | Flow::PayoutsConfirm
-            | Flow::PayoutLinkInitiate => Self::Payouts,
+            | Flow::PayoutLinkInitiate
+            | Flow::PayoutBalanceOverrideCreate
+            | Flow::PayoutBalanceOverrideDelete => Self::Payouts,
 
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
@@ -168,13 +218,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventPayloadPreview => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
-            | Flow::ApiKeyList => Self::ApiKeys,
+            | Flow::ApiKeyList
+            | Flow::AdminApiKeyCreate
+            | Flow::AdminApiKeyRetrieve
+            | Flow::AdminApiKeyList
+            | Flow::AdminApiKeyRoleAssign
+            | Flow::AdminApiKeyRevoke
+            | Flow::AdminRoleList => Self::ApiKeys,
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
@@ -187,9 +244,13 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CardsInfo => Self::CardsInfo,
 
-            Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
+            Flow::CreateFile
+            | Flow::DeleteFile
+            | Flow::RetrieveFile
+            | Flow::CreateFileDownloadUrl
+            | Flow::DownloadFile => Self::Files,
 
-            Flow::CacheInvalidate => Self::Cache,
+            Flow::CacheInvalidate | Flow::BankListCacheInvalidate => Self::Cache,
 
             Flow::ProfileCreate
             | Flow::ProfileUpdate
@@ -197,17 +258,47 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::WebhookSecretRetrieve
+            | Flow::WebhookSecretGenerate
+            | Flow::WebhookSecretRotate
+            | Flow::EmailTemplateCreate
+            | Flow::EmailTemplateRetrieve
+            | Flow::EmailTemplateList
+            | Flow::EmailTemplateUpdate
+            | Flow::EmailTemplateDelete
+            | Flow::EmbedTokenCreate
+            | Flow::PaymentTemplateCreate
+            | Flow::PaymentTemplateRetrieve
+            | Flow::PaymentTemplateList
+            | Flow::PaymentTemplateUpdate
+            | Flow::PaymentTemplateDelete
+            | Flow::LedgerBalancesRetrieve
+            | Flow::LedgerStatementRetrieve => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkDeactivate
+            | Flow::PaymentLinkExpiryUpdate
+            | Flow::PaymentLinkRegenerate
+            | Flow::PaymentLinkTemplateCreate
+            | Flow::PaymentLinkTemplateRetrieve
+            | Flow::PaymentLinkTemplateList
+            | Flow::PaymentLinkTemplateUpdate
+            | Flow::PaymentLinkTemplateUsageList
+            | Flow::PaymentLinkTemplateInitiate
+            | Flow::LinkDeliveryRetrieve
+            | Flow::LinkDeliveryList
+            | Flow::LinkDeliveryStatusCallback => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
-            Flow::RustLockerMigration => Self::RustLockerMigration,
+            Flow::RustLockerMigration
+            | Flow::ScheduleLockerMigration
+            | Flow::RetrieveLockerMigrationStatus => Self::RustLockerMigration,
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
@@ -249,12 +340,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RecoveryCodeVerify
             | Flow::RecoveryCodesGenerate
             | Flow::TerminateTwoFactorAuth
+            | Flow::WebauthnRegisterBegin
+            | Flow::WebauthnRegisterFinish
+            | Flow::WebauthnAuthenticateBegin
+            | Flow::WebauthnVerify
+            | Flow::WebauthnCredentialsList
+            | Flow::WebauthnCredentialDelete
+            | Flow::ResetTwoFactorAuth
             | Flow::TwoFactorAuthStatus
             | Flow::CreateUserAuthenticationMethod
             | Flow::UpdateUserAuthenticationMethod
             | Flow::ListUserAuthenticationMethods
             | Flow::UserTransferKey
             | Flow::GetSsoAuthUrl
+            | Flow::GetSsoAuthUrlByEmail
             | Flow::SignInWithSso
             | Flow::ListOrgForUser
             | Flow::ListMerchantsForUserInOrg
@@ -266,7 +365,21 @@ impl From<Flow> for ApiIdentifier {
             | Flow::UploadFileToThemeStorage
             | Flow::CreateTheme
             | Flow::UpdateTheme
-            | Flow::DeleteTheme => Self::User,
+            | Flow::DeleteTheme
+            | Flow::ScimConfigUpsert
+            | Flow::ScimConfigRetrieve
+            | Flow::ScimConfigDelete
+            | Flow::ScimTokenRotate
+            | Flow::ScimUserCreate
+            | Flow::ScimUserRetrieve
+            | Flow::ScimUserList
+            | Flow::ScimUserReplace
+            | Flow::ScimUserPatch
+            | Flow::ScimUserDelete
+            | Flow::ScimGroupCreate
+            | Flow::ScimGroupRetrieve
+            | Flow::ScimGroupList
+            | Flow::ScimGroupPatch => Self::User,
 
             Flow::ListRolesV2
             | Flow::ListInvitableRolesAtEntityLevel
@@ -25,3 +25,42 @@ pub async fn rust_locker_migration(
     ))
     .await
 }
+
+pub async fn schedule_locker_migration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::locker_migration::LockerMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScheduleLockerMigration;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| locker_migration::schedule_locker_migration(state, &merchant_id, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_locker_migration_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveLockerMigrationStatus;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &merchant_id,
+        |state, _, _, _| locker_migration::retrieve_locker_migration_status(state, &merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,4 +1,5 @@
 pub mod bg_metrics_collector;
+pub mod merchant_labels;
 pub mod request;
 pub mod utils;
 
@@ -15,9 +16,12 @@ counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 counter_metric!(REQUEST_STATUS, GLOBAL_METER);
 histogram_metric!(REQUEST_TIME, GLOBAL_METER);
 histogram_metric!(EXTERNAL_REQUEST_TIME, GLOBAL_METER);
+counter_metric!(TRAFFIC_MIRRORED_REQUEST_COUNT, GLOBAL_METER); // Requests duplicated to the canary deployment
 
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
+counter_metric!(PAYMENT_ATTEMPT_UPDATE_CONFLICT_COUNT, GLOBAL_METER); // Updates of an attempt which conflicted with a concurrent update
+counter_metric!(PAYMENT_ATTEMPT_STALE_UPDATE_DISCARDED_COUNT, GLOBAL_METER); // Conflicting updates of an attempt discarded for status precedence
 
 counter_metric!(PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_PAYMENT, GLOBAL_METER);
@@ -68,6 +72,8 @@ counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
 
 counter_metric!(RESPONSE_DESERIALIZATION_FAILURE, GLOBAL_METER);
 counter_metric!(CONNECTOR_ERROR_RESPONSE_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_CALL_FAILURE_COUNT, GLOBAL_METER); // Connector calls failed with a server error or without a response
+counter_metric!(CONNECTOR_SLO_BREACH_COUNT, GLOBAL_METER);
 counter_metric!(REQUEST_TIMEOUT_COUNT, GLOBAL_METER);
 
 counter_metric!(EXECUTE_PRETASK_COUNT, GLOBAL_METER);
@@ -89,6 +95,7 @@ counter_metric!(CONNECTOR_HTTP_STATUS_CODE_5XX_COUNT, GLOBAL_METER);
 counter_metric!(CARD_LOCKER_FAILURES, GLOBAL_METER);
 counter_metric!(CARD_LOCKER_SUCCESSFUL_RESPONSE, GLOBAL_METER);
 counter_metric!(TEMP_LOCKER_FAILURES, GLOBAL_METER);
+counter_metric!(LOCKER_OUTAGE_FALLBACK_COUNT, GLOBAL_METER);
 histogram_metric!(CARD_ADD_TIME, GLOBAL_METER);
 histogram_metric!(CARD_GET_TIME, GLOBAL_METER);
 histogram_metric!(CARD_DELETE_TIME, GLOBAL_METER);
@@ -137,6 +144,9 @@ counter_metric!(ACCESS_TOKEN_CACHE_MISS, GLOBAL_METER);
 // A counter to indicate the integrity check failures
 counter_metric!(INTEGRITY_CHECK_FAILED, GLOBAL_METER);
 
+// A counter to indicate the client side requests received from origins not allowed for the profile
+counter_metric!(CLIENT_ORIGIN_NOT_ALLOWED, GLOBAL_METER);
+
 // Network Tokenization metrics
 histogram_metric!(GENERATE_NETWORK_TOKEN_TIME, GLOBAL_METER);
 histogram_metric!(FETCH_NETWORK_TOKEN_TIME, GLOBAL_METER);
@@ -0,0 +1,75 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use once_cell::sync::Lazy;
+use router_env::logger;
+
+use crate::configs::settings::MerchantMetricsLabelsConfig;
+
+/// The label value under which the metrics of the merchants and profiles beyond the limits are
+/// recorded
+const OTHER_LABEL_VALUE: &str = "other";
+
+/// The merchants labelled in the metrics of this instance, bounded by the configured limit
+static LABELLED_MERCHANT_IDS: Lazy<Mutex<HashSet<String>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// The profiles labelled in the metrics of this instance, bounded by the configured limit
+static LABELLED_PROFILE_IDS: Lazy<Mutex<HashSet<String>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// The merchant and profile attributes of a metric, if the metrics are configured to be labelled
+/// by them. The merchants and profiles seen after the limits of the instance are reached are
+/// labelled as `other`, except for the merchants which are configured to always be labelled.
+pub fn get_merchant_attributes(
+    config: &MerchantMetricsLabelsConfig,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> Vec<(&'static str, String)> {
+    if !config.enabled {
+        return Vec::new();
+    }
+
+    let merchant_label = if config.merchant_ids.contains(merchant_id) {
+        merchant_id.get_string_repr().to_owned()
+    } else {
+        get_label_value(
+            &LABELLED_MERCHANT_IDS,
+            merchant_id.get_string_repr(),
+            config.max_merchants,
+        )
+    };
+    let mut attributes = vec![("merchant_id", merchant_label)];
+
+    if let Some(profile_id) = profile_id {
+        attributes.push((
+            "profile_id",
+            get_label_value(
+                &LABELLED_PROFILE_IDS,
+                profile_id.get_string_repr(),
+                config.max_profiles,
+            ),
+        ));
+    }
+
+    attributes
+}
+
+fn get_label_value(
+    labelled_ids: &Mutex<HashSet<String>>,
+    id: &str,
+    max_labelled_ids: usize,
+) -> String {
+    let Ok(mut labelled_ids) = labelled_ids.lock() else {
+        logger::error!("Merchant metrics labels lock is poisoned");
+        return OTHER_LABEL_VALUE.to_owned();
+    };
+
+    if labelled_ids.contains(id) {
+        id.to_owned()
+    } else if labelled_ids.len() < max_labelled_ids {
+        labelled_ids.insert(id.to_owned());
+        id.to_owned()
+    } else {
+        OTHER_LABEL_VALUE.to_owned()
+    }
+}
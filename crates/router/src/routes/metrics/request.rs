@@ -1,7 +1,7 @@
 use router_env::metrics::add_attributes;
 
-use super::utils as metric_utils;
-use crate::services::ApplicationResponse;
+use super::{merchant_labels, utils as metric_utils};
+use crate::{configs::settings::MerchantMetricsLabelsConfig, services::ApplicationResponse};
 
 pub async fn record_request_time_metric<F, R>(
     future: F,
@@ -29,15 +29,22 @@ pub fn status_code_metrics(
     status_code: String,
     flow: String,
     merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+    merchant_labels_config: &MerchantMetricsLabelsConfig,
 ) {
+    let merchant_attributes = if merchant_labels_config.enabled {
+        merchant_labels::get_merchant_attributes(merchant_labels_config, &merchant_id, profile_id)
+    } else {
+        vec![("merchant_id", merchant_id.get_string_repr().to_owned())]
+    };
     super::REQUEST_STATUS.add(
         &super::CONTEXT,
         1,
-        &add_attributes([
-            ("status_code", status_code),
-            ("flow", flow),
-            ("merchant_id", merchant_id.get_string_repr().to_owned()),
-        ]),
+        &add_attributes(
+            [("status_code", status_code), ("flow", flow)]
+                .into_iter()
+                .chain(merchant_attributes),
+        ),
     )
 }
 
@@ -51,6 +58,7 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::PaymentLinkForm(_)
         | ApplicationResponse::FileData(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
+        ApplicationResponse::JsonAccepted(_) => 202,
         ApplicationResponse::JsonForRedirection(_) => 302,
     }
 }
@@ -1,6 +1,8 @@
 use actix_web::{web, Responder};
 use router_env::{instrument, tracing, Flow};
 
+#[cfg(feature = "v1")]
+use crate::core::payment_link::template;
 use crate::{
     core::{api_locking, payment_link::*},
     services::{api, authentication as auth},
@@ -57,6 +59,7 @@ pub async fn initiate_payment_link(
         common_utils::id_type::MerchantId,
         common_utils::id_type::PaymentId,
     )>,
+    query: web::Query<api_models::payments::PaymentLinkRenderQuery>,
 ) -> impl Responder {
     let flow = Flow::PaymentLinkInitiate;
     let (merchant_id, payment_id) = path.into_inner();
@@ -64,6 +67,7 @@ pub async fn initiate_payment_link(
     let payload = api_models::payments::PaymentLinkInitiateRequest {
         payment_id,
         merchant_id: merchant_id.clone(),
+        link_token: query.into_inner().link_token,
     };
     let headers = req.headers();
     Box::pin(api::server_wrap(
@@ -78,6 +82,7 @@ pub async fn initiate_payment_link(
                 auth.key_store,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                payload.link_token.clone(),
                 headers,
             )
         },
@@ -94,12 +99,14 @@ pub async fn initiate_secure_payment_link(
         common_utils::id_type::MerchantId,
         common_utils::id_type::PaymentId,
     )>,
+    query: web::Query<api_models::payments::PaymentLinkRenderQuery>,
 ) -> impl Responder {
     let flow = Flow::PaymentSecureLinkInitiate;
     let (merchant_id, payment_id) = path.into_inner();
     let payload = api_models::payments::PaymentLinkInitiateRequest {
         payment_id,
         merchant_id: merchant_id.clone(),
+        link_token: query.into_inner().link_token,
     };
     let headers = req.headers();
     Box::pin(api::server_wrap(
@@ -114,6 +121,7 @@ pub async fn initiate_secure_payment_link(
                 auth.key_store,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                payload.link_token.clone(),
                 headers,
             )
         },
@@ -168,6 +176,134 @@ pub async fn payments_link_list(
     .await
 }
 
+/// Payment Link - Deactivate
+///
+/// Deactivates a Payment Link. A deactivated payment link can no longer be used to make a payment
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/deactivate",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "Payment link deactivated", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Deactivate a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkDeactivate))]
+pub async fn payment_link_deactivate(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkDeactivate;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth: auth::AuthenticationData, payment_link_id, _| {
+            deactivate_payment_link(state, auth.merchant_account, payment_link_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - Update Expiry
+///
+/// Extends or shortens the expiry of a Payment Link along with the session expiry of its payment
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/expiry",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    request_body = PaymentLinkExpiryUpdateRequest,
+    responses(
+        (status = 200, description = "Payment link expiry updated", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Invalid session expiry or payment link has been deactivated"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Update the expiry of a Payment Link",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkExpiryUpdate))]
+pub async fn payment_link_expiry_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::PaymentLinkExpiryUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkExpiryUpdate;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            update_payment_link_expiry(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_link_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - Regenerate
+///
+/// Generates new URLs for a Payment Link. The previously issued URLs of the payment link stop working
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/regenerate",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "Payment link regenerated", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Payment link has been deactivated"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Regenerate a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkRegenerate))]
+pub async fn payment_link_regenerate(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkRegenerate;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth: auth::AuthenticationData, payment_link_id, _| {
+            regenerate_payment_link(state, auth.merchant_account, payment_link_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn payment_link_status(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -182,6 +318,7 @@ pub async fn payment_link_status(
     let payload = api_models::payments::PaymentLinkInitiateRequest {
         payment_id,
         merchant_id: merchant_id.clone(),
+        link_token: None,
     };
     let headers = req.headers();
     Box::pin(api::server_wrap(
@@ -204,3 +341,246 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Payment Link Template - Create
+///
+/// Creates a reusable payment link. Every visit of the link creates a new payment from the template
+#[utoipa::path(
+    post,
+    path = "/payment_link/templates",
+    request_body = PaymentLinkTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Payment link template created", body = PaymentLinkTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Link",
+    operation_id = "Create a Payment Link Template",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkTemplateCreate))]
+pub async fn payment_link_template_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::PaymentLinkTemplateCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            template::create_payment_link_template(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link Template - List
+///
+/// Lists the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/templates/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of templates to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of templates to skip"),
+    ),
+    responses(
+        (status = 200, description = "Payment link templates retrieved", body = PaymentLinkTemplateListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Payment Link",
+    operation_id = "List all Payment Link Templates",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkTemplateList))]
+pub async fn payment_link_template_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<api_models::payments::PaymentLinkTemplateListConstraints>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            template::list_payment_link_templates(state, auth.merchant_account, constraints)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link Template - Retrieve
+///
+/// Retrieves a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for payment link template")
+    ),
+    responses(
+        (status = 200, description = "Payment link template retrieved", body = PaymentLinkTemplateResponse),
+        (status = 404, description = "No payment link template found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Payment Link Template",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkTemplateRetrieve))]
+pub async fn payment_link_template_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, template_id, _| {
+            template::retrieve_payment_link_template(state, auth.merchant_account, template_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link Template - Update
+///
+/// Updates the usage limit, expiry or status of a reusable payment link
+#[utoipa::path(
+    post,
+    path = "/payment_link/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for payment link template")
+    ),
+    request_body = PaymentLinkTemplateUpdateRequest,
+    responses(
+        (status = 200, description = "Payment link template updated", body = PaymentLinkTemplateResponse),
+        (status = 404, description = "No payment link template found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Update a Payment Link Template",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkTemplateUpdate))]
+pub async fn payment_link_template_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::PaymentLinkTemplateUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateUpdate;
+    let template_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            template::update_payment_link_template(
+                state,
+                auth.merchant_account,
+                template_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link Template - List Usages
+///
+/// Lists the payment links created on every use of a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/templates/{template_id}/usages",
+    params(
+        ("template_id" = String, Path, description = "The identifier for payment link template")
+    ),
+    responses(
+        (status = 200, description = "Payment link template usages retrieved", body = PaymentLinkTemplateUsageResponse),
+        (status = 404, description = "No payment link template found")
+    ),
+    tag = "Payment Link",
+    operation_id = "List the usages of a Payment Link Template",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkTemplateUsageList))]
+pub async fn payment_link_template_usage_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateUsageList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, template_id, _| {
+            template::list_payment_link_template_usages(state, auth.merchant_account, template_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn initiate_payment_link_template(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query: web::Query<api_models::payments::PaymentLinkTemplateRenderQuery>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateInitiate;
+    let (merchant_id, template_id) = path.into_inner();
+
+    let payload = api_models::payments::PaymentLinkTemplateInitiateRequest {
+        merchant_id: merchant_id.clone(),
+        template_id,
+        amount: query.into_inner().amount,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            template::initiate_payment_link_template_flow(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payload.template_id,
+                payload.amount,
+            )
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
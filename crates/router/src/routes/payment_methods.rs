@@ -8,6 +8,8 @@ use common_utils::{errors::CustomResult, id_type};
 use diesel_models::enums::IntentStatus;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::merchant_key_store::MerchantKeyStore;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payments::HeaderPayload;
 use router_env::{instrument, logger, tracing, Flow};
 
 use super::app::{AppState, SessionState};
@@ -17,6 +19,14 @@ use crate::core::payment_methods::{
     payment_method_intent_confirm, payment_method_intent_create, retrieve_payment_method,
     update_payment_method,
 };
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+use crate::core::payment_methods::{sharing, tokenization};
+#[cfg(feature = "v1")]
+use crate::types::transformers::ForeignTryFrom;
 use crate::{
     core::{
         api_locking,
@@ -71,6 +81,185 @@ pub async fn create_payment_method_api(
     .await
 }
 
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenize))]
+pub async fn vault_tokenize_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::VaultTokenizeRequest>,
+) -> HttpResponse {
+    let flow = Flow::VaultTokenize;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            tokenization::tokenize_card(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ScopedApiKeyAuth(common_enums::ApiKeyScope::Tokenize),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::VaultDetokenize))]
+pub async fn vault_detokenize_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::VaultDetokenizeRequest>,
+) -> HttpResponse {
+    let flow = Flow::VaultDetokenize;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            tokenization::detokenize_card(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ScopedApiKeyAuth(common_enums::ApiKeyScope::Detokenize),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsShare))]
+pub async fn share_payment_method_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::SharePaymentMethodRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsShare;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            sharing::share_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_method_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsShareList))]
+pub async fn list_shared_payment_methods_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsShareList;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_method_id,
+        |state, auth: auth::AuthenticationData, payment_method_id, _| {
+            sharing::list_shared_payment_methods(state, auth.merchant_account, payment_method_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "v1",
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsShareRevoke))]
+pub async fn revoke_shared_payment_method_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, id_type::MerchantId)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsShareRevoke;
+    let (payment_method_id, target_merchant_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_method_id,
+        |state, auth: auth::AuthenticationData, payment_method_id, _| {
+            sharing::revoke_shared_payment_method(
+                state,
+                auth.merchant_account,
+                payment_method_id,
+                target_merchant_id.clone(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsConsentEventsList))]
+pub async fn list_payment_method_consent_events_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsConsentEventsList;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_method_id,
+        |state, auth: auth::AuthenticationData, payment_method_id, _| {
+            cards::list_payment_method_consent_events(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_method_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsCreate))]
 pub async fn create_payment_method_api(
@@ -424,10 +613,14 @@ pub async fn list_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::PaymentMethodsList;
     let payload = json_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-        Ok((auth, _auth_flow)) => (auth, _auth_flow),
+    let (auth, auth_flow) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+        Ok((auth, auth_flow)) => (auth, auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     Box::pin(api::server_wrap(
         flow,
@@ -435,7 +628,14 @@ pub async fn list_payment_method_api(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, _| {
-            cards::list_payment_methods(state, auth.merchant_account, auth.key_store, req)
+            cards::list_payment_methods(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                auth_flow,
+                header_payload.origin.clone(),
+            )
         },
         &*auth,
         api_locking::LockAction::NotApplicable,
@@ -714,6 +914,42 @@ pub async fn render_pm_collect_link(
     .await
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+/// Submit the payment method details collected through a payment method collect link
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodCollectLinkSubmit))]
+pub async fn submit_pm_collect_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+    json_payload: web::Json<payment_methods::PaymentMethodCollectLinkSubmitRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodCollectLinkSubmit;
+    let (merchant_id, pm_collect_link_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = merchant_id.clone();
+    payload.pm_collect_link_id = pm_collect_link_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::submit_pm_collect_link(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
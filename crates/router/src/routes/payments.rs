@@ -9,6 +9,8 @@ use error_stack::report;
 use hyperswitch_domain_models::payments::HeaderPayload;
 use masking::PeekInterface;
 use router_env::{env, instrument, logger, tracing, types, Flow};
+#[cfg(feature = "v1")]
+use tracing_futures::Instrument;
 
 use super::app::ReqState;
 use crate::{
@@ -43,6 +45,9 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    // The payment id is generated again once the merchant is authenticated if the merchant has
+    // configured a prefix for the generated payment ids
+    let is_payment_id_generated = payload.payment_id.is_none();
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -74,7 +79,10 @@ pub async fn payments_create(
         state,
         &req,
         payload,
-        |state, auth: auth::AuthenticationData, req, req_state| {
+        |state, auth: auth::AuthenticationData, mut req, req_state| {
+            if is_payment_id_generated {
+                set_payment_id_for_merchant(&mut req, &auth.merchant_account);
+            }
             authorize_verify_select::<_>(
                 payments::PaymentCreate,
                 state,
@@ -284,6 +292,7 @@ pub async fn payments_retrieve(
         client_secret: json_payload.client_secret.clone(),
         expand_attempts: json_payload.expand_attempts,
         expand_captures: json_payload.expand_captures,
+        expand: json_payload.expand.clone(),
         ..Default::default()
     };
     let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
@@ -440,14 +449,12 @@ pub async fn payments_update(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
-            authorize_verify_select::<_>(
-                payments::PaymentUpdate,
+            update_or_increment_authorization(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
-                HeaderPayload::default(),
                 req,
                 auth_flow,
             )
@@ -458,6 +465,69 @@ pub async fn payments_update(
     .await
 }
 
+/// Updates the amount of an authorized payment by incrementing the authorization at the
+/// connector, and performs a regular `PaymentUpdate` otherwise.
+#[cfg(feature = "v1")]
+async fn update_or_increment_authorization(
+    state: app::SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::PaymentsRequest,
+    auth_flow: api::AuthFlow,
+) -> errors::RouterResponse<payment_types::PaymentsResponse> {
+    let incremental_authorization_request =
+        payments::helpers::get_incremental_authorization_request_for_amount_update(
+            &state,
+            &merchant_account,
+            &key_store,
+            auth_flow,
+            &req,
+        )
+        .await?;
+
+    match incremental_authorization_request {
+        Some(incremental_authorization_request) => {
+            payments::payments_core::<
+                api_types::IncrementalAuthorization,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::IncrementalAuthorization>,
+            >(
+                state,
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                payments::PaymentIncrementalAuthorization,
+                incremental_authorization_request,
+                auth_flow,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            )
+            .await
+        }
+        None => {
+            authorize_verify_select::<_>(
+                payments::PaymentUpdate,
+                state,
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                HeaderPayload::default(),
+                req,
+                auth_flow,
+            )
+            .await
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsPostSessionTokens, payment_id))]
 pub async fn payments_post_session_tokens(
@@ -554,6 +624,31 @@ pub async fn payments_confirm(
 
     let locking_action = payload.get_locking_input(flow.clone());
 
+    if payload.async_confirm == Some(true) {
+        return Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: auth::AuthenticationData, req, req_state| {
+                authorize_verify_select_async(
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.profile_id,
+                    auth.key_store,
+                    header_payload.clone(),
+                    req,
+                    auth_flow,
+                    locking_action.clone(),
+                )
+            },
+            &*auth_type,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await;
+    }
+
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -630,6 +725,77 @@ pub async fn payments_capture(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureOnShipment, payment_id))]
+pub async fn payments_capture_on_shipment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsShipmentRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let flow = Flow::PaymentsCaptureOnShipment;
+    let payload = payment_types::PaymentsShipmentRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            payments::capture_on_shipment::capture_on_shipment(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsDccOffer, payment_id))]
+pub async fn payments_dcc_offer(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsDccOfferRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let flow = Flow::PaymentsDccOffer;
+    let payload = payment_types::PaymentsDccOfferRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            payments::dcc::create_dcc_offer(state, auth.merchant_account, auth.key_store, payload)
+        },
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::SessionUpdateTaxCalculation, payment_id))]
 pub async fn payments_dynamic_tax_calculation(
@@ -810,6 +976,53 @@ pub async fn payments_connector_session(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSessionTokenPrewarm, payment_id))]
+pub async fn payments_prewarm_session_tokens(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsSessionRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSessionTokenPrewarm;
+    let payload = json_payload.into_inner();
+
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get headers in payments_prewarm_session_tokens"
+            );
+            HeaderPayload::default()
+        }
+    };
+
+    tracing::Span::current().record("payment_id", payload.payment_id.get_string_repr());
+
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            payments::session_prewarm::prewarm_session_tokens(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payload,
+                header_payload.clone(),
+            )
+        },
+        &auth::HeaderAuth(auth::PublishableKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsRedirect, payment_id))]
 pub async fn payments_redirect_response(
@@ -1127,11 +1340,17 @@ pub async fn profile_payments_list(
                 req,
             )
         },
-        auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
-            &auth::JWTAuth {
-                permission: Permission::ProfilePaymentRead,
+        auth::embed_token_or_auth_type(
+            &auth::EmbedTokenAuth {
+                scope: api_models::embed_tokens::EmbedTokenScope::PaymentsRead,
             },
+            auth::auth_type(
+                &auth::HeaderAuth(auth::ApiKeyAuth),
+                &auth::JWTAuth {
+                    permission: Permission::ProfilePaymentRead,
+                },
+                req.headers(),
+            ),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1193,9 +1412,15 @@ pub async fn profile_payments_list_by_filter(
                 req,
             )
         },
-        &auth::JWTAuth {
-            permission: Permission::ProfilePaymentRead,
-        },
+        auth::embed_token_or_auth_type(
+            &auth::EmbedTokenAuth {
+                scope: api_models::embed_tokens::EmbedTokenScope::PaymentsRead,
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1268,9 +1493,15 @@ pub async fn get_payment_filters_profile(
                 auth.profile_id.map(|profile_id| vec![profile_id]),
             )
         },
-        &auth::JWTAuth {
-            permission: Permission::ProfilePaymentRead,
-        },
+        auth::embed_token_or_auth_type(
+            &auth::EmbedTokenAuth {
+                scope: api_models::embed_tokens::EmbedTokenScope::PaymentsRead,
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1546,6 +1777,70 @@ where
     }
 }
 
+/// Accepts the confirm request once it has been validated, and confirms the payment in the
+/// background. The payment is locked while it is being confirmed, as is done for a synchronous
+/// confirm.
+#[cfg(feature = "v1")]
+#[allow(clippy::too_many_arguments)]
+async fn authorize_verify_select_async(
+    state: app::SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    header_payload: HeaderPayload,
+    req: api_models::payments::PaymentsRequest,
+    auth_flow: api::AuthFlow,
+    locking_action: api_locking::LockAction,
+) -> errors::RouterResponse<api_models::payments::PaymentsAsyncConfirmResponse> {
+    let response = payments::validate_async_confirm_request(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req,
+        auth_flow,
+    )
+    .await?;
+
+    let merchant_id = merchant_account.get_id().to_owned();
+    // The payment is confirmed after the response has been sent, so the timeout budget of the
+    // request does not apply to it
+    let mut state = state;
+    state.request_deadline = None;
+    tokio::spawn(
+        async move {
+            let result = async {
+                locking_action
+                    .clone()
+                    .perform_locking_action(&state, merchant_id.clone())
+                    .await?;
+                let confirm_result = Box::pin(authorize_verify_select::<_>(
+                    payments::PaymentConfirm,
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    profile_id,
+                    key_store,
+                    header_payload,
+                    req,
+                    auth_flow,
+                ))
+                .await;
+                locking_action.free_lock_action(&state, merchant_id).await?;
+                confirm_result
+            }
+            .await;
+
+            if let Err(error) = result {
+                logger::error!(async_confirm_error=?error);
+            }
+        }
+        .in_current_span(),
+    );
+
+    Ok(api::ApplicationResponse::JsonAccepted(response))
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsIncrementalAuthorization, payment_id))]
 pub async fn payments_incremental_authorization(
@@ -1595,6 +1890,84 @@ pub async fn payments_incremental_authorization(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsInvoiceGenerate, payment_id))]
+pub async fn payments_generate_invoice(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::invoices::InvoiceCreateRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsInvoiceGenerate;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            crate::core::invoices::generate_invoice(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsClientSecretRotate, payment_id))]
+pub async fn payments_rotate_client_secret(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsClientSecretRotate;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::rotate_client_secret(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsExternalAuthentication, payment_id))]
 pub async fn payments_external_authentication(
@@ -1761,6 +2134,25 @@ pub fn get_or_generate_payment_id(
     Ok(())
 }
 
+/// Replaces the payment id generated for the request with one prefixed with the payment id prefix
+/// configured for the merchant, if any
+#[cfg(feature = "v1")]
+pub fn set_payment_id_for_merchant(
+    payload: &mut payment_types::PaymentsRequest,
+    merchant_account: &domain::MerchantAccount,
+) {
+    if let Some(prefix) = merchant_account
+        .get_id_prefixes()
+        .and_then(|id_prefixes| id_prefixes.payment_id)
+    {
+        let payment_id = common_utils::id_type::PaymentId::generate_with_prefix(&prefix);
+        tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+        payload.payment_id = Some(api_models::payments::PaymentIdType::PaymentIntentId(
+            payment_id,
+        ));
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetLockingInput for payment_types::PaymentsRequest {
     fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
@@ -1973,6 +2365,23 @@ impl GetLockingInput for payment_types::PaymentsCaptureRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsShipmentRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "oltp")]
 struct FPaymentsApproveRequest<'a>(&'a payment_types::PaymentsApproveRequest);
 
@@ -63,6 +63,7 @@ pub async fn payouts_retrieve(
         payout_id: path.into_inner(),
         force_sync: query_params.force_sync.to_owned(),
         merchant_id: query_params.merchant_id.to_owned(),
+        expand: query_params.expand.to_owned(),
     };
     let flow = Flow::PayoutsRetrieve;
     let locale = get_locale_from_header(req.headers());
@@ -93,6 +94,39 @@ pub async fn payouts_retrieve(
     ))
     .await
 }
+
+/// Payouts - List Attempts
+#[instrument(skip_all, fields(flow = ?Flow::PayoutAttemptsList))]
+pub async fn payouts_attempts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutAttemptsList;
+    let payload = payout_types::PayoutAttemptListRequest {
+        payout_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payouts_attempts_list_core(state, auth.merchant_account, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - Update
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
 pub async fn payouts_update(
@@ -246,6 +280,42 @@ pub async fn payouts_list(
     .await
 }
 
+/// Payouts - Balance
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBalance))]
+pub async fn payouts_balance(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<payout_types::PayoutBalanceRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBalance;
+    let payload = query_params.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payouts_balance_core(
+                state,
+                auth.merchant_account,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - List Profile
 #[cfg(all(feature = "olap", feature = "payouts", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
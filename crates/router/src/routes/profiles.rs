@@ -2,6 +2,12 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::embed_tokens;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+use crate::core::payouts::balance_rules;
+#[cfg(feature = "v1")]
+use crate::core::{email_templates, ledger, payment_templates};
 use crate::{
     core::{admin::*, api_locking},
     services::{api, authentication as auth, authorization::permissions},
@@ -232,16 +238,18 @@ pub async fn profiles_list(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::ProfileListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::ProfileList;
     let merchant_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         merchant_id.clone(),
-        |state, _auth, merchant_id, _| list_profile(state, merchant_id, None),
+        |state, _auth, merchant_id, _| list_profile(state, merchant_id, None, tag.clone()),
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
             &auth::JWTAuthMerchantFromRoute {
@@ -261,9 +269,11 @@ pub async fn profiles_list(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::ProfileListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::ProfileList;
     let merchant_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     Box::pin(api::server_wrap(
         flow,
@@ -271,7 +281,7 @@ pub async fn profiles_list(
         &req,
         merchant_id.clone(),
         |state, auth::AuthenticationDataWithoutProfile { .. }, merchant_id, _| {
-            list_profile(state, merchant_id, None)
+            list_profile(state, merchant_id, None, tag.clone())
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
@@ -292,9 +302,11 @@ pub async fn profiles_list_at_profile_level(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::ProfileListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::ProfileList;
     let merchant_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     Box::pin(api::server_wrap(
         flow,
@@ -306,6 +318,7 @@ pub async fn profiles_list_at_profile_level(
                 state,
                 merchant_id,
                 auth.profile_id.map(|profile_id| vec![profile_id]),
+                tag.clone(),
             )
         },
         auth::auth_type(
@@ -385,9 +398,11 @@ pub async fn payment_connector_list_profile(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::MerchantConnectorListConstraints>,
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsList;
     let merchant_id = path.into_inner();
+    let tag = query_params.into_inner().tag;
 
     api::server_wrap(
         flow,
@@ -399,6 +414,7 @@ pub async fn payment_connector_list_profile(
                 state,
                 merchant_id,
                 auth.profile_id.map(|profile_id| vec![profile_id]),
+                tag.clone(),
             )
         },
         auth::auth_type(
@@ -413,3 +429,753 @@ pub async fn payment_connector_list_profile(
     )
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSecretRetrieve))]
+pub async fn webhook_secret_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSecretRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            retrieve_webhook_secret(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSecretGenerate))]
+pub async fn webhook_secret_generate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSecretGenerate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            generate_webhook_secret(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSecretRotate))]
+pub async fn webhook_secret_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<admin::RotateWebhookSecretRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSecretRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            rotate_webhook_secret(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::EmailTemplateCreate))]
+pub async fn email_template_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<api_models::email_templates::EmailTemplateCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::EmailTemplateCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            email_templates::create_email_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::EmailTemplateList))]
+pub async fn email_templates_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::EmailTemplateList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            email_templates::list_email_templates(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::EmailTemplateRetrieve))]
+pub async fn email_template_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::EmailTemplateRetrieve;
+    let (merchant_id, profile_id, template_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        template_id,
+        |state, auth_data, template_id, _| {
+            email_templates::retrieve_email_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                template_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::EmailTemplateUpdate))]
+pub async fn email_template_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+    json_payload: web::Json<api_models::email_templates::EmailTemplateUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::EmailTemplateUpdate;
+    let (merchant_id, profile_id, template_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            email_templates::update_email_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                template_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::EmailTemplateDelete))]
+pub async fn email_template_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::EmailTemplateDelete;
+    let (merchant_id, profile_id, template_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        template_id,
+        |state, auth_data, template_id, _| {
+            email_templates::delete_email_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                template_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentTemplateCreate))]
+pub async fn payment_template_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<api_models::payment_templates::PaymentTemplateCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentTemplateCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            payment_templates::create_payment_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentTemplateList))]
+pub async fn payment_templates_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::PaymentTemplateList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            payment_templates::list_payment_templates(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentTemplateRetrieve))]
+pub async fn payment_template_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::PaymentTemplateRetrieve;
+    let (merchant_id, profile_id, template_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        template_id,
+        |state, auth_data, template_id, _| {
+            payment_templates::retrieve_payment_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                template_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentTemplateUpdate))]
+pub async fn payment_template_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+    json_payload: web::Json<api_models::payment_templates::PaymentTemplateUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentTemplateUpdate;
+    let (merchant_id, profile_id, template_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            payment_templates::update_payment_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                template_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentTemplateDelete))]
+pub async fn payment_template_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::PaymentTemplateDelete;
+    let (merchant_id, profile_id, template_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        template_id,
+        |state, auth_data, template_id, _| {
+            payment_templates::delete_payment_template(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                template_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::LedgerBalancesRetrieve))]
+pub async fn ledger_balances_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::LedgerBalancesRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            ledger::retrieve_ledger_balances(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::LedgerStatementRetrieve))]
+pub async fn ledger_statement_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    query_params: web::Query<api_models::ledger::LedgerStatementRequest>,
+) -> HttpResponse {
+    let flow = Flow::LedgerStatementRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth_data, req, _| {
+            ledger::retrieve_ledger_statement(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBalanceOverrideCreate))]
+pub async fn payout_balance_override_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBalanceOverrideCreate;
+    let (merchant_id, profile_id, payout_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payout_id,
+        |state, auth_data, payout_id, _| {
+            balance_rules::update_payout_balance_override(
+                state,
+                auth_data.merchant_account,
+                profile_id.clone(),
+                payout_id,
+                true,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBalanceOverrideDelete))]
+pub async fn payout_balance_override_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBalanceOverrideDelete;
+    let (merchant_id, profile_id, payout_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payout_id,
+        |state, auth_data, payout_id, _| {
+            balance_rules::update_payout_balance_override(
+                state,
+                auth_data.merchant_account,
+                profile_id.clone(),
+                payout_id,
+                false,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::EmbedTokenCreate))]
+pub async fn embed_token_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<api_models::embed_tokens::EmbedTokenCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::EmbedTokenCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            embed_tokens::create_embed_token(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::MerchantApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
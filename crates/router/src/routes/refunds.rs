@@ -63,7 +63,8 @@ pub async fn refunds_create(
     get,
     path = "/refunds/{refund_id}",
     params(
-        ("refund_id" = String, Path, description = "The identifier for refund")
+        ("refund_id" = String, Path, description = "The identifier for refund"),
+        ("expand" = Option<String>, Query, description = "The comma separated list of related objects to be expanded in the response")
     ),
     responses(
         (status = 200, description = "Refund retrieved", body = RefundResponse),
@@ -85,6 +86,7 @@ pub async fn refunds_retrieve(
         refund_id: path.into_inner(),
         force_sync: query_params.force_sync,
         merchant_connector_details: None,
+        expand: query_params.expand.clone(),
     };
     let flow = match query_params.force_sync {
         Some(true) => Flow::RefundsRetrieveForceSync,
@@ -99,13 +101,12 @@ pub async fn refunds_retrieve(
         &req,
         refund_request,
         |state, auth: auth::AuthenticationData, refund_request, _| {
-            refund_response_wrapper(
+            refund_retrieve_with_expansions(
                 state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
                 refund_request,
-                refund_retrieve_core_with_refund_id,
             )
         },
         auth::auth_type(
@@ -153,13 +154,12 @@ pub async fn refunds_retrieve_with_body(
         &req,
         json_payload.into_inner(),
         |state, auth: auth::AuthenticationData, req, _| {
-            refund_response_wrapper(
+            refund_retrieve_with_expansions(
                 state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
                 req,
-                refund_retrieve_core_with_refund_id,
             )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -1081,7 +1081,7 @@ pub async fn success_based_routing_update_configs(
             ))
             .await
         },
-        &auth::AdminApiAuth,
+        &auth::AdminApiAuthWithPermission(common_enums::AdminPermission::RoutingActivate),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1,3 +1,4 @@
+pub mod scim;
 pub mod theme;
 
 use actix_web::{web, HttpRequest, HttpResponse};
@@ -676,6 +677,133 @@ pub async fn check_two_factor_auth_status_with_attempts(
     .await
 }
 
+pub async fn webauthn_register_begin(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::WebauthnRegisterBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::webauthn::begin_webauthn_registration(state, user),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn webauthn_register_finish(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::WebauthnRegistrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebauthnRegisterFinish;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| {
+            user_core::webauthn::finish_webauthn_registration(state, user, req_body)
+        },
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn webauthn_authenticate_begin(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::WebauthnAuthenticateBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::webauthn::begin_webauthn_authentication(state, user),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn webauthn_verify(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::WebauthnAuthenticationRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebauthnVerify;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| user_core::webauthn::verify_webauthn(state, user, req_body),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_webauthn_credentials(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::WebauthnCredentialsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::webauthn::list_webauthn_credentials(state, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_webauthn_credential(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::WebauthnCredentialDelete;
+    let payload = user_api::WebauthnCredentialId {
+        id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        |state, user, req_body, _| {
+            user_core::webauthn::delete_webauthn_credential(state, user, req_body)
+        },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn reset_two_factor_auth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::ResetTwoFactorAuthRequest>,
+) -> HttpResponse {
+    let flow = Flow::ResetTwoFactorAuth;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req_body, _| user_core::webauthn::reset_two_factor_auth(state, req_body),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 pub async fn get_sso_auth_url(
     state: web::Data<AppState>,
@@ -696,6 +824,25 @@ pub async fn get_sso_auth_url(
     .await
 }
 
+#[cfg(feature = "v1")]
+pub async fn get_sso_auth_url_by_email(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::GetSsoAuthUrlByEmailRequest>,
+) -> HttpResponse {
+    let flow = Flow::GetSsoAuthUrlByEmail;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, _: (), req, _| user_core::get_sso_auth_url_by_email(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn sso_sign(
     state: web::Data<AppState>,
     req: HttpRequest,
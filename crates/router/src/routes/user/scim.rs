@@ -0,0 +1,306 @@
+use actix_web::{http::StatusCode, web, HttpRequest, HttpResponse};
+use api_models::user::scim as scim_api;
+use router_env::Flow;
+
+use crate::{
+    core::{api_locking, user::scim as scim_core},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// SCIM clients expect `201 Created` on creation and `204 No Content` on deletion
+fn set_success_status(mut response: HttpResponse, status_code: StatusCode) -> HttpResponse {
+    if response.status() == StatusCode::OK {
+        *response.status_mut() = status_code;
+    }
+    response
+}
+
+pub async fn upsert_scim_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimConfigRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimConfigUpsert;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, _| scim_core::upsert_scim_config(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_scim_config(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ScimConfigRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| scim_core::get_scim_config(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn rotate_scim_token(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ScimTokenRotate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| scim_core::rotate_scim_token(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_scim_config(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ScimConfigDelete;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| scim_core::delete_scim_config(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserCreate;
+
+    let response = Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_config, payload, _| scim_core::create_user(state, scim_config, payload),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await;
+
+    set_success_status(response, StatusCode::CREATED)
+}
+
+pub async fn retrieve_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserRetrieve;
+    let payload = scim_api::ScimResourceId {
+        id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, scim_config, payload, _| scim_core::retrieve_user(state, scim_config, payload.id),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_users(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, scim_config, query, _| scim_core::list_users(state, scim_config, query),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn replace_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserReplace;
+    let user_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_config, payload, _| {
+            scim_core::replace_user(state, scim_config, user_id.clone(), payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserPatch;
+    let user_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_config, payload, _| {
+            scim_core::patch_user(state, scim_config, user_id.clone(), payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserDelete;
+    let payload = scim_api::ScimResourceId {
+        id: path.into_inner(),
+    };
+
+    let response = Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, scim_config, payload, _| scim_core::delete_user(state, scim_config, payload.id),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await;
+
+    set_success_status(response, StatusCode::NO_CONTENT)
+}
+
+pub async fn create_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimGroupRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupCreate;
+
+    let response = Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_config, payload, _| scim_core::create_group(state, scim_config, payload),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await;
+
+    set_success_status(response, StatusCode::CREATED)
+}
+
+pub async fn retrieve_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupRetrieve;
+    let payload = scim_api::ScimResourceId {
+        id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, scim_config, payload, _| scim_core::retrieve_group(state, scim_config, payload.id),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_groups(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, scim_config, query, _| scim_core::list_groups(state, scim_config, query),
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupPatch;
+    let group_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_config, payload, _| {
+            scim_core::patch_group(state, scim_config, group_id.clone(), payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -7,7 +7,8 @@ use crate::{
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookPayloadPreviewRequest,
+        WebhookPayloadPreviewRequestInternal,
     },
 };
 
@@ -130,3 +131,47 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventPayloadPreview))]
+#[cfg(feature = "v1")]
+pub async fn preview_webhook_payload(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query: web::Query<WebhookPayloadPreviewRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventPayloadPreview;
+    let (merchant_id, event_id) = path.into_inner();
+
+    let request_internal = WebhookPayloadPreviewRequestInternal {
+        merchant_id: merchant_id.clone(),
+        event_id,
+        payload_version: query.into_inner().payload_version,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, req_state| {
+            webhook_events::preview_webhook_payload(
+                state,
+                req_state,
+                request_internal.merchant_id,
+                request_internal.event_id,
+                request_internal.payload_version,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
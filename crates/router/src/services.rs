@@ -25,7 +25,7 @@ pub use hyperswitch_interfaces::connector_integration_v2::{
 use masking::{ExposeInterface, StrongSecret};
 #[cfg(feature = "kv_store")]
 use storage_impl::KVRouterStore;
-use storage_impl::{config::TenantConfig, redis::RedisStore, RouterStore};
+use storage_impl::{config::TenantConfig, redis::RedisStore, DatabaseStore, RouterStore};
 use tokio::sync::oneshot;
 
 pub use self::{api::*, encryption::*};
@@ -68,7 +68,7 @@ pub async fn get_store(
     #[allow(clippy::useless_conversion)]
     let conf = (master_config.into(), replica_config.into());
 
-    let store: RouterStore<StoreType> = if test_transaction {
+    let mut store: RouterStore<StoreType> = if test_transaction {
         RouterStore::test_store(conf, tenant, &config.redis, master_enc_key).await?
     } else {
         RouterStore::from_config(
@@ -81,6 +81,14 @@ pub async fn get_store(
         .await?
     };
 
+    store.set_conflict_retry_config(config.conflict_retry.clone());
+
+    storage_impl::database::partitioning::create_isolated_merchant_partitions(
+        store.get_master_pool(),
+        &config.partitioning,
+    )
+    .await;
+
     #[cfg(feature = "kv_store")]
     let store = KVRouterStore::from_store(
         store,
@@ -1,6 +1,8 @@
 pub mod client;
 pub mod generic_link_response;
 pub mod request;
+pub mod traffic_mirroring;
+pub mod versioning;
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
@@ -31,7 +33,7 @@ pub use hyperswitch_domain_models::{
     api::{
         ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
         GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        RedirectionFormData, RedirectionPageConfig,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -60,9 +62,9 @@ use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        api_locking, connector_slo,
         errors::{self, CustomResult},
-        payments,
+        payload_logs, payments,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -163,20 +165,26 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
+            let flow_name = std::any::type_name::<T>()
+                .split("::")
+                .last()
+                .unwrap_or_default();
+            let merchant_attributes = metrics::merchant_labels::get_merchant_attributes(
+                &state.conf.merchant_metrics_labels,
+                &req.merchant_id,
+                None,
+            );
             metrics::CONNECTOR_CALL_COUNT.add(
                 &metrics::CONTEXT,
                 1,
-                &add_attributes([
-                    ("connector", req.connector.to_string()),
-                    (
-                        "flow",
-                        std::any::type_name::<T>()
-                            .split("::")
-                            .last()
-                            .unwrap_or_default()
-                            .to_string(),
-                    ),
-                ]),
+                &add_attributes(
+                    [
+                        ("connector", req.connector.to_string()),
+                        ("flow", flow_name.to_string()),
+                    ]
+                    .into_iter()
+                    .chain(merchant_attributes.clone()),
+                ),
             );
 
             let connector_request = match connector_request {
@@ -227,6 +235,16 @@ where
                                 .map_or_else(|value| value.status_code, |value| value.status_code)
                         })
                         .unwrap_or_default();
+                    connector_slo::record_connector_call(
+                        state,
+                        &req.connector,
+                        flow_name,
+                        &merchant_attributes,
+                        external_latency,
+                        response
+                            .as_ref()
+                            .map_or(true, |response| response.is_err() && status_code >= 500),
+                    );
                     let mut connector_event = ConnectorEvent::new(
                         req.connector.clone(),
                         std::any::type_name::<T>(),
@@ -296,7 +314,11 @@ where
                                     metrics::CONNECTOR_ERROR_RESPONSE_COUNT.add(
                                         &metrics::CONTEXT,
                                         1,
-                                        &add_attributes([("connector", req.connector.clone())]),
+                                        &add_attributes(
+                                            [("connector", req.connector.clone())]
+                                                .into_iter()
+                                                .chain(merchant_attributes.clone()),
+                                        ),
                                     );
 
                                     let error = match body.status_code {
@@ -375,7 +397,12 @@ pub async fn call_connector_api(
     let url = request.url.clone();
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(
+            state,
+            request,
+            state.get_connector_call_timeout_in_secs(),
+            true,
+        )
         .await;
 
     match response.as_ref() {
@@ -761,6 +788,7 @@ where
         .switch()
     })?;
     session_state.add_request_id(request_id);
+    session_state.set_request_deadline(start_instant);
     let mut request_state = session_state.get_req_state();
 
     request_state.event_context.record_info(request_id);
@@ -773,6 +801,13 @@ where
         tenant_id.get_string_repr().to_string(),
     ));
 
+    let requested_api_version =
+        versioning::get_requested_api_version(incoming_request_header).switch()?;
+    let api_version = versioning::negotiate_api_version(requested_api_version, None).switch()?;
+    request_state
+        .event_context
+        .record_info(("api_version".to_string(), api_version.to_string()));
+
     // Currently auth failures are not recorded as API events
     let (auth_out, auth_type) = api_auth
         .authenticate_and_fetch(request.headers(), &session_state)
@@ -785,6 +820,7 @@ where
         .get_merchant_id()
         .cloned()
         .unwrap_or(common_utils::id_type::MerchantId::get_merchant_id_not_found());
+    let profile_id = auth_type.get_profile_id().cloned();
 
     app_state.add_flow_name(flow.to_string());
 
@@ -850,6 +886,29 @@ where
         }
     };
 
+    let event_type = event_type.unwrap_or(ApiEventsType::Miscellaneous);
+    if state.conf.payload_logging.enabled
+        && auth_type.get_merchant_id().is_some()
+        && payload_logs::is_payload_logged(&event_type, &auth_type)
+    {
+        payload_logs::record_payload_log(
+            &session_state,
+            api_models::payload_logs::PayloadLogResponse {
+                request_id: request_id.as_hyphenated().to_string(),
+                merchant_id: merchant_id.clone(),
+                flow: flow.to_string(),
+                http_method: request.method().to_string(),
+                url_path: request.path().to_string(),
+                status_code,
+                request: serialized_request.clone(),
+                response: serialized_response.clone(),
+                error: error.clone(),
+                latency_in_millis: request_duration,
+                created_at: common_utils::date_time::now(),
+            },
+        );
+    }
+
     let api_event = ApiEvent::new(
         Some(merchant_id.clone()),
         flow,
@@ -861,7 +920,7 @@ where
         overhead_latency,
         auth_type,
         error,
-        event_type.unwrap_or(ApiEventsType::Miscellaneous),
+        event_type,
         request,
         request.method(),
     );
@@ -871,6 +930,8 @@ where
         status_code.to_string(),
         flow.to_string(),
         merchant_id.to_owned(),
+        profile_id.as_ref(),
+        &state.conf.merchant_metrics_labels,
     );
 
     output
@@ -926,6 +987,9 @@ where
         tag = ?Tag::BeginRequest, payload = ?payload,
     headers = ?incoming_header_to_log);
 
+    let mirrored_request =
+        traffic_mirroring::get_mirrored_request(&state.conf.traffic_mirroring, request, &payload);
+
     let server_wrap_util_res = metrics::request::record_request_time_metric(
         server_wrap_util(
             &flow,
@@ -945,6 +1009,16 @@ where
         response
     });
 
+    let primary_response_body = mirrored_request
+        .as_ref()
+        .and_then(|_| match server_wrap_util_res.as_ref() {
+            Ok(ApplicationResponse::Json(response))
+            | Ok(ApplicationResponse::JsonWithHeaders((response, _))) => {
+                serde_json::to_value(response).ok()
+            }
+            _ => None,
+        });
+
     let res = match server_wrap_util_res {
         Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
             Ok(res) => http_response_json(res),
@@ -956,6 +1030,16 @@ where
                 }"#,
             ),
         },
+        Ok(ApplicationResponse::JsonAccepted(response)) => match serde_json::to_string(&response) {
+            Ok(res) => http_response_json_accepted(res),
+            Err(_) => http_response_err(
+                r#"{
+                    "error": {
+                        "message": "Error serializing response from connector"
+                    }
+                }"#,
+            ),
+        },
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
@@ -980,6 +1064,7 @@ where
                 redirection_data.payment_method_data,
                 redirection_data.amount,
                 redirection_data.currency,
+                redirection_data.page_config,
                 config,
             )
             .respond_to(request)
@@ -1066,6 +1151,17 @@ where
     let response_code = res.status().as_u16();
     tracing::Span::current().record("status_code", response_code);
 
+    if let Some(mirrored_request) = mirrored_request {
+        traffic_mirroring::mirror_request(
+            Arc::clone(&state.conf),
+            mirrored_request,
+            traffic_mirroring::PrimaryResponse {
+                status_code: response_code,
+                body: primary_response_body,
+            },
+        );
+    }
+
     let end_instant = Instant::now();
     let request_duration = end_instant.saturating_duration_since(start_instant);
     logger::info!(
@@ -1130,6 +1226,12 @@ pub fn http_response_json<T: body::MessageBody + 'static>(response: T) -> HttpRe
         .body(response)
 }
 
+pub fn http_response_json_accepted<T: body::MessageBody + 'static>(response: T) -> HttpResponse {
+    HttpResponse::Accepted()
+        .content_type(mime::APPLICATION_JSON)
+        .body(response)
+}
+
 pub fn http_server_error_json_response<T: body::MessageBody + 'static>(
     response: T,
 ) -> HttpResponse {
@@ -1279,6 +1381,7 @@ pub fn build_redirection_form(
     payment_method_data: Option<PaymentMethodData>,
     amount: String,
     currency: String,
+    page_config: Option<RedirectionPageConfig>,
     config: Settings,
 ) -> maud::Markup {
     use maud::PreEscaped;
@@ -1289,17 +1392,29 @@ pub fn build_redirection_form(
             endpoint,
             method,
             form_fields,
-        } => maud::html! {
-        (maud::DOCTYPE)
-        html {
-            meta name="viewport" content="width=device-width, initial-scale=1";
-            head {
-                style {
-                    r##"
+        } => {
+            let theme = page_config
+                .as_ref()
+                .and_then(|page_config| page_config.theme.clone());
+            let message = page_config
+                .as_ref()
+                .map(|page_config| page_config.message.clone())
+                .unwrap_or_else(|| consts::DEFAULT_REDIRECTION_PAGE_MESSAGE.to_string());
+            let auto_redirect_timeout_ms = page_config
+                .as_ref()
+                .map(|page_config| page_config.auto_redirect_timeout_ms)
+                .unwrap_or(consts::DEFAULT_AUTO_REDIRECT_TIMEOUT_MS);
+            maud::html! {
+            (maud::DOCTYPE)
+            html {
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                head {
+                    style {
+                        r##"
 
                     "##
-                }
-                (PreEscaped(r##"
+                    }
+                    (PreEscaped(r##"
                 <style>
                     #loader1 {
                         width: 500px,
@@ -1311,15 +1426,15 @@ pub fn build_redirection_form(
                     }
                 </style>
                 "##))
-            }
+                }
 
-            body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
+                body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
 
-                div id="loader1" class="lottie" style="height: 150px; display: block; position: relative; margin-left: auto; margin-right: auto;" { "" }
+                    div id="loader1" class="lottie" style="height: 150px; display: block; position: relative; margin-left: auto; margin-right: auto;" { "" }
 
-                (PreEscaped(r#"<script src="https://cdnjs.cloudflare.com/ajax/libs/bodymovin/5.7.4/lottie.min.js"></script>"#))
+                    (PreEscaped(r#"<script src="https://cdnjs.cloudflare.com/ajax/libs/bodymovin/5.7.4/lottie.min.js"></script>"#))
 
-                (PreEscaped(r#"
+                    (PreEscaped(r#"
                 <script>
                 var anime = bodymovin.loadAnimation({
                     container: document.getElementById('loader1'),
@@ -1332,13 +1447,21 @@ pub fn build_redirection_form(
                 </script>
                 "#))
 
-                h3 style="text-align: center;" { "Please wait while we process your payment..." }
-                    form action=(PreEscaped(endpoint)) method=(method.to_string()) #payment_form {
-                        @for (field, value) in form_fields {
-                        input type="hidden" name=(field) value=(value);
+                    @if let Some(page_config) = &page_config {
+                        @if let Some(logo) = &page_config.logo {
+                            img src=(logo) alt="" style="display: block; max-height: 48px; margin-left: auto; margin-right: auto;";
+                        }
+                        @if let Some(merchant_name) = &page_config.merchant_name {
+                            h2 style="text-align: center;" { (merchant_name) }
+                        }
                     }
-                }
-                (PreEscaped(format!(r#"
+                    h3 style=(format!("text-align: center;{}", theme.map(|theme| format!(" color: {theme};")).unwrap_or_default())) { (message) }
+                        form action=(PreEscaped(endpoint)) method=(method.to_string()) #payment_form {
+                            @for (field, value) in form_fields {
+                            input type="hidden" name=(field) value=(value);
+                        }
+                    }
+                    (PreEscaped(format!(r#"
                     <script type="text/javascript"> {logging_template}
                     var frm = document.getElementById("payment_form");
                     var formFields = frm.querySelectorAll("input");
@@ -1346,18 +1469,19 @@ pub fn build_redirection_form(
                     if (frm.method.toUpperCase() === "GET" && formFields.length === 0) {{
                         window.setTimeout(function () {{
                             window.location.href = frm.action;
-                        }}, 300);
+                        }}, {auto_redirect_timeout_ms});
                     }} else {{
                         window.setTimeout(function () {{
                             frm.submit();
-                        }}, 300);
+                        }}, {auto_redirect_timeout_ms});
                     }}
                     </script>
                     "#)))
 
+                }
+            }
             }
         }
-        },
         RedirectForm::Html { html_data } => PreEscaped(format!(
             "{} <script>{}</script>",
             html_data, logging_template
@@ -0,0 +1,322 @@
+//! Duplicates a sample of the read only requests served to a canary deployment of the router, and
+//! reports the differences between the responses of the deployments. This helps validate an
+//! upgrade of the router against the production traffic before it is rolled out.
+
+use std::{sync::Arc, time::Duration};
+
+use actix_web::{http::Method as ActixMethod, HttpRequest};
+use error_stack::report;
+use rand::Rng;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use router_env::{
+    instrument, logger,
+    metrics::add_attributes,
+    tracing::{self, Instrument},
+};
+use serde::Serialize;
+
+use crate::{
+    configs::{settings::TrafficMirroring, Settings},
+    core::errors::{self, CustomResult},
+    headers,
+    routes::metrics,
+    services::api::client,
+};
+
+/// The differing fields reported for a response are bounded, so that a response of a different
+/// shape altogether does not flood the logs
+const MAX_REPORTED_DIFFERENCES: usize = 50;
+
+/// The incoming headers which are not forwarded to the canary deployment
+const SKIPPED_HEADERS: [&str; 3] = ["host", "content-length", "connection"];
+
+/// The incoming headers carrying the credentials of the request, which are forwarded only to a
+/// trusted canary deployment
+const CREDENTIAL_HEADERS: [&str; 4] = ["authorization", "api-key", "x-api-key", "cookie"];
+
+/// A request duplicated to the canary deployment
+#[derive(Debug)]
+pub struct MirroredRequest {
+    route: String,
+    method: reqwest::Method,
+    url: String,
+    headers: HeaderMap,
+    body: Option<serde_json::Value>,
+    /// Whether credentials of the request were not forwarded to the untrusted canary deployment
+    credentials_stripped: bool,
+}
+
+/// The response served for a duplicated request, which the response of the canary deployment is
+/// compared with
+#[derive(Debug)]
+pub struct PrimaryResponse {
+    pub status_code: u16,
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MirroredResponseOutcome {
+    Matched,
+    Mismatched,
+    /// The canary deployment rejected the request whose credentials were not forwarded to it, so
+    /// the responses are not compared
+    Unauthenticated,
+    Failed,
+}
+
+/// Report of the comparison of the responses of the deployments. Only the paths of the differing
+/// fields are reported, since the values may contain sensitive data.
+#[derive(Debug, Serialize)]
+pub struct TrafficMirroringReport {
+    pub route: String,
+    pub method: String,
+    pub outcome: MirroredResponseOutcome,
+    pub primary_status_code: u16,
+    pub canary_status_code: Option<u16>,
+    pub canary_latency_in_millis: u128,
+    pub differing_fields: Vec<String>,
+}
+
+/// Returns the request to be duplicated to the canary deployment, if traffic mirroring is enabled
+/// and the request is both eligible and sampled. Only the configured read only `GET` and `POST`
+/// routes are eligible, and `GET` requests which sync with the connector are never duplicated.
+pub fn get_mirrored_request<T: Serialize>(
+    config: &TrafficMirroring,
+    request: &HttpRequest,
+    payload: &T,
+) -> Option<MirroredRequest> {
+    let canary_base_url = config.canary_base_url.as_ref().filter(|_| config.enabled)?;
+
+    // Requests which are already duplicated are never duplicated again
+    if request.headers().contains_key(headers::X_MIRRORED_REQUEST) {
+        return None;
+    }
+
+    let route = request.match_pattern()?;
+    let (method, body) = match *request.method() {
+        ActixMethod::GET
+            if config.read_only_get_routes.contains(&route)
+                && !request.query_string().contains("force_sync=true") =>
+        {
+            (reqwest::Method::GET, None)
+        }
+        ActixMethod::POST if config.read_only_post_routes.contains(&route) => (
+            reqwest::Method::POST,
+            Some(serde_json::to_value(payload).ok()?),
+        ),
+        _ => return None,
+    };
+
+    if !rand::thread_rng().gen_bool(config.sample_percentage / 100.0) {
+        return None;
+    }
+
+    let credentials_stripped = !config.trusted_canary
+        && request
+            .headers()
+            .keys()
+            .any(|name| CREDENTIAL_HEADERS.contains(&name.as_str()));
+    let mut mirrored_headers = request
+        .headers()
+        .iter()
+        .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+        .filter(|(name, _)| config.trusted_canary || !CREDENTIAL_HEADERS.contains(&name.as_str()))
+        .filter_map(|(name, value)| {
+            Some((
+                HeaderName::from_bytes(name.as_str().as_bytes()).ok()?,
+                HeaderValue::from_bytes(value.as_bytes()).ok()?,
+            ))
+        })
+        .collect::<HeaderMap>();
+    mirrored_headers.insert(
+        HeaderName::from_static(headers::X_MIRRORED_REQUEST),
+        HeaderValue::from_static("true"),
+    );
+
+    let path_and_query = match request.query_string() {
+        "" => request.path().to_string(),
+        query => format!("{}?{query}", request.path()),
+    };
+
+    Some(MirroredRequest {
+        route,
+        method,
+        url: format!("{}{path_and_query}", canary_base_url.trim_end_matches('/')),
+        headers: mirrored_headers,
+        body,
+        credentials_stripped,
+    })
+}
+
+/// Sends the duplicated request to the canary deployment in the background and reports the
+/// differences from the response served. The response served is never affected.
+pub fn mirror_request(
+    conf: Arc<Settings>,
+    mirrored_request: MirroredRequest,
+    primary_response: PrimaryResponse,
+) {
+    tokio::spawn(
+        async move {
+            let report = compare_with_canary(&conf, mirrored_request, primary_response).await;
+            metrics::TRAFFIC_MIRRORED_REQUEST_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([
+                    ("route", report.route.clone()),
+                    ("outcome", report.outcome.to_string()),
+                ]),
+            );
+            match report.outcome {
+                MirroredResponseOutcome::Matched | MirroredResponseOutcome::Unauthenticated => {
+                    logger::debug!(traffic_mirroring_report = ?report)
+                }
+                MirroredResponseOutcome::Mismatched | MirroredResponseOutcome::Failed => {
+                    logger::warn!(
+                        traffic_mirroring_report = %serde_json::to_string(&report).unwrap_or_default(),
+                        "Canary response differs from the response served"
+                    )
+                }
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+#[instrument(skip_all)]
+async fn compare_with_canary(
+    conf: &Settings,
+    mirrored_request: MirroredRequest,
+    primary_response: PrimaryResponse,
+) -> TrafficMirroringReport {
+    let route = mirrored_request.route.clone();
+    let method = mirrored_request.method.to_string();
+    let credentials_stripped = mirrored_request.credentials_stripped;
+    let started_at = std::time::Instant::now();
+    let canary_response = send_mirrored_request(conf, mirrored_request).await;
+    let canary_latency_in_millis = started_at.elapsed().as_millis();
+
+    let (outcome, canary_status_code, differing_fields) = match canary_response {
+        // Without the credentials of the request, the canary deployment is expected to reject it
+        Ok((canary_status_code @ (401 | 403), _)) if credentials_stripped => (
+            MirroredResponseOutcome::Unauthenticated,
+            Some(canary_status_code),
+            Vec::new(),
+        ),
+        Ok((canary_status_code, canary_body)) => {
+            let mut differing_fields = Vec::new();
+            if let (Some(primary_body), Some(canary_body)) =
+                (primary_response.body.as_ref(), canary_body.as_ref())
+            {
+                collect_differing_fields(
+                    primary_body,
+                    canary_body,
+                    String::new(),
+                    &conf.traffic_mirroring.ignored_response_fields,
+                    &mut differing_fields,
+                );
+            }
+            let outcome = if canary_status_code == primary_response.status_code
+                && differing_fields.is_empty()
+            {
+                MirroredResponseOutcome::Matched
+            } else {
+                MirroredResponseOutcome::Mismatched
+            };
+            (outcome, Some(canary_status_code), differing_fields)
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to send the mirrored request to the canary");
+            (MirroredResponseOutcome::Failed, None, Vec::new())
+        }
+    };
+
+    TrafficMirroringReport {
+        route,
+        method,
+        outcome,
+        primary_status_code: primary_response.status_code,
+        canary_status_code,
+        canary_latency_in_millis,
+        differing_fields,
+    }
+}
+
+async fn send_mirrored_request(
+    conf: &Settings,
+    mirrored_request: MirroredRequest,
+) -> CustomResult<(u16, Option<serde_json::Value>), errors::ApiClientError> {
+    let client = client::create_client(&conf.proxy, false, None, None)?;
+
+    let mut request = client
+        .request(mirrored_request.method, &mirrored_request.url)
+        .headers(mirrored_request.headers)
+        .timeout(Duration::from_secs(conf.traffic_mirroring.timeout_in_secs));
+    if let Some(body) = mirrored_request.body {
+        request = request.json(&body);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|error| report!(errors::ApiClientError::RequestNotSent(error.to_string())))?;
+    let status_code = response.status().as_u16();
+    // Responses which are not JSON are compared only by their status codes
+    let body = response.json::<serde_json::Value>().await.ok();
+
+    Ok((status_code, body))
+}
+
+/// Collects the paths of the fields which differ between the responses, in the JSON pointer
+/// format. Fields present in only one of the responses are reported as differing as well.
+fn collect_differing_fields(
+    primary: &serde_json::Value,
+    canary: &serde_json::Value,
+    path: String,
+    ignored_fields: &std::collections::HashSet<String>,
+    differing_fields: &mut Vec<String>,
+) {
+    if differing_fields.len() >= MAX_REPORTED_DIFFERENCES {
+        return;
+    }
+
+    match (primary, canary) {
+        (serde_json::Value::Object(primary), serde_json::Value::Object(canary)) => {
+            let mut keys = primary.keys().chain(canary.keys()).collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+            for key in keys
+                .into_iter()
+                .filter(|key| !ignored_fields.contains(*key))
+            {
+                let field_path = format!("{path}/{key}");
+                match (primary.get(key), canary.get(key)) {
+                    (Some(primary), Some(canary)) => collect_differing_fields(
+                        primary,
+                        canary,
+                        field_path,
+                        ignored_fields,
+                        differing_fields,
+                    ),
+                    _ => differing_fields.push(field_path),
+                }
+            }
+        }
+        (serde_json::Value::Array(primary), serde_json::Value::Array(canary))
+            if primary.len() == canary.len() =>
+        {
+            for (index, (primary, canary)) in primary.iter().zip(canary.iter()).enumerate() {
+                collect_differing_fields(
+                    primary,
+                    canary,
+                    format!("{path}/{index}"),
+                    ignored_fields,
+                    differing_fields,
+                );
+            }
+        }
+        (primary, canary) if primary != canary => differing_fields.push(path),
+        _ => {}
+    }
+}
@@ -0,0 +1,73 @@
+//! Negotiation of the API version a request is served with.
+//!
+//! The request and response models of an API version are selected at compile time, so a
+//! deployment serves exactly one API version. The version a request expects is negotiated from the
+//! `X-ApiVersion` header sent by the client, falling back to the version the merchant is pinned
+//! to. Requests expecting a version which is not served by this deployment are rejected, instead
+//! of being processed against the models of a different version.
+
+use std::str::FromStr;
+
+use actix_http::header::HeaderMap;
+use common_enums::ApiVersion;
+use error_stack::ResultExt;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    headers,
+    services::authentication as auth,
+};
+
+/// The API version served by this deployment
+pub const SERVED_API_VERSION: ApiVersion = hyperswitch_domain_models::consts::API_VERSION;
+
+/// Reads the API version requested by the client, if any
+pub fn get_requested_api_version(request_headers: &HeaderMap) -> RouterResult<Option<ApiVersion>> {
+    auth::get_header_value_by_key(headers::X_API_VERSION.to_string(), request_headers)?
+        .map(|api_version| {
+            ApiVersion::from_str(api_version.trim()).change_context(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`{}` header is invalid", headers::X_API_VERSION),
+                },
+            )
+        })
+        .transpose()
+}
+
+/// Negotiates the API version to serve a request with. The version requested by the client takes
+/// precedence over the version the merchant is pinned to, and the version served by this
+/// deployment is used if neither is available.
+pub fn negotiate_api_version(
+    requested_api_version: Option<ApiVersion>,
+    pinned_api_version: Option<ApiVersion>,
+) -> RouterResult<ApiVersion> {
+    let api_version = requested_api_version
+        .or(pinned_api_version)
+        .unwrap_or(SERVED_API_VERSION);
+
+    if api_version != SERVED_API_VERSION {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "API version `{api_version}` is not served by this deployment, use API version `{SERVED_API_VERSION}` instead"
+            ),
+        })
+        .attach_printable_lazy(|| {
+            format!(
+                "requested API version: {requested_api_version:?}, pinned API version: {pinned_api_version:?}"
+            )
+        });
+    }
+
+    Ok(api_version)
+}
+
+/// Negotiates the API version to serve a request authenticated as a merchant with
+pub fn negotiate_merchant_api_version(
+    request_headers: &HeaderMap,
+    merchant_api_version: ApiVersion,
+) -> RouterResult<ApiVersion> {
+    negotiate_api_version(
+        get_requested_api_version(request_headers)?,
+        Some(merchant_api_version),
+    )
+}
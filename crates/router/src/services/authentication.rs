@@ -8,9 +8,11 @@ use api_models::payment_methods::PaymentMethodCreate;
 use api_models::payment_methods::PaymentMethodIntentConfirm;
 #[cfg(feature = "payouts")]
 use api_models::payouts;
-use api_models::{payment_methods::PaymentMethodListRequest, payments};
+use api_models::{
+    embed_tokens::EmbedTokenScope, payment_methods::PaymentMethodListRequest, payments,
+};
 use async_trait::async_trait;
-use common_enums::TokenPurpose;
+use common_enums::{AdminPermission, ApiKeyScope, TokenPurpose};
 use common_utils::{date_time, id_type};
 use error_stack::{report, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
@@ -23,7 +25,7 @@ use self::blacklist::BlackList;
 use self::detached::ExtractedPayload;
 #[cfg(feature = "partial-auth")]
 use self::detached::GetAuthType;
-use super::authorization::{self, permissions::Permission};
+use super::authorization::{self, admin_roles, permissions::Permission};
 #[cfg(feature = "olap")]
 use super::jwt;
 #[cfg(feature = "olap")]
@@ -111,6 +113,10 @@ pub enum AuthenticationType {
     AdminApiAuthWithMerchantId {
         merchant_id: id_type::MerchantId,
     },
+    ScopedAdminApiKey {
+        key_id: String,
+        merchant_id: Option<id_type::MerchantId>,
+    },
     OrganizationJwt {
         org_id: id_type::OrganizationId,
         user_id: String,
@@ -145,6 +151,13 @@ pub enum AuthenticationType {
     WebhookAuth {
         merchant_id: id_type::MerchantId,
     },
+    ScimToken {
+        merchant_id: id_type::MerchantId,
+    },
+    EmbedToken {
+        merchant_id: id_type::MerchantId,
+        profile_id: id_type::ProfileId,
+    },
     NoAuth,
 }
 
@@ -174,7 +187,10 @@ impl AuthenticationType {
                 user_id: _,
             }
             | Self::MerchantJwtWithProfileId { merchant_id, .. }
-            | Self::WebhookAuth { merchant_id } => Some(merchant_id),
+            | Self::WebhookAuth { merchant_id }
+            | Self::ScimToken { merchant_id }
+            | Self::EmbedToken { merchant_id, .. } => Some(merchant_id),
+            Self::ScopedAdminApiKey { merchant_id, .. } => merchant_id.as_ref(),
             Self::AdminApiKey
             | Self::OrganizationJwt { .. }
             | Self::UserJwt { .. }
@@ -183,6 +199,27 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    pub fn get_profile_id(&self) -> Option<&id_type::ProfileId> {
+        match self {
+            Self::MerchantJwtWithProfileId { profile_id, .. } => profile_id.as_ref(),
+            Self::EmbedToken { profile_id, .. } => Some(profile_id),
+            Self::ApiKey { .. }
+            | Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::ScopedAdminApiKey { .. }
+            | Self::OrganizationJwt { .. }
+            | Self::MerchantJwt { .. }
+            | Self::UserJwt { .. }
+            | Self::SinglePurposeJwt { .. }
+            | Self::SinglePurposeOrLoginJwt { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::ScimToken { .. }
+            | Self::NoAuth => None,
+        }
+    }
 }
 
 #[cfg(feature = "olap")]
@@ -267,6 +304,39 @@ impl AuthToken {
     }
 }
 
+/// The claims of a short lived token for a dashboard widget embedded in the portal of a platform,
+/// which only allows the routes of its scopes to be called for the data of a single profile
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct EmbedToken {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub scopes: Vec<EmbedTokenScope>,
+    pub exp: u64,
+    pub tenant_id: Option<id_type::TenantId>,
+}
+
+#[cfg(feature = "olap")]
+impl EmbedToken {
+    pub async fn new_token(
+        merchant_id: id_type::MerchantId,
+        profile_id: id_type::ProfileId,
+        scopes: Vec<EmbedTokenScope>,
+        exp_duration: std::time::Duration,
+        settings: &Settings,
+        tenant_id: Option<id_type::TenantId>,
+    ) -> UserResult<String> {
+        let exp = jwt::generate_exp(exp_duration)?.as_secs();
+        let token_payload = Self {
+            merchant_id,
+            profile_id,
+            scopes,
+            exp,
+            tenant_id,
+        };
+        jwt::generate_jwt(&token_payload, settings).await
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromToken {
     pub user_id: String,
@@ -401,42 +471,12 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("API key is empty");
-        }
+        let stored_api_key = get_stored_api_key(request_headers, state).await?;
+        validate_api_key_scope(&stored_api_key, None)?;
 
         let profile_id = HeaderMapStruct::new(request_headers)
             .get_id_type_from_header::<id_type::ProfileId>(headers::X_PROFILE_ID)?;
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
-
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
-        }
-
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -460,6 +500,8 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        api::versioning::negotiate_merchant_api_version(request_headers, merchant.version)?;
+
         let profile = state
             .store()
             .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
@@ -492,74 +534,149 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("API key is empty");
-        }
+        let stored_api_key = get_stored_api_key(request_headers, state).await?;
+        validate_api_key_scope(&stored_api_key, None)?;
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+        get_api_key_authentication_data(request_headers, state, stored_api_key).await
+    }
+}
 
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
+/// Authenticates requests made to endpoints belonging to an [`ApiKeyScope`]. Such endpoints can
+/// only be accessed by API keys that have been explicitly restricted to the scope.
+#[derive(Debug)]
+pub struct ScopedApiKeyAuth(pub ApiKeyScope);
 
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
-        }
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ScopedApiKeyAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let stored_api_key = get_stored_api_key(request_headers, state).await?;
+        validate_api_key_scope(&stored_api_key, Some(self.0))?;
 
-        let key_manager_state = &(&state.session_state()).into();
+        get_api_key_authentication_data(request_headers, state, stored_api_key).await
+    }
+}
 
-        let key_store = state
-            .store()
-            .get_merchant_key_store_by_merchant_id(
-                key_manager_state,
-                &stored_api_key.merchant_id,
-                &state.store().get_master_key().to_vec().into(),
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+#[cfg(feature = "v1")]
+async fn get_api_key_authentication_data<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    stored_api_key: storage::ApiKey,
+) -> RouterResult<(AuthenticationData, AuthenticationType)>
+where
+    A: SessionStateInfo + Sync,
+{
+    let key_manager_state = &(&state.session_state()).into();
 
-        let merchant = state
-            .store()
-            .find_merchant_account_by_merchant_id(
-                key_manager_state,
-                &stored_api_key.merchant_id,
-                &key_store,
-            )
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &stored_api_key.merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
 
-        let auth = AuthenticationData {
-            merchant_account: merchant,
-            key_store,
-            profile_id: None,
-        };
-        Ok((
-            auth.clone(),
-            AuthenticationType::ApiKey {
-                merchant_id: auth.merchant_account.get_id().clone(),
-                key_id: stored_api_key.key_id,
-            },
-        ))
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &stored_api_key.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    api::versioning::negotiate_merchant_api_version(request_headers, merchant.version)?;
+
+    let auth = AuthenticationData {
+        merchant_account: merchant,
+        key_store,
+        profile_id: None,
+    };
+    Ok((
+        auth.clone(),
+        AuthenticationType::ApiKey {
+            merchant_id: auth.merchant_account.get_id().clone(),
+            key_id: stored_api_key.key_id,
+        },
+    ))
+}
+
+/// Fetches the stored API key corresponding to the API key present in the request headers,
+/// ensuring that the API key has not expired.
+async fn get_stored_api_key<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+) -> RouterResult<storage::ApiKey>
+where
+    A: SessionStateInfo + Sync,
+{
+    let api_key = get_api_key(request_headers)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?
+        .trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized).attach_printable("API key is empty");
+    }
+
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        config.api_keys.get_inner().get_hash_key()?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
+
+    Ok(stored_api_key)
+}
+
+/// Ensures that an API key restricted to one or more scopes is only used to access the endpoints
+/// belonging to those scopes, and that scoped endpoints are only accessed by API keys restricted
+/// to the scope.
+fn validate_api_key_scope(
+    stored_api_key: &storage::ApiKey,
+    required_scope: Option<ApiKeyScope>,
+) -> RouterResult<()> {
+    let scopes = stored_api_key.scopes.as_deref().unwrap_or_default();
+
+    match required_scope {
+        None if !scopes.is_empty() => Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "API keys restricted to specific scopes can only access the endpoints of \
+                those scopes"
+                .to_string(),
+        })),
+        Some(scope) if !scopes.contains(&scope) => {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: format!("API key is not permitted the `{scope}` scope"),
+            }))
+        }
+        _ => Ok(()),
     }
 }
 
@@ -949,6 +1066,231 @@ where
     }
 }
 
+/// Authenticates requests made to the admin endpoints which require an [`AdminPermission`]. Such
+/// endpoints can be accessed by the admin API key, and by the scoped admin API keys whose admin
+/// role grants the permission.
+#[derive(Debug)]
+pub struct AdminApiAuthWithPermission(pub AdminPermission);
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<(), A> for AdminApiAuthWithPermission
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<((), AuthenticationType)> {
+        let auth_type =
+            authenticate_admin_api_key_with_permission(request_headers, state, self.0, None)
+                .await?;
+
+        Ok(((), auth_type))
+    }
+}
+
+/// Authenticates requests made by identity providers to the SCIM endpoints, using the bearer
+/// token of the SCIM configuration of a merchant.
+#[cfg(feature = "olap")]
+#[derive(Debug)]
+pub struct ScimAuth;
+
+#[cfg(feature = "olap")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<storage::ScimConfig, A> for ScimAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(storage::ScimConfig, AuthenticationType)> {
+        let token = get_jwt_from_authorization_header(request_headers)
+            .change_context(errors::ApiErrorResponse::Unauthorized)?
+            .trim();
+        if token.is_empty() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("SCIM token is empty");
+        }
+
+        let hash_key = state.conf().api_keys.get_inner().get_hash_key()?;
+        let hashed_token = api_keys::PlaintextApiKey::from(token).keyed_hash(hash_key.peek());
+
+        let scim_config = state
+            .store()
+            .find_scim_config_by_hashed_token_optional(hashed_token.into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve SCIM configuration")?
+            .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("SCIM Authentication Failure")?;
+
+        let merchant_id = scim_config.merchant_id.clone();
+        Ok((scim_config, AuthenticationType::ScimToken { merchant_id }))
+    }
+}
+
+#[derive(Debug)]
+pub struct AdminApiAuthWithPermissionAndMerchantIdFromRoute {
+    pub merchant_id: id_type::MerchantId,
+    pub permission: AdminPermission,
+}
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A>
+    for AdminApiAuthWithPermissionAndMerchantIdFromRoute
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let auth_type = authenticate_admin_api_key_with_permission(
+            request_headers,
+            state,
+            self.permission,
+            Some(self.merchant_id.clone()),
+        )
+        .await?;
+        let auth = get_admin_authentication_data(state, &self.merchant_id).await?;
+
+        Ok((auth, auth_type))
+    }
+}
+
+#[derive(Debug)]
+pub struct AdminApiAuthWithPermissionAndMerchantIdFromHeader(pub AdminPermission);
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A>
+    for AdminApiAuthWithPermissionAndMerchantIdFromHeader
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let merchant_id = HeaderMapStruct::new(request_headers)
+            .get_id_type_from_header::<id_type::MerchantId>(headers::X_MERCHANT_ID)?;
+        let auth_type = authenticate_admin_api_key_with_permission(
+            request_headers,
+            state,
+            self.0,
+            Some(merchant_id.clone()),
+        )
+        .await?;
+        let auth = get_admin_authentication_data(state, &merchant_id).await?;
+
+        Ok((auth, auth_type))
+    }
+}
+
+/// Authenticates the admin API key, or a scoped admin API key whose admin role grants the
+/// permission, present in the request headers.
+async fn authenticate_admin_api_key_with_permission<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    permission: AdminPermission,
+    merchant_id: Option<id_type::MerchantId>,
+) -> RouterResult<AuthenticationType>
+where
+    A: SessionStateInfo + Sync,
+{
+    let request_api_key =
+        get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+    let conf = state.conf();
+
+    if request_api_key == conf.secrets.get_inner().admin_api_key.peek() {
+        return Ok(match merchant_id {
+            Some(merchant_id) => AuthenticationType::AdminApiAuthWithMerchantId { merchant_id },
+            None => AuthenticationType::AdminApiKey,
+        });
+    }
+
+    let request_api_key = request_api_key.trim();
+    if request_api_key.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Admin API key is empty");
+    }
+
+    let hash_key = conf.api_keys.get_inner().get_hash_key()?;
+    let hashed_api_key =
+        api_keys::PlaintextApiKey::from(request_api_key).keyed_hash(hash_key.peek());
+
+    let admin_api_key = state
+        .store()
+        .find_admin_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve admin API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Admin Authentication Failure")?;
+
+    if admin_api_key
+        .expires_at
+        .is_some_and(|expires_at| expires_at < date_time::now())
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Admin API key has expired");
+    }
+
+    let role_info = admin_roles::PREDEFINED_ADMIN_ROLES
+        .get(admin_api_key.role_id.as_str())
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Admin role assigned to the admin API key does not exist")?;
+
+    if !role_info.has_permission(permission) {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: format!("Admin API key is not permitted the `{permission}` permission"),
+        }));
+    }
+
+    Ok(AuthenticationType::ScopedAdminApiKey {
+        key_id: admin_api_key.key_id,
+        merchant_id,
+    })
+}
+
+#[cfg(feature = "v1")]
+async fn get_admin_authentication_data<A>(
+    state: &A,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<AuthenticationData>
+where
+    A: SessionStateInfo + Sync,
+{
+    let key_manager_state = &(&state.session_state()).into();
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    Ok(AuthenticationData {
+        merchant_account: merchant,
+        key_store,
+        profile_id: None,
+    })
+}
+
 #[derive(Debug)]
 pub struct AdminApiAuthWithMerchantIdFromRoute(pub id_type::MerchantId);
 
@@ -1590,22 +1932,23 @@ where
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
         let key_manager_state = &(&state.session_state()).into();
-        state
+        let (merchant_account, key_store) = state
             .store()
             .find_merchant_account_by_publishable_key(key_manager_state, publishable_key)
             .await
-            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)
-            .map(|(merchant_account, key_store)| {
-                let merchant_id = merchant_account.get_id().clone();
-                (
-                    AuthenticationData {
-                        merchant_account,
-                        key_store,
-                        profile_id: None,
-                    },
-                    AuthenticationType::PublishableKey { merchant_id },
-                )
-            })
+            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+        api::versioning::negotiate_merchant_api_version(request_headers, merchant_account.version)?;
+
+        let merchant_id = merchant_account.get_id().clone();
+        Ok((
+            AuthenticationData {
+                merchant_account,
+                key_store,
+                profile_id: None,
+            },
+            AuthenticationType::PublishableKey { merchant_id },
+        ))
     }
 }
 
@@ -1632,6 +1975,7 @@ where
             .find_merchant_account_by_publishable_key(key_manager_state, publishable_key)
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+        api::versioning::negotiate_merchant_api_version(request_headers, merchant_account.version)?;
         let merchant_id = merchant_account.get_id().clone();
         let profile = state
             .store()
@@ -2548,6 +2892,75 @@ where
     }
 }
 
+/// Authenticates the requests of embedded dashboard widgets, made with an embed token whose scopes
+/// include the scope of the route
+pub struct EmbedTokenAuth {
+    pub scope: EmbedTokenScope,
+}
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for EmbedTokenAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let token = get_header_value_by_key(headers::X_EMBED_TOKEN.into(), request_headers)?
+            .get_required_value(headers::X_EMBED_TOKEN)?;
+        let payload = decode_jwt::<EmbedToken>(token, state).await?;
+        authorization::check_tenant(
+            payload.tenant_id.clone(),
+            &state.session_state().tenant.tenant_id,
+        )?;
+
+        if !payload.scopes.contains(&self.scope) {
+            return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: self.scope.to_string(),
+            }));
+        }
+
+        let key_manager_state = &(&state.session_state()).into();
+        let key_store = state
+            .store()
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &payload.merchant_id,
+                &state.store().get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+        let merchant = state
+            .store()
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &payload.merchant_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Failed to fetch merchant account for the merchant id")?;
+
+        let auth = AuthenticationData {
+            merchant_account: merchant,
+            key_store,
+            profile_id: Some(payload.profile_id.clone()),
+        };
+        Ok((
+            auth,
+            AuthenticationType::EmbedToken {
+                merchant_id: payload.merchant_id,
+                profile_id: payload.profile_id,
+            },
+        ))
+    }
+}
+
 pub async fn parse_jwt_payload<A, T>(headers: &HeaderMap, state: &A) -> RouterResult<T>
 where
     T: serde::de::DeserializeOwned,
@@ -3151,6 +3564,19 @@ pub fn strip_jwt_token(token: &str) -> RouterResult<&str> {
         .ok_or_else(|| errors::ApiErrorResponse::InvalidJwtToken.into())
 }
 
+/// The authentication of a route which can also be called by embedded dashboard widgets, which is
+/// the embed token authentication if the request is made with an embed token
+pub fn embed_token_or_auth_type<'a, T, A>(
+    embed_token_auth: &'a dyn AuthenticateAndFetch<T, A>,
+    auth: &'a dyn AuthenticateAndFetch<T, A>,
+    headers: &HeaderMap,
+) -> &'a dyn AuthenticateAndFetch<T, A> {
+    if headers.contains_key(headers::X_EMBED_TOKEN) {
+        return embed_token_auth;
+    }
+    auth
+}
+
 pub fn auth_type<'a, T, A>(
     default_auth: &'a dyn AuthenticateAndFetch<T, A>,
     jwt_auth_type: &'a dyn AuthenticateAndFetch<T, A>,
@@ -3322,3 +3748,239 @@ impl ReconToken {
         jwt::generate_jwt(&token_payload, settings).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use actix_web::http::header::HeaderValue;
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{
+        configs::settings::Settings,
+        consts,
+        db::StorageImpl,
+        routes::{self, SessionState},
+        services,
+    };
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                || {},
+            )
+            .unwrap()
+    }
+
+    /// Stores an admin API key assigned the given admin role, returning the plaintext API key
+    async fn insert_admin_api_key(
+        state: &SessionState,
+        role_id: &str,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> String {
+        let plaintext_api_key = api_keys::PlaintextApiKey::new(consts::API_KEY_LENGTH);
+        let hash_key = state.conf.api_keys.get_inner().get_hash_key().unwrap();
+        let now = date_time::now();
+        state
+            .store
+            .insert_admin_api_key(storage::AdminApiKeyNew {
+                key_id: api_keys::PlaintextApiKey::new_key_id()
+                    .get_string_repr()
+                    .to_owned(),
+                name: "test_admin_api_key".to_string(),
+                description: None,
+                hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+                prefix: plaintext_api_key.prefix(),
+                role_id: role_id.to_string(),
+                created_at: now,
+                expires_at,
+                last_modified_at: now,
+            })
+            .await
+            .unwrap();
+
+        plaintext_api_key.peek().to_owned()
+    }
+
+    fn get_request_headers(api_key: &str) -> HeaderMap {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            headers::API_KEY.parse().unwrap(),
+            HeaderValue::from_str(api_key).unwrap(),
+        );
+        request_headers
+    }
+
+    fn get_api_key(scopes: Option<Vec<ApiKeyScope>>) -> storage::ApiKey {
+        storage::ApiKey {
+            key_id: api_keys::PlaintextApiKey::new_key_id(),
+            merchant_id: id_type::MerchantId::default(),
+            name: "test_api_key".to_string(),
+            description: None,
+            hashed_api_key: "hashed_api_key".to_string().into(),
+            prefix: "snd_test".to_string(),
+            created_at: date_time::now(),
+            expires_at: None,
+            last_used: None,
+            scopes,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_api_key_is_permitted_every_permission() {
+        let state = get_session_state().await;
+        let admin_api_key = state.conf.secrets.get_inner().admin_api_key.clone();
+        let request_headers = get_request_headers(admin_api_key.peek());
+
+        let auth_type = authenticate_admin_api_key_with_permission(
+            &request_headers,
+            &state,
+            AdminPermission::KvToggle,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(auth_type, AuthenticationType::AdminApiKey));
+
+        let merchant_id = id_type::MerchantId::default();
+        let auth_type = authenticate_admin_api_key_with_permission(
+            &request_headers,
+            &state,
+            AdminPermission::KvToggle,
+            Some(merchant_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            auth_type,
+            AuthenticationType::AdminApiAuthWithMerchantId { merchant_id: id } if id == merchant_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scoped_admin_api_key_with_permission() {
+        let state = get_session_state().await;
+        let api_key =
+            insert_admin_api_key(&state, admin_roles::ADMIN_ROLE_ID_KV_OPERATOR, None).await;
+        let merchant_id = id_type::MerchantId::default();
+
+        let auth_type = authenticate_admin_api_key_with_permission(
+            &get_request_headers(&api_key),
+            &state,
+            AdminPermission::KvToggle,
+            Some(merchant_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            auth_type,
+            AuthenticationType::ScopedAdminApiKey {
+                merchant_id: Some(id),
+                ..
+            } if id == merchant_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scoped_admin_api_key_without_permission() {
+        let state = get_session_state().await;
+        let api_key =
+            insert_admin_api_key(&state, admin_roles::ADMIN_ROLE_ID_KV_OPERATOR, None).await;
+
+        let error = authenticate_admin_api_key_with_permission(
+            &get_request_headers(&api_key),
+            &state,
+            AdminPermission::MerchantAccountCreate,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expired_scoped_admin_api_key() {
+        let state = get_session_state().await;
+        let api_key = insert_admin_api_key(
+            &state,
+            admin_roles::ADMIN_ROLE_ID_FULL_ACCESS,
+            Some(date_time::now() - time::Duration::days(1)),
+        )
+        .await;
+
+        let error = authenticate_admin_api_key_with_permission(
+            &get_request_headers(&api_key),
+            &state,
+            AdminPermission::KvToggle,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::Unauthorized
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scoped_admin_api_key_with_unknown_role() {
+        let state = get_session_state().await;
+        let api_key = insert_admin_api_key(&state, "admin_deleted_role", None).await;
+
+        let error = authenticate_admin_api_key_with_permission(
+            &get_request_headers(&api_key),
+            &state,
+            AdminPermission::KvToggle,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::Unauthorized
+        ));
+    }
+
+    #[test]
+    fn test_validate_api_key_scope() {
+        let unscoped_api_key = get_api_key(None);
+        assert!(validate_api_key_scope(&unscoped_api_key, None).is_ok());
+        assert!(validate_api_key_scope(&unscoped_api_key, Some(ApiKeyScope::Tokenize)).is_err());
+
+        let scoped_api_key = get_api_key(Some(vec![ApiKeyScope::Tokenize]));
+        assert!(validate_api_key_scope(&scoped_api_key, Some(ApiKeyScope::Tokenize)).is_ok());
+        assert!(matches!(
+            validate_api_key_scope(&scoped_api_key, Some(ApiKeyScope::Detokenize))
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::AccessForbidden { .. }
+        ));
+        assert!(matches!(
+            validate_api_key_scope(&scoped_api_key, None)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use common_enums::AdminPermission;
+use once_cell::sync::Lazy;
+
+pub const ADMIN_ROLE_ID_FULL_ACCESS: &str = "admin_full_access";
+pub const ADMIN_ROLE_ID_MERCHANT_ONBOARDING: &str = "admin_merchant_onboarding";
+pub const ADMIN_ROLE_ID_CONNECTOR_MANAGER: &str = "admin_connector_manager";
+pub const ADMIN_ROLE_ID_ROUTING_MANAGER: &str = "admin_routing_manager";
+pub const ADMIN_ROLE_ID_KV_OPERATOR: &str = "admin_kv_operator";
+
+/// The role assigned to a scoped admin API key, which determines the admin endpoints that the
+/// key is permitted to access
+#[derive(Clone, Debug)]
+pub struct AdminRoleInfo {
+    role_id: &'static str,
+    role_name: &'static str,
+    permissions: Vec<AdminPermission>,
+}
+
+impl AdminRoleInfo {
+    pub fn get_role_id(&self) -> &'static str {
+        self.role_id
+    }
+
+    pub fn get_role_name(&self) -> &'static str {
+        self.role_name
+    }
+
+    pub fn get_permissions(&self) -> &[AdminPermission] {
+        &self.permissions
+    }
+
+    pub fn has_permission(&self, permission: AdminPermission) -> bool {
+        self.permissions.contains(&permission)
+    }
+}
+
+pub static PREDEFINED_ADMIN_ROLES: Lazy<HashMap<&'static str, AdminRoleInfo>> = Lazy::new(|| {
+    let mut roles = HashMap::new();
+
+    roles.insert(
+        ADMIN_ROLE_ID_FULL_ACCESS,
+        AdminRoleInfo {
+            role_id: ADMIN_ROLE_ID_FULL_ACCESS,
+            role_name: "full_access",
+            permissions: vec![
+                AdminPermission::MerchantAccountCreate,
+                AdminPermission::MerchantConnectorManage,
+                AdminPermission::RoutingActivate,
+                AdminPermission::KvToggle,
+            ],
+        },
+    );
+    roles.insert(
+        ADMIN_ROLE_ID_MERCHANT_ONBOARDING,
+        AdminRoleInfo {
+            role_id: ADMIN_ROLE_ID_MERCHANT_ONBOARDING,
+            role_name: "merchant_onboarding",
+            permissions: vec![
+                AdminPermission::MerchantAccountCreate,
+                AdminPermission::MerchantConnectorManage,
+            ],
+        },
+    );
+    roles.insert(
+        ADMIN_ROLE_ID_CONNECTOR_MANAGER,
+        AdminRoleInfo {
+            role_id: ADMIN_ROLE_ID_CONNECTOR_MANAGER,
+            role_name: "connector_manager",
+            permissions: vec![AdminPermission::MerchantConnectorManage],
+        },
+    );
+    roles.insert(
+        ADMIN_ROLE_ID_ROUTING_MANAGER,
+        AdminRoleInfo {
+            role_id: ADMIN_ROLE_ID_ROUTING_MANAGER,
+            role_name: "routing_manager",
+            permissions: vec![AdminPermission::RoutingActivate],
+        },
+    );
+    roles.insert(
+        ADMIN_ROLE_ID_KV_OPERATOR,
+        AdminRoleInfo {
+            role_id: ADMIN_ROLE_ID_KV_OPERATOR,
+            role_name: "kv_operator",
+            permissions: vec![AdminPermission::KvToggle],
+        },
+    );
+
+    roles
+});
@@ -44,6 +44,7 @@ fn get_group_description(group: PermissionGroup) -> &'static str {
         PermissionGroup::ReconReportsManage => "Manage reconciliation reports",
         PermissionGroup::ReconOpsView => "View and access reconciliation operations",
         PermissionGroup::ReconOpsManage => "Manage reconciliation operations",
+        PermissionGroup::PayloadLogsView => "View redacted request and response payloads of API calls",
     }
 }
 
@@ -57,5 +58,6 @@ pub fn get_parent_group_description(group: ParentGroup) -> &'static str {
         ParentGroup::Account => "Create, modify and delete Merchant Details like api keys, webhooks, etc",
         ParentGroup::ReconOps => "View, manage reconciliation operations like upload and process files, run reconciliation etc",
         ParentGroup::ReconReports => "View, manage reconciliation reports and analytics",
+        ParentGroup::PayloadLogs => "View redacted request and response payloads of API calls",
     }
 }
@@ -23,7 +23,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::MerchantDetailsView
             | Self::AccountView
             | Self::ReconOpsView
-            | Self::ReconReportsView => PermissionScope::Read,
+            | Self::ReconReportsView
+            | Self::PayloadLogsView => PermissionScope::Read,
 
             Self::OperationsManage
             | Self::ConnectorsManage
@@ -51,6 +52,7 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::AccountManage => ParentGroup::Account,
             Self::ReconOpsView | Self::ReconOpsManage => ParentGroup::ReconOps,
             Self::ReconReportsView | Self::ReconReportsManage => ParentGroup::ReconReports,
+            Self::PayloadLogsView => ParentGroup::PayloadLogs,
         }
     }
 
@@ -95,6 +97,8 @@ impl PermissionGroupExt for PermissionGroup {
 
             Self::AccountView => vec![Self::AccountView],
             Self::AccountManage => vec![Self::AccountView, Self::AccountManage],
+
+            Self::PayloadLogsView => vec![Self::PayloadLogsView],
         }
     }
 }
@@ -118,6 +122,7 @@ impl ParentGroupExt for ParentGroup {
             Self::Account => ACCOUNT.to_vec(),
             Self::ReconOps => RECON_OPS.to_vec(),
             Self::ReconReports => RECON_REPORTS.to_vec(),
+            Self::PayloadLogs => PAYLOAD_LOGS.to_vec(),
         }
     }
 
@@ -191,3 +196,5 @@ pub static RECON_REPORTS: [Resource; 3] = [
     Resource::ReconAndSettlementAnalytics,
     Resource::ReconReports,
 ];
+
+pub static PAYLOAD_LOGS: [Resource; 2] = [Resource::PayloadLog, Resource::Account];
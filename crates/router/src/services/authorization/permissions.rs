@@ -95,6 +95,10 @@ generate_permissions! {
             scopes: [Read, Write],
             entities: [Merchant]
         },
+        PayloadLog: {
+            scopes: [Read],
+            entities: [Merchant]
+        },
     ]
 }
 
@@ -115,6 +119,7 @@ pub fn get_resource_name(resource: &Resource, entity_type: &EntityType) -> &'sta
         (Resource::Report, _) => "Operation Reports",
         (Resource::User, _) => "Users",
         (Resource::WebhookEvent, _) => "Webhook Events",
+        (Resource::PayloadLog, _) => "Request Payload Logs",
         (Resource::ReconUpload, _) => "Reconciliation File Upload",
         (Resource::RunRecon, _) => "Run Reconciliation Process",
         (Resource::ReconConfig, _) => "Reconciliation Configurations",
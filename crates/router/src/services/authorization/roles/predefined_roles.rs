@@ -32,6 +32,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::PayloadLogsView,
             ],
             role_id: common_utils::consts::ROLE_ID_INTERNAL_ADMIN.to_string(),
             role_name: "internal_admin".to_string(),
@@ -56,6 +57,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::AccountView,
                 PermissionGroup::ReconOpsView,
                 PermissionGroup::ReconReportsView,
+                PermissionGroup::PayloadLogsView,
             ],
             role_id: common_utils::consts::ROLE_ID_INTERNAL_VIEW_ONLY_USER.to_string(),
             role_name: "internal_view_only".to_string(),
@@ -91,6 +93,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::PayloadLogsView,
             ],
             role_id: common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
             role_name: "organization_admin".to_string(),
@@ -125,6 +128,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::PayloadLogsView,
             ],
             role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
             role_name: "merchant_admin".to_string(),
@@ -54,6 +54,13 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    ConnectorCredentialsExpiryReminder {
+        expires_in: i64,
+        credential_type: String,
+        connector_name: String,
+        connector_label: String,
+        merchant_connector_id: String,
+    },
     WelcomeToCommunity,
 }
 
@@ -143,6 +150,20 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::ConnectorCredentialsExpiryReminder {
+                expires_in,
+                credential_type,
+                connector_name,
+                connector_label,
+                merchant_connector_id,
+            } => format!(
+                include_str!("assets/connector_credentials_expiry_reminder.html"),
+                credential_type = credential_type,
+                connector_name = connector_name,
+                connector_label = connector_label,
+                merchant_connector_id = merchant_connector_id,
+                expires_in = expires_in,
+            ),
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
@@ -507,6 +528,40 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+pub struct ConnectorCredentialsExpiryReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub expires_in: i64,
+    pub credential_type: common_enums::ConnectorCredentialType,
+    pub connector_name: String,
+    pub connector_label: Option<String>,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ConnectorCredentialsExpiryReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::ConnectorCredentialsExpiryReminder {
+            expires_in: self.expires_in,
+            credential_type: self.credential_type.to_string().replace('_', " "),
+            connector_name: self.connector_name.clone(),
+            connector_label: self
+                .connector_label
+                .clone()
+                .unwrap_or_else(|| self.connector_name.clone()),
+            merchant_connector_id: self.merchant_connector_id.get_string_repr().to_owned(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
     pub subject: &'static str,
@@ -524,3 +579,37 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct InvoiceEmail {
+    pub recipient_email: pii::Email,
+    pub subject: &'static str,
+    pub invoice_html: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for InvoiceEmail {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(self.invoice_html.clone()),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct ProfileNotificationEmail {
+    pub recipient_email: pii::Email,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ProfileNotificationEmail {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: external_services::email::IntermediateString::new(self.body.clone()),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
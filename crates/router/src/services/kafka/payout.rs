@@ -38,6 +38,9 @@ pub struct KafkaPayout<'a> {
     pub business_country: Option<storage_enums::CountryAlpha2>,
     pub business_label: Option<&'a String>,
     pub merchant_connector_id: Option<&'a id_type::MerchantConnectorAccountId>,
+    pub fee: Option<MinorUnit>,
+    pub net_amount: Option<MinorUnit>,
+    pub fee_source: Option<storage_enums::PayoutFeeSource>,
 }
 
 impl<'a> KafkaPayout<'a> {
@@ -73,6 +76,9 @@ impl<'a> KafkaPayout<'a> {
             business_country: payout_attempt.business_country,
             business_label: payout_attempt.business_label.as_ref(),
             merchant_connector_id: payout_attempt.merchant_connector_id.as_ref(),
+            fee: payout_attempt.fee,
+            net_amount: payout_attempt.net_amount,
+            fee_source: payout_attempt.fee_source,
         }
     }
 }
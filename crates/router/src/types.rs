@@ -31,7 +31,7 @@ use hyperswitch_domain_models::router_flow_types::{
     files::{Retrieve, Upload},
     mandate_revoke::MandateRevoke,
     payments::{
-        Approve, Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture,
+        Approve, Authorize, AuthorizeSessionToken, Balance, BankList, CalculateTax, Capture,
         CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync,
         PostProcessing, PostSessionTokens, PreProcessing, Reject, SdkSessionUpdate, Session,
         SetupMandate, Void,
@@ -43,8 +43,9 @@ pub use hyperswitch_domain_models::{
     payment_address::PaymentAddress,
     router_data::{
         AccessToken, AdditionalPaymentMethodConnectorResponse, ApplePayCryptogramData,
-        ApplePayPredecryptData, ConnectorAuthType, ConnectorResponseData, ErrorResponse,
-        PaymentMethodBalance, PaymentMethodToken, RecurringMandatePaymentData, RouterData,
+        ApplePayPredecryptData, ConnectorAuthType, ConnectorCurrencyConversion,
+        ConnectorRefundDetails, ConnectorResponseData, ErrorResponse, PaymentMethodBalance,
+        PaymentMethodToken, RecurringMandatePaymentData, RouterData,
     },
     router_data_v2::{
         AccessTokenFlowData, DisputesFlowData, ExternalAuthenticationFlowData, FilesFlowData,
@@ -53,11 +54,11 @@ pub use hyperswitch_domain_models::{
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        BrowserInformation, ChargeRefunds, ChargeRefundsOptions, CompleteAuthorizeData,
-        CompleteAuthorizeRedirectResponse, ConnectorCustomerData, DefendDisputeRequestData,
-        DestinationChargeRefund, DirectChargeRefund, MandateRevokeRequestData,
-        MultipleCaptureRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
-        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        BankListRequestData, BrowserInformation, ChargeRefunds, ChargeRefundsOptions,
+        CompleteAuthorizeData, CompleteAuthorizeRedirectResponse, ConnectorCustomerData,
+        DefendDisputeRequestData, DestinationChargeRefund, DirectChargeRefund,
+        MandateRevokeRequestData, MultipleCaptureRequestData, PaymentMethodTokenizationData,
+        PaymentsApproveData, PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
         PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData, RefundsData, ResponseId,
@@ -66,8 +67,8 @@ pub use hyperswitch_domain_models::{
         VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, CaptureSyncResponse, DefendDisputeResponse, MandateReference,
-        MandateRevokeResponseData, PaymentsResponseData, PreprocessingResponseId,
+        AcceptDisputeResponse, BankListResponseData, CaptureSyncResponse, DefendDisputeResponse,
+        MandateReference, MandateRevokeResponseData, PaymentsResponseData, PreprocessingResponseId,
         RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
         TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
         VerifyWebhookStatus,
@@ -75,22 +76,28 @@ pub use hyperswitch_domain_models::{
 };
 #[cfg(feature = "payouts")]
 pub use hyperswitch_domain_models::{
-    router_data_v2::PayoutFlowData, router_request_types::PayoutsData,
-    router_response_types::PayoutsResponseData,
+    router_data_v2::PayoutFlowData,
+    router_request_types::{PayoutBalanceRequestData, PayoutsData},
+    router_response_types::{
+        PayoutBalanceResponseData, PayoutConnectorBalance, PayoutRecipientStatusResponseData,
+        PayoutsResponseData,
+    },
 };
 pub use hyperswitch_interfaces::types::{
     AcceptDisputeType, ConnectorCustomerType, DefendDisputeType, IncrementalAuthorizationType,
-    MandateRevokeType, PaymentsAuthorizeType, PaymentsBalanceType, PaymentsCaptureType,
-    PaymentsCompleteAuthorizeType, PaymentsInitType, PaymentsPostProcessingType,
-    PaymentsPostSessionTokensType, PaymentsPreAuthorizeType, PaymentsPreProcessingType,
-    PaymentsSessionType, PaymentsSyncType, PaymentsVoidType, RefreshTokenType, RefundExecuteType,
-    RefundSyncType, Response, RetrieveFileType, SdkSessionUpdateType, SetupMandateType,
-    SubmitEvidenceType, TokenizationType, UploadFileType, VerifyWebhookSourceType,
+    MandateRevokeType, PaymentsAuthorizeType, PaymentsBalanceType, PaymentsBankListType,
+    PaymentsCaptureType, PaymentsCompleteAuthorizeType, PaymentsInitType,
+    PaymentsPostProcessingType, PaymentsPostSessionTokensType, PaymentsPreAuthorizeType,
+    PaymentsPreProcessingType, PaymentsSessionType, PaymentsSyncType, PaymentsVoidType,
+    RefreshTokenType, RefundExecuteType, RefundSyncType, Response, RetrieveFileType,
+    SdkSessionUpdateType, SetupMandateType, SubmitEvidenceType, TokenizationType, UploadFileType,
+    VerifyWebhookSourceType,
 };
 #[cfg(feature = "payouts")]
 pub use hyperswitch_interfaces::types::{
-    PayoutCancelType, PayoutCreateType, PayoutEligibilityType, PayoutFulfillType, PayoutQuoteType,
-    PayoutRecipientAccountType, PayoutRecipientType, PayoutSyncType,
+    PayoutBalanceType, PayoutCancelType, PayoutCreateType, PayoutEligibilityType,
+    PayoutFulfillType, PayoutQuoteType, PayoutRecipientAccountType, PayoutRecipientStatusType,
+    PayoutRecipientType, PayoutSyncType,
 };
 
 pub use crate::core::payments::CustomerDetails;
@@ -139,6 +146,8 @@ pub type SdkSessionUpdateRouterData =
 pub type PaymentsPostSessionTokensRouterData =
     RouterData<PostSessionTokens, PaymentsPostSessionTokensData, PaymentsResponseData>;
 
+pub type BankListRouterData = RouterData<BankList, BankListRequestData, BankListResponseData>;
+
 pub type PaymentsCancelRouterData = RouterData<Void, PaymentsCancelData, PaymentsResponseData>;
 pub type PaymentsRejectRouterData = RouterData<Reject, PaymentsRejectData, PaymentsResponseData>;
 pub type PaymentsApproveRouterData = RouterData<Approve, PaymentsApproveData, PaymentsResponseData>;
@@ -215,6 +224,14 @@ pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
 pub type PayoutsResponseRouterData<F, R> =
     ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
 
+#[cfg(feature = "payouts")]
+pub type PayoutBalanceRouterData =
+    RouterData<api::PoBalance, PayoutBalanceRequestData, PayoutBalanceResponseData>;
+
+#[cfg(feature = "payouts")]
+pub type PayoutRecipientStatusRouterData =
+    RouterData<api::PoRecipientStatus, PayoutsData, PayoutRecipientStatusResponseData>;
+
 #[cfg(feature = "payouts")]
 pub type PayoutActionData = Vec<(
     storage::Payouts,
@@ -892,6 +909,7 @@ impl ForeignFrom<&SetupMandateRouterData> for PaymentsAuthorizeData {
             merchant_order_reference_id: None,
             integrity_object: None,
             additional_payment_method_data: None,
+            currency_conversion: None,
             shipping_cost: data.request.shipping_cost,
         }
     }
@@ -421,6 +421,9 @@ impl ConnectorData {
                 enums::Connector::Forte => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Forte::new())))
                 }
+                enums::Connector::Generichttp => {
+                    Ok(ConnectorEnum::Old(Box::new(connector::Generichttp::new())))
+                }
                 enums::Connector::Globalpay => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Globalpay::new())))
                 }
@@ -474,6 +477,9 @@ impl ConnectorData {
                 enums::Connector::Placetopay => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Placetopay::new())))
                 }
+                enums::Connector::Plugin => {
+                    Ok(ConnectorEnum::Old(Box::new(connector::Plugin::new())))
+                }
                 enums::Connector::Powertranz => {
                     Ok(ConnectorEnum::Old(Box::new(&connector::Powertranz)))
                 }
@@ -8,8 +8,10 @@ pub use api_models::{
         MerchantAccountUpdate, MerchantConnectorCreate, MerchantConnectorDeleteResponse,
         MerchantConnectorDetails, MerchantConnectorDetailsWrap, MerchantConnectorId,
         MerchantConnectorResponse, MerchantDetails, MerchantId, PaymentMethodsEnabled,
-        ProfileCreate, ProfileResponse, ProfileUpdate, ToggleAllKVRequest, ToggleAllKVResponse,
-        ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        ProfileCreate, ProfileResponse, ProfileUpdate, RotatePublishableKeyRequest,
+        RotatePublishableKeyResponse, RotateWebhookSecretRequest, ToggleAllKVRequest,
+        ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        WebhookSecretResponse,
     },
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
@@ -42,6 +44,7 @@ impl ForeignFrom<diesel_models::organization::Organization> for OrganizationResp
             organization_name: org.get_organization_name(),
             organization_details: org.organization_details,
             metadata: org.metadata,
+            is_token_sharing_enabled: org.is_token_sharing_enabled.unwrap_or(false),
             modified_at: org.modified_at,
             created_at: org.created_at,
         }
@@ -62,6 +65,16 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             .map(|config| config.parse_value("pm_collect_link_config"))
             .transpose()?;
 
+        let id_prefixes: Option<api_models::admin::MerchantIdPrefixes> = item
+            .id_prefixes
+            .map(|id_prefixes| id_prefixes.parse_value("id_prefixes"))
+            .transpose()?;
+
+        let data_retention_policy: Option<api_models::admin::MerchantDataRetentionPolicy> = item
+            .data_retention_policy
+            .map(|data_retention_policy| data_retention_policy.parse_value("data_retention_policy"))
+            .transpose()?;
+
         Ok(Self {
             merchant_id,
             merchant_name: item.merchant_name,
@@ -86,6 +99,8 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             default_profile: item.default_profile,
             recon_status: item.recon_status,
             pm_collect_link_config,
+            id_prefixes,
+            data_retention_policy,
         })
     }
 }
@@ -132,6 +147,19 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             })
             .transpose()?;
 
+        let outgoing_webhook_auth = item
+            .outgoing_webhook_auth_config
+            .map(|config| {
+                config
+                    .into_inner()
+                    .expose()
+                    .parse_value::<api_models::admin::OutgoingWebhookAuthConfig>(
+                        "OutgoingWebhookAuthConfig",
+                    )
+            })
+            .transpose()?
+            .map(api_models::admin::OutgoingWebhookAuthConfigResponse::from);
+
         Ok(Self {
             merchant_id: item.merchant_id,
             profile_id,
@@ -154,6 +182,18 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .authentication_connector_details
                 .map(ForeignInto::foreign_into),
             payout_link_config: item.payout_link_config.map(ForeignInto::foreign_into),
+            customer_portal_link_config: item
+                .customer_portal_link_config
+                .map(ForeignInto::foreign_into),
+            invoice_config: item.invoice_config.map(ForeignInto::foreign_into),
+            enabled_webhook_events: item.enabled_webhook_events,
+            webhook_payload_version: item.webhook_payload_version.unwrap_or_default(),
+            statement_descriptor_config: item
+                .statement_descriptor_config
+                .map(ForeignInto::foreign_into),
+            dispute_representment_config: item
+                .dispute_representment_config
+                .map(ForeignInto::foreign_into),
             use_billing_as_payment_method_billing: item.use_billing_as_payment_method_billing,
             extended_card_info_config: item
                 .extended_card_info_config
@@ -169,6 +209,29 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .always_collect_shipping_details_from_wallet_connector,
             is_connector_agnostic_mit_enabled: item.is_connector_agnostic_mit_enabled,
             outgoing_webhook_custom_http_headers,
+            outgoing_webhook_auth,
+            redirect_config: item.redirect_config.map(ForeignInto::foreign_into),
+            client_origin_config: item.client_origin_config.map(ForeignInto::foreign_into),
+            is_client_secret_single_use: item.is_client_secret_single_use,
+            post_auth_rules_config: item.post_auth_rules_config.map(ForeignInto::foreign_into),
+            allowed_currencies: item.allowed_currencies,
+            tags: item.tags,
+            payout_retry_config: item.payout_retry_config.map(ForeignInto::foreign_into),
+            payout_fulfillment_config: item
+                .payout_fulfillment_config
+                .map(ForeignInto::foreign_into),
+            payment_defaults_config: item.payment_defaults_config.map(ForeignInto::foreign_into),
+            capture_on_shipment_config: item
+                .capture_on_shipment_config
+                .map(ForeignInto::foreign_into),
+            dcc_config: item.dcc_config.map(ForeignInto::foreign_into),
+            payout_fee_config: item.payout_fee_config.map(ForeignInto::foreign_into),
+            payout_balance_config: item.payout_balance_config.map(ForeignInto::foreign_into),
+            account_name_mismatch_action: item.account_name_mismatch_action,
+            connector_canary_rollout: item.connector_canary_rollout.map(ForeignInto::foreign_into),
+            operation_fallback_config: item
+                .operation_fallback_config
+                .map(ForeignInto::foreign_into),
             tax_connector_id: item.tax_connector_id,
             is_tax_connector_enabled: item.is_tax_connector_enabled,
             is_network_tokenization_enabled: item.is_network_tokenization_enabled,
@@ -198,6 +261,19 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             })
             .transpose()?;
 
+        let outgoing_webhook_auth = item
+            .outgoing_webhook_auth_config
+            .map(|config| {
+                config
+                    .into_inner()
+                    .expose()
+                    .parse_value::<api_models::admin::OutgoingWebhookAuthConfig>(
+                        "OutgoingWebhookAuthConfig",
+                    )
+            })
+            .transpose()?
+            .map(api_models::admin::OutgoingWebhookAuthConfigResponse::from);
+
         let order_fulfillment_time = item
             .order_fulfillment_time
             .map(admin::OrderFulfillmentTime::try_new)
@@ -221,6 +297,18 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .authentication_connector_details
                 .map(ForeignInto::foreign_into),
             payout_link_config: item.payout_link_config.map(ForeignInto::foreign_into),
+            customer_portal_link_config: item
+                .customer_portal_link_config
+                .map(ForeignInto::foreign_into),
+            invoice_config: item.invoice_config.map(ForeignInto::foreign_into),
+            enabled_webhook_events: item.enabled_webhook_events,
+            webhook_payload_version: item.webhook_payload_version.unwrap_or_default(),
+            statement_descriptor_config: item
+                .statement_descriptor_config
+                .map(ForeignInto::foreign_into),
+            dispute_representment_config: item
+                .dispute_representment_config
+                .map(ForeignInto::foreign_into),
             use_billing_as_payment_method_billing: item.use_billing_as_payment_method_billing,
             extended_card_info_config: item
                 .extended_card_info_config
@@ -236,6 +324,29 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .always_collect_billing_details_from_wallet_connector,
             is_connector_agnostic_mit_enabled: item.is_connector_agnostic_mit_enabled,
             outgoing_webhook_custom_http_headers,
+            outgoing_webhook_auth,
+            redirect_config: item.redirect_config.map(ForeignInto::foreign_into),
+            client_origin_config: item.client_origin_config.map(ForeignInto::foreign_into),
+            is_client_secret_single_use: item.is_client_secret_single_use,
+            post_auth_rules_config: item.post_auth_rules_config.map(ForeignInto::foreign_into),
+            allowed_currencies: item.allowed_currencies,
+            tags: item.tags,
+            payout_retry_config: item.payout_retry_config.map(ForeignInto::foreign_into),
+            payout_fulfillment_config: item
+                .payout_fulfillment_config
+                .map(ForeignInto::foreign_into),
+            payment_defaults_config: item.payment_defaults_config.map(ForeignInto::foreign_into),
+            capture_on_shipment_config: item
+                .capture_on_shipment_config
+                .map(ForeignInto::foreign_into),
+            dcc_config: item.dcc_config.map(ForeignInto::foreign_into),
+            payout_fee_config: item.payout_fee_config.map(ForeignInto::foreign_into),
+            payout_balance_config: item.payout_balance_config.map(ForeignInto::foreign_into),
+            account_name_mismatch_action: item.account_name_mismatch_action,
+            connector_canary_rollout: item.connector_canary_rollout.map(ForeignInto::foreign_into),
+            operation_fallback_config: item
+                .operation_fallback_config
+                .map(ForeignInto::foreign_into),
             order_fulfillment_time,
             order_fulfillment_time_origin: item.order_fulfillment_time_origin,
             should_collect_cvv_during_payment: item.should_collect_cvv_during_payment,
@@ -287,6 +398,157 @@ pub async fn create_profile_from_merchant_account(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
 
+    let redirect_config = request
+        .redirect_config
+        .map(|redirect_conf| match redirect_conf.validate() {
+            Ok(_) => Ok(redirect_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
+    let post_auth_rules_config = request
+        .post_auth_rules_config
+        .map(
+            |post_auth_rules_conf| match post_auth_rules_conf.validate() {
+                Ok(_) => Ok(post_auth_rules_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            },
+        )
+        .transpose()?;
+
+    let payout_retry_config = request
+        .payout_retry_config
+        .map(|payout_retry_conf| match payout_retry_conf.validate() {
+            Ok(_) => Ok(payout_retry_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
+    let payout_fulfillment_config = request
+        .payout_fulfillment_config
+        .map(
+            |payout_fulfillment_conf| match payout_fulfillment_conf.validate() {
+                Ok(_) => Ok(payout_fulfillment_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            },
+        )
+        .transpose()?;
+
+    let connector_canary_rollout = request
+        .connector_canary_rollout
+        .map(
+            |connector_canary_rollout_conf| match connector_canary_rollout_conf.validate() {
+                Ok(_) => Ok(connector_canary_rollout_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            },
+        )
+        .transpose()?;
+
+    let operation_fallback_config = request
+        .operation_fallback_config
+        .map(
+            |operation_fallback_conf| match operation_fallback_conf.validate() {
+                Ok(_) => Ok(operation_fallback_conf.foreign_into()),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: e.to_string()
+                    }
+                )),
+            },
+        )
+        .transpose()?;
+
+    let dcc_config = request
+        .dcc_config
+        .map(|dcc_conf| match dcc_conf.validate() {
+            Ok(_) => Ok(dcc_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
+    let payout_fee_config = request
+        .payout_fee_config
+        .map(|payout_fee_conf| match payout_fee_conf.validate() {
+            Ok(_) => Ok(payout_fee_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
+    let payout_balance_config = request
+        .payout_balance_config
+        .map(|payout_balance_conf| match payout_balance_conf.validate() {
+            Ok(_) => Ok(payout_balance_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
+    let client_origin_config = request
+        .client_origin_config
+        .map(|client_origin_conf| match client_origin_conf.validate() {
+            Ok(_) => Ok(client_origin_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
+    let outgoing_webhook_auth_config = request
+        .outgoing_webhook_auth
+        .map(|auth_conf| match auth_conf.validate() {
+            Ok(_) => Ok(auth_conf),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?
+        .async_map(|auth_conf| {
+            core::payment_methods::cards::create_encrypted_data(
+                &key_manager_state,
+                key_store,
+                auth_conf,
+            )
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt outgoing webhook authentication config")?;
+
     let payout_link_config = request
         .payout_link_config
         .map(|payout_conf| match payout_conf.config.validate() {
@@ -299,6 +561,18 @@ pub async fn create_profile_from_merchant_account(
         })
         .transpose()?;
 
+    let customer_portal_link_config = request
+        .customer_portal_link_config
+        .map(|portal_conf| match portal_conf.validate() {
+            Ok(_) => Ok(portal_conf.foreign_into()),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: e.to_string()
+                }
+            )),
+        })
+        .transpose()?;
+
     Ok(domain::Profile::from(domain::ProfileSetter {
         profile_id,
         merchant_id,
@@ -370,5 +644,37 @@ pub async fn create_profile_from_merchant_account(
         is_auto_retries_enabled: request.is_auto_retries_enabled.unwrap_or_default(),
         max_auto_retries_enabled: request.max_auto_retries_enabled.map(i16::from),
         is_click_to_pay_enabled: request.is_click_to_pay_enabled,
+        customer_portal_link_config,
+        invoice_config: request.invoice_config.map(ForeignInto::foreign_into),
+        enabled_webhook_events: request.enabled_webhook_events,
+        webhook_payload_version: request.webhook_payload_version,
+        statement_descriptor_config: request
+            .statement_descriptor_config
+            .map(ForeignInto::foreign_into),
+        dispute_representment_config: request
+            .dispute_representment_config
+            .map(ForeignInto::foreign_into),
+        outgoing_webhook_auth_config: outgoing_webhook_auth_config.map(Into::into),
+        redirect_config,
+        client_origin_config,
+        is_client_secret_single_use: request.is_client_secret_single_use,
+        post_auth_rules_config,
+        allowed_currencies: request.allowed_currencies,
+        tags: request.tags,
+        payout_retry_config,
+        payout_fulfillment_config,
+        payment_defaults_config: request
+            .payment_defaults_config
+            .map(ForeignInto::foreign_into),
+        capture_on_shipment_config: request
+            .capture_on_shipment_config
+            .map(ForeignInto::foreign_into),
+        dcc_config,
+        payout_fee_config,
+        payout_balance_config,
+        account_name_mismatch_action: request.account_name_mismatch_action,
+        connector_canary_rollout,
+        operation_fallback_config,
+        webhook_secret_rotation: None,
     }))
 }
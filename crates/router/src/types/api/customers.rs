@@ -5,6 +5,8 @@ pub use api_models::customers::{
     CustomerDeleteResponse, CustomerId, CustomerListRequest, CustomerRequest,
     CustomerUpdateRequest, UpdateCustomerId,
 };
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub use api_models::customers::{CustomerRetrieveBody, CustomerRetrieveRequest};
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use hyperswitch_domain_models::customer;
 use serde::Serialize;
@@ -41,6 +43,8 @@ impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for Custo
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            payment_methods: None,
+            recipient_kyc_status: cust.recipient_kyc_status,
         }
         .into()
     }
@@ -62,6 +66,7 @@ impl ForeignFrom<customer::Customer> for CustomerResponse {
             default_shipping_address: None,
             default_payment_method_id: cust.default_payment_method_id,
             id: cust.id,
+            recipient_kyc_status: cust.recipient_kyc_status,
         }
         .into()
     }
@@ -106,6 +106,8 @@ impl MandateResponseExt for MandateResponse {
                     ip_address: mandate.customer_ip_address,
                     user_agent: mandate.customer_user_agent.unwrap_or_default(),
                 }),
+                consent_text_version: None,
+                signed_mandate_file_id: mandate.signed_mandate_file_id,
             }),
             card,
             status: mandate.mandate_status,
@@ -31,6 +31,7 @@ impl PaymentLinkResponseExt for RetrievePaymentLinkResponse {
             currency: payment_link.currency,
             status,
             secure_link: payment_link.secure_link,
+            deactivated_at: payment_link.deactivated_at,
         })
     }
 }
@@ -24,8 +24,9 @@ pub use api_models::payment_methods::{
     PaymentMethodCreateData, PaymentMethodDeleteResponse, PaymentMethodId,
     PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodMigrate,
     PaymentMethodMigrateResponse, PaymentMethodResponse, PaymentMethodUpdate, PaymentMethodsData,
-    TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1, TokenizedCardValue2,
-    TokenizedWalletValue1, TokenizedWalletValue2,
+    SharePaymentMethodRequest, TokenizePayloadEncrypted, TokenizePayloadRequest,
+    TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2,
+    VaultDetokenizeRequest, VaultTokenizeRequest,
 };
 use error_stack::report;
 
@@ -57,6 +58,10 @@ impl PaymentMethodCreateExt for PaymentMethodCreate {
                 }
             }
         }
+        #[cfg(feature = "payouts")]
+        if let Some(bank_transfer) = &self.bank_transfer {
+            crate::core::payouts::validator::validate_bank_details(bank_transfer, "bank_transfer")?;
+        }
         Ok(())
     }
 }
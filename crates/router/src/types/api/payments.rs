@@ -22,14 +22,14 @@ pub use api_models::payments::{
 pub use api_models::payments::{PaymentsCreateIntentRequest, PaymentsIntentResponse};
 use error_stack::ResultExt;
 pub use hyperswitch_domain_models::router_flow_types::payments::{
-    Approve, Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture, CompleteAuthorize,
-    CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync, PaymentCreateIntent,
-    PaymentGetIntent, PaymentMethodToken, PostProcessing, PostSessionTokens, PreProcessing, Reject,
-    SdkSessionUpdate, Session, SetupMandate, Void,
+    Approve, Authorize, AuthorizeSessionToken, Balance, BankList, CalculateTax, Capture,
+    CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync,
+    PaymentCreateIntent, PaymentGetIntent, PaymentMethodToken, PostProcessing, PostSessionTokens,
+    PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate, Void,
 };
 pub use hyperswitch_interfaces::api::payments::{
     ConnectorCustomer, MandateSetup, Payment, PaymentApprove, PaymentAuthorize,
-    PaymentAuthorizeSessionToken, PaymentCapture, PaymentIncrementalAuthorization,
+    PaymentAuthorizeSessionToken, PaymentBankList, PaymentCapture, PaymentIncrementalAuthorization,
     PaymentPostSessionTokens, PaymentReject, PaymentSession, PaymentSessionUpdate, PaymentSync,
     PaymentToken, PaymentVoid, PaymentsCompleteAuthorize, PaymentsPostProcessing,
     PaymentsPreProcessing, TaxCalculation,
@@ -37,7 +37,7 @@ pub use hyperswitch_interfaces::api::payments::{
 
 pub use super::payments_v2::{
     ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
-    PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
+    PaymentAuthorizeV2, PaymentBankListV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
     PaymentPostSessionTokensV2, PaymentRejectV2, PaymentSessionUpdateV2, PaymentSessionV2,
     PaymentSyncV2, PaymentTokenV2, PaymentV2, PaymentVoidV2, PaymentsCompleteAuthorizeV2,
     PaymentsPostProcessingV2, PaymentsPreProcessingV2, TaxCalculationV2,
@@ -1,6 +1,6 @@
 pub use hyperswitch_interfaces::api::payments_v2::{
     ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
-    PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
+    PaymentAuthorizeV2, PaymentBankListV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
     PaymentPostSessionTokensV2, PaymentRejectV2, PaymentSessionUpdateV2, PaymentSessionV2,
     PaymentSyncV2, PaymentTokenV2, PaymentV2, PaymentVoidV2, PaymentsCompleteAuthorizeV2,
     PaymentsPostProcessingV2, PaymentsPreProcessingV2, TaxCalculationV2,
@@ -1,20 +1,23 @@
 pub use api_models::payouts::{
     AchBankTransfer, BacsBankTransfer, Bank as BankPayout, CardPayout, PaymentMethodTypeInfo,
-    PayoutActionRequest, PayoutAttemptResponse, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutActionRequest, PayoutAttemptListRequest, PayoutAttemptListResponse,
+    PayoutAttemptResponse, PayoutBalanceRequest, PayoutCreateRequest, PayoutCreateResponse,
     PayoutEnabledPaymentMethodsInfo, PayoutLinkResponse, PayoutListConstraints,
     PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse, PayoutMethodData,
     PayoutMethodDataResponse, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest,
     PixBankTransfer, RequiredFieldsOverrideRequest, SepaBankTransfer, Wallet as WalletPayout,
 };
 pub use hyperswitch_domain_models::router_flow_types::payouts::{
-    PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount, PoSync,
+    PoBalance, PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient,
+    PoRecipientAccount, PoRecipientStatus, PoSync,
 };
 pub use hyperswitch_interfaces::api::payouts::{
-    PayoutCancel, PayoutCreate, PayoutEligibility, PayoutFulfill, PayoutQuote, PayoutRecipient,
-    PayoutRecipientAccount, PayoutSync, Payouts,
+    PayoutBalance, PayoutCancel, PayoutCreate, PayoutEligibility, PayoutFulfill, PayoutQuote,
+    PayoutRecipient, PayoutRecipientAccount, PayoutRecipientStatus, PayoutSync, Payouts,
 };
 
 pub use super::payouts_v2::{
-    PayoutCancelV2, PayoutCreateV2, PayoutEligibilityV2, PayoutFulfillV2, PayoutQuoteV2,
-    PayoutRecipientAccountV2, PayoutRecipientV2, PayoutSyncV2, PayoutsV2,
+    PayoutBalanceV2, PayoutCancelV2, PayoutCreateV2, PayoutEligibilityV2, PayoutFulfillV2,
+    PayoutQuoteV2, PayoutRecipientAccountV2, PayoutRecipientStatusV2, PayoutRecipientV2,
+    PayoutSyncV2, PayoutsV2,
 };
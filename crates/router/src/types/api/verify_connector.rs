@@ -58,6 +58,7 @@ impl VerifyConnectorData {
             merchant_order_reference_id: None,
             integrity_object: None,
             additional_payment_method_data: None,
+            currency_conversion: None,
             shipping_cost: None,
         }
     }
@@ -2,6 +2,7 @@ pub use hyperswitch_domain_models::{
     router_request_types::authentication::{
         AcquirerDetails, AuthNFlowType, ChallengeParams, ConnectorAuthenticationRequestData,
         ConnectorPostAuthenticationRequestData, PreAuthNRequestData, PreAuthenticationData,
+        ThreeRiIndicator,
     },
     router_response_types::AuthenticationResponseData,
 };
@@ -138,6 +138,14 @@ impl UserEmail {
     pub fn get_secret(self) -> Secret<String, pii::EmailStrategy> {
         (*self.0).clone()
     }
+
+    pub fn get_domain(&self) -> UserResult<&str> {
+        self.0
+            .peek()
+            .split_once('@')
+            .map(|(_username, domain)| domain)
+            .ok_or(UserErrors::EmailParsingError.into())
+    }
 }
 
 impl TryFrom<pii::Email> for UserEmail {
@@ -312,6 +320,22 @@ impl From<InviteeUserRequestWithInvitedUserToken> for NewUserOrganization {
     }
 }
 
+type ScimUserRequestWithLineage = (
+    user_api::scim::ScimUserRequest,
+    id_type::OrganizationId,
+    id_type::MerchantId,
+);
+impl From<ScimUserRequestWithLineage> for NewUserOrganization {
+    fn from((_value, org_id, _merchant_id): ScimUserRequestWithLineage) -> Self {
+        let new_organization = api_org::OrganizationNew {
+            org_id,
+            org_name: None,
+        };
+        let db_organization = ForeignFrom::foreign_from(new_organization);
+        Self(db_organization)
+    }
+}
+
 #[derive(Clone)]
 pub struct MerchantId(String);
 
@@ -433,6 +457,8 @@ impl NewUserMerchant {
             enable_payment_response_hash: None,
             redirect_to_merchant_with_http_post: None,
             pm_collect_link_config: None,
+            id_prefixes: None,
+            data_retention_policy: None,
         })
     }
 
@@ -535,6 +561,18 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUserMerchant {
     }
 }
 
+impl From<ScimUserRequestWithLineage> for NewUserMerchant {
+    fn from(value: ScimUserRequestWithLineage) -> Self {
+        let merchant_id = value.2.clone();
+        let new_organization = NewUserOrganization::from(value);
+        Self {
+            company_name: None,
+            merchant_id,
+            new_organization,
+        }
+    }
+}
+
 type UserMerchantCreateRequestWithToken =
     (UserFromStorage, user_api::UserMerchantCreate, UserFromToken);
 
@@ -856,6 +894,28 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUser {
     }
 }
 
+impl TryFrom<ScimUserRequestWithLineage> for NewUser {
+    type Error = error_stack::Report<UserErrors>;
+
+    fn try_from(value: ScimUserRequestWithLineage) -> UserResult<Self> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let email = value.0.user_name.clone().try_into()?;
+        let name = match value.0.get_name() {
+            Some(name) => UserName::new(name)?,
+            None => UserName::try_from(value.0.user_name.clone())?,
+        };
+        let new_merchant = NewUserMerchant::from(value);
+
+        Ok(Self {
+            user_id,
+            name,
+            email,
+            password: None,
+            new_merchant,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromStorage(pub storage_user::User);
 
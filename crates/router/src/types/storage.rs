@@ -1,4 +1,5 @@
 pub mod address;
+pub mod admin_api_key;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -12,6 +13,7 @@ pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
+pub mod email_template;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
@@ -19,8 +21,11 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod installment_plan;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
+pub mod link_delivery;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -28,13 +33,20 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
+pub mod payment_link_template;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_template;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim_config;
+pub mod shared_payment_method;
+pub mod shipment_capture;
+pub mod status_history;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -61,13 +73,16 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
+    address::*, admin_api_key::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
+    configs::*, customers::*, dashboard_metadata::*, dispute::*, email_template::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*,
+    installment_plan::*, ledger_entry::*, link_delivery::*, locker_mock_up::*, mandate::*,
     merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
+    payment_link_template::*, payment_method::*, payment_method_consent_event::*,
+    payment_template::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, scim_config::*, shared_payment_method::*, shipment_capture::*,
+    status_history::*, unified_translations::*, user::*, user_authentication_method::*,
     user_role::*,
 };
 use crate::types::api::routing;
@@ -134,3 +149,37 @@ impl From<PaymentRoutingInfo> for PaymentRoutingInfoSerde {
         }))
     }
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentArchivalTrackingData {
+    pub retention_period_in_days: u16,
+    pub batch_size: u16,
+    pub interval_in_hours: u16,
+    pub payments_archived: u64,
+    /// The time at which a run of the job last archived all the payments past the retention period
+    pub last_completed_run_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct DataRetentionTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ConnectorCredentialsExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PayoutLinkExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct VoucherExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The attempt for which the voucher was issued, the payment is not cancelled if another
+    /// attempt has been made since
+    pub attempt_id: String,
+}
@@ -0,0 +1 @@
+pub use diesel_models::admin_api_key::{AdminApiKey, AdminApiKeyNew, AdminApiKeyUpdate};
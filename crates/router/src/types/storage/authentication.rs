@@ -1 +1,4 @@
-pub use diesel_models::authentication::{Authentication, AuthenticationNew, AuthenticationUpdate};
+pub use diesel_models::authentication::{
+    Authentication, AuthenticationConnectorFailover, AuthenticationConnectorFailoverHistory,
+    AuthenticationNew, AuthenticationUpdate,
+};
@@ -1 +1,10 @@
 pub use diesel_models::capture::*;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentsAutoCaptureTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The attempt which was authorized when the task was scheduled, the payment is not captured
+    /// if another attempt has been made since
+    pub attempt_id: String,
+}
@@ -0,0 +1 @@
+pub use diesel_models::email_template::{EmailTemplate, EmailTemplateNew, EmailTemplateUpdate};
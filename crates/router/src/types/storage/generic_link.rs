@@ -1,4 +1,5 @@
 pub use diesel_models::generic_link::{
-    GenericLink, GenericLinkData, GenericLinkNew, GenericLinkState, GenericLinkUpdateInternal,
-    PaymentMethodCollectLink, PayoutLink, PayoutLinkUpdate,
+    CustomerPortalLink, CustomerPortalLinkData, CustomerPortalLinkUpdate, GenericLink,
+    GenericLinkData, GenericLinkNew, GenericLinkState, GenericLinkUpdateInternal,
+    PaymentMethodCollectLink, PaymentMethodCollectLinkUpdate, PayoutLink, PayoutLinkUpdate,
 };
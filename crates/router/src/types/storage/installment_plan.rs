@@ -0,0 +1,9 @@
+pub use diesel_models::installment_plan::{
+    Installment, InstallmentPlan, InstallmentPlanNew, InstallmentPlanUpdate, InstallmentSchedule,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InstallmentPlanChargeTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub installment_plan_id: String,
+}
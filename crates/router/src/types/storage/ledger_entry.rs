@@ -0,0 +1 @@
+pub use diesel_models::ledger_entry::{LedgerAccountNet, LedgerEntry, LedgerEntryNew};
@@ -0,0 +1 @@
+pub use diesel_models::link_delivery::{LinkDelivery, LinkDeliveryNew, LinkDeliveryUpdate};
@@ -217,6 +217,9 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            presentment_currency: Default::default(),
+            presentment_amount: Default::default(),
+            exchange_rate: Default::default(),
         };
 
         let store = state
@@ -301,6 +304,9 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            presentment_currency: Default::default(),
+            presentment_amount: Default::default(),
+            exchange_rate: Default::default(),
         };
         let store = state
             .stores
@@ -398,6 +404,9 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            presentment_currency: Default::default(),
+            presentment_amount: Default::default(),
+            exchange_rate: Default::default(),
         };
         let store = state
             .stores
@@ -1,7 +1,7 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 pub use diesel_models::{
-    payment_link::{PaymentLink, PaymentLinkNew},
+    payment_link::{PaymentLink, PaymentLinkNew, PaymentLinkUpdate},
     schema::payment_link::dsl,
 };
 use error_stack::ResultExt;
@@ -12,6 +12,13 @@ use crate::{
     logger,
 };
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentLinkExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub payment_link_id: String,
+}
+
 #[async_trait::async_trait]
 pub trait PaymentLinkDbExt: Sized {
     async fn filter_by_constraints(
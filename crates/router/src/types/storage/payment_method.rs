@@ -156,3 +156,14 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct LockerMigrationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_size: u16,
+    /// The number of customers whose cards have been processed, which is also the offset of the
+    /// next batch of customers
+    pub customers_processed: u32,
+    pub cards_moved: usize,
+    pub cards_failed: usize,
+}
@@ -0,0 +1 @@
+pub use diesel_models::shared_payment_method::{SharedPaymentMethod, SharedPaymentMethodNew};
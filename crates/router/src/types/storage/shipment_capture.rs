@@ -0,0 +1,3 @@
+pub use diesel_models::shipment_capture::{
+    ShipmentCapture, ShipmentCaptureLineItem, ShipmentCaptureLineItems, ShipmentCaptureNew,
+};
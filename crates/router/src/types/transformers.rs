@@ -21,8 +21,9 @@ use super::domain;
 use crate::{
     core::errors,
     headers::{
-        ACCEPT_LANGUAGE, BROWSER_NAME, X_APP_ID, X_CLIENT_PLATFORM, X_CLIENT_SOURCE,
-        X_CLIENT_VERSION, X_MERCHANT_DOMAIN, X_PAYMENT_CONFIRM_SOURCE, X_REDIRECT_URI,
+        ACCEPT_LANGUAGE, BROWSER_NAME, ORIGIN, REFERER, X_APP_ID, X_CLIENT_PLATFORM,
+        X_CLIENT_SOURCE, X_CLIENT_VERSION, X_MERCHANT_DOMAIN, X_PAYMENT_CONFIRM_SOURCE,
+        X_REDIRECT_URI,
     },
     services::authentication::get_header_value_by_key,
     types::{
@@ -239,6 +240,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Fiservemea => Self::Fiservemea,
             api_enums::Connector::Fiuu => Self::Fiuu,
             api_enums::Connector::Forte => Self::Forte,
+            api_enums::Connector::Generichttp => Self::Generichttp,
             api_enums::Connector::Globalpay => Self::Globalpay,
             api_enums::Connector::Globepay => Self::Globepay,
             api_enums::Connector::Gocardless => Self::Gocardless,
@@ -275,6 +277,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Paypal => Self::Paypal,
             api_enums::Connector::Payu => Self::Payu,
             api_models::enums::Connector::Placetopay => Self::Placetopay,
+            api_enums::Connector::Plugin => Self::Plugin,
             api_enums::Connector::Plaid => Self::Plaid,
             api_enums::Connector::Powertranz => Self::Powertranz,
             api_enums::Connector::Prophetpay => Self::Prophetpay,
@@ -369,6 +372,8 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                             ip_address: d.ip_address,
                             user_agent: d.user_agent,
                         }),
+                    consent_text_version: d.consent_text_version,
+                    signed_mandate_file_id: d.signed_mandate_file_id,
                 }
             }),
             mandate_type: d.mandate_type.map(|d| match d {
@@ -529,6 +534,7 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             | api_enums::PaymentMethodType::DanamonVa
             | api_enums::PaymentMethodType::MandiriVa
             | api_enums::PaymentMethodType::LocalBankTransfer
+            | api_enums::PaymentMethodType::VirtualAccount
             | api_enums::PaymentMethodType::Pix => Self::BankTransfer,
             api_enums::PaymentMethodType::Givex | api_enums::PaymentMethodType::PaySafeCard => {
                 Self::GiftCard
@@ -824,6 +830,43 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes,
+        }
+    }
+}
+
+impl ForeignFrom<storage::SharedPaymentMethod>
+    for api_models::payment_methods::SharedPaymentMethodResponse
+{
+    fn foreign_from(shared_payment_method: storage::SharedPaymentMethod) -> Self {
+        Self {
+            share_id: shared_payment_method.id,
+            organization_id: shared_payment_method.organization_id,
+            source_merchant_id: shared_payment_method.source_merchant_id,
+            source_payment_method_id: shared_payment_method.source_payment_method_id,
+            target_merchant_id: shared_payment_method.target_merchant_id,
+            target_payment_method_id: shared_payment_method.target_payment_method_id,
+            customer_id: shared_payment_method.customer_id,
+            target_customer_id: shared_payment_method.target_customer_id,
+            created_at: shared_payment_method.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::PaymentMethodConsentEvent>
+    for api_models::payment_methods::PaymentMethodConsentEventResponse
+{
+    fn foreign_from(consent_event: storage::PaymentMethodConsentEvent) -> Self {
+        Self {
+            payment_method_id: consent_event.payment_method_id,
+            customer_id: consent_event.customer_id,
+            payment_id: consent_event.payment_id,
+            setup_future_usage: consent_event.setup_future_usage,
+            consent_text_version: consent_event.consent_text_version,
+            ip_address: consent_event.ip_address,
+            user_agent: consent_event.user_agent,
+            accepted_at: consent_event.accepted_at,
+            created_at: consent_event.created_at,
         }
     }
 }
@@ -838,6 +881,50 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes,
+        }
+    }
+}
+
+impl
+    ForeignFrom<(
+        diesel_models::admin_api_key::AdminApiKey,
+        crate::core::api_keys::PlaintextApiKey,
+    )> for api_models::admin_api_keys::CreateAdminApiKeyResponse
+{
+    fn foreign_from(
+        item: (
+            diesel_models::admin_api_key::AdminApiKey,
+            crate::core::api_keys::PlaintextApiKey,
+        ),
+    ) -> Self {
+        use masking::StrongSecret;
+
+        let (admin_api_key, plaintext_api_key) = item;
+        Self {
+            key_id: admin_api_key.key_id,
+            name: admin_api_key.name,
+            description: admin_api_key.description,
+            api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
+            role_id: admin_api_key.role_id,
+            created: admin_api_key.created_at,
+            expiration: admin_api_key.expires_at.into(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::admin_api_key::AdminApiKey>
+    for api_models::admin_api_keys::RetrieveAdminApiKeyResponse
+{
+    fn foreign_from(admin_api_key: diesel_models::admin_api_key::AdminApiKey) -> Self {
+        Self {
+            key_id: admin_api_key.key_id,
+            name: admin_api_key.name,
+            description: admin_api_key.description,
+            prefix: admin_api_key.prefix.into(),
+            role_id: admin_api_key.role_id,
+            created: admin_api_key.created_at,
+            expiration: admin_api_key.expires_at.into(),
         }
     }
 }
@@ -1038,6 +1125,8 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            tags: item.tags,
+            credentials_expiry: item.credentials_expiry.map(ForeignInto::foreign_into),
             status: item.status,
         };
         #[cfg(feature = "v2")]
@@ -1052,6 +1141,8 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            tags: item.tags,
+            credentials_expiry: item.credentials_expiry.map(ForeignInto::foreign_into),
             status: item.status,
         };
         Ok(response)
@@ -1134,6 +1225,8 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            tags: item.tags,
+            credentials_expiry: item.credentials_expiry.map(ForeignInto::foreign_into),
             status: item.status,
             additional_merchant_data: item
                 .additional_merchant_data
@@ -1190,6 +1283,8 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            tags: item.tags,
+            credentials_expiry: item.credentials_expiry.map(ForeignInto::foreign_into),
             status: item.status,
             additional_merchant_data: item
                 .additional_merchant_data
@@ -1252,6 +1347,33 @@ impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
             unified_message: payment_attempt.unified_message,
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
+            avs_result: payment_attempt.avs_result,
+            cvv_result: payment_attempt.cvv_result,
+            connector_response_codes: payment_attempt.connector_response_codes.map(
+                |connector_response_codes| payments::ConnectorResponseCodes {
+                    decline_code: connector_response_codes.decline_code,
+                    avs_result: connector_response_codes.avs_result,
+                    cvv_result: connector_response_codes.cvv_result,
+                    acquirer_response_code: connector_response_codes.acquirer_response_code,
+                },
+            ),
+            presentment_currency: payment_attempt.presentment_currency,
+            presentment_amount: payment_attempt.presentment_amount,
+            exchange_rate: payment_attempt.exchange_rate,
+            dcc_details: payment_attempt
+                .dcc_details
+                .map(|dcc_details| payments::DccDetails {
+                    offer_id: dcc_details.offer_id,
+                    presentment_currency: dcc_details.presentment_currency,
+                    presentment_amount: dcc_details.presentment_amount,
+                    base_exchange_rate: dcc_details.base_exchange_rate,
+                    markup_in_basis_points: dcc_details.markup_in_basis_points,
+                    exchange_rate: dcc_details.exchange_rate,
+                    accepted: dcc_details.accepted,
+                    applied: dcc_details.applied,
+                    offered_at: dcc_details.offered_at,
+                    decided_at: dcc_details.decided_at,
+                }),
         }
     }
 }
@@ -1276,6 +1398,19 @@ impl ForeignFrom<storage::Capture> for payments::CaptureResponse {
     }
 }
 
+impl ForeignFrom<storage::StatusHistory> for api_models::status_history::StatusHistoryResponse {
+    fn foreign_from(status_history: storage::StatusHistory) -> Self {
+        Self {
+            previous_status: status_history.previous_status,
+            status: status_history.status,
+            trigger: status_history.trigger,
+            connector: status_history.connector,
+            attempt_id: status_history.attempt_id,
+            created_at: status_history.created_at,
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::PayoutMethodData) -> Self {
@@ -1400,6 +1535,10 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
         let x_redirect_uri =
             get_header_value_by_key(X_REDIRECT_URI.into(), headers)?.map(|val| val.to_string());
 
+        let origin = get_header_value_by_key(ORIGIN.into(), headers)?
+            .or(get_header_value_by_key(REFERER.into(), headers)?)
+            .map(|val| val.to_string());
+
         Ok(Self {
             payment_confirm_source,
             client_source,
@@ -1411,6 +1550,7 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
             locale,
             x_app_id,
             x_redirect_uri,
+            origin,
         })
     }
 }
@@ -1488,6 +1628,10 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
         let x_redirect_uri =
             get_header_value_by_key(X_REDIRECT_URI.into(), headers)?.map(|val| val.to_string());
 
+        let origin = get_header_value_by_key(ORIGIN.into(), headers)?
+            .or(get_header_value_by_key(REFERER.into(), headers)?)
+            .map(|val| val.to_string());
+
         // TODO: combine publishable key and client secret when we unify the auth
         let client_secret = get_header_value_by_key(X_CLIENT_SECRET.into(), headers)?
             .map(common_utils::types::ClientSecret::from_str)
@@ -1507,6 +1651,7 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
             locale,
             x_app_id,
             x_redirect_uri,
+            origin,
             client_secret,
         })
     }
@@ -1621,6 +1766,154 @@ impl ForeignFrom<(storage::PaymentLink, payments::PaymentLinkStatus)>
             currency: payment_link_config.currency,
             status,
             secure_link: payment_link_config.secure_link,
+            deactivated_at: payment_link_config.deactivated_at,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::PaymentTemplate>
+    for api_models::payment_templates::PaymentTemplateResponse
+{
+    fn foreign_from(payment_template: storage::PaymentTemplate) -> Self {
+        Self {
+            template_id: payment_template.id,
+            profile_id: payment_template.profile_id,
+            name: payment_template.name,
+            currency: payment_template.currency,
+            capture_method: payment_template.capture_method,
+            metadata: payment_template.metadata,
+            statement_descriptor_name: payment_template.statement_descriptor_name,
+            statement_descriptor_suffix: payment_template.statement_descriptor_suffix,
+            allowed_payment_method_types: payment_template
+                .allowed_payment_method_types
+                .map(|allowed_payment_method_types| allowed_payment_method_types.0),
+            is_active: payment_template.is_active,
+            created_at: payment_template.created_at,
+            modified_at: payment_template.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::LedgerEntry> for api_models::ledger::LedgerEntryResponse {
+    fn foreign_from(ledger_entry: storage::LedgerEntry) -> Self {
+        Self {
+            entry_id: ledger_entry.id,
+            transaction_id: ledger_entry.transaction_id,
+            account: ledger_entry.account,
+            direction: ledger_entry.direction,
+            amount: ledger_entry.amount,
+            currency: ledger_entry.currency,
+            source_type: ledger_entry.source_type,
+            source_id: ledger_entry.source_id,
+            created_at: ledger_entry.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::EmailTemplate> for api_models::email_templates::EmailTemplateResponse {
+    fn foreign_from(email_template: storage::EmailTemplate) -> Self {
+        Self {
+            available_variables: crate::core::email_templates::get_available_variables(
+                email_template.email_type,
+            )
+            .into_iter()
+            .map(ToString::to_string)
+            .collect(),
+            template_id: email_template.id,
+            profile_id: email_template.profile_id,
+            email_type: email_template.email_type,
+            locale: email_template.locale,
+            subject: email_template.subject,
+            body: email_template.body,
+            logo_url: email_template.logo_url,
+            primary_color: email_template.primary_color,
+            is_active: email_template.is_active,
+            created_at: email_template.created_at,
+            modified_at: email_template.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::LinkDelivery> for api_models::link_delivery::LinkDeliveryResponse {
+    fn foreign_from(link_delivery: storage::LinkDelivery) -> Self {
+        Self {
+            delivery_id: link_delivery.id,
+            profile_id: link_delivery.profile_id,
+            link_id: link_delivery.link_id,
+            link_type: link_delivery.link_type,
+            channel: link_delivery.channel,
+            recipient: link_delivery.masked_recipient,
+            status: link_delivery.status,
+            error_code: link_delivery.error_code,
+            error_message: link_delivery.error_message,
+            created_at: link_delivery.created_at,
+            modified_at: link_delivery.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::PaymentLinkTemplate> for payments::PaymentLinkTemplateResponse {
+    fn foreign_from(payment_link_template: storage::PaymentLinkTemplate) -> Self {
+        Self {
+            template_id: payment_link_template.id,
+            merchant_id: payment_link_template.merchant_id,
+            profile_id: payment_link_template.profile_id,
+            link_to_pay: payment_link_template.link_to_pay,
+            amount: payment_link_template.amount,
+            min_amount: payment_link_template.min_amount,
+            max_amount: payment_link_template.max_amount,
+            currency: payment_link_template.currency,
+            description: payment_link_template.description,
+            usage_limit: payment_link_template
+                .usage_limit
+                .and_then(|usage_limit| u32::try_from(usage_limit).ok()),
+            usage_count: u32::try_from(payment_link_template.usage_count).unwrap_or_default(),
+            is_active: payment_link_template.is_active,
+            expires_at: payment_link_template.expires_at,
+            created_at: payment_link_template.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::InstallmentPlan>
+    for api_models::installment_plans::InstallmentPlanResponse
+{
+    fn foreign_from(installment_plan: storage::InstallmentPlan) -> Self {
+        Self {
+            plan_id: installment_plan.id,
+            merchant_id: installment_plan.merchant_id,
+            profile_id: installment_plan.profile_id,
+            customer_id: installment_plan.customer_id,
+            mandate_id: installment_plan.mandate_id,
+            status: installment_plan.status,
+            currency: installment_plan.currency,
+            total_amount: installment_plan.total_amount,
+            interval_days: installment_plan.interval_days,
+            installments: installment_plan
+                .installments
+                .0
+                .into_iter()
+                .map(
+                    |installment| api_models::installment_plans::InstallmentResponse {
+                        installment_number: installment.installment_number,
+                        amount: installment.amount,
+                        due_at: installment.due_at,
+                        status: installment.status,
+                        attempt_count: installment.attempt_count,
+                        payment_id: installment.payment_id,
+                    },
+                )
+                .collect(),
+            max_retries_per_installment: installment_plan.max_retries_per_installment,
+            retry_interval_hours: installment_plan.retry_interval_hours,
+            max_missed_installments: installment_plan.max_missed_installments,
+            missed_count: installment_plan.missed_count,
+            next_charge_at: installment_plan.next_charge_at,
+            description: installment_plan.description,
+            metadata: installment_plan.metadata,
+            created_at: installment_plan.created_at,
         }
     }
 }
@@ -1918,6 +2211,7 @@ impl ForeignFrom<api_models::admin::BusinessPaymentLinkConfig>
                     .collect()
             }),
             allowed_domains: item.allowed_domains,
+            abandoned_cart_config: item.abandoned_cart_config.map(ForeignInto::foreign_into),
         }
     }
 }
@@ -1935,6 +2229,29 @@ impl ForeignFrom<diesel_models::business_profile::BusinessPaymentLinkConfig>
                     .collect()
             }),
             allowed_domains: item.allowed_domains,
+            abandoned_cart_config: item.abandoned_cart_config.map(ForeignInto::foreign_into),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::PaymentLinkAbandonedCartConfig>
+    for diesel_models::business_profile::PaymentLinkAbandonedCartConfig
+{
+    fn foreign_from(item: api_models::admin::PaymentLinkAbandonedCartConfig) -> Self {
+        Self {
+            send_recovery_email: item.send_recovery_email,
+            recovery_link_expiry: item.recovery_link_expiry,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::PaymentLinkAbandonedCartConfig>
+    for api_models::admin::PaymentLinkAbandonedCartConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::PaymentLinkAbandonedCartConfig) -> Self {
+        Self {
+            send_recovery_email: item.send_recovery_email,
+            recovery_link_expiry: item.recovery_link_expiry,
         }
     }
 }
@@ -1998,6 +2315,489 @@ impl ForeignFrom<diesel_models::business_profile::BusinessPayoutLinkConfig>
     }
 }
 
+impl ForeignFrom<api_models::admin::BusinessInvoiceConfig>
+    for diesel_models::business_profile::BusinessInvoiceConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessInvoiceConfig) -> Self {
+        Self {
+            send_email_on_payment_success: item.send_email_on_payment_success,
+            format: item.format,
+            footer_note: item.footer_note,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessInvoiceConfig>
+    for api_models::admin::BusinessInvoiceConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessInvoiceConfig) -> Self {
+        Self {
+            send_email_on_payment_success: item.send_email_on_payment_success,
+            format: item.format,
+            footer_note: item.footer_note,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessStatementDescriptorConfig>
+    for diesel_models::business_profile::BusinessStatementDescriptorConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessStatementDescriptorConfig) -> Self {
+        Self {
+            name: item.name,
+            suffix: item.suffix,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessStatementDescriptorConfig>
+    for api_models::admin::BusinessStatementDescriptorConfig
+{
+    fn foreign_from(
+        item: diesel_models::business_profile::BusinessStatementDescriptorConfig,
+    ) -> Self {
+        Self {
+            name: item.name,
+            suffix: item.suffix,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessDisputeRepresentmentConfig>
+    for diesel_models::business_profile::BusinessDisputeRepresentmentConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessDisputeRepresentmentConfig) -> Self {
+        Self {
+            max_dispute_amount: item.max_dispute_amount,
+            evidence_templates: diesel_models::business_profile::DisputeEvidenceTemplates {
+                product_description: item.evidence_templates.product_description,
+                refund_refusal_explanation: item.evidence_templates.refund_refusal_explanation,
+                cancellation_rebuttal: item.evidence_templates.cancellation_rebuttal,
+                uncategorized_text: item.evidence_templates.uncategorized_text,
+            },
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessDisputeRepresentmentConfig>
+    for api_models::admin::BusinessDisputeRepresentmentConfig
+{
+    fn foreign_from(
+        item: diesel_models::business_profile::BusinessDisputeRepresentmentConfig,
+    ) -> Self {
+        Self {
+            max_dispute_amount: item.max_dispute_amount,
+            evidence_templates: api_models::admin::DisputeEvidenceTemplates {
+                product_description: item.evidence_templates.product_description,
+                refund_refusal_explanation: item.evidence_templates.refund_refusal_explanation,
+                cancellation_rebuttal: item.evidence_templates.cancellation_rebuttal,
+                uncategorized_text: item.evidence_templates.uncategorized_text,
+            },
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessRedirectConfig>
+    for diesel_models::business_profile::BusinessRedirectConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessRedirectConfig) -> Self {
+        Self {
+            ui_config: item.ui_config,
+            locale: item.locale,
+            auto_redirect_timeout_ms: item.auto_redirect_timeout_ms,
+            allowed_return_url_domains: item.allowed_return_url_domains,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessRedirectConfig>
+    for api_models::admin::BusinessRedirectConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessRedirectConfig) -> Self {
+        Self {
+            ui_config: item.ui_config,
+            locale: item.locale,
+            auto_redirect_timeout_ms: item.auto_redirect_timeout_ms,
+            allowed_return_url_domains: item.allowed_return_url_domains,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessClientOriginConfig>
+    for diesel_models::business_profile::BusinessClientOriginConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessClientOriginConfig) -> Self {
+        Self {
+            allowed_origins: item.allowed_origins,
+            enforcement_mode: item.enforcement_mode,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessClientOriginConfig>
+    for api_models::admin::BusinessClientOriginConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessClientOriginConfig) -> Self {
+        Self {
+            allowed_origins: item.allowed_origins,
+            enforcement_mode: item.enforcement_mode,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::ConnectorCredentialsExpiry>
+    for diesel_models::merchant_connector_account::ConnectorCredentialsExpiry
+{
+    fn foreign_from(item: api_models::admin::ConnectorCredentialsExpiry) -> Self {
+        Self {
+            credentials: item
+                .credentials
+                .into_iter()
+                .map(|credential| {
+                    diesel_models::merchant_connector_account::ConnectorCredentialExpiry {
+                        credential_type: credential.credential_type,
+                        expires_at: credential.expires_at,
+                        description: credential.description,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::merchant_connector_account::ConnectorCredentialsExpiry>
+    for api_models::admin::ConnectorCredentialsExpiry
+{
+    fn foreign_from(
+        item: diesel_models::merchant_connector_account::ConnectorCredentialsExpiry,
+    ) -> Self {
+        Self {
+            credentials: item
+                .credentials
+                .into_iter()
+                .map(|credential| api_models::admin::ConnectorCredentialExpiry {
+                    credential_type: credential.credential_type,
+                    expires_at: credential.expires_at,
+                    description: credential.description,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessPostAuthRulesConfig>
+    for diesel_models::business_profile::BusinessPostAuthRulesConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessPostAuthRulesConfig) -> Self {
+        Self {
+            rules: item
+                .rules
+                .into_iter()
+                .map(|rule| diesel_models::business_profile::PostAuthRule {
+                    avs_results: rule.avs_results,
+                    cvv_results: rule.cvv_results,
+                    action: rule.action,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessPostAuthRulesConfig>
+    for api_models::admin::BusinessPostAuthRulesConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessPostAuthRulesConfig) -> Self {
+        Self {
+            rules: item
+                .rules
+                .into_iter()
+                .map(|rule| api_models::admin::PostAuthRule {
+                    avs_results: rule.avs_results,
+                    cvv_results: rule.cvv_results,
+                    action: rule.action,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessPayoutRetryConfig>
+    for diesel_models::business_profile::BusinessPayoutRetryConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessPayoutRetryConfig) -> Self {
+        Self {
+            max_single_connector_retries: item.max_single_connector_retries,
+            max_multi_connector_retries: item.max_multi_connector_retries,
+            retryable_error_categories: item.retryable_error_categories,
+            backoff_in_millis: item.backoff_in_millis,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessPayoutRetryConfig>
+    for api_models::admin::BusinessPayoutRetryConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessPayoutRetryConfig) -> Self {
+        Self {
+            max_single_connector_retries: item.max_single_connector_retries,
+            max_multi_connector_retries: item.max_multi_connector_retries,
+            retryable_error_categories: item.retryable_error_categories,
+            backoff_in_millis: item.backoff_in_millis,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessPaymentDefaultsConfig>
+    for diesel_models::business_profile::BusinessPaymentDefaultsConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessPaymentDefaultsConfig) -> Self {
+        Self {
+            capture_method: item.capture_method,
+            setup_future_usage: item.setup_future_usage,
+            authentication_type: item.authentication_type,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessPaymentDefaultsConfig>
+    for api_models::admin::BusinessPaymentDefaultsConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessPaymentDefaultsConfig) -> Self {
+        Self {
+            capture_method: item.capture_method,
+            setup_future_usage: item.setup_future_usage,
+            authentication_type: item.authentication_type,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessCaptureOnShipmentConfig>
+    for diesel_models::business_profile::BusinessCaptureOnShipmentConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessCaptureOnShipmentConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            partial_capture_enabled: item.partial_capture_enabled,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessCaptureOnShipmentConfig>
+    for api_models::admin::BusinessCaptureOnShipmentConfig
+{
+    fn foreign_from(
+        item: diesel_models::business_profile::BusinessCaptureOnShipmentConfig,
+    ) -> Self {
+        Self {
+            enabled: item.enabled,
+            partial_capture_enabled: item.partial_capture_enabled,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessDccConfig>
+    for diesel_models::business_profile::BusinessDccConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessDccConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            markup_in_basis_points: item.markup_in_basis_points,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessDccConfig>
+    for api_models::admin::BusinessDccConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessDccConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            markup_in_basis_points: item.markup_in_basis_points,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessPayoutFeeConfig>
+    for diesel_models::business_profile::BusinessPayoutFeeConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessPayoutFeeConfig) -> Self {
+        Self {
+            fee_schedules: item
+                .fee_schedules
+                .into_iter()
+                .map(
+                    |fee_schedule| diesel_models::business_profile::PayoutFeeSchedule {
+                        merchant_connector_id: fee_schedule.merchant_connector_id,
+                        currency: fee_schedule.currency,
+                        fixed_fee: fee_schedule.fixed_fee,
+                        percentage_in_basis_points: fee_schedule.percentage_in_basis_points,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessPayoutFeeConfig>
+    for api_models::admin::BusinessPayoutFeeConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessPayoutFeeConfig) -> Self {
+        Self {
+            fee_schedules: item
+                .fee_schedules
+                .into_iter()
+                .map(|fee_schedule| api_models::admin::PayoutFeeSchedule {
+                    merchant_connector_id: fee_schedule.merchant_connector_id,
+                    currency: fee_schedule.currency,
+                    fixed_fee: fee_schedule.fixed_fee,
+                    percentage_in_basis_points: fee_schedule.percentage_in_basis_points,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessPayoutBalanceConfig>
+    for diesel_models::business_profile::BusinessPayoutBalanceConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessPayoutBalanceConfig) -> Self {
+        Self {
+            holdback_percentage_in_basis_points: item.holdback_percentage_in_basis_points,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessPayoutBalanceConfig>
+    for api_models::admin::BusinessPayoutBalanceConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::BusinessPayoutBalanceConfig) -> Self {
+        Self {
+            holdback_percentage_in_basis_points: item.holdback_percentage_in_basis_points,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessOperationFallbackConfig>
+    for diesel_models::business_profile::BusinessOperationFallbackConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessOperationFallbackConfig) -> Self {
+        Self {
+            groups: item
+                .groups
+                .into_iter()
+                .map(
+                    |group| diesel_models::business_profile::OperationFallbackGroup {
+                        merchant_connector_ids: group.merchant_connector_ids,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessOperationFallbackConfig>
+    for api_models::admin::BusinessOperationFallbackConfig
+{
+    fn foreign_from(
+        item: diesel_models::business_profile::BusinessOperationFallbackConfig,
+    ) -> Self {
+        Self {
+            groups: item
+                .groups
+                .into_iter()
+                .map(|group| api_models::admin::OperationFallbackGroup {
+                    merchant_connector_ids: group.merchant_connector_ids,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::ConnectorCanaryRolloutConfig>
+    for diesel_models::business_profile::ConnectorCanaryRollout
+{
+    fn foreign_from(item: api_models::admin::ConnectorCanaryRolloutConfig) -> Self {
+        Self {
+            canary_merchant_connector_id: item.canary_merchant_connector_id,
+            incumbent_merchant_connector_id: item.incumbent_merchant_connector_id,
+            traffic_percentage_stages: item.traffic_percentage_stages,
+            stage_duration_in_secs: item.stage_duration_in_secs,
+            rollback_margin: item.rollback_margin,
+            min_sample_size: item.min_sample_size,
+            status: api_enums::CanaryRolloutStatus::InProgress,
+            started_at: common_utils::date_time::now(),
+            rolled_back_at: None,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::ConnectorCanaryRollout>
+    for api_models::admin::ConnectorCanaryRolloutResponse
+{
+    fn foreign_from(item: diesel_models::business_profile::ConnectorCanaryRollout) -> Self {
+        let current_traffic_percentage =
+            item.get_canary_traffic_percentage(common_utils::date_time::now());
+        Self {
+            canary_merchant_connector_id: item.canary_merchant_connector_id,
+            incumbent_merchant_connector_id: item.incumbent_merchant_connector_id,
+            traffic_percentage_stages: item.traffic_percentage_stages,
+            stage_duration_in_secs: item.stage_duration_in_secs,
+            rollback_margin: item.rollback_margin,
+            min_sample_size: item.min_sample_size,
+            status: item.status,
+            current_traffic_percentage,
+            started_at: item.started_at,
+            rolled_back_at: item.rolled_back_at,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::BusinessPayoutFulfillmentConfig>
+    for diesel_models::business_profile::BusinessPayoutFulfillmentConfig
+{
+    fn foreign_from(item: api_models::admin::BusinessPayoutFulfillmentConfig) -> Self {
+        Self {
+            windows: item
+                .windows
+                .into_iter()
+                .map(
+                    |window| diesel_models::business_profile::PayoutFulfillmentWindow {
+                        payout_types: window.payout_types,
+                        payout_method_types: window.payout_method_types,
+                        start_time: window.start_time,
+                        end_time: window.end_time,
+                        utc_offset_in_minutes: window.utc_offset_in_minutes,
+                        banking_days_only: window.banking_days_only,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::BusinessPayoutFulfillmentConfig>
+    for api_models::admin::BusinessPayoutFulfillmentConfig
+{
+    fn foreign_from(
+        item: diesel_models::business_profile::BusinessPayoutFulfillmentConfig,
+    ) -> Self {
+        Self {
+            windows: item
+                .windows
+                .into_iter()
+                .map(|window| api_models::admin::PayoutFulfillmentWindow {
+                    payout_types: window.payout_types,
+                    payout_method_types: window.payout_method_types,
+                    start_time: window.start_time,
+                    end_time: window.end_time,
+                    utc_offset_in_minutes: window.utc_offset_in_minutes,
+                    banking_days_only: window.banking_days_only,
+                })
+                .collect(),
+        }
+    }
+}
+
 impl ForeignFrom<api_models::admin::BusinessGenericLinkConfig>
     for diesel_models::business_profile::BusinessGenericLinkConfig
 {
@@ -46,7 +46,7 @@ use uuid::Uuid;
 
 pub use self::ext_traits::{OptionExt, ValidateCall};
 #[cfg(feature = "v1")]
-use crate::core::webhooks as webhooks_core;
+use crate::core::{email_templates, invoices, webhooks as webhooks_core};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::types::storage;
 use crate::{
@@ -1192,6 +1192,47 @@ where
     let status = payment_data.get_payment_intent().status;
     let payment_id = payment_data.get_payment_intent().get_id().to_owned();
 
+    let is_invoice_email_enabled = business_profile
+        .invoice_config
+        .as_ref()
+        .is_some_and(|config| config.send_email_on_payment_success);
+    if status == enums::IntentStatus::Succeeded && is_invoice_email_enabled {
+        let invoice_future = invoices::send_invoice_on_payment_success(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile.clone(),
+            payment_data.get_payment_intent().clone(),
+            customer.clone(),
+        );
+        tokio::spawn(
+            async move {
+                invoice_future
+                    .await
+                    .map_err(|error| logger::error!(invoice_email_error=?error))
+                    .ok();
+            }
+            .in_current_span(),
+        );
+    }
+
+    if status == enums::IntentStatus::Succeeded {
+        let receipt_future = email_templates::send_payment_receipt(
+            state.clone(),
+            merchant_account.clone(),
+            payment_data.get_payment_intent().clone(),
+            customer.clone(),
+        );
+        tokio::spawn(
+            async move {
+                receipt_future
+                    .await
+                    .map_err(|error| logger::error!(payment_receipt_email_error=?error))
+                    .ok();
+            }
+            .in_current_span(),
+        );
+    }
+
     let captures = payment_data
         .get_multiple_capture_data()
         .map(|multiple_capture_data| {
@@ -1331,6 +1372,24 @@ pub async fn trigger_refund_outgoing_webhook(
             logger::warn!("Outgoing webhook not sent because of missing event type status mapping");
         };
     }
+
+    if refund_status == enums::RefundStatus::Success {
+        let notification_future = email_templates::send_refund_notification(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            refund.clone(),
+        );
+        tokio::spawn(
+            async move {
+                notification_future
+                    .await
+                    .map_err(|error| logger::error!(refund_notification_email_error=?error))
+                    .ok();
+            }
+            .in_current_span(),
+        );
+    }
     Ok(())
 }
 
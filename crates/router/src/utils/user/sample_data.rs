@@ -360,6 +360,9 @@ pub async fn generate_sample_data(
             order_tax_amount: None,
             connector_transaction_data,
             connector_mandate_detail: None,
+            presentment_currency: None,
+            presentment_amount: None,
+            exchange_rate: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
@@ -400,6 +403,8 @@ pub async fn generate_sample_data(
                 organization_id: org_id.clone(),
                 connector_refund_data: None,
                 connector_transaction_data,
+                capture_id: None,
+                line_items: None,
             })
         } else {
             None
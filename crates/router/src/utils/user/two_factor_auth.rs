@@ -226,3 +226,213 @@ pub async fn delete_recovery_code_attempts_from_redis(
         .change_context(UserErrors::InternalServerError)
         .map(|_| ())
 }
+
+/// The relying party is the dashboard, which is identified by the host of its base url
+pub fn get_webauthn(state: &SessionState) -> UserResult<webauthn_rs::Webauthn> {
+    let rp_origin = url::Url::parse(&state.conf.user.base_url)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse the dashboard base url")?;
+    let rp_id = rp_origin
+        .host_str()
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("Dashboard base url does not have a host")?
+        .to_owned();
+
+    webauthn_rs::WebauthnBuilder::new(&rp_id, &rp_origin)
+        .change_context(UserErrors::InternalServerError)?
+        .rp_name(&state.conf.user.totp_issuer_name)
+        .build()
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn check_webauthn_in_redis(state: &SessionState, user_id: &str) -> UserResult<bool> {
+    let redis_conn = super::get_redis_connection(state)?;
+    let key = format!("{}{}", consts::user::REDIS_WEBAUTHN_PREFIX, user_id);
+    redis_conn
+        .exists::<()>(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn insert_webauthn_in_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    let key = format!("{}{}", consts::user::REDIS_WEBAUTHN_PREFIX, user_id);
+    redis_conn
+        .set_key_with_expiry(
+            key.as_str(),
+            common_utils::date_time::now_unix_timestamp(),
+            state.conf.user.two_factor_auth_expiry_in_secs,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn delete_webauthn_from_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    let key = format!("{}{}", consts::user::REDIS_WEBAUTHN_PREFIX, user_id);
+    redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+/// Checks if the user has completed any of the two factor authentication methods in the session
+pub async fn check_two_factor_auth_in_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<bool> {
+    Ok(check_totp_in_redis(state, user_id).await?
+        || check_recovery_code_in_redis(state, user_id).await?
+        || check_webauthn_in_redis(state, user_id).await?)
+}
+
+fn get_webauthn_registration_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_WEBAUTHN_REGISTRATION_PREFIX,
+        user_id
+    )
+}
+
+fn get_webauthn_authentication_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_WEBAUTHN_AUTHENTICATION_PREFIX,
+        user_id
+    )
+}
+
+pub async fn insert_webauthn_registration_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    registration: &webauthn_rs::prelude::PasskeyRegistration,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_webauthn_registration_key(user_id),
+            registration,
+            consts::user::REDIS_WEBAUTHN_CEREMONY_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_webauthn_registration_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<webauthn_rs::prelude::PasskeyRegistration> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .get_and_deserialize_key(
+            &get_webauthn_registration_key(user_id),
+            "PasskeyRegistration",
+        )
+        .await
+        .map_err(|error| match error.current_context() {
+            redis_interface::errors::RedisError::NotFound => {
+                error.change_context(UserErrors::WebauthnChallengeNotFound)
+            }
+            _ => error.change_context(UserErrors::InternalServerError),
+        })
+}
+
+pub async fn insert_webauthn_authentication_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    authentication: &webauthn_rs::prelude::PasskeyAuthentication,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_webauthn_authentication_key(user_id),
+            authentication,
+            consts::user::REDIS_WEBAUTHN_CEREMONY_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_webauthn_authentication_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<webauthn_rs::prelude::PasskeyAuthentication> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .get_and_deserialize_key(
+            &get_webauthn_authentication_key(user_id),
+            "PasskeyAuthentication",
+        )
+        .await
+        .map_err(|error| match error.current_context() {
+            redis_interface::errors::RedisError::NotFound => {
+                error.change_context(UserErrors::WebauthnChallengeNotFound)
+            }
+            _ => error.change_context(UserErrors::InternalServerError),
+        })
+}
+
+/// The challenge of a ceremony can only be used once
+pub async fn delete_webauthn_ceremonies_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .delete_multiple_keys(vec![
+            get_webauthn_registration_key(user_id),
+            get_webauthn_authentication_key(user_id),
+        ])
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+fn get_webauthn_attempts_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_WEBAUTHN_ATTEMPTS_PREFIX,
+        user_id
+    )
+}
+
+pub async fn insert_webauthn_attempts_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    user_webauthn_attempts: u8,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .set_key_with_expiry(
+            &get_webauthn_attempts_key(user_id),
+            user_webauthn_attempts,
+            consts::user::REDIS_WEBAUTHN_ATTEMPTS_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_webauthn_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<u8> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .get_key::<Option<u8>>(&get_webauthn_attempts_key(user_id))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|v| v.unwrap_or(0))
+}
+
+pub async fn delete_webauthn_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .delete_key(&get_webauthn_attempts_key(user_id))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
@@ -3,11 +3,33 @@ pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
 #[cfg(feature = "v1")]
+pub mod connector_credentials_expiry;
+#[cfg(feature = "v1")]
+pub mod data_retention;
+#[cfg(feature = "v1")]
+pub mod installment_plan_charge;
+#[cfg(feature = "v1")]
+pub mod locker_migration;
+#[cfg(feature = "v1")]
 pub mod outgoing_webhook_retry;
 #[cfg(feature = "v1")]
+pub mod payment_archival;
+#[cfg(feature = "v1")]
+pub mod payment_link_expiry;
+#[cfg(feature = "v1")]
 pub mod payment_method_status_update;
 pub mod payment_sync;
 #[cfg(feature = "v1")]
+pub mod payments_auto_capture;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_fulfillment;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_link_expiry;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_recipient_kyc_status;
+#[cfg(feature = "v1")]
 pub mod refund_router;
 #[cfg(feature = "v1")]
 pub mod tokenized_data;
+#[cfg(feature = "v1")]
+pub mod voucher_expiry;
@@ -0,0 +1,107 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::connector_credentials_expiry, errors, logger::error, routes::SessionState, types::storage,
+};
+
+pub struct ConnectorCredentialsExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ConnectorCredentialsExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::ConnectorCredentialsExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorCredentialsExpiryTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        match connector_credentials_expiry::send_connector_credentials_expiry_reminders(
+            state,
+            &merchant_account,
+            &key_store,
+        )
+        .await
+        {
+            Ok(true) => {
+                let schedule_time = common_utils::date_time::now()
+                    + time::Duration::hours(
+                        connector_credentials_expiry::CONNECTOR_CREDENTIALS_EXPIRY_CHECK_INTERVAL_IN_HOURS,
+                    );
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            // The job is finished once none of the credentials of the merchant are left to expire,
+            // and is rescheduled when the expiry of credentials is configured again
+            Ok(false) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Err(error) => {
+                error!(?error, %process.id, "Failed to send the reminders of the expiry of connector credentials");
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
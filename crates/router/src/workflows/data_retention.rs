@@ -0,0 +1,116 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::data_retention,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct DataRetentionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataRetentionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::DataRetentionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DataRetentionTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        // The job is finished once the merchant no longer has a data retention policy, and is
+        // rescheduled when a policy is configured again
+        let Some(data_retention_policy) = merchant_account
+            .get_data_retention_policy()
+            .filter(|data_retention_policy| !data_retention_policy.is_empty())
+        else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+
+        match data_retention::purge_expired_data(
+            state,
+            &merchant_account,
+            &data_retention_policy,
+            false,
+        )
+        .await
+        {
+            Ok(report) => {
+                info!(?report, %process.id, "Purged data past its retention period");
+                let schedule_time = common_utils::date_time::now()
+                    + time::Duration::hours(data_retention::DATA_RETENTION_PURGE_INTERVAL_IN_HOURS);
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            Err(error) => {
+                error!(?error, %process.id, "Failed to purge data past its retention period");
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
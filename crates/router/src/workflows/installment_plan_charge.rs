@@ -0,0 +1,247 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{installment_plans, test_clocks},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+/// Charges the installments of an installment plan against its mandate. A failed installment is
+/// retried every `retry_interval_hours` until `max_retries_per_installment` is reached, after
+/// which it is considered missed. The plan is defaulted once more than `max_missed_installments`
+/// installments have been missed.
+pub struct InstallmentPlanChargeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for InstallmentPlanChargeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::InstallmentPlanChargeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("InstallmentPlanChargeTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let installment_plan = db
+            .find_installment_plan_by_merchant_id_plan_id(
+                &tracking_data.merchant_id,
+                &tracking_data.installment_plan_id,
+            )
+            .await?;
+
+        // The plan has been cancelled since the task was scheduled
+        if installment_plan.status != enums::InstallmentPlanStatus::Active {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        // Installments are scheduled according to the time of the test clock attached to the
+        // merchant, if any
+        let clock_offset =
+            test_clocks::get_merchant_time_offset(state, &tracking_data.merchant_id).await?;
+        let now = common_utils::date_time::now() + clock_offset;
+
+        let mut installments = installment_plan.installments.clone();
+        let Some(installment_index) = installments
+            .iter()
+            .position(|installment| installment.status == enums::InstallmentStatus::Scheduled)
+        else {
+            return complete_installment_plan(
+                db,
+                process,
+                &installment_plan,
+                installments,
+                installment_plan.missed_count,
+            )
+            .await;
+        };
+
+        let charge_result = installment_plans::charge_installment(
+            state,
+            merchant_account,
+            key_store,
+            &installment_plan,
+            &installments[installment_index],
+        )
+        .await;
+
+        let installment = &mut installments[installment_index];
+        installment.attempt_count += 1;
+        let is_charged = match charge_result {
+            Ok(payments_response) => {
+                installment.payment_id = Some(payments_response.payment_id);
+                matches!(
+                    payments_response.status,
+                    enums::IntentStatus::Succeeded
+                        | enums::IntentStatus::Processing
+                        | enums::IntentStatus::RequiresCapture
+                )
+            }
+            Err(error) => {
+                logger::error!(
+                    installment_plan_id = %installment_plan.id,
+                    installment_number = installment.installment_number,
+                    ?error,
+                    "Failed to create payment for installment"
+                );
+                false
+            }
+        };
+
+        let mut missed_count = installment_plan.missed_count;
+        if is_charged {
+            installment.status = enums::InstallmentStatus::Charged;
+        } else if process.retry_count < installment_plan.max_retries_per_installment {
+            let retry_at =
+                now + time::Duration::hours(i64::from(installment_plan.retry_interval_hours));
+            logger::info!(
+                installment_plan_id = %installment_plan.id,
+                installment_number = installment.installment_number,
+                %retry_at,
+                "Installment could not be charged, scheduling retry"
+            );
+            db.update_installment_plan_by_merchant_id_plan_id(
+                &installment_plan.merchant_id,
+                &installment_plan.id,
+                storage::InstallmentPlanUpdate::ScheduleUpdate {
+                    status: enums::InstallmentPlanStatus::Active,
+                    installments,
+                    missed_count,
+                    next_charge_at: Some(retry_at),
+                },
+            )
+            .await?;
+            db.as_scheduler()
+                .retry_process(process, retry_at - clock_offset)
+                .await?;
+            return Ok(());
+        } else {
+            installment.status = enums::InstallmentStatus::Missed;
+            missed_count += 1;
+        }
+
+        if missed_count > installment_plan.max_missed_installments {
+            logger::info!(
+                installment_plan_id = %installment_plan.id,
+                missed_count,
+                "Defaulting installment plan after missed installments"
+            );
+            db.update_installment_plan_by_merchant_id_plan_id(
+                &installment_plan.merchant_id,
+                &installment_plan.id,
+                storage::InstallmentPlanUpdate::ScheduleUpdate {
+                    status: enums::InstallmentPlanStatus::Defaulted,
+                    installments: installment_plans::void_scheduled_installments(installments),
+                    missed_count,
+                    next_charge_at: None,
+                },
+            )
+            .await?;
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?;
+            return Ok(());
+        }
+
+        match installments
+            .get_next_scheduled_installment()
+            .map(|installment| installment.due_at)
+        {
+            Some(next_due_at) => {
+                // An installment which fell due while the previous one was being retried is
+                // charged right away
+                let next_charge_at = next_due_at.max(now);
+                db.update_installment_plan_by_merchant_id_plan_id(
+                    &installment_plan.merchant_id,
+                    &installment_plan.id,
+                    storage::InstallmentPlanUpdate::ScheduleUpdate {
+                        status: enums::InstallmentPlanStatus::Active,
+                        installments,
+                        missed_count,
+                        next_charge_at: Some(next_charge_at),
+                    },
+                )
+                .await?;
+                db.as_scheduler()
+                    .reset_process(process, next_charge_at - clock_offset)
+                    .await?;
+            }
+            None => {
+                complete_installment_plan(
+                    db,
+                    process,
+                    &installment_plan,
+                    installments,
+                    missed_count,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+async fn complete_installment_plan(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    installment_plan: &storage::InstallmentPlan,
+    installments: storage::InstallmentSchedule,
+    missed_count: i32,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    db.update_installment_plan_by_merchant_id_plan_id(
+        &installment_plan.merchant_id,
+        &installment_plan.id,
+        storage::InstallmentPlanUpdate::ScheduleUpdate {
+            status: enums::InstallmentPlanStatus::Completed,
+            installments,
+            missed_count,
+            next_charge_at: None,
+        },
+    )
+    .await?;
+    db.as_scheduler()
+        .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+        .await?;
+    Ok(())
+}
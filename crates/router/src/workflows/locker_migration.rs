@@ -0,0 +1,115 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::locker_migration,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, enums as storage_enums},
+};
+
+pub struct LockerMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for LockerMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: storage::LockerMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("LockerMigrationTrackingData")?;
+
+        let batch_result = match locker_migration::migrate_locker_batch(state, &tracking_data).await
+        {
+            Ok(batch_result) => batch_result,
+            Err(error) => {
+                error!(?error, %process.id, "Failed to migrate batch of cards");
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+                return Ok(());
+            }
+        };
+
+        tracking_data.customers_processed = tracking_data
+            .customers_processed
+            .saturating_add(batch_result.customers_processed);
+        tracking_data.cards_moved = tracking_data
+            .cards_moved
+            .saturating_add(batch_result.cards_moved);
+        tracking_data.cards_failed = tracking_data
+            .cards_failed
+            .saturating_add(batch_result.cards_failed);
+
+        // A batch smaller than the batch size indicates that there are no more customers left
+        let is_migration_complete =
+            batch_result.customers_processed < u32::from(tracking_data.batch_size);
+        let (status, business_status) = if is_migration_complete {
+            (
+                storage_enums::ProcessTrackerStatus::Finish,
+                business_status::COMPLETED_BY_PT,
+            )
+        } else {
+            (
+                storage_enums::ProcessTrackerStatus::New,
+                business_status::PENDING,
+            )
+        };
+
+        let now = common_utils::date_time::now();
+        let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(now),
+            tracking_data: Some(tracking_data.encode_to_value()?),
+            business_status: Some(String::from(business_status)),
+            status: Some(status),
+            updated_at: Some(now),
+        };
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
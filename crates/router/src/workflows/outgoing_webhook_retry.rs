@@ -335,7 +335,7 @@ pub(crate) async fn retry_webhook_delivery_task(
 }
 
 #[instrument(skip_all)]
-async fn get_outgoing_webhook_content_and_event_type(
+pub(crate) async fn get_outgoing_webhook_content_and_event_type(
     state: SessionState,
     req_state: ReqState,
     merchant_account: domain::MerchantAccount,
@@ -350,8 +350,10 @@ async fn get_outgoing_webhook_content_and_event_type(
 
     use crate::{
         core::{
+            connector_credentials_expiry,
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::cards::retrieve_payment_method,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -404,9 +406,8 @@ async fn get_outgoing_webhook_content_and_event_type(
             .await?
             {
                 ApplicationResponse::Json(payments_response)
-                | ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
-                    Ok(payments_response)
-                }
+                | ApplicationResponse::JsonWithHeaders((payments_response, _))
+                | ApplicationResponse::JsonAccepted(payments_response) => Ok(payments_response),
                 ApplicationResponse::StatusOk
                 | ApplicationResponse::TextPlain(_)
                 | ApplicationResponse::JsonForRedirection(_)
@@ -434,6 +435,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                 refund_id,
                 force_sync: Some(false),
                 merchant_connector_details: None,
+                expand: None,
             };
 
             let refund = Box::pin(refund_retrieve_core_with_refund_id(
@@ -461,9 +463,8 @@ async fn get_outgoing_webhook_content_and_event_type(
             let dispute_response =
                 match retrieve_dispute(state, merchant_account, None, request).await? {
                     ApplicationResponse::Json(dispute_response)
-                    | ApplicationResponse::JsonWithHeaders((dispute_response, _)) => {
-                        Ok(dispute_response)
-                    }
+                    | ApplicationResponse::JsonWithHeaders((dispute_response, _))
+                    | ApplicationResponse::JsonAccepted(dispute_response) => Ok(dispute_response),
                     ApplicationResponse::StatusOk
                     | ApplicationResponse::TextPlain(_)
                     | ApplicationResponse::JsonForRedirection(_)
@@ -493,9 +494,8 @@ async fn get_outgoing_webhook_content_and_event_type(
             let mandate_response =
                 match get_mandate(state, merchant_account, key_store, request).await? {
                     ApplicationResponse::Json(mandate_response)
-                    | ApplicationResponse::JsonWithHeaders((mandate_response, _)) => {
-                        Ok(mandate_response)
-                    }
+                    | ApplicationResponse::JsonWithHeaders((mandate_response, _))
+                    | ApplicationResponse::JsonAccepted(mandate_response) => Ok(mandate_response),
                     ApplicationResponse::StatusOk
                     | ApplicationResponse::TextPlain(_)
                     | ApplicationResponse::JsonForRedirection(_)
@@ -552,5 +552,58 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method_id = tracking_data.primary_object_id.clone();
+            let request = api_models::payment_methods::PaymentMethodId { payment_method_id };
+
+            let payment_method_response =
+                match retrieve_payment_method(state, request, key_store, merchant_account).await? {
+                    ApplicationResponse::Json(payment_method_response)
+                    | ApplicationResponse::JsonWithHeaders((payment_method_response, _))
+                    | ApplicationResponse::JsonAccepted(payment_method_response) => {
+                        Ok(payment_method_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+            // Payment methods are only notified once their details are collected, which does not
+            // change across retries
+            let event_type = Some(EventType::PaymentMethodCollected);
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::ConnectorAccounts => {
+            let response =
+                connector_credentials_expiry::get_connector_credentials_expiry_reminder_content(
+                    &state,
+                    merchant_account.get_id(),
+                    &key_store,
+                    &tracking_data.primary_object_id,
+                )
+                .await?;
+            // The reminder is not retried once the expiring credentials have been renewed
+            let event_type = (!response.credentials.is_empty())
+                .then_some(EventType::ConnectorCredentialsExpiring);
+
+            Ok((
+                OutgoingWebhookContent::ConnectorCredentialsExpiryDetails(Box::new(response)),
+                event_type,
+            ))
+        }
     }
 }
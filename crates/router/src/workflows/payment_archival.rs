@@ -0,0 +1,109 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums as storage_enums},
+};
+
+pub struct PaymentArchivalWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentArchivalWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: storage::PaymentArchivalTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentArchivalTrackingData")?;
+
+        let now = common_utils::date_time::now();
+        let created_before =
+            now - time::Duration::days(i64::from(tracking_data.retention_period_in_days));
+
+        let payments_archived = match db
+            .archive_payments_created_before(created_before, i64::from(tracking_data.batch_size))
+            .await
+        {
+            Ok(payments_archived) => payments_archived,
+            Err(error) => {
+                error!(?error, %process.id, "Failed to archive batch of payments");
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+                return Ok(());
+            }
+        };
+        info!(%process.id, payments_archived, "Archived batch of payments");
+
+        tracking_data.payments_archived = tracking_data
+            .payments_archived
+            .saturating_add(u64::try_from(payments_archived).unwrap_or(u64::MAX));
+
+        // A batch smaller than the batch size indicates that all the payments past the retention
+        // period have been archived, so the next run is scheduled after the interval. Otherwise,
+        // the next batch is archived right away.
+        let schedule_time = if payments_archived < usize::from(tracking_data.batch_size) {
+            tracking_data.last_completed_run_at = Some(now);
+            now + time::Duration::hours(i64::from(tracking_data.interval_in_hours))
+        } else {
+            now
+        };
+
+        let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(tracking_data.encode_to_value()?),
+            business_status: Some(String::from(business_status::PENDING)),
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+use api_models::webhooks::OutgoingWebhookContent;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        payment_link,
+        payments::{self as payment_flows, operations},
+        test_clocks, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+/// Emits the `payment_link_abandoned` event once a payment link expires without the payment
+/// being completed. If the profile has enabled recovery emails in its payment link config, the
+/// link is regenerated with a fresh expiry and emailed to the customer. Recovery is attempted
+/// only once for a payment link.
+pub struct PaymentLinkExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentLinkExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentLinkExpiryTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("PaymentLinkExpiryTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_link = db
+            .find_payment_link_by_payment_link_id(&tracking_data.payment_link_id)
+            .await?;
+
+        // A link deactivated by the merchant is not considered abandoned by the customer
+        if payment_link.deactivated_at.is_some() {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        // The expiry of the link has been extended since the task was scheduled. The time of the
+        // test clock attached to the merchant is used, if any
+        let clock_offset =
+            test_clocks::get_merchant_time_offset(state, &tracking_data.merchant_id).await?;
+        if let Some(fulfilment_time) = payment_link.fulfilment_time.filter(|fulfilment_time| {
+            *fulfilment_time > common_utils::date_time::now() + clock_offset
+        }) {
+            db.as_scheduler()
+                .reset_process(process, fulfilment_time - clock_offset)
+                .await?;
+            return Ok(());
+        }
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        if !matches!(
+            payment_intent.status,
+            enums::IntentStatus::RequiresPaymentMethod
+                | enums::IntentStatus::RequiresConfirmation
+                | enums::IntentStatus::RequiresCustomerAction
+        ) {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                status = ?payment_intent.status,
+                "Payment link was not abandoned as the payment has progressed"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let profile_id = payment_intent
+            .profile_id
+            .as_ref()
+            .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+            .await?;
+
+        let retrieve_request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let payments_response = match Box::pin(payment_flows::payments_core::<
+            api::PSync,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::PSync>,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account.clone(),
+            None,
+            key_store.clone(),
+            operations::PaymentStatus,
+            retrieve_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Avoid,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await?
+        {
+            services::ApplicationResponse::Json(payments_response)
+            | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+                Ok(payments_response)
+            }
+            _ => Err(sch_errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.payment_id.get_string_repr().to_owned(),
+            }),
+        }?;
+
+        let primary_object_created_at = payments_response.created;
+        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile.clone(),
+            &key_store,
+            enums::EventType::PaymentLinkAbandoned,
+            enums::EventClass::Payments,
+            tracking_data.payment_id.get_string_repr().to_owned(),
+            enums::EventObjectType::PaymentDetails,
+            OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
+            primary_object_created_at,
+        ))
+        .await?;
+
+        if let Some(abandoned_cart_config) = business_profile
+            .payment_link_config
+            .as_ref()
+            .and_then(|payment_link_config| payment_link_config.abandoned_cart_config.as_ref())
+            .filter(|abandoned_cart_config| abandoned_cart_config.send_recovery_email)
+        {
+            // The event has already been emitted, so a failure to recover the link is not retried
+            payment_link::recover_abandoned_payment_link(
+                state,
+                &merchant_account,
+                &key_store,
+                abandoned_cart_config,
+                &payment_link,
+                payment_intent.customer_id.as_ref(),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    payment_link_id = %payment_link.payment_link_id,
+                    ?error,
+                    "Failed to recover abandoned payment link"
+                )
+            })
+            .ok();
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -153,6 +153,7 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
                             connector_transaction_id: None,
                             payment_method_data: None,
                             authentication_type: None,
+                            connector_response_codes: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -0,0 +1,161 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+    workflows::payment_sync,
+};
+
+/// Captures a manually captured payment at the `capture_on` time it was created with. The
+/// outgoing webhook for the captured payment is triggered by the capture operation itself.
+pub struct PaymentsAutoCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentsAutoCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentsAutoCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsAutoCaptureTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment has been captured, voided or retried since the task was scheduled
+        if payment_intent.status != enums::IntentStatus::RequiresCapture
+            || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                status = ?payment_intent.status,
+                "Skipping auto capture of payment which is no longer capturable"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let capture_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Capture>,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            None,
+            key_store,
+            operations::PaymentCapture,
+            capture_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await;
+
+        match capture_result {
+            Ok((payment_data, _, _, _, _)) => {
+                logger::info!(
+                    payment_id = ?tracking_data.payment_id,
+                    status = ?payment_data.payment_attempt.status,
+                    "Auto captured payment"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(
+                    payment_id = ?tracking_data.payment_id,
+                    ?error,
+                    "Failed to auto capture payment"
+                );
+                let payment_attempt = db
+                    .find_payment_attempt_by_attempt_id_merchant_id(
+                        &tracking_data.attempt_id,
+                        &tracking_data.merchant_id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await?;
+                let connector = payment_attempt
+                    .connector
+                    .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+
+                // The capture is retried on the schedule configured for syncing payments of the
+                // connector
+                payment_sync::retry_sync_task(
+                    db,
+                    connector,
+                    merchant_account.get_id().to_owned(),
+                    process,
+                )
+                .await?;
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
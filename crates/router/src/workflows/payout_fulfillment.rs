@@ -0,0 +1,102 @@
+use api_models::payouts as payout_models;
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    ext_traits::{OptionExt, ValueExt},
+};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payouts,
+    errors,
+    routes::SessionState,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+/// Fulfills a payout created with `auto_fulfill` whose fulfillment was deferred until the
+/// fulfillment window configured in the business profile opens.
+pub struct PayoutFulfillmentWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutFulfillmentWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await?;
+
+        let payout_id = tracking_data.payout_id.clone();
+        let request = payout_models::PayoutRequest::PayoutRetrieveRequest(tracking_data);
+        let payout_data = payouts::make_payout_data(
+            state,
+            &merchant_account,
+            None,
+            &key_store,
+            &request,
+            DEFAULT_LOCALE,
+        )
+        .await?;
+
+        // The payout has been fulfilled or cancelled since the task was scheduled
+        if payout_data.payout_attempt.status != enums::PayoutStatus::RequiresFulfillment {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        Box::pin(payouts::payouts_fulfill_core(
+            state.clone(),
+            merchant_account,
+            key_store,
+            payout_models::PayoutActionRequest { payout_id },
+            DEFAULT_LOCALE,
+        ))
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,97 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, utils as pt_utils,
+};
+
+use crate::{core::payout_link, errors, logger::error, routes::SessionState, types::storage};
+
+/// Sweeps the payout links of a merchant which expired without the payout method details being
+/// submitted, cancelling their payouts. The sweep is rescheduled for the next payout link of the
+/// merchant to expire, and is finished once no payout links await submission.
+pub struct PayoutLinkExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutLinkExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::PayoutLinkExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutLinkExpiryTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        match payout_link::expire_payout_links(state, &merchant_account, &key_store).await {
+            Ok(Some(schedule_time)) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?
+            }
+            Ok(None) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            Err(error) => {
+                error!(?error, %process.id, "Failed to expire payout links");
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                // The sweep is scheduled again when the merchant creates a payout link
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+            }
+        };
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
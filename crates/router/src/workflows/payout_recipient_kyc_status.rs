@@ -0,0 +1,178 @@
+use api_models::payouts as payout_models;
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    ext_traits::{OptionExt, ValueExt},
+};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, utils as pt_utils,
+};
+
+use crate::{
+    core::payouts::{self, helpers as payouts_helpers},
+    errors,
+    routes::SessionState,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+/// Polls the KYC status of the recipient of a payout which is awaiting the recipient's
+/// verification before it can be fulfilled. Payouts created with `auto_fulfill` are fulfilled
+/// once the recipient is verified by the connector.
+pub struct PayoutRecipientKycStatusWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutRecipientKycStatusWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await?;
+
+        let payout_id = tracking_data.payout_id.clone();
+        let request = payout_models::PayoutRequest::PayoutRetrieveRequest(tracking_data);
+        let mut payout_data = payouts::make_payout_data(
+            state,
+            &merchant_account,
+            None,
+            &key_store,
+            &request,
+            DEFAULT_LOCALE,
+        )
+        .await?;
+
+        // The payout has been fulfilled or cancelled since the task was scheduled
+        if payout_data.payout_attempt.status != enums::PayoutStatus::RequiresFulfillment {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let connector = payout_data
+            .payout_attempt
+            .connector
+            .clone()
+            .get_required_value("connector")?;
+        let connector_data = api::ConnectorData::get_payout_connector_by_name(
+            &state.conf.connectors,
+            &connector,
+            api::GetToken::Connector,
+            payout_data.payout_attempt.merchant_connector_id.clone(),
+        )?;
+
+        let recipient_kyc_status = payouts::check_recipient_kyc_status(
+            state,
+            &merchant_account,
+            &key_store,
+            &connector_data,
+            &mut payout_data,
+        )
+        .await?;
+
+        match recipient_kyc_status {
+            Some(enums::RecipientKycStatus::Verified) => {
+                if payout_data.payouts.auto_fulfill {
+                    // Fulfillment is deferred if the fulfillment window of the profile is closed
+                    match payouts_helpers::get_deferred_fulfillment_time(&payout_data) {
+                        Some(fulfillment_time) => {
+                            payouts::add_payout_fulfillment_task(db, &payout_data, fulfillment_time)
+                                .await?
+                        }
+                        None => Box::pin(payouts::payouts_fulfill_core(
+                            state.clone(),
+                            merchant_account,
+                            key_store,
+                            payout_models::PayoutActionRequest { payout_id },
+                            DEFAULT_LOCALE,
+                        ))
+                        .await
+                        .map(|_| ())?,
+                    }
+                }
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Some(enums::RecipientKycStatus::Rejected) | None => {
+                logger::info!(
+                    ?payout_id,
+                    ?recipient_kyc_status,
+                    "Payout recipient cannot be verified, payout is not fulfilled"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Some(
+                enums::RecipientKycStatus::Pending | enums::RecipientKycStatus::RequiresInformation,
+            ) => {
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
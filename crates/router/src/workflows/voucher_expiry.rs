@@ -0,0 +1,205 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+    workflows::payment_sync,
+};
+
+/// The cancellation reason recorded on voucher payments cancelled at the expiry of the voucher
+const VOUCHER_EXPIRED_CANCELLATION_REASON: &str = "voucher_expired";
+
+/// Cancels a voucher payment once the voucher issued by the connector expires without being paid
+/// by the customer. The payment is synced with the connector before being cancelled, so that a
+/// voucher paid close to its expiry is not cancelled. The outgoing webhook for the cancelled
+/// payment is triggered by the cancel operation itself.
+pub struct VoucherExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for VoucherExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::VoucherExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("VoucherExpiryTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The voucher has been paid, or the payment cancelled or retried since the task was
+        // scheduled
+        if payment_intent.status != enums::IntentStatus::RequiresCustomerAction
+            || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                status = ?payment_intent.status,
+                "Skipping cancellation of voucher payment which is no longer awaiting payment"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let retrieve_request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: true,
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (payment_data, _, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::PSync,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::PSync>,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            None,
+            key_store.clone(),
+            operations::PaymentStatus,
+            retrieve_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await?;
+
+        // The voucher was paid, or expired at the connector, before its expiry was processed
+        if payment_data.payment_intent.status != enums::IntentStatus::RequiresCustomerAction {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                status = ?payment_data.payment_intent.status,
+                "Voucher payment was updated by the connector at the expiry of the voucher"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let cancel_request = api::PaymentsCancelRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            cancellation_reason: Some(VOUCHER_EXPIRED_CANCELLATION_REASON.to_string()),
+            merchant_connector_details: None,
+        };
+
+        let cancel_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Void,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Void>,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            None,
+            key_store,
+            operations::PaymentCancel,
+            cancel_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await;
+
+        match cancel_result {
+            Ok((payment_data, _, _, _, _)) => {
+                logger::info!(
+                    payment_id = ?tracking_data.payment_id,
+                    status = ?payment_data.payment_attempt.status,
+                    "Cancelled voucher payment at the expiry of the voucher"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(
+                    payment_id = ?tracking_data.payment_id,
+                    ?error,
+                    "Failed to cancel expired voucher payment"
+                );
+                let connector = payment_data
+                    .payment_attempt
+                    .connector
+                    .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+
+                // The cancellation is retried on the schedule configured for syncing payments of
+                // the connector
+                payment_sync::retry_sync_task(
+                    db,
+                    connector,
+                    merchant_account.get_id().to_owned(),
+                    process,
+                )
+                .await?;
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
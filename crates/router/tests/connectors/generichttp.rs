@@ -0,0 +1,226 @@
+use router::types::{self, api, storage::enums};
+use serde_json::json;
+use test_utils::connector_auth;
+
+use crate::utils::{self, ConnectorActions};
+
+#[derive(Clone, Copy)]
+struct GenerichttpTest;
+impl ConnectorActions for GenerichttpTest {}
+impl utils::Connector for GenerichttpTest {
+    fn get_data(&self) -> api::ConnectorData {
+        use router::connector::Generichttp;
+        utils::construct_connector_data_old(
+            Box::new(Generichttp::new()),
+            types::Connector::Generichttp,
+            api::GetToken::Connector,
+            None,
+        )
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        utils::to_connector_auth_type(
+            connector_auth::ConnectorAuthentication::new()
+                .generichttp
+                .expect("Missing connector authentication configuration")
+                .into(),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "generichttp".to_string()
+    }
+
+    fn get_connector_meta(&self) -> Option<serde_json::Value> {
+        Some(connector_metadata())
+    }
+}
+
+static CONNECTOR: GenerichttpTest = GenerichttpTest {};
+
+fn connector_metadata() -> serde_json::Value {
+    let response_mapping = json!({
+        "status": "/status",
+        "status_map": {
+            "succeeded": "succeeded",
+            "requires_capture": "authorized",
+            "processing": "pending",
+            "pending": "pending",
+            "failed": "failed",
+            "canceled": "voided"
+        },
+        "resource_id": "/id",
+        "error_code": "/last_payment_error/code",
+        "error_message": "/last_payment_error/message"
+    });
+    json!({
+        "authentication": { "header_name": "Authorization", "value_prefix": "Bearer " },
+        "authorize": {
+            "url": "https://api.example-processor.com/v1/payments",
+            "method": "POST",
+            "request_template": {
+                "amount": "{{amount}}",
+                "currency": "{{currency}}",
+                "reference": "{{reference}}",
+                "capture_method": "{{capture_method}}",
+                "card": {
+                    "number": "{{card_number}}",
+                    "expiry": "{{card_exp_month}}/{{card_exp_year}}",
+                    "cvc": "{{card_cvc}}"
+                }
+            },
+            "response_mapping": response_mapping
+        },
+        "capture": {
+            "url": "https://api.example-processor.com/v1/payments/{{connector_transaction_id}}/capture",
+            "method": "POST",
+            "request_template": { "amount": "{{amount}}" },
+            "response_mapping": response_mapping
+        },
+        "void": {
+            "url": "https://api.example-processor.com/v1/payments/{{connector_transaction_id}}/cancel",
+            "method": "POST",
+            "request_template": { "reason": "{{cancellation_reason}}" },
+            "response_mapping": response_mapping
+        },
+        "psync": {
+            "url": "https://api.example-processor.com/v1/payments/{{connector_transaction_id}}",
+            "method": "GET",
+            "response_mapping": response_mapping
+        },
+        "refund": {
+            "url": "https://api.example-processor.com/v1/refunds",
+            "method": "POST",
+            "request_template": {
+                "payment": "{{connector_transaction_id}}",
+                "amount": "{{amount}}",
+                "reference": "{{refund_id}}"
+            },
+            "response_mapping": response_mapping
+        },
+        "rsync": {
+            "url": "https://api.example-processor.com/v1/refunds/{{connector_refund_id}}",
+            "method": "GET",
+            "response_mapping": response_mapping
+        }
+    })
+}
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        connector_meta_data: Some(connector_metadata()),
+        ..Default::default()
+    })
+}
+
+fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
+    None
+}
+
+// Cards Positive Tests
+// Creates a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_only_authorize_payment() {
+    let response = CONNECTOR
+        .authorize_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+// Captures a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_capture_authorized_payment() {
+    let response = CONNECTOR
+        .authorize_and_capture_payment(payment_method_details(), None, get_default_payment_info())
+        .await
+        .expect("Capture payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+// Synchronizes a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_sync_authorized_payment() {
+    let authorize_response = CONNECTOR
+        .authorize_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::Authorized,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("PSync response");
+    assert_eq!(response.status, enums::AttemptStatus::Authorized,);
+}
+
+// Voids a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_void_authorized_payment() {
+    let response = CONNECTOR
+        .authorize_and_void_payment(
+            payment_method_details(),
+            Some(types::PaymentsCancelData {
+                connector_transaction_id: String::from(""),
+                cancellation_reason: Some("requested_by_customer".to_string()),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Void payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Voided);
+}
+
+// Creates a payment using the automatic capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_make_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .unwrap();
+    assert_eq!(authorize_response.status, enums::AttemptStatus::Charged);
+}
+
+// Refunds a payment using the automatic capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_refund_auto_captured_payment() {
+    let response = CONNECTOR
+        .make_payment_and_refund(payment_method_details(), None, get_default_payment_info())
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success,
+    );
+}
+
+// Synchronizes a refund using the automatic capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_sync_refund() {
+    let refund_response = CONNECTOR
+        .make_payment_and_refund(payment_method_details(), None, get_default_payment_info())
+        .await
+        .unwrap();
+    let response = CONNECTOR
+        .rsync_retry_till_status_matches(
+            enums::RefundStatus::Success,
+            refund_response.response.unwrap().connector_refund_id,
+            None,
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success,
+    );
+}
@@ -37,6 +37,7 @@ mod fiserv;
 mod fiservemea;
 mod fiuu;
 mod forte;
+mod generichttp;
 mod globalpay;
 mod globepay;
 mod gocardless;
@@ -69,6 +70,7 @@ mod paypal;
 mod payu;
 mod placetopay;
 mod plaid;
+mod plugin;
 mod powertranz;
 #[cfg(feature = "dummy_connector")]
 mod prophetpay;
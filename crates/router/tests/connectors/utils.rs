@@ -407,6 +407,7 @@ pub trait ConnectorActions: Connector {
                 charges: None,
                 integrity_object: None,
                 refund_status: enums::RefundStatus::Pending,
+                line_items: None,
             }),
             payment_info,
         );
@@ -969,6 +970,7 @@ impl Default for PaymentAuthorizeType {
             integrity_object: None,
             merchant_order_reference_id: None,
             additional_payment_method_data: None,
+            currency_conversion: None,
             shipping_cost: None,
         };
         Self(data)
@@ -1055,6 +1057,7 @@ impl Default for PaymentRefundType {
             charges: None,
             integrity_object: None,
             refund_status: enums::RefundStatus::Pending,
+            line_items: None,
         };
         Self(data)
     }
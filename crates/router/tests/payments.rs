@@ -388,6 +388,7 @@ async fn payments_create_core() {
         disputes: None,
         attempts: None,
         captures: None,
+        status_history: None,
         mandate_data: None,
         setup_future_usage: None,
         off_session: None,
@@ -396,6 +397,7 @@ async fn payments_create_core() {
         payment_method: None,
         payment_method_data: None,
         payment_token: None,
+        vault_fallback_applied: None,
         shipping: None,
         billing: None,
         order_details: None,
@@ -450,6 +452,9 @@ async fn payments_create_core() {
         order_tax_amount: None,
         connector_mandate_id: None,
         shipping_cost: None,
+        presentment_currency: None,
+        presentment_amount: None,
+        exchange_rate: None,
     };
     let expected_response =
         services::ApplicationResponse::JsonWithHeaders((expected_response, vec![]));
@@ -650,6 +655,7 @@ async fn payments_create_core_adyen_no_redirect() {
             disputes: None,
             attempts: None,
             captures: None,
+            status_history: None,
             mandate_data: None,
             setup_future_usage: None,
             off_session: None,
@@ -658,6 +664,7 @@ async fn payments_create_core_adyen_no_redirect() {
             payment_method: None,
             payment_method_data: None,
             payment_token: None,
+            vault_fallback_applied: None,
             shipping: None,
             billing: None,
             order_details: None,
@@ -712,6 +719,9 @@ async fn payments_create_core_adyen_no_redirect() {
             order_tax_amount: None,
             connector_mandate_id: None,
             shipping_cost: None,
+            presentment_currency: None,
+            presentment_amount: None,
+            exchange_rate: None,
         },
         vec![],
     ));
@@ -149,6 +149,7 @@ async fn payments_create_core() {
         disputes: None,
         attempts: None,
         captures: None,
+        status_history: None,
         mandate_data: None,
         setup_future_usage: None,
         off_session: None,
@@ -157,6 +158,7 @@ async fn payments_create_core() {
         payment_method: None,
         payment_method_data: None,
         payment_token: None,
+        vault_fallback_applied: None,
         shipping: None,
         billing: None,
         order_details: None,
@@ -211,6 +213,9 @@ async fn payments_create_core() {
         order_tax_amount: None,
         connector_mandate_id: None,
         shipping_cost: None,
+        presentment_currency: None,
+        presentment_amount: None,
+        exchange_rate: None,
     };
 
     let expected_response =
@@ -420,6 +425,7 @@ async fn payments_create_core_adyen_no_redirect() {
             disputes: None,
             attempts: None,
             captures: None,
+            status_history: None,
             mandate_data: None,
             setup_future_usage: None,
             off_session: None,
@@ -428,6 +434,7 @@ async fn payments_create_core_adyen_no_redirect() {
             payment_method: None,
             payment_method_data: None,
             payment_token: None,
+            vault_fallback_applied: None,
             shipping: None,
             billing: None,
             order_details: None,
@@ -482,6 +489,9 @@ async fn payments_create_core_adyen_no_redirect() {
             order_tax_amount: None,
             connector_mandate_id: None,
             shipping_cost: None,
+            presentment_currency: None,
+            presentment_amount: None,
+            exchange_rate: None,
         },
         vec![],
     ));
@@ -62,6 +62,8 @@ pub enum Flow {
     OrganizationRetrieve,
     /// Organization update flow
     OrganizationUpdate,
+    /// Organization usage export flow
+    OrganizationUsageExport,
     /// Merchants account create flow.
     MerchantsAccountCreate,
     /// Merchants account retrieve flow.
@@ -76,8 +78,34 @@ pub enum Flow {
     MerchantConnectorsRetrieve,
     /// Merchant account list
     MerchantAccountList,
+    /// Merchant account search flow.
+    MerchantAccountSearch,
+    /// Merchant account usage retrieve flow.
+    MerchantUsageRetrieve,
+    /// Merchant account data retention purge flow.
+    MerchantDataRetentionPurge,
+    /// Merchant account publishable key rotation flow.
+    MerchantPublishableKeyRotate,
+    /// Merchant payload log retrieve flow.
+    MerchantPayloadLogRetrieve,
+    /// Merchant payload log list flow.
+    MerchantPayloadLogList,
+    /// Merchant config reconciliation flow.
+    MerchantConfigReconcile,
     /// Merchant Connectors update flow.
     MerchantConnectorsUpdate,
+    /// Merchant Connectors bulk enable/disable flow.
+    MerchantConnectorsBulkToggle,
+    /// Merchant Connectors incoming webhook health flow.
+    MerchantConnectorsWebhookHealth,
+    /// Merchant Connectors expiring credentials list flow.
+    MerchantConnectorsExpiringCredentialsList,
+    /// Connector maintenance window create flow.
+    ConnectorMaintenanceWindowCreate,
+    /// Connector maintenance window list flow.
+    ConnectorMaintenanceWindowList,
+    /// Connector maintenance window delete flow.
+    ConnectorMaintenanceWindowDelete,
     /// Merchant Connectors delete flow.
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
@@ -102,6 +130,14 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customer portal link create flow.
+    CustomerPortalLinkCreate,
+    /// Customer portal link render flow.
+    CustomerPortalLinkRender,
+    /// Customer portal payment method delete flow.
+    CustomerPortalPaymentMethodDelete,
+    /// Customer portal billing address update flow.
+    CustomerPortalBillingAddressUpdate,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -112,10 +148,44 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Installment plan create flow.
+    InstallmentPlanCreate,
+    /// Installment plan retrieve flow.
+    InstallmentPlanRetrieve,
+    /// Installment plan list flow.
+    InstallmentPlanList,
+    /// Installment plan cancel flow.
+    InstallmentPlanCancel,
+    /// Test clock create flow.
+    TestClockCreate,
+    /// Test clock retrieve flow.
+    TestClockRetrieve,
+    /// Test clock list flow.
+    TestClockList,
+    /// Test clock attach flow.
+    TestClockAttach,
+    /// Test clock detach flow.
+    TestClockDetach,
+    /// Test clock advance flow.
+    TestClockAdvance,
+    /// Test clock delete flow.
+    TestClockDelete,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
     PaymentMethodsMigrate,
+    /// Vault card without creating a payment flow
+    VaultTokenize,
+    /// Retrieve vaulted card by token flow
+    VaultDetokenize,
+    /// Share payment method with another merchant account of the organization flow
+    PaymentMethodsShare,
+    /// List shares of a payment method flow
+    PaymentMethodsShareList,
+    /// Revoke share of a payment method flow
+    PaymentMethodsShareRevoke,
+    /// List consent events of a payment method flow
+    PaymentMethodsConsentEventsList,
     /// Payment methods list flow.
     PaymentMethodsList,
     /// Payment method save flow
@@ -128,6 +198,8 @@ pub enum Flow {
     ListCountriesCurrencies,
     /// Payment method create collect link flow.
     PaymentMethodCollectLink,
+    /// Payment method collect link submit flow.
+    PaymentMethodCollectLinkSubmit,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -148,6 +220,10 @@ pub enum Flow {
     PaymentsConfirm,
     /// Payments capture flow.
     PaymentsCapture,
+    /// Payments capture on shipment flow.
+    PaymentsCaptureOnShipment,
+    /// Payments dcc offer flow.
+    PaymentsDccOffer,
     /// Payments cancel flow.
     PaymentsCancel,
     /// Payments approve flow.
@@ -156,6 +232,8 @@ pub enum Flow {
     PaymentsReject,
     /// Payments Session Token flow
     PaymentsSessionToken,
+    /// Payments Session Token pre-warm flow
+    PaymentsSessionTokenPrewarm,
     /// Payments start flow.
     PaymentsStart,
     /// Payments list flow.
@@ -193,6 +271,12 @@ pub enum Flow {
     PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    #[cfg(feature = "payouts")]
+    /// Payouts balance flow.
+    PayoutsBalance,
+    #[cfg(feature = "payouts")]
+    /// Payout attempts list flow.
+    PayoutAttemptsList,
     /// Payout link initiate flow
     PayoutLinkInitiate,
     /// Payments Redirect flow
@@ -269,6 +353,18 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// Admin API Key create flow
+    AdminApiKeyCreate,
+    /// Admin API Key retrieve flow
+    AdminApiKeyRetrieve,
+    /// Admin API Key list flow
+    AdminApiKeyList,
+    /// Admin API Key role assignment flow
+    AdminApiKeyRoleAssign,
+    /// Admin API Key revoke flow
+    AdminApiKeyRevoke,
+    /// Admin roles list flow
+    AdminRoleList,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
@@ -283,6 +379,10 @@ pub enum Flow {
     DeleteFile,
     /// Retrieve File flow
     RetrieveFile,
+    /// Create File Download Url flow
+    CreateFileDownloadUrl,
+    /// Download File flow
+    DownloadFile,
     /// Dispute Evidence submission flow
     DisputesEvidenceSubmit,
     /// Create Config Key flow
@@ -297,6 +397,8 @@ pub enum Flow {
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Invalidate the cached bank lists of a connector flow
+    BankListCacheInvalidate,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -307,6 +409,24 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment Link Deactivate flow
+    PaymentLinkDeactivate,
+    /// Payment Link Expiry Update flow
+    PaymentLinkExpiryUpdate,
+    /// Payment Link Regenerate flow
+    PaymentLinkRegenerate,
+    /// Payment Link Template Create flow
+    PaymentLinkTemplateCreate,
+    /// Payment Link Template Retrieve flow
+    PaymentLinkTemplateRetrieve,
+    /// Payment Link Template List flow
+    PaymentLinkTemplateList,
+    /// Payment Link Template Update flow
+    PaymentLinkTemplateUpdate,
+    /// Payment Link Template Usage List flow
+    PaymentLinkTemplateUsageList,
+    /// Payment Link Template Initiate flow
+    PaymentLinkTemplateInitiate,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -317,10 +437,60 @@ pub enum Flow {
     ProfileDelete,
     /// List all the profiles for a merchant
     ProfileList,
+    /// Retrieve the webhook signing secret of a profile
+    WebhookSecretRetrieve,
+    /// Generate a new webhook signing secret for a profile
+    WebhookSecretGenerate,
+    /// Rotate the webhook signing secret of a profile
+    WebhookSecretRotate,
+    /// Create an email template for a profile
+    EmailTemplateCreate,
+    /// Retrieve an email template of a profile
+    EmailTemplateRetrieve,
+    /// List all the email templates of a profile
+    EmailTemplateList,
+    /// Update an email template of a profile
+    EmailTemplateUpdate,
+    /// Delete an email template of a profile
+    EmailTemplateDelete,
+    /// Create a token for a dashboard widget embedded in the portal of a platform
+    EmbedTokenCreate,
+    /// Create a payment template for a profile
+    PaymentTemplateCreate,
+    /// Retrieve a payment template of a profile
+    PaymentTemplateRetrieve,
+    /// List all the payment templates of a profile
+    PaymentTemplateList,
+    /// Update a payment template of a profile
+    PaymentTemplateUpdate,
+    /// Delete a payment template of a profile
+    PaymentTemplateDelete,
+    /// Retrieve the balances of the ledger accounts of a profile
+    LedgerBalancesRetrieve,
+    /// Retrieve the statement of a ledger account of a profile
+    LedgerStatementRetrieve,
+    /// Allow a payout to be fulfilled regardless of the balance rules of its profile
+    PayoutBalanceOverrideCreate,
+    /// Revoke the override of the balance rules of the profile for a payout
+    PayoutBalanceOverrideDelete,
+    /// Retrieve the delivery of a link over a messaging channel
+    LinkDeliveryRetrieve,
+    /// List the deliveries of a link over messaging channels
+    LinkDeliveryList,
+    /// Status callback of the messaging provider for a link delivery
+    LinkDeliveryStatusCallback,
     /// Different verification flows
     Verification,
     /// Rust locker migration
     RustLockerMigration,
+    /// Schedule locker migration flow
+    ScheduleLockerMigration,
+    /// Retrieve locker migration status flow
+    RetrieveLockerMigrationStatus,
+    /// Schedule payment archival flow
+    SchedulePaymentArchival,
+    /// Retrieve payment archival status flow
+    RetrievePaymentArchivalStatus,
     /// Gsm Rule Creation flow
     GsmRuleCreate,
     /// Gsm Rule Retrieve flow
@@ -419,6 +589,10 @@ pub enum Flow {
     DeleteUserRole,
     /// Incremental Authorization flow
     PaymentsIncrementalAuthorization,
+    /// Invoice generation flow
+    PaymentsInvoiceGenerate,
+    /// Payments client secret rotation flow
+    PaymentsClientSecretRotate,
     /// Get action URL for connector onboarding
     GetActionUrl,
     /// Sync connector onboarding status
@@ -459,6 +633,20 @@ pub enum Flow {
     RecoveryCodesGenerate,
     /// Terminate two factor authentication
     TerminateTwoFactorAuth,
+    /// Begin WebAuthn registration
+    WebauthnRegisterBegin,
+    /// Finish WebAuthn registration
+    WebauthnRegisterFinish,
+    /// Begin WebAuthn authentication
+    WebauthnAuthenticateBegin,
+    /// Verify WebAuthn
+    WebauthnVerify,
+    /// List WebAuthn credentials
+    WebauthnCredentialsList,
+    /// Delete WebAuthn credential
+    WebauthnCredentialDelete,
+    /// Reset two factor authentication of a user
+    ResetTwoFactorAuth,
     /// Check 2FA status
     TwoFactorAuthStatus,
     /// Create user authentication method
@@ -469,6 +657,8 @@ pub enum Flow {
     ListUserAuthenticationMethods,
     /// Get sso auth url
     GetSsoAuthUrl,
+    /// Get sso auth url of the auth method configured for the email domain
+    GetSsoAuthUrlByEmail,
     /// Signin with SSO
     SignInWithSso,
     /// Auth Select
@@ -495,12 +685,42 @@ pub enum Flow {
     UpdateTheme,
     /// Delete theme
     DeleteTheme,
+    /// Create or update SCIM configuration
+    ScimConfigUpsert,
+    /// Retrieve SCIM configuration
+    ScimConfigRetrieve,
+    /// Delete SCIM configuration
+    ScimConfigDelete,
+    /// Rotate SCIM token
+    ScimTokenRotate,
+    /// Provision user through SCIM
+    ScimUserCreate,
+    /// Retrieve user through SCIM
+    ScimUserRetrieve,
+    /// List users through SCIM
+    ScimUserList,
+    /// Replace user through SCIM
+    ScimUserReplace,
+    /// Patch user through SCIM
+    ScimUserPatch,
+    /// Deprovision user through SCIM
+    ScimUserDelete,
+    /// Create group through SCIM
+    ScimGroupCreate,
+    /// Retrieve group through SCIM
+    ScimGroupRetrieve,
+    /// List groups through SCIM
+    ScimGroupList,
+    /// Patch group through SCIM
+    ScimGroupPatch,
     /// List initial webhook delivery attempts
     WebhookEventInitialDeliveryAttemptList,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Preview the outgoing webhook payload of a webhook event
+    WebhookEventPayloadPreview,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
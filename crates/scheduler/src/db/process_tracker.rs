@@ -62,6 +62,16 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_merchant_id_runners_time_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        runners: &[storage::ProcessTrackerRunner],
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -106,6 +116,29 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_processes_by_merchant_id_runners_time_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        runners: &[storage::ProcessTrackerRunner],
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_merchant_id_runners_time_status(
+            &conn,
+            merchant_id,
+            runners,
+            time_lower_limit,
+            time_upper_limit,
+            status,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -230,6 +263,19 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_processes_by_merchant_id_runners_time_status(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _runners: &[storage::ProcessTrackerRunner],
+        _time_lower_limit: PrimitiveDateTime,
+        _time_upper_limit: PrimitiveDateTime,
+        _status: storage_enums::ProcessTrackerStatus,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
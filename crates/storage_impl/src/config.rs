@@ -1,4 +1,6 @@
-use common_utils::DbConnectionParams;
+use std::collections::HashSet;
+
+use common_utils::{id_type, DbConnectionParams};
 use masking::Secret;
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -39,6 +41,23 @@ pub trait TenantConfig: Send + Sync {
     fn get_clickhouse_database(&self) -> &str;
 }
 
+/// Partitioning of the high volume tables, selected at deploy time. The tables are expected to be
+/// list partitioned by `merchant_id` before partitioning is enabled
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PartitioningConfig {
+    pub payment_attempt: TablePartitioningConfig,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct TablePartitioningConfig {
+    pub enabled: bool,
+    /// Merchants whose rows are isolated in a partition of their own, the rows of every other
+    /// merchant are stored in the default partition
+    pub isolated_merchants: HashSet<id_type::MerchantId>,
+}
+
 #[derive(Debug, serde::Deserialize, Clone, Copy, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum QueueStrategy {
@@ -72,3 +91,30 @@ impl Default for Database {
         }
     }
 }
+
+/// Retries of the writes which fail due to a conflict with a concurrent write, which are
+/// serialization failures and deadlocks in the database, and failed writes to the KV store
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct ConflictRetryConfig {
+    pub enabled: bool,
+    /// The most times a write is retried, after the first attempt
+    pub max_retries: u8,
+    /// The delay before the first retry, which is doubled for every subsequent retry, in
+    /// milliseconds. A random delay up to it is waited for, so that the conflicting writes are not
+    /// retried together.
+    pub base_delay_in_millis: u64,
+    /// The highest delay before a retry, in milliseconds
+    pub max_delay_in_millis: u64,
+}
+
+impl Default for ConflictRetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_retries: 3,
+            base_delay_in_millis: 20,
+            max_delay_in_millis: 500,
+        }
+    }
+}
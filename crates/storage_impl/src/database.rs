@@ -1 +1,2 @@
+pub mod partitioning;
 pub mod store;
@@ -0,0 +1,151 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::errors::{StorageError, StorageResult};
+use router_env::logger;
+
+use super::store::{PgPool, PgPooledConn};
+use crate::config::{PartitioningConfig, TablePartitioningConfig};
+
+const PAYMENT_ATTEMPT_TABLE: &str = "payment_attempt";
+
+/// Maximum length of an identifier in PostgreSQL, longer identifiers are truncated silently
+const MAX_IDENTIFIER_LENGTH: usize = 63;
+
+/// Whether the table is partitioned, so that partitions can be attached to it
+const IS_PARTITIONED_TABLE_QUERY: &str = "SELECT EXISTS (SELECT 1 FROM pg_partitioned_table \
+    WHERE partrelid = to_regclass(quote_ident($1))) AS is_partitioned";
+
+/// The statement creating the partition, if it does not exist. The identifiers and the literal are
+/// quoted by the database, so no value from the configuration is interpolated into the statement
+const CREATE_PARTITION_STATEMENT_QUERY: &str = "SELECT format('CREATE TABLE IF NOT EXISTS %I \
+    PARTITION OF %I FOR VALUES IN (%L)', $1, $2, $3) AS statement \
+    WHERE to_regclass(quote_ident($1)) IS NULL";
+
+#[derive(diesel::QueryableByName)]
+struct PartitionedTable {
+    #[diesel(sql_type = diesel::sql_types::Bool)]
+    is_partitioned: bool,
+}
+
+#[derive(diesel::QueryableByName)]
+struct PartitionStatement {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    statement: String,
+}
+
+/// Creates the missing partitions of the isolated merchants of every partitioned table. The
+/// partitions are created in the schema of the tenant the pool connects to, the existing
+/// partitions are left untouched, so that no DDL is run once the partitions exist.
+///
+/// A partition cannot be created while the default partition holds rows of the merchant, such
+/// rows have to be moved out of the default partition before the merchant is isolated. Failures
+/// are logged rather than failing the startup, since the rows of the merchant are stored in the
+/// default partition until its partition is created.
+pub async fn create_isolated_merchant_partitions(
+    pool: &PgPool,
+    partitioning_config: &PartitioningConfig,
+) {
+    if let Err(error) = create_table_partitions(
+        pool,
+        PAYMENT_ATTEMPT_TABLE,
+        &partitioning_config.payment_attempt,
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            table_name = PAYMENT_ATTEMPT_TABLE,
+            "Failed to create the partitions of the isolated merchants"
+        );
+    }
+}
+
+async fn create_table_partitions(
+    pool: &PgPool,
+    table_name: &str,
+    table_config: &TablePartitioningConfig,
+) -> StorageResult<()> {
+    if !table_config.enabled || table_config.isolated_merchants.is_empty() {
+        return Ok(());
+    }
+
+    let conn = pool
+        .get()
+        .await
+        .change_context(StorageError::DatabaseConnectionError)?;
+
+    let partitioned_table = diesel::sql_query(IS_PARTITIONED_TABLE_QUERY)
+        .bind::<diesel::sql_types::Text, _>(table_name.to_owned())
+        .get_result_async::<PartitionedTable>(&*conn)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable_lazy(|| format!("Failed to find whether {table_name} is partitioned"))?;
+    if !partitioned_table.is_partitioned {
+        return Err(report!(StorageError::InitializationError)).attach_printable(format!(
+            "Table {table_name} is not partitioned by merchant_id"
+        ));
+    }
+
+    for merchant_id in &table_config.isolated_merchants {
+        if let Err(error) = create_partition(&conn, table_name, merchant_id).await {
+            logger::error!(
+                ?error,
+                ?merchant_id,
+                "Failed to create the partition of the isolated merchant"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_partition(
+    conn: &PgPooledConn,
+    table_name: &str,
+    merchant_id: &id_type::MerchantId,
+) -> StorageResult<()> {
+    let partition_name = get_partition_name(table_name, merchant_id)?;
+
+    let statement = diesel::sql_query(CREATE_PARTITION_STATEMENT_QUERY)
+        .bind::<diesel::sql_types::Text, _>(partition_name.clone())
+        .bind::<diesel::sql_types::Text, _>(table_name.to_owned())
+        .bind::<diesel::sql_types::Text, _>(merchant_id.get_string_repr().to_owned())
+        .get_results_async::<PartitionStatement>(conn)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable_lazy(|| format!("Failed to look up partition {partition_name}"))?
+        .into_iter()
+        .next();
+    let Some(PartitionStatement { statement }) = statement else {
+        return Ok(());
+    };
+    logger::info!(%statement, "Creating partition for isolated merchant");
+
+    diesel::sql_query(statement)
+        .execute_async(conn)
+        .await
+        .change_context(StorageError::InitializationError)
+        .attach_printable_lazy(|| {
+            format!("Failed to create partition {partition_name} of {table_name}")
+        })?;
+
+    Ok(())
+}
+
+/// Longer identifiers would be truncated by PostgreSQL, which could map merchants to the same
+/// partition
+fn get_partition_name(
+    table_name: &str,
+    merchant_id: &id_type::MerchantId,
+) -> StorageResult<String> {
+    let partition_name = format!("{table_name}_{}", merchant_id.get_string_repr());
+
+    if partition_name.len() > MAX_IDENTIFIER_LENGTH {
+        return Err(report!(StorageError::InitializationError)).attach_printable(format!(
+            "Partition name {partition_name} exceeds {MAX_IDENTIFIER_LENGTH} characters"
+        ));
+    }
+
+    Ok(partition_name)
+}
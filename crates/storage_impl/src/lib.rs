@@ -21,7 +21,9 @@ pub mod payments;
 pub mod payouts;
 pub mod redis;
 pub mod refund;
+mod retry;
 mod reverse_lookup;
+pub mod status_history;
 mod utils;
 
 use common_utils::errors::CustomResult;
@@ -41,6 +43,7 @@ pub struct RouterStore<T: DatabaseStore> {
     db_store: T,
     cache_store: Arc<RedisStore>,
     master_encryption_key: StrongSecret<Vec<u8>>,
+    conflict_retry_config: config::ConflictRetryConfig,
     pub request_id: Option<String>,
 }
 
@@ -120,6 +123,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            conflict_retry_config: config::ConflictRetryConfig::default(),
             request_id: None,
         })
     }
@@ -140,6 +144,17 @@ impl<T: DatabaseStore> RouterStore<T> {
         &self.master_encryption_key
     }
 
+    pub fn set_conflict_retry_config(
+        &mut self,
+        conflict_retry_config: config::ConflictRetryConfig,
+    ) {
+        self.conflict_retry_config = conflict_retry_config;
+    }
+
+    pub fn get_conflict_retry_config(&self) -> &config::ConflictRetryConfig {
+        &self.conflict_retry_config
+    }
+
     /// # Panics
     ///
     /// Will panic if `CONNECTOR_AUTH_FILE_PATH` is not set
@@ -159,6 +174,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store: Arc::new(cache_store),
             master_encryption_key: encryption_key,
+            conflict_retry_config: config::ConflictRetryConfig::default(),
             request_id: None,
         })
     }
@@ -234,6 +250,10 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         self.router_store.master_key()
     }
 
+    pub fn get_conflict_retry_config(&self) -> &config::ConflictRetryConfig {
+        self.router_store.get_conflict_retry_config()
+    }
+
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
@@ -12,6 +12,10 @@ counter_metric!(KV_PUSHED_TO_DRAINER, GLOBAL_METER);
 counter_metric!(KV_FAILED_TO_PUSH_TO_DRAINER, GLOBAL_METER);
 counter_metric!(KV_SOFT_KILL_ACTIVE_UPDATE, GLOBAL_METER);
 
+// Metrics for retries of writes conflicting with concurrent writes
+counter_metric!(WRITE_CONFLICT_RETRY_COUNT, GLOBAL_METER);
+counter_metric!(WRITE_CONFLICT_RETRIES_EXHAUSTED, GLOBAL_METER);
+
 // Metrics for In-memory cache
 gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
@@ -35,6 +35,7 @@ pub struct MockDb {
     pub processes: Arc<Mutex<Vec<store::ProcessTracker>>>,
     pub redis: Arc<RedisStore>,
     pub api_keys: Arc<Mutex<Vec<store::ApiKey>>>,
+    pub admin_api_keys: Arc<Mutex<Vec<store::admin_api_key::AdminApiKey>>>,
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
@@ -61,6 +62,7 @@ pub struct MockDb {
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
+    pub ledger_entries: Arc<Mutex<Vec<store::ledger_entry::LedgerEntry>>>,
 }
 
 impl MockDb {
@@ -82,6 +84,7 @@ impl MockDb {
                     .change_context(StorageError::InitializationError)?,
             ),
             api_keys: Default::default(),
+            admin_api_keys: Default::default(),
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
@@ -107,6 +110,7 @@ impl MockDb {
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
             themes: Default::default(),
+            ledger_entries: Default::default(),
         })
     }
 }
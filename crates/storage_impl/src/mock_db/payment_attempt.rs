@@ -195,6 +195,14 @@ impl PaymentAttemptInterface for MockDb {
             organization_id: payment_attempt.organization_id,
             profile_id: payment_attempt.profile_id,
             connector_mandate_detail: payment_attempt.connector_mandate_detail,
+            connector_response_codes: None,
+            avs_result: None,
+            cvv_result: None,
+            presentment_currency: payment_attempt.presentment_currency,
+            presentment_amount: payment_attempt.presentment_amount,
+            exchange_rate: payment_attempt.exchange_rate,
+            dcc_details: None,
+            update_version: 0,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -238,6 +246,33 @@ impl PaymentAttemptInterface for MockDb {
         Ok(item.clone())
     }
 
+    #[cfg(feature = "v1")]
+    async fn update_payment_attempt_with_attempt_id_if_unmodified(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<PaymentAttempt>, StorageError> {
+        let mut payment_attempts = self.payment_attempts.lock().await;
+
+        let item = payment_attempts
+            .iter_mut()
+            .find(|item| item.attempt_id == this.attempt_id)
+            .unwrap();
+
+        if item.update_version != this.update_version {
+            return Ok(None);
+        }
+
+        *item = PaymentAttempt::from_storage_model(
+            payment_attempt
+                .to_storage_model()
+                .apply_changeset(this.to_storage_model()),
+        );
+
+        Ok(Some(item.clone()))
+    }
+
     #[cfg(feature = "v2")]
     async fn update_payment_attempt(
         &self,
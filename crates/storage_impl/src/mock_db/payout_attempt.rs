@@ -55,6 +55,16 @@ impl PayoutAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payout_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutAttempt>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     async fn get_filters_for_payouts(
         &self,
         _payouts: &[Payouts],
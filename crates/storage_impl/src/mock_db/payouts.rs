@@ -52,6 +52,17 @@ impl PayoutsInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn filter_payouts_by_profile_id_source_currency_status(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _source_currency: storage_enums::Currency,
+        _status: Vec<storage_enums::PayoutStatus>,
+    ) -> CustomResult<Vec<Payouts>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
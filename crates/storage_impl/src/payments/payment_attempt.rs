@@ -5,6 +5,8 @@ use common_utils::{
     fallback_reverse_lookup_not_found,
     types::{ConnectorTransactionId, ConnectorTransactionIdTrait},
 };
+#[cfg(feature = "v1")]
+use diesel_models::status_history::StatusHistoryNew;
 use diesel_models::{
     enums::{
         MandateAmountData as DieselMandateAmountData, MandateDataType as DieselMandateType,
@@ -36,11 +38,17 @@ use hyperswitch_domain_models::{
 use redis_interface::HsetnxReply;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use crate::status_history::{
+    push_status_transition_to_drainer, record_status_transition, CREATE_TRIGGER,
+};
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
-    redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+    redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey, VersionCheck,
+    },
     utils::{pg_connection_read, pg_connection_write, try_redis_get_else_try_database_get},
     DataModelExt, DatabaseStore, KVRouterStore, RouterStore,
 };
@@ -55,7 +63,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
-        payment_attempt
+        let payment_attempt = payment_attempt
             .to_storage_model()
             .insert(&conn)
             .await
@@ -63,7 +71,10 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
                 let new_err = diesel_error_to_data_error(er.current_context());
                 er.change_context(new_err)
             })
-            .map(PaymentAttempt::from_storage_model)
+            .map(PaymentAttempt::from_storage_model)?;
+        record_payment_attempt_status_transition(self, None, &payment_attempt, CREATE_TRIGGER)
+            .await;
+        Ok(payment_attempt)
     }
 
     #[cfg(feature = "v2")]
@@ -104,14 +115,59 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
-        this.to_storage_model()
-            .update_with_attempt_id(&conn, payment_attempt.to_storage_model())
+        let previous_status = this.status;
+        let payment_attempt_update = payment_attempt.to_storage_model();
+        let trigger: &'static str = (&payment_attempt_update).into();
+        let payment_attempt = this
+            .to_storage_model()
+            .update_with_attempt_id(&conn, payment_attempt_update)
             .await
             .map_err(|er| {
                 let new_err = diesel_error_to_data_error(er.current_context());
                 er.change_context(new_err)
             })
-            .map(PaymentAttempt::from_storage_model)
+            .map(PaymentAttempt::from_storage_model)?;
+        record_payment_attempt_status_transition(
+            self,
+            Some(previous_status),
+            &payment_attempt,
+            trigger,
+        )
+        .await;
+        Ok(payment_attempt)
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_payment_attempt_with_attempt_id_if_unmodified(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        let previous_status = this.status;
+        let payment_attempt_update = payment_attempt.to_storage_model();
+        let trigger: &'static str = (&payment_attempt_update).into();
+        let payment_attempt = this
+            .to_storage_model()
+            .update_with_attempt_id_if_unmodified(&conn, payment_attempt_update)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })?
+            .map(PaymentAttempt::from_storage_model);
+        if let Some(payment_attempt) = payment_attempt.as_ref() {
+            record_payment_attempt_status_transition(
+                self,
+                Some(previous_status),
+                payment_attempt,
+                trigger,
+            )
+            .await;
+        }
+        Ok(payment_attempt)
     }
 
     #[cfg(feature = "v2")]
@@ -564,6 +620,14 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     organization_id: payment_attempt.organization_id.clone(),
                     profile_id: payment_attempt.profile_id.clone(),
                     connector_mandate_detail: payment_attempt.connector_mandate_detail.clone(),
+                    connector_response_codes: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    presentment_currency: payment_attempt.presentment_currency,
+                    presentment_amount: payment_attempt.presentment_amount,
+                    exchange_rate: payment_attempt.exchange_rate.clone(),
+                    dcc_details: None,
+                    update_version: 0,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -609,7 +673,16 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         key: Some(key_str),
                     }
                     .into()),
-                    Ok(HsetnxReply::KeySet) => Ok(created_attempt),
+                    Ok(HsetnxReply::KeySet) => {
+                        push_payment_attempt_status_transition_to_drainer(
+                            self,
+                            None,
+                            &created_attempt,
+                            CREATE_TRIGGER,
+                        )
+                        .await;
+                        Ok(created_attempt)
+                    }
                     Err(error) => Err(error.change_context(errors::StorageError::KVError)),
                 }
             }
@@ -652,7 +725,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentAttempt>(
             self,
             storage_scheme,
-            Op::Update(key.clone(), &field, Some(&this.updated_by)),
+            Op::Update(key, &field, Some(&this.updated_by)),
         ))
         .await;
         match storage_scheme {
@@ -662,100 +735,52 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     .await
             }
             MerchantStorageScheme::RedisKv => {
-                let key_str = key.to_string();
-                let old_connector_transaction_id = &this.get_connector_payment_id();
-                let old_preprocessing_id = &this.preprocessing_step_id;
-                let updated_attempt = PaymentAttempt::from_storage_model(
-                    payment_attempt
-                        .clone()
-                        .to_storage_model()
-                        .apply_changeset(this.clone().to_storage_model()),
-                );
-                // Check for database presence as well Maybe use a read replica here ?
-                let redis_value = serde_json::to_string(&updated_attempt)
-                    .change_context(errors::StorageError::KVError)?;
-
-                let redis_entry = kv::TypedSql {
-                    op: kv::DBOperation::Update {
-                        updatable: Box::new(kv::Updateable::PaymentAttemptUpdate(Box::new(
-                            kv::PaymentAttemptUpdateMems {
-                                orig: this.clone().to_storage_model(),
-                                update_data: payment_attempt.to_storage_model(),
-                            },
-                        ))),
-                    },
-                };
-
-                match (
-                    old_connector_transaction_id,
-                    &updated_attempt.get_connector_payment_id(),
-                ) {
-                    (None, Some(connector_transaction_id)) => {
-                        add_connector_txn_id_to_reverse_lookup(
-                            self,
-                            key_str.as_str(),
-                            &this.merchant_id,
-                            updated_attempt.attempt_id.as_str(),
-                            connector_transaction_id,
-                            storage_scheme,
-                        )
-                        .await?;
-                    }
-                    (Some(old_connector_transaction_id), Some(connector_transaction_id)) => {
-                        if old_connector_transaction_id.ne(connector_transaction_id) {
-                            add_connector_txn_id_to_reverse_lookup(
-                                self,
-                                key_str.as_str(),
-                                &this.merchant_id,
-                                updated_attempt.attempt_id.as_str(),
-                                connector_transaction_id,
-                                storage_scheme,
-                            )
-                            .await?;
-                        }
-                    }
-                    (_, _) => {}
-                }
-
-                match (old_preprocessing_id, &updated_attempt.preprocessing_step_id) {
-                    (None, Some(preprocessing_id)) => {
-                        add_preprocessing_id_to_reverse_lookup(
-                            self,
-                            key_str.as_str(),
-                            &this.merchant_id,
-                            updated_attempt.attempt_id.as_str(),
-                            preprocessing_id.as_str(),
-                            storage_scheme,
-                        )
-                        .await?;
-                    }
-                    (Some(old_preprocessing_id), Some(preprocessing_id)) => {
-                        if old_preprocessing_id.ne(preprocessing_id) {
-                            add_preprocessing_id_to_reverse_lookup(
-                                self,
-                                key_str.as_str(),
-                                &this.merchant_id,
-                                updated_attempt.attempt_id.as_str(),
-                                preprocessing_id.as_str(),
-                                storage_scheme,
-                            )
-                            .await?;
-                        }
-                    }
-                    (_, _) => {}
-                }
+                update_payment_attempt_in_redis(self, this, payment_attempt, storage_scheme, false)
+                    .await?
+                    .ok_or(errors::StorageError::KVError)
+                    .attach_printable("Unconditional update of the attempt was not applied")
+            }
+        }
+    }
 
-                Box::pin(kv_wrapper::<(), _, _>(
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_payment_attempt_with_attempt_id_if_unmodified(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PaymentAttempt>, errors::StorageError> {
+        let key = PartitionKey::MerchantIdPaymentId {
+            merchant_id: &this.merchant_id,
+            payment_id: &this.payment_id,
+        };
+        let field = format!("pa_{}", this.attempt_id);
+        let decided_storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentAttempt>(
+            self,
+            storage_scheme,
+            Op::Update(key, &field, Some(&this.updated_by)),
+        ))
+        .await;
+        match decided_storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .update_payment_attempt_with_attempt_id_if_unmodified(
+                        this,
+                        payment_attempt,
+                        decided_storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                update_payment_attempt_in_redis(
                     self,
-                    KvOperation::Hset::<DieselPaymentAttempt>((&field, redis_value), redis_entry),
-                    key,
-                ))
+                    this,
+                    payment_attempt,
+                    decided_storage_scheme,
+                    true,
+                )
                 .await
-                .change_context(errors::StorageError::KVError)?
-                .try_into_hset()
-                .change_context(errors::StorageError::KVError)?;
-
-                Ok(updated_attempt)
             }
         }
     }
@@ -1511,6 +1536,14 @@ impl DataModelExt for PaymentAttempt {
             shipping_cost: self.net_amount.get_shipping_cost(),
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             connector_mandate_detail: self.connector_mandate_detail,
+            connector_response_codes: self.connector_response_codes,
+            avs_result: self.avs_result,
+            cvv_result: self.cvv_result,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            exchange_rate: self.exchange_rate,
+            dcc_details: self.dcc_details,
+            update_version: self.update_version,
         }
     }
 
@@ -1587,6 +1620,14 @@ impl DataModelExt for PaymentAttempt {
             organization_id: storage_model.organization_id,
             profile_id: storage_model.profile_id,
             connector_mandate_detail: storage_model.connector_mandate_detail,
+            connector_response_codes: storage_model.connector_response_codes,
+            avs_result: storage_model.avs_result,
+            cvv_result: storage_model.cvv_result,
+            presentment_currency: storage_model.presentment_currency,
+            presentment_amount: storage_model.presentment_amount,
+            exchange_rate: storage_model.exchange_rate,
+            dcc_details: storage_model.dcc_details,
+            update_version: storage_model.update_version,
         }
     }
 }
@@ -1670,6 +1711,9 @@ impl DataModelExt for PaymentAttemptNew {
             shipping_cost: self.net_amount.get_shipping_cost(),
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             connector_mandate_detail: self.connector_mandate_detail,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            exchange_rate: self.exchange_rate,
         }
     }
 
@@ -1742,6 +1786,9 @@ impl DataModelExt for PaymentAttemptNew {
             organization_id: storage_model.organization_id,
             profile_id: storage_model.profile_id,
             connector_mandate_detail: storage_model.connector_mandate_detail,
+            presentment_currency: storage_model.presentment_currency,
+            presentment_amount: storage_model.presentment_amount,
+            exchange_rate: storage_model.exchange_rate,
         }
     }
 }
@@ -1799,3 +1846,201 @@ async fn add_preprocessing_id_to_reverse_lookup<T: DatabaseStore>(
         .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
         .await
 }
+
+/// Updates the attempt in redis, and pushes the update to the drainer stream. If
+/// `check_update_version` is set, the attempt is updated only if its `update_version` in redis is
+/// still that of `this`, returning `None` if the attempt was modified by a concurrent update.
+#[cfg(feature = "v1")]
+async fn update_payment_attempt_in_redis<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    this: PaymentAttempt,
+    payment_attempt: PaymentAttemptUpdate,
+    storage_scheme: MerchantStorageScheme,
+    check_update_version: bool,
+) -> error_stack::Result<Option<PaymentAttempt>, errors::StorageError> {
+    let key = PartitionKey::MerchantIdPaymentId {
+        merchant_id: &this.merchant_id,
+        payment_id: &this.payment_id,
+    };
+    let field = format!("pa_{}", this.attempt_id);
+    let key_str = key.to_string();
+    let old_connector_transaction_id = &this.get_connector_payment_id();
+    let old_preprocessing_id = &this.preprocessing_step_id;
+    let payment_attempt_update = payment_attempt.to_storage_model();
+    let trigger: &'static str = (&payment_attempt_update).into();
+    let updated_attempt = PaymentAttempt::from_storage_model(
+        payment_attempt_update
+            .clone()
+            .apply_changeset(this.clone().to_storage_model()),
+    );
+    // Check for database presence as well Maybe use a read replica here ?
+    let redis_value =
+        serde_json::to_string(&updated_attempt).change_context(errors::StorageError::KVError)?;
+
+    let redis_entry = kv::TypedSql {
+        op: kv::DBOperation::Update {
+            updatable: Box::new(kv::Updateable::PaymentAttemptUpdate(Box::new(
+                kv::PaymentAttemptUpdateMems {
+                    orig: this.clone().to_storage_model(),
+                    update_data: payment_attempt_update,
+                },
+            ))),
+        },
+    };
+
+    if check_update_version {
+        let is_updated = Box::pin(kv_wrapper::<(), _, _>(
+            store,
+            KvOperation::HsetIfVersionMatches::<DieselPaymentAttempt>(
+                (&field, redis_value),
+                VersionCheck {
+                    version_field: "update_version",
+                    expected_version: this.update_version.into(),
+                },
+                redis_entry,
+            ),
+            key,
+        ))
+        .await
+        .change_context(errors::StorageError::KVError)?
+        .try_into_hsetifversionmatches()
+        .change_context(errors::StorageError::KVError)?;
+        if !is_updated {
+            return Ok(None);
+        }
+    } else {
+        Box::pin(kv_wrapper::<(), _, _>(
+            store,
+            KvOperation::Hset::<DieselPaymentAttempt>((&field, redis_value), redis_entry),
+            key,
+        ))
+        .await
+        .change_context(errors::StorageError::KVError)?
+        .try_into_hset()
+        .change_context(errors::StorageError::KVError)?;
+    }
+
+    match (
+        old_connector_transaction_id,
+        &updated_attempt.get_connector_payment_id(),
+    ) {
+        (None, Some(connector_transaction_id)) => {
+            add_connector_txn_id_to_reverse_lookup(
+                store,
+                key_str.as_str(),
+                &this.merchant_id,
+                updated_attempt.attempt_id.as_str(),
+                connector_transaction_id,
+                storage_scheme,
+            )
+            .await?;
+        }
+        (Some(old_connector_transaction_id), Some(connector_transaction_id)) => {
+            if old_connector_transaction_id.ne(connector_transaction_id) {
+                add_connector_txn_id_to_reverse_lookup(
+                    store,
+                    key_str.as_str(),
+                    &this.merchant_id,
+                    updated_attempt.attempt_id.as_str(),
+                    connector_transaction_id,
+                    storage_scheme,
+                )
+                .await?;
+            }
+        }
+        (_, _) => {}
+    }
+
+    match (old_preprocessing_id, &updated_attempt.preprocessing_step_id) {
+        (None, Some(preprocessing_id)) => {
+            add_preprocessing_id_to_reverse_lookup(
+                store,
+                key_str.as_str(),
+                &this.merchant_id,
+                updated_attempt.attempt_id.as_str(),
+                preprocessing_id.as_str(),
+                storage_scheme,
+            )
+            .await?;
+        }
+        (Some(old_preprocessing_id), Some(preprocessing_id)) => {
+            if old_preprocessing_id.ne(preprocessing_id) {
+                add_preprocessing_id_to_reverse_lookup(
+                    store,
+                    key_str.as_str(),
+                    &this.merchant_id,
+                    updated_attempt.attempt_id.as_str(),
+                    preprocessing_id.as_str(),
+                    storage_scheme,
+                )
+                .await?;
+            }
+        }
+        (_, _) => {}
+    }
+
+    push_payment_attempt_status_transition_to_drainer(
+        store,
+        Some(this.status),
+        &updated_attempt,
+        trigger,
+    )
+    .await;
+
+    Ok(Some(updated_attempt))
+}
+
+#[cfg(feature = "v1")]
+fn payment_attempt_status_transition(
+    previous_status: Option<diesel_models::enums::AttemptStatus>,
+    payment_attempt: &PaymentAttempt,
+    trigger: &str,
+) -> Option<StatusHistoryNew> {
+    (previous_status != Some(payment_attempt.status)).then(|| StatusHistoryNew {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        entity_type: diesel_models::enums::StatusHistoryEntityType::Payment,
+        entity_id: payment_attempt.payment_id.get_string_repr().to_owned(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        previous_status: previous_status.map(|status| status.to_string()),
+        status: payment_attempt.status.to_string(),
+        trigger: trigger.to_owned(),
+        connector: payment_attempt.connector.clone(),
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+#[cfg(feature = "v1")]
+async fn record_payment_attempt_status_transition<T: DatabaseStore>(
+    store: &T,
+    previous_status: Option<diesel_models::enums::AttemptStatus>,
+    payment_attempt: &PaymentAttempt,
+    trigger: &str,
+) {
+    if let Some(status_history) =
+        payment_attempt_status_transition(previous_status, payment_attempt, trigger)
+    {
+        record_status_transition(store, status_history).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn push_payment_attempt_status_transition_to_drainer<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    previous_status: Option<diesel_models::enums::AttemptStatus>,
+    payment_attempt: &PaymentAttempt,
+    trigger: &str,
+) {
+    if let Some(status_history) =
+        payment_attempt_status_transition(previous_status, payment_attempt, trigger)
+    {
+        push_status_transition_to_drainer::<DieselPaymentAttempt, _>(
+            store,
+            status_history,
+            PartitionKey::MerchantIdPaymentId {
+                merchant_id: &payment_attempt.merchant_id,
+                payment_id: &payment_attempt.payment_id,
+            },
+        )
+        .await
+    }
+}
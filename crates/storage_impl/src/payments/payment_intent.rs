@@ -22,6 +22,8 @@ use diesel_models::schema_v2::{
     payment_attempt::{self as payment_attempt_schema, dsl as pa_dsl},
     payment_intent::dsl as pi_dsl,
 };
+#[cfg(feature = "v1")]
+use diesel_models::status_history::StatusHistoryNew;
 use diesel_models::{
     enums::MerchantStorageScheme,
     kv,
@@ -50,6 +52,10 @@ use router_env::{instrument, tracing};
 
 #[cfg(feature = "olap")]
 use crate::connection;
+#[cfg(feature = "v1")]
+use crate::status_history::{
+    push_status_transition_to_drainer, record_status_transition, CREATE_TRIGGER,
+};
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
@@ -133,7 +139,16 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                         key: Some(key_str),
                     }
                     .into()),
-                    Ok(HsetnxReply::KeySet) => Ok(payment_intent),
+                    Ok(HsetnxReply::KeySet) => {
+                        push_payment_intent_status_transition_to_drainer(
+                            self,
+                            None,
+                            &payment_intent,
+                            CREATE_TRIGGER,
+                        )
+                        .await;
+                        Ok(payment_intent)
+                    }
                     Err(error) => Err(error.change_context(StorageError::KVError)),
                 }
             }
@@ -205,6 +220,8 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                 let key_str = key.to_string();
 
                 let diesel_intent_update = DieselPaymentIntentUpdate::from(payment_intent_update);
+                let trigger: &'static str = (&diesel_intent_update).into();
+                let previous_status = this.status;
                 let origin_diesel_intent = this
                     .convert()
                     .await
@@ -249,6 +266,14 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                 .await
                 .change_context(StorageError::DecryptionError)?;
 
+                push_payment_intent_status_transition_to_drainer(
+                    self,
+                    Some(previous_status),
+                    &payment_intent,
+                    trigger,
+                )
+                .await;
+
                 Ok(payment_intent)
             }
         }
@@ -485,14 +510,17 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 er.change_context(new_err)
             })?;
 
-        PaymentIntent::convert_back(
+        let payment_intent = PaymentIntent::convert_back(
             state,
             diesel_payment_intent,
             merchant_key_store.key.get_inner(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
-        .change_context(StorageError::DecryptionError)
+        .change_context(StorageError::DecryptionError)?;
+        #[cfg(feature = "v1")]
+        record_payment_intent_status_transition(self, None, &payment_intent, CREATE_TRIGGER).await;
+        Ok(payment_intent)
     }
 
     #[cfg(feature = "v1")]
@@ -507,6 +535,8 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<PaymentIntent, StorageError> {
         let conn = pg_connection_write(self).await?;
         let diesel_payment_intent_update = DieselPaymentIntentUpdate::from(payment_intent);
+        let trigger: &'static str = (&diesel_payment_intent_update).into();
+        let previous_status = this.status;
 
         let diesel_payment_intent = this
             .convert()
@@ -519,14 +549,22 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 er.change_context(new_err)
             })?;
 
-        PaymentIntent::convert_back(
+        let payment_intent = PaymentIntent::convert_back(
             state,
             diesel_payment_intent,
             merchant_key_store.key.get_inner(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
-        .change_context(StorageError::DecryptionError)
+        .change_context(StorageError::DecryptionError)?;
+        record_payment_intent_status_transition(
+            self,
+            Some(previous_status),
+            &payment_intent,
+            trigger,
+        )
+        .await;
+        Ok(payment_intent)
     }
 
     #[cfg(feature = "v2")]
@@ -1110,3 +1148,58 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         })
     }
 }
+
+#[cfg(feature = "v1")]
+fn payment_intent_status_transition(
+    previous_status: Option<diesel_models::enums::IntentStatus>,
+    payment_intent: &PaymentIntent,
+    trigger: &str,
+) -> Option<StatusHistoryNew> {
+    (previous_status != Some(payment_intent.status)).then(|| StatusHistoryNew {
+        merchant_id: payment_intent.merchant_id.clone(),
+        entity_type: diesel_models::enums::StatusHistoryEntityType::Payment,
+        entity_id: payment_intent.get_id().get_string_repr().to_owned(),
+        attempt_id: None,
+        previous_status: previous_status.map(|status| status.to_string()),
+        status: payment_intent.status.to_string(),
+        trigger: trigger.to_owned(),
+        connector: None,
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+#[cfg(feature = "v1")]
+async fn record_payment_intent_status_transition<T: DatabaseStore>(
+    store: &T,
+    previous_status: Option<diesel_models::enums::IntentStatus>,
+    payment_intent: &PaymentIntent,
+    trigger: &str,
+) {
+    if let Some(status_history) =
+        payment_intent_status_transition(previous_status, payment_intent, trigger)
+    {
+        record_status_transition(store, status_history).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn push_payment_intent_status_transition_to_drainer<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    previous_status: Option<diesel_models::enums::IntentStatus>,
+    payment_intent: &PaymentIntent,
+    trigger: &str,
+) {
+    if let Some(status_history) =
+        payment_intent_status_transition(previous_status, payment_intent, trigger)
+    {
+        push_status_transition_to_drainer::<DieselPaymentIntent, _>(
+            store,
+            status_history,
+            PartitionKey::MerchantIdPaymentId {
+                merchant_id: &payment_intent.merchant_id,
+                payment_id: payment_intent.get_id(),
+            },
+        )
+        .await
+    }
+}
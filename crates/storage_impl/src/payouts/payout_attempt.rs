@@ -10,6 +10,7 @@ use diesel_models::{
         PayoutAttemptUpdate as DieselPayoutAttemptUpdate,
     },
     reverse_lookup::ReverseLookup,
+    status_history::StatusHistoryNew,
     ReverseLookupNew,
 };
 use error_stack::ResultExt;
@@ -31,6 +32,8 @@ use crate::{
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+    retry,
+    status_history::{push_status_transition_to_drainer, record_status_transition, CREATE_TRIGGER},
     utils::{self, pg_connection_read, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
@@ -70,6 +73,11 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     additional_payout_method_data: new_payout_attempt
                         .additional_payout_method_data
                         .clone(),
+                    account_name_match_result: None,
+                    fee: None,
+                    net_amount: None,
+                    fee_source: None,
+                    balance_rules_overridden: None,
                     customer_id: new_payout_attempt.customer_id.clone(),
                     merchant_id: new_payout_attempt.merchant_id.clone(),
                     address_id: new_payout_attempt.address_id.clone(),
@@ -133,7 +141,16 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                         key: Some(key_str),
                     }
                     .into()),
-                    Ok(HsetnxReply::KeySet) => Ok(created_attempt),
+                    Ok(HsetnxReply::KeySet) => {
+                        push_payout_attempt_status_transition_to_drainer(
+                            self,
+                            None,
+                            &created_attempt,
+                            CREATE_TRIGGER,
+                        )
+                        .await;
+                        Ok(created_attempt)
+                    }
                     Err(error) => Err(error.change_context(errors::StorageError::KVError)),
                 }
             }
@@ -169,6 +186,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                 let key_str = key.to_string();
 
                 let diesel_payout_update = payout_update.clone().to_storage_model();
+                let trigger: &'static str = (&diesel_payout_update).into();
                 let origin_diesel_payout = this.clone().to_storage_model();
 
                 let diesel_payout = diesel_payout_update
@@ -180,17 +198,6 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     .encode_to_string_of_json()
                     .change_context(errors::StorageError::SerializationFailed)?;
 
-                let redis_entry = kv::TypedSql {
-                    op: kv::DBOperation::Update {
-                        updatable: Box::new(kv::Updateable::PayoutAttemptUpdate(
-                            kv::PayoutAttemptUpdateMems {
-                                orig: origin_diesel_payout,
-                                update_data: diesel_payout_update,
-                            },
-                        )),
-                    },
-                };
-
                 let updated_attempt = PayoutAttempt::from_storage_model(
                     payout_update
                         .to_storage_model()
@@ -227,17 +234,48 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     _ => {}
                 }
 
-                Box::pin(kv_wrapper::<(), _, _>(
-                    self,
-                    KvOperation::<DieselPayoutAttempt>::Hset((&field, redis_value), redis_entry),
-                    key,
-                ))
-                .await
-                .map_err(|err| err.to_redis_failed_response(&key_str))?
-                .try_into_hset()
-                .change_context(errors::StorageError::KVError)?;
+                retry::retry_on_conflict(
+                    self.get_conflict_retry_config(),
+                    "update_payout_attempt",
+                    || {
+                        let redis_entry = kv::TypedSql {
+                            op: kv::DBOperation::Update {
+                                updatable: Box::new(kv::Updateable::PayoutAttemptUpdate(
+                                    kv::PayoutAttemptUpdateMems {
+                                        orig: origin_diesel_payout.clone(),
+                                        update_data: diesel_payout_update.clone(),
+                                    },
+                                )),
+                            },
+                        };
+
+                        async {
+                            Box::pin(kv_wrapper::<(), _, _>(
+                                self,
+                                KvOperation::<DieselPayoutAttempt>::Hset(
+                                    (&field, redis_value.clone()),
+                                    redis_entry,
+                                ),
+                                key.clone(),
+                            ))
+                            .await
+                            .map_err(|err| err.to_redis_failed_response(&key_str))?
+                            .try_into_hset()
+                            .change_context(errors::StorageError::KVError)
+                        }
+                    },
+                )
+                .await?;
 
-                Ok(PayoutAttempt::from_storage_model(diesel_payout))
+                let payout_attempt = PayoutAttempt::from_storage_model(diesel_payout);
+                push_payout_attempt_status_transition_to_drainer(
+                    self,
+                    Some(this.status),
+                    &payout_attempt,
+                    trigger,
+                )
+                .await;
+                Ok(payout_attempt)
             }
         }
     }
@@ -368,6 +406,59 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPayoutAttempt>(
+            self,
+            storage_scheme,
+            Op::Find,
+        ))
+        .await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .find_payout_attempts_by_merchant_id_payout_id(
+                        merchant_id,
+                        payout_id,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let key = PartitionKey::MerchantIdPayoutAttemptId {
+                    merchant_id,
+                    payout_attempt_id: payout_id,
+                };
+                Box::pin(utils::try_redis_get_else_try_database_get(
+                    async {
+                        Box::pin(kv_wrapper(
+                            self,
+                            KvOperation::<DieselPayoutAttempt>::Scan("poa_*"),
+                            key,
+                        ))
+                        .await?
+                        .try_into_scan()
+                    },
+                    || async {
+                        self.router_store
+                            .find_payout_attempts_by_merchant_id_payout_id(
+                                merchant_id,
+                                payout_id,
+                                storage_scheme,
+                            )
+                            .await
+                    },
+                ))
+                .await
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -391,14 +482,17 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
-        new.to_storage_model()
+        let payout_attempt = new
+            .to_storage_model()
             .insert(&conn)
             .await
             .map_err(|er| {
                 let new_err = diesel_error_to_data_error(er.current_context());
                 er.change_context(new_err)
             })
-            .map(PayoutAttempt::from_storage_model)
+            .map(PayoutAttempt::from_storage_model)?;
+        record_payout_attempt_status_transition(self, None, &payout_attempt, CREATE_TRIGGER).await;
+        Ok(payout_attempt)
     }
 
     #[instrument(skip_all)]
@@ -409,16 +503,28 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         _payouts: &Payouts,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
-        let conn = pg_connection_write(self).await?;
-        this.clone()
-            .to_storage_model()
-            .update_with_attempt_id(&conn, payout.to_storage_model())
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(er.current_context());
-                er.change_context(new_err)
-            })
-            .map(PayoutAttempt::from_storage_model)
+        let payout_attempt_update = payout.to_storage_model();
+        let trigger: &'static str = (&payout_attempt_update).into();
+        let payout_attempt = retry::retry_on_conflict(
+            self.get_conflict_retry_config(),
+            "update_payout_attempt",
+            || async {
+                let conn = pg_connection_write(self).await?;
+                this.clone()
+                    .to_storage_model()
+                    .update_with_attempt_id(&conn, payout_attempt_update.clone())
+                    .await
+                    .map_err(|er| {
+                        let new_err = diesel_error_to_data_error(er.current_context());
+                        er.change_context(new_err)
+                    })
+                    .map(PayoutAttempt::from_storage_model)
+            },
+        )
+        .await?;
+        record_payout_attempt_status_transition(self, Some(this.status), &payout_attempt, trigger)
+            .await;
+        Ok(payout_attempt)
     }
 
     #[instrument(skip_all)]
@@ -463,6 +569,28 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_all_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map(|payout_attempts| {
+                payout_attempts
+                    .into_iter()
+                    .map(PayoutAttempt::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -533,6 +661,11 @@ impl DataModelExt for PayoutAttempt {
             unified_code: self.unified_code,
             unified_message: self.unified_message,
             additional_payout_method_data: self.additional_payout_method_data,
+            account_name_match_result: self.account_name_match_result,
+            fee: self.fee,
+            net_amount: self.net_amount,
+            fee_source: self.fee_source,
+            balance_rules_overridden: self.balance_rules_overridden,
         }
     }
 
@@ -560,6 +693,11 @@ impl DataModelExt for PayoutAttempt {
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
             additional_payout_method_data: storage_model.additional_payout_method_data,
+            account_name_match_result: storage_model.account_name_match_result,
+            fee: storage_model.fee,
+            net_amount: storage_model.net_amount,
+            fee_source: storage_model.fee_source,
+            balance_rules_overridden: storage_model.balance_rules_overridden,
         }
     }
 }
@@ -669,6 +807,25 @@ impl DataModelExt for PayoutAttemptUpdate {
             } => DieselPayoutAttemptUpdate::AdditionalPayoutMethodDataUpdate {
                 additional_payout_method_data,
             },
+            Self::AccountNameMatchResultUpdate {
+                account_name_match_result,
+            } => DieselPayoutAttemptUpdate::AccountNameMatchResultUpdate {
+                account_name_match_result,
+            },
+            Self::FeeUpdate {
+                fee,
+                net_amount,
+                fee_source,
+            } => DieselPayoutAttemptUpdate::FeeUpdate {
+                fee,
+                net_amount,
+                fee_source,
+            },
+            Self::BalanceRulesOverrideUpdate {
+                balance_rules_overridden,
+            } => DieselPayoutAttemptUpdate::BalanceRulesOverrideUpdate {
+                balance_rules_overridden,
+            },
         }
     }
 
@@ -704,3 +861,55 @@ async fn add_connector_payout_id_to_reverse_lookup<T: DatabaseStore>(
         .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
         .await
 }
+
+fn payout_attempt_status_transition(
+    previous_status: Option<diesel_models::enums::PayoutStatus>,
+    payout_attempt: &PayoutAttempt,
+    trigger: &str,
+) -> Option<StatusHistoryNew> {
+    (previous_status != Some(payout_attempt.status)).then(|| StatusHistoryNew {
+        merchant_id: payout_attempt.merchant_id.clone(),
+        entity_type: diesel_models::enums::StatusHistoryEntityType::Payout,
+        entity_id: payout_attempt.payout_id.clone(),
+        attempt_id: Some(payout_attempt.payout_attempt_id.clone()),
+        previous_status: previous_status.map(|status| status.to_string()),
+        status: payout_attempt.status.to_string(),
+        trigger: trigger.to_owned(),
+        connector: payout_attempt.connector.clone(),
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+async fn record_payout_attempt_status_transition<T: DatabaseStore>(
+    store: &T,
+    previous_status: Option<diesel_models::enums::PayoutStatus>,
+    payout_attempt: &PayoutAttempt,
+    trigger: &str,
+) {
+    if let Some(status_history) =
+        payout_attempt_status_transition(previous_status, payout_attempt, trigger)
+    {
+        record_status_transition(store, status_history).await
+    }
+}
+
+async fn push_payout_attempt_status_transition_to_drainer<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    previous_status: Option<diesel_models::enums::PayoutStatus>,
+    payout_attempt: &PayoutAttempt,
+    trigger: &str,
+) {
+    if let Some(status_history) =
+        payout_attempt_status_transition(previous_status, payout_attempt, trigger)
+    {
+        push_status_transition_to_drainer::<DieselPayoutAttempt, _>(
+            store,
+            status_history,
+            PartitionKey::MerchantIdPayoutAttemptId {
+                merchant_id: &payout_attempt.merchant_id,
+                payout_attempt_id: &payout_attempt.payout_id,
+            },
+        )
+        .await
+    }
+}
@@ -70,6 +70,7 @@ use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+    retry,
     utils::{self, pg_connection_read, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
@@ -199,24 +200,34 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     .encode_to_string_of_json()
                     .change_context(StorageError::SerializationFailed)?;
 
-                let redis_entry = kv::TypedSql {
-                    op: kv::DBOperation::Update {
-                        updatable: Box::new(kv::Updateable::PayoutsUpdate(kv::PayoutsUpdateMems {
-                            orig: origin_diesel_payout,
-                            update_data: diesel_payout_update,
-                        })),
-                    },
-                };
+                retry::retry_on_conflict(self.get_conflict_retry_config(), "update_payout", || {
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: Box::new(kv::Updateable::PayoutsUpdate(
+                                kv::PayoutsUpdateMems {
+                                    orig: origin_diesel_payout.clone(),
+                                    update_data: diesel_payout_update.clone(),
+                                },
+                            )),
+                        },
+                    };
 
-                Box::pin(kv_wrapper::<(), _, _>(
-                    self,
-                    KvOperation::<DieselPayouts>::Hset((&field, redis_value), redis_entry),
-                    key,
-                ))
-                .await
-                .map_err(|err| err.to_redis_failed_response(&key_str))?
-                .try_into_hset()
-                .change_context(StorageError::KVError)?;
+                    async {
+                        Box::pin(kv_wrapper::<(), _, _>(
+                            self,
+                            KvOperation::<DieselPayouts>::Hset(
+                                (&field, redis_value.clone()),
+                                redis_entry,
+                            ),
+                            key.clone(),
+                        ))
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key_str))?
+                        .try_into_hset()
+                        .change_context(StorageError::KVError)
+                    }
+                })
+                .await?;
 
                 Ok(Payouts::from_storage_model(diesel_payout))
             }
@@ -329,6 +340,24 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         .map(|payout| payout.map(Payouts::from_storage_model))
     }
 
+    #[instrument(skip_all)]
+    async fn filter_payouts_by_profile_id_source_currency_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        source_currency: diesel_models::enums::Currency,
+        status: Vec<diesel_models::enums::PayoutStatus>,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        self.router_store
+            .filter_payouts_by_profile_id_source_currency_status(
+                merchant_id,
+                profile_id,
+                source_currency,
+                status,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -437,16 +466,24 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         _payout_attempt: &PayoutAttempt,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Payouts, StorageError> {
-        let conn = pg_connection_write(self).await?;
-        this.clone()
-            .to_storage_model()
-            .update(&conn, payout.to_storage_model())
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(er.current_context());
-                er.change_context(new_err)
-            })
-            .map(Payouts::from_storage_model)
+        let payout_update = payout.to_storage_model();
+        retry::retry_on_conflict(
+            self.get_conflict_retry_config(),
+            "update_payout",
+            || async {
+                let conn = pg_connection_write(self).await?;
+                this.clone()
+                    .to_storage_model()
+                    .update(&conn, payout_update.clone())
+                    .await
+                    .map_err(|er| {
+                        let new_err = diesel_error_to_data_error(er.current_context());
+                        er.change_context(new_err)
+                    })
+                    .map(Payouts::from_storage_model)
+            },
+        )
+        .await
     }
 
     #[instrument(skip_all)]
@@ -483,6 +520,35 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn filter_payouts_by_profile_id_source_currency_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        source_currency: diesel_models::enums::Currency,
+        status: Vec<diesel_models::enums::PayoutStatus>,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::filter_by_merchant_id_profile_id_source_currency_status(
+            &conn,
+            merchant_id,
+            profile_id,
+            source_currency,
+            status,
+        )
+        .await
+        .map(|payouts| {
+            payouts
+                .into_iter()
+                .map(Payouts::from_storage_model)
+                .collect()
+        })
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
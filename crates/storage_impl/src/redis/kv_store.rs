@@ -122,6 +122,8 @@ pub trait RedisConnInterface {
 /// An enum to represent what operation to do on
 pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     Hset((&'a str, String), TypedSql),
+    /// Sets the field only if the version of its current value matches the expected version
+    HsetIfVersionMatches((&'a str, String), VersionCheck<'a>, TypedSql),
     SetNx(&'a S, TypedSql),
     HSetNx(&'a str, &'a S, TypedSql),
     HGet(&'a str),
@@ -129,12 +131,19 @@ pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     Scan(&'a str),
 }
 
+/// The version that the value stored in a field is expected to have for the field to be updated
+pub struct VersionCheck<'a> {
+    pub version_field: &'a str,
+    pub expected_version: i64,
+}
+
 #[derive(TryGetEnumVariant)]
 #[error(RedisError::UnknownResult)]
 pub enum KvResult<T: de::DeserializeOwned> {
     HGet(T),
     Get(T),
     Hset(()),
+    HsetIfVersionMatches(bool),
     SetNx(redis_interface::SetnxReply),
     HSetNx(redis_interface::HsetnxReply),
     Scan(Vec<T>),
@@ -147,6 +156,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             KvOperation::Hset(_, _) => f.write_str("Hset"),
+            KvOperation::HsetIfVersionMatches(_, _, _) => f.write_str("HsetIfVersionMatches"),
             KvOperation::SetNx(_, _) => f.write_str("Setnx"),
             KvOperation::HSetNx(_, _, _) => f.write_str("HSetNx"),
             KvOperation::HGet(_) => f.write_str("Hget"),
@@ -191,6 +201,30 @@ where
                 Ok(KvResult::Hset(()))
             }
 
+            KvOperation::HsetIfVersionMatches((field, value), version_check, sql) => {
+                logger::debug!(kv_operation= %operation, value = ?value);
+
+                let is_set = redis_conn
+                    .set_hash_field_if_version_matches(
+                        &key,
+                        field,
+                        value,
+                        version_check.version_field,
+                        version_check.expected_version,
+                        Some(ttl),
+                    )
+                    .await?;
+
+                // A stale update is neither written nor drained to the database
+                if is_set {
+                    store
+                        .push_to_drainer_stream::<S>(sql, partition_key)
+                        .await?;
+                }
+
+                Ok(KvResult::HsetIfVersionMatches(is_set))
+            }
+
             KvOperation::HGet(field) => {
                 let result = redis_conn
                     .get_hash_field_and_deserialize(&key, field, type_name)
@@ -0,0 +1,80 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use hyperswitch_domain_models::errors::StorageError;
+use router_env::{logger, metrics::add_attributes};
+
+use crate::{config::ConflictRetryConfig, metrics};
+
+/// Performs a write, retrying it with a jittered exponential backoff when it fails due to a
+/// conflict with a concurrent write. The write must be idempotent, since a write to the KV store
+/// may have been partially applied before it failed.
+pub(crate) async fn retry_on_conflict<F, Fut, T>(
+    config: &ConflictRetryConfig,
+    operation: &'static str,
+    write: F,
+) -> error_stack::Result<T, StorageError>
+where
+    F: Fn() -> Fut,
+    Fut: futures::Future<Output = error_stack::Result<T, StorageError>>,
+{
+    let mut retry_count = 0;
+    loop {
+        let result = write().await;
+        let Err(error) = &result else {
+            return result;
+        };
+        if !config.enabled || !is_write_conflict(error.current_context()) {
+            return result;
+        }
+        if retry_count >= config.max_retries {
+            metrics::WRITE_CONFLICT_RETRIES_EXHAUSTED.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("operation", operation)]),
+            );
+            return result;
+        }
+
+        retry_count += 1;
+        let delay = get_backoff_delay(config, retry_count);
+        logger::warn!(
+            ?error,
+            operation,
+            retry_count,
+            delay_in_millis = delay.as_millis(),
+            "Retrying write which conflicted with a concurrent write"
+        );
+        metrics::WRITE_CONFLICT_RETRY_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("operation", operation)]),
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+fn is_write_conflict(error: &StorageError) -> bool {
+    match error {
+        StorageError::DatabaseError(database_error) => matches!(
+            database_error.current_context(),
+            diesel_models::errors::DatabaseError::SerializationFailure
+        ),
+        StorageError::KVError => true,
+        _ => false,
+    }
+}
+
+/// A random delay up to the exponential backoff of the retry, bounded by the highest delay
+fn get_backoff_delay(config: &ConflictRetryConfig, retry_count: u8) -> Duration {
+    let backoff_in_millis = config
+        .base_delay_in_millis
+        .saturating_mul(2_u64.saturating_pow(u32::from(retry_count.saturating_sub(1))))
+        .min(config.max_delay_in_millis);
+    let jitter = RandomState::new().build_hasher().finish();
+
+    Duration::from_millis(jitter % backoff_in_millis.saturating_add(1))
+}
@@ -0,0 +1,64 @@
+use diesel_models::{kv, status_history::StatusHistoryNew};
+use hyperswitch_domain_models::errors::StorageError;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    diesel_error_to_data_error,
+    redis::kv_store::{KvStorePartition, PartitionKey},
+    utils::pg_connection_write,
+    DatabaseStore, KVRouterStore,
+};
+
+/// The trigger recorded for the initial status of an entity
+pub const CREATE_TRIGGER: &str = "create";
+
+/// Persists a status transition of a payment, refund or payout.
+///
+/// Recording the status history is best effort, a failure is logged and does not fail the
+/// operation which caused the transition.
+#[instrument(skip_all)]
+pub async fn record_status_transition<T: DatabaseStore>(
+    store: &T,
+    status_history: StatusHistoryNew,
+) {
+    let result: error_stack::Result<_, StorageError> = async {
+        let conn = pg_connection_write(store).await?;
+        status_history.insert(&conn).await.map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to record status transition");
+    }
+}
+
+/// Pushes a status transition of an entity stored in redis to the drainer stream, so that it is
+/// persisted by the drainer along with the entity itself.
+///
+/// The transition is pushed to the shard of the entity, `R`, so that the drainer inserts it in
+/// the order of the writes to the entity. Like [`record_status_transition`], this is best effort.
+#[instrument(skip_all)]
+pub async fn push_status_transition_to_drainer<R, T>(
+    store: &KVRouterStore<T>,
+    status_history: StatusHistoryNew,
+    partition_key: PartitionKey<'_>,
+) where
+    R: KvStorePartition,
+    T: DatabaseStore,
+{
+    let redis_entry = kv::TypedSql {
+        op: kv::DBOperation::Insert {
+            insertable: Box::new(kv::Insertable::StatusHistory(status_history)),
+        },
+    };
+
+    if let Err(error) = store
+        .push_to_drainer_stream::<R>(redis_entry, partition_key)
+        .await
+    {
+        logger::error!(?error, "Failed to push status transition to drainer stream");
+    }
+}
@@ -43,6 +43,7 @@ pub struct ConnectorAuthentication {
     pub fiservemea: Option<HeaderKey>,
     pub fiuu: Option<HeaderKey>,
     pub forte: Option<MultiAuthKey>,
+    pub generichttp: Option<HeaderKey>,
     pub globalpay: Option<BodyKey>,
     pub globepay: Option<BodyKey>,
     pub gocardless: Option<HeaderKey>,
@@ -72,6 +73,7 @@ pub struct ConnectorAuthentication {
     pub paypal: Option<BodyKey>,
     pub payu: Option<BodyKey>,
     pub placetopay: Option<BodyKey>,
+    pub plugin: Option<HeaderKey>,
     pub plaid: Option<BodyKey>,
     pub powertranz: Option<BodyKey>,
     pub prophetpay: Option<HeaderKey>,
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS status_history_merchant_id_entity_id_index;
+DROP TABLE IF EXISTS status_history;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS status_history (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64),
+    previous_status VARCHAR(64),
+    status VARCHAR(64) NOT NULL,
+    trigger VARCHAR(64) NOT NULL,
+    connector VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS status_history_merchant_id_entity_id_index ON status_history (merchant_id, entity_type, entity_id);
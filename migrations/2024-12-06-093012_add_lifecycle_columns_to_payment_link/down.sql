@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_link DROP COLUMN IF EXISTS deactivated_at;
+
+ALTER TABLE payment_link DROP COLUMN IF EXISTS link_token;
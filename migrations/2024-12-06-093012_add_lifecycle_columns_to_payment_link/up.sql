@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_link ADD COLUMN IF NOT EXISTS deactivated_at TIMESTAMP;
+
+ALTER TABLE payment_link ADD COLUMN IF NOT EXISTS link_token VARCHAR(64);
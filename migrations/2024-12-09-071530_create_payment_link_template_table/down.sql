@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_link_template_id_index;
+
+ALTER TABLE payment_link DROP COLUMN IF EXISTS payment_link_template_id;
+
+DROP TABLE IF EXISTS payment_link_template;
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_link_template (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    link_to_pay VARCHAR(255) NOT NULL,
+    amount BIGINT,
+    min_amount BIGINT,
+    max_amount BIGINT,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    payment_link_config JSONB,
+    session_expiry INTEGER,
+    usage_limit INTEGER,
+    usage_count INTEGER NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payment_link_template_merchant_id_index ON payment_link_template (merchant_id);
+
+ALTER TABLE payment_link ADD COLUMN IF NOT EXISTS payment_link_template_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS payment_link_template_id_index ON payment_link (payment_link_template_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS customer_portal_link_config;
+
+-- Postgres does not support removing values from an enum, `customer_portal` is left in "GenericLinkType"
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "GenericLinkType" ADD VALUE IF NOT EXISTS 'customer_portal';
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS customer_portal_link_config JSONB DEFAULT NULL;
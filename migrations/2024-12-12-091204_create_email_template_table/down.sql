@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS email_template_profile_id_email_type_locale_index;
+
+DROP TABLE IF EXISTS email_template;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS link_delivery_merchant_id_link_id_index;
+
+DROP TABLE IF EXISTS link_delivery;
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS link_delivery (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    link_id VARCHAR(64) NOT NULL,
+    link_type VARCHAR(32) NOT NULL,
+    channel VARCHAR(32) NOT NULL,
+    provider VARCHAR(64) NOT NULL,
+    provider_message_id VARCHAR(128),
+    masked_recipient VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS link_delivery_merchant_id_link_id_index ON link_delivery (merchant_id, link_id);
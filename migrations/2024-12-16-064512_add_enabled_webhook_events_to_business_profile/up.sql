@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS enabled_webhook_events "EventClass"[] DEFAULT NULL;
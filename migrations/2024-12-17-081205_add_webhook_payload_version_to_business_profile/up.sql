@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS webhook_payload_version VARCHAR(16) DEFAULT NULL;
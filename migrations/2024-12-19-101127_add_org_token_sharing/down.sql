@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS shared_payment_method_source_merchant_id_index;
+
+DROP INDEX IF EXISTS shared_payment_method_source_target_index;
+
+DROP TABLE IF EXISTS shared_payment_method;
+
+ALTER TABLE organization DROP COLUMN IF EXISTS is_token_sharing_enabled;
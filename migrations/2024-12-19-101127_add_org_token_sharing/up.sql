@@ -0,0 +1,17 @@
+-- Your SQL goes here
+ALTER TABLE organization ADD COLUMN IF NOT EXISTS is_token_sharing_enabled BOOLEAN DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS shared_payment_method (
+    id VARCHAR(64) PRIMARY KEY,
+    organization_id VARCHAR(32) NOT NULL,
+    source_merchant_id VARCHAR(64) NOT NULL,
+    source_payment_method_id VARCHAR(64) NOT NULL,
+    target_merchant_id VARCHAR(64) NOT NULL,
+    target_payment_method_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS shared_payment_method_source_target_index ON shared_payment_method (source_payment_method_id, target_merchant_id);
+
+CREATE INDEX IF NOT EXISTS shared_payment_method_source_merchant_id_index ON shared_payment_method (source_merchant_id);
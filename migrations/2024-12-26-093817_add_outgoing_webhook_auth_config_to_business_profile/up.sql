@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS outgoing_webhook_auth_config BYTEA DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+-- Payments older than the retention period are moved to these tables by the archival job.
+-- Columns added to payment_intent or payment_attempt must be added to these tables as well.
+CREATE TABLE IF NOT EXISTS payment_intent_archive (LIKE payment_intent INCLUDING ALL);
+
+CREATE TABLE IF NOT EXISTS payment_attempt_archive (LIKE payment_attempt INCLUDING ALL);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS merchant_account_previous_publishable_key_index;
+
+ALTER TABLE merchant_account
+DROP COLUMN IF EXISTS previous_publishable_key,
+DROP COLUMN IF EXISTS previous_publishable_key_expires_at;
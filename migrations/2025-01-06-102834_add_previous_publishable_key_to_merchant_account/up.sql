@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN IF NOT EXISTS previous_publishable_key VARCHAR(128) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS previous_publishable_key_expires_at TIMESTAMP DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS merchant_account_previous_publishable_key_index ON merchant_account (previous_publishable_key);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS admin_api_keys_hashed_api_key_index;
+
+DROP TABLE IF EXISTS admin_api_keys;
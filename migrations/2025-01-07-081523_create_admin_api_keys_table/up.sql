@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS admin_api_keys (
+    key_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    name VARCHAR(64) NOT NULL,
+    description VARCHAR(256) DEFAULT NULL,
+    hashed_api_key VARCHAR(128) NOT NULL,
+    prefix VARCHAR(16) NOT NULL,
+    role_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP DEFAULT NULL,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS admin_api_keys_hashed_api_key_index ON admin_api_keys (hashed_api_key);
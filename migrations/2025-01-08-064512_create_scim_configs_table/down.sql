@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS scim_configs_merchant_id_index;
+
+DROP INDEX IF EXISTS scim_configs_hashed_token_index;
+
+DROP TABLE IF EXISTS scim_configs;
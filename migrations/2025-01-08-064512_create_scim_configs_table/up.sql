@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS scim_configs (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    org_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    hashed_token VARCHAR(128) NOT NULL,
+    token_prefix VARCHAR(16) NOT NULL,
+    group_role_mappings JSONB NOT NULL DEFAULT '[]'::JSONB,
+    default_role_id VARCHAR(64) DEFAULT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS scim_configs_hashed_token_index ON scim_configs (hashed_token);
+
+CREATE UNIQUE INDEX IF NOT EXISTS scim_configs_merchant_id_index ON scim_configs (merchant_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS email_domain_index;
+
+ALTER TABLE user_authentication_methods DROP COLUMN IF EXISTS email_domain;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE user_authentication_methods ADD COLUMN IF NOT EXISTS email_domain VARCHAR(255) DEFAULT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS email_domain_index ON user_authentication_methods (email_domain);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS user_webauthn_credentials_credential_id_index;
+
+DROP INDEX IF EXISTS user_webauthn_credentials_user_id_index;
+
+DROP TABLE IF EXISTS user_webauthn_credentials;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS user_webauthn_credentials (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    user_id VARCHAR(64) NOT NULL,
+    credential_id VARCHAR(1024) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    credential JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_used_at TIMESTAMP DEFAULT NULL
+);
+
+CREATE INDEX IF NOT EXISTS user_webauthn_credentials_user_id_index ON user_webauthn_credentials (user_id);
+
+CREATE UNIQUE INDEX IF NOT EXISTS user_webauthn_credentials_credential_id_index ON user_webauthn_credentials (credential_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS fallback_payout_id VARCHAR(64) DEFAULT NULL;
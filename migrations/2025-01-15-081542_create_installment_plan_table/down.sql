@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS installment_plan_merchant_id_customer_id_index;
+
+DROP TABLE IF EXISTS installment_plan;
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS installment_plan (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    currency "Currency" NOT NULL,
+    total_amount BIGINT NOT NULL,
+    installment_count INTEGER NOT NULL,
+    interval_days INTEGER NOT NULL,
+    installments JSONB NOT NULL,
+    max_retries_per_installment INTEGER NOT NULL,
+    retry_interval_hours INTEGER NOT NULL,
+    max_missed_installments INTEGER NOT NULL,
+    missed_count INTEGER NOT NULL DEFAULT 0,
+    next_charge_at TIMESTAMP,
+    description VARCHAR(255),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS installment_plan_merchant_id_customer_id_index ON installment_plan (merchant_id, customer_id);
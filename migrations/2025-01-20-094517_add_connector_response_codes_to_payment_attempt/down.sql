@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS connector_response_codes;
+
+ALTER TABLE payment_attempt_archive DROP COLUMN IF EXISTS connector_response_codes;
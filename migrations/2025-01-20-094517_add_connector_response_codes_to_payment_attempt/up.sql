@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS connector_response_codes JSONB DEFAULT NULL;
+
+ALTER TABLE payment_attempt_archive ADD COLUMN IF NOT EXISTS connector_response_codes JSONB DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS avs_result,
+DROP COLUMN IF EXISTS cvv_result;
+
+ALTER TABLE payment_attempt_archive
+DROP COLUMN IF EXISTS avs_result,
+DROP COLUMN IF EXISTS cvv_result;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS avs_result VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS cvv_result VARCHAR(32) DEFAULT NULL;
+
+ALTER TABLE payment_attempt_archive
+ADD COLUMN IF NOT EXISTS avs_result VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS cvv_result VARCHAR(32) DEFAULT NULL;
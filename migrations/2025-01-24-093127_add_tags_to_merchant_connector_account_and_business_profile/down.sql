@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account DROP COLUMN IF EXISTS tags;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS tags;
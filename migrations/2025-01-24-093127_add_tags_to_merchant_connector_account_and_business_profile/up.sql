@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS tags TEXT[] DEFAULT NULL;
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS tags TEXT[] DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS recipient_kyc_status VARCHAR(32);
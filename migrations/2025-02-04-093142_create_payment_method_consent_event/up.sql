@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_method_consent_event (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    payment_method_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64),
+    setup_future_usage "FutureUsage" NOT NULL,
+    consent_text_version VARCHAR(64),
+    ip_address VARCHAR(64),
+    user_agent VARCHAR(255),
+    accepted_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payment_method_consent_event_merchant_id_payment_method_id_index ON payment_method_consent_event (merchant_id, payment_method_id);
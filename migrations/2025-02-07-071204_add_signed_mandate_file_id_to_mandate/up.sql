@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE mandate ADD COLUMN IF NOT EXISTS signed_mandate_file_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund
+DROP COLUMN IF EXISTS expected_settlement_date,
+DROP COLUMN IF EXISTS status_reason;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS expected_settlement_date TIMESTAMP DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS status_reason VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payout_attempt DROP COLUMN IF EXISTS account_name_match_result;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS account_name_mismatch_action;
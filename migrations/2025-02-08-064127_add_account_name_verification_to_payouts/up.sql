@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payout_attempt ADD COLUMN IF NOT EXISTS account_name_match_result VARCHAR(64);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS account_name_mismatch_action VARCHAR(64);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS credentials_expiry JSONB;
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'connector_accounts';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'connector_credentials_expiry_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_credentials_expiring';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_template_merchant_id_index;
+
+DROP INDEX IF EXISTS payment_template_profile_id_name_index;
+
+DROP TABLE IF EXISTS payment_template;
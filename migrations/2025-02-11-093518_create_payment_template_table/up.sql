@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_template (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    currency "Currency",
+    capture_method "CaptureMethod",
+    metadata JSONB,
+    statement_descriptor_name VARCHAR(255),
+    statement_descriptor_suffix VARCHAR(255),
+    allowed_payment_method_types JSONB,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS payment_template_profile_id_name_index ON payment_template (profile_id, name);
+
+CREATE INDEX IF NOT EXISTS payment_template_merchant_id_index ON payment_template (merchant_id);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS presentment_currency,
+DROP COLUMN IF EXISTS presentment_amount,
+DROP COLUMN IF EXISTS exchange_rate;
+
+ALTER TABLE payment_attempt_archive
+DROP COLUMN IF EXISTS presentment_currency,
+DROP COLUMN IF EXISTS presentment_amount,
+DROP COLUMN IF EXISTS exchange_rate;
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS presentment_currency "Currency" DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS presentment_amount BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS exchange_rate VARCHAR(32) DEFAULT NULL;
+
+ALTER TABLE payment_attempt_archive
+ADD COLUMN IF NOT EXISTS presentment_currency "Currency" DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS presentment_amount BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS exchange_rate VARCHAR(32) DEFAULT NULL;
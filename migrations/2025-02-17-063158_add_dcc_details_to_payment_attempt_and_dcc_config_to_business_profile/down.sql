@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS dcc_details;
+
+ALTER TABLE payment_attempt_archive
+DROP COLUMN IF EXISTS dcc_details;
+
+ALTER TABLE business_profile
+DROP COLUMN IF EXISTS dcc_config;
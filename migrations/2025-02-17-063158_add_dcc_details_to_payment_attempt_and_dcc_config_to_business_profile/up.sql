@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS dcc_details JSONB DEFAULT NULL;
+
+ALTER TABLE payment_attempt_archive
+ADD COLUMN IF NOT EXISTS dcc_details JSONB DEFAULT NULL;
+
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS dcc_config JSONB DEFAULT NULL;
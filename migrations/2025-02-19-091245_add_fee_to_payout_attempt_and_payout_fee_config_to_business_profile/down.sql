@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payout_attempt
+DROP COLUMN IF EXISTS fee,
+DROP COLUMN IF EXISTS net_amount,
+DROP COLUMN IF EXISTS fee_source;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS payout_fee_config;
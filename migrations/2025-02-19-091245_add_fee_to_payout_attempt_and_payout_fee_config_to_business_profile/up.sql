@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE payout_attempt
+ADD COLUMN IF NOT EXISTS fee BIGINT,
+ADD COLUMN IF NOT EXISTS net_amount BIGINT,
+ADD COLUMN IF NOT EXISTS fee_source VARCHAR(32);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS payout_fee_config JSONB;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS ledger_entry_transaction_id_index;
+
+DROP INDEX IF EXISTS ledger_entry_profile_id_account_currency_created_at_index;
+
+DROP INDEX IF EXISTS ledger_entry_merchant_id_source_type_source_id_account_index;
+
+DROP TABLE IF EXISTS ledger_entry;
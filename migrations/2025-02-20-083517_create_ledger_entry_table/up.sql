@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS ledger_entry (
+    id VARCHAR(64) PRIMARY KEY,
+    transaction_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    account VARCHAR(32) NOT NULL,
+    direction VARCHAR(16) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    source_type VARCHAR(32) NOT NULL,
+    source_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_entry_merchant_id_source_type_source_id_account_index ON ledger_entry (merchant_id, source_type, source_id, account);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_profile_id_account_currency_created_at_index ON ledger_entry (profile_id, account, currency, created_at);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_transaction_id_index ON ledger_entry (transaction_id);
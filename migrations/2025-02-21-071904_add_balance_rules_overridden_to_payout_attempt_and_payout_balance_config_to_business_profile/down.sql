@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payout_attempt DROP COLUMN IF EXISTS balance_rules_overridden;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS payout_balance_config;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payout_attempt ADD COLUMN IF NOT EXISTS balance_rules_overridden BOOLEAN;
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS payout_balance_config JSONB;
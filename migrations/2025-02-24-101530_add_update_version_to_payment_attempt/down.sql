@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS update_version;
+
+ALTER TABLE payment_attempt_archive
+DROP COLUMN IF EXISTS update_version;
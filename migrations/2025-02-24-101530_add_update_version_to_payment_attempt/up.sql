@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS update_version INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE payment_attempt_archive
+ADD COLUMN IF NOT EXISTS update_version INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS shared_payment_method_customer_target_index;
+
+ALTER TABLE shared_payment_method
+DROP COLUMN IF EXISTS target_customer_id;
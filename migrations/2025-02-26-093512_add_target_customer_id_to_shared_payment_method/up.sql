@@ -0,0 +1,14 @@
+-- Your SQL goes here
+ALTER TABLE shared_payment_method
+ADD COLUMN IF NOT EXISTS target_customer_id VARCHAR(64);
+
+-- Payment methods shared before the mapping was recorded were saved against a customer with the
+-- same customer ID in the target merchant account
+UPDATE shared_payment_method
+SET target_customer_id = customer_id
+WHERE target_customer_id IS NULL;
+
+ALTER TABLE shared_payment_method
+ALTER COLUMN target_customer_id SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS shared_payment_method_customer_target_index ON shared_payment_method (source_merchant_id, customer_id, target_merchant_id);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS shipment_capture (
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    shipment_id VARCHAR(64) NOT NULL,
+    line_items JSONB,
+    amount_to_capture BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, payment_id, shipment_id)
+);
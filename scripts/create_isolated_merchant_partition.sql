@@ -0,0 +1,34 @@
+-- Isolates the payment attempts of a merchant in a partition of their own. The payment_attempt table
+-- of the tenant schema must have been partitioned by scripts/partition_payment_attempt_by_merchant.sql.
+--
+-- Usage: psql -v schema=public -v merchant_id=merchant_1668273825 -f scripts/create_isolated_merchant_partition.sql
+-- The partition cannot be created while the default partition holds payment attempts of the merchant,
+-- such rows have to be moved out of the default partition before the merchant is isolated.
+
+BEGIN;
+
+SET LOCAL search_path TO :"schema";
+
+-- psql variables are not interpolated within the function body, the merchant id is passed through a
+-- setting instead
+SELECT set_config('partitioning.merchant_id', :'merchant_id', true);
+
+DO $$
+DECLARE
+    merchant_id TEXT := current_setting('partitioning.merchant_id');
+    partition_name TEXT := 'payment_attempt_' || merchant_id;
+BEGIN
+    -- Longer identifiers are truncated silently, which could map merchants to the same partition
+    IF length(partition_name) > 63 THEN
+        RAISE EXCEPTION 'Partition name % exceeds 63 characters', partition_name;
+    END IF;
+
+    EXECUTE format(
+        'CREATE TABLE IF NOT EXISTS %I PARTITION OF payment_attempt FOR VALUES IN (%L)',
+        partition_name,
+        merchant_id
+    );
+END
+$$;
+
+COMMIT;
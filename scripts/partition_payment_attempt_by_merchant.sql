@@ -0,0 +1,46 @@
+-- Converts the payment_attempt table of a tenant schema into a table list partitioned by merchant_id.
+-- The existing rows are retained in the default partition, the partitions of the isolated merchants
+-- are created by scripts/create_isolated_merchant_partition.sql, or by the application on startup when
+-- `partitioning.payment_attempt.enabled` is set.
+--
+-- Usage: psql -v schema=public -f scripts/partition_payment_attempt_by_merchant.sql
+-- Run this during a maintenance window, since the table is locked while it is converted.
+
+BEGIN;
+
+SET LOCAL search_path TO :"schema";
+
+-- Retain the existing table, along with its indexes, as the default partition
+ALTER TABLE payment_attempt RENAME TO payment_attempt_default;
+
+ALTER TABLE payment_attempt_default RENAME CONSTRAINT payment_attempt_pkey TO payment_attempt_default_pkey;
+
+ALTER INDEX payment_attempt_payment_id_merchant_id_attempt_id_index RENAME TO payment_attempt_default_payment_id_merchant_id_attempt_id_index;
+
+ALTER INDEX payment_attempt_payment_id_merchant_id_index RENAME TO payment_attempt_default_payment_id_merchant_id_index;
+
+ALTER INDEX payment_attempt_attempt_id_merchant_id_index RENAME TO payment_attempt_default_attempt_id_merchant_id_index;
+
+ALTER INDEX payment_attempt_connector_transaction_id_merchant_id_index RENAME TO payment_attempt_default_connector_transaction_id_merchant_id_index;
+
+ALTER INDEX preprocessing_step_id_index RENAME TO payment_attempt_default_preprocessing_step_id_index;
+
+CREATE TABLE payment_attempt (LIKE payment_attempt_default INCLUDING DEFAULTS INCLUDING CONSTRAINTS)
+PARTITION BY LIST (merchant_id);
+
+ALTER TABLE payment_attempt ADD PRIMARY KEY (attempt_id, merchant_id);
+
+ALTER TABLE payment_attempt ATTACH PARTITION payment_attempt_default DEFAULT;
+
+-- The matching indexes of the default partition are attached to these, new partitions get their own
+CREATE UNIQUE INDEX payment_attempt_payment_id_merchant_id_attempt_id_index ON payment_attempt (payment_id, merchant_id, attempt_id);
+
+CREATE INDEX payment_attempt_payment_id_merchant_id_index ON payment_attempt (payment_id, merchant_id);
+
+CREATE INDEX payment_attempt_attempt_id_merchant_id_index ON payment_attempt (attempt_id, merchant_id);
+
+CREATE INDEX payment_attempt_connector_transaction_id_merchant_id_index ON payment_attempt (connector_transaction_id, merchant_id);
+
+CREATE INDEX preprocessing_step_id_index ON payment_attempt (preprocessing_step_id);
+
+COMMIT;